@@ -1,24 +1,35 @@
 //! Unified MCP server for all agentic-tools.
 //!
 //! This binary exposes all 19+ tools from the various domain crates through a single
-//! MCP stdio server, with optional allowlist filtering.
+//! MCP server, with optional allowlist filtering. It speaks stdio by default, or
+//! streamable HTTP with bearer-token auth when started with `--http`.
 
 #[cfg(not(unix))]
 compile_error!(
     "agentic-mcp only supports Unix-like platforms (Linux/macOS). Windows is not supported."
 );
 
+mod resources;
+
 use agentic_config::loader::load_merged;
+use agentic_config::types::LoggingConfig;
+use agentic_logging::LogPolicy;
+use agentic_logging::RedactionPolicy;
 use agentic_tools_core::fmt::TextOptions;
 use agentic_tools_mcp::OutputMode;
 use agentic_tools_mcp::RegistryServer;
 use agentic_tools_mcp::ServiceExt;
+use agentic_tools_mcp::http::HttpOptions;
+use agentic_tools_mcp::http::MCP_PATH;
 use agentic_tools_mcp::stdio;
 use agentic_tools_registry::AgenticTools;
 use agentic_tools_registry::AgenticToolsConfig;
 use clap::Parser;
 use colored::Colorize;
+use resources::AgenticResources;
+use resources::ThoughtsResources;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs;
 use std::sync::Arc;
@@ -47,6 +58,23 @@ struct Args {
     #[arg(long)]
     suppress_search_reminder: bool,
 
+    /// Named profile from the server config whose tools to expose
+    #[arg(long, value_name = "NAME", env = "AGENTIC_MCP_PROFILE")]
+    profile: Option<String>,
+
+    /// Serve streamable HTTP on this address (e.g. `127.0.0.1:8765`) instead of stdio
+    #[arg(long, value_name = "ADDR")]
+    http: Option<String>,
+
+    /// Bearer token granting access to every enabled tool over HTTP
+    #[arg(
+        long,
+        value_name = "TOKEN",
+        env = "AGENTIC_MCP_HTTP_TOKEN",
+        hide_env_values = true
+    )]
+    http_token: Option<String>,
+
     // Convenience flags for individual tool filtering
     // TODO(3): Probably don't need these convenience flags. They are kinda archaic for the old
     // agentic-tools setup. We likely can remove them after ensuring no one else uses them.
@@ -73,24 +101,85 @@ struct Args {
     /// Enable `cli_just_execute` tool
     #[arg(long)]
     cli_just_execute: bool,
+
+    /// Enable `cli_batch` tool
+    #[arg(long)]
+    cli_batch: bool,
 }
 
 #[derive(Deserialize)]
 struct FileConfig {
     allowlist: Option<HashSet<String>>,
     output: Option<String>,
+    /// Per-domain sections and `plugins`; see `agentic_tools_registry::domains`.
+    #[serde(default)]
+    extras: serde_json::Value,
+    #[serde(default)]
+    http: HttpFileConfig,
+    /// Named allowlists, selected by `--profile` or by clients at `initialize`.
+    #[serde(default)]
+    profiles: HashMap<String, HashSet<String>>,
 }
 
-fn parse_config(args: &Args) -> (AgenticToolsConfig, Option<String>) {
+/// Server config settings that are not part of the tool registry config.
+#[derive(Default)]
+struct ServerSettings {
+    output: Option<String>,
+    http: HttpFileConfig,
+    profiles: HashMap<String, HashSet<String>>,
+}
+
+/// `http` section of the server config: tokens accepted by `--http`.
+#[derive(Deserialize, Default)]
+struct HttpFileConfig {
+    #[serde(default)]
+    tokens: Vec<HttpToken>,
+    /// `Host` headers to accept; defaults to loopback only.
+    allowed_hosts: Option<Vec<String>>,
+}
+
+#[derive(Deserialize)]
+struct HttpToken {
+    token: String,
+    /// Tools this token may use, within the server's own allowlist.
+    allowlist: Option<HashSet<String>>,
+}
+
+impl HttpFileConfig {
+    fn into_options(self, cli_token: Option<String>) -> HttpOptions {
+        let mut options = HttpOptions::new();
+        if let Some(token) = cli_token {
+            options = options.with_token(token);
+        }
+        for HttpToken { token, allowlist } in self.tokens {
+            options = match allowlist {
+                Some(allowlist) => options.with_restricted_token(token, allowlist),
+                None => options.with_token(token),
+            };
+        }
+        if let Some(hosts) = self.allowed_hosts {
+            options = options.with_allowed_hosts(hosts);
+        }
+        options
+    }
+}
+
+fn parse_config(args: &Args) -> anyhow::Result<(AgenticToolsConfig, ServerSettings)> {
     // Parse server config if provided
     let mut allowlist: Option<HashSet<String>> = None;
-    let mut file_output: Option<String> = None;
+    let mut extras = serde_json::Value::Null;
+    let mut settings = ServerSettings::default();
     if let Some(path) = args.server_config.as_deref() {
         match fs::read_to_string(path) {
             Ok(s) => {
                 if let Ok(fc) = serde_json::from_str::<FileConfig>(&s) {
                     allowlist = fc.allowlist;
-                    file_output = fc.output;
+                    extras = fc.extras;
+                    settings = ServerSettings {
+                        output: fc.output,
+                        http: fc.http,
+                        profiles: fc.profiles,
+                    };
                 } else {
                     eprintln!("Warning: Failed to parse config JSON; ignoring");
                 }
@@ -133,18 +222,103 @@ fn parse_config(args: &Args) -> (AgenticToolsConfig, Option<String>) {
     if args.cli_just_execute {
         flag_set.insert("cli_just_execute".to_string());
     }
+    if args.cli_batch {
+        flag_set.insert("cli_batch".to_string());
+    }
 
     if !flag_set.is_empty() {
         allowlist.get_or_insert_with(HashSet::new).extend(flag_set);
     }
 
-    (
+    // --profile narrows whatever the allowlist already permits
+    if let Some(name) = args.profile.as_deref() {
+        let Some(profile) = settings.profiles.get(name) else {
+            anyhow::bail!(
+                "unknown profile '{name}'; define it under `profiles` in --server-config"
+            );
+        };
+        allowlist = Some(match allowlist {
+            Some(set) => set.intersection(profile).cloned().collect(),
+            None => profile.clone(),
+        });
+    }
+
+    Ok((
         AgenticToolsConfig {
             allowlist,
+            extras,
             ..Default::default()
         },
-        file_output,
-    )
+        settings,
+    ))
+}
+
+/// Tool call log rotation/retention from `[logging]`; zero disables each setting.
+fn tool_log_policy(cfg: &LoggingConfig) -> LogPolicy {
+    let non_zero = |days: u32| (days > 0).then_some(days);
+    LogPolicy {
+        max_file_bytes: (cfg.tool_logs_max_file_mb > 0)
+            .then(|| cfg.tool_logs_max_file_mb.saturating_mul(1024 * 1024)),
+        retention_days: non_zero(cfg.tool_logs_retention_days),
+        compress_after_days: non_zero(cfg.tool_logs_compress_after_days),
+        prune_on_write: cfg.tool_logs_prune_on_write,
+    }
+}
+
+/// Request/response redaction from `[logging]`; built-in rules for the listed servers.
+fn tool_log_redaction(cfg: &LoggingConfig) -> RedactionPolicy {
+    let max_content_bytes = (cfg.redact_max_content_kb > 0).then(|| {
+        usize::try_from(cfg.redact_max_content_kb.saturating_mul(1024)).unwrap_or(usize::MAX)
+    });
+    RedactionPolicy::builtin(cfg.redact_servers.iter().cloned(), max_content_bytes)
+}
+
+/// Install the OTLP span exporter when `[logging] otlp_endpoint` is set.
+#[cfg(feature = "otlp")]
+fn start_otlp_export(cfg: &LoggingConfig) {
+    let Some(endpoint) = cfg
+        .otlp_endpoint
+        .as_deref()
+        .filter(|e| !e.trim().is_empty())
+    else {
+        return;
+    };
+    let exporter = agentic_logging::OtlpExporter::start(agentic_logging::OtlpConfig {
+        service_name: cfg.otlp_service_name.clone(),
+        headers: cfg
+            .otlp_headers
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+        ..agentic_logging::OtlpConfig::new(endpoint.trim())
+    });
+    agentic_logging::install_exporter(exporter);
+}
+
+#[cfg(not(feature = "otlp"))]
+fn start_otlp_export(cfg: &LoggingConfig) {
+    if cfg.otlp_endpoint.is_some() {
+        eprintln!(
+            "{} logging.otlp_endpoint is set but agentic-mcp was built without the `otlp` feature; spans will not be exported",
+            "WARN".yellow()
+        );
+    }
+}
+
+/// Mirror tool call records into the `SQLite` index when `[logging] tool_logs_sqlite_index` is set.
+#[cfg(feature = "sqlite")]
+fn start_sqlite_index(cfg: &LoggingConfig) {
+    agentic_logging::set_sqlite_index_enabled(cfg.tool_logs_sqlite_index);
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn start_sqlite_index(cfg: &LoggingConfig) {
+    if cfg.tool_logs_sqlite_index {
+        eprintln!(
+            "{} logging.tool_logs_sqlite_index is set but agentic-mcp was built without the `sqlite` feature; records will not be indexed",
+            "WARN".yellow()
+        );
+    }
 }
 
 #[tokio::main]
@@ -169,7 +343,7 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Parse server config (allowlist, output mode)
-    let (mut reg_cfg, file_output) = parse_config(&args);
+    let (mut reg_cfg, settings) = parse_config(&args)?;
 
     // Attach tool config sections from agentic.toml
     reg_cfg.subagents = loaded.config.subagents.clone();
@@ -184,7 +358,12 @@ async fn main() -> anyhow::Result<()> {
     reg_cfg.review = loaded.config.review.clone();
     reg_cfg.thoughts = loaded.config.thoughts.clone();
 
-    let reg = AgenticTools::new(reg_cfg);
+    agentic_logging::set_default_policy(tool_log_policy(&loaded.config.logging));
+    agentic_logging::set_default_redaction(tool_log_redaction(&loaded.config.logging));
+    start_otlp_export(&loaded.config.logging);
+    start_sqlite_index(&loaded.config.logging);
+
+    let reg = AgenticTools::builder(reg_cfg).load_plugins().await.build();
 
     if args.list_tools {
         let mut names = reg.list_names();
@@ -196,7 +375,7 @@ async fn main() -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let output_mode = match (args.output.as_deref(), file_output.as_deref()) {
+    let output_mode = match (args.output.as_deref(), settings.output.as_deref()) {
         (Some("structured"), _) | (None, Some("structured")) => OutputMode::Structured,
         _ => OutputMode::Text, // default
     };
@@ -207,14 +386,42 @@ async fn main() -> anyhow::Result<()> {
         output_mode
     );
 
-    let server = RegistryServer::new(Arc::new(reg))
-        .with_info("agentic-mcp", env!("CARGO_PKG_VERSION"))
-        .with_output_mode(output_mode)
-        .with_text_options(
-            TextOptions::default().with_suppress_search_reminder(args.suppress_search_reminder),
+    let reg = Arc::new(reg);
+    let text_options =
+        TextOptions::default().with_suppress_search_reminder(args.suppress_search_reminder);
+    let config = loaded.config;
+    let profiles = settings.profiles;
+    let make_server = move || {
+        let server = RegistryServer::new(Arc::clone(&reg))
+            .with_info("agentic-mcp", env!("CARGO_PKG_VERSION"))
+            .with_output_mode(output_mode)
+            .with_text_options(text_options.clone())
+            .with_resources(AgenticResources::new(config.clone()))
+            .with_resources(ThoughtsResources);
+        profiles.iter().fold(server, |server, (name, tools)| {
+            server.with_profile(name.clone(), tools.iter().cloned())
+        })
+    };
+
+    if let Some(addr) = args.http.as_deref() {
+        let options = settings.http.into_options(args.http_token);
+        if !options.requires_auth() {
+            eprintln!(
+                "{} no HTTP tokens configured; requests are not authenticated",
+                "WARN".yellow()
+            );
+        }
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        eprintln!(
+            "Serving MCP over HTTP at http://{}{MCP_PATH}",
+            listener.local_addr()?
         );
+        agentic_tools_mcp::http::serve(listener, make_server, options).await?;
+        return Ok(());
+    }
+
     let transport = stdio();
-    let service = server.serve(transport).await?;
+    let service = make_server().serve(transport).await?;
     service.waiting().await?;
 
     Ok(())
@@ -0,0 +1,135 @@
+//! MCP resources served alongside the tools.
+//!
+//! - `agentic://config`: the merged `agentic.toml` configuration.
+//! - `agentic://logs/recent`: recent tool calls and log statistics.
+//! - `thoughts://<research|plans|artifacts|logs>/<file>`: documents in the active
+//!   thoughts work directory.
+
+use agentic_config::types::AgenticConfig;
+use agentic_tools_core::ToolError;
+use agentic_tools_mcp::ResourceEntry;
+use agentic_tools_mcp::ResourceProvider;
+use agentic_tools_mcp::ResourceText;
+use futures::future::BoxFuture;
+use thoughts_tool::DocumentType;
+use thoughts_tool::ThoughtsError;
+use thoughts_tool::mcp::ToolLogsQuery;
+
+const CONFIG_URI: &str = "agentic://config";
+const RECENT_LOGS_URI: &str = "agentic://logs/recent";
+const THOUGHTS_SCHEME: &str = "thoughts://";
+
+/// Document types listed as resources; logs are readable but not listed, as in
+/// `list_documents`.
+const LISTED_DOCUMENTS: [DocumentType; 3] = [
+    DocumentType::Research,
+    DocumentType::Plan,
+    DocumentType::Artifact,
+];
+
+/// The merged configuration and recent tool-call logs.
+pub struct AgenticResources {
+    config: AgenticConfig,
+}
+
+impl AgenticResources {
+    pub fn new(config: AgenticConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl ResourceProvider for AgenticResources {
+    fn list(&self) -> BoxFuture<'_, Result<Vec<ResourceEntry>, ToolError>> {
+        Box::pin(async {
+            Ok(vec![
+                ResourceEntry::new(CONFIG_URI, "config")
+                    .with_description("Merged agentic.toml configuration")
+                    .with_mime_type("application/json"),
+                ResourceEntry::new(RECENT_LOGS_URI, "recent tool calls")
+                    .with_description("Most recent tool calls and per-tool statistics")
+                    .with_mime_type("application/json"),
+            ])
+        })
+    }
+
+    fn read<'a>(&'a self, uri: &'a str) -> BoxFuture<'a, Result<Option<ResourceText>, ToolError>> {
+        Box::pin(async move {
+            match uri {
+                CONFIG_URI => ResourceText::json(&self.config).map(Some),
+                RECENT_LOGS_URI => {
+                    let report =
+                        thoughts_tool::mcp::tool_logs_query_impl_adapter(ToolLogsQuery::default())
+                            .await
+                            .map_err(|e| ToolError::internal(format!("{e:#}")))?;
+                    ResourceText::json(&report).map(Some)
+                }
+                _ => Ok(None),
+            }
+        })
+    }
+}
+
+/// Documents in the active thoughts work directory.
+pub struct ThoughtsResources;
+
+impl ResourceProvider for ThoughtsResources {
+    fn list(&self) -> BoxFuture<'_, Result<Vec<ResourceEntry>, ToolError>> {
+        Box::pin(async {
+            let mut entries = Vec::new();
+            for doc_type in &LISTED_DOCUMENTS {
+                let docs = thoughts_tool::list_documents(Some(doc_type)).map_err(thoughts_error)?;
+                entries.extend(docs.files.into_iter().filter_map(|doc| {
+                    let file = doc.path.rsplit('/').next()?;
+                    Some(
+                        ResourceEntry::new(
+                            format!("{THOUGHTS_SCHEME}{}/{file}", doc_type.subdir_name()),
+                            file,
+                        )
+                        .with_description(format!("{} document", doc_type.singular_label()))
+                        .with_mime_type(mime_type(file)),
+                    )
+                }));
+            }
+            Ok(entries)
+        })
+    }
+
+    fn read<'a>(&'a self, uri: &'a str) -> BoxFuture<'a, Result<Option<ResourceText>, ToolError>> {
+        Box::pin(async move {
+            let Some(path) = uri.strip_prefix(THOUGHTS_SCHEME) else {
+                return Ok(None);
+            };
+            let Some((subdir, file)) = path.split_once('/') else {
+                return Err(ToolError::invalid_input(format!(
+                    "expected {THOUGHTS_SCHEME}<subdir>/<file>, got {uri}"
+                )));
+            };
+            let doc_type: DocumentType = serde_json::from_value(subdir.into())
+                .map_err(|e| ToolError::invalid_input(e.to_string()))?;
+            let text = thoughts_tool::read_document(&doc_type, file).map_err(thoughts_error)?;
+            Ok(Some(
+                ResourceText::new(text).with_mime_type(mime_type(file)),
+            ))
+        })
+    }
+}
+
+fn mime_type(file: &str) -> &'static str {
+    if std::path::Path::new(file)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+    {
+        "text/markdown"
+    } else {
+        "text/plain"
+    }
+}
+
+fn thoughts_error(e: ThoughtsError) -> ToolError {
+    match e {
+        ThoughtsError::Io(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            ToolError::not_found(e.to_string())
+        }
+        e => ToolError::internal(e.to_string()),
+    }
+}
@@ -34,7 +34,7 @@ pub fn write_markdown_best_effort(
 ) -> Option<String> {
     let dir = resolve_logs_dir()?;
 
-    let writer = LogWriter::new(dir);
+    let writer = LogWriter::new(dir).with_server("opencode-orchestrator-mcp");
     match writer.write_markdown_response(completed_at, call_id, content) {
         Ok(filename) if !filename.is_empty() => Some(filename),
         Ok(_) => None,
@@ -87,6 +87,8 @@ mod tests {
             model: None,
             token_usage: None,
             summary: None,
+            trace_id: Some(timer.trace_id),
+            parent_call_id: timer.parent_call_id,
         }
     }
 
@@ -171,6 +171,8 @@ fn log_tool_success<TReq: Serialize, TOut: TextFormat>(
         summary: log_meta
             .token_usage_saturated
             .then(|| serde_json::json!({"token_usage_saturated": true})),
+        trace_id: Some(timer.trace_id.clone()),
+        parent_call_id: timer.parent_call_id.clone(),
     };
 
     logging::append_record_best_effort(&record);
@@ -199,6 +201,8 @@ fn log_tool_error<TReq: Serialize>(
         model: None,
         token_usage: None,
         summary: None,
+        trace_id: Some(timer.trace_id.clone()),
+        parent_call_id: timer.parent_call_id.clone(),
     };
 
     logging::append_record_best_effort(&record);
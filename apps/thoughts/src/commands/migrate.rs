@@ -0,0 +1,54 @@
+use anyhow::Context;
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::config::RepoConfigManager;
+use crate::git::utils::get_current_control_repo_root;
+
+#[expect(clippy::unused_async, reason = "async for command API consistency")]
+pub async fn execute(dry_run: bool) -> Result<()> {
+    let repo_root = get_current_control_repo_root().context("Not in a git repository")?;
+    let mgr = RepoConfigManager::new(repo_root);
+
+    let report = mgr.migrate(dry_run)?;
+    if report.is_noop() {
+        println!(
+            "{} Configuration is already at version {}",
+            "✓".green(),
+            report.to_version
+        );
+        return Ok(());
+    }
+
+    println!(
+        "Migrating configuration {} -> {}",
+        report.from_version.yellow(),
+        report.to_version.green()
+    );
+    for step in &report.steps {
+        println!("  - {step}");
+    }
+    for w in &report.warnings {
+        eprintln!("Warning: {w}");
+    }
+
+    if dry_run {
+        println!("\n{} No changes written (dry run)", "ℹ".blue());
+        return Ok(());
+    }
+
+    if let Some(backup) = &report.backup_path {
+        println!("Backup written to {}", backup.display());
+    }
+    println!(
+        "{} Upgraded .thoughts/config.json to version {}",
+        "✓".green(),
+        report.to_version
+    );
+    println!(
+        "Run {} to apply the migrated mounts",
+        "thoughts mount update".cyan()
+    );
+
+    Ok(())
+}
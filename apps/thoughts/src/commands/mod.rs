@@ -1,5 +1,6 @@
 pub mod config;
 pub mod init;
+pub mod migrate;
 pub mod mount;
 pub mod references;
 pub mod status;
@@ -92,6 +92,13 @@ enum Commands {
         #[command(subcommand)]
         command: WorkCommands,
     },
+
+    /// Upgrade .thoughts/config.json to the current config version
+    Migrate {
+        /// Show the migration steps without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -347,5 +354,6 @@ async fn main() -> Result<()> {
                 execute(which).await
             }
         },
+        Commands::Migrate { dry_run } => commands::migrate::execute(dry_run).await,
     }
 }
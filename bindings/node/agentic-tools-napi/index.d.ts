@@ -6,6 +6,10 @@
  * @module agentic-tools-napi
  */
 
+// Per-tool input/output types, `ToolInputs`/`ToolOutputs`, and `callToolTyped`,
+// generated from the registry's schemas by `npm run gen:types`.
+export * from './tools';
+
 // =============================================================================
 // Configuration Types
 // =============================================================================
@@ -16,6 +20,39 @@ export interface InitConfig {
   allowlist?: string[];
   /** Enable strict schema mode (additionalProperties: false) */
   strict?: boolean;
+  /** Registry schema version this package was built against; init() throws on mismatch */
+  expectedSchemaVersion?: number;
+  /** Run at most this many tool calls at once; further calls wait for a slot */
+  maxConcurrentCalls?: number;
+  /** Timeout for calls without `timeoutMs` in their CallContext; waiting for a slot counts */
+  defaultTimeoutMs?: number;
+}
+
+/** Registry manifest returned by init() and getManifest() */
+export interface ToolManifest {
+  /** Schema version of the native registry */
+  schemaVersion: number;
+  /** Hash over every tool hash; changes whenever any tool changes */
+  registryHash: string;
+  /** Content hash of each tool's name, description, and schemas */
+  tools: Record<string, string>;
+}
+
+/** Version and deprecation metadata of one tool, returned by getToolMeta() */
+export interface ToolMeta {
+  /** Registered tool name */
+  name: string;
+  /** Description without the deprecation notice listTools appends */
+  description: string;
+  /** Version of the tool's contract, if it declares one */
+  version?: string;
+  /** Present when the tool is deprecated */
+  deprecated?: {
+    /** Tool to call instead */
+    replacement?: string;
+    /** Extra migration guidance */
+    note?: string;
+  };
 }
 
 // =============================================================================
@@ -34,14 +71,18 @@ export interface LsInput {
   ignore?: string[];
   /** Include hidden files (default: false) */
   hidden?: boolean;
+  /** Text rendering for this call (default: transport setting) */
+  format?: 'json' | 'text' | 'markdown';
 }
 
 /** Input for ask_agent tool */
 export interface AskAgentInput {
   /** Agent type: 'locator' (fast discovery) or 'analyzer' (deep analysis) */
   agent_type?: 'locator' | 'analyzer';
-  /** Location: 'codebase', 'thoughts', 'references', or 'web' */
-  location?: 'codebase' | 'thoughts' | 'references' | 'web';
+  /** Location: 'codebase', 'thoughts', 'references', 'web', or 'path' */
+  location?: 'codebase' | 'thoughts' | 'references' | 'web' | 'path';
+  /** Absolute directory for location 'path' (e.g., a sibling repo) */
+  location_path?: string;
   /** Task to perform; plain language question/instructions */
   query: string;
 }
@@ -80,6 +121,10 @@ export interface SearchGrepInput {
   head_limit?: number;
   /** Skip the first N results (default: 0) */
   offset?: number;
+  /** Reuse a persistent trigram index to skip files that cannot match (default: false) */
+  use_index?: boolean;
+  /** Text rendering for this call (default: transport setting) */
+  format?: 'json' | 'text' | 'markdown';
 }
 
 /** Input for search_glob tool */
@@ -120,6 +165,18 @@ export interface JustExecuteInput {
   args?: Record<string, unknown>;
 }
 
+/** A single call inside a cli_batch request */
+export type BatchCall =
+  | { tool: 'ls' | 'cli_ls'; args: LsInput }
+  | { tool: 'grep' | 'cli_grep'; args: SearchGrepInput }
+  | { tool: 'glob' | 'cli_glob'; args: SearchGlobInput };
+
+/** Input for cli_batch tool */
+export interface BatchInput {
+  /** Calls to run concurrently (max 16) */
+  calls: BatchCall[];
+}
+
 /** Input for reasoning request tool */
 export interface ReasoningRequestInput {
   /** Prompt to pass to the reasoning model */
@@ -194,6 +251,21 @@ export interface GlobOutput {
   warnings: string[];
 }
 
+/** One entry of a cli_batch response, in request order */
+export interface BatchItem {
+  /** Tool name that handled this call (e.g., 'cli_grep') */
+  tool: string;
+  /** Tool output when the call succeeded */
+  output?: LsOutput | GrepOutput | GlobOutput;
+  /** Error message when the call failed */
+  error?: string;
+}
+
+/** Output from cli_batch tool */
+export interface BatchOutput {
+  results: BatchItem[];
+}
+
 /** Item in just_search output */
 export interface JustSearchItem {
   recipe: string;
@@ -251,35 +323,136 @@ export type SchemaPatches = Record<string, ToolSchemaPatch>;
  * Initialize the agentic-tools registry with all available tools.
  *
  * @param configJson - JSON configuration string
- * @throws Error if config is invalid JSON or registry is already initialized
+ * @returns JSON string of the registry {@link ToolManifest}
+ * @throws Error if config is invalid JSON, the schema version does not match
+ *   `expectedSchemaVersion`, or registry is already initialized
  *
  * @example
  * ```typescript
  * import { init } from 'agentic-tools-napi';
  * init('{}'); // Initialize with all tools
  * init('{"allowlist": ["ls", "search_grep"]}'); // Only specific tools
+ * init('{"expectedSchemaVersion": 1}'); // Fail fast on a stale native binary
  * ```
  */
-export function init(configJson: string): void;
+export function init(configJson: string): string;
+
+/**
+ * Get the registry manifest (schema version and per-tool content hashes).
+ *
+ * @returns JSON string of the registry {@link ToolManifest}
+ * @throws Error if registry is not initialized
+ */
+export function getManifest(): string;
 
 /**
  * List available tools with their schemas for a specific provider.
  *
- * @param provider - Provider format: "openai", "anthropic", or "mcp"
+ * @param provider - Provider format: "openai", "anthropic", "gemini", or "mcp"
  * @returns JSON string containing an array of tool definitions
  * @throws Error if registry is not initialized
  */
-export function listTools(provider: 'openai' | 'anthropic' | 'mcp'): string;
+export function listTools(provider: 'openai' | 'anthropic' | 'gemini' | 'mcp'): string;
+
+/** Optional per-call context for callTool, callToolStreaming, and callToolWithHandle. */
+export interface CallContext {
+  /** Absolute directory that relative tool paths resolve against (default: process cwd) */
+  cwd?: string;
+  /** Calls sharing a session id share pagination state until endSession(id) */
+  sessionId?: string;
+  /** Cancel the call after this many milliseconds; overrides defaultTimeoutMs, 0 disables */
+  timeoutMs?: number;
+}
 
 /**
  * Execute a tool with JSON arguments.
  *
  * @param name - Name of the tool to call
  * @param argsJson - JSON string containing the tool arguments
+ * @param context - Optional working directory, session id, and timeout
  * @returns Promise resolving to a ToolCallResult with data (JSON string) and text
+ * @throws Error if registry is not initialized, context is invalid, or tool execution
+ *   fails. A timed-out call rejects with `code === 'Cancelled'`.
+ */
+export function callTool(
+  name: string,
+  argsJson: string,
+  context?: CallContext
+): Promise<ToolCallResult>;
+
+/**
+ * Drop the state kept for a session named in a CallContext.
+ *
+ * @param sessionId - Session id previously passed as `context.sessionId`
+ * @returns Whether the session existed
+ */
+export function endSession(sessionId: string): boolean;
+
+/** Incremental event pushed to a `callToolStreaming` callback (JSON-decoded). */
+export type ToolEvent =
+  | { type: 'output'; stream: 'stdout' | 'stderr'; text: string }
+  | { type: 'transcript'; entry: unknown }
+  | { type: 'text_delta'; text: string }
+  | { type: 'progress'; message: string }
+  | { type: 'chunk'; data: unknown };
+
+/**
+ * Execute a tool, pushing incremental events to a callback while it runs.
+ *
+ * @param name - Name of the tool to call
+ * @param argsJson - JSON string containing the tool arguments
+ * @param onEvent - Called with each event as a JSON string (see ToolEvent)
+ * @param context - Optional working directory, session id, and timeout
+ * @returns Promise resolving to the same ToolCallResult as callTool
  * @throws Error if registry is not initialized or tool execution fails
  */
-export function callTool(name: string, argsJson: string): Promise<ToolCallResult>;
+export function callToolStreaming(
+  name: string,
+  argsJson: string,
+  onEvent: (eventJson: string) => void,
+  context?: CallContext
+): Promise<ToolCallResult>;
+
+/** A running tool call started with `callToolWithHandle`. */
+export class ToolCallHandle {
+  /** Ask the tool to stop; subprocesses started by the tool are killed. */
+  cancel(): void;
+  /** Whether cancel() has been called. */
+  isCancelled(): boolean;
+  /**
+   * Wait for the call to finish. Can be awaited only once.
+   * @throws Error if the tool fails or was cancelled
+   */
+  result(): Promise<ToolCallResult>;
+}
+
+/**
+ * Start a tool call in the background and return a handle that can cancel it.
+ *
+ * @param name - Name of the tool to call
+ * @param argsJson - JSON string containing the tool arguments
+ * @param onEvent - Optional callback receiving events as in callToolStreaming
+ * @param context - Optional working directory, session id, and timeout
+ * @returns Handle whose result() resolves to the same ToolCallResult as callTool
+ * @throws Error if context is invalid
+ */
+export function callToolWithHandle(
+  name: string,
+  argsJson: string,
+  onEvent?: ((eventJson: string) => void) | null,
+  context?: CallContext
+): ToolCallHandle;
+
+/**
+ * Render the registry's input/output schemas as a `.d.ts` module.
+ *
+ * This is what `npm run gen:types` writes to `tools.d.ts`. Schema patches are
+ * applied first, so hosts that patch schemas can render matching declarations.
+ *
+ * @returns TypeScript declaration source
+ * @throws Error if registry is not initialized
+ */
+export function renderTypeDeclarations(): string;
 
 /**
  * Apply schema patches for runtime customization.
@@ -304,6 +477,13 @@ export function toolCount(): number;
  */
 export function getToolNames(): string[];
 
+/**
+ * Get version and deprecation metadata of all registered tools, sorted by name.
+ *
+ * @returns JSON string of {@link ToolMeta}[]
+ */
+export function getToolMeta(): string;
+
 // =============================================================================
 // Typed Tool Functions
 // =============================================================================
@@ -356,6 +536,14 @@ export function callJustSearch(argsJson: string): Promise<ToolCallResult>;
  */
 export function callJustExecute(argsJson: string): Promise<ToolCallResult>;
 
+/**
+ * Run several read-only tools (ls, grep, glob) concurrently.
+ *
+ * @param argsJson - JSON string with BatchInput
+ * @returns Promise resolving to a ToolCallResult with BatchOutput in data
+ */
+export function callBatch(argsJson: string): Promise<ToolCallResult>;
+
 /**
  * Request assistance from the reasoning model.
  *
@@ -406,6 +594,11 @@ export function callJustSearchTyped(input?: JustSearchInput): Promise<JustSearch
  */
 export function callJustExecuteTyped(input: JustExecuteInput): Promise<JustExecuteOutput>;
 
+/**
+ * Helper to call cli_batch with typed input and output.
+ */
+export function callBatchTyped(input: BatchInput): Promise<BatchOutput>;
+
 /**
  * Helper to call reasoning request with typed input.
  * Returns a string because the reasoning tool's output is a JSON string literal.
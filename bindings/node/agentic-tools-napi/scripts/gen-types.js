@@ -0,0 +1,28 @@
+#!/usr/bin/env node
+// Regenerates tools.d.ts from the native registry's JSON Schemas.
+// Run after building the native binary: `node scripts/gen-types.js [--check]`.
+// With --check, exits non-zero instead of writing when the file is stale.
+
+const { readFileSync, writeFileSync } = require('node:fs');
+const { join } = require('node:path');
+
+const native = require('../index.node');
+const target = join(__dirname, '..', 'tools.d.ts');
+
+if (!native.isInitialized()) native.init('{}');
+const rendered = native.renderTypeDeclarations();
+
+if (process.argv.includes('--check')) {
+  let current = '';
+  try {
+    current = readFileSync(target, 'utf8');
+  } catch {
+    // Missing file counts as stale.
+  }
+  if (current !== rendered) {
+    console.error('tools.d.ts is out of date; run `node scripts/gen-types.js`');
+    process.exit(1);
+  }
+} else {
+  writeFileSync(target, rendered);
+}
@@ -6,12 +6,18 @@ const native = require('./index.node');
 
 // Core functions (re-exported intact)
 export const init = native.init;
+export const getManifest = native.getManifest;
 export const listTools = native.listTools;
 export const callTool = native.callTool;
+export const callToolStreaming = native.callToolStreaming;
+export const callToolWithHandle = native.callToolWithHandle;
+export const endSession = native.endSession;
+export const renderTypeDeclarations = native.renderTypeDeclarations;
 export const setSchemaPatches = native.setSchemaPatches;
 export const isInitialized = native.isInitialized;
 export const toolCount = native.toolCount;
 export const getToolNames = native.getToolNames;
+export const getToolMeta = native.getToolMeta;
 
 // JSON-string per-tool wrappers (re-exported intact)
 export const callLs = native.callLs;
@@ -20,6 +26,7 @@ export const callGrep = native.callGrep;
 export const callGlob = native.callGlob;
 export const callJustSearch = native.callJustSearch;
 export const callJustExecute = native.callJustExecute;
+export const callBatch = native.callBatch;
 export const callReasoningRequest = native.callReasoningRequest;
 
 // Internal helpers
@@ -59,6 +66,11 @@ async function callTyped<TOut = any>(
   return parseResult<TOut>(result.data, label);
 }
 
+// Generic typed call (declared per tool in the generated tools.d.ts)
+export async function callToolTyped(name: string, input: unknown): Promise<any> {
+  return callTyped((json) => native.callTool(name, json), input, `callToolTyped(${name})`);
+}
+
 // Typed helper implementations (runtime-typed; definitions are in index.d.ts)
 export async function callLsTyped(input: unknown): Promise<any> {
   return callTyped(native.callLs, input, 'callLsTyped');
@@ -84,6 +96,10 @@ export async function callJustExecuteTyped(input: unknown): Promise<any> {
   return callTyped(native.callJustExecute, input, 'callJustExecuteTyped');
 }
 
+export async function callBatchTyped(input: unknown): Promise<any> {
+  return callTyped(native.callBatch, input, 'callBatchTyped');
+}
+
 export async function callReasoningRequestTyped(input: unknown): Promise<any> {
   return callTyped(native.callReasoningRequest, input, 'callReasoningRequestTyped');
 }
@@ -5,10 +5,16 @@
 //!
 //! ## Generic Exports
 //!
-//! - `init(config)`: Initialize the tool registry with all available tools
+//! - `init(config)`: Initialize the tool registry with all available tools; returns the manifest
+//! - `getManifest()`: Schema version and per-tool content hashes of the registry
 //! - `listTools(provider)`: List available tools with schemas for a provider
-//! - `callTool(name, args)`: Execute a tool with JSON arguments
+//! - `getToolMeta()`: Version and deprecation metadata of every registered tool
+//! - `callTool(name, args, context?)`: Execute a tool with JSON arguments
+//! - `callToolStreaming(name, args, onEvent, context?)`: Execute a tool, pushing incremental events to a callback
+//! - `callToolWithHandle(name, args, onEvent?, context?)`: Start a tool call that can be cancelled via `handle.cancel()`
+//! - `endSession(sessionId)`: Drop the state of a session named in a call context
 //! - `setSchemaPatches(patches)`: Apply runtime schema transformations
+//! - `renderTypeDeclarations()`: Render the registry's schemas as a `.d.ts` module
 //!
 //! ## Typed Exports
 //!
@@ -31,21 +37,37 @@
     reason = "`#[napi]` macro expansion generates internal metadata types with trailing empty arrays"
 )]
 
+use agentic_tools_core::EventSink;
 use agentic_tools_core::FieldConstraint;
 use agentic_tools_core::SchemaEngine;
+use agentic_tools_core::SessionState;
 use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolManifest;
 use agentic_tools_core::ToolRegistry;
 use agentic_tools_core::fmt::TextOptions;
 use agentic_tools_core::fmt::fallback_text_from_json;
+use agentic_tools_core::providers::typescript::TypedTool;
 use agentic_tools_registry::AgenticTools;
 use agentic_tools_registry::AgenticToolsConfig;
 use napi::bindgen_prelude::*;
+use napi::threadsafe_function::ErrorStrategy;
+use napi::threadsafe_function::ThreadsafeFunction;
+use napi::threadsafe_function::ThreadsafeFunctionCallMode;
 use napi_derive::napi;
 use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
 
 // =============================================================================
 // Global State
@@ -57,6 +79,18 @@ static REGISTRY: OnceCell<Arc<ToolRegistry>> = OnceCell::new();
 /// Global schema engine for runtime transforms.
 static SCHEMA_ENGINE: OnceCell<RwLock<SchemaEngine>> = OnceCell::new();
 
+/// Concurrency and timeout limits from `init()`, applied to every tool call.
+static LIMITS: OnceCell<CallLimits> = OnceCell::new();
+
+struct CallLimits {
+    permits: Option<Arc<Semaphore>>,
+    default_timeout: Option<Duration>,
+}
+
+/// Sessions named by `CallContext.sessionId`, kept until `endSession()`.
+static SESSIONS: LazyLock<Mutex<HashMap<String, Arc<SessionState>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
 // =============================================================================
 // Initialization
 // =============================================================================
@@ -71,6 +105,16 @@ static SCHEMA_ENGINE: OnceCell<RwLock<SchemaEngine>> = OnceCell::new();
 /// * `config_json` - JSON configuration string. Supports:
 ///   - `allowlist`: Array of tool names to enable (empty = all tools)
 ///   - `strict`: Boolean for strict schema mode (default: false)
+///   - `expectedSchemaVersion`: Registry schema version the JS package was built
+///     against; a mismatch fails before any global state is set
+///   - `maxConcurrentCalls`: Run at most this many tool calls at once; further
+///     calls wait for a slot (default: unlimited)
+///   - `defaultTimeoutMs`: Timeout for calls that do not set `timeoutMs` in their
+///     `CallContext` (default: none). Queueing for a slot counts toward it.
+///
+/// # Returns
+///
+/// JSON string of the registry manifest (`schemaVersion`, `registryHash`, `tools`).
 ///
 /// # Example
 ///
@@ -78,22 +122,26 @@ static SCHEMA_ENGINE: OnceCell<RwLock<SchemaEngine>> = OnceCell::new();
 /// import { init } from 'agentic-tools-napi';
 /// init('{}'); // Initialize with all tools
 /// init('{"allowlist": ["cli_ls", "cli_grep"]}'); // Only specific tools
+/// init('{"expectedSchemaVersion": 1}'); // Fail fast on a stale native binary
+/// init('{"maxConcurrentCalls": 4, "defaultTimeoutMs": 120000}'); // Bound resource usage
 /// ```
 #[napi]
-pub fn init(config_json: String) -> Result<()> {
+pub fn init(config_json: String) -> Result<String> {
     // Parse configuration
     let config: JsonValue = serde_json::from_str(&config_json)
         .map_err(|e| Error::from_reason(format!("Invalid config JSON: {e}")))?;
 
-    // Initialize schema engine
-    let strict = config
-        .get("strict")
-        .and_then(serde_json::Value::as_bool)
-        .unwrap_or(false);
-    let engine = SchemaEngine::new().with_strict(strict);
-    SCHEMA_ENGINE
-        .set(RwLock::new(engine))
-        .map_err(|_| Error::from_reason("Schema engine already initialized"))?;
+    let expected_version = config_u32(&config, "expectedSchemaVersion")?;
+    let max_concurrent = match config_u32(&config, "maxConcurrentCalls")? {
+        Some(0) => {
+            return Err(Error::from_reason(
+                "Invalid config: maxConcurrentCalls must be at least 1",
+            ));
+        }
+        other => other,
+    };
+    let default_timeout =
+        config_u32(&config, "defaultTimeoutMs")?.map(|ms| Duration::from_millis(ms.into()));
 
     // Parse allowlist from config
     let allowlist: Option<HashSet<String>> = config
@@ -111,11 +159,71 @@ pub fn init(config_json: String) -> Result<()> {
         ..Default::default()
     });
 
+    // Check compatibility before touching globals so a failed init can be retried
+    let manifest = registry.manifest();
+    if let Some(expected) = expected_version {
+        manifest
+            .check_schema_version(expected)
+            .map_err(|e| Error::from_reason(e.to_string()))?;
+    }
+
+    // Initialize schema engine
+    let strict = config
+        .get("strict")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    let engine = SchemaEngine::new().with_strict(strict);
+    SCHEMA_ENGINE
+        .set(RwLock::new(engine))
+        .map_err(|_| Error::from_reason("Schema engine already initialized"))?;
+
+    let limits = CallLimits {
+        permits: max_concurrent.map(|n| Arc::new(Semaphore::new(n as usize))),
+        default_timeout,
+    };
+    LIMITS
+        .set(limits)
+        .map_err(|_| Error::from_reason("Call limits already initialized"))?;
+
     REGISTRY
         .set(Arc::new(registry))
         .map_err(|_| Error::from_reason("Registry already initialized"))?;
 
-    Ok(())
+    manifest_json(&manifest)
+}
+
+/// Read an optional non-negative integer from the init config.
+fn config_u32(config: &JsonValue, key: &str) -> Result<Option<u32>> {
+    match config.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(v) => v
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| {
+                Error::from_reason(format!(
+                    "Invalid config: {key} must be a non-negative integer, got {v}"
+                ))
+            }),
+    }
+}
+
+/// Get the registry manifest: schema version and per-tool content hashes.
+///
+/// # Returns
+///
+/// JSON string with `schemaVersion`, `registryHash`, and `tools` (name -> hash).
+#[napi]
+pub fn get_manifest() -> Result<String> {
+    let reg = REGISTRY
+        .get()
+        .ok_or_else(|| Error::from_reason("Registry not initialized. Call init() first."))?;
+    manifest_json(&reg.manifest())
+}
+
+fn manifest_json(manifest: &ToolManifest) -> Result<String> {
+    serde_json::to_string(manifest)
+        .map_err(|e| Error::from_reason(format!("JSON serialization failed: {e}")))
 }
 
 // =============================================================================
@@ -134,6 +242,22 @@ pub struct ToolCallResult {
     pub text: String,
 }
 
+/// Per-call context accepted by `callTool`, `callToolStreaming`, and
+/// `callToolWithHandle`. Every field is optional.
+#[napi(object)]
+pub struct CallContext {
+    /// Absolute directory that relative tool paths (and subagents) resolve
+    /// against, e.g. the editor's open workspace. Defaults to the process cwd.
+    pub cwd: Option<String>,
+    /// Client-chosen session id. Calls sharing an id share implicit pagination
+    /// state and session scratch space until `endSession(id)`; calls without one
+    /// share the unscoped state.
+    pub session_id: Option<String>,
+    /// Cancel the call after this many milliseconds; overrides `defaultTimeoutMs`
+    /// from `init()`, and `0` disables it.
+    pub timeout_ms: Option<u32>,
+}
+
 // =============================================================================
 // Generic APIs
 // =============================================================================
@@ -145,7 +269,7 @@ pub struct ToolCallResult {
 ///
 /// # Arguments
 ///
-/// * `provider` - Provider format: "openai", "anthropic", or "mcp"
+/// * `provider` - Provider format: "openai", "anthropic", "gemini", or "mcp"
 ///
 /// # Returns
 ///
@@ -164,6 +288,7 @@ pub fn list_tools(provider: String) -> Result<String> {
         .iter()
         .filter_map(|name| {
             let tool = reg.get(name)?;
+            let description = tool.provider_description();
             let base_schema = tool.input_schema();
 
             // Apply schema transforms if engine exists
@@ -176,19 +301,22 @@ pub fn list_tools(provider: String) -> Result<String> {
             Some(match provider.as_str() {
                 "openai" => agentic_tools_core::providers::openai::render_function(
                     name,
-                    tool.description(),
+                    &description,
                     &schema,
                     strict,
                 ),
                 "anthropic" => agentic_tools_core::providers::anthropic::render_tool(
                     name,
-                    tool.description(),
+                    &description,
                     &schema,
                     strict,
                 ),
+                "gemini" => {
+                    agentic_tools_core::providers::gemini::render_tool(name, &description, &schema)
+                }
                 "mcp" => agentic_tools_core::providers::mcp::render_tool(
                     name,
-                    tool.description(),
+                    &description,
                     &schema,
                     tool.output_schema().as_ref(),
                 ),
@@ -204,18 +332,301 @@ pub fn list_tools(provider: String) -> Result<String> {
         .map_err(|e| Error::from_reason(format!("JSON serialization failed: {e}")))
 }
 
+/// Render the registry's input/output schemas as TypeScript declarations.
+///
+/// Schema patches are applied first, so the declarations match what `listTools`
+/// reports. `scripts/gen-types.js` writes the result to `tools.d.ts`, which
+/// `index.d.ts` re-exports to type `callToolTyped`.
+///
+/// # Returns
+///
+/// The `.d.ts` module source: one namespace per tool with `Input`/`Output` types,
+/// plus `ToolInputs`/`ToolOutputs` maps keyed by tool name.
+#[napi]
+pub fn render_type_declarations() -> Result<String> {
+    let reg = REGISTRY
+        .get()
+        .ok_or_else(|| Error::from_reason("Registry not initialized. Call init() first."))?;
+
+    let engine = SCHEMA_ENGINE.get().map(|e| e.read());
+    let mut names = reg.list_names();
+    names.sort();
+
+    let schemas: Vec<_> = names
+        .iter()
+        .filter_map(|name| {
+            let tool = reg.get(name)?;
+            let base_schema = tool.input_schema();
+            let input = match engine {
+                Some(ref eng) => eng.transform(name, base_schema),
+                None => base_schema,
+            };
+            Some((
+                name.as_str(),
+                tool.description(),
+                input,
+                tool.output_schema(),
+            ))
+        })
+        .collect();
+    let tools: Vec<TypedTool<'_>> = schemas
+        .iter()
+        .map(|(name, description, input, output)| TypedTool {
+            name,
+            description,
+            input_schema: input,
+            output_schema: output.as_ref(),
+        })
+        .collect();
+
+    Ok(agentic_tools_core::providers::typescript::render_declarations(&tools))
+}
+
 /// Execute a tool with JSON arguments.
 ///
 /// # Arguments
 ///
 /// * `name` - Name of the tool to call
 /// * `args_json` - JSON string containing the tool arguments
+/// * `context` - Optional `CallContext` (working directory, session id, timeout)
 ///
 /// # Returns
 ///
 /// A `ToolCallResult` with both `text` (human-readable) and `data` (JSON string).
+///
+/// # Example
+///
+/// ```typescript
+/// import { callTool } from 'agentic-tools-napi';
+/// await callTool('cli_grep', '{"pattern":"unimplemented!"}', {
+///   cwd: workspaceRoot,
+///   sessionId: 'editor-window-1',
+///   timeoutMs: 30_000,
+/// });
+/// ```
 #[napi]
-pub async fn call_tool(name: String, args_json: String) -> Result<ToolCallResult> {
+pub async fn call_tool(
+    name: String,
+    args_json: String,
+    context: Option<CallContext>,
+) -> Result<ToolCallResult> {
+    let (ctx, timeout) = tool_context(context, CancellationToken::new())?;
+    dispatch(name, args_json, ctx, timeout).await
+}
+
+/// Execute a tool, pushing incremental events to `on_event` while it runs.
+///
+/// Each event is a JSON string tagged by `type`: `output` (a line of subprocess
+/// output, e.g. from `cli_just_execute`), `transcript` (a subagent transcript entry
+/// from `ask_agent`), `text_delta` (streamed model text from `ask_reasoning_model`),
+/// `chunk` (a typed chunk from a streaming tool), or `progress`. Tools that do not stream simply emit nothing. Events are delivered
+/// on the JS thread in order; the returned result is the same as `callTool`'s.
+///
+/// # Example
+///
+/// ```typescript
+/// import { callToolStreaming } from 'agentic-tools-napi';
+/// const result = await callToolStreaming('cli_just_execute', '{"recipe":"test"}', (eventJson) => {
+///   const event = JSON.parse(eventJson);
+///   if (event.type === 'output') process.stdout.write(event.text);
+/// });
+/// ```
+#[napi(
+    ts_args_type = "name: string, argsJson: string, onEvent: (eventJson: string) => void, context?: CallContext"
+)]
+pub async fn call_tool_streaming(
+    name: String,
+    args_json: String,
+    on_event: ThreadsafeFunction<String, ErrorStrategy::Fatal>,
+    context: Option<CallContext>,
+) -> Result<ToolCallResult> {
+    let (ctx, timeout) = tool_context(context, CancellationToken::new())?;
+    dispatch(
+        name,
+        args_json,
+        ctx.with_events(js_event_sink(on_event)),
+        timeout,
+    )
+    .await
+}
+
+/// Forward tool events to a JS callback as JSON strings.
+fn js_event_sink(on_event: ThreadsafeFunction<String, ErrorStrategy::Fatal>) -> EventSink {
+    EventSink::new(move |event| {
+        if let Ok(json) = serde_json::to_string(&event) {
+            on_event.call(json, ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    })
+}
+
+/// A tool call started with `callToolWithHandle`.
+#[napi]
+pub struct ToolCallHandle {
+    cancel: CancellationToken,
+    task: Mutex<Option<JoinHandle<Result<ToolCallResult>>>>,
+}
+
+#[napi]
+impl ToolCallHandle {
+    /// Ask the tool to stop. Tools that manage subprocesses (`cli_just_execute`,
+    /// `ask_agent`) kill them before returning; the result then rejects with a
+    /// cancellation error. Calling this after the tool finished has no effect.
+    #[napi]
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Whether `cancel()` has been called.
+    #[napi]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Wait for the call to finish. Can be awaited only once.
+    #[napi]
+    pub async fn result(&self) -> Result<ToolCallResult> {
+        let task = self
+            .task
+            .lock()
+            .take()
+            .ok_or_else(|| Error::from_reason("Tool call result was already taken"))?;
+        task.await
+            .map_err(|e| Error::from_reason(format!("Tool call task failed: {e}")))?
+    }
+}
+
+/// Start a tool call and return a handle that can cancel it.
+///
+/// The call runs in the background as soon as this returns; await `handle.result()`
+/// for its `ToolCallResult`. `handle.cancel()` cancels the call's `ToolContext`, so
+/// cancellation-aware tools stop promptly and clean up their subprocesses.
+/// `onEvent`, if given, receives incremental events as in `callToolStreaming`;
+/// `context` is a `CallContext` as in `callTool`.
+///
+/// # Example
+///
+/// ```typescript
+/// import { callToolWithHandle } from 'agentic-tools-napi';
+/// const handle = callToolWithHandle('ask_agent', '{"query":"..."}');
+/// const timer = setTimeout(() => handle.cancel(), 60_000);
+/// try {
+///   const result = await handle.result();
+/// } finally {
+///   clearTimeout(timer);
+/// }
+/// ```
+#[napi(
+    ts_args_type = "name: string, argsJson: string, onEvent?: ((eventJson: string) => void) | null, context?: CallContext"
+)]
+pub fn call_tool_with_handle(
+    name: String,
+    args_json: String,
+    on_event: Option<ThreadsafeFunction<String, ErrorStrategy::Fatal>>,
+    context: Option<CallContext>,
+) -> Result<ToolCallHandle> {
+    let cancel = CancellationToken::new();
+    let (mut ctx, timeout) = tool_context(context, cancel.clone())?;
+    if let Some(on_event) = on_event {
+        ctx = ctx.with_events(js_event_sink(on_event));
+    }
+    let task = napi::bindgen_prelude::within_runtime_if_available(|| {
+        tokio::spawn(dispatch(name, args_json, ctx, timeout))
+    });
+    Ok(ToolCallHandle {
+        cancel,
+        task: Mutex::new(Some(task)),
+    })
+}
+
+/// Drop the state kept for `session_id` (pagination offsets, session scratch
+/// directory). Returns whether the session existed.
+#[napi]
+pub fn end_session(session_id: String) -> bool {
+    SESSIONS.lock().remove(&session_id).is_some()
+}
+
+/// Build the `ToolContext` for one call; returns the requested timeout alongside.
+fn tool_context(
+    context: Option<CallContext>,
+    cancel: CancellationToken,
+) -> Result<(ToolContext, Option<Duration>)> {
+    let mut ctx = ToolContext::with_cancel(cancel);
+    let default_timeout = LIMITS.get().and_then(|l| l.default_timeout);
+    let Some(context) = context else {
+        return Ok((ctx, default_timeout));
+    };
+
+    if let Some(cwd) = context.cwd {
+        let cwd = PathBuf::from(cwd);
+        if !cwd.is_absolute() {
+            return Err(Error::from_reason(format!(
+                "context.cwd must be an absolute path: {}",
+                cwd.display()
+            )));
+        }
+        if !cwd.is_dir() {
+            return Err(Error::from_reason(format!(
+                "context.cwd is not a directory: {}",
+                cwd.display()
+            )));
+        }
+        ctx = ctx.with_cwd(cwd);
+    }
+    if let Some(id) = context.session_id {
+        let session = Arc::clone(SESSIONS.lock().entry(id).or_default());
+        ctx = ctx.with_session(session);
+    }
+    let timeout = match context.timeout_ms {
+        Some(0) => None,
+        Some(ms) => Some(Duration::from_millis(ms.into())),
+        None => default_timeout,
+    };
+    Ok((ctx, timeout))
+}
+
+async fn dispatch(
+    name: String,
+    args_json: String,
+    ctx: ToolContext,
+    timeout: Option<Duration>,
+) -> Result<ToolCallResult> {
+    let cancel = ctx.cancellation_token();
+    let run = async move {
+        let _permit = match LIMITS.get().and_then(|l| l.permits.clone()) {
+            Some(permits) => Some(tokio::select! {
+                permit = permits.acquire_owned() => permit
+                    .map_err(|e| Error::from_reason(format!("Call limiter closed: {e}")))?,
+                () = ctx.cancelled() => {
+                    return Err(Error::from_reason(format!(
+                        "Tool execution failed: {}",
+                        ToolError::cancelled(None)
+                    )));
+                }
+            }),
+            None => None,
+        };
+        dispatch_now(name, args_json, ctx).await
+    };
+    let Some(timeout) = timeout else {
+        return run.await;
+    };
+
+    // Run on its own task so the deadline fires even while a tool blocks its
+    // worker thread. After the deadline the task is detached but keeps running:
+    // cancellation lets it clean up its subprocesses, and it holds its slot
+    // until it actually ends.
+    let mut task = tokio::spawn(run);
+    if let Ok(joined) = tokio::time::timeout(timeout, &mut task).await {
+        return joined.map_err(|e| Error::from_reason(format!("Tool call task failed: {e}")))?;
+    }
+    cancel.cancel();
+    Err(Error::new(
+        Status::Cancelled,
+        format!("Tool call timed out after {} ms", timeout.as_millis()),
+    ))
+}
+
+async fn dispatch_now(name: String, args_json: String, ctx: ToolContext) -> Result<ToolCallResult> {
     let reg = REGISTRY
         .get()
         .ok_or_else(|| Error::from_reason("Registry not initialized. Call init() first."))?;
@@ -223,7 +634,6 @@ pub async fn call_tool(name: String, args_json: String) -> Result<ToolCallResult
     let args: JsonValue = serde_json::from_str(&args_json)
         .map_err(|e| Error::from_reason(format!("Invalid args JSON: {e}")))?;
 
-    let ctx = ToolContext::default();
     let text_opts = TextOptions::default();
 
     let result = reg
@@ -342,6 +752,20 @@ pub fn tool_count() -> u32 {
     REGISTRY.get().map_or(0, |r| r.len() as u32)
 }
 
+/// Get version and deprecation metadata of all registered tools.
+///
+/// # Returns
+///
+/// JSON string of `{ name, description, version?, deprecated? }` entries sorted by name.
+#[napi]
+pub fn get_tool_meta() -> Result<String> {
+    let reg = REGISTRY
+        .get()
+        .ok_or_else(|| Error::from_reason("Registry not initialized. Call init() first."))?;
+    serde_json::to_string(&reg.list_names_with_meta())
+        .map_err(|e| Error::from_reason(format!("JSON serialization failed: {e}")))
+}
+
 /// Get names of all registered tools.
 #[napi]
 pub fn get_tool_names() -> Result<Vec<String>> {
@@ -366,7 +790,7 @@ pub fn get_tool_names() -> Result<Vec<String>> {
 /// `ToolCallResult` with `text` and `data` (JSON string for `LsOutput`)
 #[napi]
 pub async fn call_ls(args_json: String) -> Result<ToolCallResult> {
-    call_tool("cli_ls".to_string(), args_json).await
+    call_tool("cli_ls".to_string(), args_json, None).await
 }
 
 /// Ask a Claude subagent (typed wrapper).
@@ -380,7 +804,7 @@ pub async fn call_ls(args_json: String) -> Result<ToolCallResult> {
 /// `ToolCallResult` with `text` and `data` (JSON string for `AgentOutput`)
 #[napi]
 pub async fn call_ask_agent(args_json: String) -> Result<ToolCallResult> {
-    call_tool("ask_agent".to_string(), args_json).await
+    call_tool("ask_agent".to_string(), args_json, None).await
 }
 
 /// Regex-based search (typed wrapper).
@@ -394,7 +818,7 @@ pub async fn call_ask_agent(args_json: String) -> Result<ToolCallResult> {
 /// `ToolCallResult` with `text` and `data` (JSON string for `GrepOutput`)
 #[napi]
 pub async fn call_grep(args_json: String) -> Result<ToolCallResult> {
-    call_tool("cli_grep".to_string(), args_json).await
+    call_tool("cli_grep".to_string(), args_json, None).await
 }
 
 /// Glob-based file matching (typed wrapper).
@@ -408,7 +832,7 @@ pub async fn call_grep(args_json: String) -> Result<ToolCallResult> {
 /// `ToolCallResult` with `text` and `data` (JSON string for `GlobOutput`)
 #[napi]
 pub async fn call_glob(args_json: String) -> Result<ToolCallResult> {
-    call_tool("cli_glob".to_string(), args_json).await
+    call_tool("cli_glob".to_string(), args_json, None).await
 }
 
 /// Search justfile recipes (typed wrapper).
@@ -422,7 +846,7 @@ pub async fn call_glob(args_json: String) -> Result<ToolCallResult> {
 /// `ToolCallResult` with `text` and `data` (JSON string for `SearchOutput`)
 #[napi]
 pub async fn call_just_search(args_json: String) -> Result<ToolCallResult> {
-    call_tool("cli_just_search".to_string(), args_json).await
+    call_tool("cli_just_search".to_string(), args_json, None).await
 }
 
 /// Execute a justfile recipe (typed wrapper).
@@ -436,7 +860,21 @@ pub async fn call_just_search(args_json: String) -> Result<ToolCallResult> {
 /// `ToolCallResult` with `text` and `data` (JSON string for `ExecuteOutput`)
 #[napi]
 pub async fn call_just_execute(args_json: String) -> Result<ToolCallResult> {
-    call_tool("cli_just_execute".to_string(), args_json).await
+    call_tool("cli_just_execute".to_string(), args_json, None).await
+}
+
+/// Run several read-only tools concurrently (typed wrapper).
+///
+/// # Arguments
+///
+/// * `args_json` - JSON string with: calls (array of {tool: 'ls'|'grep'|'glob', args})
+///
+/// # Returns
+///
+/// `ToolCallResult` with `text` and `data` (JSON string for `BatchOutput`)
+#[napi]
+pub async fn call_batch(args_json: String) -> Result<ToolCallResult> {
+    call_tool("cli_batch".to_string(), args_json, None).await
 }
 
 // =============================================================================
@@ -454,5 +892,5 @@ pub async fn call_just_execute(args_json: String) -> Result<ToolCallResult> {
 /// `ToolCallResult` with `text` and `data` (JSON string for reasoning result)
 #[napi]
 pub async fn call_reasoning_request(args_json: String) -> Result<ToolCallResult> {
-    call_tool("ask_reasoning_model".to_string(), args_json).await
+    call_tool("ask_reasoning_model".to_string(), args_json, None).await
 }
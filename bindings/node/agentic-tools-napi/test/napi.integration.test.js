@@ -1,5 +1,5 @@
 import { test, expect, beforeAll } from 'bun:test';
-import { mkdtempSync, writeFileSync, mkdirSync, rmSync } from 'node:fs';
+import { mkdtempSync, writeFileSync, mkdirSync, readFileSync, rmSync } from 'node:fs';
 import { tmpdir } from 'node:os';
 import { join, resolve } from 'node:path';
 
@@ -40,6 +40,71 @@ test('native callLs returns ToolCallResult { data: string, text: string }', asyn
   }
 });
 
+test('callToolStreaming resolves with the same ToolCallResult as callTool', async () => {
+  const dir = makeFixture();
+  try {
+    const args = JSON.stringify({ path: dir, depth: 1 });
+    const events = [];
+    const streamed = await tools.callToolStreaming('cli_ls', args, (eventJson) => {
+      events.push(JSON.parse(eventJson));
+    });
+    const plain = await tools.callTool('cli_ls', args);
+    expect(streamed.data).toBe(plain.data);
+    expect(streamed.text).toBe(plain.text);
+    // cli_ls does not stream; every event is still a tagged object.
+    expect(events.every((e) => typeof e.type === 'string')).toBe(true);
+  } finally {
+    cleanupFixture(dir);
+  }
+});
+
+test('callToolWithHandle resolves with the same ToolCallResult as callTool', async () => {
+  const dir = makeFixture();
+  try {
+    const args = JSON.stringify({ path: dir, depth: 1 });
+    const handle = tools.callToolWithHandle('cli_ls', args);
+    const viaHandle = await handle.result();
+    const plain = await tools.callTool('cli_ls', args);
+    expect(viaHandle.data).toBe(plain.data);
+    expect(handle.isCancelled()).toBe(false);
+    await expect(handle.result()).rejects.toThrow('already taken');
+  } finally {
+    cleanupFixture(dir);
+  }
+});
+
+test('tools.d.ts matches renderTypeDeclarations()', () => {
+  const committed = readFileSync(join(__dirname, '..', 'tools.d.ts'), 'utf8');
+  expect(tools.renderTypeDeclarations()).toBe(committed);
+});
+
+test('callToolTyped parses ToolCallResult.data for any tool', async () => {
+  const dir = makeFixture();
+  try {
+    const out = await tools.callToolTyped('cli_ls', { path: dir, depth: 1 });
+    expect(resolve(out.root)).toBe(resolve(dir));
+    expect(out.entries.some((e) => e.path.endsWith('a.txt'))).toBe(true);
+  } finally {
+    cleanupFixture(dir);
+  }
+});
+
+test('callTool resolves relative paths against context.cwd', async () => {
+  const dir = makeFixture();
+  try {
+    const result = await tools.callTool('cli_ls', JSON.stringify({ path: 'subdir' }), {
+      cwd: dir,
+      sessionId: 'napi-test',
+    });
+    const parsed = JSON.parse(result.data);
+    expect(resolve(parsed.root)).toBe(resolve(join(dir, 'subdir')));
+    expect(tools.endSession('napi-test')).toBe(true);
+    await expect(tools.callTool('cli_ls', '{}', { cwd: 'relative' })).rejects.toThrow('absolute');
+  } finally {
+    cleanupFixture(dir);
+  }
+});
+
 test('callLsTyped parses ToolCallResult.data and returns LsOutput object', async () => {
   const dir = makeFixture();
   try {
@@ -67,3 +132,10 @@ test('callGrepTyped parses ToolCallResult.data and returns GrepOutput object', a
     cleanupFixture(dir);
   }
 });
+
+test('getManifest reports schema version and a hash per tool', () => {
+  const manifest = JSON.parse(tools.getManifest());
+  expect(Number.isInteger(manifest.schemaVersion)).toBe(true);
+  expect(typeof manifest.registryHash).toBe('string');
+  expect(Object.keys(manifest.tools).sort()).toEqual([...tools.getToolNames()].sort());
+});
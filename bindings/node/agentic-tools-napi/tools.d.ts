@@ -0,0 +1,3261 @@
+// Generated from the tool registry's JSON Schemas. Do not edit by hand.
+
+/**
+ * Spawn a Claude subagent for discovery or deep analysis. Returns a text response plus structured citations (files read with line ranges, searches run, URLs fetched); no side effects.
+ *
+ * Agent types:
+ * - locator (haiku): Finds WHERE things are. Fast, shallow discovery via cli_grep/cli_glob/cli_ls. Returns file paths grouped by purpose. Cannot read file contents deeply.
+ * - analyzer (sonnet): Explains HOW things work. Reads files, traces data flow, provides technical analysis. Must cite file:line for all claims.
+ *
+ * Locations:
+ * - codebase: Current repository. Paths are repo-relative.
+ * - thoughts: Active branch documents (research/plans/artifacts). Uses thoughts_list_documents for discovery.
+ * - references: Cloned reference repos. Paths start with references/{org}/{repo}/.
+ * - web: Internet search. Returns URLs with quotes and source attribution.
+ * - path: Arbitrary absolute directory given by location_path (e.g., a sibling repo). Same tools as codebase; paths are relative to that directory.
+ *
+ * When to use:
+ * - Use locator when you need to find files/resources but don't yet know where they are.
+ * - Use analyzer when you need to understand implementation details or extract specific information with citations.
+ * - Use thoughts/references locations when the answer likely exists in existing documentation or external examples.
+ * - Use web when you need external documentation, API references, or information not in the codebase.
+ *
+ * When NOT to use:
+ * - If you already know the file path, use Read directly.
+ * - If you need a simple directory listing, use cli_ls.
+ * - For pattern matching in known locations, use cli_grep or cli_glob.
+ * - This tool cannot write files or make changes.
+ *
+ * Usage notes:
+ * - Provide clear, specific queries. The subagent is stateless and receives no prior context.
+ * - Locator returns locations only; use analyzer or Read for content.
+ * - Multiple ask_agent calls can run in parallel for independent queries.
+ */
+export namespace AskAgent {
+  /** Input for the `ask_agent` tool. */
+  export type Input = {
+    /**
+     * Agent type: 'locator' (fast discovery, haiku) or 'analyzer' (deep analysis, sonnet). Default: locator
+     *
+     * Optional; omit or use null.
+     */
+    agent_type?: null | AgentType;
+    /**
+     * Location: 'codebase'|'thoughts'|'references'|'web'|'path'. Default: codebase
+     *
+     * Optional; omit or use null.
+     */
+    location?: null | AgentLocation;
+    /**
+     * Absolute directory for location='path' (e.g., a sibling repo); required for 'path', rejected otherwise
+     *
+     * Optional; omit or use null.
+     */
+    location_path?: null | string;
+    /** Task to perform; plain language question/instructions for the subagent */
+    query: string;
+  };
+  /**
+   * Output from `ask_agent` tool - the subagent's text response plus the sources it
+   * consulted, so callers can verify claims against what was actually looked at.
+   */
+  export type Output = {
+    citations?: Citation[];
+    text: string;
+  };
+  /**
+   * Agent location determines the working context and available tools.
+   * - Codebase: Current repository (code, configs, tests)
+   * - Thoughts: Thought documents in active branch
+   * - References: Cloned reference repositories
+   * - Web: Internet search (no working directory)
+   * - Path: Arbitrary absolute directory supplied via `location_path` (e.g., a sibling repo)
+   */
+  export type AgentLocation = "codebase" | "thoughts" | "references" | "web" | "path";
+  /**
+   * Agent type determines the model and behavior characteristics.
+   * - Locator: Fast discovery (haiku), finds WHERE things are
+   * - Analyzer: Deep analysis (sonnet), understands HOW things work
+   */
+  export type AgentType = "locator" | "analyzer";
+  /** A source the subagent consulted, extracted from its tool-use events. */
+  export type Citation = {
+    /** Optional; omit or use null. */
+    end_line?: null | number;
+    kind: "file";
+    path: string;
+    /** Optional; omit or use null. */
+    start_line?: null | number;
+  } | {
+    kind: "search";
+    /** Optional; omit or use null. */
+    path?: null | string;
+    /** Optional; omit or use null. */
+    pattern?: null | string;
+    tool: string;
+  } | {
+    kind: "url";
+    url: string;
+  };
+}
+
+/** Request assistance from a super smart comrade! This is a great tool to use anytime you want to double check something, or get a second opinion. In addition, it can write full plans for you! The tool will automatically optimize the prompt you send it and combine it with any and all context you pass along. It is best practice to pass as much context as possible and to write descriptions for them that accurately reflect the purpose of the files and/or directories of files (in relation to the prompt). Even though the responses from this tool are from an expert, be sure to look over them with a close eye. Better to have 2 experts than 1, right ;) */
+export namespace AskReasoningModel {
+  /** Input for the reasoning model request tool. */
+  export type Input = {
+    /**
+     * List of directories that will be expanded into files. You can choose if you
+     * want to walk the directory recursively or not, if you want to specify a
+     * maximum amount of files, and if you want to whitelist/filter by certain file
+     * extensions. This can be useful for passing more files that are important to
+     * a problem context without having to specify every file path.
+     *
+     * Optional; omit or use null.
+     */
+    directories?: null | DirectoryMeta[];
+    /**
+     * A list of file paths and their descriptions. File paths can be relative from
+     * the directory you were launched from, or full paths from the root of file system.
+     */
+    files: FileMeta[];
+    /**
+     * When `PromptType::Plan`, optional filename to write directly into
+     * `thoughts/{branch}/plans/`. If set, returns the repo-relative path of the
+     * created file instead of the content.
+     *
+     * Optional; omit or use null.
+     */
+    output_filename?: null | string;
+    /**
+     * Prompt to pass in to the request. Be specific and detailed, but attempt
+     * to avoid using biasing language. This tool works best with neutral verbiage.
+     * This allows it to reason over the scope of the problem more efficiently.
+     */
+    prompt: string;
+    /**
+     * Type of the output you desire. An enum with either "plan" or "reasoning" as
+     * options. Reasoning is perfect for anytime you need to ask a question or consider
+     * something deeply. "plan" is useful for writing fully-fledged implementation
+     * plans given a certain desire and context.
+     */
+    prompt_type: PromptType;
+  };
+  export type Output = unknown;
+  export type DirectoryMeta = {
+    description: string;
+    directory_path: string;
+    /** Optional; omit or use null. */
+    extensions?: null | string[];
+    include_hidden?: boolean;
+    /** Maximum number of files to include from this directory (default: 1000) */
+    max_files?: number;
+    recursive?: boolean;
+  };
+  export type FileMeta = {
+    description: string;
+    filename: string;
+  };
+  export type PromptType = "reasoning" | "plan";
+}
+
+/** Run several read-only calls (ls, grep, glob) concurrently and return all results in request order. Each call is {tool, args} with the same args as cli_ls/cli_grep/cli_glob. A failing call reports its error without failing the batch. Max 16 calls. */
+export namespace CliBatch {
+  /** Input for the `cli_batch` tool. */
+  export type Input = {
+    /** Calls to run concurrently; each is {tool: 'ls'|'grep'|'glob', args: {...}} */
+    calls: BatchCall[];
+  };
+  /** Output from `cli_batch` tool. */
+  export type Output = {
+    results: BatchItem[];
+  };
+  /** A single read-only tool call inside a `cli_batch` request. */
+  export type BatchCall = {
+    args: LsInput;
+    tool: "ls";
+  } | {
+    args: SearchGrepInput;
+    tool: "grep";
+  } | {
+    args: SearchGlobInput;
+    tool: "glob";
+  };
+  /** One entry of a `cli_batch` response, in the same order as the request. */
+  export type BatchItem = {
+    /**
+     * Error message when the call failed
+     *
+     * Optional; omit or use null.
+     */
+    error?: null | string;
+    /**
+     * Tool output when the call succeeded
+     *
+     * Optional; omit or use null.
+     */
+    output?: null | BatchResult;
+    /** Tool name that handled this call (e.g., `cli_grep`) */
+    tool: string;
+  };
+  /** Output of a single call inside a `cli_batch` request. */
+  export type BatchResult = LsOutput | GrepOutput | GlobOutput;
+  /** Depth of directory traversal (0-10) */
+  export type Depth0to10 = number;
+  export type EntryKind = "file" | "dir" | "symlink";
+  /** Output from `search_glob` tool. */
+  export type GlobOutput = {
+    /** Matched file/directory paths (relative to root) */
+    entries: string[];
+    /** Whether there are more results beyond `head_limit` */
+    has_more: boolean;
+    /** Root directory searched */
+    root: string;
+    /** Warnings encountered during search */
+    warnings: string[];
+  };
+  /** Output from `search_grep` tool. */
+  export type GrepOutput = {
+    /** Whether there are more results beyond `head_limit` */
+    has_more: boolean;
+    /**
+     * Lines to display (mode-specific):
+     * - files: relative file paths
+     * - content: `path:line: content`
+     * - count: usually empty; see summary
+     */
+    lines: string[];
+    /** Output mode used */
+    mode: OutputMode;
+    /** Root directory searched */
+    root: string;
+    /**
+     * Optional summary (e.g., total matches for count mode)
+     *
+     * Optional; omit or use null.
+     */
+    summary?: null | string;
+    /** Warnings encountered during search (e.g., binary files skipped) */
+    warnings: string[];
+  };
+  export type LsEntry = {
+    kind: EntryKind;
+    path: string;
+  };
+  /** Input for the ls tool. */
+  export type LsInput = {
+    /**
+     * Traversal depth: 0=header, 1=children (default), 2-10=tree
+     *
+     * Optional; omit or use null.
+     */
+    depth?: null | Depth0to10;
+    /**
+     * Text rendering for this call: 'text' (compact), 'markdown', or 'json'. Default: transport setting
+     *
+     * Optional; omit or use null.
+     */
+    format?: null | RenderFormat;
+    /**
+     * Include hidden files (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    hidden?: null | boolean;
+    /**
+     * Additional glob patterns to ignore
+     *
+     * Optional; omit or use null.
+     */
+    ignore?: null | string[];
+    /**
+     * Directory path (absolute or relative to cwd)
+     *
+     * Optional; omit or use null.
+     */
+    path?: null | string;
+    /**
+     * Filter: 'all' (default), 'files', or 'dirs'
+     *
+     * Optional; omit or use null.
+     */
+    show?: null | Show;
+  };
+  export type LsOutput = {
+    entries: LsEntry[];
+    has_more: boolean;
+    root: string;
+    warnings: string[];
+  };
+  /**
+   * Output mode for `search_grep` results.
+   *
+   * Options: 'files' (default, returns unique file paths - most token-efficient),
+   * 'content' (returns matching lines with context), or 'count' (returns total match count only).
+   */
+  export type OutputMode = "files" | "content" | "count";
+  /**
+   * Text rendering requested on a single call: 'text' (compact listing),
+   * 'markdown' (listing in a fenced block), or 'json' (pretty-printed JSON).
+   *
+   * When set, this overrides the transport's [`TextOptions`] for that call.
+   */
+  export type RenderFormat = "json" | "text" | "markdown";
+  /** Input for the `search_glob` tool. */
+  export type SearchGlobInput = {
+    /**
+     * Max results to return (default: 500, capped at 1000)
+     *
+     * Optional; omit or use null.
+     */
+    head_limit?: null | number;
+    /**
+     * Additional glob patterns to ignore (exclude)
+     *
+     * Optional; omit or use null.
+     */
+    ignore?: null | string[];
+    /**
+     * Include hidden files (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_hidden?: null | boolean;
+    /**
+     * Include paths normally ignored by gitignore and default ignores (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_ignored?: null | boolean;
+    /**
+     * Skip the first N results (default: 0)
+     *
+     * Optional; omit or use null.
+     */
+    offset?: null | number;
+    /**
+     * Directory path (absolute or relative to cwd)
+     *
+     * Optional; omit or use null.
+     */
+    path?: null | string;
+    /** Glob pattern to match against (e.g., '**\/*.rs') */
+    pattern: string;
+    /**
+     * Sort order: 'name' (default) or 'mtime' (newest first)
+     *
+     * Optional; omit or use null.
+     */
+    sort?: null | SortOrder;
+  };
+  /** Input for the `search_grep` tool. */
+  export type SearchGrepInput = {
+    /**
+     * Case-insensitive matching (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    case_insensitive?: null | boolean;
+    /**
+     * Context lines before and after matches (overridden by `context_before/after` if provided)
+     *
+     * Optional; omit or use null.
+     */
+    context?: null | number;
+    /**
+     * Context lines after match
+     *
+     * Optional; omit or use null.
+     */
+    context_after?: null | number;
+    /**
+     * Context lines before match
+     *
+     * Optional; omit or use null.
+     */
+    context_before?: null | number;
+    /**
+     * Text rendering for this call: 'text' (compact), 'markdown', or 'json'. Default: transport setting
+     *
+     * Optional; omit or use null.
+     */
+    format?: null | RenderFormat;
+    /**
+     * Include-only glob patterns (files to consider)
+     *
+     * Optional; omit or use null.
+     */
+    globs?: null | string[];
+    /**
+     * Max results to return (default: 200, capped at 1000)
+     *
+     * Optional; omit or use null.
+     */
+    head_limit?: null | number;
+    /**
+     * Additional glob patterns to ignore (exclude)
+     *
+     * Optional; omit or use null.
+     */
+    ignore?: null | string[];
+    /**
+     * Search binary files as text (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_binary?: null | boolean;
+    /**
+     * Include hidden files (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_hidden?: null | boolean;
+    /**
+     * Include paths normally ignored by gitignore and default ignores (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_ignored?: null | boolean;
+    /**
+     * Show line numbers in content mode (default: true)
+     *
+     * Optional; omit or use null.
+     */
+    line_numbers?: null | boolean;
+    /**
+     * Output mode: 'files' (default), 'content', or 'count'
+     *
+     * Optional; omit or use null.
+     */
+    mode?: null | OutputMode;
+    /**
+     * Allow '.' to match newlines; patterns may span lines (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    multiline?: null | boolean;
+    /**
+     * Skip the first N results (default: 0)
+     *
+     * Optional; omit or use null.
+     */
+    offset?: null | number;
+    /**
+     * Directory path (absolute or relative to cwd)
+     *
+     * Optional; omit or use null.
+     */
+    path?: null | string;
+    /** Regex pattern to search for */
+    pattern: string;
+    /**
+     * Reuse a persistent trigram index to skip files that cannot match; useful for repeated searches in large repos (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    use_index?: null | boolean;
+  };
+  export type Show = "all" | "files" | "dirs";
+  /**
+   * Sort order for `search_glob` results: 'name' (default, alphabetical case-insensitive) or
+   * 'mtime' (newest modification time first).
+   */
+  export type SortOrder = "name" | "mtime";
+}
+
+/** Glob-based path match. Applies default ignores (gitignore + common dirs like node_modules/, target/, logs/). If expected matches are missing, retry with include_ignored=true. Sorting by name (default) or mtime (newest first). Stateless pagination via head_limit+offset. */
+export namespace CliGlob {
+  /** Input for the `search_glob` tool. */
+  export type Input = {
+    /**
+     * Max results to return (default: 500, capped at 1000)
+     *
+     * Optional; omit or use null.
+     */
+    head_limit?: null | number;
+    /**
+     * Additional glob patterns to ignore (exclude)
+     *
+     * Optional; omit or use null.
+     */
+    ignore?: null | string[];
+    /**
+     * Include hidden files (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_hidden?: null | boolean;
+    /**
+     * Include paths normally ignored by gitignore and default ignores (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_ignored?: null | boolean;
+    /**
+     * Skip the first N results (default: 0)
+     *
+     * Optional; omit or use null.
+     */
+    offset?: null | number;
+    /**
+     * Directory path (absolute or relative to cwd)
+     *
+     * Optional; omit or use null.
+     */
+    path?: null | string;
+    /** Glob pattern to match against (e.g., '**\/*.rs') */
+    pattern: string;
+    /**
+     * Sort order: 'name' (default) or 'mtime' (newest first)
+     *
+     * Optional; omit or use null.
+     */
+    sort?: null | SortOrder;
+  };
+  /** Output from `search_glob` tool. */
+  export type Output = {
+    /** Matched file/directory paths (relative to root) */
+    entries: string[];
+    /** Whether there are more results beyond `head_limit` */
+    has_more: boolean;
+    /** Root directory searched */
+    root: string;
+    /** Warnings encountered during search */
+    warnings: string[];
+  };
+  /**
+   * Sort order for `search_glob` results: 'name' (default, alphabetical case-insensitive) or
+   * 'mtime' (newest modification time first).
+   */
+  export type SortOrder = "name" | "mtime";
+}
+
+/** Regex-based search. Applies default ignores (gitignore + common dirs like node_modules/, target/, logs/). If expected matches are missing, retry with include_ignored=true. Modes: files (default), content, count. Stateless pagination via head_limit+offset. For repeated searches in large repos, set use_index=true to skip files via a cached trigram index (status reported in warnings). */
+export namespace CliGrep {
+  /** Input for the `search_grep` tool. */
+  export type Input = {
+    /**
+     * Case-insensitive matching (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    case_insensitive?: null | boolean;
+    /**
+     * Context lines before and after matches (overridden by `context_before/after` if provided)
+     *
+     * Optional; omit or use null.
+     */
+    context?: null | number;
+    /**
+     * Context lines after match
+     *
+     * Optional; omit or use null.
+     */
+    context_after?: null | number;
+    /**
+     * Context lines before match
+     *
+     * Optional; omit or use null.
+     */
+    context_before?: null | number;
+    /**
+     * Text rendering for this call: 'text' (compact), 'markdown', or 'json'. Default: transport setting
+     *
+     * Optional; omit or use null.
+     */
+    format?: null | RenderFormat;
+    /**
+     * Include-only glob patterns (files to consider)
+     *
+     * Optional; omit or use null.
+     */
+    globs?: null | string[];
+    /**
+     * Max results to return (default: 200, capped at 1000)
+     *
+     * Optional; omit or use null.
+     */
+    head_limit?: null | number;
+    /**
+     * Additional glob patterns to ignore (exclude)
+     *
+     * Optional; omit or use null.
+     */
+    ignore?: null | string[];
+    /**
+     * Search binary files as text (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_binary?: null | boolean;
+    /**
+     * Include hidden files (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_hidden?: null | boolean;
+    /**
+     * Include paths normally ignored by gitignore and default ignores (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_ignored?: null | boolean;
+    /**
+     * Show line numbers in content mode (default: true)
+     *
+     * Optional; omit or use null.
+     */
+    line_numbers?: null | boolean;
+    /**
+     * Output mode: 'files' (default), 'content', or 'count'
+     *
+     * Optional; omit or use null.
+     */
+    mode?: null | OutputMode;
+    /**
+     * Allow '.' to match newlines; patterns may span lines (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    multiline?: null | boolean;
+    /**
+     * Skip the first N results (default: 0)
+     *
+     * Optional; omit or use null.
+     */
+    offset?: null | number;
+    /**
+     * Directory path (absolute or relative to cwd)
+     *
+     * Optional; omit or use null.
+     */
+    path?: null | string;
+    /** Regex pattern to search for */
+    pattern: string;
+    /**
+     * Reuse a persistent trigram index to skip files that cannot match; useful for repeated searches in large repos (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    use_index?: null | boolean;
+  };
+  /** Output from `search_grep` tool. */
+  export type Output = {
+    /** Whether there are more results beyond `head_limit` */
+    has_more: boolean;
+    /**
+     * Lines to display (mode-specific):
+     * - files: relative file paths
+     * - content: `path:line: content`
+     * - count: usually empty; see summary
+     */
+    lines: string[];
+    /** Output mode used */
+    mode: OutputMode;
+    /** Root directory searched */
+    root: string;
+    /**
+     * Optional summary (e.g., total matches for count mode)
+     *
+     * Optional; omit or use null.
+     */
+    summary?: null | string;
+    /** Warnings encountered during search (e.g., binary files skipped) */
+    warnings: string[];
+  };
+  /**
+   * Output mode for `search_grep` results.
+   *
+   * Options: 'files' (default, returns unique file paths - most token-efficient),
+   * 'content' (returns matching lines with context), or 'count' (returns total match count only).
+   */
+  export type OutputMode = "files" | "content" | "count";
+  /**
+   * Text rendering requested on a single call: 'text' (compact listing),
+   * 'markdown' (listing in a fenced block), or 'json' (pretty-printed JSON).
+   *
+   * When set, this overrides the transport's [`TextOptions`] for that call.
+   */
+  export type RenderFormat = "json" | "text" | "markdown";
+}
+
+/** Execute a just recipe. Defaults to root justfile if no dir specified. Only disambiguate if recipe not in root. */
+export namespace CliJustExecute {
+  /** Input for the `just_execute` tool. */
+  export type Input = {
+    /**
+     * Arguments keyed by parameter name; star params accept arrays
+     *
+     * Optional; omit or use null.
+     */
+    args?: null | Record<string, unknown>;
+    /**
+     * Directory containing the justfile (optional; defaults to root if recipe exists there)
+     *
+     * Optional; omit or use null.
+     */
+    dir?: null | string;
+    /** Recipe name (e.g., 'check', 'test', 'build') */
+    recipe: string;
+  };
+  /** Output from the execute tool. */
+  export type Output = {
+    /** Directory where recipe was executed */
+    dir: string;
+    /**
+     * Exit code (if available)
+     *
+     * Optional; omit or use null.
+     */
+    exit_code?: null | number;
+    /** Recipe that was executed */
+    recipe: string;
+    /** Standard error */
+    stderr: string;
+    /** Standard output */
+    stdout: string;
+    /** Whether execution succeeded (exit code 0) */
+    success: boolean;
+  };
+}
+
+/** Search justfile recipes by name or docs. Optional dir filter. Same params => next page. Page size: 10. */
+export namespace CliJustSearch {
+  /** Input for the `just_search` tool. */
+  export type Input = {
+    /**
+     * Directory filter (repo-relative or absolute)
+     *
+     * Optional; omit or use null.
+     */
+    dir?: null | string;
+    /**
+     * Search query (substring match on name/docs)
+     *
+     * Optional; omit or use null.
+     */
+    query?: null | string;
+  };
+  /** Output from the search tool. */
+  export type Output = {
+    /** Whether more results are available */
+    has_more: boolean;
+    /** Items in the current page */
+    items: SearchItem[];
+  };
+  /** A single search result item. */
+  export type SearchItem = {
+    /** Directory containing the justfile */
+    dir: string;
+    /**
+     * Documentation comment (first line)
+     *
+     * Optional; omit or use null.
+     */
+    doc?: null | string;
+    /** Parameter names (with ? for optional, * for variadic) */
+    params: string[];
+    /** Recipe name */
+    recipe: string;
+  };
+}
+
+/** List files and directories. Depth: 0=header only, 1=children (default), 2-10=tree. Filter with show='files'|'dirs'|'all'. Gitignore-aware. For shallow queries, call with same params again for next page. */
+export namespace CliLs {
+  /** Input for the ls tool. */
+  export type Input = {
+    /**
+     * Traversal depth: 0=header, 1=children (default), 2-10=tree
+     *
+     * Optional; omit or use null.
+     */
+    depth?: null | Depth0to10;
+    /**
+     * Text rendering for this call: 'text' (compact), 'markdown', or 'json'. Default: transport setting
+     *
+     * Optional; omit or use null.
+     */
+    format?: null | RenderFormat;
+    /**
+     * Include hidden files (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    hidden?: null | boolean;
+    /**
+     * Additional glob patterns to ignore
+     *
+     * Optional; omit or use null.
+     */
+    ignore?: null | string[];
+    /**
+     * Directory path (absolute or relative to cwd)
+     *
+     * Optional; omit or use null.
+     */
+    path?: null | string;
+    /**
+     * Filter: 'all' (default), 'files', or 'dirs'
+     *
+     * Optional; omit or use null.
+     */
+    show?: null | Show;
+  };
+  export type Output = {
+    entries: LsEntry[];
+    has_more: boolean;
+    root: string;
+    warnings: string[];
+  };
+  /** Depth of directory traversal (0-10) */
+  export type Depth0to10 = number;
+  export type EntryKind = "file" | "dir" | "symlink";
+  export type LsEntry = {
+    kind: EntryKind;
+    path: string;
+  };
+  /**
+   * Text rendering requested on a single call: 'text' (compact listing),
+   * 'markdown' (listing in a fenced block), or 'json' (pretty-printed JSON).
+   *
+   * When set, this overrides the transport's [`TextOptions`] for that call.
+   */
+  export type RenderFormat = "json" | "text" | "markdown";
+  export type Show = "all" | "files" | "dirs";
+}
+
+/** Reply to a PR review comment. Automatically prefixes with AI identifier to clearly mark automated responses. */
+export namespace GhAddCommentReply {
+  /** Input for `add_comment_reply` tool. */
+  export type Input = {
+    /** Reply message body */
+    body: string;
+    /** ID of the comment to reply to */
+    comment_id: number;
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  export type Output = {
+    body: string;
+    created_at: string;
+    html_url: string;
+    id: number;
+    /** Optional; omit or use null. */
+    in_reply_to_id?: null | number;
+    is_bot: boolean;
+    /** Optional; omit or use null. */
+    line?: null | number;
+    path: string;
+    /** Optional; omit or use null. */
+    pull_request_review_id?: null | number;
+    /** Optional; omit or use null. */
+    side?: null | string;
+    updated_at: string;
+    user: string;
+  };
+}
+
+/** Post a new top-level comment on a PR (conversation tab, not attached to code). Automatically prefixes with AI identifier. To reply to an existing review comment, use gh_add_comment_reply instead. */
+export namespace GhAddPrComment {
+  /** Input for `add_pr_comment` tool. */
+  export type Input = {
+    /** Comment body (markdown) */
+    body: string;
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** Top-level (issue-style) comment posted on a PR. */
+  export type Output = {
+    body: string;
+    created_at: string;
+    html_url: string;
+    id: number;
+    pr_number: number;
+    user: string;
+  };
+}
+
+/** Replace the body of a PR review comment (kind=review) or top-level PR comment (kind=issue). Only comments written by the authenticated user can be edited. Automatically prefixes with AI identifier. */
+export namespace GhEditComment {
+  /** Input for `edit_comment` tool. */
+  export type Input = {
+    /** New comment body (markdown); replaces the existing body */
+    body: string;
+    /** ID of the comment to edit */
+    comment_id: number;
+    /** Comment kind: review (inline, default) or issue (top-level) */
+    kind?: CommentKind;
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** PR comment after an edit. */
+  export type Output = {
+    body: string;
+    html_url: string;
+    id: number;
+    kind: CommentKind;
+    updated_at: string;
+    user: string;
+  };
+  /** Which kind of PR comment a comment ID refers to. */
+  export type CommentKind = "review" | "issue";
+}
+
+/** List files changed in a PR with status (added/modified/removed/renamed) and +/- line counts, with implicit pagination. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1. */
+export namespace GhGetChangedFiles {
+  /** Input for `get_changed_files` tool. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** Page of changed files for a PR (without patches). */
+  export type Output = {
+    files: PrFile[];
+    has_more: boolean;
+    /** Optional; omit or use null. */
+    message?: null | string;
+    owner: string;
+    pr_number: number;
+    repo: string;
+    shown_files: number;
+    total_files: number;
+  };
+  /** A file changed in a PR. */
+  export type PrFile = {
+    additions: number;
+    deletions: number;
+    filename: string;
+    /**
+     * Unified diff hunks; absent for binary or very large files
+     *
+     * Optional; omit or use null.
+     */
+    patch?: null | string;
+    /** Optional; omit or use null. */
+    previous_filename?: null | string;
+    /** added, removed, modified, renamed, copied, changed, or unchanged */
+    status: string;
+  };
+}
+
+/** Fetch the log of a GitHub Actions check run: any ##[error] lines plus the last tail_lines lines (timestamps stripped). Only Actions jobs have logs; third-party checks link out via their URL instead. */
+export namespace GhGetCheckLog {
+  /** Input for `get_check_log` tool. */
+  export type Input = {
+    /** Check run ID from `gh_get_checks` (GitHub Actions jobs only) */
+    check_run_id: number;
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+    /**
+     * Number of trailing log lines to return (default: 200, max: 2000)
+     *
+     * Optional; omit or use null.
+     */
+    tail_lines?: null | number;
+  };
+  /** Excerpt of a check run's job log. */
+  export type Output = {
+    check_run_id: number;
+    /** Lines annotated as errors (`##[error]`) anywhere in the log, capped */
+    error_lines: string[];
+    /** Last `tail_lines` lines of the log, timestamps stripped */
+    tail: string;
+    total_lines: number;
+    truncated: boolean;
+  };
+}
+
+/** List CI check runs on a PR's head commit with status and conclusion, failing runs first. Use gh_get_check_log with a failing run's ID to read its log. */
+export namespace GhGetChecks {
+  /** Input for `get_checks` tool. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** Check runs for a PR's head commit, failing runs first. */
+  export type Output = {
+    check_runs: CheckRunSummary[];
+    head_sha: string;
+    owner: string;
+    pr_number: number;
+    repo: string;
+    summary: ChecksSummary;
+  };
+  /** A single check run on a commit. */
+  export type CheckRunSummary = {
+    /** Optional; omit or use null. */
+    app_slug?: null | string;
+    /** Optional; omit or use null. */
+    completed_at?: null | string;
+    /** Optional; omit or use null. */
+    conclusion?: null | string;
+    /** Optional; omit or use null. */
+    html_url?: null | string;
+    /** Check run ID; for GitHub Actions this is also the job ID used by `gh_get_check_log` */
+    id: number;
+    name: string;
+    /**
+     * Short title from the check's output, if any
+     *
+     * Optional; omit or use null.
+     */
+    output_title?: null | string;
+    /** Optional; omit or use null. */
+    started_at?: null | string;
+    status: string;
+  };
+  /** Rollup of check suites on a PR's head commit. */
+  export type ChecksSummary = {
+    failed: number;
+    /** Neutral, skipped, or stale suites */
+    other: number;
+    passed: number;
+    pending: number;
+    total: number;
+  };
+}
+
+/** Get PR review comments with thread-level implicit pagination. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1. Use output: "summary" for a compact one-line-per-thread overview of what is still open. */
+export namespace GhGetComments {
+  /** Input for `get_comments` tool. */
+  export type Input = {
+    /**
+     * Only threads where this GitHub login wrote a comment (case-insensitive)
+     *
+     * Optional; omit or use null.
+     */
+    author?: null | string;
+    /**
+     * Filter by comment source: robot, human, or all
+     *
+     * Optional; omit or use null.
+     */
+    comment_source_type?: null | CommentSourceType;
+    /**
+     * Include resolved review comments (defaults to false)
+     *
+     * Optional; omit or use null.
+     */
+    include_resolved?: null | boolean;
+    /**
+     * Output shape: full (default, every comment body) or summary (one line per thread with file, line, status, participants, excerpt, and last activity)
+     *
+     * Optional; omit or use null.
+     */
+    output?: null | CommentOutputMode;
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * Only threads on files matching this glob (e.g., "src/net/**", "**\/*.rs")
+     *
+     * Optional; omit or use null.
+     */
+    path_glob?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+    /**
+     * Only threads with a comment updated at or after this time: RFC 3339 timestamp, YYYY-MM-DD, or an age like "24h", "7d", "2w"
+     *
+     * Optional; omit or use null.
+     */
+    updated_after?: null | string;
+  };
+  export type Output = {
+    comments: ReviewComment[];
+    /** Whether there are more pages available */
+    has_more: boolean;
+    /**
+     * Optional pagination hint message for structured consumers.
+     *
+     * Optional; omit or use null.
+     */
+    message?: null | string;
+    owner: string;
+    pr_number: number;
+    pr_url: string;
+    /**
+     * Remaining GitHub API quota, when known.
+     *
+     * Optional; omit or use null.
+     */
+    rate_limit?: null | RateLimitStatus;
+    repo: string;
+    /** Number of threads shown so far (cumulative across pagination calls) */
+    shown_threads: number;
+    /** Collapsed threads, filled instead of `comments` in summary mode. */
+    threads?: ThreadSummary[];
+    /** Total number of threads available for this query */
+    total_threads: number;
+  };
+  /** Shape of `gh_get_comments` output. */
+  export type CommentOutputMode = "full" | "summary";
+  /** Filter for comment source type: robot (bot), human, or all. */
+  export type CommentSourceType = "robot" | "human" | "all";
+  /** GitHub API quota reported by the most recent response. */
+  export type RateLimitStatus = {
+    limit: number;
+    remaining: number;
+    /** Unix timestamp (seconds) when the quota resets */
+    reset_at: number;
+    /** Rate-limit bucket (`core`, `graphql`, `search`, ...) */
+    resource: string;
+  };
+  export type ReviewComment = {
+    body: string;
+    created_at: string;
+    html_url: string;
+    id: number;
+    /** Optional; omit or use null. */
+    in_reply_to_id?: null | number;
+    is_bot: boolean;
+    /** Optional; omit or use null. */
+    line?: null | number;
+    path: string;
+    /** Optional; omit or use null. */
+    pull_request_review_id?: null | number;
+    /** Optional; omit or use null. */
+    side?: null | string;
+    updated_at: string;
+    user: string;
+  };
+  export type ThreadStatus = "unresolved" | "resolved";
+  /** One review thread collapsed for `gh_get_comments` summary mode. */
+  export type ThreadSummary = {
+    comment_count: number;
+    /** ID of the thread's first comment (use with `gh_add_comment_reply`) */
+    comment_id: number;
+    file: string;
+    first_comment_excerpt: string;
+    /** Latest `updated_at` across the thread */
+    last_activity: string;
+    /** Optional; omit or use null. */
+    line?: null | number;
+    /** Commenters in order of first appearance */
+    participants: string[];
+    status: ThreadStatus;
+  };
+}
+
+/** Get PR metadata: title, description, state, base/head refs, change stats, reviewers with their latest review state, and a check-suite summary for the head commit. Use gh_get_changed_files and gh_get_pr_diff for the code changes. */
+export namespace GhGetPr {
+  /** Input for `get_pr` tool. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** PR metadata for review context. */
+  export type Output = {
+    additions: number;
+    author: string;
+    base_ref: string;
+    body: string;
+    changed_files: number;
+    checks: ChecksSummary;
+    commits: number;
+    deletions: number;
+    head_ref: string;
+    head_sha: string;
+    is_draft: boolean;
+    /**
+     * None while GitHub is still computing mergeability
+     *
+     * Optional; omit or use null.
+     */
+    mergeable?: null | boolean;
+    number: number;
+    owner: string;
+    repo: string;
+    reviewers: PrReviewer[];
+    state: string;
+    title: string;
+    url: string;
+  };
+  /** Rollup of check suites on a PR's head commit. */
+  export type ChecksSummary = {
+    failed: number;
+    /** Neutral, skipped, or stale suites */
+    other: number;
+    passed: number;
+    pending: number;
+    total: number;
+  };
+  /** A reviewer on a PR and where their review stands. */
+  export type PrReviewer = {
+    /** User login, or `team:<slug>` for requested teams */
+    login: string;
+    /** Latest review state (APPROVED, `CHANGES_REQUESTED`, COMMENTED, DISMISSED) or REQUESTED if pending */
+    state: string;
+  };
+}
+
+/** Get the unified diff of a PR, one patch per file, with implicit pagination by file. Optionally limit to specific paths. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1. */
+export namespace GhGetPrDiff {
+  /** Input for `get_pr_diff` tool. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /** Limit the diff to these files or directories (repo-relative). Default: all files */
+    paths?: string[];
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** Page of per-file diffs for a PR. */
+  export type Output = {
+    files: PrFile[];
+    has_more: boolean;
+    /** Optional; omit or use null. */
+    message?: null | string;
+    owner: string;
+    pr_number: number;
+    repo: string;
+    shown_files: number;
+    total_files: number;
+  };
+  /** A file changed in a PR. */
+  export type PrFile = {
+    additions: number;
+    deletions: number;
+    filename: string;
+    /**
+     * Unified diff hunks; absent for binary or very large files
+     *
+     * Optional; omit or use null.
+     */
+    patch?: null | string;
+    /** Optional; omit or use null. */
+    previous_filename?: null | string;
+    /** added, removed, modified, renamed, copied, changed, or unchanged */
+    status: string;
+  };
+}
+
+/** List pull requests in the repository with implicit pagination. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1. */
+export namespace GhGetPrs {
+  /** Input for `list_prs` tool. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+    /**
+     * PR state filter: open, closed, or all
+     *
+     * Optional; omit or use null.
+     */
+    state?: null | string;
+  };
+  export type Output = {
+    has_more: boolean;
+    /** Optional; omit or use null. */
+    message?: null | string;
+    owner: string;
+    prs: PrSummary[];
+    /**
+     * Remaining GitHub API quota, when known.
+     *
+     * Optional; omit or use null.
+     */
+    rate_limit?: null | RateLimitStatus;
+    repo: string;
+    shown_prs: number;
+    state: string;
+    total_prs: number;
+  };
+  export type PrSummary = {
+    author: string;
+    comment_count: number;
+    created_at: string;
+    number: number;
+    review_comment_count: number;
+    state: string;
+    title: string;
+    updated_at: string;
+  };
+  /** GitHub API quota reported by the most recent response. */
+  export type RateLimitStatus = {
+    limit: number;
+    remaining: number;
+    /** Unix timestamp (seconds) when the quota resets */
+    reset_at: number;
+    /** Rate-limit bucket (`core`, `graphql`, `search`, ...) */
+    resource: string;
+  };
+}
+
+/** Add an emoji reaction to a PR review comment (kind=review) or top-level PR comment (kind=issue). Use for lightweight acknowledgement (e.g. eyes when starting work, +1 when done) instead of posting a reply. Reacting twice with the same emoji is a no-op. */
+export namespace GhReactToComment {
+  /** Input for `react_to_comment` tool. */
+  export type Input = {
+    /** ID of the comment to react to */
+    comment_id: number;
+    /** Comment kind: review (inline, default) or issue (top-level) */
+    kind?: CommentKind;
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /** Reaction: +1, -1, laugh, confused, heart, hooray, rocket, or eyes */
+    reaction: Reaction;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** Reaction added to a PR comment. */
+  export type Output = {
+    comment_id: number;
+    comment_kind: CommentKind;
+    content: string;
+    id: number;
+    user: string;
+  };
+  /** Which kind of PR comment a comment ID refers to. */
+  export type CommentKind = "review" | "issue";
+  /** Reaction emoji accepted by `gh_react_to_comment`. */
+  export type Reaction = "laugh" | "confused" | "heart" | "hooray" | "rocket" | "eyes" | "+1" | "-1";
+}
+
+/** Mark a PR review thread as resolved after addressing it. Accepts the thread's GraphQL node ID or the ID of any review comment in the thread. Use gh_unresolve_thread to reopen. */
+export namespace GhResolveThread {
+  /** Input for `resolve_thread` and `unresolve_thread` tools. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number used to look up the thread from a comment ID (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+    /** Review thread to update: its GraphQL node ID (e.g. "PRRT_...") or the numeric ID of any review comment in it (as shown by `gh_get_comments`) */
+    thread_id: string;
+  };
+  /** Review thread state after a resolve/unresolve mutation. */
+  export type Output = {
+    is_resolved: boolean;
+    /** GraphQL node ID of the review thread */
+    thread_id: string;
+  };
+}
+
+/** Submit a PR review (comment, approve, or request_changes) with an optional summary body and new inline comments on diff lines. Automatically prefixes the body and each inline comment with AI identifier. */
+export namespace GhSubmitReview {
+  /** Input for `submit_review` tool. */
+  export type Input = {
+    /**
+     * Review summary (required for `request_changes`; for comment, required unless inline comments are given)
+     *
+     * Optional; omit or use null.
+     */
+    body?: null | string;
+    /** Review verdict: comment, approve, or `request_changes` */
+    event: ReviewEvent;
+    /** New inline comments on lines in the PR diff */
+    inline_comments?: ReviewInlineComment[];
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+  };
+  /** Review submitted on a PR. */
+  export type Output = {
+    body: string;
+    html_url: string;
+    id: number;
+    /** Number of inline comments submitted with the review */
+    inline_comments: number;
+    pr_number: number;
+    state: string;
+    /** Optional; omit or use null. */
+    submitted_at?: null | string;
+    user: string;
+  };
+  /** Review verdict for `gh_submit_review`. */
+  export type ReviewEvent = "approve" | "request_changes" | "comment";
+  /** New inline comment attached to a submitted review. */
+  export type ReviewInlineComment = {
+    /** Comment body */
+    body: string;
+    /** Line the comment applies to (last line for multi-line comments) */
+    line: number;
+    /** File path relative to the repository root */
+    path: string;
+    /**
+     * Diff side: RIGHT (new code, default) or LEFT (removed code)
+     *
+     * Optional; omit or use null.
+     */
+    side?: null | string;
+    /**
+     * First line for a multi-line comment
+     *
+     * Optional; omit or use null.
+     */
+    start_line?: null | number;
+  };
+}
+
+/** Reopen a resolved PR review thread. Accepts the thread's GraphQL node ID or the ID of any review comment in the thread. */
+export namespace GhUnresolveThread {
+  /** Input for `resolve_thread` and `unresolve_thread` tools. */
+  export type Input = {
+    /**
+     * Repository owner (defaults to the current checkout's owner)
+     *
+     * Optional; omit or use null.
+     */
+    owner?: null | string;
+    /**
+     * PR number used to look up the thread from a comment ID (auto-detected if not provided)
+     *
+     * Optional; omit or use null.
+     */
+    pr_number?: null | number;
+    /**
+     * Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+     *
+     * Optional; omit or use null.
+     */
+    repo?: null | string;
+    /** Review thread to update: its GraphQL node ID (e.g. "PRRT_...") or the numeric ID of any review comment in it (as shown by `gh_get_comments`) */
+    thread_id: string;
+  };
+  /** Review thread state after a resolve/unresolve mutation. */
+  export type Output = {
+    is_resolved: boolean;
+    /** GraphQL node ID of the review thread */
+    thread_id: string;
+  };
+}
+
+/** Link a URL (PR, design doc, log dump) to a Linear issue as an attachment. If the issue already has an attachment with the same URL (ignoring case of host, trailing slash, and #fragment), returns it with action=already_attached instead of adding a duplicate. */
+export namespace LinearAddAttachment {
+  /** Input for `add_attachment` tool. */
+  export type Input = {
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+    /**
+     * Optional subtitle shown under the title
+     *
+     * Optional; omit or use null.
+     */
+    subtitle?: null | string;
+    /** Attachment title shown on the issue (defaults to the URL when empty) */
+    title: string;
+    /** http(s) URL to link (PR, design doc, log dump, ...) */
+    url: string;
+  };
+  /** Result of an `add_attachment` operation */
+  export type Output = {
+    /** Action taken: `"created"` or `"already_attached"` (URL matched an existing attachment) */
+    action: string;
+    attachment: AttachmentSummary;
+    success: boolean;
+  };
+  export type AttachmentSummary = {
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator_name?: null | string;
+    id: string;
+    /**
+     * Integration that created the attachment (e.g. github, slack), if any
+     *
+     * Optional; omit or use null.
+     */
+    source_type?: null | string;
+    /** Optional; omit or use null. */
+    subtitle?: null | string;
+    title: string;
+    url: string;
+  };
+}
+
+/** Add a comment to a Linear issue */
+export namespace LinearAddComment {
+  /** Input for `add_comment` tool. */
+  export type Input = {
+    /** Comment body (markdown supported) */
+    body: string;
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+    /**
+     * Parent comment ID for replies (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    parent_id?: null | string;
+  };
+  export type Output = {
+    /** Optional; omit or use null. */
+    body?: null | string;
+    /** Optional; omit or use null. */
+    comment_id?: null | string;
+    /** Optional; omit or use null. */
+    created_at?: null | string;
+    success: boolean;
+  };
+}
+
+/** Add a relation between two issues (blocks, blocked_by, duplicate, related). Existing identical relations are left alone, so repeated calls are safe. Set parents with linear_update_issue parent_id; see current relations in linear_read_issue. */
+export namespace LinearAddRelation {
+  /** Input for adding an issue relation */
+  export type Input = {
+    /** Source issue identifier (UUID, key like ENG-245, or URL) */
+    issue: string;
+    /** Related issue identifier (UUID, key like ENG-245, or URL) */
+    related_issue: string;
+    /** Relation type: `blocks` (issue blocks related), `blocked_by`, `duplicate` (issue duplicates related), or `related` */
+    relation_type: string;
+  };
+  /** Result of a `set_relation` or `add_relation` operation */
+  export type Output = {
+    /** Action taken: `"created"`, `"already_exists"`, `"removed"`, or `"no_change"` */
+    action: string;
+    success: boolean;
+  };
+}
+
+/** Archive a Linear issue by ID, identifier (e.g., ENG-245), or URL */
+export namespace LinearArchiveIssue {
+  /** Input for `archive_issue` tool. */
+  export type Input = {
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+  };
+  export type Output = {
+    success: boolean;
+  };
+}
+
+/** Create a new Linear issue in a team */
+export namespace LinearCreateIssue {
+  /** Input for `create_issue` tool. */
+  export type Input = {
+    /**
+     * Assignee user ID (UUID), or "me" for the API key owner
+     *
+     * Optional; omit or use null.
+     */
+    assignee_id?: null | string;
+    /**
+     * Issue description (markdown supported)
+     *
+     * Optional; omit or use null.
+     */
+    description?: null | string;
+    /** Label IDs (UUIDs) */
+    label_ids?: string[];
+    /**
+     * Parent issue ID (UUID) for sub-issues
+     *
+     * Optional; omit or use null.
+     */
+    parent_id?: null | string;
+    /**
+     * Priority (0=None, 1=Urgent, 2=High, 3=Normal, 4=Low)
+     *
+     * Optional; omit or use null.
+     */
+    priority?: null | number;
+    /**
+     * Project ID (UUID) or name. Defaults to the configured default project
+     *
+     * Optional; omit or use null.
+     */
+    project_id?: null | string;
+    /**
+     * Workflow state ID (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    state_id?: null | string;
+    /**
+     * Team ID (UUID) or key (e.g. ENG) to create the issue in. Defaults to the configured default team
+     *
+     * Optional; omit or use null.
+     */
+    team_id?: null | string;
+    /** Issue title */
+    title: string;
+  };
+  export type Output = {
+    /** Optional; omit or use null. */
+    issue?: null | IssueSummary;
+    success: boolean;
+  };
+  export type IssueSummary = {
+    /** Optional; omit or use null. */
+    assignee?: null | UserRef;
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator?: null | UserRef;
+    /** Optional; omit or use null. */
+    due_date?: null | string;
+    id: string;
+    identifier: string;
+    label_ids: string[];
+    priority: number;
+    priority_label: string;
+    /** Optional; omit or use null. */
+    project?: null | ProjectRef;
+    /** Optional; omit or use null. */
+    state?: null | WorkflowStateRef;
+    team: TeamRef;
+    title: string;
+    updated_at: string;
+    url: string;
+  };
+  export type ProjectRef = {
+    id: string;
+    name: string;
+  };
+  export type TeamRef = {
+    id: string;
+    key: string;
+    name: string;
+  };
+  export type UserRef = {
+    email: string;
+    id: string;
+    name: string;
+  };
+  export type WorkflowStateRef = {
+    id: string;
+    name: string;
+    state_type: string;
+  };
+}
+
+/** Export a Linear issue with its description, comments, and sub-issue tree as one markdown document. Save the markdown with thoughts_write_document (doc_type=artifact) under the suggested filename to archive the context. */
+export namespace LinearExportIssue {
+  /** Input for `export_issue` tool. */
+  export type Input = {
+    /**
+     * Include the sub-issue tree (default: true)
+     *
+     * Optional; omit or use null.
+     */
+    include_children?: null | boolean;
+    /**
+     * Include each issue's comments (default: true)
+     *
+     * Optional; omit or use null.
+     */
+    include_comments?: null | boolean;
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+  };
+  export type Output = {
+    comment_count: number;
+    /** Suggested thoughts document filename, e.g. `linear-eng-245.md` */
+    filename: string;
+    issue_count: number;
+    markdown: string;
+    /** Some sub-issues were left out because the tree exceeded the export limit */
+    truncated: boolean;
+  };
+}
+
+/** List a team's cycles (sprints) with dates, progress, and status (active/next/upcoming/previous/past). Completed cycles are omitted unless include_past=true. Use a cycle ID, or "active", as cycle_id in linear_search_issues. */
+export namespace LinearGetCycles {
+  /** Input for `get_cycles` tool. */
+  export type Input = {
+    /**
+     * Pagination cursor for next page
+     *
+     * Optional; omit or use null.
+     */
+    after?: null | string;
+    /**
+     * Maximum number of results (default: 50, max 100)
+     *
+     * Optional; omit or use null.
+     */
+    first?: null | number;
+    /**
+     * Include completed cycles (default: false)
+     *
+     * Optional; omit or use null.
+     */
+    include_past?: null | boolean;
+    /**
+     * Team ID (UUID) or key (e.g. ENG). Defaults to the configured default team
+     *
+     * Optional; omit or use null.
+     */
+    team_id?: null | string;
+  };
+  export type Output = {
+    cycles: CycleSummary[];
+    /** Optional; omit or use null. */
+    end_cursor?: null | string;
+    has_next_page: boolean;
+  };
+  export type CycleSummary = {
+    ends_at: string;
+    id: string;
+    /** Optional; omit or use null. */
+    name?: null | string;
+    number: number;
+    /** Completed share of the cycle's scope (0.0-1.0) */
+    progress: number;
+    starts_at: string;
+    /** One of `"active"`, `"next"`, `"upcoming"`, `"previous"`, or `"past"` */
+    status: string;
+  };
+}
+
+/** Get threaded comments on a Linear issue (author, body, created_at, reply parent; replies follow their parent). By default returns up to 10 per call with implicit pagination: repeat calls with the same issue until has_more=false; another call after completion restarts from the beginning. Pass first/after to page explicitly with end_cursor instead. */
+export namespace LinearGetIssueComments {
+  /** Input for `get_issue_comments` tool. */
+  export type Input = {
+    /**
+     * Cursor from a previous page's `end_cursor`
+     *
+     * Optional; omit or use null.
+     */
+    after?: null | string;
+    /**
+     * Page size for explicit cursor pagination (1-100, default 50). Omit both `first` and `after` for implicit pagination.
+     *
+     * Optional; omit or use null.
+     */
+    first?: null | number;
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+  };
+  export type Output = {
+    /** Comments in thread order: each top-level comment followed by its replies */
+    comments: CommentSummary[];
+    /**
+     * Cursor for the next page when paging explicitly with `first`/`after`
+     *
+     * Optional; omit or use null.
+     */
+    end_cursor?: null | string;
+    has_more: boolean;
+    issue_identifier: string;
+    shown_comments: number;
+    total_comments: number;
+  };
+  export type CommentSummary = {
+    /** Optional; omit or use null. */
+    author_email?: null | string;
+    /** Optional; omit or use null. */
+    author_name?: null | string;
+    body: string;
+    created_at: string;
+    id: string;
+    /** Optional; omit or use null. */
+    parent_id?: null | string;
+    updated_at: string;
+    url: string;
+  };
+}
+
+/** Look up Linear metadata: users, teams, projects, workflow states, or labels. Use this to discover IDs for filtering and updating issues. */
+export namespace LinearGetMetadata {
+  /** Input for `get_metadata` tool. */
+  export type Input = {
+    /**
+     * Pagination cursor for next page
+     *
+     * Optional; omit or use null.
+     */
+    after?: null | string;
+    /**
+     * Maximum number of results (default: 50)
+     *
+     * Optional; omit or use null.
+     */
+    first?: null | number;
+    /** Kind of metadata to retrieve */
+    kind: MetadataKind;
+    /**
+     * Optional search string (case-insensitive name match)
+     *
+     * Optional; omit or use null.
+     */
+    search?: null | string;
+    /**
+     * Optional team ID to filter by (relevant for `workflow_states` and labels)
+     *
+     * Optional; omit or use null.
+     */
+    team_id?: null | string;
+  };
+  export type Output = {
+    /** Optional; omit or use null. */
+    end_cursor?: null | string;
+    has_next_page: boolean;
+    items: MetadataItem[];
+    kind: MetadataKind;
+  };
+  export type MetadataItem = {
+    /** Optional; omit or use null. */
+    email?: null | string;
+    id: string;
+    /** Optional; omit or use null. */
+    key?: null | string;
+    name: string;
+    /** Optional; omit or use null. */
+    state_type?: null | string;
+    /** Optional; omit or use null. */
+    team_id?: null | string;
+  };
+  export type MetadataKind = "users" | "teams" | "projects" | "workflow_states" | "labels";
+}
+
+/** List attachments (linked PRs, docs, and other URLs) on a Linear issue with title, URL, and source. */
+export namespace LinearListAttachments {
+  /** Input for `list_attachments` tool. */
+  export type Input = {
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+  };
+  export type Output = {
+    attachments: AttachmentSummary[];
+    issue_identifier: string;
+  };
+  export type AttachmentSummary = {
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator_name?: null | string;
+    id: string;
+    /**
+     * Integration that created the attachment (e.g. github, slack), if any
+     *
+     * Optional; omit or use null.
+     */
+    source_type?: null | string;
+    /** Optional; omit or use null. */
+    subtitle?: null | string;
+    title: string;
+    url: string;
+  };
+}
+
+/** Read a Linear issue by ID, identifier (e.g., ENG-245), or URL. Returns issue details and description only; comments are separate. For a full ticket read, also call linear_get_issue_comments until has_more=false. */
+export namespace LinearReadIssue {
+  /** Input for `read_issue` tool. */
+  export type Input = {
+    /** Issue ID, identifier (e.g., ENG-245), or URL */
+    issue: string;
+  };
+  export type Output = {
+    /** Optional; omit or use null. */
+    canceled_at?: null | string;
+    /** Optional; omit or use null. */
+    completed_at?: null | string;
+    /** Optional; omit or use null. */
+    description?: null | string;
+    /** Optional; omit or use null. */
+    estimate?: null | number;
+    issue: IssueSummary;
+    /** Optional; omit or use null. */
+    parent?: null | ParentIssueRef;
+    relations?: IssueRelationRef[];
+    /** Optional; omit or use null. */
+    started_at?: null | string;
+  };
+  /** Relation to another issue, described from the viewed issue's side. */
+  export type IssueRelationRef = {
+    /** Relation ID */
+    id: string;
+    identifier: string;
+    issue_id: string;
+    /** `"blocks"`, `"blocked_by"`, `"duplicate_of"`, `"duplicated_by"`, `"related"`, or `"similar"` */
+    relation: string;
+    title: string;
+  };
+  export type IssueSummary = {
+    /** Optional; omit or use null. */
+    assignee?: null | UserRef;
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator?: null | UserRef;
+    /** Optional; omit or use null. */
+    due_date?: null | string;
+    id: string;
+    identifier: string;
+    label_ids: string[];
+    priority: number;
+    priority_label: string;
+    /** Optional; omit or use null. */
+    project?: null | ProjectRef;
+    /** Optional; omit or use null. */
+    state?: null | WorkflowStateRef;
+    team: TeamRef;
+    title: string;
+    updated_at: string;
+    url: string;
+  };
+  export type ParentIssueRef = {
+    id: string;
+    identifier: string;
+  };
+  export type ProjectRef = {
+    id: string;
+    name: string;
+  };
+  export type TeamRef = {
+    id: string;
+    key: string;
+    name: string;
+  };
+  export type UserRef = {
+    email: string;
+    id: string;
+    name: string;
+  };
+  export type WorkflowStateRef = {
+    id: string;
+    name: string;
+    state_type: string;
+  };
+}
+
+/** Search Linear issues using full-text search and/or filters. Pass cycle_id="active" (with team_id) to scope to the current sprint, or preset=<name> to apply a saved filter set from config. */
+export namespace LinearSearchIssues {
+  /** Input for `search_issues` tool. */
+  export type Input = {
+    /**
+     * Pagination cursor
+     *
+     * Optional; omit or use null.
+     */
+    after?: null | string;
+    /**
+     * Assignee user ID (UUID), or "me" for the API key owner
+     *
+     * Optional; omit or use null.
+     */
+    assignee_id?: null | string;
+    /**
+     * Only issues created after this ISO 8601 date
+     *
+     * Optional; omit or use null.
+     */
+    created_after?: null | string;
+    /**
+     * Only issues created before this ISO 8601 date
+     *
+     * Optional; omit or use null.
+     */
+    created_before?: null | string;
+    /**
+     * Creator user ID (UUID), or "me" for the API key owner
+     *
+     * Optional; omit or use null.
+     */
+    creator_id?: null | string;
+    /**
+     * Cycle ID (UUID), or "active" for the team's current cycle
+     *
+     * Optional; omit or use null.
+     */
+    cycle_id?: null | string;
+    /**
+     * Page size (default 50, max 100)
+     *
+     * Optional; omit or use null.
+     */
+    first?: null | number;
+    /**
+     * Include comments in full-text search (default: true, only applies when query is provided)
+     *
+     * Optional; omit or use null.
+     */
+    include_comments?: null | boolean;
+    /**
+     * Name of a saved filter set from `services.linear.presets`; explicit parameters override it
+     *
+     * Optional; omit or use null.
+     */
+    preset?: null | string;
+    /**
+     * Filter by priority (0=None, 1=Urgent, 2=High, 3=Normal, 4=Low)
+     *
+     * Optional; omit or use null.
+     */
+    priority?: null | number;
+    /**
+     * Project ID (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    project_id?: null | string;
+    /**
+     * Project milestone ID (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    project_milestone_id?: null | string;
+    /**
+     * Full-text search term (searches title, description, and optionally comments)
+     *
+     * Optional; omit or use null.
+     */
+    query?: null | string;
+    /**
+     * Workflow state ID (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    state_id?: null | string;
+    /**
+     * Team ID (UUID) or key (e.g. ENG)
+     *
+     * Optional; omit or use null.
+     */
+    team_id?: null | string;
+    /**
+     * Only issues updated after this ISO 8601 date
+     *
+     * Optional; omit or use null.
+     */
+    updated_after?: null | string;
+    /**
+     * Only issues updated before this ISO 8601 date
+     *
+     * Optional; omit or use null.
+     */
+    updated_before?: null | string;
+  };
+  export type Output = {
+    /** Optional; omit or use null. */
+    end_cursor?: null | string;
+    has_next_page: boolean;
+    issues: IssueSummary[];
+    /**
+     * Most constrained Linear API quota, when known.
+     *
+     * Optional; omit or use null.
+     */
+    rate_limit?: null | RateLimitStatus;
+  };
+  export type IssueSummary = {
+    /** Optional; omit or use null. */
+    assignee?: null | UserRef;
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator?: null | UserRef;
+    /** Optional; omit or use null. */
+    due_date?: null | string;
+    id: string;
+    identifier: string;
+    label_ids: string[];
+    priority: number;
+    priority_label: string;
+    /** Optional; omit or use null. */
+    project?: null | ProjectRef;
+    /** Optional; omit or use null. */
+    state?: null | WorkflowStateRef;
+    team: TeamRef;
+    title: string;
+    updated_at: string;
+    url: string;
+  };
+  export type ProjectRef = {
+    id: string;
+    name: string;
+  };
+  /** Linear API quota reported by the most recent response. */
+  export type RateLimitStatus = {
+    limit: number;
+    remaining: number;
+    /** Unix timestamp (seconds) when the quota resets */
+    reset_at: number;
+    /** Quota bucket (`requests` or `complexity`) */
+    resource: string;
+  };
+  export type TeamRef = {
+    id: string;
+    key: string;
+    name: string;
+  };
+  export type UserRef = {
+    email: string;
+    id: string;
+    name: string;
+  };
+  export type WorkflowStateRef = {
+    id: string;
+    name: string;
+    state_type: string;
+  };
+}
+
+/** Instantly search Linear issues already fetched by linear_search_issues/linear_read_issue in this or earlier sessions, without calling the API. Each hit shows when it was fetched; stale hits are refreshed in the background. Requires services.linear.index.enabled. Fall back to linear_search_issues when nothing relevant is found. */
+export namespace LinearSearchLocal {
+  /** Input for `search_local` tool. */
+  export type Input = {
+    /**
+     * Maximum number of results (default 10, max 50)
+     *
+     * Optional; omit or use null.
+     */
+    limit?: null | number;
+    /** Free-text query (e.g. "the issue about retries") */
+    query: string;
+    /**
+     * Re-fetch stale hits from Linear in the background (default: true)
+     *
+     * Optional; omit or use null.
+     */
+    refresh?: null | boolean;
+  };
+  export type Output = {
+    /** Total issues in the local index. */
+    indexed: number;
+    issues: IndexedIssue[];
+    /** Identifiers of stale hits being re-fetched in the background. */
+    refreshing: string[];
+  };
+  /** An issue served from the local index, with staleness metadata. */
+  export type IndexedIssue = {
+    age_secs: number;
+    /** When the issue was last fetched from Linear (RFC 3339). */
+    fetched_at: string;
+    issue: IssueSummary;
+    /**
+     * Matching description excerpt, with hits in `[brackets]`.
+     *
+     * Optional; omit or use null.
+     */
+    snippet?: null | string;
+    /** Older than `services.linear.index.stale_after_secs`. */
+    stale: boolean;
+  };
+  export type IssueSummary = {
+    /** Optional; omit or use null. */
+    assignee?: null | UserRef;
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator?: null | UserRef;
+    /** Optional; omit or use null. */
+    due_date?: null | string;
+    id: string;
+    identifier: string;
+    label_ids: string[];
+    priority: number;
+    priority_label: string;
+    /** Optional; omit or use null. */
+    project?: null | ProjectRef;
+    /** Optional; omit or use null. */
+    state?: null | WorkflowStateRef;
+    team: TeamRef;
+    title: string;
+    updated_at: string;
+    url: string;
+  };
+  export type ProjectRef = {
+    id: string;
+    name: string;
+  };
+  export type TeamRef = {
+    id: string;
+    key: string;
+    name: string;
+  };
+  export type UserRef = {
+    email: string;
+    id: string;
+    name: string;
+  };
+  export type WorkflowStateRef = {
+    id: string;
+    name: string;
+    state_type: string;
+  };
+}
+
+/** Set or remove a relation between two issues. Provide relation_type to create (blocks/duplicate/related), or omit/null to remove any existing relation. */
+export namespace LinearSetRelation {
+  /** Input for setting or removing an issue relation */
+  export type Input = {
+    /** Source issue identifier (UUID, key like ENG-245, or URL) */
+    issue: string;
+    /** Related issue identifier (UUID, key like ENG-245, or URL) */
+    related_issue: string;
+    /**
+     * Relation type: `blocks`, `blocked_by`, `duplicate`, `related`. Null/omitted to remove relation.
+     *
+     * Optional; omit or use null.
+     */
+    relation_type?: null | string;
+  };
+  /** Result of a `set_relation` or `add_relation` operation */
+  export type Output = {
+    /** Action taken: `"created"`, `"already_exists"`, `"removed"`, or `"no_change"` */
+    action: string;
+    success: boolean;
+  };
+}
+
+/** Update an existing Linear issue. Use linear_get_metadata to look up user IDs, state IDs, project IDs, and label IDs. */
+export namespace LinearUpdateIssue {
+  /** Input for updating an existing Linear issue */
+  export type Input = {
+    /**
+     * Add these label IDs (incremental)
+     *
+     * Optional; omit or use null.
+     */
+    added_label_ids?: null | string[];
+    /**
+     * Assignee user ID (UUID), or "me" for the API key owner
+     *
+     * Optional; omit or use null.
+     */
+    assignee_id?: null | string;
+    /**
+     * New description (markdown supported)
+     *
+     * Optional; omit or use null.
+     */
+    description?: null | string;
+    /**
+     * Due date in ISO 8601 format (YYYY-MM-DD)
+     *
+     * Optional; omit or use null.
+     */
+    due_date?: null | string;
+    /** Issue identifier (UUID, key like ENG-245, or Linear URL) */
+    issue: string;
+    /**
+     * Replace all labels with these IDs (overrides existing)
+     *
+     * Optional; omit or use null.
+     */
+    label_ids?: null | string[];
+    /**
+     * Parent issue ID (UUID) for sub-issues
+     *
+     * Optional; omit or use null.
+     */
+    parent_id?: null | string;
+    /**
+     * Priority: 0=None, 1=Urgent, 2=High, 3=Medium, 4=Low
+     *
+     * Optional; omit or use null.
+     */
+    priority?: null | number;
+    /**
+     * Project ID (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    project_id?: null | string;
+    /**
+     * Remove these label IDs (incremental)
+     *
+     * Optional; omit or use null.
+     */
+    removed_label_ids?: null | string[];
+    /**
+     * Workflow state ID (UUID)
+     *
+     * Optional; omit or use null.
+     */
+    state_id?: null | string;
+    /**
+     * New title for the issue
+     *
+     * Optional; omit or use null.
+     */
+    title?: null | string;
+  };
+  export type Output = {
+    issue: IssueSummary;
+  };
+  export type IssueSummary = {
+    /** Optional; omit or use null. */
+    assignee?: null | UserRef;
+    created_at: string;
+    /** Optional; omit or use null. */
+    creator?: null | UserRef;
+    /** Optional; omit or use null. */
+    due_date?: null | string;
+    id: string;
+    identifier: string;
+    label_ids: string[];
+    priority: number;
+    priority_label: string;
+    /** Optional; omit or use null. */
+    project?: null | ProjectRef;
+    /** Optional; omit or use null. */
+    state?: null | WorkflowStateRef;
+    team: TeamRef;
+    title: string;
+    updated_at: string;
+    url: string;
+  };
+  export type ProjectRef = {
+    id: string;
+    name: string;
+  };
+  export type TeamRef = {
+    id: string;
+    key: string;
+    name: string;
+  };
+  export type UserRef = {
+    email: string;
+    id: string;
+    name: string;
+  };
+  export type WorkflowStateRef = {
+    id: string;
+    name: string;
+    state_type: string;
+  };
+}
+
+/** Fetch one page (1-based) of a cached diff snapshot by handle. Returns the diff content for that page along with metadata. */
+export namespace ReviewDiffPage {
+  /** Input for the `review_diff_page` tool. */
+  export type Input = {
+    /** Handle from `review_diff_snapshot`. */
+    diff_handle: string;
+    /** Page number to retrieve (1-based). */
+    page: number;
+  };
+  /** Output of the `review_diff_page` tool. */
+  export type Output = {
+    /** Diff content for this page. */
+    content: string;
+    /** Files included in this page. */
+    files_in_page?: string[];
+    /**
+     * Warning if this page is oversized.
+     *
+     * Optional; omit or use null.
+     */
+    oversized_warning?: null | string;
+    /** Page number (1-based). */
+    page: number;
+    /** Total number of pages. */
+    total_pages: number;
+  };
+}
+
+/** Generate a paginated git diff snapshot (pure git2), cache it server-side, and return a handle. Use 'mode=staged' for staged-only changes, or 'mode=default' for working tree + staged vs merge-base. */
+export namespace ReviewDiffSnapshot {
+  /** Input for the `review_diff_snapshot` tool. */
+  export type Input = {
+    /** Diff mode: "default" (working tree + staged) or "staged" (staged only). */
+    mode?: ReviewDiffMode;
+    /**
+     * Optional page size in lines (default: ~800).
+     *
+     * Optional; omit or use null.
+     */
+    page_size_lines?: null | number;
+    /** Optional pathspecs to limit the diff scope. */
+    paths?: string[];
+  };
+  /** Output of the `review_diff_snapshot` tool. */
+  export type Output = {
+    /** Name of the base ref used for diff (e.g., "origin/main"). */
+    base_ref_name: string;
+    /** Slugified branch name (for artifact naming). */
+    branch_slug: string;
+    /** List of changed files. */
+    changed_files?: string[];
+    /** Opaque handle to reference this snapshot. */
+    diff_handle: string;
+    /** Whether there are any changes in the diff. */
+    has_changes: boolean;
+    /** Pagination metadata. */
+    paging: DiffPaging;
+    /** Diff statistics. */
+    stats: DiffStats;
+  };
+  /** Index entry for a single page in the diff. */
+  export type DiffPageIndex = {
+    /** Files included in this page. */
+    files: string[];
+    /** Number of lines in this page. */
+    line_count: number;
+    /**
+     * Warning if this page is oversized (single large file/hunk).
+     *
+     * Optional; omit or use null.
+     */
+    oversized_warning?: null | string;
+    /** Page number (1-based). */
+    page: number;
+  };
+  /** Pagination metadata for the diff snapshot. */
+  export type DiffPaging = {
+    /** Index of all pages. */
+    page_index?: DiffPageIndex[];
+    /** Page size in lines. */
+    page_size_lines: number;
+    /** Total lines in the diff. */
+    total_lines: number;
+    /** Total number of pages. */
+    total_pages: number;
+  };
+  /** Statistics about the diff. */
+  export type DiffStats = {
+    /** Total lines deleted. */
+    deletions: number;
+    /** Number of files changed. */
+    files_changed: number;
+    /** Total lines inserted. */
+    insertions: number;
+  };
+  /** Mode for diff generation. */
+  export type ReviewDiffMode = "default" | "staged";
+}
+
+/** Run a lens-based adversarial code review over a cached diff snapshot. The diff is embedded in the reviewer prompt (fileless). Returns a validated ReviewReport with findings and verdict. */
+export namespace ReviewRun {
+  /** Input for the `review_run` tool. */
+  export type Input = {
+    /** Handle from `review_diff_snapshot`. */
+    diff_handle: string;
+    /**
+     * Optional focus guidance for the reviewer.
+     *
+     * Optional; omit or use null.
+     */
+    focus?: null | string;
+    /** Which review lens to use. */
+    lens: ReviewLens;
+    /**
+     * Maximum pages to review (defaults to all remaining).
+     *
+     * Optional; omit or use null.
+     */
+    max_pages?: null | number;
+    /**
+     * Page to start reviewing from (1-based, defaults to 1).
+     *
+     * Optional; omit or use null.
+     */
+    page_start?: null | number;
+  };
+  /** Output from the `review_run` tool. */
+  export type Output = {
+    /**
+     * Warning if the diff was large.
+     *
+     * Optional; omit or use null.
+     */
+    large_diff_warning?: null | string;
+    /** Pagination info. */
+    paging: ReviewRunPaging;
+    /** The validated review report. */
+    report: ReviewReport;
+  };
+  /** Confidence level for a finding. */
+  export type Confidence = "high" | "medium";
+  /** A single review finding. */
+  export type ReviewFinding = {
+    /** Category (should match the review lens). */
+    category: ReviewLens;
+    /**
+     * Caveat explaining uncertainty (required when confidence=medium).
+     *
+     * Optional; omit or use null.
+     */
+    caveat?: null | string;
+    /** Confidence level. */
+    confidence: Confidence;
+    /** Evidence from the diff supporting the finding. */
+    evidence: string;
+    /** File path where the issue was found. */
+    file: string;
+    /** Line number (best-effort; 0 if unknown). */
+    line: number;
+    /** Severity level. */
+    severity: Severity;
+    /** Suggested fix or next step. */
+    suggested_fix: string;
+    /** Short title describing the issue. */
+    title: string;
+  };
+  /** Lens for code review focus area. */
+  export type ReviewLens = "security" | "correctness" | "maintainability" | "testing" | "simplification" | "completeness";
+  /** Complete review report from a single lens reviewer. */
+  export type ReviewReport = {
+    /** List of findings (may be empty if approved). */
+    findings?: ReviewFinding[];
+    /** Which lens produced this report. */
+    lens: ReviewLens;
+    /** Optional notes from the reviewer. */
+    notes?: string[];
+    /** Overall verdict. */
+    verdict: ReviewVerdict;
+  };
+  /** Pagination info in the review run output. */
+  export type ReviewRunPaging = {
+    /** First page reviewed (1-based). */
+    page_start: number;
+    /** Number of pages reviewed. */
+    pages_reviewed: number;
+    /** Total pages in the snapshot. */
+    total_pages: number;
+  };
+  /** Overall verdict from the review. */
+  export type ReviewVerdict = "approved" | "needs_changes";
+  /** Severity level for a finding. */
+  export type Severity = "critical" | "high" | "medium" | "low";
+}
+
+/** Add a GitHub repository as a reference and ensure it is cloned and mounted. Input must be an HTTPS GitHub URL (https://github.com/org/repo or .git) or generic https://*.git clone URL. Optional ref selects a full ref name (for example refs/heads/main). SSH URLs (git@…) are rejected. Idempotent and safe to retry; first-time clones may take time. */
+export namespace ThoughtsAddReference {
+  /** Input for the `add_reference` tool. */
+  export type Input = {
+    /**
+     * Optional description for why this reference was added
+     *
+     * Optional; omit or use null.
+     */
+    description?: null | string;
+    /**
+     * Optional full git ref name to clone, which must start with refs/heads/
+     * or refs/tags/ (for example refs/heads/main).
+     * Shorthand values like "main" are rejected.
+     * Note: refs/remotes/* is a local remote-tracking namespace and is rejected for new inputs.
+     *
+     * Optional; omit or use null.
+     */
+    ref?: null | string;
+    /** HTTPS GitHub URL (<https://github.com/org/repo>) or generic https://*.git clone URL */
+    url: string;
+  };
+  export type Output = {
+    already_existed: boolean;
+    cloned: boolean;
+    config_updated: boolean;
+    /** Optional; omit or use null. */
+    mapping_path?: null | string;
+    mount_path: string;
+    mount_target: string;
+    mounted: boolean;
+    org: string;
+    /** Optional; omit or use null. */
+    ref?: null | string;
+    repo: string;
+    url: string;
+    warnings?: string[];
+  };
+}
+
+/** List remote branches, tags, and full ref names for a repository without cloning it */
+export namespace ThoughtsGetRepoRefs {
+  /** Input for the `get_repo_refs` tool. */
+  export type Input = {
+    /**
+     * Maximum refs to return (1-200, default 100)
+     *
+     * Optional; omit or use null.
+     */
+    limit?: null | number;
+    /** HTTPS GitHub URL (<https://github.com/org/repo>) or generic https://*.git clone URL */
+    url: string;
+  };
+  export type Output = {
+    entries: RemoteRef[];
+    total: number;
+    truncated: boolean;
+    url: string;
+  };
+  export type RemoteRef = {
+    name: string;
+    /** Optional; omit or use null. */
+    oid?: null | string;
+    /** Optional; omit or use null. */
+    peeled?: null | string;
+    /** Optional; omit or use null. */
+    target?: null | string;
+  };
+}
+
+/** Return a compile-time embedded template (research, plan, requirements, pr_description) with usage guidance */
+export namespace ThoughtsGetTemplate {
+  /** Input for the `get_template` tool. */
+  export type Input = {
+    /** Which template to fetch (research, plan, requirements, `pr_description`) */
+    template: TemplateType;
+  };
+  export type Output = {
+    template_type: TemplateType;
+  };
+  export type TemplateType = "research" | "plan" | "requirements" | "pr_description";
+}
+
+/** List files in the current active work directory */
+export namespace ThoughtsListDocuments {
+  /** Input for the `list_active_documents` tool. */
+  export type Input = {
+    /**
+     * Optional subdirectory filter by document type.
+     *
+     * Optional; omit or use null.
+     */
+    subdir?: null | DocumentType;
+  };
+  /** Result of listing documents in the active work directory. */
+  export type Output = {
+    base: string;
+    files: DocumentInfo[];
+  };
+  /** Metadata about a single document file. */
+  export type DocumentInfo = {
+    doc_type: string;
+    modified: string;
+    path: string;
+    size: number;
+  };
+  /** Document type categories for thoughts workspace. */
+  export type DocumentType = "research" | "plan" | "artifact" | "log";
+}
+
+/** List reference repository directory paths (references/org/repo or references/org/repo@ref_key) */
+export namespace ThoughtsListReferences {
+  /** Input for the `list_references` tool. */
+  export type Input = Record<string, unknown>;
+  export type Output = {
+    base: string;
+    entries: ReferenceItem[];
+  };
+  export type ReferenceItem = {
+    /** Optional; omit or use null. */
+    description?: null | string;
+    path: string;
+  };
+}
+
+/** Report every configured thoughts/context/reference mount and whether it is mounted, degraded, or missing. Read-only; works even when other thoughts tools fail readiness checks. */
+export namespace ThoughtsMountStatus {
+  /** Input for the `mount_status` tool. */
+  export type Input = Record<string, unknown>;
+  export type Output = {
+    healthy: boolean;
+    mounts: MountStatusEntry[];
+    /** Active mounts under `.thoughts-data` that the configuration no longer lists. */
+    unexpected?: string[];
+  };
+  export type MountHealth = "mounted" | "degraded" | "unmounted";
+  export type MountStatusEntry = {
+    /** Optional; omit or use null. */
+    detail?: null | string;
+    health: MountHealth;
+    /** Mount identifier (thoughts, context path, or references/org/repo[@ref]). */
+    mount: string;
+    sources?: string[];
+    target: string;
+  };
+}
+
+/** Preview (default) or apply a thoughts sync: remount missing mounts and pull/commit/push auto-sync thoughts and context repositories, like 'thoughts mount update' plus 'thoughts sync --all'. apply=true requires thoughts.allow_mcp_sync in config. */
+export namespace ThoughtsSync {
+  /** Input for the `sync` tool. */
+  export type Input = {
+    /**
+     * Remount missing mounts and git-sync auto-sync mounts. Defaults to false, which
+     * only reports what would be done. Requires `thoughts.allow_mcp_sync = true`.
+     */
+    apply?: boolean;
+  };
+  export type Output = {
+    /** False for a preview: nothing was mounted or synced. */
+    applied: boolean;
+    /** Mount status after the run (or the current status for a preview). */
+    status: MountStatusReport;
+    synced?: SyncMountResult[];
+    /** Configured mounts that were not healthy before the run. */
+    to_mount: string[];
+    /** Auto-sync git mounts (thoughts and context) that sync pulls, commits, and pushes. */
+    to_sync: string[];
+    warnings?: string[];
+  };
+  export type MountHealth = "mounted" | "degraded" | "unmounted";
+  export type MountStatusEntry = {
+    /** Optional; omit or use null. */
+    detail?: null | string;
+    health: MountHealth;
+    /** Mount identifier (thoughts, context path, or references/org/repo[@ref]). */
+    mount: string;
+    sources?: string[];
+    target: string;
+  };
+  export type MountStatusReport = {
+    healthy: boolean;
+    mounts: MountStatusEntry[];
+    /** Active mounts under `.thoughts-data` that the configuration no longer lists. */
+    unexpected?: string[];
+  };
+  export type SyncMountResult = {
+    /** Optional; omit or use null. */
+    error?: null | string;
+    mount: string;
+    ok: boolean;
+  };
+}
+
+/** Check each configured reference: mapped to a local clone, clone present and a git repository, origin matching the configured URL. Read-only. */
+export namespace ThoughtsVerifyReferences {
+  /** Input for the `verify_references` tool. */
+  export type Input = Record<string, unknown>;
+  export type Output = {
+    ok: boolean;
+    references: ReferenceCheck[];
+  };
+  export type ReferenceCheck = {
+    issues?: string[];
+    /** Optional; omit or use null. */
+    mapping_path?: null | string;
+    /** Optional; omit or use null. */
+    mount?: null | string;
+    ok: boolean;
+    /** Optional; omit or use null. */
+    ref?: null | string;
+    url: string;
+  };
+}
+
+/** Write markdown to the active work directory */
+export namespace ThoughtsWriteDocument {
+  /** Input for the `write_document` tool. */
+  export type Input = {
+    /** Content to write to the document. */
+    content: string;
+    doc_type: DocumentType;
+    /** Filename for the document. */
+    filename: string;
+  };
+  /** Result of successfully writing a document. */
+  export type Output = {
+    bytes_written: number;
+    /**
+     * GitHub URL for the document (available after sync).
+     * None if the remote is not GitHub-hosted or URL couldn't be computed.
+     *
+     * Optional; omit or use null.
+     */
+    github_url?: null | string;
+    path: string;
+  };
+  /** Document type categories for thoughts workspace. */
+  export type DocumentType = "research" | "plan" | "artifact" | "log";
+}
+
+/** Query the tool call logs of the active work directory. Filters by server, tool, success, time range (since/until), and trace_id (an originating call and everything it caused, such as a subagent's tool calls), and reports call counts, failures, p50/p95 durations per tool, token totals per model, and the most recent matching calls. */
+export namespace ToolLogsQuery {
+  /** Input for the `tool_logs_query` tool. */
+  export type Input = {
+    /**
+     * Most recent matching calls to list (default 20, max 200).
+     *
+     * Optional; omit or use null.
+     */
+    limit?: null | number;
+    /**
+     * Only calls logged by this server (e.g. `thoughts_tool`, `gpt5_reasoner`).
+     *
+     * Optional; omit or use null.
+     */
+    server?: null | string;
+    /**
+     * Calls completed at or after this time: RFC 3339, YYYY-MM-DD, or an age like 24h/7d.
+     *
+     * Optional; omit or use null.
+     */
+    since?: null | string;
+    /**
+     * Only successful (true) or failed (false) calls.
+     *
+     * Optional; omit or use null.
+     */
+    success?: null | boolean;
+    /**
+     * Only calls to this tool (e.g. `thoughts_sync`).
+     *
+     * Optional; omit or use null.
+     */
+    tool?: null | string;
+    /**
+     * Only calls in this trace: the originating call's ID, e.g. an `ask_agent` call
+     * whose subagent's tool calls should be listed with it.
+     *
+     * Optional; omit or use null.
+     */
+    trace_id?: null | string;
+    /**
+     * Calls completed before this time, in the same formats as `since`.
+     *
+     * Optional; omit or use null.
+     */
+    until?: null | string;
+  };
+  export type Output = {
+    logs_dir: string;
+    /** Most recent matching calls, newest first. */
+    recent: ToolLogEntry[];
+    stats: LogStats;
+  };
+  /** Aggregates over a set of tool call records. */
+  export type LogStats = {
+    failed_calls: number;
+    /** Per model for calls that reported one, most tokens first. */
+    models: ModelUsage[];
+    p50_duration_ms: number;
+    p95_duration_ms: number;
+    /** Lines that were not valid records (e.g. torn writes or merge leftovers). */
+    skipped_lines?: number;
+    /** Per (server, tool), most-called first. */
+    tools: ToolStats[];
+    total_calls: number;
+  };
+  export type ModelUsage = {
+    calls: number;
+    completion_tokens: number;
+    model: string;
+    prompt_tokens: number;
+    reasoning_tokens?: number;
+    total_tokens: number;
+  };
+  /** One logged call, without its request payload. */
+  export type ToolLogEntry = {
+    call_id: string;
+    /** RFC 3339 completion time. */
+    completed_at: string;
+    duration_ms: number;
+    /** Optional; omit or use null. */
+    error?: null | string;
+    /** Optional; omit or use null. */
+    model?: null | string;
+    /**
+     * Call that made this one, for calls from subagents and nested tools.
+     *
+     * Optional; omit or use null.
+     */
+    parent_call_id?: null | string;
+    server: string;
+    success: boolean;
+    tool: string;
+  };
+  export type ToolStats = {
+    calls: number;
+    failures: number;
+    max_duration_ms: number;
+    p50_duration_ms: number;
+    p95_duration_ms: number;
+    server: string;
+    tool: string;
+  };
+}
+
+/** Fetch a URL over HTTP and convert the page to clean Markdown with metadata. Default summarize=false; set summarize=true to generate a short Haiku summary (requires Anthropic credentials). */
+export namespace WebFetch {
+  /** Input for the `web_fetch` tool. */
+  export type Input = {
+    /**
+     * Maximum bytes to download (default: 5MB, hard limit: 20MB)
+     *
+     * Optional; omit or use null.
+     */
+    max_bytes?: null | number;
+    /**
+     * Whether to generate a Haiku summary (default: false).
+     * Requires Anthropic credentials when enabled.
+     */
+    summarize?: boolean;
+    /** The URL to fetch */
+    url: string;
+  };
+  /** Output from the `web_fetch` tool. */
+  export type Output = {
+    /** The converted content (markdown for HTML, raw for text, pretty-printed for JSON) */
+    content: string;
+    /** Content-Type header value */
+    content_type: string;
+    /** The final URL after redirects */
+    final_url: string;
+    /** When the page was retrieved */
+    retrieved_at: string;
+    /**
+     * Optional Haiku summary (only present when summarize=true)
+     *
+     * Optional; omit or use null.
+     */
+    summary?: null | string;
+    /**
+     * Page title (extracted from HTML if available)
+     *
+     * Optional; omit or use null.
+     */
+    title?: null | string;
+    /** Whether the content was truncated due to size limits */
+    truncated: boolean;
+    /** Approximate word count of the content */
+    word_count: number;
+  };
+}
+
+/** Semantic/neural web search (Exa). Use NATURAL LANGUAGE queries (questions/descriptions). Do NOT use keyword-stuffed, Google-style queries. Returns compact, citable result cards with URLs plus a short trimmed context to orient you. Near-duplicate results are collapsed and each card carries a source quality score (official docs boosted, SEO spam penalized). */
+export namespace WebSearch {
+  /** Input for the `web_search` tool. */
+  export type Input = {
+    /**
+     * Number of results to return (default: 8, max: 20)
+     *
+     * Optional; omit or use null.
+     */
+    num_results?: null | number;
+    /**
+     * Search query. Use a natural-language question or description;
+     * Exa is semantic/neural search — do NOT use keyword-stuffed queries.
+     */
+    query: string;
+  };
+  /** Output from the `web_search` tool. */
+  export type Output = {
+    /**
+     * Trimmed orientation context from Exa (if available)
+     *
+     * Optional; omit or use null.
+     */
+    context?: null | string;
+    /** The original search query */
+    query: string;
+    /** Results dropped as duplicates or by the per-domain cap */
+    removed_results: number;
+    /** Compact, citable result cards */
+    results: WebSearchResultCard[];
+    /** When the search was performed */
+    retrieved_at: string;
+  };
+  /** A single result card from web search. */
+  export type WebSearchResultCard = {
+    /**
+     * Author (if available)
+     *
+     * Optional; omit or use null.
+     */
+    author?: null | string;
+    /** Domain extracted from URL */
+    domain: string;
+    /** URLs of near-identical results collapsed into this one */
+    duplicates: string[];
+    /**
+     * Published date (if available)
+     *
+     * Optional; omit or use null.
+     */
+    published_date?: null | string;
+    /**
+     * Source quality score (0-100, 50 is neutral)
+     *
+     * Optional; omit or use null.
+     */
+    quality?: null | number;
+    /** Heuristics that adjusted the quality score (e.g. "official docs", "seo slug") */
+    quality_signals: string[];
+    /**
+     * Relevance score (0-100)
+     *
+     * Optional; omit or use null.
+     */
+    score?: null | number;
+    /**
+     * Short snippet (up to 300 chars) from highlights or summary
+     *
+     * Optional; omit or use null.
+     */
+    snippet?: null | string;
+    /**
+     * Page title
+     *
+     * Optional; omit or use null.
+     */
+    title?: null | string;
+    /** URL of the result */
+    url: string;
+  };
+}
+
+export interface ToolInputs {
+  ask_agent: AskAgent.Input;
+  ask_reasoning_model: AskReasoningModel.Input;
+  cli_batch: CliBatch.Input;
+  cli_glob: CliGlob.Input;
+  cli_grep: CliGrep.Input;
+  cli_just_execute: CliJustExecute.Input;
+  cli_just_search: CliJustSearch.Input;
+  cli_ls: CliLs.Input;
+  gh_add_comment_reply: GhAddCommentReply.Input;
+  gh_add_pr_comment: GhAddPrComment.Input;
+  gh_edit_comment: GhEditComment.Input;
+  gh_get_changed_files: GhGetChangedFiles.Input;
+  gh_get_check_log: GhGetCheckLog.Input;
+  gh_get_checks: GhGetChecks.Input;
+  gh_get_comments: GhGetComments.Input;
+  gh_get_pr: GhGetPr.Input;
+  gh_get_pr_diff: GhGetPrDiff.Input;
+  gh_get_prs: GhGetPrs.Input;
+  gh_react_to_comment: GhReactToComment.Input;
+  gh_resolve_thread: GhResolveThread.Input;
+  gh_submit_review: GhSubmitReview.Input;
+  gh_unresolve_thread: GhUnresolveThread.Input;
+  linear_add_attachment: LinearAddAttachment.Input;
+  linear_add_comment: LinearAddComment.Input;
+  linear_add_relation: LinearAddRelation.Input;
+  linear_archive_issue: LinearArchiveIssue.Input;
+  linear_create_issue: LinearCreateIssue.Input;
+  linear_export_issue: LinearExportIssue.Input;
+  linear_get_cycles: LinearGetCycles.Input;
+  linear_get_issue_comments: LinearGetIssueComments.Input;
+  linear_get_metadata: LinearGetMetadata.Input;
+  linear_list_attachments: LinearListAttachments.Input;
+  linear_read_issue: LinearReadIssue.Input;
+  linear_search_issues: LinearSearchIssues.Input;
+  linear_search_local: LinearSearchLocal.Input;
+  linear_set_relation: LinearSetRelation.Input;
+  linear_update_issue: LinearUpdateIssue.Input;
+  review_diff_page: ReviewDiffPage.Input;
+  review_diff_snapshot: ReviewDiffSnapshot.Input;
+  review_run: ReviewRun.Input;
+  thoughts_add_reference: ThoughtsAddReference.Input;
+  thoughts_get_repo_refs: ThoughtsGetRepoRefs.Input;
+  thoughts_get_template: ThoughtsGetTemplate.Input;
+  thoughts_list_documents: ThoughtsListDocuments.Input;
+  thoughts_list_references: ThoughtsListReferences.Input;
+  thoughts_mount_status: ThoughtsMountStatus.Input;
+  thoughts_sync: ThoughtsSync.Input;
+  thoughts_verify_references: ThoughtsVerifyReferences.Input;
+  thoughts_write_document: ThoughtsWriteDocument.Input;
+  tool_logs_query: ToolLogsQuery.Input;
+  web_fetch: WebFetch.Input;
+  web_search: WebSearch.Input;
+}
+
+export interface ToolOutputs {
+  ask_agent: AskAgent.Output;
+  ask_reasoning_model: AskReasoningModel.Output;
+  cli_batch: CliBatch.Output;
+  cli_glob: CliGlob.Output;
+  cli_grep: CliGrep.Output;
+  cli_just_execute: CliJustExecute.Output;
+  cli_just_search: CliJustSearch.Output;
+  cli_ls: CliLs.Output;
+  gh_add_comment_reply: GhAddCommentReply.Output;
+  gh_add_pr_comment: GhAddPrComment.Output;
+  gh_edit_comment: GhEditComment.Output;
+  gh_get_changed_files: GhGetChangedFiles.Output;
+  gh_get_check_log: GhGetCheckLog.Output;
+  gh_get_checks: GhGetChecks.Output;
+  gh_get_comments: GhGetComments.Output;
+  gh_get_pr: GhGetPr.Output;
+  gh_get_pr_diff: GhGetPrDiff.Output;
+  gh_get_prs: GhGetPrs.Output;
+  gh_react_to_comment: GhReactToComment.Output;
+  gh_resolve_thread: GhResolveThread.Output;
+  gh_submit_review: GhSubmitReview.Output;
+  gh_unresolve_thread: GhUnresolveThread.Output;
+  linear_add_attachment: LinearAddAttachment.Output;
+  linear_add_comment: LinearAddComment.Output;
+  linear_add_relation: LinearAddRelation.Output;
+  linear_archive_issue: LinearArchiveIssue.Output;
+  linear_create_issue: LinearCreateIssue.Output;
+  linear_export_issue: LinearExportIssue.Output;
+  linear_get_cycles: LinearGetCycles.Output;
+  linear_get_issue_comments: LinearGetIssueComments.Output;
+  linear_get_metadata: LinearGetMetadata.Output;
+  linear_list_attachments: LinearListAttachments.Output;
+  linear_read_issue: LinearReadIssue.Output;
+  linear_search_issues: LinearSearchIssues.Output;
+  linear_search_local: LinearSearchLocal.Output;
+  linear_set_relation: LinearSetRelation.Output;
+  linear_update_issue: LinearUpdateIssue.Output;
+  review_diff_page: ReviewDiffPage.Output;
+  review_diff_snapshot: ReviewDiffSnapshot.Output;
+  review_run: ReviewRun.Output;
+  thoughts_add_reference: ThoughtsAddReference.Output;
+  thoughts_get_repo_refs: ThoughtsGetRepoRefs.Output;
+  thoughts_get_template: ThoughtsGetTemplate.Output;
+  thoughts_list_documents: ThoughtsListDocuments.Output;
+  thoughts_list_references: ThoughtsListReferences.Output;
+  thoughts_mount_status: ThoughtsMountStatus.Output;
+  thoughts_sync: ThoughtsSync.Output;
+  thoughts_verify_references: ThoughtsVerifyReferences.Output;
+  thoughts_write_document: ThoughtsWriteDocument.Output;
+  tool_logs_query: ToolLogsQuery.Output;
+  web_fetch: WebFetch.Output;
+  web_search: WebSearch.Output;
+}
+
+/** Call a tool with typed input; resolves with its parsed structured output. */
+export function callToolTyped<N extends keyof ToolInputs>(
+  name: N,
+  input: ToolInputs[N]
+): Promise<ToolOutputs[N]>;
@@ -0,0 +1,48 @@
+"""Type stubs for the `agentic_tools` extension module (see src/lib.rs)."""
+
+from typing import Any, Awaitable, Optional
+
+class ToolCallResult:
+    """Result from a tool call, containing both human-readable text and JSON data."""
+
+    data: str
+    """JSON string containing the tool result data."""
+    text: str
+    """Human-readable text representation of the result."""
+
+    def json(self) -> Any:
+        """Decode `data` with the standard library's `json` module."""
+
+def init(config_json: str) -> str: ...
+def get_manifest() -> str: ...
+def list_tools(provider: str) -> str: ...
+def call_tool(
+    name: str,
+    args_json: str,
+    *,
+    cwd: Optional[str] = None,
+    session_id: Optional[str] = None,
+    timeout_ms: Optional[int] = None,
+) -> ToolCallResult: ...
+def call_tool_async(
+    name: str,
+    args_json: str,
+    *,
+    cwd: Optional[str] = None,
+    session_id: Optional[str] = None,
+    timeout_ms: Optional[int] = None,
+) -> Awaitable[ToolCallResult]: ...
+def end_session(session_id: str) -> bool: ...
+def set_schema_patches(patches_json: str) -> None: ...
+def is_initialized() -> bool: ...
+def tool_count() -> int: ...
+def get_tool_names() -> list[str]: ...
+def get_tool_meta() -> str: ...
+def call_ls(args_json: str) -> ToolCallResult: ...
+def call_ask_agent(args_json: str) -> ToolCallResult: ...
+def call_grep(args_json: str) -> ToolCallResult: ...
+def call_glob(args_json: str) -> ToolCallResult: ...
+def call_just_search(args_json: str) -> ToolCallResult: ...
+def call_just_execute(args_json: str) -> ToolCallResult: ...
+def call_batch(args_json: str) -> ToolCallResult: ...
+def call_reasoning_request(args_json: str) -> ToolCallResult: ...
@@ -0,0 +1,673 @@
+//! Python bindings for agentic-tools.
+//!
+//! This crate builds the `agentic_tools` extension module (via maturin) so Python
+//! agent frameworks such as `LangChain`, or custom harnesses, can call the same
+//! Rust-based tools as the MCP server and the N-API bindings. It shares the
+//! `AgenticTools` registry and mirrors the `agentic-tools-napi` surface: arguments
+//! and results cross the boundary as JSON strings with identical shapes.
+//!
+//! ## Generic Exports
+//!
+//! - `init(config_json)`: Initialize the tool registry with all available tools; returns the manifest
+//! - `get_manifest()`: Schema version and per-tool content hashes of the registry
+//! - `list_tools(provider)`: List available tools with schemas for a provider
+//! - `get_tool_meta()`: Version and deprecation metadata of every registered tool
+//! - `call_tool(name, args_json, *, cwd, session_id, timeout_ms)`: Execute a tool, blocking without holding the GIL
+//! - `call_tool_async(name, args_json, *, cwd, session_id, timeout_ms)`: Awaitable variant for asyncio
+//! - `end_session(session_id)`: Drop the state of a session named in a call
+//! - `set_schema_patches(patches_json)`: Apply runtime schema transformations
+//!
+//! ## Typed Exports
+//!
+//! Per-tool typed wrappers for commonly used tools:
+//! - `call_ls(args_json)`: List files and directories
+//! - `call_grep(args_json)`: Regex-based search; retry with `include_ignored: true` if expected matches are missing
+//! - `call_glob(args_json)`: Glob-based file matching; retry with `include_ignored: true` if expected matches are missing
+//! - `call_ask_agent(args_json)`: Spawn Claude subagent
+//! - `call_just_search(args_json)`: Search justfile recipes
+//! - `call_just_execute(args_json)`: Execute justfile recipe
+//! - `call_batch(args_json)`: Run several read-only tools concurrently
+//! - `call_reasoning_request(args_json)`: GPT-5 reasoning model request
+
+#![expect(
+    clippy::needless_pass_by_value,
+    reason = "pyo3 functions take owned strings; `&str` cannot be extracted under abi3 before Python 3.10"
+)]
+
+use agentic_tools_core::FieldConstraint;
+use agentic_tools_core::SchemaEngine;
+use agentic_tools_core::SessionState;
+use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolManifest;
+use agentic_tools_core::ToolRegistry;
+use agentic_tools_core::fmt::TextOptions;
+use agentic_tools_core::fmt::fallback_text_from_json;
+use agentic_tools_registry::AgenticTools;
+use agentic_tools_registry::AgenticToolsConfig;
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::PyTimeoutError;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+
+// =============================================================================
+// Global State
+// =============================================================================
+
+/// Global registry instance, initialized once via `init()`.
+static REGISTRY: OnceCell<Arc<ToolRegistry>> = OnceCell::new();
+
+/// Global schema engine for runtime transforms.
+static SCHEMA_ENGINE: OnceCell<RwLock<SchemaEngine>> = OnceCell::new();
+
+/// Concurrency and timeout limits from `init()`, applied to every tool call.
+static LIMITS: OnceCell<CallLimits> = OnceCell::new();
+
+struct CallLimits {
+    permits: Option<Arc<Semaphore>>,
+    default_timeout: Option<Duration>,
+}
+
+/// Sessions named by a call's `session_id`, kept until `end_session()`.
+static SESSIONS: LazyLock<Mutex<HashMap<String, Arc<SessionState>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn registry() -> PyResult<&'static Arc<ToolRegistry>> {
+    REGISTRY
+        .get()
+        .ok_or_else(|| PyRuntimeError::new_err("Registry not initialized. Call init() first."))
+}
+
+// =============================================================================
+// Initialization
+// =============================================================================
+
+/// Initialize the agentic-tools registry with all available tools.
+///
+/// Must be called before any other functions. Accepts the same config JSON as
+/// the N-API `init()`:
+///
+/// - `allowlist`: Array of tool names to enable (empty = all tools)
+/// - `strict`: Boolean for strict schema mode (default: false)
+/// - `expectedSchemaVersion`: Registry schema version the caller was built
+///   against; a mismatch fails before any global state is set
+/// - `maxConcurrentCalls`: Run at most this many tool calls at once; further
+///   calls wait for a slot (default: unlimited)
+/// - `defaultTimeoutMs`: Timeout for calls that do not pass `timeout_ms`
+///   (default: none). Queueing for a slot counts toward it.
+///
+/// Returns the registry manifest as a JSON string (`schemaVersion`,
+/// `registryHash`, `tools`).
+///
+/// ```python
+/// import agentic_tools
+/// agentic_tools.init("{}")  # Initialize with all tools
+/// agentic_tools.init('{"allowlist": ["cli_ls", "cli_grep"]}')  # Only specific tools
+/// ```
+#[pyfunction]
+pub fn init(config_json: String) -> PyResult<String> {
+    let config: JsonValue = serde_json::from_str(&config_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid config JSON: {e}")))?;
+
+    let expected_version = config_u32(&config, "expectedSchemaVersion")?;
+    let max_concurrent = match config_u32(&config, "maxConcurrentCalls")? {
+        Some(0) => {
+            return Err(PyValueError::new_err(
+                "Invalid config: maxConcurrentCalls must be at least 1",
+            ));
+        }
+        other => other,
+    };
+    let default_timeout =
+        config_u32(&config, "defaultTimeoutMs")?.map(|ms| Duration::from_millis(ms.into()));
+
+    let allowlist: Option<HashSet<String>> = config
+        .get("allowlist")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(std::string::ToString::to_string))
+                .collect()
+        });
+
+    let registry = AgenticTools::new(AgenticToolsConfig {
+        allowlist,
+        ..Default::default()
+    });
+
+    // Check compatibility before touching globals so a failed init can be retried
+    let manifest = registry.manifest();
+    if let Some(expected) = expected_version {
+        manifest
+            .check_schema_version(expected)
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    }
+
+    let strict = config
+        .get("strict")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false);
+    SCHEMA_ENGINE
+        .set(RwLock::new(SchemaEngine::new().with_strict(strict)))
+        .map_err(|_| PyRuntimeError::new_err("Schema engine already initialized"))?;
+
+    let limits = CallLimits {
+        permits: max_concurrent.map(|n| Arc::new(Semaphore::new(n as usize))),
+        default_timeout,
+    };
+    LIMITS
+        .set(limits)
+        .map_err(|_| PyRuntimeError::new_err("Call limits already initialized"))?;
+
+    REGISTRY
+        .set(Arc::new(registry))
+        .map_err(|_| PyRuntimeError::new_err("Registry already initialized"))?;
+
+    manifest_json(&manifest)
+}
+
+/// Read an optional non-negative integer from the init config.
+fn config_u32(config: &JsonValue, key: &str) -> PyResult<Option<u32>> {
+    match config.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(v) => v
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Invalid config: {key} must be a non-negative integer, got {v}"
+                ))
+            }),
+    }
+}
+
+/// Get the registry manifest: schema version and per-tool content hashes, as JSON.
+#[pyfunction]
+pub fn get_manifest() -> PyResult<String> {
+    manifest_json(&registry()?.manifest())
+}
+
+fn manifest_json(manifest: &ToolManifest) -> PyResult<String> {
+    serde_json::to_string(manifest)
+        .map_err(|e| PyRuntimeError::new_err(format!("JSON serialization failed: {e}")))
+}
+
+// =============================================================================
+// Result Types
+// =============================================================================
+
+/// Result from a tool call, containing both human-readable text and JSON data.
+#[pyclass(frozen, get_all, module = "agentic_tools")]
+pub struct ToolCallResult {
+    /// JSON string containing the tool result data.
+    pub data: String,
+    /// Human-readable text representation of the result.
+    pub text: String,
+}
+
+#[pymethods]
+impl ToolCallResult {
+    /// Decode `data` with the standard library's `json` module.
+    fn json<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        py.import("json")?.call_method1("loads", (&self.data,))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ToolCallResult(text={:?}, data={:?})", self.text, self.data)
+    }
+}
+
+/// Per-call context, passed as keyword arguments to `call_tool` and
+/// `call_tool_async`. Every field is optional.
+struct CallContext {
+    /// Absolute directory that relative tool paths (and subagents) resolve
+    /// against. Defaults to the process cwd.
+    cwd: Option<String>,
+    /// Calls sharing an id share implicit pagination state and session scratch
+    /// space until `end_session(id)`.
+    session_id: Option<String>,
+    /// Cancel the call after this many milliseconds; overrides `defaultTimeoutMs`
+    /// from `init()`, and `0` disables it.
+    timeout_ms: Option<u32>,
+}
+
+// =============================================================================
+// Generic APIs
+// =============================================================================
+
+/// List available tools with their schemas for a specific provider.
+///
+/// `provider` is one of "openai", "anthropic", "gemini", or "mcp". Schema patches are
+/// applied before rendering. Returns a JSON array of tool definitions.
+#[pyfunction]
+pub fn list_tools(provider: String) -> PyResult<String> {
+    let reg = registry()?;
+
+    let engine = SCHEMA_ENGINE.get().map(|e| e.read());
+    let strict = engine.as_ref().is_some_and(|e| e.is_strict());
+    let names = reg.list_names();
+
+    let tools: Vec<JsonValue> = names
+        .iter()
+        .filter_map(|name| {
+            let tool = reg.get(name)?;
+            let description = tool.provider_description();
+            let base_schema = tool.input_schema();
+            let schema = match engine {
+                Some(ref eng) => eng.transform(name, base_schema),
+                None => base_schema,
+            };
+
+            Some(match provider.as_str() {
+                "openai" => agentic_tools_core::providers::openai::render_function(
+                    name,
+                    &description,
+                    &schema,
+                    strict,
+                ),
+                "anthropic" => agentic_tools_core::providers::anthropic::render_tool(
+                    name,
+                    &description,
+                    &schema,
+                    strict,
+                ),
+                "gemini" => {
+                    agentic_tools_core::providers::gemini::render_tool(name, &description, &schema)
+                }
+                "mcp" => agentic_tools_core::providers::mcp::render_tool(
+                    name,
+                    &description,
+                    &schema,
+                    tool.output_schema().as_ref(),
+                ),
+                _ => serde_json::json!({
+                    "name": name,
+                    "error": format!("Unknown provider: {}", provider)
+                }),
+            })
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&tools)
+        .map_err(|e| PyRuntimeError::new_err(format!("JSON serialization failed: {e}")))
+}
+
+/// Execute a tool with JSON arguments, blocking until it finishes.
+///
+/// The GIL is released while the tool runs, so other Python threads keep going.
+/// Keyword arguments form the call context: `cwd` (absolute directory for
+/// relative paths), `session_id` (shares pagination state until
+/// `end_session`), and `timeout_ms` (raises `TimeoutError` when exceeded).
+///
+/// ```python
+/// result = agentic_tools.call_tool(
+///     "cli_grep", '{"pattern": "unimplemented!"}', cwd=workspace_root, timeout_ms=30_000
+/// )
+/// print(result.text)
+/// ```
+#[pyfunction]
+#[pyo3(signature = (name, args_json, *, cwd = None, session_id = None, timeout_ms = None))]
+pub fn call_tool(
+    py: Python<'_>,
+    name: String,
+    args_json: String,
+    cwd: Option<String>,
+    session_id: Option<String>,
+    timeout_ms: Option<u32>,
+) -> PyResult<ToolCallResult> {
+    let context = CallContext {
+        cwd,
+        session_id,
+        timeout_ms,
+    };
+    let cancel = CancellationToken::new();
+    let (ctx, timeout) = tool_context(context, cancel.clone())?;
+    py.allow_threads(|| {
+        let _cancel_on_exit = cancel.drop_guard();
+        pyo3_async_runtimes::tokio::get_runtime().block_on(dispatch(name, args_json, ctx, timeout))
+    })
+}
+
+/// Execute a tool with JSON arguments from asyncio.
+///
+/// Takes the same arguments as `call_tool` and must be called from a running
+/// event loop; returns an awaitable resolving to a `ToolCallResult`. Cancelling
+/// the awaiting task cancels the tool's context, so cancellation-aware tools
+/// (`cli_just_execute`, `ask_agent`) stop their subprocesses.
+///
+/// ```python
+/// result = await agentic_tools.call_tool_async("cli_ls", '{"depth": 1}', session_id="chat-1")
+/// ```
+#[pyfunction]
+#[pyo3(signature = (name, args_json, *, cwd = None, session_id = None, timeout_ms = None))]
+pub fn call_tool_async(
+    py: Python<'_>,
+    name: String,
+    args_json: String,
+    cwd: Option<String>,
+    session_id: Option<String>,
+    timeout_ms: Option<u32>,
+) -> PyResult<Bound<'_, PyAny>> {
+    let context = CallContext {
+        cwd,
+        session_id,
+        timeout_ms,
+    };
+    let cancel = CancellationToken::new();
+    let (ctx, timeout) = tool_context(context, cancel.clone())?;
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        // Dropped when the call finishes or when asyncio cancels the awaiting task.
+        let _cancel_on_exit = cancel.drop_guard();
+        dispatch(name, args_json, ctx, timeout).await
+    })
+}
+
+/// Drop the state kept for `session_id` (pagination offsets, session scratch
+/// directory). Returns whether the session existed.
+#[pyfunction]
+pub fn end_session(session_id: String) -> bool {
+    SESSIONS.lock().remove(&session_id).is_some()
+}
+
+/// Build the `ToolContext` for one call; returns the requested timeout alongside.
+fn tool_context(
+    context: CallContext,
+    cancel: CancellationToken,
+) -> PyResult<(ToolContext, Option<Duration>)> {
+    let mut ctx = ToolContext::with_cancel(cancel);
+    let default_timeout = LIMITS.get().and_then(|l| l.default_timeout);
+
+    if let Some(cwd) = context.cwd {
+        let cwd = PathBuf::from(cwd);
+        if !cwd.is_absolute() {
+            return Err(PyValueError::new_err(format!(
+                "cwd must be an absolute path: {}",
+                cwd.display()
+            )));
+        }
+        if !cwd.is_dir() {
+            return Err(PyValueError::new_err(format!(
+                "cwd is not a directory: {}",
+                cwd.display()
+            )));
+        }
+        ctx = ctx.with_cwd(cwd);
+    }
+    if let Some(id) = context.session_id {
+        let session = Arc::clone(SESSIONS.lock().entry(id).or_default());
+        ctx = ctx.with_session(session);
+    }
+    let timeout = match context.timeout_ms {
+        Some(0) => None,
+        Some(ms) => Some(Duration::from_millis(ms.into())),
+        None => default_timeout,
+    };
+    Ok((ctx, timeout))
+}
+
+async fn dispatch(
+    name: String,
+    args_json: String,
+    ctx: ToolContext,
+    timeout: Option<Duration>,
+) -> PyResult<ToolCallResult> {
+    let cancel = ctx.cancellation_token();
+    let run = async move {
+        let _permit = match LIMITS.get().and_then(|l| l.permits.clone()) {
+            Some(permits) => Some(tokio::select! {
+                permit = permits.acquire_owned() => permit
+                    .map_err(|e| PyRuntimeError::new_err(format!("Call limiter closed: {e}")))?,
+                () = ctx.cancelled() => {
+                    return Err(PyRuntimeError::new_err(format!(
+                        "Tool execution failed: {}",
+                        ToolError::cancelled(None)
+                    )));
+                }
+            }),
+            None => None,
+        };
+        dispatch_now(name, args_json, ctx).await
+    };
+    let Some(timeout) = timeout else {
+        return run.await;
+    };
+
+    // Run on its own task so the deadline fires even while a tool blocks its
+    // worker thread; see the N-API binding for the detached-task semantics.
+    let mut task = tokio::spawn(run);
+    if let Ok(joined) = tokio::time::timeout(timeout, &mut task).await {
+        return joined
+            .map_err(|e| PyRuntimeError::new_err(format!("Tool call task failed: {e}")))?;
+    }
+    cancel.cancel();
+    Err(PyTimeoutError::new_err(format!(
+        "Tool call timed out after {} ms",
+        timeout.as_millis()
+    )))
+}
+
+async fn dispatch_now(
+    name: String,
+    args_json: String,
+    ctx: ToolContext,
+) -> PyResult<ToolCallResult> {
+    let reg = registry()?;
+
+    let args: JsonValue = serde_json::from_str(&args_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid args JSON: {e}")))?;
+
+    let text_opts = TextOptions::default();
+
+    let result = reg
+        .dispatch_json_formatted(&name, args, &ctx, &text_opts)
+        .await
+        .map_err(|e| PyRuntimeError::new_err(format!("Tool execution failed: {e}")))?;
+
+    let data = serde_json::to_string(&result.data)
+        .map_err(|e| PyRuntimeError::new_err(format!("Result serialization failed: {e}")))?;
+    let text = result
+        .text
+        .unwrap_or_else(|| fallback_text_from_json(&result.data));
+
+    Ok(ToolCallResult { data, text })
+}
+
+/// Apply schema patches for runtime customization.
+///
+/// `patches_json` is a JSON object keyed by tool name, in the same format as the
+/// N-API `setSchemaPatches()`: per-field `enum`, `minimum`/`maximum`, and
+/// `pattern` constraints under `properties`, or a direct merge patch.
+///
+/// ```python
+/// agentic_tools.set_schema_patches(json.dumps({
+///     "ask_agent": {"properties": {"agent_type": {"enum": ["locator", "analyzer"]}}}
+/// }))
+/// ```
+#[pyfunction]
+pub fn set_schema_patches(patches_json: String) -> PyResult<()> {
+    let engine_lock = SCHEMA_ENGINE.get().ok_or_else(|| {
+        PyRuntimeError::new_err("Schema engine not initialized. Call init() first.")
+    })?;
+
+    let patches: JsonValue = serde_json::from_str(&patches_json)
+        .map_err(|e| PyValueError::new_err(format!("Invalid patches JSON: {e}")))?;
+
+    let patches_obj = patches
+        .as_object()
+        .ok_or_else(|| PyValueError::new_err("Patches must be a JSON object"))?;
+
+    let mut engine = engine_lock.write();
+
+    for (tool_name, tool_patches) in patches_obj {
+        if let Some(props) = tool_patches.get("properties").and_then(|p| p.as_object()) {
+            for (field_name, field_patch) in props {
+                let path = vec!["properties".to_string(), field_name.clone()];
+
+                if let Some(enum_vals) = field_patch.get("enum").and_then(|e| e.as_array()) {
+                    engine.constrain_field(
+                        tool_name,
+                        path.clone(),
+                        FieldConstraint::Enum(enum_vals.clone()),
+                    );
+                }
+
+                let minimum = field_patch.get("minimum").cloned();
+                let maximum = field_patch.get("maximum").cloned();
+                if minimum.is_some() || maximum.is_some() {
+                    engine.constrain_field(
+                        tool_name,
+                        path.clone(),
+                        FieldConstraint::Range { minimum, maximum },
+                    );
+                }
+
+                if let Some(pattern) = field_patch.get("pattern").and_then(|p| p.as_str()) {
+                    engine.constrain_field(
+                        tool_name,
+                        path,
+                        FieldConstraint::Pattern(pattern.to_string()),
+                    );
+                }
+            }
+        } else {
+            engine.constrain_field(
+                tool_name,
+                vec![],
+                FieldConstraint::MergePatch(tool_patches.clone()),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Check if the registry has been initialized.
+#[pyfunction]
+pub fn is_initialized() -> bool {
+    REGISTRY.get().is_some()
+}
+
+/// Get the number of registered tools.
+#[pyfunction]
+pub fn tool_count() -> usize {
+    REGISTRY.get().map_or(0, |r| r.len())
+}
+
+/// Get version and deprecation metadata of all registered tools, sorted by name,
+/// as a JSON array of `{name, description, version?, deprecated?}`.
+#[pyfunction]
+pub fn get_tool_meta() -> PyResult<String> {
+    serde_json::to_string(&registry()?.list_names_with_meta())
+        .map_err(|e| PyRuntimeError::new_err(format!("JSON serialization failed: {e}")))
+}
+
+/// Get names of all registered tools.
+#[pyfunction]
+pub fn get_tool_names() -> PyResult<Vec<String>> {
+    Ok(registry()?.list_names())
+}
+
+// =============================================================================
+// Typed Exports
+// =============================================================================
+
+fn call_named(py: Python<'_>, name: &str, args_json: String) -> PyResult<ToolCallResult> {
+    call_tool(py, name.to_string(), args_json, None, None, None)
+}
+
+/// List files and directories. `args_json`: path?, depth?, show?, ignore?, hidden?
+#[pyfunction]
+pub fn call_ls(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "cli_ls", args_json)
+}
+
+/// Ask a Claude subagent. `args_json`: `agent_type`?, location?, query
+#[pyfunction]
+pub fn call_ask_agent(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "ask_agent", args_json)
+}
+
+/// Regex-based search. `args_json`: pattern, path?, mode?, globs?, ignore?,
+/// `include_hidden`?, `include_ignored`?, etc.
+#[pyfunction]
+pub fn call_grep(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "cli_grep", args_json)
+}
+
+/// Glob-based file matching. `args_json`: pattern, path?, ignore?,
+/// `include_hidden`?, `include_ignored`?, sort?, `head_limit`?, offset?
+#[pyfunction]
+pub fn call_glob(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "cli_glob", args_json)
+}
+
+/// Search justfile recipes. `args_json`: query?, dir?
+#[pyfunction]
+pub fn call_just_search(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "cli_just_search", args_json)
+}
+
+/// Execute a justfile recipe. `args_json`: recipe, dir?, args?
+#[pyfunction]
+pub fn call_just_execute(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "cli_just_execute", args_json)
+}
+
+/// Run several read-only tools concurrently. `args_json`: calls (array of
+/// {tool: 'ls'|'grep'|'glob', args})
+#[pyfunction]
+pub fn call_batch(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "cli_batch", args_json)
+}
+
+/// Request assistance from the reasoning model. `args_json`: prompt, files,
+/// `prompt_type`, directories?, `output_filename`?
+#[pyfunction]
+pub fn call_reasoning_request(py: Python<'_>, args_json: String) -> PyResult<ToolCallResult> {
+    call_named(py, "ask_reasoning_model", args_json)
+}
+
+// =============================================================================
+// Module
+// =============================================================================
+
+#[pymodule]
+fn agentic_tools(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<ToolCallResult>()?;
+
+    m.add_function(wrap_pyfunction!(init, m)?)?;
+    m.add_function(wrap_pyfunction!(get_manifest, m)?)?;
+    m.add_function(wrap_pyfunction!(list_tools, m)?)?;
+    m.add_function(wrap_pyfunction!(call_tool, m)?)?;
+    m.add_function(wrap_pyfunction!(call_tool_async, m)?)?;
+    m.add_function(wrap_pyfunction!(end_session, m)?)?;
+    m.add_function(wrap_pyfunction!(set_schema_patches, m)?)?;
+    m.add_function(wrap_pyfunction!(is_initialized, m)?)?;
+    m.add_function(wrap_pyfunction!(tool_count, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tool_names, m)?)?;
+    m.add_function(wrap_pyfunction!(get_tool_meta, m)?)?;
+
+    m.add_function(wrap_pyfunction!(call_ls, m)?)?;
+    m.add_function(wrap_pyfunction!(call_ask_agent, m)?)?;
+    m.add_function(wrap_pyfunction!(call_grep, m)?)?;
+    m.add_function(wrap_pyfunction!(call_glob, m)?)?;
+    m.add_function(wrap_pyfunction!(call_just_search, m)?)?;
+    m.add_function(wrap_pyfunction!(call_just_execute, m)?)?;
+    m.add_function(wrap_pyfunction!(call_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(call_reasoning_request, m)?)?;
+
+    Ok(())
+}
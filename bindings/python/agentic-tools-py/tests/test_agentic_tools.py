@@ -0,0 +1,90 @@
+# Native pyo3 bindings. Requires the extension to be built first (maturin develop).
+import asyncio
+import json
+import os
+
+import pytest
+
+import agentic_tools
+
+
+@pytest.fixture(scope="module", autouse=True)
+def registry():
+    if not agentic_tools.is_initialized():
+        agentic_tools.init("{}")
+
+
+@pytest.fixture
+def fixture_dir(tmp_path):
+    (tmp_path / "a.txt").write_text("hello world\n")
+    (tmp_path / "subdir").mkdir()
+    (tmp_path / "subdir" / "b.txt").write_text("hello again\n")
+    return tmp_path
+
+
+def test_call_ls_returns_tool_call_result(fixture_dir):
+    result = agentic_tools.call_ls(json.dumps({"path": str(fixture_dir), "depth": 1}))
+    assert isinstance(result.data, str)
+    assert isinstance(result.text, str)
+
+    parsed = result.json()
+    assert os.path.realpath(parsed["root"]) == os.path.realpath(fixture_dir)
+    assert isinstance(parsed["entries"], list)
+    assert isinstance(parsed["has_more"], bool)
+
+
+def test_call_tool_async_matches_call_tool(fixture_dir):
+    args = json.dumps({"path": str(fixture_dir), "depth": 1})
+    plain = agentic_tools.call_tool("cli_ls", args)
+
+    async def call():
+        return await agentic_tools.call_tool_async("cli_ls", args)
+
+    awaited = asyncio.run(call())
+    assert awaited.data == plain.data
+    assert awaited.text == plain.text
+
+
+def test_relative_paths_resolve_against_cwd(fixture_dir):
+    result = agentic_tools.call_tool(
+        "cli_grep", json.dumps({"pattern": "again"}), cwd=str(fixture_dir)
+    )
+    assert "b.txt" in result.text
+
+
+def test_relative_cwd_is_rejected():
+    with pytest.raises(ValueError, match="absolute"):
+        agentic_tools.call_tool("cli_ls", "{}", cwd="relative/dir")
+
+
+def test_invalid_args_json_raises_value_error():
+    with pytest.raises(ValueError, match="Invalid args JSON"):
+        agentic_tools.call_tool("cli_ls", "not json")
+
+
+def test_unknown_tool_raises_runtime_error():
+    with pytest.raises(RuntimeError, match="Tool execution failed"):
+        agentic_tools.call_tool("no_such_tool", "{}")
+
+
+def test_list_tools_renders_provider_formats():
+    names = set(agentic_tools.get_tool_names())
+    assert len(names) == agentic_tools.tool_count()
+
+    anthropic = json.loads(agentic_tools.list_tools("anthropic"))
+    assert {tool["name"] for tool in anthropic} == names
+    assert all("input_schema" in tool for tool in anthropic)
+
+
+def test_manifest_lists_every_tool():
+    manifest = json.loads(agentic_tools.get_manifest())
+    assert isinstance(manifest["schemaVersion"], int)
+    assert set(manifest["tools"]) == set(agentic_tools.get_tool_names())
+
+
+def test_end_session_reports_whether_session_existed(fixture_dir):
+    agentic_tools.call_tool(
+        "cli_ls", json.dumps({"path": str(fixture_dir)}), session_id="py-session"
+    )
+    assert agentic_tools.end_session("py-session") is True
+    assert agentic_tools.end_session("py-session") is False
@@ -0,0 +1,307 @@
+//! WebAssembly bindings for the pure-computation subset of agentic-tools.
+//!
+//! Web UIs that already hold a tool listing (an MCP `tools/list` response, or
+//! `listTools("mcp")` from the N-API bindings) can render provider schemas,
+//! validate arguments, and format results client-side without a server round
+//! trip. Nothing here touches the filesystem, subprocesses, or the network, so
+//! the crate builds for `wasm32-unknown-unknown`:
+//!
+//! ```bash
+//! wasm-pack build bindings/wasm --target web
+//! ```
+//!
+//! ## Exports
+//!
+//! Tool listings (`toolsJson`) are JSON arrays of MCP tool definitions:
+//! `{ name, description, inputSchema, outputSchema? }`.
+//!
+//! - `renderTools(toolsJson, provider, strict)`: Render tool definitions for "openai", "anthropic", "gemini", or "mcp"
+//! - `renderTypeDeclarations(toolsJson)`: Render the tools' schemas as a `.d.ts` module
+//! - `validateArgs(toolsJson, name, argsJson)`: Check arguments against a tool's input schema
+//! - `formatResult(dataJson)`: Human-readable fallback text for a tool result
+//! - `parseOptimizerOutput(payloadJson)`: Check and assemble a message-optimizer tool payload
+
+use agentic_tools_core::fmt::fallback_text_from_json;
+use agentic_tools_core::providers::typescript::TypedTool;
+use schemars::Schema;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use wasm_bindgen::prelude::*;
+
+/// One entry of an MCP tool listing.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ToolDefinition {
+    name: String,
+    #[serde(default)]
+    description: String,
+    input_schema: Schema,
+    #[serde(default)]
+    output_schema: Option<Schema>,
+}
+
+fn parse_tools(tools_json: &str) -> Result<Vec<ToolDefinition>, String> {
+    serde_json::from_str(tools_json).map_err(|e| format!("Invalid tools JSON: {e}"))
+}
+
+fn to_json(value: &impl serde::Serialize) -> Result<String, String> {
+    serde_json::to_string(value).map_err(|e| format!("JSON serialization failed: {e}"))
+}
+
+// =============================================================================
+// Schema Rendering
+// =============================================================================
+
+/// Render a tool listing for a provider.
+///
+/// # Arguments
+///
+/// * `tools_json` - JSON array of MCP tool definitions
+/// * `provider` - Provider format: "openai", "anthropic", "gemini", or "mcp"
+/// * `strict` - Strict schema mode for providers that support it
+///
+/// # Returns
+///
+/// JSON string containing an array of tool definitions.
+#[wasm_bindgen(js_name = renderTools)]
+pub fn render_tools(tools_json: &str, provider: &str, strict: bool) -> Result<String, JsError> {
+    render_tools_impl(tools_json, provider, strict).map_err(|e| JsError::new(&e))
+}
+
+fn render_tools_impl(tools_json: &str, provider: &str, strict: bool) -> Result<String, String> {
+    let tools = parse_tools(tools_json)?;
+    let rendered = tools
+        .iter()
+        .map(|tool| match provider {
+            "openai" => Ok(agentic_tools_core::providers::openai::render_function(
+                &tool.name,
+                &tool.description,
+                &tool.input_schema,
+                strict,
+            )),
+            "anthropic" => Ok(agentic_tools_core::providers::anthropic::render_tool(
+                &tool.name,
+                &tool.description,
+                &tool.input_schema,
+                strict,
+            )),
+            "gemini" => Ok(agentic_tools_core::providers::gemini::render_tool(
+                &tool.name,
+                &tool.description,
+                &tool.input_schema,
+            )),
+            "mcp" => Ok(agentic_tools_core::providers::mcp::render_tool(
+                &tool.name,
+                &tool.description,
+                &tool.input_schema,
+                tool.output_schema.as_ref(),
+            )),
+            other => Err(format!("Unknown provider: {other}")),
+        })
+        .collect::<Result<Vec<JsonValue>, String>>()?;
+    to_json(&rendered)
+}
+
+/// Render a tool listing as TypeScript declarations.
+///
+/// # Returns
+///
+/// The `.d.ts` module source: one namespace per tool with `Input`/`Output` types,
+/// plus `ToolInputs`/`ToolOutputs` maps keyed by tool name.
+#[wasm_bindgen(js_name = renderTypeDeclarations)]
+pub fn render_type_declarations(tools_json: &str) -> Result<String, JsError> {
+    let tools = parse_tools(tools_json).map_err(|e| JsError::new(&e))?;
+    let typed: Vec<TypedTool<'_>> = tools
+        .iter()
+        .map(|tool| TypedTool {
+            name: &tool.name,
+            description: &tool.description,
+            input_schema: &tool.input_schema,
+            output_schema: tool.output_schema.as_ref(),
+        })
+        .collect();
+    Ok(agentic_tools_core::providers::typescript::render_declarations(&typed))
+}
+
+// =============================================================================
+// Validation
+// =============================================================================
+
+/// Validate tool arguments against the tool's input schema.
+///
+/// # Arguments
+///
+/// * `tools_json` - JSON array of MCP tool definitions
+/// * `name` - Name of the tool the arguments are for
+/// * `args_json` - JSON string containing the tool arguments
+///
+/// # Returns
+///
+/// JSON array of `{ path, message }` violations, where `path` is a JSON Pointer
+/// into the arguments; an empty array means the arguments are valid.
+///
+/// # Example
+///
+/// ```typescript
+/// import { validateArgs } from 'agentic-tools-wasm';
+/// const errors = JSON.parse(validateArgs(toolsJson, 'cli_grep', JSON.stringify(form)));
+/// for (const { path, message } of errors) markInvalid(path, message);
+/// ```
+#[wasm_bindgen(js_name = validateArgs)]
+pub fn validate_args(tools_json: &str, name: &str, args_json: &str) -> Result<String, JsError> {
+    validate_args_impl(tools_json, name, args_json).map_err(|e| JsError::new(&e))
+}
+
+fn validate_args_impl(tools_json: &str, name: &str, args_json: &str) -> Result<String, String> {
+    let tools = parse_tools(tools_json)?;
+    let tool = tools
+        .iter()
+        .find(|t| t.name == name)
+        .ok_or_else(|| format!("Unknown tool: {name}"))?;
+    let args: JsonValue =
+        serde_json::from_str(args_json).map_err(|e| format!("Invalid args JSON: {e}"))?;
+
+    let schema = serde_json::to_value(&tool.input_schema)
+        .map_err(|e| format!("JSON serialization failed: {e}"))?;
+    let validator = jsonschema::validator_for(&schema)
+        .map_err(|e| format!("Invalid input schema for {name}: {e}"))?;
+    let violations: Vec<JsonValue> = validator
+        .iter_errors(&args)
+        .map(|error| {
+            serde_json::json!({
+                "path": error.instance_path.to_string(),
+                "message": error.to_string(),
+            })
+        })
+        .collect();
+    to_json(&violations)
+}
+
+// =============================================================================
+// Formatting
+// =============================================================================
+
+/// Render a tool result's JSON data as human-readable text.
+///
+/// Uses the same fallback formatting the registry applies to tools without a
+/// custom text format.
+#[wasm_bindgen(js_name = formatResult)]
+pub fn format_result(data_json: &str) -> Result<String, JsError> {
+    let data: JsonValue = serde_json::from_str(data_json)
+        .map_err(|e| JsError::new(&format!("Invalid data JSON: {e}")))?;
+    Ok(fallback_text_from_json(&data))
+}
+
+/// Check a message-optimizer tool payload and assemble the final prompt.
+///
+/// # Arguments
+///
+/// * `payload_json` - JSON object with `system_prompt` and `user_prompt`
+///
+/// # Returns
+///
+/// JSON string with `system_prompt`, `user_prompt`, and `assembled_prompt`.
+#[wasm_bindgen(js_name = parseOptimizerOutput)]
+pub fn parse_optimizer_output(payload_json: &str) -> Result<String, JsError> {
+    parse_optimizer_output_impl(payload_json).map_err(|e| JsError::new(&e))
+}
+
+fn parse_optimizer_output_impl(payload_json: &str) -> Result<String, String> {
+    let payload: JsonValue =
+        serde_json::from_str(payload_json).map_err(|e| format!("Invalid payload JSON: {e}"))?;
+    let prompt = message_optimizer::parse_optimizer_output(&payload).map_err(|e| e.to_string())?;
+    to_json(&prompt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tools_json() -> String {
+        serde_json::json!([{
+            "name": "cli_grep",
+            "description": "Search files",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "pattern": { "type": "string" },
+                    "head_limit": { "type": "integer", "minimum": 1 }
+                },
+                "required": ["pattern"]
+            }
+        }])
+        .to_string()
+    }
+
+    #[test]
+    fn render_tools_uses_provider_shape() {
+        let anthropic: JsonValue =
+            serde_json::from_str(&render_tools_impl(&tools_json(), "anthropic", false).unwrap())
+                .unwrap();
+        assert_eq!(anthropic[0]["name"], "cli_grep");
+        assert!(anthropic[0]["input_schema"].is_object());
+
+        let mcp: JsonValue =
+            serde_json::from_str(&render_tools_impl(&tools_json(), "mcp", false).unwrap()).unwrap();
+        assert!(mcp[0]["inputSchema"].is_object());
+    }
+
+    #[test]
+    fn render_tools_rejects_unknown_provider() {
+        let err = render_tools_impl(&tools_json(), "gopher", false).unwrap_err();
+        assert_eq!(err, "Unknown provider: gopher");
+    }
+
+    #[test]
+    fn validate_args_reports_field_paths() {
+        let valid = validate_args_impl(&tools_json(), "cli_grep", r#"{"pattern":"x"}"#).unwrap();
+        assert_eq!(valid, "[]");
+
+        let invalid: JsonValue = serde_json::from_str(
+            &validate_args_impl(&tools_json(), "cli_grep", r#"{"pattern":1,"head_limit":0}"#)
+                .unwrap(),
+        )
+        .unwrap();
+        let mut paths: Vec<&str> = invalid
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["path"].as_str().unwrap())
+            .collect();
+        paths.sort_unstable();
+        assert_eq!(paths, ["/head_limit", "/pattern"]);
+    }
+
+    #[test]
+    fn validate_args_reports_missing_required_field_at_root() {
+        let invalid: JsonValue =
+            serde_json::from_str(&validate_args_impl(&tools_json(), "cli_grep", "{}").unwrap())
+                .unwrap();
+        assert_eq!(invalid[0]["path"], "");
+        assert!(invalid[0]["message"].as_str().unwrap().contains("pattern"));
+    }
+
+    #[test]
+    fn validate_args_rejects_unknown_tool() {
+        let err = validate_args_impl(&tools_json(), "cli_ls", "{}").unwrap_err();
+        assert_eq!(err, "Unknown tool: cli_ls");
+    }
+
+    #[test]
+    fn parse_optimizer_output_assembles_prompt() {
+        let prompt: JsonValue = serde_json::from_str(
+            &parse_optimizer_output_impl(r#"{"system_prompt":"s","user_prompt":"u"}"#).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(prompt["system_prompt"], "s");
+        assert!(
+            prompt["assembled_prompt"]
+                .as_str()
+                .unwrap()
+                .contains("<user_prompt>\nu\n</user_prompt>")
+        );
+
+        let err = parse_optimizer_output_impl(r#"{"system_prompt":"s"}"#).unwrap_err();
+        assert!(err.starts_with("optimizer output contract violation"));
+    }
+}
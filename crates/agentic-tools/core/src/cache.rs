@@ -0,0 +1,247 @@
+//! Per-session caching of tool results.
+//!
+//! Idempotent, read-only tools opt in by returning a [`CachePolicy`] from
+//! [`Tool::cache_policy`](crate::Tool::cache_policy). Registry dispatch then
+//! serves repeat calls with the same cache key from the calling session's cache
+//! until the entry's TTL expires. Cached entries are scoped by tool name, cache
+//! key, and the context's working directory, and live in the context's
+//! [`SessionState`](crate::SessionState); calls without a session are never
+//! cached. Errors are not cached. Native calls through a
+//! [`ToolHandle`](crate::ToolHandle) bypass the cache.
+
+use crate::context::ToolContext;
+use crate::fmt::TextOptions;
+use crate::registry::FormattedResult;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How long a tool's results stay valid, and which inputs share a result.
+pub struct CachePolicy<I> {
+    ttl: Duration,
+    key: fn(&I) -> Option<String>,
+}
+
+impl<I> CachePolicy<I> {
+    /// Cache results for `ttl`, keyed by `key(input)`.
+    ///
+    /// Calls whose key is `None` are not cached, e.g. inputs that page through
+    /// session state rather than naming a fixed result.
+    pub const fn new(ttl: Duration, key: fn(&I) -> Option<String>) -> Self {
+        Self { ttl, key }
+    }
+
+    /// Time a cached result stays valid.
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Cache key for `input`, or `None` if the call should not be cached.
+    pub fn key(&self, input: &I) -> Option<String> {
+        (self.key)(input)
+    }
+}
+
+impl<I> Clone for CachePolicy<I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for CachePolicy<I> {}
+
+impl<I> std::fmt::Debug for CachePolicy<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachePolicy")
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+type CacheKey = (&'static str, Option<PathBuf>, String);
+
+struct Entry {
+    expires: Instant,
+    data: Value,
+    text: Option<(TextOptions, String)>,
+}
+
+/// Session extension holding cached results for every tool.
+#[derive(Default)]
+struct ResultCache {
+    entries: Mutex<HashMap<CacheKey, Entry>>,
+}
+
+/// Where one call's result is (or will be) cached.
+pub(crate) struct CacheSlot {
+    cache: Arc<ResultCache>,
+    key: CacheKey,
+    ttl: Duration,
+}
+
+impl CacheSlot {
+    /// Locate the cache slot for a call, if the policy and context allow caching.
+    pub(crate) fn locate<I>(
+        tool: &'static str,
+        policy: Option<CachePolicy<I>>,
+        input: &I,
+        ctx: &ToolContext,
+    ) -> Option<Self> {
+        let policy = policy?;
+        let session = ctx.session()?;
+        let key = policy.key(input)?;
+        Some(Self {
+            cache: session.get_or_insert_with(ResultCache::default),
+            key: (tool, ctx.cwd().map(PathBuf::from), key),
+            ttl: policy.ttl(),
+        })
+    }
+
+    /// Cached JSON result, if present and unexpired.
+    pub(crate) fn data(&self) -> Option<Value> {
+        self.with_entry(|entry| Some(entry.data.clone()))
+    }
+
+    /// Cached formatted result, if present, unexpired, and formatted with `opts`.
+    pub(crate) fn formatted(&self, opts: &TextOptions) -> Option<FormattedResult> {
+        self.with_entry(|entry| match &entry.text {
+            Some((cached_opts, text)) if cached_opts == opts => Some(FormattedResult {
+                data: entry.data.clone(),
+                text: Some(text.clone()),
+            }),
+            _ => None,
+        })
+    }
+
+    /// Store a fresh result, replacing any previous entry and pruning expired ones.
+    pub(crate) fn store(self, data: &Value, text: Option<(&TextOptions, &str)>) {
+        let now = Instant::now();
+        let mut entries = self.lock();
+        entries.retain(|_, entry| entry.expires > now);
+        entries.insert(
+            self.key.clone(),
+            Entry {
+                expires: now + self.ttl,
+                data: data.clone(),
+                text: text.map(|(opts, text)| (opts.clone(), text.to_string())),
+            },
+        );
+    }
+
+    fn with_entry<T>(&self, read: impl FnOnce(&Entry) -> Option<T>) -> Option<T> {
+        let entries = self.lock();
+        entries
+            .get(&self.key)
+            .filter(|entry| entry.expires > Instant::now())
+            .and_then(read)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, Entry>> {
+        self.cache
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ToolError;
+    use crate::registry::ToolRegistry;
+    use crate::session::SessionState;
+    use crate::tool::Tool;
+    use futures::future::BoxFuture;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    /// Echoes its input with a call counter; inputs starting with `_` are uncached.
+    #[derive(Clone, Default)]
+    struct CountingTool {
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl Tool for CountingTool {
+        type Input = String;
+        type Output = String;
+        const NAME: &'static str = "counting";
+        const DESCRIPTION: &'static str = "Counts calls";
+
+        fn call(
+            &self,
+            input: Self::Input,
+            _ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Box::pin(async move { Ok(format!("{input}#{n}")) })
+        }
+
+        fn cache_policy(&self) -> Option<CachePolicy<Self::Input>> {
+            Some(CachePolicy::new(
+                Duration::from_secs(60),
+                |input: &String| (!input.starts_with('_')).then(|| input.clone()),
+            ))
+        }
+    }
+
+    fn registry() -> ToolRegistry {
+        ToolRegistry::builder()
+            .register::<CountingTool, ()>(CountingTool::default())
+            .finish()
+    }
+
+    async fn call(registry: &ToolRegistry, input: &str, ctx: &ToolContext) -> Value {
+        registry
+            .dispatch_json("counting", serde_json::json!(input), ctx)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_repeat_calls_hit_session_cache() {
+        let registry = registry();
+        let session = ToolContext::default().with_session(Arc::new(SessionState::new()));
+        let other = ToolContext::default().with_session(Arc::new(SessionState::new()));
+
+        assert_eq!(call(&registry, "a", &session).await, "a#1");
+        assert_eq!(call(&registry, "a", &session).await, "a#1");
+        assert_eq!(call(&registry, "b", &session).await, "b#2");
+        assert_eq!(call(&registry, "a", &other).await, "a#3");
+        assert_eq!(
+            call(&registry, "a", &session.clone().with_cwd("/elsewhere")).await,
+            "a#4"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_uncached_without_session_or_key() {
+        let registry = registry();
+        let session = ToolContext::default().with_session(Arc::new(SessionState::new()));
+
+        assert_eq!(call(&registry, "a", &ToolContext::default()).await, "a#1");
+        assert_eq!(call(&registry, "a", &ToolContext::default()).await, "a#2");
+        assert_eq!(call(&registry, "_a", &session).await, "_a#3");
+        assert_eq!(call(&registry, "_a", &session).await, "_a#4");
+    }
+
+    #[tokio::test]
+    async fn test_formatted_hits_require_matching_text_options() {
+        let registry = registry();
+        let ctx = ToolContext::default().with_session(Arc::new(SessionState::new()));
+        let plain = TextOptions::default();
+        let markdown = TextOptions::default().with_markdown(true);
+        assert_eq!(call(&registry, "a", &ctx).await, "a#1");
+        for (opts, expected) in [(&plain, "a#2"), (&plain, "a#2"), (&markdown, "a#3")] {
+            let result = registry
+                .dispatch_json_formatted("counting", serde_json::json!("a"), &ctx, opts)
+                .await
+                .unwrap();
+            assert_eq!(result.data, expected);
+        }
+    }
+}
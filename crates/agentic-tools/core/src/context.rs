@@ -1,7 +1,19 @@
 //! Tool execution context.
 
 use crate::ToolError;
+use crate::events::EventSink;
+use crate::events::ToolEvent;
+use crate::permissions::Permissions;
+use crate::session::SessionState;
+use crate::workspace::Artifact;
+use crate::workspace::LazyScratch;
+use crate::workspace::default_artifact_root;
+use crate::workspace::promote;
 use std::future::Future;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 use tokio_util::sync::WaitForCancellationFutureOwned;
 
@@ -27,9 +39,36 @@ use tokio_util::sync::WaitForCancellationFutureOwned;
 /// For subprocess-managing tools, request cancellation should trigger explicit cleanup
 /// before returning. Dropping a future remains a backstop, not the primary cooperative
 /// cleanup path.
+///
+/// MCP servers also attach the calling client's [`SessionState`]. Tools that keep
+/// implicit pagination or other per-client state should key it with
+/// [`ToolContext::session_key`] so concurrent clients do not observe each other's
+/// offsets. Contexts without a session (direct, native, NAPI) share the unscoped keys.
+///
+/// Tools that produce intermediate files should write them under
+/// [`ToolContext::scratch_dir`] (removed when the call's context is dropped) or
+/// [`ToolContext::session_scratch_dir`], and move anything worth keeping out with
+/// [`ToolContext::promote_artifact`]. See [`crate::workspace`].
+///
+/// Long-running tools report incremental output with [`ToolContext::emit`]; it
+/// reaches the host only if one attached a sink. See [`crate::events`].
+///
+/// Hosts that serve a workspace other than the process's own (an editor
+/// extension, for example) set [`ToolContext::with_cwd`]; tools resolve relative
+/// paths against [`ToolContext::cwd`] when present.
+///
+/// Hosts restrict what a call may do with [`ToolContext::with_permissions`];
+/// registry dispatch refuses tools that declare capabilities the context does
+/// not grant. See [`crate::permissions`].
 #[derive(Clone, Debug)]
 pub struct ToolContext {
     cancel: CancellationToken,
+    session: Option<Arc<SessionState>>,
+    scratch: Arc<LazyScratch>,
+    artifact_root: Option<PathBuf>,
+    events: Option<EventSink>,
+    cwd: Option<PathBuf>,
+    permissions: Permissions,
 }
 
 impl Default for ToolContext {
@@ -46,7 +85,124 @@ impl ToolContext {
 
     /// Create a context backed by the supplied cancellation token.
     pub fn with_cancel(cancel: CancellationToken) -> Self {
-        Self { cancel }
+        Self {
+            cancel,
+            session: None,
+            scratch: Arc::new(LazyScratch::new("call")),
+            artifact_root: None,
+            events: None,
+            cwd: None,
+            permissions: Permissions::ALL,
+        }
+    }
+
+    /// Attach the calling client's session state.
+    #[must_use]
+    pub fn with_session(mut self, session: Arc<SessionState>) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// The calling client's session state, if the transport provides one.
+    pub fn session(&self) -> Option<&Arc<SessionState>> {
+        self.session.as_ref()
+    }
+
+    /// Scope a cache key to the calling session.
+    ///
+    /// Returns `key` unchanged when no session is attached.
+    pub fn session_key(&self, key: &str) -> String {
+        match &self.session {
+            Some(session) => format!("{}|{key}", session.id()),
+            None => key.to_string(),
+        }
+    }
+
+    /// Store promoted artifacts under `root` instead of the default location.
+    #[must_use]
+    pub fn with_artifact_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.artifact_root = Some(root.into());
+        self
+    }
+
+    /// Deliver events emitted during this call to `sink`.
+    #[must_use]
+    pub fn with_events(mut self, sink: EventSink) -> Self {
+        self.events = Some(sink);
+        self
+    }
+
+    /// Resolve relative paths against `dir` instead of the process working directory.
+    #[must_use]
+    pub fn with_cwd(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cwd = Some(dir.into());
+        self
+    }
+
+    /// Working directory override for this call, if the host set one.
+    pub fn cwd(&self) -> Option<&Path> {
+        self.cwd.as_deref()
+    }
+
+    /// Grant only `permissions` to this call instead of every capability.
+    #[must_use]
+    pub const fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = permissions;
+        self
+    }
+
+    /// Capabilities granted to this call.
+    pub const fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    /// Report incremental progress to the host; a no-op when no sink is attached.
+    pub fn emit(&self, event: ToolEvent) {
+        if let Some(sink) = &self.events {
+            sink.emit(event);
+        }
+    }
+
+    /// Report a human-readable status update; shorthand for emitting
+    /// [`ToolEvent::Progress`]. MCP clients that asked for progress receive it as a
+    /// progress notification.
+    pub fn progress(&self, message: impl Into<String>) {
+        self.emit(ToolEvent::Progress {
+            message: message.into(),
+        });
+    }
+
+    /// Scratch directory for this call, created on first use. It is shared by all
+    /// clones of this context and removed once the last clone is dropped.
+    pub fn scratch_dir(&self) -> io::Result<PathBuf> {
+        self.scratch.get_or_create()
+    }
+
+    /// Scratch directory that outlives the call but not the session. Falls back to
+    /// [`ToolContext::scratch_dir`] when no session is attached.
+    pub fn session_scratch_dir(&self) -> io::Result<PathBuf> {
+        match &self.session {
+            Some(session) => session.scratch_dir(),
+            None => self.scratch_dir(),
+        }
+    }
+
+    /// Directory promoted artifacts for this context are stored in.
+    pub fn artifact_dir(&self) -> PathBuf {
+        let root = self
+            .artifact_root
+            .clone()
+            .unwrap_or_else(default_artifact_root);
+        match &self.session {
+            Some(session) => root.join(session.id().to_string()),
+            None => root.join("direct"),
+        }
+    }
+
+    /// Move `src` (typically a file in scratch) into [`ToolContext::artifact_dir`]
+    /// as `name`, suffixing the name if it is already taken.
+    pub fn promote_artifact(&self, src: &Path, name: &str) -> io::Result<Artifact> {
+        promote(src, &self.artifact_dir(), name)
     }
 
     /// Clone the request cancellation token for use across `.await` boundaries.
@@ -111,6 +267,89 @@ mod tests {
         assert!(ctx.cancellation_token().is_cancelled());
     }
 
+    #[test]
+    fn session_key_is_scoped_per_session() {
+        let plain = ToolContext::default();
+        let a = ToolContext::default().with_session(Arc::new(SessionState::new()));
+        let b = ToolContext::default().with_session(Arc::new(SessionState::new()));
+
+        assert_eq!(plain.session_key("q"), "q");
+        assert!(plain.session().is_none());
+        assert_ne!(a.session_key("q"), b.session_key("q"));
+        let a_clone = a.clone();
+        assert_eq!(a.session_key("q"), a_clone.session_key("q"));
+    }
+
+    #[test]
+    fn scratch_dir_is_shared_by_clones_and_removed_with_the_last() {
+        let ctx = ToolContext::default();
+        let clone = ctx.clone();
+        let dir = ctx.scratch_dir().unwrap();
+        assert_eq!(clone.scratch_dir().unwrap(), dir);
+        assert_ne!(ToolContext::default().scratch_dir().unwrap(), dir);
+
+        drop(ctx);
+        assert!(dir.is_dir());
+        drop(clone);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn session_scratch_outlives_the_call() {
+        let session = Arc::new(SessionState::new());
+        let ctx = ToolContext::default().with_session(Arc::clone(&session));
+        let dir = ctx.session_scratch_dir().unwrap();
+        assert_ne!(dir, ctx.scratch_dir().unwrap());
+
+        drop(ctx);
+        assert!(dir.is_dir());
+        drop(session);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn promote_artifact_moves_scratch_file_into_session_dir() {
+        let root = crate::workspace::ScratchDir::create("test-artifacts").unwrap();
+        let session = Arc::new(SessionState::new());
+        let ctx = ToolContext::default()
+            .with_session(Arc::clone(&session))
+            .with_artifact_root(root.path());
+
+        let src = ctx.scratch_dir().unwrap().join("export.json");
+        std::fs::write(&src, "{}").unwrap();
+        let artifact = ctx.promote_artifact(&src, "export.json").unwrap();
+
+        assert_eq!(
+            artifact.path,
+            root.path()
+                .join(session.id().to_string())
+                .join("export.json")
+        );
+        assert_eq!(artifact.bytes, 2);
+        drop(ctx);
+        assert!(artifact.path.is_file());
+    }
+
+    #[test]
+    fn emit_reaches_attached_sink_only() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = {
+            let seen = Arc::clone(&seen);
+            EventSink::new(move |event| seen.lock().unwrap().push(event))
+        };
+        let event = ToolEvent::Progress {
+            message: "indexing".into(),
+        };
+
+        ToolContext::default().emit(event.clone());
+        let ctx = ToolContext::default().with_events(sink);
+        let clone = ctx.clone();
+        ctx.emit(event.clone());
+        clone.progress("indexing");
+
+        assert_eq!(*seen.lock().unwrap(), vec![event.clone(), event]);
+    }
+
     #[tokio::test]
     async fn run_cancellable_returns_inner_success() {
         let ctx = ToolContext::default();
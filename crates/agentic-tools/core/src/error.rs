@@ -1,5 +1,7 @@
 //! Unified error type for agentic tools.
 
+use crate::permissions::Permissions;
+use serde::Serialize;
 use thiserror::Error;
 
 /// Error type returned by tool operations.
@@ -9,6 +11,10 @@ pub enum ToolError {
     #[error("invalid input: {0}")]
     InvalidInput(String),
 
+    /// Arguments did not match the tool's input schema; one entry per violation.
+    #[error("invalid input: {}", FieldError::join(.0))]
+    InvalidArguments(Vec<FieldError>),
+
     /// Internal error during tool execution.
     #[error("internal error: {0}")]
     Internal(String),
@@ -21,6 +27,19 @@ pub enum ToolError {
     #[error("permission denied: {0}")]
     Permission(String),
 
+    /// The tool needs capabilities the registry or context did not grant.
+    #[error("permission denied: {tool} requires {missing}")]
+    PermissionDenied {
+        /// Registered name of the refused tool.
+        tool: String,
+        /// Declared capabilities that were not granted.
+        missing: Permissions,
+    },
+
+    /// The call exceeded the tool's concurrency or rate limits.
+    #[error("rate limited: {0}")]
+    RateLimited(String),
+
     /// Requested resource not found.
     #[error("not found: {0}")]
     NotFound(String),
@@ -38,6 +57,35 @@ pub enum ToolError {
     },
 }
 
+/// A single argument that failed schema validation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldError {
+    /// JSON Pointer to the offending value within the arguments (`""` for the root).
+    pub path: String,
+    /// What is wrong with the value.
+    pub message: String,
+}
+
+impl FieldError {
+    fn join(errors: &[Self]) -> String {
+        errors
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+impl std::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
 impl ToolError {
     /// Create an invalid input error.
     pub fn invalid_input(s: impl Into<String>) -> Self {
@@ -64,6 +112,11 @@ impl ToolError {
         Self::Permission(s.into())
     }
 
+    /// Create a rate limited error.
+    pub fn rate_limited(s: impl Into<String>) -> Self {
+        Self::RateLimited(s.into())
+    }
+
     /// Create a cancelled error.
     pub fn cancelled(reason: Option<String>) -> Self {
         Self::Cancelled { reason }
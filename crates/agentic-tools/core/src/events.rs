@@ -0,0 +1,82 @@
+//! Incremental events emitted while a tool runs.
+//!
+//! A tool call normally yields a single final result. Hosts that can display
+//! progress (the NAPI `callToolStreaming` binding, for example) attach an
+//! [`EventSink`] with [`ToolContext::with_events`](crate::ToolContext::with_events);
+//! long-running tools then report output as it happens through
+//! [`ToolContext::emit`](crate::ToolContext::emit). Without a sink, emitting is a
+//! no-op, so tools emit unconditionally and the final result stays authoritative.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+use std::sync::Arc;
+
+/// Which output stream of a subprocess a chunk came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// One incremental event from a running tool.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ToolEvent {
+    /// A line of subprocess output, including its trailing newline if any.
+    Output { stream: OutputStream, text: String },
+    /// One entry of a subagent transcript, as emitted by the agent runtime.
+    Transcript { entry: Value },
+    /// A chunk of model text as it is generated.
+    TextDelta { text: String },
+    /// A human-readable status update.
+    Progress { message: String },
+    /// A serialized chunk from a [`StreamingTool`](crate::StreamingTool).
+    Chunk { data: Value },
+}
+
+/// Receiver of [`ToolEvent`]s, shared by all clones of a context.
+#[derive(Clone)]
+pub struct EventSink(Arc<dyn Fn(ToolEvent) + Send + Sync>);
+
+impl EventSink {
+    /// Deliver events to `f`. It is called on whichever task emits, so it should
+    /// hand events off rather than block.
+    pub fn new(f: impl Fn(ToolEvent) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    pub fn emit(&self, event: ToolEvent) {
+        (self.0)(event);
+    }
+}
+
+impl fmt::Debug for EventSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EventSink")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn events_serialize_with_type_tag() {
+        let output = ToolEvent::Output {
+            stream: OutputStream::Stderr,
+            text: "warning\n".into(),
+        };
+        assert_eq!(
+            serde_json::to_value(&output).unwrap(),
+            json!({"type": "output", "stream": "stderr", "text": "warning\n"})
+        );
+        let delta = ToolEvent::TextDelta { text: "Hel".into() };
+        assert_eq!(
+            serde_json::to_value(&delta).unwrap(),
+            json!({"type": "text_delta", "text": "Hel"})
+        );
+    }
+}
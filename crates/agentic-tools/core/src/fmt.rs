@@ -38,6 +38,12 @@
 //! - Types with custom formatting override `fmt_text()`
 //! - Types wanting JSON fallback use an empty impl: `impl TextFormat for T {}`
 //! - The registry always calls `fmt_text()` on the native output—no detection needed
+//!
+//! # Builders
+//!
+//! [`Table`] and [`Tree`] help custom formatters stay compact: tables align
+//! columns (or render as markdown) and truncate long cells, trees print shared
+//! path prefixes once.
 
 use serde_json::Value as JsonValue;
 use std::any::Any;
@@ -53,7 +59,7 @@ pub enum TextStyle {
 }
 
 /// Options controlling text formatting behavior.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TextOptions {
     /// The rendering style to use.
     pub style: TextStyle,
@@ -133,6 +139,282 @@ impl TextFormat for String {
     }
 }
 
+// ============================================================================
+// Table and tree builders
+// ============================================================================
+
+/// Column-aligned table for compact `fmt_text` output.
+///
+/// Plain rendering pads each column to its widest cell and separates columns
+/// with two spaces; the last column is never padded and trailing whitespace is
+/// trimmed. With [`TextOptions::markdown`] the table renders as a GFM table.
+/// Cells longer than their column's [`Table::max_width`] are cut and end in `…`.
+///
+/// ```ignore
+/// let mut table = Table::new().indent(2).max_width(2, 60);
+/// table.push_row(["build", "(target)", "Build the project"]);
+/// let text = table.render(opts);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    headers: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    max_widths: Vec<Option<usize>>,
+    indent: usize,
+}
+
+impl Table {
+    /// Create an empty table without a header row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty table with a header row.
+    pub fn with_headers<I, S>(headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            headers: Some(headers.into_iter().map(Into::into).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Truncate cells of `column` to at most `width` characters.
+    #[must_use]
+    pub fn max_width(mut self, column: usize, width: usize) -> Self {
+        if self.max_widths.len() <= column {
+            self.max_widths.resize(column + 1, None);
+        }
+        self.max_widths[column] = Some(width);
+        self
+    }
+
+    /// Prefix every plain-text line with `indent` spaces.
+    #[must_use]
+    pub const fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Append a row. Rows may have different lengths; missing cells are empty.
+    pub fn push_row<I, S>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// Number of rows, excluding the header.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether the table has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Render as aligned plain text, or as a markdown table if `opts.markdown`.
+    pub fn render(&self, opts: &TextOptions) -> String {
+        let rows: Vec<Vec<String>> = self
+            .headers
+            .iter()
+            .chain(&self.rows)
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(column, cell)| self.fit(column, cell))
+                    .collect()
+            })
+            .collect();
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if opts.markdown {
+            Self::render_markdown(&rows, columns, self.headers.is_some())
+        } else {
+            self.render_plain(&rows, columns)
+        }
+    }
+
+    fn fit(&self, column: usize, cell: &str) -> String {
+        match self.max_widths.get(column).copied().flatten() {
+            Some(width) => truncate_chars(cell, width),
+            None => cell.to_string(),
+        }
+    }
+
+    fn render_plain(&self, rows: &[Vec<String>], columns: usize) -> String {
+        let mut widths = vec![0; columns];
+        for row in rows {
+            for (column, cell) in row.iter().enumerate() {
+                widths[column] = widths[column].max(cell.chars().count());
+            }
+        }
+
+        let indent = " ".repeat(self.indent);
+        let mut lines = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut line = indent.clone();
+            for (column, width) in widths.iter().enumerate() {
+                let cell = row.get(column).map_or("", String::as_str);
+                if column > 0 {
+                    line.push_str("  ");
+                }
+                line.push_str(cell);
+                if column + 1 < columns {
+                    let pad = width - cell.chars().count();
+                    line.extend(std::iter::repeat_n(' ', pad));
+                }
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+
+    fn render_markdown(rows: &[Vec<String>], columns: usize, has_headers: bool) -> String {
+        let line = |row: Option<&Vec<String>>| {
+            let cells: Vec<String> = (0..columns)
+                .map(|column| {
+                    row.and_then(|row| row.get(column))
+                        .map_or_else(String::new, |cell| cell.replace('|', "\\|"))
+                })
+                .collect();
+            format!("| {} |", cells.join(" | "))
+        };
+
+        let (header, body) = if has_headers {
+            (rows.first(), rows.get(1..).unwrap_or_default())
+        } else {
+            (None, rows)
+        };
+        let mut lines = vec![line(header), format!("|{}", " --- |".repeat(columns))];
+        lines.extend(body.iter().map(|row| line(Some(row))));
+        lines.join("\n")
+    }
+}
+
+/// Indented tree for path-like listings, so shared prefixes are printed once.
+///
+/// Children keep insertion order. Each level is indented two spaces past its
+/// parent, starting at [`Tree::indent`]; directory nodes end in `/`.
+///
+/// ```ignore
+/// let mut tree = Tree::new().indent(2);
+/// tree.insert_path("src/main.rs", false);
+/// tree.insert_path("src/lib.rs", false);
+/// assert_eq!(tree.render(), "  src/\n    main.rs\n    lib.rs");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Tree {
+    roots: Vec<TreeNode>,
+    indent: usize,
+}
+
+#[derive(Clone, Debug)]
+struct TreeNode {
+    label: String,
+    dir: bool,
+    children: Vec<Self>,
+}
+
+impl Tree {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indent top-level nodes by `indent` spaces.
+    #[must_use]
+    pub const fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Insert a `/`-separated path. Missing ancestors are added as directories;
+    /// `dir` marks the last segment. Inserting an existing path only updates
+    /// its `dir` flag. Empty segments are ignored.
+    pub fn insert_path(&mut self, path: &str, dir: bool) {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let Some((last, ancestors)) = segments.split_last() else {
+            return;
+        };
+        let level = self.level_mut(ancestors, true);
+        let index = Self::child(level, last, dir);
+        level[index].dir |= dir;
+    }
+
+    /// Append a leaf under the given ancestors, e.g. `insert(&[file], "12: fn main()")`.
+    /// Missing ancestors are added as plain (non-directory) nodes; the leaf is
+    /// always added, even if an identical one exists.
+    pub fn insert<S: AsRef<str>>(&mut self, ancestors: &[S], leaf: &str) {
+        let ancestors: Vec<&str> = ancestors.iter().map(AsRef::as_ref).collect();
+        self.level_mut(&ancestors, false).push(TreeNode {
+            label: leaf.to_string(),
+            dir: false,
+            children: Vec::new(),
+        });
+    }
+
+    /// Whether the tree has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Children of the node at `ancestors`, creating missing nodes on the way.
+    fn level_mut(&mut self, ancestors: &[&str], dirs: bool) -> &mut Vec<TreeNode> {
+        let mut level = &mut self.roots;
+        for segment in ancestors {
+            let index = Self::child(level, segment, dirs);
+            level = &mut level[index].children;
+        }
+        level
+    }
+
+    /// Index of the child labelled `label`, adding it if missing.
+    fn child(level: &mut Vec<TreeNode>, label: &str, dir: bool) -> usize {
+        if let Some(index) = level.iter().position(|node| node.label == label) {
+            return index;
+        }
+        level.push(TreeNode {
+            label: label.to_string(),
+            dir,
+            children: Vec::new(),
+        });
+        level.len() - 1
+    }
+
+    /// Render one node per line.
+    pub fn render(&self) -> String {
+        let mut lines = Vec::new();
+        Self::render_level(&self.roots, self.indent, &mut lines);
+        lines.join("\n")
+    }
+
+    fn render_level(nodes: &[TreeNode], indent: usize, lines: &mut Vec<String>) {
+        for node in nodes {
+            let slash = if node.dir && !node.label.ends_with('/') {
+                "/"
+            } else {
+                ""
+            };
+            lines.push(format!("{:indent$}{}{slash}", "", node.label));
+            Self::render_level(&node.children, indent + 2, lines);
+        }
+    }
+}
+
+/// Cut `s` to at most `max` characters, ending in `…` when shortened.
+pub fn truncate_chars(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let mut out: String = s.chars().take(max.saturating_sub(1)).collect();
+    out.push('…');
+    out
+}
+
 // ============================================================================
 // Type-erased formatter infrastructure (compatibility API)
 // ============================================================================
@@ -274,4 +556,52 @@ mod tests {
         let text = output.fmt_text(&TextOptions::default());
         assert_eq!(text, "Message: Hello");
     }
+
+    #[test]
+    fn test_table_plain_aligns_and_truncates() {
+        let mut table = Table::with_headers(["recipe", "params", "doc"])
+            .indent(2)
+            .max_width(2, 8);
+        table.push_row(["build", "(target)", "Build the project"]);
+        table.push_row(["test", "", ""]);
+
+        assert_eq!(
+            table.render(&TextOptions::default()),
+            "  recipe  params    doc\n  build   (target)  Build t…\n  test"
+        );
+    }
+
+    #[test]
+    fn test_table_markdown() {
+        let mut table = Table::new();
+        table.push_row(["a|b", "c"]);
+        table.push_row(["d"]);
+
+        assert_eq!(
+            table.render(&TextOptions::default().with_markdown(true)),
+            "|  |  |\n| --- | --- |\n| a\\|b | c |\n| d |  |"
+        );
+    }
+
+    #[test]
+    fn test_tree_groups_shared_prefixes() {
+        let mut tree = Tree::new().indent(2);
+        tree.insert_path("src", true);
+        tree.insert_path("src/main.rs", false);
+        tree.insert_path("docs/guide/intro.md", false);
+        tree.insert_path("README.md", false);
+        tree.insert(&["src/lib.rs"], "12: fn main()");
+        tree.insert(&["src/lib.rs"], "12: fn main()");
+
+        assert_eq!(
+            tree.render(),
+            "  src/\n    main.rs\n  docs/\n    guide/\n      intro.md\n  README.md\n  src/lib.rs\n    12: fn main()\n    12: fn main()"
+        );
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert_eq!(truncate_chars("héllo", 5), "héllo");
+        assert_eq!(truncate_chars("héllo", 3), "hé…");
+    }
 }
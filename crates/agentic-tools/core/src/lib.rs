@@ -4,34 +4,79 @@
 //! - [`Tool`] trait: Native-first tool definition with no serde bounds
 //! - [`ToolCodec`] trait: Serialization boundary for protocol integration
 //! - [`ToolRegistry`]: Type-safe tool storage with native and JSON dispatch
+//! - [`StreamingTool`]: Tools that stream typed chunks before their final result
+//! - [`ToolMiddleware`]: Before/after hooks installed on a registry's JSON dispatch
+//! - [`InputValidator`]: Middleware that checks arguments against each tool's input schema
+//! - [`CachePolicy`]: Opt-in per-session result caching for read-only tools
+//! - [`ToolPolicy`]: Per-tool concurrency caps and call spacing enforced in dispatch
+//! - [`Permissions`]: Capabilities tools declare and hosts grant, checked in dispatch
 //! - [`SchemaEngine`]: Runtime schema transforms for provider flexibility
-//! - [`TextFormat`] trait: Transport-agnostic text formatting for tool outputs
-//! - Provider renderers: `OpenAI`, Anthropic, and MCP schema generation
+//! - [`ToolManifest`]: Schema version and per-tool hashes for embedder handshakes
+//! - [`TextFormat`] trait: Transport-agnostic text formatting for tool outputs, with [`Table`] and [`Tree`] builders
+//! - [`SessionState`]: Per-client state attached to [`ToolContext`] by MCP servers
+//! - [`ToolEvent`]: Incremental output streamed to hosts that attach an [`EventSink`]
+//! - [`workspace`]: Scratch directories and persistent [`Artifact`]s for tool calls
+//! - Provider renderers: `OpenAI`, Anthropic, Gemini, and MCP schema generation, plus TypeScript declarations
 
+pub mod cache;
 pub mod context;
 pub mod error;
+pub mod events;
 pub mod fmt;
+pub mod manifest;
+pub mod middleware;
+pub mod permissions;
+pub mod policy;
 pub mod providers;
 pub mod registry;
 pub mod schema;
+pub mod session;
+pub mod streaming;
 pub mod tool;
+pub mod validation;
+pub mod workspace;
 
+pub use cache::CachePolicy;
 pub use context::ToolContext;
+pub use error::FieldError;
 pub use error::ToolError;
+pub use events::EventSink;
+pub use events::OutputStream;
+pub use events::ToolEvent;
 pub use fmt::ErasedFmt;
+pub use fmt::Table;
 pub use fmt::TextFormat;
 pub use fmt::TextOptions;
 pub use fmt::TextStyle;
+pub use fmt::Tree;
 pub use fmt::fallback_text_from_json;
+pub use manifest::REGISTRY_SCHEMA_VERSION;
+pub use manifest::ToolManifest;
+pub use middleware::ToolMiddleware;
+pub use permissions::Permissions;
+pub use policy::LimitBehavior;
+pub use policy::ToolPolicy;
+pub use providers::Provider;
 pub use registry::FormattedResult;
+pub use registry::NAMESPACE_SEPARATOR;
 pub use registry::ToolHandle;
+pub use registry::ToolMeta;
 pub use registry::ToolRegistry;
 pub use registry::ToolRegistryBuilder;
 pub use schema::FieldConstraint;
 pub use schema::SchemaEngine;
 pub use schema::SchemaTransform;
+pub use schema::TransformStep;
+pub use session::SessionId;
+pub use session::SessionState;
+pub use streaming::StreamItem;
+pub use streaming::StreamingTool;
+pub use streaming::ToolStream;
+pub use tool::Deprecation;
 pub use tool::Tool;
 pub use tool::ToolCodec;
+pub use validation::InputValidator;
+pub use workspace::Artifact;
 
 // Re-export BoxFuture to support macro-generated signatures without exposing futures crate
 pub use futures::future::BoxFuture;
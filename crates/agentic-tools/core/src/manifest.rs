@@ -0,0 +1,288 @@
+//! Registry manifest: schema version plus per-tool content hashes.
+//!
+//! Embedders (the napi bindings, MCP clients) compare the manifest against what
+//! they were built for so a stale JS package or native binary fails fast at
+//! startup instead of with confusing argument errors mid-session.
+
+use crate::error::ToolError;
+use crate::registry::ToolRegistry;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Digest;
+use sha2::Sha256;
+use std::collections::BTreeMap;
+
+/// Version of the registry's tool-schema contract.
+///
+/// Bump when a change to tool names, inputs, or outputs requires embedders to be
+/// rebuilt (renamed/removed tools, new required fields, changed output shapes).
+/// Additive changes are visible through the per-tool hashes without a bump.
+pub const REGISTRY_SCHEMA_VERSION: u32 = 1;
+
+/// Hex digits kept from each SHA-256 digest.
+const HASH_LEN: usize = 16;
+
+/// Snapshot of the tools a registry exposes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolManifest {
+    /// [`REGISTRY_SCHEMA_VERSION`] of the binary that built the manifest.
+    pub schema_version: u32,
+    /// Hash over every tool hash; equal registries have equal hashes.
+    pub registry_hash: String,
+    /// Content hash of each tool's name, description, and input/output schemas.
+    pub tools: BTreeMap<String, String>,
+}
+
+impl ToolManifest {
+    /// Build the manifest for every tool in `registry`.
+    pub fn from_registry(registry: &ToolRegistry) -> Self {
+        let tools: BTreeMap<String, String> = registry
+            .iter_named()
+            .map(|(name, tool)| {
+                let content = serde_json::json!({
+                    "name": name,
+                    "description": tool.description(),
+                    "input": serde_json::to_value(tool.input_schema()).unwrap_or(Value::Null),
+                    "output": tool
+                        .output_schema()
+                        .and_then(|s| serde_json::to_value(s).ok())
+                        .unwrap_or(Value::Null),
+                });
+                (name.to_string(), hash_json(&content))
+            })
+            .collect();
+
+        let mut hasher = Sha256::new();
+        for (name, hash) in &tools {
+            hasher.update(name.as_bytes());
+            hasher.update(b":");
+            hasher.update(hash.as_bytes());
+            hasher.update(b"\n");
+        }
+
+        Self {
+            schema_version: REGISTRY_SCHEMA_VERSION,
+            registry_hash: hex_prefix(&hasher.finalize()),
+            tools,
+        }
+    }
+
+    /// Fail if the embedder was built against a different schema version.
+    pub fn check_schema_version(&self, expected: u32) -> Result<(), ToolError> {
+        if expected == self.schema_version {
+            return Ok(());
+        }
+        Err(ToolError::InvalidInput(format!(
+            "tool schema version mismatch: embedder expects version {expected} but the native registry provides version {actual}; rebuild or upgrade the JS package and the native binary together",
+            actual = self.schema_version
+        )))
+    }
+
+    /// Tool names whose hash differs from `other`, including tools missing from either side.
+    pub fn changed_tools(&self, other: &Self) -> Vec<String> {
+        let mut changed: Vec<String> = self
+            .tools
+            .iter()
+            .filter(|(name, hash)| other.tools.get(*name) != Some(*hash))
+            .map(|(name, _)| name.clone())
+            .collect();
+        changed.extend(
+            other
+                .tools
+                .keys()
+                .filter(|name| !self.tools.contains_key(*name))
+                .cloned(),
+        );
+        changed.sort();
+        changed
+    }
+}
+
+fn hash_json(value: &Value) -> String {
+    let mut canonical = String::new();
+    write_canonical(value, &mut canonical);
+    hex_prefix(&Sha256::digest(canonical.as_bytes()))
+}
+
+/// Serialize with object keys sorted so hashes do not depend on map ordering.
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            out.push('{');
+            for (i, key) in keys.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(&map[key], out);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+fn hex_prefix(digest: &[u8]) -> String {
+    use std::fmt::Write;
+    let mut hex = String::with_capacity(HASH_LEN);
+    for byte in digest.iter().take(HASH_LEN / 2) {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ToolContext;
+    use crate::fmt::TextFormat;
+    use crate::fmt::TextOptions;
+    use crate::tool::Tool;
+    use futures::future::BoxFuture;
+    use schemars::JsonSchema;
+
+    #[derive(Clone)]
+    struct Echo;
+
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    struct EchoIn {
+        text: String,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    struct EchoOut {
+        text: String,
+    }
+
+    impl TextFormat for EchoOut {
+        fn fmt_text(&self, _opts: &TextOptions) -> String {
+            self.text.clone()
+        }
+    }
+
+    impl Tool for Echo {
+        type Input = EchoIn;
+        type Output = EchoOut;
+        const NAME: &'static str = "echo";
+        const DESCRIPTION: &'static str = "Echo text";
+
+        fn call(
+            &self,
+            input: Self::Input,
+            _ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            Box::pin(async move { Ok(EchoOut { text: input.text }) })
+        }
+    }
+
+    #[derive(Clone)]
+    struct Shout;
+
+    impl Tool for Shout {
+        type Input = EchoIn;
+        type Output = EchoOut;
+        const NAME: &'static str = "shout";
+        const DESCRIPTION: &'static str = "Echo text loudly";
+
+        fn call(
+            &self,
+            input: Self::Input,
+            _ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            Box::pin(async move {
+                Ok(EchoOut {
+                    text: input.text.to_uppercase(),
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn manifest_is_deterministic_and_per_tool() {
+        let a = ToolRegistry::builder()
+            .register::<Echo, ()>(Echo)
+            .register::<Shout, ()>(Shout)
+            .finish();
+        let b = ToolRegistry::builder()
+            .register::<Shout, ()>(Shout)
+            .register::<Echo, ()>(Echo)
+            .finish();
+
+        let ma = ToolManifest::from_registry(&a);
+        let mb = ToolManifest::from_registry(&b);
+        assert_eq!(ma, mb);
+        assert_eq!(ma.schema_version, REGISTRY_SCHEMA_VERSION);
+        assert_eq!(ma.tools.len(), 2);
+        assert_eq!(ma.registry_hash.len(), HASH_LEN);
+        // Same schemas, different description -> different hashes.
+        assert_ne!(ma.tools["echo"], ma.tools["shout"]);
+    }
+
+    #[test]
+    fn changed_tools_reports_differences_and_missing() {
+        let full = ToolManifest::from_registry(
+            &ToolRegistry::builder()
+                .register::<Echo, ()>(Echo)
+                .register::<Shout, ()>(Shout)
+                .finish(),
+        );
+        let mut other = ToolManifest::from_registry(
+            &ToolRegistry::builder().register::<Echo, ()>(Echo).finish(),
+        );
+        assert_ne!(full.registry_hash, other.registry_hash);
+        assert_eq!(full.changed_tools(&other), vec!["shout".to_string()]);
+
+        other.tools.insert("echo".into(), "0".repeat(HASH_LEN));
+        other.tools.insert("legacy".into(), "0".repeat(HASH_LEN));
+        assert_eq!(
+            full.changed_tools(&other),
+            vec![
+                "echo".to_string(),
+                "legacy".to_string(),
+                "shout".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn canonical_json_ignores_key_order() {
+        let a: Value =
+            serde_json::from_str(r#"{"b":1,"a":{"d":[1,{"y":2,"x":1}],"c":null}}"#).unwrap();
+        let b: Value =
+            serde_json::from_str(r#"{"a":{"c":null,"d":[1,{"x":1,"y":2}]},"b":1}"#).unwrap();
+        assert_eq!(hash_json(&a), hash_json(&b));
+    }
+
+    #[test]
+    fn check_schema_version_rejects_mismatch() {
+        let manifest = ToolManifest::from_registry(
+            &ToolRegistry::builder().register::<Echo, ()>(Echo).finish(),
+        );
+        assert!(
+            manifest
+                .check_schema_version(REGISTRY_SCHEMA_VERSION)
+                .is_ok()
+        );
+
+        let err = manifest
+            .check_schema_version(REGISTRY_SCHEMA_VERSION + 1)
+            .unwrap_err();
+        assert!(matches!(err, ToolError::InvalidInput(_)));
+        assert!(err.to_string().contains("schema version mismatch"));
+    }
+}
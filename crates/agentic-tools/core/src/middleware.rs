@@ -0,0 +1,49 @@
+//! Hooks that run around every JSON dispatch through a registry.
+//!
+//! Cross-cutting concerns (logging, auth checks, argument validation, rate
+//! limiting) are written once as a [`ToolMiddleware`] and installed on a
+//! [`ToolRegistry`](crate::ToolRegistry) with
+//! [`ToolRegistryBuilder::middleware`](crate::ToolRegistryBuilder::middleware) or
+//! [`ToolRegistry::with_middleware`](crate::ToolRegistry::with_middleware), instead
+//! of being repeated in each domain crate's tool implementations.
+//!
+//! Middleware nests like an onion: `before` hooks run in installation order, and
+//! once the call finishes the `after` hooks of every middleware whose `before`
+//! succeeded run in reverse order. A `before` hook that returns an error
+//! short-circuits the call; the tool does not run and the error is the result.
+//! Native calls through a [`ToolHandle`](crate::ToolHandle) bypass middleware.
+
+use crate::context::ToolContext;
+use crate::error::ToolError;
+use futures::future::BoxFuture;
+use serde_json::Value;
+
+/// Before/after hooks around a registry dispatch. Both hooks default to no-ops.
+pub trait ToolMiddleware: Send + Sync {
+    /// Runs before the tool is called, with the raw JSON arguments.
+    ///
+    /// May rewrite `args` (e.g., fill in defaults) or reject the call by
+    /// returning an error. Async so implementations can wait, e.g. for a rate
+    /// limiter slot.
+    fn before<'a>(
+        &'a self,
+        name: &'a str,
+        args: &'a mut Value,
+        ctx: &'a ToolContext,
+    ) -> BoxFuture<'a, Result<(), ToolError>> {
+        let _ = (name, args, ctx);
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Runs after the call finishes, with the arguments the tool received and
+    /// its JSON result or error.
+    fn after(
+        &self,
+        name: &str,
+        args: &Value,
+        ctx: &ToolContext,
+        result: Result<&Value, &ToolError>,
+    ) {
+        let _ = (name, args, ctx, result);
+    }
+}
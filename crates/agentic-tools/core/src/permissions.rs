@@ -0,0 +1,147 @@
+//! Capabilities tools declare and hosts grant.
+//!
+//! A tool declares what it needs to touch with [`Tool::PERMISSIONS`](crate::Tool::PERMISSIONS).
+//! Hosts grant permissions on the registry
+//! ([`ToolRegistryBuilder::permissions`](crate::ToolRegistryBuilder::permissions),
+//! [`ToolRegistry::with_permissions`](crate::ToolRegistry::with_permissions)) and per
+//! call ([`ToolContext::with_permissions`](crate::ToolContext::with_permissions)).
+//! JSON dispatch refuses a call with [`ToolError::PermissionDenied`](crate::ToolError::PermissionDenied)
+//! unless both grant everything the tool declares, before any middleware runs.
+//!
+//! Everything is granted by default, and tools that declare nothing always
+//! run. A host serving a registry read-only grants just [`Permissions::READ_FS`]:
+//!
+//! ```rust,ignore
+//! let registry = registry.with_permissions(Permissions::READ_FS);
+//! ```
+
+use serde::Serialize;
+use serde::ser::SerializeSeq;
+use std::fmt;
+use std::ops::BitAnd;
+use std::ops::BitOr;
+
+/// A set of capabilities.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Permissions(u8);
+
+impl Permissions {
+    /// No capabilities.
+    pub const NONE: Self = Self(0);
+    /// Read files and directories.
+    pub const READ_FS: Self = Self(1);
+    /// Create, modify, or delete files.
+    pub const WRITE_FS: Self = Self(1 << 1);
+    /// Make network requests.
+    pub const NETWORK: Self = Self(1 << 2);
+    /// Spawn processes.
+    pub const EXEC: Self = Self(1 << 3);
+    /// Every capability.
+    pub const ALL: Self = Self(0b1111);
+
+    const NAMES: [(Self, &'static str); 4] = [
+        (Self::READ_FS, "read_fs"),
+        (Self::WRITE_FS, "write_fs"),
+        (Self::NETWORK, "network"),
+        (Self::EXEC, "exec"),
+    ];
+
+    /// Capabilities in either set.
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Capabilities in both sets.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// Capabilities in `self` but not in `other`.
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Whether every capability in `other` is also in `self`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Names of the capabilities in the set, e.g. `["read_fs", "exec"]`.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Self::NAMES
+            .into_iter()
+            .filter(move |(flag, _)| self.contains(*flag))
+            .map(|(_, name)| name)
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for Permissions {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl fmt::Display for Permissions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("none");
+        }
+        f.write_str(&self.names().collect::<Vec<_>>().join(", "))
+    }
+}
+
+/// Serialized as the list of capability names.
+impl Serialize for Permissions {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+        for name in self.names() {
+            seq.serialize_element(name)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() {
+        let rw = Permissions::READ_FS | Permissions::WRITE_FS;
+        assert!(rw.contains(Permissions::READ_FS));
+        assert!(!rw.contains(Permissions::READ_FS | Permissions::EXEC));
+        assert!(rw.contains(Permissions::NONE));
+        assert_eq!(rw & Permissions::READ_FS, Permissions::READ_FS);
+        assert_eq!(rw.difference(Permissions::READ_FS), Permissions::WRITE_FS);
+        assert!(Permissions::ALL.contains(rw | Permissions::NETWORK | Permissions::EXEC));
+    }
+
+    #[test]
+    fn test_display_and_serialize() {
+        let p = Permissions::EXEC | Permissions::READ_FS;
+        assert_eq!(p.to_string(), "read_fs, exec");
+        assert_eq!(Permissions::NONE.to_string(), "none");
+        assert_eq!(
+            serde_json::to_value(p).unwrap(),
+            serde_json::json!(["read_fs", "exec"])
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! Per-tool dispatch limits: concurrency caps and minimum call spacing.
+//!
+//! Expensive tools (reasoning models, sub-agents) can be stampeded when a swarm
+//! of agents calls them in parallel. A [`ToolPolicy`] attached to a registry with
+//! [`ToolRegistryBuilder::policy`](crate::ToolRegistryBuilder::policy) or
+//! [`ToolRegistry::with_policy`](crate::ToolRegistry::with_policy) bounds how
+//! many calls to a tool run at once and how close together they may start.
+//!
+//! Limits are enforced around the tool call itself, after middleware, so calls
+//! rejected by validation never occupy a slot. Over-limit calls either wait
+//! their turn ([`LimitBehavior::Queue`]) or fail immediately with
+//! [`ToolError::RateLimited`] ([`LimitBehavior::Reject`]).
+
+use crate::error::ToolError;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+use tokio::time::Instant;
+
+/// What happens to a call that would exceed a tool's limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LimitBehavior {
+    /// Wait until the call is within limits.
+    #[default]
+    Queue,
+    /// Fail with [`ToolError::RateLimited`].
+    Reject,
+}
+
+/// Dispatch limits for a single tool. Unset limits are not enforced.
+#[derive(Debug, Clone, Default)]
+pub struct ToolPolicy {
+    /// Maximum number of calls to the tool running at the same time.
+    pub max_concurrent: Option<usize>,
+    /// Minimum time between the starts of consecutive calls.
+    pub min_interval: Option<Duration>,
+    /// How over-limit calls are handled.
+    pub on_limit: LimitBehavior,
+}
+
+impl ToolPolicy {
+    /// Create a policy with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow at most `n` calls to run at once.
+    #[must_use]
+    pub const fn max_concurrent(mut self, n: usize) -> Self {
+        self.max_concurrent = Some(n);
+        self
+    }
+
+    /// Start calls at least `interval` apart.
+    #[must_use]
+    pub const fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = Some(interval);
+        self
+    }
+
+    /// Reject over-limit calls instead of queueing them.
+    #[must_use]
+    pub const fn reject_when_limited(mut self) -> Self {
+        self.on_limit = LimitBehavior::Reject;
+        self
+    }
+}
+
+/// Shared enforcement state for one tool's policy.
+pub(crate) struct PolicyGate {
+    policy: ToolPolicy,
+    slots: Option<Arc<Semaphore>>,
+    next_start: Mutex<Option<Instant>>,
+}
+
+/// Held for the duration of a call; releases the concurrency slot on drop.
+pub(crate) struct PolicyPermit {
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+impl PolicyGate {
+    pub(crate) fn new(policy: ToolPolicy) -> Self {
+        Self {
+            slots: policy.max_concurrent.map(|n| Arc::new(Semaphore::new(n))),
+            policy,
+            next_start: Mutex::new(None),
+        }
+    }
+
+    /// Wait for (or refuse) a slot to call `name` under this policy.
+    pub(crate) async fn enter(&self, name: &str) -> Result<PolicyPermit, ToolError> {
+        let reject = self.policy.on_limit == LimitBehavior::Reject;
+
+        let slot = match &self.slots {
+            None => None,
+            Some(slots) if reject => Some(Arc::clone(slots).try_acquire_owned().map_err(|_| {
+                ToolError::rate_limited(format!(
+                    "{name} already has {} calls running",
+                    self.policy.max_concurrent.unwrap_or_default()
+                ))
+            })?),
+            Some(slots) => Some(
+                Arc::clone(slots)
+                    .acquire_owned()
+                    .await
+                    .map_err(|e| ToolError::internal(e.to_string()))?,
+            ),
+        };
+
+        if let Some(interval) = self.policy.min_interval {
+            let wait = self.reserve_start(name, interval, reject)?;
+            if !wait.is_zero() {
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        Ok(PolicyPermit { _slot: slot })
+    }
+
+    /// Claim the next start time, returning how long to wait for it.
+    fn reserve_start(
+        &self,
+        name: &str,
+        interval: Duration,
+        reject: bool,
+    ) -> Result<Duration, ToolError> {
+        let now = Instant::now();
+        let mut next_start = self
+            .next_start
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let start = match *next_start {
+            Some(next) if next > now && reject => {
+                return Err(ToolError::rate_limited(format!(
+                    "{name} may be called again in {}ms",
+                    (next - now).as_millis()
+                )));
+            }
+            Some(next) => next.max(now),
+            None => now,
+        };
+        *next_start = Some(start + interval);
+        Ok(start - now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reject_when_concurrency_exhausted() {
+        let gate = PolicyGate::new(ToolPolicy::new().max_concurrent(1).reject_when_limited());
+
+        let held = gate.enter("slow").await.unwrap();
+        let err = gate.enter("slow").await.err().unwrap();
+        assert!(matches!(err, ToolError::RateLimited(_)));
+        assert!(err.to_string().contains("slow already has 1 calls running"));
+
+        drop(held);
+        assert!(gate.enter("slow").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_queue_waits_for_free_slot() {
+        let gate = Arc::new(PolicyGate::new(ToolPolicy::new().max_concurrent(1)));
+        let held = gate.enter("slow").await.unwrap();
+
+        let waiter = tokio::spawn({
+            let gate = Arc::clone(&gate);
+            async move { gate.enter("slow").await.map(|_| ()) }
+        });
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished());
+
+        drop(held);
+        assert!(waiter.await.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_min_interval_spaces_starts() {
+        let gate = PolicyGate::new(ToolPolicy::new().min_interval(Duration::from_millis(20)));
+
+        let started = Instant::now();
+        for _ in 0..3 {
+            gate.enter("spaced").await.unwrap();
+        }
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn test_min_interval_reject() {
+        let gate = PolicyGate::new(
+            ToolPolicy::new()
+                .min_interval(Duration::from_secs(60))
+                .reject_when_limited(),
+        );
+
+        assert!(gate.enter("spaced").await.is_ok());
+        let err = gate.enter("spaced").await.err().unwrap();
+        assert!(matches!(err, ToolError::RateLimited(_)));
+    }
+}
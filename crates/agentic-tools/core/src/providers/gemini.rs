@@ -0,0 +1,295 @@
+//! Google Gemini function calling schema renderer.
+//!
+//! Gemini accepts an `OpenAPI` 3.0 subset of JSON Schema for function
+//! parameters, so schemars output is downgraded before rendering:
+//! - `$ref`s are inlined from `$defs` (recursive references become a bare `object`)
+//! - `type: ["T", "null"]` and `anyOf` with a `null` branch become `nullable: true`
+//! - `oneOf` becomes `anyOf`; `const` and all-`const` unions become string `enum`s
+//! - Keywords and `format`s Gemini rejects are dropped
+
+use schemars::Schema;
+use serde_json::Map;
+use serde_json::Value;
+
+/// Schema keywords Gemini's `Schema` object accepts.
+const SUPPORTED_KEYWORDS: &[&str] = &[
+    "type",
+    "format",
+    "title",
+    "description",
+    "nullable",
+    "enum",
+    "properties",
+    "required",
+    "propertyOrdering",
+    "items",
+    "minItems",
+    "maxItems",
+    "minProperties",
+    "maxProperties",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "minimum",
+    "maximum",
+    "default",
+    "example",
+    "anyOf",
+];
+
+/// Nesting depth at which `$ref`s stop being inlined, to bound recursive types.
+const MAX_REF_DEPTH: usize = 8;
+
+/// Render a tool as a Gemini function declaration.
+///
+/// Output format (one entry of `tools[].functionDeclarations`):
+/// ```json
+/// {
+///   "name": "...",
+///   "description": "...",
+///   "parameters": { ... }  // omitted when the tool takes no arguments
+/// }
+/// ```
+pub fn render_tool(name: &str, description: &str, input_schema: &Schema) -> Value {
+    let schema = match serde_json::to_value(input_schema) {
+        Ok(value) => value,
+        Err(error) => panic!("Schema serialization must succeed: {error}"),
+    };
+    let defs = schema.get("$defs").cloned().unwrap_or(Value::Null);
+    let parameters = downgrade(&schema, &defs, 0);
+
+    let mut obj = Map::from_iter([
+        ("name".into(), Value::String(name.to_string())),
+        ("description".into(), Value::String(description.to_string())),
+    ]);
+    let has_properties = parameters
+        .get("properties")
+        .and_then(Value::as_object)
+        .is_some_and(|props| !props.is_empty());
+    if has_properties {
+        obj.insert("parameters".into(), parameters);
+    }
+    Value::Object(obj)
+}
+
+/// Convert one JSON Schema node into Gemini's schema subset.
+fn downgrade(node: &Value, defs: &Value, depth: usize) -> Value {
+    let Some(obj) = node.as_object() else {
+        // `true`/`false` schemas: accept anything.
+        return Value::Object(Map::new());
+    };
+
+    if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+        let target = reference
+            .strip_prefix("#/$defs/")
+            .and_then(|name| defs.get(name));
+        let mut resolved = match target {
+            Some(target) if depth < MAX_REF_DEPTH => downgrade(target, defs, depth + 1),
+            _ => serde_json::json!({ "type": "object" }),
+        };
+        // Sibling keywords (usually `description`) override the referenced schema.
+        if let (Some(out), Value::Object(siblings)) = (
+            resolved.as_object_mut(),
+            downgrade_keywords(obj, defs, depth),
+        ) {
+            out.extend(siblings);
+        }
+        return resolved;
+    }
+
+    downgrade_keywords(obj, defs, depth)
+}
+
+fn downgrade_keywords(obj: &Map<String, Value>, defs: &Value, depth: usize) -> Value {
+    let mut out = Map::new();
+
+    for (key, value) in obj {
+        match key.as_str() {
+            "type" => match value {
+                Value::Array(types) => {
+                    let is_null = |t: &&Value| t.as_str() == Some("null");
+                    if let Some(first) = types.iter().find(|t| !is_null(t)) {
+                        out.insert("type".into(), first.clone());
+                    }
+                    if types.iter().any(|t| is_null(&t)) {
+                        out.insert("nullable".into(), Value::Bool(true));
+                    }
+                }
+                other => {
+                    out.insert("type".into(), other.clone());
+                }
+            },
+            "properties" => {
+                let props = value
+                    .as_object()
+                    .map(|props| {
+                        props
+                            .iter()
+                            .map(|(name, schema)| (name.clone(), downgrade(schema, defs, depth)))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                out.insert("properties".into(), Value::Object(props));
+            }
+            "items" => {
+                out.insert("items".into(), downgrade(value, defs, depth));
+            }
+            "anyOf" | "oneOf" => {
+                merge_union(
+                    &mut out,
+                    value.as_array().map_or(&[][..], Vec::as_slice),
+                    defs,
+                    depth,
+                );
+            }
+            "const" => {
+                if let Some(s) = value.as_str() {
+                    out.insert("type".into(), Value::String("string".into()));
+                    out.insert("enum".into(), serde_json::json!([s]));
+                }
+            }
+            "enum" => {
+                // Gemini only supports string enums.
+                let values = value.as_array().map_or(&[][..], Vec::as_slice);
+                if values.iter().all(Value::is_string) {
+                    out.insert("enum".into(), value.clone());
+                }
+            }
+            "format" => {
+                if value.as_str().is_some_and(|f| {
+                    matches!(
+                        f,
+                        "date-time" | "enum" | "int32" | "int64" | "float" | "double"
+                    )
+                }) {
+                    out.insert("format".into(), value.clone());
+                }
+            }
+            key if SUPPORTED_KEYWORDS.contains(&key) => {
+                out.insert(key.to_string(), value.clone());
+            }
+            _ => {}
+        }
+    }
+
+    Value::Object(out)
+}
+
+/// Fold an `anyOf`/`oneOf` into `out`: null branches become `nullable`, a
+/// single remaining branch is merged in place, and all-`const` string unions
+/// become an `enum`.
+fn merge_union(out: &mut Map<String, Value>, branches: &[Value], defs: &Value, depth: usize) {
+    let is_null = |b: &Value| b.get("type").and_then(Value::as_str) == Some("null");
+    if branches.iter().any(is_null) {
+        out.insert("nullable".into(), Value::Bool(true));
+    }
+    let rest: Vec<&Value> = branches.iter().filter(|b| !is_null(b)).collect();
+
+    let consts: Option<Vec<Value>> = rest
+        .iter()
+        .map(|b| b.get("const").filter(|c| c.is_string()).cloned())
+        .collect();
+    if let Some(consts) = consts.filter(|c| !c.is_empty()) {
+        out.insert("type".into(), Value::String("string".into()));
+        out.insert("enum".into(), Value::Array(consts));
+        return;
+    }
+
+    match rest.as_slice() {
+        [] => {}
+        [single] => {
+            if let Value::Object(inner) = downgrade(single, defs, depth) {
+                for (key, value) in inner {
+                    out.entry(key).or_insert(value);
+                }
+            }
+        }
+        many => {
+            let branches = many.iter().map(|b| downgrade(b, defs, depth)).collect();
+            out.insert("anyOf".into(), Value::Array(branches));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(schemars::JsonSchema)]
+    #[expect(dead_code)]
+    enum Mode {
+        Files,
+        Content,
+    }
+
+    #[derive(schemars::JsonSchema)]
+    #[expect(dead_code)]
+    struct Nested {
+        /// Result cap
+        limit: Option<u32>,
+    }
+
+    #[derive(schemars::JsonSchema)]
+    #[expect(dead_code)]
+    struct TestInput {
+        query: String,
+        mode: Option<Mode>,
+        nested: Nested,
+        tags: Vec<String>,
+    }
+
+    #[derive(schemars::JsonSchema)]
+    struct Empty {}
+
+    #[test]
+    fn test_render_tool() {
+        let schema = schemars::schema_for!(TestInput);
+        let rendered = render_tool("search", "Search for something", &schema);
+
+        assert_eq!(rendered["name"], "search");
+        assert_eq!(rendered["description"], "Search for something");
+        let params = &rendered["parameters"];
+        assert_eq!(params["type"], "object");
+        assert_eq!(
+            params["required"],
+            serde_json::json!(["query", "nested", "tags"])
+        );
+        assert!(params.get("$schema").is_none());
+        assert!(params.get("$defs").is_none());
+        assert!(params.get("title").is_some());
+    }
+
+    #[test]
+    fn test_downgrades_unsupported_constructs() {
+        let schema = schemars::schema_for!(TestInput);
+        let params = render_tool("search", "", &schema)["parameters"].clone();
+        let props = &params["properties"];
+
+        assert_eq!(
+            props["mode"],
+            serde_json::json!({
+                "type": "string",
+                "enum": ["Files", "Content"],
+                "nullable": true
+            })
+        );
+        assert_eq!(props["nested"]["type"], "object");
+        assert_eq!(
+            props["nested"]["properties"]["limit"],
+            serde_json::json!({
+                "type": "integer",
+                "nullable": true,
+                "minimum": 0,
+                "description": "Result cap"
+            })
+        );
+        assert_eq!(props["tags"]["items"]["type"], "string");
+    }
+
+    #[test]
+    fn test_no_arguments_omits_parameters() {
+        let schema = schemars::schema_for!(Empty);
+        let rendered = render_tool("ping", "Ping", &schema);
+        assert!(rendered.get("parameters").is_none());
+    }
+}
@@ -4,7 +4,61 @@
 //! - `OpenAI`: `{"type": "function", "function": {...}}`
 //! - Anthropic: Direct object with `input_schema` field
 //! - MCP: Direct object with `inputSchema`/`outputSchema`
+//! - Gemini: Function declaration with an `OpenAPI`-subset `parameters` schema
+//!
+//! [`typescript`] renders a whole registry as `.d.ts` declarations for JS hosts.
+//!
+//! [`Provider`] names the targets, so schema transforms can be scoped to one
+//! (see [`SchemaEngine::constrain_field_for`](crate::SchemaEngine::constrain_field_for)).
+
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
 
 pub mod anthropic;
+pub mod gemini;
 pub mod mcp;
 pub mod openai;
+pub mod typescript;
+
+/// A tool-calling provider whose schema format a registry can render.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    OpenAi,
+    Anthropic,
+    Gemini,
+    Mcp,
+}
+
+impl Provider {
+    /// Lowercase name used by bindings' `list_tools(provider)`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OpenAi => "openai",
+            Self::Anthropic => "anthropic",
+            Self::Gemini => "gemini",
+            Self::Mcp => "mcp",
+        }
+    }
+}
+
+impl fmt::Display for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Provider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "openai" => Ok(Self::OpenAi),
+            "anthropic" => Ok(Self::Anthropic),
+            "gemini" => Ok(Self::Gemini),
+            "mcp" => Ok(Self::Mcp),
+            other => Err(format!("Unknown provider: {other}")),
+        }
+    }
+}
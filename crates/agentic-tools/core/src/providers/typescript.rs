@@ -0,0 +1,493 @@
+//! TypeScript declaration renderer.
+//!
+//! Turns tool input/output JSON Schemas into a `.d.ts` module so TypeScript
+//! hosts get compile-time checking of tool arguments. Each tool becomes a
+//! namespace holding `Input`, `Output`, and the schema's `$defs`; the
+//! `ToolInputs`/`ToolOutputs` maps and a generic `callToolTyped` declaration
+//! tie tool names to those types.
+
+use schemars::Schema;
+use serde_json::Map;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A tool to include in [`render_declarations`].
+#[derive(Clone, Copy, Debug)]
+pub struct TypedTool<'a> {
+    pub name: &'a str,
+    pub description: &'a str,
+    pub input_schema: &'a Schema,
+    pub output_schema: Option<&'a Schema>,
+}
+
+/// Render a `.d.ts` module declaring types for `tools`, in the given order.
+///
+/// Output shape:
+/// ```typescript
+/// export namespace CliLs {
+///   export type Input = { path?: string | null; ... };
+///   export type Output = { entries: LsEntry[]; ... };
+///   export type LsEntry = { ... };
+/// }
+/// export interface ToolInputs { cli_ls: CliLs.Input; }
+/// export interface ToolOutputs { cli_ls: CliLs.Output; }
+/// export function callToolTyped<N extends keyof ToolInputs>(
+///   name: N,
+///   input: ToolInputs[N]
+/// ): Promise<ToolOutputs[N]>;
+/// ```
+pub fn render_declarations(tools: &[TypedTool<'_>]) -> String {
+    let mut out =
+        String::from("// Generated from the tool registry's JSON Schemas. Do not edit by hand.\n");
+    let mut entries = Vec::with_capacity(tools.len());
+
+    for tool in tools {
+        let ns = namespace_name(tool.name);
+        let input = schema_value(tool.input_schema);
+        let output = tool.output_schema.map(schema_value);
+
+        // Input and output schemas carry their own `$defs`; share a name when the
+        // definitions agree and prefix the output one when they differ.
+        let mut defs = BTreeMap::new();
+        let input_refs = collect_defs(&input, Side::Input, &mut defs);
+        let output_refs = output
+            .as_ref()
+            .map(|o| collect_defs(o, Side::Output, &mut defs))
+            .unwrap_or_default();
+
+        out.push('\n');
+        write_doc(&mut out, tool.description, 0);
+        let _ = writeln!(out, "export namespace {ns} {{");
+        write_doc(&mut out, description(&input), 1);
+        let _ = writeln!(
+            out,
+            "  export type Input = {};",
+            ts_type(&input, &input_refs, 1)
+        );
+        match &output {
+            Some(o) => {
+                write_doc(&mut out, description(o), 1);
+                let _ = writeln!(
+                    out,
+                    "  export type Output = {};",
+                    ts_type(o, &output_refs, 1)
+                );
+            }
+            None => out.push_str("  export type Output = unknown;\n"),
+        }
+        for (name, (def, side)) in &defs {
+            let refs = match side {
+                Side::Input => &input_refs,
+                Side::Output => &output_refs,
+            };
+            write_doc(&mut out, description(def), 1);
+            let _ = writeln!(out, "  export type {name} = {};", ts_type(def, refs, 1));
+        }
+        out.push_str("}\n");
+        entries.push((property_key(tool.name), ns));
+    }
+
+    for (map, member) in [("ToolInputs", "Input"), ("ToolOutputs", "Output")] {
+        let _ = write!(out, "\nexport interface {map} {{\n");
+        for (key, ns) in &entries {
+            let _ = writeln!(out, "  {key}: {ns}.{member};");
+        }
+        out.push_str("}\n");
+    }
+
+    out.push_str(
+        "\n/** Call a tool with typed input; resolves with its parsed structured output. */\n\
+         export function callToolTyped<N extends keyof ToolInputs>(\n  \
+         name: N,\n  \
+         input: ToolInputs[N]\n\
+         ): Promise<ToolOutputs[N]>;\n",
+    );
+    out
+}
+
+fn schema_value(schema: &Schema) -> Value {
+    match serde_json::to_value(schema) {
+        Ok(value) => value,
+        Err(error) => panic!("Schema serialization must succeed: {error}"),
+    }
+}
+
+/// Which schema of a tool a definition came from, so its own `$ref`s resolve
+/// against the right names.
+#[derive(Clone, Copy)]
+enum Side {
+    Input,
+    Output,
+}
+
+/// Register `schema`'s `$defs` in `defs` and return the type name for each
+/// definition key.
+fn collect_defs(
+    schema: &Value,
+    side: Side,
+    defs: &mut BTreeMap<String, (Value, Side)>,
+) -> HashMap<String, String> {
+    let mut refs = HashMap::new();
+    let Some(own) = schema.get("$defs").and_then(Value::as_object) else {
+        return refs;
+    };
+    for (key, def) in own {
+        let mut name = identifier(key);
+        if matches!(name.as_str(), "Input" | "Output") {
+            name.push('_');
+        }
+        if defs.get(&name).is_some_and(|(existing, _)| existing != def) {
+            name = format!("Output{name}");
+        }
+        defs.entry(name.clone())
+            .or_insert_with(|| (def.clone(), side));
+        refs.insert(key.clone(), name);
+    }
+    refs
+}
+
+/// Render the TypeScript type for a schema. `indent` is the nesting level of
+/// the line the type starts on.
+fn ts_type(schema: &Value, refs: &HashMap<String, String>, indent: usize) -> String {
+    let obj = match schema {
+        Value::Object(obj) => obj,
+        Value::Bool(false) => return "never".into(),
+        _ => return "unknown".into(),
+    };
+
+    if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+        let key = reference.rsplit('/').next().unwrap_or(reference);
+        return refs.get(key).cloned().unwrap_or_else(|| "unknown".into());
+    }
+    if let Some(value) = obj.get("const") {
+        return literal(value);
+    }
+    if let Some(values) = obj.get("enum").and_then(Value::as_array) {
+        return union(values.iter().map(literal).collect());
+    }
+
+    let mut parts = Vec::new();
+    match obj.get("type") {
+        Some(Value::String(ty)) => parts.push(primitive(ty, obj, refs, indent)),
+        Some(Value::Array(types)) => parts.push(union(
+            types
+                .iter()
+                .filter_map(Value::as_str)
+                .map(|ty| primitive(ty, obj, refs, indent))
+                .collect(),
+        )),
+        _ if obj.contains_key("properties") => parts.push(primitive("object", obj, refs, indent)),
+        _ => {}
+    }
+    for keyword in ["anyOf", "oneOf"] {
+        if let Some(variants) = obj.get(keyword).and_then(Value::as_array) {
+            parts.push(union(
+                variants.iter().map(|v| ts_type(v, refs, indent)).collect(),
+            ));
+        }
+    }
+    if let Some(all) = obj.get("allOf").and_then(Value::as_array) {
+        parts.extend(all.iter().map(|v| ts_type(v, refs, indent)));
+    }
+
+    match parts.len() {
+        0 => "unknown".into(),
+        1 => parts.remove(0),
+        _ => parts
+            .iter()
+            .map(|p| {
+                if p.contains(" | ") {
+                    format!("({p})")
+                } else {
+                    p.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" & "),
+    }
+}
+
+fn primitive(
+    ty: &str,
+    obj: &Map<String, Value>,
+    refs: &HashMap<String, String>,
+    indent: usize,
+) -> String {
+    match ty {
+        "string" => "string".into(),
+        "integer" | "number" => "number".into(),
+        "boolean" => "boolean".into(),
+        "null" => "null".into(),
+        "array" => {
+            let item = obj
+                .get("items")
+                .map_or_else(|| "unknown".into(), |items| ts_type(items, refs, indent));
+            if item.contains(' ') && !item.starts_with('{') {
+                format!("Array<{item}>")
+            } else {
+                format!("{item}[]")
+            }
+        }
+        "object" => object_type(obj, refs, indent),
+        _ => "unknown".into(),
+    }
+}
+
+fn object_type(obj: &Map<String, Value>, refs: &HashMap<String, String>, indent: usize) -> String {
+    let extra = match obj.get("additionalProperties") {
+        None | Some(Value::Bool(true)) => Some("unknown".to_string()),
+        Some(Value::Bool(false)) => None,
+        Some(schema) => Some(ts_type(schema, refs, indent + 1)),
+    };
+    let properties = obj
+        .get("properties")
+        .and_then(Value::as_object)
+        .filter(|p| !p.is_empty());
+    let Some(properties) = properties else {
+        return format!("Record<string, {}>", extra.as_deref().unwrap_or("never"));
+    };
+
+    let required: Vec<&str> = obj
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default();
+    let pad = "  ".repeat(indent + 1);
+    let mut out = String::from("{\n");
+    for (key, prop) in properties {
+        write_doc(&mut out, description(prop), indent + 1);
+        let optional = if required.contains(&key.as_str()) {
+            ""
+        } else {
+            "?"
+        };
+        let _ = writeln!(
+            out,
+            "{pad}{}{optional}: {};",
+            property_key(key),
+            ts_type(prop, refs, indent + 1)
+        );
+    }
+    // Only explicit `additionalProperties` schemas get an index signature;
+    // open objects with known properties stay closed for better checking.
+    if let Some(Value::Object(_)) = obj.get("additionalProperties") {
+        let _ = writeln!(out, "{pad}[key: string]: {};", extra.unwrap_or_default());
+    }
+    let _ = write!(out, "{}}}", "  ".repeat(indent));
+    out
+}
+
+fn union(mut members: Vec<String>) -> String {
+    let mut seen = Vec::with_capacity(members.len());
+    members.retain(|m| {
+        let fresh = !seen.contains(m);
+        if fresh {
+            seen.push(m.clone());
+        }
+        fresh
+    });
+    match members.len() {
+        0 => "never".into(),
+        _ => members.join(" | "),
+    }
+}
+
+fn literal(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "unknown".into())
+}
+
+fn description(schema: &Value) -> &str {
+    schema
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+}
+
+fn write_doc(out: &mut String, text: &str, indent: usize) {
+    let text = text.trim();
+    if text.is_empty() {
+        return;
+    }
+    let pad = "  ".repeat(indent);
+    let text = text.replace("*/", "*\\/");
+    let mut lines = text.lines();
+    if let (Some(only), None) = (lines.next(), lines.next()) {
+        let _ = writeln!(out, "{pad}/** {only} */");
+    } else {
+        let _ = writeln!(out, "{pad}/**");
+        for line in text.lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                let _ = writeln!(out, "{pad} *");
+            } else {
+                let _ = writeln!(out, "{pad} * {line}");
+            }
+        }
+        let _ = writeln!(out, "{pad} */");
+    }
+}
+
+/// `cli_ls` -> `CliLs`.
+fn namespace_name(tool: &str) -> String {
+    let pascal: String = tool
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect();
+    identifier(&pascal)
+}
+
+/// Replace characters TypeScript does not allow in identifiers.
+fn identifier(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+fn property_key(key: &str) -> String {
+    if identifier(key) == key {
+        key.to_string()
+    } else {
+        literal(&Value::String(key.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(schemars::JsonSchema)]
+    #[expect(dead_code)]
+    enum Show {
+        All,
+        Files,
+    }
+
+    /// Input for the test tool.
+    #[derive(schemars::JsonSchema)]
+    #[expect(dead_code)]
+    struct TestInput {
+        /// Directory to list
+        path: String,
+        depth: Option<u8>,
+        show: Option<Show>,
+        ignore: Vec<String>,
+    }
+
+    #[derive(schemars::JsonSchema)]
+    #[expect(dead_code)]
+    struct TestOutput {
+        entries: Vec<String>,
+        show: Show,
+    }
+
+    #[test]
+    fn renders_namespace_per_tool_and_name_maps() {
+        let input = schemars::schema_for!(TestInput);
+        let output = schemars::schema_for!(TestOutput);
+        let rendered = render_declarations(&[TypedTool {
+            name: "cli_ls",
+            description: "List files",
+            input_schema: &input,
+            output_schema: Some(&output),
+        }]);
+
+        assert!(rendered.contains("/** List files */\nexport namespace CliLs {"));
+        assert!(rendered.contains("  /** Input for the test tool. */\n  export type Input = {"));
+        assert!(rendered.contains("    /** Directory to list */\n    path: string;"));
+        assert!(rendered.contains("    depth?: number | null;"));
+        assert!(rendered.contains("    show?: Show | null;"));
+        assert!(rendered.contains("    ignore: string[];"));
+        assert!(rendered.contains("    entries: string[];\n    show: Show;"));
+        // `Show` appears in both schemas with the same definition: emitted once.
+        assert_eq!(
+            rendered
+                .matches("export type Show = \"All\" | \"Files\";")
+                .count(),
+            1
+        );
+        assert!(rendered.contains("export interface ToolInputs {\n  cli_ls: CliLs.Input;\n}"));
+        assert!(rendered.contains("export interface ToolOutputs {\n  cli_ls: CliLs.Output;\n}"));
+        assert!(rendered.contains("export function callToolTyped<N extends keyof ToolInputs>("));
+    }
+
+    #[test]
+    fn missing_output_schema_is_unknown() {
+        let input = schemars::schema_for!(TestInput);
+        let rendered = render_declarations(&[TypedTool {
+            name: "ask-model",
+            description: "",
+            input_schema: &input,
+            output_schema: None,
+        }]);
+
+        assert!(rendered.contains("export namespace AskModel {"));
+        assert!(rendered.contains("  export type Output = unknown;"));
+        assert!(rendered.contains("  \"ask-model\": AskModel.Input;"));
+    }
+
+    #[test]
+    fn conflicting_defs_get_output_prefix() {
+        let input = Schema::try_from(serde_json::json!({
+            "type": "object",
+            "properties": { "mode": { "$ref": "#/$defs/Mode" } },
+            "required": ["mode"],
+            "$defs": { "Mode": { "enum": ["a", "b"] } }
+        }))
+        .unwrap();
+        let output = Schema::try_from(serde_json::json!({
+            "type": "object",
+            "properties": { "mode": { "$ref": "#/$defs/Mode" } },
+            "required": ["mode"],
+            "$defs": { "Mode": { "type": "integer" } }
+        }))
+        .unwrap();
+        let rendered = render_declarations(&[TypedTool {
+            name: "t",
+            description: "",
+            input_schema: &input,
+            output_schema: Some(&output),
+        }]);
+
+        assert!(rendered.contains("export type Mode = \"a\" | \"b\";"));
+        assert!(rendered.contains("export type OutputMode = number;"));
+        assert!(rendered.contains("export type Output = {\n    mode: OutputMode;\n  };"));
+    }
+
+    #[test]
+    fn object_edge_cases() {
+        let refs = HashMap::new();
+        let map =
+            serde_json::json!({"type": "object", "additionalProperties": {"type": "integer"}});
+        assert_eq!(ts_type(&map, &refs, 0), "Record<string, number>");
+        let open = serde_json::json!({"type": "object"});
+        assert_eq!(ts_type(&open, &refs, 0), "Record<string, unknown>");
+        let tagged = serde_json::json!({"oneOf": [
+            {"type": "object", "properties": {"kind": {"const": "a"}}, "required": ["kind"]},
+            {"type": "string"}
+        ]});
+        assert_eq!(ts_type(&tagged, &refs, 0), "{\n  kind: \"a\";\n} | string");
+        let nullable_union =
+            serde_json::json!({"type": "array", "items": {"type": ["string", "null"]}});
+        assert_eq!(ts_type(&nullable_union, &refs, 0), "Array<string | null>");
+        assert_eq!(property_key("my-key"), "\"my-key\"");
+    }
+}
@@ -1,16 +1,30 @@
 //! Tool registry for dynamic dispatch and type-safe native calls.
 
+use crate::cache::CacheSlot;
 use crate::context::ToolContext;
 use crate::error::ToolError;
 use crate::fmt::TextFormat;
 use crate::fmt::TextOptions;
+use crate::middleware::ToolMiddleware;
+use crate::permissions::Permissions;
+use crate::policy::PolicyGate;
+use crate::policy::ToolPolicy;
+use crate::schema::SchemaEngine;
 use crate::schema::mcp_schema;
+use crate::streaming::JsonToolStream;
+use crate::streaming::StreamItem;
+use crate::streaming::StreamingTool;
+use crate::tool::Deprecation;
 use crate::tool::Tool;
 use crate::tool::ToolCodec;
+use crate::validation::InputValidator;
+use futures::StreamExt;
 use futures::future::BoxFuture;
 use schemars::Schema;
+use serde::Serialize;
 use serde_json::Value;
 use std::any::TypeId;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::marker::PhantomData;
@@ -26,6 +40,23 @@ pub struct FormattedResult {
     pub text: Option<String>,
 }
 
+/// A tool's name and descriptive metadata, as returned by
+/// [`ToolRegistry::list_names_with_meta`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolMeta {
+    /// Registered name.
+    pub name: String,
+    /// Description as written by the tool, without a deprecation notice.
+    pub description: &'static str,
+    /// Contract version, if the tool declares one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<&'static str>,
+    /// Deprecation details, if the tool is deprecated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<Deprecation>,
+}
+
 /// Type-erased tool for dynamic dispatch.
 pub trait ErasedTool: Send + Sync {
     /// Get the tool's name.
@@ -40,6 +71,32 @@ pub trait ErasedTool: Send + Sync {
     /// Get the output JSON schema (if available).
     fn output_schema(&self) -> Option<Schema>;
 
+    /// Capabilities the tool needs to run.
+    fn permissions(&self) -> Permissions {
+        Permissions::NONE
+    }
+
+    /// Version of the tool's contract, if declared.
+    fn version(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Deprecation details, if the tool is deprecated.
+    fn deprecation(&self) -> Option<Deprecation> {
+        None
+    }
+
+    /// Description to show models: the tool's description followed by its
+    /// deprecation notice, if any. Provider renderers should be given this.
+    fn provider_description(&self) -> Cow<'static, str> {
+        match self.deprecation() {
+            Some(deprecation) => {
+                Cow::Owned(format!("{} {}", self.description(), deprecation.notice()))
+            }
+            None => Cow::Borrowed(self.description()),
+        }
+    }
+
     /// Call the tool with JSON arguments.
     fn call_json(
         &self,
@@ -59,14 +116,34 @@ pub trait ErasedTool: Send + Sync {
         text_opts: &TextOptions,
     ) -> BoxFuture<'static, Result<FormattedResult, ToolError>>;
 
+    /// Call the tool with JSON arguments, streaming chunks before the final result.
+    ///
+    /// Tools that do not stream yield only the final result.
+    fn call_json_stream(&self, args: Value, ctx: &ToolContext) -> JsonToolStream {
+        let call = self.call_json(args, ctx);
+        futures::stream::once(async move { call.await.map(StreamItem::Final) }).boxed()
+    }
+
     /// Get the `TypeId` for type-safe handle retrieval.
     fn type_id(&self) -> TypeId;
 }
 
+/// Separator between namespace and tool name in
+/// [`ToolRegistry::merge_all_namespaced`], e.g. `gh__create_pr`.
+pub const NAMESPACE_SEPARATOR: &str = "__";
+
 /// Registry of tools for dynamic dispatch and type-safe native calls.
+///
+/// Tools are listed under their registered name, which is the tool's `NAME`
+/// unless the registry renamed or namespaced it. Aliases are extra names that
+/// resolve to a listed tool for lookup and dispatch but are not listed.
 pub struct ToolRegistry {
     map: HashMap<String, Arc<dyn ErasedTool>>,
     by_type: HashMap<TypeId, String>,
+    aliases: HashMap<String, String>,
+    middleware: Vec<Arc<dyn ToolMiddleware>>,
+    policies: HashMap<String, Arc<PolicyGate>>,
+    grants: Permissions,
 }
 
 impl ToolRegistry {
@@ -80,17 +157,137 @@ impl ToolRegistry {
         self.map.keys().cloned().collect()
     }
 
-    /// Get a tool by name.
+    /// List registered tools with their version and deprecation metadata,
+    /// sorted by name.
+    pub fn list_names_with_meta(&self) -> Vec<ToolMeta> {
+        let mut meta: Vec<ToolMeta> = self
+            .iter_named()
+            .map(|(name, tool)| ToolMeta {
+                name: name.to_string(),
+                description: tool.description(),
+                version: tool.version(),
+                deprecated: tool.deprecation(),
+            })
+            .collect();
+        meta.sort_by(|a, b| a.name.cmp(&b.name));
+        meta
+    }
+
+    /// Get a tool by name or alias.
     pub fn get(&self, name: &str) -> Option<&Arc<dyn ErasedTool>> {
-        self.map.get(name)
+        self.map.get(self.resolve(name)?)
+    }
+
+    /// Registered name that `name` refers to, following aliases.
+    ///
+    /// Returns `None` if neither a tool nor an alias has that name.
+    pub fn resolve<'a>(&'a self, name: &'a str) -> Option<&'a str> {
+        if self.map.contains_key(name) {
+            return Some(name);
+        }
+        self.aliases
+            .get(name)
+            .map(String::as_str)
+            .filter(|target| self.map.contains_key(*target))
+    }
+
+    /// Registered names paired with their tools.
+    pub fn iter_named(&self) -> impl Iterator<Item = (&str, &Arc<dyn ErasedTool>)> {
+        self.map.iter().map(|(name, tool)| (name.as_str(), tool))
+    }
+
+    /// Make `alias` resolve to the tool registered as `target`.
+    ///
+    /// Aliases are not listed. An alias that shadows a registered name is ignored.
+    #[must_use]
+    pub fn with_alias(mut self, alias: impl Into<String>, target: impl Into<String>) -> Self {
+        self.aliases.insert(alias.into(), target.into());
+        self
+    }
+
+    /// List the tool registered as `from` under `to` instead.
+    ///
+    /// `from` stays usable as an alias, so existing callers keep working. Does
+    /// nothing if no tool is registered as `from`.
+    #[must_use]
+    pub fn renamed(mut self, from: &str, to: impl Into<String>) -> Self {
+        let Some(tool) = self.map.remove(from) else {
+            return self;
+        };
+        let to = to.into();
+        for name in self.by_type.values_mut() {
+            if name == from {
+                name.clone_from(&to);
+            }
+        }
+        if let Some(gate) = self.policies.remove(from) {
+            self.policies.insert(to.clone(), gate);
+        }
+        for target in self.aliases.values_mut() {
+            if target == from {
+                target.clone_from(&to);
+            }
+        }
+        self.aliases.remove(&to);
+        self.aliases.insert(from.to_string(), to.clone());
+        self.map.insert(to, tool);
+        self
+    }
+
+    /// Install middleware on an already-built registry, after any installed earlier.
+    ///
+    /// See [`crate::middleware`] for ordering and short-circuit semantics.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: impl ToolMiddleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Limit dispatches of the named tool, replacing any policy it already has.
+    ///
+    /// See [`crate::policy`] for how limits are enforced.
+    #[must_use]
+    pub fn with_policy(mut self, name: impl Into<String>, policy: ToolPolicy) -> Self {
+        self.policies
+            .insert(name.into(), Arc::new(PolicyGate::new(policy)));
+        self
+    }
+
+    /// Grant only `permissions` to tools dispatched through this registry.
+    ///
+    /// Calls are refused unless both the registry and the call's context grant
+    /// every capability the tool declares. See [`crate::permissions`].
+    #[must_use]
+    pub const fn with_permissions(mut self, permissions: Permissions) -> Self {
+        self.grants = permissions;
+        self
+    }
+
+    /// Capabilities this registry grants to the tools it dispatches.
+    pub const fn permissions(&self) -> Permissions {
+        self.grants
+    }
+
+    /// Validate JSON arguments against each tool's input schema, as transformed
+    /// by `engine`, before dispatch.
+    ///
+    /// Installs an [`InputValidator`] built from the tools registered so far.
+    pub fn with_input_validation(self, engine: &SchemaEngine) -> Result<Self, ToolError> {
+        let validator = InputValidator::new(&self, engine)?;
+        Ok(self.with_middleware(validator))
     }
 
     /// Create a subset registry containing only the specified tools.
     ///
-    /// Tools not found in the registry are silently ignored.
+    /// Names may be aliases. Tools not found in the registry are silently
+    /// ignored. The subset keeps this registry's middleware, granted permissions
+    /// and the aliases of the tools it contains, and shares their policy limits.
     #[must_use]
     pub fn subset<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Self {
-        let allowed: HashSet<&str> = names.into_iter().collect();
+        let allowed: HashSet<&str> = names
+            .into_iter()
+            .filter_map(|name| self.resolve(name))
+            .collect();
 
         // Copy the allowed entries into the new map
         let mut map = HashMap::new();
@@ -109,21 +306,44 @@ impl ToolRegistry {
             }
         }
 
-        Self { map, by_type }
+        let policies = self
+            .policies
+            .iter()
+            .filter(|(name, _)| allowed.contains(name.as_str()))
+            .map(|(name, gate)| (name.clone(), Arc::clone(gate)))
+            .collect();
+
+        let aliases = self
+            .aliases
+            .iter()
+            .filter(|(_, target)| allowed.contains(target.as_str()))
+            .map(|(alias, target)| (alias.clone(), target.clone()))
+            .collect();
+
+        Self {
+            map,
+            by_type,
+            aliases,
+            middleware: self.middleware.iter().map(Arc::clone).collect(),
+            policies,
+            grants: self.grants,
+        }
     }
 
     /// Dispatch a tool call using JSON arguments.
+    ///
+    /// `name` may be an alias; middleware and policies see the registered name.
+    /// Calls to tools that need permissions not granted by this registry and
+    /// `ctx` fail with [`ToolError::PermissionDenied`] before middleware runs.
     pub async fn dispatch_json(
         &self,
         name: &str,
         args: Value,
         ctx: &ToolContext,
     ) -> Result<Value, ToolError> {
-        let entry = self
-            .map
-            .get(name)
-            .ok_or_else(|| ToolError::invalid_input(format!("Unknown tool: {name}")))?;
-        entry.call_json(args, ctx).await
+        let (name, entry) = self.lookup(name, ctx)?;
+        self.dispatch_through_middleware(name, args, ctx, |args| entry.call_json(args, ctx), |v| v)
+            .await
     }
 
     /// Dispatch a tool call using JSON arguments, returning both JSON data and formatted text.
@@ -138,11 +358,136 @@ impl ToolRegistry {
         ctx: &ToolContext,
         text_opts: &TextOptions,
     ) -> Result<FormattedResult, ToolError> {
-        let entry = self
-            .map
-            .get(name)
+        let (name, entry) = self.lookup(name, ctx)?;
+        self.dispatch_through_middleware(
+            name,
+            args,
+            ctx,
+            |args| entry.call_json_formatted(args, ctx, text_opts),
+            |r| &r.data,
+        )
+        .await
+    }
+
+    /// Find the tool to dispatch and check that it may run under `ctx`.
+    fn lookup<'a>(
+        &'a self,
+        name: &'a str,
+        ctx: &ToolContext,
+    ) -> Result<(&'a str, &'a Arc<dyn ErasedTool>), ToolError> {
+        let (name, entry) = self
+            .resolve(name)
+            .and_then(|name| Some((name, self.map.get(name)?)))
             .ok_or_else(|| ToolError::invalid_input(format!("Unknown tool: {name}")))?;
-        entry.call_json_formatted(args, ctx, text_opts).await
+        let missing = entry
+            .permissions()
+            .difference(self.grants & ctx.permissions());
+        if !missing.is_empty() {
+            return Err(ToolError::PermissionDenied {
+                tool: name.to_string(),
+                missing,
+            });
+        }
+        Ok((name, entry))
+    }
+
+    /// Run `call` inside the installed middleware: `before` hooks in order, then
+    /// `after` hooks in reverse for every middleware whose `before` succeeded.
+    /// The tool's policy, if any, is enforced around `call` alone.
+    async fn dispatch_through_middleware<T>(
+        &self,
+        name: &str,
+        mut args: Value,
+        ctx: &ToolContext,
+        call: impl FnOnce(Value) -> BoxFuture<'static, Result<T, ToolError>>,
+        data: impl Fn(&T) -> &Value,
+    ) -> Result<T, ToolError> {
+        let call = |args| async move {
+            let _permit = match self.policies.get(name) {
+                Some(gate) => Some(gate.enter(name).await?),
+                None => None,
+            };
+            call(args).await
+        };
+        if self.middleware.is_empty() {
+            return call(args).await;
+        }
+
+        self.run_before(name, &mut args, ctx).await?;
+        let result = call(args.clone()).await;
+        run_after(
+            &self.middleware,
+            name,
+            &args,
+            ctx,
+            result.as_ref().map(&data),
+        );
+        result
+    }
+
+    /// Run `before` hooks in order. If one rejects the call, the `after` hooks
+    /// of those that already ran are called with the rejection before returning it.
+    async fn run_before(
+        &self,
+        name: &str,
+        args: &mut Value,
+        ctx: &ToolContext,
+    ) -> Result<(), ToolError> {
+        for (entered, middleware) in self.middleware.iter().enumerate() {
+            if let Err(e) = middleware.before(name, args, ctx).await {
+                run_after(&self.middleware[..entered], name, args, ctx, Err(&e));
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Dispatch a tool call using JSON arguments, streaming its output.
+    ///
+    /// Tools registered with [`ToolRegistryBuilder::register_streaming`] yield
+    /// their chunks before the final result; other tools yield only the final
+    /// result. Middleware `before` hooks run before this returns, and `after`
+    /// hooks run when the final result or an error is yielded. The tool's policy
+    /// slot is held until then, or until the stream is dropped.
+    pub async fn dispatch_stream(
+        &self,
+        name: &str,
+        mut args: Value,
+        ctx: &ToolContext,
+    ) -> Result<JsonToolStream, ToolError> {
+        let (name, entry) = self.lookup(name, ctx)?;
+        self.run_before(name, &mut args, ctx).await?;
+
+        let permit = match self.policies.get(name) {
+            None => None,
+            Some(gate) => match gate.enter(name).await {
+                Ok(permit) => Some(permit),
+                Err(e) => {
+                    run_after(&self.middleware, name, &args, ctx, Err(&e));
+                    return Err(e);
+                }
+            },
+        };
+        let stream = entry.call_json_stream(args.clone(), ctx);
+        if self.middleware.is_empty() && permit.is_none() {
+            return Ok(stream);
+        }
+
+        let middleware = self.middleware.clone();
+        let name = name.to_string();
+        let ctx = ctx.clone();
+        let mut permit = permit;
+        Ok(stream
+            .inspect(move |item| {
+                let result = match item {
+                    Ok(StreamItem::Chunk(_)) => return,
+                    Ok(StreamItem::Final(data)) => Ok(data),
+                    Err(e) => Err(e),
+                };
+                drop(permit.take());
+                run_after(&middleware, &name, &args, &ctx, result);
+            })
+            .boxed())
     }
 
     /// Get a type-safe handle for calling a tool natively (zero JSON).
@@ -161,9 +506,9 @@ impl ToolRegistry {
         })
     }
 
-    /// Check if a tool is registered by name.
+    /// Check if a tool is registered by name or alias.
     pub fn contains(&self, name: &str) -> bool {
-        self.map.contains_key(name)
+        self.resolve(name).is_some()
     }
 
     /// Get the number of registered tools.
@@ -176,6 +521,11 @@ impl ToolRegistry {
         self.map.is_empty()
     }
 
+    /// Schema version and per-tool content hashes for compatibility checks.
+    pub fn manifest(&self) -> crate::manifest::ToolManifest {
+        crate::manifest::ToolManifest::from_registry(self)
+    }
+
     /// Clone and return erased tool entries (Arc) for composition.
     ///
     /// This enables merging multiple registries by iterating over their
@@ -186,24 +536,68 @@ impl ToolRegistry {
 
     /// Merge multiple registries into one.
     ///
-    /// Later entries with duplicate names overwrite earlier ones.
+    /// Later entries with duplicate names overwrite earlier ones. Middleware,
+    /// policies and permission grants of the inputs are not carried over;
+    /// install them on the merged registry.
     /// This is useful for composing domain-specific registries into
     /// a unified registry.
+    ///
+    /// Registered names and aliases are kept.
     pub fn merge_all(regs: impl IntoIterator<Item = Self>) -> Self {
         let mut builder = Self::builder();
         for reg in regs {
-            for erased in reg.iter_erased() {
-                builder = builder.register_erased(erased);
+            for (name, erased) in reg.iter_named() {
+                builder = builder.register_erased_as(name, Arc::clone(erased));
+            }
+            for (alias, target) in reg.aliases {
+                builder = builder.alias(alias, target);
+            }
+        }
+        builder.finish()
+    }
+
+    /// Merge registries, prefixing each tool's name with its registry's
+    /// namespace and [`NAMESPACE_SEPARATOR`].
+    ///
+    /// `merge_all_namespaced([("gh", gh), ("linear", linear)])` lists `gh__ls`
+    /// and `linear__ls` even if both domains define `ls`. Aliases are namespaced
+    /// the same way; middleware, policies and permission grants are not carried over.
+    pub fn merge_all_namespaced<'a>(regs: impl IntoIterator<Item = (&'a str, Self)>) -> Self {
+        let qualify = |ns: &str, name: &str| format!("{ns}{NAMESPACE_SEPARATOR}{name}");
+        let mut builder = Self::builder();
+        for (ns, reg) in regs {
+            for (name, erased) in reg.iter_named() {
+                builder = builder.register_erased_as(qualify(ns, name), Arc::clone(erased));
+            }
+            for (alias, target) in &reg.aliases {
+                builder = builder.alias(qualify(ns, alias), qualify(ns, target));
             }
         }
         builder.finish()
     }
 }
 
+/// Run `after` hooks in reverse installation order.
+fn run_after(
+    middleware: &[Arc<dyn ToolMiddleware>],
+    name: &str,
+    args: &Value,
+    ctx: &ToolContext,
+    result: Result<&Value, &ToolError>,
+) {
+    for middleware in middleware.iter().rev() {
+        middleware.after(name, args, ctx, result);
+    }
+}
+
 /// Builder for constructing a [`ToolRegistry`].
 #[derive(Default)]
 pub struct ToolRegistryBuilder {
     items: Vec<(String, TypeId, Arc<dyn ErasedTool>)>,
+    aliases: HashMap<String, String>,
+    middleware: Vec<Arc<dyn ToolMiddleware>>,
+    policies: HashMap<String, ToolPolicy>,
+    grants: Option<Permissions>,
 }
 
 impl ToolRegistryBuilder {
@@ -222,34 +616,62 @@ impl ToolRegistryBuilder {
         C: ToolCodec<T> + 'static,
         T::Output: TextFormat,
     {
-        struct Impl<T: Tool + Clone, C: ToolCodec<T>> {
+        let erased = erase::<T, C>(tool);
+        self.items
+            .push((T::NAME.to_string(), TypeId::of::<T>(), erased));
+        self
+    }
+
+    /// Register a [`StreamingTool`] with its codec.
+    ///
+    /// Like [`register`](Self::register), plus JSON streaming through
+    /// [`ToolRegistry::dispatch_stream`]. Chunks are serialized with serde.
+    #[must_use]
+    pub fn register_streaming<T, C>(mut self, tool: T) -> Self
+    where
+        T: StreamingTool + Clone + 'static,
+        T::Chunk: Serialize,
+        C: ToolCodec<T> + 'static,
+        T::Output: TextFormat,
+    {
+        struct Streaming<T, C> {
+            inner: Arc<dyn ErasedTool>,
             tool: T,
             _codec: PhantomData<C>,
         }
 
-        impl<T: Tool + Clone, C: ToolCodec<T>> ErasedTool for Impl<T, C>
+        impl<T, C> ErasedTool for Streaming<T, C>
         where
-            T::Output: TextFormat,
+            T: StreamingTool + Clone,
+            T::Chunk: Serialize,
+            C: ToolCodec<T>,
         {
             fn name(&self) -> &'static str {
-                T::NAME
+                self.inner.name()
             }
 
             fn description(&self) -> &'static str {
-                T::DESCRIPTION
+                self.inner.description()
             }
 
             fn input_schema(&self) -> Schema {
-                mcp_schema::cached_schema_for::<C::WireIn>()
-                    .as_ref()
-                    .clone()
+                self.inner.input_schema()
             }
 
             fn output_schema(&self) -> Option<Schema> {
-                match mcp_schema::cached_output_schema_for::<C::WireOut>() {
-                    Ok(arc) => Some(arc.as_ref().clone()),
-                    Err(_) => None,
-                }
+                self.inner.output_schema()
+            }
+
+            fn permissions(&self) -> Permissions {
+                T::PERMISSIONS
+            }
+
+            fn version(&self) -> Option<&'static str> {
+                T::VERSION
+            }
+
+            fn deprecation(&self) -> Option<Deprecation> {
+                T::DEPRECATED
             }
 
             fn call_json(
@@ -257,25 +679,7 @@ impl ToolRegistryBuilder {
                 args: Value,
                 ctx: &ToolContext,
             ) -> BoxFuture<'static, Result<Value, ToolError>> {
-                let wire_in: Result<C::WireIn, _> = serde_json::from_value(args);
-                let ctx = ctx.clone();
-                let tool = self.tool.clone();
-
-                match wire_in {
-                    Err(e) => Box::pin(async move { Err(ToolError::invalid_input(e.to_string())) }),
-                    Ok(wire) => match C::decode(wire) {
-                        Err(e) => Box::pin(async move { Err(e) }),
-                        Ok(native_in) => {
-                            let fut = tool.call(native_in, &ctx);
-                            Box::pin(async move {
-                                let out = fut.await?;
-                                let wired = C::encode(out)?;
-                                serde_json::to_value(wired)
-                                    .map_err(|e| ToolError::internal(e.to_string()))
-                            })
-                        }
-                    },
-                }
+                self.inner.call_json(args, ctx)
             }
 
             fn call_json_formatted(
@@ -284,33 +688,27 @@ impl ToolRegistryBuilder {
                 ctx: &ToolContext,
                 text_opts: &TextOptions,
             ) -> BoxFuture<'static, Result<FormattedResult, ToolError>> {
-                let wire_in: Result<C::WireIn, _> = serde_json::from_value(args);
-                let ctx = ctx.clone();
-                let tool = self.tool.clone();
-                let text_opts = text_opts.clone();
-
-                match wire_in {
-                    Err(e) => Box::pin(async move { Err(ToolError::invalid_input(e.to_string())) }),
-                    Ok(wire) => match C::decode(wire) {
-                        Err(e) => Box::pin(async move { Err(e) }),
-                        Ok(native_in) => {
-                            let fut = tool.call(native_in, &ctx);
-                            Box::pin(async move {
-                                let out = fut.await?;
-                                // Format text from the native output using TextFormat
-                                let text = out.fmt_text(&text_opts);
-                                // Then encode to wire and JSON-serialize for data
-                                let wired = C::encode(out)?;
-                                let data = serde_json::to_value(&wired)
-                                    .map_err(|e| ToolError::internal(e.to_string()))?;
-                                Ok(FormattedResult {
-                                    data,
-                                    text: Some(text),
-                                })
-                            })
-                        }
-                    },
-                }
+                self.inner.call_json_formatted(args, ctx, text_opts)
+            }
+
+            fn call_json_stream(&self, args: Value, ctx: &ToolContext) -> JsonToolStream {
+                let native_in = serde_json::from_value::<C::WireIn>(args)
+                    .map_err(|e| ToolError::invalid_input(e.to_string()))
+                    .and_then(C::decode);
+                let stream = match native_in {
+                    Ok(native_in) => self.tool.call_stream(native_in, ctx),
+                    Err(e) => return futures::stream::once(async move { Err(e) }).boxed(),
+                };
+                stream
+                    .map(|item| match item? {
+                        StreamItem::Chunk(chunk) => serde_json::to_value(chunk)
+                            .map(StreamItem::Chunk)
+                            .map_err(|e| ToolError::internal(e.to_string())),
+                        StreamItem::Final(out) => serde_json::to_value(C::encode(out)?)
+                            .map(StreamItem::Final)
+                            .map_err(|e| ToolError::internal(e.to_string())),
+                    })
+                    .boxed()
             }
 
             fn type_id(&self) -> TypeId {
@@ -318,7 +716,8 @@ impl ToolRegistryBuilder {
             }
         }
 
-        let erased: Arc<dyn ErasedTool> = Arc::new(Impl::<T, C> {
+        let erased: Arc<dyn ErasedTool> = Arc::new(Streaming::<T, C> {
+            inner: erase::<T, C>(tool.clone()),
             tool,
             _codec: PhantomData,
         });
@@ -332,10 +731,56 @@ impl ToolRegistryBuilder {
     /// This enables merging registries by iterating over their erased tools
     /// and re-registering them without needing the concrete tool types.
     #[must_use]
-    pub fn register_erased(mut self, erased: Arc<dyn ErasedTool>) -> Self {
+    pub fn register_erased(self, erased: Arc<dyn ErasedTool>) -> Self {
         let name = erased.name().to_string();
+        self.register_erased_as(name, erased)
+    }
+
+    /// Register an already-erased tool entry under `name` instead of its own name.
+    #[must_use]
+    pub fn register_erased_as(
+        mut self,
+        name: impl Into<String>,
+        erased: Arc<dyn ErasedTool>,
+    ) -> Self {
         let type_id = erased.type_id();
-        self.items.push((name, type_id, erased));
+        self.items.push((name.into(), type_id, erased));
+        self
+    }
+
+    /// Make `alias` resolve to the tool registered as `target`.
+    ///
+    /// See [`ToolRegistry::with_alias`].
+    #[must_use]
+    pub fn alias(mut self, alias: impl Into<String>, target: impl Into<String>) -> Self {
+        self.aliases.insert(alias.into(), target.into());
+        self
+    }
+
+    /// Install middleware around every JSON dispatch, after any installed earlier.
+    ///
+    /// See [`crate::middleware`] for ordering and short-circuit semantics.
+    #[must_use]
+    pub fn middleware(mut self, middleware: impl ToolMiddleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Limit dispatches of the named tool, replacing any policy set earlier.
+    ///
+    /// See [`crate::policy`] for how limits are enforced.
+    #[must_use]
+    pub fn policy(mut self, name: impl Into<String>, policy: ToolPolicy) -> Self {
+        self.policies.insert(name.into(), policy);
+        self
+    }
+
+    /// Grant only `permissions` to the registry's tools instead of every capability.
+    ///
+    /// See [`ToolRegistry::with_permissions`].
+    #[must_use]
+    pub const fn permissions(mut self, permissions: Permissions) -> Self {
+        self.grants = Some(permissions);
         self
     }
 
@@ -347,8 +792,160 @@ impl ToolRegistryBuilder {
             by_type.insert(type_id, name.clone());
             map.insert(name, erased);
         }
-        ToolRegistry { map, by_type }
+        let policies = self
+            .policies
+            .into_iter()
+            .map(|(name, policy)| (name, Arc::new(PolicyGate::new(policy))))
+            .collect();
+        ToolRegistry {
+            map,
+            by_type,
+            aliases: self.aliases,
+            middleware: self.middleware,
+            policies,
+            grants: self.grants.unwrap_or(Permissions::ALL),
+        }
+    }
+}
+
+/// Wrap a typed tool and its codec as an [`ErasedTool`].
+fn erase<T, C>(tool: T) -> Arc<dyn ErasedTool>
+where
+    T: Tool + Clone + 'static,
+    C: ToolCodec<T> + 'static,
+    T::Output: TextFormat,
+{
+    struct Impl<T: Tool + Clone, C: ToolCodec<T>> {
+        tool: T,
+        _codec: PhantomData<C>,
+    }
+
+    impl<T: Tool + Clone, C: ToolCodec<T>> ErasedTool for Impl<T, C>
+    where
+        T::Output: TextFormat,
+    {
+        fn name(&self) -> &'static str {
+            T::NAME
+        }
+
+        fn description(&self) -> &'static str {
+            T::DESCRIPTION
+        }
+
+        fn input_schema(&self) -> Schema {
+            mcp_schema::cached_schema_for::<C::WireIn>()
+                .as_ref()
+                .clone()
+        }
+
+        fn output_schema(&self) -> Option<Schema> {
+            match mcp_schema::cached_output_schema_for::<C::WireOut>() {
+                Ok(arc) => Some(arc.as_ref().clone()),
+                Err(_) => None,
+            }
+        }
+
+        fn permissions(&self) -> Permissions {
+            T::PERMISSIONS
+        }
+
+        fn version(&self) -> Option<&'static str> {
+            T::VERSION
+        }
+
+        fn deprecation(&self) -> Option<Deprecation> {
+            T::DEPRECATED
+        }
+
+        fn call_json(
+            &self,
+            args: Value,
+            ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Value, ToolError>> {
+            let wire_in: Result<C::WireIn, _> = serde_json::from_value(args);
+            let ctx = ctx.clone();
+            let tool = self.tool.clone();
+
+            match wire_in {
+                Err(e) => Box::pin(async move { Err(ToolError::invalid_input(e.to_string())) }),
+                Ok(wire) => match C::decode(wire) {
+                    Err(e) => Box::pin(async move { Err(e) }),
+                    Ok(native_in) => {
+                        let slot =
+                            CacheSlot::locate(T::NAME, tool.cache_policy(), &native_in, &ctx);
+                        if let Some(data) = slot.as_ref().and_then(CacheSlot::data) {
+                            return Box::pin(async move { Ok(data) });
+                        }
+                        let fut = tool.call(native_in, &ctx);
+                        Box::pin(async move {
+                            let out = fut.await?;
+                            let wired = C::encode(out)?;
+                            let data = serde_json::to_value(wired)
+                                .map_err(|e| ToolError::internal(e.to_string()))?;
+                            if let Some(slot) = slot {
+                                slot.store(&data, None);
+                            }
+                            Ok(data)
+                        })
+                    }
+                },
+            }
+        }
+
+        fn call_json_formatted(
+            &self,
+            args: Value,
+            ctx: &ToolContext,
+            text_opts: &TextOptions,
+        ) -> BoxFuture<'static, Result<FormattedResult, ToolError>> {
+            let wire_in: Result<C::WireIn, _> = serde_json::from_value(args);
+            let ctx = ctx.clone();
+            let tool = self.tool.clone();
+            let text_opts = text_opts.clone();
+
+            match wire_in {
+                Err(e) => Box::pin(async move { Err(ToolError::invalid_input(e.to_string())) }),
+                Ok(wire) => match C::decode(wire) {
+                    Err(e) => Box::pin(async move { Err(e) }),
+                    Ok(native_in) => {
+                        let slot =
+                            CacheSlot::locate(T::NAME, tool.cache_policy(), &native_in, &ctx);
+                        if let Some(cached) =
+                            slot.as_ref().and_then(|slot| slot.formatted(&text_opts))
+                        {
+                            return Box::pin(async move { Ok(cached) });
+                        }
+                        let fut = tool.call(native_in, &ctx);
+                        Box::pin(async move {
+                            let out = fut.await?;
+                            // Format text from the native output using TextFormat
+                            let text = out.fmt_text(&text_opts);
+                            // Then encode to wire and JSON-serialize for data
+                            let wired = C::encode(out)?;
+                            let data = serde_json::to_value(&wired)
+                                .map_err(|e| ToolError::internal(e.to_string()))?;
+                            if let Some(slot) = slot {
+                                slot.store(&data, Some((&text_opts, &text)));
+                            }
+                            Ok(FormattedResult {
+                                data,
+                                text: Some(text),
+                            })
+                        })
+                    }
+                },
+            }
+        }
+
+        fn type_id(&self) -> TypeId {
+            TypeId::of::<T>()
+        }
     }
+
+    Arc::new(Impl::<T, C> {
+        tool,
+        _codec: PhantomData,
+    })
 }
 
 /// Type-safe handle for calling a tool natively without JSON serialization.
@@ -413,6 +1010,57 @@ mod tests {
         assert_eq!(names, vec!["test_tool"]);
     }
 
+    #[test]
+    fn test_list_names_with_meta_and_deprecation_notice() {
+        #[derive(Clone)]
+        struct OldTool;
+
+        impl Tool for OldTool {
+            type Input = String;
+            type Output = String;
+            const NAME: &'static str = "old_tool";
+            const DESCRIPTION: &'static str = "Greets.";
+            const VERSION: Option<&'static str> = Some("1");
+            const DEPRECATED: Option<Deprecation> =
+                Some(Deprecation::replaced_by("test_tool").with_note("Same arguments."));
+
+            fn call(
+                &self,
+                input: Self::Input,
+                _ctx: &ToolContext,
+            ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+                Box::pin(async move { Ok(input) })
+            }
+        }
+
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .register::<OldTool, ()>(OldTool)
+            .finish();
+
+        let meta = serde_json::to_value(registry.list_names_with_meta()).unwrap();
+        assert_eq!(
+            meta,
+            serde_json::json!([
+                {
+                    "name": "old_tool",
+                    "description": "Greets.",
+                    "version": "1",
+                    "deprecated": { "replacement": "test_tool", "note": "Same arguments." }
+                },
+                { "name": "test_tool", "description": "A test tool" }
+            ])
+        );
+        assert_eq!(
+            registry.get("old_tool").unwrap().provider_description(),
+            "Greets. DEPRECATED: use test_tool instead. Same arguments."
+        );
+        assert_eq!(
+            registry.get("test_tool").unwrap().provider_description(),
+            "A test tool"
+        );
+    }
+
     #[test]
     fn test_registry_subset() {
         let registry = ToolRegistry::builder()
@@ -539,4 +1187,324 @@ mod tests {
         assert_eq!(subset.len(), 1);
         assert!(subset.contains("test_tool"));
     }
+
+    /// Logs every hook; appends `!` to string args, or rejects the call.
+    struct Recorder {
+        label: &'static str,
+        log: Arc<std::sync::Mutex<Vec<String>>>,
+        reject: bool,
+    }
+
+    impl ToolMiddleware for Recorder {
+        fn before<'a>(
+            &'a self,
+            name: &'a str,
+            args: &'a mut Value,
+            _ctx: &'a ToolContext,
+        ) -> BoxFuture<'a, Result<(), ToolError>> {
+            Box::pin(async move {
+                self.log
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}:before:{name}", self.label));
+                if self.reject {
+                    return Err(ToolError::permission("rejected"));
+                }
+                if let Value::String(s) = args {
+                    s.push('!');
+                }
+                Ok(())
+            })
+        }
+
+        fn after(
+            &self,
+            name: &str,
+            args: &Value,
+            _ctx: &ToolContext,
+            result: Result<&Value, &ToolError>,
+        ) {
+            self.log.lock().unwrap().push(format!(
+                "{}:after:{name}:{args}:{}",
+                self.label,
+                result.is_ok()
+            ));
+        }
+    }
+
+    fn recorder(
+        label: &'static str,
+        log: &Arc<std::sync::Mutex<Vec<String>>>,
+        reject: bool,
+    ) -> Recorder {
+        Recorder {
+            label,
+            log: Arc::clone(log),
+            reject,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_nests_around_dispatch() {
+        let log = Arc::default();
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .middleware(recorder("a", &log, false))
+            .middleware(recorder("b", &log, false))
+            .finish();
+
+        let result = registry
+            .dispatch_json(
+                "test_tool",
+                serde_json::json!("World"),
+                &ToolContext::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, serde_json::json!("Hello, World!!!"));
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "a:before:test_tool",
+                "b:before:test_tool",
+                r#"b:after:test_tool:"World!!":true"#,
+                r#"a:after:test_tool:"World!!":true"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_middleware_rejection_short_circuits() {
+        let log = Arc::default();
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .middleware(recorder("a", &log, false))
+            .middleware(recorder("b", &log, true))
+            .middleware(recorder("c", &log, false))
+            .finish();
+
+        let result = registry
+            .dispatch_json_formatted(
+                "test_tool",
+                serde_json::json!("World"),
+                &ToolContext::default(),
+                &TextOptions::default(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(ToolError::Permission(_))));
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "a:before:test_tool",
+                "b:before:test_tool",
+                r#"a:after:test_tool:"World!":false"#,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_middleware_survives_subset() {
+        let log = Arc::default();
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .finish()
+            .with_middleware(recorder("a", &log, false))
+            .subset(["test_tool"]);
+
+        let result = registry
+            .dispatch_json_formatted(
+                "test_tool",
+                serde_json::json!("World"),
+                &ToolContext::default(),
+                &TextOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.data, serde_json::json!("Hello, World!!"));
+        assert_eq!(log.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_policy_limits_dispatch_and_is_shared_with_subset() {
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .policy(
+                "test_tool",
+                ToolPolicy::new()
+                    .min_interval(std::time::Duration::from_secs(60))
+                    .reject_when_limited(),
+            )
+            .finish();
+        let subset = registry.subset(["test_tool"]);
+        let ctx = ToolContext::default();
+
+        let first = registry
+            .dispatch_json("test_tool", serde_json::json!("World"), &ctx)
+            .await;
+        assert!(first.is_ok());
+
+        let second = subset
+            .dispatch_json("test_tool", serde_json::json!("World"), &ctx)
+            .await;
+        assert!(matches!(second, Err(ToolError::RateLimited(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_refuses_ungranted_permissions() {
+        #[derive(Clone)]
+        struct WriteTool;
+
+        impl Tool for WriteTool {
+            type Input = String;
+            type Output = String;
+            const NAME: &'static str = "write";
+            const DESCRIPTION: &'static str = "Writes a file";
+            const PERMISSIONS: Permissions = Permissions::READ_FS.union(Permissions::WRITE_FS);
+
+            fn call(
+                &self,
+                input: Self::Input,
+                _ctx: &ToolContext,
+            ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+                Box::pin(async move { Ok(input) })
+            }
+        }
+
+        let log = Arc::default();
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .register::<WriteTool, ()>(WriteTool)
+            .permissions(Permissions::READ_FS)
+            .middleware(recorder("a", &log, false))
+            .finish();
+        let args = || serde_json::json!("x");
+        let ctx = ToolContext::default();
+
+        let err = registry
+            .dispatch_json("write", args(), &ctx)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, ToolError::PermissionDenied { tool, missing }
+                if tool == "write" && *missing == Permissions::WRITE_FS),
+            "{err:?}"
+        );
+        assert_eq!(
+            err.to_string(),
+            "permission denied: write requires write_fs"
+        );
+        assert!(log.lock().unwrap().is_empty());
+        assert!(
+            registry
+                .dispatch_json("test_tool", args(), &ctx)
+                .await
+                .is_ok()
+        );
+
+        let registry = registry.with_permissions(Permissions::ALL);
+        assert!(registry.dispatch_json("write", args(), &ctx).await.is_ok());
+        let read_only = ctx.with_permissions(Permissions::READ_FS);
+        assert!(matches!(
+            registry.dispatch_json("write", args(), &read_only).await,
+            Err(ToolError::PermissionDenied { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_merge_all_namespaced_prefixes_names() {
+        let a = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .alias("greet", "test_tool")
+            .finish();
+        let b = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .finish();
+
+        let merged = ToolRegistry::merge_all_namespaced([("a", a), ("b", b)]);
+
+        let mut names = merged.list_names();
+        names.sort();
+        assert_eq!(names, ["a__test_tool", "b__test_tool"]);
+        assert!(!merged.contains("test_tool"));
+        assert_eq!(merged.resolve("a__greet"), Some("a__test_tool"));
+
+        let result = merged
+            .dispatch_json(
+                "a__greet",
+                serde_json::json!("World"),
+                &ToolContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result, serde_json::json!("Hello, World!"));
+    }
+
+    #[tokio::test]
+    async fn test_renamed_lists_new_name_and_keeps_old_as_alias() {
+        let log = Arc::default();
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .middleware(recorder("a", &log, false))
+            .finish()
+            .renamed("test_tool", "hello");
+
+        assert_eq!(registry.list_names(), ["hello"]);
+        assert!(registry.handle::<TestTool>().is_ok());
+        assert_eq!(
+            registry.manifest().tools.keys().collect::<Vec<_>>(),
+            ["hello"]
+        );
+
+        registry
+            .dispatch_json(
+                "test_tool",
+                serde_json::json!("World"),
+                &ToolContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(log.lock().unwrap()[0], "a:before:hello");
+
+        let subset = registry.subset(["test_tool"]);
+        assert_eq!(subset.list_names(), ["hello"]);
+        assert!(subset.contains("test_tool"));
+    }
+
+    #[test]
+    fn test_alias_does_not_shadow_registered_name() {
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .finish()
+            .with_alias("test_tool", "missing")
+            .with_alias("dangling", "missing");
+
+        assert_eq!(registry.resolve("test_tool"), Some("test_tool"));
+        assert!(!registry.contains("dangling"));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_stream_runs_after_hooks_on_final() {
+        let log = Arc::default();
+        let registry = ToolRegistry::builder()
+            .register::<TestTool, ()>(TestTool)
+            .middleware(recorder("a", &log, false))
+            .finish();
+
+        let stream = registry
+            .dispatch_stream(
+                "test_tool",
+                serde_json::json!("World"),
+                &ToolContext::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(log.lock().unwrap().len(), 1);
+
+        let items: Vec<_> = stream.collect().await;
+        assert!(matches!(&items[..], [Ok(StreamItem::Final(v))] if v == "Hello, World!!"));
+        assert_eq!(log.lock().unwrap()[1], r#"a:after:test_tool:"World!":true"#);
+    }
 }
@@ -1,6 +1,8 @@
 //! Schema engine for runtime transforms.
 
+use crate::providers::Provider;
 use schemars::Schema;
+use serde::Serialize;
 use serde_json::Value as Json;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -24,6 +26,36 @@ pub enum FieldConstraint {
     MergePatch(Json),
 }
 
+/// One step of a [`SchemaEngine`] transform, as reported by
+/// [`SchemaEngine::transform_traced`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct TransformStep {
+    /// Tool whose schema was transformed.
+    pub tool: String,
+    /// Provider the step is scoped to, or `None` for steps applied for every provider.
+    pub provider: Option<Provider>,
+    /// What the step does, e.g. `enum at properties.mode`.
+    pub description: String,
+    /// Whether the step changed anything. Constraints whose path does not
+    /// exist in the schema are skipped.
+    pub applied: bool,
+}
+
+/// A per-tool constraint, optionally limited to one provider.
+type ScopedConstraint = (Option<Provider>, Vec<String>, FieldConstraint);
+
+impl FieldConstraint {
+    /// Short name of the constraint kind, used in transform traces.
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Enum(_) => "enum",
+            Self::Range { .. } => "range",
+            Self::Pattern(_) => "pattern",
+            Self::MergePatch(_) => "merge patch",
+        }
+    }
+}
+
 /// Trait for custom schema transforms.
 pub trait SchemaTransform: Send + Sync {
     /// Apply the transform to a tool's schema.
@@ -40,7 +72,7 @@ pub trait SchemaTransform: Send + Sync {
 /// Only `per_tool` constraints and `global_strict` settings are cloned.
 #[derive(Default)]
 pub struct SchemaEngine {
-    per_tool: HashMap<String, Vec<(Vec<String>, FieldConstraint)>>,
+    per_tool: HashMap<String, Vec<ScopedConstraint>>,
     global_strict: bool,
     custom_transforms: Vec<Box<dyn SchemaTransform>>,
 }
@@ -95,7 +127,26 @@ impl SchemaEngine {
         self.per_tool
             .entry(tool.to_string())
             .or_default()
-            .push((json_path, c));
+            .push((None, json_path, c));
+    }
+
+    /// Add a field constraint for a specific tool that only applies when
+    /// rendering for `provider` (see [`SchemaEngine::transform_for`]).
+    ///
+    /// Constraints apply in the order added, so a provider-scoped constraint can
+    /// override an earlier global one, e.g. a narrower enum for one provider or a
+    /// merge patch that drops `format` where it is unsupported.
+    pub fn constrain_field_for(
+        &mut self,
+        provider: Provider,
+        tool: &str,
+        json_path: Vec<String>,
+        c: FieldConstraint,
+    ) {
+        self.per_tool
+            .entry(tool.to_string())
+            .or_default()
+            .push((Some(provider), json_path, c));
     }
 
     /// Add a custom transform.
@@ -104,11 +155,55 @@ impl SchemaEngine {
     }
 
     /// Transform a tool's schema applying all constraints and transforms.
+    ///
+    /// Provider-scoped constraints are skipped; use
+    /// [`SchemaEngine::transform_for`] when rendering for a known provider.
+    pub fn transform(&self, tool: &str, schema: Schema) -> Schema {
+        self.run(tool, None, schema, None)
+    }
+
+    /// Transform a tool's schema for `provider`, applying global constraints and
+    /// those scoped to `provider`.
+    pub fn transform_for(&self, provider: Provider, tool: &str, schema: Schema) -> Schema {
+        self.run(tool, Some(provider), schema, None)
+    }
+
+    /// Like [`SchemaEngine::transform_for`] (or [`SchemaEngine::transform`] when
+    /// `provider` is `None`), also reporting each step that was considered.
+    pub fn transform_traced(
+        &self,
+        provider: Option<Provider>,
+        tool: &str,
+        schema: Schema,
+    ) -> (Schema, Vec<TransformStep>) {
+        let mut trace = Vec::new();
+        let schema = self.run(tool, provider, schema, Some(&mut trace));
+        (schema, trace)
+    }
+
     #[expect(
         clippy::needless_pass_by_value,
-        reason = "this public API intentionally consumes and returns Schema at the transformation boundary"
+        reason = "the public API intentionally consumes and returns Schema at the transformation boundary"
     )]
-    pub fn transform(&self, tool: &str, schema: Schema) -> Schema {
+    fn run(
+        &self,
+        tool: &str,
+        provider: Option<Provider>,
+        schema: Schema,
+        mut trace: Option<&mut Vec<TransformStep>>,
+    ) -> Schema {
+        let tracing = trace.is_some();
+        let mut step = |scope: Option<Provider>, description: String, applied: bool| {
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(TransformStep {
+                    tool: tool.to_string(),
+                    provider: scope,
+                    description,
+                    applied,
+                });
+            }
+        };
+
         let mut v = match serde_json::to_value(&schema) {
             Ok(value) => value,
             Err(error) => panic!("serialize schema: {error}"),
@@ -119,18 +214,31 @@ impl SchemaEngine {
             && let Some(obj) = v.as_object_mut()
         {
             obj.insert("additionalProperties".to_string(), Json::Bool(false));
+            step(None, "strict: additionalProperties=false".into(), true);
         }
 
-        // Apply per-tool constraints
+        // Apply per-tool constraints, skipping those scoped to other providers
         if let Some(entries) = self.per_tool.get(tool) {
-            for (path, constraint) in entries {
-                Self::apply_constraint(&mut v, path, constraint);
+            for (scope, path, constraint) in entries {
+                if scope.is_some() && *scope != provider {
+                    continue;
+                }
+                let applied = Self::apply_constraint(&mut v, path, constraint);
+                step(
+                    *scope,
+                    format!("{} at {}", constraint.kind(), path.join(".")),
+                    applied,
+                );
             }
         }
 
         // Apply custom transforms
-        for transform in &self.custom_transforms {
+        for (index, transform) in self.custom_transforms.iter().enumerate() {
+            let before = tracing.then(|| v.clone());
             transform.apply(tool, &mut v);
+            if let Some(before) = before {
+                step(None, format!("custom transform #{index}"), before != v);
+            }
         }
 
         // try_from only rejects non-object/non-bool JSON values.  Since we start
@@ -143,12 +251,13 @@ impl SchemaEngine {
         }
     }
 
-    fn apply_constraint(root: &mut Json, path: &[String], constraint: &FieldConstraint) {
+    /// Apply `constraint` at `path`, returning whether the path exists.
+    fn apply_constraint(root: &mut Json, path: &[String], constraint: &FieldConstraint) -> bool {
         let Some(node) = Self::find_node_mut(root, path) else {
-            return;
+            return false;
         };
         let Some(obj) = node.as_object_mut() else {
-            return;
+            return false;
         };
         match constraint {
             FieldConstraint::Enum(vals) => {
@@ -169,6 +278,7 @@ impl SchemaEngine {
                 json_patch::merge(node, merge_patch);
             }
         }
+        true
     }
 
     fn find_node_mut<'a>(root: &'a mut Json, path: &[String]) -> Option<&'a mut Json> {
@@ -496,6 +606,7 @@ pub mod mcp_schema {
 mod tests {
     use super::*;
     use serde::Serialize;
+    use serde_json::json;
 
     #[derive(schemars::JsonSchema, Serialize)]
     struct TestInput {
@@ -584,6 +695,99 @@ mod tests {
         assert_eq!(max, Some(100.0), "maximum constraint should be applied");
     }
 
+    #[test]
+    fn test_provider_scoped_constraints() {
+        let mut engine = SchemaEngine::new();
+        let name = vec!["properties".to_string(), "name".to_string()];
+        engine.constrain_field(
+            "test",
+            name.clone(),
+            FieldConstraint::Enum(vec![json!("a"), json!("b")]),
+        );
+        engine.constrain_field_for(
+            Provider::OpenAi,
+            "test",
+            name.clone(),
+            FieldConstraint::Enum(vec![json!("a")]),
+        );
+        engine.constrain_field_for(
+            Provider::Gemini,
+            "test",
+            name,
+            FieldConstraint::MergePatch(json!({ "pattern": null })),
+        );
+        let schema = || schemars::schema_for!(TestInput);
+        let name_enum = |s: Schema| s.as_value()["properties"]["name"]["enum"].clone();
+
+        assert_eq!(
+            name_enum(engine.transform("test", schema())),
+            json!(["a", "b"])
+        );
+        assert_eq!(
+            name_enum(engine.transform_for(Provider::Anthropic, "test", schema())),
+            json!(["a", "b"])
+        );
+        assert_eq!(
+            name_enum(engine.transform_for(Provider::OpenAi, "test", schema())),
+            json!(["a"])
+        );
+    }
+
+    #[test]
+    fn test_transform_trace() {
+        struct Noop;
+        impl SchemaTransform for Noop {
+            fn apply(&self, _tool: &str, _schema: &mut Json) {}
+        }
+
+        let mut engine = SchemaEngine::new().with_strict(true);
+        engine.constrain_field(
+            "test",
+            vec!["properties".into(), "missing".into()],
+            FieldConstraint::Pattern("x".into()),
+        );
+        engine.constrain_field_for(
+            Provider::OpenAi,
+            "test",
+            vec!["properties".into(), "count".into()],
+            FieldConstraint::Range {
+                minimum: Some(json!(0)),
+                maximum: None,
+            },
+        );
+        engine.constrain_field_for(
+            Provider::Gemini,
+            "test",
+            vec!["properties".into(), "count".into()],
+            FieldConstraint::Pattern("y".into()),
+        );
+        engine.add_transform(Noop);
+
+        let (_, trace) = engine.transform_traced(
+            Some(Provider::OpenAi),
+            "test",
+            schemars::schema_for!(TestInput),
+        );
+        let steps: Vec<_> = trace
+            .iter()
+            .map(|s| (s.provider, s.description.as_str(), s.applied))
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                (None, "strict: additionalProperties=false", true),
+                (None, "pattern at properties.missing", false),
+                (Some(Provider::OpenAi), "range at properties.count", true),
+                (None, "custom transform #0", false),
+            ]
+        );
+        assert!(trace.iter().all(|s| s.tool == "test"));
+        assert_eq!(
+            serde_json::to_value(&trace[2]).unwrap()["provider"],
+            "openai"
+        );
+    }
+
     // ========================================================================
     // mcp_schema module tests
     // ========================================================================
@@ -0,0 +1,150 @@
+//! Per-client session state.
+//!
+//! MCP servers create one [`SessionState`] per client connection and attach it to
+//! every [`ToolContext`](crate::ToolContext) dispatched on that connection. Tools
+//! use it to isolate state that would otherwise be shared process-wide:
+//! - [`ToolContext::session_key`](crate::ToolContext::session_key) scopes cache keys
+//!   (e.g., implicit pagination offsets) to the calling session.
+//! - [`SessionState::get_or_insert_with`] stores arbitrary typed per-session values
+//!   (e.g., overrides or counters) that live as long as the session.
+//! - [`ToolContext::session_scratch_dir`](crate::ToolContext::session_scratch_dir)
+//!   is a scratch directory removed when the session ends.
+
+use crate::workspace::LazyScratch;
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+static NEXT_SESSION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Process-unique identifier for a client session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SessionId(u64);
+
+impl SessionId {
+    /// Allocate the next process-unique session id.
+    pub fn next() -> Self {
+        Self(NEXT_SESSION_ID.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Numeric value of this id.
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for SessionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "session-{}", self.0)
+    }
+}
+
+type Extensions = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+/// State scoped to a single client session.
+pub struct SessionState {
+    id: SessionId,
+    extensions: Mutex<Extensions>,
+    scratch: LazyScratch,
+}
+
+impl Default for SessionState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for SessionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionState")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SessionState {
+    /// Create a new session with a fresh id and no stored values.
+    pub fn new() -> Self {
+        Self {
+            id: SessionId::next(),
+            extensions: Mutex::new(HashMap::new()),
+            scratch: LazyScratch::new("session"),
+        }
+    }
+
+    /// Identifier of this session.
+    pub fn id(&self) -> SessionId {
+        self.id
+    }
+
+    /// Scratch directory for this session, created on first use and removed when
+    /// the session is dropped.
+    pub fn scratch_dir(&self) -> std::io::Result<std::path::PathBuf> {
+        self.scratch.get_or_create()
+    }
+
+    /// Get the session's value of type `T`, inserting `init()` on first use.
+    pub fn get_or_insert_with<T, F>(&self, init: F) -> Arc<T>
+    where
+        T: Any + Send + Sync,
+        F: FnOnce() -> T,
+    {
+        let mut map = self
+            .extensions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let entry = map
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Arc::new(init()));
+        match Arc::clone(entry).downcast::<T>() {
+            Ok(value) => value,
+            Err(_) => unreachable!("session extensions are keyed by TypeId"),
+        }
+    }
+
+    /// Get the session's value of type `T`, if one has been stored.
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        let map = self
+            .extensions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        map.get(&TypeId::of::<T>())
+            .and_then(|v| Arc::clone(v).downcast::<T>().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn sessions_get_distinct_ids() {
+        let a = SessionState::new();
+        let b = SessionState::new();
+        assert_ne!(a.id(), b.id());
+    }
+
+    #[test]
+    fn extensions_are_per_session_and_typed() {
+        let a = SessionState::new();
+        let b = SessionState::new();
+
+        a.get_or_insert_with(AtomicUsize::default)
+            .fetch_add(2, Ordering::Relaxed);
+        a.get_or_insert_with(AtomicUsize::default)
+            .fetch_add(1, Ordering::Relaxed);
+
+        assert_eq!(
+            a.get::<AtomicUsize>().map(|c| c.load(Ordering::Relaxed)),
+            Some(3)
+        );
+        assert!(b.get::<AtomicUsize>().is_none());
+        assert!(a.get::<String>().is_none());
+    }
+}
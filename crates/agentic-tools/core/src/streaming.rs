@@ -0,0 +1,200 @@
+//! Tools that produce typed output chunks before their final result.
+//!
+//! A [`StreamingTool`] returns a [`ToolStream`]: zero or more
+//! [`StreamItem::Chunk`]s followed by exactly one [`StreamItem::Final`], or an
+//! error, after which the stream ends. Register it with
+//! [`ToolRegistryBuilder::register_streaming`](crate::ToolRegistryBuilder::register_streaming)
+//! and consume it as JSON with
+//! [`ToolRegistry::dispatch_stream`](crate::ToolRegistry::dispatch_stream).
+//!
+//! Non-streaming callers still go through [`Tool::call`], which streaming tools
+//! implement with [`collect`]: it drains the stream, forwards each chunk to the
+//! context's event sink as a [`ToolEvent::Chunk`], and returns the final result.
+//! Tools that do not stream appear to `dispatch_stream` as a single final item.
+
+use crate::context::ToolContext;
+use crate::error::ToolError;
+use crate::events::ToolEvent;
+use crate::tool::Tool;
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use serde::Serialize;
+
+/// One item of a [`ToolStream`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamItem<C, O> {
+    /// Incremental output.
+    Chunk(C),
+    /// The final result; always the last item of a successful stream.
+    Final(O),
+}
+
+/// Stream of chunks ending in a final result or an error.
+pub type ToolStream<C, O> = BoxStream<'static, Result<StreamItem<C, O>, ToolError>>;
+
+/// JSON form of a [`ToolStream`], as returned by registry dispatch.
+pub type JsonToolStream = ToolStream<serde_json::Value, serde_json::Value>;
+
+/// Extension of [`Tool`] for tools that produce incremental, typed output.
+pub trait StreamingTool: Tool {
+    /// Type of the incremental chunks.
+    type Chunk: Send + 'static;
+
+    /// Execute the tool, streaming chunks before the final result.
+    fn call_stream(
+        &self,
+        input: Self::Input,
+        ctx: &ToolContext,
+    ) -> ToolStream<Self::Chunk, Self::Output>;
+}
+
+/// Drain `stream` into its final result, for implementing [`Tool::call`].
+///
+/// Each chunk is emitted on `ctx` as a [`ToolEvent::Chunk`]; chunks that fail to
+/// serialize are skipped. A stream that ends without a final item is an
+/// internal error.
+pub fn collect<C, O>(
+    mut stream: ToolStream<C, O>,
+    ctx: &ToolContext,
+) -> BoxFuture<'static, Result<O, ToolError>>
+where
+    C: Serialize + Send + 'static,
+    O: Send + 'static,
+{
+    let ctx = ctx.clone();
+    Box::pin(async move {
+        while let Some(item) = stream.next().await {
+            match item? {
+                StreamItem::Chunk(chunk) => {
+                    if let Ok(data) = serde_json::to_value(&chunk) {
+                        ctx.emit(ToolEvent::Chunk { data });
+                    }
+                }
+                StreamItem::Final(output) => return Ok(output),
+            }
+        }
+        Err(ToolError::internal("stream ended without a final result"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventSink;
+    use crate::registry::ToolRegistry;
+    use serde_json::json;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    /// Counts to `n`, streaming each number, and finishes with the total as text.
+    #[derive(Clone)]
+    struct CountTool;
+
+    impl Tool for CountTool {
+        type Input = u32;
+        type Output = String;
+        const NAME: &'static str = "count";
+        const DESCRIPTION: &'static str = "Count to n";
+
+        fn call(
+            &self,
+            input: Self::Input,
+            ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            collect(self.call_stream(input, ctx), ctx)
+        }
+    }
+
+    impl StreamingTool for CountTool {
+        type Chunk = u32;
+
+        fn call_stream(&self, input: u32, _ctx: &ToolContext) -> ToolStream<u32, String> {
+            let chunks = (1..=input).map(|i| Ok(StreamItem::Chunk(i)));
+            let total = Ok(StreamItem::Final((1..=input).sum::<u32>().to_string()));
+            futures::stream::iter(chunks.chain([total])).boxed()
+        }
+    }
+
+    fn registry() -> ToolRegistry {
+        ToolRegistry::builder()
+            .register_streaming::<CountTool, ()>(CountTool)
+            .finish()
+    }
+
+    async fn drain(
+        stream: JsonToolStream,
+    ) -> Vec<StreamItem<serde_json::Value, serde_json::Value>> {
+        stream.map(Result::unwrap).collect().await
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_stream_yields_chunks_then_final() {
+        let stream = registry()
+            .dispatch_stream("count", json!(3), &ToolContext::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            drain(stream).await,
+            [
+                StreamItem::Chunk(json!(1)),
+                StreamItem::Chunk(json!(2)),
+                StreamItem::Chunk(json!(3)),
+                StreamItem::Final(json!("6")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_streaming_dispatch_emits_chunks_as_events() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let ctx = ToolContext::default().with_events(EventSink::new({
+            let seen = Arc::clone(&seen);
+            move |event| seen.lock().unwrap().push(event)
+        }));
+
+        let result = registry()
+            .dispatch_json("count", json!(2), &ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(result, json!("3"));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [
+                ToolEvent::Chunk { data: json!(1) },
+                ToolEvent::Chunk { data: json!(2) },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_streaming_tool_streams_single_final() {
+        #[derive(Clone)]
+        struct Echo;
+
+        impl Tool for Echo {
+            type Input = String;
+            type Output = String;
+            const NAME: &'static str = "echo";
+            const DESCRIPTION: &'static str = "Echo";
+
+            fn call(
+                &self,
+                input: Self::Input,
+                _ctx: &ToolContext,
+            ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+                Box::pin(async move { Ok(input) })
+            }
+        }
+
+        let registry = ToolRegistry::builder().register::<Echo, ()>(Echo).finish();
+        let stream = registry
+            .dispatch_stream("echo", json!("hi"), &ToolContext::default())
+            .await
+            .unwrap();
+
+        assert_eq!(drain(stream).await, [StreamItem::Final(json!("hi"))]);
+    }
+}
@@ -1,8 +1,11 @@
 //! Core tool traits for native-first tool definitions.
 
+use crate::cache::CachePolicy;
 use crate::context::ToolContext;
 use crate::error::ToolError;
+use crate::permissions::Permissions;
 use futures::future::BoxFuture;
+use serde::Serialize;
 
 /// Native-first Tool trait with NO serde bounds.
 ///
@@ -45,12 +48,84 @@ pub trait Tool: Send + Sync + 'static {
     /// Human-readable description of what the tool does.
     const DESCRIPTION: &'static str;
 
+    /// Capabilities the tool needs; dispatch refuses calls that were not granted
+    /// all of them. See [`crate::permissions`].
+    const PERMISSIONS: Permissions = Permissions::NONE;
+
+    /// Version of the tool's contract, for tools that evolve in place.
+    const VERSION: Option<&'static str> = None;
+
+    /// Set when the tool should no longer be used. Provider renderers append a
+    /// [`Deprecation::notice`] to the description so agents move to the replacement.
+    const DEPRECATED: Option<Deprecation> = None;
+
     /// Execute the tool with the given input and context.
     fn call(
         &self,
         input: Self::Input,
         ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>>;
+
+    /// Opt in to per-session result caching for JSON dispatch.
+    ///
+    /// Only idempotent, read-only tools should return a policy. See
+    /// [`crate::cache`] for how cached results are scoped.
+    fn cache_policy(&self) -> Option<CachePolicy<Self::Input>> {
+        None
+    }
+}
+
+/// Marks a tool as deprecated, optionally naming the tool to use instead.
+///
+/// ```ignore
+/// const DEPRECATED: Option<Deprecation> = Some(Deprecation::replaced_by("gh_get_comments_v2"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Deprecation {
+    /// Name of the tool that supersedes this one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<&'static str>,
+    /// Extra guidance, e.g. how arguments changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<&'static str>,
+}
+
+impl Deprecation {
+    /// Deprecated with no replacement.
+    pub const fn new() -> Self {
+        Self {
+            replacement: None,
+            note: None,
+        }
+    }
+
+    /// Deprecated in favour of the tool named `replacement`.
+    pub const fn replaced_by(replacement: &'static str) -> Self {
+        Self {
+            replacement: Some(replacement),
+            note: None,
+        }
+    }
+
+    /// Attach extra guidance.
+    #[must_use]
+    pub const fn with_note(mut self, note: &'static str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sentence appended to the tool's description for models.
+    pub fn notice(&self) -> String {
+        let mut notice = match self.replacement {
+            Some(replacement) => format!("DEPRECATED: use {replacement} instead."),
+            None => "DEPRECATED.".to_string(),
+        };
+        if let Some(note) = self.note {
+            notice.push(' ');
+            notice.push_str(note);
+        }
+        notice
+    }
 }
 
 /// Codec for serializing tool inputs/outputs at protocol boundaries.
@@ -0,0 +1,224 @@
+//! Argument validation against each tool's JSON Schema.
+//!
+//! Without validation, malformed arguments surface as serde deserialization
+//! failures that stop at the first problem and name Rust types rather than
+//! fields. [`InputValidator`] checks the raw arguments against the tool's
+//! `input_schema()` first and reports every violation as a [`FieldError`] with a
+//! JSON Pointer to the offending value.
+//!
+//! Schemas are compiled once, after [`SchemaEngine`] transforms, so the checks
+//! match the schema a provider was shown:
+//!
+//! ```rust,ignore
+//! let registry = registry.with_input_validation(&engine)?;
+//! ```
+
+use crate::context::ToolContext;
+use crate::error::FieldError;
+use crate::error::ToolError;
+use crate::middleware::ToolMiddleware;
+use crate::registry::ToolRegistry;
+use crate::schema::SchemaEngine;
+use futures::future::BoxFuture;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Middleware that rejects arguments which do not match the tool's input schema.
+///
+/// Rejected calls fail with [`ToolError::InvalidArguments`] before the tool runs.
+/// Calls to tools the validator was not built for pass through unchecked.
+pub struct InputValidator {
+    validators: HashMap<String, jsonschema::Validator>,
+}
+
+impl InputValidator {
+    /// Compile validators for every tool in `registry`, applying `engine`'s
+    /// transforms to each input schema first.
+    ///
+    /// Fails with [`ToolError::Internal`] if a transformed schema is not a valid
+    /// JSON Schema.
+    pub fn new(registry: &ToolRegistry, engine: &SchemaEngine) -> Result<Self, ToolError> {
+        let mut validators = HashMap::new();
+        for (name, tool) in registry.iter_named() {
+            let schema = engine.transform(name, tool.input_schema());
+            let validator = jsonschema::validator_for(schema.as_value()).map_err(|e| {
+                ToolError::internal(format!("invalid input schema for {name}: {e}"))
+            })?;
+            validators.insert(name.to_string(), validator);
+        }
+        Ok(Self { validators })
+    }
+
+    /// Check `args` against the named tool's input schema.
+    ///
+    /// Returns every violation, not just the first. Unknown tools validate.
+    pub fn validate(&self, name: &str, args: &Value) -> Result<(), ToolError> {
+        let Some(validator) = self.validators.get(name) else {
+            return Ok(());
+        };
+        let errors: Vec<FieldError> = validator
+            .iter_errors(args)
+            .map(|error| FieldError {
+                path: error.instance_path.to_string(),
+                message: error.to_string(),
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ToolError::InvalidArguments(errors))
+        }
+    }
+}
+
+impl ToolMiddleware for InputValidator {
+    fn before<'a>(
+        &'a self,
+        name: &'a str,
+        args: &'a mut Value,
+        _ctx: &'a ToolContext,
+    ) -> BoxFuture<'a, Result<(), ToolError>> {
+        let result = self.validate(name, args);
+        Box::pin(async move { result })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fmt::TextFormat;
+    use crate::schema::FieldConstraint;
+    use crate::tool::Tool;
+    use schemars::JsonSchema;
+    use serde::Deserialize;
+    use serde::Serialize;
+
+    #[derive(Debug, Deserialize, JsonSchema)]
+    #[expect(dead_code, reason = "only the generated schema is exercised")]
+    struct GrepInput {
+        pattern: String,
+        #[serde(default)]
+        head_limit: Option<u32>,
+        #[serde(default)]
+        mode: Option<String>,
+    }
+
+    #[derive(Serialize, JsonSchema)]
+    struct GrepOutput {
+        matches: usize,
+    }
+
+    impl TextFormat for GrepOutput {}
+
+    #[derive(Clone)]
+    struct GrepTool;
+
+    impl Tool for GrepTool {
+        type Input = GrepInput;
+        type Output = GrepOutput;
+        const NAME: &'static str = "grep";
+        const DESCRIPTION: &'static str = "Search files";
+
+        fn call(
+            &self,
+            input: Self::Input,
+            _ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            Box::pin(async move {
+                Ok(GrepOutput {
+                    matches: input.pattern.len(),
+                })
+            })
+        }
+    }
+
+    fn registry() -> ToolRegistry {
+        ToolRegistry::builder()
+            .register::<GrepTool, ()>(GrepTool)
+            .finish()
+    }
+
+    #[tokio::test]
+    async fn test_reports_every_field_error() {
+        let registry = registry()
+            .with_input_validation(&SchemaEngine::new())
+            .unwrap();
+
+        let err = registry
+            .dispatch_json(
+                "grep",
+                serde_json::json!({ "pattern": 1, "head_limit": -1 }),
+                &ToolContext::default(),
+            )
+            .await
+            .unwrap_err();
+
+        let ToolError::InvalidArguments(errors) = &err else {
+            panic!("expected InvalidArguments, got {err:?}");
+        };
+        let mut paths: Vec<&str> = errors.iter().map(|e| e.path.as_str()).collect();
+        paths.sort_unstable();
+        assert_eq!(paths, ["/head_limit", "/pattern"]);
+        assert!(err.to_string().starts_with("invalid input: /"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_required_field_reported_at_root() {
+        let validator = InputValidator::new(&registry(), &SchemaEngine::new()).unwrap();
+
+        let err = validator
+            .validate("grep", &serde_json::json!({}))
+            .unwrap_err();
+
+        let ToolError::InvalidArguments(errors) = err else {
+            panic!("expected InvalidArguments");
+        };
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "");
+        assert!(errors[0].message.contains("pattern"));
+    }
+
+    #[tokio::test]
+    async fn test_engine_transforms_apply() {
+        let mut engine = SchemaEngine::new();
+        engine.constrain_field(
+            "grep",
+            vec!["properties".into(), "mode".into()],
+            FieldConstraint::Enum(vec![
+                serde_json::json!("files"),
+                serde_json::json!("content"),
+            ]),
+        );
+        let registry = registry().with_input_validation(&engine).unwrap();
+        let ctx = ToolContext::default();
+
+        let ok = registry
+            .dispatch_json(
+                "grep",
+                serde_json::json!({ "pattern": "x", "mode": "files" }),
+                &ctx,
+            )
+            .await
+            .unwrap();
+        assert_eq!(ok, serde_json::json!({ "matches": 1 }));
+
+        let err = registry
+            .dispatch_json(
+                "grep",
+                serde_json::json!({ "pattern": "x", "mode": "count" }),
+                &ctx,
+            )
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, ToolError::InvalidArguments(errors) if errors[0].path == "/mode"),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn test_unknown_tool_passes() {
+        let validator = InputValidator::new(&registry(), &SchemaEngine::new()).unwrap();
+        assert!(validator.validate("missing", &Value::Null).is_ok());
+    }
+}
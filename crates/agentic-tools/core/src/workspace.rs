@@ -0,0 +1,213 @@
+//! Scratch directories and persistent artifacts for tool calls.
+//!
+//! Every [`ToolContext`](crate::ToolContext) can hand out a scratch directory that is
+//! created on first use and removed when the last clone of the context is dropped,
+//! i.e. when the call finishes. [`SessionState`](crate::SessionState) carries a
+//! second scratch directory that lives as long as the client session.
+//!
+//! Files worth keeping (diffs, exports, crawl dumps) are moved out of scratch with
+//! [`ToolContext::promote_artifact`](crate::ToolContext::promote_artifact), which
+//! returns an [`Artifact`] that results and logs can reference by path.
+//!
+//! Artifacts are stored under `<root>/<session>/`, where `<root>` is
+//! `AGENTIC_ARTIFACT_DIR`, else `$XDG_STATE_HOME/agentic/artifacts`, else
+//! `~/.local/state/agentic/artifacts`, and `<session>` is the session id or
+//! `direct` for contexts without a session.
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// Environment variable overriding the artifact root directory.
+pub const ARTIFACT_DIR_ENV: &str = "AGENTIC_ARTIFACT_DIR";
+
+static NEXT_SCRATCH_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A directory that is removed, with its contents, when dropped.
+#[derive(Debug)]
+pub struct ScratchDir {
+    path: PathBuf,
+}
+
+impl ScratchDir {
+    /// Create a fresh, uniquely named directory under the system temp dir.
+    pub fn create(label: &str) -> io::Result<Self> {
+        let base = std::env::temp_dir();
+        loop {
+            let id = NEXT_SCRATCH_ID.fetch_add(1, Ordering::Relaxed);
+            let path = base.join(format!("agentic-{label}-{}-{id}", std::process::id()));
+            #[expect(
+                clippy::create_dir,
+                reason = "failing on an existing directory is what makes the name unique"
+            )]
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(Self { path }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_dir_all(&self.path)
+            && e.kind() != io::ErrorKind::NotFound
+        {
+            tracing::debug!("failed to remove scratch dir {}: {e}", self.path.display());
+        }
+    }
+}
+
+/// A [`ScratchDir`] created on first access.
+#[derive(Debug)]
+pub(crate) struct LazyScratch {
+    label: &'static str,
+    dir: Mutex<Option<Arc<ScratchDir>>>,
+}
+
+impl LazyScratch {
+    pub(crate) fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            dir: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn get_or_create(&self) -> io::Result<PathBuf> {
+        let mut dir = self
+            .dir
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(dir) = dir.as_ref() {
+            return Ok(dir.path().to_path_buf());
+        }
+        let created = Arc::new(ScratchDir::create(self.label)?);
+        let path = created.path().to_path_buf();
+        *dir = Some(created);
+        Ok(path)
+    }
+}
+
+/// A file promoted out of scratch into persistent storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Artifact {
+    /// File name within the artifact directory.
+    pub name: String,
+    /// Absolute path of the stored file.
+    pub path: PathBuf,
+    /// Size in bytes.
+    pub bytes: u64,
+}
+
+impl fmt::Display for Artifact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} bytes)", self.path.display(), self.bytes)
+    }
+}
+
+/// Default root directory for promoted artifacts.
+pub fn default_artifact_root() -> PathBuf {
+    let env_dir = |var: &str| {
+        std::env::var_os(var)
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+    };
+    if let Some(root) = env_dir(ARTIFACT_DIR_ENV) {
+        return root;
+    }
+    env_dir("XDG_STATE_HOME")
+        .or_else(|| env_dir("HOME").map(|h| h.join(".local").join("state")))
+        .unwrap_or_else(std::env::temp_dir)
+        .join("agentic")
+        .join("artifacts")
+}
+
+/// Move `src` into `dir` as `name`, avoiding collisions with existing files.
+pub(crate) fn promote(src: &Path, dir: &Path, name: &str) -> io::Result<Artifact> {
+    let name = Path::new(name)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .filter(|n| !n.is_empty())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid artifact name '{name}'"),
+            )
+        })?
+        .to_string();
+    std::fs::create_dir_all(dir)?;
+
+    let mut dest_name = name.clone();
+    let mut n = 1;
+    while dir.join(&dest_name).exists() {
+        n += 1;
+        dest_name = match name.rsplit_once('.') {
+            Some((stem, ext)) if !stem.is_empty() => format!("{stem}-{n}.{ext}"),
+            _ => format!("{name}-{n}"),
+        };
+    }
+    let dest = dir.join(&dest_name);
+
+    // Scratch usually lives on a different filesystem; fall back to copy + remove.
+    if std::fs::rename(src, &dest).is_err() {
+        std::fs::copy(src, &dest)?;
+        std::fs::remove_file(src)?;
+    }
+    let bytes = std::fs::metadata(&dest)?.len();
+    Ok(Artifact {
+        name: dest_name,
+        path: dest,
+        bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scratch_dir_is_removed_on_drop() {
+        let dir = ScratchDir::create("test").unwrap();
+        let path = dir.path().to_path_buf();
+        std::fs::write(path.join("f.txt"), "x").unwrap();
+        assert!(path.is_dir());
+
+        drop(dir);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn promote_moves_file_and_avoids_collisions() {
+        let scratch = ScratchDir::create("test").unwrap();
+        let out = ScratchDir::create("test-out").unwrap();
+
+        let first = scratch.path().join("a");
+        std::fs::write(&first, "one").unwrap();
+        let a = promote(&first, out.path(), "report.diff").unwrap();
+        assert_eq!(a.name, "report.diff");
+        assert_eq!(a.bytes, 3);
+        assert!(!first.exists());
+
+        let second = scratch.path().join("b");
+        std::fs::write(&second, "two!").unwrap();
+        let b = promote(&second, out.path(), "../report.diff").unwrap();
+        assert_eq!(b.name, "report-2.diff");
+        assert_eq!(std::fs::read_to_string(&b.path).unwrap(), "two!");
+
+        let err = promote(&second, out.path(), "..").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -1,9 +1,10 @@
 //! Golden tests for provider schema rendering.
 //!
 //! These tests verify that schema rendering produces consistent output
-//! across all supported providers (`OpenAI`, Anthropic, MCP).
+//! across all supported providers (`OpenAI`, Anthropic, Gemini, MCP).
 
 use agentic_tools_core::providers::anthropic;
+use agentic_tools_core::providers::gemini;
 use agentic_tools_core::providers::mcp;
 use agentic_tools_core::providers::openai;
 use schemars::JsonSchema;
@@ -205,6 +206,27 @@ fn golden_mcp_complex_schema() {
     assert!(input_schema["properties"]["options"].is_object());
 }
 
+// =============================================================================
+// Gemini Provider Golden Tests
+// =============================================================================
+
+#[test]
+fn golden_gemini_complex_schema() {
+    let schema = schema_for!(ComplexInput);
+    let rendered = gemini::render_tool("complex_tool", "A complex tool", &schema);
+
+    assert_eq!(rendered["name"], "complex_tool");
+    let params = &rendered["parameters"];
+
+    // Nested $ref is inlined and Option<u32> becomes nullable
+    let limit = &params["properties"]["options"]["properties"]["limit"];
+    assert_eq!(limit["type"], "integer");
+    assert_eq!(limit["nullable"], true);
+    assert!(limit.get("format").is_none());
+    assert!(params.get("$defs").is_none());
+    assert!(!params.to_string().contains("$ref"));
+}
+
 // =============================================================================
 // Cross-Provider Consistency Tests
 // =============================================================================
@@ -0,0 +1,301 @@
+//! MCP client bridge exposing a remote server's tools as registry entries.
+//!
+//! [`McpBridge`] connects to an MCP server, usually a child process speaking
+//! MCP over stdio, and [`McpBridge::registry`] wraps each tool it lists as an
+//! [`ErasedTool`] that forwards JSON calls to the server. Out-of-tree tools can
+//! then be merged into a [`ToolRegistry`] like any in-tree domain.
+//!
+//! Registry metadata is `'static`, so remote tool names and descriptions are
+//! leaked once per bridged tool; bridges are meant to live for the process.
+
+use agentic_tools_core::FormattedResult;
+use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolRegistry;
+use agentic_tools_core::fmt::TextOptions;
+use agentic_tools_core::fmt::fallback_text_from_json;
+use agentic_tools_core::registry::ErasedTool;
+use futures::future::BoxFuture;
+use rmcp::RoleClient;
+use rmcp::model as m;
+use rmcp::service::RunningService;
+use rmcp::service::ServiceExt;
+use rmcp::transport::IntoTransport;
+use rmcp::transport::TokioChildProcess;
+use schemars::Schema;
+use serde_json::Value;
+use std::any::TypeId;
+use std::sync::Arc;
+use tokio::process::Command;
+
+/// Connection to an MCP server whose tools are bridged into a registry.
+#[derive(Clone)]
+pub struct McpBridge {
+    service: Arc<RunningService<RoleClient, ()>>,
+}
+
+impl McpBridge {
+    /// Complete the MCP handshake over `transport`.
+    pub async fn connect<T, E, A>(transport: T) -> Result<Self, ToolError>
+    where
+        T: IntoTransport<RoleClient, E, A>,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        let service = ()
+            .serve(transport)
+            .await
+            .map_err(|e| ToolError::external(format!("MCP handshake failed: {e}")))?;
+        Ok(Self {
+            service: Arc::new(service),
+        })
+    }
+
+    /// Spawn `command` and connect to it over stdio.
+    pub async fn spawn(command: Command) -> Result<Self, ToolError> {
+        let transport = TokioChildProcess::new(command)
+            .map_err(|e| ToolError::external(format!("failed to spawn MCP server: {e}")))?;
+        Self::connect(transport).await
+    }
+
+    /// Registry of every tool the server lists, registered under its own name.
+    pub async fn registry(&self) -> Result<ToolRegistry, ToolError> {
+        let tools = self
+            .service
+            .list_all_tools()
+            .await
+            .map_err(|e| ToolError::external(format!("MCP list_tools failed: {e}")))?;
+        Ok(tools
+            .into_iter()
+            .fold(ToolRegistry::builder(), |builder, tool| {
+                builder.register_erased(Arc::new(BridgedTool::new(self.clone(), tool)))
+            })
+            .finish())
+    }
+}
+
+/// One remote tool, forwarding calls through its [`McpBridge`].
+struct BridgedTool {
+    bridge: McpBridge,
+    name: &'static str,
+    description: &'static str,
+    input_schema: Schema,
+    output_schema: Option<Schema>,
+}
+
+impl BridgedTool {
+    fn new(bridge: McpBridge, tool: m::Tool) -> Self {
+        let to_schema = |object: &m::JsonObject| Schema::from(object.clone());
+        Self {
+            bridge,
+            name: Box::leak(tool.name.into_owned().into_boxed_str()),
+            description: Box::leak(
+                tool.description
+                    .map(std::borrow::Cow::into_owned)
+                    .unwrap_or_default()
+                    .into_boxed_str(),
+            ),
+            input_schema: to_schema(&tool.input_schema),
+            output_schema: tool.output_schema.as_deref().map(to_schema),
+        }
+    }
+
+    fn call(
+        &self,
+        args: Value,
+        ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Remote, ToolError>> {
+        let service = Arc::clone(&self.bridge.service);
+        let mut params = m::CallToolRequestParams::new(self.name);
+        match args {
+            Value::Object(arguments) => params = params.with_arguments(arguments),
+            Value::Null => {}
+            other => {
+                return Box::pin(async move {
+                    Err(ToolError::invalid_input(format!(
+                        "arguments must be a JSON object, got {other}"
+                    )))
+                });
+            }
+        }
+        let ctx = ctx.clone();
+        Box::pin(async move {
+            let result = ctx
+                .run_cancellable(async {
+                    service
+                        .call_tool(params)
+                        .await
+                        .map_err(|e| ToolError::external(format!("MCP call failed: {e}")))
+                })
+                .await?;
+            Remote::from_result(result)
+        })
+    }
+}
+
+/// A successful remote result, split into data and text.
+struct Remote {
+    data: Value,
+    text: Option<String>,
+}
+
+impl Remote {
+    /// Data is the structured content if present, else the text content parsed
+    /// as JSON, else the text itself. Error results become [`ToolError::External`].
+    fn from_result(result: m::CallToolResult) -> Result<Self, ToolError> {
+        let texts: Vec<&str> = result
+            .content
+            .iter()
+            .filter_map(|content| content.as_text().map(|t| t.text.as_str()))
+            .collect();
+        let text = (!texts.is_empty()).then(|| texts.join("\n"));
+        if result.is_error == Some(true) {
+            return Err(ToolError::external(
+                text.unwrap_or_else(|| "remote tool failed".into()),
+            ));
+        }
+        let data = match (result.structured_content, &text) {
+            (Some(data), _) => data,
+            (None, Some(text)) => {
+                serde_json::from_str(text).unwrap_or_else(|_| Value::String(text.clone()))
+            }
+            (None, None) => Value::Null,
+        };
+        Ok(Self { data, text })
+    }
+}
+
+impl ErasedTool for BridgedTool {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn input_schema(&self) -> Schema {
+        self.input_schema.clone()
+    }
+
+    fn output_schema(&self) -> Option<Schema> {
+        self.output_schema.clone()
+    }
+
+    fn call_json(
+        &self,
+        args: Value,
+        ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Value, ToolError>> {
+        let call = self.call(args, ctx);
+        Box::pin(async move { Ok(call.await?.data) })
+    }
+
+    fn call_json_formatted(
+        &self,
+        args: Value,
+        ctx: &ToolContext,
+        _text_opts: &TextOptions,
+    ) -> BoxFuture<'static, Result<FormattedResult, ToolError>> {
+        let call = self.call(args, ctx);
+        Box::pin(async move {
+            let Remote { data, text } = call.await?;
+            let text = text.or_else(|| Some(fallback_text_from_json(&data)));
+            Ok(FormattedResult { data, text })
+        })
+    }
+
+    fn type_id(&self) -> TypeId {
+        TypeId::of::<Self>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RegistryServer;
+    use agentic_tools_core::Tool;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, schemars::JsonSchema)]
+    struct GreetInput {
+        name: String,
+    }
+
+    #[derive(Clone)]
+    struct Greet;
+
+    impl Tool for Greet {
+        type Input = GreetInput;
+        type Output = String;
+        const NAME: &'static str = "greet";
+        const DESCRIPTION: &'static str = "Greet someone";
+
+        fn call(
+            &self,
+            input: Self::Input,
+            _ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            Box::pin(async move {
+                if input.name.is_empty() {
+                    Err(ToolError::invalid_input("name is empty"))
+                } else {
+                    Ok(format!("Hello, {}!", input.name))
+                }
+            })
+        }
+    }
+
+    /// Registry bridged from an in-process server exposing a single `greet` tool.
+    async fn bridged() -> ToolRegistry {
+        let registry = ToolRegistry::builder()
+            .register::<Greet, ()>(Greet)
+            .finish();
+        let server = RegistryServer::new(Arc::new(registry));
+        let (server_transport, client_transport) = tokio::io::duplex(4096);
+        tokio::spawn(async move {
+            if let Ok(running) = server.serve(server_transport).await {
+                let _ = running.waiting().await;
+            }
+        });
+        let bridge = McpBridge::connect(client_transport).await.unwrap();
+        bridge.registry().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_bridged_tools_are_listed_and_callable() {
+        let registry = bridged().await;
+        let tool = registry.get("greet").unwrap();
+        assert_eq!(tool.description(), "Greet someone");
+        assert!(tool.input_schema().as_value()["properties"]["name"].is_object());
+
+        let result = registry
+            .dispatch_json_formatted(
+                "greet",
+                serde_json::json!({ "name": "Ada" }),
+                &ToolContext::default(),
+                &TextOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.data, "Hello, Ada!");
+        assert_eq!(result.text.as_deref(), Some("Hello, Ada!"));
+    }
+
+    #[tokio::test]
+    async fn test_remote_errors_and_bad_arguments() {
+        let registry = bridged().await;
+        let ctx = ToolContext::default();
+
+        let err = registry
+            .dispatch_json("greet", serde_json::json!({ "name": "" }), &ctx)
+            .await
+            .unwrap_err();
+        assert!(matches!(&err, ToolError::External(msg) if msg.contains("name is empty")));
+
+        let err = registry
+            .dispatch_json("greet", serde_json::json!("Ada"), &ctx)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ToolError::InvalidInput(_)));
+    }
+}
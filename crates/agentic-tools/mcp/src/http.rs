@@ -0,0 +1,272 @@
+//! Streamable HTTP transport: MCP over HTTP POST with SSE responses.
+//!
+//! [`router`] mounts rmcp's streamable HTTP service at [`MCP_PATH`] behind
+//! bearer-token auth, so one tool server can be shared by several editor
+//! clients or deployed remotely. Each MCP session gets a fresh
+//! [`RegistryServer`] from the caller's factory, keeping session state apart.
+//!
+//! A token may carry its own allowlist, applied on top of the server's: one
+//! process can give an editor every tool and a remote agent read-only ones.
+
+use crate::RegistryServer;
+use axum::Router;
+use axum::extract::Request;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::http::header;
+use axum::middleware;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use rmcp::model as m;
+use rmcp::transport::streamable_http_server::StreamableHttpServerConfig;
+use rmcp::transport::streamable_http_server::StreamableHttpService;
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+
+/// Path the MCP endpoint is served on.
+pub const MCP_PATH: &str = "/mcp";
+
+/// Authentication and host settings for [`router`].
+///
+/// With no tokens, requests are not authenticated; bind such servers to
+/// loopback only.
+#[derive(Clone, Debug, Default)]
+pub struct HttpOptions {
+    tokens: Vec<(String, Access)>,
+    allowed_hosts: Option<Vec<String>>,
+}
+
+impl HttpOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accept `token` with access to every tool the server exposes.
+    #[must_use]
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.tokens.push((token.into(), Access::Full));
+        self
+    }
+
+    /// Accept `token`, limited to the tools in `allowlist`.
+    #[must_use]
+    pub fn with_restricted_token(
+        mut self,
+        token: impl Into<String>,
+        allowlist: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let allowlist = ConnectionAllowlist(Arc::new(allowlist.into_iter().collect()));
+        self.tokens
+            .push((token.into(), Access::Restricted(allowlist)));
+        self
+    }
+
+    /// Accept these `Host` headers instead of the loopback-only default, which
+    /// guards local servers against DNS rebinding. Required for remote
+    /// deployments.
+    #[must_use]
+    pub fn with_allowed_hosts(mut self, hosts: impl IntoIterator<Item = String>) -> Self {
+        self.allowed_hosts = Some(hosts.into_iter().collect());
+        self
+    }
+
+    /// Whether any token is configured.
+    pub fn requires_auth(&self) -> bool {
+        !self.tokens.is_empty()
+    }
+
+    /// Access granted by `token`, or `None` if the token is unknown.
+    fn authorize(&self, token: &str) -> Option<&Access> {
+        self.tokens
+            .iter()
+            .find(|(known, _)| constant_time_eq(known.as_bytes(), token.as_bytes()))
+            .map(|(_, access)| access)
+    }
+}
+
+/// What a token grants.
+#[derive(Clone, Debug)]
+enum Access {
+    Full,
+    Restricted(ConnectionAllowlist),
+}
+
+/// Tools a connection may use, attached to its requests by the auth layer.
+#[derive(Clone, Debug)]
+struct ConnectionAllowlist(Arc<HashSet<String>>);
+
+/// Allowlist of the HTTP connection a request arrived on, if restricted.
+pub(crate) fn connection_allowlist(extensions: &m::Extensions) -> Option<&HashSet<String>> {
+    extensions
+        .get::<axum::http::request::Parts>()?
+        .extensions
+        .get::<ConnectionAllowlist>()
+        .map(|allowlist| &*allowlist.0)
+}
+
+/// Router serving MCP at [`MCP_PATH`], building one server per session with
+/// `factory`.
+///
+/// Requests without a valid `Authorization: Bearer` token get `401` when
+/// `options` lists any tokens.
+pub fn router<F>(factory: F, options: HttpOptions) -> Router
+where
+    F: Fn() -> RegistryServer + Send + Sync + 'static,
+{
+    let mut config = StreamableHttpServerConfig::default();
+    if let Some(hosts) = options.allowed_hosts.clone() {
+        config = config.with_allowed_hosts(hosts);
+    }
+    let service = StreamableHttpService::new(
+        move || Ok(factory()),
+        Arc::new(LocalSessionManager::default()),
+        config,
+    );
+    Router::new()
+        .route_service(MCP_PATH, service)
+        .layer(middleware::from_fn_with_state(
+            Arc::new(options),
+            authenticate,
+        ))
+}
+
+/// Serve [`router`] on `listener` until the process exits.
+pub async fn serve<F>(
+    listener: TcpListener,
+    factory: F,
+    options: HttpOptions,
+) -> std::io::Result<()>
+where
+    F: Fn() -> RegistryServer + Send + Sync + 'static,
+{
+    axum::serve(listener, router(factory, options)).await
+}
+
+async fn authenticate(
+    State(options): State<Arc<HttpOptions>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if options.requires_auth() {
+        let token = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        match token.and_then(|token| options.authorize(token.trim())) {
+            Some(Access::Restricted(allowlist)) => {
+                request.extensions_mut().insert(allowlist.clone());
+            }
+            Some(Access::Full) => {}
+            None => {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    [(header::WWW_AUTHENTICATE, "Bearer")],
+                )
+                    .into_response();
+            }
+        }
+    }
+    next.run(request).await
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::McpBridge;
+    use agentic_tools_core::Tool;
+    use agentic_tools_core::ToolContext;
+    use agentic_tools_core::ToolError;
+    use agentic_tools_core::ToolRegistry;
+    use futures::future::BoxFuture;
+    use rmcp::transport::StreamableHttpClientTransport;
+    use rmcp::transport::streamable_http_client::StreamableHttpClientTransportConfig;
+
+    #[derive(serde::Deserialize, schemars::JsonSchema)]
+    struct NoInput {}
+
+    #[derive(Clone)]
+    struct Named<const WRITE: bool>;
+
+    impl<const WRITE: bool> Tool for Named<WRITE> {
+        type Input = NoInput;
+        type Output = String;
+        const NAME: &'static str = if WRITE { "write" } else { "read" };
+        const DESCRIPTION: &'static str = "Return the tool's name";
+
+        fn call(
+            &self,
+            _input: Self::Input,
+            _ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            Box::pin(async { Ok(Self::NAME.to_string()) })
+        }
+    }
+
+    /// URL of a server exposing `read` and `write`, with an admin token and a
+    /// read-only token.
+    async fn spawn_server() -> String {
+        let registry = Arc::new(
+            ToolRegistry::builder()
+                .register::<Named<false>, ()>(Named)
+                .register::<Named<true>, ()>(Named)
+                .finish(),
+        );
+        let options = HttpOptions::new()
+            .with_token("admin")
+            .with_restricted_token("reader", ["read".to_string()]);
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}{MCP_PATH}", listener.local_addr().unwrap());
+        tokio::spawn(serve(
+            listener,
+            move || RegistryServer::new(Arc::clone(&registry)),
+            options,
+        ));
+        url
+    }
+
+    async fn tool_names(url: &str, token: &str) -> Result<Vec<String>, ToolError> {
+        let transport = StreamableHttpClientTransport::from_config(
+            StreamableHttpClientTransportConfig::with_uri(url).auth_header(token),
+        );
+        let mut names = McpBridge::connect(transport)
+            .await?
+            .registry()
+            .await?
+            .list_names();
+        names.sort_unstable();
+        Ok(names)
+    }
+
+    #[tokio::test]
+    async fn tokens_gate_access_and_scope_tools() {
+        let url = spawn_server().await;
+
+        assert_eq!(tool_names(&url, "admin").await.unwrap(), ["read", "write"]);
+        assert_eq!(tool_names(&url, "reader").await.unwrap(), ["read"]);
+        assert!(tool_names(&url, "wrong").await.is_err());
+
+        let status = reqwest::Client::new()
+            .post(&url)
+            .json(&serde_json::json!({}))
+            .send()
+            .await
+            .unwrap()
+            .status();
+        assert_eq!(status.as_u16(), 401);
+    }
+
+    #[test]
+    fn constant_time_eq_compares_contents() {
+        assert!(constant_time_eq(b"token", b"token"));
+        assert!(!constant_time_eq(b"token", b"tokem"));
+        assert!(!constant_time_eq(b"token", b"token2"));
+    }
+}
@@ -1,10 +1,29 @@
 //! MCP server integration for the agentic-tools library family.
 //!
 //! This crate provides [`RegistryServer`], an rmcp-backed server handler
-//! that wraps a [`ToolRegistry`] with optional allowlist filtering.
+//! that wraps a [`ToolRegistry`] with optional allowlist filtering and serves
+//! [`ResourceProvider`] contents as MCP resources, and
+//! [`McpBridge`], a client that exposes another MCP server's tools as registry
+//! entries.
+//!
+//! With the `http` feature, [`http`] serves the same handler over streamable
+//! HTTP with bearer-token auth.
 
+mod bridge;
+#[cfg(feature = "http")]
+pub mod http;
+mod progress;
+mod resources;
 mod server;
 
+pub use bridge::McpBridge;
+
+pub use resources::ResourceEntry;
+pub use resources::ResourceProvider;
+pub use resources::ResourceText;
+
+pub use server::DEFAULT_CANCEL_GRACE;
+pub use server::MANIFEST_CAPABILITY;
 pub use server::OutputMode;
 pub use server::RegistryServer;
 
@@ -0,0 +1,63 @@
+//! MCP `notifications/progress` fed by [`ToolEvent`]s.
+//!
+//! When a `tools/call` request carries a `progressToken`, the server attaches
+//! [`progress_sink`] to the call's [`ToolContext`](agentic_tools_core::ToolContext).
+//! [`ToolEvent::Progress`] messages are forwarded as they come; other events
+//! (subprocess output, transcript entries, text deltas) count as activity and
+//! are forwarded at most once per [`ACTIVITY_INTERVAL`], so clients see a live
+//! call without a notification per line or token.
+
+use agentic_tools_core::EventSink;
+use agentic_tools_core::ToolEvent;
+use rmcp::Peer;
+use rmcp::RoleServer;
+use rmcp::model as m;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use tokio::sync::mpsc;
+
+/// Minimum spacing of notifications for events other than [`ToolEvent::Progress`].
+const ACTIVITY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Event sink sending progress notifications for `token` to `peer`.
+///
+/// Notifications are sent in order from a background task that ends once every
+/// clone of the sink is dropped.
+pub fn progress_sink(peer: Peer<RoleServer>, token: m::ProgressToken) -> EventSink {
+    let (tx, mut rx) = mpsc::unbounded_channel::<Option<String>>();
+    tokio::spawn(async move {
+        let mut progress = 0.0;
+        while let Some(message) = rx.recv().await {
+            progress += 1.0;
+            let mut param = m::ProgressNotificationParam::new(token.clone(), progress);
+            param.message = message;
+            if let Err(e) = peer.notify_progress(param).await {
+                tracing::debug!("stopping progress notifications: {e}");
+                break;
+            }
+        }
+    });
+
+    let last_sent: Mutex<Option<Instant>> = Mutex::new(None);
+    EventSink::new(move |event| {
+        let mut last_sent = last_sent
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let now = Instant::now();
+        let message = match event {
+            ToolEvent::Progress { message } => Some(message),
+            ToolEvent::Output { .. }
+            | ToolEvent::Transcript { .. }
+            | ToolEvent::TextDelta { .. }
+            | ToolEvent::Chunk { .. } => {
+                if last_sent.is_some_and(|at| now.duration_since(at) < ACTIVITY_INTERVAL) {
+                    return;
+                }
+                None
+            }
+        };
+        *last_sent = Some(now);
+        let _ = tx.send(message);
+    })
+}
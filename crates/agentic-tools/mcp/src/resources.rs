@@ -0,0 +1,81 @@
+//! Read-only context served through MCP `resources/list` and `resources/read`.
+//!
+//! A [`ResourceProvider`] owns a set of URIs (usually under one scheme, e.g.
+//! `thoughts://`) and produces their text on demand.
+//! [`RegistryServer::with_resources`](crate::RegistryServer::with_resources)
+//! attaches providers; the server lists the union of their resources and routes
+//! each read to the first provider that recognises the URI.
+
+use agentic_tools_core::ToolError;
+use futures::future::BoxFuture;
+
+/// One readable resource, as listed to clients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceEntry {
+    pub uri: String,
+    /// Short human-readable name.
+    pub name: String,
+    pub description: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+impl ResourceEntry {
+    pub fn new(uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            uri: uri.into(),
+            name: name.into(),
+            description: None,
+            mime_type: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+}
+
+/// Text of a resource returned from [`ResourceProvider::read`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceText {
+    pub mime_type: Option<String>,
+    pub text: String,
+}
+
+impl ResourceText {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            mime_type: None,
+            text: text.into(),
+        }
+    }
+
+    /// Pretty-printed JSON of `value`, typed `application/json`.
+    pub fn json<T: serde::Serialize>(value: &T) -> Result<Self, ToolError> {
+        let text = serde_json::to_string_pretty(value)
+            .map_err(|e| ToolError::internal(format!("failed to serialize resource: {e}")))?;
+        Ok(Self::new(text).with_mime_type("application/json"))
+    }
+
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+}
+
+/// Source of MCP resources.
+pub trait ResourceProvider: Send + Sync {
+    /// Resources currently available.
+    fn list(&self) -> BoxFuture<'_, Result<Vec<ResourceEntry>, ToolError>>;
+
+    /// Contents of `uri`, or `None` if this provider does not serve it.
+    fn read<'a>(&'a self, uri: &'a str) -> BoxFuture<'a, Result<Option<ResourceText>, ToolError>>;
+}
@@ -1,16 +1,39 @@
 //! MCP server handler backed by `ToolRegistry`.
 
+use crate::progress::progress_sink;
+use crate::resources::ResourceProvider;
 use agentic_tools_core::ToolContext;
 use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolManifest;
 use agentic_tools_core::ToolRegistry;
 use agentic_tools_core::fmt::TextOptions;
 use agentic_tools_core::fmt::fallback_text_from_json;
+use agentic_tools_core::session::SessionState;
 use rmcp::RoleServer;
 use rmcp::ServerHandler;
 use rmcp::model as m;
 use rmcp::service::RequestContext;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Key under `capabilities.experimental` carrying the tool manifest.
+///
+/// The server advertises its [`ToolManifest`] there in the `initialize` response.
+/// A client may send `{"expectedSchemaVersion": N}` under the same key in its
+/// `initialize` request; a mismatch fails the handshake with `invalid_params`.
+///
+/// The same object may narrow the tools the client sees: `{"profile": "name"}`
+/// selects a profile registered with [`RegistryServer::with_profile`] (unknown
+/// names fail the handshake), and `{"allowlist": ["tool", ...]}` lists tools
+/// directly. Without either, a profile named after `clientInfo.name` applies if
+/// one exists.
+pub const MANIFEST_CAPABILITY: &str = "agentic-tools";
+
+/// Default time a cancelled call gets to clean up before its future is dropped.
+pub const DEFAULT_CANCEL_GRACE: Duration = Duration::from_secs(2);
 
 /// Output mode for tool results.
 #[derive(Clone, Copy, Debug, Default)]
@@ -28,8 +51,29 @@ pub enum OutputMode {
 ///
 /// Features:
 /// - Automatic tool discovery from registry
-/// - Optional allowlist filtering
+/// - Optional allowlist filtering, narrowed per client by profiles
 /// - Configurable output mode (text or structured)
+/// - Per-session state attached to every tool call
+/// - Progress notifications from tool events when the client sends a `progressToken`
+/// - Cancellation via `notifications/cancelled`
+/// - Schema version handshake via [`MANIFEST_CAPABILITY`]
+/// - Read-only resources from attached [`ResourceProvider`]s
+///
+/// # Sessions
+///
+/// Each server instance serves one client connection. A fresh [`SessionState`] is
+/// created on construction and replaced on every `initialize`, then attached to each
+/// [`ToolContext`] so tools can isolate pagination and other per-client state. Transports
+/// that accept multiple connections should construct one `RegistryServer` per connection
+/// (the registry itself is shared via `Arc`).
+///
+/// # Cancellation
+///
+/// A cancelled request cancels its [`ToolContext`], so cooperative tools stop and
+/// clean up (killing child processes, for instance). Tools that have not finished
+/// within the cancel grace period ([`DEFAULT_CANCEL_GRACE`] unless set with
+/// [`RegistryServer::with_cancel_grace`]) are dropped, which also kills
+/// `kill_on_drop` children, and the call returns [`ToolError::Cancelled`].
 ///
 /// # Output Modes
 ///
@@ -64,6 +108,12 @@ pub struct RegistryServer {
     text_options: TextOptions,
     name: String,
     version: String,
+    session: Mutex<Arc<SessionState>>,
+    resources: Vec<Arc<dyn ResourceProvider>>,
+    profiles: HashMap<String, Arc<HashSet<String>>>,
+    /// Allowlist the current client negotiated at `initialize`.
+    client_allowlist: Mutex<Option<Arc<HashSet<String>>>>,
+    cancel_grace: Duration,
 }
 
 impl RegistryServer {
@@ -76,6 +126,11 @@ impl RegistryServer {
             text_options: TextOptions::default(),
             name: "agentic-tools".to_string(),
             version: env!("CARGO_PKG_VERSION").to_string(),
+            session: Mutex::new(Arc::new(SessionState::new())),
+            resources: Vec::new(),
+            profiles: HashMap::new(),
+            client_allowlist: Mutex::new(None),
+            cancel_grace: DEFAULT_CANCEL_GRACE,
         }
     }
 
@@ -88,6 +143,28 @@ impl RegistryServer {
         self
     }
 
+    /// Register a named allowlist clients can select at `initialize`.
+    ///
+    /// A profile narrows the server's allowlist; it never exposes tools the
+    /// server itself hides. See [`MANIFEST_CAPABILITY`] for how clients pick one.
+    #[must_use]
+    pub fn with_profile(
+        mut self,
+        name: impl Into<String>,
+        tools: impl IntoIterator<Item = String>,
+    ) -> Self {
+        self.profiles
+            .insert(name.into(), Arc::new(tools.into_iter().collect()));
+        self
+    }
+
+    /// Set how long a cancelled call may take to stop before it is dropped.
+    #[must_use]
+    pub const fn with_cancel_grace(mut self, grace: Duration) -> Self {
+        self.cancel_grace = grace;
+        self
+    }
+
     /// Set the output mode for tool results.
     #[must_use]
     pub fn with_output_mode(mut self, mode: OutputMode) -> Self {
@@ -102,6 +179,15 @@ impl RegistryServer {
         self
     }
 
+    /// Serve the resources of `provider`, in addition to any attached earlier.
+    ///
+    /// The `resources` capability is advertised once a provider is attached.
+    #[must_use]
+    pub fn with_resources(mut self, provider: impl ResourceProvider + 'static) -> Self {
+        self.resources.push(Arc::new(provider));
+        self
+    }
+
     /// Set the server name and version.
     #[must_use]
     pub fn with_info(mut self, name: &str, version: &str) -> Self {
@@ -134,8 +220,121 @@ impl RegistryServer {
             .collect()
     }
 
+    /// Get the state of the current client session.
+    pub fn session(&self) -> Arc<SessionState> {
+        Arc::clone(
+            &self
+                .session
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        )
+    }
+
+    /// Start a new client session, discarding state from the previous one.
+    fn reset_session(&self) {
+        *self
+            .session
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner) = Arc::new(SessionState::new());
+    }
+
+    /// Manifest (schema version and per-tool hashes) of the effective tools.
+    pub fn manifest(&self) -> ToolManifest {
+        self.manifest_in(&m::Extensions::new())
+    }
+
+    /// Manifest of the tools a request carrying `extensions` may use.
+    fn manifest_in(&self, extensions: &m::Extensions) -> ToolManifest {
+        let names: Vec<String> = self
+            .registry
+            .list_names()
+            .into_iter()
+            .filter(|n| self.is_allowed_in(n, extensions))
+            .collect();
+        self.registry
+            .subset(names.iter().map(String::as_str))
+            .manifest()
+    }
+
     fn is_allowed(&self, name: &str) -> bool {
         self.allowlist.as_ref().is_none_or(|set| set.contains(name))
+            && self
+                .client_allowlist
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .as_ref()
+                .is_none_or(|set| set.contains(name))
+    }
+
+    /// Allowlist the client asks for in its `initialize` request.
+    fn requested_allowlist(
+        &self,
+        params: &m::InitializeRequestParams,
+    ) -> Result<Option<Arc<HashSet<String>>>, ToolError> {
+        let requested = params
+            .capabilities
+            .experimental
+            .as_ref()
+            .and_then(|experimental| experimental.get(MANIFEST_CAPABILITY));
+        if let Some(profile) = requested.and_then(|r| r.get("profile")) {
+            let name = profile
+                .as_str()
+                .ok_or_else(|| ToolError::invalid_input("profile must be a string"))?;
+            return self
+                .profiles
+                .get(name)
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| ToolError::invalid_input(format!("unknown profile '{name}'")));
+        }
+        if let Some(allowlist) = requested.and_then(|r| r.get("allowlist")) {
+            let tools: Option<HashSet<String>> = allowlist.as_array().and_then(|tools| {
+                tools
+                    .iter()
+                    .map(|tool| tool.as_str().map(str::to_string))
+                    .collect()
+            });
+            return tools.map(|tools| Some(Arc::new(tools))).ok_or_else(|| {
+                ToolError::invalid_input("allowlist must be an array of tool names")
+            });
+        }
+        Ok(self.profiles.get(&params.client_info.name).cloned())
+    }
+
+    /// Whether a request carrying `extensions` may use `name`: allowed by the
+    /// server and by the transport connection it arrived on.
+    fn is_allowed_in(&self, name: &str, extensions: &m::Extensions) -> bool {
+        self.is_allowed(name)
+            && connection_allowlist(extensions).is_none_or(|set| set.contains(name))
+    }
+}
+
+#[cfg(feature = "http")]
+use crate::http::connection_allowlist;
+
+/// Without the HTTP transport no connection carries its own allowlist.
+#[cfg(not(feature = "http"))]
+const fn connection_allowlist(_extensions: &m::Extensions) -> Option<&HashSet<String>> {
+    None
+}
+
+/// `expectedSchemaVersion` the client sent under [`MANIFEST_CAPABILITY`], if any.
+fn expected_schema_version(params: &m::InitializeRequestParams) -> Option<u64> {
+    params
+        .capabilities
+        .experimental
+        .as_ref()?
+        .get(MANIFEST_CAPABILITY)?
+        .get("expectedSchemaVersion")?
+        .as_u64()
+}
+
+/// MCP error for a failed `resources/read`.
+fn resource_error(e: &ToolError) -> m::ErrorData {
+    match e {
+        ToolError::NotFound(_) => m::ErrorData::resource_not_found(e.to_string(), None),
+        ToolError::InvalidInput(_) => m::ErrorData::invalid_params(e.to_string(), None),
+        _ => m::ErrorData::internal_error(e.to_string(), None),
     }
 }
 
@@ -144,30 +343,59 @@ impl RegistryServer {
 impl ServerHandler for RegistryServer {
     fn initialize(
         &self,
-        _params: m::InitializeRequestParams,
-        _ctx: RequestContext<RoleServer>,
+        params: m::InitializeRequestParams,
+        ctx: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<m::InitializeResult, m::ErrorData>> + Send + '_
     {
         async move {
+            let allowlist = self
+                .requested_allowlist(&params)
+                .map_err(|e| m::ErrorData::invalid_params(e.to_string(), None))?;
+            *self
+                .client_allowlist
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner) = allowlist;
+
+            let manifest = self.manifest_in(&ctx.extensions);
+            if let Some(expected) = expected_schema_version(&params)
+                && let Err(e) = u32::try_from(expected)
+                    .map_err(|_| ToolError::InvalidInput(format!("bad schema version {expected}")))
+                    .and_then(|expected| manifest.check_schema_version(expected))
+            {
+                return Err(m::ErrorData::invalid_params(
+                    e.to_string(),
+                    serde_json::to_value(&manifest).ok(),
+                ));
+            }
+
+            self.reset_session();
+            let mut experimental = m::ExperimentalCapabilities::new();
+            if let Ok(serde_json::Value::Object(obj)) = serde_json::to_value(&manifest) {
+                experimental.insert(MANIFEST_CAPABILITY.to_string(), obj);
+            }
             let server_info =
                 m::Implementation::new(&self.name, &self.version).with_title(&self.name);
-            Ok(
-                m::InitializeResult::new(m::ServerCapabilities::builder().enable_tools().build())
-                    .with_server_info(server_info),
-            )
+            let mut capabilities = m::ServerCapabilities::builder()
+                .enable_experimental_with(experimental)
+                .enable_tools()
+                .build();
+            if !self.resources.is_empty() {
+                capabilities.resources = Some(m::ResourcesCapability::default());
+            }
+            Ok(m::InitializeResult::new(capabilities).with_server_info(server_info))
         }
     }
 
     fn list_tools(
         &self,
         _req: Option<m::PaginatedRequestParams>,
-        _ctx: RequestContext<RoleServer>,
+        ctx: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<m::ListToolsResult, m::ErrorData>> + Send + '_
     {
         async move {
             let mut tools = vec![];
             for name in self.registry.list_names() {
-                if !self.is_allowed(&name) {
+                if !self.is_allowed_in(&name, &ctx.extensions) {
                     continue;
                 }
                 if let Some(erased) = self.registry.get(&name) {
@@ -189,8 +417,9 @@ impl ServerHandler for RegistryServer {
 
                     let input_schema =
                         Arc::new(schema_json.as_object().cloned().unwrap_or_default());
-                    let mut tool = m::Tool::new(name.clone(), erased.description(), input_schema)
-                        .with_title(name);
+                    let mut tool =
+                        m::Tool::new(name.clone(), erased.provider_description(), input_schema)
+                            .with_title(name);
 
                     // Attach output_schema only in Structured mode
                     if let Some(schema) = output_schema {
@@ -211,7 +440,7 @@ impl ServerHandler for RegistryServer {
     ) -> impl std::future::Future<Output = Result<m::CallToolResult, m::ErrorData>> + Send + '_
     {
         async move {
-            if !self.is_allowed(&req.name) {
+            if !self.is_allowed_in(&req.name, &request_context.extensions) {
                 return Ok(m::CallToolResult::error(vec![m::Content::text(format!(
                     "Tool '{}' not enabled on this server",
                     req.name
@@ -219,15 +448,36 @@ impl ServerHandler for RegistryServer {
             }
 
             let args = serde_json::Value::Object(req.arguments.unwrap_or_default());
-            let ctx = ToolContext::with_cancel(request_context.ct.child_token());
+            let session = self.session();
+            let mut ctx = ToolContext::with_cancel(request_context.ct.child_token())
+                .with_session(Arc::clone(&session));
+            if let Some(token) = request_context.meta.get_progress_token() {
+                ctx = ctx.with_events(progress_sink(request_context.peer.clone(), token));
+            }
             let text_opts = self.text_options.clone();
 
-            tracing::info!(tool = %req.name, "tool dispatch started");
+            tracing::info!(tool = %req.name, session = %session.id(), "tool dispatch started");
 
-            let dispatch_result = self
+            let dispatch = self
                 .registry
-                .dispatch_json_formatted(&req.name, args, &ctx, &text_opts)
-                .await;
+                .dispatch_json_formatted(&req.name, args, &ctx, &text_opts);
+            tokio::pin!(dispatch);
+            let dispatch_result = tokio::select! {
+                result = &mut dispatch => result,
+                () = ctx.cancelled() => {
+                    tokio::time::timeout(self.cancel_grace, &mut dispatch)
+                        .await
+                        .unwrap_or_else(|_| {
+                            tracing::warn!(
+                                tool = %req.name,
+                                "tool ignored cancellation; dropping in-flight call"
+                            );
+                            Err(ToolError::cancelled(Some(
+                                "tool did not stop within the cancel grace period".into(),
+                            )))
+                        })
+                }
+            };
 
             if matches!(&dispatch_result, Err(ToolError::Cancelled { .. })) || ctx.is_cancelled() {
                 tracing::info!(tool = %req.name, "tool dispatch exiting after cancellation");
@@ -326,7 +576,23 @@ impl ServerHandler for RegistryServer {
         _ctx: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<m::ListResourcesResult, m::ErrorData>> + Send + '_
     {
-        async { Ok(m::ListResourcesResult::with_all_items(vec![])) }
+        async move {
+            let mut resources = Vec::new();
+            for provider in &self.resources {
+                // One failing provider (e.g. no active thoughts workspace) should not
+                // hide the others.
+                match provider.list().await {
+                    Ok(entries) => resources.extend(entries.into_iter().map(|entry| {
+                        let mut raw = m::RawResource::new(entry.uri, entry.name);
+                        raw.description = entry.description;
+                        raw.mime_type = entry.mime_type;
+                        m::Annotated::new(raw, None)
+                    })),
+                    Err(e) => tracing::warn!("Failed to list resources: {e}"),
+                }
+            }
+            Ok(m::ListResourcesResult::with_all_items(resources))
+        }
     }
 
     fn list_resource_templates(
@@ -341,13 +607,26 @@ impl ServerHandler for RegistryServer {
 
     fn read_resource(
         &self,
-        _req: m::ReadResourceRequestParams,
+        req: m::ReadResourceRequestParams,
         _ctx: RequestContext<RoleServer>,
     ) -> impl std::future::Future<Output = Result<m::ReadResourceResult, m::ErrorData>> + Send + '_
     {
-        async {
-            Err(m::ErrorData::invalid_request(
-                "Method not implemented",
+        async move {
+            for provider in &self.resources {
+                let text = provider
+                    .read(&req.uri)
+                    .await
+                    .map_err(|e| resource_error(&e))?;
+                if let Some(text) = text {
+                    let mut contents = m::ResourceContents::text(text.text, req.uri.as_str());
+                    if let Some(mime_type) = text.mime_type {
+                        contents = contents.with_mime_type(mime_type);
+                    }
+                    return Ok(m::ReadResourceResult::new(vec![contents]));
+                }
+            }
+            Err(m::ErrorData::resource_not_found(
+                format!("unknown resource: {}", req.uri),
                 None,
             ))
         }
@@ -587,6 +866,206 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_registry_servers_have_distinct_sessions() {
+        let registry = Arc::new(ToolRegistry::builder().finish());
+        let a = RegistryServer::new(Arc::clone(&registry));
+        let b = RegistryServer::new(registry);
+
+        assert_ne!(a.session().id(), b.session().id());
+
+        let before = a.session().id();
+        a.reset_session();
+        assert_ne!(a.session().id(), before);
+    }
+
+    #[test]
+    fn test_manifest_covers_only_allowed_tools() {
+        let registry = Arc::new(
+            ToolRegistry::builder()
+                .register::<TestObjTool, ()>(TestObjTool)
+                .register::<TestTextOptionsTool, ()>(TestTextOptionsTool)
+                .finish(),
+        );
+        let full = RegistryServer::new(Arc::clone(&registry)).manifest();
+        let filtered = RegistryServer::new(registry)
+            .with_allowlist(["test_obj_tool".to_string()])
+            .manifest();
+
+        assert_eq!(full.tools.len(), 2);
+        assert_eq!(
+            filtered.tools.keys().collect::<Vec<_>>(),
+            vec!["test_obj_tool"]
+        );
+        assert_eq!(full.tools["test_obj_tool"], filtered.tools["test_obj_tool"]);
+        assert_ne!(full.registry_hash, filtered.registry_hash);
+    }
+
+    fn initialize_params(agentic: &serde_json::Value, client: &str) -> m::InitializeRequestParams {
+        serde_json::from_value(serde_json::json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": { "experimental": { MANIFEST_CAPABILITY: agentic } },
+            "clientInfo": { "name": client, "version": "0.0.0" }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_client_allowlist_from_profile_list_or_client_name() {
+        let server = RegistryServer::new(Arc::new(ToolRegistry::builder().finish()))
+            .with_profile("readonly", ["read".to_string()])
+            .with_profile("zed", ["read".to_string(), "write".to_string()]);
+        let requested = |agentic: &serde_json::Value, client| {
+            server
+                .requested_allowlist(&initialize_params(agentic, client))
+                .map(|allowlist| {
+                    let mut tools: Vec<String> = allowlist?.iter().cloned().collect();
+                    tools.sort();
+                    Some(tools)
+                })
+        };
+
+        assert_eq!(
+            requested(&serde_json::json!({ "profile": "readonly" }), "zed").unwrap(),
+            Some(vec!["read".to_string()])
+        );
+        assert_eq!(
+            requested(&serde_json::json!({ "allowlist": ["grep"] }), "zed").unwrap(),
+            Some(vec!["grep".to_string()])
+        );
+        assert_eq!(
+            requested(&serde_json::json!({}), "zed").unwrap(),
+            Some(vec!["read".to_string(), "write".to_string()])
+        );
+        assert_eq!(requested(&serde_json::json!({}), "other").unwrap(), None);
+        assert!(requested(&serde_json::json!({ "profile": "missing" }), "zed").is_err());
+        assert!(requested(&serde_json::json!({ "allowlist": "grep" }), "zed").is_err());
+    }
+
+    #[derive(serde::Deserialize, schemars::JsonSchema)]
+    struct StepperInput {}
+
+    #[derive(Clone)]
+    struct Stepper;
+
+    impl Tool for Stepper {
+        type Input = StepperInput;
+        type Output = String;
+        const NAME: &'static str = "stepper";
+        const DESCRIPTION: &'static str = "Report two steps of progress";
+
+        fn call(
+            &self,
+            _input: StepperInput,
+            ctx: &ToolContext,
+        ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+            ctx.progress("step 1");
+            ctx.progress("step 2");
+            Box::pin(async { Ok("done".to_string()) })
+        }
+    }
+
+    /// Client forwarding received progress notifications.
+    struct ProgressClient(tokio::sync::mpsc::UnboundedSender<m::ProgressNotificationParam>);
+
+    impl rmcp::ClientHandler for ProgressClient {
+        async fn on_progress(
+            &self,
+            params: m::ProgressNotificationParam,
+            _context: rmcp::service::NotificationContext<rmcp::RoleClient>,
+        ) {
+            let _ = self.0.send(params);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tool_progress_becomes_progress_notifications() {
+        use rmcp::ServiceExt;
+
+        let registry = ToolRegistry::builder()
+            .register::<Stepper, ()>(Stepper)
+            .finish();
+        let server = RegistryServer::new(Arc::new(registry));
+        let (server_transport, client_transport) = tokio::io::duplex(4096);
+        tokio::spawn(async move {
+            if let Ok(running) = server.serve(server_transport).await {
+                let _ = running.waiting().await;
+            }
+        });
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let client = ProgressClient(tx).serve(client_transport).await.unwrap();
+
+        // The rmcp client attaches a progress token to every request.
+        let result = client
+            .call_tool(m::CallToolRequestParams::new("stepper"))
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+
+        let mut tokens = Vec::new();
+        for (progress, message) in [(1.0, "step 1"), (2.0, "step 2")] {
+            let note = rx.recv().await.unwrap();
+            tokens.push(note.progress_token);
+            assert!((note.progress - progress).abs() < f64::EPSILON);
+            assert_eq!(note.message.as_deref(), Some(message));
+        }
+        assert_eq!(tokens[0], tokens[1]);
+    }
+
+    #[tokio::test]
+    async fn test_profile_narrows_listed_and_callable_tools() {
+        use rmcp::ServiceExt;
+
+        let registry = ToolRegistry::builder()
+            .register::<TestObjTool, ()>(TestObjTool)
+            .register::<TestTextOptionsTool, ()>(TestTextOptionsTool)
+            .finish();
+        let server = RegistryServer::new(Arc::new(registry))
+            .with_profile("obj", ["test_obj_tool".to_string()]);
+        let (server_transport, client_transport) = tokio::io::duplex(4096);
+        tokio::spawn(async move {
+            if let Ok(running) = server.serve(server_transport).await {
+                let _ = running.waiting().await;
+            }
+        });
+        let client = initialize_params(&serde_json::json!({ "profile": "obj" }), "test")
+            .serve(client_transport)
+            .await
+            .unwrap();
+
+        let tools = client.list_all_tools().await.unwrap();
+        assert_eq!(
+            tools.iter().map(|t| t.name.as_ref()).collect::<Vec<_>>(),
+            ["test_obj_tool"]
+        );
+        let hidden = client
+            .call_tool(m::CallToolRequestParams::new("test_text_options_tool"))
+            .await
+            .unwrap();
+        assert_eq!(hidden.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_expected_schema_version_from_client_capabilities() {
+        let params: m::InitializeRequestParams = serde_json::from_value(serde_json::json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": {
+                "experimental": { MANIFEST_CAPABILITY: { "expectedSchemaVersion": 7 } }
+            },
+            "clientInfo": { "name": "test", "version": "0.0.0" }
+        }))
+        .unwrap();
+        assert_eq!(expected_schema_version(&params), Some(7));
+
+        let params: m::InitializeRequestParams = serde_json::from_value(serde_json::json!({
+            "protocolVersion": "2025-06-18",
+            "capabilities": {},
+            "clientInfo": { "name": "test", "version": "0.0.0" }
+        }))
+        .unwrap();
+        assert_eq!(expected_schema_version(&params), None);
+    }
+
     #[test]
     fn test_output_mode_default_is_text() {
         let registry = Arc::new(ToolRegistry::builder().finish());
@@ -595,4 +1074,66 @@ mod tests {
         // Default should be Text mode
         assert!(matches!(server.output_mode(), OutputMode::Text));
     }
+
+    /// Serves `notes://hello` and nothing else.
+    struct Notes;
+
+    impl ResourceProvider for Notes {
+        fn list(&self) -> BoxFuture<'_, Result<Vec<crate::ResourceEntry>, ToolError>> {
+            Box::pin(async {
+                Ok(vec![
+                    crate::ResourceEntry::new("notes://hello", "hello")
+                        .with_mime_type("text/plain"),
+                ])
+            })
+        }
+
+        fn read<'a>(
+            &'a self,
+            uri: &'a str,
+        ) -> BoxFuture<'a, Result<Option<crate::ResourceText>, ToolError>> {
+            Box::pin(async move {
+                Ok((uri == "notes://hello")
+                    .then(|| crate::ResourceText::new("hi").with_mime_type("text/plain")))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resources_are_listed_and_read() {
+        use rmcp::ServiceExt;
+
+        let server =
+            RegistryServer::new(Arc::new(ToolRegistry::builder().finish())).with_resources(Notes);
+        let (server_transport, client_transport) = tokio::io::duplex(4096);
+        tokio::spawn(async move {
+            if let Ok(running) = server.serve(server_transport).await {
+                let _ = running.waiting().await;
+            }
+        });
+        let client = ().serve(client_transport).await.unwrap();
+        let capabilities = &client.peer_info().unwrap().capabilities;
+        assert!(capabilities.resources.is_some());
+
+        let resources = client.list_all_resources().await.unwrap();
+        assert_eq!(resources.len(), 1);
+        assert_eq!(resources[0].uri, "notes://hello");
+
+        let read = client
+            .read_resource(m::ReadResourceRequestParams::new("notes://hello"))
+            .await
+            .unwrap();
+        assert!(matches!(
+            &read.contents[..],
+            [m::ResourceContents::TextResourceContents { text, mime_type, .. }]
+                if text == "hi" && mime_type.as_deref() == Some("text/plain")
+        ));
+
+        assert!(
+            client
+                .read_resource(m::ReadResourceRequestParams::new("notes://missing"))
+                .await
+                .is_err()
+        );
+    }
 }
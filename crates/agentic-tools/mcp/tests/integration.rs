@@ -119,6 +119,43 @@ impl Tool for CancellationProbeTool {
     }
 }
 
+/// Ignores cancellation; notifies `dropped` when its call future is dropped.
+#[derive(Clone)]
+struct StubbornTool {
+    started: Arc<Notify>,
+    dropped: Arc<Notify>,
+}
+
+struct NotifyOnDrop(Arc<Notify>);
+
+impl Drop for NotifyOnDrop {
+    fn drop(&mut self) {
+        self.0.notify_one();
+    }
+}
+
+impl Tool for StubbornTool {
+    type Input = CancellationProbeInput;
+    type Output = String;
+    const NAME: &'static str = "stubborn";
+    const DESCRIPTION: &'static str = "Never finishes and ignores cancellation";
+
+    fn call(
+        &self,
+        _input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let started = Arc::clone(&self.started);
+        let guard = NotifyOnDrop(Arc::clone(&self.dropped));
+
+        Box::pin(async move {
+            let _guard = guard;
+            started.notify_one();
+            std::future::pending().await
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, JsonSchema)]
 struct CalculateOutput {
     result: i32,
@@ -482,3 +519,72 @@ async fn test_rmcp_cancellation_flips_tool_context_mid_call() -> Result<(), Stri
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_cancelled_call_is_dropped_after_grace_period() -> Result<(), String> {
+    let started = Arc::new(Notify::new());
+    let dropped = Arc::new(Notify::new());
+    let server = Arc::new(
+        RegistryServer::new(Arc::new(
+            ToolRegistry::builder()
+                .register::<StubbornTool, ()>(StubbornTool {
+                    started: Arc::clone(&started),
+                    dropped: Arc::clone(&dropped),
+                })
+                .finish(),
+        ))
+        .with_cancel_grace(Duration::from_millis(50)),
+    );
+
+    let (server_transport, client_transport) = tokio::io::duplex(4096);
+    let (running, client) = tokio::try_join!(
+        async {
+            Arc::clone(&server)
+                .serve(server_transport)
+                .await
+                .map_err(|err| err.to_string())
+        },
+        async {
+            TestClient
+                .serve(client_transport)
+                .await
+                .map_err(|err| err.to_string())
+        },
+    )?;
+
+    let request_context =
+        rmcp::service::RequestContext::new(m::NumberOrString::Number(1), running.peer().clone());
+    let cancel = request_context.ct.clone();
+    let server_for_call = Arc::clone(&server);
+    let call_task = tokio::spawn(async move {
+        server_for_call
+            .call_tool(m::CallToolRequestParams::new("stubborn"), request_context)
+            .await
+            .map_err(|err| err.to_string())
+    });
+
+    timeout(Duration::from_secs(5), started.notified())
+        .await
+        .map_err(|_| "stubborn tool never started".to_string())?;
+
+    cancel.cancel();
+
+    timeout(Duration::from_secs(5), dropped.notified())
+        .await
+        .map_err(|_| "cancelled call was never dropped".to_string())?;
+
+    let tool_result = timeout(Duration::from_secs(5), call_task)
+        .await
+        .map_err(|_| "cancelled call never returned".to_string())?
+        .map_err(|err| err.to_string())??;
+
+    assert_eq!(tool_result.is_error, Some(true));
+    let content_json =
+        serde_json::to_string(&tool_result.content).map_err(|err| err.to_string())?;
+    assert!(content_json.contains("cancelled"));
+
+    drop(client);
+    drop(running);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Setup diagnostics for the registry's domains.
+//!
+//! Every built domain reports [`HealthCheck`]s through
+//! [`DomainFactory::doctor`]: credentials present, external binaries found,
+//! mounts healthy. [`AgenticTools::doctor`](crate::AgenticTools::doctor)
+//! collects them into a [`DoctorReport`], and the `tools_doctor` tool returns
+//! the same report so agents can diagnose missing setup themselves.
+
+use crate::AgenticToolsConfig;
+use crate::DomainConfig;
+use crate::DomainFactory;
+use agentic_tools_core::Permissions;
+use agentic_tools_core::Tool;
+use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolError;
+use agentic_tools_core::fmt::TextFormat;
+use agentic_tools_core::fmt::TextOptions;
+use futures::future::BoxFuture;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::fmt::Write;
+use std::sync::Arc;
+
+/// Outcome of one setup check.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct HealthCheck {
+    /// What was checked, e.g. `LINEAR_API_KEY`.
+    pub name: String,
+    /// Whether the check passed.
+    pub ok: bool,
+    /// What was found, or how to fix it.
+    pub detail: String,
+}
+
+impl HealthCheck {
+    /// A passing check.
+    pub fn pass(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ok: true,
+            detail: detail.into(),
+        }
+    }
+
+    /// A failing check.
+    pub fn fail(name: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ok: false,
+            detail: detail.into(),
+        }
+    }
+
+    /// Passes when environment variable `var` is set to a non-blank value.
+    pub fn env(var: &str) -> Self {
+        match std::env::var(var) {
+            Ok(value) if !value.trim().is_empty() => Self::pass(var, "set"),
+            _ => Self::fail(var, "not set"),
+        }
+    }
+}
+
+/// Readiness of one domain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DomainHealth {
+    pub domain: String,
+    /// Whether every check passed.
+    pub ready: bool,
+    pub checks: Vec<HealthCheck>,
+}
+
+impl DomainHealth {
+    /// Health of `domain` from its checks.
+    pub fn new(domain: impl Into<String>, checks: Vec<HealthCheck>) -> Self {
+        Self {
+            domain: domain.into(),
+            ready: checks.iter().all(|check| check.ok),
+            checks,
+        }
+    }
+}
+
+/// Readiness of every built domain.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DoctorReport {
+    /// Whether every domain is ready.
+    pub ready: bool,
+    pub domains: Vec<DomainHealth>,
+}
+
+impl DoctorReport {
+    /// Report over `domains`.
+    pub fn new(domains: Vec<DomainHealth>) -> Self {
+        Self {
+            ready: domains.iter().all(|domain| domain.ready),
+            domains,
+        }
+    }
+
+    /// Run the checks of each domain in `domains`.
+    pub async fn collect(config: &AgenticToolsConfig, domains: &[Arc<dyn DomainFactory>]) -> Self {
+        let mut health = Vec::with_capacity(domains.len());
+        for domain in domains {
+            let checks = domain
+                .doctor(&DomainConfig::new(config, domain.name()))
+                .await;
+            health.push(DomainHealth::new(domain.name(), checks));
+        }
+        Self::new(health)
+    }
+}
+
+impl TextFormat for DoctorReport {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let mut out = String::new();
+        for domain in &self.domains {
+            let status = if domain.ready { "ready" } else { "NOT READY" };
+            let _ = writeln!(out, "{}: {status}", domain.domain);
+            for check in &domain.checks {
+                let mark = if check.ok { "ok" } else { "FAIL" };
+                let _ = writeln!(out, "  [{mark}] {}: {}", check.name, check.detail);
+            }
+        }
+        let _ = write!(
+            out,
+            "{}",
+            if self.ready {
+                "All domains ready."
+            } else {
+                "Some domains need setup; see FAIL lines above."
+            }
+        );
+        out
+    }
+}
+
+/// Input for `tools_doctor`.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct DoctorInput {}
+
+/// Tool reporting the setup of every domain in the registry.
+#[derive(Clone)]
+pub struct DoctorTool {
+    config: Arc<AgenticToolsConfig>,
+    domains: Arc<[Arc<dyn DomainFactory>]>,
+}
+
+impl DoctorTool {
+    /// Tool diagnosing `domains` built from `config`.
+    pub fn new(config: Arc<AgenticToolsConfig>, domains: Arc<[Arc<dyn DomainFactory>]>) -> Self {
+        Self { config, domains }
+    }
+}
+
+impl Tool for DoctorTool {
+    type Input = DoctorInput;
+    type Output = DoctorReport;
+    const NAME: &'static str = "tools_doctor";
+    const DESCRIPTION: &'static str = "Check whether each tool domain is set up: API keys present, GitHub token resolved, claude binary found, thoughts mounts healthy. Call when other tools fail with missing credentials or configuration.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
+
+    fn call(
+        &self,
+        _input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let tool = self.clone();
+        Box::pin(async move { Ok(DoctorReport::collect(&tool.config, &tool.domains).await) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readiness_requires_every_check() {
+        let report = DoctorReport::new(vec![
+            DomainHealth::new("web", vec![HealthCheck::pass("EXA_API_KEY", "set")]),
+            DomainHealth::new(
+                "linear",
+                vec![
+                    HealthCheck::pass("index", "disabled"),
+                    HealthCheck::fail("LINEAR_API_KEY", "not set"),
+                ],
+            ),
+        ]);
+
+        assert!(report.domains[0].ready);
+        assert!(!report.domains[1].ready);
+        assert!(!report.ready);
+        assert_eq!(
+            report.fmt_text(&TextOptions::default()),
+            "web: ready\n  [ok] EXA_API_KEY: set\nlinear: NOT READY\n  [ok] index: disabled\n  [FAIL] LINEAR_API_KEY: not set\nSome domains need setup; see FAIL lines above."
+        );
+    }
+}
@@ -0,0 +1,481 @@
+//! Pluggable domain construction.
+//!
+//! Each group of tools is built by a [`DomainFactory`]. The built-in domains are
+//! registered by default; embedders add their own (or replace a built-in one of
+//! the same name) with [`AgenticToolsBuilder::domain`](crate::AgenticToolsBuilder::domain).
+//!
+//! A factory reads the typed [`AgenticToolsConfig`] plus its own section of
+//! `extras`, keyed by domain name:
+//!
+//! ```json
+//! {
+//!   "extras": {
+//!     "pr_comments": { "owner": "acme", "repo": "widgets" },
+//!     "linear": { "api_key": "lin_api_...", "default_team": "ENG" },
+//!     "gpt5": { "optimizer_model": "anthropic/custom-optimizer" }
+//!   }
+//! }
+//! ```
+//!
+//! Built-in domains overlay their section onto the matching typed config, so
+//! any field of that config can be set there; `pr_comments.owner`/`repo` and
+//! `linear.api_key` are extra keys with no typed counterpart.
+
+use crate::AgenticToolsConfig;
+use crate::doctor::HealthCheck;
+use agentic_tools_core::ToolRegistry;
+use futures::future::BoxFuture;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Builds one domain's tools.
+pub trait DomainFactory: Send + Sync {
+    /// Domain name; also the key of its section in `extras`.
+    fn name(&self) -> &str;
+
+    /// Names of every tool the domain can register, used to skip domains an
+    /// allowlist excludes.
+    fn tool_names(&self) -> &[&str];
+
+    /// Whether the domain is built at all. Defaults to `true`.
+    fn enabled(&self, _config: &DomainConfig<'_>) -> bool {
+        true
+    }
+
+    /// Build the domain's registry.
+    fn build(&self, config: &DomainConfig<'_>) -> ToolRegistry;
+
+    /// Check the domain's setup: credentials, external binaries, mounts.
+    /// Defaults to no checks.
+    fn doctor(&self, _config: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+        Box::pin(async { Vec::new() })
+    }
+}
+
+/// Configuration handed to a [`DomainFactory`].
+#[derive(Clone, Copy, Debug)]
+pub struct DomainConfig<'a> {
+    config: &'a AgenticToolsConfig,
+    section: &'a Value,
+}
+
+impl<'a> DomainConfig<'a> {
+    /// Configuration for the domain `name`, reading `extras.<name>`.
+    pub fn new(config: &'a AgenticToolsConfig, name: &str) -> Self {
+        Self {
+            config,
+            section: config.extras.get(name).unwrap_or(&Value::Null),
+        }
+    }
+
+    /// The full typed configuration.
+    pub const fn config(&self) -> &'a AgenticToolsConfig {
+        self.config
+    }
+
+    /// The domain's `extras` section, or `null` if absent.
+    pub const fn section(&self) -> &'a Value {
+        self.section
+    }
+
+    /// String value of `key` in the domain's section.
+    pub fn get_str(&self, key: &str) -> Option<&'a str> {
+        self.section.get(key).and_then(Value::as_str)
+    }
+
+    /// `base` with the domain's section merged over it.
+    ///
+    /// Objects merge recursively; other values replace. Keys `T` does not know
+    /// are ignored. If the merged value does not deserialize, the section is
+    /// logged and `base` is returned unchanged.
+    pub fn overlay<T: Serialize + DeserializeOwned + Clone>(&self, base: &T) -> T {
+        if !self.section.is_object() {
+            return base.clone();
+        }
+        let merged = serde_json::to_value(base).map(|mut value| {
+            merge(&mut value, self.section);
+            value
+        });
+        match merged.and_then(serde_json::from_value) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Ignoring invalid extras section: {e}");
+                base.clone()
+            }
+        }
+    }
+}
+
+fn merge(base: &mut Value, patch: &Value) {
+    match (base, patch) {
+        (Value::Object(base), Value::Object(patch)) => {
+            for (key, value) in patch {
+                merge(base.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+        (base, patch) => *base = patch.clone(),
+    }
+}
+
+/// A [`DomainFactory`] from a name, tool list, and build function.
+pub struct FnDomain {
+    name: &'static str,
+    tools: &'static [&'static str],
+    enabled: fn(&DomainConfig<'_>) -> bool,
+    build: fn(&DomainConfig<'_>) -> ToolRegistry,
+    doctor: Doctor,
+}
+
+type Doctor = fn(&DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>>;
+
+impl FnDomain {
+    /// Domain `name` registering `tools` with `build`.
+    pub const fn new(
+        name: &'static str,
+        tools: &'static [&'static str],
+        build: fn(&DomainConfig<'_>) -> ToolRegistry,
+    ) -> Self {
+        Self {
+            name,
+            tools,
+            enabled: |_| true,
+            build,
+            doctor: |_| Box::pin(async { Vec::new() }),
+        }
+    }
+
+    /// Report `doctor`'s checks from [`DomainFactory::doctor`].
+    #[must_use]
+    pub const fn with_doctor(mut self, doctor: Doctor) -> Self {
+        self.doctor = doctor;
+        self
+    }
+
+    /// Only build the domain when `enabled` returns `true`.
+    #[must_use]
+    pub const fn enabled_when(mut self, enabled: fn(&DomainConfig<'_>) -> bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl DomainFactory for FnDomain {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn tool_names(&self) -> &[&str] {
+        self.tools
+    }
+
+    fn enabled(&self, config: &DomainConfig<'_>) -> bool {
+        (self.enabled)(config)
+    }
+
+    fn build(&self, config: &DomainConfig<'_>) -> ToolRegistry {
+        (self.build)(config)
+    }
+
+    fn doctor(&self, config: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+        (self.doctor)(config)
+    }
+}
+
+const CODING_NAMES: &[&str] = &[
+    "cli_ls",
+    "ask_agent",
+    "cli_grep",
+    "cli_glob",
+    "cli_just_search",
+    "cli_just_execute",
+    "cli_batch",
+];
+
+const PR_COMMENTS_NAMES: &[&str] = &[
+    "gh_get_comments",
+    "gh_add_comment_reply",
+    "gh_add_pr_comment",
+    "gh_react_to_comment",
+    "gh_edit_comment",
+    "gh_submit_review",
+    "gh_resolve_thread",
+    "gh_unresolve_thread",
+    "gh_get_prs",
+    "gh_get_pr",
+    "gh_get_changed_files",
+    "gh_get_pr_diff",
+    "gh_get_checks",
+    "gh_get_check_log",
+];
+
+const LINEAR_NAMES: &[&str] = &[
+    "linear_search_issues",
+    "linear_read_issue",
+    "linear_create_issue",
+    "linear_add_comment",
+    "linear_get_issue_comments",
+    "linear_add_attachment",
+    "linear_list_attachments",
+    "linear_export_issue",
+    "linear_archive_issue",
+    "linear_update_issue",
+    "linear_set_relation",
+    "linear_add_relation",
+    "linear_get_metadata",
+    "linear_get_cycles",
+    "linear_search_local",
+];
+
+const GPT5_NAMES: &[&str] = &["ask_reasoning_model"];
+
+const THOUGHTS_NAMES: &[&str] = &[
+    "thoughts_write_document",
+    "thoughts_list_documents",
+    "thoughts_list_references",
+    "thoughts_get_repo_refs",
+    "thoughts_add_reference",
+    "thoughts_get_template",
+    "thoughts_mount_status",
+    "thoughts_verify_references",
+    "thoughts_sync",
+    "tool_logs_query",
+];
+
+const WEB_NAMES: &[&str] = &["web_fetch", "web_search"];
+
+const REVIEW_NAMES: &[&str] = &["review_diff_snapshot", "review_diff_page", "review_run"];
+
+const WORKSPACE_NAMES: &[&str] = &[
+    "workspace_read",
+    "workspace_todowrite",
+    "workspace_edit",
+    "workspace_apply_patch",
+];
+
+/// The built-in domains, in registration order.
+pub fn builtin_domains() -> Vec<Arc<dyn DomainFactory>> {
+    vec![
+        Arc::new(FnDomain::new("coding", CODING_NAMES, build_coding).with_doctor(doctor_coding)),
+        Arc::new(
+            FnDomain::new("pr_comments", PR_COMMENTS_NAMES, build_pr_comments)
+                .with_doctor(doctor_pr_comments),
+        ),
+        Arc::new(FnDomain::new("linear", LINEAR_NAMES, build_linear).with_doctor(doctor_linear)),
+        Arc::new(FnDomain::new("gpt5", GPT5_NAMES, build_gpt5).with_doctor(doctor_gpt5)),
+        Arc::new(
+            FnDomain::new("thoughts", THOUGHTS_NAMES, build_thoughts).with_doctor(doctor_thoughts),
+        ),
+        Arc::new(FnDomain::new("web", WEB_NAMES, build_web).with_doctor(doctor_web)),
+        Arc::new(FnDomain::new("review", REVIEW_NAMES, build_review)),
+        Arc::new(
+            FnDomain::new("workspace", WORKSPACE_NAMES, build_workspace).enabled_when(|d| {
+                let config = d.overlay(&d.config().workspace_tools);
+                config.workspace_read
+                    || config.workspace_todowrite
+                    || config.workspace_edit
+                    || config.workspace_apply_patch
+            }),
+        ),
+    ]
+}
+
+fn build_coding(d: &DomainConfig<'_>) -> ToolRegistry {
+    coding_agent_tools::build_registry(
+        d.config().subagents.clone(),
+        d.overlay(&d.config().cli_tools),
+    )
+}
+
+fn doctor_coding(_: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+    Box::pin(async {
+        let claude = match claudecode::process::find_claude_in_path().await {
+            Ok(path) => HealthCheck::pass("claude binary", path.display().to_string()),
+            Err(e) => HealthCheck::fail("claude binary", format!("{e}; ask_agent needs it")),
+        };
+        vec![claude]
+    })
+}
+
+fn build_pr_comments(d: &DomainConfig<'_>) -> ToolRegistry {
+    pr_comments::build_registry(Arc::new(pr_comments_client(d)))
+}
+
+fn doctor_pr_comments(d: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+    let client = pr_comments_client(d);
+    let token = match client.token_source_label() {
+        Some(source) => HealthCheck::pass("github token", source),
+        None => HealthCheck::fail(
+            "github token",
+            "not found; set GH_TOKEN or run `gh auth login`",
+        ),
+    };
+    let repo = match client.repository() {
+        Some((owner, repo)) => HealthCheck::pass("repository", format!("{owner}/{repo}")),
+        None => HealthCheck::fail(
+            "repository",
+            "not detected; run inside a GitHub checkout or set extras.pr_comments.owner/repo",
+        ),
+    };
+    Box::pin(async move { vec![token, repo] })
+}
+
+fn pr_comments_client(d: &DomainConfig<'_>) -> pr_comments::PrComments {
+    let github = d.overlay(&d.config().github);
+    if let (Some(owner), Some(repo)) = (d.get_str("owner"), d.get_str("repo")) {
+        pr_comments::PrComments::with_repo_and_config(owner.into(), repo.into(), github)
+    } else {
+        // TODO(2): Centralize ambient git repo detection + overrides across tool registries
+        // (avoid per-domain fallbacks like this).
+        match pr_comments::PrComments::with_config(github.clone()) {
+            Ok(t) => t,
+            Err(e) => {
+                warn!(
+                    "pr_comments: ambient repo detection failed ({}); tools will return a clear error until repo context is available",
+                    e
+                );
+                pr_comments::PrComments::disabled_with_config(format!("{e:#}"), github)
+            }
+        }
+    }
+}
+
+fn build_linear(d: &DomainConfig<'_>) -> ToolRegistry {
+    let mut linear = linear_tools::LinearTools::with_config(d.overlay(&d.config().linear));
+    if let Some(api_key) = d.get_str("api_key") {
+        linear = linear.with_api_key(api_key.into());
+    }
+    linear_tools::build_registry(Arc::new(linear))
+}
+
+fn doctor_linear(d: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+    let key = if d.get_str("api_key").is_some() {
+        HealthCheck::pass("LINEAR_API_KEY", "set in extras.linear.api_key")
+    } else {
+        HealthCheck::env("LINEAR_API_KEY")
+    };
+    Box::pin(async move { vec![key] })
+}
+
+fn build_gpt5(d: &DomainConfig<'_>) -> ToolRegistry {
+    gpt5_reasoner::build_registry(d.overlay(&d.config().reasoning))
+}
+
+fn doctor_gpt5(_: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+    let key = HealthCheck::env("OPENROUTER_API_KEY");
+    Box::pin(async move { vec![key] })
+}
+
+fn build_thoughts(d: &DomainConfig<'_>) -> ToolRegistry {
+    thoughts_mcp_tools::build_registry(d.overlay(&d.config().thoughts))
+}
+
+fn doctor_thoughts(_: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+    Box::pin(async {
+        let mounts = match thoughts_tool::mcp::mount_status_impl_adapter().await {
+            Ok(report) if report.healthy => {
+                HealthCheck::pass("mounts", format!("{} healthy", report.mounts.len()))
+            }
+            Ok(report) => {
+                let unhealthy: Vec<&str> = report
+                    .mounts
+                    .iter()
+                    .filter(|m| !matches!(m.health, thoughts_tool::mcp::MountHealth::Mounted))
+                    .map(|m| m.mount.as_str())
+                    .chain(report.unexpected.iter().map(String::as_str))
+                    .collect();
+                HealthCheck::fail(
+                    "mounts",
+                    format!(
+                        "unhealthy: {}; see thoughts_mount_status",
+                        unhealthy.join(", ")
+                    ),
+                )
+            }
+            Err(e) => HealthCheck::fail("mounts", format!("{e:#}")),
+        };
+        vec![mounts]
+    })
+}
+
+fn build_web(d: &DomainConfig<'_>) -> ToolRegistry {
+    let config = d.config();
+    let web = Arc::new(web_retrieval::WebTools::with_config(
+        d.overlay(&config.web_retrieval),
+        &config.exa,
+        config.anthropic.clone(),
+    ));
+    web_retrieval::build_registry(web)
+}
+
+fn doctor_web(_: &DomainConfig<'_>) -> BoxFuture<'static, Vec<HealthCheck>> {
+    let key = HealthCheck::env("EXA_API_KEY");
+    Box::pin(async move { vec![key] })
+}
+
+fn build_review(d: &DomainConfig<'_>) -> ToolRegistry {
+    let svc = Arc::new(review_tools::ReviewTools::with_config(
+        d.overlay(&d.config().review),
+    ));
+    review_tools::build_registry(svc)
+}
+
+fn build_workspace(d: &DomainConfig<'_>) -> ToolRegistry {
+    workspace_tools::build_registry(&d.overlay(&d.config().workspace_tools))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agentic_config::types::ReasoningConfig;
+    use serde_json::json;
+
+    fn config(extras: Value) -> AgenticToolsConfig {
+        AgenticToolsConfig {
+            extras,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn overlay_merges_section_over_typed_config() {
+        let config = config(json!({ "gpt5": { "optimizer_model": "custom/optimizer" } }));
+        let reasoning = DomainConfig::new(&config, "gpt5").overlay(&config.reasoning);
+
+        assert_eq!(reasoning.optimizer_model, "custom/optimizer");
+        assert_eq!(
+            reasoning.executor_model,
+            ReasoningConfig::default().executor_model
+        );
+    }
+
+    #[test]
+    fn overlay_merges_nested_objects() {
+        let config = config(json!({ "linear": { "index": { "enabled": true } } }));
+        let linear = DomainConfig::new(&config, "linear").overlay(&config.linear);
+
+        assert!(linear.index.enabled);
+        assert_eq!(linear.index.stale_after_secs, 900);
+    }
+
+    #[test]
+    fn invalid_section_keeps_base() {
+        let config = config(json!({ "gpt5": { "executor_timeout_secs": "soon" } }));
+        let reasoning = DomainConfig::new(&config, "gpt5").overlay(&config.reasoning);
+
+        assert_eq!(reasoning.executor_timeout_secs, 2700);
+    }
+
+    #[test]
+    fn missing_section_is_null() {
+        let config = config(json!({ "linear": { "api_key": "k" } }));
+
+        assert_eq!(
+            DomainConfig::new(&config, "linear").get_str("api_key"),
+            Some("k")
+        );
+        assert!(DomainConfig::new(&config, "gpt5").section().is_null());
+    }
+}
@@ -45,6 +45,21 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use tracing::warn;
 
+pub mod doctor;
+pub mod domains;
+pub mod plugins;
+
+pub use doctor::DoctorReport;
+pub use doctor::DoctorTool;
+pub use doctor::DomainHealth;
+pub use doctor::HealthCheck;
+pub use domains::DomainConfig;
+pub use domains::DomainFactory;
+pub use domains::FnDomain;
+use domains::builtin_domains;
+pub use plugins::PluginDomain;
+pub use plugins::PluginSpec;
+
 /// Configuration for building the unified registry.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AgenticToolsConfig {
@@ -97,7 +112,8 @@ pub struct AgenticToolsConfig {
     #[serde(default)]
     pub thoughts: ThoughtsConfig,
 
-    /// Reserved for future use (e.g., schema strictness, patches).
+    /// Per-domain sections keyed by domain name (e.g. `pr_comments`, `linear`,
+    /// `gpt5`); see [`domains`].
     #[serde(default)]
     pub extras: serde_json::Value,
 }
@@ -105,54 +121,8 @@ pub struct AgenticToolsConfig {
 /// Unified `AgenticTools` entrypoint.
 pub struct AgenticTools;
 
-// Tool name constants for each domain
-const CODING_NAMES: &[&str] = &[
-    "cli_ls",
-    "ask_agent",
-    "cli_grep",
-    "cli_glob",
-    "cli_just_search",
-    "cli_just_execute",
-];
-
-const PR_COMMENTS_NAMES: &[&str] = &["gh_get_comments", "gh_add_comment_reply", "gh_get_prs"];
-
-const LINEAR_NAMES: &[&str] = &[
-    "linear_search_issues",
-    "linear_read_issue",
-    "linear_create_issue",
-    "linear_add_comment",
-    "linear_get_issue_comments",
-    "linear_archive_issue",
-    "linear_update_issue",
-    "linear_set_relation",
-    "linear_get_metadata",
-];
-
-const GPT5_NAMES: &[&str] = &["ask_reasoning_model"];
-
-const THOUGHTS_NAMES: &[&str] = &[
-    "thoughts_write_document",
-    "thoughts_list_documents",
-    "thoughts_list_references",
-    "thoughts_get_repo_refs",
-    "thoughts_add_reference",
-    "thoughts_get_template",
-];
-
-const WEB_NAMES: &[&str] = &["web_fetch", "web_search"];
-
-const REVIEW_NAMES: &[&str] = &["review_diff_snapshot", "review_diff_page", "review_run"];
-
-const WORKSPACE_NAMES: &[&str] = &[
-    "workspace_read",
-    "workspace_todowrite",
-    "workspace_edit",
-    "workspace_apply_patch",
-];
-
 impl AgenticTools {
-    /// Build the unified `ToolRegistry` using domain registries.
+    /// Build the unified `ToolRegistry` from the built-in domains.
     ///
     /// Lazy domain gating: When an allowlist is provided, only build domains
     /// whose tools intersect the allowlist.
@@ -163,82 +133,118 @@ impl AgenticTools {
     // TODO(3): clean up new_ret_no_self as part of broader agentic-tools-registry lint conformance pass.
     #[allow(clippy::new_ret_no_self)]
     pub fn new(config: AgenticToolsConfig) -> ToolRegistry {
-        let allow = normalize_allowlist(config.allowlist);
+        Self::builder(config).build()
+    }
 
-        // Helper: decide if a domain should be built
-        let domain_wanted = |names: &[&str]| match &allow {
-            None => true,
-            Some(set) => names.iter().any(|n| set.contains(&n.to_lowercase())),
-        };
+    /// Start from the built-in domains, to add or replace domains before building.
+    pub fn builder(config: AgenticToolsConfig) -> AgenticToolsBuilder {
+        AgenticToolsBuilder {
+            config,
+            domains: builtin_domains(),
+        }
+    }
 
-        // Accumulate selected domain registries
-        let mut regs = Vec::new();
+    /// Run the setup checks of the built-in domains `config` enables.
+    ///
+    /// See [`doctor`] for what is checked.
+    pub async fn doctor(config: AgenticToolsConfig) -> DoctorReport {
+        Self::builder(config).doctor().await
+    }
 
-        // coding_agent_tools (6 tools)
-        if domain_wanted(CODING_NAMES) {
-            regs.push(coding_agent_tools::build_registry(
-                config.subagents.clone(),
-                config.cli_tools.clone(),
-            ));
-        }
+    /// Get the total count of available tools when no allowlist is applied.
+    pub fn total_tool_count() -> usize {
+        builtin_domains()
+            .iter()
+            .map(|domain| domain.tool_names().len())
+            .sum::<usize>()
+            + 1 // tools_doctor
+    }
+}
 
-        // pr_comments (3 tools)
-        if domain_wanted(PR_COMMENTS_NAMES) {
-            // TODO(2): Centralize ambient git repo detection + overrides across tool registries
-            // (avoid per-domain fallbacks like this).
-            let tool = match pr_comments::PrComments::with_config(config.github.clone()) {
-                Ok(t) => t,
-                Err(e) => {
-                    warn!(
-                        "pr_comments: ambient repo detection failed ({}); tools will return a clear error until repo context is available",
-                        e
-                    );
-                    pr_comments::PrComments::disabled_with_config(
-                        format!("{e:#}"),
-                        config.github.clone(),
-                    )
-                }
-            };
-            regs.push(pr_comments::build_registry(Arc::new(tool)));
-        }
+/// Builder for a registry assembled from [`DomainFactory`]s.
+pub struct AgenticToolsBuilder {
+    config: AgenticToolsConfig,
+    domains: Vec<Arc<dyn DomainFactory>>,
+}
 
-        // linear_tools (9 tools)
-        if domain_wanted(LINEAR_NAMES) {
-            let linear = Arc::new(linear_tools::LinearTools::with_config(
-                config.linear.clone(),
-            ));
-            regs.push(linear_tools::build_registry(linear));
+impl AgenticToolsBuilder {
+    /// Register `factory`, replacing any domain with the same name.
+    #[must_use]
+    pub fn domain(mut self, factory: impl DomainFactory + 'static) -> Self {
+        let factory: Arc<dyn DomainFactory> = Arc::new(factory);
+        match self
+            .domains
+            .iter_mut()
+            .find(|domain| domain.name() == factory.name())
+        {
+            Some(slot) => *slot = factory,
+            None => self.domains.push(factory),
         }
+        self
+    }
 
-        // gpt5_reasoner (1 tool)
-        if domain_wanted(GPT5_NAMES) {
-            regs.push(gpt5_reasoner::build_registry(config.reasoning.clone()));
+    /// Spawn every plugin listed in `extras.plugins` and register each as a domain.
+    ///
+    /// Plugins that fail to start are logged and skipped. See [`plugins`].
+    pub async fn load_plugins(mut self) -> Self {
+        for spec in PluginSpec::from_config(&self.config) {
+            match PluginDomain::load(&spec).await {
+                Ok(plugin) => self = self.domain(plugin),
+                Err(e) => warn!("plugin {}: failed to load ({e}); skipping", spec.name),
+            }
         }
+        self
+    }
 
-        // thoughts-mcp-tools (6 tools)
-        if domain_wanted(THOUGHTS_NAMES) {
-            regs.push(thoughts_mcp_tools::build_registry(config.thoughts.clone()));
-        }
+    /// Drop the domain named `name`, if registered.
+    #[must_use]
+    pub fn without_domain(mut self, name: &str) -> Self {
+        self.domains.retain(|domain| domain.name() != name);
+        self
+    }
 
-        // web-retrieval (2 tools)
-        if domain_wanted(WEB_NAMES) {
-            let web = Arc::new(web_retrieval::WebTools::with_config(
-                config.web_retrieval.clone(),
-                &config.exa,
-                config.anthropic.clone(),
-            ));
-            regs.push(web_retrieval::build_registry(web));
-        }
+    /// Domains with a tool in `allow` (all when `None`) that are enabled.
+    fn active_domains(&self, allow: Option<&HashSet<String>>) -> Vec<Arc<dyn DomainFactory>> {
+        // Helper: decide if a domain should be built
+        let domain_wanted = |names: &[&str]| match allow {
+            None => true,
+            Some(set) => names.iter().any(|n| set.contains(&n.to_lowercase())),
+        };
 
-        // review_tools (3 tools)
-        if domain_wanted(REVIEW_NAMES) {
-            let svc = Arc::new(review_tools::ReviewTools::with_config(config.review));
-            regs.push(review_tools::build_registry(svc));
-        }
+        self.domains
+            .iter()
+            .filter(|domain| domain_wanted(domain.tool_names()))
+            .filter(|domain| domain.enabled(&DomainConfig::new(&self.config, domain.name())))
+            .cloned()
+            .collect()
+    }
 
-        if workspace_tools_enabled(&config.workspace_tools) && domain_wanted(WORKSPACE_NAMES) {
-            regs.push(workspace_tools::build_registry(&config.workspace_tools));
-        }
+    /// Names of the registered domains, in build order.
+    pub fn domain_names(&self) -> Vec<&str> {
+        self.domains.iter().map(|domain| domain.name()).collect()
+    }
+
+    /// Run the setup checks of every domain [`build`](Self::build) would build.
+    pub async fn doctor(&self) -> DoctorReport {
+        let allow = normalize_allowlist(self.config.allowlist.clone());
+        DoctorReport::collect(&self.config, &self.active_domains(allow.as_ref())).await
+    }
+
+    /// Build every wanted domain, plus `tools_doctor`, and merge them into one registry.
+    pub fn build(self) -> ToolRegistry {
+        let allow = normalize_allowlist(self.config.allowlist.clone());
+        let active = self.active_domains(allow.as_ref());
+
+        let mut regs: Vec<_> = active
+            .iter()
+            .map(|domain| domain.build(&DomainConfig::new(&self.config, domain.name())))
+            .collect();
+        let doctor = DoctorTool::new(Arc::new(self.config), active.into());
+        regs.push(
+            ToolRegistry::builder()
+                .register::<DoctorTool, ()>(doctor)
+                .finish(),
+        );
 
         let merged = ToolRegistry::merge_all(regs);
 
@@ -256,25 +262,6 @@ impl AgenticTools {
             merged
         }
     }
-
-    /// Get the total count of available tools when no allowlist is applied.
-    pub fn total_tool_count() -> usize {
-        CODING_NAMES.len()
-            + PR_COMMENTS_NAMES.len()
-            + LINEAR_NAMES.len()
-            + GPT5_NAMES.len()
-            + THOUGHTS_NAMES.len()
-            + WEB_NAMES.len()
-            + REVIEW_NAMES.len()
-            + WORKSPACE_NAMES.len()
-    }
-}
-
-fn workspace_tools_enabled(config: &WorkspaceToolsConfig) -> bool {
-    config.workspace_read
-        || config.workspace_todowrite
-        || config.workspace_edit
-        || config.workspace_apply_patch
 }
 
 /// Normalize allowlist: lowercase, trim, filter empty strings.
@@ -300,7 +287,7 @@ mod tests {
 
     #[test]
     fn total_tool_count_is_30() {
-        assert_eq!(AgenticTools::total_tool_count(), 34);
+        assert_eq!(AgenticTools::total_tool_count(), 57);
     }
 
     #[test]
@@ -508,6 +495,7 @@ mod tests {
                 executor_timeout_secs: 2700,
                 empty_response_no_retry_after_secs: 600,
                 stream_heartbeat_secs: 30,
+                seed: None,
             },
             ..Default::default()
         };
@@ -532,6 +520,92 @@ mod tests {
         );
     }
 
+    fn build_echo(_: &DomainConfig<'_>) -> ToolRegistry {
+        ToolRegistry::builder().finish()
+    }
+
+    #[test]
+    fn builder_registers_and_replaces_domains() {
+        let builder = AgenticTools::builder(AgenticToolsConfig::default())
+            .domain(FnDomain::new("custom", &["custom_echo"], build_echo))
+            .domain(FnDomain::new("gpt5", &["ask_reasoning_model"], build_echo))
+            .without_domain("web");
+        let names = builder.domain_names();
+        assert_eq!(names.last(), Some(&"custom"));
+        assert!(!names.contains(&"web"));
+
+        let reg = builder.build();
+        assert!(reg.contains("cli_ls"));
+        assert!(!reg.contains("ask_reasoning_model"));
+        assert!(!reg.contains("web_fetch"));
+    }
+
+    #[test]
+    fn plugin_domains_merge_into_registry() {
+        let plugin = PluginDomain::from_registry(
+            "acme",
+            &AgenticTools::new(AgenticToolsConfig {
+                allowlist: Some(HashSet::from([String::from("cli_ls")])),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(plugin.tool_names(), ["cli_ls"]);
+
+        let reg = AgenticTools::builder(AgenticToolsConfig {
+            allowlist: Some(HashSet::from([String::from("cli_ls")])),
+            ..Default::default()
+        })
+        .without_domain("coding")
+        .domain(plugin)
+        .build();
+        assert_eq!(reg.list_names(), ["cli_ls"]);
+    }
+
+    #[tokio::test]
+    async fn tools_doctor_reports_built_domains() {
+        let reg = AgenticTools::new(AgenticToolsConfig {
+            allowlist: Some(HashSet::from([
+                String::from("tools_doctor"),
+                String::from("ask_reasoning_model"),
+            ])),
+            ..Default::default()
+        });
+        assert_eq!(reg.len(), 2);
+
+        let report = reg
+            .dispatch_json(
+                "tools_doctor",
+                serde_json::json!({}),
+                &agentic_tools_core::ToolContext::default(),
+            )
+            .await
+            .unwrap();
+        let domains = report["domains"].as_array().unwrap();
+        assert_eq!(domains.len(), 1);
+        assert_eq!(domains[0]["domain"], "gpt5");
+        assert_eq!(domains[0]["checks"][0]["name"], "OPENROUTER_API_KEY");
+    }
+
+    #[test]
+    fn extras_configure_builtin_domains() {
+        let reg = AgenticTools::new(AgenticToolsConfig {
+            allowlist: Some(HashSet::from([String::from("gh_get_prs")])),
+            extras: serde_json::json!({
+                "pr_comments": { "owner": "acme", "repo": "widgets" },
+                "workspace": { "workspace_read": true },
+            }),
+            ..Default::default()
+        });
+        assert_eq!(reg.list_names(), ["gh_get_prs"]);
+
+        let reg = AgenticTools::new(AgenticToolsConfig {
+            extras: serde_json::json!({ "workspace": { "workspace_read": true } }),
+            ..Default::default()
+        });
+        assert!(reg.contains("workspace_read"));
+        assert!(!reg.contains("workspace_edit"));
+    }
+
     #[test]
     fn workspace_tools_require_matching_toggle() {
         let reg = AgenticTools::new(AgenticToolsConfig {
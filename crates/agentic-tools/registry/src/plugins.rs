@@ -0,0 +1,162 @@
+//! Out-of-tree tools served by external MCP processes.
+//!
+//! A plugin is any MCP server speaking stdio. Each entry of `extras.plugins`
+//! names one; [`AgenticToolsBuilder::load_plugins`](crate::AgenticToolsBuilder::load_plugins)
+//! spawns it, lists its tools, and registers them as a domain named after the
+//! plugin:
+//!
+//! ```json
+//! {
+//!   "extras": {
+//!     "plugins": [
+//!       { "name": "acme", "command": "acme-tools-mcp", "args": ["--stdio"] }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Calls are forwarded over MCP, so plugins can be written in any language and
+//! need no build-time link to this workspace. Plugin tool names share the
+//! registry's namespace; allowlists apply to them like any other tool.
+
+use crate::AgenticToolsConfig;
+use crate::DomainConfig;
+use crate::DomainFactory;
+use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolRegistry;
+use agentic_tools_core::registry::ErasedTool;
+use agentic_tools_mcp::McpBridge;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::process::Command;
+use tracing::warn;
+
+/// Key in `extras` listing plugins.
+pub const PLUGINS_KEY: &str = "plugins";
+
+/// How to launch one plugin.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginSpec {
+    /// Domain name for the plugin's tools.
+    pub name: String,
+    /// Executable to spawn.
+    pub command: String,
+    /// Arguments passed to `command`.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Extra environment variables for the process.
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+}
+
+impl PluginSpec {
+    /// Plugins listed in `config.extras.plugins`.
+    ///
+    /// A malformed list is logged and treated as empty.
+    pub fn from_config(config: &AgenticToolsConfig) -> Vec<Self> {
+        let Some(value) = config.extras.get(PLUGINS_KEY) else {
+            return Vec::new();
+        };
+        serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+            warn!("Ignoring invalid extras.{PLUGINS_KEY}: {e}");
+            Vec::new()
+        })
+    }
+}
+
+/// A running plugin's tools, registered as a domain.
+pub struct PluginDomain {
+    name: String,
+    names: Vec<&'static str>,
+    tools: Vec<Arc<dyn ErasedTool>>,
+}
+
+impl PluginDomain {
+    /// Spawn the plugin described by `spec` and list its tools.
+    pub async fn load(spec: &PluginSpec) -> Result<Self, ToolError> {
+        let mut command = Command::new(&spec.command);
+        command.args(&spec.args).envs(&spec.env);
+        let bridge = McpBridge::spawn(command).await?;
+        Ok(Self::from_registry(&spec.name, &bridge.registry().await?))
+    }
+
+    /// Domain `name` serving every tool in `registry`.
+    pub fn from_registry(name: impl Into<String>, registry: &ToolRegistry) -> Self {
+        let tools = registry.iter_erased();
+        Self {
+            name: name.into(),
+            names: tools.iter().map(|tool| tool.name()).collect(),
+            tools,
+        }
+    }
+}
+
+impl DomainFactory for PluginDomain {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn tool_names(&self) -> &[&str] {
+        &self.names
+    }
+
+    fn build(&self, _config: &DomainConfig<'_>) -> ToolRegistry {
+        self.tools
+            .iter()
+            .fold(ToolRegistry::builder(), |builder, tool| {
+                builder.register_erased(Arc::clone(tool))
+            })
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn specs_read_from_extras() {
+        let config = AgenticToolsConfig {
+            extras: json!({
+                "plugins": [{ "name": "acme", "command": "acme-mcp", "args": ["--stdio"] }]
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            PluginSpec::from_config(&config),
+            [PluginSpec {
+                name: "acme".into(),
+                command: "acme-mcp".into(),
+                args: vec!["--stdio".into()],
+                env: BTreeMap::new(),
+            }]
+        );
+    }
+
+    #[test]
+    fn malformed_specs_are_ignored() {
+        let config = AgenticToolsConfig {
+            extras: json!({ "plugins": { "name": "acme" } }),
+            ..Default::default()
+        };
+
+        assert!(PluginSpec::from_config(&config).is_empty());
+        assert!(PluginSpec::from_config(&AgenticToolsConfig::default()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn missing_command_fails_to_load() {
+        let spec = PluginSpec {
+            name: "missing".into(),
+            command: "/nonexistent/agentic-plugin".into(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+        };
+
+        assert!(PluginDomain::load(&spec).await.is_err());
+    }
+}
@@ -187,6 +187,11 @@ fn apply_env_overrides(cfg: &mut AgenticConfig) {
     {
         cfg.reasoning.stream_heartbeat_secs = n;
     }
+    if let Some(v) = env_trimmed("AGENTIC_REASONING_SEED")
+        && let Ok(n) = v.parse()
+    {
+        cfg.reasoning.seed = Some(n);
+    }
 
     // --- CLI tools overrides ---
     if let Some(v) = env_trimmed("AGENTIC_CLI_TOOLS_JUST_EXECUTE_TIMEOUT_SECS")
@@ -413,6 +418,20 @@ optimizer_model = "file-model"
         assert_eq!(loaded.config.reasoning.stream_heartbeat_secs, 9);
     }
 
+    #[test]
+    #[serial]
+    fn test_reasoning_seed_env_override_applies() {
+        let temp = TempDir::new().unwrap();
+        let _guard = EnvGuard::set(CONFIG_DIR_TEST_VAR, temp.path());
+
+        let loaded = load_merged(temp.path()).unwrap();
+        assert_eq!(loaded.config.reasoning.seed, None);
+
+        let _seed = EnvGuard::set("AGENTIC_REASONING_SEED", "42");
+        let loaded = load_merged(temp.path()).unwrap();
+        assert_eq!(loaded.config.reasoning.seed, Some(42));
+    }
+
     #[test]
     #[serial]
     fn test_timeout_env_overrides_apply() {
@@ -7,6 +7,7 @@
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Root configuration for all agentic tools.
 ///
@@ -137,6 +138,10 @@ pub struct ReasoningConfig {
     pub empty_response_no_retry_after_secs: u64,
     /// Heartbeat cadence for executor streaming logs.
     pub stream_heartbeat_secs: u64,
+    /// Optional sampling seed sent to optimizer and executor (honored only by providers
+    /// that support it). Recorded in call logs for reproducibility.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
 }
 
 impl Default for ReasoningConfig {
@@ -151,6 +156,7 @@ impl Default for ReasoningConfig {
             executor_timeout_secs: 2700,
             empty_response_no_retry_after_secs: 600,
             stream_heartbeat_secs: 30,
+            seed: None,
         }
     }
 }
@@ -229,6 +235,8 @@ pub struct WebRetrievalConfig {
     pub max_search_results: u32,
     /// Summarizer configuration for Haiku-based summarization.
     pub summarizer: WebSummarizerConfig,
+    /// Post-processing of `web_search` results (deduplication and source scoring).
+    pub search_quality: WebSearchQualityConfig,
 }
 
 impl Default for WebRetrievalConfig {
@@ -239,6 +247,7 @@ impl Default for WebRetrievalConfig {
             default_search_results: 8,
             max_search_results: 20,
             summarizer: WebSummarizerConfig::default(),
+            search_quality: WebSearchQualityConfig::default(),
         }
     }
 }
@@ -265,6 +274,57 @@ impl Default for WebSummarizerConfig {
     }
 }
 
+/// Configuration for `web_search` result deduplication and source quality scoring.
+///
+/// Domain lists match the host exactly or any subdomain of it
+/// (`rust-lang.org` matches `doc.rust-lang.org`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct WebSearchQualityConfig {
+    /// Collapse results with the same normalized URL or title (default: true).
+    pub dedupe: bool,
+    /// Maximum results kept per domain after deduplication. `0` disables the cap (default: 2).
+    pub max_results_per_domain: u32,
+    /// Domains treated as official documentation and boosted.
+    pub boost_domains: Vec<String>,
+    /// Domains treated as low-quality (content farms, scraped mirrors) and penalized.
+    pub penalize_domains: Vec<String>,
+    /// Points added to the 0-100 quality score for official documentation (default: 30).
+    pub boost_points: u32,
+    /// Points removed from the 0-100 quality score per low-quality signal (default: 20).
+    pub penalty_points: u32,
+}
+
+impl Default for WebSearchQualityConfig {
+    fn default() -> Self {
+        Self {
+            dedupe: true,
+            max_results_per_domain: 2,
+            boost_domains: [
+                "docs.rs",
+                "rust-lang.org",
+                "developer.mozilla.org",
+                "docs.python.org",
+                "docs.github.com",
+                "learn.microsoft.com",
+                "developer.apple.com",
+                "docs.aws.amazon.com",
+                "cloud.google.com",
+                "kubernetes.io",
+                "postgresql.org",
+                "go.dev",
+                "nodejs.org",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+            penalize_domains: Vec::new(),
+            boost_points: 30,
+            penalty_points: 20,
+        }
+    }
+}
+
 //
 // ─────────────────────────────────────────────────────────────────────────────
 // CLI TOOLS CONFIG
@@ -391,6 +451,18 @@ pub struct LinearServiceConfig {
     pub connect_timeout_secs: u64,
     /// Per-request timeout in seconds. `0` disables the timeout.
     pub request_timeout_secs: u64,
+    /// Retries after a rate-limited (`RATELIMITED`/429) or 5xx response. `0` fails immediately.
+    pub rate_limit_max_retries: u32,
+    /// Longest single retry wait in seconds; longer waits fail instead of sleeping.
+    pub rate_limit_max_wait_secs: u64,
+    /// Team key (e.g. `ENG`) or ID used when a tool call omits `team_id`.
+    pub default_team: Option<String>,
+    /// Project name or ID used when `linear_create_issue` omits `project_id`.
+    pub default_project: Option<String>,
+    /// Named `linear_search_issues` filter sets, selected with its `preset` parameter.
+    pub presets: BTreeMap<String, LinearSearchPreset>,
+    /// Local full-text index of fetched issues.
+    pub index: LinearIndexConfig,
 }
 
 impl Default for LinearServiceConfig {
@@ -399,6 +471,56 @@ impl Default for LinearServiceConfig {
             base_url: "https://api.linear.app/graphql".into(),
             connect_timeout_secs: 10,
             request_timeout_secs: 60,
+            rate_limit_max_retries: 3,
+            rate_limit_max_wait_secs: 60,
+            default_team: None,
+            default_project: None,
+            presets: BTreeMap::new(),
+            index: LinearIndexConfig::default(),
+        }
+    }
+}
+
+/// Saved `linear_search_issues` filters. Fields mirror the tool's parameters and
+/// accept the same values (`me`, team keys, `active`); parameters passed in a
+/// call override the preset's.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct LinearSearchPreset {
+    pub query: Option<String>,
+    pub include_comments: Option<bool>,
+    pub priority: Option<i32>,
+    pub state_id: Option<String>,
+    pub assignee_id: Option<String>,
+    pub creator_id: Option<String>,
+    pub team_id: Option<String>,
+    pub project_id: Option<String>,
+    pub cycle_id: Option<String>,
+    pub project_milestone_id: Option<String>,
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
+    pub updated_after: Option<String>,
+    pub updated_before: Option<String>,
+}
+
+/// Local `SQLite` full-text index of issues returned by search/read.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct LinearIndexConfig {
+    /// Record fetched issues locally and enable `linear_search_local`.
+    pub enabled: bool,
+    /// Database path. Defaults to `$XDG_CACHE_HOME/agentic/linear-index.sqlite3`.
+    pub path: Option<String>,
+    /// Entries older than this many seconds are reported stale and refreshed in the background.
+    pub stale_after_secs: u64,
+}
+
+impl Default for LinearIndexConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: None,
+            stale_after_secs: 900,
         }
     }
 }
@@ -407,17 +529,28 @@ impl Default for LinearServiceConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct GitHubServiceConfig {
-    /// Base URL for the GitHub API.
+    /// Base URL for the GitHub REST API. For GitHub Enterprise Server use
+    /// `https://<host>/api/v3`, or set `GH_HOST` instead.
     pub base_url: String,
+    /// GraphQL endpoint. Defaults to `<host>/api/graphql` for Enterprise Server and
+    /// `<base_url>/graphql` otherwise.
+    pub graphql_url: Option<String>,
     /// Total timeout for multi-request operations in seconds. `0` disables the timeout.
     pub total_timeout_secs: u64,
+    /// Retries after a rate-limited (403/429) response. `0` fails immediately.
+    pub rate_limit_max_retries: u32,
+    /// Longest single rate-limit wait in seconds; longer waits fail instead of sleeping.
+    pub rate_limit_max_wait_secs: u64,
 }
 
 impl Default for GitHubServiceConfig {
     fn default() -> Self {
         Self {
             base_url: "https://api.github.com".into(),
+            graphql_url: None,
             total_timeout_secs: 120,
+            rate_limit_max_retries: 3,
+            rate_limit_max_wait_secs: 60,
         }
     }
 }
@@ -456,12 +589,17 @@ impl Default for ReviewConfig {
 pub struct ThoughtsConfig {
     /// Wall-clock timeout for `thoughts_add_reference` in seconds. `0` disables the timeout.
     pub add_reference_timeout_secs: u64,
+
+    /// Allow `thoughts_sync` to remount and git-sync when called with `apply = true`.
+    /// When disabled the tool only reports what it would do.
+    pub allow_mcp_sync: bool,
 }
 
 impl Default for ThoughtsConfig {
     fn default() -> Self {
         Self {
             add_reference_timeout_secs: 600,
+            allow_mcp_sync: false,
         }
     }
 }
@@ -481,6 +619,46 @@ pub struct LoggingConfig {
 
     /// Whether to enable JSON-formatted logs.
     pub json: bool,
+
+    /// Size in MiB at which a tool call log day bucket rotates into a new part file
+    /// (`0 = never rotate`).
+    pub tool_logs_max_file_mb: u64,
+
+    /// Delete tool call log buckets older than this many days (`0 = keep forever`).
+    pub tool_logs_retention_days: u32,
+
+    /// Gzip tool call logs and markdown responses older than this many days (`0 = never`).
+    pub tool_logs_compress_after_days: u32,
+
+    /// Apply retention and compression while writing logs, at most once per day.
+    pub tool_logs_prune_on_write: bool,
+
+    /// Mirror tool call records into a `SQLite` index (`tool_logs.sqlite3` in the logs
+    /// directory) for fast queries; JSONL stays the source of truth. Requires a build
+    /// with the `sqlite` feature.
+    pub tool_logs_sqlite_index: bool,
+
+    /// Servers whose logged requests and markdown responses are redacted (API keys,
+    /// secret-named fields, oversized content) before reaching disk. `"*"` matches
+    /// every server; empty logs everything verbatim.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub redact_servers: Vec<String>,
+
+    /// For redacted servers, replace request strings larger than this many KiB
+    /// (typically injected file contents) with a size placeholder (`0 = keep`).
+    pub redact_max_content_kb: u64,
+
+    /// OTLP/HTTP collector base URL (e.g. `http://localhost:4318`). When set, each tool
+    /// call is also exported as a span; requires a build with the `otlp` feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub otlp_endpoint: Option<String>,
+
+    /// `service.name` reported on exported spans.
+    pub otlp_service_name: String,
+
+    /// Extra headers sent with each OTLP export request (e.g. collector auth).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub otlp_headers: BTreeMap<String, String>,
 }
 
 impl Default for LoggingConfig {
@@ -488,6 +666,16 @@ impl Default for LoggingConfig {
         Self {
             level: "info".into(),
             json: false,
+            tool_logs_max_file_mb: 50,
+            tool_logs_retention_days: 0,
+            tool_logs_compress_after_days: 0,
+            tool_logs_prune_on_write: true,
+            tool_logs_sqlite_index: false,
+            redact_servers: Vec::new(),
+            redact_max_content_kb: 16,
+            otlp_endpoint: None,
+            otlp_service_name: "agentic".into(),
+            otlp_headers: BTreeMap::new(),
         }
     }
 }
@@ -520,6 +708,47 @@ mod tests {
         assert!(!toml_str.contains("[models]"));
     }
 
+    #[test]
+    fn test_logging_defaults_keep_tool_logs() {
+        let logging = LoggingConfig::default();
+        assert_eq!(logging.tool_logs_max_file_mb, 50);
+        assert_eq!(logging.tool_logs_retention_days, 0);
+        assert_eq!(logging.tool_logs_compress_after_days, 0);
+        assert!(logging.tool_logs_prune_on_write);
+        assert!(!logging.tool_logs_sqlite_index);
+        assert_eq!(logging.otlp_endpoint, None);
+        assert!(logging.redact_servers.is_empty());
+        assert_eq!(logging.redact_max_content_kb, 16);
+        assert_eq!(logging.otlp_service_name, "agentic");
+    }
+
+    #[test]
+    fn test_logging_otlp_settings_deserialize() {
+        let config: AgenticConfig = toml::from_str(
+            r#"
+[logging]
+otlp_endpoint = "http://localhost:4318"
+
+[logging.otlp_headers]
+x-api-key = "secret"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.logging.otlp_endpoint.as_deref(),
+            Some("http://localhost:4318")
+        );
+        assert_eq!(config.logging.otlp_service_name, "agentic");
+        assert_eq!(
+            config
+                .logging
+                .otlp_headers
+                .get("x-api-key")
+                .map(String::as_str),
+            Some("secret")
+        );
+    }
+
     #[test]
     fn test_default_models_use_undated_names() {
         let subagents = SubagentsConfig::default();
@@ -570,6 +799,26 @@ deny = ["commit"]
         assert_eq!(config.orchestrator.commands.deny, ["commit"]);
     }
 
+    #[test]
+    fn test_linear_presets_deserialize() {
+        let toml_str = r#"
+[services.linear.presets.my-open-bugs]
+assignee_id = "me"
+team_id = "ENG"
+query = "bug"
+priority = 1
+"#;
+
+        let config: AgenticConfig = toml::from_str(toml_str).unwrap();
+
+        let preset = &config.services.linear.presets["my-open-bugs"];
+        assert_eq!(preset.assignee_id.as_deref(), Some("me"));
+        assert_eq!(preset.team_id.as_deref(), Some("ENG"));
+        assert_eq!(preset.query.as_deref(), Some("bug"));
+        assert_eq!(preset.priority, Some(1));
+        assert!(preset.state_id.is_none());
+    }
+
     #[test]
     fn test_orchestrator_agents_partial_deserializes_to_empty_lists() {
         let toml_str = r"
@@ -622,6 +871,9 @@ deny = ["Bash"]
         assert_eq!(cfg.summarizer.model, "claude-haiku-4-5");
         assert_eq!(cfg.summarizer.max_tokens, 300);
         assert!((cfg.summarizer.temperature - 0.2).abs() < f64::EPSILON);
+        assert!(cfg.search_quality.dedupe);
+        assert_eq!(cfg.search_quality.max_results_per_domain, 2);
+        assert!(cfg.search_quality.penalize_domains.is_empty());
     }
 
     #[test]
@@ -663,10 +915,19 @@ deny = ["Bash"]
         assert_eq!(cfg.linear.base_url, "https://api.linear.app/graphql");
         assert_eq!(cfg.linear.connect_timeout_secs, 10);
         assert_eq!(cfg.linear.request_timeout_secs, 60);
+        assert_eq!(cfg.linear.rate_limit_max_retries, 3);
+        assert_eq!(cfg.linear.rate_limit_max_wait_secs, 60);
+        assert!(cfg.linear.default_team.is_none());
+        assert!(cfg.linear.default_project.is_none());
+        assert!(cfg.linear.presets.is_empty());
+        assert!(!cfg.linear.index.enabled);
+        assert_eq!(cfg.linear.index.stale_after_secs, 900);
 
         // GitHub
         assert_eq!(cfg.github.base_url, "https://api.github.com");
         assert_eq!(cfg.github.total_timeout_secs, 120);
+        assert_eq!(cfg.github.rate_limit_max_retries, 3);
+        assert_eq!(cfg.github.rate_limit_max_wait_secs, 60);
     }
 
     #[test]
@@ -676,6 +937,7 @@ deny = ["Bash"]
         assert_eq!(cfg.subagents.runtime_timeout_secs, 3600);
         assert_eq!(cfg.review.run_timeout_secs, 1800);
         assert_eq!(cfg.thoughts.add_reference_timeout_secs, 600);
+        assert!(!cfg.thoughts.allow_mcp_sync);
     }
 
     #[test]
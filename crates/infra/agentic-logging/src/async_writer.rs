@@ -0,0 +1,294 @@
+//! Non-blocking JSONL writer.
+//!
+//! [`LogWriter::append_jsonl`] takes a file lock and writes synchronously, which
+//! on a slow disk stalls the tool call that is logging. [`AsyncLogWriter`] hands
+//! records to a background thread through a bounded queue instead. When the queue
+//! is full the oldest queued record is dropped, so the caller never waits; drops
+//! and write failures are counted in [`AsyncWriterMetrics`].
+
+use crate::LogWriter;
+use crate::ToolCallRecord;
+use crate::logging_disabled;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Condvar;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::thread::JoinHandle;
+
+/// Default number of records queued before the oldest are dropped.
+pub const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+
+/// Counters for an [`AsyncLogWriter`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AsyncWriterMetrics {
+    /// Records waiting to be written.
+    pub queued: usize,
+    /// Records appended to disk.
+    pub written: u64,
+    /// Records dropped because the queue was full.
+    pub dropped: u64,
+    /// Records whose append failed.
+    pub failed: u64,
+}
+
+#[derive(Default)]
+struct State {
+    queue: VecDeque<ToolCallRecord>,
+    writing: bool,
+    closed: bool,
+    metrics: AsyncWriterMetrics,
+}
+
+impl State {
+    /// Queue `record`, dropping the oldest if `capacity` is reached.
+    fn push(&mut self, record: ToolCallRecord, capacity: usize) {
+        if self.queue.len() >= capacity {
+            self.queue.pop_front();
+            self.metrics.dropped += 1;
+        }
+        self.queue.push_back(record);
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    /// Signalled when records are queued or the writer closes.
+    ready: Condvar,
+    /// Signalled when the queue drains.
+    idle: Condvar,
+}
+
+impl Shared {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Background-thread JSONL writer; see the module docs.
+pub struct AsyncLogWriter {
+    shared: Arc<Shared>,
+    capacity: usize,
+    worker: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AsyncLogWriter {
+    /// Start a flusher thread appending through `writer`, queueing at most
+    /// `capacity` records.
+    pub fn start(writer: LogWriter, capacity: usize) -> Self {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            ready: Condvar::new(),
+            idle: Condvar::new(),
+        });
+        let worker = {
+            let shared = Arc::clone(&shared);
+            std::thread::Builder::new()
+                .name("agentic-log-writer".into())
+                .spawn(move || flush_loop(&writer, &shared))
+                .ok()
+        };
+        // Without a flusher nothing would drain the queue; discard instead.
+        shared.lock().closed = worker.is_none();
+        Self {
+            shared,
+            capacity: capacity.max(1),
+            worker: Mutex::new(worker),
+        }
+    }
+
+    /// The process-wide writer for `base_logs_dir`, started on first use with
+    /// [`LogWriter::new`] and [`DEFAULT_QUEUE_CAPACITY`].
+    pub fn shared(base_logs_dir: &Path) -> Arc<Self> {
+        static WRITERS: LazyLock<Mutex<HashMap<PathBuf, Arc<AsyncLogWriter>>>> =
+            LazyLock::new(|| Mutex::new(HashMap::new()));
+        let mut writers = WRITERS.lock().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(
+            writers
+                .entry(base_logs_dir.to_path_buf())
+                .or_insert_with(|| {
+                    Arc::new(Self::start(
+                        LogWriter::new(base_logs_dir),
+                        DEFAULT_QUEUE_CAPACITY,
+                    ))
+                }),
+        )
+    }
+
+    /// Queue `record` without blocking on IO.
+    ///
+    /// Drops the oldest queued record if the queue is full. Records appended
+    /// after [`Self::shutdown`], or while logging is disabled, are discarded.
+    pub fn append(&self, record: ToolCallRecord) {
+        if logging_disabled() {
+            return;
+        }
+        let mut state = self.shared.lock();
+        if state.closed {
+            return;
+        }
+        state.push(record, self.capacity);
+        drop(state);
+        self.shared.ready.notify_one();
+    }
+
+    /// Current counters.
+    pub fn metrics(&self) -> AsyncWriterMetrics {
+        let state = self.shared.lock();
+        AsyncWriterMetrics {
+            queued: state.queue.len(),
+            ..state.metrics
+        }
+    }
+
+    /// Block until every record queued so far has been written.
+    pub fn flush(&self) {
+        let mut state = self.shared.lock();
+        while !state.closed && (state.writing || !state.queue.is_empty()) {
+            state = self
+                .shared
+                .idle
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Write what is queued, then stop the flusher thread.
+    pub fn shutdown(&self) {
+        self.flush();
+        self.shared.lock().closed = true;
+        self.shared.ready.notify_all();
+        let worker = self
+            .worker
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        if let Some(worker) = worker {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn flush_loop(writer: &LogWriter, shared: &Shared) {
+    let mut state = shared.lock();
+    loop {
+        if let Some(record) = state.queue.pop_front() {
+            state.writing = true;
+            drop(state);
+            let result = writer.append_jsonl(&record);
+            state = shared.lock();
+            state.writing = false;
+            if result.is_ok() {
+                state.metrics.written += 1;
+            } else {
+                state.metrics.failed += 1;
+            }
+            if state.queue.is_empty() {
+                shared.idle.notify_all();
+            }
+        } else if state.closed {
+            shared.idle.notify_all();
+            return;
+        } else {
+            state = shared
+                .ready
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LogFilter;
+    use crate::LogReader;
+    use chrono::Utc;
+    use serial_test::serial;
+
+    fn record(i: usize) -> ToolCallRecord {
+        let now = Utc::now();
+        ToolCallRecord {
+            call_id: format!("call-{i}"),
+            server: "test".into(),
+            tool: "test".into(),
+            started_at: now,
+            completed_at: now,
+            duration_ms: 1,
+            request: serde_json::json!({}),
+            response_file: None,
+            success: true,
+            error: None,
+            failure_kind: None,
+            model: None,
+            token_usage: None,
+            summary: None,
+            trace_id: None,
+            parent_call_id: None,
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn queued_records_are_written_in_order() {
+        let temp = tempfile::tempdir().unwrap();
+        let writer = AsyncLogWriter::start(LogWriter::new(temp.path()), 16);
+        for i in 0..5 {
+            writer.append(record(i));
+        }
+        writer.flush();
+        assert_eq!(
+            writer.metrics(),
+            AsyncWriterMetrics {
+                written: 5,
+                ..AsyncWriterMetrics::default()
+            }
+        );
+
+        let ids: Vec<String> = LogReader::new(temp.path())
+            .query(&LogFilter::default())
+            .unwrap()
+            .records
+            .into_iter()
+            .map(|r| r.call_id)
+            .collect();
+        assert_eq!(ids, (0..5).map(|i| format!("call-{i}")).collect::<Vec<_>>());
+
+        writer.shutdown();
+        writer.append(record(9));
+        assert_eq!(writer.metrics().queued, 0);
+    }
+
+    #[test]
+    #[serial]
+    fn full_queue_drops_oldest() {
+        let temp = tempfile::tempdir().unwrap();
+        let writer = AsyncLogWriter::start(LogWriter::new(temp.path()), 2);
+        // Hold the queue lock so the flusher cannot drain while we overfill it.
+        {
+            let mut state = writer.shared.lock();
+            for i in 0..4 {
+                state.push(record(i), writer.capacity);
+            }
+        }
+        writer.shared.ready.notify_one();
+        writer.shutdown();
+
+        let metrics = writer.metrics();
+        assert_eq!(metrics.dropped, 2);
+        assert_eq!(metrics.written, 2);
+        let ids: Vec<String> = LogReader::new(temp.path())
+            .query(&LogFilter::default())
+            .unwrap()
+            .records
+            .into_iter()
+            .map(|r| r.call_id)
+            .collect();
+        assert_eq!(ids, vec!["call-2".to_string(), "call-3".to_string()]);
+    }
+}
@@ -3,8 +3,14 @@
 //! This crate provides utilities for logging tool calls to JSONL files with:
 //! - Atomic writes with file locking
 //! - Optional markdown response files for large outputs
-//! - Daily bucket organization
+//! - Daily bucket organization, split into part files past a size limit
+//! - Retention and compression of old buckets via [`LogPolicy`]
+//! - Per-server redaction of requests and responses via [`RedactionPolicy`]
 //! - Disable via `AGENTIC_LOGGING_DISABLED=1` environment variable
+//! - Non-blocking appends through a bounded queue via [`AsyncLogWriter`]
+//! - Trace/parent call IDs for correlating nested calls via [`TraceContext`]
+//! - Querying and aggregation via [`LogReader`]
+//! - Optional OTLP span export (feature `otlp`)
 
 use atomicwrites::AtomicFile;
 use atomicwrites::OverwriteBehavior;
@@ -23,6 +29,57 @@ use std::sync::LazyLock;
 use thiserror::Error;
 use uuid::Uuid;
 
+mod async_writer;
+#[cfg(feature = "otlp")]
+mod otlp;
+mod reader;
+mod redact;
+mod retention;
+#[cfg(feature = "sqlite")]
+mod sqlite;
+mod trace;
+mod watch;
+
+pub use async_writer::AsyncLogWriter;
+pub use async_writer::AsyncWriterMetrics;
+pub use async_writer::DEFAULT_QUEUE_CAPACITY;
+#[cfg(feature = "otlp")]
+pub use otlp::OtlpConfig;
+#[cfg(feature = "otlp")]
+pub use otlp::OtlpExporter;
+#[cfg(feature = "otlp")]
+pub use otlp::install_exporter;
+
+pub use reader::LogFilter;
+pub use reader::LogQueryResult;
+pub use reader::LogReader;
+pub use reader::LogStats;
+pub use reader::ModelUsage;
+pub use reader::ToolStats;
+pub use reader::parse_time_bound;
+pub use redact::ApiKeyRedactor;
+pub use redact::LargeContentRedactor;
+pub use redact::REDACTED;
+pub use redact::RedactionPolicy;
+pub use redact::Redactor;
+pub use redact::default_redaction;
+pub use redact::set_default_redaction;
+pub use retention::DEFAULT_MAX_FILE_BYTES;
+pub use retention::LogPolicy;
+pub use retention::PruneReport;
+pub use retention::default_policy;
+pub use retention::set_default_policy;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SQLITE_INDEX_FILE;
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteIndex;
+#[cfg(feature = "sqlite")]
+pub use sqlite::set_sqlite_index_enabled;
+pub use trace::PARENT_CALL_ID_ENV;
+pub use trace::TRACE_ID_ENV;
+pub use trace::TraceContext;
+pub use watch::LogWatcher;
+
 /// Process-wide session identifier (first 8 chars of UUID v4).
 ///
 /// Used to namespace log files and prevent conflicts between concurrent processes
@@ -42,6 +99,9 @@ pub enum LogError {
     Json(#[from] serde_json::Error),
     #[error("Atomic write error: {0}")]
     AtomicWrite(String),
+    #[cfg(feature = "sqlite")]
+    #[error("SQLite error: {0}")]
+    Sqlite(String),
 }
 
 impl<E: std::fmt::Display> From<atomicwrites::Error<E>> for LogError {
@@ -50,6 +110,13 @@ impl<E: std::fmt::Display> From<atomicwrites::Error<E>> for LogError {
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for LogError {
+    fn from(e: rusqlite::Error) -> Self {
+        Self::Sqlite(e.to_string())
+    }
+}
+
 /// Token usage information for API calls.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenUsage {
@@ -97,6 +164,12 @@ pub struct ToolCallRecord {
     /// Summary data for compact tools (e.g., {"entries": 10, "`has_more"`: true})
     #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<serde_json::Value>,
+    /// Call ID of the originating request this call was made for (see [`TraceContext`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    /// Call ID of the call that caused this one, when nested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_call_id: Option<String>,
 }
 
 /// Classify a failed tool call into a structured failure kind.
@@ -129,20 +202,33 @@ pub struct CallTimer {
     pub call_id: String,
     /// When the call started (UTC)
     pub started_at: DateTime<Utc>,
+    /// Trace this call belongs to; its own `call_id` when it starts a new trace
+    pub trace_id: String,
+    /// Call that caused this one, from the current [`TraceContext`]
+    pub parent_call_id: Option<String>,
     /// Instant for measuring elapsed time
     start_instant: std::time::Instant,
 }
 
 impl CallTimer {
-    /// Start a new timer with a fresh call ID.
+    /// Start a new timer with a fresh call ID in the current [`TraceContext`].
     pub fn start() -> Self {
+        let call_id = Uuid::new_v4().to_string();
+        let trace = TraceContext::current();
         Self {
-            call_id: Uuid::new_v4().to_string(),
+            trace_id: trace.trace_id.unwrap_or_else(|| call_id.clone()),
+            parent_call_id: trace.parent_call_id,
+            call_id,
             started_at: Utc::now(),
             start_instant: std::time::Instant::now(),
         }
     }
 
+    /// Context for calls made on behalf of this one.
+    pub fn child_context(&self) -> TraceContext {
+        TraceContext::child_of(&self.trace_id, &self.call_id)
+    }
+
     /// Finish the timer and return the completion time and duration.
     pub fn finish(&self) -> (DateTime<Utc>, u128) {
         let completed_at = Utc::now();
@@ -159,19 +245,69 @@ impl CallTimer {
     }
 }
 
+/// Date of a day bucket file or directory name (`tool_logs_YYYY-MM-DD_...`).
+pub(crate) fn bucket_day(name: &str) -> Option<chrono::NaiveDate> {
+    let date = name.strip_prefix("tool_logs_")?.get(..10)?;
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
 /// Writer for JSONL log files and markdown response files.
 pub struct LogWriter {
     base_logs_dir: PathBuf,
+    policy: LogPolicy,
+    redaction: RedactionPolicy,
+    server: Option<String>,
 }
 
 impl LogWriter {
-    /// Create a new log writer with the given base logs directory.
+    /// Create a new log writer with the given base logs directory and the
+    /// process-wide [`default_policy`] and [`default_redaction`].
     pub fn new(base_logs_dir: impl Into<PathBuf>) -> Self {
         Self {
             base_logs_dir: base_logs_dir.into(),
+            policy: default_policy(),
+            redaction: default_redaction(),
+            server: None,
         }
     }
 
+    /// The directory this writer writes day buckets into.
+    pub fn logs_dir(&self) -> &std::path::Path {
+        &self.base_logs_dir
+    }
+
+    /// Use `policy` instead of the process-wide default.
+    #[must_use]
+    pub fn with_policy(mut self, policy: LogPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Use `redaction` instead of the process-wide default.
+    #[must_use]
+    pub fn with_redaction(mut self, redaction: RedactionPolicy) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Server whose markdown responses this writer stores, so the redaction
+    /// policy can apply to them. Records carry their own server name.
+    #[must_use]
+    pub fn with_server(mut self, server: impl Into<String>) -> Self {
+        self.server = Some(server.into());
+        self
+    }
+
+    /// Delete and compress old buckets according to the writer's policy.
+    pub fn prune(&self) -> Result<PruneReport, LogError> {
+        self.prune_at(Utc::now())
+    }
+
+    /// [`Self::prune`] as of `now`.
+    pub fn prune_at(&self, now: DateTime<Utc>) -> Result<PruneReport, LogError> {
+        retention::prune_dir(&self.base_logs_dir, &self.policy, now)
+    }
+
     /// Generate the day bucket name from a timestamp.
     ///
     /// Format: `tool_logs_YYYY-MM-DD_{session_id}` where `session_id` is the first
@@ -192,7 +328,8 @@ impl LogWriter {
 
     /// Write a markdown response file and return its filename.
     ///
-    /// Returns an empty string if logging is disabled.
+    /// Redacted first when the writer's server (see [`Self::with_server`]) is
+    /// covered by its redaction policy. Returns an empty string if logging is disabled.
     pub fn write_markdown_response(
         &self,
         completed_at: DateTime<Utc>,
@@ -204,6 +341,10 @@ impl LogWriter {
         }
         let bucket = Self::day_bucket_name(completed_at);
         let (_jsonl, md_dir) = self.ensure_day_dirs(&bucket)?;
+        let content = match &self.server {
+            Some(server) => self.redaction.redact_response(server, content),
+            None => std::borrow::Cow::Borrowed(content),
+        };
         let filename = format!("{call_id}.md");
         let target = md_dir.join(&filename);
         let af = AtomicFile::new(&target, OverwriteBehavior::AllowOverwrite);
@@ -214,6 +355,8 @@ impl LogWriter {
     /// Append a tool call record to the JSONL log file.
     ///
     /// Uses file locking to prevent concurrent write corruption.
+    /// The request is redacted first if the redaction policy covers `record.server`.
+    /// With the `otlp` feature the record is also sent to the installed exporter.
     /// Returns Ok(()) if logging is disabled.
     pub fn append_jsonl(&self, record: &ToolCallRecord) -> Result<(), LogError> {
         if logging_disabled() {
@@ -221,17 +364,57 @@ impl LogWriter {
         }
         let bucket = Self::day_bucket_name(record.completed_at);
         let (jsonl_path, _md_dir) = self.ensure_day_dirs(&bucket)?;
+        let jsonl_path = self.active_part(&bucket, jsonl_path);
 
         let file = OpenOptions::new()
             .create(true)
             .append(true)
             .open(&jsonl_path)?;
+        let redacted = self
+            .redaction
+            .redact_request(&record.server, &record.request)
+            .map(|request| ToolCallRecord {
+                request,
+                ..record.clone()
+            });
         let mut lock = RwLock::new(file);
         let mut guard = lock.write()?;
-        serde_json::to_writer(&mut *guard, record)?;
+        serde_json::to_writer(&mut *guard, redacted.as_ref().unwrap_or(record))?;
         guard.write_all(b"\n")?;
+        drop(guard);
+
+        #[cfg(feature = "sqlite")]
+        sqlite::mirror(&self.base_logs_dir, redacted.as_ref().unwrap_or(record));
+
+        #[cfg(feature = "otlp")]
+        otlp::emit(record);
+
+        let now = Utc::now();
+        if self.policy.prune_on_write
+            && self.policy.prunes()
+            && retention::claim_daily_prune(&self.base_logs_dir, now.date_naive())
+        {
+            // Pruning is housekeeping; a failure must not fail the logged call.
+            let _ = self.prune_at(now);
+        }
         Ok(())
     }
+
+    /// The part file to append to: the bucket's JSONL file until it reaches
+    /// `max_file_bytes`, then `{bucket}.1.jsonl`, `{bucket}.2.jsonl`, ...
+    fn active_part(&self, bucket: &str, first: PathBuf) -> PathBuf {
+        let Some(max) = self.policy.max_file_bytes.filter(|m| *m > 0) else {
+            return first;
+        };
+        let full = |path: &PathBuf| std::fs::metadata(path).is_ok_and(|m| m.len() >= max);
+        let mut path = first;
+        let mut part = 0;
+        while full(&path) {
+            part += 1;
+            path = self.base_logs_dir.join(format!("{bucket}.{part}.jsonl"));
+        }
+        path
+    }
 }
 
 #[cfg(test)]
@@ -349,6 +532,8 @@ mod tests {
             model: None,
             token_usage: None,
             summary: None,
+            trace_id: Some(timer.trace_id),
+            parent_call_id: timer.parent_call_id,
         };
 
         writer.append_jsonl(&record).unwrap();
@@ -394,6 +579,8 @@ mod tests {
                 model: None,
                 token_usage: None,
                 summary: None,
+                trace_id: Some(timer.trace_id),
+                parent_call_id: timer.parent_call_id,
             };
             writer.append_jsonl(&record).unwrap();
         }
@@ -462,6 +649,8 @@ mod tests {
             model: None,
             token_usage: None,
             summary: None,
+            trace_id: Some(timer.trace_id.clone()),
+            parent_call_id: timer.parent_call_id.clone(),
         };
         writer.append_jsonl(&record).unwrap();
 
@@ -477,6 +666,100 @@ mod tests {
         // Guard automatically removes env var on drop
     }
 
+    #[test]
+    #[serial]
+    fn test_jsonl_rotates_into_part_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let writer = LogWriter::new(temp.path()).with_policy(LogPolicy {
+            max_file_bytes: Some(1),
+            ..LogPolicy::default()
+        });
+
+        let completed_at = Utc::now();
+        for i in 0..3 {
+            let record = ToolCallRecord {
+                call_id: format!("call-{i}"),
+                server: "test".into(),
+                tool: "test".into(),
+                started_at: completed_at,
+                completed_at,
+                duration_ms: 1,
+                request: serde_json::json!({}),
+                response_file: None,
+                success: true,
+                error: None,
+                failure_kind: None,
+                model: None,
+                token_usage: None,
+                summary: None,
+                trace_id: None,
+                parent_call_id: None,
+            };
+            writer.append_jsonl(&record).unwrap();
+        }
+
+        let bucket = LogWriter::day_bucket_name(completed_at);
+        for name in [
+            format!("{bucket}.jsonl"),
+            format!("{bucket}.1.jsonl"),
+            format!("{bucket}.2.jsonl"),
+        ] {
+            let content = std::fs::read_to_string(temp.path().join(&name)).unwrap();
+            assert_eq!(content.lines().count(), 1, "{name}");
+        }
+
+        let result = LogReader::new(temp.path())
+            .query(&LogFilter::default())
+            .unwrap();
+        assert_eq!(result.stats.total_calls, 3);
+    }
+
+    #[test]
+    #[serial]
+    fn test_redaction_applies_to_opted_in_servers() {
+        let temp = tempfile::tempdir().unwrap();
+        let redaction = RedactionPolicy::builtin(["gpt5_reasoner"], None);
+        let writer = LogWriter::new(temp.path()).with_redaction(redaction.clone());
+
+        let completed_at = Utc::now();
+        let secret = "sk-or-v1-0123456789abcdefghijklmnop";
+        for server in ["gpt5_reasoner", "thoughts_tool"] {
+            let record = ToolCallRecord {
+                call_id: format!("call-{server}"),
+                server: server.into(),
+                tool: "test".into(),
+                started_at: completed_at,
+                completed_at,
+                duration_ms: 1,
+                request: serde_json::json!({"prompt": format!("key={secret}")}),
+                response_file: None,
+                success: true,
+                error: None,
+                failure_kind: None,
+                model: None,
+                token_usage: None,
+                summary: None,
+                trace_id: None,
+                parent_call_id: None,
+            };
+            writer.append_jsonl(&record).unwrap();
+        }
+        let bucket = LogWriter::day_bucket_name(completed_at);
+        let content = std::fs::read_to_string(temp.path().join(format!("{bucket}.jsonl"))).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert!(lines[0].contains("key=[REDACTED]"));
+        assert!(lines[1].contains(secret));
+
+        let md_writer = LogWriter::new(temp.path())
+            .with_redaction(redaction)
+            .with_server("gpt5_reasoner");
+        let filename = md_writer
+            .write_markdown_response(completed_at, "md-call", &format!("# Plan\n{secret}"))
+            .unwrap();
+        let md = std::fs::read_to_string(temp.path().join(&bucket).join(filename)).unwrap();
+        assert_eq!(md, "# Plan\n[REDACTED]");
+    }
+
     #[test]
     fn test_token_usage_serialization() {
         let usage = TokenUsage {
@@ -521,6 +804,8 @@ mod tests {
             model: None,
             token_usage: None,
             summary: None,
+            trace_id: Some(timer.trace_id),
+            parent_call_id: timer.parent_call_id,
         };
 
         let json = serde_json::to_string(&record).unwrap();
@@ -553,6 +838,8 @@ mod tests {
             model: None,
             token_usage: None,
             summary: None,
+            trace_id: Some(timer.trace_id),
+            parent_call_id: timer.parent_call_id,
         };
 
         let json = serde_json::to_string(&record).unwrap();
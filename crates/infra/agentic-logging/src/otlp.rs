@@ -0,0 +1,358 @@
+//! OpenTelemetry export of tool call records (feature `otlp`).
+//!
+//! Each [`ToolCallRecord`] appended through [`crate::LogWriter`] is also sent to an
+//! OTLP/HTTP collector as one span, so dashboards can be built without parsing
+//! JSONL. Spans are encoded as OTLP JSON and posted to `{endpoint}/v1/traces` from
+//! a background thread; callers only enqueue, and records are dropped rather than
+//! blocking when the queue is full or the collector is unreachable.
+
+use crate::ToolCallRecord;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::OnceLock;
+use std::sync::mpsc;
+use std::sync::mpsc::Receiver;
+use std::sync::mpsc::SyncSender;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Records waiting to be exported before new ones are dropped.
+const QUEUE_CAPACITY: usize = 1024;
+
+/// Most spans sent in one export request.
+const MAX_BATCH: usize = 256;
+
+/// Where and how to export spans.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtlpConfig {
+    /// Collector base URL, e.g. `http://localhost:4318`; `/v1/traces` is appended.
+    pub endpoint: String,
+    /// `service.name` resource attribute.
+    pub service_name: String,
+    /// Extra request headers (e.g. collector auth).
+    pub headers: Vec<(String, String)>,
+    /// Timeout for each export request.
+    pub timeout: Duration,
+}
+
+impl OtlpConfig {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            service_name: "agentic".into(),
+            headers: Vec::new(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    fn traces_url(&self) -> String {
+        format!("{}/v1/traces", self.endpoint.trim_end_matches('/'))
+    }
+}
+
+/// Background OTLP exporter; see the module docs.
+pub struct OtlpExporter {
+    tx: Option<SyncSender<Value>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl OtlpExporter {
+    /// Start the export thread.
+    pub fn start(config: OtlpConfig) -> Self {
+        let (tx, rx) = mpsc::sync_channel(QUEUE_CAPACITY);
+        let worker = std::thread::Builder::new()
+            .name("agentic-otlp".into())
+            .spawn(move || export_loop(&config, &rx))
+            .ok();
+        Self {
+            tx: worker.as_ref().map(|_| tx),
+            worker,
+        }
+    }
+
+    /// Queue `record` for export; dropped if the queue is full.
+    pub fn export(&self, record: &ToolCallRecord) {
+        if let Some(tx) = &self.tx {
+            let _ = tx.try_send(span_json(record));
+        }
+    }
+
+    /// Export everything queued so far, then stop the thread.
+    pub fn shutdown(mut self) {
+        self.tx.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+static EXPORTER: OnceLock<OtlpExporter> = OnceLock::new();
+
+/// Install the process-wide exporter used by [`crate::LogWriter::append_jsonl`].
+///
+/// Returns false if one was already installed.
+pub fn install_exporter(exporter: OtlpExporter) -> bool {
+    EXPORTER.set(exporter).is_ok()
+}
+
+/// Send `record` to the installed exporter, if any.
+pub fn emit(record: &ToolCallRecord) {
+    if let Some(exporter) = EXPORTER.get() {
+        exporter.export(record);
+    }
+}
+
+fn export_loop(config: &OtlpConfig, rx: &Receiver<Value>) {
+    let Ok(client) = reqwest::blocking::Client::builder()
+        .timeout(config.timeout)
+        .build()
+    else {
+        return;
+    };
+    let url = config.traces_url();
+    while let Ok(first) = rx.recv() {
+        let mut spans = vec![first];
+        while spans.len() < MAX_BATCH {
+            match rx.try_recv() {
+                Ok(span) => spans.push(span),
+                Err(_) => break,
+            }
+        }
+        let mut request = client.post(&url).json(&traces_request(config, &spans));
+        for (name, value) in &config.headers {
+            request = request.header(name, value);
+        }
+        // Export is best-effort: a down collector must not affect tool calls.
+        let _ = request.send();
+    }
+}
+
+/// OTLP `ExportTraceServiceRequest` wrapping `spans` for this service.
+fn traces_request(config: &OtlpConfig, spans: &[Value]) -> Value {
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [attr("service.name", &Value::from(config.service_name.as_str()))],
+            },
+            "scopeSpans": [{
+                "scope": { "name": "agentic_logging", "version": env!("CARGO_PKG_VERSION") },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+/// Encode one record as an OTLP JSON span.
+///
+/// The span ID derives from the call ID (a UUID) and the trace ID from the
+/// record's trace ID, so re-exporting a record yields the same span and nested
+/// calls nest under their parent's span. Token usage uses the `gen_ai.*` semantic
+/// conventions; summary fields become `agentic.summary.<key>` attributes.
+fn span_json(record: &ToolCallRecord) -> Value {
+    let trace_id = otlp_id(record.trace_id.as_deref().unwrap_or(&record.call_id), 32);
+    let span_id = otlp_id(&record.call_id, 16);
+
+    let mut attributes = vec![
+        attr("agentic.server", &Value::from(record.server.as_str())),
+        attr("agentic.tool", &Value::from(record.tool.as_str())),
+        attr("agentic.call_id", &Value::from(record.call_id.as_str())),
+        attr("agentic.success", &Value::from(record.success)),
+    ];
+    let mut push = |key: &str, value: Value| attributes.push(attr(key, &value));
+    if let Some(kind) = &record.failure_kind {
+        push("agentic.failure_kind", Value::from(kind.as_str()));
+    }
+    if let Some(file) = &record.response_file {
+        push("agentic.response_file", Value::from(file.as_str()));
+    }
+    if let Some(model) = &record.model {
+        push("gen_ai.request.model", Value::from(model.as_str()));
+    }
+    if let Some(usage) = &record.token_usage {
+        push("gen_ai.usage.input_tokens", Value::from(usage.prompt));
+        push("gen_ai.usage.output_tokens", Value::from(usage.completion));
+        push("agentic.usage.total_tokens", Value::from(usage.total));
+        if let Some(reasoning) = usage.reasoning_tokens {
+            push("agentic.usage.reasoning_tokens", Value::from(reasoning));
+        }
+    }
+    if let Some(Value::Object(summary)) = &record.summary {
+        for (key, value) in summary {
+            push(&format!("agentic.summary.{key}"), value.clone());
+        }
+    }
+
+    let status = if record.success {
+        json!({ "code": 1 })
+    } else {
+        json!({ "code": 2, "message": record.error.as_deref().unwrap_or_default() })
+    };
+
+    let mut span = json!({
+        "traceId": trace_id,
+        "spanId": span_id,
+        "name": record.tool,
+        "kind": 1,
+        "startTimeUnixNano": unix_nanos(record.started_at),
+        "endTimeUnixNano": unix_nanos(record.completed_at),
+        "attributes": attributes,
+        "status": status,
+    });
+    if let Some(parent) = &record.parent_call_id {
+        span["parentSpanId"] = Value::from(otlp_id(parent, 16));
+    }
+    span
+}
+
+/// The last `len` hex digits of a UUID-like ID, zero-padded.
+fn otlp_id(id: &str, len: usize) -> String {
+    let hex: String = id
+        .chars()
+        .filter(char::is_ascii_hexdigit)
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let padded = format!("{hex:0>len$}");
+    padded[padded.len() - len..].to_string()
+}
+
+/// OTLP JSON encodes 64-bit nanosecond timestamps as decimal strings.
+fn unix_nanos(at: chrono::DateTime<chrono::Utc>) -> String {
+    at.timestamp_nanos_opt()
+        .unwrap_or_default()
+        .max(0)
+        .to_string()
+}
+
+fn attr(key: &str, value: &Value) -> Value {
+    json!({ "key": key, "value": any_value(value) })
+}
+
+/// OTLP `AnyValue` for a JSON value; objects and arrays become JSON strings.
+fn any_value(value: &Value) -> Value {
+    match value {
+        Value::Bool(b) => json!({ "boolValue": b }),
+        // intValue is int64, which OTLP JSON encodes as a string.
+        Value::Number(n) if n.is_i64() || n.is_u64() => json!({ "intValue": n.to_string() }),
+        Value::Number(n) => json!({ "doubleValue": n.as_f64() }),
+        Value::String(s) => json!({ "stringValue": s }),
+        Value::Null | Value::Array(_) | Value::Object(_) => {
+            json!({ "stringValue": value.to_string() })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenUsage;
+    use chrono::DateTime;
+    use chrono::Utc;
+
+    fn record() -> ToolCallRecord {
+        let started_at = DateTime::parse_from_rfc3339("2026-03-02T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        ToolCallRecord {
+            call_id: "0b4f6a1c-2d3e-4f50-8a9b-c0d1e2f3a4b5".into(),
+            server: "gpt5_reasoner".into(),
+            tool: "reasoning".into(),
+            started_at,
+            completed_at: started_at + chrono::Duration::milliseconds(1500),
+            duration_ms: 1500,
+            request: json!({"prompt": "secret"}),
+            response_file: None,
+            success: false,
+            error: Some("Timed out".into()),
+            failure_kind: Some("timeout".into()),
+            model: Some("openai/gpt-5".into()),
+            token_usage: Some(TokenUsage {
+                prompt: 100,
+                completion: 40,
+                total: 140,
+                reasoning_tokens: Some(30),
+            }),
+            summary: Some(json!({"files": 3, "truncated": true})),
+            trace_id: None,
+            parent_call_id: None,
+        }
+    }
+
+    fn attr_value<'a>(span: &'a Value, key: &str) -> &'a Value {
+        span["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["key"] == key)
+            .map_or_else(|| panic!("missing attribute {key}"), |a| &a["value"])
+    }
+
+    #[test]
+    fn span_json_maps_record_fields() {
+        let span = span_json(&record());
+        assert_eq!(span["traceId"], "0b4f6a1c2d3e4f508a9bc0d1e2f3a4b5");
+        assert_eq!(span["spanId"], "8a9bc0d1e2f3a4b5");
+        assert!(span.get("parentSpanId").is_none());
+        assert_eq!(span["name"], "reasoning");
+        assert_eq!(span["startTimeUnixNano"], "1772452800000000000");
+        assert_eq!(span["endTimeUnixNano"], "1772452801500000000");
+        assert_eq!(span["status"], json!({"code": 2, "message": "Timed out"}));
+
+        assert_eq!(
+            attr_value(&span, "agentic.server"),
+            &json!({"stringValue": "gpt5_reasoner"})
+        );
+        assert_eq!(
+            attr_value(&span, "gen_ai.usage.input_tokens"),
+            &json!({"intValue": "100"})
+        );
+        assert_eq!(
+            attr_value(&span, "agentic.usage.reasoning_tokens"),
+            &json!({"intValue": "30"})
+        );
+        assert_eq!(
+            attr_value(&span, "agentic.summary.truncated"),
+            &json!({"boolValue": true})
+        );
+        // Request payloads stay out of spans.
+        assert!(!span.to_string().contains("secret"));
+
+        let nested = span_json(&ToolCallRecord {
+            call_id: "11111111-2222-3333-4444-555555555555".into(),
+            trace_id: Some("0b4f6a1c-2d3e-4f50-8a9b-c0d1e2f3a4b5".into()),
+            parent_call_id: Some("0b4f6a1c-2d3e-4f50-8a9b-c0d1e2f3a4b5".into()),
+            ..record()
+        });
+        assert_eq!(nested["traceId"], "0b4f6a1c2d3e4f508a9bc0d1e2f3a4b5");
+        assert_eq!(nested["spanId"], "4444555555555555");
+        assert_eq!(nested["parentSpanId"], "8a9bc0d1e2f3a4b5");
+    }
+
+    #[test]
+    fn exporter_posts_batched_spans_to_collector() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("POST", "/v1/traces")
+            .match_header("x-api-key", "k")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "resourceSpans": [{
+                    "resource": {
+                        "attributes": [{"key": "service.name", "value": {"stringValue": "test-svc"}}]
+                    }
+                }]
+            })))
+            .with_status(200)
+            .expect_at_least(1)
+            .create();
+
+        let exporter = OtlpExporter::start(OtlpConfig {
+            service_name: "test-svc".into(),
+            headers: vec![("x-api-key".into(), "k".into())],
+            ..OtlpConfig::new(format!("{}/", server.url()))
+        });
+        exporter.export(&record());
+        exporter.export(&record());
+        exporter.shutdown();
+
+        mock.assert();
+    }
+}
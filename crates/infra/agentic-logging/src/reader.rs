@@ -0,0 +1,464 @@
+//! Querying and aggregating tool call logs.
+//!
+//! [`LogReader`] scans the `tool_logs_YYYY-MM-DD_{session}.jsonl` day buckets
+//! written by [`crate::LogWriter`] (including rotated part files and gzipped
+//! buckets), keeps the records matching a [`LogFilter`],
+//! and summarizes them as [`LogStats`]: call counts, p50/p95 durations per tool,
+//! and token totals per model.
+
+use crate::LogError;
+use crate::ToolCallRecord;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Criteria a record must meet to be returned. The default filter matches every record.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Exact server name (e.g. `thoughts_tool`).
+    pub server: Option<String>,
+    /// Exact tool name (e.g. `thoughts_write_document`).
+    pub tool: Option<String>,
+    /// Only successful (`true`) or failed (`false`) calls.
+    pub success: Option<bool>,
+    /// Calls completed at or after this time.
+    pub since: Option<DateTime<Utc>>,
+    /// Calls completed before this time.
+    pub until: Option<DateTime<Utc>>,
+    /// Calls in this trace, including the originating call itself.
+    pub trace_id: Option<String>,
+}
+
+impl LogFilter {
+    pub fn matches(&self, record: &ToolCallRecord) -> bool {
+        self.server.as_ref().is_none_or(|s| *s == record.server)
+            && self.tool.as_ref().is_none_or(|t| *t == record.tool)
+            && self.success.is_none_or(|s| s == record.success)
+            && self.since.is_none_or(|t| record.completed_at >= t)
+            && self.until.is_none_or(|t| record.completed_at < t)
+            && self
+                .trace_id
+                .as_ref()
+                .is_none_or(|t| record.trace_id.as_ref() == Some(t) || record.call_id == *t)
+    }
+
+    /// Whether a day bucket can hold matching records. Buckets are named after the
+    /// UTC completion date, so whole days outside the time range are skipped unread.
+    fn wants_day(&self, day: NaiveDate) -> bool {
+        self.since.is_none_or(|t| day >= t.date_naive())
+            && self.until.is_none_or(|t| day <= t.date_naive())
+    }
+}
+
+/// Parse a time bound: an RFC 3339 timestamp, a `YYYY-MM-DD` date (UTC midnight),
+/// or an age before `now` such as `30m`, `24h`, `7d`, or `2w`.
+pub fn parse_time_bound(raw: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let raw = raw.trim();
+    if let Ok(ts) = DateTime::parse_from_rfc3339(raw) {
+        return Some(ts.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        return date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc());
+    }
+    let unit_at = raw
+        .len()
+        .checked_sub(1)
+        .filter(|i| raw.is_char_boundary(*i))?;
+    let (amount, unit) = raw.split_at(unit_at);
+    let amount: i64 = amount.parse().ok().filter(|n| *n >= 0)?;
+    let age = match unit {
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }?;
+    now.checked_sub_signed(age)
+}
+
+/// Records matching a filter, oldest first, with their aggregates.
+#[derive(Debug, Clone)]
+pub struct LogQueryResult {
+    pub records: Vec<ToolCallRecord>,
+    pub stats: LogStats,
+}
+
+/// Aggregates over a set of tool call records.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LogStats {
+    pub total_calls: u64,
+    pub failed_calls: u64,
+    pub p50_duration_ms: u64,
+    pub p95_duration_ms: u64,
+    /// Per (server, tool), most-called first.
+    pub tools: Vec<ToolStats>,
+    /// Per model for calls that reported one, most tokens first.
+    pub models: Vec<ModelUsage>,
+    /// Lines that were not valid records (e.g. torn writes or merge leftovers).
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub skipped_lines: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ToolStats {
+    pub server: String,
+    pub tool: String,
+    pub calls: u64,
+    pub failures: u64,
+    pub p50_duration_ms: u64,
+    pub p95_duration_ms: u64,
+    pub max_duration_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ModelUsage {
+    pub model: String,
+    pub calls: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub reasoning_tokens: u64,
+}
+
+#[expect(
+    clippy::trivially_copy_pass_by_ref,
+    reason = "serde skip_serializing_if passes a reference"
+)]
+fn is_zero(n: &u64) -> bool {
+    *n == 0
+}
+
+impl LogStats {
+    /// Aggregate `records`; `skipped_lines` is carried through as given.
+    pub fn from_records(records: &[ToolCallRecord], skipped_lines: u64) -> Self {
+        let mut durations = Vec::with_capacity(records.len());
+        let mut per_tool: BTreeMap<(&str, &str), (u64, Vec<u64>)> = BTreeMap::new();
+        let mut per_model: BTreeMap<&str, ModelUsage> = BTreeMap::new();
+
+        for record in records {
+            let duration = duration_ms(record);
+            durations.push(duration);
+
+            let (failures, tool_durations) = per_tool
+                .entry((record.server.as_str(), record.tool.as_str()))
+                .or_default();
+            *failures += u64::from(!record.success);
+            tool_durations.push(duration);
+
+            if let Some(model) = &record.model {
+                let usage = per_model.entry(model).or_insert_with(|| ModelUsage {
+                    model: model.clone(),
+                    ..ModelUsage::default()
+                });
+                usage.calls += 1;
+                if let Some(tokens) = &record.token_usage {
+                    usage.prompt_tokens += u64::from(tokens.prompt);
+                    usage.completion_tokens += u64::from(tokens.completion);
+                    usage.total_tokens += u64::from(tokens.total);
+                    usage.reasoning_tokens += u64::from(tokens.reasoning_tokens.unwrap_or(0));
+                }
+            }
+        }
+
+        let mut tools: Vec<ToolStats> = per_tool
+            .into_iter()
+            .map(|((server, tool), (failures, mut durations))| {
+                durations.sort_unstable();
+                ToolStats {
+                    server: server.to_string(),
+                    tool: tool.to_string(),
+                    calls: durations.len() as u64,
+                    failures,
+                    p50_duration_ms: percentile(&durations, 50),
+                    p95_duration_ms: percentile(&durations, 95),
+                    max_duration_ms: durations.last().copied().unwrap_or(0),
+                }
+            })
+            .collect();
+        tools.sort_by(|a, b| b.calls.cmp(&a.calls));
+
+        let mut models: Vec<ModelUsage> = per_model.into_values().collect();
+        models.sort_by(|a, b| b.total_tokens.cmp(&a.total_tokens));
+
+        durations.sort_unstable();
+        Self {
+            total_calls: records.len() as u64,
+            failed_calls: records.iter().filter(|r| !r.success).count() as u64,
+            p50_duration_ms: percentile(&durations, 50),
+            p95_duration_ms: percentile(&durations, 95),
+            tools,
+            models,
+            skipped_lines,
+        }
+    }
+}
+
+fn duration_ms(record: &ToolCallRecord) -> u64 {
+    u64::try_from(record.duration_ms).unwrap_or(u64::MAX)
+}
+
+/// Nearest-rank percentile of an ascending slice; 0 when empty.
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (pct * sorted.len()).div_ceil(100).max(1);
+    sorted[rank.min(sorted.len()) - 1]
+}
+
+/// Reader for the JSONL day buckets in a logs directory.
+pub struct LogReader {
+    base_logs_dir: PathBuf,
+}
+
+impl LogReader {
+    /// Create a reader over the directory a [`crate::LogWriter`] writes to.
+    pub fn new(base_logs_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_logs_dir: base_logs_dir.into(),
+        }
+    }
+
+    /// Read every record matching `filter`, oldest first, and aggregate them.
+    ///
+    /// A missing logs directory yields an empty result. Unparseable lines are
+    /// skipped and counted in [`LogStats::skipped_lines`].
+    pub fn query(&self, filter: &LogFilter) -> Result<LogQueryResult, LogError> {
+        let mut records = Vec::new();
+        let mut skipped_lines = 0;
+        for path in self.bucket_files(filter)? {
+            skipped_lines += read_bucket(&path, filter, &mut records)?;
+        }
+        records.sort_by_key(|r| r.completed_at);
+        let stats = LogStats::from_records(&records, skipped_lines);
+        Ok(LogQueryResult { records, stats })
+    }
+
+    /// JSONL files in the logs directory whose day could contain matches, sorted by name.
+    fn bucket_files(&self, filter: &LogFilter) -> Result<Vec<PathBuf>, LogError> {
+        let entries = match std::fs::read_dir(&self.base_logs_dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut files = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let uncompressed = if path.extension().is_some_and(|ext| ext == "gz") {
+                Path::new(path.file_stem().unwrap_or_default())
+            } else {
+                path.as_path()
+            };
+            if uncompressed.extension().is_none_or(|ext| ext != "jsonl") {
+                continue;
+            }
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            // Files without a parseable date are read in full and filtered per record.
+            if crate::bucket_day(name).is_none_or(|day| filter.wants_day(day)) {
+                files.push(path);
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+}
+
+/// Append matching records from one bucket; returns the number of skipped lines.
+fn read_bucket(
+    path: &Path,
+    filter: &LogFilter,
+    out: &mut Vec<ToolCallRecord>,
+) -> Result<u64, LogError> {
+    let file = std::fs::File::open(path)?;
+    let input: Box<dyn Read> = if path.extension().is_some_and(|ext| ext == "gz") {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    let mut skipped = 0;
+    for line in BufReader::new(input).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<ToolCallRecord>(&line) {
+            Ok(record) if filter.matches(&record) => out.push(record),
+            Ok(_) => {}
+            Err(_) => skipped += 1,
+        }
+    }
+    Ok(skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TokenUsage;
+    use std::io::Write;
+
+    fn at(ts: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(ts)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn record(tool: &str, completed_at: &str, duration_ms: u128, success: bool) -> ToolCallRecord {
+        let completed_at = at(completed_at);
+        ToolCallRecord {
+            call_id: format!("{tool}-{completed_at}"),
+            server: "srv".into(),
+            tool: tool.into(),
+            started_at: completed_at,
+            completed_at,
+            duration_ms,
+            request: serde_json::json!({}),
+            response_file: None,
+            success,
+            error: (!success).then(|| "boom".to_string()),
+            failure_kind: None,
+            model: None,
+            token_usage: None,
+            summary: None,
+            trace_id: None,
+            parent_call_id: None,
+        }
+    }
+
+    fn write_bucket(dir: &Path, name: &str, records: &[ToolCallRecord], extra: &str) {
+        let mut file = std::fs::File::create(dir.join(name)).unwrap();
+        for r in records {
+            writeln!(file, "{}", serde_json::to_string(r).unwrap()).unwrap();
+        }
+        file.write_all(extra.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let d: Vec<u64> = (1..=20).collect();
+        assert_eq!(percentile(&d, 50), 10);
+        assert_eq!(percentile(&d, 95), 19);
+        assert_eq!(percentile(&[7], 95), 7);
+        assert_eq!(percentile(&[], 50), 0);
+    }
+
+    #[test]
+    fn parse_time_bound_accepts_timestamps_dates_and_ages() {
+        let now = at("2026-03-02T12:00:00Z");
+        assert_eq!(
+            parse_time_bound("2026-03-01T06:00:00Z", now),
+            Some(at("2026-03-01T06:00:00Z"))
+        );
+        assert_eq!(
+            parse_time_bound("2026-03-01", now),
+            Some(at("2026-03-01T00:00:00Z"))
+        );
+        assert_eq!(
+            parse_time_bound("36h", now),
+            Some(at("2026-03-01T00:00:00Z"))
+        );
+        for bad in ["yesterday", "5y", "-3d", "d", "3é", ""] {
+            assert_eq!(parse_time_bound(bad, now), None, "{bad}");
+        }
+    }
+
+    #[test]
+    fn query_filters_across_buckets_and_aggregates() {
+        let temp = tempfile::tempdir().unwrap();
+        let mut reasoning = record("plan", "2026-03-02T09:00:00Z", 900, true);
+        reasoning.server = "gpt5_reasoner".into();
+        reasoning.model = Some("openai/gpt-5".into());
+        reasoning.token_usage = Some(TokenUsage {
+            prompt: 100,
+            completion: 40,
+            total: 140,
+            reasoning_tokens: Some(30),
+        });
+        write_bucket(
+            temp.path(),
+            "tool_logs_2026-03-01_aaaaaaaa.jsonl",
+            &[record("ls", "2026-03-01T10:00:00Z", 10, true)],
+            "",
+        );
+        write_bucket(
+            temp.path(),
+            "tool_logs_2026-03-02_bbbbbbbb.jsonl",
+            &[
+                record("ls", "2026-03-02T08:00:00Z", 30, true),
+                record("grep", "2026-03-02T10:00:00Z", 50, false),
+                reasoning,
+            ],
+            "{\"truncated\n",
+        );
+        write_bucket(
+            temp.path(),
+            "tool_logs_2026-03-02_cccccccc.jsonl",
+            &[record("ls", "2026-03-02T07:00:00Z", 20, true)],
+            "",
+        );
+        std::fs::write(temp.path().join("notes.md"), "not a log").unwrap();
+
+        let reader = LogReader::new(temp.path());
+        let all = reader.query(&LogFilter::default()).unwrap();
+        assert_eq!(all.stats.total_calls, 5);
+        assert_eq!(all.stats.failed_calls, 1);
+        assert_eq!(all.stats.skipped_lines, 1);
+        assert_eq!(all.stats.p50_duration_ms, 30);
+        assert_eq!(all.stats.p95_duration_ms, 900);
+        assert_eq!(all.records.first().unwrap().tool, "ls");
+        assert_eq!(all.records.last().unwrap().tool, "grep");
+
+        let ls = &all.stats.tools[0];
+        assert_eq!((ls.tool.as_str(), ls.calls), ("ls", 3));
+        assert_eq!((ls.p50_duration_ms, ls.max_duration_ms), (20, 30));
+        assert_eq!(
+            all.stats.models,
+            vec![ModelUsage {
+                model: "openai/gpt-5".into(),
+                calls: 1,
+                prompt_tokens: 100,
+                completion_tokens: 40,
+                total_tokens: 140,
+                reasoning_tokens: 30,
+            }]
+        );
+
+        let filtered = reader
+            .query(&LogFilter {
+                server: Some("srv".into()),
+                since: Some(at("2026-03-02T00:00:00Z")),
+                success: Some(true),
+                ..LogFilter::default()
+            })
+            .unwrap();
+        let times: Vec<_> = filtered.records.iter().map(|r| r.completed_at).collect();
+        assert_eq!(
+            times,
+            vec![at("2026-03-02T07:00:00Z"), at("2026-03-02T08:00:00Z")]
+        );
+        assert!(filtered.stats.models.is_empty());
+    }
+
+    #[test]
+    fn query_missing_dir_is_empty() {
+        let temp = tempfile::tempdir().unwrap();
+        let result = LogReader::new(temp.path().join("absent"))
+            .query(&LogFilter::default())
+            .unwrap();
+        assert!(result.records.is_empty());
+        assert_eq!(result.stats, LogStats::default());
+    }
+}
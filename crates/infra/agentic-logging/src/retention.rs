@@ -0,0 +1,277 @@
+//! Rotation and retention policy for tool call logs.
+//!
+//! A [`LogPolicy`] caps how large one day bucket's JSONL file may grow before
+//! [`crate::LogWriter`] starts a `.N.jsonl` part file, and how long buckets are
+//! kept. [`crate::LogWriter::prune`] deletes buckets past the retention window and
+//! gzips JSONL files and markdown responses past the compression window; with
+//! `prune_on_write` the writer runs it at most once per day per logs directory.
+
+use crate::LogError;
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::Utc;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+/// Default size at which a day bucket rotates into a new part file.
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 50 * 1024 * 1024;
+
+/// How large log files may grow and how long they are kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogPolicy {
+    /// Start a new part file once the current one reaches this many bytes. `None` never rotates.
+    pub max_file_bytes: Option<u64>,
+    /// Delete buckets more than this many days old. `None` keeps them forever.
+    pub retention_days: Option<u32>,
+    /// Gzip JSONL files and markdown responses more than this many days old. `None` never compresses.
+    pub compress_after_days: Option<u32>,
+    /// Prune from [`crate::LogWriter::append_jsonl`], at most once per day per directory.
+    pub prune_on_write: bool,
+}
+
+impl Default for LogPolicy {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: Some(DEFAULT_MAX_FILE_BYTES),
+            retention_days: None,
+            compress_after_days: None,
+            prune_on_write: true,
+        }
+    }
+}
+
+impl LogPolicy {
+    /// Whether [`crate::LogWriter::prune`] has anything to do under this policy.
+    pub fn prunes(&self) -> bool {
+        self.retention_days.is_some() || self.compress_after_days.is_some()
+    }
+}
+
+static DEFAULT_POLICY: LazyLock<Mutex<LogPolicy>> =
+    LazyLock::new(|| Mutex::new(LogPolicy::default()));
+
+/// Set the policy used by writers created afterwards with [`crate::LogWriter::new`].
+///
+/// Binaries call this once at startup with the policy from their configuration.
+pub fn set_default_policy(policy: LogPolicy) {
+    *DEFAULT_POLICY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = policy;
+}
+
+/// The policy [`crate::LogWriter::new`] applies.
+pub fn default_policy() -> LogPolicy {
+    DEFAULT_POLICY
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// Last day each logs directory was pruned on write by this process.
+static LAST_PRUNED: LazyLock<Mutex<HashMap<PathBuf, NaiveDate>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Record that `dir` is pruned today; false if it already was.
+pub fn claim_daily_prune(dir: &Path, today: NaiveDate) -> bool {
+    let mut last = LAST_PRUNED.lock().unwrap_or_else(PoisonError::into_inner);
+    if last.get(dir) == Some(&today) {
+        return false;
+    }
+    last.insert(dir.to_path_buf(), today);
+    true
+}
+
+/// What a prune pass removed or compressed, by file or directory name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    pub deleted: Vec<String>,
+    pub compressed: Vec<String>,
+}
+
+/// Apply `policy`'s retention and compression windows to the buckets in `dir`.
+pub fn prune_dir(
+    dir: &Path,
+    policy: &LogPolicy,
+    now: DateTime<Utc>,
+) -> Result<PruneReport, LogError> {
+    let mut report = PruneReport::default();
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(report),
+        Err(e) => return Err(e.into()),
+    };
+    let today = now.date_naive();
+
+    let mut paths: Vec<PathBuf> = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    paths.sort();
+    for path in paths {
+        let Some(name) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(str::to_string)
+        else {
+            continue;
+        };
+        let Some(day) = crate::bucket_day(&name) else {
+            continue;
+        };
+        let age = (today - day).num_days();
+
+        if policy
+            .retention_days
+            .is_some_and(|days| age > i64::from(days))
+        {
+            if path.is_dir() {
+                std::fs::remove_dir_all(&path)?;
+            } else {
+                std::fs::remove_file(&path)?;
+            }
+            report.deleted.push(name);
+        } else if policy
+            .compress_after_days
+            .is_some_and(|days| age > i64::from(days))
+        {
+            let compressed = if path.is_dir() {
+                compress_markdown_dir(&path)?
+            } else if path.extension().is_some_and(|ext| ext == "jsonl") {
+                gzip_in_place(&path)?;
+                true
+            } else {
+                false
+            };
+            if compressed {
+                report.compressed.push(name);
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Gzip every uncompressed markdown response in a bucket directory.
+fn compress_markdown_dir(dir: &Path) -> Result<bool, LogError> {
+    let mut any = false;
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "md") {
+            gzip_in_place(&path)?;
+            any = true;
+        }
+    }
+    Ok(any)
+}
+
+/// Replace `path` with `path.gz`, writing through a temporary file so a crash
+/// never leaves a truncated archive next to a deleted original.
+fn gzip_in_place(path: &Path) -> Result<(), LogError> {
+    let mut gz_name = path.as_os_str().to_owned();
+    gz_name.push(".gz");
+    let gz_path = PathBuf::from(gz_name);
+    let mut tmp_name = gz_path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_name);
+
+    let mut encoder = GzEncoder::new(std::fs::File::create(&tmp_path)?, Compression::default());
+    std::io::copy(&mut std::fs::File::open(path)?, &mut encoder)?;
+    encoder.finish()?.sync_all()?;
+    std::fs::rename(&tmp_path, &gz_path)?;
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn touch(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn gunzip(path: &Path) -> String {
+        let mut out = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(path).unwrap())
+            .read_to_string(&mut out)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn prune_deletes_expired_and_compresses_aging_buckets() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        // 9 days old: past retention.
+        touch(&dir.join("tool_logs_2026-03-01_aaaaaaaa.jsonl"), "{}\n");
+        touch(&dir.join("tool_logs_2026-03-01_aaaaaaaa/c1.md"), "# old");
+        // 3 days old: compressed, including a rotated part.
+        touch(
+            &dir.join("tool_logs_2026-03-07_bbbbbbbb.jsonl"),
+            "{\"a\":1}\n",
+        );
+        touch(
+            &dir.join("tool_logs_2026-03-07_bbbbbbbb.1.jsonl"),
+            "{\"a\":2}\n",
+        );
+        touch(&dir.join("tool_logs_2026-03-07_bbbbbbbb/c2.md"), "# aging");
+        // Today and unrelated files are left alone.
+        touch(&dir.join("tool_logs_2026-03-10_cccccccc.jsonl"), "{}\n");
+        touch(&dir.join("notes.md"), "keep");
+
+        let policy = LogPolicy {
+            retention_days: Some(7),
+            compress_after_days: Some(1),
+            ..LogPolicy::default()
+        };
+        let report = prune_dir(dir, &policy, now()).unwrap();
+        assert_eq!(
+            report.deleted,
+            vec![
+                "tool_logs_2026-03-01_aaaaaaaa".to_string(),
+                "tool_logs_2026-03-01_aaaaaaaa.jsonl".to_string()
+            ]
+        );
+        assert_eq!(report.compressed.len(), 3);
+
+        assert!(!dir.join("tool_logs_2026-03-01_aaaaaaaa").exists());
+        assert!(!dir.join("tool_logs_2026-03-07_bbbbbbbb.jsonl").exists());
+        assert_eq!(
+            gunzip(&dir.join("tool_logs_2026-03-07_bbbbbbbb.1.jsonl.gz")),
+            "{\"a\":2}\n"
+        );
+        assert_eq!(
+            gunzip(&dir.join("tool_logs_2026-03-07_bbbbbbbb/c2.md.gz")),
+            "# aging"
+        );
+        assert!(dir.join("tool_logs_2026-03-10_cccccccc.jsonl").exists());
+        assert!(dir.join("notes.md").exists());
+
+        // A second pass finds nothing left to do.
+        assert_eq!(
+            prune_dir(dir, &policy, now()).unwrap(),
+            PruneReport::default()
+        );
+    }
+
+    #[test]
+    fn daily_prune_is_claimed_once_per_day() {
+        let dir = Path::new("/claim-test");
+        let day = now().date_naive();
+        assert!(claim_daily_prune(dir, day));
+        assert!(!claim_daily_prune(dir, day));
+        assert!(claim_daily_prune(dir, day.succ_opt().unwrap()));
+    }
+}
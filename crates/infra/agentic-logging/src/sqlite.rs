@@ -0,0 +1,314 @@
+//! `SQLite` index of tool call records.
+//!
+//! JSONL day buckets stay the source of truth; the index is a derived copy for
+//! queries over long histories that would otherwise re-read every bucket. With
+//! [`set_sqlite_index_enabled`], [`crate::LogWriter::append_jsonl`] mirrors each
+//! record (after redaction) into `{logs_dir}/tool_logs.sqlite3`. A missing or
+//! stale index can be rebuilt from the JSONL files with [`SqliteIndex::rebuild`],
+//! and mirroring failures never fail the logged call.
+//!
+//! Records live in the `tool_calls` table, one row per `call_id`, with `server`,
+//! `tool`, `started_at`, and `success` indexed. Timestamps are stored as RFC 3339
+//! UTC text with microseconds, so they sort and compare as text. The full record
+//! is kept in `record_json`.
+
+use crate::LogError;
+use crate::LogFilter;
+use crate::LogReader;
+use crate::ToolCallRecord;
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use rusqlite::Connection;
+use rusqlite::ToSql;
+use rusqlite::params;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+
+/// File name of the index inside a logs directory.
+pub const SQLITE_INDEX_FILE: &str = "tool_logs.sqlite3";
+
+const SCHEMA: &str = r"
+CREATE TABLE IF NOT EXISTS tool_calls (
+    call_id TEXT PRIMARY KEY,
+    trace_id TEXT,
+    parent_call_id TEXT,
+    server TEXT NOT NULL,
+    tool TEXT NOT NULL,
+    started_at TEXT NOT NULL,
+    completed_at TEXT NOT NULL,
+    duration_ms INTEGER NOT NULL,
+    success INTEGER NOT NULL,
+    model TEXT,
+    error TEXT,
+    record_json TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS tool_calls_server ON tool_calls (server);
+CREATE INDEX IF NOT EXISTS tool_calls_tool ON tool_calls (tool);
+CREATE INDEX IF NOT EXISTS tool_calls_started_at ON tool_calls (started_at);
+CREATE INDEX IF NOT EXISTS tool_calls_success ON tool_calls (success);
+";
+
+/// A `SQLite` mirror of tool call records; see the module docs.
+pub struct SqliteIndex {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteIndex {
+    /// Open (or create) the index at `path`.
+    pub fn open(path: &Path) -> Result<Self, LogError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Open a throwaway in-memory index.
+    pub fn open_in_memory() -> Result<Self, LogError> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, LogError> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    /// The process-wide index for `base_logs_dir`, opened on first use at
+    /// [`SQLITE_INDEX_FILE`].
+    pub fn shared(base_logs_dir: &Path) -> Result<Arc<Self>, LogError> {
+        static INDEXES: LazyLock<Mutex<HashMap<PathBuf, Arc<SqliteIndex>>>> =
+            LazyLock::new(|| Mutex::new(HashMap::new()));
+        let mut indexes = INDEXES.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(index) = indexes.get(base_logs_dir) {
+            return Ok(Arc::clone(index));
+        }
+        let index = Arc::new(Self::open(&base_logs_dir.join(SQLITE_INDEX_FILE))?);
+        indexes.insert(base_logs_dir.to_path_buf(), Arc::clone(&index));
+        Ok(index)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Connection> {
+        self.conn.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Insert `record`, replacing any row with the same `call_id`.
+    pub fn insert(&self, record: &ToolCallRecord) -> Result<(), LogError> {
+        insert_record(&self.lock(), record)
+    }
+
+    /// Re-index every record under `reader`'s logs directory. Rows already present
+    /// are replaced, so this is safe to run over a partially built index. Returns
+    /// the number of records indexed.
+    pub fn rebuild(&self, reader: &LogReader) -> Result<usize, LogError> {
+        let records = reader.query(&LogFilter::default())?.records;
+        let mut conn = self.lock();
+        let tx = conn.transaction()?;
+        for record in &records {
+            insert_record(&tx, record)?;
+        }
+        tx.commit()?;
+        Ok(records.len())
+    }
+
+    /// Records matching `filter`, oldest completion first.
+    pub fn query(&self, filter: &LogFilter) -> Result<Vec<ToolCallRecord>, LogError> {
+        let mut clauses = Vec::new();
+        let mut args: Vec<Box<dyn ToSql>> = Vec::new();
+        if let Some(server) = &filter.server {
+            clauses.push("server = ?");
+            args.push(Box::new(server.clone()));
+        }
+        if let Some(tool) = &filter.tool {
+            clauses.push("tool = ?");
+            args.push(Box::new(tool.clone()));
+        }
+        if let Some(success) = filter.success {
+            clauses.push("success = ?");
+            args.push(Box::new(success));
+        }
+        if let Some(since) = filter.since {
+            clauses.push("completed_at >= ?");
+            args.push(Box::new(timestamp(since)));
+        }
+        if let Some(until) = filter.until {
+            clauses.push("completed_at < ?");
+            args.push(Box::new(timestamp(until)));
+        }
+        if let Some(trace_id) = &filter.trace_id {
+            clauses.push("(trace_id = ? OR call_id = ?)");
+            args.push(Box::new(trace_id.clone()));
+            args.push(Box::new(trace_id.clone()));
+        }
+        let mut sql = String::from("SELECT record_json FROM tool_calls");
+        if !clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&clauses.join(" AND "));
+        }
+        sql.push_str(" ORDER BY completed_at, call_id");
+
+        let conn = self.lock();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(args.iter()), |row| {
+            row.get::<_, String>(0)
+        })?;
+        let mut records = Vec::new();
+        for json in rows {
+            records.push(serde_json::from_str(&json?)?);
+        }
+        Ok(records)
+    }
+}
+
+fn insert_record(conn: &Connection, record: &ToolCallRecord) -> Result<(), LogError> {
+    conn.execute(
+        "INSERT OR REPLACE INTO tool_calls (
+            call_id, trace_id, parent_call_id, server, tool, started_at, completed_at,
+            duration_ms, success, model, error, record_json
+         ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
+        params![
+            record.call_id,
+            record.trace_id,
+            record.parent_call_id,
+            record.server,
+            record.tool,
+            timestamp(record.started_at),
+            timestamp(record.completed_at),
+            i64::try_from(record.duration_ms).unwrap_or(i64::MAX),
+            record.success,
+            record.model,
+            record.error,
+            serde_json::to_string(record)?,
+        ],
+    )?;
+    Ok(())
+}
+
+fn timestamp(t: DateTime<Utc>) -> String {
+    t.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Mirror records appended by every [`crate::LogWriter`] into the shared index for
+/// its logs directory.
+pub fn set_sqlite_index_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Mirror `record` into the index for `base_logs_dir` if mirroring is enabled.
+pub fn mirror(base_logs_dir: &Path, record: &ToolCallRecord) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+    // The index is derived from JSONL; a failure here must not fail the logged call.
+    if let Ok(index) = SqliteIndex::shared(base_logs_dir) {
+        let _ = index.insert(record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LogWriter;
+    use chrono::TimeZone;
+    use serial_test::serial;
+
+    fn record(call_id: &str, server: &str, success: bool, minute: u32) -> ToolCallRecord {
+        let at = Utc.with_ymd_and_hms(2026, 3, 2, 12, minute, 0).unwrap();
+        ToolCallRecord {
+            call_id: call_id.into(),
+            server: server.into(),
+            tool: "test".into(),
+            started_at: at,
+            completed_at: at,
+            duration_ms: 1,
+            request: serde_json::json!({}),
+            response_file: None,
+            success,
+            error: None,
+            failure_kind: None,
+            model: None,
+            token_usage: None,
+            summary: None,
+            trace_id: Some("trace-1".into()),
+            parent_call_id: None,
+        }
+    }
+
+    fn ids(records: Vec<ToolCallRecord>) -> Vec<String> {
+        records.into_iter().map(|r| r.call_id).collect()
+    }
+
+    #[test]
+    fn query_applies_filter() {
+        let index = SqliteIndex::open_in_memory().unwrap();
+        index.insert(&record("b", "pr_comments", false, 2)).unwrap();
+        index
+            .insert(&record("a", "thoughts_tool", true, 1))
+            .unwrap();
+        index
+            .insert(&record("c", "thoughts_tool", true, 3))
+            .unwrap();
+        // Re-inserting a call replaces its row.
+        index
+            .insert(&record("c", "thoughts_tool", true, 3))
+            .unwrap();
+
+        assert_eq!(
+            ids(index.query(&LogFilter::default()).unwrap()),
+            vec!["a", "b", "c"]
+        );
+        let filter = LogFilter {
+            server: Some("thoughts_tool".into()),
+            success: Some(true),
+            since: Some(Utc.with_ymd_and_hms(2026, 3, 2, 12, 2, 0).unwrap()),
+            trace_id: Some("trace-1".into()),
+            ..LogFilter::default()
+        };
+        assert_eq!(ids(index.query(&filter).unwrap()), vec!["c"]);
+        let failed = LogFilter {
+            success: Some(false),
+            ..LogFilter::default()
+        };
+        assert_eq!(ids(index.query(&failed).unwrap()), vec!["b"]);
+    }
+
+    #[test]
+    #[serial]
+    fn writer_mirrors_and_rebuild_backfills() {
+        let temp = tempfile::tempdir().unwrap();
+        let writer = LogWriter::new(temp.path());
+        writer
+            .append_jsonl(&record("before", "test", true, 1))
+            .unwrap();
+
+        set_sqlite_index_enabled(true);
+        writer
+            .append_jsonl(&record("after", "test", true, 2))
+            .unwrap();
+        set_sqlite_index_enabled(false);
+
+        let index = SqliteIndex::shared(temp.path()).unwrap();
+        assert!(temp.path().join(SQLITE_INDEX_FILE).exists());
+        assert_eq!(
+            ids(index.query(&LogFilter::default()).unwrap()),
+            vec!["after"]
+        );
+
+        assert_eq!(index.rebuild(&LogReader::new(temp.path())).unwrap(), 2);
+        assert_eq!(
+            ids(index.query(&LogFilter::default()).unwrap()),
+            vec!["before", "after"]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Correlation of nested tool calls.
+//!
+//! Every [`crate::ToolCallRecord`] carries a `trace_id` shared by all calls made
+//! on behalf of one originating request, and the `parent_call_id` of the call
+//! that caused it. [`crate::CallTimer::start`] picks both up from the current
+//! [`TraceContext`]: the task-local one set with [`TraceContext::scope`], or, in
+//! a subagent process, the one inherited through [`TRACE_ID_ENV`] and
+//! [`PARENT_CALL_ID_ENV`] (see [`TraceContext::env_vars`]). A call with no
+//! context starts a new trace named after its own call ID.
+
+use std::future::Future;
+use std::sync::LazyLock;
+
+/// Environment variable carrying the trace ID into a spawned process.
+pub const TRACE_ID_ENV: &str = "AGENTIC_TRACE_ID";
+
+/// Environment variable carrying the spawning call's ID into a spawned process.
+pub const PARENT_CALL_ID_ENV: &str = "AGENTIC_PARENT_CALL_ID";
+
+/// The trace a tool call belongs to and the call that caused it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceContext {
+    pub trace_id: Option<String>,
+    pub parent_call_id: Option<String>,
+}
+
+tokio::task_local! {
+    static CURRENT: TraceContext;
+}
+
+/// Context inherited from the environment, read once per process.
+static INHERITED: LazyLock<TraceContext> = LazyLock::new(|| {
+    let var = |name| {
+        std::env::var(name)
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    TraceContext {
+        trace_id: var(TRACE_ID_ENV),
+        parent_call_id: var(PARENT_CALL_ID_ENV),
+    }
+});
+
+impl TraceContext {
+    /// The task-local context if inside [`Self::scope`], else the one inherited
+    /// from the environment (empty when neither is set).
+    pub fn current() -> Self {
+        CURRENT
+            .try_with(Clone::clone)
+            .unwrap_or_else(|_| INHERITED.clone())
+    }
+
+    /// Context for calls made on behalf of the call `call_id` in `trace_id`.
+    pub fn child_of(trace_id: &str, call_id: &str) -> Self {
+        Self {
+            trace_id: Some(trace_id.to_string()),
+            parent_call_id: Some(call_id.to_string()),
+        }
+    }
+
+    /// Run `fut` with this as the current context for calls it starts.
+    pub async fn scope<F: Future>(self, fut: F) -> F::Output {
+        CURRENT.scope(self, fut).await
+    }
+
+    /// Environment variables that hand this context to a spawned process.
+    pub fn env_vars(&self) -> Vec<(String, String)> {
+        [
+            (TRACE_ID_ENV, &self.trace_id),
+            (PARENT_CALL_ID_ENV, &self.parent_call_id),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.clone().map(|v| (name.to_string(), v)))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CallTimer;
+
+    #[tokio::test]
+    async fn nested_calls_inherit_trace_from_scope() {
+        let root = CallTimer::start();
+        assert_eq!(root.trace_id, root.call_id);
+        assert_eq!(root.parent_call_id, None);
+
+        let (child, grandchild) = root
+            .child_context()
+            .scope(async {
+                let child = CallTimer::start();
+                let grandchild = child
+                    .child_context()
+                    .scope(async { CallTimer::start() })
+                    .await;
+                (child, grandchild)
+            })
+            .await;
+
+        assert_eq!(child.trace_id, root.call_id);
+        assert_eq!(child.parent_call_id.as_deref(), Some(root.call_id.as_str()));
+        assert_eq!(grandchild.trace_id, root.call_id);
+        assert_eq!(
+            grandchild.parent_call_id.as_deref(),
+            Some(child.call_id.as_str())
+        );
+        assert_eq!(TraceContext::current(), TraceContext::default());
+    }
+
+    #[test]
+    fn env_vars_round_trip_names() {
+        let ctx = TraceContext::child_of("trace-1", "call-1");
+        assert_eq!(
+            ctx.env_vars(),
+            vec![
+                (TRACE_ID_ENV.to_string(), "trace-1".to_string()),
+                (PARENT_CALL_ID_ENV.to_string(), "call-1".to_string()),
+            ]
+        );
+        assert!(TraceContext::default().env_vars().is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! Live tail of tool call logs.
+//!
+//! [`LogWatcher`] watches a logs directory with the platform file notifier
+//! (inotify on Linux, `FSEvents` on macOS) and yields each [`ToolCallRecord`] as it is appended
+//! to any day-bucket JSONL file, including part files started by rotation and
+//! buckets written by other processes. Records already on disk when the watcher
+//! starts are not replayed; use [`crate::LogReader`] for history.
+
+use crate::LogError;
+use crate::ToolCallRecord;
+use futures_core::Stream;
+use notify::EventKind;
+use notify::RecommendedWatcher;
+use notify::RecursiveMode;
+use notify::Watcher;
+use std::collections::HashMap;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::task::Context;
+use std::task::Poll;
+use tokio::sync::mpsc;
+
+/// Stream of records appended to a logs directory; see the module docs.
+///
+/// Dropping the watcher stops watching.
+pub struct LogWatcher {
+    rx: mpsc::UnboundedReceiver<ToolCallRecord>,
+    _watcher: RecommendedWatcher,
+}
+
+impl LogWatcher {
+    /// Start watching `base_logs_dir`, creating it if needed.
+    pub fn start(base_logs_dir: impl Into<PathBuf>) -> Result<Self, LogError> {
+        let dir = base_logs_dir.into();
+        std::fs::create_dir_all(&dir)?;
+
+        let mut tail = Tail::default();
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if is_jsonl(&path) {
+                let len = std::fs::metadata(&path).map_or(0, |m| m.len());
+                tail.offsets.insert(path, len);
+            }
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+                if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                    return;
+                }
+                for path in event.paths.iter().filter(|p| is_jsonl(p)) {
+                    for record in tail.read_new(path) {
+                        let _ = tx.send(record);
+                    }
+                }
+            })
+            .map_err(watch_error)?;
+        watcher
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+
+        Ok(Self {
+            rx,
+            _watcher: watcher,
+        })
+    }
+
+    /// The next appended record; `None` once the watcher has failed.
+    pub async fn next(&mut self) -> Option<ToolCallRecord> {
+        self.rx.recv().await
+    }
+}
+
+impl Stream for LogWatcher {
+    type Item = ToolCallRecord;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// Read position in each JSONL file seen so far.
+#[derive(Default)]
+struct Tail {
+    offsets: HashMap<PathBuf, u64>,
+}
+
+impl Tail {
+    /// Complete lines appended to `path` since the last read, parsed. A trailing
+    /// partial line is left for the next event; unparseable lines are skipped.
+    fn read_new(&mut self, path: &Path) -> Vec<ToolCallRecord> {
+        let offset = self.offsets.get(path).copied().unwrap_or(0);
+        let mut buf = Vec::new();
+        let read = std::fs::File::open(path).and_then(|mut file| {
+            file.seek(SeekFrom::Start(offset))?;
+            file.read_to_end(&mut buf)
+        });
+        if read.is_err() {
+            return Vec::new();
+        }
+        let Some(end) = buf.iter().rposition(|b| *b == b'\n') else {
+            return Vec::new();
+        };
+        self.offsets
+            .insert(path.to_path_buf(), offset + end as u64 + 1);
+        buf[..end]
+            .split(|b| *b == b'\n')
+            .filter_map(|line| serde_json::from_slice(line).ok())
+            .collect()
+    }
+}
+
+fn is_jsonl(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "jsonl")
+}
+
+fn watch_error(e: notify::Error) -> LogError {
+    LogError::Io(std::io::Error::other(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LogWriter;
+    use chrono::Utc;
+    use serial_test::serial;
+    use std::time::Duration;
+
+    fn record(call_id: &str) -> ToolCallRecord {
+        let now = Utc::now();
+        ToolCallRecord {
+            call_id: call_id.into(),
+            server: "test".into(),
+            tool: "test".into(),
+            started_at: now,
+            completed_at: now,
+            duration_ms: 1,
+            request: serde_json::json!({}),
+            response_file: None,
+            success: true,
+            error: None,
+            failure_kind: None,
+            model: None,
+            token_usage: None,
+            summary: None,
+            trace_id: None,
+            parent_call_id: None,
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn yields_records_appended_after_start() {
+        let temp = tempfile::tempdir().unwrap();
+        let writer = LogWriter::new(temp.path());
+        writer.append_jsonl(&record("before")).unwrap();
+
+        let mut watcher = LogWatcher::start(temp.path()).unwrap();
+        writer.append_jsonl(&record("first")).unwrap();
+        writer.append_jsonl(&record("second")).unwrap();
+
+        let mut seen = Vec::new();
+        while seen.len() < 2 {
+            let next = tokio::time::timeout(Duration::from_secs(10), watcher.next())
+                .await
+                .expect("record within timeout")
+                .expect("watcher open");
+            seen.push(next.call_id);
+        }
+        assert_eq!(seen, vec!["first".to_string(), "second".to_string()]);
+    }
+
+    #[test]
+    fn tail_keeps_partial_lines_for_next_read() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("tool_logs_2026-03-02_aaaaaaaa.jsonl");
+        let line = serde_json::to_string(&record("one")).unwrap();
+        std::fs::write(&path, format!("{line}\n{{\"call_id\":")).unwrap();
+
+        let mut tail = Tail::default();
+        let first: Vec<_> = tail
+            .read_new(&path)
+            .into_iter()
+            .map(|r| r.call_id)
+            .collect();
+        assert_eq!(first, vec!["one".to_string()]);
+        assert!(tail.read_new(&path).is_empty());
+
+        let two = serde_json::to_string(&record("two")).unwrap();
+        std::fs::write(&path, format!("{line}\n{two}\n")).unwrap();
+        let second: Vec<_> = tail
+            .read_new(&path)
+            .into_iter()
+            .map(|r| r.call_id)
+            .collect();
+        assert_eq!(second, vec!["two".to_string()]);
+    }
+}
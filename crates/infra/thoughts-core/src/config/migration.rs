@@ -0,0 +1,226 @@
+//! Versioned migrations for `.thoughts/config.json`.
+//!
+//! Each on-disk layout change gets a `Migration` step that rewrites the raw
+//! JSON from one `version` to the next. Steps are applied in order until the
+//! config reaches [`CURRENT_CONFIG_VERSION`].
+
+use anyhow::Context;
+use anyhow::Result;
+use serde_json::Value;
+use serde_json::json;
+
+/// The config version written by this release.
+pub const CURRENT_CONFIG_VERSION: &str = "2.0";
+
+/// Version assumed when the `version` field is missing.
+const UNVERSIONED_CONFIG_VERSION: &str = "1.0";
+
+/// A single ordered migration step between two config versions.
+#[derive(Debug)]
+pub struct Migration {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub description: &'static str,
+    apply: fn(&Value) -> Result<Value>,
+}
+
+/// All known migrations, ordered from oldest to newest.
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: "1.0",
+    to: "2.0",
+    description: "Split v1 required mounts into context mounts and references",
+    apply: migrate_v1_to_v2,
+}];
+
+/// Result of running migrations over a raw config value.
+#[derive(Debug)]
+pub struct MigrationOutcome {
+    pub from_version: String,
+    pub to_version: String,
+    pub applied: Vec<&'static Migration>,
+    pub value: Value,
+}
+
+impl MigrationOutcome {
+    /// Whether any migration step was applied
+    pub fn changed(&self) -> bool {
+        !self.applied.is_empty()
+    }
+}
+
+/// Read the `version` field of a raw config, defaulting to v1 when absent.
+pub fn config_version(value: &Value) -> &str {
+    value
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or(UNVERSIONED_CONFIG_VERSION)
+}
+
+/// Whether a raw config needs migrating to reach the current version.
+pub fn needs_migration(value: &Value) -> bool {
+    config_version(value) != CURRENT_CONFIG_VERSION
+}
+
+/// Compute the ordered list of migrations from `from` to the current version.
+pub fn plan(from: &str) -> Result<Vec<&'static Migration>> {
+    let mut steps = Vec::new();
+    let mut version = from;
+    while version != CURRENT_CONFIG_VERSION {
+        let step = MIGRATIONS
+            .iter()
+            .find(|m| m.from == version)
+            .with_context(|| {
+                format!(
+                    "Unknown configuration version '{version}'. This config may have been written by a newer thoughts release (this release supports up to {CURRENT_CONFIG_VERSION})."
+                )
+            })?;
+        steps.push(step);
+        version = step.to;
+    }
+    Ok(steps)
+}
+
+/// Apply every pending migration to a raw config value.
+pub fn migrate(value: Value) -> Result<MigrationOutcome> {
+    let from_version = config_version(&value).to_string();
+    let steps = plan(&from_version)?;
+
+    let mut value = value;
+    for step in &steps {
+        value = (step.apply)(&value).with_context(|| {
+            format!(
+                "Failed to migrate configuration from {} to {}",
+                step.from, step.to
+            )
+        })?;
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("version".into(), Value::String(step.to.to_string()));
+        }
+    }
+
+    Ok(MigrationOutcome {
+        from_version,
+        to_version: CURRENT_CONFIG_VERSION.to_string(),
+        applied: steps,
+        value,
+    })
+}
+
+/// v1 → v2: `requires` entries become context mounts or references, and
+/// `mount_dirs.repository` becomes `mount_dirs.context`. `rules` are dropped.
+fn migrate_v1_to_v2(v1: &Value) -> Result<Value> {
+    let obj = v1
+        .as_object()
+        .context("v1 configuration must be a JSON object")?;
+
+    let mut mount_dirs = serde_json::Map::new();
+    if let Some(repo_dir) = obj
+        .get("mount_dirs")
+        .and_then(|d| d.get("repository"))
+        .and_then(|d| d.as_str())
+    {
+        mount_dirs.insert("context".into(), Value::String(repo_dir.to_string()));
+    }
+
+    let mut context_mounts = Vec::new();
+    let mut references = Vec::new();
+    let requires = obj
+        .get("requires")
+        .and_then(|r| r.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    for (idx, req) in requires.iter().enumerate() {
+        let remote = req
+            .get("remote")
+            .and_then(|r| r.as_str())
+            .with_context(|| format!("v1 required mount #{idx} is missing 'remote'"))?;
+        let mount_path = req
+            .get("mount_path")
+            .and_then(|p| p.as_str())
+            .unwrap_or_default();
+        let sync = req.get("sync").and_then(|s| s.as_str()).unwrap_or("auto");
+        let description = req.get("description").and_then(|d| d.as_str());
+
+        if sync == "none" || mount_path.starts_with("references/") {
+            references.push(match description {
+                Some(d) => json!({ "remote": remote, "description": d }),
+                None => Value::String(remote.to_string()),
+            });
+        } else {
+            let mut cm = json!({
+                "remote": remote,
+                "mount_path": mount_path,
+                "sync": sync,
+            });
+            if let Some(subpath) = req.get("subpath").and_then(|s| s.as_str()) {
+                cm["subpath"] = Value::String(subpath.to_string());
+            }
+            context_mounts.push(cm);
+        }
+    }
+
+    Ok(json!({
+        "version": "2.0",
+        "mount_dirs": mount_dirs,
+        "context_mounts": context_mounts,
+        "references": references,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RepoConfigV2;
+
+    #[test]
+    fn current_version_needs_no_steps() {
+        assert!(plan(CURRENT_CONFIG_VERSION).unwrap().is_empty());
+    }
+
+    #[test]
+    fn unknown_version_is_rejected() {
+        let err = plan("9.0").unwrap_err();
+        assert!(err.to_string().contains("newer thoughts release"));
+    }
+
+    #[test]
+    fn missing_version_is_treated_as_v1() {
+        let v = json!({ "requires": [] });
+        assert_eq!(config_version(&v), "1.0");
+        assert!(needs_migration(&v));
+    }
+
+    #[test]
+    fn migrates_v1_to_v2() {
+        let v1 = json!({
+            "version": "1.0",
+            "mount_dirs": { "repository": "ctx", "personal": "personal" },
+            "requires": [
+                { "remote": "git@github.com:org/docs.git", "mount_path": "docs", "subpath": "api", "sync": "auto" },
+                { "remote": "https://github.com/org/lib", "mount_path": "references/lib", "description": "Lib" },
+                { "remote": "https://github.com/org/other", "mount_path": "other", "sync": "none" }
+            ],
+            "rules": [{ "pattern": "*.md" }]
+        });
+
+        let outcome = migrate(v1).unwrap();
+        assert!(outcome.changed());
+        assert_eq!(outcome.from_version, "1.0");
+        assert_eq!(outcome.to_version, "2.0");
+
+        let cfg: RepoConfigV2 = serde_json::from_value(outcome.value).unwrap();
+        assert_eq!(cfg.version, "2.0");
+        assert_eq!(cfg.mount_dirs.context, "ctx");
+        assert_eq!(cfg.context_mounts.len(), 1);
+        assert_eq!(cfg.context_mounts[0].mount_path, "docs");
+        assert_eq!(cfg.context_mounts[0].subpath.as_deref(), Some("api"));
+        assert_eq!(cfg.references.len(), 2);
+    }
+
+    #[test]
+    fn v1_mount_without_remote_fails() {
+        let v1 = json!({ "version": "1.0", "requires": [{ "mount_path": "docs" }] });
+        assert!(migrate(v1).is_err());
+    }
+}
@@ -1,8 +1,11 @@
+pub mod migration;
 mod repo_manager;
 pub mod repo_mapping_manager;
 mod types;
 pub mod validation;
 
+pub use repo_manager::DesiredState;
+pub use repo_manager::MigrationReport;
 pub use repo_manager::RepoConfigManager;
 pub use repo_mapping_manager::RepoMappingManager;
 pub use repo_mapping_manager::extract_org_repo_from_url;
@@ -6,6 +6,7 @@ use crate::config::ReferenceMount;
 use crate::config::RepoConfigV2;
 use crate::config::SyncStrategy;
 use crate::config::ThoughtsMount;
+use crate::config::migration;
 use crate::mount::MountSpace;
 use crate::utils::paths;
 use anyhow::Context;
@@ -64,6 +65,25 @@ impl DesiredState {
     }
 }
 
+/// Summary of a `thoughts migrate` run
+#[derive(Debug, Clone)]
+pub struct MigrationReport {
+    pub from_version: String,
+    pub to_version: String,
+    /// Descriptions of the migration steps applied, in order
+    pub steps: Vec<String>,
+    /// Where the pre-migration config was copied, if a write happened
+    pub backup_path: Option<PathBuf>,
+    pub warnings: Vec<String>,
+}
+
+impl MigrationReport {
+    /// Whether the config was already at the current version
+    pub fn is_noop(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
 pub struct RepoConfigManager {
     repo_root: PathBuf,
 }
@@ -122,10 +142,10 @@ impl RepoConfigManager {
             }));
         }
 
-        // V1 configs are no longer supported
+        // V1 configs are no longer read directly
         anyhow::bail!(
             "Unsupported legacy config version (v1). V1 configurations are no longer supported. \
-             Please upgrade to a v2 configuration format."
+             Run 'thoughts migrate' to upgrade to the v2 configuration format."
         );
     }
 
@@ -163,7 +183,7 @@ impl RepoConfigManager {
             Ok(v2)
         } else {
             anyhow::bail!(
-                "Repository is using v1 configuration. Please migrate to v2 configuration format."
+                "Repository is using v1 configuration. Run 'thoughts migrate' to upgrade to v2."
             );
         }
     }
@@ -202,10 +222,10 @@ impl RepoConfigManager {
                 return serde_json::from_str(&raw).context("Failed to parse v2 configuration");
             }
 
-            // V1 configs are no longer supported
+            // V1 configs are no longer read directly
             anyhow::bail!(
                 "Unsupported legacy config version (v1). V1 configurations are no longer supported. \
-                 Please manually migrate to v2 format or delete the config and reinitialize."
+                 Run 'thoughts migrate' to upgrade, or delete the config and reinitialize."
             );
         }
 
@@ -372,6 +392,56 @@ impl RepoConfigManager {
         self.save_v2(config)?;
         Ok(warnings)
     }
+
+    /// Upgrade the on-disk config to the current version.
+    ///
+    /// The migrated config is validated before anything is written. When a
+    /// write happens, the original file is first copied to
+    /// `.thoughts/config.v<from>.bak-YYYYMMDD-HHMMSS.json`. With `dry_run`,
+    /// nothing is written and `backup_path` stays `None`.
+    pub fn migrate(&self, dry_run: bool) -> Result<MigrationReport> {
+        let config_path = paths::get_repo_config_path(&self.repo_root);
+        if !config_path.exists() {
+            anyhow::bail!("No repository configuration found. Run 'thoughts init' first.");
+        }
+
+        let raw = std::fs::read_to_string(&config_path)?;
+        let value: serde_json::Value = serde_json::from_str(&raw)
+            .with_context(|| format!("Failed to parse {}", config_path.display()))?;
+
+        let outcome = migration::migrate(value)?;
+        let steps: Vec<String> = outcome
+            .applied
+            .iter()
+            .map(|m| format!("{} -> {}: {}", m.from, m.to, m.description))
+            .collect();
+
+        let cfg: RepoConfigV2 = serde_json::from_value(outcome.value)
+            .context("Migrated configuration does not match the v2 schema")?;
+        let warnings = self.validate_v2_hard(&cfg)?;
+
+        let mut report = MigrationReport {
+            from_version: outcome.from_version,
+            to_version: outcome.to_version,
+            steps,
+            backup_path: None,
+            warnings,
+        };
+
+        if dry_run || report.is_noop() {
+            return Ok(report);
+        }
+
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let backup_path =
+            config_path.with_file_name(format!("config.v{}.bak-{stamp}.json", report.from_version));
+        fs::copy(&config_path, &backup_path)
+            .with_context(|| format!("Failed to back up config to {}", backup_path.display()))?;
+
+        self.save_v2(&cfg)?;
+        report.backup_path = Some(backup_path);
+        Ok(report)
+    }
 }
 
 #[cfg(test)]
@@ -1438,4 +1508,52 @@ mod tests {
             "trailing slash on references should be rejected"
         );
     }
+
+    #[test]
+    fn test_migrate_v1_writes_backup_and_v2_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mgr = RepoConfigManager::new(temp_dir.path().to_path_buf());
+
+        let v1_json = r#"{
+            "version": "1.0",
+            "mount_dirs": {"repository": "context", "personal": "personal"},
+            "requires": [
+                {"remote": "git@github.com:org/docs.git", "mount_path": "docs", "sync": "auto"}
+            ],
+            "rules": []
+        }"#;
+        let config_path = paths::get_repo_config_path(temp_dir.path());
+        std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        std::fs::write(&config_path, v1_json).unwrap();
+
+        // Dry run leaves the file untouched
+        let report = mgr.migrate(true).unwrap();
+        assert_eq!(report.steps.len(), 1);
+        assert!(report.backup_path.is_none());
+        assert_eq!(mgr.peek_config_version().unwrap().as_deref(), Some("1.0"));
+
+        let report = mgr.migrate(false).unwrap();
+        let backup = report.backup_path.expect("backup should be written");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), v1_json);
+        assert_eq!(mgr.peek_config_version().unwrap().as_deref(), Some("2.0"));
+
+        let ds = mgr.load_desired_state().unwrap().unwrap();
+        assert_eq!(ds.context_mounts.len(), 1);
+
+        // Second run is a no-op
+        assert!(mgr.migrate(false).unwrap().is_noop());
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_version() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mgr = RepoConfigManager::new(temp_dir.path().to_path_buf());
+
+        let config_path = paths::get_repo_config_path(temp_dir.path());
+        std::fs::create_dir_all(config_path.parent().unwrap()).unwrap();
+        std::fs::write(&config_path, r#"{"version": "3.0"}"#).unwrap();
+
+        assert!(mgr.migrate(false).is_err());
+        assert_eq!(mgr.peek_config_version().unwrap().as_deref(), Some("3.0"));
+    }
 }
@@ -250,6 +250,20 @@ pub fn write_document(
     })
 }
 
+/// Read a document from the active work directory.
+///
+/// # Arguments
+/// * `doc_type` - The type of document (research, plan, artifact, log)
+/// * `filename` - The filename (validated for safety)
+///
+/// # Returns
+/// The document's contents as UTF-8 text.
+pub fn read_document(doc_type: &DocumentType, filename: &str) -> TResult<String> {
+    validate_simple_filename(filename)?;
+    let aw = ensure_active_work()?;
+    Ok(fs::read_to_string(doc_type.subdir(&aw).join(filename))?)
+}
+
 /// List documents in the active work directory.
 ///
 /// # Arguments
@@ -11,9 +11,14 @@ use agentic_tools_core::fmt::TextOptions;
 use crate::documents::ActiveDocuments;
 use crate::documents::WriteDocumentOk;
 use crate::mcp::AddReferenceOk;
+use crate::mcp::MountHealth;
+use crate::mcp::MountStatusReport;
+use crate::mcp::ReferenceVerification;
 use crate::mcp::ReferencesList;
 use crate::mcp::RepoRefsList;
+use crate::mcp::SyncReport;
 use crate::mcp::TemplateResponse;
+use crate::mcp::ToolLogsReport;
 use crate::utils::human_size;
 
 impl TextFormat for WriteDocumentOk {
@@ -156,6 +161,176 @@ impl TextFormat for AddReferenceOk {
     }
 }
 
+impl TextFormat for MountStatusReport {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let mut out = if self.healthy {
+            "\u{2713} All configured mounts are active".to_string()
+        } else {
+            "\u{2717} Some configured mounts need repair".to_string()
+        };
+        if self.mounts.is_empty() {
+            out.push_str("\n<no mounts configured>");
+        }
+        for m in &self.mounts {
+            let mark = match m.health {
+                MountHealth::Mounted => "\u{2713}",
+                MountHealth::Degraded => "\u{26a0}",
+                MountHealth::Unmounted => "\u{2717}",
+            };
+            let _ = write!(out, "\n{mark} {}", m.mount);
+            if !m.target.is_empty() {
+                let _ = write!(out, " \u{2192} {}", m.target);
+            }
+            if let Some(detail) = &m.detail {
+                let _ = write!(out, "\n    {detail}");
+            }
+        }
+        if !self.unexpected.is_empty() {
+            out.push_str("\nUnexpected mounts (not in config):");
+            for u in &self.unexpected {
+                let _ = write!(out, "\n- {u}");
+            }
+        }
+        out
+    }
+}
+
+impl TextFormat for ReferenceVerification {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        if self.references.is_empty() {
+            return "No references configured".to_string();
+        }
+        let mut out = if self.ok {
+            format!("\u{2713} All {} references verified", self.references.len())
+        } else {
+            let failing = self.references.iter().filter(|r| !r.ok).count();
+            format!(
+                "\u{2717} {failing} of {} references have issues",
+                self.references.len()
+            )
+        };
+        for r in &self.references {
+            let mark = if r.ok { "\u{2713}" } else { "\u{2717}" };
+            let _ = write!(out, "\n{mark} {}", r.mount.as_deref().unwrap_or(&r.url));
+            if let Some(ref_name) = &r.ref_name {
+                let _ = write!(out, " ({ref_name})");
+            }
+            for issue in &r.issues {
+                let _ = write!(out, "\n    - {issue}");
+            }
+        }
+        out
+    }
+}
+
+impl TextFormat for SyncReport {
+    fn fmt_text(&self, opts: &TextOptions) -> String {
+        let mut out = if self.applied {
+            "Sync applied".to_string()
+        } else {
+            "Sync preview (nothing changed; call with apply=true to run)".to_string()
+        };
+        let list = |items: &[String]| {
+            if items.is_empty() {
+                "<none>".to_string()
+            } else {
+                items.join(", ")
+            }
+        };
+        let _ = write!(
+            out,
+            "\n  Mounts to repair: {}\n  Mounts to sync: {}",
+            list(&self.to_mount),
+            list(&self.to_sync)
+        );
+        for s in &self.synced {
+            match &s.error {
+                None => {
+                    let _ = write!(out, "\n\u{2713} synced {}", s.mount);
+                }
+                Some(e) => {
+                    let _ = write!(out, "\n\u{2717} {}: {e}", s.mount);
+                }
+            }
+        }
+        if !self.warnings.is_empty() {
+            out.push_str("\nWarnings:");
+            for w in &self.warnings {
+                let _ = write!(out, "\n- {w}");
+            }
+        }
+        out.push('\n');
+        out.push_str(&self.status.fmt_text(opts));
+        out
+    }
+}
+
+impl TextFormat for ToolLogsReport {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let stats = &self.stats;
+        if stats.total_calls == 0 {
+            return format!("No matching tool calls in {}", self.logs_dir);
+        }
+        let mut out = format!(
+            "{} calls ({} failed) in {}\n  Duration: p50 {}ms, p95 {}ms",
+            stats.total_calls,
+            stats.failed_calls,
+            self.logs_dir,
+            stats.p50_duration_ms,
+            stats.p95_duration_ms
+        );
+        if stats.skipped_lines > 0 {
+            let _ = write!(out, "\n  Skipped {} unreadable lines", stats.skipped_lines);
+        }
+        out.push_str("\nTools:");
+        for t in &stats.tools {
+            let _ = write!(
+                out,
+                "\n  {}/{}: {} calls, {} failed, p50 {}ms, p95 {}ms, max {}ms",
+                t.server,
+                t.tool,
+                t.calls,
+                t.failures,
+                t.p50_duration_ms,
+                t.p95_duration_ms,
+                t.max_duration_ms
+            );
+        }
+        if !stats.models.is_empty() {
+            out.push_str("\nModels:");
+            for m in &stats.models {
+                let _ = write!(
+                    out,
+                    "\n  {}: {} calls, {} tokens ({} prompt, {} completion",
+                    m.model, m.calls, m.total_tokens, m.prompt_tokens, m.completion_tokens
+                );
+                if m.reasoning_tokens > 0 {
+                    let _ = write!(out, ", {} reasoning", m.reasoning_tokens);
+                }
+                out.push(')');
+            }
+        }
+        if !self.recent.is_empty() {
+            out.push_str("\nRecent:");
+            for e in &self.recent {
+                let mark = if e.success { "\u{2713}" } else { "\u{2717}" };
+                let _ = write!(
+                    out,
+                    "\n{mark} {} {}/{} {}ms",
+                    e.completed_at, e.server, e.tool, e.duration_ms
+                );
+                if let Some(parent) = &e.parent_call_id {
+                    let _ = write!(out, " (from {})", parent.get(..8).unwrap_or(parent));
+                }
+                if let Some(err) = &e.error {
+                    let _ = write!(out, " \u{2014} {err}");
+                }
+            }
+        }
+        out
+    }
+}
+
 impl TextFormat for TemplateResponse {
     fn fmt_text(&self, _opts: &TextOptions) -> String {
         let ty = self.template_type.label();
@@ -170,8 +345,15 @@ mod tests {
     use super::*;
     use crate::documents::DocumentInfo;
     use crate::git::remote_refs::RemoteRef;
+    use crate::mcp::MountStatusEntry;
+    use crate::mcp::ReferenceCheck;
     use crate::mcp::ReferenceItem;
+    use crate::mcp::SyncMountResult;
     use crate::mcp::TemplateType;
+    use crate::mcp::ToolLogEntry;
+    use agentic_logging::LogStats;
+    use agentic_logging::ModelUsage;
+    use agentic_logging::ToolStats;
 
     #[test]
     fn write_document_text_format() {
@@ -335,4 +517,157 @@ mod tests {
         assert!(tf.starts_with("Here is the research template:"));
         assert!(tf.contains("```markdown"));
     }
+
+    fn degraded_status() -> MountStatusReport {
+        MountStatusReport {
+            healthy: false,
+            mounts: vec![
+                MountStatusEntry {
+                    mount: "thoughts".into(),
+                    target: "/repo/.thoughts-data/thoughts".into(),
+                    health: MountHealth::Mounted,
+                    sources: vec!["/clones/thoughts".into()],
+                    detail: None,
+                },
+                MountStatusEntry {
+                    mount: "references/org/lib".into(),
+                    target: "/repo/.thoughts-data/references/org/lib".into(),
+                    health: MountHealth::Unmounted,
+                    sources: Vec::new(),
+                    detail: Some("not mounted".into()),
+                },
+            ],
+            unexpected: vec!["context/old".into()],
+        }
+    }
+
+    #[test]
+    fn mount_status_text_format() {
+        let tf = degraded_status().fmt_text(&TextOptions::default());
+        assert!(tf.starts_with("\u{2717} Some configured mounts need repair"));
+        assert!(tf.contains("\u{2713} thoughts \u{2192} /repo/.thoughts-data/thoughts"));
+        assert!(tf.contains("\u{2717} references/org/lib"));
+        assert!(tf.contains("    not mounted"));
+        assert!(tf.contains("Unexpected mounts (not in config):\n- context/old"));
+    }
+
+    #[test]
+    fn reference_verification_text_format() {
+        let v = ReferenceVerification {
+            ok: false,
+            references: vec![
+                ReferenceCheck {
+                    url: "https://github.com/org/lib".into(),
+                    ref_name: Some("refs/tags/v1".into()),
+                    mount: Some("references/org/lib@v1".into()),
+                    mapping_path: Some("/clones/lib".into()),
+                    ok: false,
+                    issues: vec!["origin mismatch".into()],
+                },
+                ReferenceCheck {
+                    url: "https://github.com/org/ok".into(),
+                    ref_name: None,
+                    mount: Some("references/org/ok".into()),
+                    mapping_path: Some("/clones/ok".into()),
+                    ok: true,
+                    issues: Vec::new(),
+                },
+            ],
+        };
+        let tf = v.fmt_text(&TextOptions::default());
+        assert!(tf.starts_with("\u{2717} 1 of 2 references have issues"));
+        assert!(
+            tf.contains("\u{2717} references/org/lib@v1 (refs/tags/v1)\n    - origin mismatch")
+        );
+        assert!(tf.contains("\u{2713} references/org/ok"));
+    }
+
+    #[test]
+    fn sync_report_text_format_preview_and_applied() {
+        let preview = SyncReport {
+            applied: false,
+            to_mount: vec!["references/org/lib".into()],
+            to_sync: Vec::new(),
+            synced: Vec::new(),
+            status: degraded_status(),
+            warnings: Vec::new(),
+        };
+        let tf = preview.fmt_text(&TextOptions::default());
+        assert!(tf.starts_with("Sync preview"));
+        assert!(tf.contains("Mounts to repair: references/org/lib"));
+        assert!(tf.contains("Mounts to sync: <none>"));
+
+        let applied = SyncReport {
+            applied: true,
+            to_sync: vec!["thoughts".into()],
+            synced: vec![SyncMountResult {
+                mount: "thoughts".into(),
+                ok: false,
+                error: Some("git push failed".into()),
+            }],
+            ..preview
+        };
+        let tf = applied.fmt_text(&TextOptions::default());
+        assert!(tf.starts_with("Sync applied"));
+        assert!(tf.contains("\u{2717} thoughts: git push failed"));
+    }
+
+    #[test]
+    fn tool_logs_report_text_format() {
+        let empty = ToolLogsReport {
+            logs_dir: "/t/logs".into(),
+            stats: LogStats::default(),
+            recent: Vec::new(),
+        };
+        assert_eq!(
+            empty.fmt_text(&TextOptions::default()),
+            "No matching tool calls in /t/logs"
+        );
+
+        let report = ToolLogsReport {
+            stats: LogStats {
+                total_calls: 2,
+                failed_calls: 1,
+                p50_duration_ms: 12,
+                p95_duration_ms: 800,
+                tools: vec![ToolStats {
+                    server: "thoughts_tool".into(),
+                    tool: "thoughts_sync".into(),
+                    calls: 2,
+                    failures: 1,
+                    p50_duration_ms: 12,
+                    p95_duration_ms: 800,
+                    max_duration_ms: 800,
+                }],
+                models: vec![ModelUsage {
+                    model: "openai/gpt-5".into(),
+                    calls: 1,
+                    prompt_tokens: 10,
+                    completion_tokens: 5,
+                    total_tokens: 15,
+                    reasoning_tokens: 0,
+                }],
+                skipped_lines: 0,
+            },
+            recent: vec![ToolLogEntry {
+                call_id: "c1".into(),
+                server: "thoughts_tool".into(),
+                tool: "thoughts_sync".into(),
+                completed_at: "2026-03-02T12:00:00+00:00".into(),
+                duration_ms: 800,
+                success: false,
+                error: Some("git push failed".into()),
+                model: None,
+                parent_call_id: Some("0b4f6a1c-2d3e-4f50-8a9b-c0d1e2f3a4b5".into()),
+            }],
+            ..empty
+        };
+        let tf = report.fmt_text(&TextOptions::default());
+        assert!(tf.starts_with("2 calls (1 failed) in /t/logs"));
+        assert!(tf.contains("thoughts_tool/thoughts_sync: 2 calls, 1 failed, p50 12ms"));
+        assert!(tf.contains("openai/gpt-5: 1 calls, 15 tokens (10 prompt, 5 completion)"));
+        assert!(tf.contains(
+            "\u{2717} 2026-03-02T12:00:00+00:00 thoughts_tool/thoughts_sync 800ms (from 0b4f6a1c) \u{2014} git push failed"
+        ));
+    }
 }
@@ -28,6 +28,7 @@ pub use documents::DocumentType;
 pub use documents::WriteDocumentOk;
 pub use documents::active_logs_dir;
 pub use documents::list_documents;
+pub use documents::read_document;
 pub use documents::write_document;
 pub use error::Result;
 pub use error::ThoughtsError;
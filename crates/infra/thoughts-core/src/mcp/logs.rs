@@ -0,0 +1,179 @@
+//! Tool call log queries for the `tool_logs_query` MCP tool.
+//!
+//! Reads the JSONL logs in the active branch's `logs/` directory through
+//! [`agentic_logging::LogReader`] so an agent can check which tools failed, how
+//! slow they were, and how many tokens each model used without opening the files.
+
+use agentic_logging::LogFilter;
+use agentic_logging::LogReader;
+use agentic_logging::LogStats;
+use agentic_logging::ToolCallRecord;
+use agentic_logging::parse_time_bound;
+use anyhow::Context;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::documents::active_logs_dir;
+
+const DEFAULT_RECENT_LIMIT: usize = 20;
+const MAX_RECENT_LIMIT: usize = 200;
+
+/// Filters for [`tool_logs_query_impl_adapter`]; all are optional.
+#[derive(Debug, Clone, Default)]
+pub struct ToolLogsQuery {
+    pub server: Option<String>,
+    pub tool: Option<String>,
+    pub success: Option<bool>,
+    /// RFC 3339 timestamp, `YYYY-MM-DD`, or an age such as `24h` or `7d`.
+    pub since: Option<String>,
+    /// Same formats as `since`; exclusive.
+    pub until: Option<String>,
+    /// Calls in this trace (the originating call's ID).
+    pub trace_id: Option<String>,
+    /// Most recent matching calls to return (default 20, max 200).
+    pub limit: Option<usize>,
+}
+
+/// One logged call, without its request payload.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolLogEntry {
+    pub call_id: String,
+    pub server: String,
+    pub tool: String,
+    /// RFC 3339 completion time.
+    pub completed_at: String,
+    pub duration_ms: u64,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Call that made this one, for calls from subagents and nested tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_call_id: Option<String>,
+}
+
+impl From<&ToolCallRecord> for ToolLogEntry {
+    fn from(record: &ToolCallRecord) -> Self {
+        Self {
+            call_id: record.call_id.clone(),
+            server: record.server.clone(),
+            tool: record.tool.clone(),
+            completed_at: record.completed_at.to_rfc3339(),
+            duration_ms: u64::try_from(record.duration_ms).unwrap_or(u64::MAX),
+            success: record.success,
+            error: record.error.clone(),
+            model: record.model.clone(),
+            parent_call_id: record.parent_call_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolLogsReport {
+    pub logs_dir: String,
+    pub stats: LogStats,
+    /// Most recent matching calls, newest first.
+    pub recent: Vec<ToolLogEntry>,
+}
+
+pub async fn tool_logs_query_impl_adapter(query: ToolLogsQuery) -> Result<ToolLogsReport> {
+    let filter = build_filter(&query, Utc::now())?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_RECENT_LIMIT)
+        .clamp(1, MAX_RECENT_LIMIT);
+    let logs_dir = active_logs_dir()?;
+
+    let result = {
+        let logs_dir = logs_dir.clone();
+        tokio::task::spawn_blocking(move || LogReader::new(logs_dir).query(&filter))
+            .await
+            .context("log query task failed")??
+    };
+
+    Ok(ToolLogsReport {
+        logs_dir: logs_dir.display().to_string(),
+        recent: result
+            .records
+            .iter()
+            .rev()
+            .take(limit)
+            .map(ToolLogEntry::from)
+            .collect(),
+        stats: result.stats,
+    })
+}
+
+fn build_filter(query: &ToolLogsQuery, now: DateTime<Utc>) -> Result<LogFilter> {
+    let bound = |name: &str, raw: Option<&str>| {
+        raw.map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|v| {
+                parse_time_bound(v, now).with_context(|| {
+                    format!(
+                        "invalid argument: {name} '{v}' must be an RFC 3339 timestamp, a YYYY-MM-DD date, or an age like 24h/7d/2w"
+                    )
+                })
+            })
+            .transpose()
+    };
+    let non_blank = |v: &Option<String>| {
+        v.as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(ToString::to_string)
+    };
+    Ok(LogFilter {
+        server: non_blank(&query.server),
+        tool: non_blank(&query.tool),
+        success: query.success,
+        since: bound("since", query.since.as_deref())?,
+        until: bound("until", query.until.as_deref())?,
+        trace_id: non_blank(&query.trace_id),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_filter_parses_bounds_and_ignores_blanks() {
+        let now = DateTime::parse_from_rfc3339("2026-03-02T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let filter = build_filter(
+            &ToolLogsQuery {
+                server: Some(" ".into()),
+                tool: Some("thoughts_sync".into()),
+                since: Some("1d".into()),
+                until: Some("2026-03-02".into()),
+                ..ToolLogsQuery::default()
+            },
+            now,
+        )
+        .unwrap();
+        assert_eq!(filter.server, None);
+        assert_eq!(filter.tool.as_deref(), Some("thoughts_sync"));
+        assert_eq!(filter.since, Some(now - chrono::Duration::days(1)));
+        assert_eq!(
+            filter.until.map(|t| t.to_rfc3339()).as_deref(),
+            Some("2026-03-02T00:00:00+00:00")
+        );
+
+        let err = build_filter(
+            &ToolLogsQuery {
+                since: Some("last week".into()),
+                ..ToolLogsQuery::default()
+            },
+            now,
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("invalid argument: since"));
+    }
+}
@@ -0,0 +1,610 @@
+//! Mount status, reference verification, and sync adapters for the thoughts MCP tools.
+//!
+//! These mirror `thoughts mount status`, `thoughts references doctor`, and
+//! `thoughts sync` so an agent can diagnose a broken environment mid-session instead
+//! of only reporting the readiness failure. Status and verification never modify
+//! anything; sync previews its plan unless `apply` is set.
+
+use anyhow::Context;
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::config::DesiredState;
+use crate::config::Mount;
+use crate::config::RepoConfigManager;
+use crate::config::RepoMappingManager;
+use crate::config::SyncStrategy;
+use crate::config::extract_org_repo_from_url;
+use crate::git::GitSync;
+use crate::git::ref_key::encode_ref_key;
+use crate::git::utils::get_control_repo_root;
+use crate::git::utils::is_git_repo;
+use crate::git::utils::try_get_origin_identity;
+use crate::mount::MountInfo;
+use crate::mount::MountResolver;
+use crate::mount::MountSpace;
+use crate::mount::MountStatus;
+use crate::mount::auto_mount::update_active_mounts;
+use crate::mount::get_mount_manager;
+use crate::platform::detect_platform;
+use crate::repo_identity::RepoIdentity;
+
+const REPAIR_HINT: &str = "run thoughts_sync with apply=true or 'thoughts mount update'";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MountHealth {
+    Mounted,
+    Degraded,
+    Unmounted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MountStatusEntry {
+    /// Mount identifier (thoughts, context path, or references/org/repo[@ref]).
+    pub mount: String,
+    pub target: String,
+    pub health: MountHealth,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MountStatusReport {
+    pub healthy: bool,
+    pub mounts: Vec<MountStatusEntry>,
+    /// Active mounts under `.thoughts-data` that the configuration no longer lists.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unexpected: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceCheck {
+    pub url: String,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mapping_path: Option<String>,
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceVerification {
+    pub ok: bool,
+    pub references: Vec<ReferenceCheck>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SyncMountResult {
+    pub mount: String,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SyncReport {
+    /// False for a preview: nothing was mounted or synced.
+    pub applied: bool,
+    /// Configured mounts that were not healthy before the run.
+    pub to_mount: Vec<String>,
+    /// Auto-sync git mounts (thoughts and context) that sync pulls, commits, and pushes.
+    pub to_sync: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub synced: Vec<SyncMountResult>,
+    /// Mount status after the run (or the current status for a preview).
+    pub status: MountStatusReport,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+fn load_desired_state() -> Result<(PathBuf, DesiredState)> {
+    let control_root =
+        get_control_repo_root(&std::env::current_dir().context("failed to get current directory")?)
+            .context("failed to get control repo root")?;
+    let desired = RepoConfigManager::new(control_root.clone())
+        .load_desired_state()
+        .context("failed to load desired state")?
+        .ok_or_else(|| {
+            anyhow::anyhow!("not found: no repository configuration found. Run 'thoughts init'.")
+        })?;
+    Ok((control_root, desired))
+}
+
+/// Report every configured mount and whether it is active and healthy.
+pub async fn mount_status_impl_adapter() -> Result<MountStatusReport> {
+    let (control_root, desired) = load_desired_state()?;
+    let platform = detect_platform().context("failed to detect platform")?;
+    let mount_manager = get_mount_manager(&platform).context("failed to get mount manager")?;
+    let active = mount_manager
+        .list_mounts()
+        .await
+        .context("failed to list mounts")?;
+    Ok(build_mount_status(&control_root, &desired, &active))
+}
+
+/// Configured mount spaces in the order `thoughts mount update` mounts them.
+/// References whose URL cannot be parsed are returned as errors alongside.
+fn expected_spaces(desired: &DesiredState) -> (Vec<MountSpace>, Vec<(String, String)>) {
+    let mut spaces = Vec::new();
+    let mut invalid = Vec::new();
+    if desired.thoughts_mount.is_some() {
+        spaces.push(MountSpace::Thoughts);
+    }
+    for cm in &desired.context_mounts {
+        spaces.push(MountSpace::Context(cm.mount_path.clone()));
+    }
+    for rm in &desired.references {
+        let space = extract_org_repo_from_url(&rm.remote).and_then(|(org_path, repo)| {
+            Ok(MountSpace::Reference {
+                org_path,
+                repo,
+                ref_key: rm.ref_name.as_deref().map(encode_ref_key).transpose()?,
+            })
+        });
+        match space {
+            Ok(space) => spaces.push(space),
+            Err(e) => invalid.push((rm.remote.clone(), e.to_string())),
+        }
+    }
+    (spaces, invalid)
+}
+
+fn build_mount_status(
+    control_root: &Path,
+    desired: &DesiredState,
+    active: &[MountInfo],
+) -> MountStatusReport {
+    let data_root = control_root.join(".thoughts-data");
+    let data_root_canon = std::fs::canonicalize(&data_root).unwrap_or_else(|_| data_root.clone());
+    let mut active_by_key: HashMap<String, &MountInfo> = HashMap::new();
+    for mi in active {
+        let canon = std::fs::canonicalize(&mi.target).unwrap_or_else(|_| mi.target.clone());
+        if let Ok(rel) = canon.strip_prefix(&data_root_canon) {
+            let key = rel.to_string_lossy().trim_start_matches('/').to_string();
+            active_by_key.insert(key, mi);
+        }
+    }
+
+    let (spaces, invalid) = expected_spaces(desired);
+    let mut mounts = Vec::new();
+    for space in &spaces {
+        let key = space.relative_path(&desired.mount_dirs);
+        let target = data_root.join(&key).to_string_lossy().to_string();
+        let entry = match active_by_key.remove(&key) {
+            Some(mi) => {
+                let (health, detail) = match &mi.status {
+                    MountStatus::Mounted => (MountHealth::Mounted, None),
+                    MountStatus::Unmounted => (MountHealth::Unmounted, Some(REPAIR_HINT.into())),
+                    MountStatus::Degraded(why) | MountStatus::Error(why) => {
+                        (MountHealth::Degraded, Some(format!("{why}; {REPAIR_HINT}")))
+                    }
+                    MountStatus::Unknown => (
+                        MountHealth::Degraded,
+                        Some(format!("status unknown; {REPAIR_HINT}")),
+                    ),
+                };
+                MountStatusEntry {
+                    mount: space.as_str(),
+                    target,
+                    health,
+                    sources: mi.sources.iter().map(|s| s.display().to_string()).collect(),
+                    detail,
+                }
+            }
+            None => MountStatusEntry {
+                mount: space.as_str(),
+                target,
+                health: MountHealth::Unmounted,
+                sources: Vec::new(),
+                detail: Some(format!("not mounted; {REPAIR_HINT}")),
+            },
+        };
+        mounts.push(entry);
+    }
+    for (url, error) in invalid {
+        mounts.push(MountStatusEntry {
+            mount: url,
+            target: String::new(),
+            health: MountHealth::Unmounted,
+            sources: Vec::new(),
+            detail: Some(format!("invalid reference in config: {error}")),
+        });
+    }
+
+    let mut unexpected: Vec<String> = active_by_key.into_keys().collect();
+    unexpected.sort();
+    MountStatusReport {
+        healthy: mounts.iter().all(|m| m.health == MountHealth::Mounted),
+        mounts,
+        unexpected,
+    }
+}
+
+/// Check each configured reference: mapped to a local clone, clone present and a git
+/// repository, and its origin matches the configured URL.
+pub async fn verify_references_impl_adapter() -> Result<ReferenceVerification> {
+    let (_, desired) = load_desired_state()?;
+    let mapping = RepoMappingManager::new().context("failed to create repo mapping manager")?;
+
+    let mut references = Vec::new();
+    for rm in &desired.references {
+        let mut issues = Vec::new();
+        let mount = match extract_org_repo_from_url(&rm.remote) {
+            Ok((org_path, repo)) => rm
+                .ref_name
+                .as_deref()
+                .map(encode_ref_key)
+                .transpose()
+                .map(|ref_key| {
+                    MountSpace::Reference {
+                        org_path,
+                        repo,
+                        ref_key,
+                    }
+                    .as_str()
+                })
+                .map_err(|e| issues.push(format!("invalid ref: {e}")))
+                .ok(),
+            Err(e) => {
+                issues.push(format!("invalid reference URL: {e}"));
+                None
+            }
+        };
+
+        let mapping_path = match mapping.resolve_reference_url(&rm.remote, rm.ref_name.as_deref()) {
+            Ok(Some(path)) => {
+                issues.extend(check_reference_clone(&rm.remote, &path));
+                Some(path.to_string_lossy().to_string())
+            }
+            Ok(None) => {
+                issues.push(
+                    "not cloned or mapped; run thoughts_sync with apply=true or 'thoughts references sync'"
+                        .into(),
+                );
+                None
+            }
+            Err(e) => {
+                issues.push(format!("failed to resolve mapping: {e}"));
+                None
+            }
+        };
+
+        references.push(ReferenceCheck {
+            url: rm.remote.clone(),
+            ref_name: rm.ref_name.clone(),
+            mount,
+            mapping_path,
+            ok: issues.is_empty(),
+            issues,
+        });
+    }
+
+    Ok(ReferenceVerification {
+        ok: references.iter().all(|r| r.ok),
+        references,
+    })
+}
+
+/// The same path checks `thoughts references doctor` runs on a mapped clone.
+fn check_reference_clone(url: &str, path: &Path) -> Vec<String> {
+    const DOCTOR_HINT: &str = "run 'thoughts references doctor --fix' to prune stale mappings";
+    if !path.exists() {
+        return vec![format!(
+            "mapped path is missing: {}; {DOCTOR_HINT}",
+            path.display()
+        )];
+    }
+    if !path.is_dir() {
+        return vec![format!(
+            "mapped path is not a directory: {}; {DOCTOR_HINT}",
+            path.display()
+        )];
+    }
+    if !is_git_repo(path) {
+        return vec![format!(
+            "mapped path is not a git repository: {}",
+            path.display()
+        )];
+    }
+    let Ok(expected) = RepoIdentity::parse(url) else {
+        return Vec::new();
+    };
+    match try_get_origin_identity(path) {
+        Ok(Some(actual)) if actual.canonical_key() != expected.canonical_key() => {
+            let actual = actual.canonical_key();
+            vec![format!(
+                "origin mismatch: clone at {} points to {}/{}/{}",
+                path.display(),
+                actual.host,
+                actual.org_path,
+                actual.repo
+            )]
+        }
+        Ok(Some(_)) => Vec::new(),
+        Ok(None) => vec![format!("clone at {} has no origin remote", path.display())],
+        Err(e) => vec![format!("failed to read origin: {e}")],
+    }
+}
+
+fn auto_sync_mounts(desired: &DesiredState) -> Vec<(MountSpace, Mount)> {
+    let mut list = Vec::new();
+    if let Some(tm) = &desired.thoughts_mount
+        && tm.sync == SyncStrategy::Auto
+    {
+        list.push((
+            MountSpace::Thoughts,
+            Mount::Git {
+                url: tm.remote.clone(),
+                sync: tm.sync,
+                subpath: tm.subpath.clone(),
+            },
+        ));
+    }
+    for cm in &desired.context_mounts {
+        if cm.sync == SyncStrategy::Auto {
+            list.push((
+                MountSpace::Context(cm.mount_path.clone()),
+                Mount::Git {
+                    url: cm.remote.clone(),
+                    sync: cm.sync,
+                    subpath: cm.subpath.clone(),
+                },
+            ));
+        }
+    }
+    list
+}
+
+/// Remount missing mounts and git-sync auto-sync mounts, like `thoughts mount update`
+/// followed by `thoughts sync --all`. With `apply = false` only the plan is returned.
+pub async fn sync_impl_adapter(apply: bool) -> Result<SyncReport> {
+    let before = mount_status_impl_adapter().await?;
+    let (_, desired) = load_desired_state()?;
+    let to_mount: Vec<String> = before
+        .mounts
+        .iter()
+        .filter(|m| m.health != MountHealth::Mounted)
+        .map(|m| m.mount.clone())
+        .collect();
+    let sync_list = auto_sync_mounts(&desired);
+    let to_sync = sync_list.iter().map(|(space, _)| space.as_str()).collect();
+
+    if !apply {
+        return Ok(SyncReport {
+            applied: false,
+            to_mount,
+            to_sync,
+            synced: Vec::new(),
+            status: before,
+            warnings: Vec::new(),
+        });
+    }
+
+    let mut warnings = Vec::new();
+    if let Err(e) = update_active_mounts().await {
+        warnings.push(format!("Mount synchronization encountered an error: {e}"));
+    }
+
+    let mut synced = Vec::new();
+    for (space, mount) in sync_list {
+        let name = space.as_str();
+        let result = sync_git_mount(name.clone(), mount).await;
+        synced.push(SyncMountResult {
+            mount: name,
+            ok: result.is_ok(),
+            error: result.err().map(|e| format!("{e:#}")),
+        });
+    }
+
+    Ok(SyncReport {
+        applied: true,
+        to_mount,
+        to_sync,
+        synced,
+        status: mount_status_impl_adapter().await?,
+        warnings,
+    })
+}
+
+/// Run [`GitSync`] on a blocking thread: `git2::Repository` is not `Sync`, so the
+/// sync future cannot be held across the tool's `Send` boundary.
+async fn sync_git_mount(name: String, mount: Mount) -> Result<()> {
+    let handle = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || handle.block_on(sync_git_mount_local(&name, &mount)))
+        .await
+        .context("sync task failed")?
+}
+
+#[expect(
+    clippy::future_not_send,
+    reason = "git2::Repository is Send but not Sync; this is a known limitation"
+)]
+async fn sync_git_mount_local(name: &str, mount: &Mount) -> Result<()> {
+    let Mount::Git { url, subpath, .. } = mount else {
+        return Ok(());
+    };
+    let resolver = MountResolver::new()?;
+    let mount_path = resolver.resolve_mount(mount).context("Mount not cloned")?;
+    if !mount_path.exists() {
+        anyhow::bail!("Mount path does not exist: {}", mount_path.display());
+    }
+
+    let repo_root = if subpath.is_some() {
+        mount_path
+            .ancestors()
+            .find(|p| p.join(".git").exists())
+            .ok_or_else(|| anyhow::anyhow!("Could not find repository root"))?
+            .to_path_buf()
+    } else {
+        mount_path.clone()
+    };
+
+    GitSync::new(&repo_root, subpath.clone())?
+        .sync(name)
+        .await?;
+    RepoMappingManager::new()?.update_sync_time(url)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ContextMount;
+    use crate::config::MountDirsV2;
+    use crate::config::ReferenceMount;
+    use crate::config::ThoughtsMount;
+    use crate::mount::MountMetadata;
+    use tempfile::TempDir;
+
+    fn desired() -> DesiredState {
+        DesiredState {
+            mount_dirs: MountDirsV2::default(),
+            thoughts_mount: Some(ThoughtsMount {
+                remote: "https://github.com/example/thoughts.git".into(),
+                subpath: None,
+                sync: SyncStrategy::Auto,
+            }),
+            context_mounts: vec![ContextMount {
+                remote: "https://github.com/example/docs.git".into(),
+                subpath: None,
+                mount_path: "docs".into(),
+                sync: SyncStrategy::None,
+            }],
+            references: vec![
+                ReferenceMount {
+                    remote: "https://github.com/example/lib".into(),
+                    description: None,
+                    ref_name: None,
+                },
+                ReferenceMount {
+                    remote: "not a url".into(),
+                    description: None,
+                    ref_name: None,
+                },
+            ],
+            was_v1: false,
+        }
+    }
+
+    fn mount_info(target: PathBuf, status: MountStatus) -> MountInfo {
+        MountInfo {
+            target,
+            sources: vec![PathBuf::from("/src")],
+            status,
+            fs_type: "fuse.mergerfs".into(),
+            options: Vec::new(),
+            mounted_at: None,
+            pid: None,
+            metadata: MountMetadata::Linux {
+                mount_id: None,
+                parent_id: None,
+                major_minor: None,
+            },
+        }
+    }
+
+    #[test]
+    fn build_mount_status_classifies_each_configured_mount() {
+        let tmp = TempDir::new().unwrap();
+        let desired = desired();
+        let data = tmp.path().join(".thoughts-data");
+        let active = vec![
+            mount_info(data.join("thoughts"), MountStatus::Mounted),
+            mount_info(
+                data.join("context/docs"),
+                MountStatus::Degraded("stale handle".into()),
+            ),
+            mount_info(data.join("context/old"), MountStatus::Mounted),
+        ];
+
+        let report = build_mount_status(tmp.path(), &desired, &active);
+
+        assert!(!report.healthy);
+        let health: Vec<_> = report
+            .mounts
+            .iter()
+            .map(|m| (m.mount.as_str(), m.health))
+            .collect();
+        assert_eq!(
+            health,
+            vec![
+                ("thoughts", MountHealth::Mounted),
+                ("docs", MountHealth::Degraded),
+                ("references/example/lib", MountHealth::Unmounted),
+                ("not a url", MountHealth::Unmounted),
+            ]
+        );
+        assert!(
+            report.mounts[1]
+                .detail
+                .as_deref()
+                .unwrap()
+                .contains("stale handle")
+        );
+        assert!(
+            report.mounts[3]
+                .detail
+                .as_deref()
+                .unwrap()
+                .starts_with("invalid reference")
+        );
+        assert_eq!(report.unexpected, vec!["context/old".to_string()]);
+    }
+
+    #[test]
+    fn auto_sync_mounts_skips_non_auto_mounts() {
+        let spaces: Vec<_> = auto_sync_mounts(&desired())
+            .into_iter()
+            .map(|(space, _)| space.as_str())
+            .collect();
+        assert_eq!(spaces, vec!["thoughts".to_string()]);
+    }
+
+    #[test]
+    fn check_reference_clone_reports_path_problems() {
+        let tmp = TempDir::new().unwrap();
+        let url = "https://github.com/example/lib";
+
+        let missing = check_reference_clone(url, &tmp.path().join("missing"));
+        assert!(missing[0].starts_with("mapped path is missing"));
+
+        let file = tmp.path().join("file");
+        std::fs::write(&file, "x").unwrap();
+        assert!(check_reference_clone(url, &file)[0].contains("not a directory"));
+
+        let plain = tmp.path().join("plain");
+        std::fs::create_dir_all(&plain).unwrap();
+        assert!(check_reference_clone(url, &plain)[0].contains("not a git repository"));
+    }
+
+    #[test]
+    fn check_reference_clone_compares_origin_identity() {
+        let tmp = TempDir::new().unwrap();
+        let repo = git2::Repository::init(tmp.path()).unwrap();
+
+        assert!(
+            check_reference_clone("https://github.com/example/lib", tmp.path())[0]
+                .contains("no origin remote")
+        );
+
+        repo.remote("origin", "git@github.com:Example/lib.git")
+            .unwrap();
+        assert!(check_reference_clone("https://github.com/example/lib", tmp.path()).is_empty());
+
+        let issues = check_reference_clone("https://github.com/other/lib", tmp.path());
+        assert!(issues[0].contains("origin mismatch"), "{issues:?}");
+        assert!(issues[0].contains("github.com/example/lib"));
+    }
+}
@@ -8,8 +8,25 @@ use std::sync::OnceLock;
 use std::time::Duration;
 use tokio::sync::Semaphore;
 
+mod logs;
+mod maintenance;
 mod templates;
 
+pub use logs::ToolLogEntry;
+pub use logs::ToolLogsQuery;
+pub use logs::ToolLogsReport;
+pub use logs::tool_logs_query_impl_adapter;
+pub use maintenance::MountHealth;
+pub use maintenance::MountStatusEntry;
+pub use maintenance::MountStatusReport;
+pub use maintenance::ReferenceCheck;
+pub use maintenance::ReferenceVerification;
+pub use maintenance::SyncMountResult;
+pub use maintenance::SyncReport;
+pub use maintenance::mount_status_impl_adapter;
+pub use maintenance::sync_impl_adapter;
+pub use maintenance::verify_references_impl_adapter;
+
 use crate::config::ReferenceEntry;
 use crate::config::ReferenceMount;
 use crate::config::RepoConfigManager;
@@ -4,8 +4,8 @@
 //! wrappers to ensure consistent logging behavior.
 
 use crate::documents::active_logs_dir;
+use agentic_logging::AsyncLogWriter;
 use agentic_logging::CallTimer;
-use agentic_logging::LogWriter;
 use agentic_logging::ToolCallRecord;
 
 fn classify_failure_kind(success: bool, error: Option<&str>) -> Option<String> {
@@ -35,9 +35,8 @@ pub fn log_tool_call(
     error: Option<String>,
     summary: Option<serde_json::Value>,
 ) {
-    let writer = match active_logs_dir() {
-        Ok(dir) => LogWriter::new(dir),
-        Err(_) => return, // Logging unavailable (e.g., branch lockout)
+    let Ok(dir) = active_logs_dir() else {
+        return; // Logging unavailable (e.g., branch lockout)
     };
 
     let (completed_at, duration_ms) = timer.finish();
@@ -56,9 +55,10 @@ pub fn log_tool_call(
         model: None,
         token_usage: None,
         summary,
+        trace_id: Some(timer.trace_id.clone()),
+        parent_call_id: timer.parent_call_id.clone(),
     };
 
-    if let Err(e) = writer.append_jsonl(&record) {
-        tracing::warn!("Failed to append JSONL log: {}", e);
-    }
+    // Queued for a background flush so slow disks don't delay the tool response.
+    AsyncLogWriter::shared(&dir).append(record);
 }
@@ -0,0 +1,172 @@
+//! Authentication for generated REST routes
+//!
+//! `rest(auth = "bearer")` on a tool puts a layer in front of its route that reads the
+//! `Authorization: Bearer <token>` header and asks the router's [`AuthProvider`] to
+//! accept it before the handler runs. Set on the router,
+//! `#[universal_tool_router(rest(auth = "bearer"))]`, it is the default for every tool;
+//! `rest(auth = "none")` opts a single tool out.
+//!
+//! Install the provider on the generated router with [`with_auth`]:
+//!
+//! ```ignore
+//! let app = with_auth(MyTools::create_rest_router(tools), StaticTokens::new(["secret"]));
+//! ```
+//!
+//! Without a provider, protected routes answer `500` instead of running unauthenticated.
+
+use crate::error::ErrorCode;
+use crate::error::ToolError;
+use axum::Extension;
+use axum::Json;
+use axum::Router;
+use axum::extract::Request;
+use axum::http::StatusCode;
+use axum::http::header;
+use axum::middleware::Next;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// How a route expects callers to authenticate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthScheme {
+    /// `Authorization: Bearer <token>`
+    Bearer,
+}
+
+/// Credentials presented for a protected tool
+#[derive(Debug, Clone, Copy)]
+pub struct Credentials<'a> {
+    /// Scheme the credentials were presented with
+    pub scheme: AuthScheme,
+    /// The token itself
+    pub token: &'a str,
+    /// Name of the tool being called
+    pub tool: &'static str,
+}
+
+/// Why an [`AuthProvider`] rejected credentials
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthError {
+    /// The credentials are not valid; answered with `401`
+    Unauthenticated(String),
+    /// The credentials are valid but may not call this tool; answered with `403`
+    Forbidden(String),
+}
+
+/// Future returned by [`AuthProvider::authenticate`]
+pub type AuthFuture<'a> = Pin<Box<dyn Future<Output = Result<(), AuthError>> + Send + 'a>>;
+
+/// Decides whether credentials may call a tool
+pub trait AuthProvider: Send + Sync + 'static {
+    fn authenticate<'a>(&'a self, credentials: Credentials<'a>) -> AuthFuture<'a>;
+}
+
+/// Accepts a fixed set of bearer tokens for every tool
+#[derive(Debug, Clone, Default)]
+pub struct StaticTokens {
+    tokens: HashSet<String>,
+}
+
+impl StaticTokens {
+    /// Accept any of `tokens`
+    pub fn new<I, S>(tokens: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            tokens: tokens.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl AuthProvider for StaticTokens {
+    fn authenticate<'a>(&'a self, credentials: Credentials<'a>) -> AuthFuture<'a> {
+        let accepted = self.tokens.contains(credentials.token);
+        Box::pin(async move {
+            if accepted {
+                Ok(())
+            } else {
+                Err(AuthError::Unauthenticated(
+                    "Invalid bearer token".to_string(),
+                ))
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Provider(Arc<dyn AuthProvider>);
+
+/// Authenticate the protected routes of `router` with `provider`
+pub fn with_auth<S>(router: Router<S>, provider: impl AuthProvider) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router.layer(Extension(Provider(Arc::new(provider))))
+}
+
+/// Middleware generated in front of protected routes: runs `next` only once the
+/// installed [`AuthProvider`] accepts the request's credentials for `tool`
+pub async fn require(
+    scheme: AuthScheme,
+    tool: &'static str,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(Provider(provider)) = request.extensions().get::<Provider>().cloned() else {
+        return error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            &ToolError::internal("No AuthProvider installed; add one with `with_auth`"),
+        );
+    };
+
+    let token = match scheme {
+        AuthScheme::Bearer => request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|token| token.trim().to_string()),
+    };
+    let Some(token) = token.filter(|token| !token.is_empty()) else {
+        return unauthorized("Missing bearer token");
+    };
+
+    let credentials = Credentials {
+        scheme,
+        token: &token,
+        tool,
+    };
+    match provider.authenticate(credentials).await {
+        Ok(()) => next.run(request).await,
+        Err(AuthError::Unauthenticated(message)) => unauthorized(message),
+        Err(AuthError::Forbidden(message)) => error_response(
+            StatusCode::FORBIDDEN,
+            &ToolError::new(ErrorCode::PermissionDenied, message),
+        ),
+    }
+}
+
+fn unauthorized(message: impl Into<String>) -> Response {
+    let error = ToolError::new(ErrorCode::PermissionDenied, message);
+    let mut response = error_response(StatusCode::UNAUTHORIZED, &error);
+    response.headers_mut().insert(
+        header::WWW_AUTHENTICATE,
+        header::HeaderValue::from_static("Bearer"),
+    );
+    response
+}
+
+/// The same `{"error", "code"}` body generated handlers respond with
+fn error_response(status: StatusCode, error: &ToolError) -> Response {
+    let body = serde_json::json!({
+        "error": error.to_string(),
+        "code": format!("{:?}", error.code),
+    });
+    (status, Json(body)).into_response()
+}
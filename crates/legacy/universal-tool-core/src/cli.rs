@@ -3,14 +3,38 @@
 //! This module provides utilities for CLI applications including output formatting,
 //! progress reporting, and interactive prompts.
 
+use crate::stream::Stream;
+use crate::stream::StreamExt;
 use serde::Serialize;
 use std::fmt;
+use std::io::Write;
 use std::str::FromStr;
 
 // Re-export clap and related types so users don't need to depend on them
 pub use clap;
 pub use clap_complete;
 
+/// Print each item of a streaming tool on its own line as it arrives
+///
+/// Strings are printed as-is and other values as compact JSON, one document per line.
+pub async fn print_stream<T, S>(stream: S) -> Result<(), crate::error::ToolError>
+where
+    T: Serialize,
+    S: Stream<Item = Result<T, crate::error::ToolError>>,
+{
+    futures::pin_mut!(stream);
+    let mut stdout = std::io::stdout();
+    while let Some(item) = stream.next().await {
+        let line = match serde_json::to_value(item?)? {
+            serde_json::Value::String(s) => s,
+            value => value.to_string(),
+        };
+        writeln!(stdout, "{line}")?;
+        stdout.flush()?;
+    }
+    Ok(())
+}
+
 /// Output format options for CLI commands
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OutputFormat {
@@ -0,0 +1,239 @@
+//! HTTP client runtime for the Universal Tool Framework
+//!
+//! With the `client` feature, `#[universal_tool_router(rest(client = "ItemsClient"))]`
+//! generates an `ItemsClient` with one async method per tool, taking the tool's
+//! parameters (minus injected ones) and returning its result. Requests follow the
+//! generated REST routes, and error responses are turned back into the `ToolError`
+//! the remote tool returned.
+//!
+//! Streaming tools are read to the end of their event stream and return all items.
+
+use crate::error::ErrorCode;
+use crate::error::ToolError;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+pub use reqwest;
+pub use reqwest::Method;
+
+/// Connection shared by the methods of a generated client
+#[derive(Debug, Clone)]
+pub struct RestClient {
+    http: reqwest::Client,
+    base_url: String,
+    bearer_token: Option<String>,
+}
+
+impl RestClient {
+    /// Client for the service at `base_url` (e.g. `http://localhost:3000`)
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::Client::new())
+    }
+
+    /// Client sending requests through `http`, e.g. to set timeouts or default headers
+    pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            bearer_token: None,
+        }
+    }
+
+    /// Send `Authorization: Bearer <token>` with every request, for `rest(auth = "bearer")` routes
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    /// Base URL requests are sent to
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Request to `path` (including any route prefix), relative to the base URL
+    pub fn request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self
+            .http
+            .request(method, format!("{}{path}", self.base_url));
+        match &self.bearer_token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    /// Send `request` and decode the JSON response
+    pub async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<T, ToolError> {
+        let response = self.execute(request).await?;
+        response.json::<T>().await.map_err(|e| {
+            ToolError::new(
+                ErrorCode::SerializationError,
+                format!("Invalid response body: {e}"),
+            )
+        })
+    }
+
+    /// Send `request` to a streaming tool and collect every item of its event stream
+    pub async fn send_stream<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<Vec<T>, ToolError> {
+        let response = self.execute(request).await?;
+        let text = response.text().await.map_err(network_error)?;
+        parse_events(&text)
+    }
+
+    async fn execute(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ToolError> {
+        let response = request.send().await.map_err(network_error)?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        Err(error_from_body(&body).unwrap_or_else(|| {
+            ToolError::new(
+                status_code(status),
+                format!("Request failed with status {status}"),
+            )
+        }))
+    }
+}
+
+/// JSON object body made of `(name, value)` fields
+pub fn json_object<const N: usize>(
+    fields: [(&str, serde_json::Result<Value>); N],
+) -> Result<Value, ToolError> {
+    let mut object = serde_json::Map::new();
+    for (name, value) in fields {
+        let value = value.map_err(|e| {
+            ToolError::new(
+                ErrorCode::SerializationError,
+                format!("Failed to serialize {name}: {e}"),
+            )
+        })?;
+        object.insert(name.to_string(), value);
+    }
+    Ok(Value::Object(object))
+}
+
+/// Path segment for a path parameter: strings as-is, other values as JSON, percent-encoded
+pub fn path_segment<T: Serialize + ?Sized>(value: &T) -> Result<String, ToolError> {
+    let text = match serde_json::to_value(value)? {
+        Value::String(s) => s,
+        other => other.to_string(),
+    };
+    Ok(percent_encode(&text))
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// The `{"error": "[Code] message", "code": "Code"}` body generated handlers respond with
+fn error_from_body(body: &str) -> Option<ToolError> {
+    let value: Value = serde_json::from_str(body).ok()?;
+    let code: ErrorCode = serde_json::from_value(value.get("code")?.clone()).ok()?;
+    let error = value.get("error")?.as_str()?;
+    let prefix = format!("[{code:?}] ");
+    let message = error.strip_prefix(&prefix).unwrap_or(error);
+    Some(ToolError::new(code, message))
+}
+
+fn status_code(status: reqwest::StatusCode) -> ErrorCode {
+    match status.as_u16() {
+        400 | 422 => ErrorCode::BadRequest,
+        401 | 403 => ErrorCode::PermissionDenied,
+        404 => ErrorCode::NotFound,
+        408 => ErrorCode::Timeout,
+        409 => ErrorCode::Conflict,
+        _ => ErrorCode::ExternalServiceError,
+    }
+}
+
+fn network_error(err: reqwest::Error) -> ToolError {
+    let code = if err.is_timeout() {
+        ErrorCode::Timeout
+    } else {
+        ErrorCode::NetworkError
+    };
+    ToolError::new(code, err.to_string())
+}
+
+/// Items of a server-sent events body: `data` events are items, an `error` event
+/// carries the tool's error
+fn parse_events<T: DeserializeOwned>(text: &str) -> Result<Vec<T>, ToolError> {
+    let mut items = Vec::new();
+    for block in text.split("\n\n") {
+        let mut event = "message";
+        let mut data = Vec::new();
+        for line in block.lines() {
+            if let Some(name) = line.strip_prefix("event:") {
+                event = name.trim();
+            } else if let Some(value) = line.strip_prefix("data:") {
+                data.push(value.strip_prefix(' ').unwrap_or(value));
+            }
+        }
+        if data.is_empty() {
+            continue;
+        }
+        let data = data.join("\n");
+        if event == "error" {
+            return Err(error_from_body(&data)
+                .unwrap_or_else(|| ToolError::new(ErrorCode::ExternalServiceError, data)));
+        }
+        items.push(serde_json::from_str(&data).map_err(|e| {
+            ToolError::new(
+                ErrorCode::SerializationError,
+                format!("Invalid stream item: {e}"),
+            )
+        })?);
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_body_round_trips() {
+        let err =
+            error_from_body(r#"{"error": "[NotFound] No item 7", "code": "NotFound"}"#).unwrap();
+        assert_eq!(err.code, ErrorCode::NotFound);
+        assert_eq!(err.message, "No item 7");
+        assert!(error_from_body("not json").is_none());
+    }
+
+    #[test]
+    fn events_are_parsed() {
+        let items: Vec<u32> = parse_events("data: 1\n\n: keep-alive\n\ndata: 2\n\n").unwrap();
+        assert_eq!(items, [1, 2]);
+
+        let err = parse_events::<u32>(
+            "data: 1\n\nevent: error\ndata: {\"error\": \"[Timeout] slow\", \"code\": \"Timeout\"}\n\n",
+        )
+        .unwrap_err();
+        assert_eq!(err.code, ErrorCode::Timeout);
+        assert_eq!(err.message, "slow");
+    }
+
+    #[test]
+    fn path_segments_are_encoded() {
+        assert_eq!(path_segment("a b/c").unwrap(), "a%20b%2Fc");
+        assert_eq!(path_segment(&42u32).unwrap(), "42");
+    }
+}
@@ -0,0 +1,78 @@
+//! Parameter defaults for the Universal Tool Framework
+//!
+//! `#[universal_tool_param(default = "...", env = "...")]` applies to every interface;
+//! `cli(...)`, `mcp(...)` and `rest(...)` override them per interface, and `skip` hides
+//! the parameter from that interface entirely:
+//!
+//! ```ignore
+//! #[universal_tool_param(mcp(skip, env = "API_TOKEN"), cli(default = "dev"))]
+//! token: String,
+//! ```
+//!
+//! The CLI hands defaults to clap. MCP and REST fill a missing (or skipped) parameter
+//! with [`fill`], which reads the environment variable first and then the default.
+
+use crate::error::ToolError;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// Fill parameter `name` from environment variable `env`, falling back to `default`.
+///
+/// With neither set, `Option` parameters are `None` and others are a missing
+/// parameter error.
+pub fn fill<T: DeserializeOwned>(
+    name: &str,
+    env: Option<&str>,
+    default: Option<&str>,
+) -> Result<T, ToolError> {
+    let raw = env
+        .and_then(|var| std::env::var(var).ok())
+        .or_else(|| default.map(str::to_string));
+
+    match raw {
+        Some(raw) => parse(name, &raw),
+        None => serde_json::from_value(Value::Null)
+            .map_err(|_| ToolError::invalid_input(format!("Missing required parameter: {name}"))),
+    }
+}
+
+/// Parse a default or environment value: as JSON if it is valid JSON for `T`,
+/// otherwise as a plain string.
+pub fn parse<T: DeserializeOwned>(name: &str, raw: &str) -> Result<T, ToolError> {
+    serde_json::from_str(raw)
+        .or_else(|_| serde_json::from_value(Value::String(raw.to_string())))
+        .map_err(|e| ToolError::invalid_input(format!("Invalid value for {name}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCode;
+
+    #[test]
+    fn fills_from_default() {
+        assert_eq!(fill::<u32>("limit", None, Some("10")).unwrap(), 10);
+        assert_eq!(fill::<String>("name", None, Some("42")).unwrap(), "42");
+        assert_eq!(
+            fill::<Vec<String>>("tags", None, Some(r#"["a","b"]"#)).unwrap(),
+            vec!["a", "b"]
+        );
+        assert_eq!(fill::<Option<u32>>("limit", None, None).unwrap(), None);
+
+        let err = fill::<u32>("limit", None, None).unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidArgument);
+        assert_eq!(err.message, "Missing required parameter: limit");
+        assert!(fill::<u32>("limit", None, Some("ten")).is_err());
+    }
+
+    #[test]
+    fn env_takes_precedence_over_default() {
+        // SAFETY: the variable is unique to this test
+        unsafe { std::env::set_var("UTF_DEFAULTS_TEST_TOKEN", "from-env") };
+        let token: String = fill("token", Some("UTF_DEFAULTS_TEST_TOKEN"), Some("dev")).unwrap();
+        assert_eq!(token, "from-env");
+
+        let token: String = fill("token", Some("UTF_DEFAULTS_TEST_UNSET"), Some("dev")).unwrap();
+        assert_eq!(token, "dev");
+    }
+}
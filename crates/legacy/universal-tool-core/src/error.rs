@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use thiserror::Error;
 
 /// The primary error type that all tool functions must return in their Result.
-#[derive(Debug, Error, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Error, Serialize, Deserialize, JsonSchema)]
 pub struct ToolError {
     /// A machine-readable code for the error category.
     pub code: ErrorCode,
@@ -0,0 +1,40 @@
+//! Router-level global CLI flags for the Universal Tool Framework
+//!
+//! `#[universal_tool_router(cli(global_flags("json", "verbose")))]` adds `--json` and
+//! `--verbose` to every command. Tools read them through a `GlobalFlags` parameter,
+//! which the generated CLI fills from the parsed arguments (including the standard
+//! `--dry-run`/`--yes`/`--quiet`/`--verbose` args when enabled). MCP dispatch takes it
+//! from the `ToolState` if present; otherwise, and in REST, no flag is set.
+
+use std::collections::BTreeMap;
+
+/// Global flags given on the command line, by name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlobalFlags {
+    counts: BTreeMap<String, u8>,
+}
+
+impl GlobalFlags {
+    /// No flags set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `name` was given `count` times
+    pub fn with(mut self, name: &str, count: u8) -> Self {
+        if count > 0 {
+            self.counts.insert(name.to_string(), count);
+        }
+        self
+    }
+
+    /// Whether `--name` was given
+    pub fn is_set(&self, name: &str) -> bool {
+        self.count(name) > 0
+    }
+
+    /// How many times `--name` was given (e.g. `-vvv` is 3)
+    pub fn count(&self, name: &str) -> u8 {
+        self.counts.get(name).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,74 @@
+//! Dispatch hooks for the Universal Tool Framework
+//!
+//! `#[universal_tool_router(hooks)]` makes the generated CLI, REST, MCP and WebSocket adapters
+//! call the router's [`ToolHooks`] implementation around every tool invocation, so
+//! logging and metrics live in one place instead of in each tool method:
+//!
+//! ```ignore
+//! impl ToolHooks for MyTools {
+//!     fn after_call(&self, call: &ToolCall, duration: Duration, error: Option<&ToolError>) {
+//!         eprintln!("{} via {:?} took {duration:?}", call.tool, call.interface);
+//!     }
+//! }
+//! ```
+//!
+//! Hooks run after parameter extraction and validation, so `args` holds the values
+//! the tool receives. With `hooks` set, parameter types must implement `Serialize`.
+
+use crate::error::ToolError;
+use serde_json::Map;
+use serde_json::Value;
+use std::time::Duration;
+
+/// The interface a tool call came through
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interface {
+    Cli,
+    Rest,
+    Mcp,
+    Ws,
+}
+
+/// A tool invocation, as seen by [`ToolHooks`]
+#[derive(Debug, Clone)]
+pub struct ToolCall {
+    /// Tool name
+    pub tool: &'static str,
+    /// Interface the call came through
+    pub interface: Interface,
+    /// Parameter values by name, as JSON; `null` if one failed to serialize
+    pub args: Value,
+}
+
+impl ToolCall {
+    /// Build a call from serialized parameters
+    pub fn new<const N: usize>(
+        tool: &'static str,
+        interface: Interface,
+        args: [(&str, Result<Value, serde_json::Error>); N],
+    ) -> Self {
+        let args = args
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.unwrap_or_default()))
+            .collect::<Map<_, _>>();
+        Self {
+            tool,
+            interface,
+            args: Value::Object(args),
+        }
+    }
+}
+
+/// Callbacks around generated tool dispatch; both default to doing nothing
+pub trait ToolHooks {
+    /// Called before the tool method runs
+    fn before_call(&self, call: &ToolCall) {
+        let _ = call;
+    }
+
+    /// Called once the tool method (or, for streaming tools, its stream) finishes,
+    /// with how long it took and the error it failed with, if any
+    fn after_call(&self, call: &ToolCall, duration: Duration, error: Option<&ToolError>) {
+        let _ = (call, duration, error);
+    }
+}
@@ -5,6 +5,24 @@ pub mod error;
 pub use schemars::JsonSchema;
 pub use schemars::{self};
 
+// Streaming tool support, shared by all interfaces
+pub mod stream;
+
+// Shared state injected into `from_state` tool parameters
+pub mod state;
+
+// Parameter constraint checks used by generated adapters
+pub mod validate;
+
+// Router-level global CLI flags passed to tools
+pub mod flags;
+
+// Per-interface parameter defaults filled by generated adapters
+pub mod defaults;
+
+// Hooks the generated adapters call around every tool invocation
+pub mod hooks;
+
 // CLI utilities module (only available with cli feature)
 // NOTE: We use #[cfg(feature = "cli")] to ensure CLI utilities are only available
 // when the CLI feature is enabled. This keeps the API clean and follows idiomatic
@@ -16,6 +34,22 @@ pub mod cli;
 #[cfg(feature = "rest")]
 pub mod rest;
 
+// Authentication for protected REST routes (only available with rest feature)
+#[cfg(feature = "rest")]
+pub mod auth;
+
+// JSON-RPC over WebSocket (only available with ws feature)
+#[cfg(feature = "ws")]
+pub mod ws;
+
+// OpenAPI generation helpers (only available with openapi feature)
+#[cfg(feature = "openapi")]
+pub mod openapi;
+
+// Runtime for generated REST clients (only available with client feature)
+#[cfg(feature = "client")]
+pub mod client;
+
 // MCP utilities module (only available with mcp feature)
 #[cfg(feature = "mcp")]
 pub mod mcp;
@@ -43,6 +77,20 @@ pub mod prelude {
     // Re-export JsonSchema for generated code
     pub use schemars::JsonSchema;
 
+    // Re-export the Stream trait for streaming tool return types
+    pub use crate::stream::Stream;
+
+    // Re-export the state carrier for `from_state` parameters
+    pub use crate::state::ToolState;
+
+    // Re-export global CLI flags for tools that read them
+    pub use crate::flags::GlobalFlags;
+
+    // Re-export the dispatch hooks for routers that set `hooks`
+    pub use crate::hooks::Interface;
+    pub use crate::hooks::ToolCall;
+    pub use crate::hooks::ToolHooks;
+
     // Re-export CLI utilities when the CLI feature is enabled
     #[cfg(feature = "cli")]
     pub use crate::cli::CliFormatter;
@@ -81,9 +129,11 @@ pub mod prelude {
         IntoCallToolResult,
         // Re-export commonly used values
         JsonValue,
+        McpCallContext,
         // MCP-specific types
         McpError,
         McpErrorData,
+        McpOutputFormat,
         McpProgressReporter,
         NumberOrString,
         ProgressNotification,
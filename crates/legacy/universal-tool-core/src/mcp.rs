@@ -163,8 +163,12 @@ pub trait ProgressReporter: Send + Sync {
 
 /// MCP-specific progress reporter that stores progress information
 /// Users need to implement their own notification sending mechanism
+///
+/// The default reporter has no token and discards every report; CLI and REST
+/// adapters pass it to tools taking an `McpProgressReporter` parameter.
+#[derive(Clone, Default)]
 pub struct McpProgressReporter {
-    progress_token: ProgressToken,
+    progress_token: Option<ProgressToken>,
     /// Channel for sending progress updates to the MCP server implementation
     sender: Option<tokio::sync::mpsc::Sender<ProgressNotification>>,
 }
@@ -173,7 +177,7 @@ impl McpProgressReporter {
     /// Create a new progress reporter with a token
     pub fn new(progress_token: ProgressToken) -> Self {
         Self {
-            progress_token,
+            progress_token: Some(progress_token),
             sender: None,
         }
     }
@@ -184,10 +188,15 @@ impl McpProgressReporter {
         sender: tokio::sync::mpsc::Sender<ProgressNotification>,
     ) -> Self {
         Self {
-            progress_token,
+            progress_token: Some(progress_token),
             sender: Some(sender),
         }
     }
+
+    /// Whether reports reach a client
+    pub fn is_enabled(&self) -> bool {
+        self.progress_token.is_some() && self.sender.is_some()
+    }
 }
 
 #[async_trait::async_trait]
@@ -198,8 +207,11 @@ impl ProgressReporter for McpProgressReporter {
         total: Option<u32>,
         message: Option<String>,
     ) -> Result<(), ToolError> {
+        let Some(progress_token) = self.progress_token.clone() else {
+            return Ok(());
+        };
         let notification = ProgressNotification {
-            progress_token: self.progress_token.clone(),
+            progress_token,
             progress: current,
             total,
             message,
@@ -223,6 +235,90 @@ impl ProgressReporter for McpProgressReporter {
 /// Re-export CancellationToken for convenience
 pub use tokio_util::sync::CancellationToken;
 
+/// Per-call MCP context injected into `McpProgressReporter` and `CancellationToken`
+/// tool parameters
+///
+/// Generated MCP dispatch looks it up in the [`ToolState`](crate::state::ToolState)
+/// passed to `handle_mcp_call_with_state` / `handle_mcp_call_mcp_with_state`:
+///
+/// ```ignore
+/// let state = ToolState::new().with(McpCallContext::from_request(&context));
+/// tools.handle_mcp_call_mcp_with_state(&request.name, args, &state).await
+/// ```
+///
+/// Without one, tools get a no-op reporter and a token that is never cancelled,
+/// which is also what the CLI and REST adapters pass.
+#[derive(Clone, Default)]
+pub struct McpCallContext {
+    /// Reporter for the request's progress token; `None` when the client did not ask
+    /// for progress
+    pub progress: Option<McpProgressReporter>,
+    /// Cancelled when the client cancels the request
+    pub cancellation: CancellationToken,
+    /// Output format the client asked for; only `output = "both"` tools honor it
+    pub output: Option<McpOutputFormat>,
+}
+
+impl McpCallContext {
+    /// Context with the given cancellation token and no progress reporting
+    pub fn new(cancellation: CancellationToken) -> Self {
+        Self {
+            progress: None,
+            cancellation,
+            output: None,
+        }
+    }
+
+    /// Report progress through `reporter`
+    pub fn with_progress(mut self, reporter: McpProgressReporter) -> Self {
+        self.progress = Some(reporter);
+        self
+    }
+
+    /// Render results in `format` instead of the tool's default
+    pub fn with_output(mut self, format: McpOutputFormat) -> Self {
+        self.output = Some(format);
+        self
+    }
+
+    /// Context for an rmcp request: its cancellation token, the `outputFormat` from
+    /// the request's `_meta`, and, when the client sent a progress token, a reporter
+    /// forwarding reports as progress notifications
+    pub fn from_request(context: &RequestContext<RoleServer>) -> Self {
+        let mut ctx = Self::new(context.ct.clone());
+        ctx.output = context
+            .meta
+            .get(OUTPUT_FORMAT_META)
+            .and_then(Value::as_str)
+            .and_then(McpOutputFormat::parse);
+        // rmcp's token and ours share a wire format
+        let token = context
+            .meta
+            .get_progress_token()
+            .and_then(|token| serde_json::to_value(token).ok())
+            .and_then(|token| serde_json::from_value::<ProgressToken>(token).ok());
+        let Some(token) = token else {
+            return ctx;
+        };
+
+        let (sender, mut receiver) = tokio::sync::mpsc::channel::<ProgressNotification>(16);
+        let peer = context.peer.clone();
+        tokio::spawn(async move {
+            while let Some(notification) = receiver.recv().await {
+                let Ok(param) = serde_json::to_value(&notification)
+                    .and_then(serde_json::from_value::<model::ProgressNotificationParam>)
+                else {
+                    continue;
+                };
+                if peer.notify_progress(param).await.is_err() {
+                    break;
+                }
+            }
+        });
+        ctx.with_progress(McpProgressReporter::with_sender(token, sender))
+    }
+}
+
 /// Number or string type for MCP
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Hash, Eq)]
 #[serde(untagged)]
@@ -301,10 +397,60 @@ pub trait McpFormatter {
     fn mcp_format_text(&self) -> String;
 }
 
-/// Output carrier for MCP tools. Tools can return either text or JSON.
+/// `_meta` key a client sets to choose the output format of an `output = "both"` tool
+pub const OUTPUT_FORMAT_META: &str = "outputFormat";
+
+/// Shape of an MCP tool result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpOutputFormat {
+    /// `McpFormatter` text only
+    Text,
+    /// JSON only
+    Json,
+    /// `McpFormatter` text as content plus the JSON as structured content
+    Both,
+}
+
+impl McpOutputFormat {
+    /// Parse `"text"`, `"json"` or `"both"`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "both" => Some(Self::Both),
+            _ => None,
+        }
+    }
+}
+
+/// Output carrier for MCP tools. Tools can return text, JSON, or both.
 pub enum McpOutput {
     Text(String),
     Json(Value),
+    /// Text content alongside structured content, per the MCP spec
+    Both {
+        text: String,
+        structured: Value,
+    },
+    /// One text content entry per item of a streaming tool
+    Chunks(Vec<String>),
+}
+
+impl McpOutput {
+    /// Render `result` in `format`
+    pub fn render<T>(result: &T, format: McpOutputFormat) -> Result<Self, ToolError>
+    where
+        T: McpFormatter + Serialize,
+    {
+        Ok(match format {
+            McpOutputFormat::Text => McpOutput::Text(result.mcp_format_text()),
+            McpOutputFormat::Json => McpOutput::Json(serde_json::to_value(result)?),
+            McpOutputFormat::Both => McpOutput::Both {
+                text: result.mcp_format_text(),
+                structured: serde_json::to_value(result)?,
+            },
+        })
+    }
 }
 
 impl From<String> for McpOutput {
@@ -335,6 +481,14 @@ impl IntoCallToolResult for McpOutput {
                     serde_json::to_string_pretty(&value).unwrap_or_else(|_| "{}".to_string());
                 CallToolResult::success(vec![Content::text(pretty)])
             }
+            McpOutput::Both { text, structured } => {
+                let mut result = CallToolResult::success(vec![Content::text(text)]);
+                result.structured_content = Some(structured);
+                result
+            }
+            McpOutput::Chunks(chunks) => {
+                CallToolResult::success(chunks.into_iter().map(Content::text).collect())
+            }
         }
     }
 }
@@ -625,6 +779,32 @@ mod tests {
         assert!(content_str.contains("hello"));
     }
 
+    #[test]
+    fn mcp_output_chunks_to_result() {
+        let r = McpOutput::Chunks(vec!["one".into(), "two".into()]).into_call_tool_result();
+        assert_eq!(r.content.len(), 2);
+        assert!(format!("{:?}", r.content[1]).contains("two"));
+    }
+
+    #[test]
+    fn mcp_output_both_to_result() {
+        let r = McpOutput::Both {
+            text: "k = v".into(),
+            structured: serde_json::json!({"k": "v"}),
+        }
+        .into_call_tool_result();
+        assert_eq!(r.content.len(), 1);
+        assert!(format!("{:?}", r.content[0]).contains("k = v"));
+        assert_eq!(r.structured_content, Some(serde_json::json!({"k": "v"})));
+    }
+
+    #[test]
+    fn mcp_output_format_parses() {
+        assert_eq!(McpOutputFormat::parse("both"), Some(McpOutputFormat::Both));
+        assert_eq!(McpOutputFormat::parse("text"), Some(McpOutputFormat::Text));
+        assert_eq!(McpOutputFormat::parse("yaml"), None);
+    }
+
     #[test]
     fn mcp_output_json_to_result() {
         let val = serde_json::json!({"k": "v"});
@@ -0,0 +1,227 @@
+//! OpenAPI document generation for the Universal Tool Framework
+//!
+//! With the `openapi` feature, REST routers get an `openapi()` method describing every
+//! route from `#[universal_tool]` descriptions and parameter docs. Schemas come from the
+//! types' `JsonSchema` implementations, so no utoipa annotations are needed.
+//!
+//! The helpers here are what the generated code calls; they can also be used to extend
+//! a generated document by hand.
+
+use crate::error::ToolError;
+use crate::validate::Constraints;
+use schemars::JsonSchema;
+use utoipa::openapi::ComponentsBuilder;
+use utoipa::openapi::ContentBuilder;
+use utoipa::openapi::InfoBuilder;
+use utoipa::openapi::ObjectBuilder;
+use utoipa::openapi::OpenApiBuilder;
+use utoipa::openapi::PathItem;
+use utoipa::openapi::PathsBuilder;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::Required;
+use utoipa::openapi::ResponseBuilder;
+use utoipa::openapi::Schema;
+use utoipa::openapi::SchemaType;
+use utoipa::openapi::path::OperationBuilder;
+use utoipa::openapi::path::ParameterBuilder;
+use utoipa::openapi::request_body::RequestBodyBuilder;
+use utoipa::openapi::security::Http;
+use utoipa::openapi::security::HttpAuthScheme;
+use utoipa::openapi::security::SecurityRequirement;
+use utoipa::openapi::security::SecurityScheme;
+
+pub use utoipa::openapi::OpenApi;
+pub use utoipa::openapi::path::Operation;
+pub use utoipa::openapi::path::ParameterIn;
+pub use utoipa::openapi::path::PathItemType;
+pub use utoipa::openapi::request_body::RequestBody;
+
+const JSON: &str = "application/json";
+const EVENT_STREAM: &str = "text/event-stream";
+/// Security scheme name for `rest(auth = "bearer")` routes
+const BEARER_AUTH: &str = "bearerAuth";
+
+/// OpenAPI 3.0 schema of `T`, with subschemas inlined
+pub fn schema_for<T: JsonSchema>(description: Option<&str>) -> RefOr<Schema> {
+    constrained_schema_for::<T>(description, Constraints::default())
+}
+
+/// [`schema_for`] with a parameter's validation constraints applied
+pub fn constrained_schema_for<T: JsonSchema>(
+    description: Option<&str>,
+    constraints: Constraints,
+) -> RefOr<Schema> {
+    let mut settings = schemars::r#gen::SchemaSettings::openapi3();
+    settings.inline_subschemas = true;
+    let root = settings.into_generator().into_root_schema_for::<T>();
+
+    let mut value = serde_json::to_value(root.schema).unwrap_or_default();
+    if let (Some(description), Some(map)) = (description, value.as_object_mut()) {
+        map.insert("description".to_string(), description.into());
+    }
+    constraints.apply_to_schema(&mut value);
+    // Shapes utoipa cannot represent are documented as any JSON value
+    serde_json::from_value(value).unwrap_or_else(|_| {
+        ObjectBuilder::new()
+            .schema_type(SchemaType::Value)
+            .description(description)
+            .into()
+    })
+}
+
+/// Operation for a tool, with the error responses every generated handler can return
+pub fn operation(tool_name: &str, description: &str, tag: Option<&str>) -> OperationBuilder {
+    let mut builder = OperationBuilder::new()
+        .operation_id(Some(tool_name))
+        .summary(Some(description))
+        .response("400", error_response("Invalid request"))
+        .response("404", error_response("Not found"))
+        .response("500", error_response("Internal error"));
+    if let Some(tag) = tag {
+        builder = builder.tag(tag);
+    }
+    builder
+}
+
+/// Mark an operation as requiring a bearer token, with its `401`/`403` responses
+pub fn bearer_auth(builder: OperationBuilder) -> OperationBuilder {
+    builder
+        .security(SecurityRequirement::new(BEARER_AUTH, Vec::<String>::new()))
+        .response("401", error_response("Missing or invalid bearer token"))
+        .response("403", error_response("Not allowed to call this tool"))
+}
+
+/// Path, query or header parameter of type `T`
+pub fn parameter<T: JsonSchema>(
+    name: &str,
+    location: ParameterIn,
+    required: bool,
+    description: Option<&str>,
+    constraints: Constraints,
+) -> utoipa::openapi::path::Parameter {
+    ParameterBuilder::new()
+        .name(name)
+        .parameter_in(location)
+        .required(if required {
+            Required::True
+        } else {
+            Required::False
+        })
+        .description(description)
+        .schema(Some(constrained_schema_for::<T>(None, constraints)))
+        .build()
+}
+
+/// Body property of type `T`: `(name, schema, required)`
+pub fn property<T: JsonSchema>(
+    name: &str,
+    required: bool,
+    description: Option<&str>,
+    constraints: Constraints,
+) -> (String, RefOr<Schema>, bool) {
+    (
+        name.to_string(),
+        constrained_schema_for::<T>(description, constraints),
+        required,
+    )
+}
+
+/// JSON object request body made of `properties`
+pub fn json_body(properties: Vec<(String, RefOr<Schema>, bool)>) -> RequestBody {
+    let mut object = ObjectBuilder::new();
+    for (name, schema, required) in properties {
+        if required {
+            object = object.required(&name);
+        }
+        object = object.property(name, schema);
+    }
+    RequestBodyBuilder::new()
+        .content(JSON, ContentBuilder::new().schema(object).build())
+        .required(Some(Required::True))
+        .build()
+}
+
+/// Successful JSON response carrying a `T`
+pub fn json_response<T: JsonSchema>(description: &str) -> utoipa::openapi::Response {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            JSON,
+            ContentBuilder::new().schema(schema_for::<T>(None)).build(),
+        )
+        .build()
+}
+
+/// Server-sent events response whose `data` events each carry a `T`
+pub fn sse_response<T: JsonSchema>(description: &str) -> utoipa::openapi::Response {
+    ResponseBuilder::new()
+        .description(description)
+        .content(
+            EVENT_STREAM,
+            ContentBuilder::new().schema(schema_for::<T>(None)).build(),
+        )
+        .build()
+}
+
+fn error_response(description: &str) -> utoipa::openapi::Response {
+    json_response::<ToolError>(description)
+}
+
+/// Document titled `title` with one operation per `(path, method, operation)`
+///
+/// Paths use axum's `:param` syntax and are rewritten to OpenAPI's `{param}`.
+pub fn document(
+    title: &str,
+    version: &str,
+    operations: Vec<(String, PathItemType, Operation)>,
+) -> OpenApi {
+    let secured = operations.iter().any(|(_, _, op)| op.security.is_some());
+    let mut paths = PathsBuilder::new();
+    for (path, method, operation) in operations {
+        paths = paths.path(openapi_path(&path), PathItem::new(method, operation));
+    }
+    let mut builder = OpenApiBuilder::new()
+        .info(InfoBuilder::new().title(title).version(version))
+        .paths(paths);
+    if secured {
+        builder = builder.components(Some(
+            ComponentsBuilder::new()
+                .security_scheme(
+                    BEARER_AUTH,
+                    SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+                )
+                .build(),
+        ));
+    }
+    builder.build()
+}
+
+/// `/items/:id` -> `/items/{id}`
+fn openapi_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(name) => format!("{{{name}}}"),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openapi_path_rewrites_params() {
+        assert_eq!(openapi_path("/api/items/:id"), "/api/items/{id}");
+        assert_eq!(openapi_path("/api/items"), "/api/items");
+    }
+
+    #[test]
+    fn schema_for_uses_openapi3_nullability() {
+        let schema = serde_json::to_value(schema_for::<Option<u32>>(Some("Limit"))).unwrap();
+        assert_eq!(schema["type"], "integer");
+        assert_eq!(schema["nullable"], true);
+        assert_eq!(schema["description"], "Limit");
+    }
+}
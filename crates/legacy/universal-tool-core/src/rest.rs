@@ -25,3 +25,71 @@ pub use axum::extract::State;
 pub use axum::http::StatusCode;
 pub use axum::response::IntoResponse;
 pub use axum::response::Response;
+pub use axum::response::sse::Event;
+pub use axum::response::sse::KeepAlive;
+pub use axum::response::sse::Sse;
+
+use crate::error::ToolError;
+use crate::stream::Stream;
+use crate::stream::StreamExt;
+use std::future::Future;
+use tokio::sync::mpsc;
+
+/// Items buffered between a streaming tool and its SSE response
+const SSE_BUFFER: usize = 16;
+
+/// Feeds a streaming tool's items to the response built by [`sse_stream`]
+pub struct StreamSender<T>(mpsc::Sender<Result<T, ToolError>>);
+
+impl<T> StreamSender<T> {
+    /// Forward every item of `stream`, stopping after an error or once the client disconnects
+    ///
+    /// Returns the error that ended the stream, if any.
+    pub async fn forward<S>(&self, stream: S) -> Result<(), ToolError>
+    where
+        S: Stream<Item = Result<T, ToolError>>,
+    {
+        futures::pin_mut!(stream);
+        while let Some(item) = stream.next().await {
+            let error = item.as_ref().err().cloned();
+            if self.0.send(item).await.is_err() {
+                break;
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Respond with server-sent events produced by `run`, which is spawned onto the runtime
+///
+/// Each item is sent as a `data` event holding its JSON. An error is sent as an `error`
+/// event with `error` and `code` fields, the same body as non-streaming error responses,
+/// and ends the stream.
+pub fn sse_stream<T, F, Fut>(run: F) -> Response
+where
+    T: serde::Serialize + Send + 'static,
+    F: FnOnce(StreamSender<T>) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(SSE_BUFFER);
+    tokio::spawn(run(StreamSender(tx)));
+
+    let events = futures::stream::unfold(rx, |mut rx| async move {
+        let event = match rx.recv().await? {
+            Ok(item) => Event::default().json_data(item),
+            Err(e) => Event::default()
+                .event("error")
+                .json_data(serde_json::json!({
+                    "error": e.to_string(),
+                    "code": format!("{:?}", e.code),
+                })),
+        };
+        Some((event, rx))
+    });
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
@@ -0,0 +1,76 @@
+//! Shared state injection for the Universal Tool Framework
+//!
+//! Tool parameters marked `#[universal_tool_param(from_state)]` are not read from the
+//! request. REST handlers take them from axum request extensions, so routers provide
+//! them with `.layer(Extension(value))` (or per request from middleware). CLI and MCP
+//! dispatch takes them from a [`ToolState`] passed to `execute_cli_with_state`,
+//! `handle_mcp_call_with_state` or `handle_mcp_call_mcp_with_state`.
+
+use crate::error::ErrorCode;
+use crate::error::ToolError;
+use std::any::Any;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Values injected into `from_state` tool parameters, keyed by type
+#[derive(Clone, Default)]
+pub struct ToolState {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl ToolState {
+    /// Create an empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `value`, replacing any previous value of the same type
+    pub fn with<T: Clone + Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.insert(value);
+        self
+    }
+
+    /// Insert `value`, replacing any previous value of the same type
+    pub fn insert<T: Clone + Send + Sync + 'static>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Clone out the value of type `T`
+    pub fn get<T: Clone + 'static>(&self) -> Result<T, ToolError> {
+        self.values
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+            .cloned()
+            .ok_or_else(|| {
+                ToolError::new(
+                    ErrorCode::Internal,
+                    format!("No state of type {} provided", std::any::type_name::<T>()),
+                )
+            })
+    }
+}
+
+impl std::fmt::Debug for ToolState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToolState")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_returns_value_by_type() {
+        let state = ToolState::new().with(7u32).with("pool".to_string());
+        assert_eq!(state.get::<u32>().unwrap(), 7);
+        assert_eq!(state.get::<String>().unwrap(), "pool");
+
+        let err = state.get::<i64>().unwrap_err();
+        assert_eq!(err.code, ErrorCode::Internal);
+        assert!(err.message.contains("i64"));
+    }
+}
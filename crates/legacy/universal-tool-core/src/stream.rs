@@ -0,0 +1,48 @@
+//! Streaming tool support for the Universal Tool Framework
+//!
+//! Tool methods may return `impl Stream<Item = Result<T, ToolError>>` instead of
+//! `Result<T, ToolError>` to produce output incrementally. The generated CLI prints
+//! each item as it arrives, REST endpoints respond with server-sent events, and MCP
+//! calls answer with one content chunk per item.
+
+use crate::error::ToolError;
+
+// Re-export the stream building blocks tools need to produce their output
+pub use futures::stream::BoxStream;
+pub use futures::stream::Stream;
+pub use futures::stream::StreamExt;
+pub use futures::stream::iter;
+pub use futures::stream::once;
+pub use futures::stream::unfold;
+
+/// Collect every item of a streaming tool, stopping at the first error
+pub async fn try_collect<T, S>(stream: S) -> Result<Vec<T>, ToolError>
+where
+    S: Stream<Item = Result<T, ToolError>>,
+{
+    futures::pin_mut!(stream);
+    let mut items = Vec::new();
+    while let Some(item) = stream.next().await {
+        items.push(item?);
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCode;
+
+    #[tokio::test]
+    async fn try_collect_stops_at_first_error() {
+        let ok = iter(vec![Ok(1), Ok(2)]);
+        assert_eq!(try_collect(ok).await.unwrap(), vec![1, 2]);
+
+        let failing = iter(vec![
+            Ok(1),
+            Err(ToolError::new(ErrorCode::Internal, "boom")),
+            Ok(3),
+        ]);
+        assert_eq!(try_collect(failing).await.unwrap_err().message, "boom");
+    }
+}
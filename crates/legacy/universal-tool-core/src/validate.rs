@@ -0,0 +1,258 @@
+//! Parameter constraints for the Universal Tool Framework
+//!
+//! `#[universal_tool_param(min = 1, max = 100, pattern = "...", non_empty)]` constraints
+//! are checked by the generated CLI, REST and MCP adapters before the tool method runs,
+//! and reflected into the parameter's JSON Schema. A failing check is an
+//! `InvalidArgument` error naming the parameter.
+//!
+//! `Option` parameters are only checked when present.
+
+use crate::error::ToolError;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+/// Values `min` and `max` apply to
+pub trait Number {
+    /// The value as `f64`, or `None` if absent
+    fn to_f64(&self) -> Option<f64>;
+}
+
+/// Values `pattern` applies to
+pub trait Text {
+    /// The value as text, or `None` if absent
+    fn text(&self) -> Option<&str>;
+}
+
+/// Values `non_empty` applies to
+pub trait Length {
+    /// Number of characters or items, or `None` if absent
+    fn length(&self) -> Option<usize>;
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(impl Number for $ty {
+            #[allow(clippy::cast_lossless, clippy::cast_precision_loss)]
+            fn to_f64(&self) -> Option<f64> {
+                Some(*self as f64)
+            }
+        })*
+    };
+}
+
+impl_number!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl Text for String {
+    fn text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Text for str {
+    fn text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl Length for str {
+    fn length(&self) -> Option<usize> {
+        Some(self.chars().count())
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V, S> Length for HashMap<K, V, S> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<K, V> Length for BTreeMap<K, V> {
+    fn length(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+impl<T: Number> Number for Option<T> {
+    fn to_f64(&self) -> Option<f64> {
+        self.as_ref().and_then(Number::to_f64)
+    }
+}
+
+impl<T: Text> Text for Option<T> {
+    fn text(&self) -> Option<&str> {
+        self.as_ref().and_then(Text::text)
+    }
+}
+
+impl<T: Length> Length for Option<T> {
+    fn length(&self) -> Option<usize> {
+        self.as_ref().and_then(Length::length)
+    }
+}
+
+/// Check that `value` is at least `bound`
+pub fn min<T: Number + ?Sized>(name: &str, value: &T, bound: f64) -> Result<(), ToolError> {
+    match value.to_f64() {
+        Some(v) if v < bound => Err(ToolError::invalid_input(format!(
+            "{name} must be at least {bound}, got {v}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check that `value` is at most `bound`
+pub fn max<T: Number + ?Sized>(name: &str, value: &T, bound: f64) -> Result<(), ToolError> {
+    match value.to_f64() {
+        Some(v) if v > bound => Err(ToolError::invalid_input(format!(
+            "{name} must be at most {bound}, got {v}"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check that `value` has at least one character or item
+pub fn non_empty<T: Length + ?Sized>(name: &str, value: &T) -> Result<(), ToolError> {
+    match value.length() {
+        Some(0) => Err(ToolError::invalid_input(format!(
+            "{name} must not be empty"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Check that `value` matches the regular expression `pattern`
+pub fn pattern<T: Text + ?Sized>(
+    name: &str,
+    value: &T,
+    pattern: &'static str,
+) -> Result<(), ToolError> {
+    let Some(text) = value.text() else {
+        return Ok(());
+    };
+    if compiled(pattern)?.is_match(text) {
+        Ok(())
+    } else {
+        Err(ToolError::invalid_input(format!(
+            "{name} must match the pattern {pattern}"
+        )))
+    }
+}
+
+/// Compile `pattern` once per process
+fn compiled(pattern: &'static str) -> Result<Regex, ToolError> {
+    static CACHE: OnceLock<Mutex<HashMap<&'static str, Regex>>> = OnceLock::new();
+    let mut cache = CACHE
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(regex) = cache.get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern)
+        .map_err(|e| ToolError::internal(format!("Invalid parameter pattern {pattern}: {e}")))?;
+    cache.insert(pattern, regex.clone());
+    Ok(regex)
+}
+
+/// Constraints of one parameter, as reflected into its JSON Schema
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Constraints {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub pattern: Option<&'static str>,
+    pub non_empty: bool,
+}
+
+impl Constraints {
+    /// Add `minimum`, `maximum`, `pattern` and `minLength`/`minItems` to `schema`
+    pub fn apply_to_schema(&self, schema: &mut Value) {
+        let is_array = schema_has_type(schema, "array");
+        let Value::Object(map) = schema else {
+            return;
+        };
+        if let Some(min) = self.min {
+            map.insert("minimum".to_string(), min.into());
+        }
+        if let Some(max) = self.max {
+            map.insert("maximum".to_string(), max.into());
+        }
+        if let Some(pattern) = self.pattern {
+            map.insert("pattern".to_string(), pattern.into());
+        }
+        if self.non_empty {
+            let key = if is_array { "minItems" } else { "minLength" };
+            map.insert(key.to_string(), 1.into());
+        }
+    }
+}
+
+/// Whether `schema`'s `type` is (or, for nullable draft-07 types, includes) `ty`
+fn schema_has_type(schema: &Value, ty: &str) -> bool {
+    match schema.get("type") {
+        Some(Value::String(t)) => t == ty,
+        Some(Value::Array(types)) => types.iter().any(|t| t == ty),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCode;
+
+    #[test]
+    fn checks_bounds_text_and_length() {
+        assert!(min("limit", &5u32, 1.0).is_ok());
+        assert_eq!(
+            min("limit", &0u32, 1.0).unwrap_err().message,
+            "limit must be at least 1, got 0"
+        );
+        assert!(max("limit", &Some(101i64), 100.0).is_err());
+        assert!(max("limit", &None::<i64>, 100.0).is_ok());
+
+        assert!(non_empty("name", "x").is_ok());
+        assert!(non_empty("name", &String::new()).is_err());
+        assert!(non_empty("tags", &Vec::<String>::new()).is_err());
+
+        assert!(pattern("id", &"abc-1".to_string(), "^[a-z]+-[0-9]$").is_ok());
+        let err = pattern("id", &"ABC".to_string(), "^[a-z]+$").unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidArgument);
+        assert_eq!(
+            pattern("id", "a", "(").unwrap_err().code,
+            ErrorCode::Internal
+        );
+    }
+
+    #[test]
+    fn constraints_extend_schema() {
+        let constraints = Constraints {
+            min: Some(1.0),
+            max: None,
+            pattern: None,
+            non_empty: true,
+        };
+        let mut schema = serde_json::json!({"type": ["array", "null"]});
+        constraints.apply_to_schema(&mut schema);
+        assert_eq!(schema["minimum"], 1.0);
+        assert_eq!(schema["minItems"], 1);
+        assert!(schema.get("maximum").is_none());
+    }
+}
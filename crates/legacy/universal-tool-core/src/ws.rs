@@ -0,0 +1,251 @@
+//! WebSocket utilities for the Universal Tool Framework
+//!
+//! `#[universal_tool_router(ws(path = "/ws"))]` generates `create_ws_router()`, which
+//! serves the router's tools as JSON-RPC 2.0 over a WebSocket. Each text frame holds one
+//! request, and calls run concurrently, so responses may arrive out of order:
+//!
+//! ```text
+//! -> {"jsonrpc": "2.0", "id": 1, "method": "add", "params": {"a": 1, "b": 2}}
+//! <- {"jsonrpc": "2.0", "id": 1, "result": 3}
+//! ```
+//!
+//! A streaming tool pushes each item as a [`STREAM_ITEM`] notification carrying the
+//! request's id, then answers the request with `null` once the stream ends:
+//!
+//! ```text
+//! -> {"jsonrpc": "2.0", "id": 2, "method": "count", "params": {"to": 2}}
+//! <- {"jsonrpc": "2.0", "method": "stream.item", "params": {"id": 2, "item": 1}}
+//! <- {"jsonrpc": "2.0", "method": "stream.item", "params": {"id": 2, "item": 2}}
+//! <- {"jsonrpc": "2.0", "id": 2, "result": null}
+//! ```
+//!
+//! Errors carry the tool's [`ErrorCode`] as `error.data.code`. Requests without an
+//! `id` are notifications: the tool runs, but nothing is sent back.
+
+// Re-export the axum WebSocket types used by generated routers
+pub use axum::extract::ws::Message;
+pub use axum::extract::ws::WebSocket;
+pub use axum::extract::ws::WebSocketUpgrade;
+
+use crate::error::ErrorCode;
+use crate::error::ToolError;
+use crate::stream::Stream;
+use crate::stream::StreamExt;
+use futures::SinkExt;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+use std::future::Future;
+use tokio::sync::mpsc;
+
+/// Method of the notification carrying one item of a streaming tool
+pub const STREAM_ITEM: &str = "stream.item";
+
+/// JSON-RPC 2.0 error codes
+pub mod error_codes {
+    pub const PARSE_ERROR: i32 = -32700;
+    pub const INVALID_REQUEST: i32 = -32600;
+    pub const METHOD_NOT_FOUND: i32 = -32601;
+    pub const INVALID_PARAMS: i32 = -32602;
+    pub const INTERNAL_ERROR: i32 = -32603;
+    /// Any other tool failure; `error.data.code` says which
+    pub const TOOL_ERROR: i32 = -32000;
+}
+
+/// A JSON-RPC request frame
+#[derive(Debug, Clone, Deserialize)]
+pub struct WsRequest {
+    /// Request id, echoed in the response; absent for notifications
+    #[serde(default)]
+    pub id: Option<Value>,
+    /// Tool name
+    pub method: String,
+    /// Tool parameters, by name; an empty object if absent
+    #[serde(default = "empty_params")]
+    pub params: Value,
+}
+
+fn empty_params() -> Value {
+    Value::Object(serde_json::Map::new())
+}
+
+/// Sends frames back to the client of one call
+#[derive(Debug, Clone)]
+pub struct WsSink {
+    id: Value,
+    tx: mpsc::UnboundedSender<String>,
+}
+
+impl WsSink {
+    /// Push one item of a streaming tool to the client
+    pub fn push<T: Serialize>(&self, item: &T) -> Result<(), ToolError> {
+        let frame = notification(
+            STREAM_ITEM,
+            json!({ "id": self.id, "item": serde_json::to_value(item)? }),
+        );
+        // A closed channel means the client went away; the call is abandoned anyway
+        let _ = self.tx.send(frame);
+        Ok(())
+    }
+
+    /// Push every item of `stream`, stopping after an error or once the client disconnects
+    ///
+    /// Returns the error that ended the stream, if any.
+    pub async fn forward<T, S>(&self, stream: S) -> Result<(), ToolError>
+    where
+        T: Serialize,
+        S: Stream<Item = Result<T, ToolError>>,
+    {
+        futures::pin_mut!(stream);
+        while let Some(item) = stream.next().await {
+            self.push(&item?)?;
+            if self.tx.is_closed() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Serve JSON-RPC calls arriving on `socket` until the client disconnects
+///
+/// Calls to a name outside `methods` are answered with "method not found"; others are
+/// spawned onto the runtime and handed to `dispatch`.
+pub async fn serve<F, Fut>(socket: WebSocket, methods: &'static [&'static str], dispatch: F)
+where
+    F: Fn(String, Value, WsSink) -> Fut,
+    Fut: Future<Output = Result<Value, ToolError>> + Send + 'static,
+{
+    let (mut writer, mut reader) = socket.split();
+    let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+    let send_task = tokio::spawn(async move {
+        while let Some(frame) = rx.recv().await {
+            if writer.send(Message::Text(frame)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    while let Some(Ok(message)) = reader.next().await {
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(_) => break,
+            _ => continue,
+        };
+        let request = match serde_json::from_str::<WsRequest>(&text) {
+            Ok(request) => request,
+            Err(e) => {
+                let _ = tx.send(error_frame(
+                    Value::Null,
+                    error_codes::PARSE_ERROR,
+                    &e.to_string(),
+                    None,
+                ));
+                continue;
+            }
+        };
+        if !methods.contains(&request.method.as_str()) {
+            if let Some(id) = request.id {
+                let message = format!("Unknown method: {}", request.method);
+                let _ = tx.send(error_frame(
+                    id,
+                    error_codes::METHOD_NOT_FOUND,
+                    &message,
+                    None,
+                ));
+            }
+            continue;
+        }
+
+        let sink = WsSink {
+            id: request.id.clone().unwrap_or(Value::Null),
+            tx: tx.clone(),
+        };
+        let call = dispatch(request.method, request.params, sink);
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            let result = call.await;
+            if let Some(id) = request.id {
+                let _ = tx.send(response(id, result));
+            }
+        });
+    }
+
+    // Calls still running cannot answer a closed socket
+    send_task.abort();
+}
+
+/// The response frame for a finished call
+pub fn response(id: Value, result: Result<Value, ToolError>) -> String {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string(),
+        Err(e) => error_frame(id, error_code(e.code), &e.to_string(), Some(e.code)),
+    }
+}
+
+/// The JSON-RPC error code for a tool error
+pub fn error_code(code: ErrorCode) -> i32 {
+    match code {
+        ErrorCode::BadRequest => error_codes::INVALID_REQUEST,
+        ErrorCode::InvalidArgument => error_codes::INVALID_PARAMS,
+        ErrorCode::Internal | ErrorCode::SerializationError => error_codes::INTERNAL_ERROR,
+        _ => error_codes::TOOL_ERROR,
+    }
+}
+
+fn error_frame(id: Value, code: i32, message: &str, tool_code: Option<ErrorCode>) -> String {
+    let mut error = json!({ "code": code, "message": message });
+    if let Some(tool_code) = tool_code {
+        error["data"] = json!({ "code": format!("{tool_code:?}") });
+    }
+    json!({ "jsonrpc": "2.0", "id": id, "error": error }).to_string()
+}
+
+fn notification(method: &str, params: Value) -> String {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params }).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(text: String) -> Value {
+        serde_json::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn response_carries_result_or_error() {
+        assert_eq!(
+            frame(response(json!(1), Ok(json!(3)))),
+            json!({ "jsonrpc": "2.0", "id": 1, "result": 3 })
+        );
+
+        let error = frame(response(json!("a"), Err(ToolError::invalid_input("bad"))));
+        assert_eq!(error["id"], "a");
+        assert_eq!(error["error"]["code"], error_codes::INVALID_PARAMS);
+        assert_eq!(error["error"]["data"]["code"], "InvalidArgument");
+    }
+
+    #[tokio::test]
+    async fn forward_pushes_items_until_error() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let sink = WsSink { id: json!(7), tx };
+        let items = futures::stream::iter([Ok(1), Err(ToolError::invalid_input("stop")), Ok(2)]);
+
+        let err = sink.forward(items).await.unwrap_err();
+        assert_eq!(err.code, ErrorCode::InvalidArgument);
+
+        let pushed = frame(rx.recv().await.unwrap());
+        assert_eq!(pushed["method"], STREAM_ITEM);
+        assert_eq!(pushed["params"], json!({ "id": 7, "item": 1 }));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn request_defaults_missing_fields() {
+        let request: WsRequest = serde_json::from_str(r#"{"method": "ping"}"#).unwrap();
+        assert_eq!(request.id, None);
+        assert_eq!(request.params, json!({}));
+    }
+}
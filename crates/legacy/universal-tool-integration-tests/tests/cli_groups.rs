@@ -0,0 +1,98 @@
+//! Integration tests for CLI command groups and global flags
+//!
+//! These tests verify that `cli(group = "...")` nests tools under a parent
+//! subcommand and that router-level `global_flags` reach tools through a
+//! `GlobalFlags` parameter.
+
+use std::sync::Mutex;
+use universal_tool_core::prelude::*;
+
+#[derive(Default)]
+struct MountTools {
+    calls: Mutex<Vec<String>>,
+}
+
+impl MountTools {
+    fn record(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+}
+
+#[universal_tool_router(cli(name = "thoughts", global_flags("json"), standard_global_args = true))]
+impl MountTools {
+    /// Add a mount
+    #[universal_tool(description = "Add a mount", cli(name = "add", group = "mount"))]
+    async fn add_mount(&self, path: String, flags: GlobalFlags) -> Result<(), ToolError> {
+        self.record(format!(
+            "add {path} json={} verbose={}",
+            flags.is_set("json"),
+            flags.count("verbose")
+        ));
+        Ok(())
+    }
+
+    /// List mounts
+    #[universal_tool(description = "List mounts", cli(name = "list", group = "mount"))]
+    async fn list_mounts(&self) -> Result<Vec<String>, ToolError> {
+        self.record("list".to_string());
+        Ok(Vec::new())
+    }
+
+    /// Show status
+    #[universal_tool(description = "Show status")]
+    async fn status(&self, flags: GlobalFlags) -> Result<(), ToolError> {
+        self.record(format!("status json={}", flags.is_set("json")));
+        Ok(())
+    }
+}
+
+async fn run(tools: &MountTools, args: &[&str]) -> Result<(), ToolError> {
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(args)
+        .unwrap();
+    tools.execute_cli(matches).await
+}
+
+#[test]
+fn test_grouped_tools_are_nested() {
+    let cmd = MountTools::default().create_cli_command();
+    let top: Vec<_> = cmd.get_subcommands().map(|c| c.get_name()).collect();
+    assert_eq!(top, ["status", "mount"]);
+
+    let mount = cmd.find_subcommand("mount").unwrap();
+    let nested: Vec<_> = mount.get_subcommands().map(|c| c.get_name()).collect();
+    assert_eq!(nested, ["add", "list"]);
+
+    let add = mount.find_subcommand("add").unwrap();
+    let args: Vec<_> = add
+        .get_arguments()
+        .map(|a| a.get_id().to_string())
+        .collect();
+    assert_eq!(args, ["path"]);
+}
+
+#[tokio::test]
+async fn test_grouped_tools_dispatch_with_global_flags() {
+    let tools = MountTools::default();
+    run(
+        &tools,
+        &["thoughts", "mount", "add", "--path", "/a", "--json", "-vv"],
+    )
+    .await
+    .unwrap();
+    run(&tools, &["thoughts", "--json", "status"])
+        .await
+        .unwrap();
+    run(&tools, &["thoughts", "mount", "list"]).await.unwrap();
+
+    assert_eq!(
+        *tools.calls.lock().unwrap(),
+        ["add /a json=true verbose=2", "status json=true", "list"]
+    );
+
+    let result = MountTools::default()
+        .create_cli_command()
+        .try_get_matches_from(["thoughts", "mount"]);
+    assert!(result.is_err(), "a group requires a subcommand");
+}
@@ -0,0 +1,153 @@
+//! Integration tests for router dispatch hooks
+//!
+//! These tests verify that a router with `hooks` has `before_call`/`after_call`
+//! invoked around each tool call by the generated CLI and REST adapters, with the
+//! tool name, interface, serialized arguments and outcome.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use universal_tool_core::prelude::*;
+use universal_tool_core::rest::axum::body::Body;
+use universal_tool_core::rest::axum::body::to_bytes;
+use universal_tool_core::rest::axum::http::Request;
+use universal_tool_core::rest::axum::http::StatusCode;
+use universal_tool_core::rest::tower::ServiceExt;
+
+#[derive(Debug, PartialEq)]
+struct Record {
+    phase: &'static str,
+    tool: &'static str,
+    interface: Interface,
+    args: serde_json::Value,
+    error: Option<ErrorCode>,
+}
+
+#[derive(Default)]
+struct CounterTools {
+    records: Mutex<Vec<Record>>,
+}
+
+impl ToolHooks for CounterTools {
+    fn before_call(&self, call: &ToolCall) {
+        self.records.lock().unwrap().push(Record {
+            phase: "before",
+            tool: call.tool,
+            interface: call.interface,
+            args: call.args.clone(),
+            error: None,
+        });
+    }
+
+    fn after_call(&self, call: &ToolCall, _duration: Duration, error: Option<&ToolError>) {
+        self.records.lock().unwrap().push(Record {
+            phase: "after",
+            tool: call.tool,
+            interface: call.interface,
+            args: call.args.clone(),
+            error: error.map(|e| e.code),
+        });
+    }
+}
+
+#[universal_tool_router(cli(name = "counter"), rest(prefix = "/api"), hooks)]
+impl CounterTools {
+    /// Add two numbers
+    #[universal_tool(description = "Add two numbers", rest(method = "POST", path = "/add"))]
+    async fn add(&self, a: i64, b: Option<i64>) -> Result<i64, ToolError> {
+        Ok(a + b.unwrap_or(0))
+    }
+
+    /// Count up to a limit, failing past ten
+    #[universal_tool(description = "Count up", rest(method = "POST", path = "/count"))]
+    async fn count(&self, to: u32) -> impl Stream<Item = Result<u32, ToolError>> + Send {
+        universal_tool_core::stream::iter((1..=to).map(|n| {
+            if n > 10 {
+                Err(ToolError::invalid_input("too far"))
+            } else {
+                Ok(n)
+            }
+        }))
+    }
+}
+
+fn take_records(tools: &CounterTools) -> Vec<Record> {
+    std::mem::take(&mut *tools.records.lock().unwrap())
+}
+
+async fn post(tools: Arc<CounterTools>, path: &str, body: &'static str) -> StatusCode {
+    let app = CounterTools::create_rest_router(tools);
+    let request = Request::post(path)
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    // Drain the body so streaming handlers run to completion
+    to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    status
+}
+
+#[tokio::test]
+async fn test_cli_calls_hooks() {
+    let tools = CounterTools::default();
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(["counter", "add", "--a", "2", "--b", "3"])
+        .unwrap();
+    tools.execute_cli(matches).await.unwrap();
+
+    let records = take_records(&tools);
+    let args = serde_json::json!({"a": 2, "b": 3});
+    assert_eq!(
+        records,
+        [
+            Record {
+                phase: "before",
+                tool: "add",
+                interface: Interface::Cli,
+                args: args.clone(),
+                error: None,
+            },
+            Record {
+                phase: "after",
+                tool: "add",
+                interface: Interface::Cli,
+                args,
+                error: None,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_rest_calls_hooks() {
+    let tools = Arc::new(CounterTools::default());
+
+    let status = post(tools.clone(), "/api/add", r#"{"a": 1}"#).await;
+    assert_eq!(status, StatusCode::OK);
+    let records = take_records(&tools);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].interface, Interface::Rest);
+    assert_eq!(records[1].args, serde_json::json!({"a": 1, "b": null}));
+
+    // Requests rejected before dispatch never reach the hooks
+    let status = post(tools.clone(), "/api/add", r#"{"b": 1}"#).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(take_records(&tools).is_empty());
+}
+
+#[tokio::test]
+async fn test_streaming_hooks_see_stream_error() {
+    let tools = Arc::new(CounterTools::default());
+
+    post(tools.clone(), "/api/count", r#"{"to": 3}"#).await;
+    let records = take_records(&tools);
+    assert_eq!(records[1].phase, "after");
+    assert_eq!(records[1].error, None);
+
+    post(tools.clone(), "/api/count", r#"{"to": 12}"#).await;
+    let records = take_records(&tools);
+    assert_eq!(records[1].tool, "count");
+    assert_eq!(records[1].error, Some(ErrorCode::InvalidArgument));
+}
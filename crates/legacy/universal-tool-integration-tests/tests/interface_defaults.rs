@@ -0,0 +1,96 @@
+//! Integration tests for per-interface parameter defaults
+//!
+//! These tests verify that `cli(...)`/`rest(...)` overrides on `#[universal_tool_param]`
+//! change a parameter's default per interface, and that `skip` hides it from one
+//! interface while it stays a flag in another.
+
+use std::sync::Arc;
+use universal_tool_core::prelude::*;
+use universal_tool_core::rest::axum::body::Body;
+use universal_tool_core::rest::axum::body::to_bytes;
+use universal_tool_core::rest::axum::http::Request;
+use universal_tool_core::rest::axum::http::StatusCode;
+use universal_tool_core::rest::tower::ServiceExt;
+
+struct DeployTools;
+
+#[universal_tool_router(cli(name = "deploy"), rest(prefix = "/api"))]
+impl DeployTools {
+    /// Deploy a target
+    #[universal_tool(
+        description = "Deploy a target",
+        rest(method = "POST", path = "/deploy")
+    )]
+    async fn deploy(
+        &self,
+        target: String,
+        #[universal_tool_param(default = "us-east")] region: String,
+        #[universal_tool_param(
+            cli(default = "dev"),
+            rest(skip, env = "UTF_TEST_DEPLOY_TOKEN", default = "server")
+        )]
+        token: String,
+    ) -> Result<String, ToolError> {
+        Ok(format!("{token}@{region}/{target}"))
+    }
+}
+
+async fn run_cli(args: &[&str]) -> Result<(), ToolError> {
+    let matches = DeployTools
+        .create_cli_command()
+        .try_get_matches_from(args)
+        .unwrap();
+    DeployTools.execute_cli(matches).await
+}
+
+async fn post(body: &'static str) -> (StatusCode, serde_json::Value) {
+    let app = DeployTools::create_rest_router(Arc::new(DeployTools));
+    let request = Request::post("/api/deploy")
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_cli_uses_cli_defaults() {
+    let command = DeployTools.create_cli_command();
+    let deploy = command.find_subcommand("deploy").unwrap();
+    let token = deploy
+        .get_arguments()
+        .find(|arg| arg.get_id() == "token")
+        .unwrap();
+    assert_eq!(token.get_default_values(), ["dev"]);
+
+    run_cli(&["deploy", "deploy", "--target", "web"])
+        .await
+        .unwrap();
+    run_cli(&["deploy", "deploy", "--target", "web", "--token", "t"])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_rest_fills_skipped_and_missing_params() {
+    let (status, body) = post(r#"{"target": "web"}"#).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "server@us-east/web");
+
+    // A skipped parameter cannot be set by the caller
+    let (status, body) = post(r#"{"target": "web", "region": "eu", "token": "t"}"#).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "server@eu/web");
+}
+
+#[test]
+fn test_openapi_omits_skipped_params() {
+    let doc = serde_json::to_value(DeployTools.openapi()).unwrap();
+    let schema = &doc["paths"]["/api/deploy"]["post"]["requestBody"]["content"]["application/json"]
+        ["schema"];
+    assert_eq!(schema["required"], serde_json::json!(["target"]));
+    assert!(schema["properties"]["region"].is_object());
+    assert!(schema["properties"].get("token").is_none());
+}
@@ -0,0 +1,90 @@
+//! Integration tests for OpenAPI generation
+//!
+//! These tests verify that `openapi()` documents every REST route from the
+//! `#[universal_tool]` metadata without any utoipa annotations.
+
+#![allow(dead_code, unused_variables)]
+
+use serde::Deserialize;
+use serde::Serialize;
+use universal_tool_core::prelude::*;
+use universal_tool_core::schemars;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct Item {
+    id: u32,
+    name: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct Paging {
+    limit: Option<u32>,
+}
+
+struct ItemTools;
+
+#[universal_tool_router(rest(prefix = "/api"), openapi_tag = "items")]
+impl ItemTools {
+    /// Create an item
+    #[universal_tool(description = "Create an item", rest(method = "POST", path = "/items"))]
+    async fn create_item(
+        &self,
+        #[universal_tool_param(description = "Display name")] name: String,
+        tags: Option<Vec<String>>,
+    ) -> Result<Item, ToolError> {
+        Ok(Item { id: 1, name })
+    }
+
+    /// Fetch one item
+    #[universal_tool(description = "Get an item", rest(method = "GET", path = "/items/:id"))]
+    async fn get_item(&self, id: u32) -> Result<Item, ToolError> {
+        Ok(Item {
+            id,
+            name: String::new(),
+        })
+    }
+
+    /// List items
+    #[universal_tool(description = "List items", rest(method = "GET", path = "/items"))]
+    async fn list_items(
+        &self,
+        #[universal_tool_param(source = "query")] paging: Paging,
+    ) -> impl Stream<Item = Result<Item, ToolError>> + Send {
+        universal_tool_core::stream::iter(Vec::new())
+    }
+}
+
+#[test]
+fn test_openapi_documents_routes() {
+    let doc = serde_json::to_value(ItemTools.openapi()).unwrap();
+    let paths = &doc["paths"];
+
+    let create = &paths["/api/items"]["post"];
+    assert_eq!(create["operationId"], "create_item");
+    assert_eq!(create["summary"], "Create an item");
+    assert_eq!(create["tags"][0], "items");
+    let body = &create["requestBody"]["content"]["application/json"]["schema"];
+    assert_eq!(body["required"], serde_json::json!(["name"]));
+    assert_eq!(body["properties"]["name"]["description"], "Display name");
+    assert_eq!(body["properties"]["tags"]["type"], "array");
+    let ok = &create["responses"]["200"]["content"]["application/json"]["schema"];
+    assert_eq!(ok["properties"]["id"]["type"], "integer");
+    assert!(create["responses"]["400"].is_object());
+
+    let get = &paths["/api/items/{id}"]["get"];
+    assert_eq!(get["parameters"][0]["name"], "id");
+    assert_eq!(get["parameters"][0]["in"], "path");
+    assert_eq!(get["parameters"][0]["required"], true);
+    assert!(get.get("requestBody").is_none());
+
+    let list = &paths["/api/items"]["get"];
+    assert_eq!(list["parameters"][0]["in"], "query");
+    assert!(list["responses"]["200"]["content"]["text/event-stream"].is_object());
+}
+
+#[test]
+fn test_openapi_spec_is_json() {
+    let spec: serde_json::Value = serde_json::from_str(&ItemTools.get_openapi_spec()).unwrap();
+    assert_eq!(spec["info"]["title"], "ItemTools");
+    assert!(spec["openapi"].as_str().unwrap().starts_with("3."));
+}
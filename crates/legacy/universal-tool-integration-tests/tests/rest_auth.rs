@@ -0,0 +1,122 @@
+//! Integration tests for REST authentication
+//!
+//! These tests verify that `rest(auth = "bearer")` routes only run once the router's
+//! `AuthProvider` accepts the caller's token, that `rest(auth = "none")` opts a tool
+//! out of a router-wide default, and that the OpenAPI document declares the scheme.
+
+use std::sync::Arc;
+use universal_tool_core::auth::AuthError;
+use universal_tool_core::auth::AuthFuture;
+use universal_tool_core::auth::AuthProvider;
+use universal_tool_core::auth::Credentials;
+use universal_tool_core::auth::StaticTokens;
+use universal_tool_core::auth::with_auth;
+use universal_tool_core::prelude::*;
+use universal_tool_core::rest::axum::Router;
+use universal_tool_core::rest::axum::body::Body;
+use universal_tool_core::rest::axum::body::to_bytes;
+use universal_tool_core::rest::axum::http::Request;
+use universal_tool_core::rest::axum::http::StatusCode;
+use universal_tool_core::rest::axum::http::header;
+use universal_tool_core::rest::tower::ServiceExt;
+
+struct VaultTools;
+
+#[universal_tool_router(rest(prefix = "/api", auth = "bearer"))]
+impl VaultTools {
+    /// Read a secret
+    #[universal_tool(description = "Read a secret", rest(method = "POST", path = "/read"))]
+    async fn read(&self, key: String) -> Result<String, ToolError> {
+        Ok(format!("value of {key}"))
+    }
+
+    /// Report whether the vault is up
+    #[universal_tool(
+        description = "Health check",
+        rest(method = "GET", path = "/health", auth = "none")
+    )]
+    async fn health(&self) -> Result<String, ToolError> {
+        Ok("ok".to_string())
+    }
+}
+
+async fn send(app: Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+fn read_request(token: Option<&str>) -> Request<Body> {
+    let mut request = Request::post("/api/read").header("content-type", "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {token}"));
+    }
+    request.body(Body::from(r#"{"key": "db"}"#)).unwrap()
+}
+
+fn app() -> Router {
+    with_auth(
+        VaultTools::create_rest_router(Arc::new(VaultTools)),
+        StaticTokens::new(["secret"]),
+    )
+}
+
+#[tokio::test]
+async fn test_bearer_token_required() {
+    let response = app().oneshot(read_request(None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+
+    let (status, body) = send(app(), read_request(Some("wrong"))).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "PermissionDenied");
+
+    let (status, body) = send(app(), read_request(Some("secret"))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "value of db");
+}
+
+#[tokio::test]
+async fn test_auth_none_opts_out() {
+    let request = Request::get("/api/health").body(Body::empty()).unwrap();
+    let (status, body) = send(app(), request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "ok");
+}
+
+#[tokio::test]
+async fn test_forbidden_is_403() {
+    struct Nobody;
+    impl AuthProvider for Nobody {
+        fn authenticate<'a>(&'a self, credentials: Credentials<'a>) -> AuthFuture<'a> {
+            let tool = credentials.tool;
+            Box::pin(async move { Err(AuthError::Forbidden(format!("No access to {tool}"))) })
+        }
+    }
+
+    let app = with_auth(VaultTools::create_rest_router(Arc::new(VaultTools)), Nobody);
+    let (status, body) = send(app, read_request(Some("anything"))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["error"], "[PermissionDenied] No access to read");
+}
+
+#[tokio::test]
+async fn test_missing_provider_fails_closed() {
+    let app = VaultTools::create_rest_router(Arc::new(VaultTools));
+    let (status, _) = send(app, read_request(Some("secret"))).await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[test]
+fn test_openapi_declares_bearer_auth() {
+    let doc = serde_json::to_value(VaultTools.openapi()).unwrap();
+    assert_eq!(
+        doc["components"]["securitySchemes"]["bearerAuth"]["scheme"],
+        "bearer"
+    );
+    let read = &doc["paths"]["/api/read"]["post"];
+    assert_eq!(read["security"], serde_json::json!([{"bearerAuth": []}]));
+    assert!(read["responses"]["401"].is_object());
+    assert!(doc["paths"]["/api/health"]["get"].get("security").is_none());
+}
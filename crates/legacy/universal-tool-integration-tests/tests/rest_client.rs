@@ -0,0 +1,116 @@
+//! Integration tests for generated REST clients
+//!
+//! These tests serve a router on a local port and call every tool through the
+//! client generated by `rest(client = "...")`, including error mapping.
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::sync::Arc;
+use universal_tool_core::prelude::*;
+use universal_tool_core::schemars;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+struct Item {
+    id: u32,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+struct Paging {
+    limit: usize,
+}
+
+struct ItemTools;
+
+#[universal_tool_router(rest(prefix = "/api", client = "ItemsClient"))]
+impl ItemTools {
+    /// Create an item
+    #[universal_tool(description = "Create an item", rest(method = "POST", path = "/items"))]
+    pub async fn create_item(
+        &self,
+        name: String,
+        tags: Option<Vec<String>>,
+    ) -> Result<Item, ToolError> {
+        let suffix = tags.map(|t| t.join(",")).unwrap_or_default();
+        Ok(Item {
+            id: 1,
+            name: format!("{name}{suffix}"),
+        })
+    }
+
+    /// Fetch one item
+    #[universal_tool(description = "Get an item", rest(method = "GET", path = "/items/:id"))]
+    pub async fn get_item(&self, id: u32) -> Result<Item, ToolError> {
+        if id == 0 {
+            return Err(ToolError::not_found(format!("No item {id}")));
+        }
+        Ok(Item {
+            id,
+            name: "widget".to_string(),
+        })
+    }
+
+    /// List items
+    #[universal_tool(description = "List items", rest(method = "GET", path = "/items"))]
+    pub async fn list_items(
+        &self,
+        #[universal_tool_param(source = "query")] paging: Paging,
+    ) -> impl Stream<Item = Result<Item, ToolError>> + Send {
+        let items = (1..=paging.limit as u32).map(|id| {
+            Ok(Item {
+                id,
+                name: format!("item {id}"),
+            })
+        });
+        universal_tool_core::stream::iter(items.collect::<Vec<_>>())
+    }
+}
+
+async fn serve() -> ItemsClient {
+    let app = ItemTools::create_rest_router(Arc::new(ItemTools));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        universal_tool_core::rest::axum::serve(listener, app)
+            .await
+            .unwrap();
+    });
+    ItemsClient::new(format!("http://{addr}"))
+}
+
+#[tokio::test]
+async fn test_client_calls_tools() {
+    let client = serve().await;
+
+    let item = client
+        .create_item("box".to_string(), Some(vec!["-a".to_string()]))
+        .await
+        .unwrap();
+    assert_eq!(item.name, "box-a");
+
+    let item = client.get_item(7).await.unwrap();
+    assert_eq!(
+        item,
+        Item {
+            id: 7,
+            name: "widget".to_string()
+        }
+    );
+
+    let items = client.list_items(Paging { limit: 3 }).await.unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[2].name, "item 3");
+}
+
+#[tokio::test]
+async fn test_client_maps_errors() {
+    let client = serve().await;
+
+    let err = client.get_item(0).await.unwrap_err();
+    assert_eq!(err.code, ErrorCode::NotFound);
+    assert_eq!(err.message, "No item 0");
+
+    let unreachable = ItemsClient::new("http://127.0.0.1:1");
+    let err = unreachable.get_item(1).await.unwrap_err();
+    assert_eq!(err.code, ErrorCode::NetworkError);
+}
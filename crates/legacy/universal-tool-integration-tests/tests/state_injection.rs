@@ -0,0 +1,86 @@
+//! Integration tests for `from_state` parameter injection
+//!
+//! These tests verify that shared state reaches tool methods from the CLI
+//! (`execute_cli_with_state`) and from REST request extensions.
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use universal_tool_core::prelude::*;
+use universal_tool_core::rest::Extension;
+use universal_tool_core::rest::axum::body::Body;
+use universal_tool_core::rest::axum::body::to_bytes;
+use universal_tool_core::rest::axum::http::Request;
+use universal_tool_core::rest::tower::ServiceExt;
+
+/// Stand-in for a connection pool shared by every call
+#[derive(Clone, Default)]
+struct Pool(Arc<Mutex<Vec<String>>>);
+
+struct NoteTools;
+
+#[universal_tool_router(cli(name = "notes"), rest(prefix = "/api"))]
+impl NoteTools {
+    /// Store a note in the shared pool
+    #[universal_tool(description = "Add a note", rest(method = "POST", path = "/notes"))]
+    async fn add_note(
+        &self,
+        text: String,
+        #[universal_tool_param(from_state)] pool: Pool,
+    ) -> Result<usize, ToolError> {
+        let mut notes = pool.0.lock().unwrap();
+        notes.push(text);
+        Ok(notes.len())
+    }
+}
+
+#[test]
+fn test_state_params_are_not_cli_arguments() {
+    let cmd = NoteTools.create_cli_command();
+    let add = cmd
+        .get_subcommands()
+        .find(|c| c.get_name() == "add-note")
+        .expect("add-note command not found");
+    let args: Vec<_> = add
+        .get_arguments()
+        .map(|a| a.get_id().to_string())
+        .collect();
+    assert_eq!(args, ["text"]);
+}
+
+#[tokio::test]
+async fn test_cli_injects_state() {
+    let tools = NoteTools;
+    let pool = Pool::default();
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(["notes", "add-note", "--text", "hello"])
+        .unwrap();
+
+    let state = ToolState::new().with(pool.clone());
+    tools.execute_cli_with_state(matches, &state).await.unwrap();
+    assert_eq!(*pool.0.lock().unwrap(), ["hello"]);
+
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(["notes", "add-note", "--text", "hello"])
+        .unwrap();
+    let err = tools.execute_cli(matches).await.unwrap_err();
+    assert!(err.message.contains("Pool"), "{}", err.message);
+}
+
+#[tokio::test]
+async fn test_rest_injects_extension() {
+    let pool = Pool::default();
+    let app = NoteTools::create_rest_router(Arc::new(NoteTools)).layer(Extension(pool.clone()));
+
+    let request = Request::post("/api/notes")
+        .header("content-type", "application/json")
+        .body(Body::from(r#"{"text": "hi"}"#))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(&body[..], b"1");
+    assert_eq!(*pool.0.lock().unwrap(), ["hi"]);
+}
@@ -0,0 +1,87 @@
+//! Integration tests for streaming tools
+//!
+//! These tests verify that tools returning `impl Stream<Item = Result<T, ToolError>>`
+//! print incrementally on the CLI and respond with server-sent events over REST.
+
+use std::sync::Arc;
+use universal_tool_core::prelude::*;
+use universal_tool_core::rest::axum::body::Body;
+use universal_tool_core::rest::axum::body::to_bytes;
+use universal_tool_core::rest::axum::http::Request;
+use universal_tool_core::rest::tower::ServiceExt;
+use universal_tool_core::stream;
+
+struct CountTools;
+
+#[universal_tool_router(cli(name = "count"), rest(prefix = "/api"), mcp(name = "count"))]
+impl CountTools {
+    /// Count from one to `to`, failing past ten
+    #[universal_tool(description = "Count up", rest(method = "POST", path = "/count"))]
+    fn count(&self, to: u32) -> impl Stream<Item = Result<u32, ToolError>> + Send {
+        stream::iter((1..=to).map(|n| {
+            if n > 10 {
+                Err(ToolError::new(ErrorCode::InvalidArgument, "too far"))
+            } else {
+                Ok(n)
+            }
+        }))
+    }
+
+    /// Split `text` into words, one item per word
+    #[universal_tool(description = "Split words")]
+    async fn words(&self, text: String) -> impl Stream<Item = Result<String, ToolError>> + Send {
+        let words: Vec<_> = text.split_whitespace().map(|w| Ok(w.to_string())).collect();
+        stream::iter(words)
+    }
+}
+
+#[tokio::test]
+async fn test_cli_streams_items() {
+    let tools = CountTools;
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(["count", "count", "--to", "3"])
+        .unwrap();
+    assert!(tools.execute_cli(matches).await.is_ok());
+
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(["count", "words", "--text", "a b"])
+        .unwrap();
+    assert!(tools.execute_cli(matches).await.is_ok());
+
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(["count", "count", "--to", "11"])
+        .unwrap();
+    assert_eq!(
+        tools.execute_cli(matches).await.unwrap_err().message,
+        "too far"
+    );
+}
+
+#[tokio::test]
+async fn test_rest_responds_with_server_sent_events() {
+    let app = CountTools::create_rest_router(Arc::new(CountTools));
+    let request = Request::post("/api/count")
+        .header("content-type", "application/json")
+        .body(Body::from(r#"{"to": 11}"#))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/event-stream"
+    );
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    let data: Vec<_> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .collect();
+    assert_eq!(data.len(), 11);
+    assert_eq!(data[..3], ["1", "2", "3"]);
+    assert!(body.contains("event: error"));
+    assert!(data[10].contains("too far"));
+}
@@ -0,0 +1,115 @@
+//! Integration tests for parameter validation attributes
+//!
+//! These tests verify that `min`, `max`, `pattern` and `non_empty` are enforced by
+//! the generated CLI and REST adapters before the method runs, and show up in the
+//! OpenAPI schema.
+
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use universal_tool_core::prelude::*;
+use universal_tool_core::rest::axum::body::Body;
+use universal_tool_core::rest::axum::http::Request;
+use universal_tool_core::rest::axum::http::StatusCode;
+use universal_tool_core::rest::tower::ServiceExt;
+
+#[derive(Default)]
+struct SearchTools {
+    calls: AtomicUsize,
+}
+
+#[universal_tool_router(cli(name = "search"), rest(prefix = "/api"))]
+impl SearchTools {
+    /// Search for items
+    #[universal_tool(description = "Search items", rest(method = "POST", path = "/search"))]
+    async fn search(
+        &self,
+        #[universal_tool_param(non_empty, pattern = "^[a-z ]+$")] query: String,
+        #[universal_tool_param(min = 1, max = 100)] limit: Option<u32>,
+    ) -> Result<usize, ToolError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(query.len().min(limit.unwrap_or(10) as usize))
+    }
+}
+
+async fn run_cli(tools: &SearchTools, args: &[&str]) -> Result<(), ToolError> {
+    let matches = tools
+        .create_cli_command()
+        .try_get_matches_from(args)
+        .unwrap();
+    tools.execute_cli(matches).await
+}
+
+#[tokio::test]
+async fn test_cli_rejects_invalid_arguments() {
+    let tools = SearchTools::default();
+
+    run_cli(
+        &tools,
+        &["search", "search", "--query", "rust", "--limit", "5"],
+    )
+    .await
+    .unwrap();
+    // Absent optional parameters are not checked
+    run_cli(&tools, &["search", "search", "--query", "rust"])
+        .await
+        .unwrap();
+
+    let err = run_cli(
+        &tools,
+        &["search", "search", "--query", "rust", "--limit", "0"],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidArgument);
+    assert_eq!(err.message, "limit must be at least 1, got 0");
+
+    let err = run_cli(&tools, &["search", "search", "--query", "Rust!"])
+        .await
+        .unwrap_err();
+    assert!(err.message.contains("query must match"), "{}", err.message);
+
+    let err = run_cli(&tools, &["search", "search", "--query", ""])
+        .await
+        .unwrap_err();
+    assert_eq!(err.message, "query must not be empty");
+
+    assert_eq!(tools.calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_rest_rejects_invalid_body() {
+    let tools = Arc::new(SearchTools::default());
+    let post = |body: &'static str| {
+        Request::post("/api/search")
+            .header("content-type", "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    };
+
+    let app = SearchTools::create_rest_router(tools.clone());
+    let response = app
+        .clone()
+        .oneshot(post(r#"{"query": "rust", "limit": 500}"#))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .oneshot(post(r#"{"query": "rust", "limit": 50}"#))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(tools.calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_constraints_are_in_openapi_schema() {
+    let doc = serde_json::to_value(SearchTools::default().openapi()).unwrap();
+    let schema = &doc["paths"]["/api/search"]["post"]["requestBody"]["content"]["application/json"]
+        ["schema"]["properties"];
+    assert_eq!(schema["query"]["minLength"], 1);
+    assert_eq!(schema["query"]["pattern"], "^[a-z ]+$");
+    assert_eq!(schema["limit"]["minimum"], 1.0);
+    assert_eq!(schema["limit"]["maximum"], 100.0);
+}
@@ -0,0 +1,131 @@
+//! Integration tests for the WebSocket adapter
+//!
+//! These tests verify that `create_ws_router` serves tools as JSON-RPC 2.0 over a real
+//! WebSocket: calls are answered by id, streaming tools push `stream.item`
+//! notifications before their response, and errors carry JSON-RPC codes.
+
+use futures::SinkExt;
+use futures::StreamExt;
+use serde_json::Value;
+use serde_json::json;
+use std::sync::Arc;
+use tokio_tungstenite::tungstenite::Message;
+use universal_tool_core::prelude::*;
+
+struct CounterTools;
+
+#[universal_tool_router(ws(path = "/rpc"))]
+impl CounterTools {
+    /// Add two numbers
+    #[universal_tool(description = "Add two numbers")]
+    async fn add(&self, a: i64, b: i64) -> Result<i64, ToolError> {
+        Ok(a + b)
+    }
+
+    /// Count up to a limit, failing past ten
+    #[universal_tool(description = "Count up")]
+    async fn count(&self, to: u32) -> impl Stream<Item = Result<u32, ToolError>> + Send {
+        universal_tool_core::stream::iter((1..=to).map(|n| {
+            if n > 10 {
+                Err(ToolError::invalid_input("too far"))
+            } else {
+                Ok(n)
+            }
+        }))
+    }
+}
+
+type Socket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+async fn connect() -> Socket {
+    let app = CounterTools::create_ws_router(Arc::new(CounterTools));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        universal_tool_core::rest::serve(listener, app)
+            .await
+            .unwrap();
+    });
+    let (socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}/rpc"))
+        .await
+        .unwrap();
+    socket
+}
+
+async fn call(socket: &mut Socket, request: Value) {
+    socket
+        .send(Message::Text(request.to_string()))
+        .await
+        .unwrap();
+}
+
+async fn recv(socket: &mut Socket) -> Value {
+    loop {
+        match socket.next().await.unwrap().unwrap() {
+            Message::Text(text) => return serde_json::from_str(&text).unwrap(),
+            _ => continue,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_call_is_answered_by_id() {
+    let mut socket = connect().await;
+    call(
+        &mut socket,
+        json!({"jsonrpc": "2.0", "id": 1, "method": "add", "params": {"a": 2, "b": 3}}),
+    )
+    .await;
+    assert_eq!(
+        recv(&mut socket).await,
+        json!({"jsonrpc": "2.0", "id": 1, "result": 5})
+    );
+}
+
+#[tokio::test]
+async fn test_stream_pushes_items_then_responds() {
+    let mut socket = connect().await;
+    call(
+        &mut socket,
+        json!({"jsonrpc": "2.0", "id": "c", "method": "count", "params": {"to": 2}}),
+    )
+    .await;
+    for n in 1..=2 {
+        assert_eq!(
+            recv(&mut socket).await,
+            json!({"jsonrpc": "2.0", "method": "stream.item", "params": {"id": "c", "item": n}})
+        );
+    }
+    assert_eq!(
+        recv(&mut socket).await,
+        json!({"jsonrpc": "2.0", "id": "c", "result": null})
+    );
+}
+
+#[tokio::test]
+async fn test_errors_use_jsonrpc_codes() {
+    let mut socket = connect().await;
+
+    call(
+        &mut socket,
+        json!({"jsonrpc": "2.0", "id": 1, "method": "nope"}),
+    )
+    .await;
+    let response = recv(&mut socket).await;
+    assert_eq!(response["error"]["code"], -32601);
+
+    call(
+        &mut socket,
+        json!({"jsonrpc": "2.0", "id": 2, "method": "add", "params": {"a": 1}}),
+    )
+    .await;
+    let response = recv(&mut socket).await;
+    assert_eq!(response["id"], 2);
+    assert_eq!(response["error"]["code"], -32602);
+    assert_eq!(response["error"]["data"]["code"], "InvalidArgument");
+
+    socket.send(Message::Text("not json".into())).await.unwrap();
+    let response = recv(&mut socket).await;
+    assert_eq!(response["error"]["code"], -32700);
+}
@@ -9,7 +9,9 @@ use crate::codegen::validation;
 use crate::model::ParamSource;
 use crate::model::RouterDef;
 use crate::model::ToolDef;
+use proc_macro2::Ident;
 use proc_macro2::TokenStream;
+use quote::format_ident;
 use quote::quote;
 
 /// Generates all CLI-related methods for a router
@@ -56,9 +58,8 @@ fn generate_create_cli_command(router: &RouterDef) -> TokenStream {
         .map(|d| d.as_str())
         .unwrap_or("Tool suite generated by UTF");
 
-    // Generate subcommands
-    // TODO(2): Support command_path for nested subcommands
-    let subcommands: Vec<_> = router.tools.iter().map(generate_tool_subcommand).collect();
+    // Generate subcommands, nesting tools under their command groups
+    let subcommands = generate_group_subcommands(&CommandGroup::new(&router.tools));
 
     // Debug: Print number of subcommands
     if std::env::var("UTF_DEBUG").is_ok() {
@@ -130,47 +131,32 @@ fn generate_create_cli_command(router: &RouterDef) -> TokenStream {
         quote! {}
     };
 
-    // Check if we actually have global/standard args
-    let has_global_args = router
+    // Custom global flags, readable by tools through a `GlobalFlags` parameter
+    let global_flags = router
         .metadata
         .cli_config
-        .as_ref()
-        .map(|c| !c.global_output_formats.is_empty())
-        .unwrap_or(false);
-
-    let has_standard_args = router
-        .metadata
-        .cli_config
-        .as_ref()
-        .map(|c| c.standard_global_args)
-        .unwrap_or(false);
+        .iter()
+        .flat_map(|c| &c.global_flags)
+        .map(|flag| {
+            let help = format!("Enable --{flag} for every command");
+            quote! {
+                .arg(
+                    Arg::new(#flag)
+                        .long(#flag)
+                        .help(#help)
+                        .action(ArgAction::SetTrue)
+                        .global(true)
+                )
+            }
+        });
 
-    // Build command initialization based on what we have
-    let cmd_init = match (has_global_args, has_standard_args) {
-        (true, true) => quote! {
-            let mut cmd = Command::new(#cli_name)
-                .about(#about)
-                .version(env!("CARGO_PKG_VERSION"))
-                #global_args
-                #standard_args;
-        },
-        (true, false) => quote! {
-            let mut cmd = Command::new(#cli_name)
-                .about(#about)
-                .version(env!("CARGO_PKG_VERSION"))
-                #global_args;
-        },
-        (false, true) => quote! {
-            let mut cmd = Command::new(#cli_name)
-                .about(#about)
-                .version(env!("CARGO_PKG_VERSION"))
-                #standard_args;
-        },
-        (false, false) => quote! {
-            let mut cmd = Command::new(#cli_name)
-                .about(#about)
-                .version(env!("CARGO_PKG_VERSION"));
-        },
+    let cmd_init = quote! {
+        let mut cmd = Command::new(#cli_name)
+            .about(#about)
+            .version(env!("CARGO_PKG_VERSION"))
+            #global_args
+            #standard_args
+            #( #global_flags )*;
     };
 
     quote! {
@@ -180,14 +166,70 @@ fn generate_create_cli_command(router: &RouterDef) -> TokenStream {
 
             #cmd_init
 
-            // Test re-enabling this expansion
-            #(#subcommands)*
+            #( cmd = cmd.subcommand(#subcommands); )*
 
             cmd
         }
     }
 }
 
+/// Tools grouped by their CLI command path, in declaration order
+#[derive(Default)]
+struct CommandGroup<'a> {
+    tools: Vec<&'a ToolDef>,
+    groups: Vec<(String, CommandGroup<'a>)>,
+}
+
+impl<'a> CommandGroup<'a> {
+    fn new(tools: &'a [ToolDef]) -> Self {
+        let mut root = Self::default();
+        for tool in tools {
+            let path = tool
+                .metadata
+                .cli_config
+                .as_ref()
+                .map(|c| c.command_path.as_slice())
+                .unwrap_or_default();
+            root.insert(path, tool);
+        }
+        root
+    }
+
+    fn insert(&mut self, path: &[String], tool: &'a ToolDef) {
+        let Some((first, rest)) = path.split_first() else {
+            self.tools.push(tool);
+            return;
+        };
+        let name = to_kebab_case(first);
+        let index = match self.groups.iter().position(|(n, _)| *n == name) {
+            Some(index) => index,
+            None => {
+                self.groups.push((name, CommandGroup::default()));
+                self.groups.len() - 1
+            }
+        };
+        self.groups[index].1.insert(rest, tool);
+    }
+}
+
+/// Generates the subcommands of a group: its tools, then its nested groups
+fn generate_group_subcommands(group: &CommandGroup<'_>) -> Vec<TokenStream> {
+    let tools = group
+        .tools
+        .iter()
+        .map(|tool| generate_tool_subcommand(tool));
+    let groups = group.groups.iter().map(|(name, inner)| {
+        let subcommands = generate_group_subcommands(inner);
+        quote! {
+            Command::new(#name)
+                .subcommand_required(true)
+                .arg_required_else_help(true)
+                #( .subcommand(#subcommands) )*
+        }
+    });
+    tools.chain(groups).collect()
+}
+
 /// Generates a subcommand for a single tool
 fn generate_tool_subcommand(tool: &ToolDef) -> TokenStream {
     // Use CLI name if configured, otherwise use tool_name or method_name
@@ -210,7 +252,7 @@ fn generate_tool_subcommand(tool: &ToolDef) -> TokenStream {
     let args: Vec<_> = tool
         .params
         .iter()
-        .filter(|p| matches!(p.source, ParamSource::Body))
+        .filter(|p| matches!(p.source, ParamSource::Body) && !p.metadata.skipped_in("cli"))
         .map(validation::generate_cli_arg_config)
         .collect();
 
@@ -261,40 +303,87 @@ fn generate_tool_subcommand(tool: &ToolDef) -> TokenStream {
         subcommand = quote! { #subcommand .arg(#arg) };
     }
 
-    quote! {
-        cmd = cmd.subcommand(#subcommand);
-    }
+    subcommand
 }
 
 /// Generates the execute_cli() method
 fn generate_execute_cli(router: &RouterDef) -> TokenStream {
-    let match_arms: Vec<_> = router
-        .tools
+    let dispatch = generate_group_dispatch(
+        &CommandGroup::new(&router.tools),
+        router,
+        &format_ident!("matches"),
+        0,
+    );
+
+    // Flags every command accepts, collected for `GlobalFlags` parameters
+    let cli_config = router.metadata.cli_config.as_ref();
+    let mut flags: Vec<_> = cli_config
         .iter()
-        .map(|tool| generate_execute_match_arm(tool, router))
+        .flat_map(|c| &c.global_flags)
+        .map(|flag| quote! { .with(#flag, u8::from(matches.get_flag(#flag))) })
         .collect();
+    if cli_config.is_some_and(|c| c.standard_global_args) {
+        flags.extend(["dry-run", "yes", "quiet"].map(|flag| {
+            quote! { .with(#flag, u8::from(matches.get_flag(#flag))) }
+        }));
+        flags.push(quote! { .with("verbose", matches.get_count("verbose")) });
+    }
 
     quote! {
         /// Executes a tool based on parsed CLI arguments
         pub async fn execute_cli(&self, matches: ::universal_tool_core::cli::clap::ArgMatches) -> Result<(), ::universal_tool_core::prelude::ToolError> {
-            match matches.subcommand() {
-                #(#match_arms,)*
-                Some((cmd, _)) => {
-                    eprintln!("Error: Unknown command '{}'", cmd);
-                    eprintln!("Try '--help' for more information.");
-                    Err(::universal_tool_core::prelude::ToolError::new(
-                        ::universal_tool_core::prelude::ErrorCode::NotFound,
-                        format!("Unknown command: {}", cmd)
-                    ))
-                },
-                None => {
-                    eprintln!("No command specified");
-                    eprintln!("Try '--help' for more information.");
-                    Err(::universal_tool_core::prelude::ToolError::new(
-                        ::universal_tool_core::prelude::ErrorCode::InvalidArgument,
-                        "No command specified"
-                    ))
-                }
+            self.execute_cli_with_state(matches, &::universal_tool_core::state::ToolState::new()).await
+        }
+
+        /// Executes a tool based on parsed CLI arguments, injecting `from_state` parameters from `state`
+        #[allow(unused_variables)]
+        pub async fn execute_cli_with_state(
+            &self,
+            matches: ::universal_tool_core::cli::clap::ArgMatches,
+            state: &::universal_tool_core::state::ToolState,
+        ) -> Result<(), ::universal_tool_core::prelude::ToolError> {
+            let global_flags = ::universal_tool_core::flags::GlobalFlags::new() #( #flags )*;
+            #dispatch
+        }
+    }
+}
+
+/// Generates the `match` over a group's subcommands, recursing into nested groups
+fn generate_group_dispatch(
+    group: &CommandGroup<'_>,
+    router: &RouterDef,
+    matches: &Ident,
+    depth: usize,
+) -> TokenStream {
+    let tool_arms = group
+        .tools
+        .iter()
+        .map(|tool| generate_execute_match_arm(tool, router));
+    let group_arms = group.groups.iter().map(|(name, inner)| {
+        let inner_matches = format_ident!("group_matches_{}", depth);
+        let inner_dispatch = generate_group_dispatch(inner, router, &inner_matches, depth + 1);
+        quote! { Some((#name, #inner_matches)) => #inner_dispatch }
+    });
+
+    quote! {
+        match #matches.subcommand() {
+            #(#tool_arms,)*
+            #(#group_arms,)*
+            Some((cmd, _)) => {
+                eprintln!("Error: Unknown command '{}'", cmd);
+                eprintln!("Try '--help' for more information.");
+                Err(::universal_tool_core::prelude::ToolError::new(
+                    ::universal_tool_core::prelude::ErrorCode::NotFound,
+                    format!("Unknown command: {}", cmd)
+                ))
+            },
+            None => {
+                eprintln!("No command specified");
+                eprintln!("Try '--help' for more information.");
+                Err(::universal_tool_core::prelude::ToolError::new(
+                    ::universal_tool_core::prelude::ErrorCode::InvalidArgument,
+                    "No command specified"
+                ))
             }
         }
     }
@@ -419,6 +508,38 @@ fn generate_execute_match_arm(tool: &ToolDef, router: &RouterDef) -> TokenStream
         .and_then(|c| c.confirm.as_ref())
         .is_some();
 
+    let hooked = |call: TokenStream| {
+        crate::codegen::shared::generate_hooked_call(
+            router,
+            tool,
+            "cli",
+            quote! { self },
+            |p| {
+                let name = &p.name;
+                quote! { #name }
+            },
+            call,
+        )
+    };
+
+    // Streaming tools print each item as it arrives instead of one final document
+    if tool.stream_item.is_some() {
+        let print = hooked(quote! {
+            {
+                let stream = #method_call;
+                ::universal_tool_core::cli::print_stream(stream).await
+            }
+        });
+        return quote! {
+            Some((#cli_name_str, sub_matches)) => {
+                #( #param_extractions )*
+
+                #print
+            }
+        };
+    }
+    let method_call = hooked(method_call);
+
     // Generate the match arm
     // Note: We need to ensure proper statement separation when expanding param_extractions
     quote! {
@@ -0,0 +1,203 @@
+//! Typed REST client code generation for Universal Tool Framework
+//!
+//! With the client feature, `rest(client = "ItemsClient")` on a router generates an
+//! `ItemsClient` struct with one async method per tool that calls the tool's REST
+//! route over HTTP.
+
+use crate::codegen::rest::determine_http_method;
+use crate::codegen::rest::generate_route_path;
+use crate::codegen::rest::rest_prefix;
+use crate::codegen::types::get_generic_args;
+use crate::model::HttpMethod;
+use crate::model::ParamSource;
+use crate::model::RouterDef;
+use crate::model::ToolDef;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the client struct and its methods
+pub fn generate_client(router: &RouterDef) -> TokenStream {
+    let Some(client_name) = router
+        .metadata
+        .rest_config
+        .as_ref()
+        .and_then(|c| c.client.as_ref())
+    else {
+        return TokenStream::new();
+    };
+    let struct_name = router
+        .struct_type
+        .segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_default();
+    let struct_doc = format!(" Typed HTTP client for the REST routes of `{struct_name}`");
+
+    let prefix = rest_prefix(router);
+    let methods = router
+        .tools
+        .iter()
+        .map(|tool| generate_client_method(tool, &prefix));
+
+    quote! {
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone)]
+        pub struct #client_name {
+            inner: ::universal_tool_core::client::RestClient,
+        }
+
+        #[allow(dead_code)]
+        impl #client_name {
+            /// Client for the service at `base_url` (e.g. `http://localhost:3000`)
+            pub fn new(base_url: impl ::std::convert::Into<::std::string::String>) -> Self {
+                Self {
+                    inner: ::universal_tool_core::client::RestClient::new(base_url),
+                }
+            }
+
+            /// Client sending requests through `http`, e.g. to set timeouts or default headers
+            pub fn with_client(
+                base_url: impl ::std::convert::Into<::std::string::String>,
+                http: ::universal_tool_core::client::reqwest::Client,
+            ) -> Self {
+                Self {
+                    inner: ::universal_tool_core::client::RestClient::with_client(base_url, http),
+                }
+            }
+
+            /// Send `Authorization: Bearer <token>` with every request
+            pub fn with_bearer_token(
+                mut self,
+                token: impl ::std::convert::Into<::std::string::String>,
+            ) -> Self {
+                self.inner = self.inner.with_bearer_token(token);
+                self
+            }
+
+            #( #methods )*
+        }
+    }
+}
+
+/// Generates the client method calling one tool
+fn generate_client_method(tool: &ToolDef, prefix: &str) -> TokenStream {
+    let method_name = &tool.method_name;
+    let vis = &tool.visibility;
+    let doc = format!(" {}", tool.metadata.description);
+
+    // Everything the caller supplies; injected parameters stay on the server
+    let params: Vec<_> = tool
+        .params
+        .iter()
+        .filter(|p| {
+            matches!(
+                p.source,
+                ParamSource::Body | ParamSource::Path | ParamSource::Query
+            ) && !p.metadata.skipped_in("rest")
+        })
+        .collect();
+    let args = params.iter().map(|p| {
+        let name = &p.name;
+        let ty = &p.ty;
+        quote! { #name: #ty }
+    });
+
+    let path = generate_path(tool, prefix);
+    let http_method = match determine_http_method(tool) {
+        HttpMethod::Get => quote! { GET },
+        HttpMethod::Post => quote! { POST },
+        HttpMethod::Put => quote! { PUT },
+        HttpMethod::Delete => quote! { DELETE },
+        HttpMethod::Patch => quote! { PATCH },
+    };
+
+    let queries = params
+        .iter()
+        .filter(|p| p.source == ParamSource::Query)
+        .map(|p| {
+            let name = &p.name;
+            if p.is_optional {
+                quote! {
+                    if let ::std::option::Option::Some(query) = &#name {
+                        request = request.query(query);
+                    }
+                }
+            } else {
+                quote! { request = request.query(&#name); }
+            }
+        });
+
+    let body_fields: Vec<_> = params
+        .iter()
+        .filter(|p| p.source == ParamSource::Body)
+        .map(|p| {
+            let name = &p.name;
+            let key = name.to_string();
+            quote! { (#key, ::serde_json::to_value(&#name)) }
+        })
+        .collect();
+    let body = if body_fields.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let body = ::universal_tool_core::client::json_object([#( #body_fields ),*])?;
+            request = request.json(&body);
+        }
+    };
+
+    let (return_type, send) = match &tool.stream_item {
+        Some(item) => (quote! { ::std::vec::Vec<#item> }, quote! { send_stream }),
+        None => {
+            let ok_type = get_generic_args(&tool.return_type)
+                .first()
+                .map(|ty| quote! { #ty })
+                .unwrap_or_else(|| quote! { () });
+            (ok_type, quote! { send })
+        }
+    };
+
+    quote! {
+        #[doc = #doc]
+        #vis async fn #method_name(
+            &self,
+            #( #args ),*
+        ) -> ::std::result::Result<#return_type, ::universal_tool_core::error::ToolError> {
+            #path
+            #[allow(unused_mut)]
+            let mut request = self
+                .inner
+                .request(::universal_tool_core::client::Method::#http_method, &path);
+            #( #queries )*
+            #body
+            self.inner.#send(request).await
+        }
+    }
+}
+
+/// Generates `let path = ...;`, filling `:param` segments from path parameters
+fn generate_path(tool: &ToolDef, prefix: &str) -> TokenStream {
+    let full_path = format!(
+        "{}{}",
+        prefix.trim_end_matches('/'),
+        generate_route_path(tool)
+    );
+
+    let segments = full_path.split('/').map(|segment| {
+        let param = segment.strip_prefix(':').and_then(|name| {
+            tool.params
+                .iter()
+                .find(|p| p.source == ParamSource::Path && p.name == name)
+        });
+        match param {
+            Some(param) => {
+                let name = &param.name;
+                quote! { &::universal_tool_core::client::path_segment(&#name)? }
+            }
+            None => quote! { #segment },
+        }
+    });
+
+    quote! {
+        let path = [#( #segments ),*].join("/");
+    }
+}
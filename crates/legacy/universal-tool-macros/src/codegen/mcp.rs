@@ -34,7 +34,11 @@ pub fn generate_mcp_methods(router: &RouterDef) -> TokenStream {
 
 /// Generates the handle_mcp_call() method for dispatching MCP tool calls
 fn generate_mcp_dispatch_method(router: &RouterDef) -> TokenStream {
-    let match_arms: Vec<_> = router.tools.iter().map(generate_tool_match_arm).collect();
+    let match_arms: Vec<_> = router
+        .tools
+        .iter()
+        .map(|tool| generate_tool_match_arm(tool, router))
+        .collect();
 
     quote! {
         /// Handles MCP tool calls by dispatching to the appropriate tool method
@@ -43,6 +47,20 @@ fn generate_mcp_dispatch_method(router: &RouterDef) -> TokenStream {
             &self,
             method: &str,
             params: ::serde_json::Value
+        ) -> ::std::result::Result<::serde_json::Value, ::universal_tool_core::error::ToolError> {
+            self.handle_mcp_call_with_state(method, params, &::universal_tool_core::state::ToolState::new()).await
+        }
+
+        /// Handles MCP tool calls, injecting `from_state` parameters from `state`
+        ///
+        /// `McpProgressReporter` and `CancellationToken` parameters come from the
+        /// `McpCallContext` in `state`, if any.
+        #[allow(unused_variables)]
+        pub async fn handle_mcp_call_with_state(
+            &self,
+            method: &str,
+            params: ::serde_json::Value,
+            state: &::universal_tool_core::state::ToolState,
         ) -> ::std::result::Result<::serde_json::Value, ::universal_tool_core::error::ToolError> {
             match method {
                 #(#match_arms)*
@@ -64,7 +82,7 @@ fn generate_mcp_dispatch_method_mcp(router: &RouterDef) -> TokenStream {
     let match_arms: Vec<_> = router
         .tools
         .iter()
-        .map(generate_tool_match_arm_mcp)
+        .map(|tool| generate_tool_match_arm_mcp(tool, router))
         .collect();
 
     quote! {
@@ -73,6 +91,20 @@ fn generate_mcp_dispatch_method_mcp(router: &RouterDef) -> TokenStream {
             &self,
             method: &str,
             params: ::serde_json::Value
+        ) -> ::std::result::Result<::universal_tool_core::mcp::McpOutput, ::universal_tool_core::error::ToolError> {
+            self.handle_mcp_call_mcp_with_state(method, params, &::universal_tool_core::state::ToolState::new()).await
+        }
+
+        /// Like `handle_mcp_call_mcp`, injecting `from_state` parameters from `state`
+        ///
+        /// `McpProgressReporter` and `CancellationToken` parameters come from the
+        /// `McpCallContext` in `state`, if any.
+        #[allow(unused_variables)]
+        pub async fn handle_mcp_call_mcp_with_state(
+            &self,
+            method: &str,
+            params: ::serde_json::Value,
+            state: &::universal_tool_core::state::ToolState,
         ) -> ::std::result::Result<::universal_tool_core::mcp::McpOutput, ::universal_tool_core::error::ToolError> {
             match method {
                 #(#match_arms)*
@@ -90,7 +122,7 @@ fn generate_mcp_dispatch_method_mcp(router: &RouterDef) -> TokenStream {
 }
 
 /// Generates a match arm for a single tool
-fn generate_tool_match_arm(tool: &ToolDef) -> TokenStream {
+fn generate_tool_match_arm(tool: &ToolDef, router: &RouterDef) -> TokenStream {
     let tool_name = &tool.tool_name;
 
     // Generate per-field extraction bindings for MCP
@@ -122,7 +154,7 @@ fn generate_tool_match_arm(tool: &ToolDef) -> TokenStream {
     };
 
     // Generate method call (now uses local variables instead of params.#field)
-    let method_call = generate_method_call(tool);
+    let method_call = generate_method_call(tool, router);
 
     quote! {
         #tool_name => {
@@ -135,7 +167,7 @@ fn generate_tool_match_arm(tool: &ToolDef) -> TokenStream {
 }
 
 /// Generates the method call with proper parameter passing and result serialization
-fn generate_method_call(tool: &ToolDef) -> TokenStream {
+fn generate_method_call(tool: &ToolDef, router: &RouterDef) -> TokenStream {
     // Build parameter list, handling special MCP parameters
     // For normal params, use the local variable bound by per-field extraction
     let param_args: Vec<_> = tool
@@ -146,7 +178,10 @@ fn generate_method_call(tool: &ToolDef) -> TokenStream {
             let ty = &param.ty;
             let ty_str = quote!(#ty).to_string();
 
-            if ty_str.contains("ProgressReporter") {
+            if param.source.is_injected() {
+                // Bound from state or the call context by the extraction above
+                quote! { #name }
+            } else if ty_str.contains("ProgressReporter") {
                 // For now, pass None for progress reporter
                 // TODO(3): In the future, could accept progress_token in params and create reporter
                 quote! { None }
@@ -165,14 +200,57 @@ fn generate_method_call(tool: &ToolDef) -> TokenStream {
     let method_call =
         crate::codegen::shared::generate_normalized_method_call(tool, quote! { self }, param_args);
 
+    if tool.stream_item.is_some() {
+        // Streaming tools return the array of all items
+        let collect = generate_hooked_collect(tool, router, method_call);
+        return quote! {
+            let items = #collect?;
+            ::std::result::Result::Ok(::serde_json::to_value(&items)?)
+        };
+    }
+    let method_call = generate_hooked(tool, router, method_call);
+
     quote! {
         let result = #method_call?;
         ::std::result::Result::Ok(::serde_json::to_value(&result)?)
     }
 }
 
+/// Wraps an MCP tool call in the router's hooks, if enabled
+fn generate_hooked(tool: &ToolDef, router: &RouterDef, call: TokenStream) -> TokenStream {
+    crate::codegen::shared::generate_hooked_call(
+        router,
+        tool,
+        "mcp",
+        quote! { self },
+        |p| {
+            let name = &p.name;
+            quote! { #name }
+        },
+        call,
+    )
+}
+
+/// Generates the collection of a streaming tool's items, wrapped in the router's hooks
+fn generate_hooked_collect(
+    tool: &ToolDef,
+    router: &RouterDef,
+    method_call: TokenStream,
+) -> TokenStream {
+    generate_hooked(
+        tool,
+        router,
+        quote! {
+            {
+                let stream = #method_call;
+                ::universal_tool_core::stream::try_collect(stream).await
+            }
+        },
+    )
+}
+
 /// Generates a match arm for the new MCP dispatch method with output mode support
-fn generate_tool_match_arm_mcp(tool: &ToolDef) -> TokenStream {
+fn generate_tool_match_arm_mcp(tool: &ToolDef, router: &RouterDef) -> TokenStream {
     let tool_name = &tool.tool_name;
 
     // Generate per-field extraction bindings for MCP
@@ -212,7 +290,10 @@ fn generate_tool_match_arm_mcp(tool: &ToolDef) -> TokenStream {
             let ty = &param.ty;
             let ty_str = quote!(#ty).to_string();
 
-            if ty_str.contains("ProgressReporter") {
+            if param.source.is_injected() {
+                // Bound from state or the call context by the extraction above
+                quote! { #name }
+            } else if ty_str.contains("ProgressReporter") {
                 quote! { None }
             } else if ty_str.contains("CancellationToken") {
                 quote! { ::universal_tool_core::mcp::CancellationToken::new() }
@@ -225,28 +306,56 @@ fn generate_tool_match_arm_mcp(tool: &ToolDef) -> TokenStream {
     let method_call =
         crate::codegen::shared::generate_normalized_method_call(tool, quote! { self }, param_args);
 
+    let output_mode = tool
+        .metadata
+        .mcp_config
+        .as_ref()
+        .and_then(|c| c.output_mode);
+    let text_mode = matches!(
+        output_mode,
+        Some(crate::model::McpOutputMode::Text | crate::model::McpOutputMode::Both)
+    );
+
     // Determine output mode at codegen time
-    let output_mode_tokens = if let Some(mcp_config) = &tool.metadata.mcp_config {
-        if matches!(
-            mcp_config.output_mode,
-            Some(crate::model::McpOutputMode::Text)
-        ) {
-            // TEXT mode: require McpFormatter at compile time
-            quote! {
-                let result = #method_call?;
-                let text = ::universal_tool_core::mcp::McpFormatter::mcp_format_text(&result);
-                ::std::result::Result::Ok(::universal_tool_core::mcp::McpOutput::Text(text))
-            }
+    let output_mode_tokens = if tool.stream_item.is_some() {
+        // Streaming tools answer with one content chunk per item, as text in "both" mode
+        let format_item = if text_mode {
+            quote! { ::std::result::Result::<_, ::serde_json::Error>::Ok(::universal_tool_core::mcp::McpFormatter::mcp_format_text(item)) }
         } else {
-            // JSON mode
-            quote! {
-                let result = #method_call?;
-                let val = ::serde_json::to_value(&result)?;
-                ::std::result::Result::Ok(::universal_tool_core::mcp::McpOutput::Json(val))
-            }
+            quote! { ::serde_json::to_string_pretty(item) }
+        };
+        let collect = generate_hooked_collect(tool, router, method_call);
+        quote! {
+            let items = #collect?;
+            let chunks = items
+                .iter()
+                .map(|item| #format_item)
+                .collect::<::std::result::Result<::std::vec::Vec<_>, _>>()?;
+            ::std::result::Result::Ok(::universal_tool_core::mcp::McpOutput::Chunks(chunks))
+        }
+    } else if output_mode == Some(crate::model::McpOutputMode::Text) {
+        // TEXT mode: require McpFormatter at compile time
+        let method_call = generate_hooked(tool, router, method_call);
+        quote! {
+            let result = #method_call?;
+            let text = ::universal_tool_core::mcp::McpFormatter::mcp_format_text(&result);
+            ::std::result::Result::Ok(::universal_tool_core::mcp::McpOutput::Text(text))
+        }
+    } else if output_mode == Some(crate::model::McpOutputMode::Both) {
+        // BOTH mode: require McpFormatter and Serialize; the request may narrow it
+        let method_call = generate_hooked(tool, router, method_call);
+        quote! {
+            let result = #method_call?;
+            let format = state
+                .get::<::universal_tool_core::mcp::McpCallContext>()
+                .ok()
+                .and_then(|ctx| ctx.output)
+                .unwrap_or(::universal_tool_core::mcp::McpOutputFormat::Both);
+            ::universal_tool_core::mcp::McpOutput::render(&result, format)
         }
     } else {
-        // Default to JSON
+        // JSON mode, the default
+        let method_call = generate_hooked(tool, router, method_call);
         quote! {
             let result = #method_call?;
             let val = ::serde_json::to_value(&result)?;
@@ -353,6 +462,10 @@ fn generate_tool_definition(tool: &ToolDef) -> TokenStream {
 fn generate_param_schema(param: &ParamDef) -> TokenStream {
     let param_type = &param.ty;
     let description = &param.metadata.description.as_deref().unwrap_or("");
+    let constraints = param.metadata.has_constraints().then(|| {
+        let constraints = validation::generate_schema_constraints(param);
+        quote! { #constraints.apply_to_schema(&mut json_schema); }
+    });
 
     quote! {
         {
@@ -366,6 +479,7 @@ fn generate_param_schema(param: &ParamDef) -> TokenStream {
                     map.insert("description".to_string(), ::serde_json::Value::String(#description.to_string()));
                 }
             }
+            #constraints
             json_schema
         }
     }
@@ -396,7 +510,7 @@ fn generate_tool_schema(tool: &ToolDef) -> TokenStream {
             .map(|param| {
                 let name = &param.name.to_string();
                 let schema = generate_param_schema(param);
-                let required = !is_optional_type(&param.ty);
+                let required = !is_optional_type(&param.ty) && !param.metadata.has_fallback("mcp");
                 quote! {
                     properties.insert(#name.to_string(), #schema);
                     if #required {
@@ -4,6 +4,7 @@
 //! - CLI (Command Line Interface)
 //! - REST (HTTP API)
 //! - MCP (Model Context Protocol)
+//! - WebSocket (JSON-RPC)
 //!
 //! The generation is split into shared utilities and interface-specific modules.
 
@@ -18,6 +19,12 @@ pub mod cli; // CLI generation (Task 4 - implemented)
 pub mod mcp;
 pub mod rest; // REST generation (Task 6 - implemented) // MCP generation (Task 8 - implemented)
 
+// JSON-RPC over WebSocket
+pub mod ws;
+
+// Typed HTTP clients for REST routers
+pub mod client;
+
 // Re-export commonly used types
 // pub use shared::*;
 // pub use structs::*;
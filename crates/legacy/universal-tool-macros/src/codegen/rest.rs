@@ -5,8 +5,11 @@
 
 use crate::codegen::shared::to_kebab_case;
 use crate::codegen::shared::to_pascal_case;
+use crate::codegen::types::get_generic_args;
 use crate::model::HttpMethod;
+use crate::model::ParamDef;
 use crate::model::ParamSource;
+use crate::model::RestAuth;
 use crate::model::RouterDef;
 use crate::model::ToolDef;
 use proc_macro2::TokenStream;
@@ -73,17 +76,9 @@ fn generate_create_rest_router(router: &RouterDef, module_name: &syn::Ident) ->
     let routes = router
         .tools
         .iter()
-        .map(|tool| generate_tool_route(tool, module_name));
+        .map(|tool| generate_tool_route(tool, router, module_name));
 
-    // Compute prefix precedence: rest_config.prefix > base_path > "/api"
-    let prefix: String = router
-        .metadata
-        .rest_config
-        .as_ref()
-        .and_then(|c| c.prefix.as_ref())
-        .cloned()
-        .or_else(|| router.metadata.base_path.clone())
-        .unwrap_or_else(|| "/api".to_string());
+    let prefix = rest_prefix(router);
 
     quote! {
         /// Creates an axum::Router with routes for all tools, nested under configured prefix.
@@ -107,6 +102,28 @@ fn generate_create_rest_router(router: &RouterDef, module_name: &syn::Ident) ->
     }
 }
 
+/// Prefix all routes are nested under: rest_config.prefix > base_path > "/api"
+pub fn rest_prefix(router: &RouterDef) -> String {
+    router
+        .metadata
+        .rest_config
+        .as_ref()
+        .and_then(|c| c.prefix.as_ref())
+        .cloned()
+        .or_else(|| router.metadata.base_path.clone())
+        .unwrap_or_else(|| "/api".to_string())
+}
+
+/// Authentication a tool's route requires: its own rest(auth), else the router's
+pub fn route_auth(tool: &ToolDef, router: &RouterDef) -> RestAuth {
+    tool.metadata
+        .rest_config
+        .as_ref()
+        .and_then(|c| c.auth)
+        .or_else(|| router.metadata.rest_config.as_ref().and_then(|c| c.auth))
+        .unwrap_or(RestAuth::None)
+}
+
 /// Generates parameter structs for each tool
 fn generate_param_structs(router: &RouterDef) -> TokenStream {
     router
@@ -116,11 +133,7 @@ fn generate_param_structs(router: &RouterDef) -> TokenStream {
             let struct_name = get_params_struct_name(tool);
 
             // Only generate struct if there are body parameters
-            let body_params: Vec<_> = tool
-                .params
-                .iter()
-                .filter(|p| matches!(p.source, ParamSource::Body))
-                .collect();
+            let body_params: Vec<_> = tool.params.iter().filter(|p| is_body_param(p)).collect();
 
             if body_params.is_empty() {
                 quote! {}
@@ -135,9 +148,23 @@ fn generate_param_structs(router: &RouterDef) -> TokenStream {
                         .unwrap_or("")
                         .to_string();
 
-                    quote! {
-                        #[doc = #doc]
-                        pub #name: #ty
+                    // Parameters with a fallback may be left out of the request
+                    if param.metadata.has_fallback("rest") {
+                        let ty = if param.is_optional {
+                            quote! { #ty }
+                        } else {
+                            quote! { ::std::option::Option<#ty> }
+                        };
+                        quote! {
+                            #[doc = #doc]
+                            #[serde(default)]
+                            pub #name: #ty
+                        }
+                    } else {
+                        quote! {
+                            #[doc = #doc]
+                            pub #name: #ty
+                        }
                     }
                 });
 
@@ -152,14 +179,43 @@ fn generate_param_structs(router: &RouterDef) -> TokenStream {
         .collect()
 }
 
+/// Whether a parameter is a field of the tool's generated params struct
+fn is_body_param(param: &ParamDef) -> bool {
+    param.source == ParamSource::Body && !param.metadata.skipped_in("rest")
+}
+
+/// Whether the handler binds a parameter to a local filled from its env var or
+/// default: skipped parameters, and body parameters with a fallback
+fn is_filled_param(param: &ParamDef) -> bool {
+    if param.source.is_injected() {
+        return false;
+    }
+    param.metadata.skipped_in("rest")
+        || (is_body_param(param) && param.metadata.has_fallback("rest"))
+}
+
+/// Expression for a parameter's value inside the handler
+fn handler_value(param: &ParamDef) -> TokenStream {
+    let name = &param.name;
+    if is_body_param(param) && !is_filled_param(param) {
+        quote! { params.#name }
+    } else {
+        quote! { #name }
+    }
+}
+
 /// Generates a route for a single tool
-fn generate_tool_route(tool: &ToolDef, module_name: &syn::Ident) -> TokenStream {
+fn generate_tool_route(
+    tool: &ToolDef,
+    router: &RouterDef,
+    module_name: &syn::Ident,
+) -> TokenStream {
     let method = determine_http_method(tool);
     let path = generate_route_path(tool);
     let _handler_name = get_handler_name(tool);
 
     // Generate the handler function
-    let handler = generate_handler(tool, module_name);
+    let handler = generate_handler(tool, router, module_name);
 
     // Generate the route registration
     let route_method = match method {
@@ -170,17 +226,35 @@ fn generate_tool_route(tool: &ToolDef, module_name: &syn::Ident) -> TokenStream
         HttpMethod::Patch => quote! { ::universal_tool_core::rest::routing::patch },
     };
 
+    // Protected routes check credentials in a layer before the handler runs
+    let auth_layer = match route_auth(tool, router) {
+        RestAuth::None => quote! {},
+        RestAuth::Bearer => {
+            let tool_name = &tool.tool_name;
+            quote! {
+                .route_layer(::universal_tool_core::rest::middleware::from_fn(
+                    |request: ::universal_tool_core::rest::extract::Request,
+                     next: ::universal_tool_core::rest::middleware::Next| {
+                        ::universal_tool_core::auth::require(
+                            ::universal_tool_core::auth::AuthScheme::Bearer,
+                            #tool_name,
+                            request,
+                            next,
+                        )
+                    },
+                ))
+            }
+        }
+    };
+
     quote! {
-        .route(#path, #route_method(#handler))
+        .route(#path, #route_method(#handler) #auth_layer)
     }
 }
 
 /// Generates the handler closure for a tool
-fn generate_handler(tool: &ToolDef, module_name: &syn::Ident) -> TokenStream {
-    let has_body_params = tool
-        .params
-        .iter()
-        .any(|p| matches!(p.source, ParamSource::Body));
+fn generate_handler(tool: &ToolDef, router: &RouterDef, module_name: &syn::Ident) -> TokenStream {
+    let has_body_params = tool.params.iter().any(is_body_param);
 
     // Generate parameter extraction
     let param_extractors = generate_param_extractors(tool);
@@ -211,11 +285,46 @@ fn generate_handler(tool: &ToolDef, module_name: &syn::Ident) -> TokenStream {
     let method_args_vec: Vec<TokenStream> = tool
         .params
         .iter()
+        .map(|param| match param.source {
+            ParamSource::Progress | ParamSource::Cancellation | ParamSource::Flags => {
+                crate::codegen::validation::generate_context_param_value(param, "rest")
+            }
+            _ => handler_value(param),
+        })
+        .collect();
+
+    // Skipped parameters, and body parameters left out of the request, are filled
+    // from their env var or default before validation
+    let fills: Vec<TokenStream> = tool
+        .params
+        .iter()
+        .filter(|param| is_filled_param(param))
         .map(|param| {
             let name = &param.name;
-            match param.source {
-                ParamSource::Body => quote! { params.#name },
-                _ => quote! { #name },
+            let ty = &param.ty;
+            let fill = crate::codegen::validation::generate_fill(param, "rest");
+            let filled = quote! {
+                match #fill {
+                    Ok(value) => value,
+                    Err(e) => return { #error_handling },
+                }
+            };
+            if param.metadata.skipped_in("rest") {
+                quote! { let #name: #ty = #filled; }
+            } else if param.is_optional {
+                quote! {
+                    let #name: #ty = match params.#name {
+                        value @ ::std::option::Option::Some(_) => value,
+                        ::std::option::Option::None => #filled,
+                    };
+                }
+            } else {
+                quote! {
+                    let #name: #ty = match params.#name {
+                        ::std::option::Option::Some(value) => value,
+                        ::std::option::Option::None => #filled,
+                    };
+                }
             }
         })
         .collect();
@@ -227,24 +336,79 @@ fn generate_handler(tool: &ToolDef, module_name: &syn::Ident) -> TokenStream {
         method_args_vec,
     );
 
+    // Constraint checks run before the method and answer with the error response
+    let checks: Vec<TokenStream> = tool
+        .params
+        .iter()
+        .filter(|param| param.source != ParamSource::State && param.metadata.has_constraints())
+        .map(|param| {
+            crate::codegen::validation::generate_constraint_checks(param, &handler_value(param))
+        })
+        .collect();
+    let validation = if checks.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let checked = (|| -> ::std::result::Result<(), ::universal_tool_core::error::ToolError> {
+                #( #checks )*
+                ::std::result::Result::Ok(())
+            })();
+            if let Err(e) = checked {
+                return { #error_handling };
+            }
+        }
+    };
+
+    let hooked = |call: TokenStream| {
+        crate::codegen::shared::generate_hooked_call(
+            router,
+            tool,
+            "rest",
+            quote! { state },
+            handler_value,
+            call,
+        )
+    };
+
+    // Streaming tools run on a spawned task feeding a server-sent events response
+    let respond = if tool.stream_item.is_some() {
+        let forward = hooked(quote! {
+            {
+                let stream = #method_call;
+                sender.forward(stream).await
+            }
+        });
+        quote! {
+            ::universal_tool_core::rest::sse_stream(move |sender| async move {
+                let _ = #forward;
+            })
+        }
+    } else {
+        let method_call = hooked(method_call);
+        quote! {
+            match #method_call {
+                Ok(result) => (::universal_tool_core::rest::StatusCode::OK, ::universal_tool_core::rest::Json(result)).into_response(),
+                Err(e) => { #error_handling },
+            }
+        }
+    };
+
     if has_body_params {
         let params_struct = get_params_struct_name(tool);
         quote! {
             |::universal_tool_core::rest::State(state): ::universal_tool_core::rest::State<::std::sync::Arc<Self>>#param_extractors,
              ::universal_tool_core::rest::Json(params): ::universal_tool_core::rest::Json<#module_name::#params_struct>| async move {
-                match #method_call {
-                    Ok(result) => (::universal_tool_core::rest::StatusCode::OK, ::universal_tool_core::rest::Json(result)).into_response(),
-                    Err(e) => { #error_handling },
-                }
+                #( #fills )*
+                #validation
+                #respond
             }
         }
     } else {
         quote! {
             |::universal_tool_core::rest::State(state): ::universal_tool_core::rest::State<::std::sync::Arc<Self>>#param_extractors| async move {
-                match #method_call {
-                    Ok(result) => (::universal_tool_core::rest::StatusCode::OK, ::universal_tool_core::rest::Json(result)).into_response(),
-                    Err(e) => { #error_handling },
-                }
+                #( #fills )*
+                #validation
+                #respond
             }
         }
     }
@@ -253,7 +417,7 @@ fn generate_handler(tool: &ToolDef, module_name: &syn::Ident) -> TokenStream {
 /// Generates parameter extractors for non-body parameters
 fn generate_param_extractors(tool: &ToolDef) -> TokenStream {
     let extractors = tool.params.iter()
-        .filter(|p| !matches!(p.source, ParamSource::Body))
+        .filter(|p| !matches!(p.source, ParamSource::Body) && !p.metadata.skipped_in("rest"))
         .map(|param| {
             let name = &param.name;
             let ty = &param.ty;
@@ -261,6 +425,7 @@ fn generate_param_extractors(tool: &ToolDef) -> TokenStream {
             match param.source {
                 ParamSource::Path => quote! { , ::universal_tool_core::rest::Path(#name): ::universal_tool_core::rest::Path<#ty> },
                 ParamSource::Query => quote! { , ::universal_tool_core::rest::Query(#name): ::universal_tool_core::rest::Query<#ty> },
+                ParamSource::State => quote! { , ::universal_tool_core::rest::Extension(#name): ::universal_tool_core::rest::Extension<#ty> },
                 _ => quote! {},
             }
         });
@@ -268,26 +433,183 @@ fn generate_param_extractors(tool: &ToolDef) -> TokenStream {
     quote! { #( #extractors )* }
 }
 
-/// Generates the get_openapi_spec() method
-fn generate_get_openapi_spec(_router: &RouterDef) -> TokenStream {
-    // TODO(3): Implement full OpenAPI generation when openapi feature is enabled
-    // This would use utoipa macros to generate a proper OpenAPI spec with schemas
-    // For now, return a placeholder that lets users know the feature is not enabled
+/// Generates the get_openapi_spec() method, plus openapi() when the openapi feature is enabled
+fn generate_get_openapi_spec(router: &RouterDef) -> TokenStream {
+    if !cfg!(feature = "openapi") {
+        return quote! {
+            /// Returns OpenAPI documentation for all tools
+            ///
+            /// Note: Full OpenAPI generation requires the 'openapi' feature to be enabled:
+            /// ```toml
+            /// universal-tool-core = { version = "0.1", features = ["rest", "openapi"] }
+            /// ```
+            pub fn get_openapi_spec(&self) -> String {
+                "OpenAPI generation requires the 'openapi' feature to be enabled".to_string()
+            }
+        };
+    }
+
+    let title = router
+        .struct_type
+        .segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_else(|| "tools".to_string());
+    let prefix = rest_prefix(router);
+    let operations = router.tools.iter().map(|tool| {
+        generate_openapi_operation(
+            tool,
+            &prefix,
+            router.metadata.openapi_tag.as_deref(),
+            route_auth(tool, router),
+        )
+    });
+
     quote! {
-        /// Returns OpenAPI documentation for all tools
+        /// Returns the OpenAPI document describing every REST route
         ///
-        /// Note: Full OpenAPI generation requires the 'openapi' feature to be enabled:
-        /// ```toml
-        /// universal-tool-core = { version = "0.1", features = ["rest", "openapi"] }
-        /// ```
+        /// Parameter, body and response schemas come from the types' `JsonSchema` impls.
+        pub fn openapi(&self) -> ::universal_tool_core::openapi::OpenApi {
+            ::universal_tool_core::openapi::document(
+                #title,
+                env!("CARGO_PKG_VERSION"),
+                ::std::vec![#( #operations ),*],
+            )
+        }
+
+        /// Returns the OpenAPI document for all tools as pretty-printed JSON
         pub fn get_openapi_spec(&self) -> String {
-            "OpenAPI generation requires the 'openapi' feature to be enabled".to_string()
+            self.openapi().to_pretty_json().unwrap_or_default()
+        }
+    }
+}
+
+/// Generates the `(path, method, operation)` entry documenting one tool
+fn generate_openapi_operation(
+    tool: &ToolDef,
+    prefix: &str,
+    tag: Option<&str>,
+    auth: RestAuth,
+) -> TokenStream {
+    let path = format!(
+        "{}{}",
+        prefix.trim_end_matches('/'),
+        generate_route_path(tool)
+    );
+    let method = match determine_http_method(tool) {
+        HttpMethod::Get => quote! { Get },
+        HttpMethod::Post => quote! { Post },
+        HttpMethod::Put => quote! { Put },
+        HttpMethod::Delete => quote! { Delete },
+        HttpMethod::Patch => quote! { Patch },
+    };
+    let name = &tool.tool_name;
+    let description = &tool.metadata.description;
+    let tag = match tag {
+        Some(tag) => quote! { ::std::option::Option::Some(#tag) },
+        None => quote! { ::std::option::Option::None },
+    };
+
+    let parameters = tool.params.iter().filter_map(|param| {
+        if param.metadata.skipped_in("rest") {
+            return None;
+        }
+        let location = match param.source {
+            ParamSource::Path => quote! { Path },
+            ParamSource::Query => quote! { Query },
+            _ => return None,
+        };
+        let ty = &param.ty;
+        let param_name = param.name.to_string();
+        let required = !param.is_optional;
+        let param_description = optional_str(param.metadata.description.as_deref());
+        let constraints = crate::codegen::validation::generate_schema_constraints(param);
+        Some(quote! {
+            .parameter(::universal_tool_core::openapi::parameter::<#ty>(
+                #param_name,
+                ::universal_tool_core::openapi::ParameterIn::#location,
+                #required,
+                #param_description,
+                #constraints,
+            ))
+        })
+    });
+
+    let properties: Vec<_> = tool
+        .params
+        .iter()
+        .filter(|p| is_body_param(p))
+        .map(|param| {
+            let ty = &param.ty;
+            let param_name = param.name.to_string();
+            let required = !param.is_optional && !param.metadata.has_fallback("rest");
+            let param_description = optional_str(param.metadata.description.as_deref());
+            let constraints = crate::codegen::validation::generate_schema_constraints(param);
+            quote! {
+                ::universal_tool_core::openapi::property::<#ty>(
+                    #param_name,
+                    #required,
+                    #param_description,
+                    #constraints,
+                )
+            }
+        })
+        .collect();
+    let request_body = if properties.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            .request_body(::std::option::Option::Some(
+                ::universal_tool_core::openapi::json_body(::std::vec![#( #properties ),*])
+            ))
         }
+    };
+
+    let response = match &tool.stream_item {
+        Some(item) => quote! {
+            ::universal_tool_core::openapi::sse_response::<#item>("Stream of results")
+        },
+        None => {
+            let ok_type = get_generic_args(&tool.return_type)
+                .first()
+                .map(|ty| quote! { #ty })
+                .unwrap_or_else(|| quote! { () });
+            quote! {
+                ::universal_tool_core::openapi::json_response::<#ok_type>("Successful response")
+            }
+        }
+    };
+
+    let operation = quote! {
+        ::universal_tool_core::openapi::operation(#name, #description, #tag)
+            #( #parameters )*
+            #request_body
+            .response("200", #response)
+    };
+    let operation = match auth {
+        RestAuth::None => operation,
+        RestAuth::Bearer => quote! { ::universal_tool_core::openapi::bearer_auth(#operation) },
+    };
+
+    quote! {
+        (
+            #path.to_string(),
+            ::universal_tool_core::openapi::PathItemType::#method,
+            #operation.build(),
+        )
+    }
+}
+
+/// `Option<&str>` literal tokens
+fn optional_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { ::std::option::Option::Some(#value) },
+        None => quote! { ::std::option::Option::None },
     }
 }
 
 /// Determines the HTTP method for a tool
-fn determine_http_method(tool: &ToolDef) -> HttpMethod {
+pub fn determine_http_method(tool: &ToolDef) -> HttpMethod {
     // Check if REST config specifies a method
     if let Some(rest_config) = &tool.metadata.rest_config {
         return rest_config.method;
@@ -312,7 +634,7 @@ fn determine_http_method(tool: &ToolDef) -> HttpMethod {
 }
 
 /// Generates the route path for a tool
-fn generate_route_path(tool: &ToolDef) -> String {
+pub fn generate_route_path(tool: &ToolDef) -> String {
     // Check if REST config specifies a path
     if let Some(rest_config) = &tool.metadata.rest_config
         && let Some(path) = &rest_config.path
@@ -345,67 +667,3 @@ fn get_handler_name(tool: &ToolDef) -> syn::Ident {
     let name = format!("handle_rest_{}", tool.method_name);
     syn::Ident::new(&name, tool.method_name.span())
 }
-
-/// Generate OpenAPI schemas for parameter structs
-#[allow(dead_code)] // Will be used when OpenAPI feature is completed
-fn generate_openapi_schemas(router: &RouterDef) -> TokenStream {
-    let schemas = router
-        .tools
-        .iter()
-        .filter(|tool| {
-            tool.params
-                .iter()
-                .any(|p| matches!(p.source, ParamSource::Body))
-        })
-        .map(|_tool| {
-            quote! {
-                // ToSchema is already derived above
-            }
-        });
-
-    quote! { #( #schemas )* }
-}
-
-/// Generate OpenAPI path documentation
-#[allow(dead_code)] // Will be used when OpenAPI feature is completed
-fn generate_openapi_paths(router: &RouterDef) -> TokenStream {
-    let paths = router.tools.iter().map(|tool| {
-        let fn_name = syn::Ident::new(
-            &format!("__openapi_path_{}", tool.method_name),
-            tool.method_name.span()
-        );
-        let path = generate_route_path(tool);
-        let method = determine_http_method(tool);
-        let method_str = format!("{method:?}").to_lowercase();
-        let description = &tool.metadata.description;
-
-        // Determine request body
-        let has_body = tool.params.iter().any(|p| matches!(p.source, ParamSource::Body));
-        let request_body = if has_body {
-            let struct_name = get_params_struct_name(tool);
-            quote! { request_body = #struct_name, }
-        } else {
-            quote! {}
-        };
-
-        // Extract the success type from Result<T, E>
-        let return_type = &tool.return_type;
-
-        quote! {
-            // OpenAPI paths are always generated when REST is enabled
-            #[::utoipa::path(
-                #method_str,
-                path = #path,
-                #request_body
-                responses(
-                    (status = 200, description = #description, body = #return_type),
-                    (status = 400, description = "Bad request", body = ::universal_tool_core::error::ToolError),
-                    (status = 500, description = "Internal server error", body = ::universal_tool_core::error::ToolError)
-                )
-            )]
-            async fn #fn_name() {}
-        }
-    });
-
-    quote! { #( #paths )* }
-}
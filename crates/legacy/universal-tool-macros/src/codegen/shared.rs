@@ -4,6 +4,8 @@
 
 #![allow(dead_code)]
 
+use crate::model::ParamDef;
+use crate::model::RouterDef;
 use crate::model::ToolDef;
 use proc_macro2::TokenStream;
 use quote::format_ident;
@@ -92,6 +94,64 @@ pub fn generate_normalized_method_call(
     }
 }
 
+/// Wraps `call`, an expression evaluating to `Result<_, ToolError>`, in the router's
+/// `ToolHooks::before_call`/`after_call` when the router sets `hooks`
+///
+/// `value` gives the expression holding each parameter's extracted value; the
+/// arguments are serialized before `call` runs.
+pub fn generate_hooked_call(
+    router: &RouterDef,
+    tool: &ToolDef,
+    interface: &str,
+    hooks: TokenStream,
+    value: impl Fn(&ParamDef) -> TokenStream,
+    call: TokenStream,
+) -> TokenStream {
+    if !router.metadata.hooks {
+        return call;
+    }
+
+    let tool_name = &tool.tool_name;
+    let interface_kind = match interface {
+        "cli" => quote! { Cli },
+        "rest" => quote! { Rest },
+        "ws" => quote! { Ws },
+        _ => quote! { Mcp },
+    };
+    let args = tool
+        .params
+        .iter()
+        .filter(|p| {
+            crate::codegen::validation::should_include_param(p, interface)
+                || (!p.source.is_injected() && p.metadata.skipped_in(interface))
+        })
+        .map(|p| {
+            let key = p.name.to_string();
+            let value = value(p);
+            quote! { (#key, ::serde_json::to_value(&#value)) }
+        });
+
+    quote! {
+        {
+            let call = ::universal_tool_core::hooks::ToolCall::new(
+                #tool_name,
+                ::universal_tool_core::hooks::Interface::#interface_kind,
+                [#( #args ),*],
+            );
+            ::universal_tool_core::hooks::ToolHooks::before_call(&*#hooks, &call);
+            let started = ::std::time::Instant::now();
+            let result = #call;
+            ::universal_tool_core::hooks::ToolHooks::after_call(
+                &*#hooks,
+                &call,
+                started.elapsed(),
+                result.as_ref().err(),
+            );
+            result
+        }
+    }
+}
+
 /// Generate method signature for documentation/schemas
 pub fn generate_method_signature(tool: &ToolDef) -> String {
     let params = tool
@@ -191,13 +251,6 @@ pub fn generate_progress_helper(_tool_name: &str, _message: &str) -> TokenStream
     quote! {}
 }
 
-/// Check if we need to generate special handling for streaming
-/// (UTF doesn't support streaming, but we need to detect and error on it)
-pub fn check_streaming_type(return_type: &Type) -> bool {
-    let type_str = quote!(#return_type).to_string();
-    type_str.contains("Stream") || type_str.contains("Streaming")
-}
-
 /// Convert any case format to kebab-case
 /// Handles: camelCase, PascalCase, snake_case, kebab-case, and mixed formats
 pub fn to_kebab_case(input: &str) -> String {
@@ -524,6 +577,7 @@ mod tests {
                 },
             ],
             return_type: parse_quote!(AnalysisResult),
+            stream_item: None,
             is_async: true,
             metadata: Default::default(),
             visibility: parse_quote!(pub),
@@ -264,6 +264,7 @@ mod tests {
                 create_test_param("count", parse_quote!(Option<u32>), true),
             ],
             return_type: parse_quote!(String),
+            stream_item: None,
             is_async: false,
             visibility: parse_quote!(pub),
             metadata: Default::default(),
@@ -286,6 +287,7 @@ mod tests {
             tool_name: "test".to_string(),
             params: vec![],
             return_type: parse_quote!(()),
+            stream_item: None,
             is_async: false,
             visibility: parse_quote!(pub),
             metadata: Default::default(),
@@ -10,6 +10,7 @@ use crate::codegen::shared::is_hashmap_type;
 use crate::codegen::shared::is_optional_type;
 use crate::codegen::shared::is_vec_type;
 use crate::model::ParamDef;
+use crate::model::ParamSource;
 use crate::model::ToolDef;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -21,13 +22,29 @@ use syn::parse_str;
 pub fn generate_param_extraction(param: &ParamDef, interface: &str) -> TokenStream {
     let param_ident = &param.name;
 
+    if param.source == ParamSource::State && interface != "rest" {
+        return generate_state_param_extraction(param);
+    }
+    if matches!(
+        param.source,
+        ParamSource::Progress | ParamSource::Cancellation | ParamSource::Flags
+    ) {
+        let value = generate_context_param_value(param, interface);
+        return quote! { let #param_ident = #value; };
+    }
+    if param.metadata.skipped_in(interface) {
+        let param_type = &param.ty;
+        let fill = generate_fill(param, interface);
+        return quote! { let #param_ident: #param_type = #fill?; };
+    }
+
     match interface {
         "cli" => generate_cli_param_extraction(param),
         "rest" => {
             // REST params come from the generated params struct
             quote! { let #param_ident = params.#param_ident; }
         }
-        "mcp" => generate_mcp_param_extraction(param),
+        "mcp" | "ws" => generate_json_param_extraction(param, interface),
         _ => quote! {},
     }
 }
@@ -249,17 +266,86 @@ fn generate_cli_param_extraction(param: &ParamDef) -> TokenStream {
     }
 }
 
-/// Generates MCP-specific parameter extraction with validation
-fn generate_mcp_param_extraction(param: &ParamDef) -> TokenStream {
+/// Generates extraction of an injected parameter from the dispatch `state`
+fn generate_state_param_extraction(param: &ParamDef) -> TokenStream {
+    let param_ident = &param.name;
+    let param_type = &param.ty;
+
+    quote! {
+        let #param_ident = state.get::<#param_type>()?;
+    }
+}
+
+/// Generates the value of a progress, cancellation or global flags parameter: taken
+/// from the `McpCallContext` in the dispatch `state` for MCP (global flags from the
+/// CLI arguments), a no-op stub otherwise
+pub fn generate_context_param_value(param: &ParamDef, interface: &str) -> TokenStream {
+    let optional = is_optional_type(&param.ty);
+    match (param.source, interface) {
+        (ParamSource::Flags, "cli") => quote! { global_flags.clone() },
+        (ParamSource::Flags, "mcp") => quote! {
+            state.get::<::universal_tool_core::flags::GlobalFlags>().unwrap_or_default()
+        },
+        (ParamSource::Flags, _) => quote! {
+            ::universal_tool_core::flags::GlobalFlags::default()
+        },
+        (ParamSource::Progress, "mcp") if optional => quote! {
+            state.get::<::universal_tool_core::mcp::McpCallContext>().ok().and_then(|ctx| ctx.progress)
+        },
+        (ParamSource::Progress, "mcp") => quote! {
+            state.get::<::universal_tool_core::mcp::McpCallContext>().ok().and_then(|ctx| ctx.progress).unwrap_or_default()
+        },
+        (ParamSource::Progress, _) if optional => quote! { ::std::option::Option::None },
+        (ParamSource::Progress, _) => quote! {
+            <::universal_tool_core::mcp::McpProgressReporter as ::std::default::Default>::default()
+        },
+        (_, "mcp") => quote! {
+            state.get::<::universal_tool_core::mcp::McpCallContext>().map(|ctx| ctx.cancellation).unwrap_or_default()
+        },
+        _ => quote! { ::universal_tool_core::mcp::CancellationToken::new() },
+    }
+}
+
+/// Generates a `defaults::fill` call for a parameter missing from (or skipped in)
+/// `interface`, evaluating to `Result<T, ToolError>`
+pub fn generate_fill(param: &ParamDef, interface: &str) -> TokenStream {
+    let param_name = param.name.to_string();
+    let param_type = &param.ty;
+    let option = |value: Option<&str>| match value {
+        Some(v) => quote! { ::std::option::Option::Some(#v) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let env = option(param.metadata.env_for(interface));
+    let default = option(param.metadata.default_for(interface));
+
+    quote! {
+        ::universal_tool_core::defaults::fill::<#param_type>(#param_name, #env, #default)
+    }
+}
+
+/// Generates extraction with validation from the JSON `params` object of an MCP or
+/// WebSocket call
+fn generate_json_param_extraction(param: &ParamDef, interface: &str) -> TokenStream {
     let param_name = &param.name.to_string();
     let param_ident = &param.name;
     let param_type = &param.ty;
 
-    let missing_err = error_handling::generate_missing_param_error(param_name, "mcp");
-    let parse_err =
-        error_handling::generate_parse_error(param_name, &quote!(#param_type).to_string(), "mcp");
+    let missing_err = error_handling::generate_missing_param_error(param_name, interface);
+    let parse_err = error_handling::generate_parse_error(
+        param_name,
+        &quote!(#param_type).to_string(),
+        interface,
+    );
 
-    if is_optional_type(&param.ty) {
+    if param.metadata.has_fallback(interface) {
+        let fill = generate_fill(param, interface);
+        quote! {
+            let #param_ident: #param_type = match params.get(#param_name) {
+                Some(v) => ::serde_json::from_value(v.clone()).map_err(|_| #parse_err)?,
+                None => #fill?,
+            };
+        }
+    } else if is_optional_type(&param.ty) {
         quote! {
             let #param_ident: #param_type = params.get(#param_name)
                 .map(|v| ::serde_json::from_value(v.clone())
@@ -276,20 +362,91 @@ fn generate_mcp_param_extraction(param: &ParamDef) -> TokenStream {
     }
 }
 
-/// Generates extraction code for all parameters of a tool
+/// Generates extraction code for all parameters of a tool, followed by their
+/// constraint checks
 pub fn generate_params_extraction(tool: &ToolDef, interface: &str) -> Vec<TokenStream> {
-    tool.params
+    let extractions = tool
+        .params
+        .iter()
+        .filter(|p| {
+            should_include_param(p, interface)
+                || p.source.is_injected()
+                || p.metadata.skipped_in(interface)
+        })
+        .map(|param| generate_param_extraction(param, interface));
+    let checks = tool
+        .params
         .iter()
         .filter(|p| should_include_param(p, interface))
-        .map(|param| generate_param_extraction(param, interface))
-        .collect()
+        .map(|param| {
+            let param_ident = &param.name;
+            generate_constraint_checks(param, &quote! { #param_ident })
+        });
+    extractions.chain(checks).collect()
+}
+
+/// Generates the `min`/`max`/`pattern`/`non_empty` checks for a parameter whose
+/// value is available as `value`; each check returns early with `InvalidArgument`
+pub fn generate_constraint_checks(param: &ParamDef, value: &TokenStream) -> TokenStream {
+    let name = param.name.to_string();
+    let metadata = &param.metadata;
+
+    let min = metadata.min.map(|bound| {
+        quote! { ::universal_tool_core::validate::min(#name, &#value, #bound)?; }
+    });
+    let max = metadata.max.map(|bound| {
+        quote! { ::universal_tool_core::validate::max(#name, &#value, #bound)?; }
+    });
+    let pattern = metadata.pattern.as_ref().map(|pattern| {
+        quote! { ::universal_tool_core::validate::pattern(#name, &#value, #pattern)?; }
+    });
+    let non_empty = metadata.non_empty.then(|| {
+        quote! { ::universal_tool_core::validate::non_empty(#name, &#value)?; }
+    });
+
+    quote! { #non_empty #min #max #pattern }
+}
+
+/// Generates a `validate::Constraints` expression reflecting a parameter's
+/// constraints into its schema
+pub fn generate_schema_constraints(param: &ParamDef) -> TokenStream {
+    let metadata = &param.metadata;
+    let bound = |value: Option<f64>| match value {
+        Some(v) => quote! { ::std::option::Option::Some(#v) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let min = bound(metadata.min);
+    let max = bound(metadata.max);
+    let pattern = match &metadata.pattern {
+        Some(p) => quote! { ::std::option::Option::Some(#p) },
+        None => quote! { ::std::option::Option::None },
+    };
+    let non_empty = metadata.non_empty;
+
+    quote! {
+        ::universal_tool_core::validate::Constraints {
+            min: #min,
+            max: #max,
+            pattern: #pattern,
+            non_empty: #non_empty,
+        }
+    }
 }
 
 /// Checks if a parameter should be included based on interface
 pub fn should_include_param(param: &ParamDef, interface: &str) -> bool {
+    // Injected state and call context are never part of a tool's inputs
+    if param.source.is_injected() {
+        return false;
+    }
+    // Skipped parameters are filled from their env var or default instead
+    if param.metadata.skipped_in(interface) {
+        return false;
+    }
+
     match interface {
-        "mcp" => {
-            // MCP skips ProgressReporter and CancellationToken
+        "mcp" | "ws" => {
+            // MCP and WebSocket skip ProgressReporter and CancellationToken
             let param_ty = &param.ty;
             let type_str = quote!(#param_ty).to_string();
             !type_str.contains("ProgressReporter") && !type_str.contains("CancellationToken")
@@ -359,10 +516,12 @@ pub fn generate_cli_arg_config(param: &ParamDef) -> TokenStream {
 
     // Determine all the method calls we need
     let value_parser = generate_cli_value_parser(param);
+    // A default or env var satisfies clap on its own
     let is_required = !is_optional_type(&param.ty)
         && !is_bool_type(&param.ty)
         && !is_vec_type(&param.ty)
-        && !is_hashmap_type(&param.ty);
+        && !is_hashmap_type(&param.ty)
+        && !param.metadata.has_fallback("cli");
     let is_bool = is_bool_type(&param.ty);
     let is_multi = is_vec_type(&param.ty) || is_hashmap_type(&param.ty);
 
@@ -407,14 +566,14 @@ pub fn generate_cli_arg_config(param: &ParamDef) -> TokenStream {
     };
 
     // Add environment variable support if specified
-    let with_env = if let Some(env_var) = &param.metadata.env {
+    let with_env = if let Some(env_var) = param.metadata.env_for("cli") {
         quote! { .env(#env_var) }
     } else {
         quote! {}
     };
 
     // Add default value support if specified
-    let with_default = if let Some(default_val) = &param.metadata.default {
+    let with_default = if let Some(default_val) = param.metadata.default_for("cli") {
         quote! { .default_value(#default_val) }
     } else {
         quote! {}
@@ -458,5 +617,34 @@ mod tests {
         assert!(should_include_param(&progress_param, "cli"));
         assert!(should_include_param(&progress_param, "rest"));
         assert!(!should_include_param(&progress_param, "mcp"));
+
+        let cancel_param = ParamDef {
+            name: parse_quote!(cancel),
+            ty: parse_quote!(CancellationToken),
+            source: crate::model::ParamSource::Cancellation,
+            is_optional: false,
+            metadata: Default::default(),
+        };
+
+        assert!(!should_include_param(&cancel_param, "cli"));
+        assert!(!should_include_param(&cancel_param, "rest"));
+        assert!(!should_include_param(&cancel_param, "mcp"));
+    }
+
+    #[test]
+    fn test_context_params_are_stubbed_outside_mcp() {
+        let progress = ParamDef {
+            name: parse_quote!(progress),
+            ty: parse_quote!(Option<McpProgressReporter>),
+            source: crate::model::ParamSource::Progress,
+            is_optional: true,
+            metadata: Default::default(),
+        };
+
+        let cli = generate_context_param_value(&progress, "cli").to_string();
+        assert_eq!(cli, quote!(::std::option::Option::None).to_string());
+        let mcp = generate_context_param_value(&progress, "mcp").to_string();
+        assert!(mcp.contains("McpCallContext"), "{mcp}");
+        assert!(!mcp.contains("unwrap_or_default"), "{mcp}");
     }
 }
@@ -0,0 +1,196 @@
+//! WebSocket code generation for Universal Tool Framework
+//!
+//! This module generates `handle_ws_call()` and `create_ws_router()`, which serve a
+//! router's tools as JSON-RPC 2.0 over a WebSocket using `universal_tool_core::ws`.
+
+use crate::codegen::shared::generate_hooked_call;
+use crate::codegen::shared::generate_normalized_method_call;
+use crate::codegen::validation;
+use crate::model::RouterDef;
+use crate::model::ToolDef;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates all WebSocket-related methods for a router
+pub fn generate_ws_methods(router: &RouterDef) -> TokenStream {
+    let struct_type = &router.struct_type;
+    let dispatch_method = generate_ws_dispatch_method(router);
+    let router_methods = generate_ws_router_methods(router);
+
+    quote! {
+        impl #struct_type {
+            #dispatch_method
+
+            #router_methods
+        }
+    }
+}
+
+/// Generates the handle_ws_call() method for dispatching WebSocket tool calls
+fn generate_ws_dispatch_method(router: &RouterDef) -> TokenStream {
+    let match_arms: Vec<_> = router
+        .tools
+        .iter()
+        .map(|tool| generate_tool_match_arm(tool, router))
+        .collect();
+
+    quote! {
+        /// Handles a WebSocket JSON-RPC call by dispatching to the appropriate tool method
+        ///
+        /// Streaming tools push their items through `sink` and return `null`.
+        #[allow(unused_variables)]
+        pub async fn handle_ws_call(
+            &self,
+            method: &str,
+            params: ::serde_json::Value,
+            state: &::universal_tool_core::state::ToolState,
+            sink: &::universal_tool_core::ws::WsSink,
+        ) -> ::std::result::Result<::serde_json::Value, ::universal_tool_core::error::ToolError> {
+            match method {
+                #(#match_arms)*
+                _ => {
+                    ::std::result::Result::Err(
+                        ::universal_tool_core::error::ToolError::new(
+                            ::universal_tool_core::error::ErrorCode::NotFound,
+                            ::std::format!("Unknown method: {}", method)
+                        )
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Generates a match arm for a single tool
+fn generate_tool_match_arm(tool: &ToolDef, router: &RouterDef) -> TokenStream {
+    let tool_name = &tool.tool_name;
+    let param_extractions = validation::generate_params_extraction(tool, "ws");
+
+    // If there are includable params, assert params is a JSON object and bind it
+    let has_includable_params = tool
+        .params
+        .iter()
+        .any(|p| validation::should_include_param(p, "ws"));
+    let object_assertion = if has_includable_params {
+        quote! {
+            let params = match params {
+                ::serde_json::Value::Object(map) => map,
+                _ => {
+                    return ::std::result::Result::Err(
+                        ::universal_tool_core::error::ToolError::new(
+                            ::universal_tool_core::error::ErrorCode::InvalidArgument,
+                            "Parameters must be a JSON object"
+                        )
+                    );
+                }
+            };
+        }
+    } else {
+        quote! {}
+    };
+
+    // Every parameter, including injected ones, is bound by the extraction above
+    let param_args = tool
+        .params
+        .iter()
+        .map(|param| {
+            let name = &param.name;
+            quote! { #name }
+        })
+        .collect();
+    let method_call = generate_normalized_method_call(tool, quote! { self }, param_args);
+
+    let call = if tool.stream_item.is_some() {
+        // Streaming tools push each item as it arrives, then answer with null
+        let forward = generate_hooked(
+            tool,
+            router,
+            quote! {
+                {
+                    let stream = #method_call;
+                    sink.forward(stream).await
+                }
+            },
+        );
+        quote! {
+            #forward?;
+            ::std::result::Result::Ok(::serde_json::Value::Null)
+        }
+    } else {
+        let method_call = generate_hooked(tool, router, method_call);
+        quote! {
+            let result = #method_call?;
+            ::std::result::Result::Ok(::serde_json::to_value(&result)?)
+        }
+    };
+
+    quote! {
+        #tool_name => {
+            #object_assertion
+            #( #param_extractions )*
+            #call
+        }
+    }
+}
+
+/// Wraps a WebSocket tool call in the router's hooks, if enabled
+fn generate_hooked(tool: &ToolDef, router: &RouterDef, call: TokenStream) -> TokenStream {
+    generate_hooked_call(
+        router,
+        tool,
+        "ws",
+        quote! { self },
+        |p| {
+            let name = &p.name;
+            quote! { #name }
+        },
+        call,
+    )
+}
+
+/// Generates create_ws_router() and create_ws_router_with_state()
+fn generate_ws_router_methods(router: &RouterDef) -> TokenStream {
+    let path = router
+        .metadata
+        .ws_config
+        .as_ref()
+        .and_then(|c| c.path.clone())
+        .unwrap_or_else(|| "/ws".to_string());
+    let tool_names = router.tools.iter().map(|tool| &tool.tool_name);
+
+    quote! {
+        /// Creates an axum router serving every tool as JSON-RPC over a WebSocket
+        ///
+        /// Usage: `let app = MyStruct::create_ws_router(Arc::new(my_instance));`
+        pub fn create_ws_router(tools: ::std::sync::Arc<Self>) -> ::universal_tool_core::rest::Router {
+            Self::create_ws_router_with_state(tools, ::universal_tool_core::state::ToolState::new())
+        }
+
+        /// Like `create_ws_router`, injecting `from_state` parameters from `state`
+        pub fn create_ws_router_with_state(
+            tools: ::std::sync::Arc<Self>,
+            state: ::universal_tool_core::state::ToolState,
+        ) -> ::universal_tool_core::rest::Router {
+            const METHODS: &[&str] = &[#( #tool_names ),*];
+
+            ::universal_tool_core::rest::Router::new().route(
+                #path,
+                ::universal_tool_core::rest::routing::get(
+                    move |upgrade: ::universal_tool_core::ws::WebSocketUpgrade| {
+                        let tools = tools.clone();
+                        let state = state.clone();
+                        async move {
+                            upgrade.on_upgrade(move |socket| {
+                                ::universal_tool_core::ws::serve(socket, METHODS, move |method, params, sink| {
+                                    let tools = tools.clone();
+                                    let state = state.clone();
+                                    async move { tools.handle_ws_call(&method, params, &state, &sink).await }
+                                })
+                            })
+                        }
+                    },
+                ),
+            )
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Debug expansion mode.
+//!
+//! Setting `UTF_MACRO_DEBUG=1` while compiling makes `universal_tool_router` write
+//! the generated code for each interface to
+//! `<target>/utf-macro-debug/<crate>/<Type>/{cli,rest,mcp,expanded}.rs`, so a failing
+//! expansion can be read directly instead of through `cargo expand` on the whole crate.
+//!
+//! `<target>` is `CARGO_TARGET_DIR` when set, otherwise the `target/` directory next to
+//! the nearest `Cargo.lock` above the crate being compiled.
+//!
+//! `UTF_MACRO_DEBUG=expand` writes to `$OUT_DIR/utf-macro-debug/<Type>/` instead, next to
+//! the crate's other build outputs; `OUT_DIR` is only set for crates with a build
+//! script, so without one it falls back to the `<target>` location above.
+
+use proc_macro2::TokenStream;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Environment variable that enables debug expansion output.
+pub const DEBUG_ENV: &str = "UTF_MACRO_DEBUG";
+
+/// Value of `UTF_MACRO_DEBUG` selecting the `OUT_DIR` location.
+pub const EXPAND_MODE: &str = "expand";
+
+/// Whether debug expansion output is enabled (`UTF_MACRO_DEBUG` set to anything but `0`/empty).
+pub fn enabled() -> bool {
+    std::env::var(DEBUG_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Resolve the directory expansions for `type_name` are written to.
+pub fn output_dir(type_name: &str) -> PathBuf {
+    let expand = std::env::var(DEBUG_ENV).is_ok_and(|v| v == EXPAND_MODE);
+    if expand && let Some(out_dir) = std::env::var_os("OUT_DIR") {
+        return PathBuf::from(out_dir)
+            .join("utf-macro-debug")
+            .join(type_name);
+    }
+
+    let target = std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .or_else(|| {
+            let manifest = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR")?);
+            let root = manifest
+                .ancestors()
+                .find(|dir| dir.join("Cargo.lock").is_file())
+                .unwrap_or(&manifest);
+            Some(root.join("target"))
+        })
+        .unwrap_or_else(|| PathBuf::from("target"));
+    let crate_name = std::env::var("CARGO_CRATE_NAME").unwrap_or_else(|_| "unknown".to_string());
+
+    target
+        .join("utf-macro-debug")
+        .join(crate_name)
+        .join(type_name)
+}
+
+/// Write one file per non-empty interface into `dir`, pretty-printed when the tokens
+/// parse as a file. Returns the paths written.
+pub fn write_expansions(
+    dir: &Path,
+    parts: &[(&str, &TokenStream)],
+) -> std::io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+    let mut written = Vec::new();
+    for (interface, tokens) in parts {
+        if tokens.is_empty() {
+            continue;
+        }
+        let path = dir.join(format!("{interface}.rs"));
+        std::fs::write(&path, pretty(tokens))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Dump expansions for a router if debug mode is on. Failures are reported as warnings
+/// on stderr and never fail the build.
+pub fn dump(type_name: &str, parts: &[(&str, &TokenStream)]) {
+    if !enabled() {
+        return;
+    }
+    let dir = output_dir(type_name);
+    match write_expansions(&dir, parts) {
+        Ok(paths) => {
+            for path in paths {
+                eprintln!("{DEBUG_ENV}: wrote {}", path.display());
+            }
+        }
+        Err(e) => eprintln!(
+            "{DEBUG_ENV}: failed to write expansions to {}: {e}",
+            dir.display()
+        ),
+    }
+}
+
+fn pretty(tokens: &TokenStream) -> String {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => tokens.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[test]
+    fn write_expansions_skips_empty_interfaces_and_pretty_prints() {
+        let dir = std::env::temp_dir().join(format!("utf-macro-debug-test-{}", std::process::id()));
+        let cli = quote! { impl Foo { fn cli(&self) -> u8 { 1 } } };
+        let empty = TokenStream::new();
+
+        let written = write_expansions(&dir, &[("cli", &cli), ("mcp", &empty)]).unwrap();
+
+        assert_eq!(written, vec![dir.join("cli.rs")]);
+        let contents = std::fs::read_to_string(dir.join("cli.rs")).unwrap();
+        assert!(contents.contains("impl Foo {\n"));
+        assert!(!dir.join("mcp.rs").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
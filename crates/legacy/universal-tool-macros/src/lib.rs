@@ -3,11 +3,13 @@
 //! This crate provides the procedural macros that power UTF's code generation.
 //! Users should depend on `universal-tool-core`, not this crate directly.
 //!
-//! See `parser.rs` for detailed documentation on the feature propagation system.
+//! See `parser.rs` for detailed documentation on the feature propagation system, and
+//! `debug.rs` for the `UTF_MACRO_DEBUG=1` (or `=expand`) expansion dump.
 
 use proc_macro::TokenStream;
 
 mod codegen;
+mod debug;
 mod model;
 mod parser;
 
@@ -33,6 +33,8 @@ pub struct ToolDef {
     pub params: Vec<ParamDef>,
     /// The return type of the method
     pub return_type: Type,
+    /// Item type `T` when the method returns `impl Stream<Item = Result<T, ToolError>>`
+    pub stream_item: Option<Type>,
     /// Metadata from the tool attribute
     pub metadata: ToolMetadata,
     /// Whether the method is async
@@ -69,6 +71,26 @@ pub enum ParamSource {
     Path,
     /// REST headers
     Header,
+    /// Shared state injected by the host: an axum `Extension` in REST, a `ToolState`
+    /// entry in CLI/MCP dispatch
+    State,
+    /// `McpProgressReporter` (or `Option` of one) wired from the MCP call context;
+    /// a no-op reporter in CLI/REST
+    Progress,
+    /// `CancellationToken` wired from the MCP call context; never cancelled in CLI/REST
+    Cancellation,
+    /// `GlobalFlags` parsed from the router-level CLI flags; empty in REST
+    Flags,
+}
+
+impl ParamSource {
+    /// Whether the value is supplied by the framework rather than the caller.
+    pub fn is_injected(self) -> bool {
+        matches!(
+            self,
+            Self::State | Self::Progress | Self::Cancellation | Self::Flags
+        )
+    }
 }
 
 /// Metadata for the router attribute.
@@ -85,6 +107,10 @@ pub struct RouterMetadata {
     pub mcp_config: Option<RouterMcpConfig>,
     /// REST configuration (router-level)
     pub rest_config: Option<RouterRestConfig>,
+    /// WebSocket configuration (router-level)
+    pub ws_config: Option<RouterWsConfig>,
+    /// Whether the router type implements `ToolHooks` for the adapters to call
+    pub hooks: bool,
 }
 
 /// Router-level CLI configuration.
@@ -98,6 +124,8 @@ pub struct RouterCliConfig {
     pub global_output_formats: Vec<String>,
     /// Whether to add standard global args (--dry-run, --yes, --quiet, --verbose)
     pub standard_global_args: bool,
+    /// Custom boolean flags available to every command (e.g. "json" for --json)
+    pub global_flags: Vec<String>,
 }
 
 /// Router-level MCP configuration.
@@ -114,6 +142,17 @@ pub struct RouterMcpConfig {
 pub struct RouterRestConfig {
     /// Base prefix for all REST endpoints
     pub prefix: Option<String>,
+    /// Name of the typed HTTP client to generate (with the client feature)
+    pub client: Option<syn::Ident>,
+    /// Authentication every tool requires unless it sets its own
+    pub auth: Option<RestAuth>,
+}
+
+/// Router-level WebSocket configuration.
+#[derive(Debug, Default)]
+pub struct RouterWsConfig {
+    /// Route the WebSocket is served on; defaults to "/ws"
+    pub path: Option<String>,
 }
 
 /// Metadata for individual tool methods.
@@ -139,6 +178,17 @@ pub struct RestConfig {
     pub path: Option<String>,
     /// HTTP method
     pub method: HttpMethod,
+    /// Authentication this endpoint requires, overriding the router's
+    pub auth: Option<RestAuth>,
+}
+
+/// Authentication a REST endpoint requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestAuth {
+    /// Open to anyone
+    None,
+    /// `Authorization: Bearer <token>`, checked by the installed `AuthProvider`
+    Bearer,
 }
 
 /// HTTP methods supported by REST endpoints.
@@ -157,6 +207,8 @@ pub enum HttpMethod {
 pub enum McpOutputMode {
     Json,
     Text,
+    /// Text content plus structured content; clients may ask for either alone
+    Both,
 }
 
 /// MCP-specific configuration.
@@ -164,7 +216,7 @@ pub enum McpOutputMode {
 pub struct McpConfig {
     /// MCP annotations for the tool
     pub annotations: McpAnnotations,
-    /// Output mode for this tool (Text or Json)
+    /// Output mode for this tool (Text, Json or Both)
     pub output_mode: Option<McpOutputMode>,
 }
 
@@ -241,6 +293,71 @@ pub struct ParamMetadata {
     pub multiple: bool,
     /// Value delimiter for multiple values (default: comma)
     pub delimiter: Option<char>,
+    /// Inclusive lower bound for numeric parameters
+    pub min: Option<f64>,
+    /// Inclusive upper bound for numeric parameters
+    pub max: Option<f64>,
+    /// Regular expression string parameters must match
+    pub pattern: Option<String>,
+    /// Whether strings and collections must not be empty
+    pub non_empty: bool,
+    /// CLI-only overrides
+    pub cli: InterfaceParamConfig,
+    /// MCP-only overrides
+    pub mcp: InterfaceParamConfig,
+    /// REST-only overrides
+    pub rest: InterfaceParamConfig,
+}
+
+/// Per-interface parameter overrides from `cli(...)`, `mcp(...)` or `rest(...)`.
+#[derive(Debug, Default, Clone)]
+pub struct InterfaceParamConfig {
+    /// Hide the parameter from this interface and fill it from `env`/`default`
+    pub skip: bool,
+    /// Default value (as a string to parse), overriding the shared one
+    pub default: Option<String>,
+    /// Environment variable to read from, overriding the shared one
+    pub env: Option<String>,
+}
+
+impl ParamMetadata {
+    /// Whether any validation constraint is set.
+    pub fn has_constraints(&self) -> bool {
+        self.min.is_some() || self.max.is_some() || self.pattern.is_some() || self.non_empty
+    }
+
+    fn interface(&self, interface: &str) -> Option<&InterfaceParamConfig> {
+        match interface {
+            "cli" => Some(&self.cli),
+            "mcp" => Some(&self.mcp),
+            "rest" => Some(&self.rest),
+            _ => None,
+        }
+    }
+
+    /// Whether the parameter is hidden from `interface`.
+    pub fn skipped_in(&self, interface: &str) -> bool {
+        self.interface(interface).is_some_and(|c| c.skip)
+    }
+
+    /// Default value for `interface`: its override, else the shared default.
+    pub fn default_for(&self, interface: &str) -> Option<&str> {
+        self.interface(interface)
+            .and_then(|c| c.default.as_deref())
+            .or(self.default.as_deref())
+    }
+
+    /// Environment variable for `interface`: its override, else the shared one.
+    pub fn env_for(&self, interface: &str) -> Option<&str> {
+        self.interface(interface)
+            .and_then(|c| c.env.as_deref())
+            .or(self.env.as_deref())
+    }
+
+    /// Whether a missing value can be filled from an environment variable or default.
+    pub fn has_fallback(&self, interface: &str) -> bool {
+        self.default_for(interface).is_some() || self.env_for(interface).is_some()
+    }
 }
 
 /// Validation result for tool definitions.
@@ -271,6 +388,26 @@ impl RouterDef {
             }
         }
 
+        // Global flags must not clash with each other or the standard global args
+        if let Some(cli_config) = &self.metadata.cli_config {
+            let mut flag_names = std::collections::HashSet::new();
+            if cli_config.standard_global_args {
+                flag_names.extend(["dry-run", "yes", "quiet", "verbose"]);
+            }
+            for flag in &cli_config.global_flags {
+                if !flag_names.insert(flag.as_str()) {
+                    errors.push(ValidationError {
+                        span: proc_macro2::Span::call_site(),
+                        message: format!("Duplicate global flag: --{flag}"),
+                        help: Some(
+                            "standard_global_args already adds --dry-run, --yes, --quiet and --verbose"
+                                .to_string(),
+                        ),
+                    });
+                }
+            }
+        }
+
         // Validate each tool
         for tool in &self.tools {
             if let Err(tool_errors) = tool.validate() {
@@ -340,7 +477,7 @@ impl ToolDef {
                         continue;
                     }
 
-                    if param.source == ParamSource::Body {
+                    if param.source == ParamSource::Body && !param.metadata.skipped_in("rest") {
                         errors.push(ValidationError {
                             span: param.name.span(),
                             message: "GET requests cannot have body parameters".to_string(),
@@ -351,6 +488,17 @@ impl ToolDef {
             }
         }
 
+        // Path parameters are part of the route and cannot be filled in
+        for param in &self.params {
+            if param.source == ParamSource::Path && param.metadata.skipped_in("rest") {
+                errors.push(ValidationError {
+                    span: param.name.span(),
+                    message: "Path parameters cannot be skipped in REST".to_string(),
+                    help: Some("Remove `rest(skip)` or the parameter from the path".to_string()),
+                });
+            }
+        }
+
         if errors.is_empty() {
             Ok(())
         } else {
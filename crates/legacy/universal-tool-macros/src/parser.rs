@@ -9,19 +9,26 @@ use darling::ast::NestedMeta;
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::Attribute;
+use syn::Expr;
+use syn::ExprLit;
+use syn::ExprUnary;
 use syn::FnArg;
 use syn::GenericArgument;
 use syn::ImplItem;
 use syn::ItemImpl;
+use syn::Lit;
 use syn::LitStr;
 use syn::Pat;
 use syn::PatType;
 use syn::PathArguments;
 use syn::ReturnType;
 use syn::Type;
+use syn::TypeParamBound;
 use syn::TypePath;
+use syn::UnOp;
 use syn::parse2;
 
+use crate::codegen::types::get_generic_args;
 use crate::model::*;
 use syn::visit_mut::VisitMut;
 use syn::visit_mut::{self};
@@ -36,10 +43,11 @@ pub fn parse_router(attr: TokenStream, item: TokenStream) -> syn::Result<TokenSt
         RouterAttr::default()
     } else {
         // Parse the attribute tokens into NestedMeta
-        let nested_metas = NestedMeta::parse_meta_list(attr.clone())
-            .map_err(|e| syn::Error::new_spanned(&attr, e))?;
+        let nested_metas = NestedMeta::parse_meta_list(attr.clone())?;
 
-        RouterAttr::from_list(&nested_metas).map_err(|e| syn::Error::new_spanned(&attr, e))?
+        // darling attaches spans to the offending item (with "did you mean" hints for
+        // unknown fields); only fall back to the whole attribute when it has none.
+        RouterAttr::from_list(&nested_metas).map_err(|e| syn::Error::from(e.with_span(&attr)))?
     };
 
     // Convert to our internal model
@@ -108,6 +116,26 @@ pub fn parse_router(attr: TokenStream, item: TokenStream) -> syn::Result<TokenSt
             (TokenStream::new(), TokenStream::new())
         };
 
+    // Only generate the WebSocket adapter if the ws feature is enabled AND router has ws(...) attribute
+    let ws_methods = if cfg!(feature = "ws") && router_def.metadata.ws_config.is_some() {
+        crate::codegen::ws::generate_ws_methods(&router_def)
+    } else {
+        TokenStream::new()
+    };
+
+    // Only generate a REST client if the client feature is enabled AND rest(client = "...") is set
+    let client_code = if cfg!(feature = "client")
+        && router_def
+            .metadata
+            .rest_config
+            .as_ref()
+            .is_some_and(|c| c.client.is_some())
+    {
+        crate::codegen::client::generate_client(&router_def)
+    } else {
+        TokenStream::new()
+    };
+
     // Strip universal_tool_param attributes from the impl block before returning
     let mut cleaned_impl_block = impl_block.clone();
     strip_param_attributes(&mut cleaned_impl_block);
@@ -122,6 +150,8 @@ pub fn parse_router(attr: TokenStream, item: TokenStream) -> syn::Result<TokenSt
         #cli_methods
         #rest_methods
         #mcp_methods
+        #ws_methods
+        #client_code
     };
 
     // Debug: Print the generated code to stderr for inspection
@@ -129,6 +159,30 @@ pub fn parse_router(attr: TokenStream, item: TokenStream) -> syn::Result<TokenSt
         eprintln!("Generated code:\n{output}");
     }
 
+    // UTF_MACRO_DEBUG=1: write each interface's expansion to target/utf-macro-debug/
+    if crate::debug::enabled() {
+        let rest = quote! {
+            #rest_module
+            #rest_methods
+        };
+        let type_name = router_def
+            .struct_type
+            .segments
+            .last()
+            .map_or_else(|| "router".to_string(), |s| s.ident.to_string());
+        crate::debug::dump(
+            &type_name,
+            &[
+                ("cli", &cli_methods),
+                ("rest", &rest),
+                ("mcp", &mcp_methods),
+                ("ws", &ws_methods),
+                ("client", &client_code),
+                ("expanded", &output),
+            ],
+        );
+    }
+
     Ok(output)
 }
 
@@ -146,6 +200,10 @@ struct RouterAttr {
     rest: Option<RouterRestAttr>,
     /// MCP-specific configuration
     mcp: Option<RouterMcpAttr>,
+    /// WebSocket-specific configuration
+    ws: Option<RouterWsAttr>,
+    /// Call `ToolHooks` around every generated dispatch
+    hooks: bool,
 }
 
 /// Router-level CLI configuration
@@ -160,6 +218,8 @@ struct RouterCliAttr {
     global_output_formats: Option<Vec<LitStr>>,
     /// Add standard global args
     standard_global_args: Option<bool>,
+    /// Custom global boolean flags
+    global_flags: Option<Vec<LitStr>>,
 }
 
 /// Router-level REST configuration
@@ -168,6 +228,10 @@ struct RouterCliAttr {
 struct RouterRestAttr {
     /// Base prefix for all REST endpoints
     prefix: Option<String>,
+    /// Name of the generated typed client
+    client: Option<LitStr>,
+    /// Default authentication for every tool: "bearer" or "none"
+    auth: Option<LitStr>,
 }
 
 /// Router-level MCP configuration
@@ -180,6 +244,14 @@ struct RouterMcpAttr {
     version: Option<String>,
 }
 
+/// Router-level WebSocket configuration
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct RouterWsAttr {
+    /// Route the WebSocket is served on
+    path: Option<String>,
+}
+
 /// Darling attribute structure for #[universal_tool(...)]
 #[derive(Debug, Default, FromMeta)]
 #[darling(default)]
@@ -207,13 +279,10 @@ struct ToolAttr {
 struct RestAttr {
     /// Custom path for this endpoint
     path: Option<String>,
-    /// HTTP method (GET, POST, etc.)
-    #[darling(default = "default_http_method")]
-    method: String,
-}
-
-fn default_http_method() -> String {
-    "POST".to_string()
+    /// HTTP method (GET, POST, etc.); defaults to POST
+    method: Option<LitStr>,
+    /// Authentication: "bearer" or "none"
+    auth: Option<LitStr>,
 }
 
 /// MCP configuration attributes
@@ -229,7 +298,7 @@ struct McpAttr {
     /// Open world hint - accepts additional parameters
     open_world: Option<bool>,
     /// Output mode: "text" or "json"
-    output: Option<String>,
+    output: Option<LitStr>,
 }
 
 /// CLI configuration attributes
@@ -258,6 +327,8 @@ struct CliAttr {
     interactive: Option<bool>,
     /// Command path
     command_path: Option<Vec<LitStr>>,
+    /// Parent command grouping this one (shorthand for a one-level command path)
+    group: Option<LitStr>,
 }
 
 /// Darling attribute structure for #[universal_tool_param(...)]
@@ -265,7 +336,7 @@ struct CliAttr {
 #[darling(default, attributes(universal_tool_param))]
 struct ParamAttr {
     /// Parameter source (body, query, path, header)
-    source: Option<String>,
+    source: Option<LitStr>,
     /// Parameter description
     description: Option<String>,
     /// Short flag for CLI
@@ -284,6 +355,44 @@ struct ParamAttr {
     delimiter: Option<char>,
     /// Completions hint
     completions: Option<String>,
+    /// Inject from shared state instead of reading from the request
+    from_state: bool,
+    /// Inclusive lower bound (numeric literal)
+    min: Option<Expr>,
+    /// Inclusive upper bound (numeric literal)
+    max: Option<Expr>,
+    /// Regular expression the value must match
+    pattern: Option<String>,
+    /// Reject empty strings and collections
+    non_empty: bool,
+    /// CLI-only overrides
+    cli: Option<ParamInterfaceAttr>,
+    /// MCP-only overrides
+    mcp: Option<ParamInterfaceAttr>,
+    /// REST-only overrides
+    rest: Option<ParamInterfaceAttr>,
+}
+
+/// Per-interface parameter overrides, e.g. `mcp(skip, env = "TOKEN")`
+#[derive(Debug, Default, FromMeta)]
+#[darling(default)]
+struct ParamInterfaceAttr {
+    /// Hide the parameter from this interface
+    skip: bool,
+    /// Default value for this interface
+    default: Option<String>,
+    /// Environment variable for this interface
+    env: Option<String>,
+}
+
+impl From<ParamInterfaceAttr> for InterfaceParamConfig {
+    fn from(attr: ParamInterfaceAttr) -> Self {
+        Self {
+            skip: attr.skip,
+            default: attr.default,
+            env: attr.env,
+        }
+    }
 }
 
 /// Parse an impl block into our RouterDef model.
@@ -320,6 +429,7 @@ fn parse_impl_to_router(impl_block: &ItemImpl, router_attr: RouterAttr) -> syn::
         tools,
         metadata: RouterMetadata {
             openapi_tag: router_attr.openapi_tag,
+            hooks: router_attr.hooks,
             base_path: router_attr
                 .rest
                 .as_ref()
@@ -333,14 +443,39 @@ fn parse_impl_to_router(impl_block: &ItemImpl, router_attr: RouterAttr) -> syn::
                     .map(|v| v.into_iter().map(|lit| lit.value()).collect())
                     .unwrap_or_default(),
                 standard_global_args: c.standard_global_args.unwrap_or(false),
+                global_flags: c
+                    .global_flags
+                    .map(|v| v.into_iter().map(|lit| lit.value()).collect())
+                    .unwrap_or_default(),
             }),
             mcp_config: router_attr.mcp.map(|m| crate::model::RouterMcpConfig {
                 name: m.name,
                 version: m.version,
             }),
+            ws_config: router_attr
+                .ws
+                .map(|w| crate::model::RouterWsConfig { path: w.path }),
             rest_config: router_attr
                 .rest
-                .map(|r| crate::model::RouterRestConfig { prefix: r.prefix }),
+                .map(|r| {
+                    let client = r
+                        .client
+                        .map(|lit| {
+                            lit.parse::<syn::Ident>().map_err(|_| {
+                                syn::Error::new_spanned(
+                                    &lit,
+                                    "`client` must be a type name, e.g. client = \"ItemsClient\"",
+                                )
+                            })
+                        })
+                        .transpose()?;
+                    Ok::<_, syn::Error>(crate::model::RouterRestConfig {
+                        prefix: r.prefix,
+                        client,
+                        auth: r.auth.as_ref().map(parse_rest_auth).transpose()?,
+                    })
+                })
+                .transpose()?,
         },
     })
 }
@@ -356,22 +491,30 @@ fn parse_tool_method(method: &syn::ImplItemFn) -> syn::Result<Option<ToolDef>> {
     let method_name = method.sig.ident.clone();
     let tool_name = tool_attr.name.unwrap_or_else(|| method_name.to_string());
 
+    validate_signature(&method.sig)?;
+
     // Parse parameters
     let mut params = parse_parameters(&method.sig.inputs)?;
 
     // Check return type
     let return_type = match &method.sig.output {
         ReturnType::Default => {
-            return Err(syn::Error::new_spanned(
-                &method.sig,
-                "Tool methods must have a return type",
+            return Err(syn::Error::new(
+                method.sig.paren_token.span.close(),
+                format!(
+                    "tool method `{method_name}` must have a return type\n\
+                     help: add `-> Result<T, ToolError>` after the parameter list"
+                ),
             ));
         }
         ReturnType::Type(_, ty) => (**ty).clone(),
     };
 
-    // Validate it returns Result<T, ToolError>
-    validate_return_type(&return_type)?;
+    // Validate it returns Result<T, ToolError> or a stream of them
+    let stream_item = stream_item_type(&return_type)?;
+    if stream_item.is_none() {
+        validate_return_type(&return_type)?;
+    }
 
     // Extract description from doc comments if not provided
     let description = if tool_attr.description.is_empty() {
@@ -385,52 +528,91 @@ fn parse_tool_method(method: &syn::ImplItemFn) -> syn::Result<Option<ToolDef>> {
     let metadata = ToolMetadata {
         description,
         short_description: tool_attr.short,
-        rest_config: tool_attr.rest.map(|r| RestConfig {
-            path: r.path,
-            method: parse_http_method(&r.method).unwrap_or_default(),
-        }),
-        mcp_config: tool_attr.mcp.map(|m| {
-            let output_mode = match m.output.as_deref() {
-                None => None,
-                Some("text") => Some(crate::model::McpOutputMode::Text),
-                Some("json") => Some(crate::model::McpOutputMode::Json),
-                Some(other) => {
-                    panic!(
-                        "Invalid mcp(output) value: {}. Expected \"text\" or \"json\".",
-                        other
-                    );
-                }
-            };
-
-            McpConfig {
-                annotations: McpAnnotations {
-                    read_only_hint: m.read_only,
-                    destructive_hint: m.destructive,
-                    idempotent_hint: m.idempotent,
-                    open_world_hint: m.open_world,
-                },
-                output_mode,
-            }
-        }),
-        cli_config: tool_attr.cli.map(|c| CliConfig {
-            name: c.name,
-            aliases: c.alias,
-            hidden: c.hidden,
-            output_formats: c
-                .output_formats
-                .map(|v| v.into_iter().map(|lit| lit.value()).collect())
-                .unwrap_or_default(),
-            progress_style: c.progress_style,
-            examples: vec![], // Examples in attributes are complex to parse with darling
-            supports_stdin: c.supports_stdin.unwrap_or(false),
-            supports_stdout: c.supports_stdout.unwrap_or(false),
-            confirm: c.confirm,
-            interactive: c.interactive.unwrap_or(false),
-            command_path: c
-                .command_path
-                .map(|v| v.into_iter().map(|lit| lit.value()).collect())
-                .unwrap_or_default(),
-        }),
+        rest_config: tool_attr
+            .rest
+            .map(|r| -> syn::Result<RestConfig> {
+                let method = match &r.method {
+                    None => HttpMethod::default(),
+                    Some(lit) => parse_http_method(&lit.value()).ok_or_else(|| {
+                        unknown_value_error(
+                            lit,
+                            "HTTP method",
+                            &lit.value().to_uppercase(),
+                            &["GET", "POST", "PUT", "DELETE", "PATCH"],
+                        )
+                    })?,
+                };
+                Ok(RestConfig {
+                    path: r.path,
+                    method,
+                    auth: r.auth.as_ref().map(parse_rest_auth).transpose()?,
+                })
+            })
+            .transpose()?,
+        mcp_config: tool_attr
+            .mcp
+            .map(|m| -> syn::Result<McpConfig> {
+                let output_mode = match &m.output {
+                    None => None,
+                    Some(lit) => match lit.value().as_str() {
+                        "text" => Some(crate::model::McpOutputMode::Text),
+                        "json" => Some(crate::model::McpOutputMode::Json),
+                        "both" => Some(crate::model::McpOutputMode::Both),
+                        other => {
+                            return Err(unknown_value_error(
+                                lit,
+                                "mcp(output) value",
+                                other,
+                                &["text", "json", "both"],
+                            ));
+                        }
+                    },
+                };
+
+                Ok(McpConfig {
+                    annotations: McpAnnotations {
+                        read_only_hint: m.read_only,
+                        destructive_hint: m.destructive,
+                        idempotent_hint: m.idempotent,
+                        open_world_hint: m.open_world,
+                    },
+                    output_mode,
+                })
+            })
+            .transpose()?,
+        cli_config: tool_attr
+            .cli
+            .map(|c| {
+                let command_path = match (c.group, c.command_path) {
+                    (Some(group), Some(_)) => {
+                        return Err(syn::Error::new_spanned(
+                            group,
+                            "`group` cannot be combined with `command_path`",
+                        ));
+                    }
+                    (Some(group), None) => vec![group.value()],
+                    (None, path) => path
+                        .map(|v| v.into_iter().map(|lit| lit.value()).collect())
+                        .unwrap_or_default(),
+                };
+                Ok(CliConfig {
+                    name: c.name,
+                    aliases: c.alias,
+                    hidden: c.hidden,
+                    output_formats: c
+                        .output_formats
+                        .map(|v| v.into_iter().map(|lit| lit.value()).collect())
+                        .unwrap_or_default(),
+                    progress_style: c.progress_style,
+                    examples: vec![], // Examples in attributes are complex to parse with darling
+                    supports_stdin: c.supports_stdin.unwrap_or(false),
+                    supports_stdout: c.supports_stdout.unwrap_or(false),
+                    confirm: c.confirm,
+                    interactive: c.interactive.unwrap_or(false),
+                    command_path,
+                })
+            })
+            .transpose()?,
     };
 
     // Update parameter sources based on REST path
@@ -457,6 +639,7 @@ fn parse_tool_method(method: &syn::ImplItemFn) -> syn::Result<Option<ToolDef>> {
         tool_name,
         params,
         return_type,
+        stream_item,
         metadata,
         is_async: method.sig.asyncness.is_some(),
         visibility: method.vis.clone(),
@@ -469,13 +652,95 @@ fn find_tool_attribute(attrs: &[Attribute]) -> syn::Result<Option<ToolAttr>> {
         if attr.path().is_ident("universal_tool") {
             let meta = &attr.meta;
             return Ok(Some(
-                ToolAttr::from_meta(meta).map_err(|e| syn::Error::new_spanned(attr, e))?,
+                ToolAttr::from_meta(meta).map_err(|e| syn::Error::from(e.with_span(attr)))?,
             ));
         }
     }
     Ok(None)
 }
 
+/// Check the parts of a tool method's signature the generated adapters rely on:
+/// a `&self` receiver and no generic parameters.
+fn validate_signature(sig: &syn::Signature) -> syn::Result<()> {
+    let method_name = &sig.ident;
+    match sig.receiver() {
+        None => {
+            return Err(syn::Error::new(
+                method_name.span(),
+                format!(
+                    "tool method `{method_name}` must take `&self`\n\
+                     help: add `&self` as the first parameter"
+                ),
+            ));
+        }
+        Some(receiver)
+            if receiver.reference.is_none()
+                || receiver.mutability.is_some()
+                || receiver.colon_token.is_some() =>
+        {
+            return Err(syn::Error::new_spanned(
+                receiver,
+                format!(
+                    "tool method `{method_name}` must take `&self`\n\
+                     help: the generated adapters share the router; use interior mutability \
+                     (e.g. a `Mutex` field) for state the tool changes"
+                ),
+            ));
+        }
+        Some(_) => {}
+    }
+
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &sig.generics,
+            format!(
+                "tool method `{method_name}` cannot be generic\n\
+                 help: use concrete parameter and return types"
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reject parameter types the generated adapters cannot deserialize into, pointing
+/// at the offending (possibly nested) type.
+fn validate_param_type(ty: &Type) -> syn::Result<()> {
+    let help = match ty {
+        Type::Reference(_) => {
+            "help: tool parameters are deserialized, so they must be owned (e.g. `String` instead of `&str`)"
+        }
+        Type::ImplTrait(_) | Type::TraitObject(_) => {
+            "help: use a concrete type that implements `Deserialize` and `JsonSchema`"
+        }
+        Type::BareFn(_) | Type::Ptr(_) | Type::Never(_) | Type::Infer(_) | Type::Macro(_) => {
+            "help: use a type that implements `Deserialize` and `JsonSchema`"
+        }
+        Type::Group(group) => return validate_param_type(&group.elem),
+        Type::Paren(paren) => return validate_param_type(&paren.elem),
+        Type::Path(_) => {
+            // Check type arguments too, e.g. `Option<&str>`
+            for arg in get_generic_args(ty) {
+                validate_param_type(arg)?;
+            }
+            return Ok(());
+        }
+        Type::Array(array) => return validate_param_type(&array.elem),
+        Type::Slice(slice) => return validate_param_type(&slice.elem),
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                validate_param_type(elem)?;
+            }
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+    Err(syn::Error::new_spanned(
+        ty,
+        format!("unsupported tool parameter type\n{help}"),
+    ))
+}
+
 /// Parse function parameters into our model.
 fn parse_parameters(
     inputs: &syn::punctuated::Punctuated<FnArg, syn::Token![,]>,
@@ -501,41 +766,75 @@ fn parse_typed_param(pat_type: &PatType) -> syn::Result<ParamDef> {
     // Extract parameter name
     let name = match &*pat_type.pat {
         Pat::Ident(pat_ident) => pat_ident.ident.clone(),
-        _ => {
+        other => {
             return Err(syn::Error::new_spanned(
-                pat_type,
-                "Tool parameters must be simple identifiers",
+                other,
+                "Tool parameters must be simple identifiers\n\
+                 help: bind the whole value to a name (e.g. `args: Args`) and destructure it in the body",
             ));
         }
     };
 
-    // Parse parameter attributes
-    let param_attr = ParamAttr::from_attributes(&pat_type.attrs)
-        .map_err(|e| syn::Error::new_spanned(pat_type, e))?;
+    // Parse parameter attributes; errors point at the offending item inside
+    // #[universal_tool_param(...)], falling back to the attribute itself.
+    let param_attr = ParamAttr::from_attributes(&pat_type.attrs).map_err(|e| {
+        let fallback = pat_type
+            .attrs
+            .iter()
+            .find(|a| a.path().is_ident("universal_tool_param"));
+        match fallback {
+            Some(attr) => syn::Error::from(e.with_span(attr)),
+            None => syn::Error::from(e.with_span(pat_type)),
+        }
+    })?;
 
     // Parse parameter source
-    let source = if let Some(source_str) = param_attr.source {
-        match source_str.as_str() {
+    let source = if param_attr.from_state {
+        if let Some(lit) = &param_attr.source {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "`from_state` parameters cannot also set `source`",
+            ));
+        }
+        ParamSource::State
+    } else if let Some(lit) = &param_attr.source {
+        match lit.value().as_str() {
             "body" => ParamSource::Body,
             "query" => ParamSource::Query,
             "path" => ParamSource::Path,
             "header" => ParamSource::Header,
-            _ => {
-                return Err(syn::Error::new_spanned(
-                    pat_type,
-                    format!(
-                        "Invalid parameter source: {source_str}. Must be one of: body, query, path, header"
-                    ),
+            other => {
+                return Err(unknown_value_error(
+                    lit,
+                    "parameter source",
+                    other,
+                    &["body", "query", "path", "header"],
                 ));
             }
         }
     } else {
-        ParamSource::default()
+        context_param_source(&pat_type.ty).unwrap_or_default()
     };
 
+    // Injected state is never deserialized, so any type goes
+    if source != ParamSource::State {
+        validate_param_type(&pat_type.ty)?;
+    }
+
     // Check if type is Option<T>
     let is_optional = is_option_type(&pat_type.ty);
 
+    let min = param_attr.min.as_ref().map(parse_bound).transpose()?;
+    let max = param_attr.max.as_ref().map(parse_bound).transpose()?;
+    if let (Some(lo), Some(hi), Some(expr)) = (min, max, &param_attr.max)
+        && lo > hi
+    {
+        return Err(syn::Error::new_spanned(
+            expr,
+            format!("`max` ({hi}) must not be less than `min` ({lo})"),
+        ));
+    }
+
     Ok(ParamDef {
         name,
         ty: (*pat_type.ty).clone(),
@@ -554,10 +853,60 @@ fn parse_typed_param(pat_type: &PatType) -> syn::Result<ParamDef> {
             completions: param_attr.completions,
             multiple: param_attr.multiple.unwrap_or(false),
             delimiter: param_attr.delimiter,
+            min,
+            max,
+            pattern: param_attr.pattern,
+            non_empty: param_attr.non_empty,
+            cli: param_attr.cli.map(Into::into).unwrap_or_default(),
+            mcp: param_attr.mcp.map(Into::into).unwrap_or_default(),
+            rest: param_attr.rest.map(Into::into).unwrap_or_default(),
         },
     })
 }
 
+/// Source of parameters the MCP call context provides, recognized by type name.
+fn context_param_source(ty: &Type) -> Option<ParamSource> {
+    let ty = if is_option_type(ty) {
+        get_generic_args(ty).first().copied()?
+    } else {
+        ty
+    };
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    match type_path.path.segments.last()?.ident.to_string().as_str() {
+        "McpProgressReporter" => Some(ParamSource::Progress),
+        "CancellationToken" => Some(ParamSource::Cancellation),
+        "GlobalFlags" => Some(ParamSource::Flags),
+        _ => None,
+    }
+}
+
+/// Parse a `min`/`max` bound: an integer or float literal, optionally negated.
+fn parse_bound(expr: &Expr) -> syn::Result<f64> {
+    let value = match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<f64>()?,
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => lit.base10_parse::<f64>()?,
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) if matches!(**inner, Expr::Lit(_)) => -parse_bound(inner)?,
+        other => {
+            return Err(syn::Error::new_spanned(
+                other,
+                "expected a numeric literal, e.g. `min = 1` or `max = 0.5`",
+            ));
+        }
+    };
+    Ok(value)
+}
+
 /// Check if a type is Option<T>.
 fn is_option_type(ty: &Type) -> bool {
     if let Type::Path(TypePath { path, .. }) = ty
@@ -614,6 +963,41 @@ fn validate_return_type(ty: &Type) -> syn::Result<()> {
     ))
 }
 
+/// For `impl Stream<Item = Result<T, ToolError>>`, return `T`; `None` for any other
+/// non-`impl` type.
+fn stream_item_type(ty: &Type) -> syn::Result<Option<Type>> {
+    let Type::ImplTrait(impl_trait) = ty else {
+        return Ok(None);
+    };
+
+    let item = impl_trait.bounds.iter().find_map(|bound| {
+        let TypeParamBound::Trait(trait_bound) = bound else {
+            return None;
+        };
+        let segment = trait_bound.path.segments.last()?;
+        if segment.ident != "Stream" {
+            return None;
+        }
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        args.args.iter().find_map(|arg| match arg {
+            GenericArgument::AssocType(assoc) if assoc.ident == "Item" => Some(&assoc.ty),
+            _ => None,
+        })
+    });
+
+    let Some(item) = item else {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "`impl Trait` tool return types must be `impl Stream<Item = Result<T, ToolError>>`",
+        ));
+    };
+    validate_return_type(item)?;
+
+    Ok(get_generic_args(item).first().map(|t| (*t).clone()))
+}
+
 /// Check if a type is ToolError (or a path ending in ToolError).
 fn is_tool_error_type(ty: &Type) -> bool {
     if let Type::Path(TypePath { path, .. }) = ty
@@ -624,6 +1008,34 @@ fn is_tool_error_type(ty: &Type) -> bool {
     false
 }
 
+/// Build an error for an unrecognized attribute value, spanned on the literal and with a
+/// "did you mean" hint when a close match exists.
+fn unknown_value_error(lit: &LitStr, what: &str, value: &str, expected: &[&str]) -> syn::Error {
+    let mut message = format!(
+        "unknown {what} `{value}`; expected one of: {}",
+        expected.join(", ")
+    );
+    if let Some(suggestion) = did_you_mean(value, expected) {
+        message.push_str(&format!("\nhelp: did you mean `{suggestion}`?"));
+    }
+    syn::Error::new_spanned(lit, message)
+}
+
+/// Closest candidate to `value`, if any is similar enough to be a likely typo.
+fn did_you_mean<'a>(value: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|c| {
+            (
+                *c,
+                strsim::jaro_winkler(&value.to_lowercase(), &c.to_lowercase()),
+            )
+        })
+        .filter(|(_, score)| *score > 0.8)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(c, _)| c)
+}
+
 /// Parse HTTP method string.
 fn parse_http_method(method: &str) -> Option<HttpMethod> {
     match method.to_uppercase().as_str() {
@@ -636,6 +1048,20 @@ fn parse_http_method(method: &str) -> Option<HttpMethod> {
     }
 }
 
+/// Parse a `rest(auth = "...")` value.
+fn parse_rest_auth(lit: &LitStr) -> syn::Result<RestAuth> {
+    match lit.value().as_str() {
+        "bearer" => Ok(RestAuth::Bearer),
+        "none" => Ok(RestAuth::None),
+        other => Err(unknown_value_error(
+            lit,
+            "rest(auth) value",
+            other,
+            &["bearer", "none"],
+        )),
+    }
+}
+
 /// Extract documentation from doc comment attributes.
 fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
     let mut docs = Vec::new();
@@ -716,6 +1142,22 @@ mod tests {
         assert!(validate_return_type(&wrong_error).is_err());
     }
 
+    #[test]
+    fn test_stream_item_type() {
+        let stream: Type = syn::parse_quote!(impl Stream<Item = Result<String, ToolError>> + Send);
+        let item = stream_item_type(&stream).unwrap().unwrap();
+        assert_eq!(quote!(#item).to_string(), "String");
+
+        let plain: Type = syn::parse_quote!(Result<String, ToolError>);
+        assert!(stream_item_type(&plain).unwrap().is_none());
+
+        let wrong_item: Type = syn::parse_quote!(impl Stream<Item = String>);
+        assert!(stream_item_type(&wrong_item).is_err());
+
+        let not_stream: Type = syn::parse_quote!(impl Iterator<Item = Result<String, ToolError>>);
+        assert!(stream_item_type(&not_stream).is_err());
+    }
+
     #[test]
     fn test_is_option_type() {
         let opt_type: Type = syn::parse_quote!(Option<String>);
@@ -735,6 +1177,326 @@ mod tests {
         assert_eq!(parse_http_method("INVALID"), None);
     }
 
+    fn parse_err(input: TokenStream) -> String {
+        match parse_router(TokenStream::new(), input) {
+            Ok(_) => panic!("expected parse error"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_invalid_param_source_suggests_closest() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item")]
+                pub async fn get(
+                    &self,
+                    #[universal_tool_param(source = "qeury")] id: String,
+                ) -> Result<String, ToolError> {
+                    Ok(id)
+                }
+            }
+        });
+        assert!(msg.contains("unknown parameter source `qeury`"), "{msg}");
+        assert!(msg.contains("did you mean `query`?"), "{msg}");
+    }
+
+    #[test]
+    fn test_from_state_conflicts_with_source() {
+        let msg = parse_err(quote! {
+            impl Api {
+                #[universal_tool(description = "Get")]
+                pub async fn get(
+                    &self,
+                    #[universal_tool_param(from_state, source = "query")] pool: Pool,
+                ) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("cannot also set `source`"), "{msg}");
+    }
+
+    #[test]
+    fn test_cli_group_conflicts_with_command_path() {
+        let msg = parse_err(quote! {
+            impl Api {
+                #[universal_tool(
+                    description = "Add",
+                    cli(group = "mount", command_path("mount", "add"))
+                )]
+                pub async fn add(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(
+            msg.contains("cannot be combined with `command_path`"),
+            "{msg}"
+        );
+    }
+
+    #[test]
+    fn test_context_params_are_recognized_by_type() {
+        let source = |arg: FnArg| {
+            let FnArg::Typed(pat_type) = arg else {
+                panic!("expected typed argument");
+            };
+            parse_typed_param(&pat_type).unwrap().source
+        };
+        assert_eq!(
+            source(syn::parse_quote!(progress: McpProgressReporter)),
+            ParamSource::Progress
+        );
+        assert_eq!(
+            source(syn::parse_quote!(progress: Option<mcp::McpProgressReporter>)),
+            ParamSource::Progress
+        );
+        assert_eq!(
+            source(syn::parse_quote!(cancel: CancellationToken)),
+            ParamSource::Cancellation
+        );
+        assert_eq!(
+            source(
+                syn::parse_quote!(#[universal_tool_param(from_state)] cancel: CancellationToken)
+            ),
+            ParamSource::State
+        );
+    }
+
+    #[test]
+    fn test_param_constraints() {
+        let arg: FnArg = syn::parse_quote! {
+            #[universal_tool_param(min = -1, max = 2.5, pattern = "^[a-z]+$", non_empty)]
+            value: Option<String>
+        };
+        let FnArg::Typed(pat_type) = arg else {
+            panic!("expected typed argument");
+        };
+        let param = parse_typed_param(&pat_type).unwrap();
+        assert_eq!(param.metadata.min, Some(-1.0));
+        assert_eq!(param.metadata.max, Some(2.5));
+        assert_eq!(param.metadata.pattern.as_deref(), Some("^[a-z]+$"));
+        assert!(param.metadata.non_empty);
+
+        let msg = parse_err(quote! {
+            impl Api {
+                #[universal_tool(description = "List")]
+                pub async fn list(
+                    &self,
+                    #[universal_tool_param(min = 10, max = 1)] limit: u32,
+                ) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("must not be less than `min`"), "{msg}");
+    }
+
+    #[test]
+    fn test_interface_param_overrides() {
+        let arg: FnArg = syn::parse_quote! {
+            #[universal_tool_param(env = "TOKEN", mcp(skip), cli(default = "dev"))]
+            token: String
+        };
+        let FnArg::Typed(pat_type) = arg else {
+            panic!("expected typed argument");
+        };
+        let param = parse_typed_param(&pat_type).unwrap();
+        assert!(param.metadata.skipped_in("mcp"));
+        assert!(!param.metadata.skipped_in("cli"));
+        assert_eq!(param.metadata.default_for("cli"), Some("dev"));
+        assert_eq!(param.metadata.default_for("rest"), None);
+        assert_eq!(param.metadata.env_for("mcp"), Some("TOKEN"));
+
+        let msg = parse_err(quote! {
+            impl Api {
+                #[universal_tool(description = "Get", rest(method = "GET", path = "/items/:id"))]
+                pub async fn get(
+                    &self,
+                    #[universal_tool_param(rest(skip))] id: u32,
+                ) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("cannot be skipped in REST"), "{msg}");
+    }
+
+    #[test]
+    fn test_invalid_mcp_output_is_error_not_panic() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item", mcp(output = "jsno"))]
+                pub async fn get(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("unknown mcp(output) value `jsno`"), "{msg}");
+        assert!(msg.contains("did you mean `json`?"), "{msg}");
+    }
+
+    #[test]
+    fn test_mcp_output_both_is_accepted() {
+        let input = quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item", mcp(output = "both"))]
+                pub async fn get(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        };
+        assert!(parse_router(TokenStream::new(), input).is_ok());
+
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item", mcp(output = "bth"))]
+                pub async fn get(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("did you mean `both`?"), "{msg}");
+    }
+
+    #[test]
+    fn test_unsupported_signatures_are_rejected() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item")]
+                pub async fn get(id: u32) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("tool method `get` must take `&self`"), "{msg}");
+
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item")]
+                pub async fn get(&mut self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("interior mutability"), "{msg}");
+
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item")]
+                pub async fn get<T: Default>(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("cannot be generic"), "{msg}");
+    }
+
+    #[test]
+    fn test_borrowed_param_types_are_rejected() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item")]
+                pub async fn get(&self, name: Option<&str>) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("unsupported tool parameter type"), "{msg}");
+        assert!(msg.contains("`String` instead of `&str`"), "{msg}");
+
+        // Injected state is never deserialized
+        let input = quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item")]
+                pub async fn get(
+                    &self,
+                    #[universal_tool_param(from_state)] db: Arc<dyn Db>,
+                ) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        };
+        assert!(parse_router(TokenStream::new(), input).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_http_method_is_rejected() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item", rest(method = "GETS"))]
+                pub async fn get(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("unknown HTTP method `GETS`"), "{msg}");
+        assert!(msg.contains("did you mean `GET`?"), "{msg}");
+    }
+
+    #[test]
+    fn test_ws_router_generates_adapter() {
+        let input = quote! {
+            impl MyTools {
+                #[universal_tool(description = "Count up")]
+                pub async fn count(&self, to: u32) -> impl Stream<Item = Result<u32, ToolError>> {
+                    universal_tool_core::stream::iter((1..=to).map(Ok))
+                }
+            }
+        };
+        let output = parse_router(quote! { ws(path = "/rpc") }, input).unwrap();
+        syn::parse2::<syn::File>(output.clone()).unwrap();
+        let output = output.to_string();
+        assert_eq!(output.contains("create_ws_router"), cfg!(feature = "ws"));
+    }
+
+    #[test]
+    fn test_invalid_rest_auth_is_rejected() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Get item", rest(auth = "berer"))]
+                pub async fn get(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("unknown rest(auth) value `berer`"), "{msg}");
+        assert!(msg.contains("did you mean `bearer`?"), "{msg}");
+    }
+
+    #[test]
+    fn test_unknown_tool_attribute_field_suggests_alternative() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(descripton = "typo")]
+                pub async fn get(&self) -> Result<String, ToolError> {
+                    Ok(String::new())
+                }
+            }
+        });
+        assert!(msg.contains("descripton"), "{msg}");
+        assert!(msg.contains("description"), "{msg}");
+    }
+
+    #[test]
+    fn test_missing_return_type_has_help() {
+        let msg = parse_err(quote! {
+            impl MyTools {
+                #[universal_tool(description = "Nothing")]
+                pub async fn nothing(&self) {}
+            }
+        });
+        assert!(msg.contains("`nothing` must have a return type"), "{msg}");
+        assert!(msg.contains("-> Result<T, ToolError>"), "{msg}");
+    }
+
+    #[test]
+    fn test_did_you_mean_ignores_distant_values() {
+        assert_eq!(did_you_mean("hedaer", &["body", "header"]), Some("header"));
+        assert_eq!(did_you_mean("zzz", &["body", "header"]), None);
+    }
+
     #[test]
     fn test_extract_doc_comment() {
         let attrs: Vec<Attribute> = vec![
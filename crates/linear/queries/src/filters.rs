@@ -6,6 +6,8 @@ use linear_schema::linear as schema;
 pub struct StringComparator {
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub eq: Option<String>,
+    #[cynic(rename = "eqIgnoreCase", skip_serializing_if = "Option::is_none")]
+    pub eq_ignore_case: Option<String>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub contains: Option<String>,
     #[cynic(rename = "containsIgnoreCase", skip_serializing_if = "Option::is_none")]
@@ -53,6 +55,22 @@ pub struct IdComparator {
     pub eq: Option<cynic::Id>,
 }
 
+/// ID comparator for issue references (`IssueIDComparator` in the schema)
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear", graphql_type = "IssueIDComparator")]
+pub struct IssueIdComparator {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub eq: Option<cynic::Id>,
+}
+
+/// Boolean comparator for filtering by flags
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear")]
+pub struct BooleanComparator {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub eq: Option<bool>,
+}
+
 /// Date comparator for filtering by date ranges
 #[derive(cynic::InputObject, Clone, Debug, Default)]
 #[cynic(schema = "linear")]
@@ -103,6 +121,32 @@ pub struct NullableProjectFilter {
     pub id: Option<IdComparator>,
 }
 
+/// Filter for nullable cycle fields (by ID or active flag)
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear")]
+pub struct NullableCycleFilter {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub id: Option<IdComparator>,
+    #[cynic(rename = "isActive", skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<BooleanComparator>,
+}
+
+/// Filter for nullable project milestone fields (by ID)
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear")]
+pub struct NullableProjectMilestoneFilter {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub id: Option<IdComparator>,
+}
+
+/// Filter for nullable issue fields such as `parent` (by ID)
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear")]
+pub struct NullableIssueFilter {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub id: Option<IssueIdComparator>,
+}
+
 // ============================================================================
 // Metadata query filters
 // ============================================================================
@@ -133,6 +177,16 @@ pub struct NullableTeamFilter {
     pub key: Option<StringComparator>,
 }
 
+/// Cycle filtering options.
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear")]
+pub struct CycleFilter {
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamFilter>,
+    #[cynic(rename = "isPast", skip_serializing_if = "Option::is_none")]
+    pub is_past: Option<BooleanComparator>,
+}
+
 /// Issue label filtering options.
 #[derive(cynic::InputObject, Clone, Debug, Default)]
 #[cynic(schema = "linear")]
@@ -166,10 +220,16 @@ pub struct IssueFilter {
     pub team: Option<TeamFilter>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub project: Option<NullableProjectFilter>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub cycle: Option<NullableCycleFilter>,
+    #[cynic(rename = "projectMilestone", skip_serializing_if = "Option::is_none")]
+    pub project_milestone: Option<NullableProjectMilestoneFilter>,
     #[cynic(rename = "createdAt", skip_serializing_if = "Option::is_none")]
     pub created_at: Option<DateComparator>,
     #[cynic(rename = "updatedAt", skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<DateComparator>,
     #[cynic(skip_serializing_if = "Option::is_none")]
     pub number: Option<NumberComparator>,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<NullableIssueFilter>,
 }
@@ -1,6 +1,7 @@
 use crate::scalars::DateTime;
 use crate::scalars::TimelessDate;
 use crate::types::Issue;
+use crate::types::IssueAttachment;
 use linear_schema::linear as schema;
 
 #[derive(cynic::InputObject, Clone, Debug, Default)]
@@ -235,3 +236,38 @@ pub struct IssueRelationDeleteMutation {
     #[cynic(rename = "issueRelationDelete")]
     pub issue_relation_delete: DeletePayload,
 }
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema = "linear")]
+pub struct AttachmentCreateInput {
+    #[cynic(rename = "issueId")]
+    pub issue_id: String,
+    pub title: String,
+    pub url: String,
+    #[cynic(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(schema = "linear")]
+pub struct AttachmentPayload {
+    pub success: bool,
+    pub attachment: IssueAttachment,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct AttachmentCreateArguments {
+    pub input: AttachmentCreateInput,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    graphql_type = "Mutation",
+    schema = "linear",
+    variables = "AttachmentCreateArguments"
+)]
+pub struct AttachmentCreateMutation {
+    #[arguments(input: $input)]
+    #[cynic(rename = "attachmentCreate")]
+    pub attachment_create: AttachmentPayload,
+}
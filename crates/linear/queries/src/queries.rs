@@ -1,16 +1,20 @@
+use crate::filters::CycleFilter;
 use crate::filters::IssueFilter;
 use crate::filters::IssueLabelFilter;
 use crate::filters::ProjectFilter;
 use crate::filters::TeamFilter;
 use crate::filters::UserFilter;
 use crate::filters::WorkflowStateFilter;
+use crate::types::CycleConnection;
 use crate::types::Issue;
+use crate::types::IssueAttachmentConnection;
 use crate::types::IssueCommentConnection;
 use crate::types::IssueConnection;
 use crate::types::IssueLabelConnection;
 use crate::types::IssueSearchPayload;
 use crate::types::ProjectConnection;
 use crate::types::TeamConnection;
+use crate::types::User;
 use crate::types::UserConnection;
 use crate::types::WorkflowStateConnection;
 use linear_schema::linear as schema;
@@ -93,6 +97,13 @@ pub struct UsersQuery {
     pub users: UserConnection,
 }
 
+/// The user the API key belongs to.
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(graphql_type = "Query", schema = "linear")]
+pub struct ViewerQuery {
+    pub viewer: User,
+}
+
 #[derive(cynic::QueryVariables, Debug, Clone)]
 pub struct TeamsArguments {
     pub first: Option<i32>,
@@ -167,6 +178,24 @@ pub struct IssueLabelsQuery {
     pub issue_labels: IssueLabelConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct CyclesArguments {
+    pub first: Option<i32>,
+    pub after: Option<String>,
+    pub filter: Option<CycleFilter>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "CyclesArguments"
+)]
+pub struct CyclesQuery {
+    #[arguments(first: $first, after: $after, filter: $filter)]
+    pub cycles: CycleConnection,
+}
+
 // ============================================================================
 // Issue relation queries
 // ============================================================================
@@ -175,6 +204,9 @@ pub struct IssueLabelsQuery {
 #[cynic(schema = "linear")]
 pub struct IssueRelation {
     pub id: cynic::Id,
+    #[cynic(rename = "type")]
+    pub relation_type: String,
+    pub issue: RelatedIssue,
     #[cynic(rename = "relatedIssue")]
     pub related_issue: RelatedIssue,
 }
@@ -183,6 +215,8 @@ pub struct IssueRelation {
 #[cynic(schema = "linear", graphql_type = "Issue")]
 pub struct RelatedIssue {
     pub id: cynic::Id,
+    pub identifier: String,
+    pub title: String,
 }
 
 #[derive(cynic::QueryFragment, Debug, Clone)]
@@ -250,3 +284,38 @@ pub struct IssueCommentsQuery {
     #[arguments(id: $id)]
     pub issue: Option<IssueWithComments>,
 }
+
+// ============================================================================
+// Issue attachments query
+// ============================================================================
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(
+    schema = "linear",
+    graphql_type = "Issue",
+    variables = "IssueAttachmentsArguments"
+)]
+pub struct IssueWithAttachments {
+    pub id: cynic::Id,
+    pub identifier: String,
+    #[arguments(first: $first, after: $after)]
+    pub attachments: IssueAttachmentConnection,
+}
+
+#[derive(cynic::QueryVariables, Debug, Clone)]
+pub struct IssueAttachmentsArguments {
+    pub id: String,
+    pub first: Option<i32>,
+    pub after: Option<String>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    graphql_type = "Query",
+    schema = "linear",
+    variables = "IssueAttachmentsArguments"
+)]
+pub struct IssueAttachmentsQuery {
+    #[arguments(id: $id)]
+    pub issue: Option<IssueWithAttachments>,
+}
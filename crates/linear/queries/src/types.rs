@@ -216,3 +216,64 @@ pub struct IssueCommentConnection {
     #[cynic(rename = "pageInfo")]
     pub page_info: PageInfo,
 }
+
+// ============================================================================
+// Issue attachment types
+// ============================================================================
+
+/// Attachment (linked URL) on an issue.
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "Attachment")]
+pub struct IssueAttachment {
+    pub id: cynic::Id,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub url: String,
+    #[cynic(rename = "sourceType")]
+    pub source_type: Option<String>,
+    #[cynic(rename = "createdAt")]
+    pub created_at: DateTime,
+    pub creator: Option<User>,
+}
+
+/// Connection type for issue attachments query.
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(schema = "linear", graphql_type = "AttachmentConnection")]
+pub struct IssueAttachmentConnection {
+    pub nodes: Vec<IssueAttachment>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
+
+// ============================================================================
+// Cycle types
+// ============================================================================
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(schema = "linear")]
+pub struct Cycle {
+    pub id: cynic::Id,
+    pub number: f64,
+    pub name: Option<String>,
+    #[cynic(rename = "startsAt")]
+    pub starts_at: DateTime,
+    #[cynic(rename = "endsAt")]
+    pub ends_at: DateTime,
+    #[cynic(rename = "isActive")]
+    pub is_active: bool,
+    #[cynic(rename = "isNext")]
+    pub is_next: bool,
+    #[cynic(rename = "isPrevious")]
+    pub is_previous: bool,
+    #[cynic(rename = "isPast")]
+    pub is_past: bool,
+    pub progress: f64,
+}
+
+#[derive(cynic::QueryFragment, Debug, Clone)]
+#[cynic(schema = "linear")]
+pub struct CycleConnection {
+    pub nodes: Vec<Cycle>,
+    #[cynic(rename = "pageInfo")]
+    pub page_info: PageInfo,
+}
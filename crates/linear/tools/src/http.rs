@@ -1,14 +1,19 @@
+use crate::rate_limit;
+use crate::rate_limit::RateLimitTracker;
+use crate::rate_limit::RetryPolicy;
 use agentic_config::types::LinearServiceConfig;
 use anyhow::Result;
 use anyhow::anyhow;
-use cynic::http::ReqwestExt;
 use reqwest::Client;
+use std::sync::Arc;
 use std::time::Duration;
 
 pub struct LinearClient {
     client: Client,
     url: String,
     api_key: String,
+    retry_policy: RetryPolicy,
+    rate_limits: Arc<RateLimitTracker>,
 }
 
 /// Centralized GraphQL error extraction - fails fast on any errors
@@ -71,9 +76,18 @@ impl LinearClient {
             client,
             url,
             api_key,
+            retry_policy: RetryPolicy::from_config(config),
+            rate_limits: Arc::new(RateLimitTracker::new()),
         })
     }
 
+    /// Record response quotas in `tracker` instead of a client-local one.
+    #[must_use]
+    pub fn with_rate_limits(mut self, tracker: Arc<RateLimitTracker>) -> Self {
+        self.rate_limits = tracker;
+        self
+    }
+
     pub async fn run<Q, V>(&self, op: cynic::Operation<Q, V>) -> Result<cynic::GraphQlResponse<Q>>
     where
         Q: serde::de::DeserializeOwned + 'static,
@@ -93,8 +107,62 @@ impl LinearClient {
             req = req.bearer_auth(&self.api_key);
         }
 
-        let result = req.run_graphql(op).await;
-        result.map_err(|e| anyhow!(e))
+        let req = req.json(&op);
+
+        // Retry rate-limited and 5xx responses while the wait fits the policy.
+        let mut attempt = 0u32;
+        loop {
+            let pending = req
+                .try_clone()
+                .ok_or_else(|| anyhow!("internal: Linear request body cannot be retried"))?;
+            let response = pending.send().await?;
+            self.rate_limits.record(response.headers());
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let text = response.text().await?;
+            let now = rate_limit::now_unix();
+            let retry = (!status.is_success() || text.contains("\"RATELIMITED\""))
+                .then(|| rate_limit::retry_delay(status, &headers, &text, attempt, now))
+                .flatten();
+            let Some(wait) = retry else {
+                return parse_response(status, &text);
+            };
+
+            if attempt >= self.retry_policy.max_retries || wait > self.retry_policy.max_wait {
+                if rate_limit::is_rate_limited(status, &text) {
+                    return Err(rate_limit::rate_limited_error(
+                        wait,
+                        attempt,
+                        self.rate_limits.snapshot().as_ref(),
+                        now,
+                    ));
+                }
+                return parse_response(status, &text);
+            }
+
+            tracing::warn!(
+                status = status.as_u16(),
+                wait_secs = wait.as_secs(),
+                attempt,
+                "Linear request failed; retrying"
+            );
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+}
+
+/// Decode a GraphQL response body. Error statuses still carry a GraphQL body
+/// (e.g. a 400 with `errors`); only bodies that are not one become HTTP errors.
+fn parse_response<Q>(status: reqwest::StatusCode, text: &str) -> Result<cynic::GraphQlResponse<Q>>
+where
+    Q: serde::de::DeserializeOwned,
+{
+    match serde_json::from_str(text) {
+        Ok(resp) => Ok(resp),
+        Err(_) if !status.is_success() => Err(anyhow!("Linear API returned {status}: {text}")),
+        Err(e) => Err(anyhow!("Failed to parse Linear response: {e}")),
     }
 }
 
@@ -129,6 +197,7 @@ mod tests {
             base_url: "https://linear.example/graphql".into(),
             connect_timeout_secs: 0,
             request_timeout_secs: 0,
+            ..Default::default()
         };
 
         let client = LinearClient::new(Some("token".into()), &config).unwrap();
@@ -0,0 +1,321 @@
+//! Local full-text index of fetched issues.
+//!
+//! When `services.linear.index.enabled` is set, every issue returned by
+//! `search_issues`/`read_issue` is upserted into a `SQLite` database with an FTS5
+//! table over identifier, title and description. `linear_search_local` queries it
+//! without touching the API; each hit carries the time it was fetched so callers
+//! can judge staleness, and stale hits are re-read in the background.
+
+use crate::models::IndexedIssue;
+use crate::models::IssueSummary;
+use anyhow::Context;
+use anyhow::Result;
+use rusqlite::Connection;
+use rusqlite::OptionalExtension;
+use rusqlite::params;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+const SCHEMA: &str = r"
+CREATE TABLE IF NOT EXISTS issues (
+    id TEXT PRIMARY KEY,
+    identifier TEXT NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT,
+    summary_json TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL
+);
+CREATE VIRTUAL TABLE IF NOT EXISTS issues_fts USING fts5(
+    id UNINDEXED,
+    identifier,
+    title,
+    description,
+    tokenize = 'porter unicode61'
+);
+";
+
+/// Default database location: `$XDG_CACHE_HOME/agentic/linear-index.sqlite3`,
+/// falling back to `~/.cache`.
+pub fn default_index_path() -> Option<PathBuf> {
+    let cache = std::env::var("XDG_CACHE_HOME")
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".cache")))?;
+    Some(cache.join("agentic").join("linear-index.sqlite3"))
+}
+
+pub struct IssueIndex {
+    conn: Mutex<Connection>,
+    stale_after: Duration,
+    /// Issue ids with a background refresh in flight.
+    refreshing: Mutex<HashSet<String>>,
+}
+
+impl IssueIndex {
+    /// Open (or create) the index at `path`.
+    pub fn open(path: &Path, stale_after: Duration) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let conn = Connection::open(path)
+            .with_context(|| format!("failed to open issue index {}", path.display()))?;
+        Self::with_connection(conn, stale_after)
+    }
+
+    /// Open a throwaway in-memory index.
+    pub fn open_in_memory(stale_after: Duration) -> Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?, stale_after)
+    }
+
+    fn with_connection(conn: Connection, stale_after: Duration) -> Result<Self> {
+        conn.execute_batch(SCHEMA)
+            .context("failed to initialize issue index schema")?;
+        Ok(Self {
+            conn: Mutex::new(conn),
+            stale_after,
+            refreshing: Mutex::new(HashSet::new()),
+        })
+    }
+
+    /// Upsert issues fetched from the API. A `None` description keeps whatever
+    /// description was stored previously.
+    pub fn record(&self, issues: &[(IssueSummary, Option<String>)]) -> Result<()> {
+        self.record_at(issues, chrono::Utc::now().timestamp())
+    }
+
+    fn record_at(&self, issues: &[(IssueSummary, Option<String>)], fetched_at: i64) -> Result<()> {
+        let mut conn = self.lock();
+        let tx = conn.transaction()?;
+        for (issue, description) in issues {
+            tx.execute(
+                "INSERT INTO issues (id, identifier, title, description, summary_json, fetched_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT(id) DO UPDATE SET
+                    identifier = excluded.identifier,
+                    title = excluded.title,
+                    description = COALESCE(excluded.description, issues.description),
+                    summary_json = excluded.summary_json,
+                    fetched_at = excluded.fetched_at",
+                params![
+                    issue.id,
+                    issue.identifier,
+                    issue.title,
+                    description,
+                    serde_json::to_string(issue)?,
+                    fetched_at,
+                ],
+            )?;
+            tx.execute("DELETE FROM issues_fts WHERE id = ?1", params![issue.id])?;
+            tx.execute(
+                "INSERT INTO issues_fts (id, identifier, title, description)
+                 SELECT id, identifier, title, COALESCE(description, '') FROM issues WHERE id = ?1",
+                params![issue.id],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Number of issues in the index.
+    pub fn len(&self) -> Result<usize> {
+        let n: i64 = self
+            .lock()
+            .query_row("SELECT COUNT(*) FROM issues", [], |r| r.get(0))?;
+        Ok(usize::try_from(n).unwrap_or(0))
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Rank indexed issues against a free-text query. Title matches weigh most,
+    /// then identifier, then description.
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<IndexedIssue>> {
+        self.search_at(query, limit, chrono::Utc::now().timestamp())
+    }
+
+    fn search_at(&self, query: &str, limit: usize, now: i64) -> Result<Vec<IndexedIssue>> {
+        let Some(fts_query) = fts_query(query) else {
+            return Ok(Vec::new());
+        };
+        let conn = self.lock();
+        let mut stmt = conn.prepare(
+            "SELECT i.summary_json, i.fetched_at,
+                    snippet(issues_fts, 3, '[', ']', '…', 12)
+             FROM issues_fts
+             JOIN issues i ON i.id = issues_fts.id
+             WHERE issues_fts MATCH ?1
+             ORDER BY bm25(issues_fts, 0.0, 5.0, 10.0, 1.0)
+             LIMIT ?2",
+        )?;
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows = stmt.query_map(params![fts_query, limit], |r| {
+            Ok((
+                r.get::<_, String>(0)?,
+                r.get::<_, i64>(1)?,
+                r.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let stale_after = i64::try_from(self.stale_after.as_secs()).unwrap_or(i64::MAX);
+        let mut out = Vec::new();
+        for row in rows {
+            let (json, fetched_at, snippet) = row?;
+            let issue: IssueSummary = serde_json::from_str(&json)
+                .context("internal: corrupt issue summary in local index")?;
+            let age_secs = u64::try_from(now - fetched_at).unwrap_or(0);
+            out.push(IndexedIssue {
+                issue,
+                snippet: (!snippet.trim().is_empty()).then_some(snippet),
+                fetched_at: chrono::DateTime::from_timestamp(fetched_at, 0)
+                    .map(|d| d.to_rfc3339())
+                    .unwrap_or_default(),
+                age_secs,
+                stale: now - fetched_at > stale_after,
+            });
+        }
+        Ok(out)
+    }
+
+    /// Stored description for an issue, if any.
+    pub fn description(&self, id: &str) -> Result<Option<String>> {
+        let d = self
+            .lock()
+            .query_row(
+                "SELECT description FROM issues WHERE id = ?1",
+                params![id],
+                |r| r.get::<_, Option<String>>(0),
+            )
+            .optional()?;
+        Ok(d.flatten())
+    }
+
+    /// Mark `id` as being refreshed. Returns `false` if a refresh is already running.
+    pub fn begin_refresh(&self, id: &str) -> bool {
+        self.refreshing
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(id.to_string())
+    }
+
+    pub fn end_refresh(&self, id: &str) {
+        self.refreshing
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(id);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Connection> {
+        self.conn
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Turn free text into an FTS5 query: each alphanumeric word becomes a quoted
+/// prefix term, OR-ed together so bm25 ranking decides relevance.
+fn fts_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| format!("\"{}\"*", t.to_lowercase()))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TeamRef;
+
+    fn summary(id: &str, identifier: &str, title: &str) -> IssueSummary {
+        IssueSummary {
+            id: id.into(),
+            identifier: identifier.into(),
+            title: title.into(),
+            url: format!("https://linear.app/x/issue/{identifier}"),
+            team: TeamRef {
+                id: "t1".into(),
+                key: "ENG".into(),
+                name: "Engineering".into(),
+            },
+            state: None,
+            assignee: None,
+            creator: None,
+            project: None,
+            priority: 0,
+            priority_label: "No priority".into(),
+            label_ids: vec![],
+            due_date: None,
+            created_at: "2025-01-01T00:00:00Z".into(),
+            updated_at: "2025-01-01T00:00:00Z".into(),
+        }
+    }
+
+    #[test]
+    fn fts_query_quotes_terms_and_drops_punctuation() {
+        assert_eq!(
+            fts_query("the \"retry\" bug?").as_deref(),
+            Some("\"the\"* OR \"retry\"* OR \"bug\"*")
+        );
+        assert_eq!(fts_query("  ?! "), None);
+    }
+
+    #[test]
+    fn search_ranks_stemmed_matches_and_reports_staleness() {
+        let index = IssueIndex::open_in_memory(Duration::from_secs(60)).unwrap();
+        index
+            .record_at(
+                &[
+                    (
+                        summary("a", "ENG-1", "Webhook delivery retries forever"),
+                        Some("Backoff is never applied.".into()),
+                    ),
+                    (
+                        summary("b", "ENG-2", "Dark mode toggle"),
+                        Some("Mentions a retry once in passing.".into()),
+                    ),
+                    (summary("c", "ENG-3", "Unrelated"), None),
+                ],
+                1_000,
+            )
+            .unwrap();
+
+        let hits = index
+            .search_at("the issue about retrying", 10, 1_030)
+            .unwrap();
+        let ids: Vec<_> = hits.iter().map(|h| h.issue.identifier.as_str()).collect();
+        assert_eq!(ids, ["ENG-1", "ENG-2"]);
+        assert_eq!(hits[0].age_secs, 30);
+        assert!(!hits[0].stale);
+
+        let later = index.search_at("retry", 10, 1_100).unwrap();
+        assert!(later.iter().all(|h| h.stale));
+        assert!(later[1].snippet.as_deref().unwrap().contains("[retry]"));
+    }
+
+    #[test]
+    fn record_without_description_keeps_previous_description() {
+        let index = IssueIndex::open_in_memory(Duration::from_secs(60)).unwrap();
+        index
+            .record(&[(summary("a", "ENG-1", "Old"), Some("flaky retries".into()))])
+            .unwrap();
+        index
+            .record(&[(summary("a", "ENG-1", "New"), None)])
+            .unwrap();
+
+        assert_eq!(index.len().unwrap(), 1);
+        assert_eq!(
+            index.description("a").unwrap().as_deref(),
+            Some("flaky retries")
+        );
+        let hits = index.search("retries", 5).unwrap();
+        assert_eq!(hits[0].issue.title, "New");
+    }
+}
@@ -1,12 +1,16 @@
 pub mod http;
+pub mod index;
 pub mod models;
+pub mod rate_limit;
 pub mod tools;
+pub mod webhooks;
 
 /// Test support utilities (for use in tests)
 #[doc(hidden)]
 pub mod test_support;
 
 use agentic_config::types::LinearServiceConfig;
+use agentic_tools_core::ToolContext;
 use agentic_tools_utils::pagination::PaginationCache;
 use agentic_tools_utils::pagination::paginate_slice;
 use anyhow::Context;
@@ -14,13 +18,23 @@ use anyhow::Result;
 use cynic::MutationBuilder;
 use cynic::QueryBuilder;
 use http::LinearClient;
+use index::IssueIndex;
+use linear_queries::AttachmentCreateArguments;
+use linear_queries::AttachmentCreateInput;
+use linear_queries::AttachmentCreateMutation;
+use linear_queries::BooleanComparator;
 use linear_queries::CommentCreateArguments;
 use linear_queries::CommentCreateInput;
 use linear_queries::CommentCreateMutation;
+use linear_queries::CycleFilter;
+use linear_queries::CyclesArguments;
+use linear_queries::CyclesQuery;
 use linear_queries::DateComparator;
 use linear_queries::IdComparator;
 use linear_queries::IssueArchiveArguments;
 use linear_queries::IssueArchiveMutation;
+use linear_queries::IssueAttachmentsArguments;
+use linear_queries::IssueAttachmentsQuery;
 use linear_queries::IssueByIdArguments;
 use linear_queries::IssueByIdQuery;
 use linear_queries::IssueCommentsArguments;
@@ -29,6 +43,7 @@ use linear_queries::IssueCreateArguments;
 use linear_queries::IssueCreateInput;
 use linear_queries::IssueCreateMutation;
 use linear_queries::IssueFilter;
+use linear_queries::IssueIdComparator;
 use linear_queries::IssueRelationCreateArguments;
 use linear_queries::IssueRelationCreateInput;
 use linear_queries::IssueRelationCreateMutation;
@@ -42,18 +57,23 @@ use linear_queries::IssueUpdateInput;
 use linear_queries::IssueUpdateMutation;
 use linear_queries::IssuesArguments;
 use linear_queries::IssuesQuery;
+use linear_queries::NullableCycleFilter;
+use linear_queries::NullableIssueFilter;
 use linear_queries::NullableNumberComparator;
 use linear_queries::NullableProjectFilter;
+use linear_queries::NullableProjectMilestoneFilter;
 use linear_queries::NullableUserFilter;
 use linear_queries::NumberComparator;
 use linear_queries::SearchIssuesArguments;
 use linear_queries::SearchIssuesQuery;
 use linear_queries::StringComparator;
 use linear_queries::TeamFilter;
+use linear_queries::ViewerQuery;
 use linear_queries::WorkflowStateFilter;
 use linear_queries::scalars::DateTimeOrDuration;
 use regex::Regex;
 use std::sync::Arc;
+use std::time::Duration;
 
 // Re-export agentic-tools types for MCP server usage
 pub use tools::build_registry;
@@ -76,12 +96,27 @@ fn parse_identifier(input: &str) -> Option<(String, i32)> {
 const COMMENTS_PAGE_SIZE: usize = 10;
 const ISSUE_COMMENTS_FETCH_PAGE_SIZE: i32 = 50;
 const ISSUE_COMMENTS_MAX_PAGES: usize = 100;
+/// Upper bound on `first` for explicit comment pagination.
+const ISSUE_COMMENTS_FETCH_MAX_FIRST: i32 = 100;
+const ATTACHMENTS_FETCH_PAGE_SIZE: i32 = 50;
+const ATTACHMENTS_MAX_PAGES: usize = 20;
+const LOCAL_SEARCH_DEFAULT_LIMIT: usize = 10;
+const LOCAL_SEARCH_MAX_LIMIT: usize = 50;
+/// `cycle_id` keyword that scopes a search to each team's active cycle.
+const ACTIVE_CYCLE: &str = "active";
+/// User-ID alias for the owner of the API key.
+const ME: &str = "me";
+/// Most issues (root plus sub-issues) one export will include.
+const EXPORT_MAX_ISSUES: usize = 100;
 
 #[derive(Clone)]
 pub struct LinearTools {
     api_key: Option<String>,
     config: LinearServiceConfig,
     comments_cache: Arc<PaginationCache<models::CommentSummary, String>>,
+    index: Option<Arc<IssueIndex>>,
+    rate_limits: Arc<rate_limit::RateLimitTracker>,
+    viewer_id: Arc<tokio::sync::OnceCell<String>>,
 }
 
 impl LinearTools {
@@ -90,22 +125,66 @@ impl LinearTools {
     }
 
     pub fn with_config(config: LinearServiceConfig) -> Self {
+        let index = config.index.enabled.then(|| open_index(&config)).flatten();
         Self {
             api_key: std::env::var("LINEAR_API_KEY").ok(),
             config,
             comments_cache: Arc::new(PaginationCache::new()),
+            index,
+            rate_limits: Arc::new(rate_limit::RateLimitTracker::new()),
+            viewer_id: Arc::new(tokio::sync::OnceCell::new()),
         }
     }
 
+    /// Use `api_key` instead of the `LINEAR_API_KEY` environment variable.
+    #[must_use]
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Use `index` as the local issue index regardless of config.
+    #[must_use]
+    pub fn with_index(mut self, index: Arc<IssueIndex>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
     pub fn config(&self) -> &LinearServiceConfig {
         &self.config
     }
 
+    /// Look up a configured search preset by name.
+    pub fn search_preset(&self, name: &str) -> Result<&agentic_config::types::LinearSearchPreset> {
+        self.config.presets.get(name.trim()).ok_or_else(|| {
+            let known: Vec<&str> = self.config.presets.keys().map(String::as_str).collect();
+            anyhow::anyhow!(
+                "invalid argument: unknown preset '{name}'; configured presets: {}",
+                if known.is_empty() {
+                    "<none> (add them under services.linear.presets)".to_string()
+                } else {
+                    known.join(", ")
+                }
+            )
+        })
+    }
+
     fn client(&self) -> Result<LinearClient> {
         LinearClient::new(self.api_key.clone(), &self.config)
+            .map(|c| c.with_rate_limits(Arc::clone(&self.rate_limits)))
             .context("internal: failed to create Linear client")
     }
 
+    /// Record fetched issues in the local index, if enabled. Failures are logged,
+    /// never surfaced: the index is a cache.
+    fn index_fetched(&self, issues: &[(models::IssueSummary, Option<String>)]) {
+        if let Some(index) = &self.index
+            && let Err(e) = index.record(issues)
+        {
+            tracing::warn!("failed to update local issue index: {e:#}");
+        }
+    }
+
     fn resolve_issue_id(input: &str) -> IssueIdentifier {
         // Try to parse as identifier (handles lowercase and URLs)
         if let Some((key, number)) = parse_identifier(input) {
@@ -154,6 +233,121 @@ impl LinearTools {
             }
         }
     }
+
+    /// `team_id`, falling back to the configured `default_team`.
+    fn team_or_default(&self, team_id: Option<String>) -> Result<String> {
+        team_id
+            .filter(|t| !t.trim().is_empty())
+            .or_else(|| self.config.default_team.clone())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "invalid argument: team_id is required when services.linear.default_team is not set"
+                )
+            })
+    }
+
+    /// Resolve `"me"` to the API key owner's user ID (fetched once); other values
+    /// pass through.
+    async fn resolve_user_id(&self, client: &LinearClient, input: String) -> Result<String> {
+        if !input.trim().eq_ignore_ascii_case(ME) {
+            return Ok(input);
+        }
+        self.viewer_id
+            .get_or_try_init(|| async {
+                let data = http::extract_data(client.run(ViewerQuery::build(())).await?)?;
+                Ok::<_, anyhow::Error>(data.viewer.id.inner().to_string())
+            })
+            .await
+            .cloned()
+    }
+
+    /// Resolve a team key such as `ENG` to its ID; IDs pass through.
+    async fn resolve_team_id(&self, client: &LinearClient, input: String) -> Result<String> {
+        if !is_team_key(&input) {
+            return Ok(input);
+        }
+        let key = input.trim().to_uppercase();
+        let op = linear_queries::TeamsQuery::build(linear_queries::TeamsArguments {
+            first: Some(1),
+            after: None,
+            filter: Some(TeamFilter {
+                key: Some(StringComparator {
+                    eq: Some(key.clone()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+        });
+        let data = http::extract_data(client.run(op).await?)?;
+        data.teams
+            .nodes
+            .into_iter()
+            .next()
+            .map(|t| t.id.inner().to_string())
+            .ok_or_else(|| anyhow::anyhow!("not found: Team {key} not found"))
+    }
+
+    /// Resolve a project name (case-insensitive) to its ID; IDs pass through.
+    async fn resolve_project_id(&self, client: &LinearClient, input: String) -> Result<String> {
+        if is_uuid(&input) {
+            return Ok(input);
+        }
+        let name = input.trim().to_string();
+        let op = linear_queries::ProjectsQuery::build(linear_queries::ProjectsArguments {
+            first: Some(1),
+            after: None,
+            filter: Some(linear_queries::ProjectFilter {
+                name: Some(StringComparator {
+                    eq_ignore_case: Some(name.clone()),
+                    ..Default::default()
+                }),
+            }),
+        });
+        let data = http::extract_data(client.run(op).await?)?;
+        data.projects
+            .nodes
+            .into_iter()
+            .next()
+            .map(|p| p.id.inner().to_string())
+            .ok_or_else(|| anyhow::anyhow!("not found: Project '{name}' not found"))
+    }
+}
+
+/// Match a team by key (e.g. `ENG`) or ID, whichever `team` looks like.
+fn team_filter(team: String) -> TeamFilter {
+    if is_team_key(&team) {
+        TeamFilter {
+            key: Some(StringComparator {
+                eq: Some(team.trim().to_uppercase()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    } else {
+        TeamFilter {
+            id: Some(IdComparator {
+                eq: Some(cynic::Id::new(team)),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// Team keys are short alphabetic codes, matching the prefix of issue identifiers.
+fn is_team_key(input: &str) -> bool {
+    let input = input.trim();
+    (2..=10).contains(&input.len()) && input.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_uuid(input: &str) -> bool {
+    input.len() == 36
+        && input.char_indices().all(|(i, c)| {
+            if matches!(i, 8 | 13 | 18 | 23) {
+                c == '-'
+            } else {
+                c.is_ascii_hexdigit()
+            }
+        })
 }
 
 impl Default for LinearTools {
@@ -162,6 +356,100 @@ impl Default for LinearTools {
     }
 }
 
+fn open_index(config: &LinearServiceConfig) -> Option<Arc<IssueIndex>> {
+    let path = config
+        .index
+        .path
+        .as_ref()
+        .map(std::path::PathBuf::from)
+        .or_else(index::default_index_path)?;
+    let stale_after = Duration::from_secs(config.index.stale_after_secs);
+    match IssueIndex::open(&path, stale_after) {
+        Ok(index) => Some(Arc::new(index)),
+        Err(e) => {
+            tracing::warn!("local issue index disabled: {e:#}");
+            None
+        }
+    }
+}
+
+fn attachment_summary(a: linear_queries::IssueAttachment) -> models::AttachmentSummary {
+    models::AttachmentSummary {
+        id: a.id.inner().to_string(),
+        title: a.title,
+        subtitle: a.subtitle,
+        url: a.url,
+        source_type: a.source_type,
+        created_at: a.created_at.0,
+        creator_name: a.creator.map(|u| u.name),
+    }
+}
+
+fn relation_type_name(t: IssueRelationType) -> &'static str {
+    match t {
+        IssueRelationType::Blocks => "blocks",
+        IssueRelationType::Duplicate => "duplicate",
+        IssueRelationType::Related => "related",
+        IssueRelationType::Similar => "similar",
+    }
+}
+
+/// Flatten outgoing and inverse relations into refs labelled from the issue's side.
+fn relation_refs(issue: linear_queries::IssueWithRelations) -> Vec<models::IssueRelationRef> {
+    let outgoing = issue.relations.nodes.into_iter().map(|r| {
+        let relation = match r.relation_type.as_str() {
+            "duplicate" => "duplicate_of".to_string(),
+            other => other.to_string(),
+        };
+        (r.id, relation, r.related_issue)
+    });
+    let inverse = issue.inverse_relations.nodes.into_iter().map(|r| {
+        let relation = match r.relation_type.as_str() {
+            "blocks" => "blocked_by".to_string(),
+            "duplicate" => "duplicated_by".to_string(),
+            other => other.to_string(),
+        };
+        (r.id, relation, r.issue)
+    });
+    outgoing
+        .chain(inverse)
+        .map(|(id, relation, other)| models::IssueRelationRef {
+            id: id.inner().to_string(),
+            relation,
+            issue_id: other.id.inner().to_string(),
+            identifier: other.identifier,
+            title: other.title,
+        })
+        .collect()
+}
+
+/// Canonical form of an attachment URL for duplicate detection: scheme and host
+/// lowercased, default port and fragment dropped, trailing slash trimmed.
+/// Unparseable URLs compare by their trimmed text.
+fn normalize_attachment_url(raw: &str) -> String {
+    let raw = raw.trim();
+    let Ok(mut url) = url::Url::parse(raw) else {
+        return raw.to_string();
+    };
+    url.set_fragment(None);
+    let trimmed = url.path().trim_end_matches('/').to_string();
+    url.set_path(&trimmed);
+    url.to_string().trim_end_matches('/').to_string()
+}
+
+fn comment_summary(c: linear_queries::IssueComment) -> models::CommentSummary {
+    models::CommentSummary {
+        id: c.id.inner().to_string(),
+        body: c.body,
+        url: c.url,
+        created_at: c.created_at.0,
+        updated_at: c.updated_at.0,
+        parent_id: c.parent_id,
+        author_name: c.user.as_ref().map(|u| u.name.clone()),
+        author_email: c.user.as_ref().map(|u| u.email.clone()),
+    }
+}
+
 enum IssueIdentifier {
     Id(String),
     Identifier(String),
@@ -227,6 +515,31 @@ impl From<linear_queries::ParentIssue> for models::ParentIssueRef {
     }
 }
 
+impl From<linear_queries::Cycle> for models::CycleSummary {
+    fn from(c: linear_queries::Cycle) -> Self {
+        let status = if c.is_active {
+            "active"
+        } else if c.is_next {
+            "next"
+        } else if c.is_previous {
+            "previous"
+        } else if c.is_past {
+            "past"
+        } else {
+            "upcoming"
+        };
+        Self {
+            id: c.id.inner().to_string(),
+            number: c.number as i32,
+            name: c.name,
+            starts_at: c.starts_at.0,
+            ends_at: c.ends_at.0,
+            status: status.to_string(),
+            progress: c.progress,
+        }
+    }
+}
+
 impl From<linear_queries::Issue> for models::IssueSummary {
     fn from(i: linear_queries::Issue) -> Self {
         Self {
@@ -285,6 +598,8 @@ impl LinearTools {
         creator_id: Option<String>,
         team_id: Option<String>,
         project_id: Option<String>,
+        cycle_id: Option<String>,
+        project_milestone_id: Option<String>,
         created_after: Option<String>,
         created_before: Option<String>,
         updated_after: Option<String>,
@@ -311,6 +626,7 @@ impl LinearTools {
             });
         }
         if let Some(id) = assignee_id {
+            let id = self.resolve_user_id(&client, id).await?;
             filter.assignee = Some(NullableUserFilter {
                 id: Some(IdComparator {
                     eq: Some(cynic::Id::new(id)),
@@ -318,22 +634,40 @@ impl LinearTools {
             });
         }
         if let Some(id) = creator_id {
+            let id = self.resolve_user_id(&client, id).await?;
             filter.creator = Some(NullableUserFilter {
                 id: Some(IdComparator {
                     eq: Some(cynic::Id::new(id)),
                 }),
             });
         }
-        if let Some(id) = team_id {
-            filter.team = Some(TeamFilter {
+        if let Some(team) = team_id {
+            filter.team = Some(team_filter(team));
+        }
+        if let Some(id) = project_id {
+            filter.project = Some(NullableProjectFilter {
                 id: Some(IdComparator {
                     eq: Some(cynic::Id::new(id)),
                 }),
-                ..Default::default()
             });
         }
-        if let Some(id) = project_id {
-            filter.project = Some(NullableProjectFilter {
+        if let Some(id) = cycle_id {
+            filter.cycle = Some(if id.trim().eq_ignore_ascii_case(ACTIVE_CYCLE) {
+                NullableCycleFilter {
+                    is_active: Some(BooleanComparator { eq: Some(true) }),
+                    ..Default::default()
+                }
+            } else {
+                NullableCycleFilter {
+                    id: Some(IdComparator {
+                        eq: Some(cynic::Id::new(id)),
+                    }),
+                    ..Default::default()
+                }
+            });
+        }
+        if let Some(id) = project_milestone_id {
+            filter.project_milestone = Some(NullableProjectMilestoneFilter {
                 id: Some(IdComparator {
                     eq: Some(cynic::Id::new(id)),
                 }),
@@ -360,6 +694,8 @@ impl LinearTools {
             || filter.creator.is_some()
             || filter.team.is_some()
             || filter.project.is_some()
+            || filter.cycle.is_some()
+            || filter.project_milestone.is_some()
             || filter.created_at.is_some()
             || filter.updated_at.is_some())
         .then_some(filter);
@@ -377,12 +713,23 @@ impl LinearTools {
             let resp = client.run(op).await?;
             let data = http::extract_data(resp)?;
 
-            let issues = data.issues.nodes.into_iter().map(Into::into).collect();
+            let fetched: Vec<_> = data
+                .issues
+                .nodes
+                .into_iter()
+                .map(|n| {
+                    let description = n.description.clone();
+                    (models::IssueSummary::from(n), description)
+                })
+                .collect();
+            self.index_fetched(&fetched);
+            let issues = fetched.into_iter().map(|(i, _)| i).collect();
 
             Ok(models::SearchResult {
                 issues,
                 has_next_page: data.issues.page_info.has_next_page,
                 end_cursor: data.issues.page_info.end_cursor,
+                rate_limit: self.rate_limits.snapshot(),
             })
         } else {
             // Full-text search path: searchIssues
@@ -396,17 +743,23 @@ impl LinearTools {
             let resp = client.run(op).await?;
             let data = http::extract_data(resp)?;
 
-            let issues = data
+            let fetched: Vec<_> = data
                 .search_issues
                 .nodes
                 .into_iter()
-                .map(Into::into)
+                .map(|n| {
+                    let description = n.description.clone();
+                    (models::IssueSummary::from(n), description)
+                })
                 .collect();
+            self.index_fetched(&fetched);
+            let issues = fetched.into_iter().map(|(i, _)| i).collect();
 
             Ok(models::SearchResult {
                 issues,
                 has_next_page: data.search_issues.page_info.has_next_page,
                 end_cursor: data.search_issues.page_info.end_cursor,
+                rate_limit: self.rate_limits.snapshot(),
             })
         }
     }
@@ -466,8 +819,11 @@ impl LinearTools {
             id: p.id.inner().to_string(),
             identifier: p.identifier.clone(),
         });
+        let relations = Self::fetch_relations(&client, issue_data.id.inner().to_string()).await?;
+        let relations = relation_refs(relations);
 
         let summary: models::IssueSummary = issue_data.into();
+        self.index_fetched(&[(summary.clone(), description.clone())]);
 
         Ok(models::IssueDetails {
             issue: summary,
@@ -477,6 +833,141 @@ impl LinearTools {
             started_at,
             completed_at,
             canceled_at,
+            relations,
+        })
+    }
+
+    /// Render an issue, optionally with its comments and sub-issue tree, as one
+    /// markdown document for the thoughts workspace.
+    pub async fn export_issue(
+        &self,
+        issue: String,
+        include_children: bool,
+        include_comments: bool,
+    ) -> Result<models::ExportIssueResult> {
+        let root = self.read_issue(issue).await?;
+        let client = self.client()?;
+
+        let mut exported: Vec<models::ExportedIssue> = Vec::new();
+        let mut truncated = false;
+        let mut stack = vec![(0, None, root.issue, root.description)];
+        while let Some((depth, parent_identifier, issue, description)) = stack.pop() {
+            if exported.len() == EXPORT_MAX_ISSUES {
+                truncated = true;
+                break;
+            }
+            let comments = if include_comments {
+                Self::fetch_all_comments(&client, &issue.id).await?.1
+            } else {
+                Vec::new()
+            };
+            if include_children {
+                let (children, more) = Self::fetch_children(&client, &issue.id).await?;
+                truncated |= more;
+                // Reversed so the stack pops children in creation order.
+                for child in children.into_iter().rev() {
+                    stack.push((depth + 1, Some(issue.identifier.clone()), child.0, child.1));
+                }
+            }
+            exported.push(models::ExportedIssue {
+                depth,
+                parent_identifier,
+                issue,
+                description,
+                comments,
+            });
+        }
+
+        let markdown = models::render_issue_export(&exported, truncated);
+        Ok(models::ExportIssueResult {
+            filename: format!("linear-{}.md", exported[0].issue.identifier.to_lowercase()),
+            markdown,
+            issue_count: exported.len(),
+            comment_count: exported.iter().map(|e| e.comments.len()).sum(),
+            truncated,
+        })
+    }
+
+    /// Direct sub-issues of `issue_id` (oldest first) with their descriptions, and
+    /// whether more exist than one page holds.
+    async fn fetch_children(
+        client: &LinearClient,
+        issue_id: &str,
+    ) -> Result<(Vec<(models::IssueSummary, Option<String>)>, bool)> {
+        let op = IssuesQuery::build(IssuesArguments {
+            first: Some(100),
+            after: None,
+            filter: Some(IssueFilter {
+                parent: Some(NullableIssueFilter {
+                    id: Some(IssueIdComparator {
+                        eq: Some(cynic::Id::new(issue_id)),
+                    }),
+                }),
+                ..Default::default()
+            }),
+        });
+        let data = http::extract_data(client.run(op).await?)?;
+        let mut children: Vec<(models::IssueSummary, Option<String>)> = data
+            .issues
+            .nodes
+            .into_iter()
+            .map(|i| {
+                let description = i.description.clone();
+                (i.into(), description)
+            })
+            .collect();
+        children.sort_by(|a, b| a.0.created_at.cmp(&b.0.created_at));
+        Ok((children, data.issues.page_info.has_next_page))
+    }
+
+    /// Search issues previously fetched by `search_issues`/`read_issue` in the local
+    /// full-text index. Never calls the API directly; stale hits are re-read in the
+    /// background when `refresh` is set.
+    pub async fn search_local(
+        &self,
+        query: String,
+        limit: Option<usize>,
+        refresh: bool,
+    ) -> Result<models::LocalSearchResult> {
+        let index = self.index.clone().ok_or_else(|| {
+            anyhow::anyhow!(
+                "invalid: local issue index is disabled; set services.linear.index.enabled = true"
+            )
+        })?;
+        let limit = limit
+            .unwrap_or(LOCAL_SEARCH_DEFAULT_LIMIT)
+            .clamp(1, LOCAL_SEARCH_MAX_LIMIT);
+
+        let lookup = Arc::clone(&index);
+        let (issues, indexed) = tokio::task::spawn_blocking(move || {
+            Ok::<_, anyhow::Error>((lookup.search(&query, limit)?, lookup.len()?))
+        })
+        .await
+        .context("internal: local index query panicked")??;
+
+        let mut refreshing = Vec::new();
+        if refresh && self.api_key.is_some() {
+            for hit in issues.iter().filter(|h| h.stale) {
+                if !index.begin_refresh(&hit.issue.id) {
+                    continue;
+                }
+                refreshing.push(hit.issue.identifier.clone());
+                let linear = self.clone();
+                let index = Arc::clone(&index);
+                let id = hit.issue.id.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = linear.read_issue(id.clone()).await {
+                        tracing::debug!("background refresh of {id} failed: {e:#}");
+                    }
+                    index.end_refresh(&id);
+                });
+            }
+        }
+
+        Ok(models::LocalSearchResult {
+            issues,
+            indexed,
+            refreshing,
         })
     }
 
@@ -484,7 +975,7 @@ impl LinearTools {
     #[expect(clippy::too_many_arguments)]
     pub async fn create_issue(
         &self,
-        team_id: String,
+        team_id: Option<String>,
         title: String,
         description: Option<String>,
         priority: Option<i32>,
@@ -494,7 +985,17 @@ impl LinearTools {
         parent_id: Option<String>,
         label_ids: Vec<String>,
     ) -> Result<models::CreateIssueResult> {
+        let team_id = self.team_or_default(team_id)?;
         let client = self.client()?;
+        let team_id = self.resolve_team_id(&client, team_id).await?;
+        let project_id = match project_id.or_else(|| self.config.default_project.clone()) {
+            Some(p) => Some(self.resolve_project_id(&client, p).await?),
+            None => None,
+        };
+        let assignee_id = match assignee_id {
+            Some(a) => Some(self.resolve_user_id(&client, a).await?),
+            None => None,
+        };
 
         // Convert empty Vec to None for the API
         let label_ids_opt = if label_ids.is_empty() {
@@ -547,6 +1048,10 @@ impl LinearTools {
     ) -> Result<models::IssueResult> {
         let client = self.client()?;
         let id = self.resolve_to_issue_id(&client, &issue).await?;
+        let assignee_id = match assignee_id {
+            Some(a) => Some(self.resolve_user_id(&client, a).await?),
+            None => None,
+        };
 
         let input = IssueUpdateInput {
             title,
@@ -617,6 +1122,103 @@ impl LinearTools {
         })
     }
 
+    /// List the attachments (linked URLs) on an issue
+    pub async fn list_attachments(&self, issue: String) -> Result<models::AttachmentsResult> {
+        let client = self.client()?;
+        let issue_id = self.resolve_to_issue_id(&client, &issue).await?;
+        let (issue_identifier, attachments) =
+            Self::fetch_all_attachments(&client, &issue_id).await?;
+        Ok(models::AttachmentsResult {
+            issue_identifier,
+            attachments,
+        })
+    }
+
+    /// Attach a URL to an issue unless an attachment with the same URL exists
+    pub async fn add_attachment(
+        &self,
+        issue: String,
+        url: String,
+        title: String,
+        subtitle: Option<String>,
+    ) -> Result<models::AddAttachmentResult> {
+        let url = url.trim().to_string();
+        let parsed = url::Url::parse(&url)
+            .map_err(|e| anyhow::anyhow!("Invalid attachment URL '{url}': {e}"))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            anyhow::bail!("Invalid attachment URL '{url}': only http and https URLs are supported");
+        }
+        let title = match title.trim() {
+            "" => url.clone(),
+            t => t.to_string(),
+        };
+
+        let client = self.client()?;
+        let issue_id = self.resolve_to_issue_id(&client, &issue).await?;
+
+        let wanted = normalize_attachment_url(&url);
+        let (_, existing) = Self::fetch_all_attachments(&client, &issue_id).await?;
+        if let Some(attachment) = existing
+            .into_iter()
+            .find(|a| normalize_attachment_url(&a.url) == wanted)
+        {
+            return Ok(models::AddAttachmentResult {
+                success: true,
+                action: "already_attached".to_string(),
+                attachment,
+            });
+        }
+
+        let input = AttachmentCreateInput {
+            issue_id,
+            title,
+            url,
+            subtitle: subtitle.filter(|s| !s.trim().is_empty()),
+        };
+        let op = AttachmentCreateMutation::build(AttachmentCreateArguments { input });
+        let resp = client.run(op).await?;
+        let data = http::extract_data(resp)?;
+        let payload = data.attachment_create;
+
+        Ok(models::AddAttachmentResult {
+            success: payload.success,
+            action: "created".to_string(),
+            attachment: attachment_summary(payload.attachment),
+        })
+    }
+
+    async fn fetch_all_attachments(
+        client: &LinearClient,
+        issue_id: &str,
+    ) -> Result<(String, Vec<models::AttachmentSummary>)> {
+        let mut cursor: Option<String> = None;
+        let mut attachments = Vec::new();
+
+        for _ in 0..ATTACHMENTS_MAX_PAGES {
+            let op = IssueAttachmentsQuery::build(IssueAttachmentsArguments {
+                id: issue_id.to_string(),
+                first: Some(ATTACHMENTS_FETCH_PAGE_SIZE),
+                after: cursor.take(),
+            });
+            let data = http::extract_data(client.run(op).await?)?;
+            let issue = data
+                .issue
+                .ok_or_else(|| anyhow::anyhow!("Issue not found: {issue_id}"))?;
+
+            attachments.extend(issue.attachments.nodes.into_iter().map(attachment_summary));
+
+            let page_info = issue.attachments.page_info;
+            if !page_info.has_next_page || page_info.end_cursor.is_none() {
+                return Ok((issue.identifier, attachments));
+            }
+            cursor = page_info.end_cursor;
+        }
+
+        Err(anyhow::anyhow!(
+            "Issue attachments pagination for {issue_id} exceeded {ATTACHMENTS_MAX_PAGES} pages"
+        ))
+    }
+
     /// Archive a Linear issue
     pub async fn archive_issue(&self, issue: String) -> Result<models::ArchiveIssueResult> {
         let client = self.client()?;
@@ -870,6 +1472,37 @@ impl LinearTools {
         }
     }
 
+    /// List a team's cycles, current and upcoming first unless `include_past`
+    pub async fn get_cycles(
+        &self,
+        team_id: Option<String>,
+        include_past: bool,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<models::CyclesResult> {
+        let team = self.team_or_default(team_id)?;
+        let client = self.client()?;
+        let filter = CycleFilter {
+            team: Some(team_filter(team)),
+            is_past: (!include_past).then_some(BooleanComparator { eq: Some(false) }),
+        };
+        let op = CyclesQuery::build(CyclesArguments {
+            first: Some(first.unwrap_or(50).clamp(1, 100)),
+            after,
+            filter: Some(filter),
+        });
+        let data = http::extract_data(client.run(op).await?)?;
+
+        let mut cycles: Vec<models::CycleSummary> =
+            data.cycles.nodes.into_iter().map(Into::into).collect();
+        cycles.sort_by_key(|c| c.number);
+        Ok(models::CyclesResult {
+            cycles,
+            has_next_page: data.cycles.page_info.has_next_page,
+            end_cursor: data.cycles.page_info.end_cursor,
+        })
+    }
+
     /// Set or remove a relation between two issues
     pub async fn set_relation(
         &self,
@@ -883,38 +1516,10 @@ impl LinearTools {
 
         if let Some(rel_type) = relation_type {
             // Create relation
-            let relation_type = match rel_type.to_lowercase().as_str() {
-                "blocks" => IssueRelationType::Blocks,
-                "duplicate" => IssueRelationType::Duplicate,
-                "related" => IssueRelationType::Related,
-                other => anyhow::bail!(
-                    "Invalid relation type: {other}. Must be one of: blocks, duplicate, related"
-                ),
-            };
-
-            let input = IssueRelationCreateInput {
-                issue_id,
-                related_issue_id,
-                relation_type,
-            };
-
-            let op = IssueRelationCreateMutation::build(IssueRelationCreateArguments { input });
-            let resp = client.run(op).await?;
-            let data = http::extract_data(resp)?;
-
-            Ok(models::SetRelationResult {
-                success: data.issue_relation_create.success,
-                action: "created".to_string(),
-            })
+            Self::create_relation(&client, issue_id, related_issue_id, &rel_type).await
         } else {
             // Remove relation - need to find it first
-            let op = IssueRelationsQuery::build(IssueRelationsArguments { id: issue_id });
-            let resp = client.run(op).await?;
-            let data = http::extract_data(resp)?;
-
-            let issue_with_relations = data
-                .issue
-                .ok_or_else(|| anyhow::anyhow!("not found: Issue not found"))?;
+            let issue_with_relations = Self::fetch_relations(&client, issue_id).await?;
 
             // Search in both relations and inverse_relations
             let relation_id = issue_with_relations
@@ -928,7 +1533,7 @@ impl LinearTools {
                         .inverse_relations
                         .nodes
                         .iter()
-                        .find(|r| r.related_issue.id.inner() == related_issue_id)
+                        .find(|r| r.issue.id.inner() == related_issue_id)
                         .map(|r| r.id.inner().to_string())
                 });
 
@@ -955,15 +1560,105 @@ impl LinearTools {
         }
     }
 
-    /// Get comments on a Linear issue with implicit pagination
-    pub async fn get_issue_comments(&self, issue: String) -> Result<models::CommentsResult> {
+    /// Add a relation between two issues unless an equivalent one already exists.
+    /// `blocked_by` is stored as `related_issue` blocking `issue`.
+    pub async fn add_relation(
+        &self,
+        issue: String,
+        related_issue: String,
+        relation_type: String,
+    ) -> Result<models::SetRelationResult> {
+        let client = self.client()?;
+        let issue_id = self.resolve_to_issue_id(&client, &issue).await?;
+        let related_issue_id = self.resolve_to_issue_id(&client, &related_issue).await?;
+        Self::create_relation(&client, issue_id, related_issue_id, &relation_type).await
+    }
+
+    async fn create_relation(
+        client: &LinearClient,
+        issue_id: String,
+        related_issue_id: String,
+        relation_type: &str,
+    ) -> Result<models::SetRelationResult> {
+        let (relation_type, issue_id, related_issue_id) = match relation_type
+            .to_lowercase()
+            .as_str()
+        {
+            "blocks" => (IssueRelationType::Blocks, issue_id, related_issue_id),
+            "blocked_by" => (IssueRelationType::Blocks, related_issue_id, issue_id),
+            "duplicate" => (IssueRelationType::Duplicate, issue_id, related_issue_id),
+            "related" => (IssueRelationType::Related, issue_id, related_issue_id),
+            other => anyhow::bail!(
+                "Invalid relation type: {other}. Must be one of: blocks, blocked_by, duplicate, related"
+            ),
+        };
+        if issue_id == related_issue_id {
+            anyhow::bail!("Invalid relation: an issue cannot be related to itself");
+        }
+
+        // Linear accepts duplicate relations; skip creating one that already exists.
+        let existing = Self::fetch_relations(client, issue_id.clone()).await?;
+        let type_name = relation_type_name(relation_type);
+        let symmetric = matches!(relation_type, IssueRelationType::Related);
+        let exists =
+            existing.relations.nodes.iter().any(|r| {
+                r.relation_type == type_name && r.related_issue.id.inner() == related_issue_id
+            }) || (symmetric
+                && existing.inverse_relations.nodes.iter().any(|r| {
+                    r.relation_type == type_name && r.issue.id.inner() == related_issue_id
+                }));
+        if exists {
+            return Ok(models::SetRelationResult {
+                success: true,
+                action: "already_exists".to_string(),
+            });
+        }
+
+        let input = IssueRelationCreateInput {
+            issue_id,
+            related_issue_id,
+            relation_type,
+        };
+        let op = IssueRelationCreateMutation::build(IssueRelationCreateArguments { input });
+        let data = http::extract_data(client.run(op).await?)?;
+
+        Ok(models::SetRelationResult {
+            success: data.issue_relation_create.success,
+            action: "created".to_string(),
+        })
+    }
+
+    async fn fetch_relations(
+        client: &LinearClient,
+        issue_id: String,
+    ) -> Result<linear_queries::IssueWithRelations> {
+        let op = IssueRelationsQuery::build(IssueRelationsArguments { id: issue_id });
+        let data = http::extract_data(client.run(op).await?)?;
+        data.issue
+            .ok_or_else(|| anyhow::anyhow!("not found: Issue not found"))
+    }
+
+    /// Get threaded comments on a Linear issue. Without `first`/`after`, pages
+    /// implicitly through a cached copy; with either, returns one upstream page
+    /// and its `end_cursor`.
+    pub async fn get_issue_comments(
+        &self,
+        issue: String,
+        first: Option<i32>,
+        after: Option<String>,
+        ctx: &ToolContext,
+    ) -> Result<models::CommentsResult> {
         let client = self.client()?;
 
         // Resolve issue identifier to UUID
         let issue_id = self.resolve_to_issue_id(&client, &issue).await?;
 
-        // Cache key includes page size for correctness
-        let cache_key = format!("{issue_id}|{COMMENTS_PAGE_SIZE}");
+        if first.is_some() || after.is_some() {
+            return Self::fetch_comments_page(&client, &issue_id, first, after).await;
+        }
+
+        // Cache key includes page size for correctness and is scoped to the calling session
+        let cache_key = ctx.session_key(&format!("{issue_id}|{COMMENTS_PAGE_SIZE}"));
 
         // Sweep expired entries
         self.comments_cache.sweep_expired();
@@ -1018,6 +1713,45 @@ impl LinearTools {
             shown_comments: shown,
             total_comments: total,
             has_more,
+            end_cursor: None,
+        })
+    }
+
+    async fn fetch_comments_page(
+        client: &LinearClient,
+        issue_id: &str,
+        first: Option<i32>,
+        after: Option<String>,
+    ) -> Result<models::CommentsResult> {
+        let first = first
+            .unwrap_or(ISSUE_COMMENTS_FETCH_PAGE_SIZE)
+            .clamp(1, ISSUE_COMMENTS_FETCH_MAX_FIRST);
+        let op = IssueCommentsQuery::build(IssueCommentsArguments {
+            id: issue_id.to_string(),
+            first: Some(first),
+            after,
+        });
+        let data = http::extract_data(client.run(op).await?)?;
+        let issue = data
+            .issue
+            .ok_or_else(|| anyhow::anyhow!("Issue not found: {issue_id}"))?;
+
+        let comments = models::thread_comments(
+            issue
+                .comments
+                .nodes
+                .into_iter()
+                .map(comment_summary)
+                .collect(),
+        );
+        let count = comments.len();
+        Ok(models::CommentsResult {
+            issue_identifier: issue.identifier,
+            comments,
+            shown_comments: count,
+            total_comments: count,
+            has_more: issue.comments.page_info.has_next_page,
+            end_cursor: issue.comments.page_info.end_cursor,
         })
     }
 
@@ -1047,26 +1781,13 @@ impl LinearTools {
                 identifier = Some(issue.identifier.clone());
             }
 
-            all_comments.extend(
-                issue
-                    .comments
-                    .nodes
-                    .into_iter()
-                    .map(|c| models::CommentSummary {
-                        id: c.id.inner().to_string(),
-                        body: c.body,
-                        url: c.url,
-                        created_at: c.created_at.0,
-                        updated_at: c.updated_at.0,
-                        parent_id: c.parent_id,
-                        author_name: c.user.as_ref().map(|u| u.name.clone()),
-                        author_email: c.user.as_ref().map(|u| u.email.clone()),
-                    }),
-            );
+            all_comments.extend(issue.comments.nodes.into_iter().map(comment_summary));
 
             if !issue.comments.page_info.has_next_page {
-                all_comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
-                return Ok((identifier.unwrap_or_default(), all_comments));
+                return Ok((
+                    identifier.unwrap_or_default(),
+                    models::thread_comments(all_comments),
+                ));
             }
 
             cursor.clone_from(&issue.comments.page_info.end_cursor);
@@ -1091,8 +1812,30 @@ impl LinearTools {
 
 #[cfg(test)]
 mod tests {
+    use super::normalize_attachment_url;
     use super::parse_identifier;
 
+    #[test]
+    fn normalize_attachment_url_ignores_cosmetic_differences() {
+        let canonical = normalize_attachment_url("https://github.com/org/repo/pull/12");
+        for variant in [
+            "https://GitHub.com/org/repo/pull/12/",
+            "https://github.com:443/org/repo/pull/12#discussion_r1",
+            "  https://github.com/org/repo/pull/12  ",
+        ] {
+            assert_eq!(normalize_attachment_url(variant), canonical, "{variant}");
+        }
+        assert_ne!(
+            normalize_attachment_url("https://github.com/org/repo/pull/13"),
+            canonical
+        );
+        assert_ne!(
+            normalize_attachment_url("https://example.com/doc?v=1"),
+            normalize_attachment_url("https://example.com/doc?v=2")
+        );
+        assert_eq!(normalize_attachment_url("not a url"), "not a url");
+    }
+
     #[test]
     fn parse_plain_uppercase() {
         assert_eq!(parse_identifier("ENG-245"), Some(("ENG".into(), 245)));
@@ -79,6 +79,50 @@ pub struct SearchResult {
     pub issues: Vec<IssueSummary>,
     pub has_next_page: bool,
     pub end_cursor: Option<String>,
+    /// Most constrained Linear API quota, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitStatus>,
+}
+
+/// Linear API quota reported by the most recent response.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Quota bucket (`requests` or `complexity`)
+    pub resource: String,
+    pub limit: u64,
+    pub remaining: u64,
+    /// Unix timestamp (seconds) when the quota resets
+    pub reset_at: u64,
+}
+
+impl RateLimitStatus {
+    /// True when less than a tenth of the quota is left.
+    pub const fn is_low(&self) -> bool {
+        self.remaining.saturating_mul(10) < self.limit
+    }
+
+    /// One-line summary such as `Linear API quota (requests): 120/1500 remaining, resets in 14m`.
+    pub fn summary(&self, now_unix: u64) -> String {
+        let mins = self.reset_at.saturating_sub(now_unix).div_ceil(60);
+        format!(
+            "Linear API quota ({resource}): {remaining}/{limit} remaining, resets in {mins}m",
+            resource = self.resource,
+            remaining = self.remaining,
+            limit = self.limit
+        )
+    }
+}
+
+/// Relation to another issue, described from the viewed issue's side.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueRelationRef {
+    /// Relation ID
+    pub id: String,
+    /// `"blocks"`, `"blocked_by"`, `"duplicate_of"`, `"duplicated_by"`, `"related"`, or `"similar"`
+    pub relation: String,
+    pub issue_id: String,
+    pub identifier: String,
+    pub title: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -91,6 +135,8 @@ pub struct IssueDetails {
     pub started_at: Option<String>,
     pub completed_at: Option<String>,
     pub canceled_at: Option<String>,
+    #[serde(default)]
+    pub relations: Vec<IssueRelationRef>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -131,10 +177,42 @@ pub struct CommentSummary {
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CommentsResult {
     pub issue_identifier: String,
+    /// Comments in thread order: each top-level comment followed by its replies
     pub comments: Vec<CommentSummary>,
     pub shown_comments: usize,
     pub total_comments: usize,
     pub has_more: bool,
+    /// Cursor for the next page when paging explicitly with `first`/`after`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_cursor: Option<String>,
+}
+
+/// Order comments as threads: top-level comments (and replies whose parent is not
+/// present) by creation time, each followed by its replies, recursively.
+pub fn thread_comments(mut comments: Vec<CommentSummary>) -> Vec<CommentSummary> {
+    comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    let ids: std::collections::HashSet<String> = comments.iter().map(|c| c.id.clone()).collect();
+    let mut children: std::collections::HashMap<String, Vec<CommentSummary>> =
+        std::collections::HashMap::new();
+    let mut roots = Vec::new();
+    for c in comments {
+        match c.parent_id.clone() {
+            Some(parent) if parent != c.id && ids.contains(&parent) => {
+                children.entry(parent).or_default().push(c);
+            }
+            _ => roots.push(c),
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(ids.len());
+    let mut stack: Vec<CommentSummary> = roots.into_iter().rev().collect();
+    while let Some(c) = stack.pop() {
+        if let Some(replies) = children.remove(&c.id) {
+            stack.extend(replies.into_iter().rev());
+        }
+        ordered.push(c);
+    }
+    ordered
 }
 
 // ============================================================================
@@ -235,6 +313,13 @@ impl TextFormat for SearchResult {
         {
             let _ = writeln!(out, "\n[More results available, cursor: {cursor}]");
         }
+        if let Some(status) = self.rate_limit.as_ref().filter(|s| s.is_low()) {
+            let _ = writeln!(
+                out,
+                "Warning: {}",
+                status.summary(crate::rate_limit::now_unix())
+            );
+        }
         out
     }
 }
@@ -278,6 +363,22 @@ impl TextFormat for IssueDetails {
             let _ = writeln!(out, "Created: {} | Updated: {}", i.created_at, i.updated_at);
         }
 
+        if let Some(parent) = &self.parent {
+            let _ = writeln!(out, "Parent: {}", parent.identifier);
+        }
+        if !self.relations.is_empty() {
+            let _ = writeln!(out, "Relations:");
+            for r in &self.relations {
+                let _ = writeln!(
+                    out,
+                    "  {} {} - {}",
+                    r.relation.replace('_', " "),
+                    r.identifier,
+                    r.title
+                );
+            }
+        }
+
         // Description
         if let Some(description) = self.description.as_ref().filter(|d| !d.trim().is_empty()) {
             let _ = writeln!(out, "\n{description}");
@@ -338,12 +439,21 @@ impl TextFormat for CommentsResult {
         }
 
         let mut out = String::new();
-        let start = self.shown_comments.saturating_sub(self.comments.len()) + 1;
-        let _ = writeln!(
-            out,
-            "Comments for {} (showing {}-{} of {}):",
-            self.issue_identifier, start, self.shown_comments, self.total_comments
-        );
+        if self.end_cursor.is_some() {
+            let _ = writeln!(
+                out,
+                "Comments for {} ({} on this page):",
+                self.issue_identifier,
+                self.comments.len()
+            );
+        } else {
+            let start = self.shown_comments.saturating_sub(self.comments.len()) + 1;
+            let _ = writeln!(
+                out,
+                "Comments for {} (showing {}-{} of {}):",
+                self.issue_identifier, start, self.shown_comments, self.total_comments
+            );
+        }
 
         for c in &self.comments {
             let author = c.author_name.as_deref().unwrap_or("Unknown");
@@ -368,7 +478,13 @@ impl TextFormat for CommentsResult {
             let _ = writeln!(out);
         }
 
-        if self.has_more {
+        if let Some(cursor) = &self.end_cursor {
+            if self.has_more {
+                let _ = writeln!(out, "(more comments: after={cursor})");
+            } else {
+                let _ = writeln!(out, "(no more comments)");
+            }
+        } else if self.has_more {
             let _ = writeln!(
                 out,
                 "(more comments available - call linear_get_issue_comments again)"
@@ -390,11 +506,11 @@ pub struct ArchiveIssueResult {
     pub success: bool,
 }
 
-/// Result of a `set_relation` operation
+/// Result of a `set_relation` or `add_relation` operation
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SetRelationResult {
     pub success: bool,
-    /// Action taken: `"created"`, `"removed"`, or `"no_change"`
+    /// Action taken: `"created"`, `"already_exists"`, `"removed"`, or `"no_change"`
     pub action: String,
 }
 
@@ -412,6 +528,7 @@ impl TextFormat for SetRelationResult {
     fn fmt_text(&self, _opts: &TextOptions) -> String {
         match (self.success, self.action.as_str()) {
             (true, "created") => "Relation created successfully".into(),
+            (true, "already_exists") => "Relation already exists".into(),
             (true, "removed") => "Relation removed successfully".into(),
             (true, "no_change") => "No relation change needed".into(),
             (false, _) => "Failed to modify relation".into(),
@@ -420,6 +537,262 @@ impl TextFormat for SetRelationResult {
     }
 }
 
+// ============================================================================
+// Attachment models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AttachmentSummary {
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub url: String,
+    /// Integration that created the attachment (e.g. github, slack), if any
+    pub source_type: Option<String>,
+    pub created_at: String,
+    pub creator_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AttachmentsResult {
+    pub issue_identifier: String,
+    pub attachments: Vec<AttachmentSummary>,
+}
+
+/// Result of an `add_attachment` operation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AddAttachmentResult {
+    pub success: bool,
+    /// Action taken: `"created"` or `"already_attached"` (URL matched an existing attachment)
+    pub action: String,
+    pub attachment: AttachmentSummary,
+}
+
+impl TextFormat for AttachmentsResult {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        if self.attachments.is_empty() {
+            return format!("No attachments on {}", self.issue_identifier);
+        }
+        let mut out = format!(
+            "Attachments on {} ({}):\n",
+            self.issue_identifier,
+            self.attachments.len()
+        );
+        for a in &self.attachments {
+            let _ = write!(out, "  {} - {}", a.title, a.url);
+            if let Some(source) = &a.source_type {
+                let _ = write!(out, " [{source}]");
+            }
+            let _ = writeln!(out);
+            if let Some(subtitle) = a.subtitle.as_deref().filter(|s| !s.is_empty()) {
+                let _ = writeln!(out, "    {subtitle}");
+            }
+        }
+        out
+    }
+}
+
+impl TextFormat for AddAttachmentResult {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let a = &self.attachment;
+        match (self.success, self.action.as_str()) {
+            (false, _) => "Failed to add attachment".into(),
+            (true, "already_attached") => {
+                format!("Already attached ({}): {} - {}", a.id, a.title, a.url)
+            }
+            _ => format!("Attachment added ({}): {} - {}", a.id, a.title, a.url),
+        }
+    }
+}
+
+// ============================================================================
+// Cycle models
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CycleSummary {
+    pub id: String,
+    pub number: i32,
+    pub name: Option<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+    /// One of `"active"`, `"next"`, `"upcoming"`, `"previous"`, or `"past"`
+    pub status: String,
+    /// Completed share of the cycle's scope (0.0-1.0)
+    pub progress: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CyclesResult {
+    pub cycles: Vec<CycleSummary>,
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
+impl TextFormat for CyclesResult {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        if self.cycles.is_empty() {
+            return "Cycles: <none>".into();
+        }
+        let mut out = String::from("Cycles:\n");
+        for c in &self.cycles {
+            let _ = write!(out, "  #{}", c.number);
+            if let Some(name) = c.name.as_deref().filter(|n| !n.is_empty()) {
+                let _ = write!(out, " {name}");
+            }
+            let _ = writeln!(
+                out,
+                " [{}] {} -> {} ({:.0}% done) ({})",
+                c.status,
+                date_part(&c.starts_at),
+                date_part(&c.ends_at),
+                c.progress * 100.0,
+                c.id
+            );
+        }
+        if self.has_next_page
+            && let Some(ref cursor) = self.end_cursor
+        {
+            let _ = writeln!(out, "  (more results: after={cursor})");
+        }
+        out
+    }
+}
+
+// ============================================================================
+// Export models
+// ============================================================================
+
+/// One issue of an exported tree; trees are flattened in depth-first order.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportedIssue {
+    /// 0 for the exported issue, 1 for its sub-issues, and so on
+    pub depth: usize,
+    pub parent_identifier: Option<String>,
+    pub issue: IssueSummary,
+    pub description: Option<String>,
+    /// Comments in thread order
+    pub comments: Vec<CommentSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportIssueResult {
+    /// Suggested thoughts document filename, e.g. `linear-eng-245.md`
+    pub filename: String,
+    pub markdown: String,
+    pub issue_count: usize,
+    pub comment_count: usize,
+    /// Some sub-issues were left out because the tree exceeded the export limit
+    pub truncated: bool,
+}
+
+impl TextFormat for ExportIssueResult {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        format!(
+            "Suggested filename: {} ({} issues, {} comments{})\n\n{}",
+            self.filename,
+            self.issue_count,
+            self.comment_count,
+            if self.truncated { ", truncated" } else { "" },
+            self.markdown
+        )
+    }
+}
+
+/// Render an exported tree as one markdown document: the root issue under an H1,
+/// an outline of its sub-issues, then one H2 section per sub-issue.
+pub fn render_issue_export(issues: &[ExportedIssue], truncated: bool) -> String {
+    let mut out = String::new();
+    let Some(root) = issues.first() else {
+        return out;
+    };
+    render_exported_issue(&mut out, root, 1);
+
+    let children = &issues[1..];
+    if !children.is_empty() || truncated {
+        let _ = writeln!(out, "## Sub-issues\n");
+        for c in children {
+            let _ = writeln!(
+                out,
+                "{}- {}: {}{}",
+                "  ".repeat(c.depth.saturating_sub(1)),
+                c.issue.identifier,
+                c.issue.title,
+                c.issue
+                    .state
+                    .as_ref()
+                    .map(|s| format!(" [{}]", s.name))
+                    .unwrap_or_default()
+            );
+        }
+        if truncated {
+            let _ = writeln!(
+                out,
+                "\n_Some sub-issues were omitted (export limit reached)._"
+            );
+        }
+        out.push('\n');
+    }
+    for c in children {
+        render_exported_issue(&mut out, c, 2);
+    }
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+fn render_exported_issue(out: &mut String, e: &ExportedIssue, level: usize) {
+    let i = &e.issue;
+    let h = "#".repeat(level);
+    let _ = writeln!(out, "{h} {}: {}\n", i.identifier, i.title);
+    if let Some(parent) = &e.parent_identifier {
+        let _ = writeln!(out, "- Parent: {parent}");
+    }
+    if let Some(s) = &i.state {
+        let _ = writeln!(out, "- Status: {}", s.name);
+    }
+    let _ = writeln!(out, "- Priority: P{} ({})", i.priority, i.priority_label);
+    if let Some(u) = &i.assignee {
+        let _ = writeln!(out, "- Assignee: {}", u.name);
+    }
+    let _ = writeln!(out, "- Team: {}", i.team.key);
+    if let Some(p) = &i.project {
+        let _ = writeln!(out, "- Project: {}", p.name);
+    }
+    let _ = writeln!(
+        out,
+        "- Created: {} | Updated: {}",
+        date_part(&i.created_at),
+        date_part(&i.updated_at)
+    );
+    let _ = writeln!(out, "- URL: {}\n", i.url);
+
+    if let Some(description) = e.description.as_deref().filter(|d| !d.trim().is_empty()) {
+        let _ = writeln!(out, "{h}# Description\n\n{}\n", description.trim_end());
+    }
+    if !e.comments.is_empty() {
+        let _ = writeln!(out, "{h}# Comments\n");
+        for c in &e.comments {
+            let author = c.author_name.as_deref().unwrap_or("Unknown");
+            let date = date_part(&c.created_at);
+            if c.parent_id.is_some() {
+                let _ = writeln!(out, "> **{author}** ({date}, reply):\n>");
+                for line in c.body.trim_end().lines() {
+                    let _ = writeln!(out, ">{}{line}", if line.is_empty() { "" } else { " " });
+                }
+                out.push('\n');
+            } else {
+                let _ = writeln!(out, "**{author}** ({date}):\n\n{}\n", c.body.trim_end());
+            }
+        }
+    }
+}
+
+/// `YYYY-MM-DD` prefix of an ISO 8601 timestamp.
+fn date_part(ts: &str) -> &str {
+    ts.get(..10).unwrap_or(ts)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MetadataKind {
@@ -481,6 +854,72 @@ impl TextFormat for GetMetadataResult {
     }
 }
 
+// ============================================================================
+// Local index models
+// ============================================================================
+
+/// An issue served from the local index, with staleness metadata.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IndexedIssue {
+    pub issue: IssueSummary,
+    /// Matching description excerpt, with hits in `[brackets]`.
+    pub snippet: Option<String>,
+    /// When the issue was last fetched from Linear (RFC 3339).
+    pub fetched_at: String,
+    pub age_secs: u64,
+    /// Older than `services.linear.index.stale_after_secs`.
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LocalSearchResult {
+    pub issues: Vec<IndexedIssue>,
+    /// Total issues in the local index.
+    pub indexed: usize,
+    /// Identifiers of stale hits being re-fetched in the background.
+    pub refreshing: Vec<String>,
+}
+
+fn fmt_age(secs: u64) -> String {
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m", secs / 60),
+        3600..86400 => format!("{}h", secs / 3600),
+        _ => format!("{}d", secs / 86400),
+    }
+}
+
+impl TextFormat for LocalSearchResult {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        if self.issues.is_empty() {
+            return format!("Local index: no matches ({} issues indexed)", self.indexed);
+        }
+        let mut out = String::new();
+        let _ = writeln!(out, "Local index ({} issues indexed):", self.indexed);
+        for hit in &self.issues {
+            let i = &hit.issue;
+            let mut line = format!("{} - {}", i.identifier, i.title);
+            if let Some(s) = &i.state {
+                let _ = write!(line, " [{}]", s.name);
+            }
+            let _ = write!(line, " (fetched {} ago", fmt_age(hit.age_secs));
+            line.push_str(if hit.stale { ", stale)" } else { ")" });
+            let _ = writeln!(out, "  {line}");
+            if let Some(snippet) = &hit.snippet {
+                let _ = writeln!(out, "      {}", truncate_chars(snippet, 200));
+            }
+        }
+        if !self.refreshing.is_empty() {
+            let _ = writeln!(
+                out,
+                "\n[Refreshing in background: {}]",
+                self.refreshing.join(", ")
+            );
+        }
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -596,6 +1035,7 @@ mod tests {
             shown_comments: 0,
             total_comments: 0,
             has_more: false,
+            end_cursor: None,
         };
         let text = result.fmt_text(&TextOptions::default());
         assert!(text.contains("No comments on ENG-123"));
@@ -630,6 +1070,7 @@ mod tests {
             shown_comments: 2,
             total_comments: 2,
             has_more: false,
+            end_cursor: None,
         };
         let text = result.fmt_text(&TextOptions::default());
         assert!(text.contains("Alice"));
@@ -654,8 +1095,50 @@ mod tests {
             shown_comments: 10,
             total_comments: 15,
             has_more: true,
+            end_cursor: None,
         };
         let text = result.fmt_text(&TextOptions::default());
         assert!(text.contains("more comments available"));
     }
+
+    fn comment(id: &str, parent: Option<&str>, created_at: &str) -> CommentSummary {
+        CommentSummary {
+            id: id.into(),
+            body: format!("body {id}"),
+            url: String::new(),
+            created_at: created_at.into(),
+            updated_at: created_at.into(),
+            parent_id: parent.map(Into::into),
+            author_name: None,
+            author_email: None,
+        }
+    }
+
+    #[test]
+    fn thread_comments_groups_replies_under_parents() {
+        let ordered = thread_comments(vec![
+            comment("r1", Some("a"), "2024-01-03T00:00:00Z"),
+            comment("b", None, "2024-01-02T00:00:00Z"),
+            comment("a", None, "2024-01-01T00:00:00Z"),
+            comment("r2", Some("r1"), "2024-01-04T00:00:00Z"),
+            comment("orphan", Some("gone"), "2024-01-05T00:00:00Z"),
+        ]);
+        let ids: Vec<&str> = ordered.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "r1", "r2", "b", "orphan"]);
+    }
+
+    #[test]
+    fn comments_result_shows_cursor_when_paging_explicitly() {
+        let result = CommentsResult {
+            issue_identifier: "ENG-123".into(),
+            comments: vec![comment("c1", None, "2024-03-27T10:00:00Z")],
+            shown_comments: 1,
+            total_comments: 1,
+            has_more: true,
+            end_cursor: Some("cursor-9".into()),
+        };
+        let text = result.fmt_text(&TextOptions::default());
+        assert!(text.contains("(1 on this page)"));
+        assert!(text.contains("(more comments: after=cursor-9)"));
+    }
 }
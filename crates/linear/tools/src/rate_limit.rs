@@ -0,0 +1,244 @@
+//! Linear rate-limit tracking and retry/backoff.
+//!
+//! Linear limits both request count and query complexity per hour and reports each
+//! in `X-RateLimit-{Requests,Complexity}-*` headers. Every response updates a shared
+//! [`RateLimitTracker`] so outputs can warn when a quota runs low. Rate-limited
+//! responses (a `RATELIMITED` GraphQL error or HTTP 429) and 5xx responses are
+//! retried after `Retry-After`, the quota reset, or an exponential backoff, as long
+//! as the wait fits the [`RetryPolicy`] budget.
+
+use crate::models::RateLimitStatus;
+use agentic_config::types::LinearServiceConfig;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// First backoff step when the response gives no better hint.
+const BACKOFF_BASE: Duration = Duration::from_secs(1);
+
+/// Quota buckets Linear reports, named as in the `X-RateLimit-<bucket>-*` headers.
+const RESOURCES: [&str; 2] = ["requests", "complexity"];
+
+/// How many failed responses to retry and how long a single wait may be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub max_wait: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from_config(&LinearServiceConfig::default())
+    }
+}
+
+impl RetryPolicy {
+    pub fn from_config(cfg: &LinearServiceConfig) -> Self {
+        Self {
+            max_retries: cfg.rate_limit_max_retries,
+            max_wait: Duration::from_secs(cfg.rate_limit_max_wait_secs),
+        }
+    }
+}
+
+/// Latest quota seen per bucket, shared by every client using one API key.
+#[derive(Debug, Default)]
+pub struct RateLimitTracker {
+    latest: Mutex<HashMap<String, RateLimitStatus>>,
+}
+
+impl RateLimitTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the quotas from a response's headers, warning once a bucket runs low.
+    pub fn record(&self, headers: &HeaderMap) {
+        let mut latest = self
+            .latest
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        for status in parse_statuses(headers) {
+            let was_low = latest
+                .get(&status.resource)
+                .is_some_and(RateLimitStatus::is_low);
+            if status.is_low() && !was_low {
+                tracing::warn!("{}", status.summary(now_unix()));
+            }
+            latest.insert(status.resource.clone(), status);
+        }
+    }
+
+    /// The most constrained bucket seen so far (lowest fraction of quota left).
+    pub fn snapshot(&self) -> Option<RateLimitStatus> {
+        self.latest
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .min_by_key(|s| s.remaining.saturating_mul(1_000_000) / s.limit.max(1))
+            .cloned()
+    }
+}
+
+/// Parse the `X-RateLimit-{Requests,Complexity}-*` headers that are present.
+pub fn parse_statuses(headers: &HeaderMap) -> Vec<RateLimitStatus> {
+    RESOURCES
+        .iter()
+        .filter_map(|resource| {
+            Some(RateLimitStatus {
+                resource: (*resource).to_string(),
+                limit: header_u64(headers, &format!("x-ratelimit-{resource}-limit"))?,
+                remaining: header_u64(headers, &format!("x-ratelimit-{resource}-remaining"))?,
+                // Linear reports resets in epoch milliseconds.
+                reset_at: header_u64(headers, &format!("x-ratelimit-{resource}-reset"))
+                    .map_or(0, |ms| ms / 1000),
+            })
+        })
+        .collect()
+}
+
+/// Whether a response is a rate-limit rejection rather than an ordinary error.
+pub fn is_rate_limited(status: StatusCode, body: &str) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || body.contains("\"RATELIMITED\"")
+}
+
+/// How long to wait before retrying a failed response, or `None` when retrying
+/// cannot help (auth failures, invalid queries, ...).
+pub fn retry_delay(
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: &str,
+    attempt: u32,
+    now_unix: u64,
+) -> Option<Duration> {
+    let backoff = BACKOFF_BASE.saturating_mul(2u32.saturating_pow(attempt));
+    if is_rate_limited(status, body) {
+        if let Some(secs) = header_u64(headers, "retry-after") {
+            return Some(Duration::from_secs(secs));
+        }
+        let exhausted_reset = parse_statuses(headers)
+            .into_iter()
+            .filter(|s| s.remaining == 0 && s.reset_at > 0)
+            .map(|s| s.reset_at)
+            .max();
+        return Some(exhausted_reset.map_or(backoff, |reset| {
+            Duration::from_secs(reset.saturating_sub(now_unix).max(1))
+        }));
+    }
+    status.is_server_error().then_some(backoff)
+}
+
+/// Error for a rate limit we will not (or can no longer) wait out.
+pub fn rate_limited_error(
+    wait: Duration,
+    retries: u32,
+    status: Option<&RateLimitStatus>,
+    now_unix: u64,
+) -> anyhow::Error {
+    let mut msg = format!(
+        "rate limited: Linear API rate limit hit; retry after {secs}s (gave up after {retries} retries)",
+        secs = wait.as_secs()
+    );
+    if let Some(status) = status {
+        msg.push('\n');
+        msg.push_str(&status.summary(now_unix));
+    }
+    anyhow::anyhow!(msg)
+}
+
+pub fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (k, v) in pairs {
+            map.insert(*k, HeaderValue::from_str(v).unwrap());
+        }
+        map
+    }
+
+    const RATELIMITED_BODY: &str =
+        r#"{"errors":[{"message":"Rate limit exceeded","extensions":{"code":"RATELIMITED"}}]}"#;
+
+    #[test]
+    fn retry_delay_prefers_retry_after() {
+        let h = headers(&[("retry-after", "7")]);
+        assert_eq!(
+            retry_delay(StatusCode::BAD_REQUEST, &h, RATELIMITED_BODY, 0, 0),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn retry_delay_waits_for_exhausted_quota_reset() {
+        let h = headers(&[
+            ("x-ratelimit-requests-limit", "1500"),
+            ("x-ratelimit-requests-remaining", "0"),
+            ("x-ratelimit-requests-reset", "1060000"),
+        ]);
+        assert_eq!(
+            retry_delay(StatusCode::BAD_REQUEST, &h, RATELIMITED_BODY, 0, 1000),
+            Some(Duration::from_secs(60))
+        );
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially_for_429_and_5xx() {
+        let h = HeaderMap::new();
+        assert_eq!(
+            retry_delay(StatusCode::TOO_MANY_REQUESTS, &h, "", 2, 0),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(
+            retry_delay(StatusCode::BAD_GATEWAY, &h, "", 1, 0),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn retry_delay_ignores_client_errors() {
+        let h = HeaderMap::new();
+        assert_eq!(retry_delay(StatusCode::UNAUTHORIZED, &h, "", 0, 0), None);
+        let invalid =
+            r#"{"errors":[{"message":"bad","extensions":{"code":"GRAPHQL_VALIDATION_FAILED"}}]}"#;
+        assert_eq!(
+            retry_delay(StatusCode::BAD_REQUEST, &h, invalid, 0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn tracker_snapshot_reports_most_constrained_bucket() {
+        let tracker = RateLimitTracker::new();
+        assert!(tracker.snapshot().is_none());
+
+        tracker.record(&headers(&[
+            ("x-ratelimit-requests-limit", "1500"),
+            ("x-ratelimit-requests-remaining", "1200"),
+            ("x-ratelimit-requests-reset", "100000"),
+            ("x-ratelimit-complexity-limit", "250000"),
+            ("x-ratelimit-complexity-remaining", "9000"),
+            ("x-ratelimit-complexity-reset", "100000"),
+        ]));
+
+        let status = tracker.snapshot().expect("quota recorded");
+        assert_eq!(status.resource, "complexity");
+        assert_eq!(status.remaining, 9000);
+        assert_eq!(status.reset_at, 100);
+        assert!(status.is_low());
+    }
+}
@@ -246,6 +246,48 @@ pub fn issue_comments_response(
     }))
 }
 
+pub fn attachment_node(id: &str, title: &str, url: &str) -> Value {
+    json!({
+        "id": id,
+        "title": title,
+        "subtitle": null,
+        "url": url,
+        "sourceType": null,
+        "createdAt": "2025-01-01T00:00:00Z",
+        "creator": null
+    })
+}
+
+pub fn issue_attachments_response(
+    issue_id: &str,
+    identifier: &str,
+    nodes: &[Value],
+    has_next_page: bool,
+    end_cursor: Option<&str>,
+) -> String {
+    fixture_json(&json!({
+        "data": {
+            "issue": {
+                "id": issue_id,
+                "identifier": identifier,
+                "attachments": {
+                    "nodes": nodes,
+                    "pageInfo": {
+                        "hasNextPage": has_next_page,
+                        "endCursor": end_cursor
+                    }
+                }
+            }
+        }
+    }))
+}
+
+pub fn attachment_create_response(attachment: &Value) -> String {
+    fixture_json(&json!({
+        "data": { "attachmentCreate": { "success": true, "attachment": attachment } }
+    }))
+}
+
 pub fn archive_response(success: bool) -> String {
     fixture_json(&json!({
         "data": { "issueArchive": { "success": success } }
@@ -263,6 +305,12 @@ pub fn users_response(nodes: &[Value], has_next_page: bool, end_cursor: Option<&
     }))
 }
 
+pub fn viewer_response(id: &str, name: &str) -> String {
+    fixture_json(&json!({
+        "data": { "viewer": user_node(id, name, name, &format!("{name}@example.com")) }
+    }))
+}
+
 pub fn teams_response(nodes: &[Value], has_next_page: bool, end_cursor: Option<&str>) -> String {
     fixture_json(&json!({
         "data": {
@@ -285,6 +333,32 @@ pub fn projects_response(nodes: &[Value], has_next_page: bool, end_cursor: Optio
     }))
 }
 
+pub fn cycle_node(id: &str, number: i32, status: &str) -> Value {
+    json!({
+        "id": id,
+        "number": f64::from(number),
+        "name": null,
+        "startsAt": "2025-01-06T00:00:00.000Z",
+        "endsAt": "2025-01-20T00:00:00.000Z",
+        "isActive": status == "active",
+        "isNext": status == "next",
+        "isPrevious": status == "previous",
+        "isPast": status == "previous" || status == "past",
+        "progress": 0.25
+    })
+}
+
+pub fn cycles_response(nodes: &[Value], has_next_page: bool, end_cursor: Option<&str>) -> String {
+    fixture_json(&json!({
+        "data": {
+            "cycles": {
+                "nodes": nodes,
+                "pageInfo": { "hasNextPage": has_next_page, "endCursor": end_cursor }
+            }
+        }
+    }))
+}
+
 pub fn workflow_states_response(
     nodes: &[Value],
     has_next_page: bool,
@@ -323,27 +397,35 @@ pub fn issue_label_node(id: &str, name: &str, team: Option<&Value>) -> Value {
 // Issue relation fixtures
 // ============================================================================
 
+fn relation_issue_node(id: &str) -> Value {
+    json!({ "id": id, "identifier": format!("REL-{id}"), "title": format!("Issue {id}") })
+}
+
 pub fn issue_relations_response(
-    relations: &[(&str, &str)], // (relation_id, related_issue_id)
-    inverse_relations: &[(&str, &str)],
+    relations: &[(&str, &str, &str)], // (relation_id, type, related_issue_id)
+    inverse_relations: &[(&str, &str, &str)], // (relation_id, type, source_issue_id)
 ) -> String {
     fixture_json(&json!({
         "data": {
             "issue": {
                 "id": "source-issue-id",
                 "relations": {
-                    "nodes": relations.iter().map(|(rel_id, related_id)| {
+                    "nodes": relations.iter().map(|(rel_id, rel_type, related_id)| {
                         json!({
                             "id": rel_id,
-                            "relatedIssue": { "id": related_id }
+                            "type": rel_type,
+                            "issue": relation_issue_node("source-issue-id"),
+                            "relatedIssue": relation_issue_node(related_id)
                         })
                     }).collect::<Vec<_>>()
                 },
                 "inverseRelations": {
-                    "nodes": inverse_relations.iter().map(|(rel_id, related_id)| {
+                    "nodes": inverse_relations.iter().map(|(rel_id, rel_type, source_id)| {
                         json!({
                             "id": rel_id,
-                            "relatedIssue": { "id": related_id }
+                            "type": rel_type,
+                            "issue": relation_issue_node(source_id),
+                            "relatedIssue": relation_issue_node("source-issue-id")
                         })
                     }).collect::<Vec<_>>()
                 }
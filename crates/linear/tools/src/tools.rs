@@ -3,14 +3,20 @@
 //! Each tool delegates to the corresponding method on [`LinearTools`].
 
 use crate::LinearTools;
+use crate::models::AddAttachmentResult;
 use crate::models::ArchiveIssueResult;
+use crate::models::AttachmentsResult;
 use crate::models::CommentResult;
 use crate::models::CreateIssueResult;
+use crate::models::CyclesResult;
+use crate::models::ExportIssueResult;
 use crate::models::GetMetadataResult;
 use crate::models::IssueDetails;
 use crate::models::IssueResult;
+use crate::models::LocalSearchResult;
 use crate::models::SearchResult;
 use crate::models::SetRelationResult;
+use agentic_config::types::LinearSearchPreset;
 use agentic_tools_core::Tool;
 use agentic_tools_core::ToolContext;
 use agentic_tools_core::ToolError;
@@ -39,18 +45,24 @@ pub struct SearchIssuesInput {
     /// Workflow state ID (UUID)
     #[serde(default)]
     pub state_id: Option<String>,
-    /// Assignee user ID (UUID)
+    /// Assignee user ID (UUID), or "me" for the API key owner
     #[serde(default)]
     pub assignee_id: Option<String>,
-    /// Creator user ID (UUID)
+    /// Creator user ID (UUID), or "me" for the API key owner
     #[serde(default)]
     pub creator_id: Option<String>,
-    /// Team ID (UUID)
+    /// Team ID (UUID) or key (e.g. ENG)
     #[serde(default)]
     pub team_id: Option<String>,
     /// Project ID (UUID)
     #[serde(default)]
     pub project_id: Option<String>,
+    /// Cycle ID (UUID), or "active" for the team's current cycle
+    #[serde(default)]
+    pub cycle_id: Option<String>,
+    /// Project milestone ID (UUID)
+    #[serde(default)]
+    pub project_milestone_id: Option<String>,
     /// Only issues created after this ISO 8601 date
     #[serde(default)]
     pub created_after: Option<String>,
@@ -69,6 +81,33 @@ pub struct SearchIssuesInput {
     /// Pagination cursor
     #[serde(default)]
     pub after: Option<String>,
+    /// Name of a saved filter set from `services.linear.presets`; explicit parameters override it
+    #[serde(default)]
+    pub preset: Option<String>,
+}
+
+impl SearchIssuesInput {
+    /// Fill parameters the caller left unset from `preset`.
+    fn with_preset(self, preset: &LinearSearchPreset) -> Self {
+        let p = preset.clone();
+        Self {
+            query: self.query.or(p.query),
+            include_comments: self.include_comments.or(p.include_comments),
+            priority: self.priority.or(p.priority),
+            state_id: self.state_id.or(p.state_id),
+            assignee_id: self.assignee_id.or(p.assignee_id),
+            creator_id: self.creator_id.or(p.creator_id),
+            team_id: self.team_id.or(p.team_id),
+            project_id: self.project_id.or(p.project_id),
+            cycle_id: self.cycle_id.or(p.cycle_id),
+            project_milestone_id: self.project_milestone_id.or(p.project_milestone_id),
+            created_after: self.created_after.or(p.created_after),
+            created_before: self.created_before.or(p.created_before),
+            updated_after: self.updated_after.or(p.updated_after),
+            updated_before: self.updated_before.or(p.updated_before),
+            ..self
+        }
+    }
 }
 
 /// Tool for searching Linear issues.
@@ -87,7 +126,7 @@ impl Tool for SearchIssuesTool {
     type Input = SearchIssuesInput;
     type Output = SearchResult;
     const NAME: &'static str = "linear_search_issues";
-    const DESCRIPTION: &'static str = "Search Linear issues using full-text search and/or filters";
+    const DESCRIPTION: &'static str = "Search Linear issues using full-text search and/or filters. Pass cycle_id=\"active\" (with team_id) to scope to the current sprint, or preset=<name> to apply a saved filter set from config.";
 
     fn call(
         &self,
@@ -96,6 +135,14 @@ impl Tool for SearchIssuesTool {
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
         let linear = Arc::clone(&self.linear);
         Box::pin(async move {
+            let input = match input.preset.clone() {
+                Some(name) => input.with_preset(
+                    linear
+                        .search_preset(&name)
+                        .map_err(|e| map_anyhow_to_tool_error(&e))?,
+                ),
+                None => input,
+            };
             linear
                 .search_issues(
                     input.query,
@@ -106,6 +153,8 @@ impl Tool for SearchIssuesTool {
                     input.creator_id,
                     input.team_id,
                     input.project_id,
+                    input.cycle_id,
+                    input.project_milestone_id,
                     input.created_after,
                     input.created_before,
                     input.updated_after,
@@ -170,8 +219,9 @@ impl Tool for ReadIssueTool {
 /// Input for `create_issue` tool.
 #[derive(Debug, Clone, Deserialize, JsonSchema)]
 pub struct CreateIssueInput {
-    /// Team ID (UUID) to create the issue in
-    pub team_id: String,
+    /// Team ID (UUID) or key (e.g. ENG) to create the issue in. Defaults to the configured default team
+    #[serde(default)]
+    pub team_id: Option<String>,
     /// Issue title
     pub title: String,
     /// Issue description (markdown supported)
@@ -180,10 +230,10 @@ pub struct CreateIssueInput {
     /// Priority (0=None, 1=Urgent, 2=High, 3=Normal, 4=Low)
     #[serde(default)]
     pub priority: Option<i32>,
-    /// Assignee user ID (UUID)
+    /// Assignee user ID (UUID), or "me" for the API key owner
     #[serde(default)]
     pub assignee_id: Option<String>,
-    /// Project ID (UUID)
+    /// Project ID (UUID) or name. Defaults to the configured default project
     #[serde(default)]
     pub project_id: Option<String>,
     /// Workflow state ID (UUID)
@@ -298,6 +348,12 @@ impl Tool for AddCommentTool {
 pub struct GetIssueCommentsInput {
     /// Issue ID, identifier (e.g., ENG-245), or URL
     pub issue: String,
+    /// Page size for explicit cursor pagination (1-100, default 50). Omit both `first` and `after` for implicit pagination.
+    #[serde(default)]
+    pub first: Option<i32>,
+    /// Cursor from a previous page's `end_cursor`
+    #[serde(default)]
+    pub after: Option<String>,
 }
 
 /// Tool for fetching comments on a Linear issue with implicit pagination.
@@ -316,7 +372,59 @@ impl Tool for GetIssueCommentsTool {
     type Input = GetIssueCommentsInput;
     type Output = crate::models::CommentsResult;
     const NAME: &'static str = "linear_get_issue_comments";
-    const DESCRIPTION: &'static str = "Get comments on a Linear issue (up to 10 per call) with implicit pagination. Repeat calls with the same issue until has_more=false; another call after completion restarts from the beginning.";
+    const DESCRIPTION: &'static str = "Get threaded comments on a Linear issue (author, body, created_at, reply parent; replies follow their parent). By default returns up to 10 per call with implicit pagination: repeat calls with the same issue until has_more=false; another call after completion restarts from the beginning. Pass first/after to page explicitly with end_cursor instead.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let linear = Arc::clone(&self.linear);
+        let ctx = ctx.clone();
+        Box::pin(async move {
+            linear
+                .get_issue_comments(input.issue, input.first, input.after, &ctx)
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e))
+        })
+    }
+}
+
+// ============================================================================
+// AddAttachment Tool
+// ============================================================================
+
+/// Input for `add_attachment` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddAttachmentInput {
+    /// Issue ID, identifier (e.g., ENG-245), or URL
+    pub issue: String,
+    /// http(s) URL to link (PR, design doc, log dump, ...)
+    pub url: String,
+    /// Attachment title shown on the issue (defaults to the URL when empty)
+    pub title: String,
+    /// Optional subtitle shown under the title
+    #[serde(default)]
+    pub subtitle: Option<String>,
+}
+
+/// Tool for linking a URL to a Linear issue.
+#[derive(Clone)]
+pub struct AddAttachmentTool {
+    linear: Arc<LinearTools>,
+}
+
+impl AddAttachmentTool {
+    pub fn new(linear: Arc<LinearTools>) -> Self {
+        Self { linear }
+    }
+}
+
+impl Tool for AddAttachmentTool {
+    type Input = AddAttachmentInput;
+    type Output = AddAttachmentResult;
+    const NAME: &'static str = "linear_add_attachment";
+    const DESCRIPTION: &'static str = "Link a URL (PR, design doc, log dump) to a Linear issue as an attachment. If the issue already has an attachment with the same URL (ignoring case of host, trailing slash, and #fragment), returns it with action=already_attached instead of adding a duplicate.";
 
     fn call(
         &self,
@@ -326,7 +434,105 @@ impl Tool for GetIssueCommentsTool {
         let linear = Arc::clone(&self.linear);
         Box::pin(async move {
             linear
-                .get_issue_comments(input.issue)
+                .add_attachment(input.issue, input.url, input.title, input.subtitle)
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e))
+        })
+    }
+}
+
+// ============================================================================
+// ListAttachments Tool
+// ============================================================================
+
+/// Input for `list_attachments` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ListAttachmentsInput {
+    /// Issue ID, identifier (e.g., ENG-245), or URL
+    pub issue: String,
+}
+
+/// Tool for listing the attachments on a Linear issue.
+#[derive(Clone)]
+pub struct ListAttachmentsTool {
+    linear: Arc<LinearTools>,
+}
+
+impl ListAttachmentsTool {
+    pub fn new(linear: Arc<LinearTools>) -> Self {
+        Self { linear }
+    }
+}
+
+impl Tool for ListAttachmentsTool {
+    type Input = ListAttachmentsInput;
+    type Output = AttachmentsResult;
+    const NAME: &'static str = "linear_list_attachments";
+    const DESCRIPTION: &'static str = "List attachments (linked PRs, docs, and other URLs) on a Linear issue with title, URL, and source.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let linear = Arc::clone(&self.linear);
+        Box::pin(async move {
+            linear
+                .list_attachments(input.issue)
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e))
+        })
+    }
+}
+
+// ============================================================================
+// ExportIssue Tool
+// ============================================================================
+
+/// Input for `export_issue` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ExportIssueInput {
+    /// Issue ID, identifier (e.g., ENG-245), or URL
+    pub issue: String,
+    /// Include the sub-issue tree (default: true)
+    #[serde(default)]
+    pub include_children: Option<bool>,
+    /// Include each issue's comments (default: true)
+    #[serde(default)]
+    pub include_comments: Option<bool>,
+}
+
+/// Tool for exporting an issue tree as a markdown document.
+#[derive(Clone)]
+pub struct ExportIssueTool {
+    linear: Arc<LinearTools>,
+}
+
+impl ExportIssueTool {
+    pub fn new(linear: Arc<LinearTools>) -> Self {
+        Self { linear }
+    }
+}
+
+impl Tool for ExportIssueTool {
+    type Input = ExportIssueInput;
+    type Output = ExportIssueResult;
+    const NAME: &'static str = "linear_export_issue";
+    const DESCRIPTION: &'static str = "Export a Linear issue with its description, comments, and sub-issue tree as one markdown document. Save the markdown with thoughts_write_document (doc_type=artifact) under the suggested filename to archive the context.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let linear = Arc::clone(&self.linear);
+        Box::pin(async move {
+            linear
+                .export_issue(
+                    input.issue,
+                    input.include_children.unwrap_or(true),
+                    input.include_comments.unwrap_or(true),
+                )
                 .await
                 .map_err(|e| map_anyhow_to_tool_error(&e))
         })
@@ -396,7 +602,7 @@ pub struct UpdateIssueInput {
     /// Priority: 0=None, 1=Urgent, 2=High, 3=Medium, 4=Low
     #[serde(default)]
     pub priority: Option<i32>,
-    /// Assignee user ID (UUID)
+    /// Assignee user ID (UUID), or "me" for the API key owner
     #[serde(default)]
     pub assignee_id: Option<String>,
     /// Workflow state ID (UUID)
@@ -479,7 +685,7 @@ pub struct SetRelationInput {
     pub issue: String,
     /// Related issue identifier (UUID, key like ENG-245, or URL)
     pub related_issue: String,
-    /// Relation type: "blocks", "duplicate", "related". Null/omitted to remove relation.
+    /// Relation type: `blocks`, `blocked_by`, `duplicate`, `related`. Null/omitted to remove relation.
     #[serde(default)]
     pub relation_type: Option<String>,
 }
@@ -517,6 +723,54 @@ impl Tool for SetRelationTool {
     }
 }
 
+// ============================================================================
+// AddRelation Tool
+// ============================================================================
+
+/// Input for adding an issue relation
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddRelationInput {
+    /// Source issue identifier (UUID, key like ENG-245, or URL)
+    pub issue: String,
+    /// Related issue identifier (UUID, key like ENG-245, or URL)
+    pub related_issue: String,
+    /// Relation type: `blocks` (issue blocks related), `blocked_by`, `duplicate` (issue duplicates related), or `related`
+    pub relation_type: String,
+}
+
+/// Tool for adding issue relations without removing anything.
+#[derive(Clone)]
+pub struct AddRelationTool {
+    linear: Arc<LinearTools>,
+}
+
+impl AddRelationTool {
+    pub fn new(linear: Arc<LinearTools>) -> Self {
+        Self { linear }
+    }
+}
+
+impl Tool for AddRelationTool {
+    type Input = AddRelationInput;
+    type Output = SetRelationResult;
+    const NAME: &'static str = "linear_add_relation";
+    const DESCRIPTION: &'static str = "Add a relation between two issues (blocks, blocked_by, duplicate, related). Existing identical relations are left alone, so repeated calls are safe. Set parents with linear_update_issue parent_id; see current relations in linear_read_issue.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let linear = Arc::clone(&self.linear);
+        Box::pin(async move {
+            linear
+                .add_relation(input.issue, input.related_issue, input.relation_type)
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e))
+        })
+    }
+}
+
 // ============================================================================
 // GetMetadata Tool
 // ============================================================================
@@ -579,6 +833,115 @@ impl Tool for GetMetadataTool {
     }
 }
 
+// ============================================================================
+// GetCycles Tool
+// ============================================================================
+
+/// Input for `get_cycles` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetCyclesInput {
+    /// Team ID (UUID) or key (e.g. ENG). Defaults to the configured default team
+    #[serde(default)]
+    pub team_id: Option<String>,
+    /// Include completed cycles (default: false)
+    #[serde(default)]
+    pub include_past: Option<bool>,
+    /// Maximum number of results (default: 50, max 100)
+    #[serde(default)]
+    pub first: Option<i32>,
+    /// Pagination cursor for next page
+    #[serde(default)]
+    pub after: Option<String>,
+}
+
+/// Tool for listing a team's cycles (sprints).
+#[derive(Clone)]
+pub struct GetCyclesTool {
+    linear: Arc<LinearTools>,
+}
+
+impl GetCyclesTool {
+    pub fn new(linear: Arc<LinearTools>) -> Self {
+        Self { linear }
+    }
+}
+
+impl Tool for GetCyclesTool {
+    type Input = GetCyclesInput;
+    type Output = CyclesResult;
+    const NAME: &'static str = "linear_get_cycles";
+    const DESCRIPTION: &'static str = "List a team's cycles (sprints) with dates, progress, and status (active/next/upcoming/previous/past). Completed cycles are omitted unless include_past=true. Use a cycle ID, or \"active\", as cycle_id in linear_search_issues.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let linear = Arc::clone(&self.linear);
+        Box::pin(async move {
+            linear
+                .get_cycles(
+                    input.team_id,
+                    input.include_past.unwrap_or(false),
+                    input.first,
+                    input.after,
+                )
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e))
+        })
+    }
+}
+
+// ============================================================================
+// SearchLocal Tool
+// ============================================================================
+
+/// Input for `search_local` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SearchLocalInput {
+    /// Free-text query (e.g. "the issue about retries")
+    pub query: String,
+    /// Maximum number of results (default 10, max 50)
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Re-fetch stale hits from Linear in the background (default: true)
+    #[serde(default)]
+    pub refresh: Option<bool>,
+}
+
+/// Tool for searching the local index of previously fetched issues.
+#[derive(Clone)]
+pub struct SearchLocalTool {
+    linear: Arc<LinearTools>,
+}
+
+impl SearchLocalTool {
+    pub fn new(linear: Arc<LinearTools>) -> Self {
+        Self { linear }
+    }
+}
+
+impl Tool for SearchLocalTool {
+    type Input = SearchLocalInput;
+    type Output = LocalSearchResult;
+    const NAME: &'static str = "linear_search_local";
+    const DESCRIPTION: &'static str = "Instantly search Linear issues already fetched by linear_search_issues/linear_read_issue in this or earlier sessions, without calling the API. Each hit shows when it was fetched; stale hits are refreshed in the background. Requires services.linear.index.enabled. Fall back to linear_search_issues when nothing relevant is found.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let linear = Arc::clone(&self.linear);
+        Box::pin(async move {
+            linear
+                .search_local(input.query, input.limit, input.refresh.unwrap_or(true))
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e))
+        })
+    }
+}
+
 // ============================================================================
 // Registry Builder
 // ============================================================================
@@ -591,10 +954,16 @@ pub fn build_registry(linear: Arc<LinearTools>) -> ToolRegistry {
         .register::<CreateIssueTool, ()>(CreateIssueTool::new(Arc::clone(&linear)))
         .register::<AddCommentTool, ()>(AddCommentTool::new(Arc::clone(&linear)))
         .register::<GetIssueCommentsTool, ()>(GetIssueCommentsTool::new(Arc::clone(&linear)))
+        .register::<AddAttachmentTool, ()>(AddAttachmentTool::new(Arc::clone(&linear)))
+        .register::<ListAttachmentsTool, ()>(ListAttachmentsTool::new(Arc::clone(&linear)))
+        .register::<ExportIssueTool, ()>(ExportIssueTool::new(Arc::clone(&linear)))
         .register::<ArchiveIssueTool, ()>(ArchiveIssueTool::new(Arc::clone(&linear)))
         .register::<UpdateIssueTool, ()>(UpdateIssueTool::new(Arc::clone(&linear)))
         .register::<SetRelationTool, ()>(SetRelationTool::new(Arc::clone(&linear)))
-        .register::<GetMetadataTool, ()>(GetMetadataTool::new(linear))
+        .register::<AddRelationTool, ()>(AddRelationTool::new(Arc::clone(&linear)))
+        .register::<GetMetadataTool, ()>(GetMetadataTool::new(Arc::clone(&linear)))
+        .register::<GetCyclesTool, ()>(GetCyclesTool::new(Arc::clone(&linear)))
+        .register::<SearchLocalTool, ()>(SearchLocalTool::new(linear))
         .finish()
 }
 
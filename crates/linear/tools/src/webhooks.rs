@@ -0,0 +1,545 @@
+//! Linear webhook ingestion: payload types, signature verification, and an axum router.
+//!
+//! Linear signs each delivery with an HMAC-SHA256 of the raw body, hex-encoded in
+//! the `Linear-Signature` header, and stamps the payload with `webhookTimestamp`
+//! (epoch milliseconds). [`WebhookVerifier`] checks both before the payload is
+//! parsed, so replayed or forged deliveries never reach a handler. [`router`]
+//! wraps the verifier in a single `POST /` route that acknowledges immediately and
+//! hands each [`WebhookEvent`] to the caller's handler on a background task.
+
+use axum::Router;
+use axum::body::Bytes;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::routing::post;
+use hmac::Hmac;
+use hmac::Mac;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Header carrying the hex-encoded HMAC-SHA256 of the request body.
+pub const SIGNATURE_HEADER: &str = "linear-signature";
+
+/// How far `webhookTimestamp` may drift from the local clock, as Linear recommends.
+pub const DEFAULT_TOLERANCE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookError {
+    #[error("missing {SIGNATURE_HEADER} header")]
+    MissingSignature,
+    #[error("invalid webhook signature")]
+    InvalidSignature,
+    #[error("stale webhook: timestamp is {age_ms}ms from the local clock")]
+    StaleTimestamp { age_ms: u64 },
+    #[error("malformed webhook payload: {0}")]
+    Malformed(String),
+}
+
+impl WebhookError {
+    /// Response status for a rejected delivery.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Malformed(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::UNAUTHORIZED,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookAction {
+    Create,
+    Update,
+    Remove,
+    #[serde(other)]
+    Other,
+}
+
+impl WebhookAction {
+    fn past_tense(self) -> &'static str {
+        match self {
+            Self::Create => "created",
+            Self::Update => "updated",
+            Self::Remove => "removed",
+            Self::Other => "changed",
+        }
+    }
+}
+
+/// Who triggered the event (a user, integration, or OAuth app).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookActor {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(rename = "type", default)]
+    pub actor_type: Option<String>,
+}
+
+/// A verified delivery. `data` is left raw; [`WebhookPayload::into_event`] types it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    pub action: WebhookAction,
+    /// Entity type, e.g. `Issue`, `Comment`, `Project`.
+    #[serde(rename = "type")]
+    pub entity_type: String,
+    pub data: Value,
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Previous values of the fields an `update` changed.
+    #[serde(default)]
+    pub updated_from: Option<Value>,
+    #[serde(default)]
+    pub actor: Option<WebhookActor>,
+    pub created_at: String,
+    #[serde(default)]
+    pub organization_id: Option<String>,
+    pub webhook_timestamp: u64,
+    #[serde(default)]
+    pub webhook_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookState {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub state_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueWebhookData {
+    pub id: String,
+    #[serde(default)]
+    pub identifier: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub priority: Option<i64>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub state: Option<WebhookState>,
+    #[serde(default)]
+    pub team_id: Option<String>,
+    #[serde(default)]
+    pub assignee_id: Option<String>,
+}
+
+/// Issue reference embedded in comment payloads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookIssueRef {
+    pub id: String,
+    #[serde(default)]
+    pub identifier: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentWebhookData {
+    pub id: String,
+    #[serde(default)]
+    pub body: String,
+    #[serde(default)]
+    pub issue_id: Option<String>,
+    #[serde(default)]
+    pub issue: Option<WebhookIssueRef>,
+    #[serde(default)]
+    pub user_id: Option<String>,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// A delivery typed by entity. Entity types without a dedicated variant are
+/// passed through as [`WebhookEvent::Other`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookEvent {
+    Issue {
+        action: WebhookAction,
+        issue: IssueWebhookData,
+        updated_from: Option<Value>,
+        actor: Option<WebhookActor>,
+    },
+    Comment {
+        action: WebhookAction,
+        comment: CommentWebhookData,
+        actor: Option<WebhookActor>,
+    },
+    Other(WebhookPayload),
+}
+
+impl WebhookPayload {
+    pub fn into_event(self) -> Result<WebhookEvent, WebhookError> {
+        let malformed = |e: serde_json::Error| {
+            WebhookError::Malformed(format!("{} data: {e}", self.entity_type))
+        };
+        match self.entity_type.as_str() {
+            "Issue" => Ok(WebhookEvent::Issue {
+                action: self.action,
+                issue: serde_json::from_value(self.data.clone()).map_err(malformed)?,
+                updated_from: self.updated_from,
+                actor: self.actor,
+            }),
+            "Comment" => Ok(WebhookEvent::Comment {
+                action: self.action,
+                comment: serde_json::from_value(self.data.clone()).map_err(malformed)?,
+                actor: self.actor,
+            }),
+            _ => Ok(WebhookEvent::Other(self)),
+        }
+    }
+}
+
+impl WebhookEvent {
+    /// One-line description suitable for an agent prompt or log line.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::Issue {
+                action,
+                issue,
+                updated_from,
+                actor,
+            } => {
+                use std::fmt::Write;
+                let mut line = format!(
+                    "Issue {} {}{}: {}",
+                    issue.identifier.as_deref().unwrap_or(&issue.id),
+                    action.past_tense(),
+                    by(actor.as_ref()),
+                    issue.title
+                );
+                if let Some(fields) = updated_from.as_ref().and_then(Value::as_object) {
+                    let mut changed: Vec<&str> = fields
+                        .keys()
+                        .map(String::as_str)
+                        .filter(|k| *k != "updatedAt")
+                        .collect();
+                    changed.sort_unstable();
+                    if !changed.is_empty() {
+                        let _ = write!(line, " (changed: {})", changed.join(", "));
+                    }
+                }
+                line
+            }
+            Self::Comment {
+                action,
+                comment,
+                actor,
+            } => {
+                let issue = comment
+                    .issue
+                    .as_ref()
+                    .map(|i| i.identifier.as_deref().unwrap_or(&i.id))
+                    .or(comment.issue_id.as_deref())
+                    .unwrap_or("unknown issue");
+                format!(
+                    "Comment on {issue} {}{}: {}",
+                    action.past_tense(),
+                    by(actor.as_ref()),
+                    comment.body
+                )
+            }
+            Self::Other(payload) => format!(
+                "{} {}{}",
+                payload.entity_type,
+                payload.action.past_tense(),
+                by(payload.actor.as_ref())
+            ),
+        }
+    }
+}
+
+fn by(actor: Option<&WebhookActor>) -> String {
+    actor
+        .and_then(|a| a.name.as_deref())
+        .map(|name| format!(" by {name}"))
+        .unwrap_or_default()
+}
+
+/// Verifies deliveries signed with a webhook's signing secret.
+#[derive(Clone)]
+pub struct WebhookVerifier {
+    secret: Vec<u8>,
+    tolerance: Duration,
+}
+
+impl std::fmt::Debug for WebhookVerifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookVerifier")
+            .field("tolerance", &self.tolerance)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookVerifier {
+    pub fn new(secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            secret: secret.into(),
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Check the signature and timestamp of a raw body, then parse it.
+    pub fn verify(
+        &self,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> Result<WebhookPayload, WebhookError> {
+        self.verify_at(signature, body, now_millis())
+    }
+
+    pub fn verify_at(
+        &self,
+        signature: Option<&str>,
+        body: &[u8],
+        now_ms: u64,
+    ) -> Result<WebhookPayload, WebhookError> {
+        let signature = signature.ok_or(WebhookError::MissingSignature)?;
+        let expected = hex::decode(signature.trim()).map_err(|_| WebhookError::InvalidSignature)?;
+        // verify_slice compares in constant time.
+        self.mac(body)
+            .verify_slice(&expected)
+            .map_err(|_| WebhookError::InvalidSignature)?;
+
+        let payload: WebhookPayload =
+            serde_json::from_slice(body).map_err(|e| WebhookError::Malformed(e.to_string()))?;
+        let age_ms = now_ms.abs_diff(payload.webhook_timestamp);
+        if u128::from(age_ms) > self.tolerance.as_millis() {
+            return Err(WebhookError::StaleTimestamp { age_ms });
+        }
+        Ok(payload)
+    }
+
+    /// Hex signature for `body`, as Linear would send it.
+    pub fn sign(&self, body: &[u8]) -> String {
+        hex::encode(self.mac(body).finalize().into_bytes())
+    }
+
+    #[expect(clippy::expect_used)]
+    fn mac(&self, body: &[u8]) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC accepts keys of any length");
+        mac.update(body);
+        mac
+    }
+}
+
+/// Router with a `POST /` route for Linear deliveries; nest it wherever the
+/// service mounts webhooks.
+///
+/// Verified deliveries get `200` right away and `handler` runs on a spawned task,
+/// so slow handlers do not trip Linear's delivery timeout. Bad signatures and
+/// stale timestamps get `401`, unparseable payloads `400`.
+pub fn router<H, Fut>(verifier: WebhookVerifier, handler: H) -> Router
+where
+    H: Fn(WebhookEvent) -> Fut + Clone + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let verifier = Arc::new(verifier);
+    Router::new().route(
+        "/",
+        post(move |headers: HeaderMap, body: Bytes| async move {
+            let signature = headers.get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok());
+            match verifier
+                .verify(signature, &body)
+                .and_then(WebhookPayload::into_event)
+            {
+                Ok(event) => {
+                    tokio::spawn(handler(event));
+                    StatusCode::OK
+                }
+                Err(e) => {
+                    tracing::warn!("rejected Linear webhook: {e}");
+                    e.status_code()
+                }
+            }
+        }),
+    )
+}
+
+fn now_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    const SECRET: &str = "lin_wh_test_secret";
+    const NOW_MS: u64 = 1_760_000_000_000;
+
+    fn issue_body(timestamp: u64) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "action": "update",
+            "type": "Issue",
+            "createdAt": "2025-10-09T08:53:20.000Z",
+            "url": "https://linear.app/acme/issue/ENG-42",
+            "actor": {"id": "user-1", "name": "Ada", "type": "user"},
+            "data": {
+                "id": "issue-1",
+                "identifier": "ENG-42",
+                "title": "Fix login",
+                "priority": 2,
+                "state": {"id": "state-1", "name": "In Progress", "type": "started"},
+                "teamId": "team-1"
+            },
+            "updatedFrom": {"stateId": "state-0", "updatedAt": "2025-10-09T08:00:00.000Z"},
+            "organizationId": "org-1",
+            "webhookTimestamp": timestamp,
+            "webhookId": "wh-1"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn verify_accepts_signed_fresh_payload() {
+        let verifier = WebhookVerifier::new(SECRET);
+        let body = issue_body(NOW_MS - 5_000);
+        let sig = verifier.sign(&body);
+
+        let event = verifier
+            .verify_at(Some(&sig), &body, NOW_MS)
+            .unwrap()
+            .into_event()
+            .unwrap();
+        let WebhookEvent::Issue { action, issue, .. } = &event else {
+            panic!("expected issue event, got {event:?}");
+        };
+        assert_eq!(*action, WebhookAction::Update);
+        assert_eq!(issue.identifier.as_deref(), Some("ENG-42"));
+        assert_eq!(issue.state.as_ref().unwrap().name, "In Progress");
+        assert_eq!(
+            event.summary(),
+            "Issue ENG-42 updated by Ada: Fix login (changed: stateId)"
+        );
+    }
+
+    #[test]
+    fn verify_rejects_bad_signatures_and_stale_timestamps() {
+        let verifier = WebhookVerifier::new(SECRET);
+        let body = issue_body(NOW_MS);
+        let other = WebhookVerifier::new("other").sign(&body);
+
+        for sig in [Some(other.as_str()), Some("not-hex")] {
+            assert!(matches!(
+                verifier.verify_at(sig, &body, NOW_MS),
+                Err(WebhookError::InvalidSignature)
+            ));
+        }
+        assert!(matches!(
+            verifier.verify_at(None, &body, NOW_MS),
+            Err(WebhookError::MissingSignature)
+        ));
+
+        let sig = verifier.sign(&body);
+        assert!(matches!(
+            verifier.verify_at(Some(&sig), &body, NOW_MS + 61_000),
+            Err(WebhookError::StaleTimestamp { age_ms: 61_000 })
+        ));
+        assert!(
+            verifier
+                .with_tolerance(Duration::from_secs(120))
+                .verify_at(Some(&sig), &body, NOW_MS + 61_000)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn comment_and_unknown_entities_are_typed() {
+        let payload: WebhookPayload = serde_json::from_value(serde_json::json!({
+            "action": "create",
+            "type": "Comment",
+            "createdAt": "2025-10-09T08:53:20.000Z",
+            "data": {
+                "id": "comment-1",
+                "body": "Can you take a look?",
+                "issueId": "issue-1",
+                "issue": {"id": "issue-1", "identifier": "ENG-42", "title": "Fix login"}
+            },
+            "webhookTimestamp": NOW_MS
+        }))
+        .unwrap();
+        let event = payload.into_event().unwrap();
+        assert_eq!(
+            event.summary(),
+            "Comment on ENG-42 created: Can you take a look?"
+        );
+
+        let payload: WebhookPayload = serde_json::from_value(serde_json::json!({
+            "action": "archive",
+            "type": "Project",
+            "createdAt": "2025-10-09T08:53:20.000Z",
+            "data": {"id": "project-1"},
+            "webhookTimestamp": NOW_MS
+        }))
+        .unwrap();
+        let event = payload.into_event().unwrap();
+        assert!(matches!(&event, WebhookEvent::Other(p) if p.action == WebhookAction::Other));
+        assert_eq!(event.summary(), "Project changed");
+    }
+
+    #[tokio::test]
+    async fn router_dispatches_verified_events() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let verifier = WebhookVerifier::new(SECRET);
+        let app = router(verifier.clone(), move |event| {
+            let tx = tx.clone();
+            async move {
+                let _ = tx.send(event);
+            }
+        });
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let client = reqwest::Client::new();
+        let body = issue_body(now_millis());
+
+        let resp = client
+            .post(&url)
+            .header(SIGNATURE_HEADER, "00")
+            .body(body.clone())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status().as_u16(), 401);
+
+        let resp = client
+            .post(&url)
+            .header(SIGNATURE_HEADER, verifier.sign(&body))
+            .body(body)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(resp.status().as_u16(), 200);
+
+        let event = rx.recv().await.expect("handler received event");
+        assert!(matches!(event, WebhookEvent::Issue { .. }));
+        assert!(rx.try_recv().is_err());
+    }
+}
@@ -1,4 +1,8 @@
+use agentic_tools_core::TextFormat;
+use agentic_tools_core::TextOptions;
+use agentic_tools_core::ToolContext;
 use linear_tools::test_support::*;
+use mockito::Matcher;
 use mockito::Server;
 use serial_test::serial;
 
@@ -20,6 +24,7 @@ async fn search_issues_auth_failure() {
     let res = tool
         .search_issues(
             None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None,
         )
         .await;
     assert!(res.is_err());
@@ -36,11 +41,29 @@ async fn read_issue_by_identifier_success() {
 
     let _m = server
         .mock("POST", "/")
+        .match_body(Matcher::Regex("IssuesQuery".into()))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(issues_response(&[node], false, None))
         .create_async()
         .await;
+    let _relations = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueRelationsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(
+            &[
+                ("rel-1", "blocks", "uuid-2"),
+                ("rel-2", "duplicate", "uuid-3"),
+            ],
+            &[
+                ("rel-3", "blocks", "uuid-4"),
+                ("rel-4", "related", "uuid-5"),
+            ],
+        ))
+        .create_async()
+        .await;
 
     let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
     let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
@@ -56,6 +79,22 @@ async fn read_issue_by_identifier_success() {
     assert_eq!(res.issue.priority_label, "High");
     assert!(res.issue.creator.is_some());
     assert_eq!(res.description, Some("Description here".to_string()));
+
+    let relations: Vec<(&str, &str)> = res
+        .relations
+        .iter()
+        .map(|r| (r.relation.as_str(), r.issue_id.as_str()))
+        .collect();
+    assert_eq!(
+        relations,
+        vec![
+            ("blocks", "uuid-2"),
+            ("duplicate_of", "uuid-3"),
+            ("blocked_by", "uuid-4"),
+            ("related", "uuid-5"),
+        ]
+    );
+    assert_eq!(res.relations[2].identifier, "REL-uuid-4");
 }
 
 #[tokio::test]
@@ -89,6 +128,7 @@ async fn search_issues_success() {
     let res = tool
         .search_issues(
             None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None,
         )
         .await
         .unwrap();
@@ -147,7 +187,7 @@ async fn create_issue_success() {
     let tool = linear_tools::LinearTools::new();
     let res = tool
         .create_issue(
-            "team-uuid".to_string(),
+            Some("team-uuid".to_string()),
             "New Issue".to_string(),
             Some("Issue description".to_string()),
             Some(2),
@@ -321,7 +361,7 @@ async fn create_issue_with_state_label_parent_success() {
     let tool = linear_tools::LinearTools::new();
     let res = tool
         .create_issue(
-            "team-uuid".to_string(),
+            Some("team-uuid".to_string()),
             "Issue with extras".to_string(),
             Some("Has state, labels, parent".to_string()),
             Some(2),
@@ -378,6 +418,8 @@ async fn search_issues_with_state_filter() {
             None,
             None,
             None,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -420,6 +462,8 @@ async fn search_issues_with_date_ranges() {
             None,
             None,
             None,
+            None,
+            None,
             Some("2025-01-01T00:00:00Z".to_string()),
             Some("2025-01-31T23:59:59Z".to_string()),
             None,
@@ -455,6 +499,7 @@ async fn auth_header_personal_key() {
     let res = tool
         .search_issues(
             None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None,
         )
         .await
         .unwrap();
@@ -481,6 +526,7 @@ async fn auth_header_oauth_token() {
     let res = tool
         .search_issues(
             None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None,
         )
         .await
         .unwrap();
@@ -522,6 +568,8 @@ async fn search_issues_full_text_success() {
             None,
             None,
             None,
+            None,
+            None,
             Some(10),
             None,
         )
@@ -564,6 +612,8 @@ async fn graphql_errors_fail_fast() {
             None,
             None,
             None,
+            None,
+            None,
             Some(1),
             None,
         )
@@ -755,8 +805,18 @@ async fn update_issue_by_identifier_success() {
 async fn set_relation_create_success() {
     let mut server = Server::new_async().await;
 
+    let _existing = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueRelationsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(&[], &[]))
+        .expect(1)
+        .create_async()
+        .await;
     let _m = server
         .mock("POST", "/")
+        .match_body(Matcher::Regex("issueRelationCreate".into()))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(issue_relation_create_response(true))
@@ -792,7 +852,7 @@ async fn set_relation_remove_existing() {
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(issue_relations_response(
-            &[("rel-123", "550e8400-e29b-41d4-a716-446655440002")],
+            &[("rel-123", "blocks", "550e8400-e29b-41d4-a716-446655440002")],
             &[],
         ))
         .expect(1)
@@ -1010,6 +1070,8 @@ async fn search_issues_with_creator_id_filter() {
             None,
             None,
             None,
+            None,
+            None,
         )
         .await
         .unwrap();
@@ -1116,7 +1178,7 @@ async fn get_issue_comments_fetches_multiple_upstream_pages_then_paginates_local
     let tools = linear_tools::LinearTools::new();
 
     let first = tools
-        .get_issue_comments(issue_uuid.to_string())
+        .get_issue_comments(issue_uuid.to_string(), None, None, &ToolContext::default())
         .await
         .unwrap();
     assert_eq!(first.issue_identifier, identifier);
@@ -1128,7 +1190,7 @@ async fn get_issue_comments_fetches_multiple_upstream_pages_then_paginates_local
     assert_eq!(first.comments.last().unwrap().id, "comment-10");
 
     let second = tools
-        .get_issue_comments(issue_uuid.to_string())
+        .get_issue_comments(issue_uuid.to_string(), None, None, &ToolContext::default())
         .await
         .unwrap();
     assert_eq!(second.issue_identifier, identifier);
@@ -1139,3 +1201,1146 @@ async fn get_issue_comments_fetches_multiple_upstream_pages_then_paginates_local
     assert_eq!(second.comments.first().unwrap().id, "comment-11");
     assert_eq!(second.comments.last().unwrap().id, "comment-12");
 }
+
+#[tokio::test]
+#[serial(env)]
+async fn get_issue_comments_with_cursor_returns_one_threaded_page() {
+    let mut server = Server::new_async().await;
+    let issue_uuid = "issue-comments-uuid";
+
+    let parent = comment_node(
+        "comment-1",
+        "Parent",
+        "https://linear.app/test/comment/1",
+        "2025-01-01T00:00:00Z",
+        "2025-01-01T00:00:00Z",
+    );
+    let mut reply = comment_node(
+        "comment-3",
+        "Reply",
+        "https://linear.app/test/comment/3",
+        "2025-01-03T00:00:00Z",
+        "2025-01-03T00:00:00Z",
+    );
+    reply["parentId"] = serde_json::json!("comment-1");
+    let other = comment_node(
+        "comment-2",
+        "Other thread",
+        "https://linear.app/test/comment/2",
+        "2025-01-02T00:00:00Z",
+        "2025-01-02T00:00:00Z",
+    );
+
+    let page = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "first": 3, "after": "cursor-0" }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_comments_response(
+            issue_uuid,
+            "ENG-245",
+            &[parent, other, reply],
+            true,
+            Some("cursor-1"),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let res = linear_tools::LinearTools::new()
+        .get_issue_comments(
+            issue_uuid.to_string(),
+            Some(3),
+            Some("cursor-0".into()),
+            &ToolContext::default(),
+        )
+        .await
+        .unwrap();
+
+    let ids: Vec<&str> = res.comments.iter().map(|c| c.id.as_str()).collect();
+    assert_eq!(ids, vec!["comment-1", "comment-3", "comment-2"]);
+    assert_eq!(res.comments[1].parent_id.as_deref(), Some("comment-1"));
+    assert!(res.has_more);
+    assert_eq!(res.end_cursor.as_deref(), Some("cursor-1"));
+    page.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn list_attachments_follows_pages() {
+    let mut server = Server::new_async().await;
+    let issue_uuid = "issue-attachments-uuid";
+
+    let _page1 = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "after": null }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_attachments_response(
+            issue_uuid,
+            "ENG-245",
+            &[attachment_node(
+                "att-1",
+                "PR #12",
+                "https://github.com/org/repo/pull/12",
+            )],
+            true,
+            Some("cursor-1"),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let _page2 = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "after": "cursor-1" }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_attachments_response(
+            issue_uuid,
+            "ENG-245",
+            &[attachment_node(
+                "att-2",
+                "Design",
+                "https://docs.example.com/design",
+            )],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let res = linear_tools::LinearTools::new()
+        .list_attachments(issue_uuid.to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(res.issue_identifier, "ENG-245");
+    let ids: Vec<&str> = res.attachments.iter().map(|a| a.id.as_str()).collect();
+    assert_eq!(ids, vec!["att-1", "att-2"]);
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn add_attachment_skips_duplicate_url() {
+    let mut server = Server::new_async().await;
+    let issue_uuid = "issue-attachments-uuid";
+
+    let list = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("attachments".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_attachments_response(
+            issue_uuid,
+            "ENG-245",
+            &[attachment_node(
+                "att-1",
+                "PR #12",
+                "https://github.com/org/repo/pull/12",
+            )],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("attachmentCreate".into()))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let res = linear_tools::LinearTools::new()
+        .add_attachment(
+            issue_uuid.to_string(),
+            "https://GitHub.com/org/repo/pull/12/".into(),
+            "PR".into(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert!(res.success);
+    assert_eq!(res.action, "already_attached");
+    assert_eq!(res.attachment.id, "att-1");
+    list.assert_async().await;
+    create.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn add_attachment_creates_new_url() {
+    let mut server = Server::new_async().await;
+    let issue_uuid = "issue-attachments-uuid";
+
+    let _list = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueAttachmentsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_attachments_response(
+            issue_uuid,
+            "ENG-245",
+            &[],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("attachmentCreate".into()),
+            Matcher::PartialJson(serde_json::json!({
+                "variables": { "input": {
+                    "issueId": issue_uuid,
+                    "title": "Crash log",
+                    "url": "https://logs.example.com/run/7"
+                } }
+            })),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(attachment_create_response(&attachment_node(
+            "att-9",
+            "Crash log",
+            "https://logs.example.com/run/7",
+        )))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let res = linear_tools::LinearTools::new()
+        .add_attachment(
+            issue_uuid.to_string(),
+            "https://logs.example.com/run/7".into(),
+            "Crash log".into(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.action, "created");
+    assert_eq!(res.attachment.id, "att-9");
+    create.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn add_attachment_rejects_non_http_url() {
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+    let err = linear_tools::LinearTools::new()
+        .add_attachment("ENG-1".into(), "file:///tmp/log".into(), "log".into(), None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Invalid attachment URL"));
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn search_local_answers_from_previously_fetched_issues() {
+    let mut server = Server::new_async().await;
+
+    let mut node = search_issue_node("uuid-1", "ENG-300", "Webhook delivery");
+    node["description"] = serde_json::json!("Failed deliveries are retried without backoff");
+    let other = search_issue_node("uuid-2", "ENG-301", "Dark mode");
+
+    let m = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(search_response(&[node, other], false, None))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let index =
+        linear_tools::index::IssueIndex::open_in_memory(std::time::Duration::from_secs(900))
+            .unwrap();
+    let tool = linear_tools::LinearTools::new().with_index(std::sync::Arc::new(index));
+    tool.search_issues(
+        Some("webhook".into()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let res = tool
+        .search_local("the issue about retries".into(), None, true)
+        .await
+        .unwrap();
+    assert_eq!(res.indexed, 2);
+    assert_eq!(res.issues.len(), 1);
+    assert_eq!(res.issues[0].issue.identifier, "ENG-300");
+    assert!(!res.issues[0].stale);
+    assert!(res.refreshing.is_empty());
+    m.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn search_local_errors_when_index_disabled() {
+    let tool = linear_tools::LinearTools::new();
+    let err = tool
+        .search_local("retries".into(), None, false)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("index is disabled"));
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn search_issues_active_cycle_and_milestone_filters() {
+    let mut server = Server::new_async().await;
+    let node = issue_node("uuid-sprint", "ENG-910", "In this sprint");
+
+    let m = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": {
+                "filter": {
+                    "cycle": { "isActive": { "eq": true } },
+                    "projectMilestone": { "id": { "eq": "milestone-1" } },
+                    "team": { "id": { "eq": "team-1" } }
+                }
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issues_response(&[node], false, None))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let res = tool
+        .search_issues(
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some("team-1".into()),
+            None,
+            Some("Active".into()),
+            Some("milestone-1".into()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.issues.len(), 1);
+    assert_eq!(res.issues[0].identifier, "ENG-910");
+    m.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn get_cycles_hides_past_by_default_and_sorts_by_number() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": {
+                "filter": {
+                    "team": { "id": { "eq": "team-1" } },
+                    "isPast": { "eq": false }
+                }
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cycles_response(
+            &[
+                cycle_node("c-13", 13, "next"),
+                cycle_node("c-12", 12, "active"),
+                cycle_node("c-14", 14, "upcoming"),
+            ],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let res = tool
+        .get_cycles(Some("team-1".into()), false, None, None)
+        .await
+        .unwrap();
+
+    let summary: Vec<(i32, &str)> = res
+        .cycles
+        .iter()
+        .map(|c| (c.number, c.status.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![(12, "active"), (13, "next"), (14, "upcoming")]
+    );
+    m.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn get_cycles_include_past_drops_past_filter() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("isPast".into()))
+        .expect(0)
+        .create_async()
+        .await;
+    let _all = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cycles_response(
+            &[
+                cycle_node("c-11", 11, "previous"),
+                cycle_node("c-3", 3, "past"),
+            ],
+            true,
+            Some("cursor-2"),
+        ))
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let res = tool
+        .get_cycles(Some("team-1".into()), true, Some(2), None)
+        .await
+        .unwrap();
+
+    assert_eq!(res.cycles[0].status, "past");
+    assert_eq!(res.cycles[1].status, "previous");
+    assert!(res.has_next_page);
+    assert_eq!(res.end_cursor.as_deref(), Some("cursor-2"));
+    m.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn set_relation_removes_inverse_relation() {
+    let mut server = Server::new_async().await;
+
+    let _query = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueRelationsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(
+            &[],
+            &[("rel-inv", "blocks", "550e8400-e29b-41d4-a716-446655440002")],
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let delete = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "id": "rel-inv" }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relation_delete_response(true))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let result = tool
+        .set_relation(
+            "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.action, "removed");
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn add_relation_blocked_by_swaps_direction() {
+    let mut server = Server::new_async().await;
+
+    let existing = server
+        .mock("POST", "/")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("IssueRelationsQuery".into()),
+            Matcher::PartialJson(serde_json::json!({
+                "variables": { "id": "550e8400-e29b-41d4-a716-446655440002" }
+            })),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(&[], &[]))
+        .expect(1)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": {
+                "input": {
+                    "issueId": "550e8400-e29b-41d4-a716-446655440002",
+                    "relatedIssueId": "550e8400-e29b-41d4-a716-446655440001",
+                    "type": "blocks"
+                }
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relation_create_response(true))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let result = tool
+        .add_relation(
+            "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            "blocked_by".to_string(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.action, "created");
+    existing.assert_async().await;
+    create.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn add_relation_skips_existing_related_in_either_direction() {
+    let mut server = Server::new_async().await;
+
+    let _existing = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueRelationsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(
+            &[],
+            &[("rel-9", "related", "550e8400-e29b-41d4-a716-446655440002")],
+        ))
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("issueRelationCreate".into()))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let result = tool
+        .add_relation(
+            "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            "related".to_string(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.success);
+    assert_eq!(result.action, "already_exists");
+    create.assert_async().await;
+
+    let err = tool
+        .add_relation(
+            "550e8400-e29b-41d4-a716-446655440001".to_string(),
+            "550e8400-e29b-41d4-a716-446655440002".to_string(),
+            "parent".to_string(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Invalid relation type"));
+}
+
+// ============================================================================
+// Rate limit tests
+// ============================================================================
+
+const RATELIMITED_BODY: &str =
+    r#"{"errors":[{"message":"Rate limit exceeded","extensions":{"code":"RATELIMITED"}}]}"#;
+
+#[tokio::test]
+#[serial(env)]
+async fn search_issues_retries_rate_limited_response_and_reports_quota() {
+    let mut server = Server::new_async().await;
+
+    let limited = server
+        .mock("POST", "/")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_header("retry-after", "0")
+        .with_body(RATELIMITED_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+    let ok = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-requests-limit", "1500")
+        .with_header("x-ratelimit-requests-remaining", "100")
+        .with_header("x-ratelimit-requests-reset", "4102444800000")
+        .with_body(issues_response(
+            &[issue_node("uuid-1", "ENG-1", "After retry")],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let res = tool
+        .search_issues(
+            None, None, None, None, None, None, None, None, None, None, None, None, None, None,
+            None, None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.issues[0].identifier, "ENG-1");
+    let quota = res.rate_limit.as_ref().expect("quota recorded");
+    assert_eq!(quota.resource, "requests");
+    assert_eq!(quota.remaining, 100);
+    let text = res.fmt_text(&TextOptions::default());
+    assert!(text.contains("Warning: Linear API quota (requests): 100/1500"));
+    limited.assert_async().await;
+    ok.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn rate_limited_response_fails_when_retries_exhausted() {
+    let mut server = Server::new_async().await;
+
+    let limited = server
+        .mock("POST", "/")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_header("retry-after", "0")
+        .with_body(RATELIMITED_BODY)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let config = agentic_config::types::LinearServiceConfig {
+        rate_limit_max_retries: 0,
+        ..Default::default()
+    };
+    let tool = linear_tools::LinearTools::with_config(config);
+    let err = tool.read_issue("ENG-1".into()).await.unwrap_err();
+
+    assert!(err.to_string().starts_with("rate limited:"), "{err}");
+    limited.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn create_issue_resolves_default_team_project_and_me() {
+    let mut server = Server::new_async().await;
+
+    let teams = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "filter": { "key": { "eq": "ENG" } } }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(teams_response(
+            &[team_node("team-uuid", "ENG", "Engineering")],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let projects = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "filter": { "name": { "eqIgnoreCase": "Roadmap" } } }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(projects_response(
+            &[project_node("project-uuid", "Roadmap")],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let viewer = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("ViewerQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(viewer_response("user-me", "Ada"))
+        .expect(1)
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": {
+                "input": {
+                    "teamId": "team-uuid",
+                    "projectId": "project-uuid",
+                    "assigneeId": "user-me"
+                }
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_create_response(&issue_node(
+            "new-uuid", "ENG-501", "Defaults",
+        )))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let config = agentic_config::types::LinearServiceConfig {
+        default_team: Some("eng".into()),
+        default_project: Some("Roadmap".into()),
+        ..Default::default()
+    };
+    let tool = linear_tools::LinearTools::with_config(config);
+    let res = tool
+        .create_issue(
+            None,
+            "Defaults".into(),
+            None,
+            None,
+            Some("me".into()),
+            None,
+            None,
+            None,
+            vec![],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.issue.unwrap().identifier, "ENG-501");
+    teams.assert_async().await;
+    projects.assert_async().await;
+    viewer.assert_async().await;
+    create.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn create_issue_without_team_or_default_is_invalid() {
+    let tool = linear_tools::LinearTools::new();
+    let err = tool
+        .create_issue(
+            None,
+            "No team".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            vec![],
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().starts_with("invalid argument"), "{err}");
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn search_issues_filters_by_team_key_and_caches_me() {
+    let mut server = Server::new_async().await;
+
+    let viewer = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("ViewerQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(viewer_response("user-me", "Ada"))
+        .expect(1)
+        .create_async()
+        .await;
+    let search = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": {
+                "filter": {
+                    "team": { "key": { "eq": "ENG" } },
+                    "assignee": { "id": { "eq": "user-me" } }
+                }
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issues_response(
+            &[issue_node("uuid-1", "ENG-1", "Mine")],
+            false,
+            None,
+        ))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    for _ in 0..2 {
+        let res = tool
+            .search_issues(
+                None,
+                None,
+                None,
+                None,
+                Some("me".into()),
+                None,
+                Some("ENG".into()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(res.issues[0].identifier, "ENG-1");
+    }
+    viewer.assert_async().await;
+    search.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn export_issue_renders_tree_and_comments() {
+    let mut server = Server::new_async().await;
+
+    let mut root = issue_node("root-uuid", "ENG-1", "Root issue");
+    root["description"] = serde_json::json!("Root description");
+    let mut first = issue_node("child-a", "ENG-2", "Later child");
+    first["createdAt"] = serde_json::json!("2025-01-04T00:00:00Z");
+    let mut second = issue_node("child-b", "ENG-3", "Earlier child");
+    second["createdAt"] = serde_json::json!("2025-01-03T00:00:00Z");
+    second["state"] = workflow_state_node("s2", "Done", "completed");
+
+    let _root = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueByIdQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_by_id_response(&root))
+        .create_async()
+        .await;
+    let _relations = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueRelationsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(&[], &[]))
+        .create_async()
+        .await;
+    let mut comment = comment_node(
+        "c1",
+        "Looks good",
+        "https://linear.app/c1",
+        "2025-01-05T00:00:00Z",
+        "2025-01-05T00:00:00Z",
+    );
+    comment["user"] = user_node("u1", "Ada", "ada", "ada@example.com");
+    let mut reply = comment_node(
+        "c2",
+        "Agreed\n\nShip it",
+        "https://linear.app/c2",
+        "2025-01-06T00:00:00Z",
+        "2025-01-06T00:00:00Z",
+    );
+    reply["parentId"] = serde_json::json!("c1");
+    reply["user"] = user_node("u2", "Bob", "bob", "bob@example.com");
+    let root_comments = server
+        .mock("POST", "/")
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex("IssueCommentsQuery".into()),
+            Matcher::PartialJson(serde_json::json!({ "variables": { "id": "root-uuid" } })),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_comments_response(
+            "root-uuid",
+            "ENG-1",
+            &[reply, comment],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let _other_comments = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueCommentsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_comments_response("child", "ENG-2", &[], false, None))
+        .create_async()
+        .await;
+    let root_children = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": { "filter": { "parent": { "id": { "eq": "root-uuid" } } } }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issues_response(&[first, second], false, None))
+        .expect(1)
+        .create_async()
+        .await;
+    let leaf_children = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("\"parent\"".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issues_response(&[], false, None))
+        .expect(2)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let res = tool
+        .export_issue("root-uuid".into(), true, true)
+        .await
+        .unwrap();
+
+    assert_eq!(res.filename, "linear-eng-1.md");
+    assert_eq!(res.issue_count, 3);
+    assert_eq!(res.comment_count, 2);
+    assert!(!res.truncated);
+
+    let md = &res.markdown;
+    assert!(md.starts_with("# ENG-1: Root issue\n"), "{md}");
+    assert!(md.contains("## Description\n\nRoot description\n"), "{md}");
+    assert!(md.contains("**Ada** (2025-01-05):\n\nLooks good\n"), "{md}");
+    assert!(
+        md.contains("> **Bob** (2025-01-06, reply):\n>\n> Agreed\n>\n> Ship it\n"),
+        "{md}"
+    );
+    assert!(
+        md.contains(
+            "## Sub-issues\n\n- ENG-3: Earlier child [Done]\n- ENG-2: Later child [Todo]\n"
+        ),
+        "{md}"
+    );
+    let earlier = md.find("## ENG-3: Earlier child").unwrap();
+    let later = md.find("## ENG-2: Later child").unwrap();
+    assert!(earlier < later);
+    assert!(md[earlier..].contains("- Parent: ENG-1\n"));
+
+    root_comments.assert_async().await;
+    root_children.assert_async().await;
+    leaf_children.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn export_issue_without_children_or_comments_reads_only_the_issue() {
+    let mut server = Server::new_async().await;
+
+    let _root = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueByIdQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_by_id_response(&issue_node(
+            "root-uuid",
+            "ENG-1",
+            "Root",
+        )))
+        .create_async()
+        .await;
+    let _relations = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueRelationsQuery".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issue_relations_response(&[], &[]))
+        .create_async()
+        .await;
+    let other = server
+        .mock("POST", "/")
+        .match_body(Matcher::Regex("IssueCommentsQuery|\"parent\"".into()))
+        .expect(0)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let tool = linear_tools::LinearTools::new();
+    let res = tool
+        .export_issue("root-uuid".into(), false, false)
+        .await
+        .unwrap();
+
+    assert_eq!(res.issue_count, 1);
+    assert!(!res.markdown.contains("Sub-issues"));
+    assert!(!res.markdown.contains("Comments"));
+    other.assert_async().await;
+}
+
+#[tokio::test]
+#[serial(env)]
+async fn search_preset_fills_unset_filters_and_explicit_args_win() {
+    let mut server = Server::new_async().await;
+
+    let search = server
+        .mock("POST", "/")
+        .match_body(Matcher::PartialJson(serde_json::json!({
+            "variables": {
+                "filter": {
+                    "team": { "key": { "eq": "ENG" } },
+                    "priority": { "eq": 2.0 },
+                    "state": { "id": { "eq": "state-open" } }
+                }
+            }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(issues_response(
+            &[issue_node("uuid-1", "ENG-1", "Bug")],
+            false,
+            None,
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _url = EnvGuard::set("LINEAR_GRAPHQL_URL", &server.url());
+    let _key = EnvGuard::set("LINEAR_API_KEY", "good-key");
+
+    let mut config = agentic_config::types::LinearServiceConfig::default();
+    config.presets.insert(
+        "open-bugs".into(),
+        agentic_config::types::LinearSearchPreset {
+            team_id: Some("ENG".into()),
+            priority: Some(1),
+            state_id: Some("state-open".into()),
+            ..Default::default()
+        },
+    );
+    let registry = linear_tools::build_registry(std::sync::Arc::new(
+        linear_tools::LinearTools::with_config(config),
+    ));
+
+    let res = registry
+        .dispatch_json(
+            "linear_search_issues",
+            serde_json::json!({ "preset": "open-bugs", "priority": 2 }),
+            &ToolContext::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(res["issues"][0]["identifier"], "ENG-1");
+    search.assert_async().await;
+
+    let err = registry
+        .dispatch_json(
+            "linear_search_issues",
+            serde_json::json!({ "preset": "missing" }),
+            &ToolContext::default(),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("configured presets: open-bugs"),
+        "{err}"
+    );
+}
@@ -50,7 +50,7 @@ async fn live_create_search_read_comment_archive() {
     // 1) Create issue
     let created = tools
         .create_issue(
-            team_id.clone(),
+            Some(team_id.clone()),
             format!("Live Test {marker}"),
             Some("Created by live test".into()),
             Some(3),
@@ -87,6 +87,8 @@ async fn live_create_search_read_comment_archive() {
             None,
             None,
             None,
+            None,
+            None,
             Some(10),
             None,
         )
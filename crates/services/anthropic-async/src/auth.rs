@@ -0,0 +1,507 @@
+//! Credential provider chain.
+//!
+//! Credentials are resolved by walking an ordered list of [`TokenProvider`]s and
+//! using the first one that yields a healthy credential. The default order is:
+//!
+//! 1. [`AuthSource::OAuthToken`]: `ANTHROPIC_AUTH_TOKEN` (sent as `Authorization: Bearer`)
+//! 2. [`AuthSource::StoredApiKey`]: API key file (`ANTHROPIC_API_KEY_FILE`, or
+//!    `$XDG_CONFIG_HOME/anthropic/api_key`, or `~/.config/anthropic/api_key`)
+//! 3. [`AuthSource::EnvApiKey`]: `ANTHROPIC_API_KEY` (sent as `x-api-key`)
+//!
+//! The order can be overridden with `ANTHROPIC_AUTH_ORDER` (comma-separated source
+//! names, e.g. `env_api_key,oauth_token`) or explicitly via [`ProviderChain::from_order`].
+//! When no link yields a credential, the error lists every link that was tried and why
+//! it was skipped.
+
+use crate::config::AnthropicAuth;
+use crate::error::AnthropicError;
+use reqwest::header::HeaderValue;
+use secrecy::SecretString;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Environment variable holding an OAuth bearer token.
+pub const ENV_AUTH_TOKEN: &str = "ANTHROPIC_AUTH_TOKEN";
+/// Environment variable holding an API key.
+pub const ENV_API_KEY: &str = "ANTHROPIC_API_KEY";
+/// Environment variable overriding the stored API key file location.
+pub const ENV_API_KEY_FILE: &str = "ANTHROPIC_API_KEY_FILE";
+/// Environment variable overriding provider chain ordering.
+pub const ENV_AUTH_ORDER: &str = "ANTHROPIC_AUTH_ORDER";
+
+/// A link in the credential provider chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AuthSource {
+    /// OAuth bearer token from `ANTHROPIC_AUTH_TOKEN`
+    OAuthToken,
+    /// API key stored on disk
+    StoredApiKey,
+    /// API key from `ANTHROPIC_API_KEY`
+    EnvApiKey,
+}
+
+/// Default provider chain ordering.
+pub const DEFAULT_AUTH_ORDER: [AuthSource; 3] = [
+    AuthSource::OAuthToken,
+    AuthSource::StoredApiKey,
+    AuthSource::EnvApiKey,
+];
+
+impl AuthSource {
+    /// Stable name used in `ANTHROPIC_AUTH_ORDER` and diagnostics.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::OAuthToken => "oauth_token",
+            Self::StoredApiKey => "stored_api_key",
+            Self::EnvApiKey => "env_api_key",
+        }
+    }
+}
+
+impl fmt::Display for AuthSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuthSource {
+    type Err = AnthropicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "oauth_token" | "oauth" => Ok(Self::OAuthToken),
+            "stored_api_key" | "stored" => Ok(Self::StoredApiKey),
+            "env_api_key" | "env" => Ok(Self::EnvApiKey),
+            other => Err(AnthropicError::Config(format!(
+                "Unknown auth source '{other}' (expected oauth_token, stored_api_key, or env_api_key)"
+            ))),
+        }
+    }
+}
+
+/// Outcome of asking a single provider for credentials.
+#[derive(Debug)]
+pub enum ProviderOutcome {
+    /// The provider produced a healthy credential
+    Ready(AnthropicAuth),
+    /// The provider has nothing configured; the chain moves on
+    Missing(String),
+    /// A credential was found but failed its health check; the chain moves on
+    Unhealthy(String),
+}
+
+/// A source of Anthropic credentials.
+pub trait TokenProvider: Send + Sync + fmt::Debug {
+    /// Which chain link this provider represents.
+    fn source(&self) -> AuthSource;
+
+    /// Look up and health-check this provider's credential.
+    fn provide(&self) -> ProviderOutcome;
+}
+
+/// Record of one link the chain tried.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainAttempt {
+    /// The link that was tried
+    pub source: AuthSource,
+    /// Why the link was skipped
+    pub reason: String,
+}
+
+impl fmt::Display for ChainAttempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.source, self.reason)
+    }
+}
+
+/// Credential resolved by a [`ProviderChain`].
+#[derive(Clone, Debug)]
+pub struct ResolvedAuth {
+    /// The link that supplied the credential
+    pub source: AuthSource,
+    /// The credential itself
+    pub auth: AnthropicAuth,
+    /// Earlier links that were tried and skipped
+    pub skipped: Vec<ChainAttempt>,
+}
+
+/// Health check shared by all providers: non-empty and valid as a header value.
+fn check_secret(value: &str, what: &str) -> Result<SecretString, String> {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return Err(format!("{what} is empty"));
+    }
+    if HeaderValue::from_str(trimmed).is_err() {
+        return Err(format!(
+            "{what} contains characters not allowed in HTTP headers"
+        ));
+    }
+    Ok(SecretString::from(trimmed.to_string()))
+}
+
+fn env_outcome(var: &str, wrap: fn(SecretString) -> AnthropicAuth) -> ProviderOutcome {
+    match std::env::var(var) {
+        Ok(v) => match check_secret(&v, var) {
+            Ok(secret) => ProviderOutcome::Ready(wrap(secret)),
+            Err(reason) => ProviderOutcome::Unhealthy(reason),
+        },
+        Err(std::env::VarError::NotPresent) => ProviderOutcome::Missing(format!("{var} not set")),
+        Err(std::env::VarError::NotUnicode(_)) => {
+            ProviderOutcome::Unhealthy(format!("{var} is not valid UTF-8"))
+        }
+    }
+}
+
+/// OAuth bearer token read from an environment variable.
+#[derive(Clone, Debug)]
+pub struct OAuthTokenProvider {
+    var: String,
+}
+
+impl Default for OAuthTokenProvider {
+    fn default() -> Self {
+        Self::from_env_var(ENV_AUTH_TOKEN)
+    }
+}
+
+impl OAuthTokenProvider {
+    /// Read the token from a custom environment variable.
+    #[must_use]
+    pub fn from_env_var(var: impl Into<String>) -> Self {
+        Self { var: var.into() }
+    }
+}
+
+impl TokenProvider for OAuthTokenProvider {
+    fn source(&self) -> AuthSource {
+        AuthSource::OAuthToken
+    }
+
+    fn provide(&self) -> ProviderOutcome {
+        env_outcome(&self.var, AnthropicAuth::Bearer)
+    }
+}
+
+/// API key read from an environment variable.
+#[derive(Clone, Debug)]
+pub struct EnvApiKeyProvider {
+    var: String,
+}
+
+impl Default for EnvApiKeyProvider {
+    fn default() -> Self {
+        Self::from_env_var(ENV_API_KEY)
+    }
+}
+
+impl EnvApiKeyProvider {
+    /// Read the key from a custom environment variable.
+    #[must_use]
+    pub fn from_env_var(var: impl Into<String>) -> Self {
+        Self { var: var.into() }
+    }
+}
+
+impl TokenProvider for EnvApiKeyProvider {
+    fn source(&self) -> AuthSource {
+        AuthSource::EnvApiKey
+    }
+
+    fn provide(&self) -> ProviderOutcome {
+        env_outcome(&self.var, AnthropicAuth::ApiKey)
+    }
+}
+
+/// API key stored in a file (first non-empty line is used).
+#[derive(Clone, Debug)]
+pub struct StoredApiKeyProvider {
+    path: Option<PathBuf>,
+}
+
+impl Default for StoredApiKeyProvider {
+    fn default() -> Self {
+        Self {
+            path: default_api_key_path(),
+        }
+    }
+}
+
+impl StoredApiKeyProvider {
+    /// Read the key from a specific file.
+    #[must_use]
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+}
+
+/// Resolve the default stored API key location.
+fn default_api_key_path() -> Option<PathBuf> {
+    let non_empty = |var: &str| std::env::var(var).ok().filter(|v| !v.trim().is_empty());
+    if let Some(p) = non_empty(ENV_API_KEY_FILE) {
+        return Some(PathBuf::from(p));
+    }
+    let config_dir = non_empty("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| non_empty("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(config_dir.join("anthropic").join("api_key"))
+}
+
+impl TokenProvider for StoredApiKeyProvider {
+    fn source(&self) -> AuthSource {
+        AuthSource::StoredApiKey
+    }
+
+    fn provide(&self) -> ProviderOutcome {
+        let Some(path) = &self.path else {
+            return ProviderOutcome::Missing("no key file location (HOME not set)".into());
+        };
+        match std::fs::read_to_string(path) {
+            Ok(contents) => {
+                let key = contents
+                    .lines()
+                    .map(str::trim)
+                    .find(|l| !l.is_empty())
+                    .unwrap_or_default();
+                match check_secret(key, &path.display().to_string()) {
+                    Ok(secret) => ProviderOutcome::Ready(AnthropicAuth::ApiKey(secret)),
+                    Err(reason) => ProviderOutcome::Unhealthy(reason),
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                ProviderOutcome::Missing(format!("{} not found", path.display()))
+            }
+            Err(e) => ProviderOutcome::Unhealthy(format!("{}: {e}", path.display())),
+        }
+    }
+}
+
+/// Ordered chain of credential providers; the first healthy link wins.
+#[derive(Debug)]
+pub struct ProviderChain {
+    providers: Vec<Box<dyn TokenProvider>>,
+}
+
+impl Default for ProviderChain {
+    fn default() -> Self {
+        Self::from_order(&DEFAULT_AUTH_ORDER)
+    }
+}
+
+impl ProviderChain {
+    /// Create an empty chain; add links with [`with_provider`](Self::with_provider).
+    #[must_use]
+    pub fn empty() -> Self {
+        Self { providers: vec![] }
+    }
+
+    /// Build a chain of the built-in providers in the given order.
+    #[must_use]
+    pub fn from_order(order: &[AuthSource]) -> Self {
+        let mut chain = Self::empty();
+        for source in order {
+            chain = match source {
+                AuthSource::OAuthToken => chain.with_provider(OAuthTokenProvider::default()),
+                AuthSource::StoredApiKey => chain.with_provider(StoredApiKeyProvider::default()),
+                AuthSource::EnvApiKey => chain.with_provider(EnvApiKeyProvider::default()),
+            };
+        }
+        chain
+    }
+
+    /// Build the chain using `ANTHROPIC_AUTH_ORDER`, or the default order if unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `ANTHROPIC_AUTH_ORDER` names an unknown source or is empty.
+    pub fn from_env() -> Result<Self, AnthropicError> {
+        match std::env::var(ENV_AUTH_ORDER) {
+            Ok(raw) if !raw.trim().is_empty() => Ok(Self::from_order(&parse_order(&raw)?)),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Append a provider to the end of the chain.
+    #[must_use]
+    pub fn with_provider(mut self, provider: impl TokenProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// The chain's ordering.
+    #[must_use]
+    pub fn order(&self) -> Vec<AuthSource> {
+        self.providers.iter().map(|p| p.source()).collect()
+    }
+
+    /// Walk the chain and return the first healthy credential.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnthropicError::Config`] listing every link that was tried when none
+    /// produced a usable credential.
+    pub fn resolve(&self) -> Result<ResolvedAuth, AnthropicError> {
+        let mut skipped = Vec::new();
+        for provider in &self.providers {
+            let source = provider.source();
+            match provider.provide() {
+                ProviderOutcome::Ready(auth) => {
+                    tracing::debug!(%source, skipped = skipped.len(), "resolved Anthropic credentials");
+                    return Ok(ResolvedAuth {
+                        source,
+                        auth,
+                        skipped,
+                    });
+                }
+                ProviderOutcome::Missing(reason) | ProviderOutcome::Unhealthy(reason) => {
+                    skipped.push(ChainAttempt { source, reason });
+                }
+            }
+        }
+        Err(AnthropicError::Config(describe_failure(&skipped)))
+    }
+}
+
+/// Parse a comma-separated chain ordering such as `env_api_key,oauth_token`.
+///
+/// # Errors
+///
+/// Returns an error on unknown or duplicate sources, or an empty list.
+pub fn parse_order(raw: &str) -> Result<Vec<AuthSource>, AnthropicError> {
+    let mut order = Vec::new();
+    for part in raw.split(',').filter(|p| !p.trim().is_empty()) {
+        let source: AuthSource = part.parse()?;
+        if order.contains(&source) {
+            return Err(AnthropicError::Config(format!(
+                "Duplicate auth source '{source}' in {ENV_AUTH_ORDER}"
+            )));
+        }
+        order.push(source);
+    }
+    if order.is_empty() {
+        return Err(AnthropicError::Config(format!(
+            "{ENV_AUTH_ORDER} must list at least one auth source"
+        )));
+    }
+    Ok(order)
+}
+
+fn describe_failure(skipped: &[ChainAttempt]) -> String {
+    if skipped.is_empty() {
+        return "Missing Anthropic credentials: the auth provider chain is empty".into();
+    }
+    let tried = skipped
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ");
+    format!(
+        "Missing Anthropic credentials: set {ENV_AUTH_TOKEN} or {ENV_API_KEY}, or store a key in {ENV_API_KEY_FILE}. Tried: {tried}"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+
+    #[derive(Debug)]
+    struct Fixed(AuthSource, fn() -> ProviderOutcome);
+
+    impl TokenProvider for Fixed {
+        fn source(&self) -> AuthSource {
+            self.0
+        }
+
+        fn provide(&self) -> ProviderOutcome {
+            (self.1)()
+        }
+    }
+
+    fn ready_key() -> ProviderOutcome {
+        ProviderOutcome::Ready(AnthropicAuth::ApiKey(SecretString::from("k")))
+    }
+
+    fn missing() -> ProviderOutcome {
+        ProviderOutcome::Missing("not set".into())
+    }
+
+    fn unhealthy() -> ProviderOutcome {
+        ProviderOutcome::Unhealthy("is empty".into())
+    }
+
+    #[test]
+    fn first_healthy_link_wins() {
+        let chain = ProviderChain::empty()
+            .with_provider(Fixed(AuthSource::OAuthToken, missing))
+            .with_provider(Fixed(AuthSource::StoredApiKey, unhealthy))
+            .with_provider(Fixed(AuthSource::EnvApiKey, ready_key));
+
+        let resolved = chain.resolve().unwrap();
+        assert_eq!(resolved.source, AuthSource::EnvApiKey);
+        assert_eq!(resolved.skipped.len(), 2);
+        assert!(matches!(resolved.auth, AnthropicAuth::ApiKey(_)));
+    }
+
+    #[test]
+    fn failure_lists_every_link_tried() {
+        let chain = ProviderChain::empty()
+            .with_provider(Fixed(AuthSource::OAuthToken, missing))
+            .with_provider(Fixed(AuthSource::EnvApiKey, unhealthy));
+
+        let err = chain.resolve().unwrap_err().to_string();
+        assert!(err.contains("oauth_token (not set)"), "{err}");
+        assert!(err.contains("env_api_key (is empty)"), "{err}");
+    }
+
+    #[test]
+    fn parse_order_accepts_names_and_rejects_bad_input() {
+        assert_eq!(
+            parse_order("env_api_key, oauth_token").unwrap(),
+            vec![AuthSource::EnvApiKey, AuthSource::OAuthToken]
+        );
+        assert!(parse_order("env_api_key,bogus").is_err());
+        assert!(parse_order("env,env_api_key").is_err());
+        assert!(parse_order(" , ").is_err());
+    }
+
+    #[test]
+    fn from_order_preserves_ordering() {
+        let order = [AuthSource::StoredApiKey, AuthSource::OAuthToken];
+        assert_eq!(ProviderChain::from_order(&order).order(), order);
+    }
+
+    #[test]
+    fn stored_key_provider_reads_first_line() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("api_key");
+        std::fs::write(&path, "\n  sk-stored  \n# trailing\n").unwrap();
+
+        match StoredApiKeyProvider::from_path(&path).provide() {
+            ProviderOutcome::Ready(AnthropicAuth::ApiKey(k)) => {
+                assert_eq!(k.expose_secret(), "sk-stored");
+            }
+            other => panic!("expected stored key, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn stored_key_provider_health_checks() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let missing_path = dir.path().join("missing");
+        assert!(matches!(
+            StoredApiKeyProvider::from_path(&missing_path).provide(),
+            ProviderOutcome::Missing(_)
+        ));
+
+        let empty = dir.path().join("empty");
+        std::fs::write(&empty, "   \n").unwrap();
+        assert!(matches!(
+            StoredApiKeyProvider::from_path(&empty).provide(),
+            ProviderOutcome::Unhealthy(_)
+        ));
+    }
+}
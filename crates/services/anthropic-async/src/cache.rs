@@ -0,0 +1,588 @@
+//! Automatic prompt-cache breakpoint placement
+//!
+//! The API caches a request's prefix up to each block marked with
+//! [`CacheControl`], reading the request in the order tools → system → messages,
+//! and accepts at most [`MAX_BREAKPOINTS`] markers. [`CachePlanner`] places those
+//! markers on the boundaries that stay stable between calls: the end of the system
+//! prompt, the end of the conversation so far, the end of the previous turn, and
+//! the end of the tool definitions.
+//!
+//! ```
+//! use anthropic_async::cache::CachePlanner;
+//! use anthropic_async::types::content::{MessageParam, MessageRole};
+//! use anthropic_async::types::messages::MessagesCreateRequest;
+//!
+//! let mut req = MessagesCreateRequest {
+//!     model: "claude-sonnet-4-6".into(),
+//!     max_tokens: 1024,
+//!     system: Some("You review Rust code.\n".repeat(400).into()),
+//!     messages: vec![MessageParam {
+//!         role: MessageRole::User,
+//!         content: "Review this diff.".into(),
+//!     }],
+//!     ..Default::default()
+//! };
+//!
+//! let plan = CachePlanner::new().apply(&mut req);
+//! assert_eq!(plan.added.len(), 2);
+//! assert!(plan.cacheable_bytes > 8_000);
+//! ```
+
+use serde::Serialize;
+
+use crate::types::common::CacheControl;
+use crate::types::common::CacheTtl;
+use crate::types::common::validate_mixed_ttl_order;
+use crate::types::content::ContentBlockParam;
+use crate::types::content::MessageContentParam;
+use crate::types::content::SystemParam;
+use crate::types::content::TextBlockParam;
+use crate::types::content::ToolResultContent;
+use crate::types::content::ToolResultContentBlock;
+use crate::types::messages::MessagesCreateRequest;
+
+/// Most `cache_control` markers the API accepts in one request
+pub const MAX_BREAKPOINTS: usize = 4;
+
+/// Smallest prefix worth a breakpoint by default, about 1024 tokens
+///
+/// Shorter prefixes are below the minimum the API caches for Sonnet and Opus, so
+/// a marker on them would use up a breakpoint without caching anything.
+pub const DEFAULT_MIN_PREFIX_BYTES: usize = 4_096;
+
+/// Where a breakpoint sits in a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BreakpointLocation {
+    /// Tool definition at this index
+    Tool(usize),
+    /// System prompt block at this index
+    System(usize),
+    /// Content block `block` of message `message`
+    Message {
+        /// Index into `messages`
+        message: usize,
+        /// Index into the message's content blocks
+        block: usize,
+    },
+}
+
+/// A `cache_control` marker and the size of the prefix it caches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CacheBreakpoint {
+    /// Block carrying the marker
+    pub location: BreakpointLocation,
+    /// Serialized bytes of the request up to and including the block
+    pub prefix_bytes: usize,
+}
+
+/// What [`CachePlanner::apply`] did to a request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CachePlan {
+    /// Markers the planner added, in request order
+    pub added: Vec<CacheBreakpoint>,
+    /// Markers the request already had, in request order
+    pub existing: Vec<CacheBreakpoint>,
+    /// Bytes covered by the furthest marker, i.e. the most a cache hit can skip
+    pub cacheable_bytes: usize,
+    /// Serialized bytes of all tools, system blocks, and message blocks
+    pub total_bytes: usize,
+}
+
+/// Places `cache_control` markers on a [`MessagesCreateRequest`]
+///
+/// Markers already in the request are kept and count against the limit. A
+/// boundary is skipped when its prefix is shorter than the minimum, when the block
+/// can't carry a marker (thinking blocks), or when a marker there would break the
+/// rule that 1-hour entries come before 5-minute ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePlanner {
+    cache_control: CacheControl,
+    min_prefix_bytes: usize,
+    max_breakpoints: usize,
+}
+
+impl Default for CachePlanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CachePlanner {
+    /// Creates a planner adding 5-minute markers to prefixes of at least
+    /// [`DEFAULT_MIN_PREFIX_BYTES`]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cache_control: CacheControl::ephemeral(),
+            min_prefix_bytes: DEFAULT_MIN_PREFIX_BYTES,
+            max_breakpoints: MAX_BREAKPOINTS,
+        }
+    }
+
+    /// Sets the marker added at each breakpoint, e.g. [`CacheControl::ephemeral_1h`]
+    #[must_use]
+    pub fn with_cache_control(mut self, cache_control: CacheControl) -> Self {
+        self.cache_control = cache_control;
+        self
+    }
+
+    /// Sets the smallest prefix, in serialized bytes, that gets a breakpoint
+    ///
+    /// Haiku models need about twice the default before anything is cached.
+    #[must_use]
+    pub const fn with_min_prefix_bytes(mut self, min_prefix_bytes: usize) -> Self {
+        self.min_prefix_bytes = min_prefix_bytes;
+        self
+    }
+
+    /// Caps the markers in the request, counting existing ones
+    ///
+    /// Values above [`MAX_BREAKPOINTS`] are treated as [`MAX_BREAKPOINTS`].
+    #[must_use]
+    pub fn with_max_breakpoints(mut self, max_breakpoints: usize) -> Self {
+        self.max_breakpoints = max_breakpoints.min(MAX_BREAKPOINTS);
+        self
+    }
+
+    /// Adds markers to `req` and reports where they went
+    ///
+    /// A string system prompt or message content becomes a single text block when
+    /// it receives a marker.
+    pub fn apply(&self, req: &mut MessagesCreateRequest) -> CachePlan {
+        let layout = Layout::of(req);
+        let slots = &layout.slots;
+        let ttl = self
+            .cache_control
+            .ttl
+            .clone()
+            .unwrap_or(CacheTtl::FiveMinutes);
+
+        let mut marked: Vec<Option<CacheTtl>> = slots.iter().map(|s| s.marker.clone()).collect();
+        let mut budget = self
+            .max_breakpoints
+            .saturating_sub(marked.iter().flatten().count());
+
+        let last_message_start = layout.message_starts.last().copied();
+        let candidates = [
+            // Tools and system prompt, the prefix shared by every call
+            layout.last_cacheable(0..layout.system_end),
+            // The whole conversation, read back by the next turn
+            layout.last_cacheable(layout.system_end..slots.len()),
+            // Everything before the newest message, written by the previous turn
+            last_message_start.and_then(|start| layout.last_cacheable(layout.system_end..start)),
+            // Tool definitions alone, which survive system prompt edits
+            layout.last_cacheable(0..layout.tools_end),
+        ];
+
+        let mut added = Vec::new();
+        for idx in candidates.into_iter().flatten() {
+            if budget == 0 {
+                break;
+            }
+            if marked[idx].is_some() || slots[idx].prefix_bytes < self.min_prefix_bytes {
+                continue;
+            }
+            marked[idx] = Some(ttl.clone());
+            if !validate_mixed_ttl_order(marked.iter().flatten().cloned()) {
+                marked[idx] = None;
+                continue;
+            }
+            budget -= 1;
+            added.push(idx);
+        }
+        added.sort_unstable();
+
+        for &idx in &added {
+            mark(req, slots[idx].location, self.cache_control.clone());
+        }
+
+        let breakpoint = |idx: usize| CacheBreakpoint {
+            location: slots[idx].location,
+            prefix_bytes: slots[idx].prefix_bytes,
+        };
+        let existing: Vec<CacheBreakpoint> = slots
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.marker.is_some())
+            .map(|(idx, _)| breakpoint(idx))
+            .collect();
+        let added: Vec<CacheBreakpoint> = added.into_iter().map(breakpoint).collect();
+        let cacheable_bytes = existing
+            .iter()
+            .chain(&added)
+            .map(|b| b.prefix_bytes)
+            .max()
+            .unwrap_or(0);
+
+        CachePlan {
+            added,
+            existing,
+            cacheable_bytes,
+            total_bytes: slots.last().map_or(0, |s| s.prefix_bytes),
+        }
+    }
+}
+
+/// A block that could carry a marker, in request order
+struct Slot {
+    location: BreakpointLocation,
+    /// Serialized bytes up to and including this block
+    prefix_bytes: usize,
+    cacheable: bool,
+    /// TTL of the marker already on the block
+    marker: Option<CacheTtl>,
+}
+
+struct Layout {
+    slots: Vec<Slot>,
+    tools_end: usize,
+    system_end: usize,
+    message_starts: Vec<usize>,
+}
+
+impl Layout {
+    fn of(req: &MessagesCreateRequest) -> Self {
+        let mut layout = Self {
+            slots: Vec::new(),
+            tools_end: 0,
+            system_end: 0,
+            message_starts: Vec::with_capacity(req.messages.len()),
+        };
+
+        for (i, tool) in req.tools.iter().flatten().enumerate() {
+            layout.push(
+                BreakpointLocation::Tool(i),
+                json_len(tool),
+                true,
+                tool.cache_control.as_ref(),
+            );
+        }
+        layout.tools_end = layout.slots.len();
+
+        match &req.system {
+            Some(SystemParam::String(text)) => {
+                layout.push(BreakpointLocation::System(0), json_len(text), true, None);
+            }
+            Some(SystemParam::Blocks(blocks)) => {
+                for (i, block) in blocks.iter().enumerate() {
+                    layout.push(
+                        BreakpointLocation::System(i),
+                        json_len(block),
+                        true,
+                        block.cache_control.as_ref(),
+                    );
+                }
+            }
+            None => {}
+        }
+        layout.system_end = layout.slots.len();
+
+        for (message, param) in req.messages.iter().enumerate() {
+            layout.message_starts.push(layout.slots.len());
+            match &param.content {
+                MessageContentParam::String(text) => layout.push(
+                    BreakpointLocation::Message { message, block: 0 },
+                    json_len(text),
+                    true,
+                    None,
+                ),
+                MessageContentParam::Blocks(blocks) => {
+                    for (block, content) in blocks.iter().enumerate() {
+                        let cacheable = !matches!(
+                            content,
+                            ContentBlockParam::Thinking { .. }
+                                | ContentBlockParam::RedactedThinking { .. }
+                        );
+                        layout.push(
+                            BreakpointLocation::Message { message, block },
+                            json_len(content),
+                            cacheable,
+                            block_cache_control(content),
+                        );
+                    }
+                }
+            }
+        }
+        layout
+    }
+
+    fn push(
+        &mut self,
+        location: BreakpointLocation,
+        bytes: usize,
+        cacheable: bool,
+        marker: Option<&CacheControl>,
+    ) {
+        let prefix_bytes = self.slots.last().map_or(0, |s| s.prefix_bytes) + bytes;
+        self.slots.push(Slot {
+            location,
+            prefix_bytes,
+            cacheable,
+            marker: marker.map(|cc| cc.ttl.clone().unwrap_or(CacheTtl::FiveMinutes)),
+        });
+    }
+
+    /// Index of the last slot in `range` that can carry a marker
+    fn last_cacheable(&self, range: std::ops::Range<usize>) -> Option<usize> {
+        let start = range.start;
+        self.slots[range]
+            .iter()
+            .rposition(|s| s.cacheable)
+            .map(|i| start + i)
+    }
+}
+
+fn json_len<T: Serialize + ?Sized>(value: &T) -> usize {
+    serde_json::to_vec(value).map_or(0, |v| v.len())
+}
+
+/// The marker on a block, including one nested in a tool result
+fn block_cache_control(block: &ContentBlockParam) -> Option<&CacheControl> {
+    match block {
+        ContentBlockParam::Text { cache_control, .. }
+        | ContentBlockParam::Image { cache_control, .. }
+        | ContentBlockParam::Document { cache_control, .. }
+        | ContentBlockParam::ToolUse { cache_control, .. }
+        | ContentBlockParam::ServerToolUse { cache_control, .. }
+        | ContentBlockParam::SearchResult { cache_control, .. }
+        | ContentBlockParam::WebSearchToolResult { cache_control, .. } => cache_control.as_ref(),
+        ContentBlockParam::ToolResult {
+            cache_control,
+            content,
+            ..
+        } => cache_control.as_ref().or_else(|| match content {
+            Some(ToolResultContent::Blocks(blocks)) => blocks.iter().find_map(|b| match b {
+                ToolResultContentBlock::Text { cache_control, .. }
+                | ToolResultContentBlock::Image { cache_control, .. } => cache_control.as_ref(),
+            }),
+            _ => None,
+        }),
+        ContentBlockParam::Thinking { .. } | ContentBlockParam::RedactedThinking { .. } => None,
+    }
+}
+
+fn mark(req: &mut MessagesCreateRequest, location: BreakpointLocation, cc: CacheControl) {
+    match location {
+        BreakpointLocation::Tool(i) => {
+            if let Some(tool) = req.tools.as_mut().and_then(|tools| tools.get_mut(i)) {
+                tool.cache_control = Some(cc);
+            }
+        }
+        BreakpointLocation::System(i) => {
+            if let Some(SystemParam::String(text)) = &mut req.system {
+                req.system = Some(SystemParam::Blocks(vec![TextBlockParam::new(
+                    std::mem::take(text),
+                )]));
+            }
+            if let Some(SystemParam::Blocks(blocks)) = &mut req.system
+                && let Some(block) = blocks.get_mut(i)
+            {
+                block.cache_control = Some(cc);
+            }
+        }
+        BreakpointLocation::Message { message, block } => {
+            let Some(param) = req.messages.get_mut(message) else {
+                return;
+            };
+            if let MessageContentParam::String(text) = &mut param.content {
+                param.content = MessageContentParam::Blocks(vec![ContentBlockParam::Text {
+                    text: std::mem::take(text),
+                    citations: None,
+                    cache_control: None,
+                }]);
+            }
+            if let MessageContentParam::Blocks(blocks) = &mut param.content
+                && let Some(block) = blocks.get_mut(block)
+            {
+                match block {
+                    ContentBlockParam::Text { cache_control, .. }
+                    | ContentBlockParam::Image { cache_control, .. }
+                    | ContentBlockParam::Document { cache_control, .. }
+                    | ContentBlockParam::ToolUse { cache_control, .. }
+                    | ContentBlockParam::ServerToolUse { cache_control, .. }
+                    | ContentBlockParam::SearchResult { cache_control, .. }
+                    | ContentBlockParam::WebSearchToolResult { cache_control, .. }
+                    | ContentBlockParam::ToolResult { cache_control, .. } => {
+                        *cache_control = Some(cc);
+                    }
+                    ContentBlockParam::Thinking { .. }
+                    | ContentBlockParam::RedactedThinking { .. } => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::content::MessageParam;
+    use crate::types::content::MessageRole;
+    use crate::types::tools::Tool;
+
+    fn text(len: usize) -> String {
+        "x".repeat(len)
+    }
+
+    fn message(role: MessageRole, len: usize) -> MessageParam {
+        MessageParam {
+            role,
+            content: text(len).into(),
+        }
+    }
+
+    fn tool(name: &str, description_len: usize) -> Tool {
+        Tool {
+            name: name.into(),
+            description: Some(text(description_len)),
+            input_schema: serde_json::json!({"type": "object"}),
+            cache_control: None,
+            strict: None,
+        }
+    }
+
+    fn request() -> MessagesCreateRequest {
+        MessagesCreateRequest {
+            model: "claude-sonnet-4-6".into(),
+            max_tokens: 64,
+            tools: Some(vec![tool("a", 100), tool("b", 4_000)]),
+            system: Some(text(2_000).into()),
+            messages: vec![
+                message(MessageRole::User, 1_000),
+                message(MessageRole::Assistant, 1_000),
+                message(MessageRole::User, 100),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn locations(breakpoints: &[CacheBreakpoint]) -> Vec<BreakpointLocation> {
+        breakpoints.iter().map(|b| b.location).collect()
+    }
+
+    #[test]
+    fn marks_stable_boundaries_in_request_order() {
+        let mut req = request();
+        let plan = CachePlanner::new().apply(&mut req);
+
+        assert_eq!(
+            locations(&plan.added),
+            vec![
+                BreakpointLocation::Tool(1),
+                BreakpointLocation::System(0),
+                BreakpointLocation::Message {
+                    message: 1,
+                    block: 0
+                },
+                BreakpointLocation::Message {
+                    message: 2,
+                    block: 0
+                },
+            ]
+        );
+        assert!(plan.existing.is_empty());
+        assert_eq!(plan.cacheable_bytes, plan.total_bytes);
+        assert!(
+            plan.added
+                .windows(2)
+                .all(|w| w[0].prefix_bytes < w[1].prefix_bytes)
+        );
+
+        assert!(req.tools.as_ref().unwrap()[1].cache_control.is_some());
+        assert!(req.tools.as_ref().unwrap()[0].cache_control.is_none());
+        let Some(SystemParam::Blocks(system)) = &req.system else {
+            panic!("system prompt should be converted to blocks");
+        };
+        assert_eq!(system[0].cache_control, Some(CacheControl::ephemeral()));
+        let MessageContentParam::Blocks(blocks) = &req.messages[2].content else {
+            panic!("last message should be converted to blocks");
+        };
+        assert!(block_cache_control(&blocks[0]).is_some());
+        assert!(matches!(
+            req.messages[0].content,
+            MessageContentParam::String(_)
+        ));
+    }
+
+    #[test]
+    fn skips_short_prefixes() {
+        let mut req = MessagesCreateRequest {
+            system: Some("short".into()),
+            messages: vec![message(MessageRole::User, 10)],
+            ..request()
+        };
+        req.tools = None;
+        let plan = CachePlanner::new().apply(&mut req);
+        assert!(plan.added.is_empty());
+        assert_eq!(plan.cacheable_bytes, 0);
+        assert!(matches!(req.system, Some(SystemParam::String(_))));
+
+        let plan = CachePlanner::new().with_min_prefix_bytes(0).apply(&mut req);
+        assert_eq!(plan.added.len(), 2);
+    }
+
+    #[test]
+    fn existing_markers_count_against_the_limit() {
+        let mut req = request();
+        req.tools.as_mut().unwrap()[0].cache_control = Some(CacheControl::ephemeral_5m());
+        let plan = CachePlanner::new().with_min_prefix_bytes(0).apply(&mut req);
+
+        assert_eq!(locations(&plan.existing), vec![BreakpointLocation::Tool(0)]);
+        // System, whole conversation, previous turn; tools end no longer fits
+        assert_eq!(plan.added.len(), 3);
+        assert!(req.tools.as_ref().unwrap()[1].cache_control.is_none());
+
+        let again = CachePlanner::new().apply(&mut req);
+        assert!(again.added.is_empty());
+        assert_eq!(again.existing.len(), MAX_BREAKPOINTS);
+    }
+
+    #[test]
+    fn keeps_one_hour_markers_ahead_of_five_minute_ones() {
+        let mut req = request();
+        let MessageContentParam::String(first) = req.messages[0].content.clone() else {
+            unreachable!()
+        };
+        req.messages[0].content = MessageContentParam::Blocks(vec![ContentBlockParam::Text {
+            text: first,
+            citations: None,
+            cache_control: Some(CacheControl::ephemeral_5m()),
+        }]);
+
+        let plan = CachePlanner::new()
+            .with_cache_control(CacheControl::ephemeral_1h())
+            .apply(&mut req);
+        assert_eq!(
+            locations(&plan.added),
+            vec![BreakpointLocation::Tool(1), BreakpointLocation::System(0)]
+        );
+    }
+
+    #[test]
+    fn thinking_blocks_are_not_marked() {
+        let mut req = request();
+        req.messages[2].content = MessageContentParam::Blocks(vec![
+            ContentBlockParam::Text {
+                text: text(100),
+                citations: None,
+                cache_control: None,
+            },
+            ContentBlockParam::Thinking {
+                thinking: text(100),
+                signature: "sig".into(),
+            },
+        ]);
+        let plan = CachePlanner::new().with_max_breakpoints(2).apply(&mut req);
+        assert_eq!(
+            locations(&plan.added),
+            vec![
+                BreakpointLocation::System(0),
+                BreakpointLocation::Message {
+                    message: 2,
+                    block: 0
+                },
+            ]
+        );
+        assert!(plan.cacheable_bytes < plan.total_bytes);
+    }
+}
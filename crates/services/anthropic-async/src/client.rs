@@ -1,11 +1,28 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicU16;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
 use backon::ExponentialBuilder;
 use backon::Retryable;
+use reqwest::Method;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use crate::config::Config;
+use crate::config::HDR_ANTHROPIC_BETA;
 use crate::error::AnthropicError;
+use crate::middleware::Interceptor;
+use crate::middleware::Interceptors;
+use crate::middleware::RequestEvent;
+use crate::middleware::ResponseEvent;
 use crate::retry;
+use crate::retry::CircuitBreaker;
+use crate::retry::RetryBudget;
 
 /// Anthropic API client
 ///
@@ -16,6 +33,9 @@ pub struct Client<C: Config> {
     http: reqwest::Client,
     config: C,
     backoff: ExponentialBuilder,
+    interceptors: Interceptors,
+    retry_budget: Option<Arc<RetryBudget>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
 }
 
 impl Client<crate::config::AnthropicConfig> {
@@ -56,6 +76,9 @@ impl<C: Config> Client<C> {
                 .expect("reqwest client"),
             config,
             backoff: retry::default_backoff_builder(),
+            interceptors: Interceptors::default(),
+            retry_budget: None,
+            circuit_breaker: None,
         }
     }
 
@@ -77,6 +100,36 @@ impl<C: Config> Client<C> {
         self
     }
 
+    /// Adds an interceptor called around every API call
+    ///
+    /// Interceptors run in the order they were added. Keep a clone of the `Arc` to
+    /// read back whatever the interceptor collects.
+    #[must_use]
+    pub fn with_interceptor(mut self, interceptor: Arc<dyn Interceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Caps retries with a budget shared by every call on this client and its clones
+    ///
+    /// Once the budget is spent, failed calls return their error instead of
+    /// retrying until successful calls earn retries back.
+    #[must_use]
+    pub fn with_retry_budget(mut self, budget: Arc<RetryBudget>) -> Self {
+        self.retry_budget = Some(budget);
+        self
+    }
+
+    /// Fails calls fast while the API keeps reporting itself overloaded
+    ///
+    /// Keep a clone of the `Arc` to read [`CircuitBreaker::state`], or register a
+    /// listener on the breaker before adding it.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
     /// Returns a reference to the client's configuration
     #[must_use]
     pub const fn config(&self) -> &C {
@@ -93,7 +146,7 @@ impl<C: Config> Client<C> {
                 .query(&self.config.query())
                 .build()?)
         };
-        self.execute(mk).await
+        self.execute(Method::GET, path, mk).await
     }
 
     pub(crate) async fn get_with_query<Q, O>(
@@ -115,7 +168,40 @@ impl<C: Config> Client<C> {
                 .query(query)
                 .build()?)
         };
-        self.execute(mk).await
+        self.execute(Method::GET, path, mk).await
+    }
+
+    /// Sends a GET request and returns the raw response body, for non-JSON payloads
+    pub(crate) async fn get_bytes(&self, path: &str) -> Result<bytes::Bytes, AnthropicError> {
+        // Validate auth before any request
+        self.config.validate_auth()?;
+
+        let mk = || async {
+            let headers = self.config.headers()?;
+            Ok(self
+                .http
+                .get(self.config.url(path))
+                .headers(headers)
+                .query(&self.config.query())
+                .build()?)
+        };
+        self.execute_raw(Method::GET, path, mk).await
+    }
+
+    pub(crate) async fn delete<O: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<O, AnthropicError> {
+        let mk = || async {
+            let headers = self.config.headers()?;
+            Ok(self
+                .http
+                .delete(self.config.url(path))
+                .headers(headers)
+                .query(&self.config.query())
+                .build()?)
+        };
+        self.execute(Method::DELETE, path, mk).await
     }
 
     pub(crate) async fn post<I, O>(&self, path: &str, body: I) -> Result<O, AnthropicError>
@@ -133,7 +219,39 @@ impl<C: Config> Client<C> {
                 .json(&body)
                 .build()?)
         };
-        self.execute(mk).await
+        self.execute(Method::POST, path, mk).await
+    }
+
+    /// Sends a request with `beta` added to the `anthropic-beta` header
+    ///
+    /// For endpoints that only work under a beta. `customize` adds the query or
+    /// body and runs again for every retry. Returns the raw response body.
+    pub(crate) async fn execute_with_beta<F>(
+        &self,
+        method: Method,
+        path: &str,
+        beta: &str,
+        customize: F,
+    ) -> Result<bytes::Bytes, AnthropicError>
+    where
+        F: Fn(reqwest::RequestBuilder) -> Result<reqwest::RequestBuilder, AnthropicError>
+            + Send
+            + Sync,
+    {
+        // Validate auth before any request
+        self.config.validate_auth()?;
+
+        let mk = || async {
+            let mut headers = self.config.headers()?;
+            add_beta(&mut headers, beta)?;
+            let req = self
+                .http
+                .request(method.clone(), self.config.url(path))
+                .headers(headers)
+                .query(&self.config.query());
+            Ok(customize(req)?.build()?)
+        };
+        self.execute_raw(method.clone(), path, mk).await
     }
 
     /// Sends a POST request and returns the raw response for streaming.
@@ -157,22 +275,102 @@ impl<C: Config> Client<C> {
             .json(&body)
             .build()?;
 
-        let response = self
-            .http
-            .execute(request)
-            .await
-            .map_err(AnthropicError::Reqwest)?;
-
-        let status = response.status();
-        if status.is_success() {
-            Ok(response)
-        } else {
-            let bytes = response.bytes().await.map_err(AnthropicError::Reqwest)?;
-            Err(crate::error::deserialize_api_error(status, &bytes))
+        let method = Method::POST;
+        self.interceptors.on_request(&RequestEvent {
+            method: &method,
+            path,
+        });
+        let started = Instant::now();
+        let (status, result) = match self.admit() {
+            Ok(probe) => {
+                let outcome = self.send_stream(request).await;
+                if probe {
+                    self.release_probe();
+                }
+                outcome
+            }
+            Err(e) => (None, Err(e)),
+        };
+        self.interceptors.on_response(&ResponseEvent {
+            method: &method,
+            path,
+            status,
+            latency: started.elapsed(),
+            retries: 0,
+            usage: None,
+            error: result.as_ref().err(),
+        });
+        result
+    }
+
+    #[cfg(feature = "streaming")]
+    async fn send_stream(
+        &self,
+        request: reqwest::Request,
+    ) -> (
+        Option<StatusCode>,
+        Result<reqwest::Response, AnthropicError>,
+    ) {
+        match self.http.execute(request).await {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_success() {
+                    self.record_response(None);
+                    (Some(status), Ok(response))
+                } else {
+                    let result = match response.bytes().await {
+                        Ok(bytes) => Err(crate::error::deserialize_api_error(status, &bytes)),
+                        Err(e) => Err(AnthropicError::Reqwest(e)),
+                    };
+                    self.record_response(result.as_ref().err());
+                    (Some(status), result)
+                }
+            }
+            Err(e) => (None, Err(AnthropicError::Reqwest(e))),
         }
     }
 
-    async fn execute<O, M, Fut>(&self, mk: M) -> Result<O, AnthropicError>
+    /// Asks the circuit breaker, if any, to let a call through
+    ///
+    /// Returns whether the call is the breaker's half-open probe.
+    fn admit(&self) -> Result<bool, AnthropicError> {
+        self.circuit_breaker
+            .as_ref()
+            .map_or(Ok(false), |breaker| breaker.acquire())
+    }
+
+    fn release_probe(&self) {
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.release_probe();
+        }
+    }
+
+    /// Tells the circuit breaker an attempt got a response, failing with `error`
+    fn record_response(&self, error: Option<&AnthropicError>) {
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.record(error.is_some_and(AnthropicError::is_overloaded));
+        }
+    }
+
+    /// Whether a failed attempt may be retried, spending from the retry budget
+    fn should_retry(&self, error: &AnthropicError) -> bool {
+        error.is_retryable()
+            && self
+                .circuit_breaker
+                .as_ref()
+                .is_none_or(|breaker| breaker.allows_retry())
+            && self
+                .retry_budget
+                .as_ref()
+                .is_none_or(|budget| budget.try_withdraw())
+    }
+
+    async fn execute<O, M, Fut>(
+        &self,
+        method: Method,
+        path: &str,
+        mk: M,
+    ) -> Result<O, AnthropicError>
     where
         O: DeserializeOwned,
         M: Fn() -> Fut + Send + Sync,
@@ -181,37 +379,126 @@ impl<C: Config> Client<C> {
         // Validate auth before any request
         self.config.validate_auth()?;
 
-        let bytes = self.execute_raw(mk).await?;
+        let bytes = self.execute_raw(method, path, mk).await?;
         let resp: O =
             serde_json::from_slice(&bytes).map_err(|e| crate::error::map_deser(&e, &bytes))?;
         Ok(resp)
     }
 
-    async fn execute_raw<M, Fut>(&self, mk: M) -> Result<bytes::Bytes, AnthropicError>
+    async fn execute_raw<M, Fut>(
+        &self,
+        method: Method,
+        path: &str,
+        mk: M,
+    ) -> Result<bytes::Bytes, AnthropicError>
     where
         M: Fn() -> Fut + Send + Sync,
         Fut: core::future::Future<Output = Result<reqwest::Request, AnthropicError>> + Send,
     {
         let http_client = self.http.clone();
 
-        (|| async {
-            let request = mk().await?;
-            let response = http_client
-                .execute(request)
-                .await
-                .map_err(AnthropicError::Reqwest)?;
+        self.interceptors.on_request(&RequestEvent {
+            method: &method,
+            path,
+        });
+        let started = Instant::now();
+        // Attempt count and last HTTP status (0 when no response arrived)
+        let attempts = AtomicU32::new(0);
+        let last_status = AtomicU16::new(0);
 
-            let status = response.status();
-            let bytes = response.bytes().await.map_err(AnthropicError::Reqwest)?;
+        let result = match self.admit() {
+            Ok(probe) => {
+                let result = (|| async {
+                    attempts.fetch_add(1, Ordering::Relaxed);
+                    last_status.store(0, Ordering::Relaxed);
+                    let request = mk().await?;
+                    let response = http_client
+                        .execute(request)
+                        .await
+                        .map_err(AnthropicError::Reqwest)?;
 
-            if status.is_success() {
-                return Ok(bytes);
+                    let status = response.status();
+                    last_status.store(status.as_u16(), Ordering::Relaxed);
+                    let bytes = response.bytes().await.map_err(AnthropicError::Reqwest)?;
+
+                    let result = if status.is_success() {
+                        Ok(bytes)
+                    } else {
+                        Err(crate::error::deserialize_api_error(status, &bytes))
+                    };
+                    self.record_response(result.as_ref().err());
+                    result
+                })
+                .retry(self.backoff)
+                .when(|e| self.should_retry(e))
+                .await;
+
+                if probe {
+                    self.release_probe();
+                }
+                if result.is_ok()
+                    && let Some(budget) = &self.retry_budget
+                {
+                    budget.deposit();
+                }
+                result
             }
+            Err(e) => Err(e),
+        };
+
+        if !self.interceptors.is_empty() {
+            let usage = result
+                .as_ref()
+                .ok()
+                .and_then(|body| crate::middleware::usage_from_body(body));
+            self.interceptors.on_response(&ResponseEvent {
+                method: &method,
+                path,
+                status: StatusCode::from_u16(last_status.into_inner()).ok(),
+                latency: started.elapsed(),
+                retries: attempts.into_inner().saturating_sub(1),
+                usage: usage.as_ref(),
+                error: result.as_ref().err(),
+            });
+        }
+        result
+    }
+}
+
+/// Adds `beta` to the `anthropic-beta` header unless it is already listed
+fn add_beta(headers: &mut HeaderMap, beta: &str) -> Result<(), AnthropicError> {
+    let value = match headers.get(HDR_ANTHROPIC_BETA).map(HeaderValue::to_str) {
+        Some(Ok(existing)) if existing.split(',').any(|b| b.trim() == beta) => return Ok(()),
+        Some(Ok(existing)) => format!("{existing},{beta}"),
+        _ => beta.to_string(),
+    };
+    headers.insert(
+        HDR_ANTHROPIC_BETA,
+        HeaderValue::from_str(&value)
+            .map_err(|_| AnthropicError::Config("Invalid anthropic-beta header".into()))?,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_beta_merges_without_duplicates() {
+        let mut headers = HeaderMap::new();
+        add_beta(&mut headers, "files-api-2025-04-14").unwrap();
+        assert_eq!(headers[HDR_ANTHROPIC_BETA], "files-api-2025-04-14");
 
-            Err(crate::error::deserialize_api_error(status, &bytes))
-        })
-        .retry(self.backoff)
-        .when(AnthropicError::is_retryable)
-        .await
+        headers.insert(
+            HDR_ANTHROPIC_BETA,
+            HeaderValue::from_static("prompt-caching-2024-07-31"),
+        );
+        add_beta(&mut headers, "files-api-2025-04-14").unwrap();
+        add_beta(&mut headers, "files-api-2025-04-14").unwrap();
+        assert_eq!(
+            headers[HDR_ANTHROPIC_BETA],
+            "prompt-caching-2024-07-31,files-api-2025-04-14"
+        );
     }
 }
@@ -1,3 +1,5 @@
+use crate::auth::AuthSource;
+use crate::auth::ProviderChain;
 use reqwest::header::AUTHORIZATION;
 use reqwest::header::HeaderMap;
 use reqwest::header::HeaderValue;
@@ -51,6 +53,12 @@ pub struct AnthropicConfig {
     /// Skip auth validation (for proxy/testing scenarios where auth is handled externally)
     #[serde(skip)]
     dangerously_skip_auth: bool,
+    /// Provider chain link that supplied `auth`, if resolved via the chain
+    #[serde(skip)]
+    auth_source: Option<AuthSource>,
+    /// Why the provider chain failed, reported by `validate_auth`
+    #[serde(skip)]
+    auth_error: Option<String>,
 }
 
 /// Helper to read and normalize an env var (trim + filter empty).
@@ -63,27 +71,25 @@ fn env_trimmed(name: &str) -> Option<String> {
 
 impl Default for AnthropicConfig {
     fn default() -> Self {
-        let api_key = env_trimmed("ANTHROPIC_API_KEY").map(SecretString::from);
-        let bearer = env_trimmed("ANTHROPIC_AUTH_TOKEN").map(SecretString::from);
         let api_base =
             env_trimmed("ANTHROPIC_BASE_URL").unwrap_or_else(|| ANTHROPIC_DEFAULT_BASE.into());
 
-        let auth = match (api_key, bearer) {
-            (Some(k), Some(t)) => AnthropicAuth::Both {
-                api_key: k,
-                bearer: t,
-            },
-            (Some(k), None) => AnthropicAuth::ApiKey(k),
-            (None, Some(t)) => AnthropicAuth::Bearer(t),
-            _ => AnthropicAuth::None,
-        };
-
-        Self {
+        let cfg = Self {
             api_base,
             version: ANTHROPIC_VERSION.into(),
-            auth,
+            auth: AnthropicAuth::None,
             beta: vec![],
             dangerously_skip_auth: false,
+            auth_source: None,
+            auth_error: None,
+        };
+
+        match ProviderChain::from_env() {
+            Ok(chain) => cfg.with_provider_chain(&chain),
+            Err(e) => Self {
+                auth_error: Some(e.to_string()),
+                ..cfg
+            },
         }
     }
 }
@@ -91,10 +97,10 @@ impl Default for AnthropicConfig {
 impl AnthropicConfig {
     /// Creates a new configuration with default settings
     ///
-    /// Attempts to read from environment variables:
-    /// - `ANTHROPIC_API_KEY` for API key authentication
-    /// - `ANTHROPIC_AUTH_TOKEN` for bearer token authentication
-    /// - `ANTHROPIC_BASE_URL` for custom API base URL (defaults to `https://api.anthropic.com`)
+    /// Credentials are resolved through the default [`ProviderChain`]
+    /// (`ANTHROPIC_AUTH_TOKEN` → stored API key file → `ANTHROPIC_API_KEY`, reorderable
+    /// via `ANTHROPIC_AUTH_ORDER`); exactly one credential is used.
+    /// `ANTHROPIC_BASE_URL` sets a custom API base URL (defaults to `https://api.anthropic.com`).
     #[must_use]
     pub fn new() -> Self {
         Self::default()
@@ -123,7 +129,7 @@ impl AnthropicConfig {
     /// This will use the `x-api-key` header for authentication.
     #[must_use]
     pub fn with_api_key(mut self, k: impl Into<String>) -> Self {
-        self.auth = AnthropicAuth::ApiKey(SecretString::from(k.into()));
+        self.set_explicit_auth(AnthropicAuth::ApiKey(SecretString::from(k.into())));
         self
     }
 
@@ -132,7 +138,7 @@ impl AnthropicConfig {
     /// This will use the `Authorization: Bearer` header for authentication.
     #[must_use]
     pub fn with_bearer(mut self, t: impl Into<String>) -> Self {
-        self.auth = AnthropicAuth::Bearer(SecretString::from(t.into()));
+        self.set_explicit_auth(AnthropicAuth::Bearer(SecretString::from(t.into())));
         self
     }
 
@@ -142,13 +148,48 @@ impl AnthropicConfig {
     /// This matches the behavior of the official Python SDK when both credentials are present.
     #[must_use]
     pub fn with_both(mut self, api_key: impl Into<String>, bearer: impl Into<String>) -> Self {
-        self.auth = AnthropicAuth::Both {
+        self.set_explicit_auth(AnthropicAuth::Both {
             api_key: SecretString::from(api_key.into()),
             bearer: SecretString::from(bearer.into()),
-        };
+        });
+        self
+    }
+
+    /// Resolves credentials through a [`ProviderChain`], replacing any configured auth.
+    ///
+    /// The first healthy link wins. If no link yields a credential, auth is cleared and
+    /// [`validate_auth`](Self::validate_auth) reports every link that was tried.
+    #[must_use]
+    pub fn with_provider_chain(mut self, chain: &ProviderChain) -> Self {
+        match chain.resolve() {
+            Ok(resolved) => {
+                self.auth = resolved.auth;
+                self.auth_source = Some(resolved.source);
+                self.auth_error = None;
+            }
+            Err(e) => {
+                self.auth = AnthropicAuth::None;
+                self.auth_source = None;
+                self.auth_error = Some(e.to_string());
+            }
+        }
         self
     }
 
+    /// Returns the provider chain link that supplied the credentials, if any.
+    ///
+    /// `None` when credentials were set explicitly or nothing resolved.
+    #[must_use]
+    pub fn auth_source(&self) -> Option<AuthSource> {
+        self.auth_source
+    }
+
+    fn set_explicit_auth(&mut self, auth: AnthropicAuth) {
+        self.auth = auth;
+        self.auth_source = None;
+        self.auth_error = None;
+    }
+
     /// Sets custom beta feature strings
     ///
     /// These will be sent in the `anthropic-beta` header as a comma-separated list.
@@ -204,8 +245,10 @@ impl AnthropicConfig {
                 Ok(())
             }
             _ => Err(AnthropicError::Config(
-                "Missing Anthropic credentials: set ANTHROPIC_API_KEY or ANTHROPIC_AUTH_TOKEN"
-                    .into(),
+                self.auth_error.clone().unwrap_or_else(|| {
+                    "Missing Anthropic credentials: set ANTHROPIC_API_KEY or ANTHROPIC_AUTH_TOKEN"
+                        .into()
+                }),
             )),
         }
     }
@@ -338,6 +381,8 @@ pub enum BetaFeature {
     StructuredOutputs20251113,
     /// Alias to the latest structured outputs beta (currently 2025-11-13)
     StructuredOutputsLatest,
+    /// Files API (2025-04-14), needed to reference uploaded files in messages
+    FilesApi20250414,
     /// Custom beta feature string
     Other(String),
 }
@@ -352,6 +397,7 @@ impl From<BetaFeature> for String {
             BetaFeature::StructuredOutputs20251113 | BetaFeature::StructuredOutputsLatest => {
                 "structured-outputs-2025-11-13".into()
             }
+            BetaFeature::FilesApi20250414 => "files-api-2025-04-14".into(),
             BetaFeature::Other(s) => s,
         }
     }
@@ -417,6 +463,8 @@ mod tests {
             auth: AnthropicAuth::None,
             beta: vec![],
             dangerously_skip_auth: false,
+            auth_source: None,
+            auth_error: None,
         };
         assert!(cfg.validate_auth().is_err());
     }
@@ -538,6 +586,8 @@ mod tests {
             auth: AnthropicAuth::None,
             beta: vec![],
             dangerously_skip_auth: false,
+            auth_source: None,
+            auth_error: None,
         };
         assert!(cfg_normal.validate_auth().is_err());
 
@@ -21,10 +21,16 @@ pub enum AnthropicError {
     /// Serialization/deserialization error
     #[error("Serialization error: {0}")]
     Serde(String),
+
+    /// The client's circuit breaker is open and the request was not sent
+    ///
+    /// See [`CircuitBreaker`](crate::retry::CircuitBreaker).
+    #[error("Circuit breaker open: API is overloaded")]
+    CircuitOpen,
 }
 
 /// API error object from Anthropic
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ApiErrorObject {
     /// Error type (e.g., "`invalid_request_error`", "`rate_limit_error`")
     pub r#type: Option<String>,
@@ -56,9 +62,20 @@ impl AnthropicError {
                 .status_code
                 .is_some_and(crate::retry::is_retryable_status),
             Self::Reqwest(e) => e.is_timeout() || e.is_connect(),
-            Self::Config(_) | Self::Serde(_) => false,
+            Self::Config(_) | Self::Serde(_) | Self::CircuitOpen => false,
         }
     }
+
+    /// Determines if the API reported itself overloaded (529 / `overloaded_error`)
+    #[must_use]
+    pub fn is_overloaded(&self) -> bool {
+        matches!(
+            self,
+            Self::Api(obj)
+                if obj.status_code == Some(529)
+                    || obj.r#type.as_deref() == Some("overloaded_error")
+        )
+    }
 }
 
 /// Maps a serde deserialization error to an `AnthropicError` with context
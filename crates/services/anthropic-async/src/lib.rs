@@ -38,12 +38,18 @@
 //!
 //! Use [`CacheControl`](types::common::CacheControl) to cache prompts and reduce costs.
 
+/// Credential provider chain
+pub mod auth;
+/// Automatic prompt-cache breakpoint placement
+pub mod cache;
 /// HTTP client implementation
 pub mod client;
 /// Configuration types for the client
 pub mod config;
 /// Error types
 pub mod error;
+/// Request/response interceptors for logging and metrics
+pub mod middleware;
 /// API resource implementations
 pub mod resources;
 /// Retry logic utilities
@@ -57,9 +63,17 @@ pub(crate) mod sse;
 /// Test support utilities (for use in tests)
 #[doc(hidden)]
 pub mod test_support;
+/// Offline token estimation
+pub mod tokens;
+/// Tool-use conversation loop over an `agentic_tools_core` registry
+#[cfg(feature = "tool-runner")]
+pub mod tool_runner;
 /// Request and response types
 pub mod types;
 
+pub use crate::auth::AuthSource;
+pub use crate::auth::ProviderChain;
+pub use crate::auth::TokenProvider;
 pub use crate::client::Client;
 pub use crate::config::AnthropicAuth;
 pub use crate::config::AnthropicConfig;
@@ -71,6 +85,7 @@ pub use crate::error::ApiErrorObject;
 #[cfg(feature = "streaming")]
 pub mod streaming {
     pub use crate::sse::streaming::Accumulator;
+    pub use crate::sse::streaming::AccumulatorEvent;
     pub use crate::sse::streaming::ContentBlockDeltaData;
     pub use crate::sse::streaming::ContentBlockStartData;
     pub use crate::sse::streaming::Event;
@@ -0,0 +1,126 @@
+//! Request/response interceptors for logging and metrics
+//!
+//! An [`Interceptor`] registered with
+//! [`Client::with_interceptor`](crate::Client::with_interceptor) sees every API
+//! call the client makes, so token usage and latency can be recorded in one place
+//! instead of at each call site.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use anthropic_async::Client;
+//! use anthropic_async::middleware::{Interceptor, ResponseEvent};
+//!
+//! struct LogUsage;
+//!
+//! impl Interceptor for LogUsage {
+//!     fn on_response(&self, event: &ResponseEvent<'_>) {
+//!         if let Some(usage) = event.usage {
+//!             println!("{} {}: {:?} in {:?}", event.method, event.path, usage, event.latency);
+//!         }
+//!     }
+//! }
+//!
+//! let client = Client::new().with_interceptor(Arc::new(LogUsage));
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Method;
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::error::AnthropicError;
+use crate::types::common::Usage;
+
+/// An API call about to be sent
+#[derive(Debug, Clone, Copy)]
+pub struct RequestEvent<'a> {
+    /// HTTP method
+    pub method: &'a Method,
+    /// API path, e.g. `/v1/messages`
+    pub path: &'a str,
+}
+
+/// A finished API call, after any retries
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseEvent<'a> {
+    /// HTTP method
+    pub method: &'a Method,
+    /// API path, e.g. `/v1/messages`
+    pub path: &'a str,
+    /// Status of the last attempt, or `None` if no response arrived
+    pub status: Option<StatusCode>,
+    /// Time from the first attempt until the call finished, including backoff
+    pub latency: Duration,
+    /// Attempts after the first
+    pub retries: u32,
+    /// Token usage reported in the response body, if any
+    pub usage: Option<&'a Usage>,
+    /// The error the call ended with, if it failed
+    pub error: Option<&'a AnthropicError>,
+}
+
+/// Hooks called around every API call a [`Client`](crate::Client) makes
+///
+/// Both methods default to doing nothing. They run inline on the request path, so
+/// they should be cheap; hand slow work such as writing to disk to another task.
+///
+/// Streaming calls report `on_response` once the response headers arrive, without
+/// usage; read usage from the stream's `message_start` and `message_delta` events.
+pub trait Interceptor: Send + Sync {
+    /// Called once before the first attempt of a call
+    fn on_request(&self, _event: &RequestEvent<'_>) {}
+
+    /// Called once when a call succeeds or gives up
+    fn on_response(&self, _event: &ResponseEvent<'_>) {}
+}
+
+/// Interceptors registered on a client, called in registration order
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn Interceptor>>);
+
+impl std::fmt::Debug for Interceptors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interceptors")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl Interceptors {
+    pub(crate) fn push(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.0.push(interceptor);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn on_request(&self, event: &RequestEvent<'_>) {
+        for interceptor in &self.0 {
+            interceptor.on_request(event);
+        }
+    }
+
+    pub(crate) fn on_response(&self, event: &ResponseEvent<'_>) {
+        for interceptor in &self.0 {
+            interceptor.on_response(event);
+        }
+    }
+}
+
+/// The `usage` field of a response body, ignoring everything else
+#[derive(Deserialize)]
+struct UsageEnvelope {
+    #[serde(default)]
+    usage: Option<Usage>,
+}
+
+/// Token usage in a JSON response body, if it has any
+pub(crate) fn usage_from_body(body: &[u8]) -> Option<Usage> {
+    serde_json::from_slice::<UsageEnvelope>(body)
+        .ok()
+        .and_then(|envelope| envelope.usage)
+}
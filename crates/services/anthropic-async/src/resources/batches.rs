@@ -0,0 +1,252 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::error::AnthropicError;
+use crate::resources::messages::validate_messages_create_request;
+use crate::types::batches::BatchResultEntry;
+use crate::types::batches::DeletedMessageBatch;
+use crate::types::batches::MessageBatch;
+use crate::types::batches::MessageBatchCreateRequest;
+use crate::types::batches::MessageBatchListParams;
+use crate::types::batches::MessageBatchListResponse;
+use crate::types::batches::parse_batch_results;
+
+/// Longest `custom_id` the API accepts
+const MAX_CUSTOM_ID_LEN: usize = 64;
+
+/// Validate a batch create request
+///
+/// Requires at least one request, `custom_id`s that are unique and 1-64 characters
+/// of `[a-zA-Z0-9_-]`, and params that pass the same checks as a single
+/// `messages().create()` call. Batch requests cannot stream.
+fn validate_batch_create_request(req: &MessageBatchCreateRequest) -> Result<(), AnthropicError> {
+    if req.requests.is_empty() {
+        return Err(AnthropicError::Config(
+            "Message batch must contain at least one request".into(),
+        ));
+    }
+
+    let mut seen = HashSet::with_capacity(req.requests.len());
+    for request in &req.requests {
+        let id = request.custom_id.as_str();
+        let valid = !id.is_empty()
+            && id.len() <= MAX_CUSTOM_ID_LEN
+            && id
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-');
+        if !valid {
+            return Err(AnthropicError::Config(format!(
+                "Invalid batch custom_id {id:?}: must be 1-64 characters of [a-zA-Z0-9_-]"
+            )));
+        }
+        if !seen.insert(id) {
+            return Err(AnthropicError::Config(format!(
+                "Duplicate batch custom_id {id:?}"
+            )));
+        }
+        if request.params.stream == Some(true) {
+            return Err(AnthropicError::Config(format!(
+                "Batch request {id:?} cannot stream"
+            )));
+        }
+        validate_messages_create_request(&request.params)?;
+    }
+    Ok(())
+}
+
+/// API resource for the `/v1/messages/batches` endpoints
+///
+/// Batches process many Messages requests asynchronously at a discount. Results
+/// become available once the batch has ended, typically within 24 hours.
+pub struct Batches<'c, C: Config> {
+    client: &'c Client<C>,
+}
+
+impl<'c, C: Config> Batches<'c, C> {
+    /// Creates a new Batches resource
+    #[must_use]
+    pub const fn new(client: &'c Client<C>) -> Self {
+        Self { client }
+    }
+
+    /// Create a message batch
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The batch is empty, or a `custom_id` is invalid or repeated
+    /// - A request's params fail validation
+    /// - The API returns an error
+    pub async fn create(
+        &self,
+        req: impl Into<MessageBatchCreateRequest>,
+    ) -> Result<MessageBatch, AnthropicError> {
+        let req = req.into();
+        validate_batch_create_request(&req)?;
+
+        self.client.post("/v1/messages/batches", req).await
+    }
+
+    /// Retrieve a message batch, e.g. to poll its status
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn retrieve(&self, batch_id: &str) -> Result<MessageBatch, AnthropicError> {
+        self.client
+            .get(&format!("/v1/messages/batches/{batch_id}"))
+            .await
+    }
+
+    /// List message batches, most recent first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn list(
+        &self,
+        params: &MessageBatchListParams,
+    ) -> Result<MessageBatchListResponse, AnthropicError> {
+        self.client
+            .get_with_query("/v1/messages/batches", params)
+            .await
+    }
+
+    /// Cancel a message batch
+    ///
+    /// The batch moves to `canceling` while in-flight requests finish; unprocessed
+    /// requests end up with canceled results.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn cancel(&self, batch_id: &str) -> Result<MessageBatch, AnthropicError> {
+        self.client
+            .post(
+                &format!("/v1/messages/batches/{batch_id}/cancel"),
+                serde_json::json!({}),
+            )
+            .await
+    }
+
+    /// Delete an ended message batch
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn delete(&self, batch_id: &str) -> Result<DeletedMessageBatch, AnthropicError> {
+        self.client
+            .delete(&format!("/v1/messages/batches/{batch_id}"))
+            .await
+    }
+
+    /// Fetch the results of an ended batch
+    ///
+    /// Results are in no particular order; match them to requests by `custom_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch has not ended, the API request fails, or a
+    /// result line cannot be parsed.
+    pub async fn results(&self, batch_id: &str) -> Result<Vec<BatchResultEntry>, AnthropicError> {
+        let body = self
+            .client
+            .get_bytes(&format!("/v1/messages/batches/{batch_id}/results"))
+            .await?;
+        parse_batch_results(&body)
+    }
+
+    /// Poll a batch every `poll_interval` until it has ended
+    ///
+    /// There is no overall timeout; wrap the call in `tokio::time::timeout` to bound it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from polling.
+    pub async fn wait_for_completion(
+        &self,
+        batch_id: &str,
+        poll_interval: Duration,
+    ) -> Result<MessageBatch, AnthropicError> {
+        loop {
+            let batch = self.retrieve(batch_id).await?;
+            if batch.is_ended() {
+                return Ok(batch);
+            }
+            tracing::debug!(
+                batch_id,
+                status = ?batch.processing_status,
+                processing = batch.request_counts.processing,
+                "message batch still processing"
+            );
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+impl<C: Config> crate::Client<C> {
+    /// Returns the Message Batches API resource
+    #[must_use]
+    pub const fn batches(&self) -> Batches<'_, C> {
+        Batches::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::batches::BatchRequest;
+    use crate::types::messages::MessagesCreateRequest;
+
+    fn request(custom_id: &str) -> BatchRequest {
+        BatchRequest::new(
+            custom_id,
+            MessagesCreateRequest {
+                model: "claude-sonnet-4-6".into(),
+                max_tokens: 16,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn accepts_valid_batch() {
+        let req = MessageBatchCreateRequest::from(vec![request("a-1"), request("b_2")]);
+        assert!(validate_batch_create_request(&req).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_batch() {
+        let req = MessageBatchCreateRequest::default();
+        assert!(validate_batch_create_request(&req).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_and_duplicate_custom_ids() {
+        let too_long = "x".repeat(65);
+        for ids in [
+            vec![""],
+            vec!["has space"],
+            vec![too_long.as_str()],
+            vec!["dup", "dup"],
+        ] {
+            let req =
+                MessageBatchCreateRequest::from(ids.into_iter().map(request).collect::<Vec<_>>());
+            let err = validate_batch_create_request(&req).unwrap_err();
+            assert!(matches!(err, AnthropicError::Config(_)));
+        }
+    }
+
+    #[test]
+    fn rejects_streaming_and_invalid_params() {
+        let mut streaming = request("a");
+        streaming.params.stream = Some(true);
+        assert!(validate_batch_create_request(&vec![streaming].into()).is_err());
+
+        let mut hot = request("b");
+        hot.params.temperature = Some(2.0);
+        assert!(validate_batch_create_request(&vec![hot].into()).is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use reqwest::Method;
+use reqwest::multipart::Form;
+use reqwest::multipart::Part;
+use serde::de::DeserializeOwned;
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::error::AnthropicError;
+use crate::types::files::DeletedFile;
+use crate::types::files::FileListParams;
+use crate::types::files::FileListResponse;
+use crate::types::files::FileMetadata;
+use crate::types::files::FileUpload;
+
+/// Beta the Files endpoints require, sent with every request they make
+const FILES_API_BETA: &str = "files-api-2025-04-14";
+
+fn parse<O: DeserializeOwned>(body: &[u8]) -> Result<O, AnthropicError> {
+    serde_json::from_slice(body).map_err(|e| crate::error::map_deser(&e, body))
+}
+
+/// API resource for the `/v1/files` endpoints
+///
+/// Uploaded files can be referenced from content blocks by ID, e.g.
+/// [`DocumentSource::File`](crate::types::content::DocumentSource::File), instead of
+/// being base64-inlined in every request. Requests that reference files need
+/// [`BetaFeature::FilesApi20250414`](crate::config::BetaFeature::FilesApi20250414)
+/// enabled on the client's config; the Files endpoints themselves send it
+/// automatically.
+pub struct Files<'c, C: Config> {
+    client: &'c Client<C>,
+}
+
+impl<'c, C: Config> Files<'c, C> {
+    /// Creates a new Files resource
+    #[must_use]
+    pub const fn new(client: &'c Client<C>) -> Self {
+        Self { client }
+    }
+
+    /// Upload a file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the MIME type is invalid, the API request fails, or the
+    /// response cannot be parsed.
+    pub async fn upload(&self, file: FileUpload) -> Result<FileMetadata, AnthropicError> {
+        // Fail on a bad MIME type before the first attempt rather than inside it
+        Part::bytes(Vec::new()).mime_str(&file.mime_type)?;
+
+        let body = self
+            .client
+            .execute_with_beta(Method::POST, "/v1/files", FILES_API_BETA, |req| {
+                let part = Part::stream_with_length(
+                    reqwest::Body::from(file.data.clone()),
+                    file.data.len() as u64,
+                )
+                .file_name(file.filename.clone())
+                .mime_str(&file.mime_type)?;
+                Ok(req.multipart(Form::new().part("file", part)))
+            })
+            .await?;
+        parse(&body)
+    }
+
+    /// Retrieve a file's metadata
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn retrieve(&self, file_id: &str) -> Result<FileMetadata, AnthropicError> {
+        let body = self
+            .client
+            .execute_with_beta(
+                Method::GET,
+                &format!("/v1/files/{file_id}"),
+                FILES_API_BETA,
+                Ok,
+            )
+            .await?;
+        parse(&body)
+    }
+
+    /// List uploaded files, most recent first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn list(&self, params: &FileListParams) -> Result<FileListResponse, AnthropicError> {
+        let body = self
+            .client
+            .execute_with_beta(Method::GET, "/v1/files", FILES_API_BETA, |req| {
+                Ok(req.query(params))
+            })
+            .await?;
+        parse(&body)
+    }
+
+    /// Delete a file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API request fails or the response cannot be parsed.
+    pub async fn delete(&self, file_id: &str) -> Result<DeletedFile, AnthropicError> {
+        let body = self
+            .client
+            .execute_with_beta(
+                Method::DELETE,
+                &format!("/v1/files/{file_id}"),
+                FILES_API_BETA,
+                Ok,
+            )
+            .await?;
+        parse(&body)
+    }
+
+    /// Download a file's contents
+    ///
+    /// Only files with [`FileMetadata::downloadable`] set can be downloaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file is not downloadable or the API request fails.
+    pub async fn download(&self, file_id: &str) -> Result<bytes::Bytes, AnthropicError> {
+        self.client
+            .execute_with_beta(
+                Method::GET,
+                &format!("/v1/files/{file_id}/content"),
+                FILES_API_BETA,
+                Ok,
+            )
+            .await
+    }
+}
+
+impl<C: Config> crate::Client<C> {
+    /// Returns the Files API resource
+    #[must_use]
+    pub const fn files(&self) -> Files<'_, C> {
+        Files::new(self)
+    }
+}
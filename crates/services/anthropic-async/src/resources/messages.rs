@@ -79,7 +79,9 @@ fn collect_block_param_ttls(ttls: &mut Vec<CacheTtl>, block: &ContentBlockParam)
 ///    WebSearchToolResult}.cache_control.ttl`
 /// 4. `ContentBlockParam::ToolResult.cache_control.ttl`
 /// 5. `ToolResultContentBlock::{Text, Image}.cache_control.ttl` (nested inside `ToolResult`)
-fn validate_messages_create_request(req: &MessagesCreateRequest) -> Result<(), AnthropicError> {
+pub(crate) fn validate_messages_create_request(
+    req: &MessagesCreateRequest,
+) -> Result<(), AnthropicError> {
     // Validate TTL ordering across all cacheable locations
     // Order: system → tools → messages (canonical traversal)
     let mut ttls = Vec::new();
@@ -183,6 +185,10 @@ impl<'c, C: Config> Messages<'c, C> {
 
     /// Count tokens for a message request
     ///
+    /// Accepts a [`MessagesCreateRequest`] (owned or borrowed) as well, so a request
+    /// can be checked against the context window before it is sent. For a local
+    /// estimate without a round trip, see [`crate::tokens::TokenEstimator`].
+    ///
     /// # Errors
     ///
     /// Returns an error if:
@@ -190,10 +196,12 @@ impl<'c, C: Config> Messages<'c, C> {
     /// - The API returns an error
     pub async fn count_tokens(
         &self,
-        req: MessageTokensCountRequest,
+        req: impl Into<MessageTokensCountRequest>,
     ) -> Result<MessageTokensCountResponse, AnthropicError> {
         // No TTL validation needed for token counting
-        self.client.post("/v1/messages/count_tokens", req).await
+        self.client
+            .post("/v1/messages/count_tokens", req.into())
+            .await
     }
 
     /// Create a new message with streaming response
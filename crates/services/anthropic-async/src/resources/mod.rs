@@ -1,9 +1,15 @@
 //! API resource implementations for the Anthropic client
 
+/// Message Batches API resource
+pub mod batches;
+/// Files API resource
+pub mod files;
 /// Messages API resource
 pub mod messages;
 /// Models API resource
 pub mod models;
 
+pub use batches::Batches;
+pub use files::Files;
 pub use messages::Messages;
 pub use models::Models;
@@ -1,6 +1,14 @@
 use backon::ExponentialBuilder;
 use reqwest::header::HeaderMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::atomic::AtomicU32;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
+use std::time::Instant;
+
+use crate::error::AnthropicError;
 
 /// Creates the default exponential backoff builder
 ///
@@ -53,6 +61,256 @@ pub fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
     None
 }
 
+/// Shared cap on retries across every call a client makes
+///
+/// A token bucket: each retry spends a token and each successful call earns back
+/// part of one, so when most calls fail the retries dry up instead of multiplying
+/// the load on a struggling API. Clones of a [`Client`](crate::Client) share the
+/// budget; pass the same `Arc` to several clients to share it between them too.
+#[derive(Debug)]
+pub struct RetryBudget {
+    /// Balance in units of `1 / successes_per_retry` retries
+    balance: AtomicU32,
+    capacity: u32,
+    successes_per_retry: u32,
+}
+
+impl RetryBudget {
+    /// Successful calls needed to earn back one retry, unless configured
+    pub const DEFAULT_SUCCESSES_PER_RETRY: u32 = 10;
+
+    /// Creates a full budget allowing up to `max_retries` retries in a burst
+    #[must_use]
+    pub fn new(max_retries: u32) -> Self {
+        Self::with_rate(max_retries, Self::DEFAULT_SUCCESSES_PER_RETRY)
+    }
+
+    /// Sets how many successful calls earn back one retry (at least 1)
+    ///
+    /// Refills the budget.
+    #[must_use]
+    pub fn with_successes_per_retry(self, successes: u32) -> Self {
+        Self::with_rate(self.max_retries(), successes)
+    }
+
+    fn with_rate(max_retries: u32, successes_per_retry: u32) -> Self {
+        let successes_per_retry = successes_per_retry.max(1);
+        let capacity = max_retries.saturating_mul(successes_per_retry);
+        Self {
+            balance: AtomicU32::new(capacity),
+            capacity,
+            successes_per_retry,
+        }
+    }
+
+    /// Maximum number of retries the budget holds
+    #[must_use]
+    pub const fn max_retries(&self) -> u32 {
+        self.capacity / self.successes_per_retry
+    }
+
+    /// Number of retries currently available
+    #[must_use]
+    pub fn available(&self) -> u32 {
+        self.balance.load(Ordering::Relaxed) / self.successes_per_retry
+    }
+
+    /// Spends one retry, returning `false` if the budget is exhausted
+    pub(crate) fn try_withdraw(&self) -> bool {
+        self.balance
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |balance| {
+                balance.checked_sub(self.successes_per_retry)
+            })
+            .is_ok()
+    }
+
+    /// Credits a successful call
+    pub(crate) fn deposit(&self) {
+        // The closure always returns `Some`, so this cannot fail
+        let _ = self
+            .balance
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |balance| {
+                Some(balance.saturating_add(1).min(self.capacity))
+            });
+    }
+}
+
+/// State of a [`CircuitBreaker`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// The API is overloaded; requests fail fast until the cooldown passes
+    Open,
+    /// The cooldown passed; a single probe request is let through
+    HalfOpen,
+}
+
+type StateListener = Arc<dyn Fn(CircuitState, CircuitState) + Send + Sync>;
+
+#[derive(Debug)]
+struct BreakerInner {
+    state: CircuitState,
+    consecutive_overloaded: u32,
+    opened_at: Instant,
+    probe_in_flight: bool,
+}
+
+/// Circuit breaker that stops calls while the API reports itself overloaded
+///
+/// Opens after `failure_threshold` consecutive 529 / `overloaded_error`
+/// responses. While open, calls fail immediately with
+/// [`AnthropicError::CircuitOpen`] and in-flight calls stop retrying. Once the
+/// cooldown has passed the breaker goes half-open and lets one probe call
+/// through: any response that is not an overload closes it again, another
+/// overload reopens it for a fresh cooldown. Responses other than overloads
+/// reset the consecutive count; connection errors leave it untouched.
+///
+/// Like [`RetryBudget`], the breaker is shared through an `Arc`.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    inner: Mutex<BreakerInner>,
+    listeners: Vec<StateListener>,
+}
+
+impl CircuitBreaker {
+    /// Cooldown before a half-open probe, unless configured
+    pub const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+    /// Creates a closed breaker that opens after `failure_threshold` (at least 1)
+    /// consecutive overloaded responses
+    #[must_use]
+    pub fn new(failure_threshold: u32) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown: Self::DEFAULT_COOLDOWN,
+            inner: Mutex::new(BreakerInner {
+                state: CircuitState::Closed,
+                consecutive_overloaded: 0,
+                opened_at: Instant::now(),
+                probe_in_flight: false,
+            }),
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Sets how long the breaker stays open before probing
+    #[must_use]
+    pub const fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Adds a listener called with `(from, to)` on every state change
+    ///
+    /// Listeners run synchronously on the task that caused the change, after the
+    /// breaker's lock is released, so they can call [`Self::state`].
+    #[must_use]
+    pub fn with_listener(
+        mut self,
+        listener: impl Fn(CircuitState, CircuitState) + Send + Sync + 'static,
+    ) -> Self {
+        self.listeners.push(Arc::new(listener));
+        self
+    }
+
+    /// Current state
+    ///
+    /// An open breaker reports [`CircuitState::Open`] until the next call after
+    /// the cooldown turns it half-open.
+    #[must_use]
+    pub fn state(&self) -> CircuitState {
+        self.lock().state
+    }
+
+    /// Admits a call, returning whether it is the half-open probe
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AnthropicError::CircuitOpen`] if the breaker is open, or half-open
+    /// with a probe already in flight.
+    pub(crate) fn acquire(&self) -> Result<bool, AnthropicError> {
+        let mut inner = self.lock();
+        match inner.state {
+            CircuitState::Closed => Ok(false),
+            CircuitState::Open if inner.opened_at.elapsed() >= self.cooldown => {
+                inner.probe_in_flight = true;
+                self.transition(inner, CircuitState::HalfOpen);
+                Ok(true)
+            }
+            CircuitState::HalfOpen if !inner.probe_in_flight => {
+                inner.probe_in_flight = true;
+                Ok(true)
+            }
+            CircuitState::Open | CircuitState::HalfOpen => Err(AnthropicError::CircuitOpen),
+        }
+    }
+
+    /// Whether a failed call may retry
+    pub(crate) fn allows_retry(&self) -> bool {
+        self.lock().state != CircuitState::Open
+    }
+
+    /// Records the outcome of an attempt that received a response
+    pub(crate) fn record(&self, overloaded: bool) {
+        let mut inner = self.lock();
+        match (inner.state, overloaded) {
+            (CircuitState::Closed, true) => {
+                inner.consecutive_overloaded += 1;
+                if inner.consecutive_overloaded >= self.failure_threshold {
+                    self.open(inner);
+                }
+            }
+            (CircuitState::Closed, false) => inner.consecutive_overloaded = 0,
+            (CircuitState::HalfOpen, true) => self.open(inner),
+            (CircuitState::HalfOpen, false) => {
+                inner.consecutive_overloaded = 0;
+                inner.probe_in_flight = false;
+                self.transition(inner, CircuitState::Closed);
+            }
+            // Late responses from calls admitted before the breaker opened
+            (CircuitState::Open, _) => {}
+        }
+    }
+
+    /// Lets another probe through if the probe call ended without a verdict
+    pub(crate) fn release_probe(&self) {
+        self.lock().probe_in_flight = false;
+    }
+
+    fn open(&self, mut inner: std::sync::MutexGuard<'_, BreakerInner>) {
+        inner.opened_at = Instant::now();
+        inner.probe_in_flight = false;
+        self.transition(inner, CircuitState::Open);
+    }
+
+    fn transition(&self, mut inner: std::sync::MutexGuard<'_, BreakerInner>, to: CircuitState) {
+        let from = std::mem::replace(&mut inner.state, to);
+        drop(inner);
+        if from != to {
+            for listener in &self.listeners {
+                listener(from, to);
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerInner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for CircuitBreaker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreaker")
+            .field("failure_threshold", &self.failure_threshold)
+            .field("cooldown", &self.cooldown)
+            .field("inner", &self.inner)
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +341,85 @@ mod tests {
         let d = parse_retry_after(&h).expect("Should parse retry-after-ms");
         assert_eq!(d.as_millis(), 5000);
     }
+
+    #[test]
+    fn budget_spends_and_refills() {
+        let budget = RetryBudget::new(2).with_successes_per_retry(3);
+        assert_eq!(budget.max_retries(), 2);
+        assert!(budget.try_withdraw());
+        assert!(budget.try_withdraw());
+        assert!(!budget.try_withdraw());
+        assert_eq!(budget.available(), 0);
+
+        budget.deposit();
+        budget.deposit();
+        assert!(!budget.try_withdraw());
+        budget.deposit();
+        assert!(budget.try_withdraw());
+
+        for _ in 0..100 {
+            budget.deposit();
+        }
+        assert_eq!(budget.available(), 2);
+    }
+
+    #[test]
+    fn breaker_opens_after_consecutive_overloads() {
+        let breaker = CircuitBreaker::new(3);
+        breaker.record(true);
+        breaker.record(true);
+        breaker.record(false);
+        breaker.record(true);
+        breaker.record(true);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(matches!(breaker.acquire(), Ok(false)));
+
+        breaker.record(true);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allows_retry());
+        assert!(matches!(
+            breaker.acquire(),
+            Err(AnthropicError::CircuitOpen)
+        ));
+    }
+
+    #[test]
+    fn breaker_half_open_allows_one_probe() {
+        use CircuitState::*;
+
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&transitions);
+        let breaker = CircuitBreaker::new(1)
+            .with_cooldown(Duration::ZERO)
+            .with_listener(move |from, to| seen.lock().unwrap().push((from, to)));
+
+        breaker.record(true);
+        assert!(matches!(breaker.acquire(), Ok(true)));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(matches!(
+            breaker.acquire(),
+            Err(AnthropicError::CircuitOpen)
+        ));
+
+        // A probe that got no response lets the next call probe instead
+        breaker.release_probe();
+        assert!(matches!(breaker.acquire(), Ok(true)));
+        breaker.record(true);
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        assert!(matches!(breaker.acquire(), Ok(true)));
+        breaker.record(false);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        assert_eq!(
+            *transitions.lock().unwrap(),
+            vec![
+                (Closed, Open),
+                (Open, HalfOpen),
+                (HalfOpen, Open),
+                (Open, HalfOpen),
+                (HalfOpen, Closed),
+            ]
+        );
+    }
 }
@@ -121,7 +121,6 @@ pub mod streaming {
     /// Streaming event types from Anthropic Messages API
     #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
     #[serde(tag = "type", rename_all = "snake_case")]
-    #[allow(clippy::derive_partial_eq_without_eq)] // ContentBlock doesn't impl Eq
     #[non_exhaustive]
     pub enum Event {
         /// Message creation started
@@ -176,7 +175,7 @@ pub mod streaming {
     }
 
     /// Payload for `message_start` event
-    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+    #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
     pub struct MessageStartPayload {
         /// Message ID
         pub id: String,
@@ -456,6 +455,8 @@ pub mod streaming {
             id: String,
             name: String,
             input_json: String,
+            /// Parsed input, set once `content_block_stop` arrives
+            input: Option<Result<serde_json::Value, String>>,
         },
         Thinking {
             thinking: String,
@@ -464,6 +465,34 @@ pub mod streaming {
         Unknown,
     }
 
+    /// Something the accumulator finished assembling
+    #[derive(Debug, Clone, PartialEq)]
+    #[non_exhaustive]
+    pub enum AccumulatorEvent {
+        /// A `tool_use` block finished streaming and its input parsed
+        ToolUseComplete {
+            /// Index of the content block
+            index: usize,
+            /// Tool use ID
+            id: String,
+            /// Tool name
+            name: String,
+            /// Complete tool input
+            input: serde_json::Value,
+        },
+        /// The message finished (after `message_stop`)
+        MessageComplete(Box<MessagesCreateResponse>),
+    }
+
+    /// Parse the concatenated `input_json_delta`s of a tool block
+    fn parse_tool_input(input_json: &str) -> Result<serde_json::Value, String> {
+        if input_json.is_empty() {
+            Ok(serde_json::Value::Object(serde_json::Map::new()))
+        } else {
+            serde_json::from_str(input_json).map_err(|e| format!("tool input JSON: {e}"))
+        }
+    }
+
     impl Accumulator {
         /// Create a new accumulator
         #[must_use]
@@ -485,6 +514,59 @@ pub mod streaming {
             &mut self,
             event: &Event,
         ) -> Result<Option<MessagesCreateResponse>, AnthropicError> {
+            self.update(event)?;
+
+            if self.complete {
+                Ok(Some(self.build_response()?))
+            } else {
+                Ok(None)
+            }
+        }
+
+        /// Apply an event and report what it completed
+        ///
+        /// Unlike [`apply`](Self::apply), this reports each `tool_use` block as soon
+        /// as its `content_block_stop` arrives, so a tool can start running while the
+        /// rest of the message is still streaming. Tool blocks the stream never
+        /// stops are only reported as part of the final message.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if:
+        /// - An error event is received
+        /// - A finished tool block's input is not valid JSON
+        /// - Events arrive out of order
+        pub fn apply_events(
+            &mut self,
+            event: &Event,
+        ) -> Result<Vec<AccumulatorEvent>, AnthropicError> {
+            self.update(event)?;
+
+            let mut events = Vec::new();
+            if let Event::ContentBlockStop { index } = event
+                && let Some(AccumulatorBlock::ToolUse {
+                    id,
+                    name,
+                    input: Some(input),
+                    ..
+                }) = self.content_blocks.get(*index)
+            {
+                events.push(AccumulatorEvent::ToolUseComplete {
+                    index: *index,
+                    id: id.clone(),
+                    name: name.clone(),
+                    input: input.clone().map_err(AnthropicError::Serde)?,
+                });
+            }
+            if self.complete {
+                events.push(AccumulatorEvent::MessageComplete(Box::new(
+                    self.build_response()?,
+                )));
+            }
+            Ok(events)
+        }
+
+        fn update(&mut self, event: &Event) -> Result<(), AnthropicError> {
             match event {
                 Event::MessageStart { message } => {
                     self.id = Some(message.id.clone());
@@ -515,6 +597,7 @@ pub mod streaming {
                                 id: id.clone(),
                                 name: name.clone(),
                                 input_json: String::new(),
+                                input: None,
                             }
                         }
                         ContentBlockStartData::Thinking {
@@ -562,8 +645,16 @@ pub mod streaming {
                         _ => {}
                     }
                 }
-                Event::ContentBlockStop { .. } | Event::Ping | Event::Unknown { .. } => {
-                    // Block complete, keep-alive, or unknown event - nothing to do
+                Event::ContentBlockStop { index } => {
+                    if let Some(AccumulatorBlock::ToolUse {
+                        input_json, input, ..
+                    }) = self.content_blocks.get_mut(*index)
+                    {
+                        *input = Some(parse_tool_input(input_json));
+                    }
+                }
+                Event::Ping | Event::Unknown { .. } => {
+                    // Keep-alive or unknown event - nothing to do
                 }
                 Event::MessageDelta { delta, usage } => {
                     if let Some(reason) = &delta.stop_reason {
@@ -582,15 +673,11 @@ pub mod streaming {
                         message: error.message.clone(),
                         request_id: None,
                         code: None,
+                        status_code: None,
                     }));
                 }
             }
-
-            if self.complete {
-                Ok(Some(self.build_response()?))
-            } else {
-                Ok(None)
-            }
+            Ok(())
         }
 
         /// Build the final response from accumulated data
@@ -607,14 +694,13 @@ pub mod streaming {
                         id,
                         name,
                         input_json,
+                        input,
                     } => {
-                        let input: serde_json::Value = if input_json.is_empty() {
-                            serde_json::Value::Object(serde_json::Map::new())
-                        } else {
-                            serde_json::from_str(input_json).map_err(|e| {
-                                AnthropicError::Serde(format!("tool input JSON: {e}"))
-                            })?
-                        };
+                        let input = match input {
+                            Some(parsed) => parsed.clone(),
+                            None => parse_tool_input(input_json),
+                        }
+                        .map_err(AnthropicError::Serde)?;
                         Ok(ContentBlock::ToolUse {
                             id: id.clone(),
                             name: name.clone(),
@@ -0,0 +1,315 @@
+//! Offline token estimation
+//!
+//! [`Messages::count_tokens`](crate::resources::messages::Messages::count_tokens)
+//! asks the API for an exact count. When a round trip is too slow or costly, e.g.
+//! when trimming context before every request, a [`TokenEstimator`] gives a
+//! local estimate instead.
+//!
+//! ```
+//! use anthropic_async::tokens::{HeuristicEstimator, TokenEstimator};
+//! use anthropic_async::types::content::{MessageParam, MessageRole};
+//! use anthropic_async::types::messages::MessagesCreateRequest;
+//!
+//! let req = MessagesCreateRequest {
+//!     model: "claude-sonnet-4-6".into(),
+//!     max_tokens: 1024,
+//!     messages: vec![MessageParam {
+//!         role: MessageRole::User,
+//!         content: "Summarize the attached report.".into(),
+//!     }],
+//!     ..Default::default()
+//! };
+//!
+//! let estimator = HeuristicEstimator::default();
+//! assert!(estimator.fits_within(&(&req).into(), 200_000));
+//! ```
+
+use crate::types::content::ContentBlockParam;
+use crate::types::content::DocumentSource;
+use crate::types::content::MessageContentParam;
+use crate::types::content::SystemParam;
+use crate::types::content::ToolResultContent;
+use crate::types::content::ToolResultContentBlock;
+use crate::types::messages::MessageTokensCountRequest;
+
+/// Tokens charged for an image when its size is unknown
+///
+/// Images are billed by pixel area and capped near this value, so it is an upper
+/// bound for images the API doesn't downscale.
+pub const DEFAULT_IMAGE_TOKENS: u64 = 1_600;
+
+/// Tokens added per message for role and turn framing
+const MESSAGE_OVERHEAD: u64 = 4;
+
+/// Tokens added per tool definition for its framing in the system prompt
+const TOOL_OVERHEAD: u64 = 16;
+
+/// Estimates input tokens without calling the API
+///
+/// Implementors provide [`estimate_text`](Self::estimate_text); the provided
+/// methods walk a request and sum its text, images, and tool definitions.
+pub trait TokenEstimator: Send + Sync {
+    /// Estimated tokens for a piece of text
+    fn estimate_text(&self, text: &str) -> u64;
+
+    /// Estimated tokens for one image or non-text document page
+    fn estimate_image(&self) -> u64 {
+        DEFAULT_IMAGE_TOKENS
+    }
+
+    /// Estimated input tokens for a whole request
+    fn estimate_request(&self, req: &MessageTokensCountRequest) -> u64 {
+        let system = match &req.system {
+            Some(SystemParam::String(text)) => self.estimate_text(text),
+            Some(SystemParam::Blocks(blocks)) => {
+                blocks.iter().map(|b| self.estimate_text(&b.text)).sum()
+            }
+            None => 0,
+        };
+        let messages: u64 = req
+            .messages
+            .iter()
+            .map(|m| {
+                MESSAGE_OVERHEAD
+                    + match &m.content {
+                        MessageContentParam::String(text) => self.estimate_text(text),
+                        MessageContentParam::Blocks(blocks) => {
+                            blocks.iter().map(|b| estimate_block(self, b)).sum()
+                        }
+                    }
+            })
+            .sum();
+        let tools: u64 = req
+            .tools
+            .iter()
+            .flatten()
+            .map(|tool| {
+                TOOL_OVERHEAD
+                    + self.estimate_text(&tool.name)
+                    + tool
+                        .description
+                        .as_deref()
+                        .map_or(0, |d| self.estimate_text(d))
+                    + self.estimate_text(&tool.input_schema.to_string())
+            })
+            .sum();
+        system + messages + tools
+    }
+
+    /// Whether the estimate for `req` is at most `limit` tokens
+    fn fits_within(&self, req: &MessageTokensCountRequest, limit: u64) -> bool {
+        self.estimate_request(req) <= limit
+    }
+}
+
+fn estimate_block<E: TokenEstimator + ?Sized>(estimator: &E, block: &ContentBlockParam) -> u64 {
+    match block {
+        ContentBlockParam::Text { text, .. } => estimator.estimate_text(text),
+        ContentBlockParam::Image { .. } => estimator.estimate_image(),
+        ContentBlockParam::Document { source, .. } => match source {
+            DocumentSource::Base64 { media_type, data } if media_type.starts_with("text/") => {
+                // Decoded text is about 3/4 the size of its base64 encoding
+                estimator.estimate_text(data) * 3 / 4
+            }
+            DocumentSource::Base64 { .. }
+            | DocumentSource::Url { .. }
+            | DocumentSource::File { .. } => estimator.estimate_image(),
+        },
+        ContentBlockParam::ToolUse { name, input, .. }
+        | ContentBlockParam::ServerToolUse { name, input, .. } => {
+            estimator.estimate_text(name) + estimator.estimate_text(&input.to_string())
+        }
+        ContentBlockParam::ToolResult { content, .. } => match content {
+            Some(ToolResultContent::String(text)) => estimator.estimate_text(text),
+            Some(ToolResultContent::Blocks(blocks)) => blocks
+                .iter()
+                .map(|b| match b {
+                    ToolResultContentBlock::Text { text, .. } => estimator.estimate_text(text),
+                    ToolResultContentBlock::Image { .. } => estimator.estimate_image(),
+                })
+                .sum(),
+            None => 0,
+        },
+        ContentBlockParam::Thinking { thinking, .. } => estimator.estimate_text(thinking),
+        ContentBlockParam::RedactedThinking { .. } => 0,
+        ContentBlockParam::SearchResult {
+            content,
+            source,
+            title,
+            ..
+        } => {
+            estimator.estimate_text(title)
+                + estimator.estimate_text(source)
+                + content
+                    .iter()
+                    .map(|c| estimator.estimate_text(&c.to_string()))
+                    .sum::<u64>()
+        }
+        ContentBlockParam::WebSearchToolResult { content, .. } => {
+            estimator.estimate_text(&content.to_string())
+        }
+    }
+}
+
+/// Character-count estimator tuned to overestimate rather than underestimate
+///
+/// ASCII text is counted at `chars_per_token` characters per token (3.5 by
+/// default, below the ~4 typical of English prose). Other characters, such as
+/// CJK text or emoji, are counted as one token each. Use the API's
+/// `count_tokens` when an exact figure matters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeuristicEstimator {
+    chars_per_token: f64,
+    image_tokens: u64,
+}
+
+impl Default for HeuristicEstimator {
+    fn default() -> Self {
+        Self {
+            chars_per_token: 3.5,
+            image_tokens: DEFAULT_IMAGE_TOKENS,
+        }
+    }
+}
+
+impl HeuristicEstimator {
+    /// Sets how many ASCII characters make up one token
+    ///
+    /// Values below 1 are treated as 1.
+    #[must_use]
+    pub const fn with_chars_per_token(mut self, chars_per_token: f64) -> Self {
+        self.chars_per_token = chars_per_token.max(1.0);
+        self
+    }
+
+    /// Sets the tokens charged per image
+    #[must_use]
+    pub const fn with_image_tokens(mut self, image_tokens: u64) -> Self {
+        self.image_tokens = image_tokens;
+        self
+    }
+}
+
+impl TokenEstimator for HeuristicEstimator {
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss,
+        reason = "character counts are far below f64's exact integer range"
+    )]
+    fn estimate_text(&self, text: &str) -> u64 {
+        let (ascii, other) = text.chars().fold((0u64, 0u64), |(a, o), c| {
+            if c.is_ascii() { (a + 1, o) } else { (a, o + 1) }
+        });
+        (ascii as f64 / self.chars_per_token).ceil() as u64 + other
+    }
+
+    fn estimate_image(&self) -> u64 {
+        self.image_tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::content::ImageSource;
+    use crate::types::content::MessageParam;
+    use crate::types::content::MessageRole;
+    use crate::types::messages::MessagesCreateRequest;
+    use crate::types::tools::Tool;
+
+    fn request(content: MessageContentParam) -> MessageTokensCountRequest {
+        MessageTokensCountRequest {
+            model: "claude-sonnet-4-6".into(),
+            system: None,
+            messages: vec![MessageParam {
+                role: MessageRole::User,
+                content,
+            }],
+            tools: None,
+            tool_choice: None,
+        }
+    }
+
+    #[test]
+    fn heuristic_counts_ascii_and_wide_chars() {
+        let est = HeuristicEstimator::default();
+        assert_eq!(est.estimate_text(""), 0);
+        assert_eq!(est.estimate_text("abcdefg"), 2);
+        assert_eq!(est.estimate_text("abcdefgh"), 3);
+        assert_eq!(est.estimate_text("日本語"), 3);
+        assert_eq!(est.with_chars_per_token(4.0).estimate_text("abcdefgh"), 2);
+        assert_eq!(est.with_chars_per_token(0.0).estimate_text("abc"), 3);
+    }
+
+    #[test]
+    fn request_sums_system_messages_and_tools() {
+        let est = HeuristicEstimator::default().with_chars_per_token(1.0);
+        let mut req = request("hello".into());
+        assert_eq!(est.estimate_request(&req), MESSAGE_OVERHEAD + 5);
+
+        req.system = Some(SystemParam::String("be brief".into()));
+        req.tools = Some(vec![Tool {
+            name: "ls".into(),
+            description: Some("list".into()),
+            input_schema: serde_json::json!({}),
+            cache_control: None,
+            strict: None,
+        }]);
+        assert_eq!(
+            est.estimate_request(&req),
+            8 + MESSAGE_OVERHEAD + 5 + TOOL_OVERHEAD + 2 + 4 + 2
+        );
+        assert!(est.fits_within(&req, 100));
+        assert!(!est.fits_within(&req, 10));
+    }
+
+    #[test]
+    fn blocks_charge_images_and_tool_io() {
+        let est = HeuristicEstimator::default()
+            .with_chars_per_token(1.0)
+            .with_image_tokens(100);
+        let req = request(MessageContentParam::Blocks(vec![
+            ContentBlockParam::Image {
+                source: ImageSource::Url {
+                    url: "https://example.com/a.png".into(),
+                },
+                cache_control: None,
+            },
+            ContentBlockParam::ToolUse {
+                id: "toolu_1".into(),
+                name: "ls".into(),
+                input: serde_json::json!({"p": 1}),
+                cache_control: None,
+            },
+            ContentBlockParam::ToolResult {
+                tool_use_id: "toolu_1".into(),
+                content: Some("abc".into()),
+                is_error: None,
+                cache_control: None,
+            },
+        ]));
+        assert_eq!(
+            est.estimate_request(&req),
+            MESSAGE_OVERHEAD + 100 + 2 + r#"{"p":1}"#.len() as u64 + 3
+        );
+    }
+
+    #[test]
+    fn count_request_from_create_request() {
+        let create = MessagesCreateRequest {
+            model: "claude-sonnet-4-6".into(),
+            max_tokens: 16,
+            system: Some(SystemParam::String("sys".into())),
+            messages: vec![MessageParam {
+                role: MessageRole::User,
+                content: "hi".into(),
+            }],
+            ..Default::default()
+        };
+        let count = MessageTokensCountRequest::from(&create);
+        assert_eq!(count.model, create.model);
+        assert_eq!(count.system, create.system);
+        assert_eq!(count.messages, create.messages);
+    }
+}
@@ -0,0 +1,285 @@
+//! Tool-use conversation loop (requires `tool-runner` feature)
+//!
+//! [`ToolRunner`] drives the Messages API for services that expose an
+//! [`agentic_tools_core::ToolRegistry`] to the model. Each `tool_use` block in a
+//! response is dispatched to the registry and answered with a `tool_result` block,
+//! and the conversation continues until the model stops asking for tools.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use agentic_tools_core::ToolRegistry;
+//! use anthropic_async::Client;
+//! use anthropic_async::tool_runner::ToolRunner;
+//! use anthropic_async::types::content::{MessageParam, MessageRole};
+//! use anthropic_async::types::messages::MessagesCreateRequest;
+//!
+//! # async fn example(registry: ToolRegistry) -> Result<(), anthropic_async::AnthropicError> {
+//! let client = Client::new();
+//! let runner = ToolRunner::new(Arc::new(registry)).with_max_iterations(8);
+//!
+//! let req = MessagesCreateRequest {
+//!     model: "claude-sonnet-4-6".into(),
+//!     max_tokens: 1024,
+//!     messages: vec![MessageParam {
+//!         role: MessageRole::User,
+//!         content: "List the files in src/".into(),
+//!     }],
+//!     ..Default::default()
+//! };
+//!
+//! let outcome = runner.run(&client, req).await?;
+//! println!("{}", outcome.text());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::Arc;
+
+use agentic_tools_core::TextOptions;
+use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolRegistry;
+use agentic_tools_core::fallback_text_from_json;
+use futures::future::join_all;
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::error::AnthropicError;
+use crate::types::common::Usage;
+use crate::types::content::ContentBlock;
+use crate::types::content::ContentBlockParam;
+use crate::types::content::MessageContentParam;
+use crate::types::content::MessageParam;
+use crate::types::content::MessageRole;
+use crate::types::content::ToolResultContent;
+use crate::types::messages::MessagesCreateRequest;
+use crate::types::messages::MessagesCreateResponse;
+use crate::types::tools::Tool;
+
+/// Default cap on tool rounds in one [`ToolRunner::run`]
+pub const DEFAULT_MAX_ITERATIONS: usize = 16;
+
+/// Why a [`ToolRunner::run`] returned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolRunStop {
+    /// The model finished without asking for another tool
+    Finished,
+    /// The model still wanted tools when the iteration cap was reached
+    ///
+    /// The last message of the transcript holds the unanswered `tool_use` blocks.
+    IterationLimit,
+}
+
+/// Result of a tool-use conversation
+#[derive(Debug, Clone)]
+pub struct ToolRunOutcome {
+    /// The model's last response
+    pub response: MessagesCreateResponse,
+    /// The request's messages followed by every assistant turn and tool result
+    pub messages: Vec<MessageParam>,
+    /// Number of tool rounds dispatched
+    pub iterations: usize,
+    /// Token usage summed over every request of the run
+    pub usage: Usage,
+    /// Why the run stopped
+    pub stop: ToolRunStop,
+}
+
+impl ToolRunOutcome {
+    /// Text blocks of the last response, joined with newlines
+    #[must_use]
+    pub fn text(&self) -> String {
+        self.response
+            .content
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Runs the agentic loop against a [`ToolRegistry`]
+///
+/// Tool calls in one response are dispatched concurrently and answered in order.
+/// Failed calls, including calls to tools the registry doesn't know, are sent back
+/// as `tool_result` blocks with `is_error` set so the model can recover; only API
+/// errors end the run.
+#[derive(Clone)]
+pub struct ToolRunner {
+    registry: Arc<ToolRegistry>,
+    context: ToolContext,
+    text_options: TextOptions,
+    max_iterations: usize,
+}
+
+impl ToolRunner {
+    /// Creates a runner dispatching to `registry` with a default [`ToolContext`]
+    #[must_use]
+    pub fn new(registry: Arc<ToolRegistry>) -> Self {
+        Self {
+            registry,
+            context: ToolContext::default(),
+            text_options: TextOptions::default(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+        }
+    }
+
+    /// Sets the context tool calls run under, e.g. for cancellation or permissions
+    #[must_use]
+    pub fn with_context(mut self, context: ToolContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Sets how tool outputs are rendered into `tool_result` text
+    #[must_use]
+    pub fn with_text_options(mut self, text_options: TextOptions) -> Self {
+        self.text_options = text_options;
+        self
+    }
+
+    /// Caps the number of tool rounds per run
+    #[must_use]
+    pub const fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Tool definitions for every tool in the registry
+    #[must_use]
+    pub fn tools(&self) -> Vec<Tool> {
+        self.registry
+            .iter_named()
+            .map(|(name, tool)| Tool {
+                name: name.to_string(),
+                description: Some(tool.provider_description().into_owned()),
+                input_schema: serde_json::to_value(tool.input_schema())
+                    .unwrap_or_else(|_| serde_json::json!({ "type": "object" })),
+                cache_control: None,
+                strict: None,
+            })
+            .collect()
+    }
+
+    /// Sends `request` and answers tool calls until the model stops asking for them
+    ///
+    /// When the request has no `tools`, the registry's [`tools`](Self::tools) are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails, or if a response contains a content block
+    /// that can't be sent back to the API.
+    pub async fn run<C: Config>(
+        &self,
+        client: &Client<C>,
+        mut request: MessagesCreateRequest,
+    ) -> Result<ToolRunOutcome, AnthropicError> {
+        if request.tools.is_none() && !self.registry.is_empty() {
+            request.tools = Some(self.tools());
+        }
+
+        let mut usage = Usage::default();
+        let mut iterations = 0;
+        loop {
+            let response = client.messages().create(request.clone()).await?;
+            add_usage(&mut usage, response.usage.as_ref());
+            request.messages.push(
+                response
+                    .try_into_message_param()
+                    .map_err(|e| AnthropicError::Serde(e.to_string()))?,
+            );
+
+            let calls = tool_uses(&response);
+            let stop = if calls.is_empty() || response.stop_reason.as_deref() != Some("tool_use") {
+                Some(ToolRunStop::Finished)
+            } else if iterations == self.max_iterations {
+                Some(ToolRunStop::IterationLimit)
+            } else {
+                None
+            };
+            if let Some(stop) = stop {
+                return Ok(ToolRunOutcome {
+                    response,
+                    messages: request.messages,
+                    iterations,
+                    usage,
+                    stop,
+                });
+            }
+
+            iterations += 1;
+            let results = join_all(
+                calls
+                    .into_iter()
+                    .map(|(id, name, input)| self.call(id, name, input)),
+            )
+            .await;
+            request.messages.push(MessageParam {
+                role: MessageRole::User,
+                content: MessageContentParam::Blocks(results),
+            });
+        }
+    }
+
+    /// Dispatch one tool call and wrap its outcome as a `tool_result` block
+    async fn call(&self, id: String, name: String, input: serde_json::Value) -> ContentBlockParam {
+        let result = self
+            .registry
+            .dispatch_json_formatted(&name, input, &self.context, &self.text_options)
+            .await;
+        let (text, is_error) = match result {
+            Ok(output) => (
+                output
+                    .text
+                    .unwrap_or_else(|| fallback_text_from_json(&output.data)),
+                None,
+            ),
+            Err(e) => {
+                tracing::debug!(tool = %name, error = %e, "tool call failed");
+                (e.to_string(), Some(true))
+            }
+        };
+        ContentBlockParam::ToolResult {
+            tool_use_id: id,
+            content: Some(ToolResultContent::String(text)),
+            is_error,
+            cache_control: None,
+        }
+    }
+}
+
+/// The `(id, name, input)` of each `tool_use` block in a response
+fn tool_uses(response: &MessagesCreateResponse) -> Vec<(String, String, serde_json::Value)> {
+    response
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::ToolUse { id, name, input } => {
+                Some((id.clone(), name.clone(), input.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn add_usage(total: &mut Usage, usage: Option<&Usage>) {
+    let Some(usage) = usage else { return };
+    let add = |total: &mut Option<u64>, n: Option<u64>| {
+        if let Some(n) = n {
+            *total = Some(total.unwrap_or(0) + n);
+        }
+    };
+    add(&mut total.input_tokens, usage.input_tokens);
+    add(&mut total.output_tokens, usage.output_tokens);
+    add(
+        &mut total.cache_creation_input_tokens,
+        usage.cache_creation_input_tokens,
+    );
+    add(
+        &mut total.cache_read_input_tokens,
+        usage.cache_read_input_tokens,
+    );
+}
@@ -0,0 +1,277 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::messages::MessagesCreateRequest;
+use super::messages::MessagesCreateResponse;
+use crate::error::AnthropicError;
+use crate::error::ApiErrorObject;
+
+/// One request in a message batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchRequest {
+    /// Caller-chosen ID used to match the request to its result
+    ///
+    /// Must be 1-64 characters of `[a-zA-Z0-9_-]` and unique within the batch.
+    pub custom_id: String,
+    /// Messages API parameters for this request
+    pub params: MessagesCreateRequest,
+}
+
+impl BatchRequest {
+    /// Creates a batch request
+    #[must_use]
+    pub fn new(custom_id: impl Into<String>, params: MessagesCreateRequest) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            params,
+        }
+    }
+}
+
+/// Request to create a message batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MessageBatchCreateRequest {
+    /// Requests to process, each with a unique `custom_id`
+    pub requests: Vec<BatchRequest>,
+}
+
+impl From<Vec<BatchRequest>> for MessageBatchCreateRequest {
+    fn from(requests: Vec<BatchRequest>) -> Self {
+        Self { requests }
+    }
+}
+
+/// Processing status of a message batch
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessingStatus {
+    /// Requests are still being processed
+    InProgress,
+    /// Cancellation was requested and in-flight requests are finishing
+    Canceling,
+    /// Every request has a result
+    Ended,
+    /// Unknown status (forward compatibility)
+    #[serde(other)]
+    Unknown,
+}
+
+/// Number of requests in a batch by state
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct BatchRequestCounts {
+    /// Requests still processing
+    pub processing: u64,
+    /// Requests that completed successfully
+    pub succeeded: u64,
+    /// Requests that failed
+    pub errored: u64,
+    /// Requests canceled before processing
+    pub canceled: u64,
+    /// Requests that expired before processing
+    pub expired: u64,
+}
+
+/// A message batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MessageBatch {
+    /// Batch ID
+    pub id: String,
+    /// Type of resource (always "`message_batch`")
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Processing status
+    pub processing_status: ProcessingStatus,
+    /// Request counts by state
+    pub request_counts: BatchRequestCounts,
+    /// When the batch was created
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// When unprocessed requests expire
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    /// When processing ended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// When cancellation was requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cancel_initiated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// When the batch was archived and its results became unavailable
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// URL of the results file, set once processing has ended
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub results_url: Option<String>,
+}
+
+impl MessageBatch {
+    /// Whether every request has a result
+    #[must_use]
+    pub fn is_ended(&self) -> bool {
+        self.processing_status == ProcessingStatus::Ended
+    }
+}
+
+/// Response from listing message batches
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MessageBatchListResponse {
+    /// Batches, most recent first
+    pub data: Vec<MessageBatch>,
+    /// Whether there are more batches available
+    pub has_more: bool,
+    /// ID of the first batch in the list
+    pub first_id: Option<String>,
+    /// ID of the last batch in the list
+    pub last_id: Option<String>,
+}
+
+/// Parameters for listing message batches
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MessageBatchListParams {
+    /// Return batches after this ID (for pagination)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+    /// Return batches before this ID (for pagination)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+    /// Maximum number of batches to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// Response from deleting a message batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeletedMessageBatch {
+    /// ID of the deleted batch
+    pub id: String,
+    /// Type of resource (always "`message_batch_deleted`")
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+/// Result of one batch request, as a line of the results file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchResultEntry {
+    /// The `custom_id` of the request
+    pub custom_id: String,
+    /// What happened to the request
+    pub result: BatchResult,
+}
+
+/// Outcome of one batch request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BatchResult {
+    /// The request completed
+    Succeeded {
+        /// The model's response
+        message: MessagesCreateResponse,
+    },
+    /// The request failed
+    Errored {
+        /// The error response
+        error: BatchError,
+    },
+    /// The batch was canceled before the request was processed
+    Canceled,
+    /// The batch expired before the request was processed
+    Expired,
+}
+
+impl BatchResult {
+    /// The response, if the request succeeded
+    #[must_use]
+    pub const fn message(&self) -> Option<&MessagesCreateResponse> {
+        match self {
+            Self::Succeeded { message } => Some(message),
+            _ => None,
+        }
+    }
+}
+
+/// Error response of a failed batch request
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchError {
+    /// Type of the envelope (always "error")
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    /// The API error
+    pub error: ApiErrorObject,
+}
+
+/// Parses a batch results file (JSONL, one [`BatchResultEntry`] per line)
+///
+/// # Errors
+///
+/// Returns an error if a non-empty line is not a valid result entry.
+pub fn parse_batch_results(body: &[u8]) -> Result<Vec<BatchResultEntry>, AnthropicError> {
+    body.split(|b| *b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(|line| serde_json::from_slice(line).map_err(|e| crate::error::map_deser(&e, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_request_ser() {
+        let req = MessageBatchCreateRequest::from(vec![BatchRequest::new(
+            "req-1",
+            MessagesCreateRequest {
+                model: "claude-sonnet-4-6".into(),
+                max_tokens: 16,
+                ..Default::default()
+            },
+        )]);
+        let v = serde_json::to_value(&req).unwrap();
+        assert_eq!(v["requests"][0]["custom_id"], "req-1");
+        assert_eq!(v["requests"][0]["params"]["model"], "claude-sonnet-4-6");
+        assert_eq!(v["requests"][0]["params"]["max_tokens"], 16);
+    }
+
+    #[test]
+    fn message_batch_de() {
+        let batch: MessageBatch = serde_json::from_value(serde_json::json!({
+            "id": "msgbatch_1",
+            "type": "message_batch",
+            "processing_status": "in_progress",
+            "request_counts": {"processing": 2, "succeeded": 0, "errored": 0, "canceled": 0, "expired": 0},
+            "created_at": "2025-01-01T00:00:00Z",
+            "expires_at": "2025-01-02T00:00:00Z",
+            "ended_at": null,
+            "results_url": null
+        }))
+        .unwrap();
+        assert_eq!(batch.processing_status, ProcessingStatus::InProgress);
+        assert_eq!(batch.request_counts.processing, 2);
+        assert!(!batch.is_ended());
+    }
+
+    #[test]
+    fn unknown_processing_status_de() {
+        let status: ProcessingStatus = serde_json::from_str(r#""paused""#).unwrap();
+        assert_eq!(status, ProcessingStatus::Unknown);
+    }
+
+    #[test]
+    fn parse_results_jsonl() {
+        let body = br#"{"custom_id":"a","result":{"type":"succeeded","message":{"id":"msg_1","type":"message","role":"assistant","content":[{"type":"text","text":"hi"}],"model":"claude-sonnet-4-6","stop_reason":"end_turn"}}}
+{"custom_id":"b","result":{"type":"errored","error":{"type":"error","error":{"type":"invalid_request_error","message":"bad"}}}}
+
+{"custom_id":"c","result":{"type":"expired"}}
+"#;
+        let results = parse_batch_results(body).unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].result.message().is_some());
+        let BatchResult::Errored { error } = &results[1].result else {
+            panic!("expected errored result");
+        };
+        assert_eq!(error.error.message, "bad");
+        assert_eq!(results[2].result, BatchResult::Expired);
+    }
+
+    #[test]
+    fn parse_results_rejects_bad_lines() {
+        let err = parse_batch_results(b"{\"custom_id\":\"a\"}\n").unwrap_err();
+        assert!(matches!(err, AnthropicError::Serde(_)));
+    }
+}
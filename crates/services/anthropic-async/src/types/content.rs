@@ -68,6 +68,21 @@ pub enum ImageSource {
         /// URL to the image
         url: String,
     },
+    /// Image uploaded through the Files API
+    File {
+        /// ID returned by `client.files().upload()`
+        file_id: String,
+    },
+}
+
+impl ImageSource {
+    /// References an image uploaded through the Files API
+    #[must_use]
+    pub fn file(file_id: impl Into<String>) -> Self {
+        Self::File {
+            file_id: file_id.into(),
+        }
+    }
 }
 
 /// Document source for multimodal content
@@ -86,6 +101,21 @@ pub enum DocumentSource {
         /// URL to the document
         url: String,
     },
+    /// Document uploaded through the Files API
+    File {
+        /// ID returned by `client.files().upload()`
+        file_id: String,
+    },
+}
+
+impl DocumentSource {
+    /// References a document uploaded through the Files API
+    #[must_use]
+    pub fn file(file_id: impl Into<String>) -> Self {
+        Self::File {
+            file_id: file_id.into(),
+        }
+    }
 }
 
 /// Role of a message in a conversation
@@ -627,6 +657,21 @@ mod tests {
         assert!(s.contains(r#""cache_control""#));
     }
 
+    #[test]
+    fn file_sources_ser() {
+        let doc = ContentBlockParam::Document {
+            source: DocumentSource::file("file_011"),
+            cache_control: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&doc).unwrap(),
+            serde_json::json!({"type": "document", "source": {"type": "file", "file_id": "file_011"}})
+        );
+        let image: ImageSource =
+            serde_json::from_str(r#"{"type":"file","file_id":"file_012"}"#).unwrap();
+        assert_eq!(image, ImageSource::file("file_012"));
+    }
+
     #[test]
     fn content_block_param_search_result_roundtrip() {
         let cb = ContentBlockParam::SearchResult {
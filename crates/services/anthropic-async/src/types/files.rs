@@ -0,0 +1,122 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A file to upload to the Files API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileUpload {
+    /// Name the file is stored under
+    pub filename: String,
+    /// MIME type, e.g. "application/pdf"
+    pub mime_type: String,
+    /// File contents
+    pub data: bytes::Bytes,
+}
+
+impl FileUpload {
+    /// Creates an upload from in-memory contents
+    #[must_use]
+    pub fn new(
+        filename: impl Into<String>,
+        mime_type: impl Into<String>,
+        data: impl Into<bytes::Bytes>,
+    ) -> Self {
+        Self {
+            filename: filename.into(),
+            mime_type: mime_type.into(),
+            data: data.into(),
+        }
+    }
+}
+
+/// Metadata of an uploaded file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileMetadata {
+    /// File ID, used to reference the file in content blocks
+    pub id: String,
+    /// Type of resource (always "file")
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Original filename
+    pub filename: String,
+    /// MIME type of the file
+    pub mime_type: String,
+    /// Size of the file in bytes
+    pub size_bytes: u64,
+    /// When the file was uploaded
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Whether the file's contents can be downloaded
+    ///
+    /// Only files created by tools, not uploaded ones, are downloadable.
+    #[serde(default)]
+    pub downloadable: bool,
+}
+
+/// Response from listing files
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileListResponse {
+    /// Files, most recent first
+    pub data: Vec<FileMetadata>,
+    /// Whether there are more files available
+    pub has_more: bool,
+    /// ID of the first file in the list
+    pub first_id: Option<String>,
+    /// ID of the last file in the list
+    pub last_id: Option<String>,
+}
+
+/// Parameters for listing files
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileListParams {
+    /// Return files after this ID (for pagination)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_id: Option<String>,
+    /// Return files before this ID (for pagination)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_id: Option<String>,
+    /// Maximum number of files to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// Response from deleting a file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeletedFile {
+    /// ID of the deleted file
+    pub id: String,
+    /// Type of resource (always "`file_deleted`")
+    #[serde(rename = "type")]
+    pub kind: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_metadata_de() {
+        let file: FileMetadata = serde_json::from_value(serde_json::json!({
+            "id": "file_011",
+            "type": "file",
+            "filename": "report.pdf",
+            "mime_type": "application/pdf",
+            "size_bytes": 1024,
+            "created_at": "2025-01-01T00:00:00Z"
+        }))
+        .unwrap();
+        assert_eq!(file.id, "file_011");
+        assert_eq!(file.size_bytes, 1024);
+        assert!(!file.downloadable);
+    }
+
+    #[test]
+    fn list_params_skip_unset() {
+        let params = FileListParams {
+            limit: Some(10),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            serde_json::json!({"limit": 10})
+        );
+    }
+}
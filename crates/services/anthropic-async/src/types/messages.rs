@@ -287,6 +287,30 @@ pub struct MessageTokensCountRequest {
     pub tool_choice: Option<ToolChoice>,
 }
 
+impl From<&MessagesCreateRequest> for MessageTokensCountRequest {
+    fn from(req: &MessagesCreateRequest) -> Self {
+        Self {
+            model: req.model.clone(),
+            system: req.system.clone(),
+            messages: req.messages.clone(),
+            tools: req.tools.clone(),
+            tool_choice: req.tool_choice.clone(),
+        }
+    }
+}
+
+impl From<MessagesCreateRequest> for MessageTokensCountRequest {
+    fn from(req: MessagesCreateRequest) -> Self {
+        Self {
+            model: req.model,
+            system: req.system,
+            messages: req.messages,
+            tools: req.tools,
+            tool_choice: req.tool_choice,
+        }
+    }
+}
+
 /// Response from counting tokens
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct MessageTokensCountResponse {
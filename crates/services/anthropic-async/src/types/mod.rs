@@ -1,9 +1,13 @@
 //! Type definitions for Anthropic API requests and responses
 
+/// Message Batches API types
+pub mod batches;
 /// Common types used across the API
 pub mod common;
 /// Content block types for requests and responses
 pub mod content;
+/// Files API types
+pub mod files;
 /// Messages API types
 pub mod messages;
 /// Models API types
@@ -11,6 +15,13 @@ pub mod models;
 /// Tool calling types
 pub mod tools;
 
+pub use batches::BatchRequest;
+pub use batches::BatchResult;
+pub use batches::BatchResultEntry;
+pub use batches::MessageBatch;
+pub use batches::MessageBatchCreateRequest;
+pub use batches::MessageBatchListParams;
+pub use batches::ProcessingStatus;
 pub use common::CacheControl;
 pub use common::CacheTtl;
 pub use common::Metadata;
@@ -26,6 +37,11 @@ pub use content::MessageParam;
 pub use content::MessageRole;
 pub use content::SystemParam;
 pub use content::TextBlockParam;
+pub use files::DeletedFile;
+pub use files::FileListParams;
+pub use files::FileListResponse;
+pub use files::FileMetadata;
+pub use files::FileUpload;
 pub use messages::MessageTokensCountRequest;
 pub use messages::MessageTokensCountResponse;
 pub use messages::MessagesCreateRequest;
@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use anthropic_async::AnthropicConfig;
+use anthropic_async::AnthropicError;
+use anthropic_async::Client;
+use anthropic_async::types::batches::*;
+use anthropic_async::types::content::*;
+use anthropic_async::types::messages::*;
+use serde_json::Value;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::body_partial_json;
+use wiremock::matchers::header_exists;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::matchers::query_param;
+
+fn client(server: &MockServer) -> Client<AnthropicConfig> {
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test-key")
+        .with_api_base(server.uri());
+    Client::with_config(cfg)
+}
+
+fn batch(status: &str, processing: u64, succeeded: u64) -> Value {
+    json!({
+        "id": "msgbatch_123",
+        "type": "message_batch",
+        "processing_status": status,
+        "request_counts": {
+            "processing": processing,
+            "succeeded": succeeded,
+            "errored": 0,
+            "canceled": 0,
+            "expired": 0
+        },
+        "created_at": "2025-01-01T00:00:00Z",
+        "expires_at": "2025-01-02T00:00:00Z",
+        "ended_at": if status == "ended" { json!("2025-01-01T00:10:00Z") } else { Value::Null },
+        "cancel_initiated_at": null,
+        "archived_at": null,
+        "results_url": if status == "ended" {
+            json!("https://api.anthropic.com/v1/messages/batches/msgbatch_123/results")
+        } else {
+            Value::Null
+        }
+    })
+}
+
+fn request(custom_id: &str, prompt: &str) -> BatchRequest {
+    BatchRequest::new(
+        custom_id,
+        MessagesCreateRequest {
+            model: "claude-sonnet-4-6".into(),
+            max_tokens: 64,
+            messages: vec![MessageParam {
+                role: MessageRole::User,
+                content: prompt.into(),
+            }],
+            ..Default::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn create_sends_requests_with_custom_ids() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages/batches"))
+        .and(header_exists("x-api-key"))
+        .and(body_partial_json(json!({
+            "requests": [
+                {"custom_id": "q1", "params": {"model": "claude-sonnet-4-6", "max_tokens": 64}},
+                {"custom_id": "q2"}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(batch("in_progress", 2, 0)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let created = client(&server)
+        .batches()
+        .create(vec![request("q1", "2+2?"), request("q2", "3+3?")])
+        .await
+        .unwrap();
+    assert_eq!(created.id, "msgbatch_123");
+    assert_eq!(created.processing_status, ProcessingStatus::InProgress);
+    assert_eq!(created.request_counts.processing, 2);
+    assert!(created.results_url.is_none());
+}
+
+#[tokio::test]
+async fn create_rejects_duplicate_custom_ids_before_sending() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .batches()
+        .create(vec![request("q1", "a"), request("q1", "b")])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AnthropicError::Config(_)));
+}
+
+#[tokio::test]
+async fn wait_for_completion_polls_until_ended() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/messages/batches/msgbatch_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(batch("in_progress", 2, 0)))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/messages/batches/msgbatch_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(batch("ended", 0, 2)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let ended = client(&server)
+        .batches()
+        .wait_for_completion("msgbatch_123", Duration::from_millis(10))
+        .await
+        .unwrap();
+    assert!(ended.is_ended());
+    assert_eq!(ended.request_counts.succeeded, 2);
+    assert!(ended.results_url.is_some());
+}
+
+#[tokio::test]
+async fn results_parse_jsonl_lines() {
+    let server = MockServer::start().await;
+    let lines = [
+        json!({"custom_id": "q2", "result": {"type": "succeeded", "message": {
+            "id": "msg_2",
+            "type": "message",
+            "role": "assistant",
+            "content": [{"type": "text", "text": "6"}],
+            "model": "claude-sonnet-4-6",
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 10, "output_tokens": 1}
+        }}}),
+        json!({"custom_id": "q1", "result": {"type": "errored", "error": {
+            "type": "error",
+            "error": {"type": "overloaded_error", "message": "Overloaded"}
+        }}}),
+        json!({"custom_id": "q3", "result": {"type": "canceled"}}),
+    ];
+    let body = lines
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join("\n");
+    Mock::given(method("GET"))
+        .and(path("/v1/messages/batches/msgbatch_123/results"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body + "\n", "application/binary"))
+        .mount(&server)
+        .await;
+
+    let results = client(&server)
+        .batches()
+        .results("msgbatch_123")
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].custom_id, "q2");
+    let message = results[0].result.message().unwrap();
+    assert!(matches!(&message.content[0], ContentBlock::Text { text, .. } if text == "6"));
+    let BatchResult::Errored { error } = &results[1].result else {
+        panic!("expected errored result");
+    };
+    assert_eq!(error.error.r#type.as_deref(), Some("overloaded_error"));
+    assert_eq!(results[2].result, BatchResult::Canceled);
+}
+
+#[tokio::test]
+async fn list_cancel_and_delete() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/messages/batches"))
+        .and(query_param("limit", "5"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [batch("ended", 0, 2)],
+            "has_more": false,
+            "first_id": "msgbatch_123",
+            "last_id": "msgbatch_123"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages/batches/msgbatch_123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(batch("canceling", 1, 1)))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/messages/batches/msgbatch_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "msgbatch_123",
+            "type": "message_batch_deleted"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let batches = client.batches();
+    let listed = batches
+        .list(&MessageBatchListParams {
+            limit: Some(5),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(listed.data.len(), 1);
+    assert_eq!(listed.last_id.as_deref(), Some("msgbatch_123"));
+
+    let canceling = batches.cancel("msgbatch_123").await.unwrap();
+    assert_eq!(canceling.processing_status, ProcessingStatus::Canceling);
+
+    let deleted = batches.delete("msgbatch_123").await.unwrap();
+    assert_eq!(deleted.kind, "message_batch_deleted");
+}
@@ -5,6 +5,7 @@ use anthropic_async::types::messages::*;
 use wiremock::Mock;
 use wiremock::MockServer;
 use wiremock::ResponseTemplate;
+use wiremock::matchers::body_partial_json;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
 
@@ -39,3 +40,40 @@ async fn test_count_tokens() {
     let response = client.messages().count_tokens(req).await.unwrap();
     assert_eq!(response.input_tokens, 42);
 }
+
+#[tokio::test]
+async fn test_count_tokens_from_create_request() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/messages/count_tokens"))
+        .and(body_partial_json(serde_json::json!({
+            "model": "claude-haiku-4-5",
+            "system": "Be brief"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "input_tokens": 17 })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let req = MessagesCreateRequest {
+        model: "claude-haiku-4-5".into(),
+        max_tokens: 256,
+        system: Some("Be brief".into()),
+        messages: vec![MessageParam {
+            role: MessageRole::User,
+            content: "Count my tokens".into(),
+        }],
+        ..Default::default()
+    };
+
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test")
+        .with_api_base(server.uri());
+    let client = Client::with_config(cfg);
+
+    let response = client.messages().count_tokens(&req).await.unwrap();
+    assert_eq!(response.input_tokens, 17);
+}
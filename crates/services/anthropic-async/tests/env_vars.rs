@@ -1,5 +1,8 @@
 use anthropic_async::AnthropicConfig;
+use anthropic_async::AuthSource;
 use anthropic_async::config::ANTHROPIC_DEFAULT_BASE;
+use anthropic_async::config::Config;
+use anthropic_async::config::HDR_X_API_KEY;
 use anthropic_async::test_support::EnvGuard;
 use serial_test::serial;
 
@@ -67,3 +70,110 @@ fn test_both_auth_from_env() {
         });
     });
 }
+
+#[test]
+#[serial(env)]
+fn test_provider_chain_prefers_oauth_token_when_both_set() {
+    EnvGuard::with_set(
+        "ANTHROPIC_API_KEY_FILE",
+        "/nonexistent/anthropic/api_key",
+        || {
+            EnvGuard::with_removed("ANTHROPIC_AUTH_ORDER", || {
+                EnvGuard::with_set("ANTHROPIC_API_KEY", "test-key", || {
+                    EnvGuard::with_set("ANTHROPIC_AUTH_TOKEN", "test-bearer", || {
+                        let config = AnthropicConfig::new();
+                        assert_eq!(config.auth_source(), Some(AuthSource::OAuthToken));
+                        let h = config.headers().unwrap();
+                        assert!(h.contains_key(reqwest::header::AUTHORIZATION));
+                        assert!(!h.contains_key(HDR_X_API_KEY));
+                    });
+                });
+            });
+        },
+    );
+}
+
+#[test]
+#[serial(env)]
+fn test_provider_chain_respects_auth_order() {
+    EnvGuard::with_set(
+        "ANTHROPIC_API_KEY_FILE",
+        "/nonexistent/anthropic/api_key",
+        || {
+            EnvGuard::with_set("ANTHROPIC_AUTH_ORDER", "env_api_key,oauth_token", || {
+                EnvGuard::with_set("ANTHROPIC_API_KEY", "test-key", || {
+                    EnvGuard::with_set("ANTHROPIC_AUTH_TOKEN", "test-bearer", || {
+                        let config = AnthropicConfig::new();
+                        assert_eq!(config.auth_source(), Some(AuthSource::EnvApiKey));
+                        let h = config.headers().unwrap();
+                        assert!(h.contains_key(HDR_X_API_KEY));
+                        assert!(!h.contains_key(reqwest::header::AUTHORIZATION));
+                    });
+                });
+            });
+        },
+    );
+}
+
+#[test]
+#[serial(env)]
+fn test_provider_chain_uses_stored_key_file() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let key_file = dir.path().join("api_key");
+    std::fs::write(&key_file, "stored-key\n").unwrap();
+
+    EnvGuard::with_set("ANTHROPIC_API_KEY_FILE", key_file.to_str().unwrap(), || {
+        EnvGuard::with_removed("ANTHROPIC_AUTH_ORDER", || {
+            EnvGuard::with_removed("ANTHROPIC_AUTH_TOKEN", || {
+                EnvGuard::with_set("ANTHROPIC_API_KEY", "env-key", || {
+                    let config = AnthropicConfig::new();
+                    assert_eq!(config.auth_source(), Some(AuthSource::StoredApiKey));
+                });
+            });
+        });
+    });
+}
+
+#[test]
+#[serial(env)]
+fn test_provider_chain_error_lists_links_tried() {
+    EnvGuard::with_set(
+        "ANTHROPIC_API_KEY_FILE",
+        "/nonexistent/anthropic/api_key",
+        || {
+            EnvGuard::with_removed("ANTHROPIC_AUTH_ORDER", || {
+                EnvGuard::with_removed("ANTHROPIC_API_KEY", || {
+                    EnvGuard::with_set("ANTHROPIC_AUTH_TOKEN", "   ", || {
+                        let config = AnthropicConfig::new();
+                        assert_eq!(config.auth_source(), None);
+                        let err = config.validate_auth().unwrap_err().to_string();
+                        assert!(
+                            err.contains("oauth_token (ANTHROPIC_AUTH_TOKEN is empty)"),
+                            "{err}"
+                        );
+                        assert!(
+                            err.contains(
+                                "stored_api_key (/nonexistent/anthropic/api_key not found)"
+                            ),
+                            "{err}"
+                        );
+                        assert!(
+                            err.contains("env_api_key (ANTHROPIC_API_KEY not set)"),
+                            "{err}"
+                        );
+                    });
+                });
+            });
+        },
+    );
+}
+
+#[test]
+#[serial(env)]
+fn test_invalid_auth_order_is_reported() {
+    EnvGuard::with_set("ANTHROPIC_AUTH_ORDER", "keychain", || {
+        let config = AnthropicConfig::new();
+        let err = config.validate_auth().unwrap_err().to_string();
+        assert!(err.contains("Unknown auth source 'keychain'"), "{err}");
+    });
+}
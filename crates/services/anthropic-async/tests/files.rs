@@ -0,0 +1,207 @@
+use anthropic_async::AnthropicConfig;
+use anthropic_async::AnthropicError;
+use anthropic_async::Client;
+use anthropic_async::config::BetaFeature;
+use anthropic_async::types::content::*;
+use anthropic_async::types::files::*;
+use anthropic_async::types::messages::*;
+use serde_json::Value;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::body_partial_json;
+use wiremock::matchers::body_string_contains;
+use wiremock::matchers::header;
+use wiremock::matchers::headers;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::matchers::query_param;
+
+fn client(server: &MockServer) -> Client<AnthropicConfig> {
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test-key")
+        .with_api_base(server.uri());
+    Client::with_config(cfg)
+}
+
+fn file(id: &str) -> Value {
+    json!({
+        "id": id,
+        "type": "file",
+        "filename": "report.pdf",
+        "mime_type": "application/pdf",
+        "size_bytes": 9,
+        "created_at": "2025-01-01T00:00:00Z",
+        "downloadable": false
+    })
+}
+
+#[tokio::test]
+async fn upload_sends_multipart_with_files_beta() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/files"))
+        .and(header("anthropic-beta", "files-api-2025-04-14"))
+        .and(body_string_contains("filename=\"report.pdf\""))
+        .and(body_string_contains("Content-Type: application/pdf"))
+        .and(body_string_contains("%PDF-1.7\n"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(file("file_011")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let uploaded = client(&server)
+        .files()
+        .upload(FileUpload::new(
+            "report.pdf",
+            "application/pdf",
+            &b"%PDF-1.7\n"[..],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(uploaded.id, "file_011");
+    assert_eq!(uploaded.size_bytes, 9);
+}
+
+#[tokio::test]
+async fn upload_rejects_invalid_mime_type_before_sending() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let err = client(&server)
+        .files()
+        .upload(FileUpload::new("a.bin", "not a mime\n", vec![0u8]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AnthropicError::Reqwest(_)));
+}
+
+#[tokio::test]
+async fn beta_is_merged_with_configured_betas() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/files/file_011"))
+        .and(headers(
+            "anthropic-beta",
+            vec!["prompt-caching-2024-07-31", "files-api-2025-04-14"],
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(file("file_011")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test-key")
+        .with_api_base(server.uri())
+        .with_beta_features([BetaFeature::PromptCaching20240731]);
+    let retrieved = Client::with_config(cfg)
+        .files()
+        .retrieve("file_011")
+        .await
+        .unwrap();
+    assert_eq!(retrieved.filename, "report.pdf");
+}
+
+#[tokio::test]
+async fn list_and_delete() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/files"))
+        .and(query_param("limit", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": [file("file_2"), file("file_1")],
+            "has_more": true,
+            "first_id": "file_2",
+            "last_id": "file_1"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/files/file_1"))
+        .and(header("anthropic-beta", "files-api-2025-04-14"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "file_1",
+            "type": "file_deleted"
+        })))
+        .mount(&server)
+        .await;
+
+    let client = client(&server);
+    let files = client.files();
+    let listed = files
+        .list(&FileListParams {
+            limit: Some(2),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(listed.data.len(), 2);
+    assert!(listed.has_more);
+    assert_eq!(listed.last_id.as_deref(), Some("file_1"));
+
+    let deleted = files.delete("file_1").await.unwrap();
+    assert_eq!(deleted.kind, "file_deleted");
+}
+
+#[tokio::test]
+async fn messages_reference_uploaded_documents() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .and(header("anthropic-beta", "files-api-2025-04-14"))
+        .and(body_partial_json(json!({
+            "messages": [{
+                "role": "user",
+                "content": [
+                    {"type": "document", "source": {"type": "file", "file_id": "file_011"}},
+                    {"type": "text", "text": "Summarize this report."}
+                ]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "content": [{"type": "text", "text": "It is short."}],
+            "model": "claude-sonnet-4-6"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test-key")
+        .with_api_base(server.uri())
+        .with_beta_features([BetaFeature::FilesApi20250414]);
+    let req = MessagesCreateRequest {
+        model: "claude-sonnet-4-6".into(),
+        max_tokens: 64,
+        messages: vec![MessageParam {
+            role: MessageRole::User,
+            content: MessageContentParam::Blocks(vec![
+                ContentBlockParam::Document {
+                    source: DocumentSource::file("file_011"),
+                    cache_control: None,
+                },
+                ContentBlockParam::Text {
+                    text: "Summarize this report.".into(),
+                    citations: None,
+                    cache_control: None,
+                },
+            ]),
+        }],
+        ..Default::default()
+    };
+
+    let response = Client::with_config(cfg)
+        .messages()
+        .create(req)
+        .await
+        .unwrap();
+    assert_eq!(response.id, "msg_1");
+}
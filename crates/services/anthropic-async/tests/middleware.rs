@@ -0,0 +1,162 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anthropic_async::AnthropicConfig;
+use anthropic_async::Client;
+use anthropic_async::middleware::Interceptor;
+use anthropic_async::middleware::RequestEvent;
+use anthropic_async::middleware::ResponseEvent;
+use anthropic_async::types::common::Usage;
+use anthropic_async::types::content::*;
+use anthropic_async::types::messages::*;
+use backon::ExponentialBuilder;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+/// What the recorder saw for one call
+#[derive(Debug, Clone, PartialEq)]
+struct Call {
+    path: String,
+    status: Option<u16>,
+    retries: u32,
+    usage: Option<Usage>,
+    failed: bool,
+}
+
+#[derive(Default)]
+struct Recorder {
+    requests: Mutex<Vec<String>>,
+    responses: Mutex<Vec<Call>>,
+}
+
+impl Interceptor for Recorder {
+    fn on_request(&self, event: &RequestEvent<'_>) {
+        self.requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(format!("{} {}", event.method, event.path));
+    }
+
+    fn on_response(&self, event: &ResponseEvent<'_>) {
+        self.responses
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(Call {
+                path: event.path.to_string(),
+                status: event.status.map(|s| s.as_u16()),
+                retries: event.retries,
+                usage: event.usage.cloned(),
+                failed: event.error.is_some(),
+            });
+    }
+}
+
+fn client(server: &MockServer, recorder: &Arc<Recorder>) -> Client<AnthropicConfig> {
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test")
+        .with_api_base(server.uri());
+    Client::with_config(cfg)
+        .with_backoff(
+            ExponentialBuilder::default()
+                .with_min_delay(Duration::from_millis(1))
+                .with_max_times(2),
+        )
+        .with_interceptor(Arc::clone(recorder) as Arc<dyn Interceptor>)
+}
+
+fn request() -> MessagesCreateRequest {
+    MessagesCreateRequest {
+        model: "claude-sonnet-4-6".into(),
+        max_tokens: 16,
+        messages: vec![MessageParam {
+            role: MessageRole::User,
+            content: "hi".into(),
+        }],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn reports_usage_and_retries() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(ResponseTemplate::new(529).set_body_json(serde_json::json!({
+            "type": "error",
+            "error": {"type": "overloaded_error", "message": "Overloaded"}
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "content": [{"type": "text", "text": "hello"}],
+            "model": "claude-sonnet-4-6",
+            "usage": {"input_tokens": 12, "output_tokens": 3}
+        })))
+        .mount(&server)
+        .await;
+
+    let recorder = Arc::new(Recorder::default());
+    client(&server, &recorder)
+        .messages()
+        .create(request())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *recorder.requests.lock().unwrap(),
+        vec!["POST /v1/messages".to_string()]
+    );
+    assert_eq!(
+        *recorder.responses.lock().unwrap(),
+        vec![Call {
+            path: "/v1/messages".into(),
+            status: Some(200),
+            retries: 1,
+            usage: Some(Usage {
+                input_tokens: Some(12),
+                output_tokens: Some(3),
+                ..Default::default()
+            }),
+            failed: false,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn reports_failed_calls() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/v1/models/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "type": "error",
+            "error": {"type": "not_found_error", "message": "model not found"}
+        })))
+        .mount(&server)
+        .await;
+
+    let recorder = Arc::new(Recorder::default());
+    let result = client(&server, &recorder).models().get("missing").await;
+    assert!(result.is_err());
+
+    let responses = recorder.responses.lock().unwrap();
+    assert_eq!(
+        *responses,
+        vec![Call {
+            path: "/v1/models/missing".into(),
+            status: Some(404),
+            retries: 0,
+            usage: None,
+            failed: true,
+        }]
+    );
+}
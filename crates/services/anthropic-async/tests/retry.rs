@@ -1,10 +1,16 @@
 use anthropic_async::AnthropicConfig;
+use anthropic_async::AnthropicError;
 use anthropic_async::Client;
+use anthropic_async::retry::CircuitBreaker;
+use anthropic_async::retry::CircuitState;
+use anthropic_async::retry::RetryBudget;
 use anthropic_async::types::content::*;
 use anthropic_async::types::messages::*;
+use backon::ExponentialBuilder;
 use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
+use std::time::Duration;
 use wiremock::Mock;
 use wiremock::MockServer;
 use wiremock::ResponseTemplate;
@@ -147,3 +153,108 @@ async fn test_non_retryable_400() {
         _ => panic!("Expected Api error"),
     }
 }
+
+fn overloaded() -> ResponseTemplate {
+    ResponseTemplate::new(529).set_body_json(serde_json::json!({
+        "type": "error",
+        "error": {"type": "overloaded_error", "message": "Overloaded"}
+    }))
+}
+
+fn fast_client(server: &MockServer) -> Client<AnthropicConfig> {
+    let cfg = AnthropicConfig::new()
+        .with_api_base(server.uri())
+        .with_api_key("test");
+    Client::with_config(cfg).with_backoff(
+        ExponentialBuilder::default()
+            .with_min_delay(Duration::from_millis(1))
+            .with_max_times(10),
+    )
+}
+
+fn ping() -> MessagesCreateRequest {
+    MessagesCreateRequest {
+        model: "claude".into(),
+        max_tokens: 10,
+        messages: vec![MessageParam {
+            role: MessageRole::User,
+            content: "test".into(),
+        }],
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn retry_budget_is_shared_across_calls() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(overloaded())
+        .expect(5)
+        .mount(&server)
+        .await;
+
+    let budget = Arc::new(RetryBudget::new(3));
+    let client = fast_client(&server).with_retry_budget(Arc::clone(&budget));
+
+    // First call spends the whole budget: 1 attempt + 3 retries
+    assert!(client.messages().create(ping()).await.is_err());
+    assert_eq!(budget.available(), 0);
+
+    // A clone shares the budget, so its call is not retried at all
+    let err = client.clone().messages().create(ping()).await.unwrap_err();
+    assert!(err.is_overloaded());
+}
+
+#[tokio::test]
+async fn circuit_breaker_fails_fast_then_probes() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(overloaded())
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "msg",
+            "type": "message",
+            "role": "assistant",
+            "content": [{"type": "text", "text": "Success"}],
+            "model": "claude"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let transitions = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = Arc::clone(&transitions);
+    let breaker = Arc::new(
+        CircuitBreaker::new(2)
+            .with_cooldown(Duration::from_millis(50))
+            .with_listener(move |_, to| seen.lock().unwrap().push(to)),
+    );
+    let client = fast_client(&server).with_circuit_breaker(Arc::clone(&breaker));
+
+    // The second overload opens the breaker, which stops the retries
+    let err = client.messages().create(ping()).await.unwrap_err();
+    assert!(err.is_overloaded());
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    let err = client.messages().create(ping()).await.unwrap_err();
+    assert!(matches!(err, AnthropicError::CircuitOpen));
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let response = client.messages().create(ping()).await.unwrap();
+    assert_eq!(response.kind, "message");
+    assert_eq!(
+        *transitions.lock().unwrap(),
+        vec![
+            CircuitState::Open,
+            CircuitState::HalfOpen,
+            CircuitState::Closed
+        ]
+    );
+}
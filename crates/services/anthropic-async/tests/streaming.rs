@@ -5,6 +5,7 @@
 #![cfg(feature = "streaming")]
 
 use anthropic_async::streaming::Accumulator;
+use anthropic_async::streaming::AccumulatorEvent;
 use anthropic_async::streaming::ContentBlockDeltaData;
 use anthropic_async::streaming::ContentBlockStartData;
 use anthropic_async::streaming::Event;
@@ -599,3 +600,110 @@ fn accumulator_delta_invalid_index() {
             .contains("unknown block index")
     );
 }
+
+// =============================================================================
+// Accumulator Events
+// =============================================================================
+
+fn message_start() -> Event {
+    Event::MessageStart {
+        message: MessageStartPayload {
+            id: "msg_tools".to_string(),
+            kind: "message".to_string(),
+            role: MessageRole::Assistant,
+            model: "claude".to_string(),
+            content: vec![],
+            stop_reason: None,
+            stop_sequence: None,
+            usage: None,
+        },
+    }
+}
+
+fn tool_start(index: usize, id: &str) -> Event {
+    Event::ContentBlockStart {
+        index,
+        content_block: ContentBlockStartData::ToolUse {
+            id: id.to_string(),
+            name: "get_weather".to_string(),
+            input: serde_json::json!({}),
+        },
+    }
+}
+
+fn json_delta(index: usize, partial_json: &str) -> Event {
+    Event::ContentBlockDelta {
+        index,
+        delta: ContentBlockDeltaData::InputJsonDelta {
+            partial_json: partial_json.to_string(),
+        },
+    }
+}
+
+#[test]
+fn accumulator_events_report_tool_use_before_message_stop() {
+    let mut acc = Accumulator::new();
+    let mut seen = Vec::new();
+
+    for event in [
+        message_start(),
+        tool_start(0, "tool_1"),
+        json_delta(0, r#"{"city":"#),
+        json_delta(0, r#""Paris"}"#),
+        Event::ContentBlockStop { index: 0 },
+        tool_start(1, "tool_2"),
+        Event::ContentBlockStop { index: 1 },
+    ] {
+        seen.extend(acc.apply_events(&event).unwrap());
+    }
+
+    assert_eq!(
+        seen,
+        vec![
+            AccumulatorEvent::ToolUseComplete {
+                index: 0,
+                id: "tool_1".to_string(),
+                name: "get_weather".to_string(),
+                input: serde_json::json!({"city": "Paris"}),
+            },
+            AccumulatorEvent::ToolUseComplete {
+                index: 1,
+                id: "tool_2".to_string(),
+                name: "get_weather".to_string(),
+                input: serde_json::json!({}),
+            },
+        ]
+    );
+
+    acc.apply_events(&Event::MessageDelta {
+        delta: MessageDeltaPayload {
+            stop_reason: Some("tool_use".to_string()),
+            stop_sequence: None,
+        },
+        usage: Some(MessageDeltaUsage { output_tokens: 12 }),
+    })
+    .unwrap();
+    let events = acc.apply_events(&Event::MessageStop).unwrap();
+    let [AccumulatorEvent::MessageComplete(response)] = events.as_slice() else {
+        panic!("expected MessageComplete, got {events:?}");
+    };
+    assert_eq!(response.content.len(), 2);
+    assert!(matches!(
+        &response.content[0],
+        ContentBlock::ToolUse { input, .. } if input["city"] == "Paris"
+    ));
+}
+
+#[test]
+fn accumulator_events_fail_on_invalid_tool_json_at_block_stop() {
+    let mut acc = Accumulator::new();
+    acc.apply_events(&message_start()).unwrap();
+    acc.apply_events(&tool_start(0, "tool_1")).unwrap();
+    acc.apply_events(&json_delta(0, r#"{"city": invalid"#))
+        .unwrap();
+
+    let err = acc
+        .apply_events(&Event::ContentBlockStop { index: 0 })
+        .unwrap_err();
+    assert!(err.to_string().contains("tool input JSON"));
+}
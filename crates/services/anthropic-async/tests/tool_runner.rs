@@ -0,0 +1,265 @@
+//! Tests for the `ToolRunner` agentic loop
+
+#![cfg(feature = "tool-runner")]
+
+use std::sync::Arc;
+
+use agentic_tools_core::TextFormat;
+use agentic_tools_core::Tool;
+use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolRegistry;
+use anthropic_async::AnthropicConfig;
+use anthropic_async::Client;
+use anthropic_async::tool_runner::ToolRunStop;
+use anthropic_async::tool_runner::ToolRunner;
+use anthropic_async::types::content::MessageParam;
+use anthropic_async::types::content::MessageRole;
+use anthropic_async::types::messages::MessagesCreateRequest;
+use futures::future::BoxFuture;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[derive(Clone)]
+struct AddTool;
+
+#[derive(Deserialize, JsonSchema)]
+struct AddInput {
+    a: i64,
+    b: i64,
+}
+
+#[derive(Serialize, JsonSchema)]
+struct AddOutput {
+    sum: i64,
+}
+
+impl TextFormat for AddOutput {
+    fn fmt_text(&self, _opts: &agentic_tools_core::TextOptions) -> String {
+        self.sum.to_string()
+    }
+}
+
+impl Tool for AddTool {
+    type Input = AddInput;
+    type Output = AddOutput;
+    const NAME: &'static str = "add";
+    const DESCRIPTION: &'static str = "Add two integers";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        Box::pin(async move {
+            Ok(AddOutput {
+                sum: input.a + input.b,
+            })
+        })
+    }
+}
+
+fn runner() -> ToolRunner {
+    let registry = ToolRegistry::builder()
+        .register::<AddTool, ()>(AddTool)
+        .finish();
+    ToolRunner::new(Arc::new(registry))
+}
+
+fn client(server: &MockServer) -> Client<AnthropicConfig> {
+    let cfg = AnthropicConfig::new()
+        .with_api_key("test-key")
+        .with_api_base(server.uri());
+    Client::with_config(cfg)
+}
+
+fn request() -> MessagesCreateRequest {
+    MessagesCreateRequest {
+        model: "claude-sonnet-4-6".into(),
+        max_tokens: 256,
+        messages: vec![MessageParam {
+            role: MessageRole::User,
+            content: "What is 2 + 3, and what is 5 + 8?".into(),
+        }],
+        ..Default::default()
+    }
+}
+
+fn tool_use_response(calls: &[(&str, &str, Value)]) -> Value {
+    let content: Vec<Value> = calls
+        .iter()
+        .map(
+            |(id, name, input)| json!({"type": "tool_use", "id": id, "name": name, "input": input}),
+        )
+        .collect();
+    json!({
+        "id": "msg_tool",
+        "type": "message",
+        "role": "assistant",
+        "content": content,
+        "model": "claude-sonnet-4-6",
+        "stop_reason": "tool_use",
+        "usage": {"input_tokens": 50, "output_tokens": 20}
+    })
+}
+
+fn text_response(text: &str) -> Value {
+    json!({
+        "id": "msg_text",
+        "type": "message",
+        "role": "assistant",
+        "content": [{"type": "text", "text": text}],
+        "model": "claude-sonnet-4-6",
+        "stop_reason": "end_turn",
+        "usage": {"input_tokens": 80, "output_tokens": 10}
+    })
+}
+
+/// Request bodies the mock server received, in order
+#[expect(
+    clippy::unwrap_used,
+    reason = "request recording is on by default and the client only sends JSON"
+)]
+async fn received_bodies(server: &MockServer) -> Vec<Value> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| serde_json::from_slice(&r.body).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn dispatches_tool_calls_until_the_model_stops() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(tool_use_response(&[
+                ("toolu_1", "add", json!({"a": 2, "b": 3})),
+                ("toolu_2", "add", json!({"a": 5, "b": 8})),
+            ])),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(text_response("5 and 13.")))
+        .mount(&server)
+        .await;
+
+    let outcome = runner().run(&client(&server), request()).await.unwrap();
+
+    assert_eq!(outcome.stop, ToolRunStop::Finished);
+    assert_eq!(outcome.iterations, 1);
+    assert_eq!(outcome.text(), "5 and 13.");
+    assert_eq!(outcome.usage.input_tokens, Some(130));
+    assert_eq!(outcome.usage.output_tokens, Some(30));
+    // user, assistant tool_use, user tool_result, assistant text
+    assert_eq!(outcome.messages.len(), 4);
+
+    let bodies = received_bodies(&server).await;
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(bodies[0]["tools"][0]["name"], "add");
+    assert_eq!(bodies[0]["tools"][0]["description"], "Add two integers");
+    let results = &bodies[1]["messages"][2]["content"];
+    assert_eq!(bodies[1]["messages"][2]["role"], "user");
+    assert_eq!(results[0]["type"], "tool_result");
+    assert_eq!(results[0]["tool_use_id"], "toolu_1");
+    assert_eq!(results[0]["content"], "5");
+    assert_eq!(results[1]["tool_use_id"], "toolu_2");
+    assert_eq!(results[1]["content"], "13");
+    assert!(results[0].get("is_error").is_none());
+}
+
+#[tokio::test]
+async fn failed_calls_are_reported_to_the_model() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(tool_use_response(&[
+                ("toolu_1", "subtract", json!({"a": 2, "b": 3})),
+                ("toolu_2", "add", json!({"a": "two"})),
+            ])),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(text_response("Sorry.")))
+        .mount(&server)
+        .await;
+
+    let outcome = runner().run(&client(&server), request()).await.unwrap();
+    assert_eq!(outcome.stop, ToolRunStop::Finished);
+
+    let bodies = received_bodies(&server).await;
+    let results = &bodies[1]["messages"][2]["content"];
+    assert_eq!(results[0]["is_error"], true);
+    assert!(
+        results[0]["content"]
+            .as_str()
+            .unwrap()
+            .contains("Unknown tool: subtract")
+    );
+    assert_eq!(results[1]["is_error"], true);
+}
+
+#[tokio::test]
+async fn stops_at_the_iteration_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(tool_use_response(&[(
+                "toolu_1",
+                "add",
+                json!({"a": 1, "b": 1}),
+            )])),
+        )
+        .mount(&server)
+        .await;
+
+    let outcome = runner()
+        .with_max_iterations(2)
+        .run(&client(&server), request())
+        .await
+        .unwrap();
+
+    assert_eq!(outcome.stop, ToolRunStop::IterationLimit);
+    assert_eq!(outcome.iterations, 2);
+    assert_eq!(received_bodies(&server).await.len(), 3);
+    // The unanswered tool_use turn ends the transcript
+    let last = outcome.messages.last().unwrap();
+    assert_eq!(last.role, MessageRole::Assistant);
+}
+
+#[tokio::test]
+async fn explicit_tools_are_left_alone() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(text_response("Hi")))
+        .mount(&server)
+        .await;
+
+    let mut req = request();
+    req.tools = Some(Vec::new());
+    let outcome = runner().run(&client(&server), req).await.unwrap();
+
+    assert_eq!(outcome.iterations, 0);
+    let bodies = received_bodies(&server).await;
+    assert_eq!(bodies[0]["tools"], json!([]));
+}
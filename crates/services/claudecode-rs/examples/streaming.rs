@@ -48,8 +48,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("Initialized with model: {:?}", sys.model);
                     }
                 }
-                Event::Unknown => {
-                    // Forward compatibility
+                Event::ControlRequest(_) | Event::Unknown => {
+                    // Answered by the session / forward compatibility
                 }
             }
         }
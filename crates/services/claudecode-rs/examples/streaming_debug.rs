@@ -88,6 +88,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     println!("  Session ID: {}", err.session_id);
                     println!("  Error: {}", err.error);
                 }
+                Event::ControlRequest(req) => {
+                    println!("Control request event!");
+                    println!("  Request ID: {}", req.request_id);
+                }
                 Event::Unknown => {
                     println!("Unknown event type (forward compatibility)");
                 }
@@ -1,15 +1,35 @@
+use claudecode::types::AssistantEvent;
+use claudecode::types::Content;
+use claudecode::types::ControlRequest;
+use claudecode::types::ControlRequestEvent;
 use claudecode::types::Event;
+use claudecode::types::Message;
+use claudecode::types::PermissionRequest;
 use claudecode::types::Result as ClaudeResult;
 use claudecode::types::ResultEvent;
 use claudecode::types::SystemEvent;
+use claudecode::types::Usage;
+use claudecode::types::UserInput;
 use std::env;
 use std::path::PathBuf;
 use std::process::Child;
 use std::process::Command;
 use std::process::Stdio;
+use tokio::io::AsyncBufReadExt;
 use tokio::io::AsyncWriteExt;
 use tokio::signal::unix::SignalKind;
 
+const FAKE_VERSION: &str = "1.0.80";
+const FAKE_HELP_FLAGS: &[&str] = &[
+    "--print",
+    "--output-format <format>",
+    "--input-format <format>",
+    "--model <model>",
+    "--mcp-config <configs...>",
+    "--disallowedTools <tools...>",
+    "--verbose",
+];
+
 #[derive(serde::Serialize)]
 struct PidInfo {
     parent_pid: u32,
@@ -49,6 +69,23 @@ impl Drop for HelperChild {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
+
+    // Capability probes: an older CLI that hides --strict-mcp-config from --help
+    match args.get(1).map(String::as_str) {
+        Some("--help") => {
+            println!("Usage: claude [options] [query]");
+            for flag in FAKE_HELP_FLAGS {
+                println!("  {flag}");
+            }
+            return Ok(());
+        }
+        Some("--version") => {
+            println!("{FAKE_VERSION} (Claude Code)");
+            return Ok(());
+        }
+        _ => {}
+    }
+
     let output_format = arg_value(&args, "--output-format").unwrap_or_else(|| "text".into());
     let query = args
         .iter()
@@ -77,7 +114,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     maybe_force_error_after_spawn()?;
 
-    emit_output(&output_format).await?;
+    // Like the real CLI, a resumed (unforked) session keeps its ID
+    let session_id = arg_value(&args, "--resume")
+        .filter(|_| !args.iter().any(|arg| arg == "--fork-session"))
+        .unwrap_or_else(|| "fake-session".into());
+
+    // Streaming input: answer each user message on stdin, exit once it closes
+    if arg_value(&args, "--input-format").as_deref() == Some("stream-json") {
+        let prompt_permissions =
+            arg_value(&args, "--permission-prompt-tool").as_deref() == Some("stdio");
+        return echo_input(&session_id, prompt_permissions).await;
+    }
+
+    emit_output(&output_format, &session_id).await?;
 
     let mut sigterm = tokio::signal::unix::signal(SignalKind::terminate())?;
     let mut sigint = tokio::signal::unix::signal(SignalKind::interrupt())?;
@@ -114,7 +163,129 @@ fn arg_value(args: &[String], flag: &str) -> Option<String> {
         .map(|window| window[1].clone())
 }
 
-async fn emit_output(output_format: &str) -> Result<(), Box<dyn std::error::Error>> {
+async fn echo_input(
+    session_id: &str,
+    prompt_permissions: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut stdout = tokio::io::stdout();
+    write_event(&mut stdout, &system_event(session_id)).await?;
+
+    let mut lines = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+    let mut request_count = 0;
+    let mut turn: u32 = 0;
+    while let Some(line) = lines.next_line().await? {
+        turn += 1;
+        let input: UserInput = serde_json::from_str(&line)?;
+        let text = input
+            .message
+            .content
+            .iter()
+            .filter_map(Content::get_text)
+            .collect::<String>();
+
+        // "[tool] <command>" asks to run a Bash command, prompting first when enabled
+        let reply = match text.strip_prefix("[tool] ") {
+            Some(command) if prompt_permissions => {
+                request_count += 1;
+                let request = Event::ControlRequest(ControlRequestEvent {
+                    request_id: format!("perm-{request_count}"),
+                    request: ControlRequest::CanUseTool(PermissionRequest {
+                        tool_name: "Bash".into(),
+                        input: serde_json::json!({ "command": command }),
+                        tool_use_id: None,
+                        blocked_path: None,
+                    }),
+                });
+                write_event(&mut stdout, &request).await?;
+
+                let answer = lines.next_line().await?.unwrap_or_default();
+                let answer: serde_json::Value = serde_json::from_str(&answer)?;
+                let response = &answer["response"]["response"];
+                if response["behavior"] == "allow" {
+                    format!(
+                        "ran: {}",
+                        response["updatedInput"]["command"].as_str().unwrap_or("")
+                    )
+                } else {
+                    format!("denied: {}", response["message"].as_str().unwrap_or(""))
+                }
+            }
+            Some(command) => format!("ran: {command}"),
+            None => format!("echo: {text}"),
+        };
+
+        // Like the real CLI, repeat the message (and its usage) once per content block
+        let assistant = Event::Assistant(AssistantEvent {
+            session_id: session_id.into(),
+            message: Message {
+                id: Some(format!("msg-{turn}")),
+                message_type: Some("message".into()),
+                role: "assistant".into(),
+                model: Some("fake-model".into()),
+                content: vec![Content::text(reply.clone())],
+                usage: Some(Usage {
+                    input_tokens: 10,
+                    output_tokens: 5,
+                    cache_creation_input_tokens: None,
+                    cache_read_input_tokens: Some(100),
+                    service_tier: None,
+                    server_tool_use: None,
+                }),
+            },
+        });
+        write_event(&mut stdout, &assistant).await?;
+        write_event(&mut stdout, &assistant).await?;
+
+        // The reported cost is a running total for the process
+        let result = result_event(session_id, reply, Some(0.01 * f64::from(turn)));
+        write_event(&mut stdout, &result).await?;
+    }
+
+    Ok(())
+}
+
+async fn write_event(
+    stdout: &mut tokio::io::Stdout,
+    event: &Event,
+) -> Result<(), Box<dyn std::error::Error>> {
+    stdout
+        .write_all(format!("{}\n", serde_json::to_string(event)?).as_bytes())
+        .await?;
+    stdout.flush().await?;
+    Ok(())
+}
+
+fn system_event(session_id: &str) -> Event {
+    Event::System(SystemEvent {
+        session_id: session_id.into(),
+        subtype: Some("init".into()),
+        cwd: None,
+        model: Some("fake-model".into()),
+        permission_mode: None,
+        api_key_source: None,
+        tools: None,
+        mcp_servers: None,
+    })
+}
+
+fn result_event(session_id: &str, result: String, total_cost_usd: Option<f64>) -> Event {
+    Event::Result(ResultEvent {
+        session_id: session_id.into(),
+        result: Some(result),
+        is_error: false,
+        error: None,
+        total_cost_usd,
+        duration_ms: Some(1),
+        duration_api_ms: Some(1),
+        num_turns: Some(1),
+        usage: None,
+    })
+}
+
+async fn emit_output(
+    output_format: &str,
+    session_id: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
     let mut stdout = tokio::io::stdout();
 
     match output_format {
@@ -124,7 +295,7 @@ async fn emit_output(output_format: &str) -> Result<(), Box<dyn std::error::Erro
                 content: Some("fake json output".into()),
                 result: Some("fake json output".into()),
                 is_error: false,
-                session_id: Some("fake-session".into()),
+                session_id: Some(session_id.into()),
                 ..ClaudeResult::default()
             };
             stdout
@@ -132,27 +303,8 @@ async fn emit_output(output_format: &str) -> Result<(), Box<dyn std::error::Erro
                 .await?;
         }
         "stream-json" => {
-            let system = Event::System(SystemEvent {
-                session_id: "fake-session".into(),
-                subtype: Some("init".into()),
-                cwd: None,
-                model: Some("fake-model".into()),
-                permission_mode: None,
-                api_key_source: None,
-                tools: None,
-                mcp_servers: None,
-            });
-            let result = Event::Result(ResultEvent {
-                session_id: "fake-session".into(),
-                result: Some("fake stream output".into()),
-                is_error: false,
-                error: None,
-                total_cost_usd: None,
-                duration_ms: Some(1),
-                duration_api_ms: Some(1),
-                num_turns: Some(1),
-                usage: None,
-            });
+            let system = system_event(session_id);
+            let result = result_event(session_id, "fake stream output".into(), None);
             stdout
                 .write_all(
                     format!(
@@ -16,13 +16,44 @@ use tracing::debug;
 #[derive(Debug, Clone)]
 pub struct Client {
     claude_path: PathBuf,
+    /// Replayed in place of the CLI, when set
+    #[cfg(feature = "mock")]
+    replay: Option<std::sync::Arc<crate::mock::ReplayScript>>,
 }
 
 impl Client {
     /// Create a new client by finding claude in PATH
+    ///
+    /// With the `mock` feature, a fixture named by
+    /// [`REPLAY_FIXTURE_ENV`](crate::mock::REPLAY_FIXTURE_ENV) is replayed instead.
     pub async fn new() -> Result<Self> {
+        #[cfg(feature = "mock")]
+        if let Some(path) = std::env::var_os(crate::mock::REPLAY_FIXTURE_ENV) {
+            let script = crate::mock::ReplayScript::load(path).await?;
+            return Ok(Self::replay(script));
+        }
+
         let claude_path = find_claude_in_path().await?;
-        Ok(Self { claude_path })
+        Ok(Self::from_path(claude_path))
+    }
+
+    /// Create a client whose sessions replay `script` instead of running the CLI
+    ///
+    /// Every launch replays the script from the start.
+    #[cfg(feature = "mock")]
+    pub fn replay(script: crate::mock::ReplayScript) -> Self {
+        Self {
+            claude_path: PathBuf::from("claude"),
+            replay: Some(std::sync::Arc::new(script)),
+        }
+    }
+
+    const fn from_path(claude_path: PathBuf) -> Self {
+        Self {
+            claude_path,
+            #[cfg(feature = "mock")]
+            replay: None,
+        }
     }
 
     /// Create a new client with a specific claude path
@@ -33,9 +64,7 @@ impl Client {
                 path: path.to_path_buf(),
             });
         }
-        Ok(Self {
-            claude_path: path.to_path_buf(),
-        })
+        Ok(Self::from_path(path.to_path_buf()))
     }
 
     /// Launch a new Claude session asynchronously
@@ -51,11 +80,21 @@ impl Client {
             .as_ref()
             .map(|dir| expand_tilde(dir.to_str().unwrap_or("")));
 
+        let pipe_stdin = config.input_format == Some(crate::types::InputFormat::StreamJson);
+
+        #[cfg(feature = "mock")]
+        if let Some(script) = self.replay.as_ref() {
+            let process = ProcessHandle::replay(script, pipe_stdin);
+            return Session::new(config, process).await;
+        }
+
         let process = ProcessHandle::spawn(
             &self.claude_path,
             args,
             working_dir.as_deref(),
             config.env.as_ref(),
+            &config.env_policy,
+            pipe_stdin,
         )
         .await?;
 
@@ -67,6 +106,19 @@ impl Client {
         Ok(session)
     }
 
+    /// Launch a session continuing the conversation `session_id` (maps to --resume)
+    ///
+    /// `session_id` is usually [`Session::claude_session_id`] or the `session_id` of an
+    /// earlier call's result, so multi-turn workflows keep their context.
+    pub async fn resume(
+        &self,
+        session_id: impl Into<String>,
+        mut config: SessionConfig,
+    ) -> Result<Session> {
+        config.resume_session_id = Some(session_id.into());
+        self.launch(config).await
+    }
+
     /// Launch a session and wait for it to complete
     pub async fn launch_and_wait(&self, config: SessionConfig) -> Result<ClaudeResult> {
         let session = self.launch(config).await?;
@@ -88,6 +140,11 @@ impl Client {
     /// }
     /// ```
     pub async fn probe_cli(&self) -> Result<crate::probe::CliCapabilities> {
+        // A replay accepts every flag, which unknown capabilities express
+        #[cfg(feature = "mock")]
+        if self.replay.is_some() {
+            return Ok(crate::probe::CliCapabilities::default());
+        }
         crate::probe::probe_cli(&self.claude_path).await
     }
 
@@ -141,6 +198,10 @@ impl Client {
         if config.dangerously_skip_permissions {
             args.push("--dangerously-skip-permissions".to_string());
         }
+        if config.permission_handler.is_some() {
+            args.push("--permission-prompt-tool".to_string());
+            args.push("stdio".to_string());
+        }
 
         // Prompts
         if let Some(ref prompt) = config.system_prompt {
@@ -177,6 +238,10 @@ impl Client {
         if config.replay_user_messages {
             args.push("--replay-user-messages".to_string());
         }
+        if let Some(ref style) = config.output_style {
+            args.push("--output-style".to_string());
+            args.push(style.clone());
+        }
 
         // Configuration
         if let Some(ref settings) = config.settings {
@@ -238,9 +303,12 @@ impl Client {
             args.push("--verbose".to_string());
         }
 
-        // Always add -- separator before query to protect dash-prefixed queries
-        args.push("--".to_string());
-        args.push(config.query.clone());
+        // With streaming input the query is sent on stdin as the first user message;
+        // otherwise add -- separator before query to protect dash-prefixed queries
+        if config.input_format != Some(crate::types::InputFormat::StreamJson) {
+            args.push("--".to_string());
+            args.push(config.query.clone());
+        }
 
         Ok((args, mcp_file))
     }
@@ -261,9 +329,7 @@ mod tests {
     use crate::types::PermissionMode;
 
     fn create_test_client() -> Client {
-        Client {
-            claude_path: PathBuf::from("/usr/bin/claude"),
-        }
+        Client::from_path(PathBuf::from("/usr/bin/claude"))
     }
 
     #[tokio::test]
@@ -409,7 +475,7 @@ mod tests {
         let client = create_test_client();
         let config = SessionConfig::builder("test")
             .input_format(InputFormat::StreamJson)
-            .output_format(OutputFormat::Text)
+            .output_format(OutputFormat::StreamingJson)
             .build()
             .unwrap();
 
@@ -417,6 +483,29 @@ mod tests {
 
         let input_pos = args.iter().position(|a| a == "--input-format").unwrap();
         assert_eq!(args[input_pos + 1], "stream-json");
+
+        // The query goes to stdin instead of the command line
+        assert!(!args.contains(&"--".to_string()));
+        assert!(!args.contains(&"test".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_build_args_with_permission_handler() {
+        let client = create_test_client();
+        let config = SessionConfig::builder("test")
+            .input_format(InputFormat::StreamJson)
+            .output_format(OutputFormat::StreamingJson)
+            .permission_handler(|_| async { crate::PermissionDecision::allow() })
+            .build()
+            .unwrap();
+
+        let (args, _) = client.build_args(&config).await.unwrap();
+
+        let pos = args
+            .iter()
+            .position(|a| a == "--permission-prompt-tool")
+            .unwrap();
+        assert_eq!(args[pos + 1], "stdio");
     }
 
     #[tokio::test]
@@ -426,6 +515,7 @@ mod tests {
             .json_schema(r#"{"type":"object"}"#)
             .include_partial_messages(true)
             .replay_user_messages(true)
+            .output_style("Explanatory")
             .output_format(OutputFormat::Text)
             .build()
             .unwrap();
@@ -436,6 +526,8 @@ mod tests {
         assert_eq!(args[schema_pos + 1], r#"{"type":"object"}"#);
         assert!(args.contains(&"--include-partial-messages".to_string()));
         assert!(args.contains(&"--replay-user-messages".to_string()));
+        let style_pos = args.iter().position(|a| a == "--output-style").unwrap();
+        assert_eq!(args[style_pos + 1], "Explanatory");
     }
 
     #[tokio::test]
@@ -1,5 +1,11 @@
 use crate::error::ClaudeError;
 use crate::error::Result;
+use crate::mcp::builder::MCPConfigBuilder;
+use crate::mcp::builder::RemoteServerBuilder;
+use crate::mcp::builder::StdioServerBuilder;
+use crate::metrics::MetricsCallback;
+use crate::permission::PermissionHandler;
+use crate::probe::CliCapabilities;
 use crate::types::InputFormat;
 use crate::types::Model;
 use crate::types::OutputFormat;
@@ -8,8 +14,13 @@ use serde::Deserialize;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
-/// MCP Server configuration - supports both stdio (subprocess) and HTTP server types
+/// MCP Server configuration - supports stdio (subprocess), HTTP, and SSE server types
+///
+/// See [`crate::mcp::builder`] for builders that add arguments, environment, and
+/// credentials one at a time.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum MCPServer {
@@ -28,6 +39,13 @@ pub enum MCPServer {
         #[serde(skip_serializing_if = "Option::is_none")]
         headers: Option<HashMap<String, String>>,
     },
+    /// Server-sent events MCP server
+    #[serde(rename = "sse")]
+    Sse {
+        url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        headers: Option<HashMap<String, String>>,
+    },
 }
 
 impl MCPServer {
@@ -68,14 +86,133 @@ impl MCPServer {
             headers: Some(headers),
         }
     }
+
+    /// Create a new SSE MCP server
+    pub fn sse(url: impl Into<String>) -> Self {
+        Self::Sse {
+            url: url.into(),
+            headers: None,
+        }
+    }
+
+    /// Start building a stdio MCP server
+    pub fn stdio_builder(command: impl Into<String>) -> StdioServerBuilder {
+        StdioServerBuilder::new(command)
+    }
+
+    /// Start building an HTTP MCP server
+    pub fn http_builder(url: impl Into<String>) -> RemoteServerBuilder {
+        RemoteServerBuilder::http(url)
+    }
+
+    /// Start building an SSE MCP server
+    pub fn sse_builder(url: impl Into<String>) -> RemoteServerBuilder {
+        RemoteServerBuilder::sse(url)
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MCPConfig {
     #[serde(rename = "mcpServers")]
     pub mcp_servers: HashMap<String, MCPServer>,
 }
 
+impl MCPConfig {
+    pub fn builder() -> MCPConfigBuilder {
+        MCPConfigBuilder::new()
+    }
+
+    /// True when no servers are configured
+    pub fn is_empty(&self) -> bool {
+        self.mcp_servers.is_empty()
+    }
+
+    /// Add `vars` to the environment of every stdio server
+    ///
+    /// Remote servers are left alone; pass their credentials as headers instead.
+    pub fn inject_env<K, V>(&mut self, vars: impl IntoIterator<Item = (K, V)>)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let vars: Vec<(String, String)> = vars
+            .into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect();
+        if vars.is_empty() {
+            return;
+        }
+        for server in self.mcp_servers.values_mut() {
+            if let MCPServer::Stdio { env, .. } = server {
+                env.get_or_insert_with(HashMap::new)
+                    .extend(vars.iter().cloned());
+            }
+        }
+    }
+}
+
+/// Controls which parent environment variables the Claude process inherits.
+///
+/// The default inherits the full parent environment. Patterns are exact
+/// variable names or a prefix followed by `*` (e.g. `LC_*`). Deny wins over allow.
+/// Extra variables from [`SessionConfig::env`] are applied last and are never filtered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EnvPolicy {
+    /// Only inherit parent variables matching these patterns. `None` inherits everything.
+    pub allow: Option<Vec<String>>,
+    /// Never inherit parent variables matching these patterns.
+    pub deny: Vec<String>,
+    /// Replacement `PATH` for the Claude process.
+    pub path: Option<String>,
+}
+
+impl EnvPolicy {
+    /// Variables the Claude CLI needs to locate its config, credentials, and a shell.
+    pub const ESSENTIAL_VARS: &'static [&'static str] = &[
+        "HOME", "USER", "LOGNAME", "SHELL", "TERM", "LANG", "LC_*", "TMPDIR", "TZ", "XDG_*",
+    ];
+
+    /// True when the parent environment is passed through unchanged.
+    pub fn inherits_all(&self) -> bool {
+        self.allow.is_none() && self.deny.is_empty() && self.path.is_none()
+    }
+
+    /// Whether a parent variable named `key` passes the allow/deny filters.
+    pub fn permits(&self, key: &str) -> bool {
+        if self.deny.iter().any(|p| env_pattern_matches(p, key)) {
+            return false;
+        }
+        self.allow
+            .as_ref()
+            .is_none_or(|allow| allow.iter().any(|p| env_pattern_matches(p, key)))
+    }
+
+    /// Build the child environment from `parent`, the `PATH` override, and `extra` vars.
+    pub fn resolve(
+        &self,
+        parent: impl IntoIterator<Item = (String, String)>,
+        extra: Option<&HashMap<String, String>>,
+    ) -> HashMap<String, String> {
+        let mut env: HashMap<String, String> = parent
+            .into_iter()
+            .filter(|(k, _)| self.permits(k))
+            .collect();
+        if let Some(path) = &self.path {
+            env.insert("PATH".to_string(), path.clone());
+        }
+        if let Some(extra) = extra {
+            env.extend(extra.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        env
+    }
+}
+
+fn env_pattern_matches(pattern: &str, key: &str) -> bool {
+    pattern
+        .strip_suffix('*')
+        .map_or(pattern == key, |prefix| key.starts_with(prefix))
+}
+
 /// Configuration for a Claude CLI session
 #[derive(Debug, Clone, Default)]
 pub struct SessionConfig {
@@ -117,6 +254,8 @@ pub struct SessionConfig {
     pub dangerously_skip_permissions: bool,
     /// Allow dangerous permission skipping (maps to --allow-dangerously-skip-permissions)
     pub allow_dangerously_skip_permissions: bool,
+    /// Per-invocation tool approval (maps to --permission-prompt-tool stdio)
+    pub permission_handler: Option<Arc<dyn PermissionHandler>>,
 
     // Prompts
     /// System prompt override (maps to --system-prompt)
@@ -139,6 +278,8 @@ pub struct SessionConfig {
     pub include_partial_messages: bool,
     /// Replay user messages (maps to --replay-user-messages)
     pub replay_user_messages: bool,
+    /// Output style for responses (maps to --output-style)
+    pub output_style: Option<String>,
 
     // Configuration
     /// Settings JSON (maps to --settings)
@@ -167,10 +308,18 @@ pub struct SessionConfig {
     pub working_dir: Option<PathBuf>,
     /// Environment variables to inject into the Claude process
     pub env: Option<HashMap<String, String>>,
+    /// Which parent environment variables the Claude process inherits
+    pub env_policy: EnvPolicy,
+    /// Kill the process this long after launch
+    pub timeout: Option<Duration>,
+    /// Kill the process after this long without output
+    pub idle_timeout: Option<Duration>,
 
     // Misc
     /// Enable verbose output
     pub verbose: bool,
+    /// Called with updated usage and cost as the session runs
+    pub metrics_callback: Option<Arc<dyn MetricsCallback>>,
 }
 
 impl SessionConfig {
@@ -197,6 +346,30 @@ impl SessionConfig {
             });
         }
 
+        // The CLI only reads stream-json input alongside stream-json output
+        if self.input_format == Some(InputFormat::StreamJson)
+            && self.output_format != OutputFormat::StreamingJson
+        {
+            return Err(ClaudeError::InvalidConfiguration {
+                message: "Streaming JSON input requires streaming JSON output".to_string(),
+            });
+        }
+
+        // Permission prompts are answered over the streaming JSON channels
+        if self.permission_handler.is_some() && self.input_format != Some(InputFormat::StreamJson) {
+            return Err(ClaudeError::InvalidConfiguration {
+                message: "A permission handler requires streaming JSON input".to_string(),
+            });
+        }
+
+        if self.timeout.is_some_and(|t| t.is_zero())
+            || self.idle_timeout.is_some_and(|t| t.is_zero())
+        {
+            return Err(ClaudeError::InvalidConfiguration {
+                message: "Timeouts must be greater than zero".to_string(),
+            });
+        }
+
         // Safe dangerous permissions: both must be set together or neither
         if self.dangerously_skip_permissions ^ self.allow_dangerously_skip_permissions {
             return Err(ClaudeError::InvalidConfiguration {
@@ -206,11 +379,52 @@ impl SessionConfig {
 
         Ok(())
     }
+
+    /// Clear optional settings whose flags `caps` says the CLI lacks
+    ///
+    /// Returns the flags that were dropped. Settings that change what the session
+    /// means (the query, session, format, and permission flags) are never dropped; an
+    /// unsupported one still fails when the CLI starts. Nothing is dropped when `caps`
+    /// is [unknown](CliCapabilities::is_unknown).
+    pub fn drop_unsupported(&mut self, caps: &CliCapabilities) -> Vec<&'static str> {
+        let mut dropped = Vec::new();
+        if caps.is_unknown() {
+            return dropped;
+        }
+        let mut check = |flag: &'static str, set: bool| {
+            let drop = set && !caps.supports(flag);
+            if drop {
+                dropped.push(flag);
+            }
+            drop
+        };
+
+        if check("--strict-mcp-config", self.strict_mcp_config) {
+            self.strict_mcp_config = false;
+        }
+        if check("--disallowedTools", self.disallowed_tools.is_some()) {
+            self.disallowed_tools = None;
+        }
+        if check("--output-style", self.output_style.is_some()) {
+            self.output_style = None;
+        }
+        if check("--fallback-model", self.fallback_model.is_some()) {
+            self.fallback_model = None;
+        }
+        if check("--include-partial-messages", self.include_partial_messages) {
+            self.include_partial_messages = false;
+        }
+        if check("--setting-sources", self.setting_sources.is_some()) {
+            self.setting_sources = None;
+        }
+        dropped
+    }
 }
 
 /// Builder for `SessionConfig` with fluent API
 pub struct SessionConfigBuilder {
     config: SessionConfig,
+    capabilities: Option<CliCapabilities>,
 }
 
 impl SessionConfigBuilder {
@@ -221,6 +435,7 @@ impl SessionConfigBuilder {
                 query: query.into(),
                 ..Default::default()
             },
+            capabilities: None,
         }
     }
 
@@ -316,6 +531,17 @@ impl SessionConfigBuilder {
         self
     }
 
+    /// Approve or deny each tool invocation programmatically
+    /// (maps to --permission-prompt-tool stdio)
+    ///
+    /// Requires `InputFormat::StreamJson` and `OutputFormat::StreamingJson`; see
+    /// [`permission`](crate::permission).
+    #[must_use]
+    pub fn permission_handler(mut self, handler: impl PermissionHandler + 'static) -> Self {
+        self.config.permission_handler = Some(Arc::new(handler));
+        self
+    }
+
     // Prompts
     /// Set system prompt override (maps to --system-prompt)
     #[must_use]
@@ -395,6 +621,13 @@ impl SessionConfigBuilder {
         self
     }
 
+    /// Set the output style (maps to --output-style)
+    #[must_use]
+    pub fn output_style(mut self, style: impl Into<String>) -> Self {
+        self.config.output_style = Some(style.into());
+        self
+    }
+
     // Configuration
     /// Set settings JSON (maps to --settings)
     #[must_use]
@@ -479,6 +712,67 @@ impl SessionConfigBuilder {
         self
     }
 
+    /// Only inherit parent environment variables matching these patterns
+    /// (exact names or `PREFIX*`). Combine with `EnvPolicy::ESSENTIAL_VARS`
+    /// so the CLI can still find its config and credentials.
+    #[must_use]
+    pub fn env_allow<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.config
+            .env_policy
+            .allow
+            .get_or_insert_with(Vec::new)
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Inherit only `EnvPolicy::ESSENTIAL_VARS` plus any other allowed patterns
+    #[must_use]
+    pub fn env_allow_essentials(self) -> Self {
+        self.env_allow(EnvPolicy::ESSENTIAL_VARS.iter().copied())
+    }
+
+    /// Never inherit parent environment variables matching these patterns
+    #[must_use]
+    pub fn env_deny<S: Into<String>>(mut self, patterns: impl IntoIterator<Item = S>) -> Self {
+        self.config
+            .env_policy
+            .deny
+            .extend(patterns.into_iter().map(Into::into));
+        self
+    }
+
+    /// Replace `PATH` for the Claude process
+    #[must_use]
+    pub fn path_override(mut self, path: impl Into<String>) -> Self {
+        self.config.env_policy.path = Some(path.into());
+        self
+    }
+
+    /// Set the full environment inheritance policy
+    #[must_use]
+    pub fn env_policy(mut self, policy: EnvPolicy) -> Self {
+        self.config.env_policy = policy;
+        self
+    }
+
+    /// Kill the process group if the session runs longer than `timeout`
+    ///
+    /// `Session::wait` then fails with `ClaudeError::Timeout`.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Kill the process group if it writes no output for `timeout`
+    ///
+    /// With streaming input this includes time spent waiting for the next message.
+    #[must_use]
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.config.idle_timeout = Some(timeout);
+        self
+    }
+
     // Misc
     /// Enable verbose output
     #[must_use]
@@ -487,8 +781,37 @@ impl SessionConfigBuilder {
         self
     }
 
+    /// Call `callback` with updated usage and cost as the session runs
+    ///
+    /// The same totals are on the result from `Session::wait`.
+    #[must_use]
+    pub fn on_metrics(mut self, callback: impl MetricsCallback + 'static) -> Self {
+        self.config.metrics_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Target a CLI with these capabilities, usually from `Client::probe_cli`
+    ///
+    /// `build` then omits optional settings the CLI doesn't support, logging a
+    /// warning for each, instead of letting the CLI fail at spawn time. See
+    /// [`SessionConfig::drop_unsupported`].
+    #[must_use]
+    pub fn capabilities(mut self, caps: &CliCapabilities) -> Self {
+        self.capabilities = Some(caps.clone());
+        self
+    }
+
     /// Build the `SessionConfig`, validating all settings
-    pub fn build(self) -> Result<SessionConfig> {
+    pub fn build(mut self) -> Result<SessionConfig> {
+        if let Some(caps) = self.capabilities.as_ref() {
+            for flag in self.config.drop_unsupported(caps) {
+                tracing::warn!(
+                    flag,
+                    version = ?caps.version,
+                    "Claude CLI does not support {flag}; omitting it"
+                );
+            }
+        }
         self.config.validate()?;
         Ok(self.config)
     }
@@ -551,6 +874,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_session_config_validation_streaming_input_needs_streaming_output() {
+        let config = SessionConfig {
+            query: "test".to_string(),
+            input_format: Some(InputFormat::StreamJson),
+            output_format: OutputFormat::Json,
+            ..Default::default()
+        };
+        let result = config.validate();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Streaming JSON input requires streaming JSON output")
+        );
+
+        let config = SessionConfig {
+            output_format: OutputFormat::StreamingJson,
+            ..config
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_session_config_validation_permission_handler_needs_streaming_input() {
+        let builder = SessionConfig::builder("test")
+            .output_format(OutputFormat::StreamingJson)
+            .permission_handler(|_| async { crate::PermissionDecision::allow() });
+        let result = builder.build();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("A permission handler requires streaming JSON input")
+        );
+
+        let config = SessionConfig::builder("test")
+            .input_format(InputFormat::StreamJson)
+            .output_format(OutputFormat::StreamingJson)
+            .permission_handler(|_| async { crate::PermissionDecision::allow() })
+            .build()
+            .unwrap();
+        assert!(config.permission_handler.is_some());
+    }
+
+    #[test]
+    fn test_session_config_validation_zero_timeout() {
+        let result = SessionConfig::builder("test")
+            .idle_timeout(Duration::ZERO)
+            .build();
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Timeouts must be greater than zero")
+        );
+
+        let config = SessionConfig::builder("test")
+            .timeout(Duration::from_secs(60))
+            .idle_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        assert_eq!(config.timeout, Some(Duration::from_secs(60)));
+    }
+
     #[test]
     fn test_session_config_validation_dangerous_permissions() {
         // Only one dangerous flag set should fail
@@ -654,6 +1042,62 @@ mod tests {
         assert_eq!(config.env.as_ref().unwrap().get("KEY").unwrap(), "VALUE");
     }
 
+    #[test]
+    fn test_env_policy_default_inherits_all() {
+        let policy = EnvPolicy::default();
+        assert!(policy.inherits_all());
+        assert!(policy.permits("ANYTHING"));
+    }
+
+    #[test]
+    fn test_env_policy_allow_deny_path_and_extra() {
+        let config = SessionConfig::builder("query")
+            .env_allow_essentials()
+            .env_allow(["PATH", "GITHUB_*"])
+            .env_deny(["GITHUB_TOKEN"])
+            .path_override("/opt/bin")
+            .env_var("EXTRA", "1")
+            .build()
+            .unwrap();
+
+        let parent = [
+            ("HOME", "/home/me"),
+            ("LC_ALL", "C"),
+            ("PATH", "/usr/bin"),
+            ("GITHUB_TOKEN", "secret"),
+            ("GITHUB_HOST", "ghe.example"),
+            ("AWS_SECRET_ACCESS_KEY", "secret"),
+        ]
+        .map(|(k, v)| (k.to_string(), v.to_string()));
+
+        let env = config.env_policy.resolve(parent, config.env.as_ref());
+
+        assert_eq!(env.get("HOME").map(String::as_str), Some("/home/me"));
+        assert_eq!(env.get("LC_ALL").map(String::as_str), Some("C"));
+        assert_eq!(env.get("PATH").map(String::as_str), Some("/opt/bin"));
+        assert_eq!(
+            env.get("GITHUB_HOST").map(String::as_str),
+            Some("ghe.example")
+        );
+        assert_eq!(env.get("EXTRA").map(String::as_str), Some("1"));
+        assert!(!env.contains_key("GITHUB_TOKEN"));
+        assert!(!env.contains_key("AWS_SECRET_ACCESS_KEY"));
+    }
+
+    #[test]
+    fn test_env_policy_extra_vars_bypass_deny() {
+        let policy = EnvPolicy {
+            deny: vec!["API_*".to_string()],
+            ..Default::default()
+        };
+        let extra = HashMap::from([("API_KEY".to_string(), "scoped".to_string())]);
+        let env = policy.resolve(
+            [("API_KEY".to_string(), "parent".to_string())],
+            Some(&extra),
+        );
+        assert_eq!(env.get("API_KEY").map(String::as_str), Some("scoped"));
+    }
+
     #[test]
     fn test_default_output_format() {
         let config = SessionConfig::builder("test").build().unwrap();
@@ -661,6 +1105,47 @@ mod tests {
         assert_eq!(config.output_format, OutputFormat::StreamingJson);
     }
 
+    #[test]
+    fn test_builder_drops_flags_the_cli_lacks() {
+        let mut caps = CliCapabilities {
+            version: Some(crate::probe::CliVersion::new(1, 0, 10)),
+            ..Default::default()
+        };
+        caps.flags.insert("--include-partial-messages".to_string());
+
+        let config = SessionConfig::builder("test")
+            .strict_mcp_config(true)
+            .disallow_tool("Bash")
+            .output_style("Explanatory")
+            .include_partial_messages(true)
+            .capabilities(&caps)
+            .build()
+            .unwrap();
+
+        assert!(!config.strict_mcp_config);
+        assert!(config.output_style.is_none());
+        // Listed in --help, or old enough per the version table
+        assert!(config.include_partial_messages);
+        assert_eq!(config.disallowed_tools, Some(vec!["Bash".to_string()]));
+    }
+
+    #[test]
+    fn test_unknown_capabilities_drop_nothing() {
+        let mut config = SessionConfig::builder("test")
+            .strict_mcp_config(true)
+            .output_style("Explanatory")
+            .build()
+            .unwrap();
+
+        assert!(
+            config
+                .drop_unsupported(&CliCapabilities::default())
+                .is_empty()
+        );
+        assert!(config.strict_mcp_config);
+        assert_eq!(config.output_style.as_deref(), Some("Explanatory"));
+    }
+
     #[test]
     fn test_mcp_config_serialization_stdio() {
         let mut servers = HashMap::new();
@@ -688,7 +1173,7 @@ mod tests {
                 assert_eq!(args, &vec!["arg1".to_string(), "arg2".to_string()]);
                 assert!(env.is_none());
             }
-            MCPServer::Http { .. } => panic!("Expected Stdio server"),
+            _ => panic!("Expected Stdio server"),
         }
     }
 
@@ -721,7 +1206,7 @@ mod tests {
                 assert!(headers.is_some());
                 assert_eq!(headers.as_ref().unwrap()["Authorization"], "Bearer token");
             }
-            MCPServer::Stdio { .. } => panic!("Expected Http server"),
+            _ => panic!("Expected Http server"),
         }
     }
 
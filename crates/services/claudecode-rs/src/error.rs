@@ -1,4 +1,6 @@
+use std::fmt;
 use std::path::PathBuf;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -36,6 +38,12 @@ pub enum ClaudeError {
     #[error("Session error: {message}")]
     SessionError { message: String },
 
+    #[error("Session exceeded its {kind} timeout of {timeout:?}")]
+    Timeout {
+        kind: TimeoutKind,
+        timeout: Duration,
+    },
+
     #[error("CLI probe failed: {message}")]
     ProbeError { message: String },
 
@@ -46,6 +54,24 @@ pub enum ClaudeError {
     },
 }
 
+/// Which session timeout killed the process
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutKind {
+    /// `SessionConfig::timeout`, measured from launch
+    WallClock,
+    /// `SessionConfig::idle_timeout`, measured from the last output
+    Idle,
+}
+
+impl fmt::Display for TimeoutKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WallClock => write!(f, "wall-clock"),
+            Self::Idle => write!(f, "idle"),
+        }
+    }
+}
+
 impl From<serde_json::Error> for ClaudeError {
     fn from(err: serde_json::Error) -> Self {
         Self::JsonParseError {
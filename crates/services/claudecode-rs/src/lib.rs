@@ -69,6 +69,10 @@ pub mod client;
 pub mod config;
 pub mod error;
 pub mod mcp;
+pub mod metrics;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod permission;
 pub mod probe;
 pub mod process;
 pub mod session;
@@ -77,13 +81,21 @@ pub mod types;
 
 // Re-export main types
 pub use client::Client;
+pub use config::EnvPolicy;
 pub use config::MCPConfig;
 pub use config::MCPServer;
 pub use config::SessionConfig;
 pub use config::SessionConfigBuilder;
 pub use error::ClaudeError;
 pub use error::Result;
+pub use error::TimeoutKind;
+pub use metrics::MetricsCallback;
+pub use metrics::SessionMetrics;
+pub use metrics::TokenUsage;
+pub use permission::PermissionDecision;
+pub use permission::PermissionHandler;
 pub use probe::CliCapabilities;
+pub use probe::CliVersion;
 pub use session::Session;
 pub use types::AssistantEvent;
 pub use types::Content;
@@ -95,11 +107,13 @@ pub use types::Message;
 pub use types::Model;
 pub use types::OutputFormat;
 pub use types::PermissionMode;
+pub use types::PermissionRequest;
 pub use types::Result as ClaudeResult;
 pub use types::ResultEvent;
 pub use types::ServerToolUse;
 pub use types::SystemEvent;
 pub use types::Usage;
+pub use types::UserInput;
 
 // Version information
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
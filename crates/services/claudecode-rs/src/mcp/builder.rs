@@ -0,0 +1,368 @@
+//! Typed builders for MCP server configs.
+//!
+//! [`MCPConfigBuilder`] collects named servers and checks them when built: names
+//! must be non-empty and unique and must not contain `__` (which would make
+//! `mcp__<server>__<tool>` IDs ambiguous), stdio commands must be non-empty, and
+//! remote URLs must be `http://` or `https://`.
+//!
+//! ```rust
+//! use claudecode::{MCPConfig, MCPServer};
+//!
+//! # fn example() -> claudecode::Result<()> {
+//! let config = MCPConfig::builder()
+//!     .server(
+//!         "tools",
+//!         MCPServer::stdio_builder("agentic-mcp")
+//!             .args(["--allow", "cli_ls,cli_grep"])
+//!             .env("RUST_LOG", "warn"),
+//!     )
+//!     .server(
+//!         "docs",
+//!         MCPServer::http_builder("https://docs.example.com/mcp").bearer_token("secret"),
+//!     )
+//!     .env("TRACE_ID", "abc123")
+//!     .build()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config::MCPConfig;
+use crate::config::MCPServer;
+use crate::error::ClaudeError;
+use crate::error::Result;
+use std::collections::HashMap;
+
+/// Builds an [`MCPServer::Stdio`] definition
+#[derive(Debug, Clone)]
+pub struct StdioServerBuilder {
+    command: String,
+    args: Vec<String>,
+    env: HashMap<String, String>,
+}
+
+impl StdioServerBuilder {
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+            args: Vec::new(),
+            env: HashMap::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    #[must_use]
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set an environment variable for the server process
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn envs<I, K, V>(mut self, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.env
+            .extend(vars.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    /// Copy `key` from this process's environment, if set
+    ///
+    /// Useful for handing a credential such as an API key to the server without
+    /// writing it into code.
+    #[must_use]
+    pub fn env_from_parent(self, key: impl Into<String>) -> Self {
+        let key = key.into();
+        match std::env::var(&key) {
+            Ok(value) => self.env(key, value),
+            Err(_) => self,
+        }
+    }
+
+    pub fn build(self) -> MCPServer {
+        MCPServer::Stdio {
+            command: self.command,
+            args: self.args,
+            env: (!self.env.is_empty()).then_some(self.env),
+        }
+    }
+}
+
+impl From<StdioServerBuilder> for MCPServer {
+    fn from(builder: StdioServerBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Remote transport for a [`RemoteServerBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RemoteTransport {
+    Http,
+    Sse,
+}
+
+/// Builds an [`MCPServer::Http`] or [`MCPServer::Sse`] definition
+#[derive(Debug, Clone)]
+pub struct RemoteServerBuilder {
+    transport: RemoteTransport,
+    url: String,
+    headers: HashMap<String, String>,
+}
+
+impl RemoteServerBuilder {
+    pub fn http(url: impl Into<String>) -> Self {
+        Self::new(RemoteTransport::Http, url.into())
+    }
+
+    pub fn sse(url: impl Into<String>) -> Self {
+        Self::new(RemoteTransport::Sse, url.into())
+    }
+
+    fn new(transport: RemoteTransport, url: String) -> Self {
+        Self {
+            transport,
+            url,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Send a header with every request to the server
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    /// Authenticate with `Authorization: Bearer <token>`
+    #[must_use]
+    pub fn bearer_token(self, token: impl AsRef<str>) -> Self {
+        let value = format!("Bearer {}", token.as_ref());
+        self.header("Authorization", value)
+    }
+
+    pub fn build(self) -> MCPServer {
+        let headers = (!self.headers.is_empty()).then_some(self.headers);
+        match self.transport {
+            RemoteTransport::Http => MCPServer::Http {
+                url: self.url,
+                headers,
+            },
+            RemoteTransport::Sse => MCPServer::Sse {
+                url: self.url,
+                headers,
+            },
+        }
+    }
+}
+
+impl From<RemoteServerBuilder> for MCPServer {
+    fn from(builder: RemoteServerBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builds an [`MCPConfig`] from named servers
+#[derive(Debug, Clone, Default)]
+pub struct MCPConfigBuilder {
+    servers: Vec<(String, MCPServer)>,
+    env: Vec<(String, String)>,
+}
+
+impl MCPConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn server(mut self, name: impl Into<String>, server: impl Into<MCPServer>) -> Self {
+        self.servers.push((name.into(), server.into()));
+        self
+    }
+
+    /// Set an environment variable on every stdio server
+    ///
+    /// Applied after each server's own environment, so it wins on conflicts.
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    #[must_use]
+    pub fn envs<I, K, V>(mut self, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.env
+            .extend(vars.into_iter().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+
+    pub fn build(self) -> Result<MCPConfig> {
+        let mut mcp_servers = HashMap::with_capacity(self.servers.len());
+        for (name, server) in self.servers {
+            validate_server(&name, &server)?;
+            if mcp_servers.insert(name.clone(), server).is_some() {
+                return Err(invalid(format!("MCP server '{name}' is defined twice")));
+            }
+        }
+        let mut config = MCPConfig { mcp_servers };
+        config.inject_env(self.env);
+        Ok(config)
+    }
+}
+
+fn validate_server(name: &str, server: &MCPServer) -> Result<()> {
+    if name.trim().is_empty() {
+        return Err(invalid("MCP server name cannot be empty".to_string()));
+    }
+    if name.contains("__") {
+        return Err(invalid(format!(
+            "MCP server name '{name}' cannot contain '__'"
+        )));
+    }
+    match server {
+        MCPServer::Stdio { command, .. } => {
+            if command.trim().is_empty() {
+                return Err(invalid(format!("MCP server '{name}' has an empty command")));
+            }
+        }
+        MCPServer::Http { url, .. } | MCPServer::Sse { url, .. } => {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(invalid(format!(
+                    "MCP server '{name}' URL must start with http:// or https://: {url}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+const fn invalid(message: String) -> ClaudeError {
+    ClaudeError::InvalidConfiguration { message }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stdio_builder_collects_args_and_env() {
+        let server = MCPServer::stdio_builder("node")
+            .arg("server.js")
+            .args(["--port", "0"])
+            .env("LOG", "debug")
+            .build();
+
+        let MCPServer::Stdio { command, args, env } = server else {
+            panic!("expected stdio server");
+        };
+        assert_eq!(command, "node");
+        assert_eq!(args, ["server.js", "--port", "0"]);
+        assert_eq!(env.unwrap()["LOG"], "debug");
+    }
+
+    #[test]
+    fn stdio_builder_without_env_serializes_without_env() {
+        let json = serde_json::to_value(MCPServer::stdio_builder("node").build()).unwrap();
+        assert!(json.get("env").is_none());
+    }
+
+    #[test]
+    fn env_from_parent_skips_unset_vars() {
+        let server = MCPServer::stdio_builder("node")
+            .env_from_parent("PATH")
+            .env_from_parent("CLAUDECODE_TEST_SURELY_UNSET")
+            .build();
+
+        let MCPServer::Stdio { env: Some(env), .. } = server else {
+            panic!("expected stdio server with env");
+        };
+        assert!(env.contains_key("PATH"));
+        assert_eq!(env.len(), 1);
+    }
+
+    #[test]
+    fn remote_builders_pick_transport_and_headers() {
+        let http = MCPServer::http_builder("https://example.com/mcp")
+            .bearer_token("secret")
+            .build();
+        let MCPServer::Http { headers, .. } = http else {
+            panic!("expected http server");
+        };
+        assert_eq!(headers.unwrap()["Authorization"], "Bearer secret");
+
+        let sse = MCPServer::sse_builder("https://example.com/sse")
+            .header("X-Team", "core")
+            .build();
+        let json = serde_json::to_value(&sse).unwrap();
+        assert_eq!(json["type"], "sse");
+        assert_eq!(json["headers"]["X-Team"], "core");
+    }
+
+    #[test]
+    fn config_builder_injects_env_into_stdio_servers() {
+        let config = MCPConfig::builder()
+            .server("local", MCPServer::stdio_builder("node").env("A", "1"))
+            .server("remote", MCPServer::http_builder("https://example.com/mcp"))
+            .env("A", "2")
+            .env("TRACE", "t")
+            .build()
+            .unwrap();
+
+        let MCPServer::Stdio { env: Some(env), .. } = &config.mcp_servers["local"] else {
+            panic!("expected stdio server with env");
+        };
+        assert_eq!(env["A"], "2");
+        assert_eq!(env["TRACE"], "t");
+        assert!(matches!(
+            &config.mcp_servers["remote"],
+            MCPServer::Http { headers: None, .. }
+        ));
+    }
+
+    #[test]
+    fn config_builder_rejects_bad_servers() {
+        let cases = [
+            MCPConfig::builder().server("", MCPServer::stdio("node", vec![])),
+            MCPConfig::builder().server("a__b", MCPServer::stdio("node", vec![])),
+            MCPConfig::builder().server("a", MCPServer::stdio(" ", vec![])),
+            MCPConfig::builder().server("a", MCPServer::sse("ws://example.com")),
+            MCPConfig::builder()
+                .server("a", MCPServer::stdio("node", vec![]))
+                .server("a", MCPServer::http("https://example.com")),
+        ];
+        for builder in cases {
+            assert!(matches!(
+                builder.build(),
+                Err(ClaudeError::InvalidConfiguration { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn empty_config_builds() {
+        let config = MCPConfig::builder().env("A", "1").build().unwrap();
+        assert!(config.is_empty());
+    }
+}
@@ -1,10 +1,15 @@
-//! MCP (Model Context Protocol) validation module.
+//! MCP (Model Context Protocol) configuration and validation module.
 //!
-//! This module provides utilities for validating MCP server configurations
-//! and tool whitelists before launching Claude sessions.
+//! This module provides builders for MCP server configurations and utilities for
+//! validating them and tool whitelists before launching Claude sessions.
 
+pub mod builder;
 pub mod validate;
 
+pub use builder::MCPConfigBuilder;
+pub use builder::RemoteServerBuilder;
+pub use builder::StdioServerBuilder;
+
 pub use validate::KNOWN_BUILTIN_TOOLS;
 pub use validate::McpServerResult;
 pub use validate::McpServerValidationError;
@@ -15,6 +20,7 @@ pub use validate::ToolWhitelistError;
 pub use validate::ToolWhitelistReport;
 pub use validate::TransportType;
 pub use validate::ValidateOptions;
+pub use validate::ValidationDepth;
 pub use validate::ensure_valid_mcp_config;
 pub use validate::validate_mcp_config;
 pub use validate::validate_tool_whitelist;
@@ -24,9 +24,27 @@ use tokio::time::timeout;
 
 // === Configuration ===
 
+/// How far to take each server during validation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationDepth {
+    /// Don't contact servers; reports are empty
+    Skip,
+    /// Start the server and complete the MCP handshake
+    Handshake,
+    /// Handshake, then list the server's tools
+    #[default]
+    ToolsList,
+}
+
 /// Options for MCP validation.
+///
+/// Start from a preset — [`ValidateOptions::full`] (the default),
+/// [`ValidateOptions::handshake_only`], or [`ValidateOptions::skip_validation`] —
+/// and override fields as needed.
 #[derive(Debug, Clone)]
 pub struct ValidateOptions {
+    /// How far to validate each server (default: tools/list)
+    pub depth: ValidationDepth,
     /// Timeout for MCP handshake (default: 10s)
     pub handshake_timeout: Duration,
     /// Timeout for tools/list (default: 5s)
@@ -44,6 +62,7 @@ pub struct ValidateOptions {
 impl Default for ValidateOptions {
     fn default() -> Self {
         Self {
+            depth: ValidationDepth::ToolsList,
             handshake_timeout: Duration::from_secs(10),
             tools_list_timeout: Duration::from_secs(5),
             overall_timeout: Duration::from_secs(15),
@@ -54,6 +73,36 @@ impl Default for ValidateOptions {
     }
 }
 
+impl ValidateOptions {
+    /// Spawn, handshake, and list tools for every server
+    pub fn full() -> Self {
+        Self::default()
+    }
+
+    /// Spawn and handshake, without listing tools
+    ///
+    /// Successful results carry no tools, and whitelisted MCP tools are accepted
+    /// for any server that completes the handshake.
+    pub fn handshake_only() -> Self {
+        Self {
+            depth: ValidationDepth::Handshake,
+            overall_timeout: Duration::from_secs(10),
+            ..Self::default()
+        }
+    }
+
+    /// Don't contact any server
+    ///
+    /// Validation reports are empty, and whitelisted MCP tools are accepted for
+    /// any configured server.
+    pub fn skip_validation() -> Self {
+        Self {
+            depth: ValidationDepth::Skip,
+            ..Self::default()
+        }
+    }
+}
+
 // === Result Types ===
 
 /// Transport type for the MCP server.
@@ -61,6 +110,7 @@ impl Default for ValidateOptions {
 pub enum TransportType {
     Stdio,
     Http,
+    Sse,
 }
 
 /// Successful MCP server validation result.
@@ -68,11 +118,11 @@ pub enum TransportType {
 pub struct McpServerValidationSuccess {
     /// Server info from handshake
     pub info: ServerInfo,
-    /// Tools available from this server
+    /// Tools available from this server; empty unless tools were listed
     pub tools: Vec<Tool>,
     /// Time taken for handshake in milliseconds
     pub handshake_ms: u64,
-    /// Time taken for tools/list in milliseconds
+    /// Time taken for tools/list in milliseconds; zero unless tools were listed
     pub tools_list_ms: u64,
     /// Transport type used
     pub transport: TransportType,
@@ -186,10 +236,17 @@ pub struct McpValidationAggregateError {
 ///
 /// Returns a report with per-server results. Use `report.all_ok()` to check
 /// if all servers validated successfully, or `report.failed()` to get errors.
+/// With [`ValidationDepth::Skip`] the report is empty.
 pub async fn validate_mcp_config(
     config: &MCPConfig,
     opts: &ValidateOptions,
 ) -> McpValidationReport {
+    if opts.depth == ValidationDepth::Skip {
+        return McpValidationReport {
+            servers: HashMap::new(),
+        };
+    }
+
     let semaphore = Arc::new(Semaphore::new(opts.parallelism));
     let mut handles: Vec<(String, tokio::task::JoinHandle<McpServerResult>)> = Vec::new();
 
@@ -385,6 +442,18 @@ pub async fn validate_tool_whitelist(
 
                 // Check server validation result
                 match report.servers.get(&server_name) {
+                    // Shallower presets can't see tools, so trust the whitelist
+                    result
+                        if opts.depth == ValidationDepth::Skip
+                            || (opts.depth == ValidationDepth::Handshake
+                                && result.is_some_and(McpServerResult::is_ok)) =>
+                    {
+                        ok_mcp.extend(
+                            expected_tools
+                                .into_iter()
+                                .map(|tool| format!("mcp__{server_name}__{tool}")),
+                        );
+                    }
                     Some(McpServerResult::Ok(success)) => {
                         let available_tools: HashSet<_> =
                             success.tools.iter().map(|t| t.name.as_ref()).collect();
@@ -523,7 +592,7 @@ async fn validate_single_server(
                 Err(e) => McpServerResult::Err(e),
             }
         }
-        MCPServer::Http { url, headers } => {
+        MCPServer::Http { url, headers } | MCPServer::Sse { url, headers } => {
             match validate_http_server(url, headers.as_ref(), opts).await {
                 Ok(success) => McpServerResult::Ok(Box::new(success)),
                 Err(e) => McpServerResult::Err(e),
@@ -652,19 +721,25 @@ async fn validate_stdio_server(
             };
 
             // List tools with timeout (no stderr_tail here by design)
-            let tools_start = Instant::now();
-            let tools = match timeout(opts.tools_list_timeout, handshake_result.list_all_tools())
-                .await
-            {
-                Err(_) => {
-                    return Err(McpServerValidationError::ToolsListTimeout(
-                        opts.tools_list_timeout,
-                    ));
-                }
-                Ok(Ok(tools)) => tools,
-                Ok(Err(e)) => return Err(McpServerValidationError::ToolsListError(format!("{e}"))),
+            let (tools, tools_list_ms) = if opts.depth == ValidationDepth::ToolsList {
+                let tools_start = Instant::now();
+                let tools =
+                    match timeout(opts.tools_list_timeout, handshake_result.list_all_tools()).await
+                    {
+                        Err(_) => {
+                            return Err(McpServerValidationError::ToolsListTimeout(
+                                opts.tools_list_timeout,
+                            ));
+                        }
+                        Ok(Ok(tools)) => tools,
+                        Ok(Err(e)) => {
+                            return Err(McpServerValidationError::ToolsListError(format!("{e}")));
+                        }
+                    };
+                (tools, tools_start.elapsed().as_millis() as u64)
+            } else {
+                (Vec::new(), 0)
             };
-            let tools_list_ms = tools_start.elapsed().as_millis() as u64;
 
             // Cleanup: gracefully close transport
             let _ = handshake_result.close().await;
@@ -784,6 +859,46 @@ mod tests {
         assert!(opts.parallelism >= 1);
     }
 
+    #[test]
+    fn test_validate_options_presets() {
+        assert_eq!(ValidateOptions::full().depth, ValidationDepth::ToolsList);
+        assert_eq!(
+            ValidateOptions::handshake_only().depth,
+            ValidationDepth::Handshake
+        );
+        assert_eq!(
+            ValidateOptions::skip_validation().depth,
+            ValidationDepth::Skip
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skip_validation_contacts_no_servers() {
+        let cfg = MCPConfig::builder()
+            .server(
+                "missing",
+                MCPServer::stdio("/nonexistent/mcp-server", vec![]),
+            )
+            .build()
+            .unwrap();
+
+        let opts = ValidateOptions::skip_validation();
+        let report = ensure_valid_mcp_config(&cfg, &opts).await.unwrap();
+        assert!(report.servers.is_empty());
+
+        let tools = vec!["Read".to_string(), "mcp__missing__ls".to_string()];
+        let whitelist = validate_tool_whitelist(&tools, Some(&cfg), &opts)
+            .await
+            .unwrap();
+        assert_eq!(whitelist.ok_mcp, vec!["mcp__missing__ls".to_string()]);
+
+        let unknown = vec!["mcp__other__ls".to_string()];
+        assert!(matches!(
+            validate_tool_whitelist(&unknown, Some(&cfg), &opts).await,
+            Err(ToolWhitelistError::MissingServers(_))
+        ));
+    }
+
     #[test]
     fn test_levenshtein_same() {
         assert_eq!(levenshtein("test", "test"), 0);
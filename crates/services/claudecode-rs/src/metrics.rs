@@ -0,0 +1,268 @@
+//! Token and cost accounting across a session
+//!
+//! Token counts come from the usage on each assistant message, broken down by model.
+//! The CLI repeats a message's usage on every event for that message, so each message
+//! ID is counted once. Output formats without per-message events fall back to the usage
+//! on the final result. Cost is the CLI's running `total_cost_usd`, taken from the
+//! latest result.
+
+use crate::types::Event;
+use crate::types::Result as ClaudeResult;
+use crate::types::Usage;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+/// Token counts summed over one or more API calls
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_creation_input_tokens: u64,
+    pub cache_read_input_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Every token counted, cached or not
+    pub const fn total(&self) -> u64 {
+        self.input_tokens
+            + self.output_tokens
+            + self.cache_creation_input_tokens
+            + self.cache_read_input_tokens
+    }
+
+    fn add(&mut self, usage: &Usage) {
+        let count = |tokens: i32| u64::try_from(tokens).unwrap_or(0);
+        self.input_tokens += count(usage.input_tokens);
+        self.output_tokens += count(usage.output_tokens);
+        self.cache_creation_input_tokens += usage.cache_creation_input_tokens.map_or(0, count);
+        self.cache_read_input_tokens += usage.cache_read_input_tokens.map_or(0, count);
+    }
+}
+
+/// Usage and spend of a session so far
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionMetrics {
+    /// Tokens across all models
+    pub usage: TokenUsage,
+    /// Tokens per model; empty when the output format doesn't report models
+    pub by_model: BTreeMap<String, TokenUsage>,
+    /// Total spend reported by the CLI, once a result has arrived
+    pub total_cost_usd: Option<f64>,
+}
+
+/// Receives a [`SessionMetrics`] snapshot whenever a session's usage or cost changes
+///
+/// Implemented for any `Fn(&SessionMetrics)`. Called on the session's worker task, so
+/// it should return quickly.
+pub trait MetricsCallback: Send + Sync {
+    fn on_metrics(&self, metrics: &SessionMetrics);
+}
+
+impl<F> MetricsCallback for F
+where
+    F: Fn(&SessionMetrics) + Send + Sync,
+{
+    fn on_metrics(&self, metrics: &SessionMetrics) {
+        self(metrics);
+    }
+}
+
+impl std::fmt::Debug for dyn MetricsCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MetricsCallback")
+    }
+}
+
+#[derive(Default)]
+struct RecorderState {
+    metrics: SessionMetrics,
+    seen_messages: HashSet<String>,
+    result_usage: TokenUsage,
+}
+
+/// Accumulates a session's metrics from its output
+pub(crate) struct MetricsRecorder {
+    state: Mutex<RecorderState>,
+    callback: Option<Arc<dyn MetricsCallback>>,
+}
+
+impl MetricsRecorder {
+    pub(crate) fn new(callback: Option<Arc<dyn MetricsCallback>>) -> Self {
+        Self {
+            state: Mutex::new(RecorderState::default()),
+            callback,
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> SessionMetrics {
+        self.state
+            .lock()
+            .map(|state| state.metrics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Record a streaming event
+    pub(crate) fn observe(&self, event: &Event) {
+        match event {
+            Event::Assistant(assistant) => {
+                let message = &assistant.message;
+                let Some(usage) = message.usage.as_ref() else {
+                    return;
+                };
+                self.update(|state| {
+                    if let Some(id) = message.id.as_ref()
+                        && !state.seen_messages.insert(id.clone())
+                    {
+                        return false;
+                    }
+                    let model = message.model.clone().unwrap_or_default();
+                    state.metrics.by_model.entry(model).or_default().add(usage);
+                    state.metrics.usage.add(usage);
+                    true
+                });
+            }
+            Event::Result(result) => {
+                self.record_result(result.usage.as_ref(), result.total_cost_usd);
+            }
+            _ => {}
+        }
+    }
+
+    /// Record the single result of a JSON or text session
+    pub(crate) fn observe_result(&self, result: &ClaudeResult) {
+        self.record_result(result.usage.as_ref(), result.total_cost_usd);
+    }
+
+    fn record_result(&self, usage: Option<&Usage>, total_cost_usd: Option<f64>) {
+        if usage.is_none() && total_cost_usd.is_none() {
+            return;
+        }
+        self.update(|state| {
+            if let Some(usage) = usage {
+                state.result_usage.add(usage);
+            }
+            // Without per-message usage, the results are all there is
+            if state.metrics.by_model.is_empty() {
+                state.metrics.usage = state.result_usage;
+            }
+            if total_cost_usd.is_some() {
+                state.metrics.total_cost_usd = total_cost_usd;
+            }
+            true
+        });
+    }
+
+    /// Apply `change`, notifying the callback if it reports a change
+    fn update(&self, change: impl FnOnce(&mut RecorderState) -> bool) {
+        let snapshot = {
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            if !change(&mut state) {
+                return;
+            }
+            state.metrics.clone()
+        };
+        if let Some(callback) = self.callback.as_ref() {
+            callback.on_metrics(&snapshot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AssistantEvent;
+    use crate::types::Message;
+    use crate::types::ResultEvent;
+
+    fn usage(input: i32, output: i32, cache_read: Option<i32>) -> Usage {
+        Usage {
+            input_tokens: input,
+            output_tokens: output,
+            cache_creation_input_tokens: None,
+            cache_read_input_tokens: cache_read,
+            service_tier: None,
+            server_tool_use: None,
+        }
+    }
+
+    fn assistant(id: &str, model: &str, usage: Usage) -> Event {
+        Event::Assistant(AssistantEvent {
+            session_id: "s".into(),
+            message: Message {
+                id: Some(id.into()),
+                message_type: Some("message".into()),
+                role: "assistant".into(),
+                model: Some(model.into()),
+                content: Vec::new(),
+                usage: Some(usage),
+            },
+        })
+    }
+
+    fn result(cost: f64, usage: Usage) -> Event {
+        Event::Result(ResultEvent {
+            session_id: "s".into(),
+            result: None,
+            is_error: false,
+            error: None,
+            total_cost_usd: Some(cost),
+            duration_ms: None,
+            duration_api_ms: None,
+            num_turns: None,
+            usage: Some(usage),
+        })
+    }
+
+    #[test]
+    fn counts_each_message_once_per_model() {
+        let recorder = MetricsRecorder::new(None);
+        recorder.observe(&assistant("m1", "opus", usage(10, 5, Some(100))));
+        recorder.observe(&assistant("m1", "opus", usage(10, 5, Some(100))));
+        recorder.observe(&assistant("m2", "haiku", usage(3, 2, None)));
+        recorder.observe(&result(0.25, usage(999, 999, None)));
+
+        let metrics = recorder.snapshot();
+        assert_eq!(metrics.usage.input_tokens, 13);
+        assert_eq!(metrics.usage.output_tokens, 7);
+        assert_eq!(metrics.usage.cache_read_input_tokens, 100);
+        assert_eq!(metrics.usage.total(), 120);
+        assert_eq!(metrics.by_model["opus"].input_tokens, 10);
+        assert_eq!(metrics.by_model["haiku"].output_tokens, 2);
+        assert_eq!(metrics.total_cost_usd, Some(0.25));
+    }
+
+    #[test]
+    fn falls_back_to_result_usage() {
+        let recorder = MetricsRecorder::new(None);
+        recorder.observe_result(&ClaudeResult {
+            total_cost_usd: Some(0.5),
+            usage: Some(usage(40, 8, None)),
+            ..Default::default()
+        });
+
+        let metrics = recorder.snapshot();
+        assert_eq!(metrics.usage.input_tokens, 40);
+        assert!(metrics.by_model.is_empty());
+        assert_eq!(metrics.total_cost_usd, Some(0.5));
+    }
+
+    #[test]
+    fn callback_sees_every_change() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&calls);
+        let recorder = MetricsRecorder::new(Some(Arc::new(move |metrics: &SessionMetrics| {
+            seen.lock().unwrap().push(metrics.usage.total());
+        })));
+        recorder.observe(&assistant("m1", "opus", usage(1, 1, None)));
+        recorder.observe(&assistant("m1", "opus", usage(1, 1, None)));
+        recorder.observe(&result(0.1, usage(2, 2, None)));
+
+        assert_eq!(*calls.lock().unwrap(), vec![2, 2]);
+    }
+}
@@ -0,0 +1,502 @@
+//! Scripted replay backend for tests (feature `mock`)
+//!
+//! A [`ReplayScript`] stands in for the Claude CLI: [`Client::replay`](crate::Client::replay)
+//! launches sessions that read the script's lines as the CLI's stdout instead of spawning
+//! a process, so agent flows can be tested without the `claude` binary or a network.
+//!
+//! Fixtures are JSONL files of the events the CLI prints with `--output-format
+//! stream-json`; a JSON-output fixture is the single result object. Each `result` event
+//! ends a turn. Sessions with streaming input (`InputFormat::StreamJson`) get one turn per
+//! user message, and after writing a `control_request` event the replay waits for its
+//! `control_response`, so permission handlers are exercised too. Everything written to
+//! stdin is recorded in [`ReplayInputs`].
+//!
+//! ```rust,no_run
+//! use claudecode::mock::ReplayScript;
+//! use claudecode::{Client, SessionConfig};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let script = ReplayScript::load("tests/fixtures/hello.jsonl").await?;
+//! let client = Client::replay(script);
+//!
+//! let config = SessionConfig::builder("Say hello").build()?;
+//! let result = client.launch_and_wait(config).await?;
+//! assert_eq!(result.content.as_deref(), Some("Hello!"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Code that builds its own client with [`Client::new`](crate::Client::new) replays the
+//! fixture named by [`REPLAY_FIXTURE_ENV`] when it is set.
+
+use crate::error::ClaudeError;
+use crate::error::Result;
+use crate::process::InputPipe;
+use crate::process::OutputPipe;
+use crate::types::Event;
+use nix::sys::signal::Signal;
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::os::unix::process::ExitStatusExt;
+use std::path::Path;
+use std::process::ExitStatus;
+use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::io::DuplexStream;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Path of a JSONL fixture that [`Client::new`](crate::Client::new) replays instead of
+/// finding the CLI
+pub const REPLAY_FIXTURE_ENV: &str = "CLAUDECODE_REPLAY_FIXTURE";
+
+const PIPE_CAPACITY: usize = 64 * 1024;
+
+/// Lines the replay writes to stdout, split into turns
+#[derive(Debug, Clone, Default)]
+pub struct ReplayScript {
+    turns: Vec<Vec<String>>,
+    stderr: Option<String>,
+    exit_code: i32,
+    line_delay: Option<Duration>,
+    inputs: ReplayInputs,
+}
+
+impl ReplayScript {
+    /// Parse a JSONL fixture, one CLI output event per line
+    ///
+    /// Blank lines are skipped; any other line must be valid JSON.
+    pub fn from_jsonl(jsonl: &str) -> Result<Self> {
+        let mut lines = Vec::new();
+        for line in jsonl.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            serde_json::from_str::<Value>(line).map_err(|source| ClaudeError::JsonParseError {
+                source,
+                line: Some(line.to_string()),
+            })?;
+            lines.push(line.to_string());
+        }
+        Ok(Self::from_lines(lines))
+    }
+
+    /// Read a JSONL fixture from `path`
+    pub async fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let jsonl = tokio::fs::read_to_string(path).await?;
+        Self::from_jsonl(&jsonl)
+    }
+
+    /// Replay `events` as streaming JSON output
+    pub fn from_events(events: &[Event]) -> Result<Self> {
+        let lines = events
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(Self::from_lines(lines))
+    }
+
+    /// Replay `output` verbatim, as from `--output-format text`
+    pub fn text(output: impl Into<String>) -> Self {
+        let output: String = output.into();
+        Self::from_lines(output.lines().map(str::to_string).collect())
+    }
+
+    fn from_lines(lines: Vec<String>) -> Self {
+        let mut turns = vec![Vec::new()];
+        for line in lines {
+            let ends_turn = is_result(&line);
+            if let Some(turn) = turns.last_mut() {
+                turn.push(line);
+            }
+            if ends_turn {
+                turns.push(Vec::new());
+            }
+        }
+        // Drop the empty turn opened by a trailing result
+        if turns.len() > 1 && turns.last().is_some_and(Vec::is_empty) {
+            turns.pop();
+        }
+        Self {
+            turns,
+            ..Self::default()
+        }
+    }
+
+    /// Write `stderr` to the replay's stderr before any output
+    #[must_use]
+    pub fn with_stderr(mut self, stderr: impl Into<String>) -> Self {
+        self.stderr = Some(stderr.into());
+        self
+    }
+
+    /// Exit with `code` once the script is done (default 0)
+    #[must_use]
+    pub const fn with_exit_code(mut self, code: i32) -> Self {
+        self.exit_code = code;
+        self
+    }
+
+    /// Pause before each line, e.g. to exercise timeouts
+    #[must_use]
+    pub const fn with_line_delay(mut self, delay: Duration) -> Self {
+        self.line_delay = Some(delay);
+        self
+    }
+
+    /// Number of turns in the script
+    pub fn turns(&self) -> usize {
+        self.turns.len()
+    }
+
+    /// Everything sessions replaying this script write to stdin
+    ///
+    /// Clones of the script share the same record.
+    pub fn inputs(&self) -> ReplayInputs {
+        self.inputs.clone()
+    }
+}
+
+fn is_result(line: &str) -> bool {
+    json_type(line).as_deref() == Some("result")
+}
+
+fn json_type(line: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(line).ok()?;
+    value.get("type")?.as_str().map(str::to_string)
+}
+
+/// The stdin lines written to replayed sessions, parsed as JSON
+#[derive(Debug, Clone, Default)]
+pub struct ReplayInputs {
+    lines: Arc<StdMutex<Vec<Value>>>,
+}
+
+impl ReplayInputs {
+    /// Every line received so far, in order
+    pub fn lines(&self) -> Vec<Value> {
+        self.lines.lock().map(|l| l.clone()).unwrap_or_default()
+    }
+
+    /// The text of each user message received so far
+    pub fn user_messages(&self) -> Vec<String> {
+        self.of_type("user")
+            .iter()
+            .map(|line| {
+                line["message"]["content"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|block| block["text"].as_str())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    /// The `response` of each successful control response received so far
+    pub fn control_responses(&self) -> Vec<Value> {
+        self.of_type("control_response")
+            .into_iter()
+            .map(|line| line["response"]["response"].clone())
+            .collect()
+    }
+
+    fn of_type(&self, kind: &str) -> Vec<Value> {
+        self.lines()
+            .into_iter()
+            .filter(|line| line["type"] == kind)
+            .collect()
+    }
+
+    fn record(&self, line: Value) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.push(line);
+        }
+    }
+}
+
+enum ReplayState {
+    Running(JoinHandle<ExitStatus>),
+    Exited(ExitStatus),
+}
+
+struct ControlInner {
+    cancel: CancellationToken,
+    signal: Arc<StdMutex<Option<Signal>>>,
+    state: Mutex<ReplayState>,
+}
+
+/// Stops and waits for a running replay, in place of a process group
+#[derive(Clone)]
+pub(crate) struct ReplayControl {
+    inner: Arc<ControlInner>,
+}
+
+/// A started replay and the ends of its pipes
+pub(crate) struct ReplayPipes {
+    pub(crate) control: ReplayControl,
+    pub(crate) stdin: Option<InputPipe>,
+    pub(crate) stdout: OutputPipe,
+    pub(crate) stderr: OutputPipe,
+}
+
+impl ReplayControl {
+    /// Start replaying `script` on the current runtime
+    pub(crate) fn start(script: &ReplayScript, pipe_stdin: bool) -> ReplayPipes {
+        let (stdout_reader, stdout_writer) = tokio::io::duplex(PIPE_CAPACITY);
+        let (stderr_reader, stderr_writer) = tokio::io::duplex(PIPE_CAPACITY);
+        let (stdin, stdin_reader) = if pipe_stdin {
+            let (writer, reader) = tokio::io::duplex(PIPE_CAPACITY);
+            (Some(Box::pin(writer) as InputPipe), Some(reader))
+        } else {
+            (None, None)
+        };
+
+        let cancel = CancellationToken::new();
+        let signal = Arc::new(StdMutex::new(None));
+        let replay = Replay {
+            script: script.clone(),
+            stdout: stdout_writer,
+            stderr: stderr_writer,
+            stdin: stdin_reader.map(|reader| BufReader::new(reader).lines()),
+            unread: VecDeque::new(),
+        };
+        let task = tokio::spawn({
+            let cancel = cancel.clone();
+            let signal = Arc::clone(&signal);
+            async move {
+                tokio::select! {
+                    biased;
+                    () = cancel.cancelled() => {
+                        let signal = signal.lock().ok().and_then(|s| *s);
+                        ExitStatus::from_raw(signal.unwrap_or(Signal::SIGKILL) as i32)
+                    }
+                    status = replay.run() => status,
+                }
+            }
+        });
+
+        ReplayPipes {
+            control: Self {
+                inner: Arc::new(ControlInner {
+                    cancel,
+                    signal,
+                    state: Mutex::new(ReplayState::Running(task)),
+                }),
+            },
+            stdin,
+            stdout: Box::pin(stdout_reader),
+            stderr: Box::pin(stderr_reader),
+        }
+    }
+
+    /// End the replay as if the process was killed by `signal`
+    pub(crate) fn signal(&self, signal: Signal) {
+        if let Ok(mut slot) = self.inner.signal.lock() {
+            slot.get_or_insert(signal);
+        }
+        self.inner.cancel.cancel();
+    }
+
+    pub(crate) async fn wait(&self) -> ExitStatus {
+        let mut state = self.inner.state.lock().await;
+        match &mut *state {
+            ReplayState::Running(task) => {
+                let status = task
+                    .await
+                    .unwrap_or_else(|_| ExitStatus::from_raw(Signal::SIGKILL as i32));
+                *state = ReplayState::Exited(status);
+                status
+            }
+            ReplayState::Exited(status) => *status,
+        }
+    }
+
+    pub(crate) fn try_wait(&self) -> Option<ExitStatus> {
+        let mut state = self.inner.state.try_lock().ok()?;
+        match &mut *state {
+            ReplayState::Running(task) if task.is_finished() => {
+                let status = futures::FutureExt::now_or_never(task)?
+                    .unwrap_or_else(|_| ExitStatus::from_raw(Signal::SIGKILL as i32));
+                *state = ReplayState::Exited(status);
+                Some(status)
+            }
+            ReplayState::Running(_) => None,
+            ReplayState::Exited(status) => Some(*status),
+        }
+    }
+}
+
+/// The replay task's side of the pipes
+struct Replay {
+    script: ReplayScript,
+    stdout: DuplexStream,
+    stderr: DuplexStream,
+    stdin: Option<tokio::io::Lines<BufReader<DuplexStream>>>,
+    // Lines read while waiting for another kind, such as a user message sent mid-turn
+    unread: VecDeque<Value>,
+}
+
+impl Replay {
+    async fn run(mut self) -> ExitStatus {
+        match self.replay().await {
+            // An exit code in the high byte, as in a wait status
+            Ok(code) => ExitStatus::from_raw((code & 0xff) << 8),
+            Err(e) => {
+                let _ = self
+                    .stderr
+                    .write_all(format!("replay failed: {e}\n").as_bytes())
+                    .await;
+                ExitStatus::from_raw(1 << 8)
+            }
+        }
+    }
+
+    async fn replay(&mut self) -> std::io::Result<i32> {
+        if let Some(stderr) = self.script.stderr.take() {
+            self.stderr.write_all(stderr.as_bytes()).await?;
+        }
+
+        let turns = std::mem::take(&mut self.script.turns);
+        if self.stdin.is_none() {
+            for line in turns.iter().flatten() {
+                self.write_line(line).await?;
+            }
+            return Ok(self.script.exit_code);
+        }
+
+        for turn in &turns {
+            // Like the CLI, stop quietly once input closes
+            if self.next_user_message().await?.is_none() {
+                return Ok(self.script.exit_code);
+            }
+            for line in turn {
+                self.write_line(line).await?;
+                if json_type(line).as_deref() == Some("control_request") {
+                    self.await_control_response(line).await?;
+                }
+            }
+        }
+
+        if self.next_user_message().await?.is_some() {
+            return Err(std::io::Error::other(format!(
+                "script has no turn left for user message {}",
+                turns.len() + 1
+            )));
+        }
+        Ok(self.script.exit_code)
+    }
+
+    async fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        if let Some(delay) = self.script.line_delay {
+            tokio::time::sleep(delay).await;
+        }
+        self.stdout.write_all(line.as_bytes()).await?;
+        self.stdout.write_all(b"\n").await?;
+        self.stdout.flush().await
+    }
+
+    /// Take the next line of `kind`, reading stdin and recording everything read
+    async fn read_until(&mut self, kind: &str) -> std::io::Result<Option<Value>> {
+        if let Some(index) = self.unread.iter().position(|line| line["type"] == kind) {
+            return Ok(self.unread.remove(index));
+        }
+        let Some(stdin) = self.stdin.as_mut() else {
+            return Ok(None);
+        };
+        while let Some(line) = stdin.next_line().await? {
+            let Ok(value) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            self.script.inputs.record(value.clone());
+            if value["type"] == kind {
+                return Ok(Some(value));
+            }
+            self.unread.push_back(value);
+        }
+        Ok(None)
+    }
+
+    async fn next_user_message(&mut self) -> std::io::Result<Option<Value>> {
+        self.read_until("user").await
+    }
+
+    async fn await_control_response(&mut self, request: &str) -> std::io::Result<()> {
+        let request_id = serde_json::from_str::<Value>(request)
+            .ok()
+            .and_then(|v| v["request_id"].as_str().map(str::to_string));
+        loop {
+            let Some(response) = self.read_until("control_response").await? else {
+                return Err(std::io::Error::other(
+                    "stdin closed before control response",
+                ));
+            };
+            if request_id.is_none()
+                || response["response"]["request_id"].as_str() == request_id.as_deref()
+            {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STREAM: &str = r#"
+{"type":"system","subtype":"init","session_id":"s1"}
+{"type":"result","subtype":"success","session_id":"s1","result":"one","is_error":false}
+{"type":"result","subtype":"success","session_id":"s1","result":"two","is_error":false}
+"#;
+
+    #[test]
+    fn splits_turns_at_results() {
+        let script = ReplayScript::from_jsonl(STREAM).unwrap();
+        assert_eq!(script.turns(), 2);
+        assert_eq!(script.turns[0].len(), 2);
+    }
+
+    #[test]
+    fn rejects_invalid_json_lines() {
+        let err = ReplayScript::from_jsonl("{\"type\":\"system\"}\nnot json").unwrap_err();
+        match err {
+            ClaudeError::JsonParseError { line, .. } => {
+                assert_eq!(line.as_deref(), Some("not json"));
+            }
+            other => panic!("expected JsonParseError, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn text_scripts_are_one_turn() {
+        let script = ReplayScript::text("line one\nline two");
+        assert_eq!(script.turns(), 1);
+        assert_eq!(script.turns[0], ["line one", "line two"]);
+    }
+
+    #[test]
+    fn inputs_pick_out_user_text_and_control_responses() {
+        let inputs = ReplayInputs::default();
+        inputs.record(serde_json::to_value(crate::types::UserInput::text("hi")).unwrap());
+        inputs.record(serde_json::json!({
+            "type": "control_response",
+            "response": { "subtype": "success", "request_id": "r1", "response": { "behavior": "allow" } }
+        }));
+
+        assert_eq!(inputs.user_messages(), ["hi"]);
+        assert_eq!(inputs.control_responses()[0]["behavior"], "allow");
+    }
+
+    #[tokio::test]
+    async fn signal_ends_replay_with_that_signal() {
+        let script = ReplayScript::text("x").with_line_delay(Duration::from_secs(30));
+        let pipes = ReplayControl::start(&script, false);
+        pipes.control.signal(Signal::SIGTERM);
+        let status = pipes.control.wait().await;
+        assert_eq!(status.signal(), Some(Signal::SIGTERM as i32));
+        assert_eq!(pipes.control.try_wait(), Some(status));
+    }
+}
@@ -0,0 +1,182 @@
+//! Programmatic tool-use approval
+//!
+//! A [`PermissionHandler`] set with
+//! [`SessionConfigBuilder::permission_handler`](crate::SessionConfigBuilder::permission_handler)
+//! is asked about each tool invocation the CLI would otherwise prompt for, instead of
+//! relying on a blanket [`PermissionMode`](crate::PermissionMode). The CLI's permission
+//! prompt protocol (`--permission-prompt-tool stdio`) runs over the streaming JSON
+//! channels: a `control_request` event on stdout, answered by a `control_response` line
+//! on stdin. Handlers therefore require streaming JSON input and output.
+//!
+//! ```rust,no_run
+//! use claudecode::{Client, InputFormat, OutputFormat, SessionConfig};
+//! use claudecode::{PermissionDecision, PermissionRequest};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = SessionConfig::builder("Clean up the build directory")
+//!     .input_format(InputFormat::StreamJson)
+//!     .output_format(OutputFormat::StreamingJson)
+//!     .permission_handler(|request: PermissionRequest| async move {
+//!         match request.input["command"].as_str() {
+//!             Some(command) if command.starts_with("rm ") => {
+//!                 PermissionDecision::deny("Deleting files is not allowed")
+//!             }
+//!             _ => PermissionDecision::allow(),
+//!         }
+//!     })
+//!     .build()?;
+//!
+//! let result = Client::new().await?.launch_and_wait(config).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::PermissionRequest;
+use futures::future::BoxFuture;
+use serde_json::Value;
+use serde_json::json;
+use std::future::Future;
+
+/// Decides whether the CLI may run a tool
+///
+/// Implemented for any `Fn(PermissionRequest) -> impl Future<Output = PermissionDecision>`.
+/// Requests are handled concurrently, each on its own task.
+pub trait PermissionHandler: Send + Sync {
+    fn decide(&self, request: PermissionRequest) -> BoxFuture<'static, PermissionDecision>;
+}
+
+impl<F, Fut> PermissionHandler for F
+where
+    F: Fn(PermissionRequest) -> Fut + Send + Sync,
+    Fut: Future<Output = PermissionDecision> + Send + 'static,
+{
+    fn decide(&self, request: PermissionRequest) -> BoxFuture<'static, PermissionDecision> {
+        Box::pin(self(request))
+    }
+}
+
+impl std::fmt::Debug for dyn PermissionHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PermissionHandler")
+    }
+}
+
+/// The answer to a [`PermissionRequest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermissionDecision {
+    /// Run the tool, optionally with rewritten input
+    Allow { updated_input: Option<Value> },
+    /// Refuse the tool; `message` is shown to the model, and `interrupt` ends the turn
+    Deny { message: String, interrupt: bool },
+}
+
+impl PermissionDecision {
+    /// Run the tool with its original input
+    pub fn allow() -> Self {
+        Self::Allow {
+            updated_input: None,
+        }
+    }
+
+    /// Run the tool with `input` in place of the requested input
+    pub fn allow_with_input(input: Value) -> Self {
+        Self::Allow {
+            updated_input: Some(input),
+        }
+    }
+
+    /// Refuse the tool and let the model carry on
+    pub fn deny(message: impl Into<String>) -> Self {
+        Self::Deny {
+            message: message.into(),
+            interrupt: false,
+        }
+    }
+
+    /// Refuse the tool and stop the current turn
+    pub fn deny_and_interrupt(message: impl Into<String>) -> Self {
+        Self::Deny {
+            message: message.into(),
+            interrupt: true,
+        }
+    }
+
+    /// The CLI's wire form, falling back to `input` when no rewrite was given
+    pub(crate) fn to_wire(&self, input: &Value) -> Value {
+        match self {
+            Self::Allow { updated_input } => json!({
+                "behavior": "allow",
+                "updatedInput": updated_input.as_ref().unwrap_or(input),
+            }),
+            Self::Deny { message, interrupt } => json!({
+                "behavior": "deny",
+                "message": message,
+                "interrupt": interrupt,
+            }),
+        }
+    }
+}
+
+/// The stdin line answering control request `request_id`
+pub(crate) fn control_response(request_id: &str, response: &Value) -> String {
+    json!({
+        "type": "control_response",
+        "response": {
+            "subtype": "success",
+            "request_id": request_id,
+            "response": response,
+        },
+    })
+    .to_string()
+}
+
+/// The stdin line rejecting control request `request_id`
+pub(crate) fn control_error(request_id: &str, error: &str) -> String {
+    json!({
+        "type": "control_response",
+        "response": {
+            "subtype": "error",
+            "request_id": request_id,
+            "error": error,
+        },
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allow_falls_back_to_original_input() {
+        let input = json!({ "command": "ls" });
+        assert_eq!(
+            PermissionDecision::allow().to_wire(&input),
+            json!({ "behavior": "allow", "updatedInput": { "command": "ls" } })
+        );
+        assert_eq!(
+            PermissionDecision::allow_with_input(json!({ "command": "ls -a" })).to_wire(&input),
+            json!({ "behavior": "allow", "updatedInput": { "command": "ls -a" } })
+        );
+    }
+
+    #[test]
+    fn deny_carries_message_and_interrupt() {
+        assert_eq!(
+            PermissionDecision::deny_and_interrupt("no").to_wire(&Value::Null),
+            json!({ "behavior": "deny", "message": "no", "interrupt": true })
+        );
+    }
+
+    #[test]
+    fn control_response_wraps_request_id() {
+        let line: Value = serde_json::from_str(&control_response("req-1", &json!({}))).unwrap();
+        assert_eq!(line["type"], "control_response");
+        assert_eq!(line["response"]["subtype"], "success");
+        assert_eq!(line["response"]["request_id"], "req-1");
+
+        let line: Value = serde_json::from_str(&control_error("req-2", "nope")).unwrap();
+        assert_eq!(line["response"]["subtype"], "error");
+        assert_eq!(line["response"]["error"], "nope");
+    }
+}
@@ -3,25 +3,87 @@
 //! Provides optional functionality to probe the Claude CLI for supported flags
 //! and capabilities. This is useful for validating SDK compatibility with the
 //! installed CLI version.
+//!
+//! Flags are scraped from `claude --help`. Some flags are left out of the help
+//! text, so the version from `claude --version` is checked against
+//! [`VERSIONED_FLAGS`] as well. Pass the result to
+//! [`SessionConfigBuilder::capabilities`](crate::SessionConfigBuilder::capabilities)
+//! to drop settings the installed CLI would reject.
 
 use crate::error::ClaudeError;
 use crate::error::Result;
 use std::collections::HashSet;
+use std::fmt;
 use std::path::Path;
 use std::process::Stdio;
 use tokio::io::AsyncReadExt;
 use tokio::process::Command;
 
+/// A Claude CLI release number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CliVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl CliVersion {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parse the first `major.minor[.patch]` token in `text`.
+    ///
+    /// Accepts `claude --version` output such as `2.0.14 (Claude Code)`. Pre-release
+    /// and build suffixes are ignored.
+    pub fn parse(text: &str) -> Option<Self> {
+        text.split_whitespace().find_map(|token| {
+            let token = token.trim_start_matches('v');
+            let core = token.split(['-', '+']).next()?;
+            let mut parts = core.split('.').map(str::parse::<u32>);
+            let major = parts.next()?.ok()?;
+            let minor = parts.next()?.ok()?;
+            let patch = match parts.next() {
+                Some(patch) => patch.ok()?,
+                None => 0,
+            };
+            Some(Self::new(major, minor, patch))
+        })
+    }
+}
+
+impl fmt::Display for CliVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Flags the CLI doesn't always list in `--help`, with the release that added them.
+pub const VERSIONED_FLAGS: &[(&str, CliVersion)] = &[
+    ("--disallowedTools", CliVersion::new(1, 0, 0)),
+    ("--strict-mcp-config", CliVersion::new(1, 0, 73)),
+    ("--output-style", CliVersion::new(2, 0, 0)),
+];
+
 /// Represents the capabilities detected from the CLI.
 #[derive(Debug, Clone, Default)]
 pub struct CliCapabilities {
     /// Set of flags detected from `claude --help` output.
     pub flags: HashSet<String>,
+    /// Version reported by `claude --version`, if it could be parsed.
+    pub version: Option<CliVersion>,
 }
 
 impl CliCapabilities {
     /// Check if a specific flag is supported by the CLI.
     ///
+    /// A flag is supported when `--help` lists it, or when it appears in
+    /// [`VERSIONED_FLAGS`] and the CLI is at least that version.
+    ///
     /// # Example
     /// ```ignore
     /// let caps = client.probe_cli().await?;
@@ -31,6 +93,16 @@ impl CliCapabilities {
     /// ```
     pub fn supports(&self, flag: &str) -> bool {
         self.flags.contains(flag)
+            || self.version.is_some_and(|version| {
+                VERSIONED_FLAGS
+                    .iter()
+                    .any(|(name, since)| *name == flag && version >= *since)
+            })
+    }
+
+    /// True when the probe found nothing, so support can't be judged either way.
+    pub fn is_unknown(&self) -> bool {
+        self.flags.is_empty() && self.version.is_none()
     }
 
     /// Check if all the given flags are supported.
@@ -47,7 +119,8 @@ impl CliCapabilities {
 /// Probe the Claude CLI for supported flags by parsing `--help` output.
 ///
 /// This function runs `claude --help` and extracts all flags (tokens starting
-/// with `--`) from the output.
+/// with `--`) from the output, then runs `claude --version` for the release
+/// number. A failed version probe leaves [`CliCapabilities::version`] unset.
 ///
 /// # Arguments
 /// * `claude_path` - Path to the claude executable
@@ -65,15 +138,31 @@ impl CliCapabilities {
 /// println!("Supports {} flags", caps.flags.len());
 /// ```
 pub async fn probe_cli(claude_path: &Path) -> Result<CliCapabilities> {
+    let help = run_for_stdout(claude_path, "--help").await?;
+    let flags = parse_flags_from_help(&help);
+
+    let version = match run_for_stdout(claude_path, "--version").await {
+        Ok(output) => CliVersion::parse(&output),
+        Err(e) => {
+            tracing::debug!("claude --version probe failed: {e}");
+            None
+        }
+    };
+
+    Ok(CliCapabilities { flags, version })
+}
+
+/// Run `claude <arg>` and return its stdout.
+async fn run_for_stdout(claude_path: &Path, arg: &str) -> Result<String> {
     let mut cmd = Command::new(claude_path);
-    cmd.arg("--help")
+    cmd.arg(arg)
         .stdin(Stdio::null())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped());
 
     let mut child = cmd.spawn().map_err(|e| ClaudeError::SpawnError {
         command: claude_path.display().to_string(),
-        args: vec!["--help".into()],
+        args: vec![arg.into()],
         source: e,
     })?;
 
@@ -83,22 +172,21 @@ pub async fn probe_cli(claude_path: &Path) -> Result<CliCapabilities> {
             .read_to_string(&mut stdout_content)
             .await
             .map_err(|e| ClaudeError::ProbeError {
-                message: format!("Failed to read --help stdout: {e}"),
+                message: format!("Failed to read {arg} stdout: {e}"),
             })?;
     }
 
     let status = child.wait().await.map_err(|e| ClaudeError::ProbeError {
-        message: format!("Failed to wait for --help: {e}"),
+        message: format!("Failed to wait for {arg}: {e}"),
     })?;
 
     if !status.success() {
         return Err(ClaudeError::ProbeError {
-            message: "claude --help exited with non-zero status".into(),
+            message: format!("claude {arg} exited with non-zero status"),
         });
     }
 
-    let flags = parse_flags_from_help(&stdout_content);
-    Ok(CliCapabilities { flags })
+    Ok(stdout_content)
 }
 
 /// Parse flags from help output text.
@@ -209,4 +297,35 @@ Options:
         assert!(caps.supports_any(&["--nonexistent", "--model"]));
         assert!(!caps.supports_any(&["--nonexistent", "--also-nonexistent"]));
     }
+
+    #[test]
+    fn test_cli_version_parse() {
+        assert_eq!(
+            CliVersion::parse("2.0.14 (Claude Code)"),
+            Some(CliVersion::new(2, 0, 14))
+        );
+        assert_eq!(
+            CliVersion::parse("claude v1.2-beta.1"),
+            Some(CliVersion::new(1, 2, 0))
+        );
+        assert_eq!(CliVersion::parse("Claude Code"), None);
+        assert!(CliVersion::new(1, 0, 73) < CliVersion::new(1, 1, 0));
+        assert_eq!(CliVersion::new(2, 0, 14).to_string(), "2.0.14");
+    }
+
+    #[test]
+    fn test_supports_versioned_flags_missing_from_help() {
+        let mut caps = CliCapabilities::default();
+        assert!(caps.is_unknown());
+        assert!(!caps.supports("--strict-mcp-config"));
+
+        caps.version = Some(CliVersion::new(1, 0, 50));
+        assert!(!caps.is_unknown());
+        assert!(caps.supports("--disallowedTools"));
+        assert!(!caps.supports("--strict-mcp-config"));
+
+        caps.version = Some(CliVersion::new(2, 0, 1));
+        assert!(caps.supports_all(&["--strict-mcp-config", "--output-style"]));
+        assert!(!caps.supports("--model"));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::config::EnvPolicy;
 use crate::error::ClaudeError;
 use crate::error::Result;
 use nix::errno::Errno;
@@ -7,10 +8,19 @@ use nix::unistd::Pid;
 use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
+use std::pin::Pin;
 use std::process::Stdio;
 use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::task::Context;
+use std::task::Poll;
 use std::time::Duration;
+use std::time::Instant;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncWrite;
 use tokio::io::BufReader;
+use tokio::io::ReadBuf;
 use tokio::process::Child;
 use tokio::process::Command;
 use tokio::sync::Mutex;
@@ -18,22 +28,131 @@ use which::which;
 
 pub(crate) const KILL_GRACE: Duration = Duration::from_millis(250);
 
+/// Read end of the backend's stdout or stderr
+pub(crate) type OutputPipe = Pin<Box<dyn AsyncRead + Send>>;
+/// Write end of the backend's stdin
+pub(crate) type InputPipe = Pin<Box<dyn AsyncWrite + Send>>;
+
 pub struct ProcessHandle {
-    child: Arc<Mutex<Child>>,
-    stdout_reader: Option<BufReader<tokio::process::ChildStdout>>,
-    stderr_reader: Option<BufReader<tokio::process::ChildStderr>>,
+    backend: Backend,
+    activity: Arc<Activity>,
+    stdin_writer: Option<InputPipe>,
+    stdout_reader: Option<BufReader<ActivityReader<OutputPipe>>>,
+    stderr_reader: Option<BufReader<OutputPipe>>,
+}
+
+/// What produces a session's output
+#[derive(Clone)]
+enum Backend {
+    /// A Claude CLI child process, leading its own process group
+    Child(Arc<Mutex<Child>>),
+    /// A scripted replay on the current runtime
+    #[cfg(feature = "mock")]
+    Replay(crate::mock::ReplayControl),
+}
+
+/// When the process started and when it last wrote to stdout
+#[derive(Debug)]
+pub(crate) struct Activity {
+    started: Instant,
+    last_ms: AtomicU64,
+}
+
+impl Activity {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            last_ms: AtomicU64::new(0),
+        }
+    }
+
+    fn touch(&self) {
+        let elapsed = u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        self.last_ms.store(elapsed, Ordering::Relaxed);
+    }
+
+    pub(crate) const fn started(&self) -> Instant {
+        self.started
+    }
+
+    /// The last stdout read, or the start if there was none
+    pub(crate) fn last(&self) -> Instant {
+        self.started + Duration::from_millis(self.last_ms.load(Ordering::Relaxed))
+    }
+}
+
+/// A reader that records each non-empty read in an [`Activity`]
+pub(crate) struct ActivityReader<R> {
+    inner: R,
+    activity: Arc<Activity>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ActivityReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let before = buf.filled().len();
+        let poll = Pin::new(&mut this.inner).poll_read(cx, buf);
+        if buf.filled().len() > before {
+            this.activity.touch();
+        }
+        poll
+    }
+}
+
+/// Reaps a killed child, so it doesn't linger as a zombie
+#[derive(Clone)]
+pub(crate) struct Reaper {
+    backend: Backend,
+}
+
+impl Reaper {
+    /// Wait for the child on the current runtime, if there is one
+    ///
+    /// Without a runtime, tokio's `kill_on_drop` reaping is the fallback.
+    pub(crate) fn reap_in_background(&self) {
+        // A replay task finishes on its own once cancelled
+        #[cfg_attr(
+            not(feature = "mock"),
+            expect(irrefutable_let_patterns, reason = "replay backend is feature-gated")
+        )]
+        let Backend::Child(child) = &self.backend else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let child = Arc::clone(child);
+        runtime.spawn(async move {
+            let _ = child.lock().await.wait().await;
+        });
+    }
 }
 
 #[derive(Clone)]
 pub struct KillHandle {
-    pid: i32,
+    target: KillTarget,
+}
+
+#[derive(Clone)]
+enum KillTarget {
+    ProcessGroup(i32),
+    #[cfg(feature = "mock")]
+    Replay(crate::mock::ReplayControl),
 }
 
 impl std::fmt::Debug for KillHandle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("KillHandle")
-            .field("pid", &self.pid)
-            .finish_non_exhaustive()
+        let mut debug = f.debug_struct("KillHandle");
+        match &self.target {
+            KillTarget::ProcessGroup(pid) => debug.field("pid", pid),
+            #[cfg(feature = "mock")]
+            KillTarget::Replay(_) => debug.field("replay", &true),
+        };
+        debug.finish_non_exhaustive()
     }
 }
 
@@ -45,6 +164,8 @@ impl ProcessHandle {
     /// * `args` - Command line arguments
     /// * `working_dir` - Optional working directory
     /// * `env_overlay` - Optional environment variables to add/override
+    /// * `env_policy` - Which parent environment variables are inherited
+    /// * `pipe_stdin` - Keep a pipe to the child's stdin (otherwise it reads null)
     #[expect(
         clippy::unused_async,
         reason = "async for API consistency with wait and kill"
@@ -54,10 +175,16 @@ impl ProcessHandle {
         args: Vec<String>,
         working_dir: Option<&Path>,
         env_overlay: Option<&HashMap<String, String>>,
+        env_policy: &EnvPolicy,
+        pipe_stdin: bool,
     ) -> Result<Self> {
         let mut cmd = Command::new(claude_path);
         cmd.args(&args)
-            .stdin(Stdio::null())
+            .stdin(if pipe_stdin {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .kill_on_drop(true);
@@ -73,14 +200,20 @@ impl ProcessHandle {
             cmd.current_dir(dir);
         }
 
-        // Apply environment overlay: inherit current env and add/override with overlay
-        if let Some(env_map) = env_overlay {
-            // Inherit all current environment variables
-            cmd.envs(std::env::vars());
-            // Override/add from the overlay
-            for (k, v) in env_map {
-                cmd.env(k, v);
+        if env_policy.inherits_all() {
+            // Apply environment overlay: inherit current env and add/override with overlay
+            if let Some(env_map) = env_overlay {
+                // Inherit all current environment variables
+                cmd.envs(std::env::vars());
+                // Override/add from the overlay
+                for (k, v) in env_map {
+                    cmd.env(k, v);
+                }
             }
+        } else {
+            // Isolated environment: only what the policy lets through, plus the overlay
+            cmd.env_clear()
+                .envs(env_policy.resolve(std::env::vars(), env_overlay));
         }
 
         let mut child = cmd.spawn().map_err(|e| ClaudeError::SpawnError {
@@ -102,66 +235,145 @@ impl ProcessHandle {
                 message: "Failed to capture stderr".to_string(),
             })?;
 
-        Ok(Self {
-            child: Arc::new(Mutex::new(child)),
-            stdout_reader: Some(BufReader::new(stdout)),
+        let stdin = child.stdin.take();
+
+        Ok(Self::from_pipes(
+            Backend::Child(Arc::new(Mutex::new(child))),
+            stdin.map(|stdin| Box::pin(stdin) as InputPipe),
+            Box::pin(stdout),
+            Box::pin(stderr),
+        ))
+    }
+
+    /// Replay `script` in place of the Claude CLI
+    ///
+    /// With `pipe_stdin`, the replay waits for a user message before each turn.
+    #[cfg(feature = "mock")]
+    pub fn replay(script: &crate::mock::ReplayScript, pipe_stdin: bool) -> Self {
+        let pipes = crate::mock::ReplayControl::start(script, pipe_stdin);
+        Self::from_pipes(
+            Backend::Replay(pipes.control),
+            pipes.stdin,
+            pipes.stdout,
+            pipes.stderr,
+        )
+    }
+
+    fn from_pipes(
+        backend: Backend,
+        stdin: Option<InputPipe>,
+        stdout: OutputPipe,
+        stderr: OutputPipe,
+    ) -> Self {
+        let activity = Arc::new(Activity::new());
+        Self {
+            backend,
+            stdin_writer: stdin,
+            stdout_reader: Some(BufReader::new(ActivityReader {
+                inner: stdout,
+                activity: Arc::clone(&activity),
+            })),
+            activity,
             stderr_reader: Some(BufReader::new(stderr)),
-        })
+        }
     }
 
     pub async fn wait(self) -> Result<std::process::ExitStatus> {
-        let mut child = self.child.lock().await;
-        Ok(child.wait().await?)
+        match &self.backend {
+            Backend::Child(child) => Ok(child.lock().await.wait().await?),
+            #[cfg(feature = "mock")]
+            Backend::Replay(control) => Ok(control.wait().await),
+        }
     }
 
     pub async fn kill(&mut self) -> Result<()> {
-        let mut child = self.child.lock().await;
-        child.kill().await?;
+        match &self.backend {
+            Backend::Child(child) => child.lock().await.kill().await?,
+            #[cfg(feature = "mock")]
+            Backend::Replay(control) => {
+                control.signal(Signal::SIGKILL);
+                control.wait().await;
+            }
+        }
         Ok(())
     }
 
+    /// The child's PID; `None` once it has been reaped, or for a replay
     pub fn id(&self) -> Option<u32> {
-        self.child.try_lock().ok().and_then(|child| child.id())
+        match &self.backend {
+            Backend::Child(child) => child.try_lock().ok().and_then(|child| child.id()),
+            #[cfg(feature = "mock")]
+            Backend::Replay(_) => None,
+        }
     }
 
     pub fn try_wait(&mut self) -> Result<Option<std::process::ExitStatus>> {
-        let mut child = self
-            .child
-            .try_lock()
-            .map_err(|_| ClaudeError::SessionError {
-                message: "Process wait already in progress".to_string(),
-            })?;
-        Ok(child.try_wait()?)
+        match &self.backend {
+            Backend::Child(child) => {
+                let mut child = child.try_lock().map_err(|_| ClaudeError::SessionError {
+                    message: "Process wait already in progress".to_string(),
+                })?;
+                Ok(child.try_wait()?)
+            }
+            #[cfg(feature = "mock")]
+            Backend::Replay(control) => Ok(control.try_wait()),
+        }
     }
 
     pub fn kill_handle(&self) -> Result<KillHandle> {
-        let pid = self.id().ok_or_else(|| ClaudeError::SessionError {
-            message: "Process not found or already terminated".to_string(),
-        })?;
+        let target = match &self.backend {
+            Backend::Child(_) => {
+                let pid = self.id().ok_or_else(|| ClaudeError::SessionError {
+                    message: "Process not found or already terminated".to_string(),
+                })?;
+                let pid = i32::try_from(pid).map_err(|_| ClaudeError::SessionError {
+                    message: format!("PID {pid} out of i32 range"),
+                })?;
+                KillTarget::ProcessGroup(pid)
+            }
+            #[cfg(feature = "mock")]
+            Backend::Replay(control) => KillTarget::Replay(control.clone()),
+        };
+        Ok(KillHandle { target })
+    }
 
-        let pid = i32::try_from(pid).map_err(|_| ClaudeError::SessionError {
-            message: format!("PID {pid} out of i32 range"),
-        })?;
+    pub(crate) fn take_stdout(&mut self) -> Option<BufReader<ActivityReader<OutputPipe>>> {
+        self.stdout_reader.take()
+    }
 
-        Ok(KillHandle { pid })
+    pub(crate) fn activity(&self) -> Arc<Activity> {
+        Arc::clone(&self.activity)
     }
 
-    pub(crate) fn take_stdout(&mut self) -> Option<BufReader<tokio::process::ChildStdout>> {
-        self.stdout_reader.take()
+    pub(crate) fn reaper(&self) -> Reaper {
+        Reaper {
+            backend: self.backend.clone(),
+        }
     }
 
-    pub(crate) fn take_stderr(&mut self) -> Option<BufReader<tokio::process::ChildStderr>> {
+    pub(crate) fn take_stdin(&mut self) -> Option<InputPipe> {
+        self.stdin_writer.take()
+    }
+
+    pub(crate) fn take_stderr(&mut self) -> Option<BufReader<OutputPipe>> {
         self.stderr_reader.take()
     }
 }
 
 impl KillHandle {
     pub fn signal(&self, sig: Signal) -> nix::Result<()> {
-        signal_process_group(self.pid, sig)
+        match &self.target {
+            KillTarget::ProcessGroup(pid) => signal_process_group(*pid, sig),
+            #[cfg(feature = "mock")]
+            KillTarget::Replay(control) => {
+                control.signal(sig);
+                Ok(())
+            }
+        }
     }
 
     pub async fn graceful_terminate(&self) -> Result<()> {
-        tracing::info!(pid = self.pid, "terminating Claude process group");
+        tracing::info!(process = ?self, "terminating Claude process group");
         self.signal(Signal::SIGTERM).map_err(nix_to_claude_error)?;
 
         tokio::time::sleep(KILL_GRACE).await;
@@ -172,7 +384,7 @@ impl KillHandle {
     }
 
     pub fn kill_now(&self) -> nix::Result<()> {
-        tracing::info!(pid = self.pid, "force killing Claude process group");
+        tracing::info!(process = ?self, "force killing Claude process group");
         self.signal(Signal::SIGKILL)
     }
 }
@@ -303,4 +515,38 @@ mod tests {
             std::env::remove_var("CLAUDE_PATH");
         }
     }
+
+    #[tokio::test]
+    async fn test_spawn_applies_env_policy() {
+        use tokio::io::AsyncReadExt;
+
+        let policy = EnvPolicy {
+            allow: Some(Vec::new()),
+            path: Some("/bin:/usr/bin".to_string()),
+            ..Default::default()
+        };
+        let extra = HashMap::from([("EXTRA".to_string(), "1".to_string())]);
+        let mut process = ProcessHandle::spawn(
+            Path::new("/bin/sh"),
+            vec![
+                "-c".to_string(),
+                r#"printf '%s|%s|%s' "$HOME" "$PATH" "$EXTRA""#.to_string(),
+            ],
+            None,
+            Some(&extra),
+            &policy,
+            false,
+        )
+        .await
+        .unwrap();
+
+        let mut out = String::new();
+        process
+            .take_stdout()
+            .unwrap()
+            .read_to_string(&mut out)
+            .await
+            .unwrap();
+        assert_eq!(out, "|/bin:/usr/bin|1");
+    }
 }
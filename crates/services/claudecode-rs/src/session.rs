@@ -1,23 +1,40 @@
 use crate::config::SessionConfig;
 use crate::error::ClaudeError;
 use crate::error::Result;
+use crate::error::TimeoutKind;
+use crate::metrics::MetricsRecorder;
+use crate::metrics::SessionMetrics;
+use crate::permission::PermissionHandler;
+use crate::permission::control_error;
+use crate::permission::control_response;
+use crate::process::Activity;
+use crate::process::InputPipe;
 use crate::process::KillHandle;
+use crate::process::OutputPipe;
 use crate::process::ProcessHandle;
+use crate::process::Reaper;
 use crate::stream::JsonStreamParser;
 use crate::stream::SingleJsonParser;
 use crate::stream::TextParser;
+use crate::types::ControlRequest;
+use crate::types::ControlRequestEvent;
 use crate::types::Event;
 use crate::types::OutputFormat;
 use crate::types::Result as ClaudeResult;
+use crate::types::UserInput;
 use chrono::Utc;
 use futures::StreamExt;
 use nix::sys::signal::Signal;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
+use std::time::Instant;
 use tempfile::NamedTempFile;
 use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
 use tokio::sync::RwLock;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
@@ -28,11 +45,77 @@ use uuid::Uuid;
 
 const TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
 
+/// Child stdin of an `InputFormat::StreamJson` session
+///
+/// Closing waits for every sent message to be answered with a result event, since the
+/// CLI may still need stdin for permission responses mid-turn.
+struct SessionInput {
+    stdin: Mutex<Option<InputPipe>>,
+    pending_turns: AtomicUsize,
+    closing: AtomicBool,
+}
+
+impl SessionInput {
+    fn new(stdin: Option<InputPipe>) -> Self {
+        Self {
+            stdin: Mutex::new(stdin),
+            pending_turns: AtomicUsize::new(0),
+            closing: AtomicBool::new(false),
+        }
+    }
+
+    async fn write_line(&self, line: &str) -> Result<()> {
+        let mut stdin = self.stdin.lock().await;
+        let writer = stdin.as_mut().ok_or_else(|| ClaudeError::SessionError {
+            message: "Session input already closed".to_string(),
+        })?;
+        writer.write_all(line.as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+        writer.flush().await?;
+        Ok(())
+    }
+
+    async fn send_turn(&self, line: &str) -> Result<()> {
+        if self.closing.load(Ordering::SeqCst) {
+            return Err(ClaudeError::SessionError {
+                message: "Session input already closed".to_string(),
+            });
+        }
+        self.pending_turns.fetch_add(1, Ordering::SeqCst);
+        let result = self.write_line(line).await;
+        if result.is_err() {
+            self.finish_turn().await;
+        }
+        result
+    }
+
+    /// Record a result event, closing stdin if it answered the last pending turn
+    async fn finish_turn(&self) {
+        let _ = self
+            .pending_turns
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1));
+        if self.closing.load(Ordering::SeqCst) && self.pending_turns.load(Ordering::SeqCst) == 0 {
+            self.stdin.lock().await.take();
+        }
+    }
+
+    async fn close(&self) {
+        self.closing.store(true, Ordering::SeqCst);
+        if self.pending_turns.load(Ordering::SeqCst) == 0 {
+            self.stdin.lock().await.take();
+        }
+    }
+}
+
 pub struct Session {
     id: String,
     config: SessionConfig,
     start_time: chrono::DateTime<Utc>,
     kill: KillHandle,
+    reaper: Reaper,
+
+    // Child stdin, kept open for InputFormat::StreamJson sessions
+    input: Arc<SessionInput>,
 
     // Event channel for streaming
     events_tx: Option<mpsc::UnboundedSender<Event>>,
@@ -41,18 +124,22 @@ pub struct Session {
     // Background tasks
     worker_task: std::sync::Mutex<Option<JoinHandle<()>>>,
     stderr_task: std::sync::Mutex<Option<JoinHandle<()>>>,
+    supervisor_task: std::sync::Mutex<Option<JoinHandle<()>>>,
     process_group_owned: Arc<AtomicBool>,
 
     // Result storage
     result: Arc<RwLock<Option<ClaudeResult>>>,
     error: Arc<RwLock<Option<ClaudeError>>>,
+    timed_out: Arc<RwLock<Option<ClaudeError>>>,
+    claude_session_id: Arc<RwLock<Option<String>>>,
+    metrics: Arc<MetricsRecorder>,
 
     // Temp file for MCP config (must be kept alive)
     _mcp_temp_file: Option<NamedTempFile>,
 }
 
 impl Session {
-    pub async fn new(config: SessionConfig, process: ProcessHandle) -> Result<Self> {
+    pub async fn new(config: SessionConfig, mut process: ProcessHandle) -> Result<Self> {
         // Determine session ID from explicit_session_id, resume_session_id, or generate new
         let id = if let Some(ref id) = config.explicit_session_id {
             id.clone()
@@ -70,7 +157,18 @@ impl Session {
             _ => (None, None),
         };
 
+        // Known up front only when the CLI is told which ID to use
+        let claude_session_id = config.explicit_session_id.clone().or_else(|| {
+            config
+                .resume_session_id
+                .clone()
+                .filter(|_| !config.fork_session)
+        });
+
         let kill = process.kill_handle()?;
+        let reaper = process.reaper();
+        let activity = process.activity();
+        let stdin = process.take_stdin();
         let result = Arc::new(RwLock::new(None));
         let error = Arc::new(RwLock::new(None));
         let process_group_owned = Arc::new(AtomicBool::new(true));
@@ -80,18 +178,31 @@ impl Session {
             config: config.clone(),
             start_time: Utc::now(),
             kill,
+            reaper,
+            input: Arc::new(SessionInput::new(stdin)),
             events_tx,
             events,
             worker_task: std::sync::Mutex::new(None),
             stderr_task: std::sync::Mutex::new(None),
+            supervisor_task: std::sync::Mutex::new(None),
             process_group_owned,
             result: Arc::clone(&result),
             error: Arc::clone(&error),
+            timed_out: Arc::new(RwLock::new(None)),
+            claude_session_id: Arc::new(RwLock::new(claude_session_id)),
+            metrics: Arc::new(MetricsRecorder::new(config.metrics_callback.clone())),
             _mcp_temp_file: None,
         };
 
         // Start background tasks based on output format
         session.start_tasks(process).await?;
+        session.start_supervisor(&activity)?;
+
+        // Streaming input takes the query as its first user message
+        if session.config.input_format == Some(crate::types::InputFormat::StreamJson) {
+            let query = session.config.query.clone();
+            session.send_user_message(query).await?;
+        }
 
         Ok(session)
     }
@@ -103,7 +214,11 @@ impl Session {
     async fn start_tasks(&mut self, mut process: ProcessHandle) -> Result<()> {
         let result = Arc::clone(&self.result);
         let error = Arc::clone(&self.error);
+        let claude_session_id = Arc::clone(&self.claude_session_id);
         let process_group_owned = Arc::clone(&self.process_group_owned);
+        let input = Arc::clone(&self.input);
+        let metrics = Arc::clone(&self.metrics);
+        let permission_handler = self.config.permission_handler.clone();
 
         match self.config.output_format {
             OutputFormat::StreamingJson => {
@@ -131,7 +246,11 @@ impl Session {
                         events_tx,
                         result_clone,
                         Arc::clone(&error),
+                        claude_session_id,
                         process_group_owned,
+                        input,
+                        permission_handler,
+                        metrics,
                     )
                     .await
                     {
@@ -145,6 +264,9 @@ impl Session {
                     match Self::handle_json(process, Arc::clone(&error), process_group_owned).await
                     {
                         Ok(r) => {
+                            metrics.observe_result(&r);
+                            Self::record_session_id(&claude_session_id, r.session_id.as_deref())
+                                .await;
                             result.write().await.replace(r);
                         }
                         Err(e) => {
@@ -159,6 +281,9 @@ impl Session {
                     match Self::handle_text(process, Arc::clone(&error), process_group_owned).await
                     {
                         Ok(r) => {
+                            metrics.observe_result(&r);
+                            Self::record_session_id(&claude_session_id, r.session_id.as_deref())
+                                .await;
                             result.write().await.replace(r);
                         }
                         Err(e) => {
@@ -173,6 +298,59 @@ impl Session {
         Ok(())
     }
 
+    /// Enforce the configured timeouts, if any
+    fn start_supervisor(&self, activity: &Arc<Activity>) -> Result<()> {
+        if self.config.timeout.is_none() && self.config.idle_timeout.is_none() {
+            return Ok(());
+        }
+        let supervisor_task = tokio::spawn(Self::supervise(
+            self.kill.clone(),
+            Arc::clone(activity),
+            self.config.timeout,
+            self.config.idle_timeout,
+            Arc::clone(&self.timed_out),
+        ));
+        Self::store_task(&self.supervisor_task, supervisor_task)
+    }
+
+    /// Kill the process group once `timeout` has passed since launch or `idle_timeout`
+    /// since the last output, recording which in `timed_out`
+    async fn supervise(
+        kill: KillHandle,
+        activity: Arc<Activity>,
+        timeout: Option<Duration>,
+        idle_timeout: Option<Duration>,
+        timed_out: Arc<RwLock<Option<ClaudeError>>>,
+    ) {
+        let (kind, limit) = loop {
+            let wall = timeout.map(|t| (activity.started() + t, TimeoutKind::WallClock, t));
+            let idle = idle_timeout.map(|t| (activity.last() + t, TimeoutKind::Idle, t));
+            let Some((deadline, kind, limit)) = wall
+                .into_iter()
+                .chain(idle)
+                .min_by_key(|(deadline, ..)| *deadline)
+            else {
+                return;
+            };
+            tokio::time::sleep_until(deadline.into()).await;
+
+            // Output since the deadline was computed pushes the idle deadline back
+            if kind == TimeoutKind::Idle && activity.last() + limit > Instant::now() {
+                continue;
+            }
+            break (kind, limit);
+        };
+
+        warn!(timeout = ?limit, %kind, "Claude session timed out, killing process group");
+        timed_out.write().await.replace(ClaudeError::Timeout {
+            kind,
+            timeout: limit,
+        });
+        if let Err(e) = kill.graceful_terminate().await {
+            warn!("Failed to kill timed-out session: {}", e);
+        }
+    }
+
     fn store_task(
         slot: &std::sync::Mutex<Option<JoinHandle<()>>>,
         task: JoinHandle<()>,
@@ -216,7 +394,7 @@ impl Session {
     }
 
     async fn capture_stderr(
-        stderr: tokio::io::BufReader<tokio::process::ChildStderr>,
+        stderr: tokio::io::BufReader<OutputPipe>,
         error: Arc<RwLock<Option<ClaudeError>>>,
     ) {
         let mut stderr_content = String::new();
@@ -233,12 +411,20 @@ impl Session {
         }
     }
 
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "the worker owns every piece of shared session state it updates"
+    )]
     async fn handle_streaming_json(
         mut process: ProcessHandle,
         events_tx: mpsc::UnboundedSender<Event>,
         result_arc: Arc<RwLock<Option<ClaudeResult>>>,
         error: Arc<RwLock<Option<ClaudeError>>>,
+        claude_session_id: Arc<RwLock<Option<String>>>,
         process_group_owned: Arc<AtomicBool>,
+        input: Arc<SessionInput>,
+        permission_handler: Option<Arc<dyn PermissionHandler>>,
+        metrics: Arc<MetricsRecorder>,
     ) -> Result<()> {
         let stdout = process
             .take_stdout()
@@ -254,8 +440,25 @@ impl Session {
         while let Some(result) = stream.next().await {
             match result {
                 Ok(event) => {
+                    metrics.observe(&event);
+
+                    // The init event reports the session ID first; the result confirms it
+                    if let Event::System(ref system) = event {
+                        Self::record_session_id(&claude_session_id, Some(&system.session_id)).await;
+                    }
+
+                    if let Event::ControlRequest(ref request) = event {
+                        Self::answer_control_request(
+                            request.clone(),
+                            Arc::clone(&input),
+                            permission_handler.clone(),
+                        );
+                    }
+
                     // Check if this is a result event and store it
                     if let Event::Result(ref result_event) = event {
+                        Self::record_session_id(&claude_session_id, Some(&result_event.session_id))
+                            .await;
                         let claude_result = ClaudeResult {
                             result_type: Some("result".to_string()),
                             subtype: None,
@@ -270,8 +473,10 @@ impl Session {
                             num_turns: result_event.num_turns,
                             exit_code: None,
                             usage: result_event.usage.clone(),
+                            metrics: None,
                         };
                         result_arc.write().await.replace(claude_result);
+                        input.finish_turn().await;
                     }
 
                     // Send event
@@ -306,6 +511,38 @@ impl Session {
         Ok(())
     }
 
+    /// Answer a control request on its own task, so a slow handler doesn't stall events
+    fn answer_control_request(
+        event: ControlRequestEvent,
+        input: Arc<SessionInput>,
+        permission_handler: Option<Arc<dyn PermissionHandler>>,
+    ) {
+        tokio::spawn(async move {
+            let line = match (event.request, permission_handler) {
+                (ControlRequest::CanUseTool(request), Some(handler)) => {
+                    let tool_input = request.input.clone();
+                    let decision = handler.decide(request).await;
+                    control_response(&event.request_id, &decision.to_wire(&tool_input))
+                }
+                (ControlRequest::CanUseTool(_), None) => {
+                    control_error(&event.request_id, "No permission handler registered")
+                }
+                (ControlRequest::Unknown, _) => {
+                    control_error(&event.request_id, "Unsupported control request")
+                }
+            };
+            if let Err(e) = input.write_line(&line).await {
+                warn!("Failed to answer control request: {}", e);
+            }
+        });
+    }
+
+    async fn record_session_id(slot: &RwLock<Option<String>>, id: Option<&str>) {
+        if let Some(id) = id.filter(|id| !id.is_empty()) {
+            slot.write().await.replace(id.to_string());
+        }
+    }
+
     async fn handle_json(
         mut process: ProcessHandle,
         _error: Arc<RwLock<Option<ClaudeError>>>,
@@ -372,49 +609,120 @@ impl Session {
         Ok(result)
     }
 
+    /// Send a user message to a session launched with `InputFormat::StreamJson`
+    ///
+    /// The message is written to the CLI's stdin while the event stream stays live; the
+    /// reply arrives as further events, ending with a result event for the turn.
+    pub async fn send_user_message(&self, text: impl Into<String>) -> Result<()> {
+        self.send_input(&UserInput::text(text)).await
+    }
+
+    /// Write one framed input message to a session launched with `InputFormat::StreamJson`
+    pub async fn send_input(&self, input: &UserInput) -> Result<()> {
+        if self.config.input_format != Some(crate::types::InputFormat::StreamJson) {
+            return Err(ClaudeError::SessionError {
+                message: "Session was not launched with InputFormat::StreamJson".to_string(),
+            });
+        }
+        self.input.send_turn(&serde_json::to_string(input)?).await
+    }
+
+    /// Close the session's input, letting the CLI exit once every sent message is answered
+    ///
+    /// Stdin stays open until then so permission prompts can still be answered.
+    /// [`wait`](Self::wait) does this itself. A no-op without streaming input.
+    pub async fn close_input(&self) {
+        self.input.close().await;
+    }
+
     /// Wait for the session to complete and return the result
+    ///
+    /// For a streaming-input session this closes its input first, so the result is the
+    /// one for the last turn.
     pub async fn wait(&self) -> Result<ClaudeResult> {
+        self.close_input().await;
+
         let worker_task = Self::take_task(&self.worker_task)?;
         let stderr_task = Self::take_task(&self.stderr_task)?;
 
         Self::await_task(worker_task, "worker").await?;
         Self::await_task(stderr_task, "stderr").await?;
 
+        // A supervisor that fired is still killing the rest of the process group
+        let supervisor_task = Self::take_task(&self.supervisor_task)?;
+        if self.timed_out.read().await.is_some() {
+            Self::await_task(supervisor_task, "supervisor").await?;
+        } else if let Some(task) = supervisor_task {
+            task.abort();
+        }
+
+        // A timeout explains whatever else went wrong when the process was killed
+        let timed_out = self.timed_out.write().await.take();
+        if let Some(error) = timed_out {
+            return Err(error);
+        }
+
         // Check for errors first - preserve original error variant (e.g., ProcessFailed{stderr})
         let error = self.error.write().await.take();
         if let Some(error) = error {
             return Err(error);
         }
 
-        // Return result
-        self.result
-            .read()
-            .await
-            .clone()
-            .ok_or_else(|| ClaudeError::SessionError {
-                message: "No result available".to_string(),
-            })
+        // Return result, with the session ID even if the output format doesn't report it
+        let mut result =
+            self.result
+                .read()
+                .await
+                .clone()
+                .ok_or_else(|| ClaudeError::SessionError {
+                    message: "No result available".to_string(),
+                })?;
+        if result.session_id.is_none() {
+            result.session_id = self.claude_session_id().await;
+        }
+        result.metrics = Some(self.metrics());
+        Ok(result)
     }
 
     pub async fn cancel(&self) -> Result<()> {
         info!(session_id = %self.id, "cancelling Claude session");
         self.kill.graceful_terminate().await?;
+        self.shutdown().await
+    }
+
+    /// Kill the Claude process group immediately, skipping the grace period that
+    /// [`cancel`](Self::cancel) gives it to exit on SIGTERM
+    pub async fn abort(&self) -> Result<()> {
+        info!(session_id = %self.id, "aborting Claude session");
+        self.kill
+            .kill_now()
+            .map_err(|err| ClaudeError::SessionError {
+                message: format!("Failed to kill process group: {err}"),
+            })?;
+        self.shutdown().await
+    }
+
+    /// Kill the Claude process
+    pub async fn kill(&mut self) -> Result<()> {
+        self.abort().await
+    }
+
+    /// Stop the background tasks and reap the signalled process
+    async fn shutdown(&self) -> Result<()> {
         self.process_group_owned.store(false, Ordering::Release);
+        if let Some(task) = Self::take_task(&self.supervisor_task)? {
+            task.abort();
+        }
 
         let worker_task = Self::take_task(&self.worker_task)?;
         let stderr_task = Self::take_task(&self.stderr_task)?;
         Self::shutdown_task(worker_task, "worker").await?;
         Self::shutdown_task(stderr_task, "stderr").await?;
+        self.reaper.reap_in_background();
 
         Ok(())
     }
 
-    /// Kill the Claude process
-    pub async fn kill(&mut self) -> Result<()> {
-        info!(session_id = %self.id, "force-killing Claude session");
-        self.cancel().await
-    }
-
     /// Send interrupt signal to the Claude process
     ///
     /// On Unix systems, this sends SIGINT which allows graceful shutdown.
@@ -435,6 +743,23 @@ impl Session {
         &self.id
     }
 
+    /// The Claude CLI's ID for this conversation, once known
+    ///
+    /// Streaming JSON output reports it in the init event, JSON output only in the final
+    /// result, and text output not at all (unless the session was launched with an explicit
+    /// or resumed ID). Pass it to [`Client::resume`](crate::Client::resume) to continue the
+    /// conversation in a later session.
+    pub async fn claude_session_id(&self) -> Option<String> {
+        self.claude_session_id.read().await.clone()
+    }
+
+    /// Usage and spend accumulated so far
+    ///
+    /// See [`metrics`](crate::metrics) for how usage is counted.
+    pub fn metrics(&self) -> SessionMetrics {
+        self.metrics.snapshot()
+    }
+
     /// Get the start time
     pub fn start_time(&self) -> chrono::DateTime<Utc> {
         self.start_time
@@ -466,20 +791,20 @@ impl Session {
 
 impl Drop for Session {
     fn drop(&mut self) {
-        if self.process_group_owned.load(Ordering::Acquire) {
-            let _ = self.kill.kill_now();
+        let killed =
+            self.process_group_owned.load(Ordering::Acquire) && self.kill.kill_now().is_ok();
+
+        for slot in [&self.worker_task, &self.stderr_task, &self.supervisor_task] {
+            if let Ok(mut task) = slot.lock()
+                && let Some(task) = task.take()
+            {
+                task.abort();
+            }
         }
 
-        if let Ok(mut worker_task) = self.worker_task.lock()
-            && let Some(task) = worker_task.take()
-        {
-            task.abort();
-        }
-
-        if let Ok(mut stderr_task) = self.stderr_task.lock()
-            && let Some(task) = stderr_task.take()
-        {
-            task.abort();
+        // Reap once the aborted worker releases the child
+        if killed {
+            self.reaper.reap_in_background();
         }
     }
 }
@@ -487,22 +812,51 @@ impl Drop for Session {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::EnvPolicy;
     use crate::config::SessionConfig;
     use crate::error::ClaudeError;
     use crate::types::OutputFormat;
     use std::path::Path;
 
-    async fn test_kill_handle() -> KillHandle {
-        let process = ProcessHandle::spawn(
+    async fn test_process() -> ProcessHandle {
+        ProcessHandle::spawn(
             Path::new("/bin/sh"),
             vec!["-c".to_string(), "exit 0".to_string()],
             None,
             None,
+            &EnvPolicy::default(),
+            false,
         )
         .await
-        .unwrap();
+        .unwrap()
+    }
 
-        process.kill_handle().unwrap()
+    /// A session around `process` whose worker finished with `error`
+    fn test_session(
+        config: SessionConfig,
+        process: &ProcessHandle,
+        error: Option<ClaudeError>,
+    ) -> Session {
+        Session {
+            id: "test".into(),
+            config,
+            start_time: Utc::now(),
+            kill: process.kill_handle().unwrap(),
+            reaper: process.reaper(),
+            input: Arc::new(SessionInput::new(None)),
+            events_tx: None,
+            events: None,
+            worker_task: std::sync::Mutex::new(None),
+            stderr_task: std::sync::Mutex::new(None),
+            supervisor_task: std::sync::Mutex::new(None),
+            process_group_owned: Arc::new(AtomicBool::new(false)),
+            result: Arc::new(RwLock::new(None)),
+            error: Arc::new(RwLock::new(error)),
+            timed_out: Arc::new(RwLock::new(None)),
+            claude_session_id: Arc::new(RwLock::new(None)),
+            metrics: Arc::new(MetricsRecorder::new(None)),
+            _mcp_temp_file: None,
+        }
     }
 
     #[tokio::test]
@@ -512,25 +866,16 @@ mod tests {
             .build()
             .unwrap();
 
-        let kill = test_kill_handle().await;
+        let process = test_process().await;
 
-        let session = Session {
-            id: "test".into(),
-            config: cfg,
-            start_time: Utc::now(),
-            kill,
-            events_tx: None,
-            events: None,
-            worker_task: std::sync::Mutex::new(None),
-            stderr_task: std::sync::Mutex::new(None),
-            process_group_owned: Arc::new(AtomicBool::new(false)),
-            result: Arc::new(RwLock::new(None)),
-            error: Arc::new(RwLock::new(Some(ClaudeError::ProcessFailed {
+        let session = test_session(
+            cfg,
+            &process,
+            Some(ClaudeError::ProcessFailed {
                 code: 1,
                 stderr: "stderr details".into(),
-            }))),
-            _mcp_temp_file: None,
-        };
+            }),
+        );
 
         let err = session.wait().await.unwrap_err();
         match err {
@@ -549,24 +894,15 @@ mod tests {
             .build()
             .unwrap();
 
-        let kill = test_kill_handle().await;
+        let process = test_process().await;
 
-        let session = Session {
-            id: "test".into(),
-            config: cfg,
-            start_time: Utc::now(),
-            kill,
-            events_tx: None,
-            events: None,
-            worker_task: std::sync::Mutex::new(None),
-            stderr_task: std::sync::Mutex::new(None),
-            process_group_owned: Arc::new(AtomicBool::new(false)),
-            result: Arc::new(RwLock::new(None)),
-            error: Arc::new(RwLock::new(Some(ClaudeError::SessionError {
+        let session = test_session(
+            cfg,
+            &process,
+            Some(ClaudeError::SessionError {
                 message: "custom session error".into(),
-            }))),
-            _mcp_temp_file: None,
-        };
+            }),
+        );
 
         let err = session.wait().await.unwrap_err();
         match err {
@@ -583,22 +919,9 @@ mod tests {
             .unwrap();
 
         let io = std::io::Error::other("disk full");
-        let kill = test_kill_handle().await;
+        let process = test_process().await;
 
-        let session = Session {
-            id: "test".into(),
-            config: cfg,
-            start_time: Utc::now(),
-            kill,
-            events_tx: None,
-            events: None,
-            worker_task: std::sync::Mutex::new(None),
-            stderr_task: std::sync::Mutex::new(None),
-            process_group_owned: Arc::new(AtomicBool::new(false)),
-            result: Arc::new(RwLock::new(None)),
-            error: Arc::new(RwLock::new(Some(io.into()))),
-            _mcp_temp_file: None,
-        };
+        let session = test_session(cfg, &process, Some(io.into()));
 
         let err = session.wait().await.unwrap_err();
         match err {
@@ -617,22 +940,9 @@ mod tests {
             .build()
             .unwrap();
 
-        let kill = test_kill_handle().await;
+        let process = test_process().await;
 
-        let session = Session {
-            id: "test".into(),
-            config: cfg,
-            start_time: Utc::now(),
-            kill,
-            events_tx: None,
-            events: None,
-            worker_task: std::sync::Mutex::new(None),
-            stderr_task: std::sync::Mutex::new(None),
-            process_group_owned: Arc::new(AtomicBool::new(false)),
-            result: Arc::new(RwLock::new(None)),
-            error: Arc::new(RwLock::new(None)),
-            _mcp_temp_file: None,
-        };
+        let session = test_session(cfg, &process, None);
 
         let err = session.wait().await.unwrap_err();
         match err {
@@ -653,25 +963,13 @@ mod tests {
             vec!["-c".to_string(), "sleep 5".to_string()],
             None,
             None,
+            &EnvPolicy::default(),
+            false,
         )
         .await
         .unwrap();
-        let kill = process.kill_handle().unwrap();
 
-        let session = Session {
-            id: "test".into(),
-            config: cfg,
-            start_time: Utc::now(),
-            kill,
-            events_tx: None,
-            events: None,
-            worker_task: std::sync::Mutex::new(None),
-            stderr_task: std::sync::Mutex::new(None),
-            process_group_owned: Arc::new(AtomicBool::new(false)),
-            result: Arc::new(RwLock::new(None)),
-            error: Arc::new(RwLock::new(None)),
-            _mcp_temp_file: None,
-        };
+        let session = test_session(cfg, &process, None);
 
         drop(session);
 
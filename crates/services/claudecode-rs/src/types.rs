@@ -1,3 +1,4 @@
+use crate::metrics::SessionMetrics;
 use serde::Deserialize;
 use serde::Serialize;
 use std::collections::HashMap;
@@ -118,6 +119,31 @@ pub struct Message {
     pub usage: Option<Usage>,
 }
 
+/// A user turn written to the CLI's stdin with `--input-format stream-json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserInput {
+    #[serde(rename = "type")]
+    pub input_type: String,
+    pub message: Message,
+}
+
+impl UserInput {
+    /// A user message holding `text`
+    pub fn text(text: impl Into<String>) -> Self {
+        Self {
+            input_type: "user".to_string(),
+            message: Message {
+                id: None,
+                message_type: None,
+                role: "user".to_string(),
+                model: None,
+                content: vec![Content::text(text)],
+                usage: None,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerToolUse {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -189,6 +215,10 @@ pub struct Result {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage: Option<Usage>,
+
+    /// Usage and spend accumulated by the session; filled in by `Session::wait`
+    #[serde(skip)]
+    pub metrics: Option<SessionMetrics>,
 }
 
 // Type-safe event system
@@ -203,6 +233,8 @@ pub enum Event {
     Result(ResultEvent),
     #[serde(rename = "error")]
     Error(ErrorEvent),
+    #[serde(rename = "control_request")]
+    ControlRequest(ControlRequestEvent),
     #[serde(other)]
     Unknown,
 }
@@ -271,6 +303,39 @@ pub struct ResultEvent {
     pub usage: Option<Usage>,
 }
 
+/// A request from the CLI that expects a `control_response` on stdin
+///
+/// Sessions answer these themselves; they are forwarded to the event stream for logging.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlRequestEvent {
+    pub request_id: String,
+    pub request: ControlRequest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "subtype")]
+pub enum ControlRequest {
+    /// Ask whether a tool may run (sent with `--permission-prompt-tool stdio`)
+    #[serde(rename = "can_use_tool")]
+    CanUseTool(PermissionRequest),
+    #[serde(other)]
+    Unknown,
+}
+
+/// A tool invocation awaiting approval from a [`PermissionHandler`](crate::permission::PermissionHandler)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionRequest {
+    pub tool_name: String,
+    pub input: serde_json::Value,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_use_id: Option<String>,
+
+    /// Path outside the allowed directories that triggered the prompt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_path: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorEvent {
     pub session_id: String,
@@ -397,6 +462,24 @@ mod tests {
             _ => panic!("Expected Error event"),
         }
 
+        // Test control request event
+        let json = r#"{"type":"control_request","request_id":"req-1","request":{"subtype":"can_use_tool","tool_name":"Bash","input":{"command":"ls"}}}"#;
+        let event: Event = serde_json::from_str(json).unwrap();
+        match event {
+            Event::ControlRequest(req) => {
+                assert_eq!(req.request_id, "req-1");
+                match req.request {
+                    ControlRequest::CanUseTool(permission) => {
+                        assert_eq!(permission.tool_name, "Bash");
+                        assert_eq!(permission.input["command"], "ls");
+                        assert_eq!(permission.tool_use_id, None);
+                    }
+                    ControlRequest::Unknown => panic!("Expected CanUseTool request"),
+                }
+            }
+            _ => panic!("Expected ControlRequest event"),
+        }
+
         // Test unknown event type
         let json = r#"{"type":"unknown_type","session_id":"123"}"#;
         let event: Event = serde_json::from_str(json).unwrap();
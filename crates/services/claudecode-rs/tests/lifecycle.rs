@@ -1,13 +1,23 @@
 #![expect(clippy::unwrap_used, reason = "Tests should panic on failure")]
 
+use claudecode::ClaudeError;
 use claudecode::Client;
+use claudecode::Event;
+use claudecode::InputFormat;
 use claudecode::OutputFormat;
+use claudecode::PermissionDecision;
+use claudecode::PermissionRequest;
 use claudecode::SessionConfig;
+use claudecode::SessionMetrics;
+use claudecode::TimeoutKind;
 use nix::unistd::Pid;
+use serde_json::json;
 use serial_test::serial;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 use std::time::Instant;
 use tempfile::TempDir;
@@ -218,3 +228,247 @@ async fn session_cancel_completes_within_bound_for_hung_process() {
     wait_for_process_exit(pids.parent_pid).await;
     wait_for_process_exit(pids.child_pid).await;
 }
+
+#[tokio::test]
+#[serial]
+async fn resume_continues_reported_session_id() {
+    let temp_dir = TempDir::new().unwrap();
+    let pid_path = pid_file(&temp_dir);
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+
+    let mut session = client
+        .launch(config(OutputFormat::StreamingJson, "first turn", &pid_path))
+        .await
+        .unwrap();
+    assert_eq!(session.claude_session_id().await, None);
+    let mut events = session.take_event_stream().unwrap();
+    while let Some(event) = events.recv().await {
+        if matches!(event, Event::Result(_)) {
+            break;
+        }
+    }
+    let session_id = session.claude_session_id().await.unwrap();
+    assert_eq!(session_id, "fake-session");
+    session.cancel().await.unwrap();
+
+    let mut session = client
+        .resume(
+            "earlier-session",
+            config(OutputFormat::StreamingJson, "second turn", &pid_path),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        session.claude_session_id().await.as_deref(),
+        Some("earlier-session")
+    );
+    let mut events = session.take_event_stream().unwrap();
+    match events.recv().await.unwrap() {
+        Event::System(system) => assert_eq!(system.session_id, "earlier-session"),
+        other => panic!("expected init event, got {other:?}"),
+    }
+    session.cancel().await.unwrap();
+}
+
+async fn next_result(events: &mut tokio::sync::mpsc::UnboundedReceiver<Event>) -> String {
+    while let Some(event) = events.recv().await {
+        if let Event::Result(result) = event {
+            return result.result.unwrap();
+        }
+    }
+    panic!("event stream ended before a result");
+}
+
+#[tokio::test]
+#[serial]
+async fn streaming_input_sends_user_messages_to_live_session() {
+    let temp_dir = TempDir::new().unwrap();
+    let pid_path = pid_file(&temp_dir);
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let cfg = SessionConfig::builder("first")
+        .input_format(InputFormat::StreamJson)
+        .env_var("FAKE_CLAUDE_PID_FILE", pid_path.display().to_string())
+        .build()
+        .unwrap();
+
+    let mut session = client.launch(cfg).await.unwrap();
+    let pids = wait_for_pid_info(&pid_path).await;
+    let mut events = session.take_event_stream().unwrap();
+
+    // The query is the first turn; later turns go to the same live process
+    assert_eq!(next_result(&mut events).await, "echo: first");
+    session.send_user_message("second").await.unwrap();
+    assert_eq!(next_result(&mut events).await, "echo: second");
+
+    // Waiting closes the input, so the CLI finishes after the last turn
+    let result = session.wait().await.unwrap();
+    assert_eq!(result.result.as_deref(), Some("echo: second"));
+    assert!(session.send_user_message("third").await.is_err());
+
+    wait_for_process_exit(pids.parent_pid).await;
+    wait_for_process_exit(pids.child_pid).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn permission_handler_answers_tool_prompts() {
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let cfg = SessionConfig::builder("[tool] ls")
+        .input_format(InputFormat::StreamJson)
+        .permission_handler(|request: PermissionRequest| async move {
+            assert_eq!(request.tool_name, "Bash");
+            match request.input["command"].as_str() {
+                Some(command) if command.starts_with("rm ") => {
+                    PermissionDecision::deny("no deleting")
+                }
+                Some("pwd") => PermissionDecision::allow_with_input(json!({ "command": "pwd -P" })),
+                _ => PermissionDecision::allow(),
+            }
+        })
+        .build()
+        .unwrap();
+
+    let mut session = client.launch(cfg).await.unwrap();
+    let mut events = session.take_event_stream().unwrap();
+
+    assert_eq!(next_result(&mut events).await, "ran: ls");
+    session
+        .send_user_message("[tool] rm -rf build")
+        .await
+        .unwrap();
+    assert_eq!(next_result(&mut events).await, "denied: no deleting");
+
+    // Input stays open until the pending turn's prompt is answered
+    session.send_user_message("[tool] pwd").await.unwrap();
+    let result = session.wait().await.unwrap();
+    assert_eq!(result.result.as_deref(), Some("ran: pwd -P"));
+}
+
+#[tokio::test]
+#[serial]
+async fn metrics_accumulate_across_turns() {
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let updates = Arc::new(Mutex::new(0));
+    let seen = Arc::clone(&updates);
+    let cfg = SessionConfig::builder("first")
+        .input_format(InputFormat::StreamJson)
+        .on_metrics(move |_: &SessionMetrics| *seen.lock().unwrap() += 1)
+        .build()
+        .unwrap();
+
+    let mut session = client.launch(cfg).await.unwrap();
+    let mut events = session.take_event_stream().unwrap();
+    assert_eq!(next_result(&mut events).await, "echo: first");
+    session.send_user_message("second").await.unwrap();
+    let result = session.wait().await.unwrap();
+
+    // Each message is repeated, but counted once; cost is the latest running total
+    let metrics = result.metrics.unwrap();
+    assert_eq!(metrics.usage.input_tokens, 20);
+    assert_eq!(metrics.usage.output_tokens, 10);
+    assert_eq!(metrics.usage.cache_read_input_tokens, 200);
+    assert_eq!(metrics.by_model["fake-model"].total(), 230);
+    assert_eq!(metrics.total_cost_usd, Some(0.02));
+    assert_eq!(session.metrics(), metrics);
+    assert_eq!(*updates.lock().unwrap(), 4);
+}
+
+#[tokio::test]
+#[serial]
+async fn wall_clock_timeout_kills_session() {
+    let temp_dir = TempDir::new().unwrap();
+    let pid_path = pid_file(&temp_dir);
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let cfg = SessionConfig::builder("[hang] timeout test")
+        .output_format(OutputFormat::Text)
+        .timeout(Duration::from_millis(300))
+        .env_var("FAKE_CLAUDE_PID_FILE", pid_path.display().to_string())
+        .build()
+        .unwrap();
+
+    let session = client.launch(cfg).await.unwrap();
+    let pids = wait_for_pid_info(&pid_path).await;
+
+    match session.wait().await.unwrap_err() {
+        ClaudeError::Timeout { kind, timeout } => {
+            assert_eq!(kind, TimeoutKind::WallClock);
+            assert_eq!(timeout, Duration::from_millis(300));
+        }
+        other => panic!("expected Timeout, got {other:?}"),
+    }
+
+    wait_for_process_exit(pids.parent_pid).await;
+    wait_for_process_exit(pids.child_pid).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn idle_timeout_kills_quiet_session() {
+    let temp_dir = TempDir::new().unwrap();
+    let pid_path = pid_file(&temp_dir);
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let cfg = SessionConfig::builder("first")
+        .input_format(InputFormat::StreamJson)
+        .timeout(Duration::from_secs(30))
+        .idle_timeout(Duration::from_millis(300))
+        .env_var("FAKE_CLAUDE_PID_FILE", pid_path.display().to_string())
+        .build()
+        .unwrap();
+
+    let mut session = client.launch(cfg).await.unwrap();
+    let pids = wait_for_pid_info(&pid_path).await;
+    let mut events = session.take_event_stream().unwrap();
+
+    // After answering, the CLI sits waiting for input until the idle timeout kills it
+    assert_eq!(next_result(&mut events).await, "echo: first");
+    while events.recv().await.is_some() {}
+
+    match session.wait().await.unwrap_err() {
+        ClaudeError::Timeout { kind, .. } => assert_eq!(kind, TimeoutKind::Idle),
+        other => panic!("expected Timeout, got {other:?}"),
+    }
+
+    wait_for_process_exit(pids.parent_pid).await;
+    wait_for_process_exit(pids.child_pid).await;
+}
+
+#[tokio::test]
+#[serial]
+async fn session_abort_kills_process_group_without_grace() {
+    let temp_dir = TempDir::new().unwrap();
+    let pid_path = pid_file(&temp_dir);
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let cfg = config(OutputFormat::Text, "[hang] abort test", &pid_path);
+
+    let session = client.launch(cfg).await.unwrap();
+    let pids = wait_for_pid_info(&pid_path).await;
+
+    session.abort().await.unwrap();
+    assert!(!session.is_running());
+
+    wait_for_process_exit(pids.parent_pid).await;
+    wait_for_process_exit(pids.child_pid).await;
+}
+
+#[tokio::test]
+async fn probed_capabilities_drop_unsupported_flags() {
+    let client = Client::with_path(fake_claude_path()).await.unwrap();
+    let caps = client.probe_cli().await.unwrap();
+    assert_eq!(
+        caps.version.map(|v| v.to_string()).as_deref(),
+        Some("1.0.80")
+    );
+    assert!(caps.supports("--disallowedTools"));
+    // Hidden from --help, but the version is new enough
+    assert!(caps.supports("--strict-mcp-config"));
+    assert!(!caps.supports("--output-style"));
+
+    let config = SessionConfig::builder("hello")
+        .strict_mcp_config(true)
+        .output_style("Explanatory")
+        .capabilities(&caps)
+        .build()
+        .unwrap();
+    assert!(config.strict_mcp_config);
+    assert!(config.output_style.is_none());
+}
@@ -0,0 +1,157 @@
+use claudecode::ClaudeError;
+use claudecode::Client;
+use claudecode::Event;
+use claudecode::InputFormat;
+use claudecode::OutputFormat;
+use claudecode::PermissionDecision;
+use claudecode::PermissionRequest;
+use claudecode::SessionConfig;
+use claudecode::TimeoutKind;
+use claudecode::mock::ReplayScript;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[tokio::test]
+async fn replays_streaming_fixture() {
+    let script = ReplayScript::load(fixture("replay_basic.jsonl"))
+        .await
+        .unwrap();
+    let client = Client::replay(script);
+
+    let config = SessionConfig::builder("Say hello").build().unwrap();
+    let mut session = client.launch(config).await.unwrap();
+
+    let mut events = session.take_event_stream().unwrap();
+    let mut kinds = Vec::new();
+    while let Some(event) = events.recv().await {
+        kinds.push(match event {
+            Event::System(_) => "system",
+            Event::Assistant(_) => "assistant",
+            Event::Result(_) => "result",
+            _ => "other",
+        });
+    }
+    assert_eq!(kinds, ["system", "assistant", "result"]);
+
+    let result = session.wait().await.unwrap();
+    assert_eq!(result.content.as_deref(), Some("Hello!"));
+    assert_eq!(result.session_id.as_deref(), Some("replay-session"));
+    let metrics = result.metrics.unwrap();
+    assert_eq!(metrics.usage.input_tokens, 12);
+    assert_eq!(metrics.total_cost_usd, Some(0.002));
+}
+
+#[tokio::test]
+async fn every_launch_replays_from_the_start() {
+    let script = ReplayScript::from_jsonl(
+        r#"{"type":"result","session_id":"s","result":"done","is_error":false}"#,
+    )
+    .unwrap();
+    let client = Client::replay(script);
+
+    for _ in 0..2 {
+        let config = SessionConfig::builder("go")
+            .output_format(OutputFormat::Json)
+            .build()
+            .unwrap();
+        let result = client.launch_and_wait(config).await.unwrap();
+        assert_eq!(result.result.as_deref(), Some("done"));
+    }
+}
+
+#[tokio::test]
+async fn streaming_input_replays_a_turn_per_message_and_answers_permissions() {
+    let script = ReplayScript::load(fixture("replay_permission.jsonl"))
+        .await
+        .unwrap();
+    let inputs = script.inputs();
+    let client = Client::replay(script);
+
+    let config = SessionConfig::builder("Clean the build directory")
+        .input_format(InputFormat::StreamJson)
+        .permission_handler(|request: PermissionRequest| async move {
+            if request.input["command"]
+                .as_str()
+                .unwrap_or("")
+                .starts_with("rm ")
+            {
+                PermissionDecision::deny("Deleting files is not allowed")
+            } else {
+                PermissionDecision::allow()
+            }
+        })
+        .build()
+        .unwrap();
+    let session = client.launch(config).await.unwrap();
+    session.send_user_message("List the files").await.unwrap();
+
+    let result = session.wait().await.unwrap();
+    assert_eq!(result.content.as_deref(), Some("Listed 3 files."));
+    assert_eq!(
+        inputs.user_messages(),
+        ["Clean the build directory", "List the files"]
+    );
+    let responses = inputs.control_responses();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0]["behavior"], "deny");
+    assert_eq!(responses[0]["message"], "Deleting files is not allowed");
+}
+
+#[tokio::test]
+async fn stderr_and_exit_code_surface_as_process_failure() {
+    let script = ReplayScript::text("")
+        .with_stderr("Error: not logged in\n")
+        .with_exit_code(1);
+    let client = Client::replay(script);
+
+    let config = SessionConfig::builder("hello").build().unwrap();
+    let err = client.launch_and_wait(config).await.unwrap_err();
+    match err {
+        ClaudeError::ProcessFailed { stderr, .. } => assert!(stderr.contains("not logged in")),
+        other => panic!("expected ProcessFailed, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn idle_timeout_stops_a_stalled_replay() {
+    let script = ReplayScript::load(fixture("replay_basic.jsonl"))
+        .await
+        .unwrap()
+        .with_line_delay(Duration::from_secs(30));
+    let client = Client::replay(script);
+
+    let config = SessionConfig::builder("hello")
+        .idle_timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let err = client.launch_and_wait(config).await.unwrap_err();
+    assert!(matches!(
+        err,
+        ClaudeError::Timeout {
+            kind: TimeoutKind::Idle,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn abort_ends_the_replay() {
+    let script = ReplayScript::load(fixture("replay_basic.jsonl"))
+        .await
+        .unwrap()
+        .with_line_delay(Duration::from_secs(30));
+    let client = Client::replay(script);
+
+    let config = SessionConfig::builder("hello").build().unwrap();
+    let session = client.launch(config).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(5), session.abort())
+        .await
+        .unwrap()
+        .unwrap();
+}
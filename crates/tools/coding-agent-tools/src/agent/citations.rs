@@ -0,0 +1,174 @@
+//! Citation extraction from subagent tool-use events.
+//!
+//! The subagent runs with streaming JSON output; every assistant `tool_use` block is
+//! inspected and mapped to a [`Citation`] describing what was actually looked at.
+
+use crate::types::Citation;
+use claudecode::types::Content;
+use claudecode::types::Event;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Accumulates citations from a stream of subagent events, preserving first-seen
+/// order and dropping exact duplicates.
+#[derive(Debug, Default)]
+pub struct CitationCollector {
+    citations: Vec<Citation>,
+}
+
+impl CitationCollector {
+    /// Inspect one event, recording any citations from its tool-use blocks.
+    pub fn observe(&mut self, event: &Event) {
+        let Event::Assistant(assistant) = event else {
+            return;
+        };
+        for content in &assistant.message.content {
+            if let Content::ToolUse { name, input, .. } = content
+                && let Some(citation) = citation_for(name, input)
+                && !self.citations.contains(&citation)
+            {
+                self.citations.push(citation);
+            }
+        }
+    }
+
+    pub fn finish(self) -> Vec<Citation> {
+        self.citations
+    }
+}
+
+/// Map a single tool invocation to a citation. MCP tool names
+/// (`mcp__<server>__<tool>`) are matched on their final segment.
+fn citation_for(name: &str, input: &HashMap<String, Value>) -> Option<Citation> {
+    let tool = name.rsplit("__").next().unwrap_or(name);
+    let str_field = |key: &str| input.get(key).and_then(Value::as_str).map(str::to_string);
+
+    match tool {
+        "Read" => {
+            let path = str_field("file_path")?;
+            let offset = input.get("offset").and_then(Value::as_u64);
+            let limit = input.get("limit").and_then(Value::as_u64);
+            let (start_line, end_line) = match (offset, limit) {
+                (None, None) => (None, None),
+                (offset, limit) => {
+                    let start = offset.unwrap_or(1).max(1);
+                    (Some(start), limit.map(|l| start + l.saturating_sub(1)))
+                }
+            };
+            Some(Citation::File {
+                path,
+                start_line,
+                end_line,
+            })
+        }
+        "cli_grep" | "cli_glob" | "cli_ls" | "Grep" | "Glob" | "LS" => Some(Citation::Search {
+            tool: tool.to_string(),
+            path: str_field("path"),
+            pattern: str_field("pattern"),
+        }),
+        "web_search" | "WebSearch" => Some(Citation::Search {
+            tool: tool.to_string(),
+            path: None,
+            pattern: str_field("query"),
+        }),
+        "web_fetch" | "WebFetch" => str_field("url").map(|url| Citation::Url { url }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn input(v: Value) -> HashMap<String, Value> {
+        serde_json::from_value(v).unwrap()
+    }
+
+    #[test]
+    fn read_with_offset_and_limit_yields_line_range() {
+        let c = citation_for(
+            "Read",
+            &input(json!({"file_path": "src/lib.rs", "offset": 10, "limit": 5})),
+        );
+        assert_eq!(
+            c,
+            Some(Citation::File {
+                path: "src/lib.rs".into(),
+                start_line: Some(10),
+                end_line: Some(14),
+            })
+        );
+
+        let whole = citation_for("Read", &input(json!({"file_path": "a.rs"})));
+        assert_eq!(
+            whole,
+            Some(Citation::File {
+                path: "a.rs".into(),
+                start_line: None,
+                end_line: None,
+            })
+        );
+    }
+
+    #[test]
+    fn mcp_tools_are_matched_by_suffix() {
+        let grep = citation_for(
+            "mcp__agentic-mcp__cli_grep",
+            &input(json!({"pattern": "fn main", "path": "src"})),
+        );
+        assert_eq!(
+            grep,
+            Some(Citation::Search {
+                tool: "cli_grep".into(),
+                path: Some("src".into()),
+                pattern: Some("fn main".into()),
+            })
+        );
+
+        let fetch = citation_for(
+            "mcp__agentic-mcp__web_fetch",
+            &input(json!({"url": "https://example.com"})),
+        );
+        assert_eq!(
+            fetch,
+            Some(Citation::Url {
+                url: "https://example.com".into()
+            })
+        );
+
+        assert_eq!(citation_for("TodoWrite", &input(json!({}))), None);
+    }
+
+    #[test]
+    fn collector_dedupes_and_ignores_non_assistant_events() {
+        let event: Event = serde_json::from_value(json!({
+            "type": "assistant",
+            "session_id": "s",
+            "message": {
+                "role": "assistant",
+                "content": [
+                    {"type": "text", "text": "looking"},
+                    {"type": "tool_use", "id": "1", "name": "Read", "input": {"file_path": "a.rs"}},
+                    {"type": "tool_use", "id": "2", "name": "Read", "input": {"file_path": "a.rs"}},
+                    {"type": "tool_use", "id": "3", "name": "mcp__x__web_fetch", "input": {"url": "https://u"}}
+                ]
+            }
+        }))
+        .unwrap();
+        let result: Event = serde_json::from_value(json!({
+            "type": "result",
+            "session_id": "s",
+            "result": "done"
+        }))
+        .unwrap();
+
+        let mut collector = CitationCollector::default();
+        collector.observe(&event);
+        collector.observe(&result);
+        let citations = collector.finish();
+        assert_eq!(citations.len(), 2);
+        assert_eq!(citations[1].to_string(), "https://u");
+    }
+}
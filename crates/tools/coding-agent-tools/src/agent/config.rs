@@ -1,8 +1,9 @@
 //! Mapping utilities for agent configuration.
 
-use std::collections::HashMap;
+use std::path::PathBuf;
 
 use agentic_config::types::SubagentsConfig;
+use agentic_logging::TraceContext;
 use claudecode::config::MCPConfig;
 use claudecode::config::MCPServer;
 use claudecode::types::Model;
@@ -43,6 +44,7 @@ pub fn model_for(agent_type: AgentType, cfg: &SubagentsConfig) -> Model {
 /// This list includes both built-in tools and MCP tools (prefixed with "mcp__").
 pub fn enabled_tools_for(agent_type: AgentType, location: AgentLocation) -> Vec<String> {
     use AgentLocation::Codebase;
+    use AgentLocation::Path;
     use AgentLocation::References;
     use AgentLocation::Thoughts;
     use AgentLocation::Web;
@@ -50,7 +52,7 @@ pub fn enabled_tools_for(agent_type: AgentType, location: AgentLocation) -> Vec<
     use AgentType::Locator;
 
     match (agent_type, location) {
-        (Locator, Codebase) => vec![
+        (Locator, Codebase | Path) => vec![
             "mcp__agentic-mcp__cli_ls".into(),
             "mcp__agentic-mcp__cli_grep".into(),
             "mcp__agentic-mcp__cli_glob".into(),
@@ -71,7 +73,7 @@ pub fn enabled_tools_for(agent_type: AgentType, location: AgentLocation) -> Vec<
             "mcp__agentic-mcp__web_search".into(),
             "mcp__agentic-mcp__web_fetch".into(),
         ],
-        (Analyzer, Codebase) => vec![
+        (Analyzer, Codebase | Path) => vec![
             "Read".into(),
             "mcp__agentic-mcp__cli_ls".into(),
             "mcp__agentic-mcp__cli_grep".into(),
@@ -105,6 +107,38 @@ pub fn enabled_tools_for(agent_type: AgentType, location: AgentLocation) -> Vec<
     }
 }
 
+/// Resolve the working directory for a location.
+///
+/// `location_path` is required for [`AgentLocation::Path`] and must be an absolute
+/// (or `~`-prefixed) path to an existing directory. It is rejected for all other
+/// locations, which use the caller's working directory.
+pub fn resolve_location_path(
+    location: AgentLocation,
+    location_path: Option<&str>,
+) -> Result<Option<PathBuf>, String> {
+    match (location, location_path) {
+        (AgentLocation::Path, None) => {
+            Err("location='path' requires location_path (an absolute directory)".into())
+        }
+        (AgentLocation::Path, Some(raw)) => {
+            let raw = raw.trim();
+            if !(raw.starts_with('/') || raw == "~" || raw.starts_with("~/")) {
+                return Err(format!("location_path must be absolute: {raw}"));
+            }
+            let abs = PathBuf::from(crate::paths::to_abs_string(raw)?);
+            if !abs.is_dir() {
+                return Err(format!(
+                    "location_path is not an existing directory: {}",
+                    abs.display()
+                ));
+            }
+            Ok(Some(abs))
+        }
+        (_, Some(_)) => Err("location_path is only valid with location='path'".into()),
+        (_, None) => Ok(None),
+    }
+}
+
 /// Compose the system prompt for a given type × location combination.
 pub fn compose_prompt(agent_type: AgentType, location: AgentLocation) -> String {
     compose_prompt_impl(agent_type, location)
@@ -137,36 +171,35 @@ fn agentic_mcp_allowlist_from(enabled: &[String]) -> Vec<String> {
 /// Uses single agentic-mcp server with --allow flag for all locations.
 /// Returns empty config if no MCP tools are enabled.
 pub fn build_mcp_config(_location: AgentLocation, enabled_tools: &[String]) -> MCPConfig {
-    let mut servers: HashMap<String, MCPServer> = HashMap::new();
-
     // Build allowlist from enabled MCP tools
     let allowlist = agentic_mcp_allowlist_from(enabled_tools);
 
     // If no MCP tools are enabled, do not expose the server at all
     if allowlist.is_empty() {
-        return MCPConfig {
-            mcp_servers: servers,
-        };
+        return MCPConfig::default();
     }
 
     // Use --allow "tool1,tool2" (no "mcp" subcommand, no individual flags)
-    let args = vec![
-        "--allow".to_string(),
-        allowlist.join(","),
-        "--suppress-search-reminder".to_string(),
-    ];
-
-    servers.insert(
-        "agentic-mcp".to_string(),
-        MCPServer::stdio("agentic-mcp", args),
-    );
+    let server = MCPServer::stdio_builder("agentic-mcp")
+        .arg("--allow")
+        .arg(allowlist.join(","))
+        .arg("--suppress-search-reminder");
+
+    let mut config = MCPConfig::default();
+    config
+        .mcp_servers
+        .insert("agentic-mcp".to_string(), server.build());
+    config
+}
 
-    MCPConfig {
-        mcp_servers: servers,
-    }
+/// Pass `trace` to the subagent's stdio MCP servers through the environment, so
+/// the tool calls they log are correlated with the spawning call.
+pub fn attach_trace_env(config: &mut MCPConfig, trace: &TraceContext) {
+    config.inject_env(trace.env_vars());
 }
 
 #[cfg(test)]
+#[expect(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
@@ -249,6 +282,37 @@ mod tests {
         assert!(tools.contains(&"mcp__agentic-mcp__cli_glob".to_string()));
     }
 
+    #[test]
+    fn test_enabled_tools_path_matches_codebase() {
+        for t in [AgentType::Locator, AgentType::Analyzer] {
+            assert_eq!(
+                enabled_tools_for(t, AgentLocation::Path),
+                enabled_tools_for(t, AgentLocation::Codebase)
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_location_path() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = tmp.path().to_str().unwrap();
+
+        let resolved = resolve_location_path(AgentLocation::Path, Some(dir))
+            .unwrap()
+            .unwrap();
+        assert!(resolved.is_dir());
+
+        assert!(resolve_location_path(AgentLocation::Path, None).is_err());
+        assert!(resolve_location_path(AgentLocation::Path, Some("relative/dir")).is_err());
+        let missing = tmp.path().join("missing");
+        assert!(resolve_location_path(AgentLocation::Path, missing.to_str()).is_err());
+        assert!(resolve_location_path(AgentLocation::Codebase, Some(dir)).is_err());
+        assert_eq!(
+            resolve_location_path(AgentLocation::Codebase, None).unwrap(),
+            None
+        );
+    }
+
     #[test]
     fn test_enabled_tools_locator_thoughts() {
         let tools = enabled_tools_for(AgentType::Locator, AgentLocation::Thoughts);
@@ -366,7 +430,7 @@ mod tests {
         assert_eq!(config.mcp_servers.len(), 1); // Single server for all locations
     }
 
-    // Test all 8 type×location combinations have valid tools
+    // Test all 10 type×location combinations have valid tools
     #[test]
     fn test_all_combinations_have_tools() {
         for agent_type in [AgentType::Locator, AgentType::Analyzer] {
@@ -375,6 +439,7 @@ mod tests {
                 AgentLocation::Thoughts,
                 AgentLocation::References,
                 AgentLocation::Web,
+                AgentLocation::Path,
             ] {
                 let tools = enabled_tools_for(agent_type, location);
                 assert!(
@@ -385,7 +450,7 @@ mod tests {
         }
     }
 
-    // Test all 8 type×location combinations have valid prompts
+    // Test all 10 type×location combinations have valid prompts
     #[test]
     fn test_all_combinations_have_prompts() {
         for agent_type in [AgentType::Locator, AgentType::Analyzer] {
@@ -394,6 +459,7 @@ mod tests {
                 AgentLocation::Thoughts,
                 AgentLocation::References,
                 AgentLocation::Web,
+                AgentLocation::Path,
             ] {
                 let prompt = compose_prompt(agent_type, location);
                 assert!(
@@ -483,6 +549,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_attach_trace_env_sets_stdio_env() {
+        let enabled = enabled_tools_for(AgentType::Locator, AgentLocation::Codebase);
+        let mut config = build_mcp_config(AgentLocation::Codebase, &enabled);
+        attach_trace_env(&mut config, &TraceContext::child_of("trace-1", "call-1"));
+
+        let Some(MCPServer::Stdio { env: Some(env), .. }) = config.mcp_servers.get("agentic-mcp")
+        else {
+            panic!("expected agentic-mcp stdio server with env");
+        };
+        assert_eq!(
+            env.get(agentic_logging::TRACE_ID_ENV).map(String::as_str),
+            Some("trace-1")
+        );
+        assert_eq!(
+            env.get(agentic_logging::PARENT_CALL_ID_ENV)
+                .map(String::as_str),
+            Some("call-1")
+        );
+    }
+
     #[test]
     fn test_build_mcp_config_includes_suppress_search_reminder_flag() {
         let enabled = enabled_tools_for(AgentType::Locator, AgentLocation::Codebase);
@@ -496,7 +583,7 @@ mod tests {
                 assert_eq!(command, "agentic-mcp");
                 assert!(args.contains(&"--suppress-search-reminder".to_string()));
             }
-            MCPServer::Http { .. } => panic!("expected stdio MCP server"),
+            _ => panic!("expected stdio MCP server"),
         }
     }
 }
@@ -3,12 +3,16 @@
 //! This module provides configuration and utilities for spawning opinionated
 //! Claude Code subagents with specific behaviors based on type and location.
 
+pub mod citations;
 pub mod config;
 pub mod prompts;
 
+pub use citations::CitationCollector;
+pub use config::attach_trace_env;
 pub use config::build_mcp_config;
 pub use config::compose_prompt;
 pub use config::enabled_tools_for;
 pub use config::model_for;
+pub use config::resolve_location_path;
 pub use prompts::ANALYZER_BASE_PROMPT;
 pub use prompts::LOCATOR_BASE_PROMPT;
@@ -84,6 +84,16 @@ Guidelines:
 - Be selective; go deep on 2-3 relevant references
 ";
 
+pub const PATH_OVERLAY: &str = r"
+Context: A local directory outside the current repository (e.g., a sibling repo).
+Working directory: the directory supplied by the caller.
+
+Guidelines:
+- Prefer paths relative to that directory
+- Do not assume the directory shares conventions with the caller's repository
+- For locator, organize results by purpose; for analyzer, include file:line citations
+";
+
 pub const WEB_OVERLAY: &str = r"
 Context: The web.
 
@@ -566,23 +576,33 @@ pub fn compose_prompt_impl(agent_type: AgentType, location: AgentLocation) -> St
         AgentLocation::Thoughts => THOUGHTS_OVERLAY,
         AgentLocation::References => REFERENCES_OVERLAY,
         AgentLocation::Web => WEB_OVERLAY,
+        AgentLocation::Path => PATH_OVERLAY,
     };
     let strategy = match (agent_type, location) {
-        (AgentType::Locator, AgentLocation::Codebase) => STRATEGY_LOCATOR_CODEBASE,
+        (AgentType::Locator, AgentLocation::Codebase | AgentLocation::Path) => {
+            STRATEGY_LOCATOR_CODEBASE
+        }
         (AgentType::Locator, AgentLocation::Thoughts) => STRATEGY_LOCATOR_THOUGHTS,
         (AgentType::Locator, AgentLocation::References) => STRATEGY_LOCATOR_REFERENCES,
         (AgentType::Locator, AgentLocation::Web) => STRATEGY_LOCATOR_WEB,
-        (AgentType::Analyzer, AgentLocation::Codebase) => STRATEGY_ANALYZER_CODEBASE,
+        (AgentType::Analyzer, AgentLocation::Codebase | AgentLocation::Path) => {
+            STRATEGY_ANALYZER_CODEBASE
+        }
         (AgentType::Analyzer, AgentLocation::Thoughts) => STRATEGY_ANALYZER_THOUGHTS,
         (AgentType::Analyzer, AgentLocation::References) => STRATEGY_ANALYZER_REFERENCES,
         (AgentType::Analyzer, AgentLocation::Web) => STRATEGY_ANALYZER_WEB,
+        // An arbitrary directory is explored the same way as the local codebase
     };
     let template = match (agent_type, location) {
-        (AgentType::Locator, AgentLocation::Codebase) => TEMPLATE_LOCATOR_CODEBASE,
+        (AgentType::Locator, AgentLocation::Codebase | AgentLocation::Path) => {
+            TEMPLATE_LOCATOR_CODEBASE
+        }
         (AgentType::Locator, AgentLocation::Thoughts) => TEMPLATE_LOCATOR_THOUGHTS,
         (AgentType::Locator, AgentLocation::References) => TEMPLATE_LOCATOR_REFERENCES,
         (AgentType::Locator, AgentLocation::Web) => TEMPLATE_LOCATOR_WEB,
-        (AgentType::Analyzer, AgentLocation::Codebase) => TEMPLATE_ANALYZER_CODEBASE,
+        (AgentType::Analyzer, AgentLocation::Codebase | AgentLocation::Path) => {
+            TEMPLATE_ANALYZER_CODEBASE
+        }
         (AgentType::Analyzer, AgentLocation::Thoughts) => TEMPLATE_ANALYZER_THOUGHTS,
         (AgentType::Analyzer, AgentLocation::References) => TEMPLATE_ANALYZER_REFERENCES,
         (AgentType::Analyzer, AgentLocation::Web) => TEMPLATE_ANALYZER_WEB,
@@ -672,6 +692,8 @@ mod tests {
             (Analyzer, Thoughts),
             (Analyzer, References),
             (Analyzer, Web),
+            (Locator, Path),
+            (Analyzer, Path),
         ];
         for (t, l) in cases {
             let prompt = compose_prompt_impl(t, l);
@@ -1,5 +1,7 @@
 //! Regex-based content search with multiple output modes.
 
+use crate::grep_index;
+use crate::grep_index::GrepIndexCache;
 use crate::types::GrepOutput;
 use crate::types::OutputMode;
 use crate::walker::{self};
@@ -14,6 +16,7 @@ use std::io::BufRead;
 use std::io::BufReader;
 use std::io::Read;
 use std::path::Path;
+use std::path::PathBuf;
 
 /// Configuration for grep search.
 #[derive(Debug)]
@@ -199,6 +202,18 @@ fn search_file_multiline(
 
 /// Run grep search with the given configuration.
 pub fn run(cfg: GrepConfig) -> Result<GrepOutput, ToolError> {
+    run_inner(cfg, None)
+}
+
+/// Run grep search, using the trigram index in `cache` to skip files that cannot match.
+///
+/// The index is built lazily on first use and refreshed per file by mtime, so results
+/// are identical to [`run`]. An `Index: ...` status line is appended to the warnings.
+pub fn run_indexed(cfg: GrepConfig, cache: &GrepIndexCache) -> Result<GrepOutput, ToolError> {
+    run_inner(cfg, Some(cache))
+}
+
+fn run_inner(cfg: GrepConfig, cache: Option<&GrepIndexCache>) -> Result<GrepOutput, ToolError> {
     // Validate root path
     let root_path = Path::new(&cfg.root);
     if !root_path.exists() {
@@ -275,6 +290,7 @@ pub fn run(cfg: GrepConfig) -> Result<GrepOutput, ToolError> {
             &cfg.ignore_globs,
         )?;
 
+        let mut files: Vec<(PathBuf, String)> = Vec::new();
         for result in builder.build() {
             match result {
                 Ok(entry) => {
@@ -297,38 +313,66 @@ pub fn run(cfg: GrepConfig) -> Result<GrepOutput, ToolError> {
                         continue;
                     }
 
-                    // Check binary
-                    if !cfg.include_binary {
-                        match is_binary_file(path) {
-                            Ok(true) => {
-                                binary_skipped += 1;
-                                continue;
-                            }
-                            Ok(false) => {}
-                            Err(_) => continue,
-                        }
-                    }
-
-                    // Search the file
-                    let search_result = if cfg.multiline {
-                        search_file_multiline(path, &rel_path, &regex)
-                    } else {
-                        search_file_lines(path, &rel_path, &regex, &cfg)
-                    };
+                    files.push((path.to_path_buf(), rel_path));
+                }
+                Err(e) => {
+                    warnings.push(format!("Walk error: {e}"));
+                }
+            }
+        }
 
-                    match search_result {
-                        Ok(Some(m)) => all_matches.push(m),
-                        Ok(None) => {}
-                        Err(e) => {
-                            warnings.push(format!("Could not read {rel_path}: {e}"));
-                        }
+        // Narrow to files whose trigrams cover the pattern's required literals
+        let index_status;
+        let candidates: Vec<&(PathBuf, String)> = if let Some(cache) = cache {
+            let key = grep_index::make_key(
+                &cfg.root,
+                cfg.include_hidden,
+                cfg.include_ignored,
+                &cfg.ignore_globs,
+            );
+            let index = cache.get_or_create(&key);
+            let mut index = index
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let required = grep_index::required_trigrams(&cfg.pattern, cfg.case_insensitive);
+            let (candidates, stats) = index.filter(&files, &required);
+            index_status = Some(stats.status());
+            candidates
+        } else {
+            index_status = None;
+            files.iter().collect()
+        };
+
+        for (path, rel_path) in candidates {
+            // Check binary
+            if !cfg.include_binary {
+                match is_binary_file(path) {
+                    Ok(true) => {
+                        binary_skipped += 1;
+                        continue;
                     }
+                    Ok(false) => {}
+                    Err(_) => continue,
                 }
+            }
+
+            // Search the file
+            let search_result = if cfg.multiline {
+                search_file_multiline(path, rel_path, &regex)
+            } else {
+                search_file_lines(path, rel_path, &regex, &cfg)
+            };
+
+            match search_result {
+                Ok(Some(m)) => all_matches.push(m),
+                Ok(None) => {}
                 Err(e) => {
-                    warnings.push(format!("Walk error: {e}"));
+                    warnings.push(format!("Could not read {rel_path}: {e}"));
                 }
             }
         }
+
+        warnings.extend(index_status);
     }
 
     // Add binary skip warning if applicable
@@ -389,5 +433,6 @@ pub fn run(cfg: GrepConfig) -> Result<GrepOutput, ToolError> {
         has_more,
         warnings,
         summary,
+        format: None,
     })
 }
@@ -0,0 +1,316 @@
+//! Lazily built trigram index for repeated `cli_grep` searches.
+//!
+//! Each indexed file stores the set of (ASCII-lowercased) byte trigrams it contains.
+//! A search extracts the literal runs every match must contain from the regex, and only
+//! files containing all of their trigrams are read and scanned. Entries are keyed by
+//! path and invalidated when a file's mtime or length changes, so the index stays
+//! correct without explicit rebuilds; the walk itself still runs to pick up changes.
+
+use regex_syntax::ParserBuilder;
+use regex_syntax::hir::Hir;
+use regex_syntax::hir::HirKind;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+/// Files larger than this are never indexed; they are always scanned.
+pub const MAX_INDEXED_FILE_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Index entry for a single file.
+#[derive(Debug)]
+struct IndexedFile {
+    mtime: Option<SystemTime>,
+    len: u64,
+    /// Sorted, deduplicated packed trigrams; `None` when the file was too large to index.
+    trigrams: Option<Vec<u32>>,
+}
+
+impl IndexedFile {
+    fn may_contain(&self, required: &[u32]) -> bool {
+        self.trigrams
+            .as_ref()
+            .is_none_or(|tris| required.iter().all(|t| tris.binary_search(t).is_ok()))
+    }
+}
+
+/// Trigram index over the files under one search root.
+#[derive(Debug, Default)]
+pub struct GrepIndex {
+    files: HashMap<PathBuf, IndexedFile>,
+}
+
+/// What a filtering pass did, reported to callers as `index_status`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IndexStats {
+    /// Files considered
+    pub total: usize,
+    /// Files (re)indexed during this pass because they were new or changed
+    pub refreshed: usize,
+    /// Files that may match and will be scanned
+    pub candidates: usize,
+    /// Whether the pattern yielded any trigrams to filter on
+    pub filtered: bool,
+}
+
+impl IndexStats {
+    /// Human-readable status line for `GrepOutput.warnings`.
+    pub fn status(&self) -> String {
+        let state = if self.refreshed == self.total {
+            "built".to_string()
+        } else if self.refreshed == 0 {
+            "reused".to_string()
+        } else {
+            format!("updated {} changed", self.refreshed)
+        };
+        if self.filtered {
+            format!(
+                "Index: {state} ({} files); scanned {} candidate file{}",
+                self.total,
+                self.candidates,
+                if self.candidates == 1 { "" } else { "s" }
+            )
+        } else {
+            format!(
+                "Index: {state} ({} files); pattern has no literal of 3+ chars, scanned all files",
+                self.total
+            )
+        }
+    }
+}
+
+impl GrepIndex {
+    /// Refresh entries for `files` and return those that may contain every `required`
+    /// trigram. Entries for files no longer present are dropped.
+    pub fn filter<'a>(
+        &mut self,
+        files: &'a [(PathBuf, String)],
+        required: &[u32],
+    ) -> (Vec<&'a (PathBuf, String)>, IndexStats) {
+        let mut stats = IndexStats {
+            total: files.len(),
+            filtered: !required.is_empty(),
+            ..IndexStats::default()
+        };
+        let mut seen: HashSet<&Path> = HashSet::with_capacity(files.len());
+        let mut candidates = Vec::new();
+
+        for file in files {
+            let path = file.0.as_path();
+            seen.insert(path);
+            // Unreadable metadata: let the scan surface the error
+            let Ok(meta) = std::fs::metadata(path) else {
+                candidates.push(file);
+                continue;
+            };
+            let (mtime, len) = (meta.modified().ok(), meta.len());
+
+            let fresh = self
+                .files
+                .get(path)
+                .is_some_and(|e| e.mtime.is_some() && e.mtime == mtime && e.len == len);
+            if !fresh {
+                stats.refreshed += 1;
+                let trigrams = if len > MAX_INDEXED_FILE_BYTES {
+                    None
+                } else {
+                    match std::fs::read(path) {
+                        Ok(bytes) => Some(trigrams_of(&bytes)),
+                        Err(_) => None,
+                    }
+                };
+                self.files.insert(
+                    path.to_path_buf(),
+                    IndexedFile {
+                        mtime,
+                        len,
+                        trigrams,
+                    },
+                );
+            }
+
+            if self.files.get(path).is_none_or(|e| e.may_contain(required)) {
+                candidates.push(file);
+            }
+        }
+
+        self.files.retain(|p, _| seen.contains(p.as_path()));
+        stats.candidates = candidates.len();
+        (candidates, stats)
+    }
+}
+
+/// Process-wide cache of trigram indexes keyed by search root and walk options.
+#[derive(Debug, Default)]
+pub struct GrepIndexCache {
+    map: Mutex<HashMap<String, Arc<Mutex<GrepIndex>>>>,
+}
+
+impl GrepIndexCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get or create the index for the given key.
+    pub fn get_or_create(&self, key: &str) -> Arc<Mutex<GrepIndex>> {
+        let mut map = self
+            .map
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        Arc::clone(map.entry(key.to_string()).or_default())
+    }
+}
+
+/// Build a cache key from the options that determine which files get walked.
+pub fn make_key(
+    root: &str,
+    include_hidden: bool,
+    include_ignored: bool,
+    ignore_globs: &[String],
+) -> String {
+    let mut ignores = ignore_globs.to_vec();
+    ignores.sort();
+    format!(
+        "{root}|h={include_hidden}|i={include_ignored}|x={}",
+        ignores.join(",")
+    )
+}
+
+fn pack(t: &[u8]) -> u32 {
+    (u32::from(t[0].to_ascii_lowercase()) << 16)
+        | (u32::from(t[1].to_ascii_lowercase()) << 8)
+        | u32::from(t[2].to_ascii_lowercase())
+}
+
+/// All distinct ASCII-lowercased trigrams of `bytes`, sorted.
+fn trigrams_of(bytes: &[u8]) -> Vec<u32> {
+    let mut tris: Vec<u32> = bytes.windows(3).map(pack).collect();
+    tris.sort_unstable();
+    tris.dedup();
+    tris
+}
+
+/// Trigrams that every match of `pattern` must contain.
+///
+/// Only literal runs that are required parts of a top-level concatenation are used,
+/// so the result is always safe to filter on. Returns an empty list (no filtering)
+/// when the pattern has no such literal of at least three bytes or fails to parse.
+pub fn required_trigrams(pattern: &str, case_insensitive: bool) -> Vec<u32> {
+    // Parse case-sensitively: literals survive as literals, and matching against the
+    // lowercased index is a superset of both case-sensitive and -insensitive matches.
+    let Ok(hir) = ParserBuilder::new().build().parse(pattern) else {
+        return vec![];
+    };
+
+    let mut runs: Vec<Vec<u8>> = Vec::new();
+    collect_required_literals(&hir, &mut runs);
+
+    let mut tris: Vec<u32> = runs
+        .iter()
+        .flat_map(|run| run.windows(3))
+        // Unicode case folding can map non-ASCII bytes to different byte sequences
+        .filter(|w| !case_insensitive || w.is_ascii())
+        .map(pack)
+        .collect();
+    tris.sort_unstable();
+    tris.dedup();
+    tris
+}
+
+fn collect_required_literals(hir: &Hir, runs: &mut Vec<Vec<u8>>) {
+    match hir.kind() {
+        HirKind::Literal(lit) => runs.push(lit.0.to_vec()),
+        HirKind::Capture(cap) => collect_required_literals(&cap.sub, runs),
+        HirKind::Concat(parts) => {
+            let mut current: Vec<u8> = Vec::new();
+            for part in parts {
+                if let HirKind::Literal(lit) = part.kind() {
+                    current.extend_from_slice(&lit.0);
+                } else {
+                    if !current.is_empty() {
+                        runs.push(std::mem::take(&mut current));
+                    }
+                    collect_required_literals(part, runs);
+                }
+            }
+            if !current.is_empty() {
+                runs.push(current);
+            }
+        }
+        // Alternations, repetitions, classes, and look-arounds guarantee no literal
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+#[expect(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn required_trigrams_from_literals() {
+        assert_eq!(required_trigrams("abc", false), vec![pack(b"abc")]);
+        let mut expected = vec![pack(b"mai"), pack(b"ain")];
+        expected.sort_unstable();
+        assert_eq!(required_trigrams("fn\\s+main", false), expected);
+        assert!(required_trigrams("ab", false).is_empty());
+        assert!(required_trigrams("foo|bar", false).is_empty());
+        assert!(required_trigrams("(foo)?", false).is_empty());
+        assert!(required_trigrams("[", false).is_empty());
+    }
+
+    #[test]
+    fn required_trigrams_are_case_folded() {
+        assert_eq!(
+            required_trigrams("ABC", false),
+            required_trigrams("abc", true)
+        );
+    }
+
+    #[test]
+    fn filter_skips_files_without_required_trigrams() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let a = dir.path().join("a.txt");
+        let b = dir.path().join("b.txt");
+        fs::write(&a, "needle here").unwrap();
+        fs::write(&b, "nothing").unwrap();
+        let files = vec![(a, "a.txt".to_string()), (b, "b.txt".to_string())];
+
+        let mut index = GrepIndex::default();
+        let (candidates, stats) = index.filter(&files, &required_trigrams("NEEDLE", true));
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].1, "a.txt");
+        assert_eq!(stats.refreshed, 2);
+        assert!(stats.status().starts_with("Index: built (2 files)"));
+
+        let (_, stats) = index.filter(&files, &required_trigrams("needle", false));
+        assert_eq!(stats.refreshed, 0);
+        assert!(stats.status().starts_with("Index: reused"));
+    }
+
+    #[test]
+    fn filter_reindexes_changed_files_and_drops_removed() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let a = dir.path().join("a.txt");
+        fs::write(&a, "old").unwrap();
+        let files = vec![(a.clone(), "a.txt".to_string())];
+
+        let mut index = GrepIndex::default();
+        let (candidates, _) = index.filter(&files, &required_trigrams("fresh", false));
+        assert!(candidates.is_empty());
+
+        fs::write(&a, "fresh content").unwrap();
+        filetime::set_file_mtime(&a, filetime::FileTime::from_unix_time(1, 0)).unwrap();
+        let (candidates, stats) = index.filter(&files, &required_trigrams("fresh", false));
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(stats.refreshed, 1);
+
+        index.filter(&[], &[]);
+        assert!(index.files.is_empty());
+    }
+}
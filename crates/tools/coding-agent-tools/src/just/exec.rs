@@ -7,11 +7,15 @@ use super::parser::ParamKind;
 use super::security::SecurityValidator;
 use super::types::ExecuteOutput;
 use crate::paths;
+use agentic_tools_core::OutputStream;
 use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolEvent;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::time::Duration;
-use tokio::io::AsyncReadExt;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncRead;
+use tokio::io::BufReader;
 use tokio::process::Command;
 
 /// Execute a recipe by name, with optional directory disambiguation and arguments.
@@ -131,6 +135,7 @@ pub async fn execute_recipe(
         }
     }
 
+    ctx.progress(format!("running just {recipe_name}"));
     let mut child = Command::new("just")
         .args(&argv)
         .current_dir(&chosen_dir)
@@ -149,16 +154,8 @@ pub async fn execute_recipe(
         .take()
         .ok_or_else(|| "Failed to capture just stderr".to_string())?;
 
-    let stdout_task = tokio::spawn(async move {
-        let mut stdout = stdout;
-        let mut bytes = Vec::new();
-        stdout.read_to_end(&mut bytes).await.map(|_| bytes)
-    });
-    let stderr_task = tokio::spawn(async move {
-        let mut stderr = stderr;
-        let mut bytes = Vec::new();
-        stderr.read_to_end(&mut bytes).await.map(|_| bytes)
-    });
+    let stdout_task = tokio::spawn(read_emitting(stdout, OutputStream::Stdout, ctx.clone()));
+    let stderr_task = tokio::spawn(read_emitting(stderr, OutputStream::Stderr, ctx.clone()));
 
     let status = if timeout_secs == 0 {
         tokio::select! {
@@ -213,6 +210,26 @@ pub async fn execute_recipe(
     })
 }
 
+/// Read `reader` to the end, emitting each line as a [`ToolEvent::Output`].
+async fn read_emitting(
+    reader: impl AsyncRead + Unpin,
+    stream: OutputStream,
+    ctx: ToolContext,
+) -> std::io::Result<Vec<u8>> {
+    let mut reader = BufReader::new(reader);
+    let mut bytes = Vec::new();
+    loop {
+        let start = bytes.len();
+        if reader.read_until(b'\n', &mut bytes).await? == 0 {
+            return Ok(bytes);
+        }
+        ctx.emit(ToolEvent::Output {
+            stream,
+            text: String::from_utf8_lossy(&bytes[start..]).into_owned(),
+        });
+    }
+}
+
 fn value_to_arg(v: &Value) -> Result<String, String> {
     match v {
         Value::String(s) => Ok(s.clone()),
@@ -426,6 +443,29 @@ mod tests {
         assert!(output.stdout.contains("hello world"));
     }
 
+    #[tokio::test]
+    async fn output_lines_are_emitted_as_events() {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let ctx = ToolContext::default().with_events(agentic_tools_core::EventSink::new({
+            let events = std::sync::Arc::clone(&events);
+            move |event| events.lock().unwrap().push(event)
+        }));
+
+        let bytes = read_emitting(&b"one\ntwo\npartial"[..], OutputStream::Stderr, ctx)
+            .await
+            .unwrap();
+
+        assert_eq!(bytes, b"one\ntwo\npartial");
+        let lines: Vec<ToolEvent> = ["one\n", "two\n", "partial"]
+            .into_iter()
+            .map(|text| ToolEvent::Output {
+                stream: OutputStream::Stderr,
+                text: text.into(),
+            })
+            .collect();
+        assert_eq!(*events.lock().unwrap(), lines);
+    }
+
     #[tokio::test]
     async fn non_zero_exit_code() {
         skip_if_just_unavailable!();
@@ -67,6 +67,7 @@ pub enum ParamKind {
 pub async fn parse_justfile(path: &str) -> Result<Vec<ParsedRecipe>, String> {
     let out = Command::new("just")
         .args(["--dump", "--dump-format", "json", "--justfile", path])
+        .kill_on_drop(true)
         .output()
         .await
         .map_err(|e| format!("Failed to run just dump for {path}: {e}"))?;
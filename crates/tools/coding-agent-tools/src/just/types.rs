@@ -1,5 +1,6 @@
 //! Types for just search and execute tools.
 
+use agentic_tools_core::fmt::Table;
 use agentic_tools_core::fmt::TextFormat;
 use agentic_tools_core::fmt::TextOptions;
 use schemars::JsonSchema;
@@ -49,24 +50,32 @@ pub struct SearchOutput {
     pub has_more: bool,
 }
 
+/// Longest doc comment shown per recipe in search output.
+const DOC_WIDTH: usize = 80;
+
 impl TextFormat for SearchOutput {
-    fn fmt_text(&self, _opts: &TextOptions) -> String {
+    fn fmt_text(&self, opts: &TextOptions) -> String {
         use std::fmt::Write;
         let mut out = String::new();
         let _ = writeln!(out, "just recipes:");
+        let mut table = Table::with_headers(["recipe", "dir", "doc"])
+            .indent(2)
+            .max_width(2, DOC_WIDTH);
         for it in &self.items {
-            let params = if it.params.is_empty() {
-                String::new()
+            let recipe = if it.params.is_empty() {
+                it.recipe.clone()
             } else {
-                format!("({})", it.params.join(", "))
+                format!("{} ({})", it.recipe, it.params.join(", "))
             };
-            let _ = writeln!(out, "  {} {}  [dir: {}]", it.recipe, params, it.dir);
-            if let Some(doc) = &it.doc
-                && let Some(line) = doc.lines().next()
-                && !line.trim().is_empty()
-            {
-                let _ = writeln!(out, "    {}", line.trim());
-            }
+            let doc = it
+                .doc
+                .as_deref()
+                .and_then(|doc| doc.lines().next())
+                .map_or("", str::trim);
+            table.push_row([recipe.as_str(), it.dir.as_str(), doc]);
+        }
+        if !table.is_empty() {
+            let _ = writeln!(out, "{}", table.render(opts));
         }
         if self.has_more {
             let _ = writeln!(
@@ -152,11 +161,17 @@ mod tests {
             has_more: true,
         };
         let text = output.fmt_text(&TextOptions::default());
-        assert!(text.contains("build (target)"));
-        assert!(text.contains("[dir: /repo/crate1]"));
-        assert!(text.contains("Build the project"));
-        assert!(text.contains("test   [dir: /repo/crate2]"));
-        assert!(text.contains("more results"));
+        assert_eq!(
+            text,
+            "just recipes:\n\
+             \x20 recipe          dir           doc\n\
+             \x20 build (target)  /repo/crate1  Build the project\n\
+             \x20 test            /repo/crate2\n\
+             (more results — call again with same params for next page)"
+        );
+
+        let md = output.fmt_text(&TextOptions::default().with_markdown(true));
+        assert!(md.contains("| build (target) | /repo/crate1 | Build the project |"));
     }
 
     #[test]
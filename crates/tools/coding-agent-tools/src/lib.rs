@@ -6,6 +6,7 @@ compile_error!(
 pub mod agent;
 pub mod glob;
 pub mod grep;
+pub mod grep_index;
 pub mod just;
 mod logging;
 pub mod pagination;
@@ -19,6 +20,7 @@ pub use tools::build_registry;
 use agentic_config::types::CliToolsConfig;
 use agentic_config::types::SubagentsConfig;
 use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolEvent;
 use claudecode::types::Result as ClaudeResult;
 use std::future::Future;
 use std::sync::Arc;
@@ -49,6 +51,18 @@ fn pick_non_empty_text(result: &claudecode::types::Result) -> Option<String> {
         })
 }
 
+/// Log summary for a finished agent session: its spend, plus any extra fields.
+fn spend_summary(
+    metrics: Option<&claudecode::SessionMetrics>,
+    mut summary: serde_json::Value,
+) -> Option<serde_json::Value> {
+    if let Some(metrics) = metrics {
+        summary["total_cost_usd"] = serde_json::json!(metrics.total_cost_usd);
+        summary["usage"] = serde_json::json!(metrics.usage);
+    }
+    (summary != serde_json::json!({})).then_some(summary)
+}
+
 const ASK_AGENT_TIMEOUT_CLEANUP_TIMEOUT_SECS: u64 = 5;
 
 async fn wait_for_claude_result<F, C, CFn>(
@@ -162,6 +176,8 @@ pub struct CodingAgentTools {
     just_registry: Arc<just::JustRegistry>,
     /// Pagination cache for just search results
     just_pager: Arc<just::pager::PaginationCache>,
+    /// Trigram indexes for `cli_grep` with `use_index`
+    grep_index: Arc<grep_index::GrepIndexCache>,
 }
 
 impl Default for CodingAgentTools {
@@ -182,6 +198,7 @@ impl CodingAgentTools {
             pager: Arc::new(pagination::PaginationCache::new()),
             just_registry: Arc::new(just::JustRegistry::new()),
             just_pager: Arc::new(just::pager::PaginationCache::new()),
+            grep_index: Arc::new(grep_index::GrepIndexCache::new()),
         }
     }
 }
@@ -201,6 +218,7 @@ impl CodingAgentTools {
         show: Option<Show>,
         ignore: Option<Vec<String>>,
         hidden: Option<bool>,
+        ctx: &agentic_tools_core::ToolContext,
     ) -> Result<LsOutput, ToolError> {
         use std::path::Path;
 
@@ -250,6 +268,7 @@ impl CodingAgentTools {
                     "Path is a file, not a directory. Use the 'read' tool to view file contents."
                         .into(),
                 ],
+                format: None,
             };
             let summary = serde_json::json!({
                 "entries": 0,
@@ -282,13 +301,13 @@ impl CodingAgentTools {
 
         // Determine pagination params
         let page_size = pagination::page_size_for(show_val, depth_val);
-        let query_key = pagination::make_key(
+        let query_key = ctx.session_key(&pagination::make_key(
             &abs_root,
             depth_val,
             show_val,
             include_hidden,
             &combined_ignores,
-        );
+        ));
 
         // Acquire per-query lock (level 2), serialize same-param calls
         let qlock = self.pager.get_or_create(&query_key);
@@ -346,6 +365,7 @@ impl CodingAgentTools {
             entries,
             has_more,
             warnings: all_warnings,
+            format: None,
         };
 
         // Log success with summary
@@ -365,6 +385,7 @@ impl CodingAgentTools {
         &self,
         agent_type: Option<types::AgentType>,
         location: Option<types::AgentLocation>,
+        location_path: Option<String>,
         query: String,
         ctx: &agentic_tools_core::ToolContext,
     ) -> Result<AgentOutput, ToolError> {
@@ -383,6 +404,7 @@ impl CodingAgentTools {
         let req_json = serde_json::json!({
             "agent_type": format!("{agent_type:?}").to_lowercase(),
             "location": format!("{location:?}").to_lowercase(),
+            "location_path": &location_path,
             "query": &query,
             "runtime_timeout_secs": self.subagents.runtime_timeout_secs,
         });
@@ -400,6 +422,14 @@ impl CodingAgentTools {
             return Err(ToolError::InvalidInput("Query cannot be empty".into()));
         }
 
+        let working_dir = match agent::resolve_location_path(location, location_path.as_deref()) {
+            Ok(dir) => dir.or_else(|| ctx.cwd().map(std::path::Path::to_path_buf)),
+            Err(e) => {
+                log_ctx.finish(req_json, None, false, Some(e.clone()), None, None, None);
+                return Err(ToolError::InvalidInput(e));
+            }
+        };
+
         // Compose configuration
         let model = agent::model_for(agent_type, &self.subagents);
         let system_prompt = agent::compose_prompt(agent_type, location);
@@ -412,10 +442,12 @@ impl CodingAgentTools {
             .partition(|t| !t.starts_with("mcp__"));
 
         // Build MCP config with --allow flag for tool filtering
-        let mcp_config = agent::build_mcp_config(location, &enabled_tools);
+        let mut mcp_config = agent::build_mcp_config(location, &enabled_tools);
+        // The subagent's tool calls log as children of this ask_agent call
+        agent::attach_trace_env(&mut mcp_config, &log_ctx.timer.child_context());
 
         // Validate MCP servers before launching (spawn, handshake, tools/list)
-        let opts = ValidateOptions::default();
+        let opts = ValidateOptions::full();
         if let Err(e) = ensure_valid_mcp_config(&mcp_config, &opts).await {
             use std::fmt::Write;
             let mut details = String::new();
@@ -439,13 +471,17 @@ impl CodingAgentTools {
         // Build session config
         let builder = SessionConfig::builder(query)
             .model(model)
-            .output_format(OutputFormat::Text)
+            .output_format(OutputFormat::StreamingJson)
             .permission_mode(PermissionMode::DontAsk)
             .system_prompt(system_prompt)
             .tools(builtin_tools) // controls built-in tools in schema
             .allowed_tools(enabled_tools.clone()) // auto-approve enabled tools (built-in + MCP)
             .mcp_config(mcp_config)
             .strict_mcp_config(true); // prevent inheritance of global MCP tools
+        let builder = match working_dir {
+            Some(dir) => builder.working_dir(dir),
+            None => builder,
+        };
 
         let config = match builder.build() {
             Ok(c) => c,
@@ -484,7 +520,7 @@ impl CodingAgentTools {
             }
         };
 
-        let session = match client.launch(config).await {
+        let mut session = match client.launch(config).await {
             Ok(session) => session,
             Err(e) => {
                 let error_msg = format!("Failed to start Claude session: {e}");
@@ -501,6 +537,24 @@ impl CodingAgentTools {
             }
         };
 
+        ctx.progress(format!("{model} agent started"));
+
+        // Drain transcript events in the background so citations can be attached to the
+        // output; each event is also streamed to the host as it arrives.
+        let citations_task = session.take_event_stream().map(|mut events| {
+            let ctx = ctx.clone();
+            tokio::spawn(async move {
+                let mut collector = agent::CitationCollector::default();
+                while let Some(event) = events.recv().await {
+                    collector.observe(&event);
+                    if let Ok(entry) = serde_json::to_value(&event) {
+                        ctx.emit(ToolEvent::Transcript { entry });
+                    }
+                }
+                collector.finish()
+            })
+        });
+
         let result = match wait_for_claude_result_with_timeout(
             ctx,
             session.wait(),
@@ -545,7 +599,7 @@ impl CodingAgentTools {
                 None,
                 false,
                 Some(error_msg.clone()),
-                None,
+                spend_summary(result.metrics.as_ref(), serde_json::json!({})),
                 Some(model.to_string()),
                 None,
             );
@@ -554,6 +608,10 @@ impl CodingAgentTools {
 
         // Return plain text output (reject empty/whitespace-only strings)
         if let Some(text) = pick_non_empty_text(&result) {
+            let citations = match citations_task {
+                Some(task) => task.await.unwrap_or_default(),
+                None => Vec::new(),
+            };
             // Write markdown response file and capture timestamp for consistent logging
             let (response_file, completed_at) = log_ctx
                 .write_markdown_response(&text)
@@ -563,11 +621,14 @@ impl CodingAgentTools {
                 response_file,
                 true,
                 None,
-                None,
+                spend_summary(
+                    result.metrics.as_ref(),
+                    serde_json::json!({ "citations": citations.len() }),
+                ),
                 Some(model.to_string()),
                 completed_at,
             );
-            return Ok(AgentOutput::new(text));
+            return Ok(AgentOutput::new(text).with_citations(citations));
         }
 
         let error_msg = "Claude session produced no text output (empty or whitespace-only)";
@@ -576,7 +637,7 @@ impl CodingAgentTools {
             None,
             false,
             Some(error_msg.into()),
-            None,
+            spend_summary(result.metrics.as_ref(), serde_json::json!({})),
             Some(model.to_string()),
             None,
         );
@@ -610,6 +671,7 @@ impl CodingAgentTools {
         include_binary: Option<bool>,
         head_limit: Option<usize>,
         offset: Option<usize>,
+        use_index: Option<bool>,
     ) -> Result<GrepOutput, ToolError> {
         // Start logging context
         let log_ctx = logging::ToolLogCtx::start("cli_grep");
@@ -631,6 +693,7 @@ impl CodingAgentTools {
             "include_binary": include_binary,
             "head_limit": head_limit,
             "offset": offset,
+            "use_index": use_index,
         });
 
         let path_str = path.unwrap_or_else(|| ".".into());
@@ -666,7 +729,13 @@ impl CodingAgentTools {
             offset: offset.unwrap_or(0),
         };
 
-        match grep::run(cfg) {
+        let result = if use_index.unwrap_or(false) {
+            grep::run_indexed(cfg, &self.grep_index)
+        } else {
+            grep::run(cfg)
+        };
+
+        match result {
             Ok(output) => {
                 let summary = serde_json::json!({
                     "lines": output.lines.len(),
@@ -780,7 +849,8 @@ impl CodingAgentTools {
             return Err(e);
         }
 
-        let repo_root = match paths::to_abs_string(".") {
+        let root = paths::in_cwd(None, ctx.cwd()).unwrap_or_else(|| ".".into());
+        let repo_root = match paths::to_abs_string(&root) {
             Ok(r) => r,
             Err(e) => {
                 log_ctx.finish(req_json, None, false, Some(e.clone()), None, None, None);
@@ -788,7 +858,11 @@ impl CodingAgentTools {
             }
         };
         let q = query.unwrap_or_default();
-        let dir_filter = match dir.as_ref().map(|d| paths::to_abs_string(d)).transpose() {
+        let dir_filter = match dir
+            .and_then(|d| paths::in_cwd(Some(d), ctx.cwd()))
+            .map(|d| paths::to_abs_string(&d))
+            .transpose()
+        {
             Ok(f) => f,
             Err(e) => {
                 log_ctx.finish(req_json, None, false, Some(e.clone()), None, None, None);
@@ -797,7 +871,10 @@ impl CodingAgentTools {
         };
 
         self.just_pager.sweep_expired();
-        let key = just::pager::make_key(dir_filter.as_deref().unwrap_or(&repo_root), &q);
+        let key = ctx.session_key(&just::pager::make_key(
+            dir_filter.as_deref().unwrap_or(&repo_root),
+            &q,
+        ));
         let qlock = self.just_pager.get_or_create(&key);
 
         // Check if we need to refresh - do this without holding lock across await
@@ -924,7 +1001,8 @@ impl CodingAgentTools {
             return Err(ToolError::Internal(error_msg));
         }
 
-        let repo_root = match paths::to_abs_string(".") {
+        let root = paths::in_cwd(None, ctx.cwd()).unwrap_or_else(|| ".".into());
+        let repo_root = match paths::to_abs_string(&root) {
             Ok(r) => r,
             Err(e) => {
                 log_ctx.finish(req_json, None, false, Some(e.clone()), None, None, None);
@@ -935,7 +1013,7 @@ impl CodingAgentTools {
         match just::exec::execute_recipe(
             &self.just_registry,
             &recipe,
-            dir,
+            dir.and_then(|d| paths::in_cwd(Some(d), ctx.cwd())),
             args,
             &repo_root,
             self.cli_tools.just_execute_timeout_secs,
@@ -1049,6 +1127,21 @@ mod ask_agent_filter_tests {
         assert_eq!(pick_non_empty_text(&r).as_deref(), Some("text"));
     }
 
+    #[test]
+    fn spend_summary_merges_metrics_into_extra_fields() {
+        assert_eq!(spend_summary(None, serde_json::json!({})), None);
+
+        let metrics = claudecode::SessionMetrics {
+            total_cost_usd: Some(0.5),
+            ..Default::default()
+        };
+        let summary = spend_summary(Some(&metrics), serde_json::json!({ "citations": 2 }))
+            .unwrap_or_else(|| panic!("metrics should produce a summary"));
+        assert_eq!(summary["citations"], 2);
+        assert_eq!(summary["total_cost_usd"], 0.5);
+        assert_eq!(summary["usage"]["input_tokens"], 0);
+    }
+
     #[test]
     fn respects_precedence_of_result_over_content() {
         let r = ClaudeResult {
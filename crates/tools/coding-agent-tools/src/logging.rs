@@ -3,6 +3,7 @@
 //! Provides a helper context that reduces duplication when logging tool calls
 //! to the thoughts logs directory using `agentic_logging`.
 
+use agentic_logging::AsyncLogWriter;
 use agentic_logging::CallTimer;
 use agentic_logging::LogWriter;
 use agentic_logging::ToolCallRecord;
@@ -34,12 +35,15 @@ impl ToolLogCtx {
     /// is still created but logging will be a no-op.
     pub fn start(tool: &str) -> Self {
         let timer = CallTimer::start();
-        let writer = active_logs_dir().ok().map(LogWriter::new);
+        let server = "coding_agent_tools";
+        let writer = active_logs_dir()
+            .ok()
+            .map(|dir| LogWriter::new(dir).with_server(server));
 
         Self {
             timer,
             writer,
-            server: "coding_agent_tools".to_string(),
+            server: server.to_string(),
             tool: tool.to_string(),
         }
     }
@@ -60,13 +64,15 @@ impl ToolLogCtx {
             .map(|filename| (filename, completed_at))
     }
 
-    /// Finish the logging context and append the JSONL record.
+    /// Finish the logging context and queue the JSONL record on the shared
+    /// [`AsyncLogWriter`] for the logs directory, so disk IO stays off the tool's path.
     ///
     /// If `completed_at` is provided (e.g., from `write_markdown_response`), it will be
     /// used for the JSONL record to ensure consistent day-bucket placement. Otherwise,
     /// a fresh timestamp is captured.
     ///
-    /// This is best-effort: errors are logged via tracing but do not fail the call.
+    /// This is best-effort: write failures and drops are counted in the writer's
+    /// metrics but do not fail the call.
     pub fn finish(
         self,
         request: serde_json::Value,
@@ -101,11 +107,11 @@ impl ToolLogCtx {
             model,
             token_usage: None,
             summary,
+            trace_id: Some(self.timer.trace_id),
+            parent_call_id: self.timer.parent_call_id,
         };
 
-        if let Err(e) = writer.append_jsonl(&record) {
-            tracing::warn!("Failed to append JSONL log: {}", e);
-        }
+        AsyncLogWriter::shared(writer.logs_dir()).append(record);
     }
 }
 
@@ -360,6 +366,8 @@ mod tests {
             model: None, // coding_agent_tools doesn't use models (except ask_agent)
             token_usage: None,
             summary: Some(serde_json::json!({"entries": 10})),
+            trace_id: None,
+            parent_call_id: None,
         };
 
         let json = serde_json::to_string(&record).unwrap();
@@ -1,5 +1,6 @@
 //! Path normalization utilities for the ls tool.
 
+use std::path::Path;
 use std::path::PathBuf;
 
 const HOME_ERR: &str = "Could not determine home directory. Ensure the HOME environment variable is set or the system can resolve the user's home directory.";
@@ -63,12 +64,47 @@ pub fn to_abs_string(p: &str) -> Result<String, String> {
     Ok(abs.to_string_lossy().to_string())
 }
 
+/// Resolve an optional tool path against the context's working directory override.
+///
+/// `None` stands for the working directory itself. Without an override the path is
+/// returned unchanged, so [`to_abs_string`] later resolves it against the process
+/// working directory; absolute and `~` paths never depend on the override.
+pub fn in_cwd(path: Option<String>, cwd: Option<&Path>) -> Option<String> {
+    let Some(cwd) = cwd else {
+        return path;
+    };
+    Some(match path {
+        None => cwd.to_string_lossy().into_owned(),
+        Some(p) if p == "~" || p.starts_with("~/") => p,
+        Some(p) => cwd.join(p).to_string_lossy().into_owned(),
+    })
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used)]
 mod tests {
     use super::*;
     use serial_test::serial;
-    use std::path::Path;
+
+    #[test]
+    fn in_cwd_rebases_relative_paths_only() {
+        let cwd = Path::new("/work/repo");
+        assert_eq!(in_cwd(Some("src".into()), None).as_deref(), Some("src"));
+        assert_eq!(in_cwd(None, None), None);
+        assert_eq!(in_cwd(None, Some(cwd)).as_deref(), Some("/work/repo"));
+        assert_eq!(
+            in_cwd(Some("src".into()), Some(cwd)).as_deref(),
+            Some("/work/repo/src")
+        );
+        assert_eq!(
+            in_cwd(Some("/etc".into()), Some(cwd)).as_deref(),
+            Some("/etc")
+        );
+        assert_eq!(
+            in_cwd(Some("~/notes".into()), Some(cwd)).as_deref(),
+            Some("~/notes")
+        );
+    }
 
     #[test]
     fn relative_path_becomes_absolute() {
@@ -4,18 +4,25 @@
 
 use crate::CodingAgentTools;
 use crate::just;
+use crate::paths;
 use crate::types::AgentLocation;
 use crate::types::AgentOutput;
 use crate::types::AgentType;
+use crate::types::BatchItem;
+use crate::types::BatchOutput;
+use crate::types::BatchResult;
 use crate::types::Depth;
 use crate::types::GlobOutput;
 use crate::types::GrepOutput;
 use crate::types::LsOutput;
 use crate::types::OutputMode;
+use crate::types::RenderFormat;
 use crate::types::Show;
 use crate::types::SortOrder;
 use agentic_config::types::CliToolsConfig;
 use agentic_config::types::SubagentsConfig;
+use agentic_tools_core::CachePolicy;
+use agentic_tools_core::Permissions;
 use agentic_tools_core::Tool;
 use agentic_tools_core::ToolContext;
 use agentic_tools_core::ToolError;
@@ -48,6 +55,9 @@ pub struct LsInput {
     /// Include hidden files (default: false)
     #[serde(default)]
     pub hidden: Option<bool>,
+    /// Text rendering for this call: 'text' (compact), 'markdown', or 'json'. Default: transport setting
+    #[serde(default)]
+    pub format: Option<RenderFormat>,
 }
 
 /// Tool for listing files and directories.
@@ -67,23 +77,30 @@ impl Tool for LsTool {
     type Output = LsOutput;
     const NAME: &'static str = "cli_ls";
     const DESCRIPTION: &'static str = "List files and directories. Depth: 0=header only, 1=children (default), 2-10=tree. Filter with show='files'|'dirs'|'all'. Gitignore-aware. For shallow queries, call with same params again for next page.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
 
     fn call(
         &self,
         input: Self::Input,
-        _ctx: &ToolContext,
+        ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
         let tools = Arc::clone(&self.tools);
+        let ctx = ctx.clone();
         Box::pin(async move {
             tools
                 .ls(
-                    input.path,
+                    paths::in_cwd(input.path, ctx.cwd()),
                     input.depth,
                     input.show,
                     input.ignore,
                     input.hidden,
+                    &ctx,
                 )
                 .await
+                .map(|mut out| {
+                    out.format = input.format;
+                    out
+                })
         })
     }
 }
@@ -98,9 +115,12 @@ pub struct AskAgentInput {
     /// Agent type: 'locator' (fast discovery, haiku) or 'analyzer' (deep analysis, sonnet). Default: locator
     #[serde(default)]
     pub agent_type: Option<AgentType>,
-    /// Location: 'codebase'|'thoughts'|'references'|'web'. Default: codebase
+    /// Location: 'codebase'|'thoughts'|'references'|'web'|'path'. Default: codebase
     #[serde(default)]
     pub location: Option<AgentLocation>,
+    /// Absolute directory for location='path' (e.g., a sibling repo); required for 'path', rejected otherwise
+    #[serde(default)]
+    pub location_path: Option<String>,
     /// Task to perform; plain language question/instructions for the subagent
     pub query: String,
 }
@@ -121,7 +141,7 @@ impl Tool for AskAgentTool {
     type Input = AskAgentInput;
     type Output = AgentOutput;
     const NAME: &'static str = "ask_agent";
-    const DESCRIPTION: &'static str = "Spawn a Claude subagent for discovery or deep analysis. Returns a single text response; no side effects.
+    const DESCRIPTION: &'static str = "Spawn a Claude subagent for discovery or deep analysis. Returns a text response plus structured citations (files read with line ranges, searches run, URLs fetched); no side effects.
 
 Agent types:
 - locator (haiku): Finds WHERE things are. Fast, shallow discovery via cli_grep/cli_glob/cli_ls. Returns file paths grouped by purpose. Cannot read file contents deeply.
@@ -132,6 +152,7 @@ Locations:
 - thoughts: Active branch documents (research/plans/artifacts). Uses thoughts_list_documents for discovery.
 - references: Cloned reference repos. Paths start with references/{org}/{repo}/.
 - web: Internet search. Returns URLs with quotes and source attribution.
+- path: Arbitrary absolute directory given by location_path (e.g., a sibling repo). Same tools as codebase; paths are relative to that directory.
 
 When to use:
 - Use locator when you need to find files/resources but don't yet know where they are.
@@ -149,6 +170,7 @@ Usage notes:
 - Provide clear, specific queries. The subagent is stateless and receives no prior context.
 - Locator returns locations only; use analyzer or Read for content.
 - Multiple ask_agent calls can run in parallel for independent queries.";
+    const PERMISSIONS: Permissions = Permissions::EXEC.union(Permissions::NETWORK);
 
     fn call(
         &self,
@@ -159,7 +181,13 @@ Usage notes:
         let ctx = ctx.clone();
         Box::pin(async move {
             tools
-                .ask_agent(input.agent_type, input.location, input.query, &ctx)
+                .ask_agent(
+                    input.agent_type,
+                    input.location,
+                    input.location_path,
+                    input.query,
+                    &ctx,
+                )
                 .await
         })
     }
@@ -219,6 +247,12 @@ pub struct SearchGrepInput {
     /// Skip the first N results (default: 0)
     #[serde(default)]
     pub offset: Option<usize>,
+    /// Reuse a persistent trigram index to skip files that cannot match; useful for repeated searches in large repos (default: false)
+    #[serde(default)]
+    pub use_index: Option<bool>,
+    /// Text rendering for this call: 'text' (compact), 'markdown', or 'json'. Default: transport setting
+    #[serde(default)]
+    pub format: Option<RenderFormat>,
 }
 
 /// Tool for regex-based code search.
@@ -237,19 +271,21 @@ impl Tool for SearchGrepTool {
     type Input = SearchGrepInput;
     type Output = GrepOutput;
     const NAME: &'static str = "cli_grep";
-    const DESCRIPTION: &'static str = "Regex-based search. Applies default ignores (gitignore + common dirs like node_modules/, target/, logs/). If expected matches are missing, retry with include_ignored=true. Modes: files (default), content, count. Stateless pagination via head_limit+offset.";
+    const DESCRIPTION: &'static str = "Regex-based search. Applies default ignores (gitignore + common dirs like node_modules/, target/, logs/). If expected matches are missing, retry with include_ignored=true. Modes: files (default), content, count. Stateless pagination via head_limit+offset. For repeated searches in large repos, set use_index=true to skip files via a cached trigram index (status reported in warnings).";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
 
     fn call(
         &self,
         input: Self::Input,
-        _ctx: &ToolContext,
+        ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
         let tools = Arc::clone(&self.tools);
+        let path = paths::in_cwd(input.path, ctx.cwd());
         Box::pin(async move {
             tools
                 .search_grep(
                     input.pattern,
-                    input.path,
+                    path,
                     input.mode,
                     input.globs,
                     input.ignore,
@@ -264,8 +300,13 @@ impl Tool for SearchGrepTool {
                     input.include_binary,
                     input.head_limit,
                     input.offset,
+                    input.use_index,
                 )
                 .await
+                .map(|mut out| {
+                    out.format = input.format;
+                    out
+                })
         })
     }
 }
@@ -302,6 +343,10 @@ pub struct SearchGlobInput {
     pub offset: Option<usize>,
 }
 
+/// How long a session reuses a glob result for an identical request. Kept short
+/// so files the agent creates show up on the next search soon after.
+const GLOB_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(5);
+
 /// Tool for glob-based file matching.
 #[derive(Clone)]
 pub struct SearchGlobTool {
@@ -319,18 +364,20 @@ impl Tool for SearchGlobTool {
     type Output = GlobOutput;
     const NAME: &'static str = "cli_glob";
     const DESCRIPTION: &'static str = "Glob-based path match. Applies default ignores (gitignore + common dirs like node_modules/, target/, logs/). If expected matches are missing, retry with include_ignored=true. Sorting by name (default) or mtime (newest first). Stateless pagination via head_limit+offset.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
 
     fn call(
         &self,
         input: Self::Input,
-        _ctx: &ToolContext,
+        ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
         let tools = Arc::clone(&self.tools);
+        let path = paths::in_cwd(input.path, ctx.cwd());
         Box::pin(async move {
             tools
                 .search_glob(
                     input.pattern,
-                    input.path,
+                    path,
                     input.ignore,
                     input.include_hidden,
                     input.include_ignored,
@@ -341,6 +388,12 @@ impl Tool for SearchGlobTool {
                 .await
         })
     }
+
+    fn cache_policy(&self) -> Option<CachePolicy<Self::Input>> {
+        Some(CachePolicy::new(GLOB_CACHE_TTL, |input| {
+            Some(format!("{input:?}"))
+        }))
+    }
 }
 
 // ============================================================================
@@ -375,6 +428,7 @@ impl Tool for JustSearchTool {
     type Output = just::SearchOutput;
     const NAME: &'static str = "cli_just_search";
     const DESCRIPTION: &'static str = "Search justfile recipes by name or docs. Optional dir filter. Same params => next page. Page size: 10.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
 
     fn call(
         &self,
@@ -421,6 +475,7 @@ impl Tool for JustExecuteTool {
     type Output = just::ExecuteOutput;
     const NAME: &'static str = "cli_just_execute";
     const DESCRIPTION: &'static str = "Execute a just recipe. Defaults to root justfile if no dir specified. Only disambiguate if recipe not in root.";
+    const PERMISSIONS: Permissions = Permissions::EXEC;
 
     fn call(
         &self,
@@ -437,6 +492,130 @@ impl Tool for JustExecuteTool {
     }
 }
 
+// ============================================================================
+// Batch Tool
+// ============================================================================
+
+/// Maximum number of calls accepted by a single `cli_batch` request.
+pub const MAX_BATCH_CALLS: usize = 16;
+
+/// A single read-only tool call inside a `cli_batch` request.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(tag = "tool", content = "args", rename_all = "snake_case")]
+pub enum BatchCall {
+    /// List a directory (same args as `cli_ls`)
+    #[serde(alias = "cli_ls")]
+    Ls(LsInput),
+    /// Regex search (same args as `cli_grep`)
+    #[serde(alias = "cli_grep")]
+    Grep(SearchGrepInput),
+    /// Glob match (same args as `cli_glob`)
+    #[serde(alias = "cli_glob")]
+    Glob(SearchGlobInput),
+}
+
+/// Input for the `cli_batch` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct BatchInput {
+    /// Calls to run concurrently; each is {tool: 'ls'|'grep'|'glob', args: {...}}
+    pub calls: Vec<BatchCall>,
+}
+
+/// Tool for running several read-only tools concurrently in one round trip.
+#[derive(Clone)]
+pub struct BatchTool {
+    tools: Arc<CodingAgentTools>,
+}
+
+impl BatchTool {
+    pub fn new(tools: Arc<CodingAgentTools>) -> Self {
+        Self { tools }
+    }
+}
+
+impl Tool for BatchTool {
+    type Input = BatchInput;
+    type Output = BatchOutput;
+    const NAME: &'static str = "cli_batch";
+    const DESCRIPTION: &'static str = "Run several read-only calls (ls, grep, glob) concurrently and return all results in request order. Each call is {tool, args} with the same args as cli_ls/cli_grep/cli_glob. A failing call reports its error without failing the batch. Max 16 calls.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
+
+    fn call(
+        &self,
+        input: Self::Input,
+        ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let tools = Arc::clone(&self.tools);
+        let ctx = ctx.clone();
+        Box::pin(async move {
+            if input.calls.is_empty() {
+                return Err(ToolError::InvalidInput(
+                    "calls must contain at least one entry".into(),
+                ));
+            }
+            if input.calls.len() > MAX_BATCH_CALLS {
+                return Err(ToolError::InvalidInput(format!(
+                    "Too many calls in batch: {} (max {MAX_BATCH_CALLS})",
+                    input.calls.len()
+                )));
+            }
+
+            // Spawn each call so the (synchronous) walkers run in parallel
+            let handles: Vec<_> = input
+                .calls
+                .into_iter()
+                .map(|call| {
+                    let (name, fut): (&str, BoxFuture<'static, Result<BatchResult, ToolError>>) =
+                        match call {
+                            BatchCall::Ls(i) => {
+                                let fut = LsTool::new(Arc::clone(&tools)).call(i, &ctx);
+                                (
+                                    LsTool::NAME,
+                                    Box::pin(async move { fut.await.map(BatchResult::Ls) }),
+                                )
+                            }
+                            BatchCall::Grep(i) => {
+                                let fut = SearchGrepTool::new(Arc::clone(&tools)).call(i, &ctx);
+                                (
+                                    SearchGrepTool::NAME,
+                                    Box::pin(async move { fut.await.map(BatchResult::Grep) }),
+                                )
+                            }
+                            BatchCall::Glob(i) => {
+                                let fut = SearchGlobTool::new(Arc::clone(&tools)).call(i, &ctx);
+                                (
+                                    SearchGlobTool::NAME,
+                                    Box::pin(async move { fut.await.map(BatchResult::Glob) }),
+                                )
+                            }
+                        };
+                    (name, tokio::spawn(fut))
+                })
+                .collect();
+
+            let mut results = Vec::with_capacity(handles.len());
+            for (name, handle) in handles {
+                let outcome = tokio::select! {
+                    () = ctx.cancelled() => return Err(ToolError::cancelled(None)),
+                    joined = handle => joined,
+                };
+                let (output, error) = match outcome {
+                    Ok(Ok(out)) => (Some(out), None),
+                    Ok(Err(e)) => (None, Some(e.to_string())),
+                    Err(e) => (None, Some(format!("{name} task failed: {e}"))),
+                };
+                results.push(BatchItem {
+                    tool: name.to_string(),
+                    output,
+                    error,
+                });
+            }
+
+            Ok(BatchOutput { results })
+        })
+    }
+}
+
 // ============================================================================
 // Registry Builder
 // ============================================================================
@@ -450,7 +629,8 @@ pub fn build_registry(subagents: SubagentsConfig, cli_tools: CliToolsConfig) ->
         .register::<SearchGrepTool, ()>(SearchGrepTool::new(Arc::clone(&tools)))
         .register::<SearchGlobTool, ()>(SearchGlobTool::new(Arc::clone(&tools)))
         .register::<JustSearchTool, ()>(JustSearchTool::new(Arc::clone(&tools)))
-        .register::<JustExecuteTool, ()>(JustExecuteTool::new(tools))
+        .register::<JustExecuteTool, ()>(JustExecuteTool::new(Arc::clone(&tools)))
+        .register::<BatchTool, ()>(BatchTool::new(tools))
         .finish()
 }
 
@@ -1,5 +1,6 @@
 use agentic_tools_core::fmt::TextFormat;
 use agentic_tools_core::fmt::TextOptions;
+use agentic_tools_core::fmt::Tree;
 use schemars::JsonSchema;
 use schemars::Schema;
 use serde::Deserialize;
@@ -21,6 +22,7 @@ pub enum AgentType {
 /// - Thoughts: Thought documents in active branch
 /// - References: Cloned reference repositories
 /// - Web: Internet search (no working directory)
+/// - Path: Arbitrary absolute directory supplied via `location_path` (e.g., a sibling repo)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum AgentLocation {
@@ -29,23 +31,100 @@ pub enum AgentLocation {
     Thoughts,
     References,
     Web,
+    Path,
 }
 
-/// Output from `ask_agent` tool - plain text response from the subagent.
+/// A source the subagent consulted, extracted from its tool-use events.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Citation {
+    /// A file read by the subagent. Line range is 1-based and inclusive; absent when
+    /// the whole file was read.
+    File {
+        path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start_line: Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end_line: Option<u64>,
+    },
+    /// A search or listing (`cli_grep`, `cli_glob`, `cli_ls`, `web_search`, ...).
+    Search {
+        tool: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pattern: Option<String>,
+    },
+    /// A URL fetched by the subagent.
+    Url { url: String },
+}
+
+impl std::fmt::Display for Citation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::File {
+                path,
+                start_line,
+                end_line,
+            } => match (start_line, end_line) {
+                (Some(s), Some(e)) => write!(f, "{path}:{s}-{e}"),
+                (Some(s), None) => write!(f, "{path}:{s}-"),
+                _ => write!(f, "{path}"),
+            },
+            Self::Search {
+                tool,
+                path,
+                pattern,
+            } => {
+                write!(f, "{tool}")?;
+                if let Some(p) = pattern {
+                    write!(f, " {p:?}")?;
+                }
+                if let Some(p) = path {
+                    write!(f, " in {p}")?;
+                }
+                Ok(())
+            }
+            Self::Url { url } => write!(f, "{url}"),
+        }
+    }
+}
+
+/// Output from `ask_agent` tool - the subagent's text response plus the sources it
+/// consulted, so callers can verify claims against what was actually looked at.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AgentOutput {
     pub text: String,
+    #[serde(default)]
+    pub citations: Vec<Citation>,
 }
 
 impl AgentOutput {
     pub fn new(text: String) -> Self {
-        Self { text }
+        Self {
+            text,
+            citations: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_citations(mut self, citations: Vec<Citation>) -> Self {
+        self.citations = citations;
+        self
     }
 }
 
 impl TextFormat for AgentOutput {
     fn fmt_text(&self, _opts: &TextOptions) -> String {
-        self.text.clone()
+        use std::fmt::Write;
+        let mut out = self.text.clone();
+        if !self.citations.is_empty() {
+            out.push_str("\n\nSources:");
+            for c in &self.citations {
+                let _ = write!(out, "\n- {c}");
+            }
+        }
+        out
     }
 }
 
@@ -146,6 +225,52 @@ pub struct LsOutput {
     pub entries: Vec<LsEntry>,
     pub has_more: bool,
     pub warnings: Vec<String>,
+    /// Per-call text rendering requested by the caller (not serialized)
+    #[serde(skip)]
+    pub format: Option<RenderFormat>,
+}
+
+// =============================================================================
+// Per-call text rendering
+// =============================================================================
+
+/// Text rendering requested on a single call: 'text' (compact listing),
+/// 'markdown' (listing in a fenced block), or 'json' (pretty-printed JSON).
+///
+/// When set, this overrides the transport's [`TextOptions`] for that call.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum RenderFormat {
+    Json,
+    #[default]
+    Text,
+    Markdown,
+}
+
+impl RenderFormat {
+    /// Fold this per-call choice into the transport's text options.
+    pub fn apply(self, opts: &TextOptions) -> TextOptions {
+        opts.clone().with_markdown(matches!(self, Self::Markdown))
+    }
+}
+
+/// Shared `fmt_text` dispatch for outputs that honor a per-call [`RenderFormat`].
+fn render_with_format<T: Serialize>(
+    value: &T,
+    format: Option<RenderFormat>,
+    opts: &TextOptions,
+    render: impl FnOnce(&TextOptions) -> String,
+) -> String {
+    if format == Some(RenderFormat::Json) {
+        return serde_json::to_string_pretty(value).unwrap_or_else(|_| "{}".to_string());
+    }
+    let opts = format.map_or_else(|| opts.clone(), |f| f.apply(opts));
+    let text = render(&opts);
+    if opts.markdown {
+        format!("```\n{text}\n```")
+    } else {
+        text
+    }
 }
 
 // =============================================================================
@@ -188,20 +313,26 @@ fn decode_truncation_info(s: &str) -> Option<(usize, usize, usize)> {
 }
 
 impl TextFormat for LsOutput {
-    fn fmt_text(&self, _opts: &TextOptions) -> String {
+    fn fmt_text(&self, opts: &TextOptions) -> String {
+        render_with_format(self, self.format, opts, |_| self.render_text())
+    }
+}
+
+impl LsOutput {
+    fn render_text(&self) -> String {
         use std::fmt::Write;
         let mut out = String::new();
 
         // Header: absolute canonical path with trailing /
         let _ = writeln!(out, "{}/", self.root.trim_end_matches('/'));
 
-        // Body: 2-space indent, directories with trailing /
+        // Body: 2-space indent per level, directories with trailing /
+        let mut tree = Tree::new().indent(2);
         for entry in &self.entries {
-            let _ = write!(out, "  {}", entry.path);
-            if matches!(entry.kind, EntryKind::Dir) && !entry.path.ends_with('/') {
-                out.push('/');
-            }
-            out.push('\n');
+            tree.insert_path(&entry.path, matches!(entry.kind, EntryKind::Dir));
+        }
+        if !tree.is_empty() {
+            let _ = writeln!(out, "{}", tree.render());
         }
 
         // Separate truncation sentinel from normal warnings
@@ -301,10 +432,19 @@ pub struct GrepOutput {
     pub warnings: Vec<String>,
     /// Optional summary (e.g., total matches for count mode)
     pub summary: Option<String>,
+    /// Per-call text rendering requested by the caller (not serialized)
+    #[serde(skip)]
+    pub format: Option<RenderFormat>,
 }
 
 impl TextFormat for GrepOutput {
     fn fmt_text(&self, opts: &TextOptions) -> String {
+        render_with_format(self, self.format, opts, |opts| self.render_text(opts))
+    }
+}
+
+impl GrepOutput {
+    fn render_text(&self, opts: &TextOptions) -> String {
         use std::fmt::Write;
         let mut out = String::new();
 
@@ -320,8 +460,19 @@ impl TextFormat for GrepOutput {
             self.root.trim_end_matches('/')
         );
 
+        let mut tree = Tree::new().indent(2);
         for line in &self.lines {
-            let _ = writeln!(out, "  {line}");
+            match self.mode {
+                OutputMode::Files => tree.insert_path(line, false),
+                OutputMode::Content => match split_content_line(line) {
+                    Some((path, rest)) => tree.insert(&[path], rest),
+                    None => tree.insert::<&str>(&[], line),
+                },
+                OutputMode::Count => tree.insert::<&str>(&[], line),
+            }
+        }
+        if !tree.is_empty() {
+            let _ = writeln!(out, "{}", tree.render());
         }
 
         if let Some(ref s) = self.summary {
@@ -347,6 +498,18 @@ impl TextFormat for GrepOutput {
     }
 }
 
+/// Split a content-mode line (`path:line: text` or `path: text`) into the path
+/// and the rest (`line: text` or `text`), so matches can be grouped by file.
+fn split_content_line(line: &str) -> Option<(&str, &str)> {
+    let (prefix, text) = line.split_once(": ")?;
+    match prefix.rsplit_once(':') {
+        Some((path, num)) if !num.is_empty() && num.bytes().all(|b| b.is_ascii_digit()) => {
+            Some((path, &line[path.len() + 1..]))
+        }
+        _ => Some((prefix, text)),
+    }
+}
+
 /// Output from `search_glob` tool.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GlobOutput {
@@ -389,6 +552,90 @@ impl TextFormat for GlobOutput {
     }
 }
 
+// =============================================================================
+// cli_batch types
+// =============================================================================
+
+/// Output of a single call inside a `cli_batch` request.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum BatchResult {
+    Ls(LsOutput),
+    Grep(GrepOutput),
+    Glob(GlobOutput),
+}
+
+impl BatchResult {
+    fn is_search(&self) -> bool {
+        matches!(self, Self::Grep(_) | Self::Glob(_))
+    }
+}
+
+impl TextFormat for BatchResult {
+    fn fmt_text(&self, opts: &TextOptions) -> String {
+        match self {
+            Self::Ls(o) => o.fmt_text(opts),
+            Self::Grep(o) => o.fmt_text(opts),
+            Self::Glob(o) => o.fmt_text(opts),
+        }
+    }
+}
+
+/// One entry of a `cli_batch` response, in the same order as the request.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchItem {
+    /// Tool name that handled this call (e.g., `cli_grep`)
+    pub tool: String,
+    /// Tool output when the call succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<BatchResult>,
+    /// Error message when the call failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Output from `cli_batch` tool.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchOutput {
+    pub results: Vec<BatchItem>,
+}
+
+impl TextFormat for BatchOutput {
+    fn fmt_text(&self, opts: &TextOptions) -> String {
+        use std::fmt::Write;
+        let mut out = String::new();
+
+        // Render each result without its own reminder; emit it once at the end
+        let inner_opts = opts.clone().with_suppress_search_reminder(true);
+        let mut any_search = false;
+
+        for (i, item) in self.results.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            let _ = writeln!(out, "[{}] {}", i + 1, item.tool);
+            match (&item.output, &item.error) {
+                (Some(o), _) => {
+                    any_search |= o.is_search();
+                    let _ = writeln!(out, "{}", o.fmt_text(&inner_opts));
+                }
+                (None, Some(e)) => {
+                    let _ = writeln!(out, "Error: {e}");
+                }
+                (None, None) => {
+                    let _ = writeln!(out, "(no output)");
+                }
+            }
+        }
+
+        if any_search && !opts.suppress_search_reminder {
+            let _ = writeln!(out, "\n{SEARCH_REMINDER}");
+        }
+
+        out.trim_end().to_string()
+    }
+}
+
 #[cfg(test)]
 #[expect(clippy::unwrap_used)]
 mod tests {
@@ -422,6 +669,7 @@ mod tests {
             AgentLocation::Thoughts,
             AgentLocation::References,
             AgentLocation::Web,
+            AgentLocation::Path,
         ] {
             let json = serde_json::to_string(&location).unwrap();
             let deserialized: AgentLocation = serde_json::from_str(&json).unwrap();
@@ -459,6 +707,10 @@ mod tests {
             serde_json::to_string(&AgentLocation::Web).unwrap(),
             "\"web\""
         );
+        assert_eq!(
+            serde_json::to_string(&AgentLocation::Path).unwrap(),
+            "\"path\""
+        );
     }
 
     #[test]
@@ -470,6 +722,7 @@ mod tests {
             has_more: false,
             warnings: vec![],
             summary: None,
+            format: None,
         };
 
         let text = output.fmt_text(&TextOptions::default());
@@ -485,6 +738,7 @@ mod tests {
             has_more: false,
             warnings: vec![],
             summary: None,
+            format: None,
         };
 
         let text = output.fmt_text(&TextOptions::new().with_suppress_search_reminder(true));
@@ -516,4 +770,150 @@ mod tests {
         let text = output.fmt_text(&TextOptions::new().with_suppress_search_reminder(true));
         assert!(!text.contains(SEARCH_REMINDER));
     }
+
+    #[test]
+    fn batch_fmt_text_emits_single_search_reminder() {
+        let grep = GrepOutput {
+            root: "/tmp/repo".into(),
+            mode: OutputMode::Files,
+            lines: vec!["src/lib.rs".into()],
+            has_more: false,
+            warnings: vec![],
+            summary: None,
+            format: None,
+        };
+        let glob = GlobOutput {
+            root: "/tmp/repo".into(),
+            entries: vec!["src/main.rs".into()],
+            has_more: false,
+            warnings: vec![],
+        };
+        let output = BatchOutput {
+            results: vec![
+                BatchItem {
+                    tool: "cli_grep".into(),
+                    output: Some(BatchResult::Grep(grep)),
+                    error: None,
+                },
+                BatchItem {
+                    tool: "cli_glob".into(),
+                    output: Some(BatchResult::Glob(glob)),
+                    error: None,
+                },
+                BatchItem {
+                    tool: "cli_ls".into(),
+                    output: None,
+                    error: Some("Path does not exist: /nope".into()),
+                },
+            ],
+        };
+
+        let text = output.fmt_text(&TextOptions::default());
+        assert_eq!(text.matches(SEARCH_REMINDER).count(), 1);
+        assert!(text.contains("[1] cli_grep"));
+        assert!(text.contains("[2] cli_glob"));
+        assert!(text.contains("[3] cli_ls\nError: Path does not exist: /nope"));
+    }
+
+    #[test]
+    fn ls_fmt_text_honors_per_call_format() {
+        let mut output = LsOutput {
+            root: "/tmp/repo".into(),
+            entries: vec![LsEntry {
+                path: "src".into(),
+                kind: EntryKind::Dir,
+            }],
+            has_more: false,
+            warnings: vec![],
+            format: Some(RenderFormat::Json),
+        };
+        let json = output.fmt_text(&TextOptions::default());
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["root"], "/tmp/repo");
+        assert!(parsed.get("format").is_none());
+
+        output.format = Some(RenderFormat::Markdown);
+        let md = output.fmt_text(&TextOptions::default());
+        assert_eq!(md, "```\n/tmp/repo/\n  src/\n```");
+
+        // Explicit text overrides a markdown transport default
+        output.format = Some(RenderFormat::Text);
+        let text = output.fmt_text(&TextOptions::new().with_markdown(true));
+        assert_eq!(text, "/tmp/repo/\n  src/");
+    }
+
+    #[test]
+    fn ls_fmt_text_nests_deep_entries() {
+        let entry = |path: &str, kind| LsEntry {
+            path: path.into(),
+            kind,
+        };
+        let output = LsOutput {
+            root: "/tmp/repo".into(),
+            entries: vec![
+                entry("src", EntryKind::Dir),
+                entry("src/lib.rs", EntryKind::File),
+                entry("src/bin/main.rs", EntryKind::File),
+                entry("Cargo.toml", EntryKind::File),
+            ],
+            has_more: false,
+            warnings: vec![],
+            format: None,
+        };
+
+        assert_eq!(
+            output.fmt_text(&TextOptions::default()),
+            "/tmp/repo/\n  src/\n    lib.rs\n    bin/\n      main.rs\n  Cargo.toml"
+        );
+    }
+
+    #[test]
+    fn grep_fmt_text_groups_content_by_file() {
+        let output = GrepOutput {
+            root: "/tmp/repo".into(),
+            mode: OutputMode::Content,
+            lines: vec![
+                "src/lib.rs:3: fn a()".into(),
+                "src/lib.rs:9: fn b(x: u8)".into(),
+                "README.md: fn c".into(),
+            ],
+            has_more: false,
+            warnings: vec![],
+            summary: None,
+            format: None,
+        };
+
+        assert_eq!(
+            output.fmt_text(&TextOptions::new().with_suppress_search_reminder(true)),
+            "grep results (content) in /tmp/repo/\n  src/lib.rs\n    3: fn a()\n    9: fn b(x: u8)\n  README.md\n    fn c"
+        );
+    }
+
+    #[test]
+    fn grep_fmt_text_without_format_follows_transport_options() {
+        let output = GrepOutput {
+            root: "/tmp/repo".into(),
+            mode: OutputMode::Files,
+            lines: vec!["src/lib.rs".into()],
+            has_more: false,
+            warnings: vec![],
+            summary: None,
+            format: None,
+        };
+
+        let text = output.fmt_text(
+            &TextOptions::new()
+                .with_markdown(true)
+                .with_suppress_search_reminder(true),
+        );
+        assert!(text.starts_with("```\ngrep results (files)"));
+        assert!(text.ends_with("```"));
+    }
+
+    #[test]
+    fn render_format_deserializes_lowercase() {
+        let f: RenderFormat = serde_json::from_str("\"markdown\"").unwrap();
+        assert_eq!(f, RenderFormat::Markdown);
+        assert!(serde_json::from_str::<RenderFormat>("\"yaml\"").is_err());
+    }
 }
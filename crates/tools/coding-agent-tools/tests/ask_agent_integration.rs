@@ -17,6 +17,7 @@ async fn locator_codebase_basic() {
         .ask_agent(
             Some(AgentType::Locator),
             Some(AgentLocation::Codebase),
+            None,
             "Find Cargo.toml files and related config".into(),
             &ctx,
         )
@@ -41,6 +42,7 @@ async fn analyzer_web_basic() {
         .ask_agent(
             Some(AgentType::Analyzer),
             Some(AgentLocation::Web),
+            None,
             "Summarize the core concepts of Rust error handling with sources".into(),
             &ctx,
         )
@@ -65,6 +67,7 @@ async fn locator_web_basic() {
         .ask_agent(
             Some(AgentType::Locator),
             Some(AgentLocation::Web),
+            None,
             "Find the official Rust documentation for the Result type".into(),
             &ctx,
         )
@@ -89,6 +92,7 @@ async fn analyzer_codebase_basic() {
         .ask_agent(
             Some(AgentType::Analyzer),
             Some(AgentLocation::Codebase),
+            None,
             "Analyze how the ls tool handles pagination in this codebase".into(),
             &ctx,
         )
@@ -113,6 +117,7 @@ async fn ask_agent_empty_query_fails() {
         .ask_agent(
             Some(AgentType::Locator),
             Some(AgentLocation::Codebase),
+            None,
             "   ".into(), // empty/whitespace only
             &ctx,
         )
@@ -130,7 +135,13 @@ async fn ask_agent_defaults_to_locator_codebase() {
     let ctx = ToolContext::default();
     // Test that defaults work (locator + codebase)
     let out = tools
-        .ask_agent(None, None, "Find test files in this project".into(), &ctx)
+        .ask_agent(
+            None,
+            None,
+            None,
+            "Find test files in this project".into(),
+            &ctx,
+        )
         .await;
 
     match out {
@@ -0,0 +1,131 @@
+//! Integration tests for `cli_batch`.
+#![expect(clippy::unwrap_used)]
+
+use agentic_config::types::CliToolsConfig;
+use agentic_config::types::SubagentsConfig;
+use agentic_tools_core::ToolContext;
+use coding_agent_tools::build_registry;
+use serde_json::json;
+use std::fs;
+use tempfile::TempDir;
+
+fn setup_test_dir() -> TempDir {
+    let tmp = TempDir::new().unwrap();
+    fs::write(tmp.path().join("alpha.rs"), "fn needle() {}\n").unwrap();
+    fs::write(tmp.path().join("beta.txt"), "nothing here\n").unwrap();
+    tmp
+}
+
+#[tokio::test]
+async fn batch_returns_results_in_request_order() {
+    let tmp = setup_test_dir();
+    let root = tmp.path().to_string_lossy().to_string();
+    let reg = build_registry(SubagentsConfig::default(), CliToolsConfig::default());
+
+    let args = json!({
+        "calls": [
+            {"tool": "glob", "args": {"pattern": "*.rs", "path": root}},
+            {"tool": "cli_grep", "args": {"pattern": "needle", "path": root}},
+            {"tool": "ls", "args": {"path": root}},
+        ]
+    });
+    let out = reg
+        .dispatch_json("cli_batch", args, &ToolContext::default())
+        .await
+        .unwrap();
+
+    let results = out["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["tool"], "cli_glob");
+    assert_eq!(results[0]["output"]["entries"], json!(["alpha.rs"]));
+    assert_eq!(results[1]["tool"], "cli_grep");
+    assert_eq!(results[1]["output"]["lines"], json!(["alpha.rs"]));
+    assert_eq!(results[2]["tool"], "cli_ls");
+    assert_eq!(results[2]["output"]["entries"].as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn batch_reports_per_call_errors_without_failing() {
+    let tmp = setup_test_dir();
+    let root = tmp.path().to_string_lossy().to_string();
+    let missing = tmp.path().join("missing").to_string_lossy().to_string();
+    let reg = build_registry(SubagentsConfig::default(), CliToolsConfig::default());
+
+    let args = json!({
+        "calls": [
+            {"tool": "ls", "args": {"path": missing}},
+            {"tool": "glob", "args": {"pattern": "*.txt", "path": root}},
+        ]
+    });
+    let out = reg
+        .dispatch_json("cli_batch", args, &ToolContext::default())
+        .await
+        .unwrap();
+
+    let results = out["results"].as_array().unwrap();
+    assert!(results[0]["output"].is_null());
+    assert!(
+        results[0]["error"]
+            .as_str()
+            .unwrap()
+            .contains("does not exist")
+    );
+    assert_eq!(results[1]["output"]["entries"], json!(["beta.txt"]));
+}
+
+#[tokio::test]
+async fn batch_rejects_empty_and_oversized_requests() {
+    let reg = build_registry(SubagentsConfig::default(), CliToolsConfig::default());
+    let ctx = ToolContext::default();
+
+    let empty = reg
+        .dispatch_json("cli_batch", json!({"calls": []}), &ctx)
+        .await;
+    assert!(empty.is_err());
+
+    let calls: Vec<_> = (0..=coding_agent_tools::tools::MAX_BATCH_CALLS)
+        .map(|_| json!({"tool": "ls", "args": {}}))
+        .collect();
+    let oversized = reg
+        .dispatch_json("cli_batch", json!({ "calls": calls }), &ctx)
+        .await;
+    assert!(oversized.is_err());
+}
+
+#[tokio::test]
+async fn batch_rejects_unknown_tool() {
+    let reg = build_registry(SubagentsConfig::default(), CliToolsConfig::default());
+    let args = json!({"calls": [{"tool": "just_execute", "args": {"recipe": "check"}}]});
+    let result = reg
+        .dispatch_json("cli_batch", args, &ToolContext::default())
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn batch_resolves_relative_paths_against_context_cwd() {
+    let tmp = setup_test_dir();
+    fs::create_dir_all(tmp.path().join("sub")).unwrap();
+    fs::write(tmp.path().join("sub").join("gamma.rs"), "fn needle() {}\n").unwrap();
+    let reg = build_registry(SubagentsConfig::default(), CliToolsConfig::default());
+    let ctx = ToolContext::default().with_cwd(tmp.path());
+
+    let args = json!({
+        "calls": [
+            {"tool": "glob", "args": {"pattern": "*.rs"}},
+            {"tool": "grep", "args": {"pattern": "needle", "path": "sub"}},
+            {"tool": "ls", "args": {"path": "sub"}},
+        ]
+    });
+    let out = reg.dispatch_json("cli_batch", args, &ctx).await.unwrap();
+
+    let results = out["results"].as_array().unwrap();
+    assert_eq!(
+        results[0]["output"]["entries"],
+        json!(["alpha.rs", "sub/gamma.rs"])
+    );
+    assert_eq!(results[1]["output"]["lines"], json!(["gamma.rs"]));
+    let entries = results[2]["output"]["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["path"], "gamma.rs");
+}
@@ -817,3 +817,91 @@ fn test_grep_include_ignored_does_not_imply_include_hidden() {
     assert!(result_without_hidden.lines.is_empty());
     assert_eq!(result_with_hidden.lines, vec![".hidden_match"]);
 }
+
+/// Build a content-mode `GrepConfig` with defaults for index tests.
+fn content_cfg(
+    root: &str,
+    pattern: &str,
+    case_insensitive: bool,
+) -> coding_agent_tools::grep::GrepConfig {
+    coding_agent_tools::grep::GrepConfig {
+        root: root.to_string(),
+        pattern: pattern.to_string(),
+        mode: OutputMode::Content,
+        include_globs: vec![],
+        ignore_globs: vec![],
+        include_hidden: false,
+        include_ignored: false,
+        case_insensitive,
+        multiline: false,
+        line_numbers: true,
+        context: None,
+        context_before: None,
+        context_after: None,
+        include_binary: false,
+        head_limit: 200,
+        offset: 0,
+    }
+}
+
+#[test]
+fn test_grep_indexed_matches_plain_scan() {
+    let tmp = setup_test_dir();
+    let root = tmp.path().to_string_lossy().to_string();
+    let cache = coding_agent_tools::grep_index::GrepIndexCache::new();
+
+    for (pattern, ci) in [
+        ("Hello", false),
+        ("hello", true),
+        ("fn\\s+main", false),
+        ("ba.", false),
+    ] {
+        let plain = coding_agent_tools::grep::run(content_cfg(&root, pattern, ci)).unwrap();
+        let indexed =
+            coding_agent_tools::grep::run_indexed(content_cfg(&root, pattern, ci), &cache).unwrap();
+        assert_eq!(plain.lines, indexed.lines, "pattern {pattern}");
+        assert!(indexed.warnings.iter().any(|w| w.starts_with("Index: ")));
+        assert!(!plain.warnings.iter().any(|w| w.starts_with("Index: ")));
+    }
+}
+
+#[test]
+fn test_grep_indexed_reports_status_and_refreshes_changed_files() {
+    let tmp = TempDir::new().unwrap();
+    let root = tmp.path().to_string_lossy().to_string();
+    fs::write(tmp.path().join("a.txt"), "alpha needle\n").unwrap();
+    fs::write(tmp.path().join("b.txt"), "beta\n").unwrap();
+    let cache = coding_agent_tools::grep_index::GrepIndexCache::new();
+
+    let first =
+        coding_agent_tools::grep::run_indexed(content_cfg(&root, "needle", false), &cache).unwrap();
+    assert_eq!(first.lines, vec!["a.txt:1: alpha needle"]);
+    assert!(
+        first
+            .warnings
+            .contains(&"Index: built (2 files); scanned 1 candidate file".to_string())
+    );
+
+    let b = tmp.path().join("b.txt");
+    fs::write(&b, "beta needle\n").unwrap();
+    filetime::set_file_mtime(&b, filetime::FileTime::from_unix_time(1, 0)).unwrap();
+
+    let second =
+        coding_agent_tools::grep::run_indexed(content_cfg(&root, "needle", false), &cache).unwrap();
+    assert_eq!(second.lines.len(), 2);
+    assert!(
+        second
+            .warnings
+            .contains(&"Index: updated 1 changed (2 files); scanned 2 candidate files".to_string())
+    );
+
+    let third =
+        coding_agent_tools::grep::run_indexed(content_cfg(&root, "ne+dle", false), &cache).unwrap();
+    assert_eq!(third.lines.len(), 2);
+    assert!(
+        third
+            .warnings
+            .iter()
+            .any(|w| w.starts_with("Index: reused (2 files)"))
+    );
+}
@@ -164,6 +164,7 @@ mod text_format_tests {
             entries: vec![],
             has_more: false,
             warnings: vec![],
+            format: None,
         };
         let text = output.fmt_text(&TextOptions::default());
         assert!(text.starts_with("/home/user/project/"));
@@ -185,6 +186,7 @@ mod text_format_tests {
             ],
             has_more: false,
             warnings: vec![],
+            format: None,
         };
         let text = output.fmt_text(&TextOptions::default());
 
@@ -201,6 +203,7 @@ mod text_format_tests {
             entries: vec![],
             has_more: true,
             warnings: vec![],
+            format: None,
         };
         let text = output.fmt_text(&TextOptions::default());
         assert!(text.contains("truncated"));
@@ -214,6 +217,7 @@ mod text_format_tests {
             entries: vec![],
             has_more: false,
             warnings: vec!["Permission denied: secret/".into()],
+            format: None,
         };
         let text = output.fmt_text(&TextOptions::default());
         assert!(text.contains("Note: Permission denied: secret/"));
@@ -235,6 +239,7 @@ mod text_format_tests {
             ],
             has_more: true,
             warnings: vec!["Skipped: node_modules".into()],
+            format: None,
         };
         let text = output.fmt_text(&TextOptions::default());
 
@@ -727,10 +732,13 @@ mod pagination_integration_tests {
 // =============================================================================
 
 mod ls_stateful_pagination_tests {
+    use agentic_tools_core::SessionState;
+    use agentic_tools_core::ToolContext;
     use coding_agent_tools::CodingAgentTools;
     use coding_agent_tools::types::Show;
     use std::fs;
     use std::path::Path;
+    use std::sync::Arc;
     use tempfile::TempDir;
 
     fn create_files(root: &Path, count: usize) {
@@ -751,7 +759,14 @@ mod ls_stateful_pagination_tests {
 
         // Page 1
         let out1 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out1.entries.len(), 100);
@@ -761,7 +776,14 @@ mod ls_stateful_pagination_tests {
 
         // Page 2 (identical params)
         let out2 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out2.entries.len(), 100);
@@ -770,13 +792,48 @@ mod ls_stateful_pagination_tests {
         assert_eq!(out2.entries.last().unwrap().path, "file_0199.txt");
 
         // Page 3 (identical params)
-        let out3 = tools.ls(Some(path), None, None, None, None).await.unwrap();
+        let out3 = tools
+            .ls(Some(path), None, None, None, None, &ToolContext::default())
+            .await
+            .unwrap();
         assert_eq!(out3.entries.len(), 50);
         assert!(!out3.has_more, "no more pages after last page");
         assert_eq!(out3.entries.first().unwrap().path, "file_0200.txt");
         assert_eq!(out3.entries.last().unwrap().path, "file_0249.txt");
     }
 
+    #[tokio::test]
+    async fn ls_pagination_is_isolated_per_session() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        create_files(root, 150);
+
+        let tools = CodingAgentTools::new();
+        let path = root.to_string_lossy().to_string();
+        let session_a = ToolContext::default().with_session(Arc::new(SessionState::new()));
+        let session_b = ToolContext::default().with_session(Arc::new(SessionState::new()));
+
+        let a1 = tools
+            .ls(Some(path.clone()), None, None, None, None, &session_a)
+            .await
+            .unwrap();
+        let b1 = tools
+            .ls(Some(path.clone()), None, None, None, None, &session_b)
+            .await
+            .unwrap();
+        let a2 = tools
+            .ls(Some(path), None, None, None, None, &session_a)
+            .await
+            .unwrap();
+
+        // Each session starts at its own first page
+        assert_eq!(a1.entries.first().unwrap().path, "file_0000.txt");
+        assert_eq!(b1.entries.first().unwrap().path, "file_0000.txt");
+        // Session B's call did not advance session A's offset
+        assert_eq!(a2.entries.first().unwrap().path, "file_0100.txt");
+        assert!(!a2.has_more);
+    }
+
     #[tokio::test]
     async fn ls_new_params_reset_pagination() {
         let dir = TempDir::new().unwrap();
@@ -788,20 +845,41 @@ mod ls_stateful_pagination_tests {
 
         // Page 1 (default show=all)
         let _out1 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
 
         // Page 2 (identical params)
         let out2 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out2.entries.first().unwrap().path, "file_0100.txt");
 
         // Change param: show=files (filtered mode with page_size=1000) should reset to page 1
         let out_reset = tools
-            .ls(Some(path), None, Some(Show::Files), None, None)
+            .ls(
+                Some(path),
+                None,
+                Some(Show::Files),
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out_reset.entries.first().unwrap().path, "file_0000.txt");
@@ -818,6 +896,7 @@ mod ls_stateful_pagination_tests {
 // =============================================================================
 
 mod ls_parallel_and_cache_tests {
+    use agentic_tools_core::ToolContext;
     use coding_agent_tools::CodingAgentTools;
     use coding_agent_tools::types::Show;
     use std::collections::HashSet;
@@ -841,9 +920,10 @@ mod ls_parallel_and_cache_tests {
         let path = root.to_string_lossy().to_string();
 
         // Launch two identical ls calls in parallel
+        let ctx = ToolContext::default();
         let (a, b) = tokio::join!(
-            tools.ls(Some(path.clone()), None, None, None, None),
-            tools.ls(Some(path.clone()), None, None, None, None)
+            tools.ls(Some(path.clone()), None, None, None, None, &ctx),
+            tools.ls(Some(path.clone()), None, None, None, None, &ctx)
         );
         let out_a = a.unwrap();
         let out_b = b.unwrap();
@@ -870,9 +950,17 @@ mod ls_parallel_and_cache_tests {
         let path = root.to_string_lossy().to_string();
 
         // Launch two ls calls with different params in parallel
+        let ctx = ToolContext::default();
         let (a, b) = tokio::join!(
-            tools.ls(Some(path.clone()), None, None, None, None),
-            tools.ls(Some(path.clone()), None, Some(Show::Files), None, None)
+            tools.ls(Some(path.clone()), None, None, None, None, &ctx),
+            tools.ls(
+                Some(path.clone()),
+                None,
+                Some(Show::Files),
+                None,
+                None,
+                &ctx
+            )
         );
         let out_a = a.unwrap();
         let out_b = b.unwrap();
@@ -893,7 +981,14 @@ mod ls_parallel_and_cache_tests {
 
         // Page 1
         let out1 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out1.entries.len(), 100);
@@ -904,7 +999,14 @@ mod ls_parallel_and_cache_tests {
 
         // Page 2 should still be exactly the last original entry (cached)
         let out2 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out2.entries.len(), 1);
@@ -923,18 +1025,39 @@ mod ls_parallel_and_cache_tests {
 
         // Page 1 (100 entries), page 2 (50 entries)
         let _ = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         let out2 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert!(!out2.has_more, "page 2 should be last page");
 
         // Next call should restart to page 1 (cache entry was removed)
         let out3 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         assert_eq!(out3.entries.first().unwrap().path, "file_0000.txt");
@@ -964,6 +1087,7 @@ mod truncation_sentinel_tests {
 }
 
 mod enhanced_truncation_message_tests {
+    use agentic_tools_core::ToolContext;
     use agentic_tools_core::fmt::TextFormat;
     use agentic_tools_core::fmt::TextOptions;
     use coding_agent_tools::CodingAgentTools;
@@ -989,7 +1113,14 @@ mod enhanced_truncation_message_tests {
 
         // Page 1: showing 100 of 250, 2 pages remaining
         let out1 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         let text1 = out1.fmt_text(&TextOptions::default());
@@ -1009,7 +1140,14 @@ mod enhanced_truncation_message_tests {
 
         // Page 2: showing 200 of 250, 1 page remaining
         let out2 = tools
-            .ls(Some(path.clone()), None, None, None, None)
+            .ls(
+                Some(path.clone()),
+                None,
+                None,
+                None,
+                None,
+                &ToolContext::default(),
+            )
             .await
             .unwrap();
         let text2 = out2.fmt_text(&TextOptions::default());
@@ -1037,7 +1175,10 @@ mod enhanced_truncation_message_tests {
         let tools = CodingAgentTools::new();
         let path = root.to_string_lossy().to_string();
 
-        let out = tools.ls(Some(path), None, None, None, None).await.unwrap();
+        let out = tools
+            .ls(Some(path), None, None, None, None, &ToolContext::default())
+            .await
+            .unwrap();
         let text = out.fmt_text(&TextOptions::default());
 
         // The sentinel should not appear in the formatted output
@@ -1,6 +1,7 @@
 #![expect(clippy::unwrap_used)]
 #![expect(clippy::undocumented_unsafe_blocks)]
 
+use agentic_tools_core::ToolContext;
 use coding_agent_tools::CodingAgentTools;
 use serial_test::serial;
 use tempfile::TempDir;
@@ -19,7 +20,14 @@ async fn ls_works_with_tilde_home_override() {
 
     let tools = CodingAgentTools::new();
     let out = tools
-        .ls(Some("~".into()), None, None, None, Some(true))
+        .ls(
+            Some("~".into()),
+            None,
+            None,
+            None,
+            Some(true),
+            &ToolContext::default(),
+        )
         .await
         .unwrap();
     assert!(out.root.starts_with(home.to_string_lossy().as_ref()));
@@ -44,7 +52,14 @@ async fn ls_works_with_tilde_slash_home_override() {
 
     let tools = CodingAgentTools::new();
     let out = tools
-        .ls(Some("~/".into()), None, None, None, Some(true))
+        .ls(
+            Some("~/".into()),
+            None,
+            None,
+            None,
+            Some(true),
+            &ToolContext::default(),
+        )
         .await
         .unwrap();
     assert!(out.root.starts_with(home.to_string_lossy().as_ref()));
@@ -115,6 +130,7 @@ async fn search_grep_accepts_tilde_root() {
             None,
             Some(10),
             Some(0),
+            None,
         )
         .await;
     assert!(res.is_ok());
@@ -132,7 +148,16 @@ async fn tilde_expansion_error_when_home_unavailable() {
     }
 
     let tools = CodingAgentTools::new();
-    let res = tools.ls(Some("~".into()), None, None, None, None).await;
+    let res = tools
+        .ls(
+            Some("~".into()),
+            None,
+            None,
+            None,
+            None,
+            &ToolContext::default(),
+        )
+        .await;
 
     unsafe {
         std::env::remove_var("__CAT_FORCE_HOME_NONE");
@@ -12,6 +12,8 @@ use crate::errors::ReasonerError;
 use crate::optimizer::call_optimizer;
 use crate::optimizer::parser::OptimizerOutput;
 use crate::optimizer::parser::parse_optimizer_output;
+use crate::repro::ReproMetadata;
+use crate::repro::SAMPLING_TEMPERATURE;
 use crate::template::inject_files;
 use crate::token::enforce_limit;
 use crate::types::DirectoryMeta;
@@ -23,6 +25,7 @@ use agentic_logging::LogWriter;
 use agentic_logging::ToolCallRecord;
 use agentic_tools_core::ToolContext;
 use agentic_tools_core::ToolError;
+use agentic_tools_core::ToolEvent;
 use async_openai::error::OpenAIError;
 use async_openai::types::chat::ChatCompletionRequestMessage;
 use async_openai::types::chat::ChatCompletionRequestUserMessageArgs;
@@ -32,7 +35,9 @@ use async_openai::types::chat::CreateChatCompletionRequestArgs;
 use async_openai::types::chat::FinishReason;
 use async_openai::types::chat::ReasoningEffort;
 use futures::StreamExt;
+
 use std::collections::HashSet;
+use std::sync::OnceLock;
 use thoughts_tool::DocumentType;
 use thoughts_tool::write_document;
 
@@ -157,13 +162,16 @@ pub async fn gpt5_reasoner_impl(
 
     // Initialize writer early (best-effort)
     let writer = match thoughts_tool::active_logs_dir() {
-        Ok(dir) => Some(LogWriter::new(dir)),
+        Ok(dir) => Some(LogWriter::new(dir).with_server(&server)),
         Err(e) => {
             tracing::debug!("JSONL logging unavailable: {}", e);
             None
         }
     };
 
+    // Filled in once the final file list is known; logged with every record after that
+    let repro: OnceLock<serde_json::Value> = OnceLock::new();
+
     // Best-effort JSONL append closure
     // Takes files_count as parameter to avoid capturing `files` (which is mutated later)
     let log_record = |success: bool,
@@ -183,6 +191,7 @@ pub async fn gpt5_reasoner_impl(
                 "directories": directories,
                 "files_count": files_count,
                 "output_filename": output_filename,
+                "reproducibility": repro.get(),
             });
             let failure_kind = agentic_logging::classify_failure_kind(success, error.as_deref());
             let record = ToolCallRecord {
@@ -200,6 +209,8 @@ pub async fn gpt5_reasoner_impl(
                 model,
                 token_usage,
                 summary,
+                trace_id: Some(timer.trace_id.clone()),
+                parent_call_id: timer.parent_call_id.clone(),
             };
             if let Err(e) = w.append_jsonl(&record) {
                 tracing::warn!("Failed to append JSONL log: {}", e);
@@ -294,6 +305,11 @@ pub async fn gpt5_reasoner_impl(
     }
     // ===== END NEW =====
 
+    repro.get_or_init(|| {
+        serde_json::to_value(ReproMetadata::capture(&prompt, &prompt_type, &files, cfg))
+            .unwrap_or_default()
+    });
+
     if ctx.is_cancelled() {
         return Err(ToolError::cancelled(None));
     }
@@ -308,6 +324,7 @@ pub async fn gpt5_reasoner_impl(
 
     let mut parsed: Option<OptimizerOutput> = None;
 
+    ctx.progress(format!("optimizing prompt with {opt_model}"));
     for attempt in 0..=TEMPLATE_RETRIES {
         if attempt > 0 {
             tracing::warn!(
@@ -329,6 +346,7 @@ pub async fn gpt5_reasoner_impl(
                 &prompt_type,
                 &prompt,
                 &files,
+                cfg.seed,
             ))
             .await
         {
@@ -529,6 +547,12 @@ pub async fn gpt5_reasoner_impl(
     );
 
     for attempt in 0..=EXECUTOR_RETRIES {
+        // Streamed text restarts from scratch on each attempt
+        ctx.progress(format!(
+            "running {executor_model} (attempt {} of {})",
+            attempt + 1,
+            EXECUTOR_RETRIES + 1
+        ));
         if attempt > 0 {
             tracing::warn!(
                 "Executor API attempt {} of {}",
@@ -559,7 +583,7 @@ pub async fn gpt5_reasoner_impl(
             .model(executor_model)
             .messages([ChatCompletionRequestMessage::User(user_msg)])
             .reasoning_effort(reasoning_effort.clone())
-            .temperature(0.2)
+            .temperature(SAMPLING_TEMPERATURE)
             .stream_options(ChatCompletionStreamOptions {
                 include_usage: Some(true),
                 include_obfuscation: None,
@@ -567,6 +591,9 @@ pub async fn gpt5_reasoner_impl(
         if let Some(n) = cfg.max_completion_tokens {
             req_builder.max_completion_tokens(n);
         }
+        if let Some(seed) = cfg.seed {
+            req_builder.seed(seed);
+        }
 
         let req = match req_builder.build() {
             Ok(r) => r,
@@ -641,6 +668,7 @@ pub async fn gpt5_reasoner_impl(
                                         Some(attempt_started.elapsed().as_millis());
                                 }
                                 stream_state.content.push_str(&delta);
+                                ctx.emit(ToolEvent::TextDelta { text: delta });
                             }
                         }
 
@@ -46,6 +46,9 @@ pub enum ReasonerError {
 
     #[error("File not found: {0}")]
     MissingFile(PathBuf),
+
+    #[error("Log record has no reproducibility metadata")]
+    MissingReproMetadata,
 }
 
 impl From<ReasonerError> for ToolError {
@@ -60,6 +63,7 @@ impl From<ReasonerError> for ToolError {
             | ReasonerError::CorpusOptimizerPromptTokenEstimateLimit { .. }
             | ReasonerError::OptimizerSelectedUnknownFiles(_)
             | ReasonerError::Template(_)
+            | ReasonerError::MissingReproMetadata
             | ReasonerError::Yaml(_)
             | ReasonerError::Json(_) => Self::InvalidInput(e.to_string()),
             ReasonerError::OpenAI(_) => Self::External(e.to_string()),
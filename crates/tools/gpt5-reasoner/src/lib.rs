@@ -1,6 +1,7 @@
 pub mod client;
 pub mod errors;
 pub mod optimizer;
+pub mod repro;
 pub mod template;
 pub mod token;
 pub mod tools;
@@ -10,6 +11,8 @@ pub use types::*;
 
 pub mod engine;
 pub use engine::gpt5_reasoner_impl;
+pub use repro::ReproMetadata;
+pub use repro::reproduce;
 pub use tools::build_registry;
 
 pub(crate) const PLAN_STRUCTURE_FILENAME: &str = "plan_structure.md";
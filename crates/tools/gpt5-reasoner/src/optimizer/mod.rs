@@ -51,6 +51,7 @@ pub async fn call_optimizer(
     pt: &PromptType,
     prompt: &str,
     files: &[FileMeta],
+    seed: Option<i64>,
 ) -> Result<String> {
     // Prepare the user prompt once; clone per attempt when building request
     let user_prompt = build_user_prompt(pt, prompt, files);
@@ -79,7 +80,11 @@ pub async fn call_optimizer(
                         .map_err(ReasonerError::OpenAI)?,
                 ),
             ])
-            .temperature(0.2);
+            .temperature(crate::repro::SAMPLING_TEMPERATURE);
+        if let Some(seed) = seed {
+            // Providers that do not support seeding ignore it
+            req_builder.seed(seed);
+        }
 
         // Set reasoning_effort for reasoning models
         let using_reasoning =
@@ -0,0 +1,264 @@
+//! Reproducibility metadata for reasoning requests.
+//!
+//! Every call records a [`ReproMetadata`] under `request.reproducibility` in its JSONL
+//! log record: the models and sampling parameters used, the seed (if configured), a hash
+//! of the prompt, and a content hash per input file. [`reproduce`] re-runs a recorded
+//! request with the same parameters so outputs can be compared across prompt changes.
+
+use crate::errors::ReasonerError;
+use crate::gpt5_reasoner_impl;
+use crate::types::FileMeta;
+use crate::types::PromptType;
+use agentic_config::types::ReasoningConfig;
+use agentic_tools_core::ToolContext;
+use agentic_tools_core::ToolError;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+/// Current schema version of [`ReproMetadata`].
+pub const REPRO_VERSION: u32 = 1;
+
+/// Sampling temperature used for both optimizer and executor requests.
+pub const SAMPLING_TEMPERATURE: f32 = 0.2;
+
+/// Input file with the content hash observed when the request ran.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileFingerprint {
+    pub filename: String,
+    pub description: String,
+    /// Hex SHA-256 of the file contents; `None` for embedded or unreadable files.
+    pub sha256: Option<String>,
+}
+
+/// Everything needed to re-run a reasoning request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReproMetadata {
+    pub version: u32,
+    pub prompt_type: PromptType,
+    pub prompt: String,
+    pub prompt_sha256: String,
+    pub optimizer_model: String,
+    pub executor_model: String,
+    pub reasoning_effort: Option<String>,
+    pub temperature: f32,
+    pub max_input_tokens: Option<u32>,
+    pub max_completion_tokens: Option<u32>,
+    pub seed: Option<i64>,
+    /// Final file list after directory expansion and auto-injection.
+    pub files: Vec<FileFingerprint>,
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+    let digest = Sha256::digest(bytes);
+    let mut out = String::with_capacity(digest.len() * 2);
+    for byte in &digest {
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
+
+fn file_sha256(path: &str) -> Option<String> {
+    std::fs::read(path).ok().map(|bytes| sha256_hex(&bytes))
+}
+
+impl ReproMetadata {
+    /// Capture metadata for a request about to run with `cfg`.
+    pub fn capture(
+        prompt: &str,
+        prompt_type: &PromptType,
+        files: &[FileMeta],
+        cfg: &ReasoningConfig,
+    ) -> Self {
+        Self {
+            version: REPRO_VERSION,
+            prompt_type: prompt_type.clone(),
+            prompt: prompt.to_string(),
+            prompt_sha256: sha256_hex(prompt.as_bytes()),
+            optimizer_model: cfg.optimizer_model.clone(),
+            executor_model: cfg.executor_model.clone(),
+            reasoning_effort: cfg.reasoning_effort.clone(),
+            temperature: SAMPLING_TEMPERATURE,
+            max_input_tokens: cfg.max_input_tokens,
+            max_completion_tokens: cfg.max_completion_tokens,
+            seed: cfg.seed,
+            files: files
+                .iter()
+                .map(|f| FileFingerprint {
+                    filename: f.filename.clone(),
+                    description: f.description.clone(),
+                    sha256: file_sha256(&f.filename),
+                })
+                .collect(),
+        }
+    }
+
+    /// Extract metadata from a JSONL log record written by a previous call.
+    pub fn from_log_record(record: &serde_json::Value) -> crate::errors::Result<Self> {
+        let value = record
+            .get("request")
+            .and_then(|r| r.get("reproducibility"))
+            .filter(|v| !v.is_null())
+            .ok_or(ReasonerError::MissingReproMetadata)?;
+        Ok(serde_json::from_value(value.clone())?)
+    }
+
+    /// Files whose current content hash differs from the recorded one (or that are gone).
+    pub fn changed_files(&self) -> Vec<String> {
+        self.files
+            .iter()
+            .filter(|f| f.sha256.is_some() && file_sha256(&f.filename) != f.sha256)
+            .map(|f| f.filename.clone())
+            .collect()
+    }
+
+    /// `base` with the recorded models, limits, and seed applied.
+    ///
+    /// Transport settings (API base URL, timeouts) are kept from `base`.
+    pub fn apply_to(&self, base: &ReasoningConfig) -> ReasoningConfig {
+        ReasoningConfig {
+            optimizer_model: self.optimizer_model.clone(),
+            executor_model: self.executor_model.clone(),
+            reasoning_effort: self.reasoning_effort.clone(),
+            max_input_tokens: self.max_input_tokens,
+            max_completion_tokens: self.max_completion_tokens,
+            seed: self.seed,
+            ..base.clone()
+        }
+    }
+
+    fn file_metas(&self) -> Vec<FileMeta> {
+        self.files
+            .iter()
+            .map(|f| FileMeta {
+                filename: f.filename.clone(),
+                description: f.description.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Re-run a recorded request with its original prompt, files, models, and seed.
+///
+/// The result is always returned inline (plans are not written to thoughts). Files
+/// whose content changed since the recording are logged as a warning, since they make
+/// the comparison meaningless.
+pub async fn reproduce(
+    meta: &ReproMetadata,
+    base_cfg: &ReasoningConfig,
+    ctx: &ToolContext,
+) -> Result<String, ToolError> {
+    if meta.version > REPRO_VERSION {
+        return Err(ToolError::invalid_input(format!(
+            "Unsupported reproducibility metadata version {} (max {REPRO_VERSION})",
+            meta.version
+        )));
+    }
+
+    let changed = meta.changed_files();
+    if !changed.is_empty() {
+        tracing::warn!(
+            files = ?changed,
+            "Input files changed since the recorded request; reproduction may diverge"
+        );
+    }
+
+    gpt5_reasoner_impl(
+        meta.prompt.clone(),
+        meta.file_metas(),
+        None,
+        &meta.apply_to(base_cfg),
+        meta.prompt_type.clone(),
+        None,
+        ctx,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample(dir: &TempDir) -> (ReproMetadata, std::path::PathBuf) {
+        let path = dir.path().join("a.rs");
+        std::fs::write(&path, "fn a() {}").unwrap();
+        let cfg = ReasoningConfig {
+            seed: Some(7),
+            ..ReasoningConfig::default()
+        };
+        let files = vec![
+            FileMeta {
+                filename: path.to_string_lossy().to_string(),
+                description: "a".into(),
+            },
+            FileMeta {
+                filename: crate::PLAN_STRUCTURE_FILENAME.into(),
+                description: "embedded".into(),
+            },
+        ];
+        (
+            ReproMetadata::capture("why?", &PromptType::Plan, &files, &cfg),
+            path,
+        )
+    }
+
+    #[test]
+    fn capture_records_params_and_hashes() {
+        let dir = TempDir::new().unwrap();
+        let (meta, _) = sample(&dir);
+
+        assert_eq!(meta.version, REPRO_VERSION);
+        assert_eq!(meta.seed, Some(7));
+        assert_eq!(meta.prompt_sha256, sha256_hex(b"why?"));
+        assert_eq!(meta.files[0].sha256, Some(sha256_hex(b"fn a() {}")));
+        assert_eq!(meta.files[1].sha256, None);
+        assert!(meta.changed_files().is_empty());
+    }
+
+    #[test]
+    fn changed_files_detects_edits() {
+        let dir = TempDir::new().unwrap();
+        let (meta, path) = sample(&dir);
+        std::fs::write(&path, "fn b() {}").unwrap();
+        assert_eq!(
+            meta.changed_files(),
+            vec![path.to_string_lossy().to_string()]
+        );
+    }
+
+    #[test]
+    fn round_trips_through_log_record() {
+        let dir = TempDir::new().unwrap();
+        let (meta, _) = sample(&dir);
+        let record = serde_json::json!({ "request": { "reproducibility": meta } });
+
+        let parsed = ReproMetadata::from_log_record(&record).unwrap();
+        assert_eq!(parsed.files, meta.files);
+        assert_eq!(parsed.seed, Some(7));
+        assert!((parsed.temperature - SAMPLING_TEMPERATURE).abs() < f32::EPSILON);
+
+        let missing = serde_json::json!({ "request": { "prompt": "x" } });
+        assert!(matches!(
+            ReproMetadata::from_log_record(&missing),
+            Err(ReasonerError::MissingReproMetadata)
+        ));
+    }
+
+    #[test]
+    fn apply_to_keeps_transport_settings() {
+        let dir = TempDir::new().unwrap();
+        let (meta, _) = sample(&dir);
+        let base = ReasoningConfig {
+            api_base_url: Some("http://localhost:1".into()),
+            executor_model: "other".into(),
+            ..ReasoningConfig::default()
+        };
+        let cfg = meta.apply_to(&base);
+        assert_eq!(cfg.api_base_url.as_deref(), Some("http://localhost:1"));
+        assert_eq!(cfg.executor_model, meta.executor_model);
+        assert_eq!(cfg.seed, Some(7));
+    }
+}
@@ -139,6 +139,8 @@ fn tool_call_record_serialization() {
         model: Some("openai/gpt-5.2".into()),
         token_usage: None,
         summary: None,
+        trace_id: None,
+        parent_call_id: None,
     };
 
     let json = serde_json::to_string(&record).unwrap();
@@ -180,6 +182,8 @@ fn tool_call_record_serialization_includes_summary_when_present() {
         model: Some("openai/gpt-5.2".into()),
         token_usage: None,
         summary: Some(serde_json::json!({"attempt_index": 0, "response_id": "resp_123"})),
+        trace_id: None,
+        parent_call_id: None,
     };
 
     let json = serde_json::to_string(&record).unwrap();
@@ -13,6 +13,7 @@ use anthropic_async::types::tools::ToolChoice;
 use crate::error::MessageOptimizerError;
 use crate::prompts::OPTIMIZER_SYSTEM;
 use crate::types::ModelOutput;
+use crate::types::parse_model_output;
 
 pub const OPTIMIZER_MODEL: &str = "claude-sonnet-4-6";
 pub const TOOL_NAME: &str = "emit_optimized_prompt";
@@ -115,23 +116,3 @@ pub fn extract_model_output(
 
     parse_model_output(input)
 }
-
-pub fn parse_model_output(input: &serde_json::Value) -> Result<ModelOutput, MessageOptimizerError> {
-    let output: ModelOutput = serde_json::from_value(input.clone()).map_err(|error| {
-        MessageOptimizerError::OutputContract(format!("invalid tool payload: {error}"))
-    })?;
-
-    if output.system_prompt.trim().is_empty() {
-        return Err(MessageOptimizerError::OutputContract(
-            "system_prompt must not be empty".to_string(),
-        ));
-    }
-
-    if output.user_prompt.trim().is_empty() {
-        return Err(MessageOptimizerError::OutputContract(
-            "user_prompt must not be empty".to_string(),
-        ));
-    }
-
-    Ok(output)
-}
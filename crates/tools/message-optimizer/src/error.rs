@@ -5,6 +5,7 @@ pub enum MessageOptimizerError {
     #[error("message must not be empty")]
     EmptyMessage,
 
+    #[cfg(feature = "client")]
     #[error("anthropic api error: {0}")]
     Anthropic(#[from] anthropic_async::AnthropicError),
 
@@ -1,5 +1,7 @@
+#[cfg(feature = "client")]
 mod anthropic;
 mod error;
+#[cfg(feature = "client")]
 mod prompts;
 mod types;
 
@@ -7,20 +9,28 @@ pub use crate::error::MessageOptimizerError;
 pub use crate::types::OptimizeMessageRequest;
 pub use crate::types::OptimizedPrompt;
 
+#[cfg(feature = "client")]
 use crate::anthropic::AnthropicOptimizer;
+#[cfg(feature = "client")]
 use crate::anthropic::OptimizerBackend;
+#[cfg(feature = "client")]
 use crate::prompts::build_optimizer_user;
+#[cfg(feature = "client")]
 use crate::prompts::build_repair_user;
 use crate::types::ModelOutput;
+use crate::types::parse_model_output;
 
+#[cfg(feature = "client")]
 const MAX_ATTEMPTS: usize = 3;
 
+#[cfg(feature = "client")]
 pub async fn optimize_message(
     request: OptimizeMessageRequest,
 ) -> Result<OptimizedPrompt, MessageOptimizerError> {
     optimize_message_with_backend(&AnthropicOptimizer::new(), request).await
 }
 
+#[cfg(feature = "client")]
 pub(crate) async fn optimize_message_with_backend<B: OptimizerBackend>(
     backend: &B,
     request: OptimizeMessageRequest,
@@ -43,6 +53,15 @@ pub(crate) async fn optimize_message_with_backend<B: OptimizerBackend>(
     }
 }
 
+/// Parse the optimizer's tool payload (`system_prompt`, `user_prompt`) into an
+/// [`OptimizedPrompt`], enforcing the same output contract as `optimize_message`.
+pub fn parse_optimizer_output(
+    input: &serde_json::Value,
+) -> Result<OptimizedPrompt, MessageOptimizerError> {
+    parse_model_output(input).map(build_optimized_prompt)
+}
+
+#[cfg(feature = "client")]
 fn validate_request(request: &OptimizeMessageRequest) -> Result<(), MessageOptimizerError> {
     if request.message.trim().is_empty() {
         return Err(MessageOptimizerError::EmptyMessage);
@@ -69,7 +88,7 @@ pub(crate) fn assemble(system_prompt: &str, user_prompt: &str) -> String {
     )
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "client"))]
 mod tests {
     use std::sync::Mutex;
 
@@ -80,7 +99,7 @@ mod tests {
     use super::*;
     use crate::anthropic::TOOL_NAME;
     use crate::anthropic::extract_model_output;
-    use crate::anthropic::parse_model_output;
+    use crate::types::parse_model_output;
 
     struct StubBackend {
         responses: Mutex<Vec<Result<ModelOutput, MessageOptimizerError>>>,
@@ -171,6 +190,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_optimizer_output_assembles_prompt() {
+        let prompt = parse_optimizer_output(&serde_json::json!({
+            "system_prompt": "system",
+            "user_prompt": "user",
+        }))
+        .map_err(|error| error.to_string());
+
+        assert_eq!(
+            prompt,
+            Ok(OptimizedPrompt {
+                system_prompt: "system".to_string(),
+                user_prompt: "user".to_string(),
+                assembled_prompt: assemble("system", "user"),
+            })
+        );
+    }
+
     #[test]
     fn extract_model_output_rejects_missing_tool_call() {
         let response = response_with_content(vec![ContentBlock::Text {
@@ -2,6 +2,8 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::error::MessageOptimizerError;
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct OptimizeMessageRequest {
     pub message: String,
@@ -21,3 +23,23 @@ pub struct ModelOutput {
     pub system_prompt: String,
     pub user_prompt: String,
 }
+
+pub fn parse_model_output(input: &serde_json::Value) -> Result<ModelOutput, MessageOptimizerError> {
+    let output: ModelOutput = serde_json::from_value(input.clone()).map_err(|error| {
+        MessageOptimizerError::OutputContract(format!("invalid tool payload: {error}"))
+    })?;
+
+    if output.system_prompt.trim().is_empty() {
+        return Err(MessageOptimizerError::OutputContract(
+            "system_prompt must not be empty".to_string(),
+        ));
+    }
+
+    if output.user_prompt.trim().is_empty() {
+        return Err(MessageOptimizerError::OutputContract(
+            "user_prompt must not be empty".to_string(),
+        ));
+    }
+
+    Ok(output)
+}
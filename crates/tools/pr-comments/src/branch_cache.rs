@@ -0,0 +1,110 @@
+//! Cache of branch -> PR number lookups used when `pr_number` is omitted.
+//!
+//! Entries are keyed by `(owner, repo, branch)` and remember the HEAD commit they were
+//! resolved at. A lookup only hits when the branch and HEAD are unchanged, so switching
+//! branches or committing forces a fresh GitHub query. A TTL bounds staleness when a PR
+//! is closed and reopened without HEAD moving.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// How long a cached lookup stays valid even if HEAD does not move.
+pub const PR_LOOKUP_TTL: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone)]
+struct Entry {
+    head_sha: String,
+    pr_number: u64,
+    cached_at: Instant,
+}
+
+#[derive(Debug, Default)]
+pub struct PrNumberCache {
+    entries: Mutex<HashMap<(String, String, String), Entry>>,
+}
+
+impl PrNumberCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the cached PR number if it was resolved at the same HEAD within the TTL.
+    pub fn get(&self, owner: &str, repo: &str, branch: &str, head_sha: &str) -> Option<u64> {
+        self.get_at(owner, repo, branch, head_sha, Instant::now())
+    }
+
+    fn get_at(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        head_sha: &str,
+        now: Instant,
+    ) -> Option<u64> {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let key = (owner.to_string(), repo.to_string(), branch.to_string());
+        let entry = entries.get(&key)?;
+        if entry.head_sha == head_sha && now.duration_since(entry.cached_at) < PR_LOOKUP_TTL {
+            Some(entry.pr_number)
+        } else {
+            entries.remove(&key);
+            None
+        }
+    }
+
+    /// Record a successful lookup. Only positive results are cached so a newly opened
+    /// PR is picked up on the next call.
+    pub fn insert(&self, owner: &str, repo: &str, branch: &str, head_sha: &str, pr_number: u64) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        entries.insert(
+            (owner.to_string(), repo.to_string(), branch.to_string()),
+            Entry {
+                head_sha: head_sha.to_string(),
+                pr_number,
+                cached_at: Instant::now(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit_requires_same_branch_and_head() {
+        let cache = PrNumberCache::new();
+        cache.insert("o", "r", "feature", "sha1", 7);
+
+        assert_eq!(cache.get("o", "r", "feature", "sha1"), Some(7));
+        assert_eq!(cache.get("o", "r", "other", "sha1"), None);
+        assert_eq!(cache.get("o", "other", "feature", "sha1"), None);
+    }
+
+    #[test]
+    fn head_move_invalidates_entry() {
+        let cache = PrNumberCache::new();
+        cache.insert("o", "r", "feature", "sha1", 7);
+
+        assert_eq!(cache.get("o", "r", "feature", "sha2"), None);
+        // Entry was dropped; going back to the old HEAD does not resurrect it
+        assert_eq!(cache.get("o", "r", "feature", "sha1"), None);
+    }
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        let cache = PrNumberCache::new();
+        cache.insert("o", "r", "feature", "sha1", 7);
+
+        let later = Instant::now() + PR_LOOKUP_TTL + Duration::from_secs(1);
+        assert_eq!(cache.get_at("o", "r", "feature", "sha1", later), None);
+    }
+}
@@ -0,0 +1,242 @@
+//! Path, author, and age filters for `gh_get_comments`.
+//!
+//! Filters apply per thread so replies are never shown without their parent:
+//! - `path_glob` matches the file the thread is attached to
+//! - `author` keeps threads where that user wrote any comment
+//! - `updated_after` keeps threads with any comment updated at or after the cutoff
+//!
+//! The review comments endpoint only supports `since` server-side, which
+//! [`CommentFilter::since`] exposes; path and author are matched client-side.
+
+use crate::models::Thread;
+use anyhow::Result;
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::Utc;
+use globset::GlobBuilder;
+use globset::GlobMatcher;
+
+/// Parsed comment filters. The default filter matches every thread.
+#[derive(Debug, Clone, Default)]
+pub struct CommentFilter {
+    path: Option<GlobMatcher>,
+    author: Option<String>,
+    updated_after: Option<DateTime<Utc>>,
+    key: String,
+}
+
+impl CommentFilter {
+    /// Parse raw tool arguments. Blank values are treated as absent.
+    ///
+    /// `updated_after` accepts an RFC 3339 timestamp, a `YYYY-MM-DD` date (UTC
+    /// midnight), or a relative age such as `30m`, `24h`, `7d`, or `2w`.
+    pub fn parse(
+        path_glob: Option<&str>,
+        author: Option<&str>,
+        updated_after: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<Self> {
+        let path_glob = non_blank(path_glob);
+        let author = non_blank(author).map(|a| a.trim_start_matches('@').to_string());
+        let updated_after_raw = non_blank(updated_after);
+
+        let path = path_glob
+            .map(|pattern| {
+                GlobBuilder::new(pattern)
+                    .literal_separator(true)
+                    .build()
+                    .map(|g| g.compile_matcher())
+                    .map_err(|e| {
+                        anyhow::anyhow!("invalid argument: invalid path_glob '{pattern}': {e}")
+                    })
+            })
+            .transpose()?;
+        let updated_after = updated_after_raw
+            .map(|raw| parse_cutoff(raw, now))
+            .transpose()?;
+
+        let key = format!(
+            "{}|{}|{}",
+            path_glob.unwrap_or_default(),
+            author.as_deref().unwrap_or_default().to_lowercase(),
+            updated_after_raw.unwrap_or_default()
+        );
+
+        Ok(Self {
+            path,
+            author,
+            updated_after,
+            key,
+        })
+    }
+
+    /// Whether no filter is set.
+    pub fn is_empty(&self) -> bool {
+        self.path.is_none() && self.author.is_none() && self.updated_after.is_none()
+    }
+
+    /// Cutoff to send as the REST `since` parameter.
+    pub fn since(&self) -> Option<DateTime<Utc>> {
+        self.updated_after
+    }
+
+    /// Stable pagination-key fragment built from the raw arguments, so a relative
+    /// age keeps paging the same result set across calls.
+    pub fn cache_key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn matches(&self, thread: &Thread) -> bool {
+        if let Some(glob) = &self.path
+            && !glob.is_match(&thread.parent.path)
+        {
+            return false;
+        }
+        let mut comments = std::iter::once(&thread.parent).chain(&thread.replies);
+        if let Some(author) = &self.author
+            && !comments
+                .clone()
+                .any(|c| c.user.eq_ignore_ascii_case(author))
+        {
+            return false;
+        }
+        if let Some(cutoff) = self.updated_after
+            && !comments.any(|c| {
+                DateTime::parse_from_rfc3339(&c.updated_at)
+                    .is_ok_and(|updated| updated.with_timezone(&Utc) >= cutoff)
+            })
+        {
+            return false;
+        }
+        true
+    }
+}
+
+fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|v| !v.is_empty())
+}
+
+fn parse_cutoff(raw: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(ts) = DateTime::parse_from_rfc3339(raw) {
+        return Ok(ts.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(raw, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc());
+    }
+    let invalid = || {
+        anyhow::anyhow!(
+            "invalid argument: updated_after '{raw}' must be an RFC 3339 timestamp, a YYYY-MM-DD date, or an age like 24h/7d/2w"
+        )
+    };
+    let (amount, unit) = raw.split_at(raw.len() - raw.chars().last().map_or(0, char::len_utf8));
+    let amount: i64 = amount
+        .parse()
+        .ok()
+        .filter(|n| *n >= 0)
+        .ok_or_else(invalid)?;
+    let age = match unit {
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)?;
+    now.checked_sub_signed(age).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ReviewComment;
+
+    fn comment(id: u64, user: &str, path: &str, updated_at: &str) -> ReviewComment {
+        ReviewComment {
+            id,
+            user: user.into(),
+            is_bot: false,
+            body: String::new(),
+            path: path.into(),
+            line: None,
+            side: None,
+            created_at: updated_at.into(),
+            updated_at: updated_at.into(),
+            html_url: String::new(),
+            pull_request_review_id: None,
+            in_reply_to_id: None,
+        }
+    }
+
+    fn thread() -> Thread {
+        Thread {
+            parent: comment(1, "alice", "src/net/client.rs", "2026-01-01T00:00:00Z"),
+            replies: vec![comment(
+                2,
+                "Bob",
+                "src/net/client.rs",
+                "2026-03-01T12:00:00Z",
+            )],
+            is_resolved: false,
+        }
+    }
+
+    fn now() -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-02T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn filter(path: Option<&str>, author: Option<&str>, after: Option<&str>) -> CommentFilter {
+        CommentFilter::parse(path, author, after, now()).unwrap()
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let f = filter(None, Some("  "), None);
+        assert!(f.is_empty());
+        assert!(f.since().is_none());
+        assert!(f.matches(&thread()));
+    }
+
+    #[test]
+    fn path_glob_matches_thread_file() {
+        assert!(filter(Some("src/net/**"), None, None).matches(&thread()));
+        assert!(filter(Some("src/**/*.rs"), None, None).matches(&thread()));
+        assert!(!filter(Some("src/*.rs"), None, None).matches(&thread()));
+        assert!(!filter(Some("docs/**"), None, None).matches(&thread()));
+    }
+
+    #[test]
+    fn author_matches_any_comment_case_insensitively() {
+        assert!(filter(None, Some("@bob"), None).matches(&thread()));
+        assert!(filter(None, Some("ALICE"), None).matches(&thread()));
+        assert!(!filter(None, Some("carol"), None).matches(&thread()));
+    }
+
+    #[test]
+    fn updated_after_accepts_timestamps_dates_and_ages() {
+        assert!(filter(None, None, Some("2026-03-01T00:00:00Z")).matches(&thread()));
+        assert!(filter(None, None, Some("2026-03-01")).matches(&thread()));
+        assert!(!filter(None, None, Some("2026-03-02")).matches(&thread()));
+        assert!(filter(None, None, Some("2d")).matches(&thread()));
+        assert!(!filter(None, None, Some("12h")).matches(&thread()));
+
+        let f = filter(None, None, Some("1w"));
+        assert_eq!(f.since(), Some(now() - Duration::weeks(1)));
+        assert_eq!(f.cache_key(), "||1w");
+    }
+
+    #[test]
+    fn parse_rejects_bad_input() {
+        for bad in ["yesterday", "5y", "-3d", "d", "3é"] {
+            let err = CommentFilter::parse(None, None, Some(bad), now()).unwrap_err();
+            assert!(
+                err.to_string().starts_with("invalid argument"),
+                "{bad}: {err}"
+            );
+        }
+        let err = CommentFilter::parse(Some("src/["), None, None, now()).unwrap_err();
+        assert!(err.to_string().contains("path_glob"));
+    }
+}
@@ -0,0 +1,140 @@
+//! `ETag` cache for conditional GitHub REST requests.
+//!
+//! Responses that carry an `ETag` are stored per request URL. The next GET for the
+//! same URL sends `If-None-Match`; a `304 Not Modified` reply reuses the stored body.
+//! GitHub does not count 304s against the primary rate limit, so re-reading an
+//! unchanged PR costs neither quota nor the transfer of every comment page.
+//! The cache is bounded; the least recently used entry is evicted first.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+/// Maximum number of cached responses (one per paginated URL).
+pub const MAX_ENTRIES: usize = 512;
+
+#[derive(Debug, Clone)]
+struct Entry {
+    etag: String,
+    body: serde_json::Value,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct EtagCache {
+    entries: Mutex<HashMap<String, Entry>>,
+    clock: AtomicU64,
+    hits: AtomicU64,
+}
+
+impl EtagCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The validator to send as `If-None-Match` for `url`, if one is cached.
+    pub fn etag(&self, url: &str) -> Option<String> {
+        self.lock().get(url).map(|entry| entry.etag.clone())
+    }
+
+    /// The cached body for `url` after the server answered `304 Not Modified`.
+    pub fn revalidated(&self, url: &str) -> Option<serde_json::Value> {
+        let tick = self.tick();
+        let mut entries = self.lock();
+        let entry = entries.get_mut(url)?;
+        entry.last_used = tick;
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(entry.body.clone())
+    }
+
+    /// Remember a fresh `200` response for `url`.
+    pub fn store(&self, url: &str, etag: String, body: serde_json::Value) {
+        let tick = self.tick();
+        let mut entries = self.lock();
+        if !entries.contains_key(url)
+            && entries.len() >= MAX_ENTRIES
+            && let Some(oldest) = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone())
+        {
+            entries.remove(&oldest);
+        }
+        entries.insert(
+            url.to_string(),
+            Entry {
+                etag,
+                body,
+                last_used: tick,
+            },
+        );
+    }
+
+    /// Number of responses served from the cache after a 304.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn revalidated_returns_stored_body_and_counts_hit() {
+        let cache = EtagCache::new();
+        assert!(cache.etag("u").is_none());
+        assert!(cache.revalidated("u").is_none());
+
+        cache.store("u", "\"abc\"".into(), json!([1, 2]));
+        assert_eq!(cache.etag("u").as_deref(), Some("\"abc\""));
+        assert_eq!(cache.revalidated("u"), Some(json!([1, 2])));
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[test]
+    fn store_replaces_existing_entry() {
+        let cache = EtagCache::new();
+        cache.store("u", "\"v1\"".into(), json!(1));
+        cache.store("u", "\"v2\"".into(), json!(2));
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.etag("u").as_deref(), Some("\"v2\""));
+        assert_eq!(cache.revalidated("u"), Some(json!(2)));
+    }
+
+    #[test]
+    fn store_evicts_least_recently_used_when_full() {
+        let cache = EtagCache::new();
+        for i in 0..MAX_ENTRIES {
+            cache.store(&format!("u{i}"), format!("\"{i}\""), json!(i));
+        }
+        // Touch the oldest entry so u1 becomes the eviction candidate.
+        assert!(cache.revalidated("u0").is_some());
+
+        cache.store("new", "\"n\"".into(), json!("n"));
+        assert_eq!(cache.len(), MAX_ENTRIES);
+        assert!(cache.etag("u0").is_some());
+        assert!(cache.etag("u1").is_none());
+        assert!(cache.etag("new").is_some());
+    }
+}
@@ -7,9 +7,17 @@ pub struct GitInfo {
     pub owner: String,
     pub repo: String,
     pub current_branch: Option<String>,
+    /// Commit HEAD points at; used to invalidate cached branch lookups when HEAD moves.
+    pub head_sha: Option<String>,
 }
 
 pub fn get_git_info() -> Result<GitInfo> {
+    get_git_info_for_host(crate::host::GITHUB_COM)
+}
+
+/// Like [`get_git_info`], but expects `origin` to live on `host` (e.g. a GitHub
+/// Enterprise Server hostname).
+pub fn get_git_info_for_host(host: &str) -> Result<GitInfo> {
     let repo = Repository::discover(".").context("Not in a git repository")?;
 
     let remote = repo
@@ -18,33 +26,50 @@ pub fn get_git_info() -> Result<GitInfo> {
 
     let url = remote.url().context("Remote 'origin' has no URL")?;
 
-    let (owner, repo_name) = parse_github_url(url)?;
+    let (owner, repo_name) = parse_remote_url(url, host)?;
 
-    let current_branch = repo
-        .head()
-        .ok()
+    let head = repo.head().ok();
+    let current_branch = head
+        .as_ref()
         .and_then(|head| head.shorthand().map(String::from));
+    let head_sha = head
+        .as_ref()
+        .and_then(git2::Reference::target)
+        .map(|oid| oid.to_string());
 
     Ok(GitInfo {
         owner,
         repo: repo_name,
         current_branch,
+        head_sha,
     })
 }
 
 pub fn parse_github_url(url: &str) -> Result<(String, String)> {
+    parse_remote_url(url, crate::host::GITHUB_COM)
+}
+
+/// Parse `owner/repo` from an HTTPS or SSH remote URL on `host`.
+pub fn parse_remote_url(url: &str, host: &str) -> Result<(String, String)> {
     // Handle both HTTPS and SSH URLs
-    if url.starts_with("git@github.com:") {
+    if let Some(path) = url
+        .strip_prefix("git@")
+        .and_then(|rest| rest.strip_prefix(host))
+        .and_then(|rest| rest.strip_prefix(':'))
+    {
         // SSH format: git@github.com:owner/repo.git
-        let path = url.trim_start_matches("git@github.com:");
         parse_repo_path(path)
     } else if let Ok(parsed) = Url::parse(url) {
         // HTTPS format: https://github.com/owner/repo.git
-        if parsed.host_str() == Some("github.com") {
+        // (also ssh://git@host/owner/repo.git)
+        if parsed
+            .host_str()
+            .is_some_and(|h| h.eq_ignore_ascii_case(host))
+        {
             let path = parsed.path().trim_start_matches('/');
             parse_repo_path(path)
         } else {
-            anyhow::bail!("Not a GitHub URL: {url}")
+            anyhow::bail!("Not a {host} URL: {url}")
         }
     } else {
         anyhow::bail!("Invalid git remote URL: {url}")
@@ -85,4 +110,18 @@ mod tests {
             assert_eq!(repo, expected_repo);
         }
     }
+
+    #[test]
+    fn test_parse_enterprise_remote_urls() {
+        for url in [
+            "https://ghe.example.com/owner/repo.git",
+            "git@ghe.example.com:owner/repo.git",
+            "ssh://git@ghe.example.com/owner/repo",
+        ] {
+            let (owner, repo) = parse_remote_url(url, "ghe.example.com").unwrap();
+            assert_eq!((owner.as_str(), repo.as_str()), ("owner", "repo"));
+        }
+        assert!(parse_remote_url("https://github.com/owner/repo", "ghe.example.com").is_err());
+        assert!(parse_github_url("git@ghe.example.com:owner/repo.git").is_err());
+    }
 }
@@ -1,44 +1,118 @@
 use crate::OpenPrRefLookupResult;
+use crate::etag_cache::EtagCache;
+use crate::host::GitHubEndpoints;
+use crate::models::CheckRunSummary;
 use crate::models::CheckSuiteSummary;
+use crate::models::ChecksSummary;
+use crate::models::CommentKind;
 use crate::models::CommentSourceType;
+use crate::models::EditedComment;
 use crate::models::GraphQLResponse;
 use crate::models::IssueCommentSummary;
 use crate::models::MarkPullRequestReadyForReviewData;
 use crate::models::OpenPrRefData;
+use crate::models::PostedIssueComment;
+use crate::models::PostedReaction;
+use crate::models::PrDetails;
+use crate::models::PrFile;
 use crate::models::PrRef;
 use crate::models::PrSummary;
 use crate::models::PullRequestData;
 use crate::models::PullRequestReviewSummary;
+use crate::models::RateLimitStatus;
+use crate::models::Reaction;
+use crate::models::ResolvedThread;
 use crate::models::ReviewComment;
+use crate::models::ReviewEvent;
+use crate::models::ReviewInlineComment;
+use crate::models::ReviewThreadResolutionData;
+use crate::models::SubmittedReview;
 use crate::models::Thread;
+use crate::rate_limit::RateLimitTracker;
+use crate::rate_limit::RetryPolicy;
 use anyhow::Result;
 use octocrab::Octocrab;
+use octocrab::service::middleware::retry::RateLimitMetrics;
+use octocrab::service::middleware::retry::RetryConfig;
 use reqwest::header::ACCEPT;
 use reqwest::header::AUTHORIZATION;
+use reqwest::header::ETAG;
 use reqwest::header::HeaderMap;
 use reqwest::header::HeaderValue;
+use reqwest::header::IF_NONE_MATCH;
 use reqwest::header::USER_AGENT;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::sync::Arc;
 use std::time::Duration;
 
 const REST_PER_PAGE: usize = 100;
 
+#[derive(Clone)]
 pub struct GitHubClient {
     client: Octocrab,
     http: reqwest::Client,
     owner: String,
     repo: String,
     api_base_url: String,
+    graphql_url: String,
+    retry_policy: RetryPolicy,
+    rate_limits: Arc<RateLimitTracker>,
+    /// Conditional-request cache; shared by clones so repeated fetches revalidate.
+    etag_cache: Arc<EtagCache>,
 }
 
 impl GitHubClient {
+    /// Client for a repository on github.com.
     pub fn new(owner: String, repo: String, token: Option<String>) -> Result<Self> {
+        Self::with_endpoints(owner, repo, token, &GitHubEndpoints::default())
+    }
+
+    /// Client for a repository on the host described by `endpoints` (github.com or
+    /// GitHub Enterprise Server).
+    pub fn with_endpoints(
+        owner: String,
+        repo: String,
+        token: Option<String>,
+        endpoints: &GitHubEndpoints,
+    ) -> Result<Self> {
+        Self::with_retry_policy(
+            owner,
+            repo,
+            token,
+            endpoints,
+            RetryPolicy::default(),
+            Arc::new(RateLimitTracker::new()),
+        )
+    }
+
+    /// Client that retries rate-limited requests per `retry_policy` and records
+    /// observed quota in `rate_limits`.
+    pub fn with_retry_policy(
+        owner: String,
+        repo: String,
+        token: Option<String>,
+        endpoints: &GitHubEndpoints,
+        retry_policy: RetryPolicy,
+        rate_limits: Arc<RateLimitTracker>,
+    ) -> Result<Self> {
         let header_token = token.clone();
         let builder = Octocrab::builder()
+            .base_uri(endpoints.api_base_url.as_str())
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid GitHub API base URL {}: {e:?}",
+                    endpoints.api_base_url
+                )
+            })?
             .set_connect_timeout(Some(Duration::from_secs(10)))
             .set_read_timeout(Some(Duration::from_secs(30)))
-            .set_write_timeout(Some(Duration::from_secs(30)));
+            .set_write_timeout(Some(Duration::from_secs(30)))
+            .add_retry_config(RetryConfig::HandleRateLimits {
+                metrics: Arc::clone(&rate_limits) as Arc<dyn RateLimitMetrics>,
+                max_retries: retry_policy.max_retries as usize,
+                min_wait_seconds: 1,
+            });
 
         let builder = if let Some(token) = token {
             builder.personal_token(token)
@@ -73,12 +147,27 @@ impl GitHubClient {
             http,
             owner,
             repo,
-            api_base_url: "https://api.github.com".to_string(),
+            api_base_url: endpoints.api_base_url.clone(),
+            graphql_url: endpoints.graphql_url.clone(),
+            retry_policy,
+            rate_limits,
+            etag_cache: Arc::new(EtagCache::new()),
         })
     }
 
+    /// Latest GitHub API quota observed by this client's tracker.
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.rate_limits.snapshot()
+    }
+
+    /// Number of GET responses reused after a `304 Not Modified`.
+    pub fn etag_cache_hits(&self) -> u64 {
+        self.etag_cache.hits()
+    }
+
     #[cfg(test)]
     fn with_api_base_url(mut self, api_base_url: String) -> Self {
+        self.graphql_url = format!("{api_base_url}/graphql");
         self.api_base_url = api_base_url;
         self
     }
@@ -180,6 +269,46 @@ impl GitHubClient {
             .await
     }
 
+    pub async fn list_check_runs_for_ref(&self, sha: &str) -> Result<Vec<CheckRunSummary>> {
+        let base_path = format!(
+            "/repos/{}/{}/commits/{sha}/check-runs",
+            self.owner, self.repo
+        );
+        self.rest_get_paginated(&base_path, parse_check_runs_response)
+            .await
+    }
+
+    /// Head commit SHA of a PR.
+    pub async fn get_pr_head_sha(&self, pr_number: u64) -> Result<String> {
+        let value = self
+            .rest_get(&format!(
+                "/repos/{}/{}/pulls/{pr_number}",
+                self.owner, self.repo
+            ))
+            .await?;
+        value
+            .pointer("/head/sha")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Pull request response missing head.sha"))
+    }
+
+    /// Download the plain-text log for a GitHub Actions job (the check run ID of an
+    /// Actions check). GitHub answers with a redirect to short-lived storage.
+    pub async fn get_job_log(&self, job_id: u64) -> Result<String> {
+        let url = format!(
+            "{}/repos/{}/{}/actions/jobs/{job_id}/logs",
+            self.api_base_url, self.owner, self.repo
+        );
+        let response = self
+            .send(self.http.get(&url), "GitHub REST request")
+            .await?;
+        response
+            .text()
+            .await
+            .map_err(|e| anyhow::anyhow!("GitHub job log read failed: {e}"))
+    }
+
     pub async fn list_pull_request_reviews(
         &self,
         pr_number: u64,
@@ -192,6 +321,69 @@ impl GitHubClient {
             .await
     }
 
+    /// Fetch PR metadata along with a reviewer rollup and a check-suite summary for the head commit.
+    pub async fn get_pr_details(&self, pr_number: u64) -> Result<PrDetails> {
+        let value = self
+            .rest_get(&format!(
+                "/repos/{}/{}/pulls/{pr_number}",
+                self.owner, self.repo
+            ))
+            .await?;
+        let entry: PullRequestEntry = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Failed to parse pull request response: {e}"))?;
+
+        let reviews = self.list_pull_request_reviews(pr_number).await?;
+        let suites = self.list_check_suites_for_ref(&entry.head.sha).await?;
+
+        let requested: Vec<String> = entry
+            .requested_reviewers
+            .into_iter()
+            .map(|u| u.login)
+            .chain(
+                entry
+                    .requested_teams
+                    .into_iter()
+                    .map(|t| format!("team:{}", t.slug)),
+            )
+            .collect();
+
+        Ok(PrDetails {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            number: entry.number,
+            title: entry.title,
+            body: entry.body.unwrap_or_default(),
+            state: if entry.merged {
+                "merged".to_string()
+            } else {
+                entry.state
+            },
+            is_draft: entry.draft,
+            author: entry.user.map(|u| u.login).unwrap_or_default(),
+            url: entry.html_url,
+            base_ref: entry.base.ref_name,
+            head_ref: entry.head.ref_name,
+            head_sha: entry.head.sha,
+            additions: entry.additions,
+            deletions: entry.deletions,
+            changed_files: entry.changed_files,
+            commits: entry.commits,
+            mergeable: entry.mergeable,
+            reviewers: crate::models::summarize_reviewers(&reviews, &requested),
+            checks: ChecksSummary::from_suites(&suites),
+        })
+    }
+
+    /// List all files changed in a PR, including per-file patches.
+    pub async fn list_pr_files(&self, pr_number: u64) -> Result<Vec<PrFile>> {
+        let base_path = format!(
+            "/repos/{}/{}/pulls/{pr_number}/files",
+            self.owner, self.repo
+        );
+        self.rest_get_paginated(&base_path, parse_pr_files_response)
+            .await
+    }
+
     pub async fn list_issue_comments(&self, issue_number: u64) -> Result<Vec<IssueCommentSummary>> {
         let base_path = format!(
             "/repos/{}/{}/issues/{issue_number}/comments",
@@ -202,15 +394,44 @@ impl GitHubClient {
     }
 
     async fn rest_get_paginated<T, F>(&self, base_path: &str, parse_page: F) -> Result<Vec<T>>
+    where
+        F: Fn(serde_json::Value) -> Result<Vec<T>>,
+    {
+        self.rest_get_pages(base_path, parse_page, false).await
+    }
+
+    /// Like [`Self::rest_get_paginated`], but every page is revalidated through the
+    /// `ETag` cache so unchanged pages come back as cheap 304s.
+    async fn rest_get_paginated_conditional<T, F>(
+        &self,
+        base_path: &str,
+        parse_page: F,
+    ) -> Result<Vec<T>>
+    where
+        F: Fn(serde_json::Value) -> Result<Vec<T>>,
+    {
+        self.rest_get_pages(base_path, parse_page, true).await
+    }
+
+    async fn rest_get_pages<T, F>(
+        &self,
+        base_path: &str,
+        parse_page: F,
+        conditional: bool,
+    ) -> Result<Vec<T>>
     where
         F: Fn(serde_json::Value) -> Result<Vec<T>>,
     {
         let mut items = Vec::new();
         let mut page = 1u32;
         loop {
-            let value = self
-                .rest_get(&format!("{base_path}?page={page}&per_page={REST_PER_PAGE}"))
-                .await?;
+            let sep = if base_path.contains('?') { '&' } else { '?' };
+            let path = format!("{base_path}{sep}page={page}&per_page={REST_PER_PAGE}");
+            let value = if conditional {
+                self.rest_get_conditional(&path).await?
+            } else {
+                self.rest_get(&path).await?
+            };
             let page_items = parse_page(value)?;
             let page_len = page_items.len();
             items.extend(page_items);
@@ -225,36 +446,139 @@ impl GitHubClient {
     async fn rest_get(&self, path: &str) -> Result<serde_json::Value> {
         let url = format!("{}{path}", self.api_base_url);
         let response = self
-            .http
-            .get(&url)
-            .send()
+            .send(self.http.get(&url), "GitHub REST request")
+            .await?;
+        response
+            .json()
+            .await
+            .map_err(|e| anyhow::anyhow!("GitHub REST JSON parse failed: {e}"))
+    }
+
+    /// GET with `If-None-Match` when an `ETag` is cached for the URL; a 304 reuses the
+    /// cached body and a fresh 200 replaces it.
+    async fn rest_get_conditional(&self, path: &str) -> Result<serde_json::Value> {
+        let url = format!("{}{path}", self.api_base_url);
+        let mut request = self.http.get(&url);
+        if let Some(etag) = self.etag_cache.etag(&url) {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+        let response = self.send(request, "GitHub REST request").await?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(body) = self.etag_cache.revalidated(&url) {
+                tracing::debug!(url = %url, "GitHub ETag revalidated; reusing cached body");
+                return Ok(body);
+            }
+            // Entry was evicted between the lookup and the reply: fetch unconditionally.
+            return self.rest_get(path).await;
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body: serde_json::Value = response
+            .json()
             .await
-            .map_err(|e| anyhow::anyhow!("GitHub REST request failed: {e}"))?
-            .error_for_status()
-            .map_err(|e| anyhow::anyhow!("GitHub REST request failed: {e}"))?;
+            .map_err(|e| anyhow::anyhow!("GitHub REST JSON parse failed: {e}"))?;
+        if let Some(etag) = etag {
+            self.etag_cache.store(&url, etag, body.clone());
+        }
+        Ok(body)
+    }
+
+    async fn rest_post(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value> {
+        self.rest_send(reqwest::Method::POST, path, body).await
+    }
+
+    async fn rest_send(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}{path}", self.api_base_url);
+        let response = self
+            .send(
+                self.http.request(method, &url).json(body),
+                "GitHub REST request",
+            )
+            .await?;
         response
             .json()
             .await
             .map_err(|e| anyhow::anyhow!("GitHub REST JSON parse failed: {e}"))
     }
 
+    /// Send `request`, recording quota headers and retrying rate-limited responses
+    /// while the wait fits the retry policy. Other failures map to `"{label} failed: ..."`.
+    async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+        label: &str,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0u32;
+        loop {
+            let pending = request
+                .try_clone()
+                .ok_or_else(|| anyhow::anyhow!("internal: {label} body cannot be retried"))?;
+            let response = pending
+                .send()
+                .await
+                .map_err(|e| anyhow::anyhow!("{label} failed: {e}"))?;
+            self.rate_limits.record(response.headers());
+
+            let Err(status_err) = response.error_for_status_ref() else {
+                return Ok(response);
+            };
+            let status_err = status_err.to_string();
+            let status = response.status();
+            if status != reqwest::StatusCode::FORBIDDEN
+                && status != reqwest::StatusCode::TOO_MANY_REQUESTS
+            {
+                anyhow::bail!("{label} failed: {status_err}");
+            }
+
+            let headers = response.headers().clone();
+            let body = response.text().await.unwrap_or_default();
+            let now = crate::rate_limit::now_unix();
+            let Some((kind, wait)) =
+                crate::rate_limit::retry_delay(status, &headers, &body, attempt, now)
+            else {
+                anyhow::bail!("{label} failed: {status_err}");
+            };
+            if attempt >= self.retry_policy.max_retries || wait > self.retry_policy.max_wait {
+                return Err(crate::rate_limit::rate_limited_error(
+                    kind,
+                    label,
+                    wait,
+                    attempt,
+                    self.rate_limits.snapshot().as_ref(),
+                    now,
+                ));
+            }
+
+            tracing::warn!(
+                kind = kind.as_str(),
+                wait_secs = wait.as_secs(),
+                attempt,
+                "GitHub rate limit hit; backing off"
+            );
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+
     async fn graphql_post<T>(&self, query: &str, variables: serde_json::Value) -> Result<T>
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!("{}/graphql", self.api_base_url);
-        let response = self
-            .http
-            .post(&url)
-            .json(&serde_json::json!({
-                "query": query,
-                "variables": variables,
-            }))
-            .send()
-            .await
-            .map_err(|e| anyhow::anyhow!("GitHub GraphQL request failed: {e}"))?
-            .error_for_status()
-            .map_err(|e| anyhow::anyhow!("GitHub GraphQL request failed: {e}"))?;
+        let request = self.http.post(&self.graphql_url).json(&serde_json::json!({
+            "query": query,
+            "variables": variables,
+        }));
+        let response = self.send(request, "GitHub GraphQL request").await?;
 
         let body: GraphQLResponse<T> = response
             .json()
@@ -511,30 +835,7 @@ impl GitHubClient {
         &self,
         pr_number: u64,
     ) -> Result<HashMap<u64, bool>> {
-        let query = r"
-            query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
-                repository(owner: $owner, name: $repo) {
-                    pullRequest(number: $number) {
-                        reviewThreads(first: 100, after: $cursor) {
-                            nodes {
-                                id
-                                isResolved
-                                comments(first: 50) {
-                                    nodes {
-                                        id
-                                        databaseId
-                                    }
-                                }
-                            }
-                            pageInfo {
-                                hasNextPage
-                                endCursor
-                            }
-                        }
-                    }
-                }
-            }
-        ";
+        let query = REVIEW_THREADS_QUERY;
 
         let mut comment_resolution_map = HashMap::new();
         let mut cursor: Option<String> = None;
@@ -577,37 +878,108 @@ impl GitHubClient {
         Ok(comment_resolution_map)
     }
 
-    /// Fetch all review comments for a PR without complex filtering.
-    /// Returns all comments in API order.
-    pub async fn fetch_review_comments(&self, pr_number: u64) -> Result<Vec<ReviewComment>> {
-        let mut results = Vec::new();
-        let mut page = 1u32;
+    /// Find the GraphQL node ID of the review thread containing a review comment.
+    pub async fn find_review_thread_id(
+        &self,
+        pr_number: u64,
+        comment_id: u64,
+    ) -> Result<Option<String>> {
+        let mut cursor: Option<String> = None;
 
         loop {
-            let response = self
-                .client
-                .pulls(&self.owner, &self.repo)
-                .list_comments(Some(pr_number))
-                .page(page)
-                .per_page(100)
-                .send()
-                .await
-                .map_err(|e| {
-                    anyhow::anyhow!("Failed to fetch review comments for PR #{pr_number}: {e:?}")
-                })?;
-
-            if response.items.is_empty() {
-                break;
+            let variables = serde_json::json!({
+                "owner": self.owner,
+                "repo": self.repo,
+                "number": pr_number,
+                "cursor": cursor,
+            });
+            let response: PullRequestData =
+                self.graphql_post(REVIEW_THREADS_QUERY, variables).await?;
+            let threads = response.repository.pull_request.review_threads;
+
+            let found = threads.nodes.into_iter().find(|thread| {
+                thread
+                    .comments
+                    .nodes
+                    .iter()
+                    .any(|c| c.database_id == Some(comment_id))
+            });
+            if let Some(thread) = found {
+                return Ok(Some(thread.id));
             }
 
-            for raw in response.items {
-                results.push(ReviewComment::from(raw));
+            if !threads.page_info.has_next_page {
+                return Ok(None);
             }
-
-            page += 1;
+            cursor = threads.page_info.end_cursor;
         }
+    }
 
-        Ok(results)
+    /// Resolve or unresolve a review thread by its GraphQL node ID.
+    pub async fn set_review_thread_resolved(
+        &self,
+        thread_id: &str,
+        resolved: bool,
+    ) -> Result<ResolvedThread> {
+        let mutation = if resolved {
+            "resolveReviewThread"
+        } else {
+            "unresolveReviewThread"
+        };
+        let query = format!(
+            r"
+            mutation($threadId: ID!) {{
+                result: {mutation}(input: {{ threadId: $threadId }}) {{
+                    thread {{
+                        id
+                        isResolved
+                    }}
+                }}
+            }}
+        "
+        );
+        let variables = serde_json::json!({
+            "threadId": thread_id,
+        });
+        let response: ReviewThreadResolutionData = self.graphql_post(&query, variables).await?;
+
+        Ok(ResolvedThread {
+            thread_id: response.result.thread.id,
+            is_resolved: response.result.thread.is_resolved,
+        })
+    }
+
+    /// Fetch all review comments for a PR without complex filtering.
+    /// Returns all comments in API order. Pages are fetched conditionally, so
+    /// repeated calls for an unchanged PR are served from the `ETag` cache.
+    pub async fn fetch_review_comments(&self, pr_number: u64) -> Result<Vec<ReviewComment>> {
+        self.fetch_review_comments_since(pr_number, None).await
+    }
+
+    /// Fetch review comments, limited server-side to those updated at or after
+    /// `since` when given. Replies may come back without their (older) parent.
+    pub async fn fetch_review_comments_since(
+        &self,
+        pr_number: u64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<ReviewComment>> {
+        let query = since
+            .map(|since| {
+                format!(
+                    "?since={}",
+                    since.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+                )
+            })
+            .unwrap_or_default();
+        let base_path = format!(
+            "/repos/{}/{}/pulls/{pr_number}/comments{query}",
+            self.owner, self.repo
+        );
+        self.rest_get_paginated_conditional(&base_path, parse_review_comments_response)
+            .await
+            .map_err(|e| {
+                anyhow::anyhow!("Failed to fetch review comments for PR #{pr_number}: {e}")
+            })
     }
 
     /// Build threads from a flat list of comments.
@@ -667,6 +1039,148 @@ impl GitHubClient {
             .collect()
     }
 
+    /// Post a top-level (issue-style) comment on a PR.
+    pub async fn add_issue_comment(
+        &self,
+        pr_number: u64,
+        body: &str,
+    ) -> Result<PostedIssueComment> {
+        let path = format!(
+            "/repos/{}/{}/issues/{pr_number}/comments",
+            self.owner, self.repo
+        );
+        let value = self
+            .rest_post(&path, &serde_json::json!({ "body": body }))
+            .await?;
+        let entry: PostedCommentEntry = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Failed to parse issue comment response: {e}"))?;
+        Ok(PostedIssueComment {
+            id: entry.id,
+            pr_number,
+            user: entry.user.login,
+            body: entry.body,
+            html_url: entry.html_url,
+            created_at: entry.created_at,
+        })
+    }
+
+    /// Login of the user the token authenticates as.
+    pub async fn get_authenticated_login(&self) -> Result<String> {
+        let value = self.rest_get("/user").await?;
+        let user: ReviewUser = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Failed to parse authenticated user response: {e}"))?;
+        Ok(user.login)
+    }
+
+    /// Fetch a single review or issue comment, returning its author and body.
+    pub async fn get_comment(
+        &self,
+        kind: CommentKind,
+        comment_id: u64,
+    ) -> Result<(String, String)> {
+        let path = format!(
+            "/repos/{}/{}/{}/{comment_id}",
+            self.owner,
+            self.repo,
+            kind.api_collection()
+        );
+        let entry: PostedCommentEntry = serde_json::from_value(self.rest_get(&path).await?)
+            .map_err(|e| anyhow::anyhow!("Failed to parse comment response: {e}"))?;
+        Ok((entry.user.login, entry.body))
+    }
+
+    /// Add a reaction to a review or issue comment. GitHub returns the existing
+    /// reaction when the user has already reacted with the same content.
+    pub async fn add_comment_reaction(
+        &self,
+        kind: CommentKind,
+        comment_id: u64,
+        reaction: Reaction,
+    ) -> Result<PostedReaction> {
+        let path = format!(
+            "/repos/{}/{}/{}/{comment_id}/reactions",
+            self.owner,
+            self.repo,
+            kind.api_collection()
+        );
+        let value = self
+            .rest_post(
+                &path,
+                &serde_json::json!({ "content": reaction.as_api_str() }),
+            )
+            .await?;
+        let entry: ReactionEntry = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Failed to parse reaction response: {e}"))?;
+        Ok(PostedReaction {
+            id: entry.id,
+            comment_id,
+            comment_kind: kind,
+            content: entry.content,
+            user: entry.user.map(|u| u.login).unwrap_or_default(),
+        })
+    }
+
+    /// Replace the body of a review or issue comment.
+    pub async fn edit_comment(
+        &self,
+        kind: CommentKind,
+        comment_id: u64,
+        body: &str,
+    ) -> Result<EditedComment> {
+        let path = format!(
+            "/repos/{}/{}/{}/{comment_id}",
+            self.owner,
+            self.repo,
+            kind.api_collection()
+        );
+        let value = self
+            .rest_send(
+                reqwest::Method::PATCH,
+                &path,
+                &serde_json::json!({ "body": body }),
+            )
+            .await?;
+        let entry: EditedCommentEntry = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Failed to parse edited comment response: {e}"))?;
+        Ok(EditedComment {
+            id: entry.id,
+            kind,
+            user: entry.user.login,
+            body: entry.body,
+            html_url: entry.html_url,
+            updated_at: entry.updated_at,
+        })
+    }
+
+    /// Submit a review with an optional body and new inline comments.
+    pub async fn submit_review(
+        &self,
+        pr_number: u64,
+        event: ReviewEvent,
+        body: Option<&str>,
+        comments: &[ReviewInlineComment],
+    ) -> Result<SubmittedReview> {
+        let path = format!(
+            "/repos/{}/{}/pulls/{pr_number}/reviews",
+            self.owner, self.repo
+        );
+        let value = self
+            .rest_post(&path, &review_request_body(event, body, comments))
+            .await?;
+        let entry: SubmittedReviewEntry = serde_json::from_value(value)
+            .map_err(|e| anyhow::anyhow!("Failed to parse review response: {e}"))?;
+        Ok(SubmittedReview {
+            id: entry.id,
+            pr_number,
+            user: entry.user.login,
+            state: entry.state,
+            body: entry.body.unwrap_or_default(),
+            html_url: entry.html_url,
+            submitted_at: entry.submitted_at,
+            inline_comments: comments.len(),
+        })
+    }
+
     /// Reply to an existing review comment on a PR.
     /// Returns the created comment.
     pub async fn reply_to_comment(
@@ -707,6 +1221,30 @@ struct CheckSuiteApp {
     slug: Option<String>,
 }
 
+#[derive(serde::Deserialize)]
+struct CheckRunsEnvelope {
+    check_runs: Vec<CheckRunEntry>,
+}
+
+#[derive(serde::Deserialize)]
+struct CheckRunEntry {
+    id: u64,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    started_at: Option<String>,
+    completed_at: Option<String>,
+    html_url: Option<String>,
+    app: Option<CheckSuiteApp>,
+    #[serde(default)]
+    output: Option<CheckRunOutput>,
+}
+
+#[derive(serde::Deserialize)]
+struct CheckRunOutput {
+    title: Option<String>,
+}
+
 #[derive(serde::Deserialize)]
 struct ReviewEntry {
     id: u64,
@@ -724,6 +1262,63 @@ struct ReviewUser {
     user_type: Option<String>,
 }
 
+#[derive(serde::Deserialize)]
+struct PullRequestEntry {
+    number: u64,
+    title: String,
+    #[serde(default)]
+    body: Option<String>,
+    state: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    merged: bool,
+    #[serde(default)]
+    mergeable: Option<bool>,
+    html_url: String,
+    user: Option<ReviewUser>,
+    base: PullRequestBranch,
+    head: PullRequestBranch,
+    #[serde(default)]
+    requested_reviewers: Vec<ReviewUser>,
+    #[serde(default)]
+    requested_teams: Vec<RequestedTeam>,
+    #[serde(default)]
+    additions: u64,
+    #[serde(default)]
+    deletions: u64,
+    #[serde(default)]
+    changed_files: u64,
+    #[serde(default)]
+    commits: u64,
+}
+
+#[derive(serde::Deserialize)]
+struct PullRequestBranch {
+    #[serde(rename = "ref")]
+    ref_name: String,
+    sha: String,
+}
+
+#[derive(serde::Deserialize)]
+struct RequestedTeam {
+    slug: String,
+}
+
+#[derive(serde::Deserialize)]
+struct PrFileEntry {
+    filename: String,
+    status: String,
+    #[serde(default)]
+    additions: u64,
+    #[serde(default)]
+    deletions: u64,
+    #[serde(default)]
+    previous_filename: Option<String>,
+    #[serde(default)]
+    patch: Option<String>,
+}
+
 #[derive(serde::Deserialize)]
 struct IssueCommentEntry {
     id: u64,
@@ -732,14 +1327,109 @@ struct IssueCommentEntry {
     user: ReviewUser,
 }
 
-fn parse_check_suites_response(value: serde_json::Value) -> Result<Vec<CheckSuiteSummary>> {
-    let envelope: CheckSuitesEnvelope = serde_json::from_value(value)
-        .map_err(|e| anyhow::anyhow!("Failed to parse check suites response: {e}"))?;
-    Ok(envelope
-        .check_suites
-        .into_iter()
-        .map(|suite| CheckSuiteSummary {
-            id: suite.id,
+#[derive(serde::Deserialize)]
+struct PostedCommentEntry {
+    id: u64,
+    body: String,
+    html_url: String,
+    created_at: String,
+    user: ReviewUser,
+}
+
+#[derive(serde::Deserialize)]
+struct EditedCommentEntry {
+    id: u64,
+    body: String,
+    html_url: String,
+    updated_at: String,
+    user: ReviewUser,
+}
+
+#[derive(serde::Deserialize)]
+struct ReactionEntry {
+    id: u64,
+    content: String,
+    user: Option<ReviewUser>,
+}
+
+#[derive(serde::Deserialize)]
+struct SubmittedReviewEntry {
+    id: u64,
+    state: String,
+    #[serde(default)]
+    body: Option<String>,
+    html_url: String,
+    submitted_at: Option<String>,
+    user: ReviewUser,
+}
+
+/// Build the JSON body for `POST /pulls/{n}/reviews`.
+const REVIEW_THREADS_QUERY: &str = r"
+    query($owner: String!, $repo: String!, $number: Int!, $cursor: String) {
+        repository(owner: $owner, name: $repo) {
+            pullRequest(number: $number) {
+                reviewThreads(first: 100, after: $cursor) {
+                    nodes {
+                        id
+                        isResolved
+                        comments(first: 50) {
+                            nodes {
+                                id
+                                databaseId
+                            }
+                        }
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        }
+    }
+";
+
+fn review_request_body(
+    event: ReviewEvent,
+    body: Option<&str>,
+    comments: &[ReviewInlineComment],
+) -> serde_json::Value {
+    let comments: Vec<serde_json::Value> = comments
+        .iter()
+        .map(|c| {
+            let side = c.side.as_deref().unwrap_or("RIGHT");
+            let mut v = serde_json::json!({
+                "path": c.path,
+                "line": c.line,
+                "side": side,
+                "body": c.body,
+            });
+            if let Some(start) = c.start_line {
+                v["start_line"] = start.into();
+                v["start_side"] = side.into();
+            }
+            v
+        })
+        .collect();
+
+    let mut req = serde_json::json!({
+        "event": event.as_api_str(),
+        "comments": comments,
+    });
+    if let Some(body) = body {
+        req["body"] = body.into();
+    }
+    req
+}
+
+fn parse_check_suites_response(value: serde_json::Value) -> Result<Vec<CheckSuiteSummary>> {
+    let envelope: CheckSuitesEnvelope = serde_json::from_value(value)
+        .map_err(|e| anyhow::anyhow!("Failed to parse check suites response: {e}"))?;
+    Ok(envelope
+        .check_suites
+        .into_iter()
+        .map(|suite| CheckSuiteSummary {
+            id: suite.id,
             status: suite.status,
             conclusion: suite.conclusion,
             app_slug: suite.app.and_then(|app| app.slug),
@@ -748,6 +1438,26 @@ fn parse_check_suites_response(value: serde_json::Value) -> Result<Vec<CheckSuit
         .collect())
 }
 
+fn parse_check_runs_response(value: serde_json::Value) -> Result<Vec<CheckRunSummary>> {
+    let envelope: CheckRunsEnvelope = serde_json::from_value(value)
+        .map_err(|e| anyhow::anyhow!("Failed to parse check runs response: {e}"))?;
+    Ok(envelope
+        .check_runs
+        .into_iter()
+        .map(|run| CheckRunSummary {
+            id: run.id,
+            name: run.name,
+            status: run.status,
+            conclusion: run.conclusion,
+            app_slug: run.app.and_then(|app| app.slug),
+            started_at: run.started_at,
+            completed_at: run.completed_at,
+            html_url: run.html_url,
+            output_title: run.output.and_then(|o| o.title),
+        })
+        .collect())
+}
+
 fn parse_reviews_response(value: serde_json::Value) -> Result<Vec<PullRequestReviewSummary>> {
     let entries: Vec<ReviewEntry> = serde_json::from_value(value)
         .map_err(|e| anyhow::anyhow!("Failed to parse reviews response: {e}"))?;
@@ -764,6 +1474,12 @@ fn parse_reviews_response(value: serde_json::Value) -> Result<Vec<PullRequestRev
         .collect())
 }
 
+fn parse_review_comments_response(value: serde_json::Value) -> Result<Vec<ReviewComment>> {
+    let entries: Vec<octocrab::models::pulls::Comment> = serde_json::from_value(value)
+        .map_err(|e| anyhow::anyhow!("Failed to parse review comments response: {e}"))?;
+    Ok(entries.into_iter().map(ReviewComment::from).collect())
+}
+
 fn parse_issue_comments_response(value: serde_json::Value) -> Result<Vec<IssueCommentSummary>> {
     let entries: Vec<IssueCommentEntry> = serde_json::from_value(value)
         .map_err(|e| anyhow::anyhow!("Failed to parse issue comments response: {e}"))?;
@@ -779,12 +1495,32 @@ fn parse_issue_comments_response(value: serde_json::Value) -> Result<Vec<IssueCo
         .collect())
 }
 
+fn parse_pr_files_response(value: serde_json::Value) -> Result<Vec<PrFile>> {
+    let entries: Vec<PrFileEntry> = serde_json::from_value(value)
+        .map_err(|e| anyhow::anyhow!("Failed to parse PR files response: {e}"))?;
+    Ok(entries
+        .into_iter()
+        .map(|entry| PrFile {
+            filename: entry.filename,
+            status: entry.status,
+            additions: entry.additions,
+            deletions: entry.deletions,
+            previous_filename: entry.previous_filename,
+            patch: entry.patch,
+        })
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::GitHubClient;
     use super::REST_PER_PAGE;
     use super::parse_open_pr_ref_lookup_response;
+    use crate::models::CommentKind;
     use crate::models::OpenPrRefData;
+    use crate::models::Reaction;
+    use crate::models::ReviewEvent;
+    use crate::models::ReviewInlineComment;
     use mockito::Matcher;
     use serde_json::json;
     use std::sync::Once;
@@ -852,6 +1588,33 @@ mod tests {
         )
     }
 
+    fn review_comments_response(count: usize) -> serde_json::Value {
+        json!(
+            (0..count)
+                .map(|index| json!({
+                    "url": format!("https://api.github.com/repos/owner/repo/pulls/comments/{}", index + 1),
+                    "pull_request_review_id": 10,
+                    "id": index + 1,
+                    "node_id": format!("PRRC_{index}"),
+                    "diff_hunk": "@@ -1 +1 @@",
+                    "path": "src/lib.rs",
+                    "position": 1,
+                    "original_position": 1,
+                    "commit_id": "sha",
+                    "original_commit_id": "sha",
+                    "user": null,
+                    "body": format!("review comment {index}"),
+                    "created_at": "2026-01-01T00:00:00Z",
+                    "updated_at": "2026-01-01T00:00:00Z",
+                    "html_url": format!("https://github.com/owner/repo/pull/3#discussion_r{}", index + 1),
+                    "_links": {},
+                    "line": 1,
+                    "side": "RIGHT"
+                }))
+                .collect::<Vec<_>>()
+        )
+    }
+
     #[tokio::test]
     async fn list_check_suites_for_ref_aggregates_multiple_pages() {
         let mut server = mockito::Server::new_async().await;
@@ -955,6 +1718,105 @@ mod tests {
         page2.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn fetch_review_comments_revalidates_with_etag() {
+        let mut server = mockito::Server::new_async().await;
+        let fresh = server
+            .mock("GET", "/repos/owner/repo/pulls/3/comments")
+            .match_query(Matcher::Any)
+            .match_header("if-none-match", Matcher::Missing)
+            .with_status(200)
+            .with_header("etag", "\"v1\"")
+            .with_body(review_comments_response(2).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let not_modified = server
+            .mock("GET", "/repos/owner/repo/pulls/3/comments")
+            .match_query(Matcher::Any)
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = client(server.url());
+        let first = client
+            .fetch_review_comments(3)
+            .await
+            .expect("initial fetch should succeed");
+        let second = client
+            .clone()
+            .fetch_review_comments(3)
+            .await
+            .expect("conditional fetch should succeed");
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 2);
+        assert_eq!(second[1].body, "review comment 1");
+        assert_eq!(client.etag_cache_hits(), 1);
+        fresh.assert_async().await;
+        not_modified.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn fetch_review_comments_since_sends_since_query() {
+        let mut server = mockito::Server::new_async().await;
+        let recent = server
+            .mock("GET", "/repos/owner/repo/pulls/3/comments")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("since".into(), "2026-03-01T00:00:00Z".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("per_page".into(), REST_PER_PAGE.to_string()),
+            ]))
+            .with_status(200)
+            .with_body(review_comments_response(1).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let since = chrono::DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        let comments = client(server.url())
+            .fetch_review_comments_since(3, Some(since))
+            .await
+            .expect("since fetch should succeed");
+
+        assert_eq!(comments.len(), 1);
+        recent.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn fetch_review_comments_refreshes_cache_on_changed_etag() {
+        let mut server = mockito::Server::new_async().await;
+        let fresh = server
+            .mock("GET", "/repos/owner/repo/pulls/3/comments")
+            .match_query(Matcher::Any)
+            .match_header("if-none-match", Matcher::Missing)
+            .with_status(200)
+            .with_header("etag", "\"v1\"")
+            .with_body(review_comments_response(1).to_string())
+            .create_async()
+            .await;
+        let changed = server
+            .mock("GET", "/repos/owner/repo/pulls/3/comments")
+            .match_query(Matcher::Any)
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(200)
+            .with_header("etag", "\"v2\"")
+            .with_body(review_comments_response(3).to_string())
+            .create_async()
+            .await;
+
+        let client = client(server.url());
+        assert_eq!(client.fetch_review_comments(3).await.unwrap().len(), 1);
+        assert_eq!(client.fetch_review_comments(3).await.unwrap().len(), 3);
+        assert_eq!(client.etag_cache_hits(), 0);
+        fresh.assert_async().await;
+        changed.assert_async().await;
+    }
+
     #[tokio::test]
     async fn rest_get_reports_http_status_failures() {
         let mut server = mockito::Server::new_async().await;
@@ -981,6 +1843,68 @@ mod tests {
         mock.assert_async().await;
     }
 
+    #[tokio::test]
+    async fn rest_get_retries_secondary_rate_limit_and_records_quota() {
+        let mut server = mockito::Server::new_async().await;
+        let limited = server
+            .mock("GET", "/repos/owner/repo/pulls/7")
+            .with_status(403)
+            .with_header("retry-after", "0")
+            .with_body(r#"{"message":"You have exceeded a secondary rate limit."}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/repos/owner/repo/pulls/7")
+            .with_status(200)
+            .with_header("x-ratelimit-limit", "5000")
+            .with_header("x-ratelimit-remaining", "4321")
+            .with_header("x-ratelimit-reset", "1700000000")
+            .with_body(json!({ "head": { "sha": "abc" } }).to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let gh = client(server.url());
+        let sha = gh.get_pr_head_sha(7).await.expect("retry should succeed");
+
+        assert_eq!(sha, "abc");
+        let status = gh.rate_limit_status().expect("quota should be recorded");
+        assert_eq!(status.resource, "core");
+        assert_eq!(status.remaining, 4321);
+        limited.assert_async().await;
+        ok.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn rest_get_fails_fast_when_quota_reset_exceeds_max_wait() {
+        let mut server = mockito::Server::new_async().await;
+        let reset = crate::rate_limit::now_unix() + 3600;
+        let mock = server
+            .mock("GET", "/repos/owner/repo/pulls/7")
+            .with_status(403)
+            .with_header("x-ratelimit-limit", "5000")
+            .with_header("x-ratelimit-remaining", "0")
+            .with_header("x-ratelimit-reset", &reset.to_string())
+            .with_body(r#"{"message":"API rate limit exceeded"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let err = client(server.url())
+            .get_pr_head_sha(7)
+            .await
+            .expect_err("exhausted quota should not be waited out");
+
+        let msg = err.to_string();
+        assert!(
+            msg.starts_with("rate limited: GitHub primary rate limit"),
+            "unexpected error: {msg}"
+        );
+        assert!(msg.contains("0/5000 remaining"), "unexpected error: {msg}");
+        mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn rest_get_reports_json_parse_failures() {
         let mut server = mockito::Server::new_async().await;
@@ -1207,6 +2131,489 @@ mod tests {
         assert!(!pr.is_draft);
         mock.assert_async().await;
     }
+
+    #[tokio::test]
+    async fn set_review_thread_resolved_posts_resolve_and_unresolve_mutations() {
+        let mut server = mockito::Server::new_async().await;
+        let resolve = server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex("resolveReviewThread".into()),
+                Matcher::PartialJson(json!({ "variables": { "threadId": "PRRT_1" } })),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": { "result": { "thread": { "id": "PRRT_1", "isResolved": true } } }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let resolved = client(server.url())
+            .set_review_thread_resolved("PRRT_1", true)
+            .await
+            .expect("resolve should succeed");
+        assert_eq!(resolved.thread_id, "PRRT_1");
+        assert!(resolved.is_resolved);
+        resolve.assert_async().await;
+
+        let unresolve = server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::Regex("unresolveReviewThread".into()))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": { "result": { "thread": { "id": "PRRT_1", "isResolved": false } } }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let reopened = client(server.url())
+            .set_review_thread_resolved("PRRT_1", false)
+            .await
+            .expect("unresolve should succeed");
+        assert!(!reopened.is_resolved);
+        unresolve.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn find_review_thread_id_matches_comment_database_id() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_body(Matcher::PartialJson(json!({ "variables": { "number": 9 } })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "data": {
+                        "repository": {
+                            "pullRequest": {
+                                "reviewThreads": {
+                                    "nodes": [
+                                        {
+                                            "id": "PRRT_a",
+                                            "isResolved": false,
+                                            "comments": { "nodes": [{ "id": "c1", "databaseId": 100 }] }
+                                        },
+                                        {
+                                            "id": "PRRT_b",
+                                            "isResolved": false,
+                                            "comments": { "nodes": [
+                                                { "id": "c2", "databaseId": 200 },
+                                                { "id": "c3", "databaseId": 201 }
+                                            ] }
+                                        }
+                                    ],
+                                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                                }
+                            }
+                        }
+                    }
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create_async()
+            .await;
+
+        let gh = client(server.url());
+        assert_eq!(
+            gh.find_review_thread_id(9, 201).await.expect("lookup"),
+            Some("PRRT_b".to_string())
+        );
+        assert_eq!(
+            gh.find_review_thread_id(9, 999).await.expect("lookup"),
+            None
+        );
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn add_issue_comment_posts_body_to_issue_comments() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/repos/owner/repo/issues/5/comments")
+            .match_body(Matcher::Json(json!({ "body": "hello" })))
+            .with_status(201)
+            .with_body(
+                json!({
+                    "id": 77,
+                    "body": "hello",
+                    "html_url": "https://example.invalid/pr/5#issuecomment-77",
+                    "created_at": "2026-01-01T00:00:00Z",
+                    "user": { "login": "bot", "type": "Bot" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let posted = client(server.url())
+            .add_issue_comment(5, "hello")
+            .await
+            .expect("comment should post");
+
+        assert_eq!(posted.id, 77);
+        assert_eq!(posted.pr_number, 5);
+        assert_eq!(posted.user, "bot");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn add_comment_reaction_posts_content_to_comment_collection() {
+        let mut server = mockito::Server::new_async().await;
+        let review = server
+            .mock("POST", "/repos/owner/repo/pulls/comments/42/reactions")
+            .match_body(Matcher::Json(json!({ "content": "+1" })))
+            .with_status(201)
+            .with_body(json!({ "id": 1, "content": "+1", "user": { "login": "me" } }).to_string())
+            .create_async()
+            .await;
+        let issue = server
+            .mock("POST", "/repos/owner/repo/issues/comments/43/reactions")
+            .match_body(Matcher::Json(json!({ "content": "eyes" })))
+            .with_status(200)
+            .with_body(json!({ "id": 2, "content": "eyes", "user": { "login": "me" } }).to_string())
+            .create_async()
+            .await;
+
+        let gh = client(server.url());
+        let posted = gh
+            .add_comment_reaction(CommentKind::Review, 42, Reaction::ThumbsUp)
+            .await
+            .expect("reaction should post");
+        assert_eq!(posted.comment_id, 42);
+        assert_eq!(posted.content, "+1");
+        let posted = gh
+            .add_comment_reaction(CommentKind::Issue, 43, Reaction::Eyes)
+            .await
+            .expect("reaction should post");
+        assert_eq!(posted.comment_kind, CommentKind::Issue);
+        assert_eq!(posted.user, "me");
+
+        review.assert_async().await;
+        issue.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn edit_comment_patches_body() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("PATCH", "/repos/owner/repo/issues/comments/77")
+            .match_body(Matcher::Json(json!({ "body": "updated" })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "id": 77,
+                    "body": "updated",
+                    "html_url": "https://example.invalid/pr/5#issuecomment-77",
+                    "updated_at": "2026-01-02T00:00:00Z",
+                    "user": { "login": "me", "type": "User" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let edited = client(server.url())
+            .edit_comment(CommentKind::Issue, 77, "updated")
+            .await
+            .expect("edit should succeed");
+
+        assert_eq!(edited.body, "updated");
+        assert_eq!(edited.kind, CommentKind::Issue);
+        assert_eq!(edited.updated_at, "2026-01-02T00:00:00Z");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn submit_review_posts_event_body_and_inline_comments() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/repos/owner/repo/pulls/5/reviews")
+            .match_body(Matcher::Json(json!({
+                "event": "REQUEST_CHANGES",
+                "body": "please fix",
+                "comments": [
+                    { "path": "src/a.rs", "line": 10, "side": "RIGHT", "body": "bug" },
+                    {
+                        "path": "src/b.rs",
+                        "line": 4,
+                        "side": "LEFT",
+                        "body": "range",
+                        "start_line": 2,
+                        "start_side": "LEFT"
+                    }
+                ]
+            })))
+            .with_status(200)
+            .with_body(
+                json!({
+                    "id": 9,
+                    "state": "CHANGES_REQUESTED",
+                    "body": "please fix",
+                    "html_url": "https://example.invalid/pr/5#pullrequestreview-9",
+                    "submitted_at": "2026-01-01T00:00:00Z",
+                    "user": { "login": "bot", "type": "Bot" }
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let comments = vec![
+            ReviewInlineComment {
+                path: "src/a.rs".into(),
+                line: 10,
+                start_line: None,
+                side: None,
+                body: "bug".into(),
+            },
+            ReviewInlineComment {
+                path: "src/b.rs".into(),
+                line: 4,
+                start_line: Some(2),
+                side: Some("LEFT".into()),
+                body: "range".into(),
+            },
+        ];
+        let review = client(server.url())
+            .submit_review(
+                5,
+                ReviewEvent::RequestChanges,
+                Some("please fix"),
+                &comments,
+            )
+            .await
+            .expect("review should submit");
+
+        assert_eq!(review.id, 9);
+        assert_eq!(review.state, "CHANGES_REQUESTED");
+        assert_eq!(review.inline_comments, 2);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_pr_details_combines_pr_reviews_and_check_suites() {
+        let mut server = mockito::Server::new_async().await;
+        let pr_mock = server
+            .mock("GET", "/repos/owner/repo/pulls/8")
+            .with_status(200)
+            .with_body(
+                json!({
+                    "number": 8,
+                    "title": "Add feature",
+                    "body": "Details",
+                    "state": "open",
+                    "draft": false,
+                    "merged": false,
+                    "mergeable": true,
+                    "html_url": "https://example.invalid/pr/8",
+                    "user": { "login": "author", "type": "User" },
+                    "base": { "ref": "main", "sha": "base000" },
+                    "head": { "ref": "feature", "sha": "abc1234def" },
+                    "requested_reviewers": [{ "login": "bob", "type": "User" }],
+                    "requested_teams": [{ "slug": "core" }],
+                    "additions": 10,
+                    "deletions": 2,
+                    "changed_files": 3,
+                    "commits": 2
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let reviews_mock = server
+            .mock("GET", "/repos/owner/repo/pulls/8/reviews")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!([{
+                    "id": 1,
+                    "state": "APPROVED",
+                    "submitted_at": "2026-01-01T00:00:00Z",
+                    "user": { "login": "alice", "type": "User" }
+                }])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let suites_mock = server
+            .mock("GET", "/repos/owner/repo/commits/abc1234def/check-suites")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "check_suites": [{
+                        "id": 5,
+                        "status": "completed",
+                        "conclusion": "success",
+                        "app": { "slug": "ci" },
+                        "updated_at": "2026-01-01T00:00:00Z"
+                    }]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let details = client(server.url())
+            .get_pr_details(8)
+            .await
+            .expect("details should load");
+
+        assert_eq!(details.title, "Add feature");
+        assert_eq!(details.base_ref, "main");
+        assert_eq!(details.head_ref, "feature");
+        assert_eq!(details.mergeable, Some(true));
+        let reviewers: Vec<_> = details
+            .reviewers
+            .iter()
+            .map(|r| format!("{}={}", r.login, r.state))
+            .collect();
+        assert_eq!(
+            reviewers,
+            vec!["alice=APPROVED", "bob=REQUESTED", "team:core=REQUESTED"]
+        );
+        assert_eq!(details.checks.total, 1);
+        assert_eq!(details.checks.passed, 1);
+        pr_mock.assert_async().await;
+        reviews_mock.assert_async().await;
+        suites_mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn list_pr_files_parses_status_counts_and_patch() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/repos/owner/repo/pulls/8/files")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("per_page".into(), REST_PER_PAGE.to_string()),
+            ]))
+            .with_status(200)
+            .with_body(
+                json!([
+                    {
+                        "filename": "src/new.rs",
+                        "status": "renamed",
+                        "additions": 1,
+                        "deletions": 1,
+                        "changes": 2,
+                        "previous_filename": "src/old.rs",
+                        "patch": "@@ -1 +1 @@\n-a\n+b"
+                    },
+                    {
+                        "filename": "logo.png",
+                        "status": "added",
+                        "additions": 0,
+                        "deletions": 0,
+                        "changes": 0
+                    }
+                ])
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let files = client(server.url())
+            .list_pr_files(8)
+            .await
+            .expect("files should load");
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].previous_filename.as_deref(), Some("src/old.rs"));
+        assert!(files[0].patch.is_some());
+        assert_eq!(files[1].status, "added");
+        assert!(files[1].patch.is_none());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn list_check_runs_for_ref_parses_runs() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/repos/owner/repo/commits/abc/check-runs")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_body(
+                json!({
+                    "total_count": 1,
+                    "check_runs": [{
+                        "id": 42,
+                        "name": "test",
+                        "status": "completed",
+                        "conclusion": "failure",
+                        "started_at": "2026-01-01T00:00:00Z",
+                        "completed_at": "2026-01-01T00:05:00Z",
+                        "html_url": "https://example.invalid/runs/42",
+                        "app": { "slug": "github-actions" },
+                        "output": { "title": "2 tests failed", "summary": null }
+                    }]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let runs = client(server.url())
+            .list_check_runs_for_ref("abc")
+            .await
+            .expect("check runs should load");
+
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].id, 42);
+        assert_eq!(runs[0].conclusion.as_deref(), Some("failure"));
+        assert_eq!(runs[0].app_slug.as_deref(), Some("github-actions"));
+        assert_eq!(runs[0].output_title.as_deref(), Some("2 tests failed"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn get_job_log_follows_redirect_to_plain_text() {
+        let mut server = mockito::Server::new_async().await;
+        let redirect = server
+            .mock("GET", "/repos/owner/repo/actions/jobs/42/logs")
+            .with_status(302)
+            .with_header("location", &format!("{}/blob/log.txt", server.url()))
+            .create_async()
+            .await;
+        let blob = server
+            .mock("GET", "/blob/log.txt")
+            .with_status(200)
+            .with_body("line one\nline two\n")
+            .create_async()
+            .await;
+
+        let log = client(server.url())
+            .get_job_log(42)
+            .await
+            .expect("log should download");
+
+        assert_eq!(log, "line one\nline two\n");
+        redirect.assert_async().await;
+        blob.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn submit_review_surfaces_http_status() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("POST", "/repos/owner/repo/pulls/5/reviews")
+            .with_status(422)
+            .create_async()
+            .await;
+
+        let err = client(server.url())
+            .submit_review(5, ReviewEvent::Approve, None, &[])
+            .await
+            .expect_err("422 should fail");
+        assert!(err.to_string().contains("422"));
+    }
 }
 
 // Test helper module - public for integration tests
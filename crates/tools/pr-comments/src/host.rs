@@ -0,0 +1,153 @@
+//! GitHub host resolution: github.com or a GitHub Enterprise Server instance.
+//!
+//! `GH_HOST` (the same variable the `gh` CLI honors) selects an Enterprise host and
+//! takes precedence over `services.github.base_url`. Enterprise Server serves REST
+//! under `/api/v3` and GraphQL at `/api/graphql`; github.com uses
+//! `api.github.com` and `api.github.com/graphql`.
+
+use agentic_config::types::GitHubServiceConfig;
+use url::Url;
+
+/// Hostname of the public GitHub instance.
+pub const GITHUB_COM: &str = "github.com";
+
+const GITHUB_COM_API: &str = "https://api.github.com";
+
+/// Resolved API endpoints for one GitHub host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHubEndpoints {
+    /// Web/git hostname (e.g. `github.com`, `ghe.example.com`); also the gh-config key.
+    pub host: String,
+    /// REST API base URL, without trailing slash.
+    pub api_base_url: String,
+    /// GraphQL endpoint URL.
+    pub graphql_url: String,
+}
+
+impl Default for GitHubEndpoints {
+    fn default() -> Self {
+        Self {
+            host: GITHUB_COM.to_string(),
+            api_base_url: GITHUB_COM_API.to_string(),
+            graphql_url: format!("{GITHUB_COM_API}/graphql"),
+        }
+    }
+}
+
+impl GitHubEndpoints {
+    /// Resolve endpoints from `GH_HOST` and the service config.
+    pub fn resolve(config: &GitHubServiceConfig) -> Self {
+        Self::from_parts(std::env::var("GH_HOST").ok().as_deref(), config)
+    }
+
+    fn from_parts(gh_host: Option<&str>, config: &GitHubServiceConfig) -> Self {
+        let gh_host = gh_host
+            .map(normalize_host)
+            .filter(|h| !h.is_empty() && h != GITHUB_COM);
+
+        let (host, api_base_url) = if let Some(host) = gh_host {
+            let api = format!("https://{host}/api/v3");
+            (host, api)
+        } else {
+            let api = config.base_url.trim().trim_end_matches('/').to_string();
+            (host_of(&api), api)
+        };
+        let graphql_url = config
+            .graphql_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .map_or_else(|| graphql_url_for(&api_base_url), str::to_string);
+
+        Self {
+            host,
+            api_base_url,
+            graphql_url,
+        }
+    }
+
+    /// Whether this points at GitHub Enterprise Server rather than github.com.
+    pub fn is_enterprise(&self) -> bool {
+        self.host != GITHUB_COM
+    }
+}
+
+/// Strip scheme, path and case from a `GH_HOST`-style value.
+fn normalize_host(raw: &str) -> String {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("https://")
+        .or_else(|| raw.strip_prefix("http://"))
+        .unwrap_or(raw);
+    raw.split('/').next().unwrap_or("").to_ascii_lowercase()
+}
+
+/// Hostname for a REST base URL; `api.github.com` maps to `github.com`.
+fn host_of(api_base_url: &str) -> String {
+    let host = Url::parse(api_base_url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_ascii_lowercase))
+        .unwrap_or_else(|| GITHUB_COM.to_string());
+    if host == "api.github.com" {
+        GITHUB_COM.to_string()
+    } else {
+        host
+    }
+}
+
+/// GraphQL endpoint implied by a REST base URL.
+fn graphql_url_for(api_base_url: &str) -> String {
+    match api_base_url.strip_suffix("/api/v3") {
+        Some(root) => format!("{root}/api/graphql"),
+        None => format!("{api_base_url}/graphql"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_targets_github_com() {
+        let endpoints = GitHubEndpoints::from_parts(None, &GitHubServiceConfig::default());
+        assert_eq!(endpoints, GitHubEndpoints::default());
+        assert!(!endpoints.is_enterprise());
+
+        let same = GitHubEndpoints::from_parts(Some("github.com"), &GitHubServiceConfig::default());
+        assert_eq!(same, GitHubEndpoints::default());
+    }
+
+    #[test]
+    fn gh_host_selects_enterprise_endpoints() {
+        let endpoints = GitHubEndpoints::from_parts(
+            Some("https://GHE.example.com/"),
+            &GitHubServiceConfig::default(),
+        );
+        assert_eq!(endpoints.host, "ghe.example.com");
+        assert_eq!(endpoints.api_base_url, "https://ghe.example.com/api/v3");
+        assert_eq!(endpoints.graphql_url, "https://ghe.example.com/api/graphql");
+        assert!(endpoints.is_enterprise());
+    }
+
+    #[test]
+    fn config_base_url_and_graphql_override() {
+        let config = GitHubServiceConfig {
+            base_url: "https://ghe.corp.internal/api/v3/".into(),
+            ..Default::default()
+        };
+        let endpoints = GitHubEndpoints::from_parts(None, &config);
+        assert_eq!(endpoints.host, "ghe.corp.internal");
+        assert_eq!(endpoints.api_base_url, "https://ghe.corp.internal/api/v3");
+        assert_eq!(
+            endpoints.graphql_url,
+            "https://ghe.corp.internal/api/graphql"
+        );
+
+        let config = GitHubServiceConfig {
+            graphql_url: Some("https://gql.corp.internal/graphql".into()),
+            ..config
+        };
+        let endpoints = GitHubEndpoints::from_parts(None, &config);
+        assert_eq!(endpoints.graphql_url, "https://gql.corp.internal/graphql");
+    }
+}
@@ -1,29 +1,61 @@
+mod branch_cache;
+pub mod comment_filter;
+pub mod etag_cache;
 pub mod git;
 pub mod github;
+pub mod host;
 pub mod logging;
 pub mod models;
 pub mod pagination;
+pub mod rate_limit;
 pub mod tools;
 
 use agentic_config::types::GitHubServiceConfig;
+use agentic_tools_core::ToolContext;
 use anyhow::Context;
 use anyhow::Result;
+use comment_filter::CommentFilter;
+use host::GITHUB_COM;
+use host::GitHubEndpoints;
+use models::CheckLog;
+use models::CheckRunList;
 use models::CheckSuiteSummary;
+use models::ChecksSummary;
+use models::CommentKind;
+use models::CommentOutputMode;
 use models::CommentSourceType;
+use models::EditedComment;
 use models::IssueCommentSummary;
+use models::PostedIssueComment;
+use models::PostedReaction;
+use models::PrDetails;
+use models::PrDiff;
+use models::PrFile;
+use models::PrFileList;
 use models::PrRef;
 use models::PrSummary;
 use models::PrSummaryList;
 use models::PullRequestReviewSummary;
+use models::Reaction;
+use models::ResolvedThread;
 use models::ReviewComment;
 use models::ReviewCommentList;
+use models::ReviewEvent;
+use models::ReviewInlineComment;
+use models::SubmittedReview;
 use models::Thread;
+use models::ThreadSummary;
+use pagination::FILE_LIST_PAGE_SIZE;
 use pagination::PaginationCache;
 use pagination::QueryLock;
+use pagination::SUMMARY_PAGE_SIZE;
 use pagination::make_key;
+use pagination::make_pr_files_key;
 use pagination::make_pr_list_key;
 use pagination::paginate_slice;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::time::Duration;
 
 #[cfg(test)]
@@ -32,6 +64,12 @@ use std::ffi::OsString;
 // Re-export agentic-tools types for MCP server usage
 pub use tools::build_registry;
 
+/// Default number of trailing log lines returned by `gh_get_check_log`.
+pub const DEFAULT_LOG_TAIL_LINES: usize = 200;
+
+/// Upper bound on `tail_lines` for `gh_get_check_log`.
+pub const MAX_LOG_TAIL_LINES: usize = 2000;
+
 /// AI response prefix to clearly identify automated replies.
 pub const AI_PREFIX: &str = "\u{1F916} AI response: ";
 
@@ -55,6 +93,93 @@ pub fn with_ai_prefix(body: &str) -> String {
     }
 }
 
+/// Map a failed GitHub write to a message with actionable hints.
+fn write_error_hint(e: &anyhow::Error, not_found: &str) -> anyhow::Error {
+    let msg = e.to_string();
+    if msg.contains("401") || msg.contains("403") {
+        anyhow::anyhow!(
+            "{msg}\n\nHint: For private repositories, ensure your token has the 'repo' scope."
+        )
+    } else if msg.contains("404") || msg.contains("Could not resolve to a node") {
+        anyhow::anyhow!("not found: {not_found}")
+    } else if msg.contains("422") {
+        anyhow::anyhow!(
+            "invalid argument: {msg}\n\nHint: inline comments must target lines present in the PR diff."
+        )
+    } else {
+        anyhow::anyhow!("{msg}")
+    }
+}
+
+/// Refuse to edit comments written by anyone but the authenticated user.
+fn ensure_own_comment(viewer: &str, author: &str, comment_id: u64) -> Result<()> {
+    anyhow::ensure!(
+        viewer.eq_ignore_ascii_case(author),
+        "permission denied: comment {comment_id} was written by '{author}', not the authenticated user '{viewer}'; only our own comments can be edited"
+    );
+    Ok(())
+}
+
+/// Check an explicit `owner`/`repo` value against GitHub's naming rules, so it is
+/// safe to splice into REST paths.
+fn validate_repo_component(kind: &str, value: &str) -> std::result::Result<(), String> {
+    if value.is_empty() {
+        return Err(format!(
+            "{kind} is required: the ambient repository is unknown, so pass both owner and repo"
+        ));
+    }
+    let valid = value.len() <= 100
+        && value != "."
+        && value != ".."
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("{kind} '{value}' is not a valid GitHub name"))
+    }
+}
+
+/// Validate review input and apply the AI prefix to the review body and inline comments.
+fn prepare_review(
+    event: ReviewEvent,
+    body: Option<String>,
+    inline_comments: Vec<ReviewInlineComment>,
+) -> Result<(Option<String>, Vec<ReviewInlineComment>)> {
+    let body = body.filter(|b| !b.trim().is_empty());
+    anyhow::ensure!(
+        event != ReviewEvent::RequestChanges || body.is_some(),
+        "invalid argument: body is required when requesting changes"
+    );
+    anyhow::ensure!(
+        event != ReviewEvent::Comment || body.is_some() || !inline_comments.is_empty(),
+        "invalid argument: a comment review needs a body or at least one inline comment"
+    );
+
+    let inline_comments = inline_comments
+        .into_iter()
+        .map(|mut c| {
+            anyhow::ensure!(
+                !c.path.trim().is_empty() && !c.body.trim().is_empty(),
+                "invalid argument: inline comments need a path and a non-empty body"
+            );
+            if let Some(start) = c.start_line {
+                anyhow::ensure!(
+                    start <= c.line,
+                    "invalid argument: start_line {start} is after line {} in {}",
+                    c.line,
+                    c.path
+                );
+            }
+            c.body = with_ai_prefix(&c.body);
+            Ok(c)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((body.map(|b| with_ai_prefix(&b)), inline_comments))
+}
+
 #[derive(Clone)]
 pub struct PrComments {
     owner: String,
@@ -62,15 +187,28 @@ pub struct PrComments {
     token: Option<String>,
     token_source: Option<GitHubTokenSource>,
     github_config: GitHubServiceConfig,
+    endpoints: GitHubEndpoints,
     pager: Arc<PaginationCache<Thread>>,
     pr_list_pager: Arc<PaginationCache<PrSummary>>,
+    pr_files_pager: Arc<PaginationCache<PrFile>>,
+    pr_number_cache: Arc<branch_cache::PrNumberCache>,
+    /// GitHub clients keyed by (owner, repo), shared by all repo-scoped instances.
+    clients: Arc<Mutex<HashMap<(String, String), github::GitHubClient>>>,
+    /// GitHub API quota observed across all clients (they share one token).
+    rate_limits: Arc<rate_limit::RateLimitTracker>,
     init_error: Option<String>,
+    /// Set by [`PrComments::for_repo`] when `owner`/`repo` point away from the ambient checkout.
+    explicit_repo: bool,
+    /// Validation failure for an explicit `owner`/`repo`, reported on first use.
+    target_error: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GitHubTokenSource {
     GhToken,
     GitHubToken,
+    GhEnterpriseToken,
+    GitHubEnterpriseToken,
     GhConfig,
 }
 
@@ -79,6 +217,8 @@ impl GitHubTokenSource {
         match self {
             Self::GhToken => "GH_TOKEN",
             Self::GitHubToken => "GITHUB_TOKEN",
+            Self::GhEnterpriseToken => "GH_ENTERPRISE_TOKEN",
+            Self::GitHubEnterpriseToken => "GITHUB_ENTERPRISE_TOKEN",
             Self::GhConfig => "gh-config",
         }
     }
@@ -91,20 +231,31 @@ pub struct OpenPrRefLookupResult {
 }
 
 impl PrComments {
-    fn resolve_token() -> (Option<String>, Option<GitHubTokenSource>) {
-        if let Ok(t) = std::env::var("GH_TOKEN") {
-            let t = t.trim().to_string();
-            if !t.is_empty() {
-                tracing::debug!("Using GitHub token from environment");
-                return (Some(t), Some(GitHubTokenSource::GhToken));
-            }
-        }
-
-        if let Ok(t) = std::env::var("GITHUB_TOKEN") {
-            let t = t.trim().to_string();
-            if !t.is_empty() {
-                tracing::debug!("Using GitHub token from environment");
-                return (Some(t), Some(GitHubTokenSource::GitHubToken));
+    /// Resolve a token for `host` the way the `gh` CLI does: `GH_TOKEN`/`GITHUB_TOKEN`
+    /// for github.com, `GH_ENTERPRISE_TOKEN`/`GITHUB_ENTERPRISE_TOKEN` for Enterprise
+    /// hosts, then the host's entry in gh-config.
+    fn resolve_token(host: &str) -> (Option<String>, Option<GitHubTokenSource>) {
+        let env_sources: [(&str, GitHubTokenSource); 2] = if host == GITHUB_COM {
+            [
+                ("GH_TOKEN", GitHubTokenSource::GhToken),
+                ("GITHUB_TOKEN", GitHubTokenSource::GitHubToken),
+            ]
+        } else {
+            [
+                ("GH_ENTERPRISE_TOKEN", GitHubTokenSource::GhEnterpriseToken),
+                (
+                    "GITHUB_ENTERPRISE_TOKEN",
+                    GitHubTokenSource::GitHubEnterpriseToken,
+                ),
+            ]
+        };
+        for (var, source) in env_sources {
+            if let Ok(t) = std::env::var(var) {
+                let t = t.trim().to_string();
+                if !t.is_empty() {
+                    tracing::debug!("Using GitHub token from environment");
+                    return (Some(t), Some(source));
+                }
             }
         }
 
@@ -116,7 +267,7 @@ impl PrComments {
             }
         };
 
-        match hosts.retrieve_token(gh_config::GITHUB_COM) {
+        match hosts.retrieve_token(host) {
             Ok(Some(t)) => {
                 tracing::debug!("Using GitHub token from gh-config");
                 (Some(t), Some(GitHubTokenSource::GhConfig))
@@ -151,8 +302,10 @@ impl PrComments {
     }
 
     pub fn with_config(github_config: GitHubServiceConfig) -> Result<Self> {
-        let git_info = git::get_git_info().context("Failed to get git information")?;
-        let (token, token_source) = Self::resolve_token();
+        let endpoints = GitHubEndpoints::resolve(&github_config);
+        let git_info =
+            git::get_git_info_for_host(&endpoints.host).context("Failed to get git information")?;
+        let (token, token_source) = Self::resolve_token(&endpoints.host);
 
         Ok(Self {
             owner: git_info.owner,
@@ -160,9 +313,16 @@ impl PrComments {
             token,
             token_source,
             github_config,
+            endpoints,
             pager: Arc::new(PaginationCache::new()),
             pr_list_pager: Arc::new(PaginationCache::new()),
+            pr_files_pager: Arc::new(PaginationCache::new()),
+            pr_number_cache: Arc::new(branch_cache::PrNumberCache::new()),
+            clients: Arc::new(Mutex::new(HashMap::new())),
+            rate_limits: Arc::new(rate_limit::RateLimitTracker::new()),
             init_error: None,
+            explicit_repo: false,
+            target_error: None,
         })
     }
 
@@ -175,16 +335,24 @@ impl PrComments {
         repo: String,
         github_config: GitHubServiceConfig,
     ) -> Self {
-        let (token, token_source) = Self::resolve_token();
+        let endpoints = GitHubEndpoints::resolve(&github_config);
+        let (token, token_source) = Self::resolve_token(&endpoints.host);
         Self {
             owner,
             repo,
             token,
             token_source,
             github_config,
+            endpoints,
             pager: Arc::new(PaginationCache::new()),
             pr_list_pager: Arc::new(PaginationCache::new()),
+            pr_files_pager: Arc::new(PaginationCache::new()),
+            pr_number_cache: Arc::new(branch_cache::PrNumberCache::new()),
+            clients: Arc::new(Mutex::new(HashMap::new())),
+            rate_limits: Arc::new(rate_limit::RateLimitTracker::new()),
             init_error: None,
+            explicit_repo: false,
+            target_error: None,
         }
     }
 
@@ -195,19 +363,56 @@ impl PrComments {
     }
 
     pub fn disabled_with_config(init_error: String, github_config: GitHubServiceConfig) -> Self {
-        let (token, token_source) = Self::resolve_token();
+        let endpoints = GitHubEndpoints::resolve(&github_config);
+        let (token, token_source) = Self::resolve_token(&endpoints.host);
         Self {
             owner: String::new(),
             repo: String::new(),
             token,
             token_source,
             github_config,
+            endpoints,
             pager: Arc::new(PaginationCache::new()),
             pr_list_pager: Arc::new(PaginationCache::new()),
+            pr_files_pager: Arc::new(PaginationCache::new()),
+            pr_number_cache: Arc::new(branch_cache::PrNumberCache::new()),
+            clients: Arc::new(Mutex::new(HashMap::new())),
+            rate_limits: Arc::new(rate_limit::RateLimitTracker::new()),
             init_error: Some(init_error),
+            explicit_repo: false,
+            target_error: None,
         }
     }
 
+    /// Scope this instance to `owner`/`repo`, each falling back to the ambient
+    /// repository when `None` or blank. Caches and GitHub clients stay shared, so a
+    /// single server can serve agents working across several checkouts. Invalid
+    /// names are reported by the first call on the returned instance.
+    #[must_use]
+    pub fn for_repo(&self, owner: Option<String>, repo: Option<String>) -> Self {
+        let owner = owner
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        let repo = repo.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+        if owner.is_none() && repo.is_none() {
+            return self.clone();
+        }
+
+        let mut scoped = self.clone();
+        if let Some(owner) = owner {
+            scoped.owner = owner;
+        }
+        if let Some(repo) = repo {
+            scoped.repo = repo;
+        }
+        scoped.explicit_repo =
+            self.explicit_repo || scoped.owner != self.owner || scoped.repo != self.repo;
+        scoped.target_error = validate_repo_component("owner", &scoped.owner)
+            .and_then(|()| validate_repo_component("repo", &scoped.repo))
+            .err();
+        scoped
+    }
+
     async fn with_github_total_timeout<T, F>(&self, label: &str, fut: F) -> Result<T>
     where
         F: std::future::Future<Output = Result<T>>,
@@ -228,6 +433,9 @@ impl PrComments {
     /// Check that repo context is available before making API calls.
     /// Returns an error with actionable guidance if owner/repo are empty.
     fn ensure_repo_configured(&self) -> Result<()> {
+        if let Some(e) = &self.target_error {
+            anyhow::bail!("invalid argument: {e}");
+        }
         if !self.owner.is_empty() && !self.repo.is_empty() {
             return Ok(());
         }
@@ -245,8 +453,25 @@ This tool relies on ambient git repo detection. Run it inside a git checkout wit
     }
 
     fn github_client(&self) -> Result<github::GitHubClient> {
-        github::GitHubClient::new(self.owner.clone(), self.repo.clone(), self.token.clone())
-            .context("internal: failed to create GitHub client")
+        let key = (self.owner.clone(), self.repo.clone());
+        let mut clients = self
+            .clients
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(client) = clients.get(&key) {
+            return Ok(client.clone());
+        }
+        let client = github::GitHubClient::with_retry_policy(
+            self.owner.clone(),
+            self.repo.clone(),
+            self.token.clone(),
+            &self.endpoints,
+            rate_limit::RetryPolicy::from_config(&self.github_config),
+            Arc::clone(&self.rate_limits),
+        )
+        .context("internal: failed to create GitHub client")?;
+        clients.insert(key, client.clone());
+        Ok(client)
     }
 
     async fn get_pr_number(&self, pr_number: Option<u64>) -> Result<u64> {
@@ -254,12 +479,29 @@ This tool relies on ambient git repo detection. Run it inside a git checkout wit
             return Ok(pr);
         }
 
+        // Branch detection only makes sense for the checkout we are running in
+        anyhow::ensure!(
+            !self.explicit_repo,
+            "pr_number is required when owner/repo name a repository other than the current checkout ({owner}/{repo})",
+            owner = self.owner.as_str(),
+            repo = self.repo.as_str()
+        );
+
         // Try to detect from current branch
-        let git_info = git::get_git_info()?;
+        let git_info = git::get_git_info_for_host(&self.endpoints.host)?;
         let branch = git_info
             .current_branch
             .context("Could not determine current git branch")?;
 
+        // Reuse the last lookup while the branch and HEAD are unchanged
+        if let Some(sha) = git_info.head_sha.as_deref()
+            && let Some(pr) = self
+                .pr_number_cache
+                .get(&self.owner, &self.repo, &branch, sha)
+        {
+            return Ok(pr);
+        }
+
         let client = self.github_client()?;
 
         match self
@@ -269,7 +511,13 @@ This tool relies on ambient git repo detection. Run it inside a git checkout wit
             )
             .await
         {
-            Ok(Some(pr)) => Ok(pr),
+            Ok(Some(pr)) => {
+                if let Some(sha) = git_info.head_sha.as_deref() {
+                    self.pr_number_cache
+                        .insert(&self.owner, &self.repo, &branch, sha, pr);
+                }
+                Ok(pr)
+            }
             Ok(None) => Err(anyhow::anyhow!(
                 "No open PR found for branch '{branch}' in {owner}/{repo}. \n\
                 Make sure you have an open PR for this branch.",
@@ -298,6 +546,12 @@ This tool relies on ambient git repo detection. Run it inside a git checkout wit
         }
     }
 
+    /// `(owner, repo)` the tools target, or `None` when repo detection failed.
+    pub fn repository(&self) -> Option<(&str, &str)> {
+        (!self.owner.is_empty() && !self.repo.is_empty())
+            .then_some((self.owner.as_str(), self.repo.as_str()))
+    }
+
     pub fn token_source_label(&self) -> Option<&'static str> {
         self.token_source
             .map(GitHubTokenSource::as_diagnostic_label)
@@ -371,12 +625,17 @@ This tool relies on ambient git repo detection. Run it inside a git checkout wit
 // Removed universal-tool-core macros; Tool impls live in tools.rs
 impl PrComments {
     /// Get PR review comments with thread-level pagination.
-    /// Repeated calls with same params return next page.
+    /// Repeated calls with same params return next page. `filter` narrows the
+    /// threads by path, author, and age; `updated_after` is also sent as `since`.
+    /// Summary mode collapses each thread to one [`ThreadSummary`] line.
     pub async fn get_comments(
         &self,
         pr_number: Option<u64>,
         comment_source_type: Option<CommentSourceType>,
         include_resolved: Option<bool>,
+        filter: &CommentFilter,
+        output: CommentOutputMode,
+        ctx: &ToolContext,
     ) -> Result<ReviewCommentList> {
         self.ensure_repo_configured()
             .context("invalid argument: missing repository context")?;
@@ -388,9 +647,15 @@ impl PrComments {
 
         let src = comment_source_type.unwrap_or_default();
         let include_resolved = include_resolved.unwrap_or(false);
-        let page_size = Self::page_size_from_env();
+        let summary = output == CommentOutputMode::Summary;
+        let page_size = if summary {
+            SUMMARY_PAGE_SIZE
+        } else {
+            Self::page_size_from_env()
+        };
         let pr_url = format!(
-            "https://github.com/{owner}/{repo}/pull/{pr}",
+            "https://{host}/{owner}/{repo}/pull/{pr}",
+            host = self.endpoints.host,
             owner = self.owner,
             repo = self.repo
         );
@@ -398,15 +663,16 @@ impl PrComments {
         // Sweep expired cache entries opportunistically
         self.pager.sweep_expired();
 
-        // Build cache key
-        let key = make_key(
+        // Build cache key, scoped to the calling session
+        let key = ctx.session_key(&make_key(
             &self.owner,
             &self.repo,
             pr,
             src,
             include_resolved,
             page_size,
-        );
+            &format!("{}|{}", output.as_str(), filter.cache_key()),
+        ));
 
         // Get or create per-query lock
         let query_lock = self.pager.get_or_create(&key);
@@ -428,7 +694,7 @@ impl PrComments {
                 .with_github_total_timeout(
                     &format!("fetching review comments for PR #{pr}"),
                     async {
-                        let comments = client.fetch_review_comments(pr).await.map_err(|e| {
+                        let scope_hint = |e: anyhow::Error| {
                             let msg = e.to_string();
                             if msg.contains("401") || msg.contains("403") {
                                 anyhow::anyhow!(
@@ -437,9 +703,30 @@ impl PrComments {
                             } else {
                                 anyhow::anyhow!("{msg}")
                             }
-                        })?;
+                        };
+
+                        // `since` drops the older parents of recent replies, so it only
+                        // decides whether anything matches; matching threads are then
+                        // rebuilt from the full (ETag-cached) comment list.
+                        let any_recent = match filter.since() {
+                            Some(since) => !client
+                                .fetch_review_comments_since(pr, Some(since))
+                                .await
+                                .map_err(scope_hint)?
+                                .is_empty(),
+                            None => true,
+                        };
+                        let comments = if any_recent {
+                            client.fetch_review_comments(pr).await.map_err(scope_hint)?
+                        } else {
+                            Vec::new()
+                        };
 
-                        let resolution_map = if include_resolved {
+                        // Summaries report each thread's status, so resolution is
+                        // needed even when resolved threads are included.
+                        let resolution_map = if (include_resolved && !summary)
+                            || comments.is_empty()
+                        {
                             std::collections::HashMap::new()
                         } else {
                             client
@@ -455,7 +742,8 @@ impl PrComments {
 
             // Build and filter threads
             let threads = github::GitHubClient::build_threads(comments, &resolution_map);
-            let filtered = github::GitHubClient::filter_threads(threads, src, include_resolved);
+            let mut filtered = github::GitHubClient::filter_threads(threads, src, include_resolved);
+            filtered.retain(|thread| filter.matches(thread));
 
             guarded_post_fetch_reset(&query_lock, filtered, page_size);
         }
@@ -473,15 +761,26 @@ impl PrComments {
         let total_threads = state.results.len();
         let shown_threads = state.next_offset;
 
-        // Flatten threads to comments for output
-        let comments: Vec<_> = page_threads
-            .into_iter()
-            .flat_map(|t| {
-                let mut cs = vec![t.parent];
-                cs.extend(t.replies);
-                cs
-            })
-            .collect();
+        // Flatten threads to comments for output, or collapse them in summary mode
+        let (comments, threads): (Vec<_>, Vec<_>) = if summary {
+            (
+                Vec::new(),
+                page_threads
+                    .iter()
+                    .map(ThreadSummary::from_thread)
+                    .collect(),
+            )
+        } else {
+            let comments = page_threads
+                .into_iter()
+                .flat_map(|t| {
+                    let mut cs = vec![t.parent];
+                    cs.extend(t.replies);
+                    cs
+                })
+                .collect();
+            (comments, Vec::new())
+        };
 
         // Build pagination message only when there are more pages
         let message = if has_more {
@@ -504,15 +803,21 @@ impl PrComments {
             pr_number: pr,
             pr_url,
             comments,
+            threads,
             shown_threads,
             total_threads,
             has_more,
             message,
+            rate_limit: self.rate_limits.snapshot(),
         })
     }
 
     /// List pull requests in the repository
-    pub async fn list_prs(&self, state: Option<String>) -> Result<PrSummaryList> {
+    pub async fn list_prs(
+        &self,
+        state: Option<String>,
+        ctx: &ToolContext,
+    ) -> Result<PrSummaryList> {
         self.ensure_repo_configured()
             .context("invalid argument: missing repository context")?;
 
@@ -521,7 +826,12 @@ impl PrComments {
 
         self.pr_list_pager.sweep_expired();
 
-        let key = make_pr_list_key(&self.owner, &self.repo, &state, page_size);
+        let key = ctx.session_key(&make_pr_list_key(
+            &self.owner,
+            &self.repo,
+            &state,
+            page_size,
+        ));
         let query_lock = self.pr_list_pager.get_or_create(&key);
 
         // TODO(2): On cache miss, list_prs blocks page 1 on a full GitHub PR fetch so we can compute
@@ -591,9 +901,265 @@ impl PrComments {
             total_prs,
             has_more,
             message,
+            rate_limit: self.rate_limits.snapshot(),
+        })
+    }
+
+    /// Get PR metadata: title, body, refs, reviewers, and a checks summary.
+    pub async fn get_pr(&self, pr_number: Option<u64>) -> Result<PrDetails> {
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let pr = self
+            .get_pr_number(pr_number)
+            .await
+            .context("invalid argument: failed to determine PR number")?;
+
+        let client = self.github_client()?;
+
+        self.with_github_total_timeout(&format!("fetching PR #{pr}"), async {
+            client
+                .get_pr_details(pr)
+                .await
+                .map_err(|e| write_error_hint(&e, &format!("PR #{pr} not found")))
+        })
+        .await
+    }
+
+    /// Page through the files changed in a PR. Fetches the full file list once per query
+    /// and serves subsequent pages from the session-scoped cache.
+    async fn page_pr_files(
+        &self,
+        pr: u64,
+        view: &str,
+        page_size: usize,
+        paths: &[String],
+        ctx: &ToolContext,
+    ) -> Result<(Vec<PrFile>, usize, usize, bool)> {
+        self.pr_files_pager.sweep_expired();
+
+        let key = ctx.session_key(&make_pr_files_key(
+            &self.owner,
+            &self.repo,
+            pr,
+            view,
+            page_size,
+        ));
+        let query_lock = self.pr_files_pager.get_or_create(&key);
+
+        let needs_fetch = {
+            let state = query_lock
+                .state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            state.is_empty() || state.is_expired()
+        };
+
+        if needs_fetch {
+            let client = self.github_client()?;
+            let mut files = self
+                .with_github_total_timeout(&format!("listing files for PR #{pr}"), async {
+                    client
+                        .list_pr_files(pr)
+                        .await
+                        .map_err(|e| write_error_hint(&e, &format!("PR #{pr} not found")))
+                })
+                .await?;
+            if !paths.is_empty() {
+                files.retain(|f| {
+                    paths.iter().any(|p| {
+                        f.filename == *p
+                            || f.filename
+                                .starts_with(&format!("{}/", p.trim_end_matches('/')))
+                    })
+                });
+            }
+            guarded_post_fetch_reset(&query_lock, files, page_size);
+        }
+
+        let mut pager_state = query_lock
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let (files, has_more) = paginate_slice(
+            &pager_state.results,
+            pager_state.next_offset,
+            pager_state.page_size,
+        );
+        pager_state.next_offset += files.len();
+
+        let total = pager_state.results.len();
+        let shown = pager_state.next_offset;
+
+        if !has_more {
+            drop(pager_state);
+            self.pr_files_pager.remove_if_same(&key, &query_lock);
+        }
+
+        Ok((files, shown, total, has_more))
+    }
+
+    /// List files changed in a PR (status and line counts, no patches), paginated.
+    pub async fn get_changed_files(
+        &self,
+        pr_number: Option<u64>,
+        ctx: &ToolContext,
+    ) -> Result<PrFileList> {
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let pr = self
+            .get_pr_number(pr_number)
+            .await
+            .context("invalid argument: failed to determine PR number")?;
+
+        let (files, shown_files, total_files, has_more) = self
+            .page_pr_files(pr, "files", FILE_LIST_PAGE_SIZE, &[], ctx)
+            .await?;
+
+        let files = files
+            .into_iter()
+            .map(|f| PrFile { patch: None, ..f })
+            .collect();
+        let message = has_more.then(|| {
+            format!(
+                "Showing {shown_files} out of {total_files} files. Call gh_get_changed_files again for more."
+            )
+        });
+
+        Ok(PrFileList {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            pr_number: pr,
+            files,
+            shown_files,
+            total_files,
+            has_more,
+            message,
+        })
+    }
+
+    /// Get the PR diff as per-file patches, paginated by file. `paths` limits the
+    /// diff to matching files or directories.
+    pub async fn get_pr_diff(
+        &self,
+        pr_number: Option<u64>,
+        paths: Vec<String>,
+        ctx: &ToolContext,
+    ) -> Result<PrDiff> {
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let pr = self
+            .get_pr_number(pr_number)
+            .await
+            .context("invalid argument: failed to determine PR number")?;
+
+        let paths: Vec<String> = paths
+            .into_iter()
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        let view = format!("diff:{}", paths.join(","));
+        let page_size = Self::page_size_from_env();
+
+        let (files, shown_files, total_files, has_more) = self
+            .page_pr_files(pr, &view, page_size, &paths, ctx)
+            .await?;
+
+        let message = has_more.then(|| {
+            format!(
+                "Showing {shown_files} out of {total_files} files. Call gh_get_pr_diff again for more."
+            )
+        });
+
+        Ok(PrDiff {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            pr_number: pr,
+            files,
+            shown_files,
+            total_files,
+            has_more,
+            message,
         })
     }
 
+    /// List check runs on a PR's head commit with their conclusions, failing runs first.
+    pub async fn get_checks(&self, pr_number: Option<u64>) -> Result<CheckRunList> {
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let pr = self
+            .get_pr_number(pr_number)
+            .await
+            .context("invalid argument: failed to determine PR number")?;
+
+        let client = self.github_client()?;
+
+        let (head_sha, mut check_runs) = self
+            .with_github_total_timeout(&format!("listing checks for PR #{pr}"), async {
+                let head_sha = client
+                    .get_pr_head_sha(pr)
+                    .await
+                    .map_err(|e| write_error_hint(&e, &format!("PR #{pr} not found")))?;
+                let runs = client.list_check_runs_for_ref(&head_sha).await?;
+                Ok((head_sha, runs))
+            })
+            .await?;
+
+        CheckRunList::sort_runs(&mut check_runs);
+        let summary = ChecksSummary::tally(
+            check_runs
+                .iter()
+                .map(|r| (r.status.as_str(), r.conclusion.as_deref())),
+        );
+
+        Ok(CheckRunList {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            pr_number: pr,
+            head_sha,
+            summary,
+            check_runs,
+        })
+    }
+
+    /// Fetch the tail of a check run's job log plus any `##[error]` lines.
+    /// Only GitHub Actions check runs have downloadable logs.
+    pub async fn get_check_log(
+        &self,
+        check_run_id: u64,
+        tail_lines: Option<usize>,
+    ) -> Result<CheckLog> {
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let tail_lines = tail_lines
+            .unwrap_or(DEFAULT_LOG_TAIL_LINES)
+            .clamp(1, MAX_LOG_TAIL_LINES);
+
+        let client = self.github_client()?;
+
+        let raw = self
+            .with_github_total_timeout(
+                &format!("downloading log for check run {check_run_id}"),
+                async {
+                    client.get_job_log(check_run_id).await.map_err(|e| {
+                        write_error_hint(
+                            &e,
+                            &format!(
+                                "Log for check run {check_run_id} not found (only GitHub Actions jobs have logs, and they expire)"
+                            ),
+                        )
+                    })
+                },
+            )
+            .await?;
+
+        Ok(CheckLog::from_raw(check_run_id, &raw, tail_lines))
+    }
+
     /// Reply to a PR review comment. Automatically prefixes with AI identifier.
     pub async fn add_comment_reply(
         &self,
@@ -628,16 +1194,10 @@ impl PrComments {
                         .reply_to_comment(pr, comment_id, &prefixed_body)
                         .await
                         .map_err(|e| {
-                            let msg = e.to_string();
-                            if msg.contains("401") || msg.contains("403") {
-                                anyhow::anyhow!(
-                                    "{msg}\n\nHint: For private repositories, ensure your token has the 'repo' scope."
-                                )
-                            } else if msg.contains("404") {
-                                anyhow::anyhow!("not found: Comment {comment_id} not found on PR #{pr}")
-                            } else {
-                                anyhow::anyhow!("{msg}")
-                            }
+                            write_error_hint(
+                                &e,
+                                &format!("Comment {comment_id} not found on PR #{pr}"),
+                            )
                         })
                 },
             )
@@ -645,6 +1205,171 @@ impl PrComments {
 
         Ok(comment)
     }
+
+    /// Post a top-level (issue-style) comment on a PR. Automatically prefixes with AI identifier.
+    pub async fn add_pr_comment(
+        &self,
+        pr_number: Option<u64>,
+        body: String,
+    ) -> Result<PostedIssueComment> {
+        anyhow::ensure!(
+            !body.trim().is_empty(),
+            "invalid argument: Body cannot be empty"
+        );
+
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let pr = self
+            .get_pr_number(pr_number)
+            .await
+            .context("invalid argument: failed to determine PR number")?;
+
+        let client = self.github_client()?;
+        let prefixed_body = with_ai_prefix(&body);
+
+        self.with_github_total_timeout(&format!("posting comment on PR #{pr}"), async {
+            client
+                .add_issue_comment(pr, &prefixed_body)
+                .await
+                .map_err(|e| write_error_hint(&e, &format!("PR #{pr} not found")))
+        })
+        .await
+    }
+
+    /// React to a review or issue comment with an emoji.
+    pub async fn react_to_comment(
+        &self,
+        comment_id: u64,
+        kind: CommentKind,
+        reaction: Reaction,
+    ) -> Result<PostedReaction> {
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let client = self.github_client()?;
+
+        self.with_github_total_timeout(&format!("reacting to comment {comment_id}"), async {
+            client
+                .add_comment_reaction(kind, comment_id, reaction)
+                .await
+                .map_err(|e| write_error_hint(&e, &format!("Comment {comment_id} not found")))
+        })
+        .await
+    }
+
+    /// Replace the body of one of our own review or issue comments. The new body
+    /// keeps the AI identifier prefix.
+    pub async fn edit_comment(
+        &self,
+        comment_id: u64,
+        kind: CommentKind,
+        body: String,
+    ) -> Result<EditedComment> {
+        anyhow::ensure!(
+            !body.trim().is_empty(),
+            "invalid argument: Body cannot be empty"
+        );
+
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let client = self.github_client()?;
+        let prefixed_body = with_ai_prefix(&body);
+
+        self.with_github_total_timeout(&format!("editing comment {comment_id}"), async {
+            let not_found = format!("Comment {comment_id} not found");
+            let (viewer, (author, _)) = tokio::try_join!(
+                client.get_authenticated_login(),
+                client.get_comment(kind, comment_id),
+            )
+            .map_err(|e| write_error_hint(&e, &not_found))?;
+            ensure_own_comment(&viewer, &author, comment_id)?;
+
+            client
+                .edit_comment(kind, comment_id, &prefixed_body)
+                .await
+                .map_err(|e| write_error_hint(&e, &not_found))
+        })
+        .await
+    }
+
+    /// Submit a PR review with an optional body and new inline comments.
+    /// Automatically prefixes the body and every inline comment with AI identifier.
+    pub async fn submit_review(
+        &self,
+        pr_number: Option<u64>,
+        event: ReviewEvent,
+        body: Option<String>,
+        inline_comments: Vec<ReviewInlineComment>,
+    ) -> Result<SubmittedReview> {
+        let (body, inline_comments) = prepare_review(event, body, inline_comments)?;
+
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let pr = self
+            .get_pr_number(pr_number)
+            .await
+            .context("invalid argument: failed to determine PR number")?;
+
+        let client = self.github_client()?;
+
+        self.with_github_total_timeout(&format!("submitting review on PR #{pr}"), async {
+            client
+                .submit_review(pr, event, body.as_deref(), &inline_comments)
+                .await
+                .map_err(|e| write_error_hint(&e, &format!("PR #{pr} not found")))
+        })
+        .await
+    }
+
+    /// Resolve or unresolve a review thread. `thread_id` is either the thread's GraphQL
+    /// node ID or the numeric ID of any review comment in it (looked up on the PR).
+    pub async fn set_thread_resolved(
+        &self,
+        pr_number: Option<u64>,
+        thread_id: String,
+        resolved: bool,
+    ) -> Result<ResolvedThread> {
+        let thread_id = thread_id.trim().to_string();
+        anyhow::ensure!(
+            !thread_id.is_empty(),
+            "invalid argument: thread_id cannot be empty"
+        );
+
+        self.ensure_repo_configured()
+            .context("invalid argument: missing repository context")?;
+
+        let client = self.github_client()?;
+
+        let node_id = match thread_id.parse::<u64>() {
+            Ok(comment_id) => {
+                let pr = self
+                    .get_pr_number(pr_number)
+                    .await
+                    .context("invalid argument: failed to determine PR number")?;
+                self.with_github_total_timeout(
+                    &format!("looking up review thread for comment {comment_id} on PR #{pr}"),
+                    client.find_review_thread_id(pr, comment_id),
+                )
+                .await?
+                .with_context(|| {
+                    format!("not found: no review thread contains comment {comment_id} on PR #{pr}")
+                })?
+            }
+            Err(_) => thread_id,
+        };
+
+        let action = if resolved { "resolving" } else { "unresolving" };
+        self.with_github_total_timeout(&format!("{action} review thread {node_id}"), async {
+            client
+                .set_review_thread_resolved(&node_id, resolved)
+                .await
+                .map_err(|e| write_error_hint(&e, &format!("Review thread {node_id} not found")))
+        })
+        .await
+    }
 }
 
 // Removed universal-tool-core MCP server; use ToolRegistry in tools.rs
@@ -750,7 +1475,7 @@ mod tests {
     #[test]
     fn late_concurrent_caller_does_not_rewind_next_offset() {
         let cache: PaginationCache<Thread> = PaginationCache::new();
-        let key = make_key("owner", "repo", 123, CommentSourceType::All, false, 2);
+        let key = make_key("owner", "repo", 123, CommentSourceType::All, false, 2, "");
         let query_lock = cache.get_or_create(&key);
 
         let needs_fetch_a = {
@@ -822,6 +1547,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ensure_own_comment_matches_login_case_insensitively() {
+        assert!(ensure_own_comment("Octocat", "octocat", 1).is_ok());
+        let err = ensure_own_comment("octocat", "someone-else", 7).unwrap_err();
+        assert!(err.to_string().starts_with("permission denied: comment 7"));
+    }
+
     #[test]
     fn with_ai_prefix_adds_prefix() {
         let body = "This is a reply";
@@ -839,6 +1571,60 @@ mod tests {
         assert_eq!(result.matches(AI_PREFIX).count(), 1);
     }
 
+    fn inline(path: &str, line: u64, body: &str) -> ReviewInlineComment {
+        ReviewInlineComment {
+            path: path.into(),
+            line,
+            start_line: None,
+            side: None,
+            body: body.into(),
+        }
+    }
+
+    #[test]
+    fn prepare_review_prefixes_body_and_inline_comments() {
+        let (body, comments) = prepare_review(
+            ReviewEvent::Comment,
+            Some("Overall looks good".into()),
+            vec![inline("src/lib.rs", 3, "nit")],
+        )
+        .unwrap();
+        assert_eq!(body, Some(format!("{AI_PREFIX}Overall looks good")));
+        assert_eq!(comments[0].body, format!("{AI_PREFIX}nit"));
+    }
+
+    #[test]
+    fn prepare_review_allows_bodyless_approve_and_inline_only_comment() {
+        let (body, _) = prepare_review(ReviewEvent::Approve, Some("  ".into()), vec![]).unwrap();
+        assert_eq!(body, None);
+
+        let (body, comments) =
+            prepare_review(ReviewEvent::Comment, None, vec![inline("a.rs", 1, "x")]).unwrap();
+        assert_eq!(body, None);
+        assert_eq!(comments.len(), 1);
+    }
+
+    #[test]
+    fn prepare_review_rejects_invalid_input() {
+        let cases = [
+            prepare_review(ReviewEvent::RequestChanges, None, vec![]),
+            prepare_review(ReviewEvent::Comment, None, vec![]),
+            prepare_review(ReviewEvent::Approve, None, vec![inline("a.rs", 1, " ")]),
+            prepare_review(
+                ReviewEvent::Approve,
+                None,
+                vec![ReviewInlineComment {
+                    start_line: Some(5),
+                    ..inline("a.rs", 2, "x")
+                }],
+            ),
+        ];
+        for case in cases {
+            let err = case.unwrap_err().to_string();
+            assert!(err.starts_with("invalid argument:"), "{err}");
+        }
+    }
+
     #[test]
     fn with_ai_prefix_handles_empty_body() {
         let body = "";
@@ -910,7 +1696,7 @@ mod tests {
         TokenEnvGuard::set_var("GH_TOKEN", "   ");
         TokenEnvGuard::set_var("GITHUB_TOKEN", " fallback-token ");
 
-        let (token, source) = PrComments::resolve_token();
+        let (token, source) = PrComments::resolve_token(GITHUB_COM);
 
         assert_eq!(token.as_deref(), Some("fallback-token"));
         assert_eq!(source, Some(GitHubTokenSource::GitHubToken));
@@ -978,7 +1764,7 @@ mod tests {
     #[test]
     fn completed_comment_pagination_restarts_with_fresh_state_on_next_identical_call() {
         let cache: PaginationCache<Thread> = PaginationCache::new();
-        let key = make_key("owner", "repo", 123, CommentSourceType::All, false, 10);
+        let key = make_key("owner", "repo", 123, CommentSourceType::All, false, 10, "");
 
         let first_lock = cache.get_or_create(&key);
         {
@@ -1042,4 +1828,63 @@ mod tests {
         assert!(restarted_state.is_empty());
         assert_eq!(restarted_state.next_offset, 0);
     }
+
+    #[tokio::test]
+    async fn for_repo_falls_back_per_field_and_shares_clients() {
+        let ambient = PrComments::with_repo("acme".into(), "app".into());
+
+        let same = ambient.for_repo(Some(" ".into()), None);
+        assert_eq!((same.owner.as_str(), same.repo.as_str()), ("acme", "app"));
+        assert!(!same.explicit_repo);
+
+        let other = ambient.for_repo(None, Some("infra".into()));
+        assert_eq!(
+            (other.owner.as_str(), other.repo.as_str()),
+            ("acme", "infra")
+        );
+        assert!(other.explicit_repo);
+        assert!(other.ensure_repo_configured().is_ok());
+
+        other.github_client().unwrap();
+        other.github_client().unwrap();
+        ambient.github_client().unwrap();
+        let clients = ambient
+            .clients
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert_eq!(clients.len(), 2);
+    }
+
+    #[test]
+    fn for_repo_reports_invalid_or_missing_names_on_use() {
+        let ambient = PrComments::with_repo("acme".into(), "app".into());
+        let err = ambient
+            .for_repo(Some("acme/evil".into()), None)
+            .ensure_repo_configured()
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("owner 'acme/evil' is not a valid GitHub name")
+        );
+
+        let disabled = PrComments::disabled("not a git repo".into());
+        let err = disabled
+            .for_repo(Some("acme".into()), None)
+            .ensure_repo_configured()
+            .unwrap_err();
+        assert!(err.to_string().contains("repo is required"));
+
+        let scoped = disabled.for_repo(Some("acme".into()), Some("app".into()));
+        assert!(scoped.ensure_repo_configured().is_ok());
+    }
+
+    #[tokio::test]
+    async fn explicit_repo_requires_pr_number() {
+        let scoped = PrComments::with_repo("acme".into(), "app".into())
+            .for_repo(Some("other".into()), Some("repo".into()));
+
+        assert_eq!(scoped.get_pr_number(Some(7)).await.unwrap(), 7);
+        let err = scoped.get_pr_number(None).await.unwrap_err();
+        assert!(err.to_string().contains("pr_number is required"));
+    }
 }
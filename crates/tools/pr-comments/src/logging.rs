@@ -3,6 +3,7 @@
 //! Provides a helper context that reduces duplication when logging tool calls
 //! to the thoughts logs directory using `agentic_logging`.
 
+use agentic_logging::AsyncLogWriter;
 use agentic_logging::CallTimer;
 use agentic_logging::LogWriter;
 use agentic_logging::ToolCallRecord;
@@ -44,12 +45,14 @@ impl ToolLogCtx {
         }
     }
 
-    /// Finish the logging context and append the JSONL record.
+    /// Finish the logging context and queue the JSONL record on the shared
+    /// [`AsyncLogWriter`] for the logs directory, so disk IO stays off the tool's path.
     ///
     /// If `completed_at` is provided, it will be used for the JSONL record to ensure
     /// consistent day-bucket placement. Otherwise, a fresh timestamp is captured.
     ///
-    /// This is best-effort: errors are logged via tracing but do not fail the call.
+    /// This is best-effort: write failures and drops are counted in the writer's
+    /// metrics but do not fail the call.
     #[expect(clippy::too_many_arguments)]
     pub fn finish(
         self,
@@ -85,11 +88,11 @@ impl ToolLogCtx {
             model,
             token_usage: None,
             summary,
+            trace_id: Some(self.timer.trace_id),
+            parent_call_id: self.timer.parent_call_id,
         };
 
-        if let Err(e) = writer.append_jsonl(&record) {
-            tracing::warn!("Failed to append JSONL log: {e}");
-        }
+        AsyncLogWriter::shared(writer.logs_dir()).append(record);
     }
 }
 
@@ -19,6 +19,476 @@ pub enum CommentSourceType {
     All,
 }
 
+/// Shape of `gh_get_comments` output.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CommentOutputMode {
+    /// Every comment with its full body
+    #[default]
+    Full,
+    /// One entry per thread: file, line, status, participants, excerpt, last activity
+    Summary,
+}
+
+impl CommentOutputMode {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Summary => "summary",
+        }
+    }
+}
+
+/// Review verdict for `gh_submit_review`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewEvent {
+    /// Leave feedback without approving or blocking
+    Comment,
+    Approve,
+    RequestChanges,
+}
+
+impl ReviewEvent {
+    /// Value expected by the GitHub REST API.
+    pub const fn as_api_str(self) -> &'static str {
+        match self {
+            Self::Comment => "COMMENT",
+            Self::Approve => "APPROVE",
+            Self::RequestChanges => "REQUEST_CHANGES",
+        }
+    }
+}
+
+/// Which kind of PR comment a comment ID refers to.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CommentKind {
+    /// Inline review comment on the diff (IDs from `gh_get_comments`)
+    #[default]
+    Review,
+    /// Top-level conversation comment (IDs from `gh_add_pr_comment`)
+    Issue,
+}
+
+impl CommentKind {
+    /// REST collection holding comments of this kind.
+    pub const fn api_collection(self) -> &'static str {
+        match self {
+            Self::Review => "pulls/comments",
+            Self::Issue => "issues/comments",
+        }
+    }
+}
+
+/// Reaction emoji accepted by `gh_react_to_comment`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Reaction {
+    /// 👍
+    #[serde(rename = "+1", alias = "thumbs_up")]
+    ThumbsUp,
+    /// 👎
+    #[serde(rename = "-1", alias = "thumbs_down")]
+    ThumbsDown,
+    Laugh,
+    Confused,
+    Heart,
+    Hooray,
+    Rocket,
+    Eyes,
+}
+
+impl Reaction {
+    /// Value expected by the GitHub REST API.
+    pub const fn as_api_str(self) -> &'static str {
+        match self {
+            Self::ThumbsUp => "+1",
+            Self::ThumbsDown => "-1",
+            Self::Laugh => "laugh",
+            Self::Confused => "confused",
+            Self::Heart => "heart",
+            Self::Hooray => "hooray",
+            Self::Rocket => "rocket",
+            Self::Eyes => "eyes",
+        }
+    }
+}
+
+/// GitHub API quota reported by the most recent response.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Rate-limit bucket (`core`, `graphql`, `search`, ...)
+    pub resource: String,
+    pub limit: u64,
+    pub remaining: u64,
+    /// Unix timestamp (seconds) when the quota resets
+    pub reset_at: u64,
+}
+
+impl RateLimitStatus {
+    /// True when less than a tenth of the quota is left.
+    pub const fn is_low(&self) -> bool {
+        self.remaining.saturating_mul(10) < self.limit
+    }
+
+    /// One-line summary such as `GitHub API quota (core): 312/5000 remaining, resets in 14m`.
+    pub fn summary(&self, now_unix: u64) -> String {
+        let mins = self.reset_at.saturating_sub(now_unix).div_ceil(60);
+        format!(
+            "GitHub API quota ({resource}): {remaining}/{limit} remaining, resets in {mins}m",
+            resource = self.resource,
+            remaining = self.remaining,
+            limit = self.limit
+        )
+    }
+}
+
+/// Quota footer for text output, shown only when the quota is running low.
+fn rate_limit_footer(status: Option<&RateLimitStatus>) -> Option<String> {
+    let status = status.filter(|s| s.is_low())?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    Some(format!("Warning: {}", status.summary(now)))
+}
+
+/// New inline comment attached to a submitted review.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ReviewInlineComment {
+    /// File path relative to the repository root
+    pub path: String,
+    /// Line the comment applies to (last line for multi-line comments)
+    pub line: u64,
+    /// First line for a multi-line comment
+    #[serde(default)]
+    pub start_line: Option<u64>,
+    /// Diff side: RIGHT (new code, default) or LEFT (removed code)
+    #[serde(default)]
+    pub side: Option<String>,
+    /// Comment body
+    pub body: String,
+}
+
+/// Top-level (issue-style) comment posted on a PR.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PostedIssueComment {
+    pub id: u64,
+    pub pr_number: u64,
+    pub user: String,
+    pub body: String,
+    pub html_url: String,
+    pub created_at: String,
+}
+
+/// Reaction added to a PR comment.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PostedReaction {
+    pub id: u64,
+    pub comment_id: u64,
+    pub comment_kind: CommentKind,
+    pub content: String,
+    pub user: String,
+}
+
+/// PR comment after an edit.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct EditedComment {
+    pub id: u64,
+    pub kind: CommentKind,
+    pub user: String,
+    pub body: String,
+    pub html_url: String,
+    pub updated_at: String,
+}
+
+/// Review submitted on a PR.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct SubmittedReview {
+    pub id: u64,
+    pub pr_number: u64,
+    pub user: String,
+    pub state: String,
+    pub body: String,
+    pub html_url: String,
+    pub submitted_at: Option<String>,
+    /// Number of inline comments submitted with the review
+    pub inline_comments: usize,
+}
+
+/// Review thread state after a resolve/unresolve mutation.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ResolvedThread {
+    /// GraphQL node ID of the review thread
+    pub thread_id: String,
+    pub is_resolved: bool,
+}
+
+/// Rollup of check suites on a PR's head commit.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ChecksSummary {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub pending: usize,
+    /// Neutral, skipped, or stale suites
+    pub other: usize,
+}
+
+/// Conclusions GitHub reports for checks that should be treated as failing.
+const FAILING_CONCLUSIONS: &[&str] = &[
+    "failure",
+    "timed_out",
+    "cancelled",
+    "action_required",
+    "startup_failure",
+];
+
+/// Whether a completed check's conclusion counts as failing.
+pub fn is_failing_conclusion(conclusion: Option<&str>) -> bool {
+    conclusion.is_some_and(|c| FAILING_CONCLUSIONS.contains(&c))
+}
+
+impl ChecksSummary {
+    /// Tally `(status, conclusion)` pairs from check suites or check runs.
+    pub fn tally<'a>(items: impl IntoIterator<Item = (&'a str, Option<&'a str>)>) -> Self {
+        let mut summary = Self::default();
+        for (status, conclusion) in items {
+            summary.total += 1;
+            if status != "completed" {
+                summary.pending += 1;
+            } else if conclusion == Some("success") {
+                summary.passed += 1;
+            } else if is_failing_conclusion(conclusion) {
+                summary.failed += 1;
+            } else {
+                summary.other += 1;
+            }
+        }
+        summary
+    }
+
+    pub fn from_suites(suites: &[CheckSuiteSummary]) -> Self {
+        Self::tally(
+            suites
+                .iter()
+                .map(|s| (s.status.as_str(), s.conclusion.as_deref())),
+        )
+    }
+}
+
+/// A single check run on a commit.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CheckRunSummary {
+    /// Check run ID; for GitHub Actions this is also the job ID used by `gh_get_check_log`
+    pub id: u64,
+    pub name: String,
+    pub status: String,
+    pub conclusion: Option<String>,
+    pub app_slug: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub html_url: Option<String>,
+    /// Short title from the check's output, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_title: Option<String>,
+}
+
+/// Check runs for a PR's head commit, failing runs first.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CheckRunList {
+    pub owner: String,
+    pub repo: String,
+    pub pr_number: u64,
+    pub head_sha: String,
+    pub summary: ChecksSummary,
+    pub check_runs: Vec<CheckRunSummary>,
+}
+
+impl CheckRunList {
+    /// Order runs failing, pending, then the rest; stable by name within each group.
+    pub fn sort_runs(runs: &mut [CheckRunSummary]) {
+        runs.sort_by_key(|r| {
+            let rank = if r.status != "completed" {
+                1
+            } else if is_failing_conclusion(r.conclusion.as_deref()) {
+                0
+            } else {
+                2
+            };
+            (rank, r.name.clone())
+        });
+    }
+}
+
+/// Excerpt of a check run's job log.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct CheckLog {
+    pub check_run_id: u64,
+    pub total_lines: usize,
+    /// Lines annotated as errors (`##[error]`) anywhere in the log, capped
+    pub error_lines: Vec<String>,
+    /// Last `tail_lines` lines of the log, timestamps stripped
+    pub tail: String,
+    pub truncated: bool,
+}
+
+/// Maximum number of `##[error]` lines kept in a [`CheckLog`].
+pub const MAX_ERROR_LINES: usize = 20;
+
+impl CheckLog {
+    /// Build an excerpt from a raw Actions log: strip the per-line timestamps, collect
+    /// error annotations, and keep the last `tail_lines` lines.
+    pub fn from_raw(check_run_id: u64, raw: &str, tail_lines: usize) -> Self {
+        let lines: Vec<&str> = raw.lines().map(strip_log_timestamp).collect();
+        let error_lines = lines
+            .iter()
+            .filter(|l| l.contains("##[error]"))
+            .take(MAX_ERROR_LINES)
+            .map(|l| (*l).to_string())
+            .collect();
+        let start = lines.len().saturating_sub(tail_lines);
+        Self {
+            check_run_id,
+            total_lines: lines.len(),
+            error_lines,
+            tail: lines[start..].join("\n"),
+            truncated: start > 0,
+        }
+    }
+}
+
+/// Drop the leading RFC 3339 timestamp GitHub Actions prefixes to each log line.
+fn strip_log_timestamp(line: &str) -> &str {
+    let line = line.trim_start_matches('\u{feff}');
+    match line.split_once(' ') {
+        Some((ts, rest))
+            if ts.len() >= 20
+                && ts.ends_with('Z')
+                && ts.as_bytes().get(4) == Some(&b'-')
+                && ts.as_bytes().get(10) == Some(&b'T') =>
+        {
+            rest
+        }
+        _ => line,
+    }
+}
+
+/// A reviewer on a PR and where their review stands.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PrReviewer {
+    /// User login, or `team:<slug>` for requested teams
+    pub login: String,
+    /// Latest review state (APPROVED, `CHANGES_REQUESTED`, COMMENTED, DISMISSED) or REQUESTED if pending
+    pub state: String,
+}
+
+/// Combine submitted reviews with pending review requests into one reviewer list.
+///
+/// A reviewer's latest APPROVED/`CHANGES_REQUESTED`/DISMISSED review wins over later
+/// COMMENTED ones (matching GitHub's own rollup); a pending request overrides any
+/// earlier review since the author has asked for another look.
+pub fn summarize_reviewers(
+    reviews: &[PullRequestReviewSummary],
+    requested: &[String],
+) -> Vec<PrReviewer> {
+    let mut reviewers: Vec<PrReviewer> = Vec::new();
+    for review in reviews {
+        if review.state == "PENDING" {
+            continue;
+        }
+        match reviewers.iter_mut().find(|r| r.login == review.user_login) {
+            Some(existing) => {
+                if review.state != "COMMENTED" || existing.state == "COMMENTED" {
+                    existing.state.clone_from(&review.state);
+                }
+            }
+            None => reviewers.push(PrReviewer {
+                login: review.user_login.clone(),
+                state: review.state.clone(),
+            }),
+        }
+    }
+    for login in requested {
+        match reviewers.iter_mut().find(|r| &r.login == login) {
+            Some(existing) => existing.state = "REQUESTED".to_string(),
+            None => reviewers.push(PrReviewer {
+                login: login.clone(),
+                state: "REQUESTED".to_string(),
+            }),
+        }
+    }
+    reviewers
+}
+
+/// PR metadata for review context.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PrDetails {
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub state: String,
+    pub is_draft: bool,
+    pub author: String,
+    pub url: String,
+    pub base_ref: String,
+    pub head_ref: String,
+    pub head_sha: String,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changed_files: u64,
+    pub commits: u64,
+    /// None while GitHub is still computing mergeability
+    pub mergeable: Option<bool>,
+    pub reviewers: Vec<PrReviewer>,
+    pub checks: ChecksSummary,
+}
+
+/// A file changed in a PR.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct PrFile {
+    pub filename: String,
+    /// added, removed, modified, renamed, copied, changed, or unchanged
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_filename: Option<String>,
+    /// Unified diff hunks; absent for binary or very large files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+}
+
+/// Page of changed files for a PR (without patches).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PrFileList {
+    pub owner: String,
+    pub repo: String,
+    pub pr_number: u64,
+    pub files: Vec<PrFile>,
+    pub shown_files: usize,
+    pub total_files: usize,
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Page of per-file diffs for a PR.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PrDiff {
+    pub owner: String,
+    pub repo: String,
+    pub pr_number: u64,
+    pub files: Vec<PrFile>,
+    pub shown_files: usize,
+    pub total_files: usize,
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ReviewComment {
     pub id: u64,
@@ -92,6 +562,9 @@ pub struct ReviewCommentList {
     pub pr_number: u64,
     pub pr_url: String,
     pub comments: Vec<ReviewComment>,
+    /// Collapsed threads, filled instead of `comments` in summary mode.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub threads: Vec<ThreadSummary>,
 
     /// Number of threads shown so far (cumulative across pagination calls)
     pub shown_threads: usize,
@@ -103,6 +576,10 @@ pub struct ReviewCommentList {
     /// Optional pagination hint message for structured consumers.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+
+    /// Remaining GitHub API quota, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitStatus>,
 }
 
 /// A thread of review comments: a parent comment and its replies.
@@ -114,6 +591,75 @@ pub struct Thread {
     pub is_resolved: bool,
 }
 
+/// Maximum characters kept from a thread's first comment in summary mode.
+pub const SUMMARY_EXCERPT_CHARS: usize = 120;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ThreadStatus {
+    Unresolved,
+    Resolved,
+}
+
+/// One review thread collapsed for `gh_get_comments` summary mode.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct ThreadSummary {
+    /// ID of the thread's first comment (use with `gh_add_comment_reply`)
+    pub comment_id: u64,
+    pub file: String,
+    pub line: Option<u64>,
+    pub status: ThreadStatus,
+    /// Commenters in order of first appearance
+    pub participants: Vec<String>,
+    pub comment_count: usize,
+    pub first_comment_excerpt: String,
+    /// Latest `updated_at` across the thread
+    pub last_activity: String,
+}
+
+impl ThreadSummary {
+    pub fn from_thread(thread: &Thread) -> Self {
+        let comments = || std::iter::once(&thread.parent).chain(&thread.replies);
+        let mut participants: Vec<String> = Vec::new();
+        for c in comments() {
+            if !c.user.is_empty() && !participants.contains(&c.user) {
+                participants.push(c.user.clone());
+            }
+        }
+        let last_activity = comments()
+            .max_by_key(|c| chrono::DateTime::parse_from_rfc3339(&c.updated_at).ok())
+            .map(|c| c.updated_at.clone())
+            .unwrap_or_default();
+
+        Self {
+            comment_id: thread.parent.id,
+            file: thread.parent.path.clone(),
+            line: thread.parent.line,
+            status: if thread.is_resolved {
+                ThreadStatus::Resolved
+            } else {
+                ThreadStatus::Unresolved
+            },
+            participants,
+            comment_count: 1 + thread.replies.len(),
+            first_comment_excerpt: excerpt(&thread.parent.body, SUMMARY_EXCERPT_CHARS),
+            last_activity,
+        }
+    }
+}
+
+/// Collapse whitespace and cut to `max_chars`, marking the cut with an ellipsis.
+fn excerpt(body: &str, max_chars: usize) -> String {
+    let flat = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= max_chars {
+        return flat;
+    }
+    let mut cut: String = flat.chars().take(max_chars.saturating_sub(1)).collect();
+    cut.truncate(cut.trim_end().len());
+    cut.push('…');
+    cut
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PrSummaryList {
     pub owner: String,
@@ -127,6 +673,10 @@ pub struct PrSummaryList {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
+
+    /// Remaining GitHub API quota, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitStatus>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -265,9 +815,17 @@ impl ReviewCommentList {
             total_threads = self.total_threads
         );
 
+        if !self.threads.is_empty() {
+            self.fmt_summary(&mut out);
+            return out.trim_end().to_string();
+        }
+
         if self.comments.is_empty() {
             let _ = writeln!(out, "No matching review comment threads.");
             let _ = writeln!(out, "{}", self.pagination_footer());
+            if let Some(footer) = rate_limit_footer(self.rate_limit.as_ref()) {
+                let _ = writeln!(out, "{footer}");
+            }
             return out.trim_end().to_string();
         }
 
@@ -344,9 +902,47 @@ impl ReviewCommentList {
         }
 
         let _ = writeln!(out, "\n{}", self.pagination_footer());
+        if let Some(footer) = rate_limit_footer(self.rate_limit.as_ref()) {
+            let _ = writeln!(out, "{footer}");
+        }
         out.trim_end().to_string()
     }
 
+    fn fmt_summary(&self, out: &mut String) {
+        let mut by_file: BTreeMap<&str, Vec<&ThreadSummary>> = BTreeMap::new();
+        for t in &self.threads {
+            by_file.entry(&t.file).or_default().push(t);
+        }
+        for (file, threads) in by_file {
+            let _ = writeln!(out, "\n{file}");
+            for t in threads {
+                let line = t.line.map_or_else(|| "?".into(), |n| n.to_string());
+                let status = match t.status {
+                    ThreadStatus::Unresolved => "open",
+                    ThreadStatus::Resolved => "resolved",
+                };
+                let participants = if t.participants.is_empty() {
+                    "<unknown>".to_string()
+                } else {
+                    t.participants.join(", ")
+                };
+                let _ = writeln!(
+                    out,
+                    "  [{line}] {status} #{id} {participants} ({count}, last {last}): {excerpt}",
+                    id = t.comment_id,
+                    count = t.comment_count,
+                    last = fmt_ts(&t.last_activity),
+                    excerpt = t.first_comment_excerpt
+                );
+            }
+        }
+
+        let _ = writeln!(out, "\n{}", self.pagination_footer());
+        if let Some(footer) = rate_limit_footer(self.rate_limit.as_ref()) {
+            let _ = writeln!(out, "{footer}");
+        }
+    }
+
     fn pagination_footer(&self) -> String {
         if self.has_more {
             format!(
@@ -393,6 +989,9 @@ impl PrSummaryList {
         if self.prs.is_empty() {
             let _ = writeln!(out, "No matching pull requests.");
             let _ = writeln!(out, "{}", self.pagination_footer());
+            if let Some(footer) = rate_limit_footer(self.rate_limit.as_ref()) {
+                let _ = writeln!(out, "{footer}");
+            }
             return out.trim_end().to_string();
         }
 
@@ -421,6 +1020,9 @@ impl PrSummaryList {
         }
 
         let _ = writeln!(out, "\n{}", self.pagination_footer());
+        if let Some(footer) = rate_limit_footer(self.rate_limit.as_ref()) {
+            let _ = writeln!(out, "{footer}");
+        }
         out.trim_end().to_string()
     }
 
@@ -481,6 +1083,375 @@ impl TextFormat for ReviewComment {
     }
 }
 
+impl TextFormat for PostedIssueComment {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let opts = FormatOptions::get();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "{}", fmt_header("Comment posted:"));
+        let mut head = format!(
+            "PR #{pr} {user}",
+            pr = self.pr_number,
+            user = fmt_user(&self.user)
+        );
+        if opts.show_ids {
+            let _ = write!(head, " #{id}", id = self.id);
+        }
+        if opts.show_urls {
+            let _ = write!(head, " {html_url}", html_url = self.html_url);
+        }
+        if opts.show_dates {
+            let _ = write!(head, " @{}", fmt_ts(&self.created_at));
+        }
+        let _ = writeln!(out, "{head}");
+        let body = indent_multiline(&self.body, "  ");
+        let _ = writeln!(out, "  {body}");
+
+        out
+    }
+}
+
+impl TextFormat for PostedReaction {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        format!(
+            "Reacted {content} to {kind} comment #{id} as {user}.",
+            content = self.content,
+            kind = match self.comment_kind {
+                CommentKind::Review => "review",
+                CommentKind::Issue => "issue",
+            },
+            id = self.comment_id,
+            user = fmt_user(&self.user)
+        )
+    }
+}
+
+impl TextFormat for EditedComment {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let opts = FormatOptions::get();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "{}", fmt_header("Comment edited:"));
+        let mut head = format!("#{id} {user}", id = self.id, user = fmt_user(&self.user));
+        if opts.show_urls {
+            let _ = write!(head, " {html_url}", html_url = self.html_url);
+        }
+        if opts.show_dates {
+            let _ = write!(head, " @{}", fmt_ts(&self.updated_at));
+        }
+        let _ = writeln!(out, "{head}");
+        let body = indent_multiline(&self.body, "  ");
+        let _ = writeln!(out, "  {body}");
+
+        out
+    }
+}
+
+impl TextFormat for SubmittedReview {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let opts = FormatOptions::get();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "{}", fmt_header("Review submitted:"));
+        let mut head = format!(
+            "PR #{pr} {state} {user} ({n} inline comment{s})",
+            pr = self.pr_number,
+            state = self.state,
+            user = fmt_user(&self.user),
+            n = self.inline_comments,
+            s = if self.inline_comments == 1 { "" } else { "s" },
+        );
+        if opts.show_ids {
+            let _ = write!(head, " #{id}", id = self.id);
+        }
+        if opts.show_urls {
+            let _ = write!(head, " {html_url}", html_url = self.html_url);
+        }
+        if opts.show_dates
+            && let Some(ts) = &self.submitted_at
+        {
+            let _ = write!(head, " @{}", fmt_ts(ts));
+        }
+        let _ = writeln!(out, "{head}");
+        if !self.body.is_empty() {
+            let body = indent_multiline(&self.body, "  ");
+            let _ = writeln!(out, "  {body}");
+        }
+
+        out
+    }
+}
+
+impl TextFormat for PrDetails {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}",
+            fmt_header(&format!(
+                "{owner}/{repo}#{number}: {title}",
+                owner = self.owner,
+                repo = self.repo,
+                number = self.number,
+                title = self.title
+            ))
+        );
+        let _ = writeln!(out, "PR: {url}", url = self.url);
+        let draft = if self.is_draft { " (draft)" } else { "" };
+        let _ = writeln!(
+            out,
+            "State: {state}{draft} by {author}",
+            state = self.state,
+            author = fmt_user(&self.author)
+        );
+        let short_sha = self.head_sha.get(..7).unwrap_or(&self.head_sha);
+        let _ = writeln!(
+            out,
+            "Branches: {base} <- {head} @{short_sha}",
+            base = self.base_ref,
+            head = self.head_ref
+        );
+        let mergeable = match self.mergeable {
+            Some(true) => "yes",
+            Some(false) => "no",
+            None => "unknown",
+        };
+        let _ = writeln!(
+            out,
+            "Changes: +{additions} -{deletions} in {files} file(s), {commits} commit(s); mergeable: {mergeable}",
+            additions = self.additions,
+            deletions = self.deletions,
+            files = self.changed_files,
+            commits = self.commits
+        );
+        if self.reviewers.is_empty() {
+            let _ = writeln!(out, "Reviewers: none");
+        } else {
+            let reviewers = self
+                .reviewers
+                .iter()
+                .map(|r| format!("{} ({})", r.login, r.state))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let _ = writeln!(out, "Reviewers: {reviewers}");
+        }
+        let c = &self.checks;
+        let _ = writeln!(
+            out,
+            "Checks: {total} suite(s) — {passed} passed, {failed} failed, {pending} pending, {other} other",
+            total = c.total,
+            passed = c.passed,
+            failed = c.failed,
+            pending = c.pending,
+            other = c.other
+        );
+        if !self.body.trim().is_empty() {
+            let body = indent_multiline(self.body.trim_end(), "  ");
+            let _ = writeln!(out, "\nDescription:\n  {body}");
+        }
+        out.trim_end().to_string()
+    }
+}
+
+fn fmt_file_line(file: &PrFile) -> String {
+    let mut line = format!(
+        "{status:<9} +{additions} -{deletions} {filename}",
+        status = file.status,
+        additions = file.additions,
+        deletions = file.deletions,
+        filename = file.filename
+    );
+    if let Some(prev) = &file.previous_filename {
+        let _ = write!(line, " (from {prev})");
+    }
+    line
+}
+
+fn files_pagination_footer(shown: usize, total: usize, has_more: bool, tool: &str) -> String {
+    if has_more {
+        format!(
+            "(more results — showing {shown} of {total} files; call {tool} again with same params for next page)"
+        )
+    } else {
+        format!(
+            "(complete — showing {shown} of {total} files; stop here; another identical {tool} call restarts from page 1)"
+        )
+    }
+}
+
+impl TextFormat for PrFileList {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}",
+            fmt_header(&format!(
+                "Changed files for {owner}/{repo}#{pr_number}:",
+                owner = self.owner,
+                repo = self.repo,
+                pr_number = self.pr_number
+            ))
+        );
+        if self.files.is_empty() {
+            let _ = writeln!(out, "No changed files.");
+        }
+        for file in &self.files {
+            let _ = writeln!(out, "{}", fmt_file_line(file));
+        }
+        let _ = writeln!(
+            out,
+            "\n{}",
+            files_pagination_footer(
+                self.shown_files,
+                self.total_files,
+                self.has_more,
+                "gh_get_changed_files"
+            )
+        );
+        out.trim_end().to_string()
+    }
+}
+
+impl TextFormat for PrDiff {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}",
+            fmt_header(&format!(
+                "Diff for {owner}/{repo}#{pr_number}:",
+                owner = self.owner,
+                repo = self.repo,
+                pr_number = self.pr_number
+            ))
+        );
+        if self.files.is_empty() {
+            let _ = writeln!(out, "No matching files.");
+        }
+        for file in &self.files {
+            let old = file.previous_filename.as_deref().unwrap_or(&file.filename);
+            let _ = writeln!(out, "\ndiff --git a/{old} b/{new}", new = file.filename);
+            match &file.patch {
+                Some(patch) => {
+                    let _ = writeln!(out, "{}", patch.trim_end());
+                }
+                None => {
+                    let _ = writeln!(
+                        out,
+                        "(no patch available: binary or too large; {})",
+                        fmt_file_line(file)
+                    );
+                }
+            }
+        }
+        let _ = writeln!(
+            out,
+            "\n{}",
+            files_pagination_footer(
+                self.shown_files,
+                self.total_files,
+                self.has_more,
+                "gh_get_pr_diff"
+            )
+        );
+        out.trim_end().to_string()
+    }
+}
+
+impl TextFormat for CheckRunList {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let opts = FormatOptions::get();
+        let mut out = String::new();
+        let short_sha = self.head_sha.get(..7).unwrap_or(&self.head_sha);
+        let _ = writeln!(
+            out,
+            "{}",
+            fmt_header(&format!(
+                "Checks for {owner}/{repo}#{pr} @{short_sha}:",
+                owner = self.owner,
+                repo = self.repo,
+                pr = self.pr_number
+            ))
+        );
+        let c = &self.summary;
+        let _ = writeln!(
+            out,
+            "{total} run(s) — {passed} passed, {failed} failed, {pending} pending, {other} other",
+            total = c.total,
+            passed = c.passed,
+            failed = c.failed,
+            pending = c.pending,
+            other = c.other
+        );
+        if self.check_runs.is_empty() {
+            let _ = writeln!(out, "No check runs.");
+        }
+        for run in &self.check_runs {
+            let state = run.conclusion.as_deref().unwrap_or(&run.status);
+            let mut line = format!("{state:<10} {name} #{id}", name = run.name, id = run.id);
+            if let Some(app) = &run.app_slug {
+                let _ = write!(line, " ({app})");
+            }
+            if opts.show_urls
+                && let Some(url) = &run.html_url
+            {
+                let _ = write!(line, " {url}");
+            }
+            let _ = writeln!(out, "{line}");
+            if let Some(title) = &run.output_title
+                && is_failing_conclusion(run.conclusion.as_deref())
+            {
+                let _ = writeln!(out, "  {title}");
+            }
+        }
+        if c.failed > 0 {
+            let _ = writeln!(
+                out,
+                "\nUse gh_get_check_log with a failing run's #id to see its log."
+            );
+        }
+        out.trim_end().to_string()
+    }
+}
+
+impl TextFormat for CheckLog {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{}",
+            fmt_header(&format!(
+                "Log for check run #{id} ({total} lines):",
+                id = self.check_run_id,
+                total = self.total_lines
+            ))
+        );
+        if !self.error_lines.is_empty() {
+            let _ = writeln!(out, "Errors:");
+            for line in &self.error_lines {
+                let _ = writeln!(out, "  {line}");
+            }
+            let _ = writeln!(out);
+        }
+        if self.truncated {
+            let _ = writeln!(out, "... (earlier lines omitted)");
+        }
+        let _ = writeln!(out, "{}", self.tail);
+        out.trim_end().to_string()
+    }
+}
+
+impl TextFormat for ResolvedThread {
+    fn fmt_text(&self, _opts: &TextOptions) -> String {
+        let state = if self.is_resolved {
+            "resolved"
+        } else {
+            "unresolved"
+        };
+        format!("Thread {id} {state}.", id = self.thread_id)
+    }
+}
+
 impl From<octocrab::models::pulls::Comment> for ReviewComment {
     fn from(comment: octocrab::models::pulls::Comment) -> Self {
         let (user, is_bot) = match comment.user {
@@ -597,6 +1568,24 @@ pub struct MarkPullRequestReadyForReviewPayload {
     pub pull_request: OpenPrRefNode,
 }
 
+/// Response for `resolveReviewThread`/`unresolveReviewThread`, aliased to `result`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewThreadResolutionData {
+    pub result: ReviewThreadResolutionPayload,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewThreadResolutionPayload {
+    pub thread: ReviewThreadState,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewThreadState {
+    pub id: String,
+    #[serde(rename = "isResolved")]
+    pub is_resolved: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Repository {
     #[serde(rename = "pullRequest")]
@@ -748,6 +1737,7 @@ mod mcp_format_tests {
             pr_number: 123,
             pr_url: "https://github.com/octo/hello-world/pull/123".into(),
             comments,
+            threads: vec![],
             shown_threads,
             total_threads,
             has_more,
@@ -756,6 +1746,7 @@ mod mcp_format_tests {
                     "Showing {shown_threads} out of {total_threads} threads. Call gh_get_comments again for more."
                 )
             }),
+            rate_limit: None,
         }
     }
 
@@ -780,6 +1771,7 @@ mod mcp_format_tests {
                     "Showing {shown_prs} out of {total_prs} pull requests. Call gh_get_prs again for more."
                 )
             }),
+            rate_limit: None,
         }
     }
 
@@ -897,6 +1889,28 @@ mod mcp_format_tests {
         assert!(text.contains("call gh_get_comments again with same params"));
     }
 
+    #[test]
+    fn format_pr_summary_list_warns_only_when_quota_is_low() {
+        let mut list = sample_pr_summary_list(vec![], false);
+        list.rate_limit = Some(RateLimitStatus {
+            resource: "core".into(),
+            limit: 5000,
+            remaining: 4000,
+            reset_at: 0,
+        });
+        let text = list.fmt_text_with_options(&FormatOptions::default());
+        assert!(!text.contains("GitHub API quota"));
+
+        list.rate_limit = Some(RateLimitStatus {
+            resource: "core".into(),
+            limit: 5000,
+            remaining: 12,
+            reset_at: 0,
+        });
+        let text = list.fmt_text_with_options(&FormatOptions::default());
+        assert!(text.contains("Warning: GitHub API quota (core): 12/5000 remaining"));
+    }
+
     #[test]
     fn format_review_comment_list_hides_reply_urls_by_default() {
         let list = sample_review_list(
@@ -970,10 +1984,12 @@ mod mcp_format_tests {
             pr_number: 123,
             pr_url: "https://github.com/octo/hello-world/pull/123".into(),
             comments: vec![],
+            threads: vec![],
             shown_threads: 0,
             total_threads: 0,
             has_more: false,
             message: None,
+            rate_limit: None,
         };
 
         let text = list.fmt_text_with_options(&FormatOptions::default());
@@ -1039,6 +2055,7 @@ mod mcp_format_tests {
             total_prs: 0,
             has_more: false,
             message: None,
+            rate_limit: None,
         };
 
         let text = list.fmt_text_with_options(&FormatOptions::default());
@@ -1076,10 +2093,12 @@ mod mcp_format_tests {
             pr_number: 123,
             pr_url: "https://github.com/octo/hello-world/pull/123".into(),
             comments: vec![],
+            threads: vec![],
             shown_threads: 0,
             total_threads: 0,
             has_more: false,
             message: None,
+            rate_limit: None,
         };
         let s = match serde_json::to_string(&w) {
             Ok(json) => json,
@@ -1106,10 +2125,12 @@ mod mcp_format_tests {
             pr_number: 123,
             pr_url: "https://github.com/octo/hello-world/pull/123".into(),
             comments: vec![],
+            threads: vec![],
             shown_threads: 5,
             total_threads: 5,
             has_more: false,
             message: None,
+            rate_limit: None,
         };
         assert!(list_complete.message.is_none());
 
@@ -1121,10 +2142,12 @@ mod mcp_format_tests {
             pr_number: 123,
             pr_url: "https://github.com/octo/hello-world/pull/123".into(),
             comments: vec![],
+            threads: vec![],
             shown_threads: 5,
             total_threads: 15,
             has_more: true,
             message: Some(msg.clone()),
+            rate_limit: None,
         };
         assert!(list_partial.message.is_some());
         let Some(m) = list_partial.message else {
@@ -1140,10 +2163,12 @@ mod mcp_format_tests {
             pr_number: 123,
             pr_url: "https://github.com/octo/hello-world/pull/123".into(),
             comments: vec![],
+            threads: vec![],
             shown_threads: 5,
             total_threads: 15,
             has_more: true,
             message: Some(msg),
+            rate_limit: None,
         };
         let s = match serde_json::to_string(&list_with_msg) {
             Ok(json) => json,
@@ -1151,4 +2176,241 @@ mod mcp_format_tests {
         };
         assert!(s.contains("\"message\""));
     }
+
+    fn suite(status: &str, conclusion: Option<&str>) -> CheckSuiteSummary {
+        CheckSuiteSummary {
+            id: 1,
+            status: status.into(),
+            conclusion: conclusion.map(Into::into),
+            app_slug: None,
+            updated_at: "2026-01-01T00:00:00Z".into(),
+        }
+    }
+
+    fn review(user: &str, state: &str) -> PullRequestReviewSummary {
+        PullRequestReviewSummary {
+            id: 1,
+            user_login: user.into(),
+            user_type: None,
+            state: state.into(),
+            submitted_at: None,
+            commit_id: None,
+        }
+    }
+
+    #[test]
+    fn checks_summary_buckets_suites() {
+        let summary = ChecksSummary::from_suites(&[
+            suite("completed", Some("success")),
+            suite("completed", Some("failure")),
+            suite("completed", Some("skipped")),
+            suite("in_progress", None),
+        ]);
+        assert_eq!(
+            summary,
+            ChecksSummary {
+                total: 4,
+                passed: 1,
+                failed: 1,
+                pending: 1,
+                other: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn summarize_reviewers_keeps_verdict_over_later_comments_and_marks_requests() {
+        let reviewers = summarize_reviewers(
+            &[
+                review("alice", "APPROVED"),
+                review("alice", "COMMENTED"),
+                review("bob", "COMMENTED"),
+                review("bob", "CHANGES_REQUESTED"),
+                review("carol", "APPROVED"),
+            ],
+            &["carol".to_string(), "team:core".to_string()],
+        );
+        let states: Vec<(&str, &str)> = reviewers
+            .iter()
+            .map(|r| (r.login.as_str(), r.state.as_str()))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                ("alice", "APPROVED"),
+                ("bob", "CHANGES_REQUESTED"),
+                ("carol", "REQUESTED"),
+                ("team:core", "REQUESTED"),
+            ]
+        );
+    }
+
+    #[test]
+    fn pr_diff_text_renders_git_headers_and_missing_patches() {
+        let diff = PrDiff {
+            owner: "o".into(),
+            repo: "r".into(),
+            pr_number: 3,
+            files: vec![
+                PrFile {
+                    filename: "src/new.rs".into(),
+                    status: "renamed".into(),
+                    additions: 1,
+                    deletions: 1,
+                    previous_filename: Some("src/old.rs".into()),
+                    patch: Some("@@ -1 +1 @@\n-a\n+b".into()),
+                },
+                PrFile {
+                    filename: "logo.png".into(),
+                    status: "added".into(),
+                    additions: 0,
+                    deletions: 0,
+                    previous_filename: None,
+                    patch: None,
+                },
+            ],
+            shown_files: 2,
+            total_files: 2,
+            has_more: false,
+            message: None,
+        };
+        let text = diff.fmt_text(&TextOptions::default());
+        assert!(text.contains("diff --git a/src/old.rs b/src/new.rs\n@@ -1 +1 @@"));
+        assert!(text.contains("diff --git a/logo.png b/logo.png\n(no patch available"));
+        assert!(text.contains("complete — showing 2 of 2 files"));
+    }
+
+    fn run(name: &str, status: &str, conclusion: Option<&str>) -> CheckRunSummary {
+        CheckRunSummary {
+            id: 1,
+            name: name.into(),
+            status: status.into(),
+            conclusion: conclusion.map(Into::into),
+            app_slug: None,
+            started_at: None,
+            completed_at: None,
+            html_url: None,
+            output_title: None,
+        }
+    }
+
+    #[test]
+    fn check_runs_sort_failing_then_pending_then_rest() {
+        let mut runs = vec![
+            run("lint", "completed", Some("success")),
+            run("test", "in_progress", None),
+            run("build", "completed", Some("failure")),
+            run("docs", "completed", Some("skipped")),
+        ];
+        CheckRunList::sort_runs(&mut runs);
+        let names: Vec<&str> = runs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["build", "test", "docs", "lint"]);
+    }
+
+    #[test]
+    fn check_log_strips_timestamps_collects_errors_and_tails() {
+        let raw = "\u{feff}2026-01-01T00:00:00.0000000Z ##[group]Run cargo test\n\
+                   2026-01-01T00:00:01.0000000Z running 2 tests\n\
+                   2026-01-01T00:00:02.0000000Z ##[error]test foo failed\n\
+                   2026-01-01T00:00:03.0000000Z done";
+        let log = CheckLog::from_raw(9, raw, 2);
+        assert_eq!(log.total_lines, 4);
+        assert_eq!(
+            log.error_lines,
+            vec!["##[error]test foo failed".to_string()]
+        );
+        assert_eq!(log.tail, "##[error]test foo failed\ndone");
+        assert!(log.truncated);
+
+        let full = CheckLog::from_raw(9, raw, 10);
+        assert!(!full.truncated);
+        assert!(full.tail.starts_with("##[group]Run cargo test"));
+    }
+
+    #[test]
+    fn thread_summary_collapses_thread() {
+        let mut reply = sample_review(
+            2,
+            "src/lib.rs",
+            Some(10),
+            Some("RIGHT"),
+            "bob",
+            "done",
+            "https://x/2",
+            Some(1),
+        );
+        reply.updated_at = "2025-02-01T00:00:00Z".into();
+        let again = sample_review(
+            3,
+            "src/lib.rs",
+            Some(10),
+            Some("RIGHT"),
+            "alice",
+            "thanks",
+            "https://x/3",
+            Some(1),
+        );
+        let thread = Thread {
+            parent: sample_review(
+                1,
+                "src/lib.rs",
+                Some(10),
+                Some("RIGHT"),
+                "alice",
+                &format!("Please   handle\nthe error {}", "x".repeat(200)),
+                "https://x/1",
+                None,
+            ),
+            replies: vec![reply, again],
+            is_resolved: true,
+        };
+
+        let summary = ThreadSummary::from_thread(&thread);
+        assert_eq!(summary.comment_id, 1);
+        assert_eq!(summary.file, "src/lib.rs");
+        assert_eq!(summary.line, Some(10));
+        assert_eq!(summary.status, ThreadStatus::Resolved);
+        assert_eq!(summary.participants, vec!["alice", "bob"]);
+        assert_eq!(summary.comment_count, 3);
+        assert_eq!(summary.last_activity, "2025-02-01T00:00:00Z");
+        assert!(
+            summary
+                .first_comment_excerpt
+                .starts_with("Please handle the error x")
+        );
+        assert!(summary.first_comment_excerpt.ends_with('…'));
+        assert_eq!(
+            summary.first_comment_excerpt.chars().count(),
+            SUMMARY_EXCERPT_CHARS
+        );
+    }
+
+    #[test]
+    fn review_comment_list_renders_summary_lines() {
+        let mut list = sample_review_list(vec![], false);
+        list.threads = vec![ThreadSummary {
+            comment_id: 7,
+            file: "src/net.rs".into(),
+            line: None,
+            status: ThreadStatus::Unresolved,
+            participants: vec!["alice".into(), "bob".into()],
+            comment_count: 2,
+            first_comment_excerpt: "Retry on 503?".into(),
+            last_activity: "2025-01-02T00:00:00Z".into(),
+        }];
+        list.shown_threads = 1;
+        list.total_threads = 1;
+
+        let text = list.fmt_text_with_options(&FormatOptions::default());
+        assert!(text.contains("\nsrc/net.rs\n"));
+        assert!(
+            text.contains("  [?] open #7 alice, bob (2, last 2025-01-02T00:00:00Z): Retry on 503?")
+        );
+        assert!(!text.contains("Legend"));
+        assert!(text.contains("(complete — showing 1 of 1 threads"));
+
+        let json = serde_json::to_value(&list).unwrap();
+        assert_eq!(json["threads"][0]["status"], "unresolved");
+        assert_eq!(json["comments"], serde_json::json!([]));
+    }
 }
@@ -16,13 +16,14 @@ pub fn make_key(
     src: CommentSourceType,
     include_resolved: bool,
     page_size: usize,
+    filters: &str,
 ) -> String {
     let src_str = match src {
         CommentSourceType::Robot => "robot",
         CommentSourceType::Human => "human",
         CommentSourceType::All => "all",
     };
-    format!("{owner}|{repo}|{pr}|{src_str}|{include_resolved}|{page_size}")
+    format!("{owner}|{repo}|{pr}|{src_str}|{include_resolved}|{page_size}|{filters}")
 }
 
 /// Generate a cache key for PR list pagination from query parameters.
@@ -30,6 +31,18 @@ pub fn make_pr_list_key(owner: &str, repo: &str, state: &str, page_size: usize)
     format!("{owner}|{repo}|{state}|{page_size}")
 }
 
+/// Page size (threads per page) for `gh_get_comments` summary mode; entries are one line each.
+pub const SUMMARY_PAGE_SIZE: usize = 50;
+
+/// Page size (files per page) for `gh_get_changed_files`; entries are one line each.
+pub const FILE_LIST_PAGE_SIZE: usize = 100;
+
+/// Generate a cache key for PR file pagination. `view` distinguishes the
+/// changed-files listing from diff queries (which include their path filter).
+pub fn make_pr_files_key(owner: &str, repo: &str, pr: u64, view: &str, page_size: usize) -> String {
+    format!("{owner}|{repo}|{pr}|{view}|{page_size}")
+}
+
 // Re-export pagination types from utils for convenience
 pub use agentic_tools_utils::pagination::DEFAULT_TTL;
 pub use agentic_tools_utils::pagination::PaginationCache;
@@ -43,12 +56,31 @@ mod tests {
 
     #[test]
     fn make_key_generates_consistent_key() {
-        let key1 = make_key("owner", "repo", 123, CommentSourceType::All, false, 10);
-        let key2 = make_key("owner", "repo", 123, CommentSourceType::All, false, 10);
+        let key1 = make_key("owner", "repo", 123, CommentSourceType::All, false, 10, "");
+        let key2 = make_key("owner", "repo", 123, CommentSourceType::All, false, 10, "");
         assert_eq!(key1, key2);
 
-        let key3 = make_key("owner", "repo", 123, CommentSourceType::Robot, false, 10);
+        let key3 = make_key(
+            "owner",
+            "repo",
+            123,
+            CommentSourceType::Robot,
+            false,
+            10,
+            "",
+        );
         assert_ne!(key1, key3);
+
+        let key4 = make_key(
+            "owner",
+            "repo",
+            123,
+            CommentSourceType::All,
+            false,
+            10,
+            "src/**||",
+        );
+        assert_ne!(key1, key4);
     }
 
     #[test]
@@ -61,6 +93,17 @@ mod tests {
         assert_ne!(key1, key3);
     }
 
+    #[test]
+    fn make_pr_files_key_separates_views() {
+        let files = make_pr_files_key("owner", "repo", 7, "files", 100);
+        let diff = make_pr_files_key("owner", "repo", 7, "diff:", 10);
+        let diff_filtered = make_pr_files_key("owner", "repo", 7, "diff:src/lib.rs", 10);
+
+        assert_eq!(files, make_pr_files_key("owner", "repo", 7, "files", 100));
+        assert_ne!(files, diff);
+        assert_ne!(diff, diff_filtered);
+    }
+
     #[test]
     fn comment_source_type_serde_roundtrip() {
         use serde_json;
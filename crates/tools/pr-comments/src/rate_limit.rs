@@ -0,0 +1,288 @@
+//! GitHub rate-limit tracking and retry/backoff.
+//!
+//! Every REST/GraphQL response updates a shared [`RateLimitTracker`] from its
+//! `x-ratelimit-*` headers so outputs can report remaining quota. Rate-limited
+//! responses (primary quota exhausted, or a secondary burst limit) are retried
+//! after `Retry-After`, the quota reset, or an exponential backoff, as long as the
+//! wait fits the [`RetryPolicy`] budget. Octocrab-backed calls get the equivalent
+//! behaviour from octocrab's own `HandleRateLimits` retry layer.
+
+use crate::models::RateLimitStatus;
+use agentic_config::types::GitHubServiceConfig;
+use octocrab::service::middleware::retry::RateLimitMetrics;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// First backoff step for secondary rate limits that carry no `Retry-After`.
+const SECONDARY_BACKOFF_BASE: Duration = Duration::from_secs(2);
+
+/// How many rate-limited responses to retry and how long a single wait may be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub max_wait: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from_config(&GitHubServiceConfig::default())
+    }
+}
+
+impl RetryPolicy {
+    pub fn from_config(cfg: &GitHubServiceConfig) -> Self {
+        Self {
+            max_retries: cfg.rate_limit_max_retries,
+            max_wait: Duration::from_secs(cfg.rate_limit_max_wait_secs),
+        }
+    }
+}
+
+/// Latest quota seen per rate-limit resource, shared by every client using one token.
+#[derive(Debug, Default)]
+pub struct RateLimitTracker {
+    latest: Mutex<HashMap<String, RateLimitStatus>>,
+}
+
+impl RateLimitTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the quota from a response's headers, if present.
+    pub fn record(&self, headers: &HeaderMap) {
+        if let Some(status) = parse_status(headers) {
+            self.latest
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .insert(status.resource.clone(), status);
+        }
+    }
+
+    /// The most constrained resource seen so far (lowest fraction of quota left).
+    pub fn snapshot(&self) -> Option<RateLimitStatus> {
+        self.latest
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .min_by_key(|s| {
+                // remaining/limit compared without floats: scale to parts per million
+                s.remaining.saturating_mul(1_000_000) / s.limit.max(1)
+            })
+            .cloned()
+    }
+}
+
+impl RateLimitMetrics for RateLimitTracker {
+    fn retry_after_error(
+        &self,
+        req: &http::Request<octocrab::OctoBody>,
+        status_code: http::StatusCode,
+        retries_remaining: usize,
+    ) {
+        tracing::debug!(
+            uri = %req.uri(),
+            status = status_code.as_u16(),
+            retries_remaining,
+            "GitHub request failed; retrying"
+        );
+    }
+
+    fn rate_limited(
+        &self,
+        req: &http::Request<octocrab::OctoBody>,
+        status_code: http::StatusCode,
+        retries_remaining: usize,
+        waiting_seconds: u64,
+    ) {
+        tracing::warn!(
+            uri = %req.uri(),
+            status = status_code.as_u16(),
+            retries_remaining,
+            waiting_seconds,
+            "GitHub rate limit hit; backing off"
+        );
+    }
+}
+
+/// Which GitHub limit a response tripped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKind {
+    /// Hourly quota exhausted (`x-ratelimit-remaining: 0`).
+    Primary,
+    /// Burst/concurrency limit, signalled by `Retry-After`, a 429, or the error message.
+    Secondary,
+}
+
+impl RateLimitKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+        }
+    }
+}
+
+/// Parse `x-ratelimit-*` headers.
+pub fn parse_status(headers: &HeaderMap) -> Option<RateLimitStatus> {
+    Some(RateLimitStatus {
+        resource: header_str(headers, "x-ratelimit-resource")
+            .unwrap_or("core")
+            .to_string(),
+        limit: header_u64(headers, "x-ratelimit-limit")?,
+        remaining: header_u64(headers, "x-ratelimit-remaining")?,
+        reset_at: header_u64(headers, "x-ratelimit-reset")?,
+    })
+}
+
+/// Classify a failed response and compute how long to wait before retrying.
+/// Returns `None` for failures that are not rate limits (e.g. a genuine 403).
+pub fn retry_delay(
+    status: StatusCode,
+    headers: &HeaderMap,
+    body: &str,
+    attempt: u32,
+    now_unix: u64,
+) -> Option<(RateLimitKind, Duration)> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+
+    if let Some(secs) = header_u64(headers, "retry-after") {
+        return Some((RateLimitKind::Secondary, Duration::from_secs(secs)));
+    }
+    if header_u64(headers, "x-ratelimit-remaining") == Some(0)
+        && let Some(reset) = header_u64(headers, "x-ratelimit-reset")
+    {
+        let wait = reset.saturating_sub(now_unix).max(1);
+        return Some((RateLimitKind::Primary, Duration::from_secs(wait)));
+    }
+    let lc = body.to_ascii_lowercase();
+    if status == StatusCode::TOO_MANY_REQUESTS
+        || lc.contains("secondary rate limit")
+        || lc.contains("abuse detection")
+    {
+        let backoff = SECONDARY_BACKOFF_BASE.saturating_mul(2u32.saturating_pow(attempt));
+        return Some((RateLimitKind::Secondary, backoff));
+    }
+    None
+}
+
+/// Error for a rate limit we will not (or can no longer) wait out.
+pub fn rate_limited_error(
+    kind: RateLimitKind,
+    label: &str,
+    wait: Duration,
+    retries: u32,
+    status: Option<&RateLimitStatus>,
+    now_unix: u64,
+) -> anyhow::Error {
+    let mut msg = format!(
+        "rate limited: GitHub {kind} rate limit hit during {label}; retry after {secs}s (gave up after {retries} retries)",
+        kind = kind.as_str(),
+        secs = wait.as_secs()
+    );
+    if let Some(status) = status {
+        msg.push('\n');
+        msg.push_str(&status.summary(now_unix));
+    }
+    anyhow::anyhow!(msg)
+}
+
+pub fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name)?.to_str().ok()
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    header_str(headers, name)?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (k, v) in pairs {
+            map.insert(*k, HeaderValue::from_str(v).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn retry_delay_prefers_retry_after() {
+        let h = headers(&[("retry-after", "7"), ("x-ratelimit-remaining", "0")]);
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &h, "", 0, 0),
+            Some((RateLimitKind::Secondary, Duration::from_secs(7)))
+        );
+    }
+
+    #[test]
+    fn retry_delay_waits_for_primary_reset() {
+        let h = headers(&[
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1060"),
+        ]);
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &h, "", 0, 1000),
+            Some((RateLimitKind::Primary, Duration::from_secs(60)))
+        );
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially_for_secondary_message() {
+        let body = r#"{"message":"You have exceeded a secondary rate limit."}"#;
+        let h = HeaderMap::new();
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &h, body, 2, 0),
+            Some((RateLimitKind::Secondary, Duration::from_secs(8)))
+        );
+    }
+
+    #[test]
+    fn retry_delay_ignores_plain_forbidden_and_other_statuses() {
+        let h = headers(&[("x-ratelimit-remaining", "4999")]);
+        assert_eq!(
+            retry_delay(StatusCode::FORBIDDEN, &h, "Resource not accessible", 0, 0),
+            None
+        );
+        assert_eq!(
+            retry_delay(StatusCode::INTERNAL_SERVER_ERROR, &h, "", 0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn tracker_snapshot_reports_most_constrained_resource() {
+        let tracker = RateLimitTracker::new();
+        assert!(tracker.snapshot().is_none());
+
+        tracker.record(&headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "4000"),
+            ("x-ratelimit-reset", "100"),
+        ]));
+        tracker.record(&headers(&[
+            ("x-ratelimit-resource", "graphql"),
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "10"),
+            ("x-ratelimit-reset", "100"),
+        ]));
+
+        let status = tracker.snapshot().expect("quota recorded");
+        assert_eq!(status.resource, "graphql");
+        assert_eq!(status.remaining, 10);
+        assert!(status.is_low());
+    }
+}
@@ -3,11 +3,27 @@
 //! Each tool delegates to the corresponding method on [`PrComments`].
 
 use crate::PrComments;
+use crate::comment_filter::CommentFilter;
 use crate::logging::ToolLogCtx;
+use crate::models::CheckLog;
+use crate::models::CheckRunList;
+use crate::models::CommentKind;
+use crate::models::CommentOutputMode;
 use crate::models::CommentSourceType;
+use crate::models::EditedComment;
+use crate::models::PostedIssueComment;
+use crate::models::PostedReaction;
+use crate::models::PrDetails;
+use crate::models::PrDiff;
+use crate::models::PrFileList;
 use crate::models::PrSummaryList;
+use crate::models::Reaction;
+use crate::models::ResolvedThread;
 use crate::models::ReviewComment;
 use crate::models::ReviewCommentList;
+use crate::models::ReviewEvent;
+use crate::models::ReviewInlineComment;
+use crate::models::SubmittedReview;
 use agentic_tools_core::Tool;
 use agentic_tools_core::ToolContext;
 use agentic_tools_core::ToolError;
@@ -17,6 +33,28 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use std::sync::Arc;
 
+// ============================================================================
+// Repository targeting
+// ============================================================================
+
+/// Optional repository override accepted by every tool. Each field falls back to
+/// the repository detected from the server's working directory.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct RepoTarget {
+    /// Repository owner (defaults to the current checkout's owner)
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Repository name (defaults to the current checkout's repo). When this names a different repository, `pr_number` must be given explicitly.
+    #[serde(default)]
+    pub repo: Option<String>,
+}
+
+impl RepoTarget {
+    fn scope(&self, pr_comments: &PrComments) -> PrComments {
+        pr_comments.for_repo(self.owner.clone(), self.repo.clone())
+    }
+}
+
 // ============================================================================
 // GetComments Tool
 // ============================================================================
@@ -33,6 +71,20 @@ pub struct GetCommentsInput {
     /// Include resolved review comments (defaults to false)
     #[serde(default)]
     pub include_resolved: Option<bool>,
+    /// Only threads on files matching this glob (e.g., "src/net/**", "**/*.rs")
+    #[serde(default)]
+    pub path_glob: Option<String>,
+    /// Only threads where this GitHub login wrote a comment (case-insensitive)
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Only threads with a comment updated at or after this time: RFC 3339 timestamp, YYYY-MM-DD, or an age like "24h", "7d", "2w"
+    #[serde(default)]
+    pub updated_after: Option<String>,
+    /// Output shape: full (default, every comment body) or summary (one line per thread with file, line, status, participants, excerpt, and last activity)
+    #[serde(default)]
+    pub output: Option<CommentOutputMode>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
 }
 
 /// Tool for fetching PR review comments with pagination.
@@ -51,31 +103,52 @@ impl Tool for GetCommentsTool {
     type Input = GetCommentsInput;
     type Output = ReviewCommentList;
     const NAME: &'static str = "gh_get_comments";
-    const DESCRIPTION: &'static str = "Get PR review comments with thread-level implicit pagination. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1.";
+    const DESCRIPTION: &'static str = "Get PR review comments with thread-level implicit pagination. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1. Use output: \"summary\" for a compact one-line-per-thread overview of what is still open.";
 
     fn call(
         &self,
         input: Self::Input,
-        _ctx: &ToolContext,
+        ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
-        let pr_comments = Arc::clone(&self.pr_comments);
+        let pr_comments = input.target.scope(&self.pr_comments);
+        let ctx = ctx.clone();
         Box::pin(async move {
             let log = ToolLogCtx::start(Self::NAME);
 
             let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
                 "pr_number": input.pr_number,
                 "comment_source_type": input.comment_source_type,
                 "include_resolved": input.include_resolved,
+                "path_glob": input.path_glob,
+                "author": input.author,
+                "updated_after": input.updated_after,
+                "output": input.output,
             });
 
-            match pr_comments
-                .get_comments(
-                    input.pr_number,
-                    input.comment_source_type,
-                    input.include_resolved,
-                )
-                .await
-            {
+            let result = match CommentFilter::parse(
+                input.path_glob.as_deref(),
+                input.author.as_deref(),
+                input.updated_after.as_deref(),
+                chrono::Utc::now(),
+            ) {
+                Ok(filter) => {
+                    pr_comments
+                        .get_comments(
+                            input.pr_number,
+                            input.comment_source_type,
+                            input.include_resolved,
+                            &filter,
+                            input.output.unwrap_or_default(),
+                            &ctx,
+                        )
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+
+            match result {
                 Ok(out) => {
                     log.finish(
                         request,
@@ -84,6 +157,7 @@ impl Tool for GetCommentsTool {
                         None,
                         Some(serde_json::json!({
                             "comments": out.comments.len(),
+                            "summarized_threads": out.threads.len(),
                             "shown_threads": out.shown_threads,
                             "total_threads": out.total_threads,
                             "has_more": out.has_more,
@@ -113,6 +187,8 @@ pub struct ListPrsInput {
     /// PR state filter: open, closed, or all
     #[serde(default)]
     pub state: Option<String>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
 }
 
 /// Tool for listing pull requests in the repository.
@@ -136,17 +212,20 @@ impl Tool for ListPrsTool {
     fn call(
         &self,
         input: Self::Input,
-        _ctx: &ToolContext,
+        ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
-        let pr_comments = Arc::clone(&self.pr_comments);
+        let pr_comments = input.target.scope(&self.pr_comments);
+        let ctx = ctx.clone();
         Box::pin(async move {
             let log = ToolLogCtx::start(Self::NAME);
 
             let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
                 "state": input.state,
             });
 
-            match pr_comments.list_prs(input.state).await {
+            match pr_comments.list_prs(input.state, &ctx).await {
                 Ok(out) => {
                     log.finish(
                         request,
@@ -183,6 +262,8 @@ pub struct AddCommentReplyInput {
     pub comment_id: u64,
     /// Reply message body
     pub body: String,
+    #[serde(flatten)]
+    pub target: RepoTarget,
 }
 
 /// Tool for replying to a PR review comment.
@@ -208,11 +289,13 @@ impl Tool for AddCommentReplyTool {
         input: Self::Input,
         _ctx: &ToolContext,
     ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
-        let pr_comments = Arc::clone(&self.pr_comments);
+        let pr_comments = input.target.scope(&self.pr_comments);
         Box::pin(async move {
             let log = ToolLogCtx::start(Self::NAME);
 
             let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
                 "pr_number": input.pr_number,
                 "comment_id": input.comment_id,
                 "body_len": input.body.len(),
@@ -245,27 +328,865 @@ impl Tool for AddCommentReplyTool {
 }
 
 // ============================================================================
-// Registry Builder
+// AddPrComment Tool
 // ============================================================================
 
-/// Build a `ToolRegistry` containing all `pr_comments` tools.
-pub fn build_registry(pr_comments: Arc<PrComments>) -> ToolRegistry {
-    ToolRegistry::builder()
-        .register::<GetCommentsTool, ()>(GetCommentsTool::new(Arc::clone(&pr_comments)))
-        .register::<ListPrsTool, ()>(ListPrsTool::new(Arc::clone(&pr_comments)))
-        .register::<AddCommentReplyTool, ()>(AddCommentReplyTool::new(pr_comments))
-        .finish()
+/// Input for `add_pr_comment` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct AddPrCommentInput {
+    /// PR number (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    /// Comment body (markdown)
+    pub body: String,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for posting a top-level PR comment.
+#[derive(Clone)]
+pub struct AddPrCommentTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl AddPrCommentTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for AddPrCommentTool {
+    type Input = AddPrCommentInput;
+    type Output = PostedIssueComment;
+    const NAME: &'static str = "gh_add_pr_comment";
+    const DESCRIPTION: &'static str = "Post a new top-level comment on a PR (conversation tab, not attached to code). Automatically prefixes with AI identifier. To reply to an existing review comment, use gh_add_comment_reply instead.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "pr_number": input.pr_number,
+                "body_len": input.body.len(),
+            });
+
+            match pr_comments
+                .add_pr_comment(input.pr_number, input.body)
+                .await
+            {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({ "comment_id": out.id })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
 }
 
 // ============================================================================
-// Error Conversion
+// ReactToComment Tool
 // ============================================================================
 
-/// Map `anyhow::Error` to `agentic_tools_core::ToolError` based on error message patterns.
-fn map_anyhow_to_tool_error(e: &anyhow::Error) -> ToolError {
-    let msg = e.to_string();
-    let lc = msg.to_lowercase();
-    if lc.contains("permission") || lc.contains("401") || lc.contains("403") {
+/// Input for `react_to_comment` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ReactToCommentInput {
+    /// ID of the comment to react to
+    pub comment_id: u64,
+    /// Reaction: +1, -1, laugh, confused, heart, hooray, rocket, or eyes
+    pub reaction: Reaction,
+    /// Comment kind: review (inline, default) or issue (top-level)
+    #[serde(default)]
+    pub kind: CommentKind,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for reacting to a PR comment.
+#[derive(Clone)]
+pub struct ReactToCommentTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl ReactToCommentTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for ReactToCommentTool {
+    type Input = ReactToCommentInput;
+    type Output = PostedReaction;
+    const NAME: &'static str = "gh_react_to_comment";
+    const DESCRIPTION: &'static str = "Add an emoji reaction to a PR review comment (kind=review) or top-level PR comment (kind=issue). Use for lightweight acknowledgement (e.g. eyes when starting work, +1 when done) instead of posting a reply. Reacting twice with the same emoji is a no-op.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "comment_id": input.comment_id,
+                "kind": input.kind,
+                "reaction": input.reaction,
+            });
+
+            match pr_comments
+                .react_to_comment(input.comment_id, input.kind, input.reaction)
+                .await
+            {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({ "reaction_id": out.id })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// EditComment Tool
+// ============================================================================
+
+/// Input for `edit_comment` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct EditCommentInput {
+    /// ID of the comment to edit
+    pub comment_id: u64,
+    /// New comment body (markdown); replaces the existing body
+    pub body: String,
+    /// Comment kind: review (inline, default) or issue (top-level)
+    #[serde(default)]
+    pub kind: CommentKind,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for editing one of our own PR comments.
+#[derive(Clone)]
+pub struct EditCommentTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl EditCommentTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for EditCommentTool {
+    type Input = EditCommentInput;
+    type Output = EditedComment;
+    const NAME: &'static str = "gh_edit_comment";
+    const DESCRIPTION: &'static str = "Replace the body of a PR review comment (kind=review) or top-level PR comment (kind=issue). Only comments written by the authenticated user can be edited. Automatically prefixes with AI identifier.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "comment_id": input.comment_id,
+                "kind": input.kind,
+                "body_len": input.body.len(),
+            });
+
+            match pr_comments
+                .edit_comment(input.comment_id, input.kind, input.body)
+                .await
+            {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({ "comment_id": out.id })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// SubmitReview Tool
+// ============================================================================
+
+/// Input for `submit_review` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct SubmitReviewInput {
+    /// PR number (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    /// Review verdict: comment, approve, or `request_changes`
+    pub event: ReviewEvent,
+    /// Review summary (required for `request_changes`; for comment, required unless inline comments are given)
+    #[serde(default)]
+    pub body: Option<String>,
+    /// New inline comments on lines in the PR diff
+    #[serde(default)]
+    pub inline_comments: Vec<ReviewInlineComment>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for submitting a PR review.
+#[derive(Clone)]
+pub struct SubmitReviewTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl SubmitReviewTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for SubmitReviewTool {
+    type Input = SubmitReviewInput;
+    type Output = SubmittedReview;
+    const NAME: &'static str = "gh_submit_review";
+    const DESCRIPTION: &'static str = "Submit a PR review (comment, approve, or request_changes) with an optional summary body and new inline comments on diff lines. Automatically prefixes the body and each inline comment with AI identifier.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "pr_number": input.pr_number,
+                "event": input.event,
+                "body_len": input.body.as_ref().map(String::len),
+                "inline_comments": input.inline_comments.len(),
+            });
+
+            match pr_comments
+                .submit_review(
+                    input.pr_number,
+                    input.event,
+                    input.body,
+                    input.inline_comments,
+                )
+                .await
+            {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({
+                            "review_id": out.id,
+                            "state": out.state,
+                        })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// GetPr Tool
+// ============================================================================
+
+/// Input for `get_pr` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetPrInput {
+    /// PR number (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for fetching PR metadata.
+#[derive(Clone)]
+pub struct GetPrTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl GetPrTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for GetPrTool {
+    type Input = GetPrInput;
+    type Output = PrDetails;
+    const NAME: &'static str = "gh_get_pr";
+    const DESCRIPTION: &'static str = "Get PR metadata: title, description, state, base/head refs, change stats, reviewers with their latest review state, and a check-suite summary for the head commit. Use gh_get_changed_files and gh_get_pr_diff for the code changes.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "pr_number": input.pr_number,
+            });
+
+            match pr_comments.get_pr(input.pr_number).await {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({
+                            "pr_number": out.number,
+                            "reviewers": out.reviewers.len(),
+                            "checks": out.checks.total,
+                        })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// GetChangedFiles Tool
+// ============================================================================
+
+/// Input for `get_changed_files` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetChangedFilesInput {
+    /// PR number (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for listing files changed in a PR.
+#[derive(Clone)]
+pub struct GetChangedFilesTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl GetChangedFilesTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for GetChangedFilesTool {
+    type Input = GetChangedFilesInput;
+    type Output = PrFileList;
+    const NAME: &'static str = "gh_get_changed_files";
+    const DESCRIPTION: &'static str = "List files changed in a PR with status (added/modified/removed/renamed) and +/- line counts, with implicit pagination. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        let ctx = ctx.clone();
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "pr_number": input.pr_number,
+            });
+
+            match pr_comments.get_changed_files(input.pr_number, &ctx).await {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({ "files": out.files.len() })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// GetPrDiff Tool
+// ============================================================================
+
+/// Input for `get_pr_diff` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetPrDiffInput {
+    /// PR number (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    /// Limit the diff to these files or directories (repo-relative). Default: all files
+    #[serde(default)]
+    pub paths: Vec<String>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for fetching a PR's diff.
+#[derive(Clone)]
+pub struct GetPrDiffTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl GetPrDiffTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for GetPrDiffTool {
+    type Input = GetPrDiffInput;
+    type Output = PrDiff;
+    const NAME: &'static str = "gh_get_pr_diff";
+    const DESCRIPTION: &'static str = "Get the unified diff of a PR, one patch per file, with implicit pagination by file. Optionally limit to specific paths. Repeated calls with the same params return the next page; tool output tells you whether to call again or stop, and another identical call after completion restarts from page 1.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        let ctx = ctx.clone();
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "pr_number": input.pr_number,
+                "paths": input.paths,
+            });
+
+            match pr_comments
+                .get_pr_diff(input.pr_number, input.paths, &ctx)
+                .await
+            {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({ "files": out.files.len() })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// GetChecks Tool
+// ============================================================================
+
+/// Input for `get_checks` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetChecksInput {
+    /// PR number (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for listing CI check runs on a PR.
+#[derive(Clone)]
+pub struct GetChecksTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl GetChecksTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for GetChecksTool {
+    type Input = GetChecksInput;
+    type Output = CheckRunList;
+    const NAME: &'static str = "gh_get_checks";
+    const DESCRIPTION: &'static str = "List CI check runs on a PR's head commit with status and conclusion, failing runs first. Use gh_get_check_log with a failing run's ID to read its log.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "pr_number": input.pr_number,
+            });
+
+            match pr_comments.get_checks(input.pr_number).await {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({
+                            "check_runs": out.summary.total,
+                            "failed": out.summary.failed,
+                        })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// GetCheckLog Tool
+// ============================================================================
+
+/// Input for `get_check_log` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct GetCheckLogInput {
+    /// Check run ID from `gh_get_checks` (GitHub Actions jobs only)
+    pub check_run_id: u64,
+    /// Number of trailing log lines to return (default: 200, max: 2000)
+    #[serde(default)]
+    pub tail_lines: Option<usize>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+/// Tool for reading a CI job log excerpt.
+#[derive(Clone)]
+pub struct GetCheckLogTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl GetCheckLogTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for GetCheckLogTool {
+    type Input = GetCheckLogInput;
+    type Output = CheckLog;
+    const NAME: &'static str = "gh_get_check_log";
+    const DESCRIPTION: &'static str = "Fetch the log of a GitHub Actions check run: any ##[error] lines plus the last tail_lines lines (timestamps stripped). Only Actions jobs have logs; third-party checks link out via their URL instead.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let pr_comments = input.target.scope(&self.pr_comments);
+        Box::pin(async move {
+            let log = ToolLogCtx::start(Self::NAME);
+
+            let request = serde_json::json!({
+                "owner": input.target.owner,
+                "repo": input.target.repo,
+                "check_run_id": input.check_run_id,
+                "tail_lines": input.tail_lines,
+            });
+
+            match pr_comments
+                .get_check_log(input.check_run_id, input.tail_lines)
+                .await
+            {
+                Ok(out) => {
+                    log.finish(
+                        request,
+                        None,
+                        true,
+                        None,
+                        Some(serde_json::json!({
+                            "total_lines": out.total_lines,
+                            "error_lines": out.error_lines.len(),
+                        })),
+                        None,
+                        None,
+                    );
+                    Ok(out)
+                }
+                Err(e) => {
+                    let msg = e.to_string();
+                    log.finish(request, None, false, Some(msg), None, None, None);
+                    Err(map_anyhow_to_tool_error(&e))
+                }
+            }
+        })
+    }
+}
+
+// ============================================================================
+// ResolveThread / UnresolveThread Tools
+// ============================================================================
+
+/// Input for `resolve_thread` and `unresolve_thread` tools.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct ResolveThreadInput {
+    /// Review thread to update: its GraphQL node ID (e.g. "PRRT_...") or the numeric ID of any review comment in it (as shown by `gh_get_comments`)
+    pub thread_id: String,
+    /// PR number used to look up the thread from a comment ID (auto-detected if not provided)
+    #[serde(default)]
+    pub pr_number: Option<u64>,
+    #[serde(flatten)]
+    pub target: RepoTarget,
+}
+
+fn set_thread_resolved_call(
+    pr_comments: PrComments,
+    name: &'static str,
+    input: ResolveThreadInput,
+    resolved: bool,
+) -> BoxFuture<'static, Result<ResolvedThread, ToolError>> {
+    Box::pin(async move {
+        let log = ToolLogCtx::start(name);
+
+        let request = serde_json::json!({
+            "owner": input.target.owner,
+            "repo": input.target.repo,
+            "thread_id": input.thread_id,
+            "pr_number": input.pr_number,
+        });
+
+        match pr_comments
+            .set_thread_resolved(input.pr_number, input.thread_id, resolved)
+            .await
+        {
+            Ok(out) => {
+                log.finish(
+                    request,
+                    None,
+                    true,
+                    None,
+                    Some(serde_json::json!({
+                        "thread_id": out.thread_id,
+                        "is_resolved": out.is_resolved,
+                    })),
+                    None,
+                    None,
+                );
+                Ok(out)
+            }
+            Err(e) => {
+                let msg = e.to_string();
+                log.finish(request, None, false, Some(msg), None, None, None);
+                Err(map_anyhow_to_tool_error(&e))
+            }
+        }
+    })
+}
+
+/// Tool for marking a review thread resolved.
+#[derive(Clone)]
+pub struct ResolveThreadTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl ResolveThreadTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for ResolveThreadTool {
+    type Input = ResolveThreadInput;
+    type Output = ResolvedThread;
+    const NAME: &'static str = "gh_resolve_thread";
+    const DESCRIPTION: &'static str = "Mark a PR review thread as resolved after addressing it. Accepts the thread's GraphQL node ID or the ID of any review comment in the thread. Use gh_unresolve_thread to reopen.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        set_thread_resolved_call(
+            input.target.scope(&self.pr_comments),
+            Self::NAME,
+            input,
+            true,
+        )
+    }
+}
+
+/// Tool for reopening a resolved review thread.
+#[derive(Clone)]
+pub struct UnresolveThreadTool {
+    pr_comments: Arc<PrComments>,
+}
+
+impl UnresolveThreadTool {
+    pub fn new(pr_comments: Arc<PrComments>) -> Self {
+        Self { pr_comments }
+    }
+}
+
+impl Tool for UnresolveThreadTool {
+    type Input = ResolveThreadInput;
+    type Output = ResolvedThread;
+    const NAME: &'static str = "gh_unresolve_thread";
+    const DESCRIPTION: &'static str = "Reopen a resolved PR review thread. Accepts the thread's GraphQL node ID or the ID of any review comment in the thread.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        set_thread_resolved_call(
+            input.target.scope(&self.pr_comments),
+            Self::NAME,
+            input,
+            false,
+        )
+    }
+}
+
+// ============================================================================
+// Registry Builder
+// ============================================================================
+
+/// Build a `ToolRegistry` containing all `pr_comments` tools.
+pub fn build_registry(pr_comments: Arc<PrComments>) -> ToolRegistry {
+    ToolRegistry::builder()
+        .register::<GetCommentsTool, ()>(GetCommentsTool::new(Arc::clone(&pr_comments)))
+        .register::<ListPrsTool, ()>(ListPrsTool::new(Arc::clone(&pr_comments)))
+        .register::<GetPrTool, ()>(GetPrTool::new(Arc::clone(&pr_comments)))
+        .register::<GetChangedFilesTool, ()>(GetChangedFilesTool::new(Arc::clone(&pr_comments)))
+        .register::<GetPrDiffTool, ()>(GetPrDiffTool::new(Arc::clone(&pr_comments)))
+        .register::<GetChecksTool, ()>(GetChecksTool::new(Arc::clone(&pr_comments)))
+        .register::<GetCheckLogTool, ()>(GetCheckLogTool::new(Arc::clone(&pr_comments)))
+        .register::<AddCommentReplyTool, ()>(AddCommentReplyTool::new(Arc::clone(&pr_comments)))
+        .register::<AddPrCommentTool, ()>(AddPrCommentTool::new(Arc::clone(&pr_comments)))
+        .register::<ReactToCommentTool, ()>(ReactToCommentTool::new(Arc::clone(&pr_comments)))
+        .register::<EditCommentTool, ()>(EditCommentTool::new(Arc::clone(&pr_comments)))
+        .register::<SubmitReviewTool, ()>(SubmitReviewTool::new(Arc::clone(&pr_comments)))
+        .register::<ResolveThreadTool, ()>(ResolveThreadTool::new(Arc::clone(&pr_comments)))
+        .register::<UnresolveThreadTool, ()>(UnresolveThreadTool::new(pr_comments))
+        .finish()
+}
+
+// ============================================================================
+// Error Conversion
+// ============================================================================
+
+/// Map `anyhow::Error` to `agentic_tools_core::ToolError` based on error message patterns.
+fn map_anyhow_to_tool_error(e: &anyhow::Error) -> ToolError {
+    let msg = e.to_string();
+    let lc = msg.to_lowercase();
+    if lc.contains("rate limit") {
+        ToolError::External(msg)
+    } else if lc.contains("permission") || lc.contains("401") || lc.contains("403") {
         ToolError::Permission(msg)
     } else if lc.contains("not found") || lc.contains("404") {
         ToolError::NotFound(msg)
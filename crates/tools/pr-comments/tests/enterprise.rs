@@ -0,0 +1,77 @@
+//! GitHub Enterprise Server routing: REST under `/api/v3` (both the octocrab and raw
+//! reqwest paths) and GraphQL at `/api/graphql`, with the token on every request.
+
+use pr_comments::github::GitHubClient;
+use pr_comments::host::GitHubEndpoints;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::body_partial_json;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[expect(clippy::unwrap_used, reason = "Tests should panic on failure")]
+fn enterprise_client(server: &MockServer) -> GitHubClient {
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    let endpoints = GitHubEndpoints {
+        host: "ghe.example.com".into(),
+        api_base_url: format!("{}/api/v3", server.uri()),
+        graphql_url: format!("{}/api/graphql", server.uri()),
+    };
+    GitHubClient::with_endpoints(
+        "acme".into(),
+        "app".into(),
+        Some("ghe-token".into()),
+        &endpoints,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn rest_calls_use_enterprise_api_prefix() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/repos/acme/app/pulls"))
+        .and(header("authorization", "Bearer ghe-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/repos/acme/app/pulls/7"))
+        .and(header("authorization", "Bearer ghe-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({"head": {"sha": "abc123"}})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = enterprise_client(&server);
+    assert!(client.list_prs(None).await.unwrap().is_empty());
+    assert_eq!(client.get_pr_head_sha(7).await.unwrap(), "abc123");
+}
+
+#[tokio::test]
+async fn graphql_calls_use_enterprise_endpoint() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/api/graphql"))
+        .and(header("authorization", "Bearer ghe-token"))
+        .and(body_partial_json(
+            json!({"variables": {"threadId": "PRRT_1"}}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {"result": {"thread": {"id": "PRRT_1", "isResolved": true}}}
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = enterprise_client(&server);
+    let resolved = client
+        .set_review_thread_resolved("PRRT_1", true)
+        .await
+        .unwrap();
+    assert!(resolved.is_resolved);
+}
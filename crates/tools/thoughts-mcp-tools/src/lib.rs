@@ -1,6 +1,6 @@
 //! Agentic-tools integration for `thoughts_tool`.
 //!
-//! This module provides Tool wrappers for the 6 thoughts MCP tools using the
+//! This module provides Tool wrappers for the 10 thoughts MCP tools using the
 //! agentic-tools-core framework, enabling registration in the unified registry.
 
 pub(crate) mod readiness;
@@ -13,6 +13,10 @@ pub use tools::GetRepoRefsTool;
 pub use tools::GetTemplateTool;
 pub use tools::ListActiveDocumentsTool;
 pub use tools::ListReferencesTool;
+pub use tools::MountStatusTool;
+pub use tools::SyncTool;
+pub use tools::ToolLogsQueryTool;
+pub use tools::VerifyReferencesTool;
 pub use tools::WriteDocumentTool;
 
 use agentic_config::types::ThoughtsConfig;
@@ -39,9 +43,16 @@ pub fn build_registry(thoughts: ThoughtsConfig) -> ToolRegistry {
             readiness: readiness.clone(),
         })
         .register::<AddReferenceTool, ()>(AddReferenceTool {
-            thoughts,
+            thoughts: thoughts.clone(),
             readiness: readiness.clone(),
         })
-        .register::<GetTemplateTool, ()>(GetTemplateTool { readiness })
+        .register::<GetTemplateTool, ()>(GetTemplateTool {
+            readiness: readiness.clone(),
+        })
+        .register::<ToolLogsQueryTool, ()>(ToolLogsQueryTool { readiness })
+        // Diagnostics and repair skip the readiness gate so they work when it fails.
+        .register::<MountStatusTool, ()>(MountStatusTool)
+        .register::<VerifyReferencesTool, ()>(VerifyReferencesTool)
+        .register::<SyncTool, ()>(SyncTool { thoughts })
         .finish()
 }
@@ -22,13 +22,22 @@ use thoughts_tool::documents::write_document;
 use thoughts_tool::git::ref_key::encode_ref_key;
 use thoughts_tool::git::utils::get_control_repo_root;
 use thoughts_tool::mcp::AddReferenceOk;
+use thoughts_tool::mcp::MountStatusReport;
 use thoughts_tool::mcp::ReferenceItem;
+use thoughts_tool::mcp::ReferenceVerification;
 use thoughts_tool::mcp::ReferencesList;
 use thoughts_tool::mcp::RepoRefsList;
+use thoughts_tool::mcp::SyncReport;
 use thoughts_tool::mcp::TemplateResponse;
 use thoughts_tool::mcp::TemplateType;
+use thoughts_tool::mcp::ToolLogsQuery;
+use thoughts_tool::mcp::ToolLogsReport;
 use thoughts_tool::mcp::add_reference_impl_adapter;
 use thoughts_tool::mcp::get_repo_refs_impl_adapter;
+use thoughts_tool::mcp::mount_status_impl_adapter;
+use thoughts_tool::mcp::sync_impl_adapter;
+use thoughts_tool::mcp::tool_logs_query_impl_adapter;
+use thoughts_tool::mcp::verify_references_impl_adapter;
 use thoughts_tool::mount::MountSpace;
 use thoughts_tool::utils::logging::log_tool_call;
 
@@ -574,6 +583,320 @@ impl Tool for GetTemplateTool {
     }
 }
 
+// ============================================================================
+// MountStatus Tool
+// ============================================================================
+
+/// Input for the `mount_status` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema, Default)]
+pub struct MountStatusInput {}
+
+/// Tool for reporting configured mounts and their health.
+///
+/// Skips the readiness gate: it is the tool to call when readiness fails.
+#[derive(Clone)]
+pub struct MountStatusTool;
+
+impl Tool for MountStatusTool {
+    type Input = MountStatusInput;
+    type Output = MountStatusReport;
+    const NAME: &'static str = "thoughts_mount_status";
+    const DESCRIPTION: &'static str = "Report every configured thoughts/context/reference mount and whether it is mounted, degraded, or missing. Read-only; works even when other thoughts tools fail readiness checks.";
+
+    fn call(
+        &self,
+        _input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        Box::pin(async move {
+            let timer = CallTimer::start();
+            let req_json = serde_json::json!({});
+
+            let result = mount_status_impl_adapter()
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e));
+
+            match &result {
+                Ok(report) => {
+                    let summary = serde_json::json!({
+                        "healthy": report.healthy,
+                        "mounts_count": report.mounts.len(),
+                        "unexpected_count": report.unexpected.len(),
+                    });
+                    log_tool_call(
+                        &timer,
+                        "thoughts_mount_status",
+                        req_json,
+                        true,
+                        None,
+                        Some(summary),
+                    );
+                }
+                Err(e) => {
+                    log_tool_call(
+                        &timer,
+                        "thoughts_mount_status",
+                        req_json,
+                        false,
+                        Some(e.to_string()),
+                        None,
+                    );
+                }
+            }
+
+            result
+        })
+    }
+}
+
+// ============================================================================
+// VerifyReferences Tool
+// ============================================================================
+
+/// Input for the `verify_references` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema, Default)]
+pub struct VerifyReferencesInput {}
+
+/// Tool for checking reference clones against the configuration.
+#[derive(Clone)]
+pub struct VerifyReferencesTool;
+
+impl Tool for VerifyReferencesTool {
+    type Input = VerifyReferencesInput;
+    type Output = ReferenceVerification;
+    const NAME: &'static str = "thoughts_verify_references";
+    const DESCRIPTION: &'static str = "Check each configured reference: mapped to a local clone, clone present and a git repository, origin matching the configured URL. Read-only.";
+
+    fn call(
+        &self,
+        _input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        Box::pin(async move {
+            let timer = CallTimer::start();
+            let req_json = serde_json::json!({});
+
+            let result = verify_references_impl_adapter()
+                .await
+                .map_err(|e| map_anyhow_to_tool_error(&e));
+
+            match &result {
+                Ok(verification) => {
+                    let summary = serde_json::json!({
+                        "ok": verification.ok,
+                        "references_count": verification.references.len(),
+                        "failing_count": verification.references.iter().filter(|r| !r.ok).count(),
+                    });
+                    log_tool_call(
+                        &timer,
+                        "thoughts_verify_references",
+                        req_json,
+                        true,
+                        None,
+                        Some(summary),
+                    );
+                }
+                Err(e) => {
+                    log_tool_call(
+                        &timer,
+                        "thoughts_verify_references",
+                        req_json,
+                        false,
+                        Some(e.to_string()),
+                        None,
+                    );
+                }
+            }
+
+            result
+        })
+    }
+}
+
+// ============================================================================
+// Sync Tool
+// ============================================================================
+
+/// Input for the `sync` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema, Default)]
+pub struct SyncInput {
+    /// Remount missing mounts and git-sync auto-sync mounts. Defaults to false, which
+    /// only reports what would be done. Requires `thoughts.allow_mcp_sync = true`.
+    #[serde(default)]
+    pub apply: bool,
+}
+
+/// Tool for repairing mounts and syncing thoughts/context repositories.
+#[derive(Clone)]
+pub struct SyncTool {
+    pub thoughts: ThoughtsConfig,
+}
+
+impl Tool for SyncTool {
+    type Input = SyncInput;
+    type Output = SyncReport;
+    const NAME: &'static str = "thoughts_sync";
+    const DESCRIPTION: &'static str = "Preview (default) or apply a thoughts sync: remount missing mounts and pull/commit/push auto-sync thoughts and context repositories, like 'thoughts mount update' plus 'thoughts sync --all'. apply=true requires thoughts.allow_mcp_sync in config.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let allow_apply = self.thoughts.allow_mcp_sync;
+        Box::pin(async move {
+            let timer = CallTimer::start();
+            let req_json = serde_json::json!({
+                "apply": input.apply,
+            });
+
+            let result = if input.apply && !allow_apply {
+                Err(ToolError::Permission(
+                    "permission denied: thoughts_sync apply=true is disabled; set thoughts.allow_mcp_sync = true in agentic.toml, or run 'thoughts mount update' / 'thoughts sync' via CLI"
+                        .into(),
+                ))
+            } else {
+                sync_impl_adapter(input.apply)
+                    .await
+                    .map_err(|e| map_anyhow_to_tool_error(&e))
+            };
+
+            match &result {
+                Ok(report) => {
+                    let summary = serde_json::json!({
+                        "applied": report.applied,
+                        "to_mount": report.to_mount.len(),
+                        "to_sync": report.to_sync.len(),
+                        "sync_failures": report.synced.iter().filter(|s| !s.ok).count(),
+                        "healthy": report.status.healthy,
+                    });
+                    log_tool_call(&timer, "thoughts_sync", req_json, true, None, Some(summary));
+                }
+                Err(e) => {
+                    log_tool_call(
+                        &timer,
+                        "thoughts_sync",
+                        req_json,
+                        false,
+                        Some(e.to_string()),
+                        None,
+                    );
+                }
+            }
+
+            result
+        })
+    }
+}
+
+// ============================================================================
+// ToolLogsQuery Tool
+// ============================================================================
+
+/// Input for the `tool_logs_query` tool.
+#[derive(Debug, Clone, Deserialize, JsonSchema, Default)]
+pub struct ToolLogsQueryInput {
+    /// Only calls logged by this server (e.g. `thoughts_tool`, `gpt5_reasoner`).
+    #[serde(default)]
+    pub server: Option<String>,
+    /// Only calls to this tool (e.g. `thoughts_sync`).
+    #[serde(default)]
+    pub tool: Option<String>,
+    /// Only successful (true) or failed (false) calls.
+    #[serde(default)]
+    pub success: Option<bool>,
+    /// Calls completed at or after this time: RFC 3339, YYYY-MM-DD, or an age like 24h/7d.
+    #[serde(default)]
+    pub since: Option<String>,
+    /// Calls completed before this time, in the same formats as `since`.
+    #[serde(default)]
+    pub until: Option<String>,
+    /// Only calls in this trace: the originating call's ID, e.g. an `ask_agent` call
+    /// whose subagent's tool calls should be listed with it.
+    #[serde(default)]
+    pub trace_id: Option<String>,
+    /// Most recent matching calls to list (default 20, max 200).
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Tool for querying and aggregating the tool call logs of the active branch.
+#[derive(Clone)]
+pub struct ToolLogsQueryTool {
+    pub(crate) readiness: ThoughtsMcpReadinessGate,
+}
+
+impl Tool for ToolLogsQueryTool {
+    type Input = ToolLogsQueryInput;
+    type Output = ToolLogsReport;
+    const NAME: &'static str = "tool_logs_query";
+    const DESCRIPTION: &'static str = "Query the tool call logs of the active work directory. Filters by server, tool, success, time range (since/until), and trace_id (an originating call and everything it caused, such as a subagent's tool calls), and reports call counts, failures, p50/p95 durations per tool, token totals per model, and the most recent matching calls.";
+
+    fn call(
+        &self,
+        input: Self::Input,
+        _ctx: &ToolContext,
+    ) -> BoxFuture<'static, Result<Self::Output, ToolError>> {
+        let readiness = self.readiness.clone();
+        Box::pin(async move {
+            let timer = CallTimer::start();
+            let req_json = serde_json::json!({
+                "server": &input.server,
+                "tool": &input.tool,
+                "success": input.success,
+                "since": &input.since,
+                "until": &input.until,
+                "trace_id": &input.trace_id,
+                "limit": input.limit,
+            });
+
+            ensure_ready_and_log_failure(&readiness, &timer, "tool_logs_query", &req_json).await?;
+
+            let result = tool_logs_query_impl_adapter(ToolLogsQuery {
+                server: input.server,
+                tool: input.tool,
+                success: input.success,
+                since: input.since,
+                until: input.until,
+                trace_id: input.trace_id,
+                limit: input.limit,
+            })
+            .await
+            .map_err(|e| map_anyhow_to_tool_error(&e));
+
+            match &result {
+                Ok(report) => {
+                    let summary = serde_json::json!({
+                        "total_calls": report.stats.total_calls,
+                        "failed_calls": report.stats.failed_calls,
+                        "returned": report.recent.len(),
+                    });
+                    log_tool_call(
+                        &timer,
+                        "tool_logs_query",
+                        req_json,
+                        true,
+                        None,
+                        Some(summary),
+                    );
+                }
+                Err(e) => {
+                    log_tool_call(
+                        &timer,
+                        "tool_logs_query",
+                        req_json,
+                        false,
+                        Some(e.to_string()),
+                        None,
+                    );
+                }
+            }
+
+            result
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -602,4 +925,21 @@ mod tests {
             other => panic!("expected internal readiness error, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn sync_apply_requires_opt_in() {
+        let tool = SyncTool {
+            thoughts: ThoughtsConfig::default(),
+        };
+
+        let error = tool
+            .call(SyncInput { apply: true }, &ToolContext::default())
+            .await
+            .unwrap_err();
+
+        match error {
+            ToolError::Permission(message) => assert!(message.contains("allow_mcp_sync")),
+            other => panic!("expected permission error, got {other:?}"),
+        }
+    }
 }
@@ -5,6 +5,7 @@
 
 pub mod fetch;
 pub mod haiku;
+pub mod quality;
 pub mod search;
 pub mod tools;
 pub mod types;
@@ -0,0 +1,372 @@
+//! Post-processing for `web_search` results: deduplication and source quality scoring.
+//!
+//! Exa frequently returns the same article several times (syndicated copies,
+//! scraped mirrors, tracking-parameter variants). Results are collapsed on a
+//! normalized URL or title, capped per domain, and annotated with a 0-100
+//! quality score plus the signals that produced it.
+
+use std::collections::HashMap;
+
+use agentic_config::types::WebSearchQualityConfig;
+use url::Url;
+
+use crate::types::WebSearchResultCard;
+
+/// Neutral quality score before boosts and penalties.
+const BASE_QUALITY: u32 = 50;
+/// Slugs with at least this many hyphen-separated words look keyword-stuffed.
+const SEO_SLUG_MIN_WORDS: usize = 9;
+/// Titles shorter than this (after normalization) are too generic to dedupe on.
+const MIN_TITLE_KEY_CHARS: usize = 16;
+/// Query parameters that never change page content.
+const TRACKING_PARAMS: &[&str] = &["fbclid", "gclid", "mc_cid", "mc_eid", "ref", "source"];
+
+/// Deduplicate, cap per domain, and score search results in place.
+///
+/// Returns the number of results removed. Ordering of the kept results follows
+/// the original ranking; when duplicates collide the higher-quality copy is kept
+/// and the others are listed in its `duplicates`.
+pub fn apply(results: &mut Vec<WebSearchResultCard>, cfg: &WebSearchQualityConfig) -> usize {
+    let before = results.len();
+
+    for card in results.iter_mut() {
+        let (quality, signals) = score(card, cfg);
+        card.quality = Some(quality);
+        card.quality_signals = signals;
+    }
+
+    if cfg.dedupe {
+        dedupe(results);
+    }
+
+    if cfg.max_results_per_domain > 0 {
+        let cap = cfg.max_results_per_domain as usize;
+        let mut per_domain: HashMap<String, usize> = HashMap::new();
+        results.retain(|card| {
+            let seen = per_domain.entry(domain_key(&card.domain)).or_default();
+            *seen += 1;
+            *seen <= cap
+        });
+    }
+
+    before - results.len()
+}
+
+/// Collapse cards that share a normalized URL or title.
+fn dedupe(results: &mut Vec<WebSearchResultCard>) {
+    let mut kept: Vec<WebSearchResultCard> = Vec::with_capacity(results.len());
+    let mut by_url: HashMap<String, usize> = HashMap::new();
+    let mut by_title: HashMap<String, usize> = HashMap::new();
+
+    for card in results.drain(..) {
+        let url_key = normalize_url(&card.url);
+        let title_key = card.title.as_deref().and_then(title_key);
+
+        let existing = by_url.get(&url_key).copied().or_else(|| {
+            title_key
+                .as_ref()
+                .and_then(|key| by_title.get(key).copied())
+        });
+
+        if let Some(idx) = existing {
+            let slot = &mut kept[idx];
+            let dup = if card.quality > slot.quality {
+                let mut card = card;
+                card.duplicates.append(&mut slot.duplicates);
+                std::mem::replace(slot, card)
+            } else {
+                card
+            };
+            slot.duplicates.push(dup.url);
+            slot.duplicates.extend(dup.duplicates);
+            by_url.entry(url_key).or_insert(idx);
+            by_url.entry(normalize_url(&slot.url)).or_insert(idx);
+            if let Some(key) = title_key {
+                by_title.entry(key).or_insert(idx);
+            }
+        } else {
+            let idx = kept.len();
+            by_url.insert(url_key, idx);
+            if let Some(key) = title_key {
+                by_title.insert(key, idx);
+            }
+            kept.push(card);
+        }
+    }
+
+    *results = kept;
+}
+
+/// Compute the quality score and the signals behind it.
+fn score(card: &WebSearchResultCard, cfg: &WebSearchQualityConfig) -> (u32, Vec<String>) {
+    let mut quality = BASE_QUALITY;
+    let mut signals = Vec::new();
+    let host = domain_key(&card.domain);
+
+    if domain_listed(&host, &cfg.boost_domains) || looks_like_docs(&host, &card.url) {
+        quality += cfg.boost_points;
+        signals.push("official docs".to_string());
+    }
+
+    let mut penalize = |signal: &str| {
+        quality = quality.saturating_sub(cfg.penalty_points);
+        signals.push(signal.to_string());
+    };
+    if domain_listed(&host, &cfg.penalize_domains) {
+        penalize("low-quality domain");
+    }
+    if has_seo_slug(&card.url) {
+        penalize("seo slug");
+    }
+    if card.title.as_deref().is_some_and(is_listicle_title) {
+        penalize("listicle");
+    }
+
+    (quality.min(100), signals)
+}
+
+/// Lowercased host without a leading `www.` or `m.`.
+fn domain_key(domain: &str) -> String {
+    let lower = domain.to_ascii_lowercase();
+    lower
+        .strip_prefix("www.")
+        .or_else(|| lower.strip_prefix("m."))
+        .unwrap_or(&lower)
+        .to_string()
+}
+
+/// True when `host` equals or is a subdomain of any listed domain.
+fn domain_listed(host: &str, domains: &[String]) -> bool {
+    domains.iter().any(|d| {
+        let d = d.trim().trim_start_matches('.').to_ascii_lowercase();
+        !d.is_empty()
+            && (host == d
+                || host
+                    .strip_suffix(d.as_str())
+                    .is_some_and(|rest| rest.ends_with('.')))
+    })
+}
+
+/// Documentation subdomains and `/docs/` paths are usually first-party references.
+fn looks_like_docs(host: &str, url: &str) -> bool {
+    if host.starts_with("docs.") || host.starts_with("developer.") {
+        return true;
+    }
+    Url::parse(url).is_ok_and(|u| {
+        u.path_segments()
+            .and_then(|mut segs| segs.next())
+            .is_some_and(|first| first.eq_ignore_ascii_case("docs"))
+    })
+}
+
+/// Long keyword-stuffed slugs (`/10-best-ways-to-fix-rust-error-...`).
+fn has_seo_slug(url: &str) -> bool {
+    Url::parse(url).is_ok_and(|u| {
+        u.path_segments().is_some_and(|segs| {
+            segs.into_iter()
+                .any(|seg| seg.split('-').filter(|w| !w.is_empty()).count() >= SEO_SLUG_MIN_WORDS)
+        })
+    })
+}
+
+/// "Top 10 ..." / "15 Best ..." style titles.
+fn is_listicle_title(title: &str) -> bool {
+    let words: Vec<String> = title
+        .split_whitespace()
+        .take(3)
+        .map(|w| {
+            w.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_ascii_lowercase()
+        })
+        .collect();
+    let is_number = |w: &String| !w.is_empty() && w.chars().all(|c| c.is_ascii_digit());
+    match words.as_slice() {
+        [first, second, ..] => {
+            ((first == "top" || first == "best") && is_number(second))
+                || (is_number(first) && (second == "best" || second == "top"))
+        }
+        _ => false,
+    }
+}
+
+/// Canonical form of a URL for duplicate detection: no scheme, `www.`, fragment,
+/// tracking parameters, or trailing slash.
+fn normalize_url(url: &str) -> String {
+    let Ok(parsed) = Url::parse(url) else {
+        return url.trim_end_matches('/').to_ascii_lowercase();
+    };
+    let host = domain_key(parsed.host_str().unwrap_or_default());
+    let path = parsed.path().trim_end_matches('/');
+    let mut query: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(k, _)| !k.starts_with("utm_") && !TRACKING_PARAMS.contains(&k.as_ref()))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    query.sort();
+
+    let mut out = format!("{host}{path}");
+    if !query.is_empty() {
+        let qs: Vec<String> = query.into_iter().map(|(k, v)| format!("{k}={v}")).collect();
+        out.push('?');
+        out.push_str(&qs.join("&"));
+    }
+    out
+}
+
+/// Title reduced to lowercase alphanumeric words, with a trailing
+/// " - Site Name" / " | Site Name" suffix removed.
+fn title_key(title: &str) -> Option<String> {
+    let core = title
+        .rsplit_once(" | ")
+        .or_else(|| title.rsplit_once(" - "))
+        .map_or(title, |(head, _)| head);
+    let key = core
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ");
+    (key.chars().count() >= MIN_TITLE_KEY_CHARS).then_some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(url: &str, title: &str) -> WebSearchResultCard {
+        let domain = Url::parse(url)
+            .ok()
+            .and_then(|u| u.host_str().map(String::from))
+            .unwrap_or_default();
+        WebSearchResultCard {
+            url: url.into(),
+            domain,
+            title: Some(title.into()),
+            published_date: None,
+            author: None,
+            score: None,
+            snippet: None,
+            quality: None,
+            quality_signals: Vec::new(),
+            duplicates: Vec::new(),
+        }
+    }
+
+    fn cfg() -> WebSearchQualityConfig {
+        WebSearchQualityConfig {
+            max_results_per_domain: 0,
+            ..WebSearchQualityConfig::default()
+        }
+    }
+
+    #[test]
+    fn normalize_url_ignores_scheme_www_tracking_and_trailing_slash() {
+        assert_eq!(
+            normalize_url("https://www.example.com/post/?utm_source=x&b=2&a=1#top"),
+            normalize_url("http://example.com/post?a=1&b=2"),
+        );
+        assert_ne!(
+            normalize_url("https://example.com/post?id=1"),
+            normalize_url("https://example.com/post?id=2"),
+        );
+    }
+
+    #[test]
+    fn title_key_strips_site_suffix_and_skips_short_titles() {
+        assert_eq!(
+            title_key("Understanding Rust Lifetimes | Some Blog"),
+            title_key("understanding rust lifetimes - Mirror Site"),
+        );
+        assert_eq!(title_key("Home"), None);
+    }
+
+    #[test]
+    fn dedupe_collapses_copies_and_keeps_best_source() {
+        let mut results = vec![
+            card("https://scraper.example/a", "Understanding Rust Lifetimes"),
+            card(
+                "https://blog.example.com/understanding-rust-lifetimes",
+                "Understanding Rust Lifetimes",
+            ),
+            card(
+                "https://doc.rust-lang.org/book/ch10-03-lifetime-syntax.html",
+                "Understanding Rust Lifetimes",
+            ),
+            card(
+                "https://other.example/unrelated",
+                "Something else entirely here",
+            ),
+        ];
+
+        let removed = apply(&mut results, &cfg());
+
+        assert_eq!(removed, 2);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].domain, "doc.rust-lang.org");
+        assert_eq!(results[0].quality, Some(80));
+        assert_eq!(results[0].duplicates.len(), 2);
+        assert!(
+            results[0]
+                .duplicates
+                .contains(&"https://scraper.example/a".to_string())
+        );
+    }
+
+    #[test]
+    fn max_results_per_domain_caps_after_dedupe() {
+        let mut results = vec![
+            card("https://example.com/one", "First distinct article title"),
+            card(
+                "https://www.example.com/two",
+                "Second distinct article title",
+            ),
+            card("https://example.com/three", "Third distinct article title"),
+        ];
+        let cfg = WebSearchQualityConfig {
+            max_results_per_domain: 2,
+            ..WebSearchQualityConfig::default()
+        };
+
+        assert_eq!(apply(&mut results, &cfg), 1);
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn score_penalizes_seo_signals_and_configured_domains() {
+        let mut cfg = cfg();
+        cfg.penalize_domains = vec!["farm.example".into()];
+
+        let spam = card(
+            "https://cdn.farm.example/top-10-best-ways-to-fix-rust-borrow-checker-errors-fast",
+            "Top 10 Ways to Fix Borrow Errors",
+        );
+        let (quality, signals) = score(&spam, &cfg);
+        assert_eq!(quality, 0);
+        assert_eq!(signals, vec!["low-quality domain", "seo slug", "listicle"]);
+
+        let docs = card("https://example.com/docs/guide", "Guide");
+        assert_eq!(score(&docs, &cfg), (80, vec!["official docs".to_string()]));
+    }
+
+    #[test]
+    fn domain_listed_matches_subdomains_only_on_label_boundary() {
+        let domains = vec!["rust-lang.org".to_string()];
+        assert!(domain_listed("rust-lang.org", &domains));
+        assert!(domain_listed("doc.rust-lang.org", &domains));
+        assert!(!domain_listed("notrust-lang.org", &domains));
+    }
+
+    #[test]
+    fn dedupe_disabled_keeps_everything() {
+        let mut results = vec![
+            card("https://example.com/a", "Same title for both results"),
+            card("https://example.com/a/", "Same title for both results"),
+        ];
+        let cfg = WebSearchQualityConfig {
+            dedupe: false,
+            ..cfg()
+        };
+        assert_eq!(apply(&mut results, &cfg), 0);
+        assert!(results.iter().all(|r| r.quality == Some(50)));
+    }
+}
@@ -6,6 +6,7 @@ use chrono::Utc;
 use url::Url;
 
 use crate::WebTools;
+use crate::quality;
 use crate::types::WebSearchInput;
 use crate::types::WebSearchOutput;
 use crate::types::WebSearchResultCard;
@@ -68,7 +69,7 @@ pub async fn web_search(
         .map(|s| trim_chars(&s, MAX_CONTEXT_CHARS));
 
     // Map results to cards
-    let results: Vec<WebSearchResultCard> = resp
+    let mut results: Vec<WebSearchResultCard> = resp
         .results
         .into_iter()
         .map(|r| {
@@ -84,15 +85,21 @@ pub async fn web_search(
                 author: r.author,
                 score,
                 snippet,
+                quality: None,
+                quality_signals: Vec::new(),
+                duplicates: Vec::new(),
             }
         })
         .collect();
 
+    let removed_results = quality::apply(&mut results, &tools.cfg.search_quality);
+
     Ok(WebSearchOutput {
         query: input.query,
         retrieved_at: Utc::now(),
         context,
         results,
+        removed_results,
     })
 }
 
@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+use agentic_tools_core::Permissions;
 use agentic_tools_core::ToolRegistry;
 use agentic_tools_core::context::ToolContext;
 use agentic_tools_core::error::ToolError;
@@ -38,6 +39,7 @@ impl Tool for WebFetchTool {
 
     const NAME: &'static str = "web_fetch";
     const DESCRIPTION: &'static str = "Fetch a URL over HTTP and convert the page to clean Markdown with metadata. Default summarize=false; set summarize=true to generate a short Haiku summary (requires Anthropic credentials).";
+    const PERMISSIONS: Permissions = Permissions::NETWORK;
 
     fn call(
         &self,
@@ -73,7 +75,8 @@ impl Tool for WebSearchTool {
     type Output = WebSearchOutput;
 
     const NAME: &'static str = "web_search";
-    const DESCRIPTION: &'static str = "Semantic/neural web search (Exa). Use NATURAL LANGUAGE queries (questions/descriptions). Do NOT use keyword-stuffed, Google-style queries. Returns compact, citable result cards with URLs plus a short trimmed context to orient you.";
+    const DESCRIPTION: &'static str = "Semantic/neural web search (Exa). Use NATURAL LANGUAGE queries (questions/descriptions). Do NOT use keyword-stuffed, Google-style queries. Returns compact, citable result cards with URLs plus a short trimmed context to orient you. Near-duplicate results are collapsed and each card carries a source quality score (official docs boosted, SEO spam penalized).";
+    const PERMISSIONS: Permissions = Permissions::NETWORK;
 
     fn call(
         &self,
@@ -103,6 +103,8 @@ pub struct WebSearchOutput {
     pub context: Option<String>,
     /// Compact, citable result cards
     pub results: Vec<WebSearchResultCard>,
+    /// Results dropped as duplicates or by the per-domain cap
+    pub removed_results: usize,
 }
 
 /// A single result card from web search.
@@ -122,6 +124,14 @@ pub struct WebSearchResultCard {
     pub score: Option<u32>,
     /// Short snippet (up to 300 chars) from highlights or summary
     pub snippet: Option<String>,
+    /// Source quality score (0-100, 50 is neutral)
+    pub quality: Option<u32>,
+    /// Heuristics that adjusted the quality score (e.g. "official docs", "seo slug")
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub quality_signals: Vec<String>,
+    /// URLs of near-identical results collapsed into this one
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub duplicates: Vec<String>,
 }
 
 impl TextFormat for WebSearchOutput {
@@ -139,6 +149,13 @@ impl TextFormat for WebSearchOutput {
         }
 
         let _ = write!(out, "\n--- Results ({}) ---\n", self.results.len());
+        if self.removed_results > 0 {
+            let _ = writeln!(
+                out,
+                "({} duplicate or same-domain results removed)",
+                self.removed_results
+            );
+        }
         for (i, card) in self.results.iter().enumerate() {
             let _ = write!(
                 out,
@@ -158,12 +175,29 @@ impl TextFormat for WebSearchOutput {
             if !meta.is_empty() {
                 let _ = writeln!(out, "   {}", meta.join(" | "));
             }
+            let mut scores = Vec::new();
             if let Some(score) = card.score {
-                let _ = writeln!(out, "   Score: {score}/100");
+                scores.push(format!("Score: {score}/100"));
+            }
+            if let Some(quality) = card.quality {
+                if card.quality_signals.is_empty() {
+                    scores.push(format!("Quality: {quality}/100"));
+                } else {
+                    scores.push(format!(
+                        "Quality: {quality}/100 ({})",
+                        card.quality_signals.join(", ")
+                    ));
+                }
+            }
+            if !scores.is_empty() {
+                let _ = writeln!(out, "   {}", scores.join(" | "));
             }
             if let Some(snippet) = &card.snippet {
                 let _ = writeln!(out, "   {snippet}");
             }
+            if !card.duplicates.is_empty() {
+                let _ = writeln!(out, "   Also at: {}", card.duplicates.join(", "));
+            }
         }
         out
     }
@@ -187,7 +221,11 @@ mod tests {
                 author: Some("Jane Doe".into()), // Has author
                 score: None,
                 snippet: None,
+                quality: None,
+                quality_signals: Vec::new(),
+                duplicates: Vec::new(),
             }],
+            removed_results: 0,
         };
 
         let text = output.fmt_text(&TextOptions::default());
@@ -211,7 +249,11 @@ mod tests {
                 author: Some("John Smith".into()),
                 score: None,
                 snippet: None,
+                quality: None,
+                quality_signals: Vec::new(),
+                duplicates: Vec::new(),
             }],
+            removed_results: 0,
         };
 
         let text = output.fmt_text(&TextOptions::default());
@@ -221,6 +263,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn quality_and_duplicates_displayed() {
+        let output = WebSearchOutput {
+            query: "test query".into(),
+            retrieved_at: Utc::now(),
+            context: None,
+            results: vec![WebSearchResultCard {
+                url: "https://docs.rs/tokio".into(),
+                domain: "docs.rs".into(),
+                title: Some("tokio".into()),
+                published_date: None,
+                author: None,
+                score: Some(91),
+                snippet: None,
+                quality: Some(80),
+                quality_signals: vec!["official docs".into()],
+                duplicates: vec!["https://mirror.example/tokio".into()],
+            }],
+            removed_results: 1,
+        };
+
+        let text = output.fmt_text(&TextOptions::default());
+        assert!(text.contains("(1 duplicate or same-domain results removed)"));
+        assert!(text.contains("Score: 91/100 | Quality: 80/100 (official docs)"));
+        assert!(text.contains("Also at: https://mirror.example/tokio"));
+    }
+
     #[test]
     fn date_displayed_without_author() {
         let output = WebSearchOutput {
@@ -235,7 +304,11 @@ mod tests {
                 author: None,
                 score: None,
                 snippet: None,
+                quality: None,
+                quality_signals: Vec::new(),
+                duplicates: Vec::new(),
             }],
+            removed_results: 0,
         };
 
         let text = output.fmt_text(&TextOptions::default());
@@ -1,5 +1,6 @@
 use agentic_config::types::WorkspaceToolsConfig;
 use agentic_tools_core::BoxFuture;
+use agentic_tools_core::Permissions;
 use agentic_tools_core::Tool;
 use agentic_tools_core::ToolContext;
 use agentic_tools_core::ToolError;
@@ -91,6 +92,7 @@ impl Tool for WorkspaceReadTool {
     type Output = String;
     const NAME: &'static str = "workspace_read";
     const DESCRIPTION: &'static str = "Read files or directories inside the current workspace. Prefer workspace-relative paths such as `src/main.rs`.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS;
 
     fn call(
         &self,
@@ -123,6 +125,7 @@ impl Tool for WorkspaceEditTool {
     type Output = String;
     const NAME: &'static str = "workspace_edit";
     const DESCRIPTION: &'static str = "Edit a file inside the current workspace using exact string replacement. Prefer workspace-relative paths.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS.union(Permissions::WRITE_FS);
 
     fn call(
         &self,
@@ -139,6 +142,7 @@ impl Tool for WorkspaceApplyPatchTool {
     type Output = String;
     const NAME: &'static str = "workspace_apply_patch";
     const DESCRIPTION: &'static str = "Apply an OpenCode-style patch inside the current workspace. Prefer workspace-relative paths.";
+    const PERMISSIONS: Permissions = Permissions::READ_FS.union(Permissions::WRITE_FS);
 
     fn call(
         &self,